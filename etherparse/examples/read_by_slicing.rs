@@ -44,6 +44,16 @@ fn main() {
                     value.source(),
                     value.destination()
                 ),
+                Some(LinuxSll(value)) => println!(
+                    "  LinuxSll (packet type: {:?}, source address: {:?})",
+                    value.packet_type(),
+                    value.sender_address()
+                ),
+                Some(LinuxSll2(value)) => println!(
+                    "  LinuxSll2 (interface index: {:?}, source address: {:?})",
+                    value.interface_index(),
+                    value.sender_address()
+                ),
                 None => {}
             }
 
@@ -54,6 +64,13 @@ fn main() {
                     value.outer().vlan_identifier(),
                     value.inner().vlan_identifier()
                 ),
+                Some(MultiVlan(value)) => {
+                    print!("  MultiVlan");
+                    for tag in value.tags() {
+                        print!(" {:?}", tag.vlan_identifier());
+                    }
+                    println!();
+                }
                 None => {}
             }
 
@@ -16,8 +16,8 @@ fn main() {
 
     //Lets start out with an ethernet II header containing the mac addresses
     Ethernet2Header {
-        destination: [1, 2, 3, 4, 5, 6],
-        source: [11, 12, 13, 14, 15, 16],
+        destination: MacAddress([1, 2, 3, 4, 5, 6]),
+        source: MacAddress([11, 12, 13, 14, 15, 16]),
         ether_type: ether_type::IPV4,
     }
     .write(&mut out)
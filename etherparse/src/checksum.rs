@@ -899,3 +899,190 @@ pub mod u64_16bit_word {
         }
     }
 }
+
+/// Helper for calculating the IEEE 802.3 CRC-32 used as frame check
+/// sequence (FCS) in ethernet frames.
+///
+/// The calculation uses the "slicing-by-8" algorithm (eight lookup
+/// tables generated at compile time, processing 8 bytes per step).
+///
+/// # Example
+///
+/// ```
+/// use etherparse::checksum::Crc32;
+///
+/// let crc = Crc32::new().add_slice(b"1234").add_slice(b"56789");
+/// assert_eq!(0xCBF4_3926, crc.value());
+///
+/// // the FCS is transmitted in little endian byte order
+/// let fcs = crc.value().to_le_bytes();
+/// assert_eq!([0x26, 0x39, 0xF4, 0xCB], fcs);
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Crc32 {
+    /// Partial (not yet inverted) crc value.
+    state: u32,
+}
+
+impl Crc32 {
+    /// Reversed representation of the IEEE 802.3 CRC-32 polynomial.
+    pub const POLYNOMIAL: u32 = 0xEDB8_8320;
+
+    /// Lookup tables for the slicing-by-8 algorithm.
+    const TABLES: [[u32; 256]; 8] = Crc32::gen_tables();
+
+    /// Creates a new CRC-32 calculation without any data added.
+    #[inline]
+    pub const fn new() -> Crc32 {
+        Crc32 { state: 0xffff_ffff }
+    }
+
+    /// Add the given slice to the CRC-32.
+    pub fn add_slice(self, slice: &[u8]) -> Crc32 {
+        let t = &Crc32::TABLES;
+        let mut crc = self.state;
+
+        // process 8 bytes at a time
+        let mut chunks = slice.chunks_exact(8);
+        for chunk in &mut chunks {
+            let a = crc ^ u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+            let b = u32::from_le_bytes([chunk[4], chunk[5], chunk[6], chunk[7]]);
+            crc = t[7][(a & 0xff) as usize]
+                ^ t[6][((a >> 8) & 0xff) as usize]
+                ^ t[5][((a >> 16) & 0xff) as usize]
+                ^ t[4][(a >> 24) as usize]
+                ^ t[3][(b & 0xff) as usize]
+                ^ t[2][((b >> 8) & 0xff) as usize]
+                ^ t[1][((b >> 16) & 0xff) as usize]
+                ^ t[0][(b >> 24) as usize];
+        }
+
+        // process the rest byte by byte
+        for byte in chunks.remainder() {
+            crc = t[0][((crc ^ u32::from(*byte)) & 0xff) as usize] ^ (crc >> 8);
+        }
+
+        Crc32 { state: crc }
+    }
+
+    /// Returns the CRC-32 of all data added so far.
+    #[inline]
+    pub fn value(&self) -> u32 {
+        !self.state
+    }
+
+    /// Generates the lookup tables for the slicing-by-8 algorithm.
+    const fn gen_tables() -> [[u32; 256]; 8] {
+        let mut tables = [[0u32; 256]; 8];
+
+        // classic byte wise table
+        let mut i = 0;
+        while i < 256 {
+            let mut crc = i as u32;
+            let mut bit = 0;
+            while bit < 8 {
+                crc = if 0 != crc & 1 {
+                    (crc >> 1) ^ Crc32::POLYNOMIAL
+                } else {
+                    crc >> 1
+                };
+                bit += 1;
+            }
+            tables[0][i] = crc;
+            i += 1;
+        }
+
+        // tables for the following bytes
+        let mut k = 1;
+        while k < 8 {
+            let mut i = 0;
+            while i < 256 {
+                let prev = tables[k - 1][i];
+                tables[k][i] = (prev >> 8) ^ tables[0][(prev & 0xff) as usize];
+                i += 1;
+            }
+            k += 1;
+        }
+        tables
+    }
+}
+
+impl Default for Crc32 {
+    #[inline]
+    fn default() -> Self {
+        Crc32::new()
+    }
+}
+
+#[cfg(test)]
+mod crc32_tests {
+    use super::*;
+    use alloc::format;
+    use proptest::prelude::*;
+
+    /// Bitwise reference implementation.
+    fn reference_crc32(data: &[u8]) -> u32 {
+        let mut crc = 0xffff_ffffu32;
+        for byte in data {
+            crc ^= u32::from(*byte);
+            for _ in 0..8 {
+                crc = if 0 != crc & 1 {
+                    (crc >> 1) ^ Crc32::POLYNOMIAL
+                } else {
+                    crc >> 1
+                };
+            }
+        }
+        !crc
+    }
+
+    #[test]
+    fn new_default_clone_eq_debug() {
+        assert_eq!(0, Crc32::new().value());
+        assert_eq!(Crc32::new(), Crc32::default());
+        assert_eq!(Crc32::new(), Crc32::new().clone());
+        assert_eq!("Crc32 { state: 4294967295 }", format!("{:?}", Crc32::new()));
+    }
+
+    #[test]
+    fn known_values() {
+        assert_eq!(0, Crc32::new().add_slice(&[]).value());
+        assert_eq!(0xCBF4_3926, Crc32::new().add_slice(b"123456789").value());
+        assert_eq!(
+            0x414F_A339,
+            Crc32::new()
+                .add_slice(b"The quick brown fox jumps over the lazy dog")
+                .value()
+        );
+    }
+
+    #[test]
+    fn residue() {
+        // appending the little endian crc to the data results in the
+        // well known CRC-32 residue
+        let data = b"123456789";
+        let fcs = Crc32::new().add_slice(data).value().to_le_bytes();
+        assert_eq!(
+            0x2144_DF1C,
+            Crc32::new().add_slice(data).add_slice(&fcs).value()
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn add_slice(
+            data in proptest::collection::vec(any::<u8>(), 0..100),
+            split in 0usize..100
+        ) {
+            let split = core::cmp::min(split, data.len());
+            assert_eq!(reference_crc32(&data), Crc32::new().add_slice(&data).value());
+            assert_eq!(
+                reference_crc32(&data),
+                Crc32::new()
+                    .add_slice(&data[..split])
+                    .add_slice(&data[split..])
+                    .value()
+            );
+        }
+    }
+}
@@ -0,0 +1,90 @@
+use super::IpFragRange;
+
+/// Policy deciding which data is kept if a received fragment overlaps
+/// with data of a previously received fragment.
+///
+/// Different operating systems resolve overlapping fragments differently
+/// (see "Target-Based Fragmentation Reassembly" by Judy Novak and the
+/// "target-based" policies of Snort's frag3 preprocessor). Choosing the
+/// policy of the monitored host allows an analyzer to see the same
+/// packet the host will see.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum FragOverlapPolicy {
+    /// Data of previously received fragments is always kept.
+    #[default]
+    First,
+    /// Data of the newly received fragment always replaces previously
+    /// received data.
+    Last,
+    /// Previously received data is kept, unless the new fragment starts
+    /// before the fragment that supplied the previously received data.
+    Bsd,
+    /// Same as [`FragOverlapPolicy::Bsd`], but the new fragment also wins
+    /// if it starts at the same offset as the fragment that supplied the
+    /// previously received data and is at least as long.
+    Linux,
+}
+
+impl FragOverlapPolicy {
+    /// Returns true if the data of the new fragment replaces the
+    /// overlapping data of the existing fragment.
+    ///
+    /// `existing` & `new` are the complete ranges of the fragments (not
+    /// just the overlapping parts).
+    pub fn new_data_wins(self, existing: &IpFragRange, new: &IpFragRange) -> bool {
+        use FragOverlapPolicy::*;
+        match self {
+            First => false,
+            Last => true,
+            Bsd => new.start < existing.start,
+            Linux => {
+                new.start < existing.start
+                    || (new.start == existing.start && new.end >= existing.end)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::format;
+
+    #[test]
+    fn debug_clone_eq_default() {
+        assert_eq!("Bsd", format!("{:?}", FragOverlapPolicy::Bsd));
+        assert_eq!(FragOverlapPolicy::Linux, FragOverlapPolicy::Linux.clone());
+        assert_eq!(FragOverlapPolicy::First, FragOverlapPolicy::default());
+    }
+
+    #[test]
+    fn new_data_wins() {
+        use FragOverlapPolicy::*;
+
+        let existing = IpFragRange { start: 8, end: 16 };
+        let before = IpFragRange { start: 0, end: 16 };
+        let same_longer = IpFragRange { start: 8, end: 24 };
+        let same_equal = IpFragRange { start: 8, end: 16 };
+        let same_shorter = IpFragRange { start: 8, end: 12 };
+        let after = IpFragRange { start: 12, end: 24 };
+
+        // [before, same_longer, same_equal, same_shorter, after]
+        for (policy, expected) in [
+            (First, [false, false, false, false, false]),
+            (Last, [true, true, true, true, true]),
+            (Bsd, [true, false, false, false, false]),
+            (Linux, [true, true, true, false, false]),
+        ] {
+            assert_eq!(
+                expected,
+                [
+                    policy.new_data_wins(&existing, &before),
+                    policy.new_data_wins(&existing, &same_longer),
+                    policy.new_data_wins(&existing, &same_equal),
+                    policy.new_data_wins(&existing, &same_shorter),
+                    policy.new_data_wins(&existing, &after),
+                ]
+            );
+        }
+    }
+}
@@ -0,0 +1,615 @@
+use super::{FragOverlapPolicy, IpFragRange};
+use crate::{err::ip_defrag::IpDefragError, IpFragOffset};
+use std::{vec, vec::Vec};
+
+/// Buffer reassembling the payload of a single fragmented IP packet.
+///
+/// Missing parts are tracked with a list of "holes" as described in
+/// RFC 815. Additionally the buffer remembers which fragment supplied
+/// which part of the data so overlapping fragments can be resolved
+/// according to a [`FragOverlapPolicy`].
+///
+/// # Example
+///
+/// ```
+/// use etherparse::{defrag::{FragOverlapPolicy, IpDefragBuf}, IpFragOffset};
+///
+/// let mut buf = IpDefragBuf::new(u16::MAX, FragOverlapPolicy::First);
+///
+/// // second fragment (offset 1 * 8 bytes, last fragment)
+/// buf.add(IpFragOffset::try_new(1).unwrap(), false, &[9, 10]).unwrap();
+/// assert!(false == buf.is_complete());
+///
+/// // first fragment
+/// buf.add(IpFragOffset::ZERO, true, &[1, 2, 3, 4, 5, 6, 7, 8]).unwrap();
+/// assert!(buf.is_complete());
+/// assert_eq!(buf.data(), &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct IpDefragBuf {
+    /// Maximum length of the reassembled payload.
+    max_payload_len: u16,
+
+    /// Policy used to resolve overlapping fragments.
+    overlap_policy: FragOverlapPolicy,
+
+    /// Reassembled data (holes are filled with zeros).
+    data: Vec<u8>,
+
+    /// Parts of the payload that have not been received yet (RFC 815
+    /// hole descriptors), sorted by offset.
+    holes: Vec<IpFragRange>,
+
+    /// Received parts of the payload & the range of the fragment
+    /// that supplied the data, sorted by offset & not overlapping.
+    sections: Vec<(IpFragRange, IpFragRange)>,
+
+    /// End of the payload (set when the last fragment was received).
+    end: Option<u32>,
+}
+
+impl IpDefragBuf {
+    /// Creates an empty buffer.
+    pub fn new(max_payload_len: u16, overlap_policy: FragOverlapPolicy) -> IpDefragBuf {
+        IpDefragBuf {
+            max_payload_len,
+            overlap_policy,
+            data: Vec::new(),
+            holes: vec![IpFragRange {
+                start: 0,
+                end: u32::MAX,
+            }],
+            sections: Vec::new(),
+            end: None,
+        }
+    }
+
+    /// Maximum length of the reassembled payload.
+    #[inline]
+    pub fn max_payload_len(&self) -> u16 {
+        self.max_payload_len
+    }
+
+    /// Policy used to resolve overlapping fragments.
+    #[inline]
+    pub fn overlap_policy(&self) -> FragOverlapPolicy {
+        self.overlap_policy
+    }
+
+    /// Data reassembled so far (not yet received parts are filled with zeros).
+    #[inline]
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// Parts of the payload that have not been received yet.
+    ///
+    /// As long as the last fragment has not been received the last hole
+    /// ends at `u32::MAX`.
+    #[inline]
+    pub fn holes(&self) -> &[IpFragRange] {
+        &self.holes
+    }
+
+    /// End of the payload in bytes (known after the last fragment was received).
+    #[inline]
+    pub fn end(&self) -> Option<u32> {
+        self.end
+    }
+
+    /// Returns true if all fragments were received.
+    #[inline]
+    pub fn is_complete(&self) -> bool {
+        self.end.is_some() && self.holes.is_empty()
+    }
+
+    /// Returns the reassembled data.
+    #[inline]
+    pub fn into_data(self) -> Vec<u8> {
+        self.data
+    }
+
+    /// Returns true if the given range overlaps with already received data.
+    pub fn overlaps(&self, range: &IpFragRange) -> bool {
+        self.sections.iter().any(|(s, _)| s.overlaps(range))
+    }
+
+    /// Validates the fragment and returns its range without modifying
+    /// the buffer.
+    pub fn check(
+        &self,
+        offset: IpFragOffset,
+        more_fragments: bool,
+        payload_len: usize,
+    ) -> Result<IpFragRange, IpDefragError> {
+        use IpDefragError::*;
+
+        if more_fragments && 0 != payload_len & 0b111 {
+            return Err(UnalignedFragmentPayloadLen {
+                offset,
+                payload_len,
+            });
+        }
+
+        let start = usize::from(offset.value()) * 8;
+        let end = start + payload_len;
+        if end > usize::from(self.max_payload_len) {
+            return Err(SegmentTooBig {
+                offset,
+                payload_len,
+                max: self.max_payload_len,
+            });
+        }
+        // cast ok as the end is limited by max_payload_len
+        let range = IpFragRange {
+            start: start as u32,
+            end: end as u32,
+        };
+
+        if let Some(previous_end) = self.end {
+            if range.end > previous_end || (false == more_fragments && range.end != previous_end) {
+                return Err(ConflictingEnd {
+                    previous_end,
+                    conflicting_end: range.end,
+                });
+            }
+        } else if false == more_fragments && self.data.len() > end {
+            return Err(ConflictingEnd {
+                previous_end: self.data.len() as u32,
+                conflicting_end: range.end,
+            });
+        }
+
+        Ok(range)
+    }
+
+    /// Adds the payload of a fragment to the buffer.
+    ///
+    /// `offset` is the fragment offset (in 8 octets) and `more_fragments`
+    /// must be `false` for the last fragment of the packet. On error the
+    /// fragment is discarded and the buffer is left unchanged.
+    pub fn add(
+        &mut self,
+        offset: IpFragOffset,
+        more_fragments: bool,
+        payload: &[u8],
+    ) -> Result<(), IpDefragError> {
+        let range = self.check(offset, more_fragments, payload.len())?;
+
+        // copy the data based on the overlap policy
+        if false == range.is_empty() {
+            if self.data.len() < range.end as usize {
+                self.data.resize(range.end as usize, 0);
+            }
+
+            let old_sections = core::mem::take(&mut self.sections);
+            let mut sections = Vec::with_capacity(old_sections.len() + 2);
+            let mut pos = range.start;
+            for (section, origin) in old_sections {
+                if false == section.overlaps(&range) {
+                    if section.start >= range.end && pos < range.end {
+                        self.write(range.start, pos, range.end, payload);
+                        sections.push((
+                            IpFragRange {
+                                start: pos,
+                                end: range.end,
+                            },
+                            range,
+                        ));
+                        pos = range.end;
+                    }
+                    sections.push((section, origin));
+                    continue;
+                }
+
+                // fill the gap before the section
+                if pos < section.start {
+                    self.write(range.start, pos, section.start, payload);
+                    sections.push((
+                        IpFragRange {
+                            start: pos,
+                            end: section.start,
+                        },
+                        range,
+                    ));
+                    pos = section.start;
+                }
+
+                let overlap_end = section.end.min(range.end);
+                if self.overlap_policy.new_data_wins(&origin, &range) {
+                    if section.start < pos {
+                        sections.push((
+                            IpFragRange {
+                                start: section.start,
+                                end: pos,
+                            },
+                            origin,
+                        ));
+                    }
+                    self.write(range.start, pos, overlap_end, payload);
+                    sections.push((
+                        IpFragRange {
+                            start: pos,
+                            end: overlap_end,
+                        },
+                        range,
+                    ));
+                    if overlap_end < section.end {
+                        sections.push((
+                            IpFragRange {
+                                start: overlap_end,
+                                end: section.end,
+                            },
+                            origin,
+                        ));
+                    }
+                } else {
+                    sections.push((section, origin));
+                }
+                pos = overlap_end;
+            }
+            if pos < range.end {
+                self.write(range.start, pos, range.end, payload);
+                sections.push((
+                    IpFragRange {
+                        start: pos,
+                        end: range.end,
+                    },
+                    range,
+                ));
+            }
+            self.sections = sections;
+        }
+
+        // update the holes (RFC 815)
+        let old_holes = core::mem::take(&mut self.holes);
+        for hole in old_holes {
+            let hole = if more_fragments {
+                hole
+            } else {
+                // everything after the last fragment is not a hole
+                IpFragRange {
+                    start: hole.start,
+                    end: hole.end.min(range.end),
+                }
+            };
+            if hole.is_empty() {
+                continue;
+            }
+            if false == hole.overlaps(&range) {
+                self.holes.push(hole);
+                continue;
+            }
+            if hole.start < range.start {
+                self.holes.push(IpFragRange {
+                    start: hole.start,
+                    end: range.start,
+                });
+            }
+            if range.end < hole.end {
+                self.holes.push(IpFragRange {
+                    start: range.end,
+                    end: hole.end,
+                });
+            }
+        }
+
+        if false == more_fragments {
+            self.end = Some(range.end);
+        }
+        Ok(())
+    }
+
+    /// Copies the part `[start, end)` of a fragment starting at `frag_start`
+    /// into the data buffer.
+    fn write(&mut self, frag_start: u32, start: u32, end: u32, payload: &[u8]) {
+        self.data[start as usize..end as usize]
+            .copy_from_slice(&payload[(start - frag_start) as usize..(end - frag_start) as usize]);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::{format, vec};
+
+    fn offset(value: u16) -> IpFragOffset {
+        IpFragOffset::try_new(value).unwrap()
+    }
+
+    #[test]
+    fn debug_clone_eq() {
+        let buf = IpDefragBuf::new(10, FragOverlapPolicy::Bsd);
+        assert_eq!(
+            "IpDefragBuf { max_payload_len: 10, overlap_policy: Bsd, data: [], holes: [IpFragRange { start: 0, end: 4294967295 }], sections: [], end: None }",
+            format!("{:?}", buf)
+        );
+        assert_eq!(buf, buf.clone());
+    }
+
+    #[test]
+    fn new_getters() {
+        let buf = IpDefragBuf::new(10, FragOverlapPolicy::Bsd);
+        assert_eq!(10, buf.max_payload_len());
+        assert_eq!(FragOverlapPolicy::Bsd, buf.overlap_policy());
+        assert_eq!(buf.data(), &[]);
+        assert_eq!(
+            buf.holes(),
+            &[IpFragRange {
+                start: 0,
+                end: u32::MAX
+            }]
+        );
+        assert_eq!(None, buf.end());
+        assert!(false == buf.is_complete());
+        assert!(false == buf.overlaps(&IpFragRange { start: 0, end: 8 }));
+    }
+
+    #[test]
+    fn add_in_order() {
+        let mut buf = IpDefragBuf::new(u16::MAX, FragOverlapPolicy::First);
+        buf.add(offset(0), true, &[1; 8]).unwrap();
+        assert_eq!(
+            buf.holes(),
+            &[IpFragRange {
+                start: 8,
+                end: u32::MAX
+            }]
+        );
+        assert!(buf.overlaps(&IpFragRange { start: 7, end: 8 }));
+        assert!(false == buf.overlaps(&IpFragRange { start: 8, end: 9 }));
+
+        buf.add(offset(1), true, &[2; 16]).unwrap();
+        assert_eq!(
+            buf.holes(),
+            &[IpFragRange {
+                start: 24,
+                end: u32::MAX
+            }]
+        );
+        assert!(false == buf.is_complete());
+
+        buf.add(offset(3), false, &[3; 3]).unwrap();
+        assert_eq!(buf.holes(), &[]);
+        assert_eq!(Some(27), buf.end());
+        assert!(buf.is_complete());
+
+        let mut expected = vec![1; 8];
+        expected.extend_from_slice(&[2; 16]);
+        expected.extend_from_slice(&[3; 3]);
+        assert_eq!(buf.data(), &expected[..]);
+        assert_eq!(buf.into_data(), expected);
+    }
+
+    #[test]
+    fn add_out_of_order() {
+        let mut buf = IpDefragBuf::new(u16::MAX, FragOverlapPolicy::First);
+
+        buf.add(offset(2), false, &[3; 4]).unwrap();
+        assert_eq!(buf.holes(), &[IpFragRange { start: 0, end: 16 }]);
+        assert_eq!(Some(20), buf.end());
+
+        buf.add(offset(0), true, &[1; 8]).unwrap();
+        assert_eq!(buf.holes(), &[IpFragRange { start: 8, end: 16 }]);
+        assert!(false == buf.is_complete());
+
+        buf.add(offset(1), true, &[2; 8]).unwrap();
+        assert!(buf.is_complete());
+        let mut expected = vec![1; 8];
+        expected.extend_from_slice(&[2; 8]);
+        expected.extend_from_slice(&[3; 4]);
+        assert_eq!(buf.data(), &expected[..]);
+    }
+
+    #[test]
+    fn add_hole_in_the_middle() {
+        let mut buf = IpDefragBuf::new(u16::MAX, FragOverlapPolicy::First);
+        buf.add(offset(0), true, &[1; 8]).unwrap();
+        buf.add(offset(4), true, &[1; 8]).unwrap();
+        assert_eq!(
+            buf.holes(),
+            &[
+                IpFragRange { start: 8, end: 32 },
+                IpFragRange {
+                    start: 40,
+                    end: u32::MAX
+                }
+            ]
+        );
+        buf.add(offset(2), true, &[1; 8]).unwrap();
+        assert_eq!(
+            buf.holes(),
+            &[
+                IpFragRange { start: 8, end: 16 },
+                IpFragRange { start: 24, end: 32 },
+                IpFragRange {
+                    start: 40,
+                    end: u32::MAX
+                }
+            ]
+        );
+        buf.add(offset(5), false, &[]).unwrap();
+        assert_eq!(
+            buf.holes(),
+            &[
+                IpFragRange { start: 8, end: 16 },
+                IpFragRange { start: 24, end: 32 },
+            ]
+        );
+        buf.add(offset(1), true, &[1; 8]).unwrap();
+        buf.add(offset(3), true, &[1; 8]).unwrap();
+        assert!(buf.is_complete());
+        assert_eq!(buf.data(), &[1; 40]);
+    }
+
+    #[test]
+    fn add_overlap_policies() {
+        // existing fragments: [8, 16) with 1s & [24, 32) with 2s, the new
+        // fragment [0, 40) or [8, 24) with 3s
+        let setup = |policy| {
+            let mut buf = IpDefragBuf::new(u16::MAX, policy);
+            buf.add(offset(1), true, &[1; 8]).unwrap();
+            buf.add(offset(3), true, &[2; 8]).unwrap();
+            buf
+        };
+
+        use FragOverlapPolicy::*;
+
+        // new fragment starts before the existing fragments
+        for (policy, new_wins) in [(First, false), (Last, true), (Bsd, true), (Linux, true)] {
+            let mut buf = setup(policy);
+            buf.add(offset(0), false, &[3; 40]).unwrap();
+            assert!(buf.is_complete());
+
+            let mut expected = [3; 40];
+            if false == new_wins {
+                expected[8..16].copy_from_slice(&[1; 8]);
+                expected[24..32].copy_from_slice(&[2; 8]);
+            }
+            assert_eq!(buf.data(), &expected[..], "{:?}", policy);
+        }
+
+        // new fragment starts at the same offset & is longer
+        for (policy, new_wins) in [(First, false), (Last, true), (Bsd, false), (Linux, true)] {
+            let mut buf = setup(policy);
+            buf.add(offset(1), true, &[3; 16]).unwrap();
+
+            let mut expected = [0; 32];
+            expected[8..16].copy_from_slice(&[if new_wins { 3 } else { 1 }; 8]);
+            expected[16..24].copy_from_slice(&[3; 8]);
+            expected[24..32].copy_from_slice(&[2; 8]);
+            assert_eq!(buf.data(), &expected[..], "{:?}", policy);
+            assert_eq!(
+                buf.holes(),
+                &[
+                    IpFragRange { start: 0, end: 8 },
+                    IpFragRange {
+                        start: 32,
+                        end: u32::MAX
+                    }
+                ]
+            );
+        }
+
+        // new fragment starts in the middle of an existing fragment
+        for (policy, new_wins) in [(First, false), (Last, true), (Bsd, false), (Linux, false)] {
+            let mut buf = IpDefragBuf::new(u16::MAX, policy);
+            buf.add(offset(0), true, &[1; 16]).unwrap();
+            buf.add(offset(1), true, &[3; 16]).unwrap();
+
+            let mut expected = vec![1; 8];
+            expected.extend_from_slice(&[if new_wins { 3 } else { 1 }; 8]);
+            expected.extend_from_slice(&[3; 8]);
+            assert_eq!(buf.data(), &expected[..], "{:?}", policy);
+
+            // a third fragment is compared against the fragment that
+            // supplied the data
+            buf.add(offset(1), false, &[4; 16]).unwrap();
+            let expected: [u8; 24] = match policy {
+                First | Bsd => {
+                    let mut e = [1; 24];
+                    e[16..].copy_from_slice(&[3; 8]);
+                    e
+                }
+                Last => {
+                    let mut e = [4; 24];
+                    e[..8].copy_from_slice(&[1; 8]);
+                    e
+                }
+                // same start & length as the fragment that supplied [16, 24)
+                Linux => {
+                    let mut e = [1; 24];
+                    e[16..].copy_from_slice(&[4; 8]);
+                    e
+                }
+            };
+            assert_eq!(buf.data(), &expected[..], "{:?}", policy);
+            assert!(buf.is_complete());
+        }
+    }
+
+    #[test]
+    fn add_errors() {
+        use IpDefragError::*;
+
+        // unaligned
+        {
+            let mut buf = IpDefragBuf::new(u16::MAX, FragOverlapPolicy::First);
+            assert_eq!(
+                Err(UnalignedFragmentPayloadLen {
+                    offset: offset(1),
+                    payload_len: 7
+                }),
+                buf.add(offset(1), true, &[0; 7])
+            );
+            // unaligned is ok for the last fragment
+            buf.add(offset(1), false, &[0; 7]).unwrap();
+        }
+
+        // too big
+        {
+            let mut buf = IpDefragBuf::new(16, FragOverlapPolicy::First);
+            assert_eq!(
+                Err(SegmentTooBig {
+                    offset: offset(1),
+                    payload_len: 9,
+                    max: 16,
+                }),
+                buf.add(offset(1), false, &[0; 9])
+            );
+            assert_eq!(
+                Err(SegmentTooBig {
+                    offset: IpFragOffset::try_new(IpFragOffset::MAX_U16).unwrap(),
+                    payload_len: 0,
+                    max: 16,
+                }),
+                buf.add(
+                    IpFragOffset::try_new(IpFragOffset::MAX_U16).unwrap(),
+                    false,
+                    &[]
+                )
+            );
+            buf.add(offset(1), false, &[0; 8]).unwrap();
+        }
+
+        // conflicting ends
+        {
+            let mut buf = IpDefragBuf::new(u16::MAX, FragOverlapPolicy::First);
+            buf.add(offset(1), false, &[0; 8]).unwrap();
+            let before = buf.clone();
+
+            // different last fragment
+            assert_eq!(
+                Err(ConflictingEnd {
+                    previous_end: 16,
+                    conflicting_end: 12
+                }),
+                buf.add(offset(1), false, &[0; 4])
+            );
+            // data after end
+            assert_eq!(
+                Err(ConflictingEnd {
+                    previous_end: 16,
+                    conflicting_end: 24
+                }),
+                buf.add(offset(2), true, &[0; 8])
+            );
+            assert_eq!(before, buf);
+
+            // same end is ok
+            buf.add(offset(0), false, &[0; 16]).unwrap();
+            assert!(buf.is_complete());
+        }
+        {
+            let mut buf = IpDefragBuf::new(u16::MAX, FragOverlapPolicy::First);
+            buf.add(offset(2), true, &[0; 8]).unwrap();
+            assert_eq!(
+                Err(ConflictingEnd {
+                    previous_end: 24,
+                    conflicting_end: 16
+                }),
+                buf.add(offset(1), false, &[0; 8])
+            );
+        }
+    }
+}
@@ -0,0 +1,57 @@
+use super::FragOverlapPolicy;
+
+/// Configuration of an IP defragmentation pool ([`super::Ipv4DefragPool`]
+/// or [`super::Ipv6DefragPool`]).
+///
+/// Timestamps passed to the pool & the timeout are in a unit chosen by
+/// the caller (e.g. seconds or milliseconds), they only have to be
+/// consistent.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct IpDefragConfig {
+    /// Policy used to resolve overlapping IPv4 fragments.
+    ///
+    /// Not used for IPv6, overlapping IPv6 fragments always cause the
+    /// reassembly of the packet to be abandoned (RFC 5722).
+    pub overlap_policy: FragOverlapPolicy,
+
+    /// Maximum number of bytes that can be buffered over all packets
+    /// in reassembly.
+    pub max_buffered_bytes: usize,
+
+    /// Maximum number of packets that can be in reassembly at the same time.
+    pub max_packets: usize,
+
+    /// Time after the first received fragment of a packet after which the
+    /// reassembly of the packet is abandoned (in the unit of the
+    /// timestamps passed to the pool).
+    pub timeout: u64,
+}
+
+impl Default for IpDefragConfig {
+    /// Default configuration based on the Linux defaults (4 MiB memory,
+    /// 30 time units timeout assuming the timestamps are in seconds).
+    fn default() -> Self {
+        IpDefragConfig {
+            overlap_policy: FragOverlapPolicy::First,
+            max_buffered_bytes: 4 * 1024 * 1024,
+            max_packets: 1024,
+            timeout: 30,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::format;
+
+    #[test]
+    fn debug_clone_eq_default() {
+        let config = IpDefragConfig::default();
+        assert_eq!(
+            "IpDefragConfig { overlap_policy: First, max_buffered_bytes: 4194304, max_packets: 1024, timeout: 30 }",
+            format!("{:?}", config)
+        );
+        assert_eq!(config, config.clone());
+    }
+}
@@ -0,0 +1,68 @@
+/// Range of bytes in the payload of a fragmented IP packet (`start`
+/// inclusive, `end` exclusive).
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct IpFragRange {
+    /// Offset of the first byte in the range.
+    pub start: u32,
+    /// Offset of the first byte after the range.
+    pub end: u32,
+}
+
+impl IpFragRange {
+    /// Returns the number of bytes in the range.
+    #[inline]
+    pub fn len(&self) -> u32 {
+        self.end.saturating_sub(self.start)
+    }
+
+    /// Returns true if the range contains no bytes.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.end <= self.start
+    }
+
+    /// Returns true if the two ranges share at least one byte.
+    #[inline]
+    pub fn overlaps(&self, other: &IpFragRange) -> bool {
+        self.start < other.end && other.start < self.end
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::format;
+
+    #[test]
+    fn debug_clone_eq() {
+        let range = IpFragRange { start: 1, end: 2 };
+        assert_eq!("IpFragRange { start: 1, end: 2 }", format!("{:?}", range));
+        assert_eq!(range, range.clone());
+    }
+
+    #[test]
+    fn len_is_empty() {
+        assert_eq!(0, IpFragRange { start: 2, end: 2 }.len());
+        assert!(IpFragRange { start: 2, end: 2 }.is_empty());
+        assert_eq!(0, IpFragRange { start: 3, end: 2 }.len());
+        assert!(IpFragRange { start: 3, end: 2 }.is_empty());
+        assert_eq!(8, IpFragRange { start: 8, end: 16 }.len());
+        assert!(false == IpFragRange { start: 8, end: 16 }.is_empty());
+    }
+
+    #[test]
+    fn overlaps() {
+        let range = IpFragRange { start: 8, end: 16 };
+        for (other, expected) in [
+            (IpFragRange { start: 0, end: 8 }, false),
+            (IpFragRange { start: 0, end: 9 }, true),
+            (IpFragRange { start: 10, end: 12 }, true),
+            (IpFragRange { start: 15, end: 24 }, true),
+            (IpFragRange { start: 16, end: 24 }, false),
+            (IpFragRange { start: 0, end: 24 }, true),
+        ] {
+            assert_eq!(expected, range.overlaps(&other));
+            assert_eq!(expected, other.overlaps(&range));
+        }
+    }
+}
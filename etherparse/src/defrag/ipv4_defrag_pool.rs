@@ -0,0 +1,694 @@
+use super::{IpDefragBuf, IpDefragConfig, Ipv4FragId};
+use crate::{checksum, err::ip_defrag::IpDefragError, Ipv4Slice};
+use std::{collections::HashMap, vec::Vec};
+
+/// Pool reassembling fragmented IPv4 packets.
+///
+/// Fragments are grouped by [`Ipv4FragId`] (source, destination, protocol
+/// & identification). Once all fragments of a packet have been received
+/// the complete packet (IPv4 header of the first fragment with updated
+/// length, fragmentation fields & checksum followed by the reassembled
+/// payload) is returned. It can be decoded again, e.g. via
+/// [`crate::SlicedPacket::from_ip`].
+///
+/// Timestamps are supplied by the caller and are only used to abandon
+/// packets that did not complete within [`IpDefragConfig::timeout`].
+///
+/// # Example
+///
+/// ```
+/// use etherparse::{defrag::{IpDefragConfig, Ipv4DefragPool}, *};
+///
+/// # let mut fragments = Vec::new();
+/// # for (offset, more_fragments, payload) in [(1, false, &[9u8, 10][..]), (0, true, &[1, 2, 3, 4, 5, 6, 7, 8][..])] {
+/// #     let mut header = Ipv4Header::new(payload.len() as u16, 64, ip_number::UDP, [1, 2, 3, 4], [5, 6, 7, 8]).unwrap();
+/// #     header.identification = 1234;
+/// #     header.more_fragments = more_fragments;
+/// #     header.fragment_offset = IpFragOffset::try_new(offset).unwrap();
+/// #     header.header_checksum = header.calc_header_checksum();
+/// #     let mut bytes = header.to_bytes().to_vec();
+/// #     bytes.extend_from_slice(payload);
+/// #     fragments.push(bytes);
+/// # }
+/// let mut pool = Ipv4DefragPool::new(IpDefragConfig::default());
+///
+/// let mut result = None;
+/// for (timestamp, fragment) in fragments.iter().enumerate() {
+///     let slice = Ipv4Slice::from_slice(fragment).unwrap();
+///     if let Some(packet) = pool.process(timestamp as u64, &slice).unwrap() {
+///         result = Some(packet);
+///     }
+/// }
+///
+/// let packet = result.unwrap();
+/// let sliced = SlicedPacket::from_ip(&packet).unwrap();
+/// match sliced.ip {
+///     Some(InternetSlice::Ipv4(ipv4)) => {
+///         assert!(false == ipv4.is_payload_fragmented());
+///         assert_eq!(ipv4.payload().payload, &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
+///     }
+///     _ => panic!("expected an ipv4 packet"),
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct Ipv4DefragPool {
+    /// Configuration of the pool.
+    config: IpDefragConfig,
+
+    /// Packets currently in reassembly.
+    active: HashMap<Ipv4FragId, Ipv4DefragEntry>,
+
+    /// Number of bytes buffered over all packets in reassembly.
+    buffered_bytes: usize,
+}
+
+/// Packet in reassembly.
+#[derive(Clone, Debug)]
+struct Ipv4DefragEntry {
+    /// Timestamp of the first received fragment.
+    first_timestamp: u64,
+
+    /// IPv4 header of the first fragment (fragment offset 0).
+    header: Option<Vec<u8>>,
+
+    /// Buffer containing the payload.
+    buf: IpDefragBuf,
+}
+
+impl Ipv4DefragEntry {
+    /// Number of bytes buffered by the entry.
+    fn buffered_bytes(&self) -> usize {
+        self.header.as_ref().map(|h| h.len()).unwrap_or(0) + self.buf.data().len()
+    }
+}
+
+impl Ipv4DefragPool {
+    /// Creates an empty pool.
+    pub fn new(config: IpDefragConfig) -> Ipv4DefragPool {
+        Ipv4DefragPool {
+            config,
+            active: HashMap::new(),
+            buffered_bytes: 0,
+        }
+    }
+
+    /// Configuration of the pool.
+    #[inline]
+    pub fn config(&self) -> &IpDefragConfig {
+        &self.config
+    }
+
+    /// Number of packets currently in reassembly.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.active.len()
+    }
+
+    /// Returns true if no packets are in reassembly.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.active.is_empty()
+    }
+
+    /// Number of bytes buffered over all packets in reassembly.
+    #[inline]
+    pub fn buffered_bytes(&self) -> usize {
+        self.buffered_bytes
+    }
+
+    /// Adds a packet to the pool and returns the complete packet if it
+    /// was the missing piece.
+    ///
+    /// Packets that are not fragmented are returned directly (copied).
+    /// If an error is returned the fragment is discarded and the state
+    /// of the pool is otherwise unchanged. The only exception is a
+    /// completed packet that exceeds the maximum IPv4 packet length
+    /// (possible if the first fragment has a longer header than the
+    /// other fragments), in which case the whole packet is dropped.
+    pub fn process(
+        &mut self,
+        timestamp: u64,
+        slice: &Ipv4Slice,
+    ) -> Result<Option<Vec<u8>>, IpDefragError> {
+        use IpDefragError::*;
+
+        let header = slice.header();
+        let payload = {
+            let exts = slice.extensions();
+            let mut payload = Vec::with_capacity(
+                exts.auth.map(|a| a.slice().len()).unwrap_or(0)
+                    + exts.esp.map(|e| e.slice().len()).unwrap_or(0)
+                    + slice.payload().payload.len(),
+            );
+            if let Some(auth) = exts.auth {
+                payload.extend_from_slice(auth.slice());
+            }
+            if let Some(esp) = exts.esp {
+                payload.extend_from_slice(esp.slice());
+            }
+            payload.extend_from_slice(slice.payload().payload);
+            payload
+        };
+
+        // packets that are not fragmented can be returned directly
+        if false == header.is_fragmenting_payload() {
+            let mut result = Vec::with_capacity(header.slice().len() + payload.len());
+            result.extend_from_slice(header.slice());
+            result.extend_from_slice(&payload);
+            return Ok(Some(result));
+        }
+
+        let id = Ipv4FragId::from_header_slice(&header);
+
+        // abandon the previous reassembly if it timed out
+        if self
+            .active
+            .get(&id)
+            .map(|e| self.is_expired(e, timestamp))
+            .unwrap_or(false)
+        {
+            self.remove(&id);
+        }
+
+        // check that a new packet can be added
+        if false == self.active.contains_key(&id) && self.active.len() >= self.config.max_packets {
+            self.remove_expired(timestamp);
+            if self.active.len() >= self.config.max_packets {
+                return Err(PacketLimitExceeded {
+                    max_packets: self.config.max_packets,
+                });
+            }
+        }
+
+        let offset = header.fragments_offset();
+        let more_fragments = header.more_fragments();
+        let is_first = 0 == offset.value();
+        let max_payload_len = u16::MAX - (header.slice().len() as u16);
+
+        // validate the fragment & estimate the memory requirements
+        let (old_bytes, new_bytes) =
+            {
+                let entry = self.active.get(&id);
+                let range =
+                    match entry {
+                        Some(entry) => entry.buf.check(offset, more_fragments, payload.len())?,
+                        None => IpDefragBuf::new(max_payload_len, self.config.overlap_policy)
+                            .check(offset, more_fragments, payload.len())?,
+                    };
+                let header_len = entry
+                    .and_then(|e| e.header.as_ref().map(|h| h.len()))
+                    .unwrap_or(if is_first { header.slice().len() } else { 0 });
+                let data_len = entry.map(|e| e.buf.data().len()).unwrap_or(0);
+                (
+                    entry.map(|e| e.buffered_bytes()).unwrap_or(0),
+                    header_len + data_len.max(range.end as usize),
+                )
+            };
+        if self.buffered_bytes - old_bytes + new_bytes > self.config.max_buffered_bytes {
+            self.remove_expired(timestamp);
+            if self.buffered_bytes - old_bytes + new_bytes > self.config.max_buffered_bytes {
+                return Err(MemoryLimitExceeded {
+                    max_buffered_bytes: self.config.max_buffered_bytes,
+                });
+            }
+        }
+
+        // add the fragment
+        let overlap_policy = self.config.overlap_policy;
+        let entry = self.active.entry(id).or_insert_with(|| Ipv4DefragEntry {
+            first_timestamp: timestamp,
+            header: None,
+            buf: IpDefragBuf::new(max_payload_len, overlap_policy),
+        });
+        entry.buf.add(offset, more_fragments, &payload)?;
+        if is_first && entry.header.is_none() {
+            entry.header = Some(header.slice().to_vec());
+        }
+        self.buffered_bytes = self.buffered_bytes - old_bytes + entry.buffered_bytes();
+
+        // check if the packet is complete
+        if false == (entry.buf.is_complete() && entry.header.is_some()) {
+            return Ok(None);
+        }
+        let entry = self.remove(&id).unwrap();
+        let mut result = entry.header.unwrap();
+        let data = entry.buf.into_data();
+        let total_len = result.len() + data.len();
+        if total_len > usize::from(u16::MAX) {
+            return Err(SegmentTooBig {
+                offset,
+                payload_len: payload.len(),
+                max: u16::MAX - (result.len() as u16),
+            });
+        }
+
+        // update the length, fragmentation fields & checksum
+        result[2..4].copy_from_slice(&(total_len as u16).to_be_bytes());
+        result[6] &= 0b1100_0000;
+        result[7] = 0;
+        result[10] = 0;
+        result[11] = 0;
+        let header_checksum = checksum::Sum16BitWords::new()
+            .add_slice(&result)
+            .ones_complement()
+            .to_be();
+        result[10..12].copy_from_slice(&header_checksum.to_be_bytes());
+
+        result.reserve_exact(data.len());
+        result.extend_from_slice(&data);
+        Ok(Some(result))
+    }
+
+    /// Abandons the reassembly of all packets whose first fragment was
+    /// received more than [`IpDefragConfig::timeout`] before the given
+    /// timestamp.
+    pub fn remove_expired(&mut self, timestamp: u64) {
+        let timeout = self.config.timeout;
+        let mut freed = 0;
+        self.active.retain(|_, e| {
+            let keep = timestamp.saturating_sub(e.first_timestamp) <= timeout;
+            if false == keep {
+                freed += e.buffered_bytes();
+            }
+            keep
+        });
+        self.buffered_bytes -= freed;
+    }
+
+    /// Returns true if the entry timed out.
+    fn is_expired(&self, entry: &Ipv4DefragEntry, timestamp: u64) -> bool {
+        timestamp.saturating_sub(entry.first_timestamp) > self.config.timeout
+    }
+
+    /// Removes the entry & updates the buffered bytes.
+    fn remove(&mut self, id: &Ipv4FragId) -> Option<Ipv4DefragEntry> {
+        let entry = self.active.remove(id)?;
+        self.buffered_bytes -= entry.buffered_bytes();
+        Some(entry)
+    }
+}
+
+impl Default for Ipv4DefragPool {
+    fn default() -> Self {
+        Ipv4DefragPool::new(IpDefragConfig::default())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        defrag::FragOverlapPolicy, ip_number, InternetSlice, IpFragOffset, Ipv4Header, Ipv4Options,
+        SlicedPacket, TransportSlice, UdpHeader,
+    };
+    use alloc::{format, vec, vec::Vec};
+
+    /// Builds an IPv4 fragment.
+    fn fragment(
+        identification: u16,
+        offset: u16,
+        more_fragments: bool,
+        options: &[u8],
+        payload: &[u8],
+    ) -> Vec<u8> {
+        let mut header = Ipv4Header {
+            identification,
+            more_fragments,
+            dont_fragment: false,
+            fragment_offset: IpFragOffset::try_new(offset).unwrap(),
+            ..Ipv4Header::new(0, 64, ip_number::UDP, [1, 2, 3, 4], [5, 6, 7, 8]).unwrap()
+        };
+        header.options = Ipv4Options::try_from(options).unwrap();
+        header.set_payload_len(payload.len()).unwrap();
+        header.header_checksum = header.calc_header_checksum();
+        let mut result = header.to_bytes().to_vec();
+        result.extend_from_slice(payload);
+        result
+    }
+
+    /// Processes a fragment.
+    fn process(
+        pool: &mut Ipv4DefragPool,
+        timestamp: u64,
+        fragment: &[u8],
+    ) -> Result<Option<Vec<u8>>, IpDefragError> {
+        pool.process(timestamp, &Ipv4Slice::from_slice(fragment).unwrap())
+    }
+
+    #[test]
+    fn debug_clone_default() {
+        let pool = Ipv4DefragPool::default();
+        assert_eq!(
+            format!(
+                "Ipv4DefragPool {{ config: {:?}, active: {{}}, buffered_bytes: 0 }}",
+                IpDefragConfig::default()
+            ),
+            format!("{:?}", pool)
+        );
+        let cloned = pool.clone();
+        assert_eq!(pool.config(), cloned.config());
+        assert_eq!(&IpDefragConfig::default(), pool.config());
+        assert_eq!(0, pool.len());
+        assert!(pool.is_empty());
+        assert_eq!(0, pool.buffered_bytes());
+    }
+
+    #[test]
+    fn process_not_fragmented() {
+        let mut pool = Ipv4DefragPool::default();
+        let packet = fragment(1, 0, false, &[], &[1, 2, 3, 4]);
+        assert_eq!(Ok(Some(packet.clone())), process(&mut pool, 0, &packet));
+        assert!(pool.is_empty());
+        assert_eq!(0, pool.buffered_bytes());
+    }
+
+    #[test]
+    fn process_reassemble() {
+        // udp packet with a payload of 20 bytes split into 3 fragments
+        let udp = {
+            let payload: Vec<u8> = (0..20).collect();
+            let udp = UdpHeader::with_ipv4_checksum(
+                1,
+                2,
+                &{
+                    let mut h = Ipv4Header::new(28, 64, ip_number::UDP, [1, 2, 3, 4], [5, 6, 7, 8])
+                        .unwrap();
+                    h.identification = 1;
+                    h
+                },
+                &payload,
+            )
+            .unwrap();
+            let mut result = udp.to_bytes().to_vec();
+            result.extend_from_slice(&payload);
+            result
+        };
+        let options = [1, 1, 1, 0];
+        let fragments = [
+            fragment(1, 0, true, &options, &udp[..8]),
+            fragment(1, 1, true, &[], &udp[8..24]),
+            fragment(1, 3, false, &[], &udp[24..]),
+        ];
+
+        for order in [[0, 1, 2], [2, 1, 0], [1, 2, 0], [2, 0, 1]] {
+            let mut pool = Ipv4DefragPool::default();
+            assert_eq!(Ok(None), process(&mut pool, 0, &fragments[order[0]]));
+            assert_eq!(1, pool.len());
+            assert!(pool.buffered_bytes() > 0);
+            assert_eq!(Ok(None), process(&mut pool, 1, &fragments[order[1]]));
+            assert_eq!(1, pool.len());
+            let packet = process(&mut pool, 2, &fragments[order[2]])
+                .unwrap()
+                .unwrap();
+            assert!(pool.is_empty());
+            assert_eq!(0, pool.buffered_bytes());
+
+            // check the header
+            let (header, payload) = Ipv4Header::from_slice(&packet).unwrap();
+            assert_eq!(payload, &udp[..]);
+            assert_eq!(usize::from(header.total_len), 24 + udp.len());
+            assert_eq!(&header.options[..], &options);
+            assert_eq!(header.identification, 1);
+            assert!(false == header.more_fragments);
+            assert_eq!(header.fragment_offset, IpFragOffset::ZERO);
+            assert_eq!(header.header_checksum, header.calc_header_checksum());
+
+            // slice the result again
+            let sliced = SlicedPacket::from_ip(&packet).unwrap();
+            match sliced.ip {
+                Some(InternetSlice::Ipv4(ipv4)) => assert!(false == ipv4.is_payload_fragmented()),
+                _ => panic!("expected an ipv4 packet"),
+            }
+            assert!(matches!(sliced.transport, Some(TransportSlice::Udp(_))));
+            assert_eq!(sliced.payload, &udp[8..]);
+        }
+    }
+
+    #[test]
+    fn process_keeps_dont_fragment_flag() {
+        let mut pool = Ipv4DefragPool::default();
+        let mut first = fragment(1, 0, true, &[], &[1; 8]);
+        // set the reserved & dont fragment flag
+        first[6] |= 0b1100_0000;
+        let first = {
+            let (mut header, payload) = Ipv4Header::from_slice(&first).unwrap();
+            header.header_checksum = header.calc_header_checksum();
+            let mut result = header.to_bytes().to_vec();
+            result.extend_from_slice(payload);
+            result
+        };
+        assert_eq!(Ok(None), process(&mut pool, 0, &first));
+        let packet = process(&mut pool, 0, &fragment(1, 1, false, &[], &[2; 3]))
+            .unwrap()
+            .unwrap();
+        let (header, _) = Ipv4Header::from_slice(&packet).unwrap();
+        assert!(header.dont_fragment);
+        assert_eq!(header.header_checksum, header.calc_header_checksum());
+    }
+
+    #[test]
+    fn process_separates_packets() {
+        let mut pool = Ipv4DefragPool::default();
+        assert_eq!(
+            Ok(None),
+            process(&mut pool, 0, &fragment(1, 0, true, &[], &[1; 8]))
+        );
+        assert_eq!(
+            Ok(None),
+            process(&mut pool, 0, &fragment(2, 0, true, &[], &[2; 8]))
+        );
+        // different protocol
+        {
+            let mut other = fragment(1, 1, false, &[], &[3; 8]);
+            other[9] = ip_number::TCP.0;
+            assert_eq!(Ok(None), process(&mut pool, 0, &other));
+        }
+        assert_eq!(3, pool.len());
+
+        let packet = process(&mut pool, 0, &fragment(2, 1, false, &[], &[4; 8]))
+            .unwrap()
+            .unwrap();
+        assert_eq!(&packet[20..28], &[2; 8]);
+        assert_eq!(&packet[28..], &[4; 8]);
+        assert_eq!(2, pool.len());
+    }
+
+    #[test]
+    fn process_overlap_policy() {
+        for (overlap_policy, expected) in [
+            (FragOverlapPolicy::First, [1u8; 8]),
+            (FragOverlapPolicy::Last, [2u8; 8]),
+        ] {
+            let mut pool = Ipv4DefragPool::new(IpDefragConfig {
+                overlap_policy,
+                ..Default::default()
+            });
+            assert_eq!(
+                Ok(None),
+                process(&mut pool, 0, &fragment(1, 0, true, &[], &[1; 8]))
+            );
+            assert_eq!(
+                Ok(None),
+                process(&mut pool, 0, &fragment(1, 0, true, &[], &[2; 8]))
+            );
+            let packet = process(&mut pool, 0, &fragment(1, 1, false, &[], &[3; 8]))
+                .unwrap()
+                .unwrap();
+            assert_eq!(&packet[20..28], &expected);
+        }
+    }
+
+    #[test]
+    fn process_timeout() {
+        let mut pool = Ipv4DefragPool::new(IpDefragConfig {
+            timeout: 10,
+            ..Default::default()
+        });
+        assert_eq!(
+            Ok(None),
+            process(&mut pool, 5, &fragment(1, 0, true, &[], &[1; 8]))
+        );
+
+        // still within the timeout
+        assert_eq!(
+            Ok(None),
+            process(&mut pool, 15, &fragment(2, 0, true, &[], &[1; 8]))
+        );
+        pool.remove_expired(15);
+        assert_eq!(2, pool.len());
+
+        // timed out, the first fragment of packet 1 got discarded
+        assert_eq!(
+            Ok(None),
+            process(&mut pool, 16, &fragment(1, 1, false, &[], &[2; 8]))
+        );
+        assert_eq!(2, pool.len());
+        assert_eq!(
+            Ok(None),
+            process(&mut pool, 17, &fragment(2, 1, true, &[], &[2; 8]))
+        );
+
+        // remove expired
+        pool.remove_expired(26);
+        assert_eq!(1, pool.len());
+        // the data buffer includes the not yet received part
+        assert_eq!(16, pool.buffered_bytes());
+        pool.remove_expired(27);
+        assert!(pool.is_empty());
+        assert_eq!(0, pool.buffered_bytes());
+    }
+
+    #[test]
+    fn process_packet_limit() {
+        let mut pool = Ipv4DefragPool::new(IpDefragConfig {
+            max_packets: 1,
+            timeout: 10,
+            ..Default::default()
+        });
+        assert_eq!(
+            Ok(None),
+            process(&mut pool, 0, &fragment(1, 0, true, &[], &[1; 8]))
+        );
+        assert_eq!(
+            Err(IpDefragError::PacketLimitExceeded { max_packets: 1 }),
+            process(&mut pool, 10, &fragment(2, 0, true, &[], &[1; 8]))
+        );
+        // fragments of the existing packet are still accepted
+        assert_eq!(
+            Ok(None),
+            process(&mut pool, 10, &fragment(1, 2, true, &[], &[1; 8]))
+        );
+        assert_eq!(1, pool.len());
+
+        // space gets freed up when the existing packet expires
+        assert_eq!(
+            Ok(None),
+            process(&mut pool, 11, &fragment(2, 0, true, &[], &[1; 8]))
+        );
+        assert_eq!(1, pool.len());
+    }
+
+    #[test]
+    fn process_memory_limit() {
+        let mut pool = Ipv4DefragPool::new(IpDefragConfig {
+            max_buffered_bytes: 20 + 16,
+            timeout: 10,
+            ..Default::default()
+        });
+        assert_eq!(
+            Ok(None),
+            process(&mut pool, 0, &fragment(1, 0, true, &[], &[1; 8]))
+        );
+        assert_eq!(28, pool.buffered_bytes());
+        assert_eq!(
+            Ok(None),
+            process(&mut pool, 0, &fragment(1, 1, true, &[], &[1; 8]))
+        );
+        assert_eq!(36, pool.buffered_bytes());
+        assert_eq!(
+            Err(IpDefragError::MemoryLimitExceeded {
+                max_buffered_bytes: 36
+            }),
+            process(&mut pool, 0, &fragment(1, 2, false, &[], &[1; 1]))
+        );
+        assert_eq!(
+            Err(IpDefragError::MemoryLimitExceeded {
+                max_buffered_bytes: 36
+            }),
+            process(&mut pool, 0, &fragment(2, 3, false, &[], &[1; 1]))
+        );
+        assert_eq!(36, pool.buffered_bytes());
+        assert_eq!(1, pool.len());
+
+        // overlapping data does not require more memory
+        assert_eq!(
+            Ok(None),
+            process(&mut pool, 0, &fragment(1, 0, true, &[], &[1; 16]))
+        );
+        assert_eq!(36, pool.buffered_bytes());
+
+        // memory gets freed when the packet expires
+        assert_eq!(
+            Ok(None),
+            process(&mut pool, 11, &fragment(2, 1, false, &[], &[1; 8]))
+        );
+        assert_eq!(1, pool.len());
+        assert_eq!(16, pool.buffered_bytes());
+    }
+
+    #[test]
+    fn process_errors() {
+        let mut pool = Ipv4DefragPool::default();
+        assert_eq!(
+            Ok(None),
+            process(&mut pool, 0, &fragment(1, 1, false, &[], &[1; 8]))
+        );
+        let buffered_bytes = pool.buffered_bytes();
+
+        // unaligned
+        assert_eq!(
+            Err(IpDefragError::UnalignedFragmentPayloadLen {
+                offset: IpFragOffset::ZERO,
+                payload_len: 7,
+            }),
+            process(&mut pool, 0, &fragment(1, 0, true, &[], &[1; 7]))
+        );
+        // conflicting end
+        assert_eq!(
+            Err(IpDefragError::ConflictingEnd {
+                previous_end: 16,
+                conflicting_end: 24,
+            }),
+            process(&mut pool, 0, &fragment(1, 2, true, &[], &[1; 8]))
+        );
+        // too big
+        assert_eq!(
+            Err(IpDefragError::SegmentTooBig {
+                offset: IpFragOffset::try_new(8189).unwrap(),
+                payload_len: 8,
+                max: u16::MAX - 20,
+            }),
+            process(&mut pool, 0, &fragment(2, 8189, true, &[], &[1; 8]))
+        );
+        assert_eq!(1, pool.len());
+        assert_eq!(buffered_bytes, pool.buffered_bytes());
+    }
+
+    #[test]
+    fn process_completed_packet_too_big() {
+        let mut pool = Ipv4DefragPool::default();
+
+        // last fragment fills up the maximum payload for a 20 byte header
+        let max_offset = (u16::MAX - 20) / 8;
+        let rest = vec![2; usize::from((u16::MAX - 20) % 8)];
+        assert_eq!(
+            Ok(None),
+            process(&mut pool, 0, &fragment(1, max_offset, false, &[], &rest))
+        );
+        let mut middle_offset = 1;
+        while middle_offset < max_offset {
+            let len = usize::from(max_offset - middle_offset).min(1000) * 8;
+            assert_eq!(
+                Ok(None),
+                process(
+                    &mut pool,
+                    0,
+                    &fragment(1, middle_offset, true, &[], &vec![1; len])
+                )
+            );
+            middle_offset += (len / 8) as u16;
+        }
+
+        // first fragment with options makes the reassembled packet too big
+        assert_eq!(
+            Err(IpDefragError::SegmentTooBig {
+                offset: IpFragOffset::ZERO,
+                payload_len: 8,
+                max: u16::MAX - 24,
+            }),
+            process(&mut pool, 0, &fragment(1, 0, true, &[1, 1, 1, 0], &[0; 8]))
+        );
+        assert!(pool.is_empty());
+        assert_eq!(0, pool.buffered_bytes());
+    }
+}
@@ -0,0 +1,67 @@
+use crate::{IpNumber, Ipv4HeaderSlice};
+
+/// Values identifying the fragments belonging to the same IPv4 packet
+/// (RFC 791: source, destination, protocol & identification).
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct Ipv4FragId {
+    /// IPv4 source address.
+    pub source: [u8; 4],
+    /// IPv4 destination address.
+    pub destination: [u8; 4],
+    /// IP protocol number of the payload.
+    pub protocol: IpNumber,
+    /// Value of the "identification" field.
+    pub identification: u16,
+}
+
+impl Ipv4FragId {
+    /// Creates the fragment id from the values of an IPv4 header.
+    #[inline]
+    pub fn from_header_slice(header: &Ipv4HeaderSlice) -> Ipv4FragId {
+        Ipv4FragId {
+            source: header.source(),
+            destination: header.destination(),
+            protocol: header.protocol(),
+            identification: header.identification(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{ip_number, Ipv4Header};
+    use alloc::format;
+
+    #[test]
+    fn debug_clone_eq() {
+        let id = Ipv4FragId {
+            source: [1, 2, 3, 4],
+            destination: [5, 6, 7, 8],
+            protocol: ip_number::UDP,
+            identification: 9,
+        };
+        assert_eq!(
+            "Ipv4FragId { source: [1, 2, 3, 4], destination: [5, 6, 7, 8], protocol: 17 (UDP - User Datagram), identification: 9 }",
+            format!("{:?}", id)
+        );
+        assert_eq!(id, id.clone());
+    }
+
+    #[test]
+    fn from_header_slice() {
+        let mut header = Ipv4Header::new(0, 1, ip_number::TCP, [1, 2, 3, 4], [5, 6, 7, 8]).unwrap();
+        header.identification = 1234;
+        let bytes = header.to_bytes();
+        let slice = Ipv4HeaderSlice::from_slice(&bytes).unwrap();
+        assert_eq!(
+            Ipv4FragId {
+                source: [1, 2, 3, 4],
+                destination: [5, 6, 7, 8],
+                protocol: ip_number::TCP,
+                identification: 1234,
+            },
+            Ipv4FragId::from_header_slice(&slice)
+        );
+    }
+}
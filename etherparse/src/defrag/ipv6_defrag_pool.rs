@@ -0,0 +1,770 @@
+use super::{FragOverlapPolicy, IpDefragBuf, IpDefragConfig, Ipv6FragId};
+use crate::{err::ip_defrag::IpDefragError, Ipv6ExtensionSlice, Ipv6Header, Ipv6Slice};
+use std::{collections::HashMap, vec::Vec};
+
+/// Pool reassembling fragmented IPv6 packets.
+///
+/// Fragments are grouped by [`Ipv6FragId`] (source, destination &
+/// identification). The unfragmentable part (IPv6 header & extension
+/// headers before the fragment header) is taken from the first fragment.
+/// Once all fragments of a packet have been received the complete packet
+/// (unfragmentable part without the fragment header followed by the
+/// reassembled fragmentable part) is returned. It can be decoded again,
+/// e.g. via [`crate::SlicedPacket::from_ip`].
+///
+/// The following rules are enforced:
+///
+/// * Fragments overlapping with previously received fragments cause the
+///   reassembly of the packet to be abandoned (RFC 5722).
+/// * Atomic fragments (fragment header with offset 0 & the "more
+///   fragments" flag not set) are returned directly (without the
+///   fragment header) and are not mixed with other fragments using the
+///   same identification (RFC 8200 & RFC 6946).
+///
+/// Timestamps are supplied by the caller and are only used to abandon
+/// packets that did not complete within [`IpDefragConfig::timeout`].
+///
+/// # Example
+///
+/// ```
+/// use etherparse::{defrag::{IpDefragConfig, Ipv6DefragPool}, *};
+///
+/// # let mut fragments = Vec::new();
+/// # for (offset, more_fragments, payload) in [(1, false, &[9u8, 10][..]), (0, true, &[1, 2, 3, 4, 5, 6, 7, 8][..])] {
+/// #     let header = Ipv6Header {
+/// #         payload_length: (8 + payload.len()) as u16,
+/// #         next_header: ip_number::IPV6_FRAG,
+/// #         hop_limit: 64,
+/// #         source: [1; 16],
+/// #         destination: [2; 16],
+/// #         ..Default::default()
+/// #     };
+/// #     let frag = Ipv6FragmentHeader::new(ip_number::UDP, IpFragOffset::try_new(offset).unwrap(), more_fragments, 1234);
+/// #     let mut bytes = header.to_bytes().to_vec();
+/// #     bytes.extend_from_slice(&frag.to_bytes());
+/// #     bytes.extend_from_slice(payload);
+/// #     fragments.push(bytes);
+/// # }
+/// let mut pool = Ipv6DefragPool::new(IpDefragConfig::default());
+///
+/// let mut result = None;
+/// for (timestamp, fragment) in fragments.iter().enumerate() {
+///     let slice = Ipv6Slice::from_slice(fragment).unwrap();
+///     if let Some(packet) = pool.process(timestamp as u64, &slice).unwrap() {
+///         result = Some(packet);
+///     }
+/// }
+///
+/// let packet = result.unwrap();
+/// let sliced = SlicedPacket::from_ip(&packet).unwrap();
+/// match sliced.ip {
+///     Some(InternetSlice::Ipv6(ipv6)) => {
+///         assert!(false == ipv6.is_payload_fragmented());
+///         assert!(ipv6.extensions().is_empty());
+///         assert_eq!(ipv6.payload().payload, &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
+///     }
+///     _ => panic!("expected an ipv6 packet"),
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct Ipv6DefragPool {
+    /// Configuration of the pool.
+    config: IpDefragConfig,
+
+    /// Packets currently in reassembly.
+    active: HashMap<Ipv6FragId, Ipv6DefragEntry>,
+
+    /// Number of bytes buffered over all packets in reassembly.
+    buffered_bytes: usize,
+}
+
+/// Packet in reassembly.
+#[derive(Clone, Debug)]
+struct Ipv6DefragEntry {
+    /// Timestamp of the first received fragment.
+    first_timestamp: u64,
+
+    /// Unfragmentable part of the first fragment (fragment offset 0)
+    /// with the fragment header already removed from the header chain.
+    unfragmentable: Option<Vec<u8>>,
+
+    /// Buffer containing the fragmentable part.
+    buf: IpDefragBuf,
+}
+
+impl Ipv6DefragEntry {
+    /// Number of bytes buffered by the entry.
+    fn buffered_bytes(&self) -> usize {
+        self.unfragmentable.as_ref().map(|u| u.len()).unwrap_or(0) + self.buf.data().len()
+    }
+}
+
+impl Ipv6DefragPool {
+    /// Creates an empty pool.
+    pub fn new(config: IpDefragConfig) -> Ipv6DefragPool {
+        Ipv6DefragPool {
+            config,
+            active: HashMap::new(),
+            buffered_bytes: 0,
+        }
+    }
+
+    /// Configuration of the pool.
+    #[inline]
+    pub fn config(&self) -> &IpDefragConfig {
+        &self.config
+    }
+
+    /// Number of packets currently in reassembly.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.active.len()
+    }
+
+    /// Returns true if no packets are in reassembly.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.active.is_empty()
+    }
+
+    /// Number of bytes buffered over all packets in reassembly.
+    #[inline]
+    pub fn buffered_bytes(&self) -> usize {
+        self.buffered_bytes
+    }
+
+    /// Adds a packet to the pool and returns the complete packet if it
+    /// was the missing piece.
+    ///
+    /// Packets without a fragment header are returned directly (copied),
+    /// atomic fragments are returned without the fragment header. If an
+    /// error is returned the fragment is discarded and the state of the
+    /// pool is otherwise unchanged, except for
+    /// [`IpDefragError::OverlappingFragment`] (the reassembly of the packet
+    /// is abandoned).
+    pub fn process(
+        &mut self,
+        timestamp: u64,
+        slice: &Ipv6Slice,
+    ) -> Result<Option<Vec<u8>>, IpDefragError> {
+        use IpDefragError::*;
+
+        let header = slice.header();
+        let exts = slice.extensions();
+
+        // locate the fragment header
+        let mut unfrag_exts_len = 0;
+        let mut last_ext_start = None;
+        let mut frag = None;
+        for ext in exts.clone().into_iter() {
+            if let Ipv6ExtensionSlice::Fragment(f) = ext {
+                frag = Some(f);
+                break;
+            }
+            last_ext_start = Some(unfrag_exts_len);
+            unfrag_exts_len += ext.slice().len();
+        }
+        let frag = match frag {
+            Some(frag) => frag,
+            None => {
+                // not fragmented, return the packet directly
+                let mut result = Vec::with_capacity(
+                    Ipv6Header::LEN + exts.slice().len() + slice.payload().payload.len(),
+                );
+                result.extend_from_slice(header.slice());
+                result.extend_from_slice(exts.slice());
+                result.extend_from_slice(slice.payload().payload);
+                return Ok(Some(result));
+            }
+        };
+
+        // unfragmentable part with the fragment header removed from the chain
+        let unfragmentable = || {
+            let mut result = Vec::with_capacity(Ipv6Header::LEN + unfrag_exts_len);
+            result.extend_from_slice(header.slice());
+            result.extend_from_slice(&exts.slice()[..unfrag_exts_len]);
+            let next_header_index = match last_ext_start {
+                Some(start) => Ipv6Header::LEN + start,
+                None => 6,
+            };
+            result[next_header_index] = frag.next_header().0;
+            result
+        };
+
+        // fragmentable part
+        let payload = {
+            let exts_rest = &exts.slice()[unfrag_exts_len + frag.slice().len()..];
+            let mut payload = Vec::with_capacity(exts_rest.len() + slice.payload().payload.len());
+            payload.extend_from_slice(exts_rest);
+            payload.extend_from_slice(slice.payload().payload);
+            payload
+        };
+
+        let offset = frag.fragment_offset();
+        let more_fragments = frag.more_fragments();
+        let max_payload_len = usize::from(u16::MAX).saturating_sub(unfrag_exts_len) as u16;
+
+        // atomic fragments are processed in isolation (RFC 8200 & RFC 6946)
+        if false == frag.is_fragmenting_payload() {
+            let mut result = unfragmentable();
+            Ipv6DefragPool::finalize(&mut result, &payload).map_err(|_| SegmentTooBig {
+                offset,
+                payload_len: payload.len(),
+                max: max_payload_len,
+            })?;
+            return Ok(Some(result));
+        }
+
+        let id = Ipv6FragId::from_header_slices(&header, &frag);
+
+        // abandon the previous reassembly if it timed out
+        if self
+            .active
+            .get(&id)
+            .map(|e| self.is_expired(e, timestamp))
+            .unwrap_or(false)
+        {
+            self.remove(&id);
+        }
+
+        // check that a new packet can be added
+        if false == self.active.contains_key(&id) && self.active.len() >= self.config.max_packets {
+            self.remove_expired(timestamp);
+            if self.active.len() >= self.config.max_packets {
+                return Err(PacketLimitExceeded {
+                    max_packets: self.config.max_packets,
+                });
+            }
+        }
+
+        let is_first = 0 == offset.value();
+
+        // validate the fragment & estimate the memory requirements
+        let (old_bytes, new_bytes) = {
+            let entry = self.active.get(&id);
+            let range = match entry {
+                Some(entry) => entry.buf.check(offset, more_fragments, payload.len())?,
+                None => IpDefragBuf::new(max_payload_len, FragOverlapPolicy::First).check(
+                    offset,
+                    more_fragments,
+                    payload.len(),
+                )?,
+            };
+
+            // overlapping fragments abandon the reassembly (RFC 5722)
+            if entry.map(|e| e.buf.overlaps(&range)).unwrap_or(false) {
+                self.remove(&id);
+                return Err(OverlappingFragment {
+                    offset,
+                    payload_len: payload.len(),
+                });
+            }
+
+            let unfragmentable_len = entry
+                .and_then(|e| e.unfragmentable.as_ref().map(|u| u.len()))
+                .unwrap_or(if is_first {
+                    Ipv6Header::LEN + unfrag_exts_len
+                } else {
+                    0
+                });
+            let data_len = entry.map(|e| e.buf.data().len()).unwrap_or(0);
+            (
+                entry.map(|e| e.buffered_bytes()).unwrap_or(0),
+                unfragmentable_len + data_len.max(range.end as usize),
+            )
+        };
+        if self.buffered_bytes - old_bytes + new_bytes > self.config.max_buffered_bytes {
+            self.remove_expired(timestamp);
+            if self.buffered_bytes - old_bytes + new_bytes > self.config.max_buffered_bytes {
+                return Err(MemoryLimitExceeded {
+                    max_buffered_bytes: self.config.max_buffered_bytes,
+                });
+            }
+        }
+
+        // add the fragment
+        let entry = self.active.entry(id).or_insert_with(|| Ipv6DefragEntry {
+            first_timestamp: timestamp,
+            unfragmentable: None,
+            buf: IpDefragBuf::new(max_payload_len, FragOverlapPolicy::First),
+        });
+        entry.buf.add(offset, more_fragments, &payload)?;
+        if is_first && entry.unfragmentable.is_none() {
+            entry.unfragmentable = Some(unfragmentable());
+        }
+        self.buffered_bytes = self.buffered_bytes - old_bytes + entry.buffered_bytes();
+
+        // check if the packet is complete
+        if false == (entry.buf.is_complete() && entry.unfragmentable.is_some()) {
+            return Ok(None);
+        }
+        let entry = self.remove(&id).unwrap();
+        let mut result = entry.unfragmentable.unwrap();
+        let data = entry.buf.into_data();
+        Ipv6DefragPool::finalize(&mut result, &data).map_err(|max| SegmentTooBig {
+            offset,
+            payload_len: payload.len(),
+            max,
+        })?;
+        Ok(Some(result))
+    }
+
+    /// Abandons the reassembly of all packets whose first fragment was
+    /// received more than [`IpDefragConfig::timeout`] before the given
+    /// timestamp.
+    pub fn remove_expired(&mut self, timestamp: u64) {
+        let timeout = self.config.timeout;
+        let mut freed = 0;
+        self.active.retain(|_, e| {
+            let keep = timestamp.saturating_sub(e.first_timestamp) <= timeout;
+            if false == keep {
+                freed += e.buffered_bytes();
+            }
+            keep
+        });
+        self.buffered_bytes -= freed;
+    }
+
+    /// Appends the fragmentable part to the unfragmentable part & sets
+    /// the "payload length" field of the IPv6 header.
+    ///
+    /// Returns the maximum allowed length of the fragmentable part as
+    /// error if the resulting payload length would not fit into the
+    /// "payload length" field.
+    fn finalize(unfragmentable: &mut Vec<u8>, fragmentable: &[u8]) -> Result<(), u16> {
+        let exts_len = unfragmentable.len() - Ipv6Header::LEN;
+        let payload_length = exts_len + fragmentable.len();
+        if payload_length > usize::from(u16::MAX) {
+            return Err(usize::from(u16::MAX).saturating_sub(exts_len) as u16);
+        }
+        unfragmentable[4..6].copy_from_slice(&(payload_length as u16).to_be_bytes());
+        unfragmentable.reserve_exact(fragmentable.len());
+        unfragmentable.extend_from_slice(fragmentable);
+        Ok(())
+    }
+
+    /// Returns true if the entry timed out.
+    fn is_expired(&self, entry: &Ipv6DefragEntry, timestamp: u64) -> bool {
+        timestamp.saturating_sub(entry.first_timestamp) > self.config.timeout
+    }
+
+    /// Removes the entry & updates the buffered bytes.
+    fn remove(&mut self, id: &Ipv6FragId) -> Option<Ipv6DefragEntry> {
+        let entry = self.active.remove(id)?;
+        self.buffered_bytes -= entry.buffered_bytes();
+        Some(entry)
+    }
+}
+
+impl Default for Ipv6DefragPool {
+    fn default() -> Self {
+        Ipv6DefragPool::new(IpDefragConfig::default())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        ip_number, InternetSlice, IpFragOffset, IpNumber, Ipv6FragmentHeader, SlicedPacket,
+        TransportSlice, UdpHeader,
+    };
+    use alloc::{format, vec, vec::Vec};
+
+    /// Hop by hop header (PadN option) pointing to a fragment header.
+    const HOP_BY_HOP: [u8; 8] = [ip_number::IPV6_FRAG.0, 0, 1, 4, 0, 0, 0, 0];
+
+    /// Builds an IPv6 fragment with an optional hop by hop header in
+    /// the unfragmentable part.
+    fn fragment(
+        identification: u32,
+        offset: u16,
+        more_fragments: bool,
+        with_hop_by_hop: bool,
+        next_header: IpNumber,
+        payload: &[u8],
+    ) -> Vec<u8> {
+        let unfrag_exts: &[u8] = if with_hop_by_hop { &HOP_BY_HOP } else { &[] };
+        let header = Ipv6Header {
+            payload_length: (unfrag_exts.len() + 8 + payload.len()) as u16,
+            next_header: if with_hop_by_hop {
+                ip_number::IPV6_HOP_BY_HOP
+            } else {
+                ip_number::IPV6_FRAG
+            },
+            hop_limit: 64,
+            source: [1; 16],
+            destination: [2; 16],
+            ..Default::default()
+        };
+        let frag = Ipv6FragmentHeader::new(
+            next_header,
+            IpFragOffset::try_new(offset).unwrap(),
+            more_fragments,
+            identification,
+        );
+        let mut result = header.to_bytes().to_vec();
+        result.extend_from_slice(unfrag_exts);
+        result.extend_from_slice(&frag.to_bytes());
+        result.extend_from_slice(payload);
+        result
+    }
+
+    /// Processes a fragment.
+    fn process(
+        pool: &mut Ipv6DefragPool,
+        timestamp: u64,
+        fragment: &[u8],
+    ) -> Result<Option<Vec<u8>>, IpDefragError> {
+        pool.process(timestamp, &Ipv6Slice::from_slice(fragment).unwrap())
+    }
+
+    /// Builds a simple fragment without a hop by hop header.
+    fn simple(identification: u32, offset: u16, more_fragments: bool, payload: &[u8]) -> Vec<u8> {
+        fragment(
+            identification,
+            offset,
+            more_fragments,
+            false,
+            ip_number::UDP,
+            payload,
+        )
+    }
+
+    #[test]
+    fn debug_clone_default() {
+        let pool = Ipv6DefragPool::default();
+        assert_eq!(
+            format!(
+                "Ipv6DefragPool {{ config: {:?}, active: {{}}, buffered_bytes: 0 }}",
+                IpDefragConfig::default()
+            ),
+            format!("{:?}", pool)
+        );
+        let cloned = pool.clone();
+        assert_eq!(pool.config(), cloned.config());
+        assert_eq!(&IpDefragConfig::default(), pool.config());
+        assert_eq!(0, pool.len());
+        assert!(pool.is_empty());
+        assert_eq!(0, pool.buffered_bytes());
+    }
+
+    #[test]
+    fn process_not_fragmented() {
+        let mut pool = Ipv6DefragPool::default();
+        let header = Ipv6Header {
+            payload_length: 4,
+            next_header: ip_number::UDP,
+            ..Default::default()
+        };
+        let mut packet = header.to_bytes().to_vec();
+        packet.extend_from_slice(&[1, 2, 3, 4]);
+        assert_eq!(Ok(Some(packet.clone())), process(&mut pool, 0, &packet));
+        assert!(pool.is_empty());
+    }
+
+    #[test]
+    fn process_atomic_fragment() {
+        let mut pool = Ipv6DefragPool::default();
+
+        // start a reassembly with the same identification
+        assert_eq!(
+            Ok(None),
+            process(&mut pool, 0, &simple(1, 1, true, &[2; 8]))
+        );
+
+        for with_hop_by_hop in [false, true] {
+            let packet = process(
+                &mut pool,
+                0,
+                &fragment(1, 0, false, with_hop_by_hop, ip_number::UDP, &[1; 8]),
+            )
+            .unwrap()
+            .unwrap();
+
+            let ipv6 = Ipv6Slice::from_slice(&packet).unwrap();
+            assert!(false == ipv6.is_payload_fragmented());
+            assert_eq!(ipv6.payload().ip_number, ip_number::UDP);
+            assert_eq!(ipv6.payload().payload, &[1; 8]);
+            if with_hop_by_hop {
+                assert_eq!(ipv6.header().next_header(), ip_number::IPV6_HOP_BY_HOP);
+                assert_eq!(ipv6.extensions().slice(), &{
+                    let mut h = HOP_BY_HOP;
+                    h[0] = ip_number::UDP.0;
+                    h
+                });
+                assert_eq!(ipv6.header().payload_length(), 16);
+            } else {
+                assert_eq!(ipv6.header().next_header(), ip_number::UDP);
+                assert!(ipv6.extensions().is_empty());
+                assert_eq!(ipv6.header().payload_length(), 8);
+            }
+        }
+
+        // the ongoing reassembly is not affected
+        assert_eq!(1, pool.len());
+        assert_eq!(16, pool.buffered_bytes());
+        assert_eq!(
+            Ok(None),
+            process(&mut pool, 0, &simple(1, 0, true, &[3; 8]))
+        );
+        let packet = process(&mut pool, 0, &simple(1, 2, false, &[4; 8]))
+            .unwrap()
+            .unwrap();
+        let mut expected = vec![3; 8];
+        expected.extend_from_slice(&[2; 8]);
+        expected.extend_from_slice(&[4; 8]);
+        assert_eq!(&packet[Ipv6Header::LEN..], &expected[..]);
+    }
+
+    #[test]
+    fn process_reassemble() {
+        // udp packet with a payload of 20 bytes split into 3 fragments
+        let udp = {
+            let payload: Vec<u8> = (0..20).collect();
+            let udp = UdpHeader::with_ipv6_checksum(
+                1,
+                2,
+                &Ipv6Header {
+                    source: [1; 16],
+                    destination: [2; 16],
+                    ..Default::default()
+                },
+                &payload,
+            )
+            .unwrap();
+            let mut result = udp.to_bytes().to_vec();
+            result.extend_from_slice(&payload);
+            result
+        };
+        let fragments = [
+            fragment(1, 0, true, true, ip_number::UDP, &udp[..8]),
+            // the unfragmentable part of other fragments is ignored
+            fragment(1, 1, true, false, ip_number::UDP, &udp[8..24]),
+            fragment(1, 3, false, true, ip_number::UDP, &udp[24..]),
+        ];
+
+        for order in [[0, 1, 2], [2, 1, 0], [1, 2, 0], [2, 0, 1]] {
+            let mut pool = Ipv6DefragPool::default();
+            assert_eq!(Ok(None), process(&mut pool, 0, &fragments[order[0]]));
+            assert_eq!(1, pool.len());
+            assert!(pool.buffered_bytes() > 0);
+            assert_eq!(Ok(None), process(&mut pool, 1, &fragments[order[1]]));
+            let packet = process(&mut pool, 2, &fragments[order[2]])
+                .unwrap()
+                .unwrap();
+            assert!(pool.is_empty());
+            assert_eq!(0, pool.buffered_bytes());
+
+            let sliced = SlicedPacket::from_ip(&packet).unwrap();
+            match sliced.ip {
+                Some(InternetSlice::Ipv6(ipv6)) => {
+                    assert!(false == ipv6.is_payload_fragmented());
+                    assert_eq!(
+                        usize::from(ipv6.header().payload_length()),
+                        HOP_BY_HOP.len() + udp.len()
+                    );
+                    assert_eq!(ipv6.extensions().slice().len(), HOP_BY_HOP.len());
+                    assert_eq!(ipv6.extensions().slice()[0], ip_number::UDP.0);
+                }
+                _ => panic!("expected an ipv6 packet"),
+            }
+            assert!(matches!(sliced.transport, Some(TransportSlice::Udp(_))));
+            assert_eq!(sliced.payload, &udp[8..]);
+        }
+    }
+
+    #[test]
+    fn process_reassemble_fragmentable_exts() {
+        // destination options header in the fragmentable part
+        let mut data = vec![ip_number::IPV6_ICMP.0, 0, 1, 4, 0, 0, 0, 0];
+        // icmpv6 echo request
+        data.extend_from_slice(&[128, 0, 0, 0, 0, 1, 0, 2]);
+
+        let mut pool = Ipv6DefragPool::default();
+        assert_eq!(
+            Ok(None),
+            process(
+                &mut pool,
+                0,
+                &fragment(1, 1, false, false, ip_number::IPV6_DEST_OPTIONS, &data[8..])
+            )
+        );
+        let packet = process(
+            &mut pool,
+            0,
+            &fragment(1, 0, true, false, ip_number::IPV6_DEST_OPTIONS, &data[..8]),
+        )
+        .unwrap()
+        .unwrap();
+        let sliced = SlicedPacket::from_ip(&packet).unwrap();
+        match sliced.ip {
+            Some(InternetSlice::Ipv6(ipv6)) => {
+                assert_eq!(ipv6.header().next_header(), ip_number::IPV6_DEST_OPTIONS);
+                assert_eq!(ipv6.extensions().slice(), &data[..8]);
+            }
+            _ => panic!("expected an ipv6 packet"),
+        }
+        assert!(matches!(sliced.transport, Some(TransportSlice::Icmpv6(_))));
+    }
+
+    #[test]
+    fn process_separates_packets() {
+        let mut pool = Ipv6DefragPool::default();
+        assert_eq!(
+            Ok(None),
+            process(&mut pool, 0, &simple(1, 0, true, &[1; 8]))
+        );
+        assert_eq!(
+            Ok(None),
+            process(&mut pool, 0, &simple(2, 0, true, &[2; 8]))
+        );
+        assert_eq!(2, pool.len());
+        let packet = process(&mut pool, 0, &simple(2, 1, false, &[3; 8]))
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            &packet[Ipv6Header::LEN..],
+            &[2, 2, 2, 2, 2, 2, 2, 2, 3, 3, 3, 3, 3, 3, 3, 3]
+        );
+        assert_eq!(1, pool.len());
+    }
+
+    #[test]
+    fn process_overlap() {
+        let mut pool = Ipv6DefragPool::default();
+        assert_eq!(
+            Ok(None),
+            process(&mut pool, 0, &simple(1, 0, true, &[1; 16]))
+        );
+        assert_eq!(
+            Ok(None),
+            process(&mut pool, 0, &simple(2, 0, true, &[1; 8]))
+        );
+        assert_eq!(
+            Ok(None),
+            process(&mut pool, 0, &simple(1, 3, true, &[1; 8]))
+        );
+
+        // overlapping fragment abandons the reassembly
+        assert_eq!(
+            Err(IpDefragError::OverlappingFragment {
+                offset: IpFragOffset::try_new(1).unwrap(),
+                payload_len: 8,
+            }),
+            process(&mut pool, 0, &simple(1, 1, true, &[2; 8]))
+        );
+        assert_eq!(1, pool.len());
+        assert_eq!(Ipv6Header::LEN + 8, pool.buffered_bytes());
+
+        // exact duplicates are overlaps as well
+        assert_eq!(
+            Err(IpDefragError::OverlappingFragment {
+                offset: IpFragOffset::ZERO,
+                payload_len: 8,
+            }),
+            process(&mut pool, 0, &simple(2, 0, true, &[1; 8]))
+        );
+        assert!(pool.is_empty());
+        assert_eq!(0, pool.buffered_bytes());
+
+        // later fragments start a new reassembly
+        assert_eq!(
+            Ok(None),
+            process(&mut pool, 0, &simple(1, 1, false, &[3; 8]))
+        );
+        assert_eq!(1, pool.len());
+    }
+
+    #[test]
+    fn process_timeout() {
+        let mut pool = Ipv6DefragPool::new(IpDefragConfig {
+            timeout: 10,
+            ..Default::default()
+        });
+        assert_eq!(
+            Ok(None),
+            process(&mut pool, 5, &simple(1, 0, true, &[1; 8]))
+        );
+        pool.remove_expired(15);
+        assert_eq!(1, pool.len());
+
+        // timed out, the previous fragment got discarded (no overlap error)
+        assert_eq!(
+            Ok(None),
+            process(&mut pool, 16, &simple(1, 0, true, &[1; 8]))
+        );
+        assert_eq!(1, pool.len());
+        pool.remove_expired(27);
+        assert!(pool.is_empty());
+        assert_eq!(0, pool.buffered_bytes());
+    }
+
+    #[test]
+    fn process_limits() {
+        // packet limit
+        {
+            let mut pool = Ipv6DefragPool::new(IpDefragConfig {
+                max_packets: 1,
+                ..Default::default()
+            });
+            assert_eq!(
+                Ok(None),
+                process(&mut pool, 0, &simple(1, 0, true, &[1; 8]))
+            );
+            assert_eq!(
+                Err(IpDefragError::PacketLimitExceeded { max_packets: 1 }),
+                process(&mut pool, 0, &simple(2, 0, true, &[1; 8]))
+            );
+            assert_eq!(1, pool.len());
+        }
+        // memory limit
+        {
+            let mut pool = Ipv6DefragPool::new(IpDefragConfig {
+                max_buffered_bytes: Ipv6Header::LEN + 8,
+                ..Default::default()
+            });
+            assert_eq!(
+                Ok(None),
+                process(&mut pool, 0, &simple(1, 0, true, &[1; 8]))
+            );
+            assert_eq!(Ipv6Header::LEN + 8, pool.buffered_bytes());
+            assert_eq!(
+                Err(IpDefragError::MemoryLimitExceeded {
+                    max_buffered_bytes: Ipv6Header::LEN + 8
+                }),
+                process(&mut pool, 0, &simple(1, 1, false, &[1; 1]))
+            );
+            assert_eq!(Ipv6Header::LEN + 8, pool.buffered_bytes());
+        }
+    }
+
+    #[test]
+    fn process_errors() {
+        let mut pool = Ipv6DefragPool::default();
+
+        // unaligned
+        assert_eq!(
+            Err(IpDefragError::UnalignedFragmentPayloadLen {
+                offset: IpFragOffset::ZERO,
+                payload_len: 7,
+            }),
+            process(&mut pool, 0, &simple(1, 0, true, &[1; 7]))
+        );
+
+        // too big (the unfragmentable extension headers reduce the maximum)
+        assert_eq!(
+            Err(IpDefragError::SegmentTooBig {
+                offset: IpFragOffset::try_new(8191).unwrap(),
+                payload_len: 8,
+                max: u16::MAX - 8,
+            }),
+            process(
+                &mut pool,
+                0,
+                &fragment(1, 8191, true, true, ip_number::UDP, &[1; 8])
+            )
+        );
+        assert!(pool.is_empty());
+    }
+}
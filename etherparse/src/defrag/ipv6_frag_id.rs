@@ -0,0 +1,72 @@
+use crate::{Ipv6FragmentHeaderSlice, Ipv6HeaderSlice};
+
+/// Values identifying the fragments belonging to the same IPv6 packet
+/// (RFC 8200: source, destination & identification).
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct Ipv6FragId {
+    /// IPv6 source address.
+    pub source: [u8; 16],
+    /// IPv6 destination address.
+    pub destination: [u8; 16],
+    /// Value of the "identification" field of the fragment header.
+    pub identification: u32,
+}
+
+impl Ipv6FragId {
+    /// Creates the fragment id from an IPv6 header & fragment header.
+    #[inline]
+    pub fn from_header_slices(
+        header: &Ipv6HeaderSlice,
+        frag_header: &Ipv6FragmentHeaderSlice,
+    ) -> Ipv6FragId {
+        Ipv6FragId {
+            source: header.source(),
+            destination: header.destination(),
+            identification: frag_header.identification(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{ip_number, IpFragOffset, Ipv6FragmentHeader, Ipv6Header};
+    use alloc::format;
+
+    #[test]
+    fn debug_clone_eq() {
+        let id = Ipv6FragId {
+            source: [1; 16],
+            destination: [2; 16],
+            identification: 3,
+        };
+        assert_eq!(
+            "Ipv6FragId { source: [1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1], destination: [2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2], identification: 3 }",
+            format!("{:?}", id)
+        );
+        assert_eq!(id, id.clone());
+    }
+
+    #[test]
+    fn from_header_slices() {
+        let header = Ipv6Header {
+            source: [1; 16],
+            destination: [2; 16],
+            ..Default::default()
+        };
+        let header_bytes = header.to_bytes();
+        let frag = Ipv6FragmentHeader::new(ip_number::UDP, IpFragOffset::ZERO, true, 1234);
+        let frag_bytes = frag.to_bytes();
+        assert_eq!(
+            Ipv6FragId {
+                source: [1; 16],
+                destination: [2; 16],
+                identification: 1234,
+            },
+            Ipv6FragId::from_header_slices(
+                &Ipv6HeaderSlice::from_slice(&header_bytes).unwrap(),
+                &Ipv6FragmentHeaderSlice::from_slice(&frag_bytes).unwrap()
+            )
+        );
+    }
+}
@@ -0,0 +1,23 @@
+mod frag_overlap_policy;
+pub use frag_overlap_policy::*;
+
+mod ip_defrag_buf;
+pub use ip_defrag_buf::*;
+
+mod ip_defrag_config;
+pub use ip_defrag_config::*;
+
+mod ip_frag_range;
+pub use ip_frag_range::*;
+
+mod ipv4_defrag_pool;
+pub use ipv4_defrag_pool::*;
+
+mod ipv4_frag_id;
+pub use ipv4_frag_id::*;
+
+mod ipv6_defrag_pool;
+pub use ipv6_defrag_pool::*;
+
+mod ipv6_frag_id;
+pub use ipv6_frag_id::*;
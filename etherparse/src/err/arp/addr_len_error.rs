@@ -0,0 +1,124 @@
+/// Error when creating an [`crate::ArpHeader`] or setting its addresses
+/// and the given address lengths can not be represented in an ARP packet.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum AddrLenError {
+    /// Error if the sender & target hardware addresses have different lengths
+    /// (ARP only has one length field for both).
+    HwAddrLenNonMatching {
+        sender_len: usize,
+        target_len: usize,
+    },
+
+    /// Error if the sender & target protocol addresses have different lengths
+    /// (ARP only has one length field for both).
+    ProtocolAddrLenNonMatching {
+        sender_len: usize,
+        target_len: usize,
+    },
+
+    /// Error if the hardware address length is bigger then 255 bytes
+    /// (the maximum value the "hardware address length" field can represent).
+    HwAddrLenTooBig(usize),
+
+    /// Error if the protocol address length is bigger then 255 bytes
+    /// (the maximum value the "protocol address length" field can represent).
+    ProtocolAddrLenTooBig(usize),
+}
+
+impl core::fmt::Display for AddrLenError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        use AddrLenError::*;
+        match self {
+            HwAddrLenNonMatching { sender_len, target_len } =>
+                write!(f, "ARP Error: The sender hardware address length ({} bytes) and the target hardware address length ({} bytes) differ. ARP requires both addresses to have the same length.", sender_len, target_len),
+            ProtocolAddrLenNonMatching { sender_len, target_len } =>
+                write!(f, "ARP Error: The sender protocol address length ({} bytes) and the target protocol address length ({} bytes) differ. ARP requires both addresses to have the same length.", sender_len, target_len),
+            HwAddrLenTooBig(len) =>
+                write!(f, "ARP Error: The hardware address length of {} bytes is larger then the maximum of 255 bytes supported by ARP.", len),
+            ProtocolAddrLenTooBig(len) =>
+                write!(f, "ARP Error: The protocol address length of {} bytes is larger then the maximum of 255 bytes supported by ARP.", len),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for AddrLenError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AddrLenError::*;
+    use alloc::format;
+    use std::{
+        collections::hash_map::DefaultHasher,
+        error::Error,
+        hash::{Hash, Hasher},
+    };
+
+    #[test]
+    fn debug() {
+        assert_eq!(
+            "HwAddrLenTooBig(256)",
+            format!("{:?}", HwAddrLenTooBig(256))
+        );
+    }
+
+    #[test]
+    fn clone_eq_hash() {
+        let err = ProtocolAddrLenTooBig(300);
+        assert_eq!(err, err.clone());
+        let hash_a = {
+            let mut hasher = DefaultHasher::new();
+            err.hash(&mut hasher);
+            hasher.finish()
+        };
+        let hash_b = {
+            let mut hasher = DefaultHasher::new();
+            err.clone().hash(&mut hasher);
+            hasher.finish()
+        };
+        assert_eq!(hash_a, hash_b);
+    }
+
+    #[test]
+    fn fmt() {
+        assert_eq!(
+            "ARP Error: The sender hardware address length (6 bytes) and the target hardware address length (4 bytes) differ. ARP requires both addresses to have the same length.",
+            format!("{}", HwAddrLenNonMatching{ sender_len: 6, target_len: 4 })
+        );
+        assert_eq!(
+            "ARP Error: The sender protocol address length (4 bytes) and the target protocol address length (16 bytes) differ. ARP requires both addresses to have the same length.",
+            format!("{}", ProtocolAddrLenNonMatching{ sender_len: 4, target_len: 16 })
+        );
+        assert_eq!(
+            "ARP Error: The hardware address length of 256 bytes is larger then the maximum of 255 bytes supported by ARP.",
+            format!("{}", HwAddrLenTooBig(256))
+        );
+        assert_eq!(
+            "ARP Error: The protocol address length of 300 bytes is larger then the maximum of 255 bytes supported by ARP.",
+            format!("{}", ProtocolAddrLenTooBig(300))
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn source() {
+        assert!(HwAddrLenTooBig(256).source().is_none());
+        assert!(ProtocolAddrLenTooBig(256).source().is_none());
+        assert!(HwAddrLenNonMatching {
+            sender_len: 1,
+            target_len: 2
+        }
+        .source()
+        .is_none());
+        assert!(ProtocolAddrLenNonMatching {
+            sender_len: 1,
+            target_len: 2
+        }
+        .source()
+        .is_none());
+    }
+}
@@ -0,0 +1,2 @@
+mod addr_len_error;
+pub use addr_len_error::*;
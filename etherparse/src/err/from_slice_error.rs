@@ -27,8 +27,29 @@ pub enum FromSliceError {
     /// Error while parsing a IPv6 extension header.
     Ipv6Exts(ipv6_exts::HeaderError),
 
+    /// Error while parsing a MPLS label stack.
+    Mpls(mpls::HeaderError),
+
+    /// Error while parsing a PPPoE header.
+    Pppoe(pppoe::HeaderError),
+
     /// Error while parsing a TCP extension header.
     Tcp(tcp::HeaderError),
+
+    /// Error while parsing a VLAN stack.
+    VlanStack(vlan_stack::HeaderError),
+
+    /// Error while parsing a MACsec header.
+    Macsec(macsec::HeaderError),
+
+    /// Error while parsing a GRE header.
+    Gre(gre::HeaderError),
+
+    /// Error while parsing a Geneve header.
+    Geneve(geneve::HeaderError),
+
+    /// Error while parsing a GTP-U header.
+    Gtpu(gtpu::HeaderError),
 }
 
 impl FromSliceError {
@@ -74,12 +95,54 @@ impl FromSliceError {
             _ => None,
         }
     }
+    pub fn mpls(&self) -> Option<&mpls::HeaderError> {
+        match self {
+            FromSliceError::Mpls(err) => Some(err),
+            _ => None,
+        }
+    }
+    pub fn pppoe(&self) -> Option<&pppoe::HeaderError> {
+        match self {
+            FromSliceError::Pppoe(err) => Some(err),
+            _ => None,
+        }
+    }
     pub fn tcp(&self) -> Option<&tcp::HeaderError> {
         match self {
             FromSliceError::Tcp(err) => Some(err),
             _ => None,
         }
     }
+    pub fn vlan_stack(&self) -> Option<&vlan_stack::HeaderError> {
+        match self {
+            FromSliceError::VlanStack(err) => Some(err),
+            _ => None,
+        }
+    }
+    pub fn macsec(&self) -> Option<&macsec::HeaderError> {
+        match self {
+            FromSliceError::Macsec(err) => Some(err),
+            _ => None,
+        }
+    }
+    pub fn gre(&self) -> Option<&gre::HeaderError> {
+        match self {
+            FromSliceError::Gre(err) => Some(err),
+            _ => None,
+        }
+    }
+    pub fn geneve(&self) -> Option<&geneve::HeaderError> {
+        match self {
+            FromSliceError::Geneve(err) => Some(err),
+            _ => None,
+        }
+    }
+    pub fn gtpu(&self) -> Option<&gtpu::HeaderError> {
+        match self {
+            FromSliceError::Gtpu(err) => Some(err),
+            _ => None,
+        }
+    }
 }
 
 impl core::fmt::Display for FromSliceError {
@@ -93,7 +156,14 @@ impl core::fmt::Display for FromSliceError {
             Ipv4(err) => err.fmt(f),
             Ipv6(err) => err.fmt(f),
             Ipv6Exts(err) => err.fmt(f),
+            Mpls(err) => err.fmt(f),
+            Pppoe(err) => err.fmt(f),
             Tcp(err) => err.fmt(f),
+            VlanStack(err) => err.fmt(f),
+            Macsec(err) => err.fmt(f),
+            Gre(err) => err.fmt(f),
+            Geneve(err) => err.fmt(f),
+            Gtpu(err) => err.fmt(f),
         }
     }
 }
@@ -109,7 +179,14 @@ impl std::error::Error for FromSliceError {
             FromSliceError::Ipv4(err) => Some(err),
             FromSliceError::Ipv6(err) => Some(err),
             FromSliceError::Ipv6Exts(err) => Some(err),
+            FromSliceError::Mpls(err) => Some(err),
+            FromSliceError::Pppoe(err) => Some(err),
             FromSliceError::Tcp(err) => Some(err),
+            FromSliceError::VlanStack(err) => Some(err),
+            FromSliceError::Macsec(err) => Some(err),
+            FromSliceError::Gre(err) => Some(err),
+            FromSliceError::Geneve(err) => Some(err),
+            FromSliceError::Gtpu(err) => Some(err),
         }
     }
 }
@@ -262,6 +339,132 @@ impl From<ipv6_exts::HeaderSliceError> for FromSliceError {
     }
 }
 
+// mpls error conversions
+
+impl From<mpls::HeaderError> for FromSliceError {
+    fn from(value: mpls::HeaderError) -> Self {
+        FromSliceError::Mpls(value)
+    }
+}
+
+impl From<mpls::HeaderSliceError> for FromSliceError {
+    fn from(value: mpls::HeaderSliceError) -> Self {
+        use mpls::HeaderSliceError::*;
+        match value {
+            Len(err) => FromSliceError::Len(err),
+            Content(err) => FromSliceError::Mpls(err),
+        }
+    }
+}
+
+// pppoe error conversions
+
+impl From<pppoe::HeaderError> for FromSliceError {
+    fn from(value: pppoe::HeaderError) -> Self {
+        FromSliceError::Pppoe(value)
+    }
+}
+
+impl From<pppoe::HeaderSliceError> for FromSliceError {
+    fn from(value: pppoe::HeaderSliceError) -> Self {
+        use pppoe::HeaderSliceError::*;
+        match value {
+            Len(err) => FromSliceError::Len(err),
+            Content(err) => FromSliceError::Pppoe(err),
+        }
+    }
+}
+
+// vlan stack error conversions
+
+impl From<vlan_stack::HeaderError> for FromSliceError {
+    fn from(value: vlan_stack::HeaderError) -> Self {
+        FromSliceError::VlanStack(value)
+    }
+}
+
+impl From<vlan_stack::HeaderSliceError> for FromSliceError {
+    fn from(value: vlan_stack::HeaderSliceError) -> Self {
+        use vlan_stack::HeaderSliceError::*;
+        match value {
+            Len(err) => FromSliceError::Len(err),
+            Content(err) => FromSliceError::VlanStack(err),
+        }
+    }
+}
+
+// macsec error conversions
+
+impl From<macsec::HeaderError> for FromSliceError {
+    fn from(value: macsec::HeaderError) -> Self {
+        FromSliceError::Macsec(value)
+    }
+}
+
+impl From<macsec::HeaderSliceError> for FromSliceError {
+    fn from(value: macsec::HeaderSliceError) -> Self {
+        use macsec::HeaderSliceError::*;
+        match value {
+            Len(err) => FromSliceError::Len(err),
+            Content(err) => FromSliceError::Macsec(err),
+        }
+    }
+}
+
+// gre error conversions
+
+impl From<gre::HeaderError> for FromSliceError {
+    fn from(value: gre::HeaderError) -> Self {
+        FromSliceError::Gre(value)
+    }
+}
+
+impl From<gre::HeaderSliceError> for FromSliceError {
+    fn from(value: gre::HeaderSliceError) -> Self {
+        use gre::HeaderSliceError::*;
+        match value {
+            Len(err) => FromSliceError::Len(err),
+            Content(err) => FromSliceError::Gre(err),
+        }
+    }
+}
+
+// geneve error conversions
+
+impl From<geneve::HeaderError> for FromSliceError {
+    fn from(value: geneve::HeaderError) -> Self {
+        FromSliceError::Geneve(value)
+    }
+}
+
+impl From<geneve::HeaderSliceError> for FromSliceError {
+    fn from(value: geneve::HeaderSliceError) -> Self {
+        use geneve::HeaderSliceError::*;
+        match value {
+            Len(err) => FromSliceError::Len(err),
+            Content(err) => FromSliceError::Geneve(err),
+        }
+    }
+}
+
+// gtpu error conversions
+
+impl From<gtpu::HeaderError> for FromSliceError {
+    fn from(value: gtpu::HeaderError) -> Self {
+        FromSliceError::Gtpu(value)
+    }
+}
+
+impl From<gtpu::HeaderSliceError> for FromSliceError {
+    fn from(value: gtpu::HeaderSliceError) -> Self {
+        use gtpu::HeaderSliceError::*;
+        match value {
+            Len(err) => FromSliceError::Len(err),
+            Content(err) => FromSliceError::Gtpu(err),
+        }
+    }
+}
+
 // packet error conversions
 
 impl From<packet::EthSliceError> for FromSliceError {
@@ -269,10 +472,15 @@ impl From<packet::EthSliceError> for FromSliceError {
         use packet::EthSliceError::*;
         match value {
             Len(err) => FromSliceError::Len(err),
+            VlanStack(err) => FromSliceError::VlanStack(err),
+            Macsec(err) => FromSliceError::Macsec(err),
+            Mpls(err) => FromSliceError::Mpls(err),
+            Pppoe(err) => FromSliceError::Pppoe(err),
             Ipv4(err) => FromSliceError::Ipv4(err),
             Ipv6(err) => FromSliceError::Ipv6(err),
             Ipv4Exts(err) => FromSliceError::IpAuth(err),
             Ipv6Exts(err) => FromSliceError::Ipv6Exts(err),
+            Gre(err) => FromSliceError::Gre(err),
             Tcp(err) => FromSliceError::Tcp(err),
         }
     }
@@ -284,7 +492,10 @@ impl From<packet::IpSliceError> for FromSliceError {
         match value {
             Len(err) => FromSliceError::Len(err),
             Ip(err) => FromSliceError::Ip(err),
+            Gre(err) => FromSliceError::Gre(err),
             Tcp(err) => FromSliceError::Tcp(err),
+            Geneve(err) => FromSliceError::Geneve(err),
+            Gtpu(err) => FromSliceError::Gtpu(err),
         }
     }
 }
@@ -342,7 +553,7 @@ mod tests {
 
     #[test]
     fn debug_source() {
-        let test_values: [(&str, FromSliceError); 8] = [
+        let test_values: [(&str, FromSliceError); 15] = [
             (
                 "Len",
                 Len(LenError {
@@ -378,10 +589,38 @@ mod tests {
                 "Ipv6Exts",
                 Ipv6Exts(ipv6_exts::HeaderError::HopByHopNotAtStart),
             ),
+            (
+                "Mpls",
+                Mpls(mpls::HeaderError::TooManyEntries { max_entries: 16 }),
+            ),
+            (
+                "Pppoe",
+                Pppoe(pppoe::HeaderError::UnexpectedVersion { version_number: 2 }),
+            ),
             (
                 "Tcp",
                 Tcp(tcp::HeaderError::DataOffsetTooSmall { data_offset: 1 }),
             ),
+            (
+                "VlanStack",
+                VlanStack(vlan_stack::HeaderError::TooManyTags { max_tags: 8 }),
+            ),
+            (
+                "Macsec",
+                Macsec(macsec::HeaderError::UnexpectedVersion { version_number: 1 }),
+            ),
+            (
+                "Gre",
+                Gre(gre::HeaderError::UnsupportedVersion { version_number: 2 }),
+            ),
+            (
+                "Geneve",
+                Geneve(geneve::HeaderError::UnsupportedVersion { version_number: 1 }),
+            ),
+            (
+                "Gtpu",
+                Gtpu(gtpu::HeaderError::UnsupportedVersion { version_number: 2 }),
+            ),
         ];
         for (prefix, value) in &test_values {
             // display
@@ -394,7 +633,7 @@ mod tests {
 
     #[test]
     fn display_source() {
-        let test_values: [FromSliceError; 8] = [
+        let test_values: [FromSliceError; 15] = [
             Len(LenError {
                 required_len: 0,
                 len: 0,
@@ -412,7 +651,14 @@ mod tests {
             Ipv4(ipv4::HeaderError::UnexpectedVersion { version_number: 1 }),
             Ipv6(ipv6::HeaderError::UnexpectedVersion { version_number: 1 }),
             Ipv6Exts(ipv6_exts::HeaderError::HopByHopNotAtStart),
+            Mpls(mpls::HeaderError::TooManyEntries { max_entries: 16 }),
+            Pppoe(pppoe::HeaderError::UnexpectedVersion { version_number: 2 }),
             Tcp(tcp::HeaderError::DataOffsetTooSmall { data_offset: 1 }),
+            VlanStack(vlan_stack::HeaderError::TooManyTags { max_tags: 8 }),
+            Macsec(macsec::HeaderError::UnexpectedVersion { version_number: 1 }),
+            Gre(gre::HeaderError::UnsupportedVersion { version_number: 2 }),
+            Geneve(geneve::HeaderError::UnsupportedVersion { version_number: 1 }),
+            Gtpu(gtpu::HeaderError::UnsupportedVersion { version_number: 2 }),
         ];
         for value in &test_values {
             // display
@@ -438,7 +684,14 @@ mod tests {
         let ipv6_error = || ipv6::HeaderError::UnexpectedVersion { version_number: 1 };
         let ip_auth_error = || ip_auth::HeaderError::ZeroPayloadLen;
         let ipv6_exts_error = || ipv6_exts::HeaderError::HopByHopNotAtStart;
+        let mpls_error = || mpls::HeaderError::TooManyEntries { max_entries: 16 };
+        let pppoe_error = || pppoe::HeaderError::UnexpectedVersion { version_number: 2 };
         let tcp_error = || tcp::HeaderError::DataOffsetTooSmall { data_offset: 1 };
+        let vlan_stack_error = || vlan_stack::HeaderError::TooManyTags { max_tags: 8 };
+        let macsec_error = || macsec::HeaderError::UnexpectedVersion { version_number: 1 };
+        let gre_error = || gre::HeaderError::UnsupportedVersion { version_number: 2 };
+        let geneve_error = || geneve::HeaderError::UnsupportedVersion { version_number: 1 };
+        let gtpu_error = || gtpu::HeaderError::UnsupportedVersion { version_number: 2 };
 
         // len
         assert_eq!(Len(len_error()).len(), Some(&len_error()));
@@ -474,9 +727,40 @@ mod tests {
         );
         assert_eq!(IpAuth(ip_auth_error()).ipv6_exts(), None);
 
+        // mpls
+        assert_eq!(Mpls(mpls_error()).mpls(), Some(&mpls_error()));
+        assert_eq!(IpAuth(ip_auth_error()).mpls(), None);
+
+        // pppoe
+        assert_eq!(Pppoe(pppoe_error()).pppoe(), Some(&pppoe_error()));
+        assert_eq!(IpAuth(ip_auth_error()).pppoe(), None);
+
         // tcp
         assert_eq!(Tcp(tcp_error()).tcp(), Some(&tcp_error()));
         assert_eq!(IpAuth(ip_auth_error()).tcp(), None);
+
+        // vlan_stack
+        assert_eq!(
+            VlanStack(vlan_stack_error()).vlan_stack(),
+            Some(&vlan_stack_error())
+        );
+        assert_eq!(IpAuth(ip_auth_error()).vlan_stack(), None);
+
+        // macsec
+        assert_eq!(Macsec(macsec_error()).macsec(), Some(&macsec_error()));
+        assert_eq!(IpAuth(ip_auth_error()).macsec(), None);
+
+        // gre
+        assert_eq!(Gre(gre_error()).gre(), Some(&gre_error()));
+        assert_eq!(IpAuth(ip_auth_error()).gre(), None);
+
+        // geneve
+        assert_eq!(Geneve(geneve_error()).geneve(), Some(&geneve_error()));
+        assert_eq!(IpAuth(ip_auth_error()).geneve(), None);
+
+        // gtpu
+        assert_eq!(Gtpu(gtpu_error()).gtpu(), Some(&gtpu_error()));
+        assert_eq!(IpAuth(ip_auth_error()).gtpu(), None);
     }
 
     #[test]
@@ -717,6 +1001,153 @@ mod tests {
             );
         }
 
+        // mpls errors
+        {
+            let header_error = || mpls::HeaderError::TooManyEntries { max_entries: 16 };
+            assert_eq!(
+                &header_error(),
+                FromSliceError::from(header_error()).mpls().unwrap()
+            );
+            assert_eq!(
+                &header_error(),
+                FromSliceError::from(mpls::HeaderSliceError::Content(header_error()))
+                    .mpls()
+                    .unwrap()
+            );
+            assert_eq!(
+                &len_error(),
+                FromSliceError::from(mpls::HeaderSliceError::Len(len_error()))
+                    .len()
+                    .unwrap()
+            );
+        }
+
+        // vlan stack errors
+        {
+            let header_error = || vlan_stack::HeaderError::TooManyTags { max_tags: 8 };
+            assert_eq!(
+                &header_error(),
+                FromSliceError::from(header_error()).vlan_stack().unwrap()
+            );
+            assert_eq!(
+                &header_error(),
+                FromSliceError::from(vlan_stack::HeaderSliceError::Content(header_error()))
+                    .vlan_stack()
+                    .unwrap()
+            );
+            assert_eq!(
+                &len_error(),
+                FromSliceError::from(vlan_stack::HeaderSliceError::Len(len_error()))
+                    .len()
+                    .unwrap()
+            );
+        }
+
+        // geneve errors
+        {
+            let header_error = || geneve::HeaderError::UnsupportedVersion { version_number: 1 };
+            assert_eq!(
+                &header_error(),
+                FromSliceError::from(header_error()).geneve().unwrap()
+            );
+            assert_eq!(
+                &header_error(),
+                FromSliceError::from(geneve::HeaderSliceError::Content(header_error()))
+                    .geneve()
+                    .unwrap()
+            );
+            assert_eq!(
+                &len_error(),
+                FromSliceError::from(geneve::HeaderSliceError::Len(len_error()))
+                    .len()
+                    .unwrap()
+            );
+        }
+
+        // gtpu errors
+        {
+            let header_error = || gtpu::HeaderError::UnsupportedVersion { version_number: 2 };
+            assert_eq!(
+                &header_error(),
+                FromSliceError::from(header_error()).gtpu().unwrap()
+            );
+            assert_eq!(
+                &header_error(),
+                FromSliceError::from(gtpu::HeaderSliceError::Content(header_error()))
+                    .gtpu()
+                    .unwrap()
+            );
+            assert_eq!(
+                &len_error(),
+                FromSliceError::from(gtpu::HeaderSliceError::Len(len_error()))
+                    .len()
+                    .unwrap()
+            );
+        }
+
+        // gre errors
+        {
+            let header_error = || gre::HeaderError::UnsupportedVersion { version_number: 2 };
+            assert_eq!(
+                &header_error(),
+                FromSliceError::from(header_error()).gre().unwrap()
+            );
+            assert_eq!(
+                &header_error(),
+                FromSliceError::from(gre::HeaderSliceError::Content(header_error()))
+                    .gre()
+                    .unwrap()
+            );
+            assert_eq!(
+                &len_error(),
+                FromSliceError::from(gre::HeaderSliceError::Len(len_error()))
+                    .len()
+                    .unwrap()
+            );
+        }
+
+        // macsec errors
+        {
+            let header_error = || macsec::HeaderError::UnexpectedVersion { version_number: 1 };
+            assert_eq!(
+                &header_error(),
+                FromSliceError::from(header_error()).macsec().unwrap()
+            );
+            assert_eq!(
+                &header_error(),
+                FromSliceError::from(macsec::HeaderSliceError::Content(header_error()))
+                    .macsec()
+                    .unwrap()
+            );
+            assert_eq!(
+                &len_error(),
+                FromSliceError::from(macsec::HeaderSliceError::Len(len_error()))
+                    .len()
+                    .unwrap()
+            );
+        }
+
+        // pppoe errors
+        {
+            let header_error = || pppoe::HeaderError::UnexpectedVersion { version_number: 2 };
+            assert_eq!(
+                &header_error(),
+                FromSliceError::from(header_error()).pppoe().unwrap()
+            );
+            assert_eq!(
+                &header_error(),
+                FromSliceError::from(pppoe::HeaderSliceError::Content(header_error()))
+                    .pppoe()
+                    .unwrap()
+            );
+            assert_eq!(
+                &len_error(),
+                FromSliceError::from(pppoe::HeaderSliceError::Len(len_error()))
+                    .len()
+                    .unwrap()
+            );
+        }
+
         // packet error
         {
             let ip_error = || ip::HeaderError::Ipv4Ext(ip_auth::HeaderError::ZeroPayloadLen);
@@ -724,6 +1155,13 @@ mod tests {
             let ipv6_error = || ipv6::HeaderError::UnexpectedVersion { version_number: 1 };
             let ip_auth_error = || ip_auth::HeaderError::ZeroPayloadLen;
             let ipv6_exts_error = || ipv6_exts::HeaderError::HopByHopNotAtStart;
+            let mpls_error = || mpls::HeaderError::TooManyEntries { max_entries: 16 };
+            let pppoe_error = || pppoe::HeaderError::UnexpectedVersion { version_number: 2 };
+            let vlan_stack_error = || vlan_stack::HeaderError::TooManyTags { max_tags: 8 };
+            let macsec_error = || macsec::HeaderError::UnexpectedVersion { version_number: 1 };
+            let gre_error = || gre::HeaderError::UnsupportedVersion { version_number: 2 };
+            let geneve_error = || geneve::HeaderError::UnsupportedVersion { version_number: 1 };
+            let gtpu_error = || gtpu::HeaderError::UnsupportedVersion { version_number: 2 };
             let tcp_error = || tcp::HeaderError::DataOffsetTooSmall { data_offset: 1 };
 
             // EthSliceError
@@ -733,6 +1171,30 @@ mod tests {
                     .len()
                     .unwrap()
             );
+            assert_eq!(
+                &vlan_stack_error(),
+                FromSliceError::from(packet::EthSliceError::VlanStack(vlan_stack_error()))
+                    .vlan_stack()
+                    .unwrap()
+            );
+            assert_eq!(
+                &macsec_error(),
+                FromSliceError::from(packet::EthSliceError::Macsec(macsec_error()))
+                    .macsec()
+                    .unwrap()
+            );
+            assert_eq!(
+                &mpls_error(),
+                FromSliceError::from(packet::EthSliceError::Mpls(mpls_error()))
+                    .mpls()
+                    .unwrap()
+            );
+            assert_eq!(
+                &pppoe_error(),
+                FromSliceError::from(packet::EthSliceError::Pppoe(pppoe_error()))
+                    .pppoe()
+                    .unwrap()
+            );
             assert_eq!(
                 &ipv4_error(),
                 FromSliceError::from(packet::EthSliceError::Ipv4(ipv4_error()))
@@ -757,6 +1219,12 @@ mod tests {
                     .ipv6_exts()
                     .unwrap()
             );
+            assert_eq!(
+                &gre_error(),
+                FromSliceError::from(packet::EthSliceError::Gre(gre_error()))
+                    .gre()
+                    .unwrap()
+            );
             assert_eq!(
                 &tcp_error(),
                 FromSliceError::from(packet::EthSliceError::Tcp(tcp_error()))
@@ -777,12 +1245,30 @@ mod tests {
                     .ip()
                     .unwrap()
             );
+            assert_eq!(
+                &gre_error(),
+                FromSliceError::from(packet::IpSliceError::Gre(gre_error()))
+                    .gre()
+                    .unwrap()
+            );
             assert_eq!(
                 &tcp_error(),
                 FromSliceError::from(packet::IpSliceError::Tcp(tcp_error()))
                     .tcp()
                     .unwrap()
             );
+            assert_eq!(
+                &geneve_error(),
+                FromSliceError::from(packet::IpSliceError::Geneve(geneve_error()))
+                    .geneve()
+                    .unwrap()
+            );
+            assert_eq!(
+                &gtpu_error(),
+                FromSliceError::from(packet::IpSliceError::Gtpu(gtpu_error()))
+                    .gtpu()
+                    .unwrap()
+            );
         }
 
         // tcp errors
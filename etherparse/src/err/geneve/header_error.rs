@@ -0,0 +1,75 @@
+/// Errors in a Geneve header encountered while decoding it.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum HeaderError {
+    /// Error when the version field contains a value other then 0.
+    UnsupportedVersion {
+        /// The unsupported version number in the Geneve header.
+        version_number: u8,
+    },
+}
+
+impl core::fmt::Display for HeaderError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        use HeaderError::*;
+        match self {
+            UnsupportedVersion { version_number } => write!(f, "Geneve Header Error: Encountered '{}' as version number in the Geneve header (only '0' is supported).", version_number),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for HeaderError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::HeaderError::*;
+    use alloc::format;
+    use std::{
+        collections::hash_map::DefaultHasher,
+        error::Error,
+        hash::{Hash, Hasher},
+    };
+
+    #[test]
+    fn debug() {
+        assert_eq!(
+            "UnsupportedVersion { version_number: 2 }",
+            format!("{:?}", UnsupportedVersion { version_number: 2 })
+        );
+    }
+
+    #[test]
+    fn clone_eq_hash() {
+        let err = UnsupportedVersion { version_number: 2 };
+        assert_eq!(err, err.clone());
+        let hash_a = {
+            let mut hasher = DefaultHasher::new();
+            err.hash(&mut hasher);
+            hasher.finish()
+        };
+        let hash_b = {
+            let mut hasher = DefaultHasher::new();
+            err.clone().hash(&mut hasher);
+            hasher.finish()
+        };
+        assert_eq!(hash_a, hash_b);
+    }
+
+    #[test]
+    fn fmt() {
+        assert_eq!(
+            "Geneve Header Error: Encountered '2' as version number in the Geneve header (only '0' is supported).",
+            format!("{}", UnsupportedVersion { version_number: 2 })
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn source() {
+        assert!(UnsupportedVersion { version_number: 2 }.source().is_none());
+    }
+}
@@ -0,0 +1,8 @@
+mod bad_options_len;
+pub use bad_options_len::*;
+
+mod header_error;
+pub use header_error::*;
+
+mod header_slice_error;
+pub use header_slice_error::*;
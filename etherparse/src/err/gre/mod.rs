@@ -0,0 +1,5 @@
+mod header_error;
+pub use header_error::*;
+
+mod header_slice_error;
+pub use header_slice_error::*;
@@ -0,0 +1,105 @@
+/// Errors that can occur when constructing [`crate::GtpuExtensionHeaders`].
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum ExtHeadersError {
+    /// Error when an extension header has the type `0` (reserved to
+    /// indicate that no more extension headers follow).
+    ZeroHeaderType,
+
+    /// Error when the content length of an extension header is not
+    /// compatible with the length field (the content length plus 2 must
+    /// be a multiple of 4 and at maximum 1020).
+    BadContentLen {
+        /// Invalid length of the content.
+        content_len: usize,
+    },
+
+    /// Error when the encoded extension headers exceed
+    /// [`crate::GtpuExtensionHeaders::MAX_LEN`].
+    ExceedsMaxLen {
+        /// Length required to encode the extension headers.
+        required_len: usize,
+    },
+}
+
+impl core::fmt::Display for ExtHeadersError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        use ExtHeadersError::*;
+        match self {
+            ZeroHeaderType => write!(f, "GTP-U extension header type '0' can not be used for an extension header (reserved for 'no more extension headers')."),
+            BadContentLen { content_len } => write!(f, "Content of length {} cannot be set as GTP-U extension header content. The length plus 2 must be a multiple of 4 and at maximum 1020.", content_len),
+            ExceedsMaxLen { required_len } => write!(f, "GTP-U extension headers with a length of {} exceed the maximum supported length of {}.", required_len, crate::GtpuExtensionHeaders::MAX_LEN),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ExtHeadersError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ExtHeadersError::*;
+    use alloc::format;
+    use std::{
+        collections::hash_map::DefaultHasher,
+        error::Error,
+        hash::{Hash, Hasher},
+    };
+
+    #[test]
+    fn debug() {
+        assert_eq!(
+            "BadContentLen { content_len: 3 }",
+            format!("{:?}", BadContentLen { content_len: 3 })
+        );
+    }
+
+    #[test]
+    fn clone_eq_hash() {
+        let err = BadContentLen { content_len: 3 };
+        assert_eq!(err, err.clone());
+        let hash_a = {
+            let mut hasher = DefaultHasher::new();
+            err.hash(&mut hasher);
+            hasher.finish()
+        };
+        let hash_b = {
+            let mut hasher = DefaultHasher::new();
+            err.clone().hash(&mut hasher);
+            hasher.finish()
+        };
+        assert_eq!(hash_a, hash_b);
+    }
+
+    #[test]
+    fn fmt() {
+        assert_eq!(
+            "GTP-U extension header type '0' can not be used for an extension header (reserved for 'no more extension headers').",
+            format!("{}", ZeroHeaderType)
+        );
+        assert_eq!(
+            "Content of length 3 cannot be set as GTP-U extension header content. The length plus 2 must be a multiple of 4 and at maximum 1020.",
+            format!("{}", BadContentLen { content_len: 3 })
+        );
+        assert_eq!(
+            "GTP-U extension headers with a length of 2000 exceed the maximum supported length of 1020.",
+            format!("{}", ExceedsMaxLen { required_len: 2000 })
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn source() {
+        let values = [
+            ZeroHeaderType,
+            BadContentLen { content_len: 3 },
+            ExceedsMaxLen { required_len: 2000 },
+        ];
+        for v in values {
+            assert!(v.source().is_none());
+        }
+    }
+}
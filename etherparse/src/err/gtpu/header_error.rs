@@ -0,0 +1,135 @@
+/// Errors in a GTP-U header encountered while decoding it.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum HeaderError {
+    /// Error when the version field contains a value other then 1.
+    UnsupportedVersion {
+        /// The unsupported version number in the GTP-U header.
+        version_number: u8,
+    },
+
+    /// Error when the protocol type bit is not set (header is a GTP'
+    /// header).
+    UnsupportedProtocolType,
+
+    /// Error when an extension header has a length of zero.
+    ZeroExtensionHeaderLen {
+        /// Type of the extension header with the zero length.
+        header_type: u8,
+    },
+
+    /// Error when the "length" field is too small to contain the optional
+    /// fields & extension headers.
+    LengthTooSmall {
+        /// Value of the "length" field.
+        length: u16,
+        /// Length of the optional fields & extension headers (minimum
+        /// value of the "length" field).
+        min_length: usize,
+    },
+
+    /// Error when the extension headers are too long to be stored in
+    /// [`crate::GtpuExtensionHeaders`] (only returned when converting a
+    /// [`crate::GtpuHeaderSlice`] to a [`crate::GtpuHeader`]).
+    ExtensionHeadersTooLong {
+        /// Length of the encoded extension headers.
+        len: usize,
+    },
+}
+
+impl core::fmt::Display for HeaderError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        use HeaderError::*;
+        match self {
+            UnsupportedVersion { version_number } => write!(f, "GTP-U Header Error: Encountered '{}' as version number in the GTP-U header (only '1' is supported).", version_number),
+            UnsupportedProtocolType => write!(f, "GTP-U Header Error: Protocol type bit is not set in the GTP-U header (GTP' is not supported)."),
+            ZeroExtensionHeaderLen { header_type } => write!(f, "GTP-U Header Error: Encountered an extension header (type '{}') with a length of zero.", header_type),
+            LengthTooSmall { length, min_length } => write!(f, "GTP-U Header Error: The 'length' field value '{}' is smaller then the length of the optional fields & extension headers ('{}').", length, min_length),
+            ExtensionHeadersTooLong { len } => write!(f, "GTP-U Header Error: The extension headers with a length of '{}' exceed the maximum supported length of '{}'.", len, crate::GtpuExtensionHeaders::MAX_LEN),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for HeaderError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::HeaderError::*;
+    use alloc::format;
+    use std::{
+        collections::hash_map::DefaultHasher,
+        error::Error,
+        hash::{Hash, Hasher},
+    };
+
+    #[test]
+    fn debug() {
+        assert_eq!(
+            "UnsupportedVersion { version_number: 2 }",
+            format!("{:?}", UnsupportedVersion { version_number: 2 })
+        );
+    }
+
+    #[test]
+    fn clone_eq_hash() {
+        let err = UnsupportedVersion { version_number: 2 };
+        assert_eq!(err, err.clone());
+        let hash_a = {
+            let mut hasher = DefaultHasher::new();
+            err.hash(&mut hasher);
+            hasher.finish()
+        };
+        let hash_b = {
+            let mut hasher = DefaultHasher::new();
+            err.clone().hash(&mut hasher);
+            hasher.finish()
+        };
+        assert_eq!(hash_a, hash_b);
+    }
+
+    #[test]
+    fn fmt() {
+        assert_eq!(
+            "GTP-U Header Error: Encountered '2' as version number in the GTP-U header (only '1' is supported).",
+            format!("{}", UnsupportedVersion { version_number: 2 })
+        );
+        assert_eq!(
+            "GTP-U Header Error: Protocol type bit is not set in the GTP-U header (GTP' is not supported).",
+            format!("{}", UnsupportedProtocolType)
+        );
+        assert_eq!(
+            "GTP-U Header Error: Encountered an extension header (type '133') with a length of zero.",
+            format!("{}", ZeroExtensionHeaderLen { header_type: 133 })
+        );
+        assert_eq!(
+            "GTP-U Header Error: The 'length' field value '3' is smaller then the length of the optional fields & extension headers ('4').",
+            format!("{}", LengthTooSmall { length: 3, min_length: 4 })
+        );
+        assert_eq!(
+            "GTP-U Header Error: The extension headers with a length of '1024' exceed the maximum supported length of '1020'.",
+            format!("{}", ExtensionHeadersTooLong { len: 1024 })
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn source() {
+        let values = [
+            UnsupportedVersion { version_number: 2 },
+            UnsupportedProtocolType,
+            ZeroExtensionHeaderLen { header_type: 133 },
+            LengthTooSmall {
+                length: 3,
+                min_length: 4,
+            },
+            ExtensionHeadersTooLong { len: 1024 },
+        ];
+        for v in values {
+            assert!(v.source().is_none());
+        }
+    }
+}
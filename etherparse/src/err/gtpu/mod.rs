@@ -0,0 +1,8 @@
+mod ext_headers_error;
+pub use ext_headers_error::*;
+
+mod header_error;
+pub use header_error::*;
+
+mod header_slice_error;
+pub use header_slice_error::*;
@@ -0,0 +1,187 @@
+use crate::IpFragOffset;
+
+/// Error when a fragment could not be added to a reassembly buffer or
+/// pool (see [`crate::defrag`]).
+///
+/// Fragments triggering an error are discarded, previously received
+/// fragments are kept (except for [`IpDefragError::OverlappingFragment`]).
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum IpDefragError {
+    /// Error if a fragment that is not the last fragment of a packet
+    /// ("more fragments" flag set) has a payload length that is not a
+    /// multiple of 8.
+    UnalignedFragmentPayloadLen {
+        /// Offset of the fragment.
+        offset: IpFragOffset,
+        /// Payload length of the fragment.
+        payload_len: usize,
+    },
+
+    /// Error if a fragment would extend the reassembled packet beyond
+    /// the maximum supported payload length.
+    SegmentTooBig {
+        /// Offset of the fragment.
+        offset: IpFragOffset,
+        /// Payload length of the fragment.
+        payload_len: usize,
+        /// Maximum payload length of the reassembled packet.
+        max: u16,
+    },
+
+    /// Error if the end of the packet (determined by the last fragment)
+    /// conflicts with the end of another fragment.
+    ///
+    /// Either two "last" fragments with different end positions were
+    /// received or a fragment contains data after the end of the
+    /// packet.
+    ConflictingEnd {
+        /// End of the packet (in bytes) previously determined by a
+        /// last fragment or end of the data received so far.
+        previous_end: u32,
+        /// End (in bytes) of the conflicting fragment.
+        conflicting_end: u32,
+    },
+
+    /// Error if an IPv6 fragment overlaps with a previously received
+    /// fragment of the same packet.
+    ///
+    /// As required by RFC 5722 the reassembly of the packet is abandoned
+    /// and all previously received fragments are discarded as well.
+    OverlappingFragment {
+        /// Offset of the fragment.
+        offset: IpFragOffset,
+        /// Payload length of the fragment.
+        payload_len: usize,
+    },
+
+    /// Error if adding the fragment would exceed the maximum number of
+    /// bytes allowed to be buffered.
+    MemoryLimitExceeded {
+        /// Maximum number of bytes allowed to be buffered.
+        max_buffered_bytes: usize,
+    },
+
+    /// Error if adding the fragment would require a new reassembly
+    /// buffer but the maximum number of packets is already in
+    /// reassembly.
+    PacketLimitExceeded {
+        /// Maximum number of packets allowed to be in reassembly.
+        max_packets: usize,
+    },
+}
+
+impl core::fmt::Display for IpDefragError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        use IpDefragError::*;
+        match self {
+            UnalignedFragmentPayloadLen { offset, payload_len } => write!(f, "IP Defragmentation Error: Payload length {} of the non-final fragment with offset {} (in 8 octets) is not a multiple of 8.", payload_len, offset.value()),
+            SegmentTooBig { offset, payload_len, max } => write!(f, "IP Defragmentation Error: Fragment with offset {} (in 8 octets) and payload length {} exceeds the maximum reassembled payload length of {} bytes.", offset.value(), payload_len, max),
+            ConflictingEnd { previous_end, conflicting_end } => write!(f, "IP Defragmentation Error: Fragment ending at byte {} conflicts with the previously determined packet end at byte {}.", conflicting_end, previous_end),
+            OverlappingFragment { offset, payload_len } => write!(f, "IP Defragmentation Error: Fragment with offset {} (in 8 octets) and payload length {} overlaps with previously received data (reassembly of the packet got abandoned as required by RFC 5722).", offset.value(), payload_len),
+            MemoryLimitExceeded { max_buffered_bytes } => write!(f, "IP Defragmentation Error: Buffering the fragment would exceed the memory limit of {} bytes.", max_buffered_bytes),
+            PacketLimitExceeded { max_packets } => write!(f, "IP Defragmentation Error: Fragment would start the reassembly of a new packet but the limit of {} packets in reassembly is already reached.", max_packets),
+        }
+    }
+}
+
+impl std::error::Error for IpDefragError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::IpDefragError::*;
+    use crate::IpFragOffset;
+    use alloc::format;
+    use std::{
+        collections::hash_map::DefaultHasher,
+        error::Error,
+        hash::{Hash, Hasher},
+    };
+
+    #[test]
+    fn debug() {
+        assert_eq!(
+            "MemoryLimitExceeded { max_buffered_bytes: 3 }",
+            format!(
+                "{:?}",
+                MemoryLimitExceeded {
+                    max_buffered_bytes: 3
+                }
+            )
+        );
+    }
+
+    #[test]
+    fn clone_eq_hash() {
+        let err = PacketLimitExceeded { max_packets: 2 };
+        assert_eq!(err, err.clone());
+        let hash_a = {
+            let mut hasher = DefaultHasher::new();
+            err.hash(&mut hasher);
+            hasher.finish()
+        };
+        let hash_b = {
+            let mut hasher = DefaultHasher::new();
+            err.clone().hash(&mut hasher);
+            hasher.finish()
+        };
+        assert_eq!(hash_a, hash_b);
+    }
+
+    #[test]
+    fn fmt() {
+        let offset = IpFragOffset::try_new(2).unwrap();
+        let tests = [
+            (
+                UnalignedFragmentPayloadLen {
+                    offset,
+                    payload_len: 3,
+                },
+                "IP Defragmentation Error: Payload length 3 of the non-final fragment with offset 2 (in 8 octets) is not a multiple of 8.",
+            ),
+            (
+                SegmentTooBig {
+                    offset,
+                    payload_len: 4,
+                    max: 5,
+                },
+                "IP Defragmentation Error: Fragment with offset 2 (in 8 octets) and payload length 4 exceeds the maximum reassembled payload length of 5 bytes.",
+            ),
+            (
+                ConflictingEnd {
+                    previous_end: 16,
+                    conflicting_end: 24,
+                },
+                "IP Defragmentation Error: Fragment ending at byte 24 conflicts with the previously determined packet end at byte 16.",
+            ),
+            (
+                OverlappingFragment {
+                    offset,
+                    payload_len: 8,
+                },
+                "IP Defragmentation Error: Fragment with offset 2 (in 8 octets) and payload length 8 overlaps with previously received data (reassembly of the packet got abandoned as required by RFC 5722).",
+            ),
+            (
+                MemoryLimitExceeded {
+                    max_buffered_bytes: 3,
+                },
+                "IP Defragmentation Error: Buffering the fragment would exceed the memory limit of 3 bytes.",
+            ),
+            (
+                PacketLimitExceeded { max_packets: 2 },
+                "IP Defragmentation Error: Fragment would start the reassembly of a new packet but the limit of 2 packets in reassembly is already reached.",
+            ),
+        ];
+        for (err, expected) in tests {
+            assert_eq!(expected, format!("{}", err));
+        }
+    }
+
+    #[test]
+    fn source() {
+        assert!(PacketLimitExceeded { max_packets: 2 }.source().is_none());
+    }
+}
@@ -0,0 +1,2 @@
+mod ip_defrag_error;
+pub use ip_defrag_error::*;
@@ -0,0 +1,147 @@
+use crate::err::{LenError, ValueTooBigError};
+
+/// Error when an IP packet could not be split into fragments (see
+/// [`crate::frag`]).
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum IpFragError {
+    /// Error if the MTU is too small to contain the headers that have
+    /// to be present in every fragment plus at least 8 bytes of payload.
+    MtuTooSmall {
+        /// MTU that was configured.
+        mtu: usize,
+        /// Minimum MTU required to fragment the packet.
+        min_mtu: usize,
+    },
+
+    /// Error if an IPv6 packet exceeds the MTU but already contains a
+    /// fragment header.
+    AlreadyFragmented,
+
+    /// Error if no IP header is present in the headers that should
+    /// be fragmented.
+    MissingIpHeader,
+
+    /// Error if the payload is too big to be representable by the
+    /// length fields or fragment offsets.
+    PayloadLen(ValueTooBigError<usize>),
+
+    /// Error if the extension headers of the unfragmentable part of an
+    /// IPv6 packet are longer than the data.
+    Len(LenError),
+}
+
+impl core::fmt::Display for IpFragError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        use IpFragError::*;
+        match self {
+            MtuTooSmall { mtu, min_mtu } => write!(f, "IP Fragmentation Error: MTU of {} bytes is too small to fragment the packet (an MTU of at least {} bytes is required).", mtu, min_mtu),
+            AlreadyFragmented => write!(f, "IP Fragmentation Error: IPv6 packet exceeds the MTU but already contains a fragment header."),
+            MissingIpHeader => write!(f, "IP Fragmentation Error: No IP header present that could be fragmented."),
+            PayloadLen(err) => err.fmt(f),
+            Len(err) => err.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for IpFragError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use IpFragError::*;
+        match self {
+            PayloadLen(err) => Some(err),
+            Len(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::IpFragError::*;
+    use crate::err::{Layer, LenError, LenSource, ValueTooBigError, ValueType};
+    use alloc::format;
+    use std::{
+        collections::hash_map::DefaultHasher,
+        error::Error,
+        hash::{Hash, Hasher},
+    };
+
+    #[test]
+    fn debug() {
+        assert_eq!(
+            "MtuTooSmall { mtu: 3, min_mtu: 4 }",
+            format!("{:?}", MtuTooSmall { mtu: 3, min_mtu: 4 })
+        );
+    }
+
+    #[test]
+    fn clone_eq_hash() {
+        let err = MtuTooSmall { mtu: 3, min_mtu: 4 };
+        assert_eq!(err, err.clone());
+        let hash_a = {
+            let mut hasher = DefaultHasher::new();
+            err.hash(&mut hasher);
+            hasher.finish()
+        };
+        let hash_b = {
+            let mut hasher = DefaultHasher::new();
+            err.clone().hash(&mut hasher);
+            hasher.finish()
+        };
+        assert_eq!(hash_a, hash_b);
+    }
+
+    #[test]
+    fn fmt() {
+        assert_eq!(
+            "IP Fragmentation Error: MTU of 3 bytes is too small to fragment the packet (an MTU of at least 4 bytes is required).",
+            format!("{}", MtuTooSmall { mtu: 3, min_mtu: 4 })
+        );
+        assert_eq!(
+            "IP Fragmentation Error: IPv6 packet exceeds the MTU but already contains a fragment header.",
+            format!("{}", AlreadyFragmented)
+        );
+        assert_eq!(
+            "IP Fragmentation Error: No IP header present that could be fragmented.",
+            format!("{}", MissingIpHeader)
+        );
+        {
+            let err = ValueTooBigError {
+                actual: 3,
+                max_allowed: 2,
+                value_type: ValueType::Ipv4PayloadLength,
+            };
+            assert_eq!(format!("{}", err), format!("{}", PayloadLen(err.clone())));
+        }
+        {
+            let err = LenError {
+                required_len: 2,
+                len: 1,
+                len_source: LenSource::Slice,
+                layer: Layer::Ipv6ExtHeader,
+                layer_start_offset: 0,
+            };
+            assert_eq!(format!("{}", err), format!("{}", Len(err.clone())));
+        }
+    }
+
+    #[test]
+    fn source() {
+        assert!(AlreadyFragmented.source().is_none());
+        assert!(PayloadLen(ValueTooBigError {
+            actual: 3,
+            max_allowed: 2,
+            value_type: ValueType::Ipv4PayloadLength,
+        })
+        .source()
+        .is_some());
+        assert!(Len(LenError {
+            required_len: 2,
+            len: 1,
+            len_source: LenSource::Slice,
+            layer: Layer::Ipv6ExtHeader,
+            layer_start_offset: 0,
+        })
+        .source()
+        .is_some());
+    }
+}
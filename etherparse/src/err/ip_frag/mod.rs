@@ -0,0 +1,2 @@
+mod ip_frag_error;
+pub use ip_frag_error::*;
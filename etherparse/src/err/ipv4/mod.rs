@@ -12,5 +12,11 @@ pub use header_read_error::*;
 mod header_slice_error;
 pub use header_slice_error::*;
 
+mod option_read_error;
+pub use option_read_error::*;
+
+mod option_write_error;
+pub use option_write_error::*;
+
 mod slice_error;
 pub use slice_error::*;
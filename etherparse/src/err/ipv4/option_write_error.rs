@@ -0,0 +1,78 @@
+/// Errors that can occur when serializing [`crate::Ipv4OptionElement`]s
+/// into [`crate::Ipv4Options`].
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum OptionWriteError {
+    /// Error if the serialized options (including the padding to a
+    /// multiple of 4 bytes) would exceed the maximum IPv4 options
+    /// length of 40 bytes.
+    NotEnoughSpace {
+        /// Length (including padding) the options would have required.
+        required_len: usize,
+    },
+}
+
+impl core::fmt::Display for OptionWriteError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        use OptionWriteError::*;
+        match self {
+            NotEnoughSpace { required_len } => write!(f, "IPv4 Option Error: Not enough space to store all options in the IPv4 header (maximum 40 bytes can be stored, the options would have needed {} bytes).", required_len),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for OptionWriteError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::OptionWriteError::*;
+    use alloc::format;
+    use std::{
+        collections::hash_map::DefaultHasher,
+        error::Error,
+        hash::{Hash, Hasher},
+    };
+
+    #[test]
+    fn debug() {
+        assert_eq!(
+            "NotEnoughSpace { required_len: 44 }",
+            format!("{:?}", NotEnoughSpace { required_len: 44 })
+        );
+    }
+
+    #[test]
+    fn clone_eq_hash() {
+        let err = NotEnoughSpace { required_len: 44 };
+        assert_eq!(err, err.clone());
+        let hash_a = {
+            let mut hasher = DefaultHasher::new();
+            err.hash(&mut hasher);
+            hasher.finish()
+        };
+        let hash_b = {
+            let mut hasher = DefaultHasher::new();
+            err.clone().hash(&mut hasher);
+            hasher.finish()
+        };
+        assert_eq!(hash_a, hash_b);
+    }
+
+    #[test]
+    fn fmt() {
+        assert_eq!(
+            "IPv4 Option Error: Not enough space to store all options in the IPv4 header (maximum 40 bytes can be stored, the options would have needed 44 bytes).",
+            format!("{}", NotEnoughSpace { required_len: 44 })
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn source() {
+        assert!(NotEnoughSpace { required_len: 44 }.source().is_none());
+    }
+}
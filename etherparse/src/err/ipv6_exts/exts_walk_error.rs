@@ -15,6 +15,13 @@ pub enum ExtsWalkError {
         /// IpNumber of the header which was not referenced.
         missing_ext: IpNumber,
     },
+
+    /// Error when an extension header can not be represented by
+    /// [`crate::Ipv6Extensions`] (e.g. repeated extension headers).
+    ExtNotRepresentable {
+        /// IpNumber of the header which can not be represented.
+        ext: IpNumber,
+    },
 }
 
 impl core::fmt::Display for ExtsWalkError {
@@ -24,6 +31,8 @@ impl core::fmt::Display for ExtsWalkError {
                 write!(f, "IPv6 extensions hop-by-hop is not located directly after the IPv6 header (required by IPv6)."),
             ExtsWalkError::ExtNotReferenced{ missing_ext } =>
                 write!(f, "IPv6 extensions '{:?}' is defined but is not referenced by any of the 'next_header' of the other extension headers or the IPv6 header.", missing_ext),
+            ExtsWalkError::ExtNotRepresentable{ ext } =>
+                write!(f, "IPv6 extensions '{:?}' can not be represented by the fixed IPv6 extensions struct (e.g. because it is repeated or not supported).", ext),
         }
     }
 }
@@ -78,6 +87,10 @@ mod tests {
             "IPv6 extensions '44 (IPv6-Frag - Fragment Header for IPv6)' is defined but is not referenced by any of the 'next_header' of the other extension headers or the IPv6 header.",
             format!("{}", ExtNotReferenced{ missing_ext: IpNumber::IPV6_FRAGMENTATION_HEADER })
         );
+        assert_eq!(
+            "IPv6 extensions '253 (Use for experimentation and testing)' can not be represented by the fixed IPv6 extensions struct (e.g. because it is repeated or not supported).",
+            format!("{}", ExtNotRepresentable{ ext: IpNumber::EXPERIMENTAL_AND_TESTING_0 })
+        );
     }
 
     #[cfg(feature = "std")]
@@ -8,6 +8,15 @@ pub enum HeaderError {
 
     /// Error in the ip authentification header.
     IpAuth(err::ip_auth::HeaderError),
+
+    /// Error when more extension headers are present than can be stored
+    /// in an [`crate::Ipv6ExtensionChain`] (limited to
+    /// [`crate::Ipv6ExtensionChain::MAX_HEADERS`]).
+    TooManyExtHeaders {
+        /// Maximum number of extension headers that can be stored
+        /// (value of [`crate::Ipv6ExtensionChain::MAX_HEADERS`]).
+        max_headers: usize,
+    },
 }
 
 impl core::fmt::Display for HeaderError {
@@ -16,6 +25,7 @@ impl core::fmt::Display for HeaderError {
         match self {
             HopByHopNotAtStart => write!(f, "IPv6 Extension Header Error: Encountered an IPv6 hop-by-hop header not directly after the IPv6 header. This is not allowed according to RFC 8200."),
             IpAuth(err) => err.fmt(f),
+            TooManyExtHeaders { max_headers } => write!(f, "IPv6 Extension Header Error: More then {} extension headers are present (the maximum number of headers an Ipv6ExtensionChain can store).", max_headers),
         }
     }
 }
@@ -27,6 +37,7 @@ impl std::error::Error for HeaderError {
         match self {
             HopByHopNotAtStart => None,
             IpAuth(err) => Some(err),
+            TooManyExtHeaders { max_headers: _ } => None,
         }
     }
 }
@@ -74,6 +85,10 @@ mod tests {
             let err = err::ip_auth::HeaderError::ZeroPayloadLen;
             assert_eq!(format!("{}", err), format!("{}", IpAuth(err)));
         }
+        assert_eq!(
+            "IPv6 Extension Header Error: More then 14 extension headers are present (the maximum number of headers an Ipv6ExtensionChain can store).",
+            format!("{}", TooManyExtHeaders { max_headers: 14 })
+        );
     }
 
     #[cfg(feature = "std")]
@@ -83,5 +98,6 @@ mod tests {
 
         assert!(HopByHopNotAtStart.source().is_none());
         assert!(IpAuth(ZeroPayloadLen).source().is_some());
+        assert!(TooManyExtHeaders { max_headers: 8 }.source().is_none());
     }
 }
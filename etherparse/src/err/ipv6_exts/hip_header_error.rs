@@ -0,0 +1,77 @@
+/// Error when decoding a Host Identity Protocol (HIP) header (see
+/// [`crate::HipHeaderSlice`]).
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum HipHeaderError {
+    /// Error if the header is too short to contain the fixed HIP
+    /// header fields (40 bytes, "header length" of at least 4).
+    HeaderTooShort {
+        /// Value of the "header length" field.
+        hdr_ext_len: u8,
+    },
+}
+
+impl core::fmt::Display for HipHeaderError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        use HipHeaderError::*;
+        match self {
+            HeaderTooShort { hdr_ext_len } => write!(f, "HIP Header Error: The header length ('header length' value {}) is too small to contain the fixed HIP header fields (minimum value is 4).", hdr_ext_len),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for HipHeaderError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::HipHeaderError::*;
+    use alloc::format;
+    use std::{
+        collections::hash_map::DefaultHasher,
+        error::Error,
+        hash::{Hash, Hasher},
+    };
+
+    #[test]
+    fn debug() {
+        assert_eq!(
+            "HeaderTooShort { hdr_ext_len: 3 }",
+            format!("{:?}", HeaderTooShort { hdr_ext_len: 3 })
+        );
+    }
+
+    #[test]
+    fn clone_eq_hash() {
+        let err = HeaderTooShort { hdr_ext_len: 3 };
+        assert_eq!(err, err.clone());
+        let hash_a = {
+            let mut hasher = DefaultHasher::new();
+            err.hash(&mut hasher);
+            hasher.finish()
+        };
+        let hash_b = {
+            let mut hasher = DefaultHasher::new();
+            err.clone().hash(&mut hasher);
+            hasher.finish()
+        };
+        assert_eq!(hash_a, hash_b);
+    }
+
+    #[test]
+    fn fmt() {
+        assert_eq!(
+            "HIP Header Error: The header length ('header length' value 3) is too small to contain the fixed HIP header fields (minimum value is 4).",
+            format!("{}", HeaderTooShort { hdr_ext_len: 3 })
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn source() {
+        assert!(HeaderTooShort { hdr_ext_len: 3 }.source().is_none());
+    }
+}
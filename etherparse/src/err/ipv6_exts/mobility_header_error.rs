@@ -0,0 +1,122 @@
+use crate::Ipv6MobilityHeaderType;
+
+/// Error when decoding a typed IPv6 mobility header message (e.g.
+/// [`crate::Ipv6MobilityBindingUpdateSlice`]).
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum MobilityHeaderError {
+    /// Error if the "MH Type" of the header does not match the
+    /// message type of the typed header.
+    UnexpectedMhType {
+        /// Message type that was expected.
+        expected: Ipv6MobilityHeaderType,
+        /// Message type present in the header.
+        actual: Ipv6MobilityHeaderType,
+    },
+
+    /// Error if the header is too short to contain the fixed fields
+    /// of the message.
+    MessageTooShort {
+        /// Message type of the header.
+        mh_type: Ipv6MobilityHeaderType,
+        /// Value of the "header len" field.
+        hdr_ext_len: u8,
+    },
+}
+
+impl core::fmt::Display for MobilityHeaderError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        use MobilityHeaderError::*;
+        match self {
+            UnexpectedMhType { expected, actual } => write!(f, "IPv6 Mobility Header Error: Expected the message type {:?} but the header has the message type {:?}.", expected, actual),
+            MessageTooShort { mh_type, hdr_ext_len } => write!(f, "IPv6 Mobility Header Error: The header length ('header len' value {}) is too small to contain the message type {:?}.", hdr_ext_len, mh_type),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for MobilityHeaderError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MobilityHeaderError::*;
+    use crate::Ipv6MobilityHeaderType;
+    use alloc::format;
+    use std::{
+        collections::hash_map::DefaultHasher,
+        error::Error,
+        hash::{Hash, Hasher},
+    };
+
+    #[test]
+    fn debug() {
+        assert_eq!(
+            "MessageTooShort { mh_type: 5 (Binding Update), hdr_ext_len: 0 }",
+            format!(
+                "{:?}",
+                MessageTooShort {
+                    mh_type: Ipv6MobilityHeaderType::BINDING_UPDATE,
+                    hdr_ext_len: 0
+                }
+            )
+        );
+    }
+
+    #[test]
+    fn clone_eq_hash() {
+        let err = MessageTooShort {
+            mh_type: Ipv6MobilityHeaderType::BINDING_UPDATE,
+            hdr_ext_len: 0,
+        };
+        assert_eq!(err, err.clone());
+        let hash_a = {
+            let mut hasher = DefaultHasher::new();
+            err.hash(&mut hasher);
+            hasher.finish()
+        };
+        let hash_b = {
+            let mut hasher = DefaultHasher::new();
+            err.clone().hash(&mut hasher);
+            hasher.finish()
+        };
+        assert_eq!(hash_a, hash_b);
+    }
+
+    #[test]
+    fn fmt() {
+        assert_eq!(
+            "IPv6 Mobility Header Error: Expected the message type 5 (Binding Update) but the header has the message type 6 (Binding Acknowledgement).",
+            format!(
+                "{}",
+                UnexpectedMhType {
+                    expected: Ipv6MobilityHeaderType::BINDING_UPDATE,
+                    actual: Ipv6MobilityHeaderType::BINDING_ACK
+                }
+            )
+        );
+        assert_eq!(
+            "IPv6 Mobility Header Error: The header length ('header len' value 0) is too small to contain the message type 6 (Binding Acknowledgement).",
+            format!(
+                "{}",
+                MessageTooShort {
+                    mh_type: Ipv6MobilityHeaderType::BINDING_ACK,
+                    hdr_ext_len: 0
+                }
+            )
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn source() {
+        assert!(MessageTooShort {
+            mh_type: Ipv6MobilityHeaderType::BINDING_ACK,
+            hdr_ext_len: 0
+        }
+        .source()
+        .is_none());
+    }
+}
@@ -22,3 +22,21 @@ pub use header_slice_error::*;
 
 mod header_write_error;
 pub use header_write_error::*;
+
+mod hip_header_error;
+pub use hip_header_error::*;
+
+mod mobility_header_error;
+pub use mobility_header_error::*;
+
+mod option_read_error;
+pub use option_read_error::*;
+
+mod option_write_error;
+pub use option_write_error::*;
+
+mod routing_header_error;
+pub use routing_header_error::*;
+
+mod srh_write_error;
+pub use srh_write_error::*;
@@ -0,0 +1,125 @@
+/// Errors that can occur while decoding the options of an IPv6 hop-by-hop
+/// or destination options header (see [`crate::Ipv6OptionsIterator`]) or
+/// the TLVs of a segment routing header (see [`crate::Ipv6SrhTlvsIterator`]).
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum OptionReadError {
+    /// Error if an option type was read, but not enough data is left
+    /// in the options to completely read the option.
+    UnexpectedEndOfSlice {
+        /// Type of the option that could not be read.
+        option_type: u8,
+        /// Length that would have been required to read the option
+        /// (including the type & length fields).
+        expected_len: usize,
+        /// Length of the options data that was left.
+        actual_len: usize,
+    },
+
+    /// Error if the length field of an option contains a value not
+    /// allowed for the option type (e.g. != 2 for a router alert option).
+    UnexpectedSize {
+        /// Type of the option with the unexpected length.
+        option_type: u8,
+        /// Value of the option data length field.
+        size: u8,
+    },
+}
+
+impl core::fmt::Display for OptionReadError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        use OptionReadError::*;
+        match self {
+            UnexpectedEndOfSlice { option_type, expected_len, actual_len } => write!(f, "IPv6 Option Error: Not enough data left in the options to read option of type {} (expected at least {} bytes, only {} bytes available).", option_type, expected_len, actual_len),
+            UnexpectedSize { option_type, size } => write!(f, "IPv6 Option Error: Length value of the option of type {} had unexpected value {}.", option_type, size),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for OptionReadError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::OptionReadError::*;
+    use alloc::format;
+    use std::{
+        collections::hash_map::DefaultHasher,
+        error::Error,
+        hash::{Hash, Hasher},
+    };
+
+    #[test]
+    fn debug() {
+        assert_eq!(
+            "UnexpectedSize { option_type: 5, size: 3 }",
+            format!(
+                "{:?}",
+                UnexpectedSize {
+                    option_type: 5,
+                    size: 3
+                }
+            )
+        );
+    }
+
+    #[test]
+    fn clone_eq_hash() {
+        let err = UnexpectedEndOfSlice {
+            option_type: 5,
+            expected_len: 4,
+            actual_len: 2,
+        };
+        assert_eq!(err, err.clone());
+        let hash_a = {
+            let mut hasher = DefaultHasher::new();
+            err.hash(&mut hasher);
+            hasher.finish()
+        };
+        let hash_b = {
+            let mut hasher = DefaultHasher::new();
+            err.clone().hash(&mut hasher);
+            hasher.finish()
+        };
+        assert_eq!(hash_a, hash_b);
+    }
+
+    #[test]
+    fn fmt() {
+        assert_eq!(
+            "IPv6 Option Error: Not enough data left in the options to read option of type 5 (expected at least 4 bytes, only 2 bytes available).",
+            format!(
+                "{}",
+                UnexpectedEndOfSlice {
+                    option_type: 5,
+                    expected_len: 4,
+                    actual_len: 2
+                }
+            )
+        );
+        assert_eq!(
+            "IPv6 Option Error: Length value of the option of type 5 had unexpected value 3.",
+            format!(
+                "{}",
+                UnexpectedSize {
+                    option_type: 5,
+                    size: 3
+                }
+            )
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn source() {
+        assert!(UnexpectedSize {
+            option_type: 5,
+            size: 3
+        }
+        .source()
+        .is_none());
+    }
+}
@@ -0,0 +1,100 @@
+/// Errors that can occur when serializing [`crate::Ipv6OptionElement`]s
+/// into an [`crate::Ipv6RawExtHeader`].
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum OptionWriteError {
+    /// Error if the data of an option can not be represented (e.g. data
+    /// longer then 255 bytes or a CALIPSO compartment bitmap with a length
+    /// that is not a multiple of 4).
+    UnexpectedDataLen {
+        /// Type of the option with the non representable data.
+        option_type: u8,
+        /// Length of the data of the option.
+        data_len: usize,
+    },
+
+    /// Error if the serialized header (including padding) would exceed
+    /// the maximum IPv6 extension header length
+    /// ([`crate::Ipv6RawExtHeader::MAX_LEN`]).
+    NotEnoughSpace {
+        /// Length (including the next header, length & padding bytes)
+        /// the extension header would have required.
+        required_len: usize,
+    },
+}
+
+impl core::fmt::Display for OptionWriteError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        use OptionWriteError::*;
+        match self {
+            UnexpectedDataLen { option_type, data_len } => write!(f, "IPv6 Option Error: The data of the option of type {} has a length of {} bytes that can not be represented in the option.", option_type, data_len),
+            NotEnoughSpace { required_len } => write!(f, "IPv6 Option Error: Not enough space to store all options in an IPv6 extension header (maximum 2048 bytes can be stored, the header would have needed {} bytes).", required_len),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for OptionWriteError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::OptionWriteError::*;
+    use alloc::format;
+    use std::{
+        collections::hash_map::DefaultHasher,
+        error::Error,
+        hash::{Hash, Hasher},
+    };
+
+    #[test]
+    fn debug() {
+        assert_eq!(
+            "NotEnoughSpace { required_len: 2056 }",
+            format!("{:?}", NotEnoughSpace { required_len: 2056 })
+        );
+    }
+
+    #[test]
+    fn clone_eq_hash() {
+        let err = NotEnoughSpace { required_len: 2056 };
+        assert_eq!(err, err.clone());
+        let hash_a = {
+            let mut hasher = DefaultHasher::new();
+            err.hash(&mut hasher);
+            hasher.finish()
+        };
+        let hash_b = {
+            let mut hasher = DefaultHasher::new();
+            err.clone().hash(&mut hasher);
+            hasher.finish()
+        };
+        assert_eq!(hash_a, hash_b);
+    }
+
+    #[test]
+    fn fmt() {
+        assert_eq!(
+            "IPv6 Option Error: The data of the option of type 7 has a length of 3 bytes that can not be represented in the option.",
+            format!(
+                "{}",
+                UnexpectedDataLen {
+                    option_type: 7,
+                    data_len: 3
+                }
+            )
+        );
+        assert_eq!(
+            "IPv6 Option Error: Not enough space to store all options in an IPv6 extension header (maximum 2048 bytes can be stored, the header would have needed 2056 bytes).",
+            format!("{}", NotEnoughSpace { required_len: 2056 })
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn source() {
+        assert!(NotEnoughSpace { required_len: 2056 }.source().is_none());
+    }
+}
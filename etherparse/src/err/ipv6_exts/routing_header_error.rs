@@ -0,0 +1,191 @@
+use crate::Ipv6RoutingType;
+
+/// Error when decoding or processing a typed IPv6 routing header (e.g.
+/// [`crate::Ipv6SrhSlice`]).
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum RoutingHeaderError {
+    /// Error if the routing type of the header does not match the
+    /// routing type of the typed header.
+    UnexpectedRoutingType {
+        /// Routing type that was expected.
+        expected: Ipv6RoutingType,
+        /// Routing type present in the header.
+        actual: Ipv6RoutingType,
+    },
+
+    /// Error if an operation is not supported for the routing type
+    /// of the header.
+    UnsupportedRoutingType(Ipv6RoutingType),
+
+    /// Error if the "hdr ext len" field has a value not allowed
+    /// for the routing type.
+    UnexpectedHdrExtLen {
+        /// Routing type of the header.
+        routing_type: Ipv6RoutingType,
+        /// Value of the "hdr ext len" field.
+        hdr_ext_len: u8,
+    },
+
+    /// Error if the "last entry" field of a segment routing header
+    /// references more segments then fit into the header.
+    InvalidLastEntry {
+        /// Value of the "last entry" field.
+        last_entry: u8,
+        /// Value of the "hdr ext len" field.
+        hdr_ext_len: u8,
+    },
+
+    /// Error if the compression & pad fields of a RPL source route
+    /// header do not match the length of the header.
+    InvalidRplAddressLayout {
+        /// Number of prefix octets elided from all addresses except the last.
+        cmpr_i: u8,
+        /// Number of prefix octets elided from the last address.
+        cmpr_e: u8,
+        /// Number of padding octets at the end of the header.
+        pad: u8,
+        /// Value of the "hdr ext len" field.
+        hdr_ext_len: u8,
+    },
+
+    /// Error if the routing header has no segments left to be processed.
+    NoSegmentsLeft,
+
+    /// Error if the "segments left" field is bigger then the number of
+    /// addresses present in the header.
+    SegmentsLeftTooBig {
+        /// Value of the "segments left" field.
+        segments_left: u8,
+        /// Maximum allowed value (number of addresses in the header).
+        max: u8,
+    },
+}
+
+impl core::fmt::Display for RoutingHeaderError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        use RoutingHeaderError::*;
+        match self {
+            UnexpectedRoutingType { expected, actual } => write!(f, "IPv6 Routing Header Error: Expected routing type {:?} but the header has the routing type {:?}.", expected, actual),
+            UnsupportedRoutingType(routing_type) => write!(f, "IPv6 Routing Header Error: Operation is not supported for the routing type {:?}.", routing_type),
+            UnexpectedHdrExtLen { routing_type, hdr_ext_len } => write!(f, "IPv6 Routing Header Error: The 'hdr ext len' value {} is not allowed for the routing type {:?}.", hdr_ext_len, routing_type),
+            InvalidLastEntry { last_entry, hdr_ext_len } => write!(f, "IPv6 Routing Header Error: The 'last entry' value {} of the segment routing header references more segments then fit into the header ('hdr ext len' value {}).", last_entry, hdr_ext_len),
+            InvalidRplAddressLayout { cmpr_i, cmpr_e, pad, hdr_ext_len } => write!(f, "IPv6 Routing Header Error: The RPL source route header fields 'CmprI' ({}), 'CmprE' ({}) & 'Pad' ({}) do not match the header length ('hdr ext len' value {}).", cmpr_i, cmpr_e, pad, hdr_ext_len),
+            NoSegmentsLeft => write!(f, "IPv6 Routing Header Error: No segments left to be processed in the routing header."),
+            SegmentsLeftTooBig { segments_left, max } => write!(f, "IPv6 Routing Header Error: The 'segments left' value {} is bigger then the number of addresses in the routing header ({}).", segments_left, max),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for RoutingHeaderError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RoutingHeaderError::*;
+    use crate::Ipv6RoutingType;
+    use alloc::format;
+    use std::{
+        collections::hash_map::DefaultHasher,
+        error::Error,
+        hash::{Hash, Hasher},
+    };
+
+    #[test]
+    fn debug() {
+        assert_eq!("NoSegmentsLeft", format!("{:?}", NoSegmentsLeft));
+    }
+
+    #[test]
+    fn clone_eq_hash() {
+        let err = SegmentsLeftTooBig {
+            segments_left: 3,
+            max: 2,
+        };
+        assert_eq!(err, err.clone());
+        let hash_a = {
+            let mut hasher = DefaultHasher::new();
+            err.hash(&mut hasher);
+            hasher.finish()
+        };
+        let hash_b = {
+            let mut hasher = DefaultHasher::new();
+            err.clone().hash(&mut hasher);
+            hasher.finish()
+        };
+        assert_eq!(hash_a, hash_b);
+    }
+
+    #[test]
+    fn fmt() {
+        assert_eq!(
+            "IPv6 Routing Header Error: Expected routing type 4 (Segment Routing Header) but the header has the routing type 2 (Type 2 Routing Header).",
+            format!(
+                "{}",
+                UnexpectedRoutingType {
+                    expected: Ipv6RoutingType::SEGMENT_ROUTING,
+                    actual: Ipv6RoutingType::TYPE2
+                }
+            )
+        );
+        assert_eq!(
+            "IPv6 Routing Header Error: Operation is not supported for the routing type 0 (Source Route).",
+            format!("{}", UnsupportedRoutingType(Ipv6RoutingType::SOURCE_ROUTE))
+        );
+        assert_eq!(
+            "IPv6 Routing Header Error: The 'hdr ext len' value 3 is not allowed for the routing type 2 (Type 2 Routing Header).",
+            format!(
+                "{}",
+                UnexpectedHdrExtLen {
+                    routing_type: Ipv6RoutingType::TYPE2,
+                    hdr_ext_len: 3
+                }
+            )
+        );
+        assert_eq!(
+            "IPv6 Routing Header Error: The 'last entry' value 2 of the segment routing header references more segments then fit into the header ('hdr ext len' value 4).",
+            format!(
+                "{}",
+                InvalidLastEntry {
+                    last_entry: 2,
+                    hdr_ext_len: 4
+                }
+            )
+        );
+        assert_eq!(
+            "IPv6 Routing Header Error: The RPL source route header fields 'CmprI' (1), 'CmprE' (2) & 'Pad' (3) do not match the header length ('hdr ext len' value 4).",
+            format!(
+                "{}",
+                InvalidRplAddressLayout {
+                    cmpr_i: 1,
+                    cmpr_e: 2,
+                    pad: 3,
+                    hdr_ext_len: 4
+                }
+            )
+        );
+        assert_eq!(
+            "IPv6 Routing Header Error: No segments left to be processed in the routing header.",
+            format!("{}", NoSegmentsLeft)
+        );
+        assert_eq!(
+            "IPv6 Routing Header Error: The 'segments left' value 3 is bigger then the number of addresses in the routing header (2).",
+            format!(
+                "{}",
+                SegmentsLeftTooBig {
+                    segments_left: 3,
+                    max: 2
+                }
+            )
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn source() {
+        assert!(NoSegmentsLeft.source().is_none());
+    }
+}
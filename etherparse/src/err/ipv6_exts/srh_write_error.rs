@@ -0,0 +1,105 @@
+/// Errors that can occur when serializing a segment routing header
+/// into an [`crate::Ipv6RawExtHeader`].
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum SrhWriteError {
+    /// Error if no segments were given (a segment routing header has
+    /// to contain at least one segment).
+    EmptySegmentList,
+
+    /// Error if the data of a TLV is too long to be represented in the
+    /// TLV length field.
+    UnexpectedTlvDataLen {
+        /// Type of the TLV with the non representable data.
+        tlv_type: u8,
+        /// Length of the data of the TLV.
+        data_len: usize,
+    },
+
+    /// Error if the serialized header (including padding) would exceed
+    /// the maximum IPv6 extension header length
+    /// ([`crate::Ipv6RawExtHeader::MAX_LEN`]).
+    NotEnoughSpace {
+        /// Length (including the next header, length & padding bytes)
+        /// the header would have required.
+        required_len: usize,
+    },
+}
+
+impl core::fmt::Display for SrhWriteError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        use SrhWriteError::*;
+        match self {
+            EmptySegmentList => write!(f, "IPv6 Segment Routing Header Error: The segment list has to contain at least one segment."),
+            UnexpectedTlvDataLen { tlv_type, data_len } => write!(f, "IPv6 Segment Routing Header Error: The data of the TLV of type {} has a length of {} bytes that can not be represented in the TLV.", tlv_type, data_len),
+            NotEnoughSpace { required_len } => write!(f, "IPv6 Segment Routing Header Error: Not enough space to store all segments & TLVs in an IPv6 extension header (maximum 2048 bytes can be stored, the header would have needed {} bytes).", required_len),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SrhWriteError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SrhWriteError::*;
+    use alloc::format;
+    use std::{
+        collections::hash_map::DefaultHasher,
+        error::Error,
+        hash::{Hash, Hasher},
+    };
+
+    #[test]
+    fn debug() {
+        assert_eq!("EmptySegmentList", format!("{:?}", EmptySegmentList));
+    }
+
+    #[test]
+    fn clone_eq_hash() {
+        let err = NotEnoughSpace { required_len: 2056 };
+        assert_eq!(err, err.clone());
+        let hash_a = {
+            let mut hasher = DefaultHasher::new();
+            err.hash(&mut hasher);
+            hasher.finish()
+        };
+        let hash_b = {
+            let mut hasher = DefaultHasher::new();
+            err.clone().hash(&mut hasher);
+            hasher.finish()
+        };
+        assert_eq!(hash_a, hash_b);
+    }
+
+    #[test]
+    fn fmt() {
+        assert_eq!(
+            "IPv6 Segment Routing Header Error: The segment list has to contain at least one segment.",
+            format!("{}", EmptySegmentList)
+        );
+        assert_eq!(
+            "IPv6 Segment Routing Header Error: The data of the TLV of type 5 has a length of 300 bytes that can not be represented in the TLV.",
+            format!(
+                "{}",
+                UnexpectedTlvDataLen {
+                    tlv_type: 5,
+                    data_len: 300
+                }
+            )
+        );
+        assert_eq!(
+            "IPv6 Segment Routing Header Error: Not enough space to store all segments & TLVs in an IPv6 extension header (maximum 2048 bytes can be stored, the header would have needed 2056 bytes).",
+            format!("{}", NotEnoughSpace { required_len: 2056 })
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn source() {
+        assert!(EmptySegmentList.source().is_none());
+    }
+}
@@ -3,8 +3,28 @@
 pub enum Layer {
     /// Error occured in the ethernet 2 header.
     Ethernet2Header,
+    /// Error occured in the Linux cooked capture (SLL) header.
+    LinuxSllHeader,
+    /// Error occured in the Linux cooked capture v2 (SLL2) header.
+    LinuxSll2Header,
     /// Error occured in the vlan header.
     VlanHeader,
+    /// Error occured in the MACsec header (SecTAG).
+    MacsecHeader,
+    /// Error occured in the IEEE 802.2 LLC header.
+    LlcHeader,
+    /// Error occured in the SNAP header.
+    SnapHeader,
+    /// Error occured in the MPLS label stack.
+    MplsLabelStack,
+    /// Error occured in the PPPoE header.
+    PppoeHeader,
+    /// Error occured in a PPPoE discovery tag.
+    PppoeTag,
+    /// Error occured in the PPP header.
+    PppHeader,
+    /// Error occured when decoding an ARP packet.
+    ArpPacket,
     /// Error occured when decoding an IP header (v4 or v6).
     IpHeader,
     /// Error occured in the IPv4 layer.
@@ -13,6 +33,10 @@ pub enum Layer {
     Ipv4Packet,
     /// Error occured in the IP authentification header.
     IpAuthHeader,
+    /// Error occured in the IPsec encapsulating security payload (ESP) header.
+    EspHeader,
+    /// Error occured in the trailer of an IPsec encapsulating security payload (ESP).
+    EspTrailer,
     /// Error occured in the IPv6 layer.
     Ipv6Header,
     /// Error occured verifying the payload length of an IPv6 packet.
@@ -21,10 +45,20 @@ pub enum Layer {
     Ipv6FragHeader,
     /// Error occured while decoding a generic IPv6 extension header.
     Ipv6ExtHeader,
+    /// Error occured while decoding a GRE header.
+    GreHeader,
     /// Error occured while decoding an UDP header.
     UdpHeader,
     /// Error occured verifying the length of the UDP payload.
     UdpPayload,
+    /// Error occured while decoding a VXLAN header.
+    VxlanHeader,
+    /// Error occured while decoding a Geneve header.
+    GeneveHeader,
+    /// Error occured while decoding a Geneve option.
+    GeneveOption,
+    /// Error occured while decoding a GTP-U header.
+    GtpuHeader,
     /// Error occured while decoding a TCP header.
     TcpHeader,
     /// Error occured while parsing an ICMP packet.
@@ -43,17 +77,34 @@ impl Layer {
         use Layer::*;
         match self {
             Ethernet2Header => "Ethernet 2 Header Error",
+            LinuxSllHeader => "Linux Cooked Capture Header Error",
+            LinuxSll2Header => "Linux Cooked Capture v2 Header Error",
             VlanHeader => "VLAN Header Error",
+            MacsecHeader => "MACsec Header Error",
+            LlcHeader => "LLC Header Error",
+            SnapHeader => "SNAP Header Error",
+            MplsLabelStack => "MPLS Label Stack Error",
+            PppoeHeader => "PPPoE Header Error",
+            PppoeTag => "PPPoE Tag Error",
+            PppHeader => "PPP Header Error",
+            ArpPacket => "ARP Packet Error",
             IpHeader => "IP Header Error",
             Ipv4Header => "IPv4 Header Error",
             Ipv4Packet => "IPv4 Packet Error",
             IpAuthHeader => "IP Authentification Header Error",
+            EspHeader => "ESP Header Error",
+            EspTrailer => "ESP Trailer Error",
             Ipv6Header => "IPv6 Header Error",
             Ipv6Packet => "IPv6 Packet Error",
             Ipv6FragHeader => "IPv6 Fragment Header Error",
             Ipv6ExtHeader => "IPv6 Extension Header Error",
+            GreHeader => "GRE Header Error",
             UdpHeader => "UDP Header Error",
             UdpPayload => "UDP Payload Error",
+            VxlanHeader => "VXLAN Header Error",
+            GeneveHeader => "Geneve Header Error",
+            GeneveOption => "Geneve Option Error",
+            GtpuHeader => "GTP-U Header Error",
             TcpHeader => "TCP Header Error",
             Icmpv4 => "ICMP Packet Error",
             Icmpv4Timestamp => "ICMP Timestamp Error",
@@ -68,17 +119,34 @@ impl core::fmt::Display for Layer {
         use Layer::*;
         match self {
             Ethernet2Header => write!(f, "Ethernet 2 header"),
+            LinuxSllHeader => write!(f, "Linux cooked capture header"),
+            LinuxSll2Header => write!(f, "Linux cooked capture v2 header"),
             VlanHeader => write!(f, "VLAN header"),
+            MacsecHeader => write!(f, "MACsec header"),
+            LlcHeader => write!(f, "LLC header"),
+            SnapHeader => write!(f, "SNAP header"),
+            MplsLabelStack => write!(f, "MPLS label stack"),
+            PppoeHeader => write!(f, "PPPoE header"),
+            PppoeTag => write!(f, "PPPoE tag"),
+            PppHeader => write!(f, "PPP header"),
+            ArpPacket => write!(f, "ARP packet"),
             IpHeader => write!(f, "IP header"),
             Ipv4Header => write!(f, "IPv4 header"),
             Ipv4Packet => write!(f, "IPv4 packet"),
             IpAuthHeader => write!(f, "IP authentification header"),
+            EspHeader => write!(f, "ESP header"),
+            EspTrailer => write!(f, "ESP trailer"),
             Ipv6Header => write!(f, "IPv6 header"),
             Ipv6Packet => write!(f, "IPv6 packet"),
             Ipv6FragHeader => write!(f, "IPv6 fragment header"),
             Ipv6ExtHeader => write!(f, "IPv6 extension header"),
+            GreHeader => write!(f, "GRE header"),
             UdpHeader => write!(f, "UDP header"),
             UdpPayload => write!(f, "UDP payload"),
+            VxlanHeader => write!(f, "VXLAN header"),
+            GeneveHeader => write!(f, "Geneve header"),
+            GeneveOption => write!(f, "Geneve option"),
+            GtpuHeader => write!(f, "GTP-U header"),
             TcpHeader => write!(f, "TCP header"),
             Icmpv4 => write!(f, "ICMP packet"),
             Icmpv4Timestamp => write!(f, "ICMP timestamp message"),
@@ -126,17 +194,34 @@ mod test {
     fn error_title() {
         let tests = [
             (Ethernet2Header, "Ethernet 2 Header Error"),
+            (LinuxSllHeader, "Linux Cooked Capture Header Error"),
+            (LinuxSll2Header, "Linux Cooked Capture v2 Header Error"),
             (VlanHeader, "VLAN Header Error"),
+            (MacsecHeader, "MACsec Header Error"),
+            (LlcHeader, "LLC Header Error"),
+            (SnapHeader, "SNAP Header Error"),
+            (MplsLabelStack, "MPLS Label Stack Error"),
+            (PppoeHeader, "PPPoE Header Error"),
+            (PppoeTag, "PPPoE Tag Error"),
+            (PppHeader, "PPP Header Error"),
+            (ArpPacket, "ARP Packet Error"),
             (IpHeader, "IP Header Error"),
             (Ipv4Header, "IPv4 Header Error"),
             (Ipv4Packet, "IPv4 Packet Error"),
             (IpAuthHeader, "IP Authentification Header Error"),
+            (EspHeader, "ESP Header Error"),
+            (EspTrailer, "ESP Trailer Error"),
             (Ipv6Header, "IPv6 Header Error"),
             (Ipv6Packet, "IPv6 Packet Error"),
             (Ipv6FragHeader, "IPv6 Fragment Header Error"),
             (Ipv6ExtHeader, "IPv6 Extension Header Error"),
+            (GreHeader, "GRE Header Error"),
             (UdpHeader, "UDP Header Error"),
             (UdpPayload, "UDP Payload Error"),
+            (VxlanHeader, "VXLAN Header Error"),
+            (GeneveHeader, "Geneve Header Error"),
+            (GeneveOption, "Geneve Option Error"),
+            (GtpuHeader, "GTP-U Header Error"),
             (TcpHeader, "TCP Header Error"),
             (Icmpv4, "ICMP Packet Error"),
             (Icmpv4Timestamp, "ICMP Timestamp Error"),
@@ -152,17 +237,34 @@ mod test {
     fn fmt() {
         let tests = [
             (Ethernet2Header, "Ethernet 2 header"),
+            (LinuxSllHeader, "Linux cooked capture header"),
+            (LinuxSll2Header, "Linux cooked capture v2 header"),
             (VlanHeader, "VLAN header"),
+            (MacsecHeader, "MACsec header"),
+            (LlcHeader, "LLC header"),
+            (SnapHeader, "SNAP header"),
+            (MplsLabelStack, "MPLS label stack"),
+            (PppoeHeader, "PPPoE header"),
+            (PppoeTag, "PPPoE tag"),
+            (PppHeader, "PPP header"),
+            (ArpPacket, "ARP packet"),
             (IpHeader, "IP header"),
             (Ipv4Header, "IPv4 header"),
             (Ipv4Packet, "IPv4 packet"),
             (IpAuthHeader, "IP authentification header"),
+            (EspHeader, "ESP header"),
+            (EspTrailer, "ESP trailer"),
             (Ipv6Header, "IPv6 header"),
             (Ipv6Packet, "IPv6 packet"),
             (Ipv6FragHeader, "IPv6 fragment header"),
             (Ipv6ExtHeader, "IPv6 extension header"),
+            (GreHeader, "GRE header"),
             (UdpHeader, "UDP header"),
             (UdpPayload, "UDP payload"),
+            (VxlanHeader, "VXLAN header"),
+            (GeneveHeader, "Geneve header"),
+            (GeneveOption, "Geneve option"),
+            (GtpuHeader, "GTP-U header"),
             (TcpHeader, "TCP header"),
             (Icmpv4, "ICMP packet"),
             (Icmpv4Timestamp, "ICMP timestamp message"),
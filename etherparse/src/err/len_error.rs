@@ -78,10 +78,14 @@ impl core::fmt::Display for LenError {
             use LenSource::*;
             match self.len_source {
                 Slice => "slice length",
+                Ieee802_3Len => "IEEE 802.3 'length' field",
+                MacsecShortLen => "MACsec header 'short length' field",
+                PppoeHeaderLen => "length calculated from the PPPoE header 'length' field",
                 Ipv4HeaderTotalLen => "length calculated from the IPv4 header 'total length' field",
                 Ipv6HeaderPayloadLen => {
                     "length calculated from the IPv6 header 'payload length' field"
                 }
+                Ipv6JumboPayloadLen => "length calculated from the IPv6 'jumbo payload' option",
                 UdpHeaderLen => "length calculated from the UDP header 'length' field",
                 TcpHeaderLen => "length calculated from the TCP header 'length' field",
             }
@@ -226,8 +230,12 @@ mod test {
             use LenSource::*;
             let len_source_tests = [
                 (Slice, "IPv4 Header Error: Not enough data to decode 'IPv4 header'. 2 byte(s) would be required, but only 1 byte(s) are available based on the slice length."),
+                (Ieee802_3Len, "IPv4 Header Error: Not enough data to decode 'IPv4 header'. 2 byte(s) would be required, but only 1 byte(s) are available based on the IEEE 802.3 'length' field."),
+                (MacsecShortLen, "IPv4 Header Error: Not enough data to decode 'IPv4 header'. 2 byte(s) would be required, but only 1 byte(s) are available based on the MACsec header 'short length' field."),
+                (PppoeHeaderLen, "IPv4 Header Error: Not enough data to decode 'IPv4 header'. 2 byte(s) would be required, but only 1 byte(s) are available based on the length calculated from the PPPoE header 'length' field."),
                 (Ipv4HeaderTotalLen, "IPv4 Header Error: Not enough data to decode 'IPv4 header'. 2 byte(s) would be required, but only 1 byte(s) are available based on the length calculated from the IPv4 header 'total length' field."),
                 (Ipv6HeaderPayloadLen, "IPv4 Header Error: Not enough data to decode 'IPv4 header'. 2 byte(s) would be required, but only 1 byte(s) are available based on the length calculated from the IPv6 header 'payload length' field."),
+                (Ipv6JumboPayloadLen, "IPv4 Header Error: Not enough data to decode 'IPv4 header'. 2 byte(s) would be required, but only 1 byte(s) are available based on the length calculated from the IPv6 'jumbo payload' option."),
                 (UdpHeaderLen, "IPv4 Header Error: Not enough data to decode 'IPv4 header'. 2 byte(s) would be required, but only 1 byte(s) are available based on the length calculated from the UDP header 'length' field."),
                 (TcpHeaderLen, "IPv4 Header Error: Not enough data to decode 'IPv4 header'. 2 byte(s) would be required, but only 1 byte(s) are available based on the length calculated from the TCP header 'length' field."),
             ];
@@ -269,8 +277,12 @@ mod test {
             use LenSource::*;
             let len_source_tests = [
                 (Slice, "IPv4 Header Error: Length of 2 byte(s) is too big for an 'IPv4 header' (maximum is 1 bytes). The slice length was used to determine the length."),
+                (Ieee802_3Len, "IPv4 Header Error: Length of 2 byte(s) is too big for an 'IPv4 header' (maximum is 1 bytes). The IEEE 802.3 'length' field was used to determine the length."),
+                (MacsecShortLen, "IPv4 Header Error: Length of 2 byte(s) is too big for an 'IPv4 header' (maximum is 1 bytes). The MACsec header 'short length' field was used to determine the length."),
+                (PppoeHeaderLen, "IPv4 Header Error: Length of 2 byte(s) is too big for an 'IPv4 header' (maximum is 1 bytes). The length calculated from the PPPoE header 'length' field was used to determine the length."),
                 (Ipv4HeaderTotalLen, "IPv4 Header Error: Length of 2 byte(s) is too big for an 'IPv4 header' (maximum is 1 bytes). The length calculated from the IPv4 header 'total length' field was used to determine the length."),
                 (Ipv6HeaderPayloadLen, "IPv4 Header Error: Length of 2 byte(s) is too big for an 'IPv4 header' (maximum is 1 bytes). The length calculated from the IPv6 header 'payload length' field was used to determine the length."),
+                (Ipv6JumboPayloadLen, "IPv4 Header Error: Length of 2 byte(s) is too big for an 'IPv4 header' (maximum is 1 bytes). The length calculated from the IPv6 'jumbo payload' option was used to determine the length."),
                 (UdpHeaderLen, "IPv4 Header Error: Length of 2 byte(s) is too big for an 'IPv4 header' (maximum is 1 bytes). The length calculated from the UDP header 'length' field was used to determine the length."),
                 (TcpHeaderLen, "IPv4 Header Error: Length of 2 byte(s) is too big for an 'IPv4 header' (maximum is 1 bytes). The length calculated from the TCP header 'length' field was used to determine the length."),
             ];
@@ -4,10 +4,19 @@ pub enum LenSource {
     /// Limiting length was the slice length (we don't know what determined
     /// that one originally).
     Slice,
+    /// Length field of an IEEE 802.3 Ethernet frame (type/length
+    /// field with a value from 1 to 1500).
+    Ieee802_3Len,
+    /// Short length field of a MACsec header (SecTAG).
+    MacsecShortLen,
+    /// Length field of a PPPoE header.
+    PppoeHeaderLen,
     /// Length
     Ipv4HeaderTotalLen,
     /// Error occured in the IPv6 layer.
     Ipv6HeaderPayloadLen,
+    /// "Jumbo Payload" option in the IPv6 hop-by-hop header (RFC 2675).
+    Ipv6JumboPayloadLen,
     /// Error occured while decoding an UDP header.
     UdpHeaderLen,
     /// Error occured while decoding a TCP header.
@@ -0,0 +1,2 @@
+mod parse_error;
+pub use parse_error::*;
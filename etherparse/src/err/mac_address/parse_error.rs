@@ -0,0 +1,106 @@
+/// Errors encountered while parsing a [`crate::MacAddress`] from a string.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum ParseError {
+    /// Error if the length of the string does not match any of the
+    /// supported notations (17 characters for the colon & dash notation
+    /// and 14 characters for the dotted Cisco notation).
+    UnexpectedLen {
+        /// Length of the string in bytes.
+        len: usize,
+    },
+
+    /// Error if an unexpected character is encountered (e.g. a non
+    /// hexadecimal digit or a wrong or mixed separator).
+    InvalidCharacter {
+        /// Character that was encountered.
+        character: char,
+        /// Position (in bytes) of the character in the string.
+        position: usize,
+    },
+}
+
+impl core::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        use ParseError::*;
+        match self {
+            UnexpectedLen { len } => write!(f, "MAC Address Parse Error: Unexpected string length of {} (expected 17 characters for the colon or dash notation or 14 characters for the dotted notation).", len),
+            InvalidCharacter { character, position } => write!(f, "MAC Address Parse Error: Unexpected character '{}' at position {}.", character, position),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ParseError::*;
+    use alloc::format;
+    use std::{
+        collections::hash_map::DefaultHasher,
+        error::Error,
+        hash::{Hash, Hasher},
+    };
+
+    #[test]
+    fn debug() {
+        assert_eq!(
+            "UnexpectedLen { len: 2 }",
+            format!("{:?}", UnexpectedLen { len: 2 })
+        );
+    }
+
+    #[test]
+    fn clone_eq_hash() {
+        let err = InvalidCharacter {
+            character: 'x',
+            position: 3,
+        };
+        assert_eq!(err, err.clone());
+        let hash_a = {
+            let mut hasher = DefaultHasher::new();
+            err.hash(&mut hasher);
+            hasher.finish()
+        };
+        let hash_b = {
+            let mut hasher = DefaultHasher::new();
+            err.clone().hash(&mut hasher);
+            hasher.finish()
+        };
+        assert_eq!(hash_a, hash_b);
+    }
+
+    #[test]
+    fn fmt() {
+        assert_eq!(
+            "MAC Address Parse Error: Unexpected string length of 2 (expected 17 characters for the colon or dash notation or 14 characters for the dotted notation).",
+            format!("{}", UnexpectedLen { len: 2 })
+        );
+        assert_eq!(
+            "MAC Address Parse Error: Unexpected character 'x' at position 3.",
+            format!(
+                "{}",
+                InvalidCharacter {
+                    character: 'x',
+                    position: 3
+                }
+            )
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn source() {
+        assert!(UnexpectedLen { len: 2 }.source().is_none());
+        assert!(InvalidCharacter {
+            character: 'x',
+            position: 3
+        }
+        .source()
+        .is_none());
+    }
+}
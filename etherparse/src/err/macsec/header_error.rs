@@ -0,0 +1,93 @@
+/// Errors in a MACsec header (SecTAG) encountered while decoding it.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum HeaderError {
+    /// Error when the version bit in the TCI field is not equal to 0.
+    UnexpectedVersion {
+        /// The unexpected version number in the MACsec header.
+        version_number: u8,
+    },
+
+    /// Error when the short length field contains a value bigger or
+    /// equal to 48 (only values smaller then 48 are allowed).
+    InvalidShortLen {
+        /// The invalid short length value.
+        short_len: u8,
+    },
+}
+
+impl core::fmt::Display for HeaderError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        use HeaderError::*;
+        match self {
+            UnexpectedVersion { version_number } => write!(f, "MACsec Header Error: Encountered '{}' as version number in the MACsec header (must be '0').", version_number),
+            InvalidShortLen { short_len } => write!(f, "MACsec Header Error: Encountered '{}' as short length in the MACsec header (must be smaller then 48).", short_len),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for HeaderError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::HeaderError::*;
+    use alloc::format;
+    use std::{
+        collections::hash_map::DefaultHasher,
+        error::Error,
+        hash::{Hash, Hasher},
+    };
+
+    #[test]
+    fn debug() {
+        assert_eq!(
+            "UnexpectedVersion { version_number: 1 }",
+            format!("{:?}", UnexpectedVersion { version_number: 1 })
+        );
+    }
+
+    #[test]
+    fn clone_eq_hash() {
+        let err = UnexpectedVersion { version_number: 1 };
+        assert_eq!(err, err.clone());
+        let hash_a = {
+            let mut hasher = DefaultHasher::new();
+            err.hash(&mut hasher);
+            hasher.finish()
+        };
+        let hash_b = {
+            let mut hasher = DefaultHasher::new();
+            err.clone().hash(&mut hasher);
+            hasher.finish()
+        };
+        assert_eq!(hash_a, hash_b);
+    }
+
+    #[test]
+    fn fmt() {
+        assert_eq!(
+            "MACsec Header Error: Encountered '1' as version number in the MACsec header (must be '0').",
+            format!("{}", UnexpectedVersion { version_number: 1 })
+        );
+        assert_eq!(
+            "MACsec Header Error: Encountered '48' as short length in the MACsec header (must be smaller then 48).",
+            format!("{}", InvalidShortLen { short_len: 48 })
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn source() {
+        let values = [
+            UnexpectedVersion { version_number: 1 },
+            InvalidShortLen { short_len: 48 },
+        ];
+        for v in values {
+            assert!(v.source().is_none());
+        }
+    }
+}
@@ -1,15 +1,28 @@
+pub mod arp;
 pub mod double_vlan;
+pub mod geneve;
+pub mod gre;
+pub mod gtpu;
 #[cfg(feature = "std")]
 pub mod io;
 pub mod ip;
 pub mod ip_auth;
+#[cfg(feature = "std")]
+pub mod ip_defrag;
 pub mod ip_exts;
+#[cfg(feature = "std")]
+pub mod ip_frag;
 pub mod ipv4;
 pub mod ipv4_exts;
 pub mod ipv6;
 pub mod ipv6_exts;
+pub mod mac_address;
+pub mod macsec;
+pub mod mpls;
 pub mod packet;
+pub mod pppoe;
 pub mod tcp;
+pub mod vlan_stack;
 
 mod value_type;
 pub use value_type::*;
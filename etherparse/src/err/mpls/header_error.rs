@@ -0,0 +1,76 @@
+/// Errors in an MPLS label stack encountered while decoding it.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum HeaderError {
+    /// Error when the label stack contains more entries than can be
+    /// stored in an [`crate::MplsLabelStack`].
+    TooManyEntries {
+        /// Maximum number of entries that can be stored.
+        max_entries: usize,
+    },
+}
+
+impl core::fmt::Display for HeaderError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        use HeaderError::*;
+        match self {
+            TooManyEntries { max_entries } => write!(f, "MPLS Label Stack Error: The label stack contains more then the maximum supported number of {} entries.", max_entries),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for HeaderError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::HeaderError::*;
+    use alloc::format;
+    use std::{
+        collections::hash_map::DefaultHasher,
+        error::Error,
+        hash::{Hash, Hasher},
+    };
+
+    #[test]
+    fn debug() {
+        assert_eq!(
+            "TooManyEntries { max_entries: 16 }",
+            format!("{:?}", TooManyEntries { max_entries: 16 })
+        );
+    }
+
+    #[test]
+    fn clone_eq_hash() {
+        let err = TooManyEntries { max_entries: 16 };
+        assert_eq!(err, err.clone());
+        let hash_a = {
+            let mut hasher = DefaultHasher::new();
+            err.hash(&mut hasher);
+            hasher.finish()
+        };
+        let hash_b = {
+            let mut hasher = DefaultHasher::new();
+            err.clone().hash(&mut hasher);
+            hasher.finish()
+        };
+        assert_eq!(hash_a, hash_b);
+    }
+
+    #[test]
+    fn fmt() {
+        assert_eq!(
+            "MPLS Label Stack Error: The label stack contains more then the maximum supported number of 16 entries.",
+            format!("{}", TooManyEntries { max_entries: 16 })
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn source() {
+        assert!(TooManyEntries { max_entries: 16 }.source().is_none());
+    }
+}
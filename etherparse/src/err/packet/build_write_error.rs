@@ -1,3 +1,5 @@
+#[cfg(feature = "std")]
+use crate::err::ip_frag::IpFragError;
 use crate::err::{ipv4_exts, ipv6_exts, ValueTooBigError};
 
 /// Error while writing packet
@@ -23,6 +25,9 @@ pub enum BuildWriteError {
     /// Error if ICMPv6 is packaged in an IPv4 packet (it is undefined
     /// how to calculate the checksum).
     Icmpv6InIpv4,
+
+    /// Error if the packet could not be split into fragments.
+    IpFrag(IpFragError),
 }
 
 #[cfg(feature = "std")]
@@ -67,6 +72,15 @@ impl BuildWriteError {
     pub fn is_icmpv6_in_ipv4(&self) -> bool {
         matches!(self, BuildWriteError::Icmpv6InIpv4)
     }
+
+    /// Returns the [`crate::err::ip_frag::IpFragError`] value if the
+    /// `BuildWriteError` is a `IpFrag`. Otherwise `None` is returned.
+    pub fn ip_frag(&self) -> Option<&IpFragError> {
+        match self {
+            BuildWriteError::IpFrag(err) => Some(err),
+            _ => None,
+        }
+    }
 }
 
 #[cfg(feature = "std")]
@@ -79,6 +93,7 @@ impl core::fmt::Display for BuildWriteError {
             Ipv4Exts(err) => err.fmt(f),
             Ipv6Exts(err) => err.fmt(f),
             Icmpv6InIpv4 => write!(f, "Error: ICMPv6 can not be combined with an IPv4 headers (checksum can not be calculated)."),
+            IpFrag(err) => err.fmt(f),
         }
     }
 }
@@ -93,6 +108,7 @@ impl std::error::Error for BuildWriteError {
             Ipv4Exts(err) => Some(err),
             Ipv6Exts(err) => Some(err),
             Icmpv6InIpv4 => None,
+            IpFrag(err) => Some(err),
         }
     }
 }
@@ -182,6 +198,15 @@ mod tests {
         assert!(Icmpv6InIpv4.is_icmpv6_in_ipv4());
     }
 
+    #[test]
+    fn ip_frag() {
+        assert!(Icmpv6InIpv4.ip_frag().is_none());
+        assert_eq!(
+            Some(&IpFragError::AlreadyFragmented),
+            IpFrag(IpFragError::AlreadyFragmented).ip_frag()
+        );
+    }
+
     #[test]
     fn debug() {
         let err = ipv4_exts::ExtsWalkError::ExtNotReferenced {
@@ -226,6 +251,10 @@ mod tests {
             "Error: ICMPv6 can not be combined with an IPv4 headers (checksum can not be calculated).",
             format!("{}", Icmpv6InIpv4)
         );
+        assert_eq!(
+            format!("{}", IpFragError::AlreadyFragmented),
+            format!("{}", IpFrag(IpFragError::AlreadyFragmented))
+        );
     }
 
     #[cfg(feature = "std")]
@@ -255,5 +284,6 @@ mod tests {
         .source()
         .is_some());
         assert!(Icmpv6InIpv4.source().is_none());
+        assert!(IpFrag(IpFragError::AlreadyFragmented).source().is_some());
     }
 }
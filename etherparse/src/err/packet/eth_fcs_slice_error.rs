@@ -0,0 +1,150 @@
+use super::EthSliceError;
+use crate::err::LenError;
+
+/// Error when slicing an ethernet frame with a trailing frame check
+/// sequence (FCS).
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum EthFcsSliceError {
+    /// Error if the slice is too small to contain an ethernet 2 header
+    /// and a frame check sequence.
+    Len(LenError),
+
+    /// Error if the frame check sequence at the end of the frame does
+    /// not match the CRC-32 calculated over the rest of the frame.
+    FcsMismatch {
+        /// Frame check sequence present at the end of the frame.
+        received: u32,
+        /// CRC-32 calculated over the frame (without the FCS).
+        calculated: u32,
+    },
+
+    /// Error when slicing the frame (after the FCS was removed).
+    Slice(EthSliceError),
+}
+
+impl core::fmt::Display for EthFcsSliceError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        use EthFcsSliceError::*;
+        match self {
+            Len(err) => err.fmt(f),
+            FcsMismatch { received, calculated } => write!(f, "Ethernet FCS Error: The frame check sequence 0x{:08x} does not match the calculated CRC-32 0x{:08x} of the frame.", received, calculated),
+            Slice(err) => err.fmt(f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for EthFcsSliceError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use EthFcsSliceError::*;
+        match self {
+            Len(err) => Some(err),
+            FcsMismatch { .. } => None,
+            Slice(err) => Some(err),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{EthFcsSliceError::*, *};
+    use crate::err::{Layer, LenSource};
+    use alloc::format;
+    use std::{
+        collections::hash_map::DefaultHasher,
+        error::Error,
+        hash::{Hash, Hasher},
+    };
+
+    #[test]
+    fn debug() {
+        let err = FcsMismatch {
+            received: 1,
+            calculated: 2,
+        };
+        assert_eq!(
+            "FcsMismatch { received: 1, calculated: 2 }",
+            format!("{:?}", err)
+        );
+    }
+
+    #[test]
+    fn clone_eq_hash() {
+        let err = FcsMismatch {
+            received: 1,
+            calculated: 2,
+        };
+        assert_eq!(err, err.clone());
+        let hash_a = {
+            let mut hasher = DefaultHasher::new();
+            err.hash(&mut hasher);
+            hasher.finish()
+        };
+        let hash_b = {
+            let mut hasher = DefaultHasher::new();
+            err.clone().hash(&mut hasher);
+            hasher.finish()
+        };
+        assert_eq!(hash_a, hash_b);
+    }
+
+    #[test]
+    fn fmt() {
+        // Len
+        {
+            let err = LenError {
+                required_len: 18,
+                len: 1,
+                len_source: LenSource::Slice,
+                layer: Layer::Ethernet2Header,
+                layer_start_offset: 0,
+            };
+            assert_eq!(format!("{}", err), format!("{}", Len(err)));
+        }
+
+        // FcsMismatch
+        assert_eq!(
+            "Ethernet FCS Error: The frame check sequence 0x12345678 does not match the calculated CRC-32 0x9abcdef0 of the frame.",
+            format!(
+                "{}",
+                FcsMismatch {
+                    received: 0x1234_5678,
+                    calculated: 0x9abc_def0
+                }
+            )
+        );
+
+        // Slice
+        {
+            let err = EthSliceError::Ipv4(crate::err::ipv4::HeaderError::UnexpectedVersion {
+                version_number: 1,
+            });
+            assert_eq!(format!("{}", err), format!("{}", Slice(err)));
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn source() {
+        assert!(Len(LenError {
+            required_len: 18,
+            len: 1,
+            len_source: LenSource::Slice,
+            layer: Layer::Ethernet2Header,
+            layer_start_offset: 0,
+        })
+        .source()
+        .is_some());
+        assert!(FcsMismatch {
+            received: 1,
+            calculated: 2
+        }
+        .source()
+        .is_none());
+        assert!(Slice(EthSliceError::Ipv4(
+            crate::err::ipv4::HeaderError::UnexpectedVersion { version_number: 1 }
+        ))
+        .source()
+        .is_some());
+    }
+}
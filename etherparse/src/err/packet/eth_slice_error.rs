@@ -5,6 +5,14 @@ use crate::*;
 pub enum EthSliceError {
     /// Length related errors (e.g. not enough data in slice).
     Len(err::LenError),
+    /// Error when decoding a VLAN stack.
+    VlanStack(err::vlan_stack::HeaderError),
+    /// Error when decoding a MACsec header (SecTAG).
+    Macsec(err::macsec::HeaderError),
+    /// Error when decoding an MPLS label stack.
+    Mpls(err::mpls::HeaderError),
+    /// Error when decoding a PPPoE header.
+    Pppoe(err::pppoe::HeaderError),
     /// Error when decoding an IPv4 header.
     Ipv4(err::ipv4::HeaderError),
     /// Error when decoding an IPv6 header.
@@ -13,6 +21,8 @@ pub enum EthSliceError {
     Ipv4Exts(err::ip_auth::HeaderError),
     /// Error when decoding an IPv6 extension header.
     Ipv6Exts(err::ipv6_exts::HeaderError),
+    /// Error when decoding a GRE header.
+    Gre(err::gre::HeaderError),
     /// Error when decoding a TCP header.
     Tcp(err::tcp::HeaderError),
 }
@@ -23,10 +33,15 @@ impl core::fmt::Display for EthSliceError {
 
         match self {
             Len(err) => err.fmt(f),
+            VlanStack(err) => err.fmt(f),
+            Macsec(err) => err.fmt(f),
+            Mpls(err) => err.fmt(f),
+            Pppoe(err) => err.fmt(f),
             Ipv4(err) => err.fmt(f),
             Ipv6(err) => err.fmt(f),
             Ipv4Exts(err) => err.fmt(f),
             Ipv6Exts(err) => err.fmt(f),
+            Gre(err) => err.fmt(f),
             Tcp(err) => err.fmt(f),
         }
     }
@@ -38,10 +53,15 @@ impl std::error::Error for EthSliceError {
         use EthSliceError::*;
         match self {
             Len(err) => Some(err),
+            VlanStack(err) => Some(err),
+            Macsec(err) => Some(err),
+            Mpls(err) => Some(err),
+            Pppoe(err) => Some(err),
             Ipv4(err) => Some(err),
             Ipv6(err) => Some(err),
             Ipv4Exts(err) => Some(err),
             Ipv6Exts(err) => Some(err),
+            Gre(err) => Some(err),
             Tcp(err) => Some(err),
         }
     }
@@ -98,6 +118,30 @@ mod tests {
             assert_eq!(format!("{}", err), format!("{}", Len(err)));
         }
 
+        // VlanStack
+        {
+            let err = err::vlan_stack::HeaderError::TooManyTags { max_tags: 8 };
+            assert_eq!(format!("{}", err), format!("{}", VlanStack(err)));
+        }
+
+        // Macsec
+        {
+            let err = err::macsec::HeaderError::UnexpectedVersion { version_number: 1 };
+            assert_eq!(format!("{}", err), format!("{}", Macsec(err)));
+        }
+
+        // Mpls
+        {
+            let err = err::mpls::HeaderError::TooManyEntries { max_entries: 16 };
+            assert_eq!(format!("{}", err), format!("{}", Mpls(err)));
+        }
+
+        // Pppoe
+        {
+            let err = err::pppoe::HeaderError::UnexpectedVersion { version_number: 2 };
+            assert_eq!(format!("{}", err), format!("{}", Pppoe(err)));
+        }
+
         // Ipv4Header
         {
             let err = err::ipv4::HeaderError::UnexpectedVersion { version_number: 1 };
@@ -122,6 +166,12 @@ mod tests {
             assert_eq!(format!("{}", err), format!("{}", Ipv6Exts(err)));
         };
 
+        // GreHeader
+        {
+            let err = err::gre::HeaderError::UnsupportedVersion { version_number: 2 };
+            assert_eq!(format!("{}", err), format!("{}", Gre(err)));
+        }
+
         // TcpHeader
         {
             let err = err::tcp::HeaderError::DataOffsetTooSmall { data_offset: 1 };
@@ -144,6 +194,30 @@ mod tests {
             assert!(Len(err).source().is_some());
         }
 
+        // VlanStack
+        {
+            let err = err::vlan_stack::HeaderError::TooManyTags { max_tags: 8 };
+            assert!(VlanStack(err).source().is_some());
+        }
+
+        // Macsec
+        {
+            let err = err::macsec::HeaderError::UnexpectedVersion { version_number: 1 };
+            assert!(Macsec(err).source().is_some());
+        }
+
+        // Mpls
+        {
+            let err = err::mpls::HeaderError::TooManyEntries { max_entries: 16 };
+            assert!(Mpls(err).source().is_some());
+        }
+
+        // Pppoe
+        {
+            let err = err::pppoe::HeaderError::UnexpectedVersion { version_number: 2 };
+            assert!(Pppoe(err).source().is_some());
+        }
+
         // Ipv4Header
         {
             let err = err::ipv4::HeaderError::UnexpectedVersion { version_number: 1 };
@@ -168,6 +242,12 @@ mod tests {
             assert!(Ipv6Exts(err).source().is_some());
         };
 
+        // GreHeader
+        {
+            let err = err::gre::HeaderError::UnsupportedVersion { version_number: 2 };
+            assert!(Gre(err).source().is_some());
+        }
+
         // TcpHeader
         {
             let err = err::tcp::HeaderError::DataOffsetTooSmall { data_offset: 1 };
@@ -7,8 +7,14 @@ pub enum IpSliceError {
     Len(err::LenError),
     /// Error when decoding an IP header (v4 or v6).
     Ip(err::ip::HeaderError),
+    /// Error when decoding a GRE header.
+    Gre(err::gre::HeaderError),
     /// Error when decoding a TCP header.
     Tcp(err::tcp::HeaderError),
+    /// Error when decoding a Geneve header.
+    Geneve(err::geneve::HeaderError),
+    /// Error when decoding a GTP-U header.
+    Gtpu(err::gtpu::HeaderError),
 }
 
 impl core::fmt::Display for IpSliceError {
@@ -18,7 +24,10 @@ impl core::fmt::Display for IpSliceError {
         match self {
             Len(err) => err.fmt(f),
             Ip(err) => err.fmt(f),
+            Gre(err) => err.fmt(f),
             Tcp(err) => err.fmt(f),
+            Geneve(err) => err.fmt(f),
+            Gtpu(err) => err.fmt(f),
         }
     }
 }
@@ -30,7 +39,10 @@ impl std::error::Error for IpSliceError {
         match self {
             Len(err) => Some(err),
             Ip(err) => Some(err),
+            Gre(err) => Some(err),
             Tcp(err) => Some(err),
+            Geneve(err) => Some(err),
+            Gtpu(err) => Some(err),
         }
     }
 }
@@ -89,11 +101,29 @@ mod tests {
             assert_eq!(format!("{}", err), format!("{}", Ip(err)));
         }
 
+        // GreHeader
+        {
+            let err = err::gre::HeaderError::UnsupportedVersion { version_number: 2 };
+            assert_eq!(format!("{}", err), format!("{}", Gre(err)));
+        }
+
         // TcpHeader
         {
             let err = err::tcp::HeaderError::DataOffsetTooSmall { data_offset: 1 };
             assert_eq!(format!("{}", err), format!("{}", Tcp(err)));
         }
+
+        // GeneveHeader
+        {
+            let err = err::geneve::HeaderError::UnsupportedVersion { version_number: 1 };
+            assert_eq!(format!("{}", err), format!("{}", Geneve(err)));
+        }
+
+        // GtpuHeader
+        {
+            let err = err::gtpu::HeaderError::UnsupportedVersion { version_number: 2 };
+            assert_eq!(format!("{}", err), format!("{}", Gtpu(err)));
+        }
     }
 
     #[cfg(feature = "std")]
@@ -117,10 +147,28 @@ mod tests {
             assert!(Ip(err).source().is_some());
         }
 
+        // GreHeader
+        {
+            let err = err::gre::HeaderError::UnsupportedVersion { version_number: 2 };
+            assert!(Gre(err).source().is_some());
+        }
+
         // TcpHeader
         {
             let err = err::tcp::HeaderError::DataOffsetTooSmall { data_offset: 1 };
             assert!(Tcp(err).source().is_some());
         }
+
+        // GeneveHeader
+        {
+            let err = err::geneve::HeaderError::UnsupportedVersion { version_number: 1 };
+            assert!(Geneve(err).source().is_some());
+        }
+
+        // GtpuHeader
+        {
+            let err = err::gtpu::HeaderError::UnsupportedVersion { version_number: 2 };
+            assert!(Gtpu(err).source().is_some());
+        }
     }
 }
@@ -1,6 +1,9 @@
 mod build_write_error;
 pub use build_write_error::*;
 
+mod eth_fcs_slice_error;
+pub use eth_fcs_slice_error::*;
+
 mod eth_slice_error;
 pub use eth_slice_error::*;
 
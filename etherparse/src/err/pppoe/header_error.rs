@@ -0,0 +1,92 @@
+/// Errors in a PPPoE header encountered while decoding it.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum HeaderError {
+    /// Error when the PPPoE version field is not equal to 1.
+    UnexpectedVersion {
+        /// The unexpected version number in the PPPoE header.
+        version_number: u8,
+    },
+
+    /// Error when the PPPoE type field is not equal to 1.
+    UnexpectedType {
+        /// The unexpected type number in the PPPoE header.
+        type_number: u8,
+    },
+}
+
+impl core::fmt::Display for HeaderError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        use HeaderError::*;
+        match self {
+            UnexpectedVersion { version_number } => write!(f, "PPPoE Header Error: Encountered '{}' as version number in the PPPoE header (must be '1').", version_number),
+            UnexpectedType { type_number } => write!(f, "PPPoE Header Error: Encountered '{}' as type number in the PPPoE header (must be '1').", type_number),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for HeaderError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::HeaderError::*;
+    use alloc::format;
+    use std::{
+        collections::hash_map::DefaultHasher,
+        error::Error,
+        hash::{Hash, Hasher},
+    };
+
+    #[test]
+    fn debug() {
+        assert_eq!(
+            "UnexpectedVersion { version_number: 2 }",
+            format!("{:?}", UnexpectedVersion { version_number: 2 })
+        );
+    }
+
+    #[test]
+    fn clone_eq_hash() {
+        let err = UnexpectedVersion { version_number: 2 };
+        assert_eq!(err, err.clone());
+        let hash_a = {
+            let mut hasher = DefaultHasher::new();
+            err.hash(&mut hasher);
+            hasher.finish()
+        };
+        let hash_b = {
+            let mut hasher = DefaultHasher::new();
+            err.clone().hash(&mut hasher);
+            hasher.finish()
+        };
+        assert_eq!(hash_a, hash_b);
+    }
+
+    #[test]
+    fn fmt() {
+        assert_eq!(
+            "PPPoE Header Error: Encountered '2' as version number in the PPPoE header (must be '1').",
+            format!("{}", UnexpectedVersion { version_number: 2 })
+        );
+        assert_eq!(
+            "PPPoE Header Error: Encountered '3' as type number in the PPPoE header (must be '1').",
+            format!("{}", UnexpectedType { type_number: 3 })
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn source() {
+        let values = [
+            UnexpectedVersion { version_number: 2 },
+            UnexpectedType { type_number: 3 },
+        ];
+        for v in values {
+            assert!(v.source().is_none());
+        }
+    }
+}
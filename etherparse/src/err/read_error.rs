@@ -30,8 +30,29 @@ pub enum ReadError {
     /// Error while parsing a IPv6 extension header.
     Ipv6Exts(ipv6_exts::HeaderError),
 
+    /// Error while parsing a MPLS label stack.
+    Mpls(mpls::HeaderError),
+
+    /// Error while parsing a PPPoE header.
+    Pppoe(pppoe::HeaderError),
+
     /// Error while parsing a TCP extension header.
     Tcp(tcp::HeaderError),
+
+    /// Error while parsing a VLAN stack.
+    VlanStack(vlan_stack::HeaderError),
+
+    /// Error while parsing a MACsec header.
+    Macsec(macsec::HeaderError),
+
+    /// Error while parsing a GRE header.
+    Gre(gre::HeaderError),
+
+    /// Error while parsing a Geneve header.
+    Geneve(geneve::HeaderError),
+
+    /// Error while parsing a GTP-U header.
+    Gtpu(gtpu::HeaderError),
 }
 
 impl ReadError {
@@ -83,12 +104,54 @@ impl ReadError {
             _ => None,
         }
     }
+    pub fn mpls(&self) -> Option<&mpls::HeaderError> {
+        match self {
+            ReadError::Mpls(err) => Some(err),
+            _ => None,
+        }
+    }
+    pub fn pppoe(&self) -> Option<&pppoe::HeaderError> {
+        match self {
+            ReadError::Pppoe(err) => Some(err),
+            _ => None,
+        }
+    }
     pub fn tcp(&self) -> Option<&tcp::HeaderError> {
         match self {
             ReadError::Tcp(err) => Some(err),
             _ => None,
         }
     }
+    pub fn vlan_stack(&self) -> Option<&vlan_stack::HeaderError> {
+        match self {
+            ReadError::VlanStack(err) => Some(err),
+            _ => None,
+        }
+    }
+    pub fn macsec(&self) -> Option<&macsec::HeaderError> {
+        match self {
+            ReadError::Macsec(err) => Some(err),
+            _ => None,
+        }
+    }
+    pub fn gre(&self) -> Option<&gre::HeaderError> {
+        match self {
+            ReadError::Gre(err) => Some(err),
+            _ => None,
+        }
+    }
+    pub fn geneve(&self) -> Option<&geneve::HeaderError> {
+        match self {
+            ReadError::Geneve(err) => Some(err),
+            _ => None,
+        }
+    }
+    pub fn gtpu(&self) -> Option<&gtpu::HeaderError> {
+        match self {
+            ReadError::Gtpu(err) => Some(err),
+            _ => None,
+        }
+    }
 }
 
 impl core::fmt::Display for ReadError {
@@ -103,7 +166,14 @@ impl core::fmt::Display for ReadError {
             Ipv4(err) => err.fmt(f),
             Ipv6(err) => err.fmt(f),
             Ipv6Exts(err) => err.fmt(f),
+            Mpls(err) => err.fmt(f),
+            Pppoe(err) => err.fmt(f),
             Tcp(err) => err.fmt(f),
+            VlanStack(err) => err.fmt(f),
+            Macsec(err) => err.fmt(f),
+            Gre(err) => err.fmt(f),
+            Geneve(err) => err.fmt(f),
+            Gtpu(err) => err.fmt(f),
         }
     }
 }
@@ -120,7 +190,14 @@ impl std::error::Error for ReadError {
             ReadError::Ipv4(err) => Some(err),
             ReadError::Ipv6(err) => Some(err),
             ReadError::Ipv6Exts(err) => Some(err),
+            ReadError::Mpls(err) => Some(err),
+            ReadError::Pppoe(err) => Some(err),
             ReadError::Tcp(err) => Some(err),
+            ReadError::VlanStack(err) => Some(err),
+            ReadError::Macsec(err) => Some(err),
+            ReadError::Gre(err) => Some(err),
+            ReadError::Geneve(err) => Some(err),
+            ReadError::Gtpu(err) => Some(err),
         }
     }
 }
@@ -340,6 +417,142 @@ impl From<ipv6_exts::HeaderSliceError> for ReadError {
     }
 }
 
+// mpls error conversions
+
+impl From<mpls::HeaderError> for ReadError {
+    fn from(value: mpls::HeaderError) -> Self {
+        ReadError::Mpls(value)
+    }
+}
+
+impl From<mpls::HeaderSliceError> for ReadError {
+    fn from(value: mpls::HeaderSliceError) -> Self {
+        use mpls::HeaderSliceError::*;
+        match value {
+            Len(err) => ReadError::Len(err),
+            Content(err) => ReadError::Mpls(err),
+        }
+    }
+}
+
+// pppoe error conversions
+
+impl From<pppoe::HeaderError> for ReadError {
+    fn from(value: pppoe::HeaderError) -> Self {
+        ReadError::Pppoe(value)
+    }
+}
+
+impl From<pppoe::HeaderReadError> for ReadError {
+    fn from(value: pppoe::HeaderReadError) -> Self {
+        use pppoe::HeaderReadError::*;
+        match value {
+            Io(err) => ReadError::Io(err),
+            Content(err) => ReadError::Pppoe(err),
+        }
+    }
+}
+
+impl From<pppoe::HeaderSliceError> for ReadError {
+    fn from(value: pppoe::HeaderSliceError) -> Self {
+        use pppoe::HeaderSliceError::*;
+        match value {
+            Len(err) => ReadError::Len(err),
+            Content(err) => ReadError::Pppoe(err),
+        }
+    }
+}
+
+// vlan stack error conversions
+
+impl From<vlan_stack::HeaderError> for ReadError {
+    fn from(value: vlan_stack::HeaderError) -> Self {
+        ReadError::VlanStack(value)
+    }
+}
+
+impl From<vlan_stack::HeaderSliceError> for ReadError {
+    fn from(value: vlan_stack::HeaderSliceError) -> Self {
+        use vlan_stack::HeaderSliceError::*;
+        match value {
+            Len(err) => ReadError::Len(err),
+            Content(err) => ReadError::VlanStack(err),
+        }
+    }
+}
+
+// macsec error conversions
+
+impl From<macsec::HeaderError> for ReadError {
+    fn from(value: macsec::HeaderError) -> Self {
+        ReadError::Macsec(value)
+    }
+}
+
+impl From<macsec::HeaderSliceError> for ReadError {
+    fn from(value: macsec::HeaderSliceError) -> Self {
+        use macsec::HeaderSliceError::*;
+        match value {
+            Len(err) => ReadError::Len(err),
+            Content(err) => ReadError::Macsec(err),
+        }
+    }
+}
+
+// gre error conversions
+
+impl From<gre::HeaderError> for ReadError {
+    fn from(value: gre::HeaderError) -> Self {
+        ReadError::Gre(value)
+    }
+}
+
+impl From<gre::HeaderSliceError> for ReadError {
+    fn from(value: gre::HeaderSliceError) -> Self {
+        use gre::HeaderSliceError::*;
+        match value {
+            Len(err) => ReadError::Len(err),
+            Content(err) => ReadError::Gre(err),
+        }
+    }
+}
+
+// geneve error conversions
+
+impl From<geneve::HeaderError> for ReadError {
+    fn from(value: geneve::HeaderError) -> Self {
+        ReadError::Geneve(value)
+    }
+}
+
+impl From<geneve::HeaderSliceError> for ReadError {
+    fn from(value: geneve::HeaderSliceError) -> Self {
+        use geneve::HeaderSliceError::*;
+        match value {
+            Len(err) => ReadError::Len(err),
+            Content(err) => ReadError::Geneve(err),
+        }
+    }
+}
+
+// gtpu error conversions
+
+impl From<gtpu::HeaderError> for ReadError {
+    fn from(value: gtpu::HeaderError) -> Self {
+        ReadError::Gtpu(value)
+    }
+}
+
+impl From<gtpu::HeaderSliceError> for ReadError {
+    fn from(value: gtpu::HeaderSliceError) -> Self {
+        use gtpu::HeaderSliceError::*;
+        match value {
+            Len(err) => ReadError::Len(err),
+            Content(err) => ReadError::Gtpu(err),
+        }
+    }
+}
+
 // packet error conversions
 
 impl From<packet::EthSliceError> for ReadError {
@@ -347,10 +560,15 @@ impl From<packet::EthSliceError> for ReadError {
         use packet::EthSliceError::*;
         match value {
             Len(err) => ReadError::Len(err),
+            VlanStack(err) => ReadError::VlanStack(err),
+            Macsec(err) => ReadError::Macsec(err),
+            Mpls(err) => ReadError::Mpls(err),
+            Pppoe(err) => ReadError::Pppoe(err),
             Ipv4(err) => ReadError::Ipv4(err),
             Ipv6(err) => ReadError::Ipv6(err),
             Ipv4Exts(err) => ReadError::IpAuth(err),
             Ipv6Exts(err) => ReadError::Ipv6Exts(err),
+            Gre(err) => ReadError::Gre(err),
             Tcp(err) => ReadError::Tcp(err),
         }
     }
@@ -362,7 +580,10 @@ impl From<packet::IpSliceError> for ReadError {
         match value {
             Len(err) => ReadError::Len(err),
             Ip(err) => ReadError::Ip(err),
+            Gre(err) => ReadError::Gre(err),
             Tcp(err) => ReadError::Tcp(err),
+            Geneve(err) => ReadError::Geneve(err),
+            Gtpu(err) => ReadError::Gtpu(err),
         }
     }
 }
@@ -405,7 +626,7 @@ mod tests {
 
     #[test]
     fn debug_source() {
-        let test_values: [(&str, ReadError); 8] = [
+        let test_values: [(&str, ReadError); 15] = [
             (
                 "Len",
                 Len(LenError {
@@ -441,10 +662,38 @@ mod tests {
                 "Ipv6Exts",
                 Ipv6Exts(ipv6_exts::HeaderError::HopByHopNotAtStart),
             ),
+            (
+                "Mpls",
+                Mpls(mpls::HeaderError::TooManyEntries { max_entries: 16 }),
+            ),
+            (
+                "Pppoe",
+                Pppoe(pppoe::HeaderError::UnexpectedVersion { version_number: 2 }),
+            ),
             (
                 "Tcp",
                 Tcp(tcp::HeaderError::DataOffsetTooSmall { data_offset: 1 }),
             ),
+            (
+                "VlanStack",
+                VlanStack(vlan_stack::HeaderError::TooManyTags { max_tags: 8 }),
+            ),
+            (
+                "Macsec",
+                Macsec(macsec::HeaderError::UnexpectedVersion { version_number: 1 }),
+            ),
+            (
+                "Gre",
+                Gre(gre::HeaderError::UnsupportedVersion { version_number: 2 }),
+            ),
+            (
+                "Geneve",
+                Geneve(geneve::HeaderError::UnsupportedVersion { version_number: 1 }),
+            ),
+            (
+                "Gtpu",
+                Gtpu(gtpu::HeaderError::UnsupportedVersion { version_number: 2 }),
+            ),
         ];
         for (prefix, value) in &test_values {
             // display
@@ -465,7 +714,7 @@ mod tests {
 
     #[test]
     fn display_source() {
-        let test_values: [ReadError; 8] = [
+        let test_values: [ReadError; 15] = [
             Len(LenError {
                 required_len: 0,
                 len: 0,
@@ -483,7 +732,14 @@ mod tests {
             Ipv4(ipv4::HeaderError::UnexpectedVersion { version_number: 1 }),
             Ipv6(ipv6::HeaderError::UnexpectedVersion { version_number: 1 }),
             Ipv6Exts(ipv6_exts::HeaderError::HopByHopNotAtStart),
+            Mpls(mpls::HeaderError::TooManyEntries { max_entries: 16 }),
+            Pppoe(pppoe::HeaderError::UnexpectedVersion { version_number: 2 }),
             Tcp(tcp::HeaderError::DataOffsetTooSmall { data_offset: 1 }),
+            VlanStack(vlan_stack::HeaderError::TooManyTags { max_tags: 8 }),
+            Macsec(macsec::HeaderError::UnexpectedVersion { version_number: 1 }),
+            Gre(gre::HeaderError::UnsupportedVersion { version_number: 2 }),
+            Geneve(geneve::HeaderError::UnsupportedVersion { version_number: 1 }),
+            Gtpu(gtpu::HeaderError::UnsupportedVersion { version_number: 2 }),
         ];
         for value in &test_values {
             // display
@@ -516,7 +772,14 @@ mod tests {
         let ipv6_error = || ipv6::HeaderError::UnexpectedVersion { version_number: 1 };
         let ip_auth_error = || ip_auth::HeaderError::ZeroPayloadLen;
         let ipv6_exts_error = || ipv6_exts::HeaderError::HopByHopNotAtStart;
+        let mpls_error = || mpls::HeaderError::TooManyEntries { max_entries: 16 };
+        let pppoe_error = || pppoe::HeaderError::UnexpectedVersion { version_number: 2 };
         let tcp_error = || tcp::HeaderError::DataOffsetTooSmall { data_offset: 1 };
+        let vlan_stack_error = || vlan_stack::HeaderError::TooManyTags { max_tags: 8 };
+        let macsec_error = || macsec::HeaderError::UnexpectedVersion { version_number: 1 };
+        let gre_error = || gre::HeaderError::UnsupportedVersion { version_number: 2 };
+        let geneve_error = || geneve::HeaderError::UnsupportedVersion { version_number: 1 };
+        let gtpu_error = || gtpu::HeaderError::UnsupportedVersion { version_number: 2 };
 
         // io
         assert!(Io(io_error()).io().is_some());
@@ -556,9 +819,40 @@ mod tests {
         );
         assert_eq!(IpAuth(ip_auth_error()).ipv6_exts(), None);
 
+        // mpls
+        assert_eq!(Mpls(mpls_error()).mpls(), Some(&mpls_error()));
+        assert_eq!(IpAuth(ip_auth_error()).mpls(), None);
+
+        // pppoe
+        assert_eq!(Pppoe(pppoe_error()).pppoe(), Some(&pppoe_error()));
+        assert_eq!(IpAuth(ip_auth_error()).pppoe(), None);
+
         // tcp
         assert_eq!(Tcp(tcp_error()).tcp(), Some(&tcp_error()));
         assert_eq!(IpAuth(ip_auth_error()).tcp(), None);
+
+        // vlan_stack
+        assert_eq!(
+            VlanStack(vlan_stack_error()).vlan_stack(),
+            Some(&vlan_stack_error())
+        );
+        assert_eq!(IpAuth(ip_auth_error()).vlan_stack(), None);
+
+        // macsec
+        assert_eq!(Macsec(macsec_error()).macsec(), Some(&macsec_error()));
+        assert_eq!(IpAuth(ip_auth_error()).macsec(), None);
+
+        // gre
+        assert_eq!(Gre(gre_error()).gre(), Some(&gre_error()));
+        assert_eq!(IpAuth(ip_auth_error()).gre(), None);
+
+        // geneve
+        assert_eq!(Geneve(geneve_error()).geneve(), Some(&geneve_error()));
+        assert_eq!(IpAuth(ip_auth_error()).geneve(), None);
+
+        // gtpu
+        assert_eq!(Gtpu(gtpu_error()).gtpu(), Some(&gtpu_error()));
+        assert_eq!(IpAuth(ip_auth_error()).gtpu(), None);
     }
 
     #[test]
@@ -861,6 +1155,162 @@ mod tests {
             );
         }
 
+        // mpls errors
+        {
+            let header_error = || mpls::HeaderError::TooManyEntries { max_entries: 16 };
+            assert_eq!(
+                &header_error(),
+                ReadError::from(header_error()).mpls().unwrap()
+            );
+            assert_eq!(
+                &header_error(),
+                ReadError::from(mpls::HeaderSliceError::Content(header_error()))
+                    .mpls()
+                    .unwrap()
+            );
+            assert_eq!(
+                &len_error(),
+                ReadError::from(mpls::HeaderSliceError::Len(len_error()))
+                    .len()
+                    .unwrap()
+            );
+        }
+
+        // vlan stack errors
+        {
+            let header_error = || vlan_stack::HeaderError::TooManyTags { max_tags: 8 };
+            assert_eq!(
+                &header_error(),
+                ReadError::from(header_error()).vlan_stack().unwrap()
+            );
+            assert_eq!(
+                &header_error(),
+                ReadError::from(vlan_stack::HeaderSliceError::Content(header_error()))
+                    .vlan_stack()
+                    .unwrap()
+            );
+            assert_eq!(
+                &len_error(),
+                ReadError::from(vlan_stack::HeaderSliceError::Len(len_error()))
+                    .len()
+                    .unwrap()
+            );
+        }
+
+        // geneve errors
+        {
+            let header_error = || geneve::HeaderError::UnsupportedVersion { version_number: 1 };
+            assert_eq!(
+                &header_error(),
+                ReadError::from(header_error()).geneve().unwrap()
+            );
+            assert_eq!(
+                &header_error(),
+                ReadError::from(geneve::HeaderSliceError::Content(header_error()))
+                    .geneve()
+                    .unwrap()
+            );
+            assert_eq!(
+                &len_error(),
+                ReadError::from(geneve::HeaderSliceError::Len(len_error()))
+                    .len()
+                    .unwrap()
+            );
+        }
+
+        // gtpu errors
+        {
+            let header_error = || gtpu::HeaderError::UnsupportedVersion { version_number: 2 };
+            assert_eq!(
+                &header_error(),
+                ReadError::from(header_error()).gtpu().unwrap()
+            );
+            assert_eq!(
+                &header_error(),
+                ReadError::from(gtpu::HeaderSliceError::Content(header_error()))
+                    .gtpu()
+                    .unwrap()
+            );
+            assert_eq!(
+                &len_error(),
+                ReadError::from(gtpu::HeaderSliceError::Len(len_error()))
+                    .len()
+                    .unwrap()
+            );
+        }
+
+        // gre errors
+        {
+            let header_error = || gre::HeaderError::UnsupportedVersion { version_number: 2 };
+            assert_eq!(
+                &header_error(),
+                ReadError::from(header_error()).gre().unwrap()
+            );
+            assert_eq!(
+                &header_error(),
+                ReadError::from(gre::HeaderSliceError::Content(header_error()))
+                    .gre()
+                    .unwrap()
+            );
+            assert_eq!(
+                &len_error(),
+                ReadError::from(gre::HeaderSliceError::Len(len_error()))
+                    .len()
+                    .unwrap()
+            );
+        }
+
+        // macsec errors
+        {
+            let header_error = || macsec::HeaderError::UnexpectedVersion { version_number: 1 };
+            assert_eq!(
+                &header_error(),
+                ReadError::from(header_error()).macsec().unwrap()
+            );
+            assert_eq!(
+                &header_error(),
+                ReadError::from(macsec::HeaderSliceError::Content(header_error()))
+                    .macsec()
+                    .unwrap()
+            );
+            assert_eq!(
+                &len_error(),
+                ReadError::from(macsec::HeaderSliceError::Len(len_error()))
+                    .len()
+                    .unwrap()
+            );
+        }
+
+        // pppoe errors
+        {
+            let header_error = || pppoe::HeaderError::UnexpectedVersion { version_number: 2 };
+            assert_eq!(
+                &header_error(),
+                ReadError::from(header_error()).pppoe().unwrap()
+            );
+            assert_eq!(
+                &header_error(),
+                ReadError::from(pppoe::HeaderReadError::Content(header_error()))
+                    .pppoe()
+                    .unwrap()
+            );
+            assert!(ReadError::from(pppoe::HeaderReadError::Io(io_error()))
+                .io()
+                .is_some());
+            assert_eq!(
+                &header_error(),
+                ReadError::from(pppoe::HeaderSliceError::Content(header_error()))
+                    .pppoe()
+                    .unwrap()
+            );
+            assert_eq!(
+                &len_error(),
+                ReadError::from(pppoe::HeaderSliceError::Len(len_error()))
+                    .len()
+                    .unwrap()
+            );
+        }
+
         // packet error
         {
             let ip_error = || ip::HeaderError::Ipv4Ext(ip_auth::HeaderError::ZeroPayloadLen);
@@ -868,6 +1318,13 @@ mod tests {
             let ipv6_error = || ipv6::HeaderError::UnexpectedVersion { version_number: 1 };
             let ip_auth_error = || ip_auth::HeaderError::ZeroPayloadLen;
             let ipv6_exts_error = || ipv6_exts::HeaderError::HopByHopNotAtStart;
+            let mpls_error = || mpls::HeaderError::TooManyEntries { max_entries: 16 };
+            let pppoe_error = || pppoe::HeaderError::UnexpectedVersion { version_number: 2 };
+            let vlan_stack_error = || vlan_stack::HeaderError::TooManyTags { max_tags: 8 };
+            let macsec_error = || macsec::HeaderError::UnexpectedVersion { version_number: 1 };
+            let gre_error = || gre::HeaderError::UnsupportedVersion { version_number: 2 };
+            let geneve_error = || geneve::HeaderError::UnsupportedVersion { version_number: 1 };
+            let gtpu_error = || gtpu::HeaderError::UnsupportedVersion { version_number: 2 };
             let tcp_error = || tcp::HeaderError::DataOffsetTooSmall { data_offset: 1 };
 
             // EthSliceError
@@ -877,6 +1334,30 @@ mod tests {
                     .len()
                     .unwrap()
             );
+            assert_eq!(
+                &vlan_stack_error(),
+                ReadError::from(packet::EthSliceError::VlanStack(vlan_stack_error()))
+                    .vlan_stack()
+                    .unwrap()
+            );
+            assert_eq!(
+                &macsec_error(),
+                ReadError::from(packet::EthSliceError::Macsec(macsec_error()))
+                    .macsec()
+                    .unwrap()
+            );
+            assert_eq!(
+                &mpls_error(),
+                ReadError::from(packet::EthSliceError::Mpls(mpls_error()))
+                    .mpls()
+                    .unwrap()
+            );
+            assert_eq!(
+                &pppoe_error(),
+                ReadError::from(packet::EthSliceError::Pppoe(pppoe_error()))
+                    .pppoe()
+                    .unwrap()
+            );
             assert_eq!(
                 &ipv4_error(),
                 ReadError::from(packet::EthSliceError::Ipv4(ipv4_error()))
@@ -901,6 +1382,12 @@ mod tests {
                     .ipv6_exts()
                     .unwrap()
             );
+            assert_eq!(
+                &gre_error(),
+                ReadError::from(packet::EthSliceError::Gre(gre_error()))
+                    .gre()
+                    .unwrap()
+            );
             assert_eq!(
                 &tcp_error(),
                 ReadError::from(packet::EthSliceError::Tcp(tcp_error()))
@@ -921,12 +1408,30 @@ mod tests {
                     .ip()
                     .unwrap()
             );
+            assert_eq!(
+                &gre_error(),
+                ReadError::from(packet::IpSliceError::Gre(gre_error()))
+                    .gre()
+                    .unwrap()
+            );
             assert_eq!(
                 &tcp_error(),
                 ReadError::from(packet::IpSliceError::Tcp(tcp_error()))
                     .tcp()
                     .unwrap()
             );
+            assert_eq!(
+                &geneve_error(),
+                ReadError::from(packet::IpSliceError::Geneve(geneve_error()))
+                    .geneve()
+                    .unwrap()
+            );
+            assert_eq!(
+                &gtpu_error(),
+                ReadError::from(packet::IpSliceError::Gtpu(gtpu_error()))
+                    .gtpu()
+                    .unwrap()
+            );
         }
 
         // tcp errors
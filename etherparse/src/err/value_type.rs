@@ -6,6 +6,16 @@ pub enum ValueType {
     VlanId,
     /// VLAN PCP (Priority Code Point) field in a [`crate::SingleVlanHeader`].
     VlanPcp,
+    /// Payload length of an IEEE 802.3 Ethernet frame (written into
+    /// the type/length field of an [`crate::Ethernet2Header`]).
+    Ieee802_3PayloadLength,
+    /// MPLS label field present in a [`crate::MplsLabelStackEntry`].
+    MplsLabel,
+    /// MPLS traffic class field present in a [`crate::MplsLabelStackEntry`].
+    MplsTrafficClass,
+    /// Payload length of a PPPoE packet (written into the length field
+    /// of a [`crate::PppoeHeader`]).
+    PppoePayloadLength,
     /// IP Fragment offset present in the IPv4 header and
     /// IPv6 fragmentation header.
     IpFragmentOffset,
@@ -37,6 +47,16 @@ pub enum ValueType {
     TcpPayloadLengthIpv6,
     /// Variable length data of an ICMPv6 packet.
     Icmpv6PayloadLength,
+    /// Virtual network identifier present in a [`crate::VxlanHeader`]
+    /// or [`crate::GeneveHeader`].
+    Vni,
+    /// Length of the data after the mandatory part of a
+    /// [`crate::GtpuHeader`] (sets the "length" field).
+    GtpuPayloadLength,
+    /// PDU type of a [`crate::GtpuPduSessionContainer`].
+    GtpuPduType,
+    /// QoS flow identifier (QFI) of a [`crate::GtpuPduSessionContainer`].
+    GtpuQfi,
 }
 
 impl core::fmt::Display for ValueType {
@@ -45,6 +65,10 @@ impl core::fmt::Display for ValueType {
         match self {
             VlanId => write!(f, "VLAN ID"),
             VlanPcp => write!(f, "VLAN PCP (Priority Code Point)"),
+            Ieee802_3PayloadLength => write!(f, "IEEE 802.3 Payload Length"),
+            MplsLabel => write!(f, "MPLS Label"),
+            MplsTrafficClass => write!(f, "MPLS Traffic Class"),
+            PppoePayloadLength => write!(f, "PPPoE Payload Length"),
             IpFragmentOffset => write!(f, "IP Fragment Offset"),
             Ipv4Dscp => write!(f, "IPv4 DSCP (Differentiated Services Code Point)"),
             Ipv4Ecn => write!(f, "IPv4 ECN (Explicit Congestion Notification)"),
@@ -56,6 +80,10 @@ impl core::fmt::Display for ValueType {
             TcpPayloadLengthIpv4 => write!(f, "TCP Payload Length (in IPv4 checksum calculation)"),
             TcpPayloadLengthIpv6 => write!(f, "TCP Payload Length (in IPv6 checksum calculation)"),
             Icmpv6PayloadLength => write!(f, "ICMPv6 Payload Length"),
+            Vni => write!(f, "VNI (Virtual Network Identifier)"),
+            GtpuPayloadLength => write!(f, "GTP-U Payload Length (sets 'Length')"),
+            GtpuPduType => write!(f, "GTP-U PDU Session Container PDU Type"),
+            GtpuQfi => write!(f, "GTP-U QFI (QoS Flow Identifier)"),
         }
     }
 }
@@ -88,6 +116,13 @@ mod test {
 
         assert_eq!("VLAN ID", &format!("{}", VlanId));
         assert_eq!("VLAN PCP (Priority Code Point)", &format!("{}", VlanPcp));
+        assert_eq!(
+            "IEEE 802.3 Payload Length",
+            &format!("{}", Ieee802_3PayloadLength)
+        );
+        assert_eq!("MPLS Label", &format!("{}", MplsLabel));
+        assert_eq!("MPLS Traffic Class", &format!("{}", MplsTrafficClass));
+        assert_eq!("PPPoE Payload Length", &format!("{}", PppoePayloadLength));
         assert_eq!("IP Fragment Offset", &format!("{}", IpFragmentOffset));
         assert_eq!(
             "IPv4 DSCP (Differentiated Services Code Point)",
@@ -123,5 +158,15 @@ mod test {
             &format!("{}", TcpPayloadLengthIpv6)
         );
         assert_eq!("ICMPv6 Payload Length", &format!("{}", Icmpv6PayloadLength));
+        assert_eq!("VNI (Virtual Network Identifier)", &format!("{}", Vni));
+        assert_eq!(
+            "GTP-U Payload Length (sets 'Length')",
+            &format!("{}", GtpuPayloadLength)
+        );
+        assert_eq!(
+            "GTP-U PDU Session Container PDU Type",
+            &format!("{}", GtpuPduType)
+        );
+        assert_eq!("GTP-U QFI (QoS Flow Identifier)", &format!("{}", GtpuQfi));
     }
 }
@@ -0,0 +1,76 @@
+/// Errors in a VLAN stack encountered while decoding it.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum HeaderError {
+    /// Error when the VLAN stack contains more tags than can be
+    /// stored in a [`crate::VlanStack`].
+    TooManyTags {
+        /// Maximum number of tags that can be stored.
+        max_tags: usize,
+    },
+}
+
+impl core::fmt::Display for HeaderError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        use HeaderError::*;
+        match self {
+            TooManyTags { max_tags } => write!(f, "VLAN Stack Error: The VLAN stack contains more then the maximum supported number of {} tags.", max_tags),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for HeaderError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::HeaderError::*;
+    use alloc::format;
+    use std::{
+        collections::hash_map::DefaultHasher,
+        error::Error,
+        hash::{Hash, Hasher},
+    };
+
+    #[test]
+    fn debug() {
+        assert_eq!(
+            "TooManyTags { max_tags: 8 }",
+            format!("{:?}", TooManyTags { max_tags: 8 })
+        );
+    }
+
+    #[test]
+    fn clone_eq_hash() {
+        let err = TooManyTags { max_tags: 8 };
+        assert_eq!(err, err.clone());
+        let hash_a = {
+            let mut hasher = DefaultHasher::new();
+            err.hash(&mut hasher);
+            hasher.finish()
+        };
+        let hash_b = {
+            let mut hasher = DefaultHasher::new();
+            err.clone().hash(&mut hasher);
+            hasher.finish()
+        };
+        assert_eq!(hash_a, hash_b);
+    }
+
+    #[test]
+    fn fmt() {
+        assert_eq!(
+            "VLAN Stack Error: The VLAN stack contains more then the maximum supported number of 8 tags.",
+            format!("{}", TooManyTags { max_tags: 8 })
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn source() {
+        assert!(TooManyTags { max_tags: 8 }.source().is_none());
+    }
+}
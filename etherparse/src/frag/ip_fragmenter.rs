@@ -0,0 +1,708 @@
+use crate::{
+    err::{ip_frag::IpFragError, packet::BuildWriteError, Layer, LenError, LenSource},
+    *,
+};
+use std::{vec, vec::Vec};
+
+/// Splits IP packets into fragments that fit into a given MTU
+/// (maximum transmission unit).
+///
+/// The resulting fragments start with the IP header (no link layer
+/// headers are added). To build complete frames containing fragments
+/// use the `build_fragments` methods of the [`crate::PacketBuilder`]
+/// steps.
+///
+/// # Example
+///
+/// ```
+/// use etherparse::{frag::IpFragmenter, Ipv4Header, IpNumber};
+///
+/// let fragmenter = IpFragmenter::new(
+///     60,   // MTU (maximum length of the resulting IP packets)
+///     1234, // identification
+/// );
+///
+/// let header = Ipv4Header::new(
+///     0, // payload length (recalculated)
+///     20,
+///     IpNumber::UDP,
+///     [192, 168, 1, 1],
+///     [192, 168, 1, 2],
+/// ).unwrap();
+/// let fragments = fragmenter.fragment_ipv4(&header, &[0u8; 100]).unwrap();
+///
+/// // 40 + 40 + 20 bytes payload
+/// assert_eq!(3, fragments.len());
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct IpFragmenter {
+    /// Maximum length in bytes of the resulting IP packets (including
+    /// the IP header).
+    pub mtu: usize,
+
+    /// Identification value set in the fragments (IPv4 fragments only
+    /// use the lower 16 bits).
+    pub identification: u32,
+}
+
+impl IpFragmenter {
+    /// Creates a fragmenter with the given MTU & identification value.
+    #[inline]
+    pub fn new(mtu: usize, identification: u32) -> IpFragmenter {
+        IpFragmenter {
+            mtu,
+            identification,
+        }
+    }
+
+    /// Splits an IPv4 packet into fragments.
+    ///
+    /// `payload` has to contain all data following the IPv4 header
+    /// (including extension headers). The "total length" field of the
+    /// header is recalculated.
+    ///
+    /// If the packet fits into the MTU it is returned unfragmented.
+    /// Otherwise the "more fragments" flag, fragment offset,
+    /// identification & header checksum are set in each fragment. Only
+    /// options with the "copied" flag are repeated in the non-first
+    /// fragments (RFC 791). Headers that are already fragments are
+    /// split further (keeping their offset & "more fragments" flag).
+    ///
+    /// The "don't fragment" flag is not checked & kept as it is in all
+    /// fragments (deciding if a packet is allowed to be fragmented is up
+    /// to the caller).
+    pub fn fragment_ipv4(
+        &self,
+        header: &Ipv4Header,
+        payload: &[u8],
+    ) -> Result<Vec<Vec<u8>>, IpFragError> {
+        use IpFragError::*;
+
+        let mut header = header.clone();
+        header.set_payload_len(payload.len()).map_err(PayloadLen)?;
+        if header.header_len() + payload.len() <= self.mtu {
+            return Ok(vec![ipv4_packet(&header, payload)]);
+        }
+        // the first fragment has the longest header
+        let min_mtu = header.header_len() + 8;
+        if self.mtu < min_mtu {
+            return Err(MtuTooSmall {
+                mtu: self.mtu,
+                min_mtu,
+            });
+        }
+
+        // check the offsets of already fragmented packets still fit
+        let base_offset = usize::from(header.fragment_offset.value()) * 8;
+        let max_end = usize::from(header.max_payload_len());
+        if base_offset + payload.len() > max_end {
+            return Err(PayloadLen(err::ValueTooBigError {
+                actual: base_offset + payload.len(),
+                max_allowed: max_end,
+                value_type: err::ValueType::Ipv4PayloadLength,
+            }));
+        }
+
+        let later_options = copied_ipv4_options(header.options.as_slice());
+        let mut result = Vec::new();
+        let mut start = 0;
+        while start < payload.len() {
+            let mut frag_header = header.clone();
+            if 0 != start {
+                frag_header.options = later_options.clone();
+            }
+            let max_len = self.mtu - frag_header.header_len();
+            let end = if payload.len() - start <= max_len {
+                payload.len()
+            } else {
+                start + (max_len & !0b111)
+            };
+            frag_header.identification = self.identification as u16;
+            frag_header.more_fragments = end < payload.len() || header.more_fragments;
+            // SAFETY: Safe as base_offset + payload.len() was checked to
+            //         be smaller then u16::MAX.
+            frag_header.fragment_offset =
+                unsafe { IpFragOffset::new_unchecked(((base_offset + start) / 8) as u16) };
+            frag_header
+                .set_payload_len(end - start)
+                .map_err(PayloadLen)?;
+            result.push(ipv4_packet(&frag_header, &payload[start..end]));
+            start = end;
+        }
+        Ok(result)
+    }
+
+    /// Splits an IPv6 packet into fragments.
+    ///
+    /// `payload` has to contain all data following the IPv6 header
+    /// (including extension headers). The "payload length" field of the
+    /// header is recalculated.
+    ///
+    /// If the packet fits into the MTU it is returned unfragmented.
+    /// Otherwise a fragment header is inserted after the unfragmentable
+    /// part (hop by hop, destination options in front of a routing
+    /// header, routing & shim6 headers) in each fragment.
+    pub fn fragment_ipv6(
+        &self,
+        header: &Ipv6Header,
+        payload: &[u8],
+    ) -> Result<Vec<Vec<u8>>, IpFragError> {
+        use ip_number::*;
+        use IpFragError::*;
+
+        let mut header = header.clone();
+        header
+            .set_payload_length(payload.len())
+            .map_err(PayloadLen)?;
+        if Ipv6Header::LEN + payload.len() <= self.mtu {
+            let mut packet = Vec::with_capacity(Ipv6Header::LEN + payload.len());
+            packet.extend_from_slice(&header.to_bytes());
+            packet.extend_from_slice(payload);
+            return Ok(vec![packet]);
+        }
+
+        // determine the length of the unfragmentable part & the position
+        // of the "next header" field that has to point to the fragment header
+        let mut unfrag_len = 0;
+        let mut unfrag_next_header_pos = None;
+        let mut frag_next_header = header.next_header;
+        {
+            let mut next_header = header.next_header;
+            let mut offset = 0;
+            loop {
+                match next_header {
+                    IPV6_HOP_BY_HOP | IPV6_DEST_OPTIONS | IPV6_ROUTE | SHIM6 => {
+                        let required_len = if payload.len() < offset + 2 {
+                            offset + 2
+                        } else {
+                            offset + (usize::from(payload[offset + 1]) + 1) * 8
+                        };
+                        if payload.len() < required_len {
+                            return Err(Len(LenError {
+                                required_len: required_len - offset,
+                                len: payload.len() - offset,
+                                len_source: LenSource::Slice,
+                                layer: Layer::Ipv6ExtHeader,
+                                layer_start_offset: Ipv6Header::LEN + offset,
+                            }));
+                        }
+                        let following = IpNumber(payload[offset]);
+                        // destination options only belong to the unfragmentable
+                        // part if they are followed by a routing header
+                        if IPV6_DEST_OPTIONS != next_header {
+                            unfrag_len = required_len;
+                            unfrag_next_header_pos = Some(offset);
+                            frag_next_header = following;
+                        }
+                        offset = required_len;
+                        next_header = following;
+                    }
+                    IPV6_FRAG => return Err(AlreadyFragmented),
+                    _ => break,
+                }
+            }
+        }
+
+        let headers_len = Ipv6Header::LEN + unfrag_len + Ipv6FragmentHeader::LEN;
+        let min_mtu = headers_len + 8;
+        if self.mtu < min_mtu {
+            return Err(MtuTooSmall {
+                mtu: self.mtu,
+                min_mtu,
+            });
+        }
+
+        let mut unfrag = payload[..unfrag_len].to_vec();
+        match unfrag_next_header_pos {
+            Some(pos) => unfrag[pos] = IPV6_FRAG.0,
+            None => header.next_header = IPV6_FRAG,
+        }
+
+        let fragmentable = &payload[unfrag_len..];
+        let max_len = self.mtu - headers_len;
+        let mut result = Vec::new();
+        let mut start = 0;
+        while start < fragmentable.len() {
+            let end = if fragmentable.len() - start <= max_len {
+                fragmentable.len()
+            } else {
+                start + (max_len & !0b111)
+            };
+            let frag_header = Ipv6FragmentHeader::new(
+                frag_next_header,
+                // SAFETY: Safe as the payload length was checked to be
+                //         smaller then u16::MAX.
+                unsafe { IpFragOffset::new_unchecked((start / 8) as u16) },
+                end < fragmentable.len(),
+                self.identification,
+            );
+            header
+                .set_payload_length(unfrag_len + Ipv6FragmentHeader::LEN + end - start)
+                .map_err(PayloadLen)?;
+
+            let mut packet = Vec::with_capacity(headers_len + end - start);
+            packet.extend_from_slice(&header.to_bytes());
+            packet.extend_from_slice(&unfrag);
+            packet.extend_from_slice(&frag_header.to_bytes());
+            packet.extend_from_slice(&fragmentable[start..end]);
+            result.push(packet);
+            start = end;
+        }
+        Ok(result)
+    }
+
+    /// Serializes the IP packet described by the given headers & payload
+    /// and splits it into fragments (see [`IpFragmenter::fragment_ipv4`]
+    /// & [`IpFragmenter::fragment_ipv6`]).
+    ///
+    /// The link layer headers are ignored. Apart from the IP length
+    /// fields the headers are written as they are (e.g. the checksum &
+    /// length of a UDP header are not recalculated).
+    pub fn fragment_packet_headers(
+        &self,
+        headers: &PacketHeaders,
+    ) -> Result<Vec<Vec<u8>>, BuildWriteError> {
+        use BuildWriteError::*;
+
+        let mut payload = Vec::new();
+        match &headers.ip {
+            Some(IpHeader::Version4(header, exts)) => {
+                exts.write(&mut payload, header.protocol).map_err(|err| {
+                    use err::ipv4_exts::HeaderWriteError as I;
+                    match err {
+                        I::Io(err) => Io(err),
+                        I::Content(err) => Ipv4Exts(err),
+                    }
+                })?;
+            }
+            Some(IpHeader::Version6(header, exts)) => {
+                exts.write(&mut payload, header.next_header)
+                    .map_err(|err| {
+                        use err::ipv6_exts::HeaderWriteError as I;
+                        match err {
+                            I::Io(err) => Io(err),
+                            I::Content(err) => Ipv6Exts(err),
+                        }
+                    })?;
+            }
+            None => return Err(IpFrag(IpFragError::MissingIpHeader)),
+        }
+        if let Some(transport) = &headers.transport {
+            transport.write(&mut payload).map_err(Io)?;
+        }
+        payload.extend_from_slice(headers.payload);
+
+        match &headers.ip {
+            Some(IpHeader::Version4(header, _)) => self.fragment_ipv4(header, &payload),
+            Some(IpHeader::Version6(header, _)) => self.fragment_ipv6(header, &payload),
+            None => Err(IpFragError::MissingIpHeader),
+        }
+        .map_err(IpFrag)
+    }
+}
+
+/// Serializes an IPv4 header (with a recalculated checksum) and payload.
+fn ipv4_packet(header: &Ipv4Header, payload: &[u8]) -> Vec<u8> {
+    let mut header = header.clone();
+    header.header_checksum = header.calc_header_checksum();
+    let header_bytes = header.to_bytes();
+    let mut packet = Vec::with_capacity(header_bytes.len() + payload.len());
+    packet.extend_from_slice(&header_bytes);
+    packet.extend_from_slice(payload);
+    packet
+}
+
+/// Returns the IPv4 options that have to be copied into all fragments
+/// (options with the "copied" flag set).
+fn copied_ipv4_options(options: &[u8]) -> Ipv4Options {
+    let mut buf = [0u8; 40];
+    let mut len = 0;
+    let mut rest = options;
+    while let Some(option_type) = rest.first() {
+        let option_len = match *option_type {
+            // end of option list
+            0 => break,
+            // no operation
+            1 => 1,
+            _ => match rest.get(1) {
+                Some(l) if (2..=rest.len()).contains(&usize::from(*l)) => usize::from(*l),
+                // malformed option, ignore the rest
+                _ => break,
+            },
+        };
+        if 0 != option_type & 0b1000_0000 {
+            buf[len..len + option_len].copy_from_slice(&rest[..option_len]);
+            len += option_len;
+        }
+        rest = &rest[option_len..];
+    }
+    // pad with "end of option list" to a multiple of 4 bytes
+    let padded_len = (len + 3) & !0b11;
+    // unwrap is safe as the padded length is a multiple of 4 & not
+    // bigger then the original options
+    Ipv4Options::try_from(&buf[..padded_len]).unwrap()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::defrag::{Ipv4DefragPool, Ipv6DefragPool};
+    use alloc::format;
+
+    #[test]
+    fn debug_clone_eq() {
+        let fragmenter = IpFragmenter::new(1280, 5);
+        assert_eq!(
+            "IpFragmenter { mtu: 1280, identification: 5 }",
+            format!("{:?}", fragmenter)
+        );
+        assert_eq!(fragmenter, fragmenter.clone());
+    }
+
+    fn ipv4_header() -> Ipv4Header {
+        let mut header =
+            Ipv4Header::new(0, 20, ip_number::UDP, [1, 2, 3, 4], [5, 6, 7, 8]).unwrap();
+        header.dont_fragment = false;
+        header
+    }
+
+    fn payload(len: usize) -> Vec<u8> {
+        (0..len).map(|i| i as u8).collect()
+    }
+
+    #[test]
+    fn fragment_ipv4_unfragmented() {
+        let fragments = IpFragmenter::new(48, 1)
+            .fragment_ipv4(&ipv4_header(), &payload(28))
+            .unwrap();
+        assert_eq!(1, fragments.len());
+        let slice = Ipv4Slice::from_slice(&fragments[0]).unwrap();
+        assert!(!slice.is_payload_fragmented());
+        assert_eq!(48, slice.header().total_len());
+        assert_eq!(0, slice.header().identification());
+        assert_eq!(&payload(28)[..], slice.payload().payload);
+    }
+
+    #[test]
+    fn fragment_ipv4() {
+        let payload = payload(100);
+        let fragments = IpFragmenter::new(52, 1234)
+            .fragment_ipv4(&ipv4_header(), &payload)
+            .unwrap();
+
+        // 32 + 32 + 32 + 4 bytes
+        assert_eq!(4, fragments.len());
+        for (i, fragment) in fragments.iter().enumerate() {
+            let (header, rest) = Ipv4Header::from_slice(fragment).unwrap();
+            assert_eq!(1234, header.identification);
+            assert_eq!((i * 4) as u16, header.fragment_offset.value());
+            assert_eq!(i < 3, header.more_fragments);
+            assert_eq!(header.calc_header_checksum(), header.header_checksum);
+            assert_eq!(usize::from(header.total_len), fragment.len());
+            assert!(fragment.len() <= 52);
+            assert_eq!(&payload[i * 32..(i * 32 + 32).min(100)], rest);
+        }
+
+        // reassemble
+        let mut pool = Ipv4DefragPool::default();
+        let mut result = None;
+        for fragment in fragments.iter() {
+            result = pool
+                .process(0, &Ipv4Slice::from_slice(fragment).unwrap())
+                .unwrap();
+        }
+        let result = result.unwrap();
+        let slice = Ipv4Slice::from_slice(&result).unwrap();
+        assert_eq!(&payload[..], slice.payload().payload);
+    }
+
+    #[test]
+    fn fragment_ipv4_options() {
+        let mut header = ipv4_header();
+        #[rustfmt::skip]
+        let options = Ipv4Options::try_from(&[
+            // security option (copied)
+            0x82, 3, 1,
+            // noop (not copied)
+            1,
+            // record route (not copied)
+            7, 7, 4, 0, 0, 0, 0,
+            // end of options
+            0,
+        ][..]).unwrap();
+        header.options = options;
+        let fragments = IpFragmenter::new(64, 1)
+            .fragment_ipv4(&header, &payload(72))
+            .unwrap();
+        assert_eq!(2, fragments.len());
+        {
+            let (h, rest) = Ipv4Header::from_slice(&fragments[0]).unwrap();
+            assert_eq!(header.options, h.options);
+            assert_eq!(32, rest.len());
+        }
+        {
+            let (h, rest) = Ipv4Header::from_slice(&fragments[1]).unwrap();
+            assert_eq!(&[0x82, 3, 1, 0], h.options.as_slice());
+            assert_eq!(40, rest.len());
+            assert_eq!(4, h.fragment_offset.value());
+        }
+    }
+
+    #[test]
+    fn fragment_ipv4_refragment() {
+        let mut header = ipv4_header();
+        header.fragment_offset = IpFragOffset::try_new(2).unwrap();
+        header.more_fragments = true;
+        let fragments = IpFragmenter::new(36, 1)
+            .fragment_ipv4(&header, &payload(20))
+            .unwrap();
+        assert_eq!(2, fragments.len());
+        let (h0, _) = Ipv4Header::from_slice(&fragments[0]).unwrap();
+        let (h1, _) = Ipv4Header::from_slice(&fragments[1]).unwrap();
+        assert_eq!((2, true), (h0.fragment_offset.value(), h0.more_fragments));
+        assert_eq!((4, true), (h1.fragment_offset.value(), h1.more_fragments));
+    }
+
+    #[test]
+    fn fragment_ipv4_errors() {
+        // mtu too small
+        assert_eq!(
+            Err(IpFragError::MtuTooSmall {
+                mtu: 27,
+                min_mtu: 28
+            }),
+            IpFragmenter::new(27, 1).fragment_ipv4(&ipv4_header(), &payload(9))
+        );
+        // payload too big
+        assert!(matches!(
+            IpFragmenter::new(1500, 1).fragment_ipv4(&ipv4_header(), &payload(0xffff)),
+            Err(IpFragError::PayloadLen(_))
+        ));
+        // offset of an already fragmented packet too big
+        {
+            let mut header = ipv4_header();
+            header.fragment_offset = IpFragOffset::try_new(IpFragOffset::MAX_U16).unwrap();
+            assert!(matches!(
+                IpFragmenter::new(1500, 1).fragment_ipv4(&header, &payload(1600)),
+                Err(IpFragError::PayloadLen(_))
+            ));
+        }
+    }
+
+    fn ipv6_header(next_header: IpNumber) -> Ipv6Header {
+        Ipv6Header {
+            next_header,
+            hop_limit: 20,
+            source: [1; 16],
+            destination: [2; 16],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn fragment_ipv6_unfragmented() {
+        let fragments = IpFragmenter::new(60, 1)
+            .fragment_ipv6(&ipv6_header(ip_number::UDP), &payload(20))
+            .unwrap();
+        assert_eq!(1, fragments.len());
+        let slice = Ipv6Slice::from_slice(&fragments[0]).unwrap();
+        assert_eq!(20, slice.header().payload_length());
+        assert_eq!(&payload(20)[..], slice.payload().payload);
+    }
+
+    #[test]
+    fn fragment_ipv6() {
+        use ip_number::*;
+
+        #[rustfmt::skip]
+        let exts = [
+            // hop by hop
+            IPV6_DEST_OPTIONS.0, 0, 0, 0, 0, 0, 0, 0,
+            // destination options (in front of routing)
+            IPV6_ROUTE.0, 0, 0, 0, 0, 0, 0, 0,
+            // routing
+            UDP.0, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        let mut payload_data = exts.to_vec();
+        payload_data.extend_from_slice(&payload(100));
+        let header = ipv6_header(IPV6_HOP_BY_HOP);
+
+        let fragments = IpFragmenter::new(40 + 24 + 8 + 48, 1234)
+            .fragment_ipv6(&header, &payload_data)
+            .unwrap();
+        // 100 bytes fragmentable: 48 + 48 + 4
+        assert_eq!(3, fragments.len());
+        for (i, fragment) in fragments.iter().enumerate() {
+            assert!(fragment.len() <= 120);
+            let (h, rest) = Ipv6Header::from_slice(fragment).unwrap();
+            assert_eq!(IPV6_HOP_BY_HOP, h.next_header);
+            assert_eq!(usize::from(h.payload_length), rest.len());
+            assert_eq!(&exts[..8], &rest[..8]);
+            assert_eq!(&exts[8..16], &rest[8..16]);
+            // routing header points to the fragment header
+            assert_eq!(IPV6_FRAG.0, rest[16]);
+            assert_eq!(&exts[17..24], &rest[17..24]);
+            let (frag, data) = Ipv6FragmentHeader::from_slice(&rest[24..]).unwrap();
+            assert_eq!(
+                Ipv6FragmentHeader::new(
+                    UDP,
+                    IpFragOffset::try_new((i * 6) as u16).unwrap(),
+                    i < 2,
+                    1234
+                ),
+                frag
+            );
+            assert_eq!(
+                &payload_data[24 + i * 48..(24 + i * 48 + 48).min(124)],
+                data
+            );
+        }
+
+        // reassemble
+        let mut pool = Ipv6DefragPool::default();
+        let mut result = None;
+        for fragment in fragments.iter() {
+            result = pool
+                .process(0, &Ipv6Slice::from_slice(fragment).unwrap())
+                .unwrap();
+        }
+        let result = result.unwrap();
+        let (h, rest) = Ipv6Header::from_slice(&result).unwrap();
+        assert_eq!(header.next_header, h.next_header);
+        assert_eq!(&payload_data[..], rest);
+    }
+
+    #[test]
+    fn fragment_ipv6_no_exts() {
+        let payload = payload(40);
+        let fragments = IpFragmenter::new(72, 7)
+            .fragment_ipv6(&ipv6_header(ip_number::UDP), &payload)
+            .unwrap();
+        assert_eq!(2, fragments.len());
+        let (h, rest) = Ipv6Header::from_slice(&fragments[1]).unwrap();
+        assert_eq!(ip_number::IPV6_FRAG, h.next_header);
+        let (frag, data) = Ipv6FragmentHeader::from_slice(rest).unwrap();
+        assert_eq!(
+            Ipv6FragmentHeader::new(ip_number::UDP, IpFragOffset::try_new(3).unwrap(), false, 7),
+            frag
+        );
+        assert_eq!(&payload[24..], data);
+    }
+
+    #[test]
+    fn fragment_ipv6_errors() {
+        use ip_number::*;
+
+        // mtu too small
+        assert_eq!(
+            Err(IpFragError::MtuTooSmall {
+                mtu: 55,
+                min_mtu: 56
+            }),
+            IpFragmenter::new(55, 1).fragment_ipv6(&ipv6_header(UDP), &payload(20))
+        );
+        // already fragmented
+        {
+            let mut data = Ipv6FragmentHeader::new(UDP, IpFragOffset::ZERO, true, 1)
+                .to_bytes()
+                .to_vec();
+            data.extend_from_slice(&payload(64));
+            assert_eq!(
+                Err(IpFragError::AlreadyFragmented),
+                IpFragmenter::new(60, 1).fragment_ipv6(&ipv6_header(IPV6_FRAG), &data)
+            );
+        }
+        // ext header bigger then the data
+        assert_eq!(
+            Err(IpFragError::Len(LenError {
+                required_len: 16,
+                len: 4,
+                len_source: LenSource::Slice,
+                layer: Layer::Ipv6ExtHeader,
+                layer_start_offset: 40,
+            })),
+            IpFragmenter::new(40, 1).fragment_ipv6(&ipv6_header(IPV6_ROUTE), &[UDP.0, 1, 0, 0])
+        );
+        assert_eq!(
+            Err(IpFragError::Len(LenError {
+                required_len: 2,
+                len: 1,
+                len_source: LenSource::Slice,
+                layer: Layer::Ipv6ExtHeader,
+                layer_start_offset: 40,
+            })),
+            IpFragmenter::new(40, 1).fragment_ipv6(&ipv6_header(IPV6_ROUTE), &[UDP.0])
+        );
+        // payload too big
+        assert!(matches!(
+            IpFragmenter::new(1500, 1).fragment_ipv6(&ipv6_header(UDP), &payload(0x10000)),
+            Err(IpFragError::PayloadLen(_))
+        ));
+    }
+
+    #[test]
+    fn fragment_packet_headers() {
+        let builder = PacketBuilder::ipv4([1, 2, 3, 4], [5, 6, 7, 8], 20).udp(1, 2);
+        let payload = payload(100);
+        let mut packet = Vec::with_capacity(builder.size(payload.len()));
+        builder.write(&mut packet, &payload).unwrap();
+        let headers = PacketHeaders::from_ip_slice(&packet).unwrap();
+
+        let fragments = IpFragmenter::new(60, 3)
+            .fragment_packet_headers(&headers)
+            .unwrap();
+        assert_eq!(3, fragments.len());
+
+        let mut pool = Ipv4DefragPool::default();
+        let mut result = None;
+        for fragment in fragments.iter() {
+            result = pool
+                .process(0, &Ipv4Slice::from_slice(fragment).unwrap())
+                .unwrap();
+        }
+        let result = result.unwrap();
+        assert_eq!(
+            &packet[Ipv4Header::MIN_LEN..],
+            &result[Ipv4Header::MIN_LEN..]
+        );
+
+        // ipv6
+        {
+            let builder = PacketBuilder::ipv6([1; 16], [2; 16], 20).udp(1, 2);
+            let mut packet = Vec::with_capacity(builder.size(payload.len()));
+            builder.write(&mut packet, &payload).unwrap();
+            let headers = PacketHeaders::from_ip_slice(&packet).unwrap();
+            let fragments = IpFragmenter::new(80, 3)
+                .fragment_packet_headers(&headers)
+                .unwrap();
+            // 108 bytes: 32 + 32 + 32 + 12
+            assert_eq!(4, fragments.len());
+        }
+
+        // missing ip header
+        {
+            let headers = PacketHeaders {
+                link: None,
+                vlan: None,
+                llc: None,
+                snap: None,
+                mpls: None,
+                pppoe: None,
+                ppp: None,
+                arp: None,
+                ip: None,
+                inner_ip: None,
+                transport: None,
+                payload: &[],
+                link_trailer: &[],
+            };
+            assert_eq!(
+                Some(&IpFragError::MissingIpHeader),
+                IpFragmenter::new(80, 3)
+                    .fragment_packet_headers(&headers)
+                    .unwrap_err()
+                    .ip_frag()
+            );
+        }
+    }
+}
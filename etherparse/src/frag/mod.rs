@@ -0,0 +1,2 @@
+mod ip_fragmenter;
+pub use ip_fragmenter::*;
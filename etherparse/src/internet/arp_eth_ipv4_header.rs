@@ -0,0 +1,130 @@
+use crate::*;
+
+/// ARP packet for resolving IPv4 addresses to Ethernet MAC addresses
+/// (hardware type [`ArpHardwareId::ETHERNET`] & protocol type [`EtherType::IPV4`]).
+///
+/// This is the by far most common type of ARP packet. In contrast to
+/// [`ArpHeader`] all addresses are stored in statically sized arrays.
+/// Use [`ArpHeader::try_eth_ipv4`] or [`ArpPacketSlice::try_eth_ipv4`]
+/// to get this representation from a decoded ARP packet.
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Default)]
+pub struct ArpEthIpv4Header {
+    /// Operation code (e.g. [`ArpOperation::REQUEST`] or [`ArpOperation::REPLY`]).
+    pub operation: ArpOperation,
+
+    /// MAC address of the sender.
+    pub sender_mac: MacAddress,
+
+    /// IPv4 address of the sender.
+    pub sender_ipv4: [u8; 4],
+
+    /// MAC address of the target (set to zero in requests).
+    pub target_mac: MacAddress,
+
+    /// IPv4 address of the target.
+    pub target_ipv4: [u8; 4],
+}
+
+impl ArpEthIpv4Header {
+    /// Serialized size of an ARP packet for IPv4 over Ethernet in bytes/octets.
+    pub const LEN: usize = 28;
+
+    /// Length of the serialized packet in bytes.
+    #[inline]
+    pub fn header_len(&self) -> usize {
+        ArpEthIpv4Header::LEN
+    }
+
+    /// Returns the serialized form of the packet as a statically
+    /// sized byte array.
+    pub fn to_bytes(&self) -> [u8; ArpEthIpv4Header::LEN] {
+        let hw_addr_type_be = ArpHardwareId::ETHERNET.0.to_be_bytes();
+        let proto_addr_type_be = EtherType::IPV4.0.to_be_bytes();
+        let operation_be = self.operation.0.to_be_bytes();
+        let s = &self.sender_mac.0;
+        let sp = &self.sender_ipv4;
+        let t = &self.target_mac.0;
+        let tp = &self.target_ipv4;
+        [
+            hw_addr_type_be[0],
+            hw_addr_type_be[1],
+            proto_addr_type_be[0],
+            proto_addr_type_be[1],
+            6,
+            4,
+            operation_be[0],
+            operation_be[1],
+            s[0],
+            s[1],
+            s[2],
+            s[3],
+            s[4],
+            s[5],
+            sp[0],
+            sp[1],
+            sp[2],
+            sp[3],
+            t[0],
+            t[1],
+            t[2],
+            t[3],
+            t[4],
+            t[5],
+            tp[0],
+            tp[1],
+            tp[2],
+            tp[3],
+        ]
+    }
+
+    /// Converts the packet into an [`ArpHeader`] with variable
+    /// length addresses.
+    #[inline]
+    pub fn to_header(&self) -> ArpHeader {
+        self.clone().into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_gens::*;
+    use alloc::format;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn to_bytes_to_header(input in arp_eth_ipv4_any()) {
+            let bytes = input.to_bytes();
+            assert_eq!(input.header_len(), bytes.len());
+
+            let header = input.to_header();
+            assert_eq!(&header.to_bytes()[..], &bytes[..]);
+            assert_eq!(ArpHardwareId::ETHERNET, header.hw_addr_type);
+            assert_eq!(EtherType::IPV4, header.proto_addr_type);
+            assert_eq!(input.operation, header.operation);
+            assert_eq!(&input.sender_mac.0, header.sender_hw_addr());
+            assert_eq!(&input.sender_ipv4, header.sender_protocol_addr());
+            assert_eq!(&input.target_mac.0, header.target_hw_addr());
+            assert_eq!(&input.target_ipv4, header.target_protocol_addr());
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn clone_eq_dbg(input in arp_eth_ipv4_any()) {
+            assert_eq!(input, input.clone());
+            assert_eq!(
+                format!(
+                    "ArpEthIpv4Header {{ operation: {:?}, sender_mac: {:?}, sender_ipv4: {:?}, target_mac: {:?}, target_ipv4: {:?} }}",
+                    input.operation,
+                    input.sender_mac,
+                    input.sender_ipv4,
+                    input.target_mac,
+                    input.target_ipv4,
+                ),
+                format!("{:?}", input)
+            );
+        }
+    }
+}
@@ -0,0 +1,721 @@
+use crate::{
+    err::{arp::AddrLenError, Layer, SliceWriteSpaceError},
+    *,
+};
+use arrayvec::ArrayVec;
+use core::fmt::{Debug, Formatter};
+
+/// Address Resolution Protocol packet (RFC 826) with variable length
+/// hardware & protocol addresses.
+///
+/// For the most common case of IPv4 addresses over Ethernet also
+/// [`ArpEthIpv4Header`] can be used, which stores the addresses in
+/// statically sized arrays.
+#[derive(Clone)]
+pub struct ArpHeader {
+    /// Hardware address space (e.g. [`ArpHardwareId::ETHERNET`]).
+    pub hw_addr_type: ArpHardwareId,
+
+    /// Protocol address space (e.g. [`EtherType::IPV4`]).
+    pub proto_addr_type: EtherType,
+
+    /// Operation code (e.g. [`ArpOperation::REQUEST`] or [`ArpOperation::REPLY`]).
+    pub operation: ArpOperation,
+
+    /// Length of each hardware address in bytes/octets.
+    hw_addr_size: u8,
+
+    /// Length of each protocol address in bytes/octets.
+    protocol_addr_size: u8,
+
+    /// Buffer containing the sender hardware address (only the first
+    /// `hw_addr_size` bytes are used).
+    sender_hw_addr: [u8; 0xff],
+
+    /// Buffer containing the sender protocol address (only the first
+    /// `protocol_addr_size` bytes are used).
+    sender_protocol_addr: [u8; 0xff],
+
+    /// Buffer containing the target hardware address (only the first
+    /// `hw_addr_size` bytes are used).
+    target_hw_addr: [u8; 0xff],
+
+    /// Buffer containing the target protocol address (only the first
+    /// `protocol_addr_size` bytes are used).
+    target_protocol_addr: [u8; 0xff],
+}
+
+impl Debug for ArpHeader {
+    fn fmt(&self, formatter: &mut Formatter) -> Result<(), core::fmt::Error> {
+        let mut s = formatter.debug_struct("ArpHeader");
+        s.field("hw_addr_type", &self.hw_addr_type);
+        s.field("proto_addr_type", &self.proto_addr_type);
+        s.field("operation", &self.operation);
+        s.field("sender_hw_addr", &self.sender_hw_addr());
+        s.field("sender_protocol_addr", &self.sender_protocol_addr());
+        s.field("target_hw_addr", &self.target_hw_addr());
+        s.field("target_protocol_addr", &self.target_protocol_addr());
+        s.finish()
+    }
+}
+
+impl PartialEq for ArpHeader {
+    fn eq(&self, other: &Self) -> bool {
+        self.hw_addr_type == other.hw_addr_type
+            && self.proto_addr_type == other.proto_addr_type
+            && self.operation == other.operation
+            && self.sender_hw_addr() == other.sender_hw_addr()
+            && self.sender_protocol_addr() == other.sender_protocol_addr()
+            && self.target_hw_addr() == other.target_hw_addr()
+            && self.target_protocol_addr() == other.target_protocol_addr()
+    }
+}
+
+impl Eq for ArpHeader {}
+
+impl ArpHeader {
+    /// Minimum length of an ARP packet in bytes/octets (length without
+    /// any addresses).
+    pub const MIN_LEN: usize = 8;
+
+    /// Maximum length of an ARP packet in bytes/octets.
+    ///
+    /// This number is calculated by taking the minimum length and
+    /// adding two times the maximum hardware address length (0xff) and
+    /// two times the maximum protocol address length (0xff).
+    pub const MAX_LEN: usize = 8 + 4 * 0xff;
+
+    /// Creates a new ARP packet with the given addresses.
+    ///
+    /// The sender & target hardware addresses as well as the sender &
+    /// target protocol addresses must have matching lengths (ARP only
+    /// contains one length field for each address type). Additionally
+    /// each address can at most be 255 bytes long. If these requirements
+    /// are not fullfilled an [`crate::err::arp::AddrLenError`] is returned.
+    pub fn new(
+        hw_addr_type: ArpHardwareId,
+        proto_addr_type: EtherType,
+        operation: ArpOperation,
+        sender_hw_addr: &[u8],
+        sender_protocol_addr: &[u8],
+        target_hw_addr: &[u8],
+        target_protocol_addr: &[u8],
+    ) -> Result<ArpHeader, AddrLenError> {
+        Self::check_hw_addrs(sender_hw_addr, target_hw_addr)?;
+        Self::check_protocol_addrs(sender_protocol_addr, target_protocol_addr)?;
+        // SAFETY: Safe as the address lengths were verified above.
+        Ok(unsafe {
+            Self::new_unchecked(
+                hw_addr_type,
+                proto_addr_type,
+                operation,
+                sender_hw_addr,
+                sender_protocol_addr,
+                target_hw_addr,
+                target_protocol_addr,
+            )
+        })
+    }
+
+    /// Creates a new ARP packet without checking the address lengths.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the sender & target hardware addresses
+    /// have the same length, that the sender & target protocol addresses
+    /// have the same length and that no address is longer then 255 bytes.
+    pub(crate) unsafe fn new_unchecked(
+        hw_addr_type: ArpHardwareId,
+        proto_addr_type: EtherType,
+        operation: ArpOperation,
+        sender_hw_addr: &[u8],
+        sender_protocol_addr: &[u8],
+        target_hw_addr: &[u8],
+        target_protocol_addr: &[u8],
+    ) -> ArpHeader {
+        debug_assert!(sender_hw_addr.len() == target_hw_addr.len());
+        debug_assert!(sender_protocol_addr.len() == target_protocol_addr.len());
+        debug_assert!(sender_hw_addr.len() <= 0xff);
+        debug_assert!(sender_protocol_addr.len() <= 0xff);
+
+        let mut result = ArpHeader {
+            hw_addr_type,
+            proto_addr_type,
+            operation,
+            hw_addr_size: sender_hw_addr.len() as u8,
+            protocol_addr_size: sender_protocol_addr.len() as u8,
+            sender_hw_addr: [0; 0xff],
+            sender_protocol_addr: [0; 0xff],
+            target_hw_addr: [0; 0xff],
+            target_protocol_addr: [0; 0xff],
+        };
+        result.sender_hw_addr[..sender_hw_addr.len()].copy_from_slice(sender_hw_addr);
+        result.sender_protocol_addr[..sender_protocol_addr.len()]
+            .copy_from_slice(sender_protocol_addr);
+        result.target_hw_addr[..target_hw_addr.len()].copy_from_slice(target_hw_addr);
+        result.target_protocol_addr[..target_protocol_addr.len()]
+            .copy_from_slice(target_protocol_addr);
+        result
+    }
+
+    fn check_hw_addrs(sender: &[u8], target: &[u8]) -> Result<(), AddrLenError> {
+        use AddrLenError::*;
+        if sender.len() != target.len() {
+            Err(HwAddrLenNonMatching {
+                sender_len: sender.len(),
+                target_len: target.len(),
+            })
+        } else if sender.len() > 0xff {
+            Err(HwAddrLenTooBig(sender.len()))
+        } else {
+            Ok(())
+        }
+    }
+
+    fn check_protocol_addrs(sender: &[u8], target: &[u8]) -> Result<(), AddrLenError> {
+        use AddrLenError::*;
+        if sender.len() != target.len() {
+            Err(ProtocolAddrLenNonMatching {
+                sender_len: sender.len(),
+                target_len: target.len(),
+            })
+        } else if sender.len() > 0xff {
+            Err(ProtocolAddrLenTooBig(sender.len()))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Read an ARP packet from a slice and return the packet & unused parts
+    /// of the slice (e.g. ethernet padding).
+    #[inline]
+    pub fn from_slice(slice: &[u8]) -> Result<(ArpHeader, &[u8]), err::LenError> {
+        let s = ArpPacketSlice::from_slice(slice)?;
+        Ok((s.to_header(), &slice[s.slice().len()..]))
+    }
+
+    /// Reads an ARP packet from the current position of the read argument.
+    #[cfg(feature = "std")]
+    pub fn read<T: std::io::Read + Sized>(reader: &mut T) -> Result<ArpHeader, std::io::Error> {
+        let start = {
+            let mut start = [0u8; ArpHeader::MIN_LEN];
+            reader.read_exact(&mut start)?;
+            start
+        };
+
+        let hw_addr_size = usize::from(start[4]);
+        let protocol_addr_size = usize::from(start[5]);
+
+        let mut result = ArpHeader {
+            hw_addr_type: ArpHardwareId(u16::from_be_bytes([start[0], start[1]])),
+            proto_addr_type: EtherType(u16::from_be_bytes([start[2], start[3]])),
+            operation: ArpOperation(u16::from_be_bytes([start[6], start[7]])),
+            hw_addr_size: start[4],
+            protocol_addr_size: start[5],
+            sender_hw_addr: [0; 0xff],
+            sender_protocol_addr: [0; 0xff],
+            target_hw_addr: [0; 0xff],
+            target_protocol_addr: [0; 0xff],
+        };
+        reader.read_exact(&mut result.sender_hw_addr[..hw_addr_size])?;
+        reader.read_exact(&mut result.sender_protocol_addr[..protocol_addr_size])?;
+        reader.read_exact(&mut result.target_hw_addr[..hw_addr_size])?;
+        reader.read_exact(&mut result.target_protocol_addr[..protocol_addr_size])?;
+        Ok(result)
+    }
+
+    /// Length of each hardware address in bytes/octets.
+    #[inline]
+    pub fn hw_addr_size(&self) -> u8 {
+        self.hw_addr_size
+    }
+
+    /// Length of each protocol address in bytes/octets.
+    #[inline]
+    pub fn protocol_addr_size(&self) -> u8 {
+        self.protocol_addr_size
+    }
+
+    /// Sender hardware address (e.g. MAC address).
+    #[inline]
+    pub fn sender_hw_addr(&self) -> &[u8] {
+        &self.sender_hw_addr[..usize::from(self.hw_addr_size)]
+    }
+
+    /// Sender protocol address (e.g. IPv4 address).
+    #[inline]
+    pub fn sender_protocol_addr(&self) -> &[u8] {
+        &self.sender_protocol_addr[..usize::from(self.protocol_addr_size)]
+    }
+
+    /// Target hardware address (e.g. MAC address).
+    #[inline]
+    pub fn target_hw_addr(&self) -> &[u8] {
+        &self.target_hw_addr[..usize::from(self.hw_addr_size)]
+    }
+
+    /// Target protocol address (e.g. IPv4 address).
+    #[inline]
+    pub fn target_protocol_addr(&self) -> &[u8] {
+        &self.target_protocol_addr[..usize::from(self.protocol_addr_size)]
+    }
+
+    /// Sets the sender & target hardware addresses.
+    ///
+    /// Both addresses must have the same length and can at most be
+    /// 255 bytes long. If this is not the case the addresses are not
+    /// copied and an [`crate::err::arp::AddrLenError`] is returned.
+    pub fn set_hw_addrs(&mut self, sender: &[u8], target: &[u8]) -> Result<(), AddrLenError> {
+        Self::check_hw_addrs(sender, target)?;
+        self.sender_hw_addr[..sender.len()].copy_from_slice(sender);
+        self.target_hw_addr[..target.len()].copy_from_slice(target);
+        self.hw_addr_size = sender.len() as u8;
+        Ok(())
+    }
+
+    /// Sets the sender & target protocol addresses.
+    ///
+    /// Both addresses must have the same length and can at most be
+    /// 255 bytes long. If this is not the case the addresses are not
+    /// copied and an [`crate::err::arp::AddrLenError`] is returned.
+    pub fn set_protocol_addrs(&mut self, sender: &[u8], target: &[u8]) -> Result<(), AddrLenError> {
+        Self::check_protocol_addrs(sender, target)?;
+        self.sender_protocol_addr[..sender.len()].copy_from_slice(sender);
+        self.target_protocol_addr[..target.len()].copy_from_slice(target);
+        self.protocol_addr_size = sender.len() as u8;
+        Ok(())
+    }
+
+    /// Returns the operation & addresses in an [`ArpEthIpv4Header`] if the
+    /// packet contains IPv4 addresses over Ethernet (hardware type
+    /// [`ArpHardwareId::ETHERNET`], protocol type [`EtherType::IPV4`] and
+    /// the matching address sizes 6 & 4). Otherwise `None` is returned.
+    pub fn try_eth_ipv4(&self) -> Option<ArpEthIpv4Header> {
+        if self.hw_addr_type == ArpHardwareId::ETHERNET
+            && self.proto_addr_type == EtherType::IPV4
+            && 6 == self.hw_addr_size
+            && 4 == self.protocol_addr_size
+        {
+            let s = &self.sender_hw_addr;
+            let t = &self.target_hw_addr;
+            let sp = &self.sender_protocol_addr;
+            let tp = &self.target_protocol_addr;
+            Some(ArpEthIpv4Header {
+                operation: self.operation,
+                sender_mac: MacAddress([s[0], s[1], s[2], s[3], s[4], s[5]]),
+                sender_ipv4: [sp[0], sp[1], sp[2], sp[3]],
+                target_mac: MacAddress([t[0], t[1], t[2], t[3], t[4], t[5]]),
+                target_ipv4: [tp[0], tp[1], tp[2], tp[3]],
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Serialize the packet to a given slice. Returns the unused part of the slice.
+    pub fn write_to_slice<'a>(
+        &self,
+        slice: &'a mut [u8],
+    ) -> Result<&'a mut [u8], SliceWriteSpaceError> {
+        let len = self.header_len();
+        if slice.len() < len {
+            Err(SliceWriteSpaceError {
+                required_len: len,
+                len: slice.len(),
+                layer: Layer::ArpPacket,
+                layer_start_offset: 0,
+            })
+        } else {
+            slice[..len].copy_from_slice(&self.to_bytes());
+            Ok(&mut slice[len..])
+        }
+    }
+
+    /// Writes the ARP packet to the current position of the write argument.
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn write<T: std::io::Write + Sized>(&self, writer: &mut T) -> Result<(), std::io::Error> {
+        writer.write_all(&self.to_bytes())
+    }
+
+    /// Length of the serialized packet in bytes.
+    #[inline]
+    pub fn header_len(&self) -> usize {
+        ArpHeader::MIN_LEN
+            + 2 * (usize::from(self.hw_addr_size) + usize::from(self.protocol_addr_size))
+    }
+
+    /// Returns the serialized packet.
+    pub fn to_bytes(&self) -> ArrayVec<u8, { ArpHeader::MAX_LEN }> {
+        let hw_addr_type_be = self.hw_addr_type.0.to_be_bytes();
+        let proto_addr_type_be = self.proto_addr_type.0.to_be_bytes();
+        let operation_be = self.operation.0.to_be_bytes();
+
+        let mut result = ArrayVec::<u8, { ArpHeader::MAX_LEN }>::new();
+        result.extend([
+            hw_addr_type_be[0],
+            hw_addr_type_be[1],
+            proto_addr_type_be[0],
+            proto_addr_type_be[1],
+            self.hw_addr_size,
+            self.protocol_addr_size,
+            operation_be[0],
+            operation_be[1],
+        ]);
+        // the address lengths are limited to 255 by their u8 length fields
+        // so the result can not exceed ArpHeader::MAX_LEN
+        result.try_extend_from_slice(self.sender_hw_addr()).unwrap();
+        result
+            .try_extend_from_slice(self.sender_protocol_addr())
+            .unwrap();
+        result.try_extend_from_slice(self.target_hw_addr()).unwrap();
+        result
+            .try_extend_from_slice(self.target_protocol_addr())
+            .unwrap();
+        result
+    }
+}
+
+impl From<ArpEthIpv4Header> for ArpHeader {
+    fn from(value: ArpEthIpv4Header) -> Self {
+        // SAFETY: Safe as the address lengths are matching (6 & 4).
+        unsafe {
+            ArpHeader::new_unchecked(
+                ArpHardwareId::ETHERNET,
+                EtherType::IPV4,
+                value.operation,
+                &value.sender_mac.0,
+                &value.sender_ipv4,
+                &value.target_mac.0,
+                &value.target_ipv4,
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_gens::*;
+    use alloc::{format, vec, vec::Vec};
+    use proptest::prelude::*;
+    use std::io::{Cursor, ErrorKind};
+
+    proptest! {
+        #[test]
+        fn new(input in arp_any()) {
+            use AddrLenError::*;
+
+            // ok
+            assert_eq!(
+                input,
+                ArpHeader::new(
+                    input.hw_addr_type,
+                    input.proto_addr_type,
+                    input.operation,
+                    input.sender_hw_addr(),
+                    input.sender_protocol_addr(),
+                    input.target_hw_addr(),
+                    input.target_protocol_addr(),
+                ).unwrap()
+            );
+
+            // hw address lengths non matching
+            assert_eq!(
+                HwAddrLenNonMatching {
+                    sender_len: input.sender_hw_addr().len(),
+                    target_len: input.target_hw_addr().len() + 1
+                },
+                ArpHeader::new(
+                    input.hw_addr_type,
+                    input.proto_addr_type,
+                    input.operation,
+                    input.sender_hw_addr(),
+                    input.sender_protocol_addr(),
+                    &vec![0; input.target_hw_addr().len() + 1],
+                    input.target_protocol_addr(),
+                ).unwrap_err()
+            );
+
+            // protocol address lengths non matching
+            assert_eq!(
+                ProtocolAddrLenNonMatching {
+                    sender_len: input.sender_protocol_addr().len() + 1,
+                    target_len: input.target_protocol_addr().len()
+                },
+                ArpHeader::new(
+                    input.hw_addr_type,
+                    input.proto_addr_type,
+                    input.operation,
+                    input.sender_hw_addr(),
+                    &vec![0; input.sender_protocol_addr().len() + 1],
+                    input.target_hw_addr(),
+                    input.target_protocol_addr(),
+                ).unwrap_err()
+            );
+        }
+    }
+
+    #[test]
+    fn new_too_big() {
+        use AddrLenError::*;
+        let big = [0u8; 256];
+        assert_eq!(
+            HwAddrLenTooBig(256),
+            ArpHeader::new(
+                ArpHardwareId::ETHERNET,
+                EtherType::IPV4,
+                ArpOperation::REQUEST,
+                &big,
+                &[],
+                &big,
+                &[]
+            )
+            .unwrap_err()
+        );
+        assert_eq!(
+            ProtocolAddrLenTooBig(256),
+            ArpHeader::new(
+                ArpHardwareId::ETHERNET,
+                EtherType::IPV4,
+                ArpOperation::REQUEST,
+                &[],
+                &big,
+                &[],
+                &big
+            )
+            .unwrap_err()
+        );
+        // maximum size
+        let max = ArpHeader::new(
+            ArpHardwareId::ETHERNET,
+            EtherType::IPV4,
+            ArpOperation::REQUEST,
+            &big[..255],
+            &big[..255],
+            &big[..255],
+            &big[..255],
+        )
+        .unwrap();
+        assert_eq!(ArpHeader::MAX_LEN, max.header_len());
+        assert_eq!(ArpHeader::MAX_LEN, max.to_bytes().len());
+    }
+
+    proptest! {
+        #[test]
+        fn set_addrs(
+            input in arp_any(),
+            hw_addr in proptest::collection::vec(any::<u8>(), 0..20),
+            protocol_addr in proptest::collection::vec(any::<u8>(), 0..20),
+        ) {
+            use AddrLenError::*;
+
+            // ok
+            {
+                let mut header = input.clone();
+                header.set_hw_addrs(&hw_addr, &hw_addr).unwrap();
+                header.set_protocol_addrs(&protocol_addr, &protocol_addr).unwrap();
+                assert_eq!(hw_addr.len() as u8, header.hw_addr_size());
+                assert_eq!(protocol_addr.len() as u8, header.protocol_addr_size());
+                assert_eq!(&hw_addr[..], header.sender_hw_addr());
+                assert_eq!(&hw_addr[..], header.target_hw_addr());
+                assert_eq!(&protocol_addr[..], header.sender_protocol_addr());
+                assert_eq!(&protocol_addr[..], header.target_protocol_addr());
+            }
+
+            // errors
+            {
+                let mut header = input.clone();
+                assert_eq!(
+                    HwAddrLenNonMatching{ sender_len: hw_addr.len(), target_len: hw_addr.len() + 1 },
+                    header.set_hw_addrs(&hw_addr, &vec![0; hw_addr.len() + 1]).unwrap_err()
+                );
+                assert_eq!(
+                    ProtocolAddrLenNonMatching{ sender_len: protocol_addr.len(), target_len: protocol_addr.len() + 1 },
+                    header.set_protocol_addrs(&protocol_addr, &vec![0; protocol_addr.len() + 1]).unwrap_err()
+                );
+                assert_eq!(
+                    HwAddrLenTooBig(256),
+                    header.set_hw_addrs(&[0; 256], &[0; 256]).unwrap_err()
+                );
+                assert_eq!(
+                    ProtocolAddrLenTooBig(256),
+                    header.set_protocol_addrs(&[0; 256], &[0; 256]).unwrap_err()
+                );
+                // nothing changed
+                assert_eq!(input, header);
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn from_slice(
+            input in arp_any(),
+            dummy_data in proptest::collection::vec(any::<u8>(), 0..20)
+        ) {
+            let mut buffer: Vec<u8> = Vec::with_capacity(input.header_len() + dummy_data.len());
+            input.write(&mut buffer).unwrap();
+            buffer.extend(&dummy_data[..]);
+
+            // ok
+            {
+                let (result, rest) = ArpHeader::from_slice(&buffer).unwrap();
+                assert_eq!(input, result);
+                assert_eq!(&buffer[input.header_len()..], rest);
+            }
+
+            // len error
+            for len in 0..input.header_len() {
+                assert_eq!(
+                    ArpHeader::from_slice(&buffer[..len]).unwrap_err(),
+                    ArpPacketSlice::from_slice(&buffer[..len]).unwrap_err()
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn read(
+            input in arp_any(),
+            dummy_data in proptest::collection::vec(any::<u8>(), 0..20)
+        ) {
+            let mut buffer: Vec<u8> = Vec::with_capacity(input.header_len() + dummy_data.len());
+            input.write(&mut buffer).unwrap();
+            buffer.extend(&dummy_data[..]);
+
+            // ok
+            {
+                let mut cursor = Cursor::new(&buffer);
+                let result = ArpHeader::read(&mut cursor).unwrap();
+                assert_eq!(input, result);
+                assert_eq!(cursor.position(), input.header_len() as u64);
+            }
+
+            // unexpected eof
+            for len in 0..input.header_len() {
+                let mut cursor = Cursor::new(&buffer[..len]);
+                assert_eq!(
+                    ArpHeader::read(&mut cursor).unwrap_err().kind(),
+                    ErrorKind::UnexpectedEof
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn write_to_slice(input in arp_any()) {
+            // normal write
+            {
+                let mut buffer = [0u8; ArpHeader::MAX_LEN];
+                let rest_len = input.write_to_slice(&mut buffer).unwrap().len();
+                assert_eq!(ArpHeader::MAX_LEN - input.header_len(), rest_len);
+                assert_eq!(&buffer[..input.header_len()], &input.to_bytes()[..]);
+            }
+            // len too small
+            for len in 0..input.header_len() {
+                let mut buffer = [0u8; ArpHeader::MAX_LEN];
+                assert_eq!(
+                    SliceWriteSpaceError {
+                        required_len: input.header_len(),
+                        len,
+                        layer: Layer::ArpPacket,
+                        layer_start_offset: 0,
+                    },
+                    input.write_to_slice(&mut buffer[..len]).unwrap_err()
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn write(input in arp_any()) {
+            // successfull write
+            {
+                let mut buffer: Vec<u8> = Vec::with_capacity(input.header_len());
+                input.write(&mut buffer).unwrap();
+                assert_eq!(&buffer[..], &input.to_bytes()[..]);
+            }
+
+            // not enough memory for write (unexpected eof)
+            for len in 0..input.header_len() {
+                let mut buffer = [0u8; ArpHeader::MAX_LEN];
+                let mut writer = Cursor::new(&mut buffer[..len]);
+                assert!(input.write(&mut writer).is_err());
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn header_len_to_bytes(input in arp_any()) {
+            let bytes = input.to_bytes();
+            assert_eq!(input.header_len(), bytes.len());
+            assert_eq!(
+                input.header_len(),
+                8 + 2*(input.sender_hw_addr().len() + input.sender_protocol_addr().len())
+            );
+            assert_eq!(&input.hw_addr_type.0.to_be_bytes(), &bytes[0..2]);
+            assert_eq!(&input.proto_addr_type.0.to_be_bytes(), &bytes[2..4]);
+            assert_eq!(input.hw_addr_size(), bytes[4]);
+            assert_eq!(input.protocol_addr_size(), bytes[5]);
+            assert_eq!(&input.operation.0.to_be_bytes(), &bytes[6..8]);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn try_eth_ipv4(input in arp_eth_ipv4_any()) {
+            let header: ArpHeader = input.clone().into();
+            assert_eq!(Some(input.clone()), header.try_eth_ipv4());
+
+            // hardware type
+            {
+                let mut header = header.clone();
+                header.hw_addr_type = ArpHardwareId::IEEE802;
+                assert_eq!(None, header.try_eth_ipv4());
+            }
+            // protocol type
+            {
+                let mut header = header.clone();
+                header.proto_addr_type = EtherType::IPV6;
+                assert_eq!(None, header.try_eth_ipv4());
+            }
+            // hardware address size
+            {
+                let mut header = header.clone();
+                header.set_hw_addrs(&[1,2,3,4,5], &[6,7,8,9,10]).unwrap();
+                assert_eq!(None, header.try_eth_ipv4());
+            }
+            // protocol address size
+            {
+                let mut header = header.clone();
+                header.set_protocol_addrs(&[1,2,3], &[4,5,6]).unwrap();
+                assert_eq!(None, header.try_eth_ipv4());
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn clone_eq_dbg(input in arp_any()) {
+            assert_eq!(input, input.clone());
+            assert_eq!(
+                format!(
+                    "ArpHeader {{ hw_addr_type: {:?}, proto_addr_type: {:?}, operation: {:?}, sender_hw_addr: {:?}, sender_protocol_addr: {:?}, target_hw_addr: {:?}, target_protocol_addr: {:?} }}",
+                    input.hw_addr_type,
+                    input.proto_addr_type,
+                    input.operation,
+                    input.sender_hw_addr(),
+                    input.sender_protocol_addr(),
+                    input.target_hw_addr(),
+                    input.target_protocol_addr(),
+                ),
+                format!("{:?}", input)
+            );
+        }
+    }
+}
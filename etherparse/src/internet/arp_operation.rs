@@ -0,0 +1,103 @@
+/// Operation code of an ARP packet (e.g. request or reply).
+///
+/// You can access the underlying `u16` value by using `.0` and any `u16`
+/// can be converted to an `ArpOperation`:
+///
+/// ```
+/// use etherparse::ArpOperation;
+///
+/// assert_eq!(ArpOperation::REQUEST.0, 1);
+/// assert_eq!(ArpOperation::REQUEST, ArpOperation(1));
+///
+/// // convert to ArpOperation using the from & into trait
+/// let op: ArpOperation = 2.into();
+/// assert_eq!(ArpOperation::REPLY, op);
+///
+/// // convert to u16 using the from & into trait
+/// let num: u16 = ArpOperation::REPLY.into();
+/// assert_eq!(2, num);
+/// ```
+#[derive(Default, PartialEq, Eq, Clone, Copy, Hash, Ord, PartialOrd)]
+pub struct ArpOperation(pub u16);
+
+impl ArpOperation {
+    /// ARP request \[[RFC826](https://datatracker.ietf.org/doc/html/rfc826)\]
+    pub const REQUEST: ArpOperation = Self(1);
+    /// ARP reply \[[RFC826](https://datatracker.ietf.org/doc/html/rfc826)\]
+    pub const REPLY: ArpOperation = Self(2);
+    /// Reverse ARP request \[[RFC903](https://datatracker.ietf.org/doc/html/rfc903)\]
+    pub const REVERSE_REQUEST: ArpOperation = Self(3);
+    /// Reverse ARP reply \[[RFC903](https://datatracker.ietf.org/doc/html/rfc903)\]
+    pub const REVERSE_REPLY: ArpOperation = Self(4);
+}
+
+impl From<u16> for ArpOperation {
+    #[inline]
+    fn from(val: u16) -> Self {
+        ArpOperation(val)
+    }
+}
+
+impl From<ArpOperation> for u16 {
+    #[inline]
+    fn from(val: ArpOperation) -> Self {
+        val.0
+    }
+}
+
+impl core::fmt::Debug for ArpOperation {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match *self {
+            Self::REQUEST => write!(f, "{} (ARP Request)", self.0),
+            Self::REPLY => write!(f, "{} (ARP Reply)", self.0),
+            Self::REVERSE_REQUEST => write!(f, "{} (Reverse ARP Request)", self.0),
+            Self::REVERSE_REPLY => write!(f, "{} (Reverse ARP Reply)", self.0),
+            _ => write!(f, "{}", self.0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::format;
+
+    #[test]
+    fn to_u16() {
+        assert_eq!(1, u16::from(ArpOperation::REQUEST));
+        assert_eq!(2, u16::from(ArpOperation::REPLY));
+        assert_eq!(3, u16::from(ArpOperation::REVERSE_REQUEST));
+        assert_eq!(4, u16::from(ArpOperation::REVERSE_REPLY));
+    }
+
+    #[test]
+    fn from_u16() {
+        assert_eq!(ArpOperation::from(1), ArpOperation::REQUEST);
+        assert_eq!(ArpOperation::from(2), ArpOperation::REPLY);
+        assert_eq!(ArpOperation::from(3), ArpOperation::REVERSE_REQUEST);
+        assert_eq!(ArpOperation::from(4), ArpOperation::REVERSE_REPLY);
+        assert_eq!(ArpOperation::from(0x1234), ArpOperation(0x1234));
+    }
+
+    #[test]
+    fn dbg() {
+        let pairs = &[
+            (ArpOperation::REQUEST, "1 (ARP Request)"),
+            (ArpOperation::REPLY, "2 (ARP Reply)"),
+            (ArpOperation::REVERSE_REQUEST, "3 (Reverse ARP Request)"),
+            (ArpOperation::REVERSE_REPLY, "4 (Reverse ARP Reply)"),
+            (ArpOperation(0x1234), "4660"),
+        ];
+
+        for (input, expected) in pairs {
+            assert_eq!(expected, &format!("{:?}", input));
+        }
+    }
+
+    #[test]
+    fn clone_eq_default() {
+        let value = ArpOperation::REPLY;
+        assert_eq!(value, value.clone());
+        assert_eq!(ArpOperation(0), ArpOperation::default());
+    }
+}
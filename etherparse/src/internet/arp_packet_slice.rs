@@ -0,0 +1,314 @@
+use crate::*;
+use core::slice::from_raw_parts;
+
+/// Slice containing an ARP packet (header and the sender & target addresses).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ArpPacketSlice<'a> {
+    slice: &'a [u8],
+}
+
+impl<'a> ArpPacketSlice<'a> {
+    /// Creates an ARP packet slice from an other slice.
+    ///
+    /// The resulting slice only contains the bytes described by the
+    /// hardware & protocol address length fields. Any data after that
+    /// (e.g. ethernet padding) is not part of the returned slice.
+    pub fn from_slice(slice: &'a [u8]) -> Result<ArpPacketSlice<'a>, err::LenError> {
+        // check the length of the fixed part
+        if slice.len() < ArpHeader::MIN_LEN {
+            return Err(err::LenError {
+                required_len: ArpHeader::MIN_LEN,
+                len: slice.len(),
+                len_source: err::LenSource::Slice,
+                layer: err::Layer::ArpPacket,
+                layer_start_offset: 0,
+            });
+        }
+
+        // check the length including the addresses
+        let packet_len = ArpHeader::MIN_LEN + 2 * (usize::from(slice[4]) + usize::from(slice[5]));
+        if slice.len() < packet_len {
+            return Err(err::LenError {
+                required_len: packet_len,
+                len: slice.len(),
+                len_source: err::LenSource::Slice,
+                layer: err::Layer::ArpPacket,
+                layer_start_offset: 0,
+            });
+        }
+
+        Ok(ArpPacketSlice {
+            // SAFETY:
+            // Safe as the slice length was verified above to be at
+            // least packet_len.
+            slice: unsafe { from_raw_parts(slice.as_ptr(), packet_len) },
+        })
+    }
+
+    /// Returns the slice containing the ARP packet.
+    #[inline]
+    pub fn slice(&self) -> &'a [u8] {
+        self.slice
+    }
+
+    /// Read the hardware address space (e.g. [`ArpHardwareId::ETHERNET`]).
+    #[inline]
+    pub fn hw_addr_type(&self) -> ArpHardwareId {
+        // SAFETY:
+        // Safe as the contructor checks that the slice has
+        // at least the length of ArpHeader::MIN_LEN (8).
+        ArpHardwareId(unsafe { get_unchecked_be_u16(self.slice.as_ptr()) })
+    }
+
+    /// Read the protocol address space (e.g. [`EtherType::IPV4`]).
+    #[inline]
+    pub fn proto_addr_type(&self) -> EtherType {
+        // SAFETY:
+        // Safe as the contructor checks that the slice has
+        // at least the length of ArpHeader::MIN_LEN (8).
+        EtherType(unsafe { get_unchecked_be_u16(self.slice.as_ptr().add(2)) })
+    }
+
+    /// Read the length of each hardware address in bytes/octets.
+    #[inline]
+    pub fn hw_addr_size(&self) -> u8 {
+        // SAFETY:
+        // Safe as the contructor checks that the slice has
+        // at least the length of ArpHeader::MIN_LEN (8).
+        unsafe { *self.slice.get_unchecked(4) }
+    }
+
+    /// Read the length of each protocol address in bytes/octets.
+    #[inline]
+    pub fn protocol_addr_size(&self) -> u8 {
+        // SAFETY:
+        // Safe as the contructor checks that the slice has
+        // at least the length of ArpHeader::MIN_LEN (8).
+        unsafe { *self.slice.get_unchecked(5) }
+    }
+
+    /// Read the operation code (e.g. [`ArpOperation::REQUEST`]).
+    #[inline]
+    pub fn operation(&self) -> ArpOperation {
+        // SAFETY:
+        // Safe as the contructor checks that the slice has
+        // at least the length of ArpHeader::MIN_LEN (8).
+        ArpOperation(unsafe { get_unchecked_be_u16(self.slice.as_ptr().add(6)) })
+    }
+
+    /// Sender hardware address (e.g. MAC address).
+    #[inline]
+    pub fn sender_hw_addr(&self) -> &'a [u8] {
+        let start = ArpHeader::MIN_LEN;
+        let len = usize::from(self.hw_addr_size());
+        // SAFETY:
+        // Safe as the contructor checks that the slice has
+        // the length 8 + 2*(hw_addr_size + protocol_addr_size).
+        unsafe { from_raw_parts(self.slice.as_ptr().add(start), len) }
+    }
+
+    /// Sender protocol address (e.g. IPv4 address).
+    #[inline]
+    pub fn sender_protocol_addr(&self) -> &'a [u8] {
+        let start = ArpHeader::MIN_LEN + usize::from(self.hw_addr_size());
+        let len = usize::from(self.protocol_addr_size());
+        // SAFETY:
+        // Safe as the contructor checks that the slice has
+        // the length 8 + 2*(hw_addr_size + protocol_addr_size).
+        unsafe { from_raw_parts(self.slice.as_ptr().add(start), len) }
+    }
+
+    /// Target hardware address (e.g. MAC address).
+    #[inline]
+    pub fn target_hw_addr(&self) -> &'a [u8] {
+        let start = ArpHeader::MIN_LEN
+            + usize::from(self.hw_addr_size())
+            + usize::from(self.protocol_addr_size());
+        let len = usize::from(self.hw_addr_size());
+        // SAFETY:
+        // Safe as the contructor checks that the slice has
+        // the length 8 + 2*(hw_addr_size + protocol_addr_size).
+        unsafe { from_raw_parts(self.slice.as_ptr().add(start), len) }
+    }
+
+    /// Target protocol address (e.g. IPv4 address).
+    #[inline]
+    pub fn target_protocol_addr(&self) -> &'a [u8] {
+        let start = ArpHeader::MIN_LEN
+            + 2 * usize::from(self.hw_addr_size())
+            + usize::from(self.protocol_addr_size());
+        let len = usize::from(self.protocol_addr_size());
+        // SAFETY:
+        // Safe as the contructor checks that the slice has
+        // the length 8 + 2*(hw_addr_size + protocol_addr_size).
+        unsafe { from_raw_parts(self.slice.as_ptr().add(start), len) }
+    }
+
+    /// Returns the decoded addresses and operation if the packet is an
+    /// ARP packet for IPv4 addresses over Ethernet (hardware type
+    /// [`ArpHardwareId::ETHERNET`], protocol type [`EtherType::IPV4`] and
+    /// the matching address sizes 6 & 4). Otherwise `None` is returned.
+    pub fn try_eth_ipv4(&self) -> Option<ArpEthIpv4Header> {
+        if self.slice.len() == ArpEthIpv4Header::LEN
+            && self.hw_addr_type() == ArpHardwareId::ETHERNET
+            && self.proto_addr_type() == EtherType::IPV4
+            && 6 == self.hw_addr_size()
+            && 4 == self.protocol_addr_size()
+        {
+            // SAFETY:
+            // Safe as the slice length was checked to be
+            // ArpEthIpv4Header::LEN (28).
+            let ptr = self.slice.as_ptr();
+            Some(unsafe {
+                ArpEthIpv4Header {
+                    operation: self.operation(),
+                    sender_mac: MacAddress(get_unchecked_6_byte_array(ptr.add(8))),
+                    sender_ipv4: get_unchecked_4_byte_array(ptr.add(14)),
+                    target_mac: MacAddress(get_unchecked_6_byte_array(ptr.add(18))),
+                    target_ipv4: get_unchecked_4_byte_array(ptr.add(24)),
+                }
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Decode all the fields and copy the results to an [`ArpHeader`] struct.
+    pub fn to_header(&self) -> ArpHeader {
+        // SAFETY: Safe as the address lengths are limited to 255
+        // by the u8 length fields.
+        unsafe {
+            ArpHeader::new_unchecked(
+                self.hw_addr_type(),
+                self.proto_addr_type(),
+                self.operation(),
+                self.sender_hw_addr(),
+                self.sender_protocol_addr(),
+                self.target_hw_addr(),
+                self.target_protocol_addr(),
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_gens::*;
+    use alloc::{format, vec::Vec};
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn from_slice(
+            input in arp_any(),
+            dummy_data in proptest::collection::vec(any::<u8>(), 0..20)
+        ) {
+            // serialize
+            let mut buffer: Vec<u8> = Vec::with_capacity(input.header_len() + dummy_data.len());
+            input.write(&mut buffer).unwrap();
+            buffer.extend(&dummy_data[..]);
+
+            // calls with a valid result
+            {
+                let result = ArpPacketSlice::from_slice(&buffer[..]).unwrap();
+                assert_eq!(&buffer[..input.header_len()], result.slice());
+            }
+
+            // call with not enough data in the slice
+            for len in 0..input.header_len() {
+                assert_eq!(
+                    ArpPacketSlice::from_slice(&buffer[..len]).unwrap_err(),
+                    err::LenError{
+                        required_len: if len < ArpHeader::MIN_LEN {
+                            ArpHeader::MIN_LEN
+                        } else {
+                            input.header_len()
+                        },
+                        len,
+                        len_source: err::LenSource::Slice,
+                        layer: err::Layer::ArpPacket,
+                        layer_start_offset: 0,
+                    }
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn getters(input in arp_any()) {
+            let buffer = input.to_bytes();
+            let slice = ArpPacketSlice::from_slice(&buffer).unwrap();
+            assert_eq!(input.hw_addr_type, slice.hw_addr_type());
+            assert_eq!(input.proto_addr_type, slice.proto_addr_type());
+            assert_eq!(input.hw_addr_size(), slice.hw_addr_size());
+            assert_eq!(input.protocol_addr_size(), slice.protocol_addr_size());
+            assert_eq!(input.operation, slice.operation());
+            assert_eq!(input.sender_hw_addr(), slice.sender_hw_addr());
+            assert_eq!(input.sender_protocol_addr(), slice.sender_protocol_addr());
+            assert_eq!(input.target_hw_addr(), slice.target_hw_addr());
+            assert_eq!(input.target_protocol_addr(), slice.target_protocol_addr());
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn try_eth_ipv4(
+            input in arp_eth_ipv4_any(),
+            other in arp_any()
+        ) {
+            // eth ipv4 packet
+            {
+                let buffer = input.to_bytes();
+                let slice = ArpPacketSlice::from_slice(&buffer).unwrap();
+                assert_eq!(Some(input.clone()), slice.try_eth_ipv4());
+            }
+            // any packet
+            {
+                let buffer = other.to_bytes();
+                let slice = ArpPacketSlice::from_slice(&buffer).unwrap();
+                assert_eq!(other.try_eth_ipv4(), slice.try_eth_ipv4());
+            }
+            // non matching fields
+            {
+                let base = input.to_bytes();
+                for (index, value) in [(1, 6), (3, 0xdd), (4, 5), (5, 3)] {
+                    let mut buffer = base;
+                    buffer[index] = value;
+                    if index >= 4 {
+                        // size changes also change the length of the packet
+                        let len = 8 + 2*(usize::from(buffer[4]) + usize::from(buffer[5]));
+                        let slice = ArpPacketSlice::from_slice(&buffer[..len]).unwrap();
+                        assert_eq!(None, slice.try_eth_ipv4());
+                    } else {
+                        let slice = ArpPacketSlice::from_slice(&buffer).unwrap();
+                        assert_eq!(None, slice.try_eth_ipv4());
+                    }
+                }
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn to_header(input in arp_any()) {
+            let buffer = input.to_bytes();
+            let slice = ArpPacketSlice::from_slice(&buffer).unwrap();
+            assert_eq!(input, slice.to_header());
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn clone_eq_dbg(input in arp_any()) {
+            let buffer = input.to_bytes();
+            let slice = ArpPacketSlice::from_slice(&buffer).unwrap();
+            assert_eq!(slice, slice.clone());
+            assert_eq!(
+                format!("ArpPacketSlice {{ slice: {:?} }}", slice.slice()),
+                format!("{:?}", slice)
+            );
+        }
+    }
+}
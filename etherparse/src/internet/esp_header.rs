@@ -0,0 +1,231 @@
+use crate::*;
+
+/// IPsec Encapsulating Security Payload (ESP) header (rfc4303).
+///
+/// Only the unencrypted start of the ESP packet (security parameters
+/// index & sequence number) is part of the header. The following data
+/// (payload, padding, pad length, next header & integrity check value)
+/// is usually encrypted and treated as the payload of the IP packet.
+///
+/// In case the "NULL" encryption algorithm is used the payload & trailer
+/// can be decoded via [`EspNullPayloadSlice`].
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Default)]
+pub struct EspHeader {
+    /// Security Parameters Index identifying the security association
+    /// of the packet.
+    pub spi: u32,
+    /// Counter value that increases by one for each packet sent.
+    pub sequence_number: u32,
+}
+
+impl EspHeader {
+    /// Length of the serialized header.
+    pub const LEN: usize = 8;
+
+    /// Read an ESP header from a slice and return the header & unused parts of the slice.
+    pub fn from_slice(slice: &[u8]) -> Result<(EspHeader, &[u8]), err::LenError> {
+        let s = EspHeaderSlice::from_slice(slice)?;
+        let rest = &slice[EspHeader::LEN..];
+        Ok((s.to_header(), rest))
+    }
+
+    /// Read an ESP header from the current reader position.
+    #[cfg(feature = "std")]
+    pub fn read<T: std::io::Read + Sized>(reader: &mut T) -> Result<EspHeader, std::io::Error> {
+        let mut buffer = [0u8; EspHeader::LEN];
+        reader.read_exact(&mut buffer)?;
+        Ok(EspHeader::from_bytes(buffer))
+    }
+
+    /// Read an ESP header from the current position of a length limited reader.
+    #[cfg(feature = "std")]
+    pub fn read_limited<T: std::io::Read + Sized>(
+        reader: &mut crate::io::LimitedReader<T>,
+    ) -> Result<EspHeader, err::io::LimitedReadError> {
+        // set layer so errors contain the correct layer & offset
+        reader.start_layer(err::Layer::EspHeader);
+
+        let mut buffer = [0u8; EspHeader::LEN];
+        reader.read_exact(&mut buffer)?;
+        Ok(EspHeader::from_bytes(buffer))
+    }
+
+    /// Decodes the header from the given bytes.
+    #[inline]
+    pub fn from_bytes(bytes: [u8; EspHeader::LEN]) -> EspHeader {
+        EspHeader {
+            spi: u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]),
+            sequence_number: u32::from_be_bytes([bytes[4], bytes[5], bytes[6], bytes[7]]),
+        }
+    }
+
+    /// Writes the header to the current position of the writer.
+    #[cfg(feature = "std")]
+    pub fn write<T: std::io::Write + Sized>(&self, writer: &mut T) -> Result<(), std::io::Error> {
+        writer.write_all(&self.to_bytes())
+    }
+
+    /// Length of the header in bytes.
+    #[inline]
+    pub fn header_len(&self) -> usize {
+        EspHeader::LEN
+    }
+
+    /// Returns the serialized form of the header as a statically
+    /// sized byte array.
+    #[inline]
+    pub fn to_bytes(&self) -> [u8; EspHeader::LEN] {
+        let spi = self.spi.to_be_bytes();
+        let seq = self.sequence_number.to_be_bytes();
+        [
+            spi[0], spi[1], spi[2], spi[3], seq[0], seq[1], seq[2], seq[3],
+        ]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{test_gens::*, *};
+    use alloc::{format, vec::Vec};
+    use proptest::prelude::*;
+    use std::io::{Cursor, ErrorKind};
+
+    proptest! {
+        #[test]
+        fn debug_clone_eq(header in esp_any()) {
+            assert_eq!(
+                format!(
+                    "EspHeader {{ spi: {}, sequence_number: {} }}",
+                    header.spi, header.sequence_number
+                ),
+                format!("{:?}", header)
+            );
+            assert_eq!(header, header.clone());
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn from_slice(
+            header in esp_any(),
+            dummy_data in proptest::collection::vec(any::<u8>(), 0..20)
+        ) {
+            let mut buffer = Vec::with_capacity(EspHeader::LEN + dummy_data.len());
+            buffer.extend_from_slice(&header.to_bytes());
+            buffer.extend_from_slice(&dummy_data);
+
+            // ok
+            {
+                let (actual, rest) = EspHeader::from_slice(&buffer).unwrap();
+                assert_eq!(header, actual);
+                assert_eq!(&dummy_data[..], rest);
+            }
+
+            // length error
+            for len in 0..EspHeader::LEN {
+                assert_eq!(
+                    EspHeader::from_slice(&buffer[..len]).unwrap_err(),
+                    err::LenError {
+                        required_len: EspHeader::LEN,
+                        len,
+                        len_source: err::LenSource::Slice,
+                        layer: err::Layer::EspHeader,
+                        layer_start_offset: 0,
+                    }
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn read(header in esp_any()) {
+            let bytes = header.to_bytes();
+
+            // ok
+            {
+                let mut cursor = Cursor::new(&bytes);
+                assert_eq!(header, EspHeader::read(&mut cursor).unwrap());
+                assert_eq!(cursor.position(), EspHeader::LEN as u64);
+            }
+
+            // length error
+            for len in 0..EspHeader::LEN {
+                let mut cursor = Cursor::new(&bytes[..len]);
+                assert_eq!(
+                    EspHeader::read(&mut cursor).unwrap_err().kind(),
+                    ErrorKind::UnexpectedEof
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn read_limited(header in esp_any()) {
+            use crate::io::LimitedReader;
+
+            let bytes = header.to_bytes();
+
+            // ok
+            {
+                let mut cursor = Cursor::new(&bytes);
+                let mut reader = LimitedReader::new(
+                    &mut cursor,
+                    bytes.len(),
+                    err::LenSource::Slice,
+                    0,
+                    err::Layer::Ipv4Header
+                );
+                assert_eq!(header, EspHeader::read_limited(&mut reader).unwrap());
+            }
+
+            // length error
+            for len in 0..EspHeader::LEN {
+                let mut cursor = Cursor::new(&bytes);
+                let mut reader = LimitedReader::new(
+                    &mut cursor,
+                    len,
+                    err::LenSource::Slice,
+                    0,
+                    err::Layer::Ipv4Header
+                );
+                assert_eq!(
+                    EspHeader::read_limited(&mut reader).unwrap_err().len().unwrap(),
+                    err::LenError {
+                        required_len: EspHeader::LEN,
+                        len,
+                        len_source: err::LenSource::Slice,
+                        layer: err::Layer::EspHeader,
+                        layer_start_offset: 0,
+                    }
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn write_header_len_to_bytes(header in esp_any()) {
+            let bytes = header.to_bytes();
+            assert_eq!(&bytes[..4], &header.spi.to_be_bytes());
+            assert_eq!(&bytes[4..], &header.sequence_number.to_be_bytes());
+            assert_eq!(header, EspHeader::from_bytes(bytes));
+            assert_eq!(EspHeader::LEN, header.header_len());
+
+            // write
+            {
+                let mut buffer = Vec::with_capacity(EspHeader::LEN);
+                header.write(&mut buffer).unwrap();
+                assert_eq!(&buffer[..], &bytes[..]);
+            }
+
+            // not enough space
+            for len in 0..EspHeader::LEN {
+                let mut buffer = [0u8; EspHeader::LEN];
+                let mut cursor = Cursor::new(&mut buffer[..len]);
+                assert!(header.write(&mut cursor).is_err());
+            }
+        }
+    }
+}
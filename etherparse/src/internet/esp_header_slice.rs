@@ -0,0 +1,154 @@
+use crate::*;
+use core::slice::from_raw_parts;
+
+/// Slice containing an IPsec Encapsulating Security Payload (ESP)
+/// header (rfc4303).
+///
+/// Only the unencrypted start of the ESP packet (security parameters
+/// index & sequence number) is part of the header slice.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct EspHeaderSlice<'a> {
+    slice: &'a [u8],
+}
+
+impl<'a> EspHeaderSlice<'a> {
+    /// Creates an ESP header slice from a slice.
+    pub fn from_slice(slice: &'a [u8]) -> Result<EspHeaderSlice<'a>, err::LenError> {
+        if slice.len() < EspHeader::LEN {
+            Err(err::LenError {
+                required_len: EspHeader::LEN,
+                len: slice.len(),
+                len_source: err::LenSource::Slice,
+                layer: err::Layer::EspHeader,
+                layer_start_offset: 0,
+            })
+        } else {
+            Ok(EspHeaderSlice {
+                // SAFETY:
+                // Safe as the slice length is checked to be at least
+                // EspHeader::LEN (8) before this code can be reached.
+                slice: unsafe { from_raw_parts(slice.as_ptr(), EspHeader::LEN) },
+            })
+        }
+    }
+
+    /// Creates an ESP header slice from a slice (assumes slice size was validated before).
+    ///
+    /// # Safety
+    ///
+    /// This function assumes that the passed slice has at least the length
+    /// of 8. If a slice with length less then 8 is passed to this function
+    /// the behavior will be undefined.
+    pub unsafe fn from_slice_unchecked(slice: &'a [u8]) -> EspHeaderSlice<'a> {
+        debug_assert!(slice.len() >= EspHeader::LEN);
+        EspHeaderSlice {
+            slice: from_raw_parts(slice.as_ptr(), EspHeader::LEN),
+        }
+    }
+
+    /// Returns the slice containing the ESP header.
+    #[inline]
+    pub fn slice(&self) -> &'a [u8] {
+        self.slice
+    }
+
+    /// Read the security parameters index from the slice.
+    #[inline]
+    pub fn spi(&self) -> u32 {
+        // SAFETY:
+        // Safe as slice length is checked in the constructor
+        // to be at least 8.
+        unsafe { get_unchecked_be_u32(self.slice.as_ptr()) }
+    }
+
+    /// Read the sequence number (counter value that increases by one
+    /// for each packet sent) from the slice.
+    #[inline]
+    pub fn sequence_number(&self) -> u32 {
+        // SAFETY:
+        // Safe as slice length is checked in the constructor
+        // to be at least 8.
+        unsafe { get_unchecked_be_u32(self.slice.as_ptr().add(4)) }
+    }
+
+    /// Decode all the fields and copy the results to a [`EspHeader`] struct.
+    #[inline]
+    pub fn to_header(&self) -> EspHeader {
+        EspHeader {
+            spi: self.spi(),
+            sequence_number: self.sequence_number(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{test_gens::*, *};
+    use alloc::{format, vec::Vec};
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn debug_clone_eq(header in esp_any()) {
+            let bytes = header.to_bytes();
+            let slice = EspHeaderSlice::from_slice(&bytes).unwrap();
+            assert_eq!(
+                format!("EspHeaderSlice {{ slice: {:?} }}", slice.slice()),
+                format!("{:?}", slice)
+            );
+            assert_eq!(slice, slice.clone());
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn from_slice(
+            header in esp_any(),
+            dummy_data in proptest::collection::vec(any::<u8>(), 0..20)
+        ) {
+            let mut buffer = Vec::with_capacity(EspHeader::LEN + dummy_data.len());
+            buffer.extend_from_slice(&header.to_bytes());
+            buffer.extend_from_slice(&dummy_data);
+
+            // ok
+            {
+                let slice = EspHeaderSlice::from_slice(&buffer).unwrap();
+                assert_eq!(slice.slice(), &buffer[..EspHeader::LEN]);
+            }
+
+            // length error
+            for len in 0..EspHeader::LEN {
+                assert_eq!(
+                    EspHeaderSlice::from_slice(&buffer[..len]).unwrap_err(),
+                    err::LenError {
+                        required_len: EspHeader::LEN,
+                        len,
+                        len_source: err::LenSource::Slice,
+                        layer: err::Layer::EspHeader,
+                        layer_start_offset: 0,
+                    }
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn from_slice_unchecked(header in esp_any()) {
+            let bytes = header.to_bytes();
+            let slice = unsafe { EspHeaderSlice::from_slice_unchecked(&bytes) };
+            assert_eq!(slice.slice(), &bytes[..]);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn getters_to_header(header in esp_any()) {
+            let bytes = header.to_bytes();
+            let slice = EspHeaderSlice::from_slice(&bytes).unwrap();
+            assert_eq!(slice.spi(), header.spi);
+            assert_eq!(slice.sequence_number(), header.sequence_number);
+            assert_eq!(slice.to_header(), header);
+        }
+    }
+}
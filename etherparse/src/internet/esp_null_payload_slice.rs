@@ -0,0 +1,212 @@
+use crate::*;
+
+/// Slice containing the data after an ESP header of a packet using
+/// the "NULL" encryption algorithm (rfc2410).
+///
+/// With "NULL" encryption the payload & trailer of the ESP packet are
+/// transmitted in plain text:
+///
+/// ```text
+/// +--------------------------+
+/// | payload                  |
+/// +--------------------------+
+/// | padding (0-255 bytes)    |
+/// +--------------------------+
+/// | pad length (1 byte)      |
+/// +--------------------------+
+/// | next header (1 byte)     |
+/// +--------------------------+
+/// | integrity check value    |
+/// +--------------------------+
+/// ```
+///
+/// The length of the integrity check value (ICV) depends on the
+/// negotiated integrity algorithm and is not part of the packet. It
+/// has to be passed to [`EspNullPayloadSlice::from_slice`] (e.g. 12
+/// for HMAC-SHA1-96 or 0 if no integrity algorithm is used).
+///
+/// # Example
+///
+/// ```
+/// use etherparse::{ip_number, EspNullPayloadSlice};
+///
+/// let data = [
+///     1, 2, 3, 4, 5, // payload
+///     1, 2, 3,       // padding
+///     3,             // pad length
+///     ip_number::UDP.0,
+///     9, 9, 9, 9,    // integrity check value
+/// ];
+/// let esp = EspNullPayloadSlice::from_slice(&data, 4).unwrap();
+/// assert_eq!(esp.payload(), &[1, 2, 3, 4, 5]);
+/// assert_eq!(esp.padding(), &[1, 2, 3]);
+/// assert_eq!(esp.pad_len(), 3);
+/// assert_eq!(esp.next_header(), ip_number::UDP);
+/// assert_eq!(esp.icv(), &[9, 9, 9, 9]);
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct EspNullPayloadSlice<'a> {
+    /// Slice containing the payload, the trailer & the integrity check value.
+    slice: &'a [u8],
+    /// Length of the integrity check value.
+    icv_len: usize,
+}
+
+impl<'a> EspNullPayloadSlice<'a> {
+    /// Length of the "pad length" & "next header" fields in bytes/octets.
+    pub const TRAILER_LEN: usize = 2;
+
+    /// Decodes the trailer of the data after an ESP header (payload,
+    /// trailer & integrity check value) for packets using "NULL"
+    /// encryption.
+    pub fn from_slice(
+        slice: &'a [u8],
+        icv_len: usize,
+    ) -> Result<EspNullPayloadSlice<'a>, err::LenError> {
+        let len_error = |required_len| err::LenError {
+            required_len,
+            len: slice.len(),
+            len_source: err::LenSource::Slice,
+            layer: err::Layer::EspTrailer,
+            layer_start_offset: 0,
+        };
+
+        let min_len = icv_len.saturating_add(EspNullPayloadSlice::TRAILER_LEN);
+        if slice.len() < min_len {
+            return Err(len_error(min_len));
+        }
+        let pad_len = usize::from(slice[slice.len() - min_len]);
+        if slice.len() < min_len + pad_len {
+            return Err(len_error(min_len + pad_len));
+        }
+        Ok(EspNullPayloadSlice { slice, icv_len })
+    }
+
+    /// Returns the slice containing the payload, the trailer & the
+    /// integrity check value.
+    #[inline]
+    pub fn slice(&self) -> &'a [u8] {
+        self.slice
+    }
+
+    /// Offset of the "pad length" field.
+    #[inline]
+    fn trailer_start(&self) -> usize {
+        self.slice.len() - self.icv_len - EspNullPayloadSlice::TRAILER_LEN
+    }
+
+    /// Returns the protected payload (data before the padding).
+    #[inline]
+    pub fn payload(&self) -> &'a [u8] {
+        &self.slice[..self.trailer_start() - usize::from(self.pad_len())]
+    }
+
+    /// Returns the padding between the payload & the "pad length" field.
+    #[inline]
+    pub fn padding(&self) -> &'a [u8] {
+        let end = self.trailer_start();
+        &self.slice[end - usize::from(self.pad_len())..end]
+    }
+
+    /// Returns the "pad length" field (number of padding bytes).
+    #[inline]
+    pub fn pad_len(&self) -> u8 {
+        self.slice[self.trailer_start()]
+    }
+
+    /// Returns the "next header" field identifying the type of the payload.
+    #[inline]
+    pub fn next_header(&self) -> IpNumber {
+        IpNumber(self.slice[self.trailer_start() + 1])
+    }
+
+    /// Returns the integrity check value at the end of the slice.
+    #[inline]
+    pub fn icv(&self) -> &'a [u8] {
+        &self.slice[self.slice.len() - self.icv_len..]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::format;
+
+    #[test]
+    fn debug_clone_eq() {
+        let data = [0, ip_number::UDP.0];
+        let esp = EspNullPayloadSlice::from_slice(&data, 0).unwrap();
+        assert_eq!(
+            format!(
+                "EspNullPayloadSlice {{ slice: {:?}, icv_len: 0 }}",
+                &data[..]
+            ),
+            format!("{:?}", esp)
+        );
+        assert_eq!(esp, esp.clone());
+    }
+
+    #[test]
+    fn from_slice_getters() {
+        // no padding & no icv
+        {
+            let data = [1, 2, 0, ip_number::TCP.0];
+            let esp = EspNullPayloadSlice::from_slice(&data, 0).unwrap();
+            assert_eq!(esp.slice(), &data[..]);
+            assert_eq!(esp.payload(), &[1, 2]);
+            assert!(esp.padding().is_empty());
+            assert_eq!(esp.pad_len(), 0);
+            assert_eq!(esp.next_header(), ip_number::TCP);
+            assert!(esp.icv().is_empty());
+        }
+        // padding & icv
+        {
+            let data = [1, 1, 2, 2, ip_number::UDP.0, 3, 4, 5];
+            let esp = EspNullPayloadSlice::from_slice(&data, 3).unwrap();
+            assert_eq!(esp.payload(), &[1]);
+            assert_eq!(esp.padding(), &[1, 2]);
+            assert_eq!(esp.pad_len(), 2);
+            assert_eq!(esp.next_header(), ip_number::UDP);
+            assert_eq!(esp.icv(), &[3, 4, 5]);
+        }
+        // only trailer & padding
+        {
+            let data = [1, 1, ip_number::UDP.0];
+            let esp = EspNullPayloadSlice::from_slice(&data, 0).unwrap();
+            assert!(esp.payload().is_empty());
+            assert_eq!(esp.padding(), &[1]);
+        }
+    }
+
+    #[test]
+    fn from_slice_len_error() {
+        // not enough data for the trailer & icv
+        for (len, icv_len) in [(0, 0), (1, 0), (5, 4), (0, usize::MAX)] {
+            let data = [0u8; 5];
+            assert_eq!(
+                EspNullPayloadSlice::from_slice(&data[..len], icv_len).unwrap_err(),
+                err::LenError {
+                    required_len: icv_len.saturating_add(2),
+                    len,
+                    len_source: err::LenSource::Slice,
+                    layer: err::Layer::EspTrailer,
+                    layer_start_offset: 0,
+                }
+            );
+        }
+        // pad length bigger then the available data
+        {
+            let data = [1, 2, 3, 0];
+            assert_eq!(
+                EspNullPayloadSlice::from_slice(&data, 0).unwrap_err(),
+                err::LenError {
+                    required_len: 5,
+                    len: 4,
+                    len_source: err::LenSource::Slice,
+                    layer: err::Layer::EspTrailer,
+                    layer_start_offset: 0,
+                }
+            );
+        }
+    }
+}
@@ -0,0 +1,349 @@
+use crate::{err::Layer, err::SliceWriteSpaceError, *};
+use arrayvec::ArrayVec;
+
+/// GRE header (Generic Routing Encapsulation, RFC 2784 & RFC 2890) or
+/// enhanced GRE header (version 1, used by PPTP as defined in RFC 2637).
+///
+/// GRE headers are present in IP packets with the ip number
+/// [`ip_number::GRE`]. The `protocol_type` identifies the payload via an
+/// ether type (e.g. [`ether_type::IPV4`], [`ether_type::MPLS_UNICAST`] or
+/// [`ether_type::TRANSPARENT_ETHERNET_BRIDGING`] for ethernet frames).
+///
+/// The optional fields are present in the serialized header if they are
+/// set to `Some`. The routing information of RFC 1701 (deprecated by RFC 2784)
+/// is not supported when writing headers, it can only be accessed via
+/// [`GreHeaderSlice::routing`].
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Default)]
+pub struct GreHeader {
+    /// Version of the GRE header ([`GreHeader::VERSION_GRE`] or
+    /// [`GreHeader::VERSION_ENHANCED`]).
+    pub version: u8,
+    /// Ether type identifying the payload.
+    pub protocol_type: EtherType,
+    /// Checksum over the GRE header & payload (see [`GreHeader::calc_checksum`]).
+    pub checksum: Option<u16>,
+    /// Key identifying a flow within a tunnel.
+    ///
+    /// In the enhanced GRE header (version 1) the key field is mandatory
+    /// and contains the payload length (upper 16 bits) & the call id
+    /// (lower 16 bits).
+    pub key: Option<u32>,
+    /// Sequence number of the packet.
+    pub sequence_number: Option<u32>,
+    /// Highest sequence number received by the sender (only present in
+    /// the enhanced GRE header, version 1).
+    pub acknowledgment_number: Option<u32>,
+}
+
+impl GreHeader {
+    /// Minimum length of a GRE header in bytes/octets (no optional fields).
+    pub const MIN_LEN: usize = 4;
+
+    /// Maximum length of a GRE header in bytes/octets that can be
+    /// represented by [`GreHeader`] (all optional fields present).
+    pub const MAX_LEN: usize = 20;
+
+    /// Version number of the GRE header defined in RFC 2784.
+    pub const VERSION_GRE: u8 = 0;
+
+    /// Version number of the enhanced GRE header used by PPTP (RFC 2637).
+    pub const VERSION_ENHANCED: u8 = 1;
+
+    /// Flag indicating that the checksum field is present.
+    pub const CHECKSUM_PRESENT: u8 = 0b1000_0000;
+
+    /// Flag indicating that the routing field is present (RFC 1701).
+    pub const ROUTING_PRESENT: u8 = 0b0100_0000;
+
+    /// Flag indicating that the key field is present.
+    pub const KEY_PRESENT: u8 = 0b0010_0000;
+
+    /// Flag indicating that the sequence number field is present.
+    pub const SEQUENCE_PRESENT: u8 = 0b0001_0000;
+
+    /// Flag indicating that the acknowledgment number field is present
+    /// (located in the second byte of the enhanced GRE header).
+    pub const ACK_PRESENT: u8 = 0b1000_0000;
+
+    /// Read a [`GreHeader`] from a slice and return the header & unused parts of the slice.
+    ///
+    /// Note that routing information (RFC 1701) is skipped and not part
+    /// of the returned header.
+    #[inline]
+    pub fn from_slice(slice: &[u8]) -> Result<(GreHeader, &[u8]), err::gre::HeaderSliceError> {
+        let header = GreHeaderSlice::from_slice(slice)?;
+        Ok((header.to_header(), &slice[header.slice().len()..]))
+    }
+
+    /// Serialize the header to a given slice. Returns the unused part of the slice.
+    pub fn write_to_slice<'a>(
+        &self,
+        slice: &'a mut [u8],
+    ) -> Result<&'a mut [u8], SliceWriteSpaceError> {
+        let len = self.header_len();
+        // length check
+        if slice.len() < len {
+            Err(SliceWriteSpaceError {
+                required_len: len,
+                len: slice.len(),
+                layer: Layer::GreHeader,
+                layer_start_offset: 0,
+            })
+        } else {
+            slice[..len].copy_from_slice(&self.to_bytes());
+            Ok(&mut slice[len..])
+        }
+    }
+
+    /// Writes a given GRE header to the current position of the write argument.
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn write<T: std::io::Write + Sized>(&self, writer: &mut T) -> Result<(), std::io::Error> {
+        writer.write_all(&self.to_bytes())
+    }
+
+    /// Length of the serialized header in bytes.
+    #[inline]
+    pub fn header_len(&self) -> usize {
+        GreHeader::MIN_LEN
+            + if self.checksum.is_some() { 4 } else { 0 }
+            + if self.key.is_some() { 4 } else { 0 }
+            + if self.sequence_number.is_some() { 4 } else { 0 }
+            + if self.acknowledgment_number.is_some() {
+                4
+            } else {
+                0
+            }
+    }
+
+    /// Calculates the checksum over the header & the given payload (the
+    /// current value of the `checksum` field is ignored and the header is
+    /// treated as if the checksum field is present).
+    pub fn calc_checksum(&self, payload: &[u8]) -> u16 {
+        let mut header = self.clone();
+        header.checksum = Some(0);
+        checksum::Sum16BitWords::new()
+            .add_slice(&header.to_bytes())
+            .add_slice(payload)
+            .ones_complement()
+            .to_be()
+    }
+
+    /// Sets the checksum field to the checksum calculated over the
+    /// header & the given payload.
+    pub fn update_checksum(&mut self, payload: &[u8]) {
+        self.checksum = Some(self.calc_checksum(payload));
+    }
+
+    /// Returns the serialized form of the header.
+    pub fn to_bytes(&self) -> ArrayVec<u8, { GreHeader::MAX_LEN }> {
+        let mut flags = 0;
+        if self.checksum.is_some() {
+            flags |= GreHeader::CHECKSUM_PRESENT;
+        }
+        if self.key.is_some() {
+            flags |= GreHeader::KEY_PRESENT;
+        }
+        if self.sequence_number.is_some() {
+            flags |= GreHeader::SEQUENCE_PRESENT;
+        }
+        let ack = if self.acknowledgment_number.is_some() {
+            GreHeader::ACK_PRESENT
+        } else {
+            0
+        };
+        let protocol_type_be = self.protocol_type.0.to_be_bytes();
+
+        let mut result = ArrayVec::new();
+        result.push(flags);
+        result.push(ack | (self.version & 0b111));
+        result.push(protocol_type_be[0]);
+        result.push(protocol_type_be[1]);
+        if let Some(checksum) = self.checksum {
+            let checksum_be = checksum.to_be_bytes();
+            // checksum followed by the reserved field
+            result.push(checksum_be[0]);
+            result.push(checksum_be[1]);
+            result.push(0);
+            result.push(0);
+        }
+        for value in [self.key, self.sequence_number, self.acknowledgment_number]
+            .into_iter()
+            .flatten()
+        {
+            let _ = result.try_extend_from_slice(&value.to_be_bytes());
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_gens::*;
+    use alloc::{format, vec::Vec};
+    use proptest::prelude::*;
+
+    #[test]
+    fn constants() {
+        assert_eq!(4, GreHeader::MIN_LEN);
+        assert_eq!(20, GreHeader::MAX_LEN);
+        assert_eq!(0, GreHeader::VERSION_GRE);
+        assert_eq!(1, GreHeader::VERSION_ENHANCED);
+    }
+
+    proptest! {
+        #[test]
+        fn from_slice(
+            input in gre_any(),
+            dummy_data in proptest::collection::vec(any::<u8>(), 0..20)
+        ) {
+            // serialize
+            let mut buffer: Vec<u8> = Vec::with_capacity(input.header_len() + dummy_data.len());
+            input.write(&mut buffer).unwrap();
+            buffer.extend(&dummy_data[..]);
+
+            // calls with a valid result
+            {
+                let (result, rest) = GreHeader::from_slice(&buffer[..]).unwrap();
+                assert_eq!(input, result);
+                assert_eq!(&buffer[input.header_len()..], rest);
+            }
+
+            // call with not enough data in the slice
+            for len in 0..input.header_len() {
+                assert_eq!(
+                    GreHeader::from_slice(&buffer[..len]),
+                    Err(err::gre::HeaderSliceError::Len(err::LenError{
+                        required_len: if len < GreHeader::MIN_LEN {
+                            GreHeader::MIN_LEN
+                        } else {
+                            input.header_len()
+                        },
+                        len,
+                        len_source: err::LenSource::Slice,
+                        layer: err::Layer::GreHeader,
+                        layer_start_offset: 0,
+                    }))
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn write_to_slice(input in gre_any()) {
+            // normal write
+            {
+                let mut buffer = [0u8; GreHeader::MAX_LEN];
+                let rest_len = input.write_to_slice(&mut buffer).unwrap().len();
+                assert_eq!(GreHeader::MAX_LEN - input.header_len(), rest_len);
+                assert_eq!(&input.to_bytes()[..], &buffer[..input.header_len()]);
+            }
+            // len error
+            for len in 0..input.header_len() {
+                let mut buffer = [0u8; GreHeader::MAX_LEN];
+                assert_eq!(
+                    SliceWriteSpaceError {
+                        required_len: input.header_len(),
+                        len,
+                        layer: Layer::GreHeader,
+                        layer_start_offset: 0,
+                    },
+                    input.write_to_slice(&mut buffer[..len]).unwrap_err()
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn to_bytes(input in gre_any()) {
+            let bytes = input.to_bytes();
+            assert_eq!(input.header_len(), bytes.len());
+            assert_eq!(input.checksum.is_some(), 0 != bytes[0] & GreHeader::CHECKSUM_PRESENT);
+            assert_eq!(input.key.is_some(), 0 != bytes[0] & GreHeader::KEY_PRESENT);
+            assert_eq!(input.sequence_number.is_some(), 0 != bytes[0] & GreHeader::SEQUENCE_PRESENT);
+            assert_eq!(input.acknowledgment_number.is_some(), 0 != bytes[1] & GreHeader::ACK_PRESENT);
+            assert_eq!(input.version, bytes[1] & 0b111);
+            assert_eq!(input.protocol_type.0.to_be_bytes(), [bytes[2], bytes[3]]);
+        }
+    }
+
+    #[test]
+    fn to_bytes_field_order() {
+        let header = GreHeader {
+            version: GreHeader::VERSION_ENHANCED,
+            protocol_type: EtherType(0x880B),
+            checksum: Some(0x0102),
+            key: Some(0x0304_0506),
+            sequence_number: Some(0x0708_090A),
+            acknowledgment_number: Some(0x0B0C_0D0E),
+        };
+        assert_eq!(
+            &[
+                0b1011_0000,
+                0b1000_0001,
+                0x88,
+                0x0B,
+                0x01,
+                0x02,
+                0,
+                0,
+                0x03,
+                0x04,
+                0x05,
+                0x06,
+                0x07,
+                0x08,
+                0x09,
+                0x0A,
+                0x0B,
+                0x0C,
+                0x0D,
+                0x0E
+            ],
+            &header.to_bytes()[..]
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn checksum(
+            input in gre_any(),
+            payload in proptest::collection::vec(any::<u8>(), 0..20)
+        ) {
+            let mut header = input.clone();
+            header.update_checksum(&payload);
+            assert_eq!(Some(header.calc_checksum(&payload)), header.checksum);
+
+            // the sum over the header (including checksum) & payload must be zero
+            let mut buffer = Vec::new();
+            header.write(&mut buffer).unwrap();
+            buffer.extend_from_slice(&payload);
+            assert_eq!(
+                0,
+                checksum::Sum16BitWords::new().add_slice(&buffer).ones_complement()
+            );
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn clone_eq_dbg(input in gre_any()) {
+            assert_eq!(input, input.clone());
+            assert_eq!(
+                format!(
+                    "GreHeader {{ version: {:?}, protocol_type: {:?}, checksum: {:?}, key: {:?}, sequence_number: {:?}, acknowledgment_number: {:?} }}",
+                    input.version,
+                    input.protocol_type,
+                    input.checksum,
+                    input.key,
+                    input.sequence_number,
+                    input.acknowledgment_number,
+                ),
+                format!("{:?}", input)
+            );
+        }
+    }
+}
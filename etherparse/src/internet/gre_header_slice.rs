@@ -0,0 +1,581 @@
+use crate::*;
+use core::slice::from_raw_parts;
+
+/// A slice containing a GRE header (version 0 as defined in RFC 2784,
+/// RFC 2890 & RFC 1701 or the enhanced GRE header version 1 used by
+/// PPTP as defined in RFC 2637).
+///
+/// The slice contains all optional fields indicated by the flags
+/// including the routing information (source route entries) of RFC 1701.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GreHeaderSlice<'a> {
+    slice: &'a [u8],
+}
+
+impl<'a> GreHeaderSlice<'a> {
+    /// Creates a GRE header slice from an other slice (verifies that the
+    /// version is `0` or `1`).
+    pub fn from_slice(slice: &'a [u8]) -> Result<GreHeaderSlice<'a>, err::gre::HeaderSliceError> {
+        use err::gre::{HeaderError::*, HeaderSliceError::*};
+
+        let len_error = |required_len: usize| {
+            Len(err::LenError {
+                required_len,
+                len: slice.len(),
+                len_source: err::LenSource::Slice,
+                layer: err::Layer::GreHeader,
+                layer_start_offset: 0,
+            })
+        };
+
+        //check minimum length
+        if slice.len() < GreHeader::MIN_LEN {
+            return Err(len_error(GreHeader::MIN_LEN));
+        }
+
+        // SAFETY:
+        // Safe as the slice length is checked to be at least
+        // GreHeader::MIN_LEN (4) before this.
+        let (flags, ack_version) = unsafe { (*slice.get_unchecked(0), *slice.get_unchecked(1)) };
+
+        //check version
+        let version_number = ack_version & 0b111;
+        if version_number > GreHeader::VERSION_ENHANCED {
+            return Err(Content(UnsupportedVersion { version_number }));
+        }
+
+        //determine the length of the fixed fields
+        let routing_start = GreHeaderSlice::routing_offset(flags, ack_version);
+        if slice.len() < routing_start {
+            return Err(len_error(routing_start));
+        }
+
+        //walk the source route entries (terminated by an entry with
+        //address family 0 & length 0)
+        let mut len = routing_start;
+        if 0 != flags & GreHeader::ROUTING_PRESENT {
+            loop {
+                if slice.len() < len + 4 {
+                    return Err(len_error(len + 4));
+                }
+                let address_family = u16::from_be_bytes([slice[len], slice[len + 1]]);
+                let sre_len = usize::from(slice[len + 3]);
+                len += 4 + sre_len;
+                if 0 == address_family && 0 == sre_len {
+                    break;
+                }
+            }
+        }
+
+        //all done
+        Ok(GreHeaderSlice {
+            // SAFETY:
+            // Safe as slice length is checked to be at least len before this.
+            slice: unsafe { from_raw_parts(slice.as_ptr(), len) },
+        })
+    }
+
+    /// Returns the offset of the routing field (equal to the length
+    /// of the header without routing information).
+    #[inline]
+    fn routing_offset(flags: u8, ack_version: u8) -> usize {
+        GreHeader::MIN_LEN
+            + if 0 != flags & (GreHeader::CHECKSUM_PRESENT | GreHeader::ROUTING_PRESENT) {
+                4
+            } else {
+                0
+            }
+            + if 0 != flags & GreHeader::KEY_PRESENT {
+                4
+            } else {
+                0
+            }
+            + if 0 != flags & GreHeader::SEQUENCE_PRESENT {
+                4
+            } else {
+                0
+            }
+            + if GreHeader::VERSION_ENHANCED == ack_version & 0b111
+                && 0 != ack_version & GreHeader::ACK_PRESENT
+            {
+                4
+            } else {
+                0
+            }
+    }
+
+    /// Returns the slice containing the GRE header.
+    #[inline]
+    pub fn slice(&self) -> &'a [u8] {
+        self.slice
+    }
+
+    /// Read the first byte containing the C, R, K, S & s flags and the
+    /// "recursion control" field.
+    #[inline]
+    fn flags(&self) -> u8 {
+        // SAFETY:
+        // Safe as the contructor checks that the slice has
+        // at least the length of GreHeader::MIN_LEN (4).
+        unsafe { *self.slice.get_unchecked(0) }
+    }
+
+    /// Read the "checksum present" (C) flag.
+    #[inline]
+    pub fn checksum_present(&self) -> bool {
+        0 != self.flags() & GreHeader::CHECKSUM_PRESENT
+    }
+
+    /// Read the "routing present" (R) flag (RFC 1701).
+    #[inline]
+    pub fn routing_present(&self) -> bool {
+        0 != self.flags() & GreHeader::ROUTING_PRESENT
+    }
+
+    /// Read the "key present" (K) flag.
+    #[inline]
+    pub fn key_present(&self) -> bool {
+        0 != self.flags() & GreHeader::KEY_PRESENT
+    }
+
+    /// Read the "sequence number present" (S) flag.
+    #[inline]
+    pub fn sequence_number_present(&self) -> bool {
+        0 != self.flags() & GreHeader::SEQUENCE_PRESENT
+    }
+
+    /// Read the "strict source route" (s) flag (RFC 1701).
+    #[inline]
+    pub fn strict_source_route(&self) -> bool {
+        0 != self.flags() & 0b0000_1000
+    }
+
+    /// Read the "recursion control" field (RFC 1701).
+    #[inline]
+    pub fn recursion_control(&self) -> u8 {
+        self.flags() & 0b111
+    }
+
+    /// Read the "acknowledgment sequence number present" (A) flag (only
+    /// defined for the enhanced GRE header, always `false` for version 0).
+    #[inline]
+    pub fn acknowledgment_number_present(&self) -> bool {
+        // SAFETY:
+        // Safe as the contructor checks that the slice has
+        // at least the length of GreHeader::MIN_LEN (4).
+        let ack_version = unsafe { *self.slice.get_unchecked(1) };
+        GreHeader::VERSION_ENHANCED == ack_version & 0b111
+            && 0 != ack_version & GreHeader::ACK_PRESENT
+    }
+
+    /// Read the "version" field (`0` or `1`, as verified by the constructor).
+    #[inline]
+    pub fn version(&self) -> u8 {
+        // SAFETY:
+        // Safe as the contructor checks that the slice has
+        // at least the length of GreHeader::MIN_LEN (4).
+        unsafe { *self.slice.get_unchecked(1) & 0b111 }
+    }
+
+    /// Read the "protocol type" field (ether type of the payload).
+    #[inline]
+    pub fn protocol_type(&self) -> EtherType {
+        // SAFETY:
+        // Safe as the contructor checks that the slice has
+        // at least the length of GreHeader::MIN_LEN (4).
+        EtherType(unsafe { get_unchecked_be_u16(self.slice.as_ptr().add(2)) })
+    }
+
+    /// Read the "checksum" field (if present).
+    #[inline]
+    pub fn checksum(&self) -> Option<u16> {
+        if self.checksum_present() {
+            // SAFETY:
+            // Safe as the contructor checks that the slice contains
+            // the checksum & offset fields if the C flag is set.
+            Some(unsafe { get_unchecked_be_u16(self.slice.as_ptr().add(4)) })
+        } else {
+            None
+        }
+    }
+
+    /// Read the "offset" field (if present). The field is present if
+    /// either the checksum or the routing information is present and
+    /// contains the offset of the active source route entry.
+    #[inline]
+    pub fn offset(&self) -> Option<u16> {
+        if 0 != self.flags() & (GreHeader::CHECKSUM_PRESENT | GreHeader::ROUTING_PRESENT) {
+            // SAFETY:
+            // Safe as the contructor checks that the slice contains
+            // the checksum & offset fields if the C or R flag is set.
+            Some(unsafe { get_unchecked_be_u16(self.slice.as_ptr().add(6)) })
+        } else {
+            None
+        }
+    }
+
+    /// Read the "key" field (if present).
+    ///
+    /// In the enhanced GRE header (version 1) the key contains the
+    /// payload length & call id (see [`GreHeaderSlice::payload_length`]
+    /// & [`GreHeaderSlice::call_id`]).
+    #[inline]
+    pub fn key(&self) -> Option<u32> {
+        if self.key_present() {
+            // SAFETY:
+            // Safe as the contructor checks that the slice contains
+            // the key field if the K flag is set.
+            Some(unsafe { get_unchecked_be_u32(self.slice.as_ptr().add(self.key_offset())) })
+        } else {
+            None
+        }
+    }
+
+    /// Read the "payload length" field of an enhanced GRE header (upper
+    /// 16 bits of the key). Returns `None` for version 0 headers or if
+    /// no key is present.
+    #[inline]
+    pub fn payload_length(&self) -> Option<u16> {
+        if GreHeader::VERSION_ENHANCED == self.version() {
+            self.key().map(|key| (key >> 16) as u16)
+        } else {
+            None
+        }
+    }
+
+    /// Read the "call id" field of an enhanced GRE header (lower 16 bits
+    /// of the key). Returns `None` for version 0 headers or if no key is
+    /// present.
+    #[inline]
+    pub fn call_id(&self) -> Option<u16> {
+        if GreHeader::VERSION_ENHANCED == self.version() {
+            self.key().map(|key| (key & 0xffff) as u16)
+        } else {
+            None
+        }
+    }
+
+    /// Read the "sequence number" field (if present).
+    #[inline]
+    pub fn sequence_number(&self) -> Option<u32> {
+        if self.sequence_number_present() {
+            let offset = self.key_offset() + if self.key_present() { 4 } else { 0 };
+            // SAFETY:
+            // Safe as the contructor checks that the slice contains
+            // the sequence number field if the S flag is set.
+            Some(unsafe { get_unchecked_be_u32(self.slice.as_ptr().add(offset)) })
+        } else {
+            None
+        }
+    }
+
+    /// Read the "acknowledgment number" field (if present, only defined
+    /// for the enhanced GRE header).
+    #[inline]
+    pub fn acknowledgment_number(&self) -> Option<u32> {
+        if self.acknowledgment_number_present() {
+            let offset = self.key_offset()
+                + if self.key_present() { 4 } else { 0 }
+                + if self.sequence_number_present() { 4 } else { 0 };
+            // SAFETY:
+            // Safe as the contructor checks that the slice contains
+            // the acknowledgment number field if the A flag is set.
+            Some(unsafe { get_unchecked_be_u32(self.slice.as_ptr().add(offset)) })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the routing information (list of source route entries
+    /// including the terminating "NULL" entry) if present (RFC 1701).
+    #[inline]
+    pub fn routing(&self) -> Option<&'a [u8]> {
+        if self.routing_present() {
+            // SAFETY:
+            // Safe as the contructor verified that the routing information
+            // starts at the routing offset & ends at the end of the slice.
+            let start = GreHeaderSlice::routing_offset(self.flags(), unsafe {
+                *self.slice.get_unchecked(1)
+            });
+            Some(unsafe {
+                from_raw_parts(self.slice.as_ptr().add(start), self.slice.len() - start)
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Offset of the key field.
+    #[inline]
+    fn key_offset(&self) -> usize {
+        GreHeader::MIN_LEN
+            + if 0 != self.flags() & (GreHeader::CHECKSUM_PRESENT | GreHeader::ROUTING_PRESENT) {
+                4
+            } else {
+                0
+            }
+    }
+
+    /// Length of the GRE header in bytes.
+    #[inline]
+    pub fn header_len(&self) -> usize {
+        self.slice.len()
+    }
+
+    /// Calculates the checksum over the header & the given payload (the
+    /// value of the checksum field is ignored). Returns `None` if the
+    /// header contains no checksum field.
+    pub fn calc_checksum(&self, payload: &[u8]) -> Option<u16> {
+        if self.checksum_present() {
+            Some(
+                checksum::Sum16BitWords::new()
+                    .add_slice(&self.slice[..4])
+                    .add_slice(&self.slice[6..])
+                    .add_slice(payload)
+                    .ones_complement()
+                    .to_be(),
+            )
+        } else {
+            None
+        }
+    }
+
+    /// Returns true if the header contains no checksum or if the checksum
+    /// over the header & the given payload is correct.
+    pub fn is_checksum_valid(&self, payload: &[u8]) -> bool {
+        if self.checksum_present() {
+            0 == checksum::Sum16BitWords::new()
+                .add_slice(self.slice)
+                .add_slice(payload)
+                .ones_complement()
+        } else {
+            true
+        }
+    }
+
+    /// Decode all the fields and copy the results to a [`GreHeader`] struct
+    /// (routing information is not copied).
+    pub fn to_header(&self) -> GreHeader {
+        GreHeader {
+            version: self.version(),
+            protocol_type: self.protocol_type(),
+            checksum: self.checksum(),
+            key: self.key(),
+            sequence_number: self.sequence_number(),
+            acknowledgment_number: self.acknowledgment_number(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_gens::*;
+    use alloc::{format, vec::Vec};
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn from_slice(
+            input in gre_any(),
+            dummy_data in proptest::collection::vec(any::<u8>(), 0..20),
+            bad_version in 2u8..=7,
+        ) {
+            use err::gre::{HeaderError::*, HeaderSliceError::*};
+
+            // serialize
+            let mut buffer: Vec<u8> = Vec::with_capacity(input.header_len() + dummy_data.len());
+            input.write(&mut buffer).unwrap();
+            buffer.extend(&dummy_data[..]);
+
+            // calls with a valid result
+            {
+                let result = GreHeaderSlice::from_slice(&buffer[..]).unwrap();
+                assert_eq!(&buffer[..input.header_len()], result.slice());
+                assert_eq!(input.header_len(), result.header_len());
+            }
+
+            // call with not enough data in the slice
+            for len in 0..input.header_len() {
+                assert_eq!(
+                    GreHeaderSlice::from_slice(&buffer[..len]),
+                    Err(Len(err::LenError{
+                        required_len: if len < GreHeader::MIN_LEN {
+                            GreHeader::MIN_LEN
+                        } else {
+                            input.header_len()
+                        },
+                        len,
+                        len_source: err::LenSource::Slice,
+                        layer: err::Layer::GreHeader,
+                        layer_start_offset: 0,
+                    }))
+                );
+            }
+
+            // bad version
+            {
+                let mut buffer = buffer.clone();
+                buffer[1] = (buffer[1] & 0b1111_1000) | bad_version;
+                assert_eq!(
+                    GreHeaderSlice::from_slice(&buffer[..]),
+                    Err(Content(UnsupportedVersion{ version_number: bad_version }))
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn from_slice_routing() {
+        use err::gre::HeaderSliceError::*;
+
+        #[rustfmt::skip]
+        let data = [
+            // flags (R & s set, recursion control 3), version 0 & protocol type
+            0b0100_1011, 0, 0x08, 0x00,
+            // checksum & offset
+            0, 0, 0, 4,
+            // source route entry (IPv4 address family, 2 addresses)
+            0x08, 0x00, 0, 8,
+            1, 2, 3, 4, 5, 6, 7, 8,
+            // terminating "NULL" source route entry
+            0, 0, 0, 0,
+            // payload
+            0xff, 0xff,
+        ];
+        let slice = GreHeaderSlice::from_slice(&data).unwrap();
+        assert_eq!(24, slice.header_len());
+        assert_eq!(&data[..24], slice.slice());
+        assert!(slice.routing_present());
+        assert!(slice.strict_source_route());
+        assert_eq!(3, slice.recursion_control());
+        assert!(!slice.checksum_present());
+        assert_eq!(None, slice.checksum());
+        assert_eq!(Some(4), slice.offset());
+        assert_eq!(Some(&data[8..24]), slice.routing());
+        assert_eq!(ether_type::IPV4, slice.protocol_type());
+
+        // length errors in the source route entries
+        for len in 8..24 {
+            assert_eq!(
+                GreHeaderSlice::from_slice(&data[..len]),
+                Err(Len(err::LenError {
+                    required_len: if len < 12 { 12 } else { 24 },
+                    len,
+                    len_source: err::LenSource::Slice,
+                    layer: err::Layer::GreHeader,
+                    layer_start_offset: 0,
+                }))
+            );
+        }
+    }
+
+    #[test]
+    fn pptp() {
+        #[rustfmt::skip]
+        let data = [
+            // K & S flags, A flag & version 1, protocol type (PPP)
+            0b0011_0000, 0b1000_0001, 0x88, 0x0B,
+            // payload length & call id
+            0, 4, 0x12, 0x34,
+            // sequence number
+            0, 0, 0, 1,
+            // acknowledgment number
+            0, 0, 0, 2,
+        ];
+        let slice = GreHeaderSlice::from_slice(&data).unwrap();
+        assert_eq!(GreHeader::VERSION_ENHANCED, slice.version());
+        assert_eq!(EtherType(0x880B), slice.protocol_type());
+        assert_eq!(Some(0x0004_1234), slice.key());
+        assert_eq!(Some(4), slice.payload_length());
+        assert_eq!(Some(0x1234), slice.call_id());
+        assert_eq!(Some(1), slice.sequence_number());
+        assert_eq!(Some(2), slice.acknowledgment_number());
+        assert_eq!(None, slice.routing());
+        assert_eq!(None, slice.offset());
+
+        // the A flag is reserved in version 0 headers
+        let mut data = data;
+        data[1] = 0b1000_0000;
+        let slice = GreHeaderSlice::from_slice(&data).unwrap();
+        assert_eq!(12, slice.header_len());
+        assert!(!slice.acknowledgment_number_present());
+        assert_eq!(None, slice.acknowledgment_number());
+        assert_eq!(None, slice.payload_length());
+        assert_eq!(None, slice.call_id());
+    }
+
+    proptest! {
+        #[test]
+        fn getters(input in gre_any()) {
+            let bytes = input.to_bytes();
+            let slice = GreHeaderSlice::from_slice(&bytes).unwrap();
+            assert_eq!(input.version, slice.version());
+            assert_eq!(input.protocol_type, slice.protocol_type());
+            assert_eq!(input.checksum, slice.checksum());
+            assert_eq!(input.checksum.map(|_| 0), slice.offset());
+            assert_eq!(input.key, slice.key());
+            assert_eq!(input.sequence_number, slice.sequence_number());
+            assert_eq!(input.acknowledgment_number, slice.acknowledgment_number());
+            assert!(!slice.routing_present());
+            assert_eq!(None, slice.routing());
+            assert!(!slice.strict_source_route());
+            assert_eq!(0, slice.recursion_control());
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn to_header(input in gre_any()) {
+            let bytes = input.to_bytes();
+            let slice = GreHeaderSlice::from_slice(&bytes).unwrap();
+            assert_eq!(input, slice.to_header());
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn checksum(
+            input in gre_any(),
+            payload in proptest::collection::vec(any::<u8>(), 0..20)
+        ) {
+            // without checksum
+            {
+                let mut header = input.clone();
+                header.checksum = None;
+                let bytes = header.to_bytes();
+                let slice = GreHeaderSlice::from_slice(&bytes).unwrap();
+                assert_eq!(None, slice.calc_checksum(&payload));
+                assert!(slice.is_checksum_valid(&payload));
+            }
+
+            // with checksum
+            {
+                let mut header = input.clone();
+                header.update_checksum(&payload);
+                let bytes = header.to_bytes();
+                let slice = GreHeaderSlice::from_slice(&bytes).unwrap();
+                assert_eq!(header.checksum, slice.calc_checksum(&payload));
+                assert!(slice.is_checksum_valid(&payload));
+
+                // modified payload
+                let mut payload = payload.clone();
+                payload.push(1);
+                assert!(!slice.is_checksum_valid(&payload));
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn clone_eq_dbg(input in gre_any()) {
+            let bytes = input.to_bytes();
+            let slice = GreHeaderSlice::from_slice(&bytes).unwrap();
+            assert_eq!(slice, slice.clone());
+            assert_eq!(
+                format!("GreHeaderSlice {{ slice: {:?} }}", slice.slice()),
+                format!("{:?}", slice)
+            );
+        }
+    }
+}
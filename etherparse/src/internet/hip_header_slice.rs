@@ -0,0 +1,148 @@
+use crate::{err::ipv6_exts::HipHeaderError, *};
+
+/// Slice containing a Host Identity Protocol (HIP) header (RFC 7401).
+///
+/// ```text
+///  0               1               2               3
+/// +---------------+---------------+-+-------------+-------+-----+-+
+/// |  Next Header  | Header Length |0| Packet Type |Version| RES.|1|
+/// +---------------+---------------+-+-------------+-------+-----+-+
+/// |           Checksum            |           Controls            |
+/// +-------------------------------+-------------------------------+
+/// |                Sender's Host Identity Tag (HIT)               |
+/// |                           (16 bytes)                          |
+/// +---------------------------------------------------------------+
+/// |               Receiver's Host Identity Tag (HIT)              |
+/// |                           (16 bytes)                          |
+/// +---------------------------------------------------------------+
+/// |                        HIP Parameters ...                     |
+/// +---------------------------------------------------------------+
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HipHeaderSlice<'a> {
+    /// Slice containing the complete header.
+    slice: &'a [u8],
+}
+
+impl<'a> HipHeaderSlice<'a> {
+    /// Minimum length of a HIP header (without parameters) in bytes/octets.
+    pub const MIN_LEN: usize = 40;
+
+    /// Creates a HIP header slice from a raw IPv6 extension header slice
+    /// and verifies the header is big enough to contain the fixed fields.
+    pub fn from_raw(raw: &Ipv6RawExtHeaderSlice<'a>) -> Result<HipHeaderSlice<'a>, HipHeaderError> {
+        let slice = raw.slice();
+        if slice.len() < HipHeaderSlice::MIN_LEN {
+            return Err(HipHeaderError::HeaderTooShort {
+                hdr_ext_len: slice[1],
+            });
+        }
+        Ok(HipHeaderSlice { slice })
+    }
+
+    /// Returns the slice containing the complete header.
+    #[inline]
+    pub fn slice(&self) -> &'a [u8] {
+        self.slice
+    }
+
+    /// Returns the IP protocol number of the next header or transport layer protocol.
+    #[inline]
+    pub fn next_header(&self) -> IpNumber {
+        IpNumber(self.slice[0])
+    }
+
+    /// Returns the "packet type" field (e.g. `1` for I1 packets).
+    #[inline]
+    pub fn packet_type(&self) -> u8 {
+        self.slice[2] & 0x7f
+    }
+
+    /// Returns the "version" field.
+    #[inline]
+    pub fn version(&self) -> u8 {
+        self.slice[3] >> 4
+    }
+
+    /// Returns the "checksum" field.
+    #[inline]
+    pub fn checksum(&self) -> u16 {
+        u16::from_be_bytes([self.slice[4], self.slice[5]])
+    }
+
+    /// Returns the "controls" field.
+    #[inline]
+    pub fn controls(&self) -> u16 {
+        u16::from_be_bytes([self.slice[6], self.slice[7]])
+    }
+
+    /// Returns the host identity tag of the sender.
+    #[inline]
+    pub fn sender_hit(&self) -> [u8; 16] {
+        let mut result = [0; 16];
+        result.copy_from_slice(&self.slice[8..24]);
+        result
+    }
+
+    /// Returns the host identity tag of the receiver.
+    #[inline]
+    pub fn receiver_hit(&self) -> [u8; 16] {
+        let mut result = [0; 16];
+        result.copy_from_slice(&self.slice[24..40]);
+        result
+    }
+
+    /// Returns the slice containing the encoded HIP parameters.
+    #[inline]
+    pub fn parameters(&self) -> &'a [u8] {
+        &self.slice[HipHeaderSlice::MIN_LEN..]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn from_raw_getters() {
+        #[rustfmt::skip]
+        let bytes = [
+            ip_number::IPV6_NO_NEXT_HEADER.0, 5, 0x81, 0x21,
+            0x12, 0x34, 0x56, 0x78,
+            1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16,
+            17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32,
+            41, 42, 43, 44, 45, 46, 47, 48,
+        ];
+        let raw = Ipv6RawExtHeaderSlice::from_slice(&bytes).unwrap();
+        let header = HipHeaderSlice::from_raw(&raw).unwrap();
+        assert_eq!(header.slice(), &bytes[..]);
+        assert_eq!(header.next_header(), ip_number::IPV6_NO_NEXT_HEADER);
+        assert_eq!(header.packet_type(), 1);
+        assert_eq!(header.version(), 2);
+        assert_eq!(header.checksum(), 0x1234);
+        assert_eq!(header.controls(), 0x5678);
+        assert_eq!(
+            header.sender_hit(),
+            [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16]
+        );
+        assert_eq!(
+            header.receiver_hit(),
+            [17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32]
+        );
+        assert_eq!(header.parameters(), &bytes[40..]);
+        assert_eq!(header.clone(), header);
+    }
+
+    #[test]
+    fn from_raw_error() {
+        for hdr_ext_len in 0..4u8 {
+            let mut bytes = [0u8; 32];
+            bytes[1] = hdr_ext_len;
+            let raw = Ipv6RawExtHeaderSlice::from_slice(&bytes).unwrap();
+            assert_eq!(
+                Err(HipHeaderError::HeaderTooShort { hdr_ext_len }),
+                HipHeaderSlice::from_raw(&raw)
+            );
+        }
+    }
+}
@@ -86,7 +86,6 @@ impl<'a> InternetSlice<'a> {
     /// in the given slice and determine the sub-slice containing the payload
     /// of the IP packet.
     pub fn from_ip_slice(slice: &[u8]) -> Result<InternetSlice, err::ip::SliceError> {
-        use crate::ip_number::AUTH;
         use err::ip::SliceError::*;
         use InternetSlice::*;
 
@@ -168,57 +167,31 @@ impl<'a> InternetSlice<'a> {
                     };
 
                     // slice extension headers
-                    // decode the authentification header if needed
                     let fragmented = header.is_fragmenting_payload();
-                    match header.protocol() {
-                        AUTH => {
-                            use crate::err::ip_auth::HeaderSliceError as E;
-
-                            // parse extension headers
-                            let auth = match IpAuthHeaderSlice::from_slice(header_payload) {
-                                Ok(s) => s,
-                                Err(err) => match err {
+                    let (exts, ip_number, payload) =
+                        Ipv4ExtensionsSlice::from_slice(header.protocol(), header_payload)
+                            .map_err(|err| {
+                                use crate::err::ip_auth::HeaderSliceError as E;
+                                match err {
                                     E::Len(mut l) => {
                                         // change the length source to the ipv4 header
                                         l.len_source = LenSource::Ipv4HeaderTotalLen;
                                         l.layer_start_offset += header.slice().len();
-                                        return Err(Len(l));
-                                    }
-                                    E::Content(err) => {
-                                        return Err(IpHeader(ip::HeaderError::Ipv4Ext(err)))
+                                        Len(l)
                                     }
-                                },
-                            };
-
-                            // remove the extension header from the payload
-                            let payload = unsafe {
-                                core::slice::from_raw_parts(
-                                    header_payload.as_ptr().add(auth.slice().len()),
-                                    header_payload.len() - auth.slice().len(),
-                                )
-                            };
-                            Ok(Ipv4(Ipv4Slice {
-                                header,
-                                exts: Ipv4ExtensionsSlice { auth: Some(auth) },
-                                payload: IpPayload {
-                                    ip_number: auth.next_header(),
-                                    fragmented,
-                                    len_source: LenSource::Ipv4HeaderTotalLen,
-                                    payload,
-                                },
-                            }))
-                        }
-                        ip_number => Ok(Ipv4(Ipv4Slice {
-                            header,
-                            exts: Ipv4ExtensionsSlice { auth: None },
-                            payload: IpPayload {
-                                ip_number,
-                                fragmented,
-                                len_source: LenSource::Ipv4HeaderTotalLen,
-                                payload: header_payload,
-                            },
-                        })),
-                    }
+                                    E::Content(err) => IpHeader(ip::HeaderError::Ipv4Ext(err)),
+                                }
+                            })?;
+                    Ok(Ipv4(Ipv4Slice {
+                        header,
+                        exts,
+                        payload: IpPayload {
+                            ip_number,
+                            fragmented,
+                            len_source: LenSource::Ipv4HeaderTotalLen,
+                            payload,
+                        },
+                    }))
                 }
                 6 => {
                     // check length
@@ -240,45 +213,12 @@ impl<'a> InternetSlice<'a> {
                     };
 
                     // restrict slice by the length specified in the header
-                    let (header_payload, len_source) =
-                        if 0 == header.payload_length() && slice.len() > Ipv6Header::LEN {
-                            // In case the payload_length is 0 assume that the entire
-                            // rest of the slice is part of the packet until the jumbogram
-                            // parameters can be parsed.
-
-                            // TODO: Add payload length parsing from the jumbogram
-                            (
-                                unsafe {
-                                    core::slice::from_raw_parts(
-                                        slice.as_ptr().add(Ipv6Header::LEN),
-                                        slice.len() - Ipv6Header::LEN,
-                                    )
-                                },
-                                LenSource::Slice,
-                            )
-                        } else {
-                            let payload_len = usize::from(header.payload_length());
-                            let expected_len = Ipv6Header::LEN + payload_len;
-                            if slice.len() < expected_len {
-                                return Err(Len(LenError {
-                                    required_len: expected_len,
-                                    len: slice.len(),
-                                    len_source: LenSource::Slice,
-                                    layer: Layer::Ipv6Packet,
-                                    layer_start_offset: 0,
-                                }));
-                            } else {
-                                (
-                                    unsafe {
-                                        core::slice::from_raw_parts(
-                                            slice.as_ptr().add(Ipv6Header::LEN),
-                                            payload_len,
-                                        )
-                                    },
-                                    LenSource::Ipv6HeaderPayloadLen,
-                                )
-                            }
-                        };
+                    let (header_payload, len_source) = Ipv6Slice::header_payload(
+                        header.payload_length(),
+                        header.next_header(),
+                        slice,
+                    )
+                    .map_err(Len)?;
 
                     // parse extension headers
                     let (exts, payload_ip_number, payload) =
@@ -288,7 +228,7 @@ impl<'a> InternetSlice<'a> {
                                 use crate::err::ipv6_exts::HeaderSliceError as I;
                                 match err {
                                     I::Len(mut err) => {
-                                        err.len_source = LenSource::Ipv6HeaderPayloadLen;
+                                        err.len_source = len_source;
                                         err.layer_start_offset += Ipv6Header::LEN;
                                         Len(err)
                                     }
@@ -704,4 +644,30 @@ mod test {
 
         }
     }
+
+    #[test]
+    fn from_ip_slice_jumbogram() {
+        let mut hop_by_hop = Ipv6RawExtHeader::try_from_options(ip_number::UDP, &[]).unwrap();
+        hop_by_hop.set_jumbo_payload_len(16 + 4).unwrap();
+        let ipv6 = Ipv6Header {
+            payload_length: 0,
+            next_header: ip_number::IPV6_HOP_BY_HOP,
+            ..Default::default()
+        };
+        let mut buffer = Vec::new();
+        buffer.extend_from_slice(&ipv6.to_bytes());
+        buffer.extend_from_slice(&hop_by_hop.to_bytes());
+        buffer.extend_from_slice(&[1, 2, 3, 4, 5, 6]);
+
+        let actual = InternetSlice::from_ip_slice(&buffer).unwrap();
+        assert_eq!(
+            actual.payload(),
+            &IpPayload {
+                ip_number: ip_number::UDP,
+                fragmented: false,
+                len_source: LenSource::Ipv6JumboPayloadLen,
+                payload: &[1, 2, 3, 4],
+            }
+        );
+    }
 }
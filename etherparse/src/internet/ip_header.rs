@@ -175,44 +175,8 @@ impl IpHeader {
 
                     // restrict slice by the length specified in the header
                     let (header_payload, len_source) =
-                        if 0 == header.payload_length && slice.len() > Ipv6Header::LEN {
-                            // In case the payload_length is 0 assume that the entire
-                            // rest of the slice is part of the packet until the jumbogram
-                            // parameters can be parsed.
-
-                            // TODO: Add payload length parsing from the jumbogram
-                            unsafe {
-                                (
-                                    core::slice::from_raw_parts(
-                                        slice.as_ptr().add(Ipv6Header::LEN),
-                                        slice.len() - Ipv6Header::LEN,
-                                    ),
-                                    LenSource::Slice,
-                                )
-                            }
-                        } else {
-                            let payload_len: usize = header.payload_length.into();
-                            let expected_len = Ipv6Header::LEN + payload_len;
-                            if slice.len() < expected_len {
-                                return Err(Len(LenError {
-                                    required_len: expected_len,
-                                    len: slice.len(),
-                                    len_source: LenSource::Slice,
-                                    layer: Layer::Ipv6Packet,
-                                    layer_start_offset: 0,
-                                }));
-                            } else {
-                                unsafe {
-                                    (
-                                        core::slice::from_raw_parts(
-                                            slice.as_ptr().add(Ipv6Header::LEN),
-                                            payload_len,
-                                        ),
-                                        LenSource::Ipv6HeaderPayloadLen,
-                                    )
-                                }
-                            }
-                        };
+                        Ipv6Slice::header_payload(header.payload_length, header.next_header, slice)
+                            .map_err(Len)?;
 
                     let (exts, next_header, rest) =
                         Ipv6Extensions::from_slice(header.next_header, header_payload).map_err(
@@ -322,16 +286,17 @@ impl IpHeader {
     /// containing the payload (e.g. TCP, UDP etc.) length limited by payload_length
     /// field in the IPv6 header.
     ///
-    /// Note that slice length is used as a fallback value in case the
-    /// payload_length in the IPv6 is set to zero. This is a temporary workaround
-    /// to partially support jumbograms.
+    /// In case the payload_length in the IPv6 header is set to zero the length
+    /// is taken from the "Jumbo Payload" option in the hop-by-hop header
+    /// (jumbograms, RFC 2675). If no such option is present the slice length
+    /// is used as a fallback value.
     pub fn ipv6_from_slice(
         slice: &[u8],
     ) -> Result<(IpHeader, IpPayload<'_>), err::ipv6::SliceError> {
         use err::ipv6::SliceError::*;
 
         // read ipv6 header
-        let (header, header_rest) = Ipv6Header::from_slice(slice).map_err(|err| {
+        let (header, _) = Ipv6Header::from_slice(slice).map_err(|err| {
             use err::ipv6::HeaderSliceError as I;
             match err {
                 I::Len(err) => Len(err),
@@ -341,32 +306,8 @@ impl IpHeader {
 
         // restrict slice by the length specified in the header
         let (header_payload, len_source) =
-            if 0 == header.payload_length && slice.len() > Ipv6Header::LEN {
-                // In case the payload_length is 0 assume that the entire
-                // rest of the slice is part of the packet until the jumbogram
-                // parameters can be parsed.
-
-                // TODO: Add payload length parsing from the jumbogram
-                (header_rest, LenSource::Slice)
-            } else {
-                let payload_len: usize = header.payload_length.into();
-                if header_rest.len() < payload_len {
-                    return Err(Len(LenError {
-                        required_len: payload_len + Ipv6Header::LEN,
-                        len: slice.len(),
-                        len_source: LenSource::Slice,
-                        layer: Layer::Ipv6Packet,
-                        layer_start_offset: 0,
-                    }));
-                } else {
-                    unsafe {
-                        (
-                            core::slice::from_raw_parts(header_rest.as_ptr(), payload_len),
-                            LenSource::Ipv6HeaderPayloadLen,
-                        )
-                    }
-                }
-            };
+            Ipv6Slice::header_payload(header.payload_length, header.next_header, slice)
+                .map_err(Len)?;
 
         // read ipv6 extensions headers
         let (exts, next_header, exts_rest) =
@@ -572,7 +513,9 @@ impl IpHeader {
     /// of the ip header an error is returned.
     ///
     /// Note that this function will automatically add the length of the extension
-    /// headers is they are present.
+    /// headers is they are present. For IPv6 a "Jumbo Payload" option is added
+    /// in case the length does not fit into the "payload length" field (see
+    /// [`Ipv6Extensions::set_payload_length`]).
     pub fn set_payload_len(&mut self, len: usize) -> Result<(), ValueTooBigError<usize>> {
         use crate::err::ValueType;
         match self {
@@ -589,17 +532,7 @@ impl IpHeader {
                     })
                 }
             }
-            IpHeader::Version6(ipv6_hdr, exts) => {
-                if let Some(complete_len) = len.checked_add(exts.header_len()) {
-                    ipv6_hdr.set_payload_length(complete_len)
-                } else {
-                    Err(ValueTooBigError {
-                        actual: len,
-                        max_allowed: usize::from(u16::MAX) - exts.header_len(),
-                        value_type: ValueType::Ipv4PayloadLength,
-                    })
-                }
-            }
+            IpHeader::Version6(ipv6_hdr, exts) => exts.set_payload_length(ipv6_hdr, len),
         }
     }
 
@@ -955,6 +888,48 @@ mod test {
         }
     }
 
+    #[test]
+    fn from_slice_jumbogram() {
+        let payload = [1, 2, 3, 4];
+        let ipv6 = Ipv6Header {
+            payload_length: 0,
+            next_header: IPV6_HOP_BY_HOP,
+            ..Default::default()
+        };
+        let exts = Ipv6Extensions {
+            hop_by_hop_options: Some({
+                let mut h = Ipv6RawExtHeader::try_from_options(UDP, &[]).unwrap();
+                h.set_jumbo_payload_len(16 + 4).unwrap();
+                h
+            }),
+            ..Default::default()
+        };
+
+        let mut buffer = Vec::new();
+        ipv6.write(&mut buffer).unwrap();
+        exts.write(&mut buffer, IPV6_HOP_BY_HOP).unwrap();
+        buffer.extend_from_slice(&payload);
+        buffer.extend_from_slice(&[5, 6]);
+
+        let expected_payload = IpPayload {
+            ip_number: UDP,
+            fragmented: false,
+            len_source: LenSource::Ipv6JumboPayloadLen,
+            payload: &payload,
+        };
+        assert_eq!(
+            IpHeader::from_slice(&buffer).unwrap(),
+            (
+                IpHeader::Version6(ipv6.clone(), exts.clone()),
+                expected_payload.clone()
+            )
+        );
+        assert_eq!(
+            IpHeader::ipv6_from_slice(&buffer).unwrap(),
+            (IpHeader::Version6(ipv6, exts), expected_payload)
+        );
+    }
+
     proptest! {
         #[test]
         fn read(
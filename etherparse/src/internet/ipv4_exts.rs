@@ -4,12 +4,13 @@ use crate::{err::ipv4_exts::ExtsWalkError, *};
 ///
 /// Currently supported:
 /// * Authentication Header
-///
-/// Currently not supported:
-/// - Encapsulating Security Payload Header (ESP)
+/// * Encapsulating Security Payload Header (ESP)
 #[derive(Clone, Debug, Eq, PartialEq, Default)]
 pub struct Ipv4Extensions {
     pub auth: Option<IpAuthHeader>,
+    /// ESP header (the data after the header is encrypted and
+    /// part of the payload).
+    pub esp: Option<EspHeader>,
 }
 
 impl Ipv4Extensions {
@@ -18,10 +19,14 @@ impl Ipv4Extensions {
     pub const MIN_LEN: usize = 0;
 
     /// Maximum summed up length of all extension headers in bytes/octets.
-    pub const MAX_LEN: usize = IpAuthHeader::MAX_LEN;
+    pub const MAX_LEN: usize = IpAuthHeader::MAX_LEN + EspHeader::LEN;
 
     /// Read all known ipv4 extensions and return an `Ipv4ExtensionSlices` with the
     /// identified slices, the final ip number and a slice pointing to the non parsed data.
+    ///
+    /// If an ESP header is present the returned ip number is
+    /// [`ip_number::ENCAP_SEC`] as the actual "next header" value is
+    /// part of the encrypted ESP trailer.
     pub fn from_slice(
         start_protocol: IpNumber,
         slice: &[u8],
@@ -37,14 +42,20 @@ impl Ipv4Extensions {
         reader: &mut T,
         start_ip_number: IpNumber,
     ) -> Result<(Ipv4Extensions, IpNumber), err::ip_auth::HeaderReadError> {
+        use err::ip_auth::HeaderReadError::Io;
         use ip_number::*;
-        if AUTH == start_ip_number {
+
+        let mut result = Ipv4Extensions::default();
+        let mut next_ip_number = start_ip_number;
+        if AUTH == next_ip_number {
             let header = IpAuthHeader::read(reader)?;
-            let next_ip_number = header.next_header;
-            Ok((Ipv4Extensions { auth: Some(header) }, next_ip_number))
-        } else {
-            Ok((Default::default(), start_ip_number))
+            next_ip_number = header.next_header;
+            result.auth = Some(header);
         }
+        if ENCAP_SEC == next_ip_number {
+            result.esp = Some(EspHeader::read(reader).map_err(Io)?);
+        }
+        Ok((result, next_ip_number))
     }
 
     /// Reads the known ipv4 extension headers from a length limited reader and returns the
@@ -56,13 +67,25 @@ impl Ipv4Extensions {
         start_ip_number: IpNumber,
     ) -> Result<(Ipv4Extensions, IpNumber), err::ip_auth::HeaderLimitedReadError> {
         use ip_number::*;
-        if AUTH == start_ip_number {
+
+        let mut result = Ipv4Extensions::default();
+        let mut next_ip_number = start_ip_number;
+        if AUTH == next_ip_number {
             let header = IpAuthHeader::read_limited(reader)?;
-            let next_ip_number = header.next_header;
-            Ok((Ipv4Extensions { auth: Some(header) }, next_ip_number))
-        } else {
-            Ok((Default::default(), start_ip_number))
+            next_ip_number = header.next_header;
+            result.auth = Some(header);
         }
+        if ENCAP_SEC == next_ip_number {
+            result.esp = Some(EspHeader::read_limited(reader).map_err(|err| {
+                use err::io::LimitedReadError as I;
+                use err::ip_auth::HeaderLimitedReadError as O;
+                match err {
+                    I::Io(err) => O::Io(err),
+                    I::Len(err) => O::Len(err),
+                }
+            })?);
+        }
+        Ok((result, next_ip_number))
     }
 
     /// Write the extensions to the writer.
@@ -74,27 +97,32 @@ impl Ipv4Extensions {
     ) -> Result<(), err::ipv4_exts::HeaderWriteError> {
         use err::ipv4_exts::{ExtsWalkError::*, HeaderWriteError::*};
         use ip_number::*;
-        match self.auth {
-            Some(ref header) => {
-                if AUTH == start_ip_number {
-                    header.write(writer).map_err(Io)
-                } else {
-                    Err(Content(ExtNotReferenced {
-                        missing_ext: IpNumber::AUTHENTICATION_HEADER,
-                    }))
-                }
+
+        let mut next_ip_number = start_ip_number;
+        if let Some(ref header) = self.auth {
+            if AUTH != next_ip_number {
+                return Err(Content(ExtNotReferenced {
+                    missing_ext: IpNumber::AUTHENTICATION_HEADER,
+                }));
+            }
+            header.write(writer).map_err(Io)?;
+            next_ip_number = header.next_header;
+        }
+        if let Some(ref header) = self.esp {
+            if ENCAP_SEC != next_ip_number {
+                return Err(Content(ExtNotReferenced {
+                    missing_ext: IpNumber::ENCAPSULATING_SECURITY_PAYLOAD,
+                }));
             }
-            None => Ok(()),
+            header.write(writer).map_err(Io)?;
         }
+        Ok(())
     }
 
     ///Length of the all present headers in bytes.
     pub fn header_len(&self) -> usize {
-        if let Some(ref header) = self.auth {
-            header.header_len()
-        } else {
-            0
-        }
+        self.auth.as_ref().map(|h| h.header_len()).unwrap_or(0)
+            + self.esp.as_ref().map(|h| h.header_len()).unwrap_or(0)
     }
 
     /// Sets all the next_header fields of the headers based on the adviced default order
@@ -103,11 +131,18 @@ impl Ipv4Extensions {
     /// protocol_number.
     ///
     /// If no extension headers are present the value of the argument is returned.
+    ///
+    /// If an ESP header is present `last_protocol_number` is not stored
+    /// in any header, as the "next header" field of ESP is part of the
+    /// encrypted ESP trailer.
     pub fn set_next_headers(&mut self, last_protocol_number: IpNumber) -> IpNumber {
         use ip_number::*;
 
         let mut next = last_protocol_number;
 
+        if self.esp.is_some() {
+            next = ENCAP_SEC;
+        }
         if let Some(ref mut header) = self.auth {
             header.next_header = next;
             next = AUTH;
@@ -119,26 +154,35 @@ impl Ipv4Extensions {
     /// Return next header based on the extension headers and
     /// the first ip protocol number.
     ///
+    /// If an ESP header is present [`ip_number::ENCAP_SEC`] is
+    /// returned (the actual next header is part of the encrypted
+    /// ESP trailer).
+    ///
     /// In case a header is never referenced a
     /// [`err::ipv4_exts::ExtsWalkError::ExtNotReferenced`] is returned.
     pub fn next_header(&self, first_next_header: IpNumber) -> Result<IpNumber, ExtsWalkError> {
         use ip_number::*;
+
+        let mut next = first_next_header;
         if let Some(ref auth) = self.auth {
-            if first_next_header == AUTH {
-                Ok(auth.next_header)
-            } else {
-                Err(ExtsWalkError::ExtNotReferenced {
+            if next != AUTH {
+                return Err(ExtsWalkError::ExtNotReferenced {
                     missing_ext: IpNumber::AUTHENTICATION_HEADER,
-                })
+                });
             }
-        } else {
-            Ok(first_next_header)
+            next = auth.next_header;
+        }
+        if self.esp.is_some() && next != ENCAP_SEC {
+            return Err(ExtsWalkError::ExtNotReferenced {
+                missing_ext: IpNumber::ENCAPSULATING_SECURITY_PAYLOAD,
+            });
         }
+        Ok(next)
     }
 
     /// Returns true if no IPv4 extension header is present (all fields `None`).
     #[inline]
     pub fn is_empty(&self) -> bool {
-        self.auth.is_none()
+        self.auth.is_none() && self.esp.is_none()
     }
 }
@@ -4,46 +4,59 @@ use crate::*;
 ///
 /// Currently supported:
 /// * Authentication Header
-///
-/// Currently not supported:
 /// * Encapsulating Security Payload Header (ESP)
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Default)]
 pub struct Ipv4ExtensionsSlice<'a> {
     pub auth: Option<IpAuthHeaderSlice<'a>>,
+    /// ESP header (the data after the header is encrypted and
+    /// part of the payload).
+    pub esp: Option<EspHeaderSlice<'a>>,
 }
 
 impl<'a> Ipv4ExtensionsSlice<'a> {
     /// Read all known ipv4 extensions and return an `Ipv4ExtensionSlices` with the
     /// identified slices, the final ip number and a slice pointing to the non parsed data.
+    ///
+    /// If an ESP header is present the returned ip number is
+    /// [`ip_number::ENCAP_SEC`] as the actual "next header" value is
+    /// part of the encrypted ESP trailer.
     pub fn from_slice(
         start_ip_number: IpNumber,
         start_slice: &'a [u8],
     ) -> Result<(Ipv4ExtensionsSlice, IpNumber, &[u8]), err::ip_auth::HeaderSliceError> {
+        use err::ip_auth::HeaderSliceError::Len;
         use ip_number::*;
-        if AUTH == start_ip_number {
-            let header = IpAuthHeaderSlice::from_slice(start_slice)?;
-            let rest = &start_slice[header.slice().len()..];
-            let next_header = header.next_header();
-            Ok((
-                Ipv4ExtensionsSlice { auth: Some(header) },
-                next_header,
-                rest,
-            ))
-        } else {
-            Ok((Default::default(), start_ip_number, start_slice))
+
+        let mut result = Ipv4ExtensionsSlice::default();
+        let mut next_header = start_ip_number;
+        let mut rest = start_slice;
+
+        if AUTH == next_header {
+            let header = IpAuthHeaderSlice::from_slice(rest)?;
+            rest = &rest[header.slice().len()..];
+            next_header = header.next_header();
+            result.auth = Some(header);
+        }
+        if ENCAP_SEC == next_header {
+            let header = EspHeaderSlice::from_slice(rest)
+                .map_err(|err| Len(err.add_offset(start_slice.len() - rest.len())))?;
+            rest = &rest[header.slice().len()..];
+            result.esp = Some(header);
         }
+        Ok((result, next_header, rest))
     }
 
     /// Convert the slices into actual headers.
     pub fn to_header(&self) -> Ipv4Extensions {
         Ipv4Extensions {
             auth: self.auth.as_ref().map(|v| v.to_header()),
+            esp: self.esp.as_ref().map(|v| v.to_header()),
         }
     }
 
     /// Returns true if no IPv4 extension header is present (all fields `None`).
     #[inline]
     pub fn is_empty(&self) -> bool {
-        self.auth.is_none()
+        self.auth.is_none() && self.esp.is_none()
     }
 }
@@ -306,6 +306,12 @@ impl<'a> Ipv4HeaderSlice<'a> {
         unsafe { from_raw_parts(self.slice.as_ptr().add(20), self.slice.len() - 20) }
     }
 
+    /// Returns an iterator over the typed elements of the ipv4 header options.
+    #[inline]
+    pub fn options_iterator(&self) -> Ipv4OptionsIterator<'a> {
+        Ipv4OptionsIterator::from_slice(self.options())
+    }
+
     /// Returns true if the payload is fragmented.
     ///
     /// Either data is missing (more_fragments set) or there is
@@ -472,6 +478,7 @@ mod test {
             assert_eq!(slice.source(), header.source);
             assert_eq!(slice.destination(), header.destination);
             assert_eq!(slice.options(), &header.options[..]);
+            assert_eq!(slice.options_iterator(), header.options.elements_iter());
         }
     }
 
@@ -0,0 +1,206 @@
+/// Different kinds of options that can be present in the options part
+/// of an IPv4 header.
+///
+/// Variable length data of the options references the underlying
+/// options data (no copies are made when decoding the options).
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum Ipv4OptionElement<'a> {
+    /// "No Operation" option (type 1).
+    ///
+    /// Can be used between options to align the beginning of a
+    /// subsequent option on a 32 bit boundary.
+    Noop,
+
+    /// "Record Route" option (type 7, RFC 791).
+    RecordRoute {
+        /// Octet offset (starting at 1 with the option type) of the
+        /// next free route entry.
+        pointer: u8,
+        /// Recorded route (IPv4 addresses).
+        route: &'a [[u8; 4]],
+    },
+
+    /// "Loose Source and Record Route" option (type 131, RFC 791).
+    LooseSourceRoute {
+        /// Octet offset (starting at 1 with the option type) of the
+        /// next source address to be processed.
+        pointer: u8,
+        /// Route data (IPv4 addresses).
+        route: &'a [[u8; 4]],
+    },
+
+    /// "Strict Source and Record Route" option (type 137, RFC 791).
+    StrictSourceRoute {
+        /// Octet offset (starting at 1 with the option type) of the
+        /// next source address to be processed.
+        pointer: u8,
+        /// Route data (IPv4 addresses).
+        route: &'a [[u8; 4]],
+    },
+
+    /// "Internet Timestamp" option (type 68, RFC 791).
+    Timestamp {
+        /// Octet offset (starting at 1 with the option type) of the
+        /// next free timestamp entry.
+        pointer: u8,
+        /// Number of IP modules that could not register timestamps
+        /// due to lack of space (4 bits).
+        overflow: u8,
+        /// Format of the data (4 bits, 0 = timestamps only, 1 = address
+        /// & timestamp pairs, 3 = prespecified addresses & timestamps).
+        flags: u8,
+        /// Timestamps (and addresses depending on the flags) as 32 bit words.
+        data: &'a [[u8; 4]],
+    },
+
+    /// "Router Alert" option (type 148, RFC 2113).
+    ///
+    /// A value of `0` indicates that routers shall examine the packet.
+    RouterAlert(u16),
+
+    /// "Commercial IP Security Option" (CIPSO, type 134).
+    Cipso {
+        /// Domain of interpretation.
+        doi: u32,
+        /// Encoded tags following the domain of interpretation.
+        tags: &'a [u8],
+    },
+
+    /// Option with a type not explicitly supported by etherparse.
+    Unknown {
+        /// Option type (first byte of the option).
+        option_type: u8,
+        /// Data of the option after the type & length bytes.
+        data: &'a [u8],
+    },
+}
+
+impl<'a> Ipv4OptionElement<'a> {
+    /// Returns the type number of the option (first byte of the option).
+    pub fn option_type(&self) -> u8 {
+        use crate::ipv4_option::*;
+        use Ipv4OptionElement::*;
+        match self {
+            Noop => TYPE_NOOP,
+            RecordRoute { .. } => TYPE_RECORD_ROUTE,
+            LooseSourceRoute { .. } => TYPE_LOOSE_SOURCE_ROUTE,
+            StrictSourceRoute { .. } => TYPE_STRICT_SOURCE_ROUTE,
+            Timestamp { .. } => TYPE_TIMESTAMP,
+            RouterAlert(_) => TYPE_ROUTER_ALERT,
+            Cipso { .. } => TYPE_CIPSO,
+            Unknown { option_type, .. } => *option_type,
+        }
+    }
+
+    /// Returns the length of the serialized option in bytes (including
+    /// the type & length bytes).
+    pub fn header_len(&self) -> usize {
+        use crate::ipv4_option::*;
+        use Ipv4OptionElement::*;
+        match self {
+            Noop => usize::from(LEN_NOOP),
+            RecordRoute { route, .. }
+            | LooseSourceRoute { route, .. }
+            | StrictSourceRoute { route, .. } => usize::from(MIN_LEN_ROUTE) + route.len() * 4,
+            Timestamp { data, .. } => usize::from(MIN_LEN_TIMESTAMP) + data.len() * 4,
+            RouterAlert(_) => usize::from(LEN_ROUTER_ALERT),
+            Cipso { tags, .. } => usize::from(MIN_LEN_CIPSO) + tags.len(),
+            Unknown { data, .. } => 2 + data.len(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+    use alloc::format;
+
+    #[test]
+    fn clone_eq() {
+        use Ipv4OptionElement::*;
+        let values = [
+            Noop,
+            RecordRoute {
+                pointer: 4,
+                route: &[[1, 2, 3, 4]],
+            },
+            RouterAlert(0),
+            Unknown {
+                option_type: 2,
+                data: &[1, 2],
+            },
+        ];
+        for value in values {
+            assert_eq!(value.clone(), value);
+        }
+    }
+
+    #[test]
+    fn debug() {
+        use Ipv4OptionElement::*;
+        assert_eq!("Noop", format!("{:?}", Noop));
+        assert_eq!("RouterAlert(0)", format!("{:?}", RouterAlert(0)));
+        assert_eq!(
+            "RecordRoute { pointer: 4, route: [[1, 2, 3, 4]] }",
+            format!(
+                "{:?}",
+                RecordRoute {
+                    pointer: 4,
+                    route: &[[1, 2, 3, 4]]
+                }
+            )
+        );
+    }
+
+    #[test]
+    fn option_type_header_len() {
+        use ipv4_option::*;
+        use Ipv4OptionElement::*;
+        let route: &[[u8; 4]] = &[[1, 2, 3, 4], [5, 6, 7, 8]];
+        let tests = [
+            (Noop, TYPE_NOOP, 1),
+            (RecordRoute { pointer: 4, route }, TYPE_RECORD_ROUTE, 11),
+            (
+                LooseSourceRoute { pointer: 4, route },
+                TYPE_LOOSE_SOURCE_ROUTE,
+                11,
+            ),
+            (
+                StrictSourceRoute { pointer: 4, route },
+                TYPE_STRICT_SOURCE_ROUTE,
+                11,
+            ),
+            (
+                Timestamp {
+                    pointer: 5,
+                    overflow: 0,
+                    flags: 0,
+                    data: route,
+                },
+                TYPE_TIMESTAMP,
+                12,
+            ),
+            (RouterAlert(0), TYPE_ROUTER_ALERT, 4),
+            (
+                Cipso {
+                    doi: 1,
+                    tags: &[1, 2, 3],
+                },
+                TYPE_CIPSO,
+                9,
+            ),
+            (
+                Unknown {
+                    option_type: 25,
+                    data: &[1],
+                },
+                25,
+                3,
+            ),
+        ];
+        for (element, option_type, header_len) in tests {
+            assert_eq!(option_type, element.option_type());
+            assert_eq!(header_len, element.header_len());
+        }
+    }
+}
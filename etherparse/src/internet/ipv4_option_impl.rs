@@ -0,0 +1,36 @@
+/// Module containing the constants for IPv4 header options (type
+/// numbers & sizes).
+pub mod ipv4_option {
+    /// `u8` identifying the "end of options list" IPv4 option.
+    pub const TYPE_END: u8 = 0;
+    /// `u8` identifying a "no operation" IPv4 option.
+    pub const TYPE_NOOP: u8 = 1;
+    /// `u8` identifying a "record route" IPv4 option.
+    pub const TYPE_RECORD_ROUTE: u8 = 7;
+    /// `u8` identifying an "internet timestamp" IPv4 option.
+    pub const TYPE_TIMESTAMP: u8 = 68;
+    /// `u8` identifying a "loose source and record route" IPv4 option.
+    pub const TYPE_LOOSE_SOURCE_ROUTE: u8 = 131;
+    /// `u8` identifying a "commercial IP security option" (CIPSO) IPv4 option.
+    pub const TYPE_CIPSO: u8 = 134;
+    /// `u8` identifying a "strict source and record route" IPv4 option.
+    pub const TYPE_STRICT_SOURCE_ROUTE: u8 = 137;
+    /// `u8` identifying a "router alert" IPv4 option.
+    pub const TYPE_ROUTER_ALERT: u8 = 148;
+    /// Length in octets/bytes of the "end" IPv4 option (includes type value).
+    pub const LEN_END: u8 = 1;
+    /// Length in octets/bytes of the "no operation" IPv4 option (includes type value).
+    pub const LEN_NOOP: u8 = 1;
+    /// Length in octets/bytes of the "router alert" IPv4 option (includes type value).
+    pub const LEN_ROUTER_ALERT: u8 = 4;
+    /// Minimum length in octets/bytes of the "record route", "loose source
+    /// and record route" & "strict source and record route" IPv4 options
+    /// (includes type value).
+    pub const MIN_LEN_ROUTE: u8 = 3;
+    /// Minimum length in octets/bytes of the "internet timestamp" IPv4
+    /// option (includes type value).
+    pub const MIN_LEN_TIMESTAMP: u8 = 4;
+    /// Minimum length in octets/bytes of the "commercial IP security option"
+    /// (CIPSO) IPv4 option (includes type value).
+    pub const MIN_LEN_CIPSO: u8 = 6;
+}
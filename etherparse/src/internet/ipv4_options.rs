@@ -1,3 +1,4 @@
+use crate::*;
 use core::borrow::{Borrow, BorrowMut};
 
 /// Options present in an [`crate::Ipv4Header`].
@@ -82,13 +83,116 @@ impl Ipv4Options {
     pub fn is_empty(&self) -> bool {
         self.len == 0
     }
+
+    /// Serializes the given option elements into an [`Ipv4Options`].
+    ///
+    /// The options are padded with "end of options list" (`0`) bytes
+    /// so the resulting length is a multiple of 4. An error is returned
+    /// if the padded options would exceed the maximum length of 40 bytes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use etherparse::{Ipv4Options, Ipv4OptionElement, ipv4_option::*};
+    ///
+    /// let elements = [
+    ///     Ipv4OptionElement::Noop,
+    ///     Ipv4OptionElement::RouterAlert(0),
+    /// ];
+    /// let options = Ipv4Options::try_from_elements(&elements[..]).unwrap();
+    ///
+    /// assert_eq!(
+    ///     options.as_slice(),
+    ///     &[
+    ///         TYPE_NOOP, TYPE_ROUTER_ALERT, LEN_ROUTER_ALERT, 0,
+    ///         // padding in form of "TYPE_END" (0) is automatically added
+    ///         // so the resulting options length is a multiple of 4
+    ///         0, TYPE_END, TYPE_END, TYPE_END,
+    ///     ]
+    /// );
+    /// ```
+    pub fn try_from_elements(
+        elements: &[Ipv4OptionElement],
+    ) -> Result<Ipv4Options, crate::err::ipv4::OptionWriteError> {
+        use crate::ipv4_option::*;
+        use Ipv4OptionElement::*;
+
+        // calculate the required size of the options (including padding)
+        let unpadded_len = elements.iter().fold(0, |acc, x| acc + x.header_len());
+        let required_len = unpadded_len.div_ceil(4) * 4;
+        if usize::from(Self::MAX_LEN) < required_len {
+            return Err(crate::err::ipv4::OptionWriteError::NotEnoughSpace { required_len });
+        }
+
+        let mut result = Ipv4Options {
+            len: required_len as u8,
+            buf: [0; 40],
+        };
+        let mut pos = 0;
+        for element in elements {
+            // header_len of each element is guaranteed to fit into an u8
+            // as the sum of all lengths is smaller or equal to 40
+            let len = element.header_len();
+            let t = &mut result.buf[pos..pos + len];
+            t[0] = element.option_type();
+            match element {
+                Noop => {}
+                RecordRoute { pointer, route }
+                | LooseSourceRoute { pointer, route }
+                | StrictSourceRoute { pointer, route } => {
+                    t[1] = len as u8;
+                    t[2] = *pointer;
+                    for (dst, src) in t[3..].chunks_exact_mut(4).zip(route.iter()) {
+                        dst.copy_from_slice(src);
+                    }
+                }
+                Timestamp {
+                    pointer,
+                    overflow,
+                    flags,
+                    data,
+                } => {
+                    t[1] = len as u8;
+                    t[2] = *pointer;
+                    t[3] = (overflow << 4) | (flags & 0xf);
+                    for (dst, src) in t[4..].chunks_exact_mut(4).zip(data.iter()) {
+                        dst.copy_from_slice(src);
+                    }
+                }
+                RouterAlert(value) => {
+                    t[1] = LEN_ROUTER_ALERT;
+                    t[2..4].copy_from_slice(&value.to_be_bytes());
+                }
+                Cipso { doi, tags } => {
+                    t[1] = len as u8;
+                    t[2..6].copy_from_slice(&doi.to_be_bytes());
+                    t[6..].copy_from_slice(tags);
+                }
+                Unknown { data, .. } => {
+                    t[1] = len as u8;
+                    t[2..].copy_from_slice(data);
+                }
+            }
+            pos += len;
+        }
+        Ok(result)
+    }
+
+    /// Returns an iterator that allows to iterate through the
+    /// elements of the options.
+    #[inline]
+    pub fn elements_iter(&self) -> Ipv4OptionsIterator<'_> {
+        Ipv4OptionsIterator {
+            options: self.as_slice(),
+        }
+    }
 }
 
 impl TryFrom<&[u8]> for Ipv4Options {
     type Error = crate::err::ipv4::BadOptionsLen;
 
     fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
-        if value.len() <= 40 && value.len() % 4 == 0 {
+        if value.len() <= 40 && value.len().is_multiple_of(4) {
             let mut result = Ipv4Options {
                 len: value.len() as u8,
                 buf: [0; 40],
@@ -110,6 +214,15 @@ impl TryFrom<&[u8]> for Ipv4Options {
     }
 }
 
+impl<'a> TryFrom<&'a [Ipv4OptionElement<'a>]> for Ipv4Options {
+    type Error = crate::err::ipv4::OptionWriteError;
+
+    #[inline]
+    fn try_from(value: &'a [Ipv4OptionElement<'a>]) -> Result<Self, Self::Error> {
+        Ipv4Options::try_from_elements(value)
+    }
+}
+
 impl Default for Ipv4Options {
     #[inline]
     fn default() -> Self {
@@ -256,7 +369,7 @@ mod tests {
     use super::*;
     use crate::test_gens::*;
     use proptest::prelude::*;
-    use std::format;
+    use std::{format, vec::Vec};
 
     #[test]
     fn new() {
@@ -306,6 +419,97 @@ mod tests {
         }
     }
 
+    #[test]
+    fn try_from_elements() {
+        use crate::err::ipv4::OptionWriteError::*;
+        use ipv4_option::*;
+        use Ipv4OptionElement::*;
+
+        // empty
+        assert_eq!(Ok(Ipv4Options::new()), Ipv4Options::try_from_elements(&[]));
+
+        // all elements (read back via the iterator)
+        {
+            let route: &[[u8; 4]] = &[[1, 2, 3, 4], [5, 6, 7, 8]];
+            let elements = [
+                Noop,
+                RecordRoute { pointer: 4, route },
+                LooseSourceRoute {
+                    pointer: 4,
+                    route: &[],
+                },
+                StrictSourceRoute {
+                    pointer: 8,
+                    route: &route[..1],
+                },
+                Timestamp {
+                    pointer: 5,
+                    overflow: 2,
+                    flags: 1,
+                    data: &route[1..],
+                },
+                RouterAlert(0x1234),
+            ];
+            let options = Ipv4Options::try_from_elements(&elements).unwrap();
+            #[rustfmt::skip]
+            let expected = [
+                TYPE_NOOP,
+                TYPE_RECORD_ROUTE, 11, 4, 1, 2, 3, 4, 5, 6, 7, 8,
+                TYPE_LOOSE_SOURCE_ROUTE, 3, 4,
+                TYPE_STRICT_SOURCE_ROUTE, 7, 8, 1, 2, 3, 4,
+                TYPE_TIMESTAMP, 8, 5, 0x21, 5, 6, 7, 8,
+                TYPE_ROUTER_ALERT, 4, 0x12, 0x34,
+                TYPE_END, TYPE_END,
+            ];
+            assert_eq!(&options[..], &expected[..]);
+            assert_eq!(
+                options.elements_iter().collect::<Result<Vec<_>, _>>(),
+                Ok(elements.to_vec())
+            );
+            assert_eq!(Ok(options), Ipv4Options::try_from(&elements[..]));
+        }
+        {
+            let elements = [
+                Cipso {
+                    doi: 0x01020304,
+                    tags: &[5, 6],
+                },
+                Unknown {
+                    option_type: 25,
+                    data: &[7],
+                },
+            ];
+            let options = Ipv4Options::try_from_elements(&elements).unwrap();
+            assert_eq!(
+                &options[..],
+                &[TYPE_CIPSO, 8, 1, 2, 3, 4, 5, 6, 25, 3, 7, TYPE_END][..]
+            );
+            assert_eq!(
+                options.elements_iter().collect::<Result<Vec<_>, _>>(),
+                Ok(elements.to_vec())
+            );
+        }
+
+        // maximum length
+        assert_eq!(
+            Ok(Ipv4Options::from([TYPE_NOOP; 40])),
+            Ipv4Options::try_from_elements(&[Noop; 40])
+        );
+
+        // not enough space (padding is included in the required length)
+        assert_eq!(
+            Err(NotEnoughSpace { required_len: 44 }),
+            Ipv4Options::try_from_elements(&[Noop; 41])
+        );
+        assert_eq!(
+            Err(NotEnoughSpace { required_len: 44 }),
+            Ipv4Options::try_from_elements(&[Unknown {
+                option_type: 25,
+                data: &[0; 40]
+            }])
+        );
+    }
+
     #[test]
     fn default() {
         let actual: Ipv4Options = Default::default();
@@ -0,0 +1,343 @@
+use crate::*;
+
+/// Allows iterating over the options of an IPv4 header.
+///
+/// The iterator stops at the "end of options list" option (or when
+/// all data has been processed). In case an error is encountered the
+/// error is returned and the iteration is stopped.
+///
+/// # Example
+///
+/// ```
+/// use etherparse::{Ipv4OptionsIterator, Ipv4OptionElement::*, ipv4_option::*};
+///
+/// let options = [
+///     TYPE_ROUTER_ALERT, LEN_ROUTER_ALERT, 0, 0,
+///     TYPE_NOOP, TYPE_RECORD_ROUTE, 7, 4,
+///     192, 168, 1, 1,
+/// ];
+///
+/// let mut iter = Ipv4OptionsIterator::from_slice(&options);
+/// assert_eq!(iter.next(), Some(Ok(RouterAlert(0))));
+/// assert_eq!(iter.next(), Some(Ok(Noop)));
+/// assert_eq!(
+///     iter.next(),
+///     Some(Ok(RecordRoute { pointer: 4, route: &[[192, 168, 1, 1]] }))
+/// );
+/// assert_eq!(iter.next(), None);
+/// ```
+#[derive(Clone, Eq, PartialEq)]
+pub struct Ipv4OptionsIterator<'a> {
+    pub(crate) options: &'a [u8],
+}
+
+impl<'a> Ipv4OptionsIterator<'a> {
+    /// Creates an options iterator from a slice containing encoded IPv4 options.
+    pub fn from_slice(options: &'a [u8]) -> Ipv4OptionsIterator<'a> {
+        Ipv4OptionsIterator { options }
+    }
+
+    /// Returns the non processed part of the options slice.
+    pub fn rest(&self) -> &'a [u8] {
+        self.options
+    }
+}
+
+/// Converts a slice with a length that is a multiple of 4 into a slice
+/// of 32 bit words.
+fn to_words(slice: &[u8]) -> &[[u8; 4]] {
+    debug_assert_eq!(0, slice.len() % 4);
+    // SAFETY: Safe as [u8; 4] has an alignment of 1 & the number
+    // of words is calculated based on the slice length.
+    unsafe { core::slice::from_raw_parts(slice.as_ptr() as *const [u8; 4], slice.len() / 4) }
+}
+
+impl<'a> Iterator for Ipv4OptionsIterator<'a> {
+    type Item = Result<Ipv4OptionElement<'a>, err::ipv4::OptionReadError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        use err::ipv4::OptionReadError::*;
+        use ipv4_option::*;
+        use Ipv4OptionElement::*;
+
+        let options = self.options;
+        let result = match options.first() {
+            None | Some(&TYPE_END) => None,
+            Some(&TYPE_NOOP) => Some(Ok((Noop, 1))),
+            Some(&option_type) => {
+                // check the length field can be read & is in range
+                let min_len = match option_type {
+                    TYPE_RECORD_ROUTE | TYPE_LOOSE_SOURCE_ROUTE | TYPE_STRICT_SOURCE_ROUTE => {
+                        MIN_LEN_ROUTE
+                    }
+                    TYPE_TIMESTAMP => MIN_LEN_TIMESTAMP,
+                    TYPE_ROUTER_ALERT => LEN_ROUTER_ALERT,
+                    TYPE_CIPSO => MIN_LEN_CIPSO,
+                    _ => 2,
+                };
+                if options.len() < 2 {
+                    Some(Err(UnexpectedEndOfSlice {
+                        option_type,
+                        expected_len: min_len,
+                        actual_len: options.len(),
+                    }))
+                } else {
+                    let len = options[1];
+                    let bad_size = len < min_len
+                        || match option_type {
+                            TYPE_RECORD_ROUTE
+                            | TYPE_LOOSE_SOURCE_ROUTE
+                            | TYPE_STRICT_SOURCE_ROUTE => !(len - MIN_LEN_ROUTE).is_multiple_of(4),
+                            TYPE_TIMESTAMP => !(len - MIN_LEN_TIMESTAMP).is_multiple_of(4),
+                            TYPE_ROUTER_ALERT => LEN_ROUTER_ALERT != len,
+                            _ => false,
+                        };
+                    if bad_size {
+                        Some(Err(UnexpectedSize {
+                            option_type,
+                            size: len,
+                        }))
+                    } else if options.len() < usize::from(len) {
+                        Some(Err(UnexpectedEndOfSlice {
+                            option_type,
+                            expected_len: len,
+                            actual_len: options.len(),
+                        }))
+                    } else {
+                        let option = &options[..usize::from(len)];
+                        let element = match option_type {
+                            TYPE_RECORD_ROUTE => RecordRoute {
+                                pointer: option[2],
+                                route: to_words(&option[3..]),
+                            },
+                            TYPE_LOOSE_SOURCE_ROUTE => LooseSourceRoute {
+                                pointer: option[2],
+                                route: to_words(&option[3..]),
+                            },
+                            TYPE_STRICT_SOURCE_ROUTE => StrictSourceRoute {
+                                pointer: option[2],
+                                route: to_words(&option[3..]),
+                            },
+                            TYPE_TIMESTAMP => Timestamp {
+                                pointer: option[2],
+                                overflow: option[3] >> 4,
+                                flags: option[3] & 0xf,
+                                data: to_words(&option[4..]),
+                            },
+                            TYPE_ROUTER_ALERT => {
+                                RouterAlert(u16::from_be_bytes([option[2], option[3]]))
+                            }
+                            TYPE_CIPSO => Cipso {
+                                doi: u32::from_be_bytes([
+                                    option[2], option[3], option[4], option[5],
+                                ]),
+                                tags: &option[6..],
+                            },
+                            _ => Unknown {
+                                option_type,
+                                data: &option[2..],
+                            },
+                        };
+                        Some(Ok((element, option.len())))
+                    }
+                }
+            }
+        };
+
+        match result {
+            Some(Ok((element, len))) => {
+                self.options = &options[len..];
+                Some(Ok(element))
+            }
+            Some(Err(err)) => {
+                // stop the iteration after an error
+                self.options = &options[options.len()..];
+                Some(Err(err))
+            }
+            None => {
+                self.options = &options[options.len()..];
+                None
+            }
+        }
+    }
+}
+
+impl<'a> core::fmt::Debug for Ipv4OptionsIterator<'a> {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
+        let mut list = fmt.debug_list();
+
+        // create a copy and iterate over all elements
+        for it in self.clone() {
+            match it {
+                Ok(e) => {
+                    list.entry(&e);
+                }
+                Err(e) => {
+                    list.entry(&Result::<(), err::ipv4::OptionReadError>::Err(e));
+                }
+            }
+        }
+
+        list.finish()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::err::ipv4::OptionReadError::*;
+    use alloc::{format, vec::Vec};
+    use ipv4_option::*;
+    use Ipv4OptionElement::*;
+
+    #[test]
+    fn from_slice_rest() {
+        let data = [1, 2, 3, 4];
+        let iter = Ipv4OptionsIterator::from_slice(&data);
+        assert_eq!(&data[..], iter.rest());
+    }
+
+    #[test]
+    fn next() {
+        // empty & end
+        {
+            assert_eq!(None, Ipv4OptionsIterator::from_slice(&[]).next());
+            let data = [TYPE_END, TYPE_NOOP, 0, 0];
+            let mut iter = Ipv4OptionsIterator::from_slice(&data);
+            assert_eq!(None, iter.next());
+            assert!(iter.rest().is_empty());
+        }
+
+        // all supported options
+        {
+            #[rustfmt::skip]
+            let data = [
+                TYPE_NOOP,
+                TYPE_RECORD_ROUTE, 7, 8, 1, 2, 3, 4,
+                TYPE_LOOSE_SOURCE_ROUTE, 3, 4,
+                TYPE_STRICT_SOURCE_ROUTE, 7, 4, 5, 6, 7, 8,
+                TYPE_TIMESTAMP, 8, 9, 0x31, 9, 10, 11, 12,
+                TYPE_ROUTER_ALERT, 4, 0x12, 0x34,
+                TYPE_CIPSO, 8, 0, 0, 0, 3, 13, 14,
+                25, 3, 15,
+                TYPE_END,
+            ];
+            let actual: Vec<_> = Ipv4OptionsIterator::from_slice(&data).collect();
+            assert_eq!(
+                actual,
+                [
+                    Ok(Noop),
+                    Ok(RecordRoute {
+                        pointer: 8,
+                        route: &[[1, 2, 3, 4]]
+                    }),
+                    Ok(LooseSourceRoute {
+                        pointer: 4,
+                        route: &[]
+                    }),
+                    Ok(StrictSourceRoute {
+                        pointer: 4,
+                        route: &[[5, 6, 7, 8]]
+                    }),
+                    Ok(Timestamp {
+                        pointer: 9,
+                        overflow: 3,
+                        flags: 1,
+                        data: &[[9, 10, 11, 12]]
+                    }),
+                    Ok(RouterAlert(0x1234)),
+                    Ok(Cipso {
+                        doi: 3,
+                        tags: &[13, 14]
+                    }),
+                    Ok(Unknown {
+                        option_type: 25,
+                        data: &[15]
+                    }),
+                ]
+            );
+        }
+
+        // length field missing
+        for option_type in [
+            TYPE_RECORD_ROUTE,
+            TYPE_TIMESTAMP,
+            TYPE_ROUTER_ALERT,
+            TYPE_CIPSO,
+            25,
+        ] {
+            let data = [option_type];
+            let mut iter = Ipv4OptionsIterator::from_slice(&data);
+            assert_eq!(
+                Some(Err(UnexpectedEndOfSlice {
+                    option_type,
+                    expected_len: match option_type {
+                        TYPE_RECORD_ROUTE => MIN_LEN_ROUTE,
+                        TYPE_TIMESTAMP => MIN_LEN_TIMESTAMP,
+                        TYPE_ROUTER_ALERT => LEN_ROUTER_ALERT,
+                        TYPE_CIPSO => MIN_LEN_CIPSO,
+                        _ => 2,
+                    },
+                    actual_len: 1,
+                })),
+                iter.next()
+            );
+            assert_eq!(None, iter.next());
+        }
+
+        // bad sizes
+        for (option_type, size) in [
+            (TYPE_RECORD_ROUTE, 2),
+            (TYPE_RECORD_ROUTE, 4),
+            (TYPE_LOOSE_SOURCE_ROUTE, 8),
+            (TYPE_STRICT_SOURCE_ROUTE, 9),
+            (TYPE_TIMESTAMP, 3),
+            (TYPE_TIMESTAMP, 5),
+            (TYPE_ROUTER_ALERT, 3),
+            (TYPE_ROUTER_ALERT, 5),
+            (TYPE_CIPSO, 5),
+            (25, 1),
+        ] {
+            let data = [option_type, size, 0, 0, 0, 0, 0, 0, 0, 0];
+            let mut iter = Ipv4OptionsIterator::from_slice(&data);
+            assert_eq!(Some(Err(UnexpectedSize { option_type, size })), iter.next());
+            assert_eq!(None, iter.next());
+        }
+
+        // not enough data for the complete option
+        {
+            let data = [TYPE_NOOP, TYPE_RECORD_ROUTE, 11, 4, 1, 2, 3, 4];
+            let mut iter = Ipv4OptionsIterator::from_slice(&data);
+            assert_eq!(Some(Ok(Noop)), iter.next());
+            assert_eq!(
+                Some(Err(UnexpectedEndOfSlice {
+                    option_type: TYPE_RECORD_ROUTE,
+                    expected_len: 11,
+                    actual_len: 7,
+                })),
+                iter.next()
+            );
+            assert_eq!(None, iter.next());
+        }
+    }
+
+    #[test]
+    fn clone_eq_debug() {
+        let data = [TYPE_NOOP, TYPE_ROUTER_ALERT, 4, 0, 0, 25];
+        let iter = Ipv4OptionsIterator::from_slice(&data);
+        assert_eq!(iter, iter.clone());
+        assert_eq!(
+            format!(
+                "[{:?}, {:?}, {:?}]",
+                Noop,
+                RouterAlert(0),
+                Result::<(), err::ipv4::OptionReadError>::Err(UnexpectedEndOfSlice {
+                    option_type: 25,
+                    expected_len: 2,
+                    actual_len: 1
+                })
+            ),
+            format!("{:?}", iter)
+        );
+    }
+}
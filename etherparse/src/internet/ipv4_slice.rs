@@ -1,6 +1,6 @@
 use crate::{
     err::{ipv4::SliceError, Layer, LenError, LenSource},
-    IpAuthHeaderSlice, IpNumber, IpPayload, Ipv4ExtensionsSlice, Ipv4HeaderSlice,
+    IpNumber, IpPayload, Ipv4ExtensionsSlice, Ipv4HeaderSlice,
 };
 
 /// Slice containing the IPv4 headers & payload.
@@ -16,8 +16,6 @@ impl<'a> Ipv4Slice<'a> {
     /// in the given slice and determine the sub-slice containing the payload
     /// of the IPv4 packet.
     pub fn from_slice(slice: &[u8]) -> Result<Ipv4Slice, SliceError> {
-        use crate::ip_number::AUTH;
-
         // decode the header
         let header = Ipv4HeaderSlice::from_slice(slice).map_err(|err| {
             use crate::err::ipv4::HeaderSliceError::*;
@@ -57,56 +55,31 @@ impl<'a> Ipv4Slice<'a> {
             }
         };
 
-        // decode the authentification header if needed
+        // decode the extension headers
         let fragmented = header.is_fragmenting_payload();
-        match header.protocol() {
-            AUTH => {
+        let (exts, ip_number, payload) =
+            Ipv4ExtensionsSlice::from_slice(header.protocol(), header_payload).map_err(|err| {
                 use crate::err::ip_auth::HeaderSliceError as E;
-
-                // parse extension headers
-                let auth = match IpAuthHeaderSlice::from_slice(header_payload) {
-                    Ok(s) => s,
-                    Err(err) => match err {
-                        E::Len(mut l) => {
-                            // change the length source to the ipv4 header
-                            l.len_source = LenSource::Ipv4HeaderTotalLen;
-                            l.layer_start_offset += header.slice().len();
-                            return Err(SliceError::Len(l));
-                        }
-                        E::Content(err) => return Err(SliceError::Exts(err)),
-                    },
-                };
-
-                // remove the extension header from the payload
-                let payload = unsafe {
-                    core::slice::from_raw_parts(
-                        header_payload.as_ptr().add(auth.slice().len()),
-                        header_payload.len() - auth.slice().len(),
-                    )
-                };
-                let ip_number = auth.next_header();
-                Ok(Ipv4Slice {
-                    header,
-                    exts: Ipv4ExtensionsSlice { auth: Some(auth) },
-                    payload: IpPayload {
-                        ip_number,
-                        fragmented,
-                        len_source: LenSource::Ipv4HeaderTotalLen,
-                        payload,
-                    },
-                })
-            }
-            ip_number => Ok(Ipv4Slice {
-                header,
-                exts: Ipv4ExtensionsSlice { auth: None },
-                payload: IpPayload {
-                    ip_number,
-                    fragmented,
-                    len_source: LenSource::Ipv4HeaderTotalLen,
-                    payload: header_payload,
-                },
-            }),
-        }
+                match err {
+                    E::Len(mut l) => {
+                        // change the length source to the ipv4 header
+                        l.len_source = LenSource::Ipv4HeaderTotalLen;
+                        l.layer_start_offset += header.slice().len();
+                        SliceError::Len(l)
+                    }
+                    E::Content(err) => SliceError::Exts(err),
+                }
+            })?;
+        Ok(Ipv4Slice {
+            header,
+            exts,
+            payload: IpPayload {
+                ip_number,
+                fragmented,
+                len_source: LenSource::Ipv4HeaderTotalLen,
+                payload,
+            },
+        })
     }
 
     /// Returns a slice containing the IPv4 header.
@@ -147,7 +120,7 @@ impl<'a> Ipv4Slice<'a> {
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::{ip_number, test_gens::*, Ipv4Header};
+    use crate::{ip_number, test_gens::*, IpAuthHeaderSlice, Ipv4Header};
     use alloc::{format, vec::Vec};
     use proptest::prelude::*;
 
@@ -155,7 +128,7 @@ mod test {
         #[test]
         fn debug_clone_eq(
             ipv4_base in ipv4_any(),
-            auth in ip_auth_any()
+            auth in ip_auth_with(ip_number::UDP)
         ) {
             let payload: [u8;4] = [1,2,3,4];
             let mut data = Vec::with_capacity(
@@ -191,7 +164,7 @@ mod test {
         #[test]
         fn from_slice(
             ipv4_base in ipv4_any(),
-            auth in ip_auth_any()
+            auth in ip_auth_with(ip_number::UDP)
         ) {
             let payload: [u8;6] = [1,2,3,4,5,6];
 
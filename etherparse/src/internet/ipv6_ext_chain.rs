@@ -0,0 +1,987 @@
+use crate::{
+    err::ipv6_exts::{ExtsWalkError, HeaderError},
+    *,
+};
+use arrayvec::ArrayVec;
+
+/// IPv6 extension headers stored in the order they are present on the
+/// wire (containing up to [`Ipv6ExtensionChain::MAX_HEADERS`] headers).
+///
+/// In contrast to [`Ipv6Extensions`] the chain can contain repeated
+/// headers (e.g. multiple destination options or experimental headers)
+/// and headers in a non standard order.
+/// Decoding a chain & writing it again reproduces the original bytes.
+///
+/// If the fixed layout of [`Ipv6Extensions`] is more convenient the
+/// chain can be converted via [`Ipv6ExtensionChain::to_extensions`]
+/// (as long as the headers can be represented by it).
+///
+/// # Header limit
+///
+/// The headers are stored inline (without allocations), so the number of
+/// headers is limited to [`Ipv6ExtensionChain::MAX_HEADERS`] (14). This is
+/// enough for all headers that can be stored in an [`Ipv6Extensions`] (12)
+/// plus two additional repeated headers. Decoding ([`Ipv6ExtensionChain::from_slice`]) or adding
+/// ([`Ipv6ExtensionChain::try_push`] & [`Ipv6ExtensionChain::insert`])
+/// more headers fails with [`HeaderError::TooManyExtHeaders`] (containing
+/// the limit) instead of silently dropping headers.
+///
+/// # Example
+///
+/// ```
+/// use etherparse::{ip_number, Ipv6ExtensionChain, Ipv6ExtensionHeader, Ipv6RawExtHeader};
+///
+/// let mut chain = Ipv6ExtensionChain::new();
+/// chain.try_push(Ipv6ExtensionHeader::DestinationOptions(
+///     Ipv6RawExtHeader::new_raw(ip_number::UDP, &[0; 6]).unwrap()
+/// )).unwrap();
+/// chain.try_push(Ipv6ExtensionHeader::DestinationOptions(
+///     Ipv6RawExtHeader::new_raw(ip_number::UDP, &[0; 6]).unwrap()
+/// )).unwrap();
+///
+/// assert_eq!(Some(ip_number::IPV6_DEST_OPTIONS), chain.first_header());
+/// assert_eq!(Some(ip_number::UDP), chain.next_header());
+/// assert_eq!(16, chain.header_len());
+///
+/// // repeated headers can not be represented by Ipv6Extensions
+/// assert!(chain.to_extensions().is_err());
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Default)]
+pub struct Ipv6ExtensionChain {
+    headers: ArrayVec<Ipv6ExtensionHeader, { Ipv6ExtensionChain::MAX_HEADERS }>,
+}
+
+impl Ipv6ExtensionChain {
+    /// Maximum number of extension headers that can be stored in an
+    /// [`Ipv6ExtensionChain`] (see "Header limit" in the type documentation).
+    pub const MAX_HEADERS: usize = 14;
+
+    /// Creates an empty extension header chain.
+    #[inline]
+    pub fn new() -> Ipv6ExtensionChain {
+        Ipv6ExtensionChain {
+            headers: ArrayVec::new(),
+        }
+    }
+
+    /// Reads as many extension headers as possible from the slice
+    /// (see [`Ipv6ExtensionsSlice::from_slice`]).
+    ///
+    /// Returns the found ipv6 extension headers, the next header ip number after the read
+    /// headers and a slice containing the rest of the packet after the read headers.
+    ///
+    /// If more then [`Ipv6ExtensionChain::MAX_HEADERS`] headers are present
+    /// [`HeaderError::TooManyExtHeaders`] is returned (wrapped in
+    /// [`err::ipv6_exts::HeaderSliceError::Content`]).
+    pub fn from_slice(
+        start_ip_number: IpNumber,
+        slice: &[u8],
+    ) -> Result<(Ipv6ExtensionChain, IpNumber, &[u8]), err::ipv6_exts::HeaderSliceError> {
+        use err::ipv6_exts::HeaderSliceError::*;
+        let (exts, next_header, rest) = Ipv6ExtensionsSlice::from_slice(start_ip_number, slice)?;
+        Ok((exts.to_chain().map_err(Content)?, next_header, rest))
+    }
+
+    /// Creates a chain from an [`Ipv6Extensions`] struct, by following
+    /// the `next_header` fields starting with the given `first_header`
+    /// (same order as used by [`Ipv6Extensions::write`]).
+    ///
+    /// An error is returned if a header in the [`Ipv6Extensions`] is
+    /// never referenced or the hop by hop header is not the first header.
+    pub fn from_extensions(
+        first_header: IpNumber,
+        exts: &Ipv6Extensions,
+    ) -> Result<Ipv6ExtensionChain, ExtsWalkError> {
+        use ip_number::*;
+        use ExtsWalkError::*;
+        use Ipv6ExtensionHeader as H;
+
+        let mut hop_by_hop_options = exts.hop_by_hop_options.as_ref();
+        let mut destination_options = exts.destination_options.as_ref();
+        let mut routing = exts.routing.as_ref().map(|r| &r.routing);
+        let mut fragment = exts.fragment.as_ref();
+        let mut auth = exts.auth.as_ref();
+        let mut esp = exts.esp.as_ref();
+        let mut shim6 = exts.shim6.as_ref();
+        let mut mobility = exts.mobility.as_ref();
+        let mut hip = exts.hip.as_ref();
+        let mut exp0 = exts.exp0.as_ref();
+        let mut exp1 = exts.exp1.as_ref();
+        let mut final_destination_options = exts
+            .routing
+            .as_ref()
+            .and_then(|r| r.final_destination_options.as_ref());
+
+        // Ipv6Extensions contains at most 12 headers, so all
+        // pushes below fit into the chain.
+        let mut result = Ipv6ExtensionChain::new();
+        let mut next_header = first_header;
+
+        if IPV6_HOP_BY_HOP == next_header {
+            if let Some(header) = hop_by_hop_options.take() {
+                result.headers.push(H::HopByHop(header.clone()));
+                next_header = header.next_header;
+            }
+        }
+
+        loop {
+            match next_header {
+                IPV6_HOP_BY_HOP => {
+                    if hop_by_hop_options.is_some() {
+                        return Err(HopByHopNotAtStart);
+                    }
+                    break;
+                }
+                IPV6_DEST_OPTIONS => {
+                    // destination options after a routing header are
+                    // stored as final destination options
+                    let header = if routing.is_none() && exts.routing.is_some() {
+                        final_destination_options.take()
+                    } else {
+                        destination_options.take()
+                    };
+                    if let Some(header) = header {
+                        result.headers.push(H::DestinationOptions(header.clone()));
+                        next_header = header.next_header;
+                    } else {
+                        break;
+                    }
+                }
+                IPV6_ROUTE => {
+                    if let Some(header) = routing.take() {
+                        result.headers.push(H::Routing(header.clone()));
+                        next_header = header.next_header;
+                    } else {
+                        break;
+                    }
+                }
+                SHIM6 => {
+                    if let Some(header) = shim6.take() {
+                        result.headers.push(H::Shim6(header.clone()));
+                        next_header = header.next_header;
+                    } else {
+                        break;
+                    }
+                }
+                MOBILITY => {
+                    if let Some(header) = mobility.take() {
+                        result.headers.push(H::Mobility(header.clone()));
+                        next_header = header.next_header;
+                    } else {
+                        break;
+                    }
+                }
+                HIP => {
+                    if let Some(header) = hip.take() {
+                        result.headers.push(H::Hip(header.clone()));
+                        next_header = header.next_header;
+                    } else {
+                        break;
+                    }
+                }
+                EXP0 | EXP1 => {
+                    let header = if EXP0 == next_header {
+                        exp0.take()
+                    } else {
+                        exp1.take()
+                    };
+                    if let Some(header) = header {
+                        result
+                            .headers
+                            .push(H::Experimental(next_header, header.clone()));
+                        next_header = header.next_header;
+                    } else {
+                        break;
+                    }
+                }
+                IPV6_FRAG => {
+                    if let Some(header) = fragment.take() {
+                        result.headers.push(H::Fragment(header.clone()));
+                        next_header = header.next_header;
+                    } else {
+                        break;
+                    }
+                }
+                AUTH => {
+                    if let Some(header) = auth.take() {
+                        result.headers.push(H::Authentication(header.clone()));
+                        next_header = header.next_header;
+                    } else {
+                        break;
+                    }
+                }
+                ENCAP_SEC => {
+                    if let Some(header) = esp.take() {
+                        result.headers.push(H::Esp(header.clone()));
+                    }
+                    // the data after the ESP header is encrypted
+                    break;
+                }
+                _ => break,
+            }
+        }
+
+        // check that all headers have been referenced
+        let missing_ext = if hop_by_hop_options.is_some() {
+            Some(IPV6_HOP_BY_HOP)
+        } else if destination_options.is_some() {
+            Some(IPV6_DEST_OPTIONS)
+        } else if routing.is_some() {
+            Some(IPV6_ROUTE)
+        } else if fragment.is_some() {
+            Some(IPV6_FRAG)
+        } else if auth.is_some() {
+            Some(AUTH)
+        } else if esp.is_some() {
+            Some(ENCAP_SEC)
+        } else if final_destination_options.is_some() {
+            Some(IPV6_DEST_OPTIONS)
+        } else if shim6.is_some() {
+            Some(SHIM6)
+        } else if mobility.is_some() {
+            Some(MOBILITY)
+        } else if hip.is_some() {
+            Some(HIP)
+        } else if exp0.is_some() {
+            Some(EXP0)
+        } else if exp1.is_some() {
+            Some(EXP1)
+        } else {
+            None
+        };
+        if let Some(missing_ext) = missing_ext {
+            Err(ExtNotReferenced { missing_ext })
+        } else {
+            Ok(result)
+        }
+    }
+
+    /// Converts the chain into an [`Ipv6Extensions`] struct.
+    ///
+    /// An [`ExtsWalkError::ExtNotRepresentable`] error is returned if the
+    /// chain contains headers that can not be stored in an
+    /// [`Ipv6Extensions`] (repeated headers). The
+    /// `next_header` fields of the headers are kept as they are.
+    pub fn to_extensions(&self) -> Result<Ipv6Extensions, ExtsWalkError> {
+        use ExtsWalkError::*;
+        use Ipv6ExtensionHeader as H;
+
+        let mut result = Ipv6Extensions::default();
+        for header in self.headers.iter() {
+            let not_representable = Err(ExtNotRepresentable {
+                ext: header.ip_number(),
+            });
+            match header {
+                H::HopByHop(h) => {
+                    if result.hop_by_hop_options.is_some() {
+                        return not_representable;
+                    }
+                    result.hop_by_hop_options = Some(h.clone());
+                }
+                H::DestinationOptions(h) => {
+                    if let Some(routing) = result.routing.as_mut() {
+                        if routing.final_destination_options.is_some() {
+                            return not_representable;
+                        }
+                        routing.final_destination_options = Some(h.clone());
+                    } else if result.destination_options.is_some() {
+                        return not_representable;
+                    } else {
+                        result.destination_options = Some(h.clone());
+                    }
+                }
+                H::Routing(h) => {
+                    if result.routing.is_some() {
+                        return not_representable;
+                    }
+                    result.routing = Some(Ipv6RoutingExtensions {
+                        routing: h.clone(),
+                        final_destination_options: None,
+                    });
+                }
+                H::Fragment(h) => {
+                    if result.fragment.is_some() {
+                        return not_representable;
+                    }
+                    result.fragment = Some(h.clone());
+                }
+                H::Authentication(h) => {
+                    if result.auth.is_some() {
+                        return not_representable;
+                    }
+                    result.auth = Some(h.clone());
+                }
+                H::Esp(h) => {
+                    if result.esp.is_some() {
+                        return not_representable;
+                    }
+                    result.esp = Some(h.clone());
+                }
+                H::Shim6(h) => {
+                    if result.shim6.is_some() {
+                        return not_representable;
+                    }
+                    result.shim6 = Some(h.clone());
+                }
+                H::Mobility(h) => {
+                    if result.mobility.is_some() {
+                        return not_representable;
+                    }
+                    result.mobility = Some(h.clone());
+                }
+                H::Hip(h) => {
+                    if result.hip.is_some() {
+                        return not_representable;
+                    }
+                    result.hip = Some(h.clone());
+                }
+                H::Experimental(ip_number, h) => {
+                    let target = match *ip_number {
+                        ip_number::EXP0 => &mut result.exp0,
+                        ip_number::EXP1 => &mut result.exp1,
+                        _ => return not_representable,
+                    };
+                    if target.is_some() {
+                        return not_representable;
+                    }
+                    *target = Some(h.clone());
+                }
+            }
+        }
+        Ok(result)
+    }
+
+    /// Returns the extension headers in the order they are present on the wire.
+    #[inline]
+    pub fn headers(&self) -> &[Ipv6ExtensionHeader] {
+        &self.headers
+    }
+
+    /// Returns the extension headers as mutable slice.
+    ///
+    /// Note that the `next_header` fields are not updated automatically
+    /// when modifying headers via this slice (use
+    /// [`Ipv6ExtensionChain::set_next_headers`] to re-link the headers).
+    #[inline]
+    pub fn headers_mut(&mut self) -> &mut [Ipv6ExtensionHeader] {
+        &mut self.headers
+    }
+
+    /// Returns the ip number of the first extension header (value that
+    /// has to be set as `next_header` in the IPv6 header) or `None` if
+    /// the chain is empty.
+    #[inline]
+    pub fn first_header(&self) -> Option<IpNumber> {
+        self.headers.first().map(|h| h.ip_number())
+    }
+
+    /// Returns the "next header" field of the last extension header
+    /// (ip number of the payload) or `None` if the chain is empty or
+    /// ends with an ESP header.
+    #[inline]
+    pub fn next_header(&self) -> Option<IpNumber> {
+        self.headers.last().and_then(|h| h.next_header())
+    }
+
+    /// Appends a header to the end of the chain & sets the `next_header`
+    /// of the previously last header to the ip number of the new header.
+    ///
+    /// Returns [`HeaderError::TooManyExtHeaders`] if the chain already
+    /// contains [`Ipv6ExtensionChain::MAX_HEADERS`] headers.
+    pub fn try_push(&mut self, header: Ipv6ExtensionHeader) -> Result<(), HeaderError> {
+        self.insert(self.headers.len(), header)
+    }
+
+    /// Inserts a header at the given position & updates the `next_header`
+    /// fields of the inserted & the previous header so the chain stays
+    /// linked.
+    ///
+    /// If the header is inserted at the end of the chain, the `next_header`
+    /// of the inserted header is kept as it is. If it is inserted at the
+    /// start of the chain, the `next_header` field of the IPv6 header has
+    /// to be updated to [`Ipv6ExtensionChain::first_header`].
+    ///
+    /// Returns an error if the chain already contains
+    /// [`Ipv6ExtensionChain::MAX_HEADERS`] headers.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater then the number of headers in the chain.
+    pub fn insert(
+        &mut self,
+        index: usize,
+        mut header: Ipv6ExtensionHeader,
+    ) -> Result<(), HeaderError> {
+        if self.headers.is_full() {
+            return Err(HeaderError::TooManyExtHeaders {
+                max_headers: Ipv6ExtensionChain::MAX_HEADERS,
+            });
+        }
+        if let Some(next) = self.headers.get(index) {
+            header.set_next_header(next.ip_number());
+        }
+        if index > 0 {
+            let ip_number = header.ip_number();
+            if let Some(prev) = self.headers.get_mut(index - 1) {
+                prev.set_next_header(ip_number);
+            }
+        }
+        self.headers.insert(index, header);
+        Ok(())
+    }
+
+    /// Removes & returns the header at the given position & updates the
+    /// `next_header` of the previous header so the chain stays linked.
+    ///
+    /// Returns `None` if `index` is out of bounds.
+    pub fn remove(&mut self, index: usize) -> Option<Ipv6ExtensionHeader> {
+        let removed = self.headers.pop_at(index)?;
+        if index > 0 {
+            let next = if let Some(next) = self.headers.get(index) {
+                Some(next.ip_number())
+            } else {
+                removed.next_header()
+            };
+            if let Some(next) = next {
+                self.headers[index - 1].set_next_header(next);
+            }
+        }
+        Some(removed)
+    }
+
+    /// Sets all the `next_header` fields of the headers based on their
+    /// order in the chain with the given protocol number as last
+    /// "next header" value. The return value is the protocol number of
+    /// the first header that should be entered in the ipv6 header as
+    /// next_header.
+    ///
+    /// If no extension headers are present the value of the argument is returned.
+    pub fn set_next_headers(&mut self, last_protocol_number: IpNumber) -> IpNumber {
+        let mut next = last_protocol_number;
+        for header in self.headers.iter_mut().rev() {
+            header.set_next_header(next);
+            next = header.ip_number();
+        }
+        next
+    }
+
+    /// Summed up length of all headers in bytes.
+    pub fn header_len(&self) -> usize {
+        self.headers.iter().map(|h| h.header_len()).sum()
+    }
+
+    /// Returns true if no extension headers are present.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.headers.is_empty()
+    }
+
+    /// Writes all headers in the order they are stored in the chain.
+    ///
+    /// The `next_header` fields are written as they are stored in the
+    /// headers. An error is returned if a hop by hop header is present
+    /// at a position other then the start of the chain.
+    #[cfg(feature = "std")]
+    pub fn write<T: std::io::Write + Sized>(
+        &self,
+        writer: &mut T,
+    ) -> Result<(), err::ipv6_exts::HeaderWriteError> {
+        use err::ipv6_exts::HeaderWriteError::*;
+
+        if self
+            .headers
+            .iter()
+            .skip(1)
+            .any(|h| matches!(h, Ipv6ExtensionHeader::HopByHop(_)))
+        {
+            return Err(Content(ExtsWalkError::HopByHopNotAtStart));
+        }
+        for header in self.headers.iter() {
+            header.write(writer).map_err(Io)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_gens::*;
+    use alloc::vec::Vec;
+    use proptest::prelude::*;
+    use Ipv6ExtensionHeader as H;
+
+    fn dest(next_header: IpNumber, value: u8) -> Ipv6RawExtHeader {
+        Ipv6RawExtHeader::new_raw(next_header, &[value; 6]).unwrap()
+    }
+
+    #[test]
+    fn new_default() {
+        let chain = Ipv6ExtensionChain::new();
+        assert!(chain.is_empty());
+        assert_eq!(chain, Ipv6ExtensionChain::default());
+        assert_eq!(None, chain.first_header());
+        assert_eq!(None, chain.next_header());
+        assert_eq!(0, chain.header_len());
+    }
+
+    #[test]
+    fn from_slice_write_roundtrip() {
+        use ip_number::*;
+
+        // repeated destination options, experimental headers & a
+        // non standard order
+        #[rustfmt::skip]
+        let bytes = [
+            EXP0.0, 0, 1, 1, 1, 1, 1, 1,
+            IPV6_ROUTE.0, 0, 2, 2, 2, 2, 2, 2,
+            IPV6_DEST_OPTIONS.0, 0, 3, 3, 3, 3, 3, 3,
+            IPV6_DEST_OPTIONS.0, 0, 0, 0, 0, 0, 0, 0,
+            UDP.0, 0, 4, 4, 4, 4, 4, 4,
+            // payload
+            1, 2, 3, 4,
+        ];
+        let (chain, next_header, rest) =
+            Ipv6ExtensionChain::from_slice(IPV6_DEST_OPTIONS, &bytes).unwrap();
+        assert_eq!(UDP, next_header);
+        assert_eq!(&[1, 2, 3, 4], rest);
+        assert_eq!(
+            chain.headers(),
+            &[
+                H::DestinationOptions(dest(EXP0, 1)),
+                H::Experimental(EXP0, dest(IPV6_ROUTE, 2)),
+                H::Routing(Ipv6RawExtHeader::new_raw(IPV6_DEST_OPTIONS, &[3; 6]).unwrap()),
+                H::DestinationOptions(dest(IPV6_DEST_OPTIONS, 0)),
+                H::DestinationOptions(dest(UDP, 4)),
+            ]
+        );
+        assert_eq!(Some(IPV6_DEST_OPTIONS), chain.first_header());
+        assert_eq!(Some(UDP), chain.next_header());
+        assert_eq!(bytes.len() - 4, chain.header_len());
+
+        let mut buffer = Vec::with_capacity(chain.header_len());
+        chain.write(&mut buffer).unwrap();
+        assert_eq!(&bytes[..bytes.len() - 4], &buffer[..]);
+    }
+
+    #[test]
+    fn from_slice_errors() {
+        use err::ipv6_exts::{HeaderError::*, HeaderSliceError::*};
+        use ip_number::*;
+
+        // too many headers
+        {
+            let mut bytes = Vec::new();
+            for _ in 0..Ipv6ExtensionChain::MAX_HEADERS {
+                bytes.extend_from_slice(&dest(IPV6_DEST_OPTIONS, 0).to_bytes());
+            }
+            bytes.extend_from_slice(&dest(UDP, 0).to_bytes());
+            assert_eq!(
+                Content(TooManyExtHeaders {
+                    max_headers: Ipv6ExtensionChain::MAX_HEADERS
+                }),
+                Ipv6ExtensionChain::from_slice(IPV6_DEST_OPTIONS, &bytes).unwrap_err()
+            );
+            assert!(Ipv6ExtensionChain::from_slice(IPV6_DEST_OPTIONS, &bytes[8..]).is_ok());
+        }
+        // length error
+        {
+            let bytes = dest(UDP, 0).to_bytes();
+            assert_eq!(
+                Len(err::LenError {
+                    required_len: 8,
+                    len: 7,
+                    len_source: err::LenSource::Slice,
+                    layer: err::Layer::Ipv6ExtHeader,
+                    layer_start_offset: 0,
+                }),
+                Ipv6ExtensionChain::from_slice(IPV6_DEST_OPTIONS, &bytes[..7]).unwrap_err()
+            );
+        }
+    }
+
+    #[test]
+    fn push_insert_remove() {
+        use err::ipv6_exts::HeaderError::*;
+        use ip_number::*;
+
+        let mut chain = Ipv6ExtensionChain::new();
+        chain.try_push(H::DestinationOptions(dest(TCP, 1))).unwrap();
+        assert_eq!(Some(TCP), chain.next_header());
+
+        // push links the previous header
+        chain.try_push(H::Experimental(EXP1, dest(UDP, 2))).unwrap();
+        assert_eq!(
+            chain.headers(),
+            &[
+                H::DestinationOptions(dest(EXP1, 1)),
+                H::Experimental(EXP1, dest(UDP, 2)),
+            ]
+        );
+
+        // insert in the middle
+        chain
+            .insert(
+                1,
+                H::Fragment(Ipv6FragmentHeader::new(TCP, IpFragOffset::ZERO, false, 3)),
+            )
+            .unwrap();
+        assert_eq!(
+            chain.headers(),
+            &[
+                H::DestinationOptions(dest(IPV6_FRAG, 1)),
+                H::Fragment(Ipv6FragmentHeader::new(EXP1, IpFragOffset::ZERO, false, 3)),
+                H::Experimental(EXP1, dest(UDP, 2)),
+            ]
+        );
+
+        // insert at the start
+        chain.insert(0, H::HopByHop(dest(TCP, 4))).unwrap();
+        assert_eq!(Some(IPV6_HOP_BY_HOP), chain.first_header());
+        assert_eq!(H::HopByHop(dest(IPV6_DEST_OPTIONS, 4)), chain.headers()[0]);
+
+        // remove in the middle
+        assert_eq!(
+            Some(H::Fragment(Ipv6FragmentHeader::new(
+                EXP1,
+                IpFragOffset::ZERO,
+                false,
+                3
+            ))),
+            chain.remove(2)
+        );
+        assert_eq!(H::DestinationOptions(dest(EXP1, 1)), chain.headers()[1]);
+
+        // remove at the end
+        assert_eq!(Some(H::Experimental(EXP1, dest(UDP, 2))), chain.remove(2));
+        assert_eq!(Some(UDP), chain.next_header());
+
+        // remove out of bounds & at the start
+        assert_eq!(None, chain.remove(2));
+        assert_eq!(
+            Some(H::HopByHop(dest(IPV6_DEST_OPTIONS, 4))),
+            chain.remove(0)
+        );
+        assert_eq!(chain.headers(), &[H::DestinationOptions(dest(UDP, 1))]);
+
+        // too many headers
+        while chain.headers().len() < Ipv6ExtensionChain::MAX_HEADERS {
+            chain.try_push(H::DestinationOptions(dest(UDP, 0))).unwrap();
+        }
+        let too_many = Err(TooManyExtHeaders {
+            max_headers: Ipv6ExtensionChain::MAX_HEADERS,
+        });
+        assert_eq!(
+            too_many,
+            chain.try_push(H::DestinationOptions(dest(UDP, 0)))
+        );
+        assert_eq!(
+            too_many,
+            chain.insert(0, H::DestinationOptions(dest(UDP, 0)))
+        );
+    }
+
+    #[test]
+    fn set_next_headers() {
+        use ip_number::*;
+
+        let mut chain = Ipv6ExtensionChain::new();
+        assert_eq!(UDP, chain.set_next_headers(UDP));
+
+        for header in [
+            H::DestinationOptions(dest(TCP, 0)),
+            H::Routing(dest(TCP, 0)),
+            H::DestinationOptions(dest(TCP, 0)),
+        ] {
+            chain.headers.push(header);
+        }
+        assert_eq!(IPV6_DEST_OPTIONS, chain.set_next_headers(UDP));
+        assert_eq!(
+            chain.headers(),
+            &[
+                H::DestinationOptions(dest(IPV6_ROUTE, 0)),
+                H::Routing(dest(IPV6_DEST_OPTIONS, 0)),
+                H::DestinationOptions(dest(UDP, 0)),
+            ]
+        );
+
+        // headers_mut
+        chain.headers_mut()[2] = H::Esp(EspHeader::default());
+        assert_eq!(IPV6_DEST_OPTIONS, chain.set_next_headers(UDP));
+        assert_eq!(None, chain.next_header());
+        assert_eq!(H::Routing(dest(ENCAP_SEC, 0)), chain.headers()[1]);
+    }
+
+    #[test]
+    fn write_hop_by_hop_not_at_start() {
+        use ip_number::*;
+
+        let mut chain = Ipv6ExtensionChain::new();
+        chain.try_push(H::DestinationOptions(dest(UDP, 0))).unwrap();
+        chain.try_push(H::HopByHop(dest(UDP, 0))).unwrap();
+        let mut buffer = Vec::new();
+        assert_eq!(
+            Some(&ExtsWalkError::HopByHopNotAtStart),
+            chain.write(&mut buffer).unwrap_err().content()
+        );
+        assert!(buffer.is_empty());
+
+        // io error
+        chain.remove(1);
+        let mut buffer = [0u8; 4];
+        let mut cursor = std::io::Cursor::new(&mut buffer[..]);
+        assert!(chain.write(&mut cursor).unwrap_err().io().is_some());
+    }
+
+    #[test]
+    fn to_extensions() {
+        use ip_number::*;
+        use ExtsWalkError::*;
+
+        // representable
+        {
+            let mut chain = Ipv6ExtensionChain::new();
+            for header in [
+                H::HopByHop(dest(UDP, 1)),
+                H::DestinationOptions(dest(UDP, 2)),
+                H::Routing(dest(UDP, 3)),
+                H::Fragment(Ipv6FragmentHeader::new(UDP, IpFragOffset::ZERO, false, 4)),
+                H::Authentication(IpAuthHeader::new(UDP, 5, 6, &[]).unwrap()),
+                H::DestinationOptions(dest(UDP, 7)),
+                H::Esp(EspHeader::default()),
+            ] {
+                chain.try_push(header).unwrap();
+            }
+            let exts = chain.to_extensions().unwrap();
+            assert_eq!(
+                exts,
+                Ipv6Extensions {
+                    hop_by_hop_options: Some(dest(IPV6_DEST_OPTIONS, 1)),
+                    destination_options: Some(dest(IPV6_ROUTE, 2)),
+                    routing: Some(Ipv6RoutingExtensions {
+                        routing: dest(IPV6_FRAG, 3),
+                        final_destination_options: Some(dest(ENCAP_SEC, 7)),
+                    }),
+                    fragment: Some(Ipv6FragmentHeader::new(AUTH, IpFragOffset::ZERO, false, 4)),
+                    auth: Some(IpAuthHeader::new(IPV6_DEST_OPTIONS, 5, 6, &[]).unwrap()),
+                    esp: Some(EspHeader::default()),
+                    shim6: None,
+                    mobility: None,
+                    hip: None,
+                    exp0: None,
+                    exp1: None,
+                }
+            );
+
+            // serializing via Ipv6Extensions gives the same result
+            let mut expected = Vec::new();
+            chain.write(&mut expected).unwrap();
+            let mut actual = Vec::new();
+            exts.write(&mut actual, IPV6_HOP_BY_HOP).unwrap();
+            assert_eq!(expected, actual);
+
+            // and converting back results in the same chain
+            assert_eq!(
+                Ok(chain),
+                Ipv6ExtensionChain::from_extensions(IPV6_HOP_BY_HOP, &exts)
+            );
+        }
+
+        // not representable
+        for (headers, ext) in [
+            (
+                [H::HopByHop(dest(UDP, 0)), H::HopByHop(dest(UDP, 0))],
+                IPV6_HOP_BY_HOP,
+            ),
+            (
+                [
+                    H::DestinationOptions(dest(UDP, 0)),
+                    H::DestinationOptions(dest(UDP, 0)),
+                ],
+                IPV6_DEST_OPTIONS,
+            ),
+            (
+                [H::Routing(dest(UDP, 0)), H::Routing(dest(UDP, 0))],
+                IPV6_ROUTE,
+            ),
+            (
+                [
+                    H::Fragment(Ipv6FragmentHeader::new(UDP, IpFragOffset::ZERO, false, 0)),
+                    H::Fragment(Ipv6FragmentHeader::new(UDP, IpFragOffset::ZERO, false, 0)),
+                ],
+                IPV6_FRAG,
+            ),
+            (
+                [
+                    H::Authentication(IpAuthHeader::new(UDP, 0, 0, &[]).unwrap()),
+                    H::Authentication(IpAuthHeader::new(UDP, 0, 0, &[]).unwrap()),
+                ],
+                AUTH,
+            ),
+            (
+                [H::Esp(EspHeader::default()), H::Esp(EspHeader::default())],
+                ENCAP_SEC,
+            ),
+            (
+                [
+                    H::Experimental(EXP1, dest(UDP, 0)),
+                    H::Experimental(EXP1, dest(UDP, 0)),
+                ],
+                EXP1,
+            ),
+            (
+                [
+                    H::DestinationOptions(dest(UDP, 0)),
+                    H::Experimental(UDP, dest(UDP, 0)),
+                ],
+                UDP,
+            ),
+        ] {
+            let mut chain = Ipv6ExtensionChain::new();
+            for header in headers {
+                chain.headers.push(header);
+            }
+            assert_eq!(Err(ExtNotRepresentable { ext }), chain.to_extensions());
+        }
+
+        // experimental headers
+        {
+            let mut chain = Ipv6ExtensionChain::new();
+            chain.try_push(H::Experimental(EXP0, dest(UDP, 1))).unwrap();
+            chain.try_push(H::Experimental(EXP1, dest(UDP, 2))).unwrap();
+            let exts = chain.to_extensions().unwrap();
+            assert_eq!(
+                exts,
+                Ipv6Extensions {
+                    exp0: Some(dest(EXP1, 1)),
+                    exp1: Some(dest(UDP, 2)),
+                    ..Default::default()
+                }
+            );
+            assert_eq!(Ok(chain), Ipv6ExtensionChain::from_extensions(EXP0, &exts));
+        }
+
+        // repeated destination options after a routing header
+        {
+            let mut chain = Ipv6ExtensionChain::new();
+            chain.headers.push(H::Routing(dest(UDP, 0)));
+            chain.headers.push(H::DestinationOptions(dest(UDP, 0)));
+            chain.headers.push(H::DestinationOptions(dest(UDP, 0)));
+            assert_eq!(
+                Err(ExtNotRepresentable {
+                    ext: IPV6_DEST_OPTIONS
+                }),
+                chain.to_extensions()
+            );
+        }
+    }
+
+    #[test]
+    fn from_extensions_errors() {
+        use ip_number::*;
+        use ExtsWalkError::*;
+
+        // hop by hop not at start
+        {
+            let exts = Ipv6Extensions {
+                hop_by_hop_options: Some(dest(UDP, 0)),
+                destination_options: Some(dest(IPV6_HOP_BY_HOP, 0)),
+                ..Default::default()
+            };
+            assert_eq!(
+                Err(HopByHopNotAtStart),
+                Ipv6ExtensionChain::from_extensions(IPV6_DEST_OPTIONS, &exts)
+            );
+        }
+
+        // not referenced
+        let exts = Ipv6Extensions {
+            hop_by_hop_options: Some(dest(UDP, 0)),
+            destination_options: Some(dest(UDP, 0)),
+            routing: Some(Ipv6RoutingExtensions {
+                routing: dest(UDP, 0),
+                final_destination_options: Some(dest(UDP, 0)),
+            }),
+            fragment: Some(Ipv6FragmentHeader::new(UDP, IpFragOffset::ZERO, false, 0)),
+            auth: Some(IpAuthHeader::new(UDP, 0, 0, &[]).unwrap()),
+            esp: Some(EspHeader::default()),
+            shim6: None,
+            mobility: None,
+            hip: None,
+            exp0: None,
+            exp1: None,
+        };
+        for (first_header, missing_ext) in [
+            (UDP, IPV6_HOP_BY_HOP),
+            (IPV6_HOP_BY_HOP, IPV6_DEST_OPTIONS),
+            (IPV6_DEST_OPTIONS, IPV6_HOP_BY_HOP),
+            (IPV6_ROUTE, IPV6_HOP_BY_HOP),
+            (IPV6_FRAG, IPV6_HOP_BY_HOP),
+            (AUTH, IPV6_HOP_BY_HOP),
+            (ENCAP_SEC, IPV6_HOP_BY_HOP),
+        ] {
+            assert_eq!(
+                Err(ExtNotReferenced { missing_ext }),
+                Ipv6ExtensionChain::from_extensions(first_header, &exts)
+            );
+        }
+        {
+            let mut exts = exts.clone();
+            exts.hop_by_hop_options = None;
+            exts.destination_options = None;
+            for (first_header, missing_ext) in [
+                (IPV6_ROUTE, IPV6_FRAG),
+                (IPV6_FRAG, IPV6_ROUTE),
+                (AUTH, IPV6_ROUTE),
+                (ENCAP_SEC, IPV6_ROUTE),
+            ] {
+                assert_eq!(
+                    Err(ExtNotReferenced { missing_ext }),
+                    Ipv6ExtensionChain::from_extensions(first_header, &exts)
+                );
+            }
+            exts.routing = None;
+            exts.fragment = None;
+            assert_eq!(
+                Err(ExtNotReferenced {
+                    missing_ext: ENCAP_SEC
+                }),
+                Ipv6ExtensionChain::from_extensions(AUTH, &exts)
+            );
+        }
+        {
+            let exts = Ipv6Extensions {
+                routing: Some(Ipv6RoutingExtensions {
+                    routing: dest(UDP, 0),
+                    final_destination_options: Some(dest(UDP, 0)),
+                }),
+                ..Default::default()
+            };
+            assert_eq!(
+                Err(ExtNotReferenced {
+                    missing_ext: IPV6_DEST_OPTIONS
+                }),
+                Ipv6ExtensionChain::from_extensions(IPV6_ROUTE, &exts)
+            );
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn from_extensions_to_extensions(exts in ipv6_extensions_with(ip_number::UDP)) {
+            let mut exts = exts;
+            let first_header = exts.set_next_headers(ip_number::UDP);
+            let chain = Ipv6ExtensionChain::from_extensions(first_header, &exts).unwrap();
+            assert_eq!(if exts.is_empty() { None } else { Some(first_header) }, chain.first_header());
+            assert_eq!(exts.header_len(), chain.header_len());
+            assert_eq!(Ok(exts.clone()), chain.to_extensions());
+
+            // byte exact round trip
+            let mut expected = Vec::new();
+            exts.write(&mut expected, first_header).unwrap();
+            let mut actual = Vec::new();
+            chain.write(&mut actual).unwrap();
+            assert_eq!(expected, actual);
+        }
+    }
+}
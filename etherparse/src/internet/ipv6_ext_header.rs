@@ -0,0 +1,228 @@
+use crate::*;
+
+/// A single IPv6 extension header (owned version of [`Ipv6ExtensionSlice`]).
+///
+/// Used as element of an [`Ipv6ExtensionChain`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Ipv6ExtensionHeader {
+    /// IPv6 Hop-by-Hop Option \[[RFC8200](https://datatracker.ietf.org/doc/html/rfc8200)\]
+    HopByHop(Ipv6RawExtHeader),
+    /// Routing Header for IPv6 \[[RFC8200](https://datatracker.ietf.org/doc/html/rfc8200)\] \[[RFC5095](https://datatracker.ietf.org/doc/html/rfc5095)\]
+    Routing(Ipv6RawExtHeader),
+    /// Fragment Header for IPv6 \[[RFC8200](https://datatracker.ietf.org/doc/html/rfc8200)\]
+    Fragment(Ipv6FragmentHeader),
+    /// Destination Options for IPv6 \[[RFC8200](https://datatracker.ietf.org/doc/html/rfc8200)\]
+    DestinationOptions(Ipv6RawExtHeader),
+    /// Authentication Header \[[RFC4302](https://datatracker.ietf.org/doc/html/rfc4302)\]
+    Authentication(IpAuthHeader),
+    /// Encapsulating Security Payload Header \[[RFC4303](https://datatracker.ietf.org/doc/html/rfc4303)\]
+    Esp(EspHeader),
+    /// Mobility Header \[[RFC6275](https://datatracker.ietf.org/doc/html/rfc6275)\]
+    Mobility(Ipv6RawExtHeader),
+    /// Host Identity Protocol \[[RFC7401](https://datatracker.ietf.org/doc/html/rfc7401)\]
+    Hip(Ipv6RawExtHeader),
+    /// Shim6 Protocol \[[RFC5533](https://datatracker.ietf.org/doc/html/rfc5533)\]
+    Shim6(Ipv6RawExtHeader),
+    /// Use for experimentation and testing (ip number 253 or 254)
+    /// \[[RFC3692](https://datatracker.ietf.org/doc/html/rfc3692)\]\[[RFC4727](https://datatracker.ietf.org/doc/html/rfc4727)\]
+    Experimental(IpNumber, Ipv6RawExtHeader),
+}
+
+impl Ipv6ExtensionHeader {
+    /// Returns the ip number identifying the extension header.
+    pub fn ip_number(&self) -> IpNumber {
+        use ip_number::*;
+        use Ipv6ExtensionHeader::*;
+        match self {
+            HopByHop(_) => IPV6_HOP_BY_HOP,
+            Routing(_) => IPV6_ROUTE,
+            Fragment(_) => IPV6_FRAG,
+            DestinationOptions(_) => IPV6_DEST_OPTIONS,
+            Authentication(_) => AUTH,
+            Esp(_) => ENCAP_SEC,
+            Mobility(_) => MOBILITY,
+            Hip(_) => HIP,
+            Shim6(_) => SHIM6,
+            Experimental(ip_number, _) => *ip_number,
+        }
+    }
+
+    /// Returns the "next header" field of the extension header.
+    ///
+    /// `None` is returned for ESP headers as their "next header" field
+    /// is part of the (usually encrypted) ESP trailer.
+    pub fn next_header(&self) -> Option<IpNumber> {
+        use Ipv6ExtensionHeader::*;
+        match self {
+            HopByHop(h)
+            | Routing(h)
+            | DestinationOptions(h)
+            | Mobility(h)
+            | Hip(h)
+            | Shim6(h)
+            | Experimental(_, h) => Some(h.next_header),
+            Fragment(h) => Some(h.next_header),
+            Authentication(h) => Some(h.next_header),
+            Esp(_) => None,
+        }
+    }
+
+    /// Sets the "next header" field of the extension header.
+    ///
+    /// Has no effect on ESP headers as their "next header" field is part
+    /// of the (usually encrypted) ESP trailer.
+    pub fn set_next_header(&mut self, next_header: IpNumber) {
+        use Ipv6ExtensionHeader::*;
+        match self {
+            HopByHop(h)
+            | Routing(h)
+            | DestinationOptions(h)
+            | Mobility(h)
+            | Hip(h)
+            | Shim6(h)
+            | Experimental(_, h) => {
+                h.next_header = next_header;
+            }
+            Fragment(h) => h.next_header = next_header,
+            Authentication(h) => h.next_header = next_header,
+            Esp(_) => {}
+        }
+    }
+
+    /// Length of the header in bytes.
+    pub fn header_len(&self) -> usize {
+        use Ipv6ExtensionHeader::*;
+        match self {
+            HopByHop(h)
+            | Routing(h)
+            | DestinationOptions(h)
+            | Mobility(h)
+            | Hip(h)
+            | Shim6(h)
+            | Experimental(_, h) => h.header_len(),
+            Fragment(h) => h.header_len(),
+            Authentication(h) => h.header_len(),
+            Esp(h) => h.header_len(),
+        }
+    }
+
+    /// Writes the header to the current position of the writer.
+    #[cfg(feature = "std")]
+    pub fn write<T: std::io::Write + Sized>(&self, writer: &mut T) -> Result<(), std::io::Error> {
+        use Ipv6ExtensionHeader::*;
+        match self {
+            HopByHop(h)
+            | Routing(h)
+            | DestinationOptions(h)
+            | Mobility(h)
+            | Hip(h)
+            | Shim6(h)
+            | Experimental(_, h) => h.write(writer),
+            Fragment(h) => h.write(writer),
+            Authentication(h) => h.write(writer),
+            Esp(h) => h.write(writer),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_gens::*;
+    use alloc::{format, vec::Vec};
+    use proptest::prelude::*;
+    use Ipv6ExtensionHeader::*;
+
+    fn all_headers(
+        raw: &Ipv6RawExtHeader,
+        frag: &Ipv6FragmentHeader,
+        auth: &IpAuthHeader,
+        esp: &EspHeader,
+    ) -> [Ipv6ExtensionHeader; 10] {
+        [
+            HopByHop(raw.clone()),
+            Routing(raw.clone()),
+            Fragment(frag.clone()),
+            DestinationOptions(raw.clone()),
+            Authentication(auth.clone()),
+            Esp(esp.clone()),
+            Mobility(raw.clone()),
+            Hip(raw.clone()),
+            Shim6(raw.clone()),
+            Experimental(ip_number::EXP0, raw.clone()),
+        ]
+    }
+
+    proptest! {
+        #[test]
+        fn debug_clone_eq(raw in ipv6_raw_ext_any()) {
+            let header = HopByHop(raw.clone());
+            assert_eq!(format!("HopByHop({:?})", raw), format!("{:?}", header));
+            assert_eq!(header, header.clone());
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn ip_number_next_header(
+            raw in ipv6_raw_ext_any(),
+            frag in ipv6_fragment_any(),
+            auth in ip_auth_any(),
+            esp in esp_any(),
+            next_header in ip_number_any()
+        ) {
+            use ip_number::*;
+            let expected = [
+                IPV6_HOP_BY_HOP,
+                IPV6_ROUTE,
+                IPV6_FRAG,
+                IPV6_DEST_OPTIONS,
+                AUTH,
+                ENCAP_SEC,
+                MOBILITY,
+                HIP,
+                SHIM6,
+                EXP0,
+            ];
+            for (mut header, ip_number) in all_headers(&raw, &frag, &auth, &esp).into_iter().zip(expected) {
+                assert_eq!(ip_number, header.ip_number());
+                header.set_next_header(next_header);
+                if ENCAP_SEC == ip_number {
+                    assert_eq!(None, header.next_header());
+                    assert_eq!(Esp(esp.clone()), header);
+                } else {
+                    assert_eq!(Some(next_header), header.next_header());
+                }
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn header_len_write(
+            raw in ipv6_raw_ext_any(),
+            frag in ipv6_fragment_any(),
+            auth in ip_auth_any(),
+            esp in esp_any()
+        ) {
+            let expected: [Vec<u8>; 10] = [
+                raw.to_bytes().to_vec(),
+                raw.to_bytes().to_vec(),
+                frag.to_bytes().to_vec(),
+                raw.to_bytes().to_vec(),
+                auth.to_bytes().to_vec(),
+                esp.to_bytes().to_vec(),
+                raw.to_bytes().to_vec(),
+                raw.to_bytes().to_vec(),
+                raw.to_bytes().to_vec(),
+                raw.to_bytes().to_vec(),
+            ];
+            for (header, bytes) in all_headers(&raw, &frag, &auth, &esp).iter().zip(expected) {
+                assert_eq!(bytes.len(), header.header_len());
+                let mut buffer = Vec::with_capacity(bytes.len());
+                header.write(&mut buffer).unwrap();
+                assert_eq!(bytes, buffer);
+            }
+        }
+    }
+}
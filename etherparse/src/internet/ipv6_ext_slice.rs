@@ -4,17 +4,6 @@ use crate::*;
 ///
 /// This enum is used as item type when iterating over a list of extension headers
 /// with an [Ipv6ExtensionSliceIter].
-///
-/// Note the following extension headers are missing from
-/// this enum and currently not supported (list taken on 2021-07-17
-/// from <https://www.iana.org/assignments/ipv6-parameters/ipv6-parameters.xhtml>):
-///
-/// * Encapsulating Security Payload \[[RFC4303](https://datatracker.ietf.org/doc/html/rfc4303)\]
-/// * Mobility Header \[[RFC6275](https://datatracker.ietf.org/doc/html/rfc6275)\]
-/// * Host Identity Protocol \[[RFC7401](https://datatracker.ietf.org/doc/html/rfc7401)\]
-/// * Shim6 Protocol \[[RFC5533](https://datatracker.ietf.org/doc/html/rfc5533)\]
-/// * 253 Use for experimentation and testing \[[RFC3692](https://datatracker.ietf.org/doc/html/rfc3692)\]\[[RFC4727](https://datatracker.ietf.org/doc/html/rfc4727)\]
-/// * 254 Use for experimentation and testing \[[RFC3692](https://datatracker.ietf.org/doc/html/rfc3692)\]\[[RFC4727](https://datatracker.ietf.org/doc/html/rfc4727)\]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum Ipv6ExtensionSlice<'a> {
     /// IPv6 Hop-by-Hop Option \[[RFC8200](https://datatracker.ietf.org/doc/html/rfc8200)\]
@@ -27,4 +16,82 @@ pub enum Ipv6ExtensionSlice<'a> {
     DestinationOptions(Ipv6RawExtHeaderSlice<'a>),
     /// Authentication Header \[[RFC4302](https://datatracker.ietf.org/doc/html/rfc4302)\]
     Authentication(IpAuthHeaderSlice<'a>),
+    /// Encapsulating Security Payload \[[RFC4303](https://datatracker.ietf.org/doc/html/rfc4303)\]
+    ///
+    /// Always the last extension header as the data after it is encrypted.
+    Esp(EspHeaderSlice<'a>),
+    /// Mobility Header \[[RFC6275](https://datatracker.ietf.org/doc/html/rfc6275)\]
+    ///
+    /// Use [`Ipv6MobilityHeaderSlice::from_raw`] to decode the message.
+    Mobility(Ipv6RawExtHeaderSlice<'a>),
+    /// Host Identity Protocol \[[RFC7401](https://datatracker.ietf.org/doc/html/rfc7401)\]
+    ///
+    /// Use [`HipHeaderSlice::from_raw`] to decode the header fields.
+    Hip(Ipv6RawExtHeaderSlice<'a>),
+    /// Shim6 Protocol \[[RFC5533](https://datatracker.ietf.org/doc/html/rfc5533)\]
+    ///
+    /// Use [`Shim6HeaderSlice::from_raw`] to decode the header fields.
+    Shim6(Ipv6RawExtHeaderSlice<'a>),
+    /// Use for experimentation and testing (ip number 253 or 254)
+    /// \[[RFC3692](https://datatracker.ietf.org/doc/html/rfc3692)\]\[[RFC4727](https://datatracker.ietf.org/doc/html/rfc4727)\]
+    ///
+    /// The header is expected to use the generic IPv6 extension header
+    /// format (RFC 6564).
+    Experimental(IpNumber, Ipv6RawExtHeaderSlice<'a>),
+}
+
+impl<'a> Ipv6ExtensionSlice<'a> {
+    /// Returns the ip number identifying the extension header.
+    pub fn ip_number(&self) -> IpNumber {
+        use ip_number::*;
+        use Ipv6ExtensionSlice::*;
+        match self {
+            HopByHop(_) => IPV6_HOP_BY_HOP,
+            Routing(_) => IPV6_ROUTE,
+            Fragment(_) => IPV6_FRAG,
+            DestinationOptions(_) => IPV6_DEST_OPTIONS,
+            Authentication(_) => AUTH,
+            Esp(_) => ENCAP_SEC,
+            Mobility(_) => MOBILITY,
+            Hip(_) => HIP,
+            Shim6(_) => SHIM6,
+            Experimental(ip_number, _) => *ip_number,
+        }
+    }
+
+    /// Returns the slice containing the extension header.
+    pub fn slice(&self) -> &'a [u8] {
+        use Ipv6ExtensionSlice::*;
+        match self {
+            HopByHop(s)
+            | Routing(s)
+            | DestinationOptions(s)
+            | Mobility(s)
+            | Hip(s)
+            | Shim6(s)
+            | Experimental(_, s) => s.slice(),
+            Fragment(s) => s.slice(),
+            Authentication(s) => s.slice(),
+            Esp(s) => s.slice(),
+        }
+    }
+
+    /// Decode the extension header and copy the results to an
+    /// [`Ipv6ExtensionHeader`].
+    pub fn to_header(&self) -> Ipv6ExtensionHeader {
+        use Ipv6ExtensionHeader as H;
+        use Ipv6ExtensionSlice as S;
+        match self {
+            S::HopByHop(s) => H::HopByHop(s.to_header()),
+            S::Routing(s) => H::Routing(s.to_header()),
+            S::Fragment(s) => H::Fragment(s.to_header()),
+            S::DestinationOptions(s) => H::DestinationOptions(s.to_header()),
+            S::Authentication(s) => H::Authentication(s.to_header()),
+            S::Esp(s) => H::Esp(s.to_header()),
+            S::Mobility(s) => H::Mobility(s.to_header()),
+            S::Hip(s) => H::Hip(s.to_header()),
+            S::Shim6(s) => H::Shim6(s.to_header()),
+            S::Experimental(ip_number, s) => H::Experimental(*ip_number, s.to_header()),
+        }
+    }
 }
@@ -68,6 +68,43 @@ impl<'a> Iterator for Ipv6ExtensionSliceIter<'a> {
                 self.next_header = slice.next_header();
                 Some(Authentication(slice))
             },
+            ENCAP_SEC => unsafe {
+                let slice = EspHeaderSlice::from_slice_unchecked(self.rest);
+                let len = slice.slice().len();
+                self.rest = from_raw_parts(self.rest.as_ptr().add(len), self.rest.len() - len);
+                // the data after the ESP header is encrypted
+                self.next_header = IPV6_NO_NEXT_HEADER;
+                Some(Esp(slice))
+            },
+            MOBILITY => unsafe {
+                let slice = Ipv6RawExtHeaderSlice::from_slice_unchecked(self.rest);
+                let len = slice.slice().len();
+                self.rest = from_raw_parts(self.rest.as_ptr().add(len), self.rest.len() - len);
+                self.next_header = slice.next_header();
+                Some(Mobility(slice))
+            },
+            HIP => unsafe {
+                let slice = Ipv6RawExtHeaderSlice::from_slice_unchecked(self.rest);
+                let len = slice.slice().len();
+                self.rest = from_raw_parts(self.rest.as_ptr().add(len), self.rest.len() - len);
+                self.next_header = slice.next_header();
+                Some(Hip(slice))
+            },
+            SHIM6 => unsafe {
+                let slice = Ipv6RawExtHeaderSlice::from_slice_unchecked(self.rest);
+                let len = slice.slice().len();
+                self.rest = from_raw_parts(self.rest.as_ptr().add(len), self.rest.len() - len);
+                self.next_header = slice.next_header();
+                Some(Shim6(slice))
+            },
+            EXP0 | EXP1 => unsafe {
+                let ip_number = self.next_header;
+                let slice = Ipv6RawExtHeaderSlice::from_slice_unchecked(self.rest);
+                let len = slice.slice().len();
+                self.rest = from_raw_parts(self.rest.as_ptr().add(len), self.rest.len() - len);
+                self.next_header = slice.next_header();
+                Some(Experimental(ip_number, slice))
+            },
             // done parsing, the next header is not a known/supported header extension
             _ => None,
         }
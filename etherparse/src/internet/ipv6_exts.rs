@@ -1,4 +1,7 @@
-use crate::{err::ipv6_exts::*, *};
+use crate::{
+    err::{ipv6_exts::*, ValueTooBigError, ValueType},
+    *,
+};
 
 /// IPv6 extension headers present after the ip header.
 ///
@@ -10,13 +13,16 @@ use crate::{err::ipv6_exts::*, *};
 /// * Routing Header
 /// * Fragment
 /// * Authentication Header
-///
-/// Currently not supported:
-////
 /// * Encapsulating Security Payload Header (ESP)
-/// * Host Identity Protocol (HIP)
-/// * IP Mobility
-/// * Site Multihoming by IPv6 Intermediation (SHIM6)
+/// * Mobility Header
+/// * Host Identity Protocol (HIP) Header
+/// * Site Multihoming by IPv6 Intermediation (SHIM6) Header
+/// * Experimental & testing headers (ip numbers 253 & 254, using the
+///   generic extension header format)
+///
+/// Each header can only be present once (with the exception of the
+/// destination options header). Use [`Ipv6ExtensionChain`] if repeated
+/// or non standard ordered headers have to be preserved.
 #[derive(Clone, Debug, Eq, PartialEq, Default)]
 pub struct Ipv6Extensions {
     pub hop_by_hop_options: Option<Ipv6RawExtHeader>,
@@ -24,6 +30,22 @@ pub struct Ipv6Extensions {
     pub routing: Option<Ipv6RoutingExtensions>,
     pub fragment: Option<Ipv6FragmentHeader>,
     pub auth: Option<IpAuthHeader>,
+    /// ESP header (always the last extension header as the data
+    /// after it is encrypted).
+    pub esp: Option<EspHeader>,
+    /// Shim6 header (RFC 5533, located in front of the fragment header).
+    pub shim6: Option<Ipv6RawExtHeader>,
+    /// Mobility header (RFC 6275, located after all other extension headers).
+    pub mobility: Option<Ipv6RawExtHeader>,
+    /// Host Identity Protocol header (RFC 7401, located after all other
+    /// extension headers).
+    pub hip: Option<Ipv6RawExtHeader>,
+    /// Experimental & testing header with the ip number 253 (RFC 3692,
+    /// located after all other extension headers).
+    pub exp0: Option<Ipv6RawExtHeader>,
+    /// Experimental & testing header with the ip number 254 (RFC 3692,
+    /// located after all other extension headers).
+    pub exp1: Option<Ipv6RawExtHeader>,
 }
 
 impl Ipv6Extensions {
@@ -32,10 +54,11 @@ impl Ipv6Extensions {
     pub const MIN_LEN: usize = 0;
 
     /// Maximum summed up length of all extension headers in bytes/octets.
-    pub const MAX_LEN: usize = Ipv6RawExtHeader::MAX_LEN * 2
+    pub const MAX_LEN: usize = Ipv6RawExtHeader::MAX_LEN * 7
         + Ipv6RoutingExtensions::MAX_LEN
         + Ipv6FragmentHeader::LEN
-        + IpAuthHeader::MAX_LEN;
+        + IpAuthHeader::MAX_LEN
+        + EspHeader::LEN;
 
     /// Reads as many extension headers as possible from the slice.
     ///
@@ -51,7 +74,9 @@ impl Ipv6Extensions {
     /// the struct. In such a scenario a struct with the data that could be parsed is returned
     /// together with the next header ip number and slice containing the unparsed data.
     ///
-    /// It is in the responsibility of the caller to handle a scenario like this.
+    /// It is in the responsibility of the caller to handle a scenario like this
+    /// (e.g. by using [`Ipv6ExtensionChain::from_slice`] instead, which keeps
+    /// repeated headers).
     ///
     /// The reason that no error is generated, is that even though according to RFC 8200 packets
     /// "should" not contain more then one occurence of an extension header the RFC also specifies
@@ -129,6 +154,25 @@ impl Ipv6Extensions {
                         });
                     }
                 }
+                SHIM6 | MOBILITY | HIP | EXP0 | EXP1 => {
+                    let target = match next_header {
+                        SHIM6 => &mut result.shim6,
+                        MOBILITY => &mut result.mobility,
+                        HIP => &mut result.hip,
+                        EXP0 => &mut result.exp0,
+                        _ => &mut result.exp1,
+                    };
+                    if target.is_some() {
+                        // more then one header of this type found -> abort parsing
+                        return Ok((result, next_header, rest));
+                    } else {
+                        let slice = Ipv6RawExtHeaderSlice::from_slice(rest)
+                            .map_err(|err| Len(err.add_offset(slice.len() - rest.len())))?;
+                        rest = &rest[slice.slice().len()..];
+                        next_header = slice.next_header();
+                        *target = Some(slice.to_header());
+                    }
+                }
                 IPV6_FRAG => {
                     if result.fragment.is_some() {
                         // more then one header of this type found -> abort parsing
@@ -159,6 +203,13 @@ impl Ipv6Extensions {
                         result.auth = Some(slice.to_header());
                     }
                 }
+                ENCAP_SEC => {
+                    let (header, header_rest) = EspHeader::from_slice(rest)
+                        .map_err(|err| Len(err.add_offset(slice.len() - rest.len())))?;
+                    result.esp = Some(header);
+                    // the data after the ESP header is encrypted
+                    return Ok((result, next_header, header_rest));
+                }
                 _ => {
                     // done parsing, the next header is not a known header extension
                     return Ok((result, next_header, rest));
@@ -254,6 +305,23 @@ impl Ipv6Extensions {
                         });
                     }
                 }
+                SHIM6 | MOBILITY | HIP | EXP0 | EXP1 => {
+                    let target = match next_protocol {
+                        SHIM6 => &mut result.shim6,
+                        MOBILITY => &mut result.mobility,
+                        HIP => &mut result.hip,
+                        EXP0 => &mut result.exp0,
+                        _ => &mut result.exp1,
+                    };
+                    if target.is_some() {
+                        // more then one header of this type found -> abort parsing
+                        return Ok((result, next_protocol));
+                    } else {
+                        let header = Ipv6RawExtHeader::read(reader).map_err(Io)?;
+                        next_protocol = header.next_header;
+                        *target = Some(header);
+                    }
+                }
                 IPV6_FRAG => {
                     if result.fragment.is_some() {
                         // more then one header of this type found -> abort parsing
@@ -280,6 +348,11 @@ impl Ipv6Extensions {
                         result.auth = Some(header);
                     }
                 }
+                ENCAP_SEC => {
+                    result.esp = Some(EspHeader::read(reader).map_err(Io)?);
+                    // the data after the ESP header is encrypted
+                    return Ok((result, next_protocol));
+                }
                 _ => {
                     // done parsing, the next header is not a known header extension
                     return Ok((result, next_protocol));
@@ -389,6 +462,24 @@ impl Ipv6Extensions {
                         });
                     }
                 }
+                SHIM6 | MOBILITY | HIP | EXP0 | EXP1 => {
+                    let target = match next_protocol {
+                        SHIM6 => &mut result.shim6,
+                        MOBILITY => &mut result.mobility,
+                        HIP => &mut result.hip,
+                        EXP0 => &mut result.exp0,
+                        _ => &mut result.exp1,
+                    };
+                    if target.is_some() {
+                        // more then one header of this type found -> abort parsing
+                        return Ok((result, next_protocol));
+                    } else {
+                        let header =
+                            Ipv6RawExtHeader::read_limited(reader).map_err(map_limited_err)?;
+                        next_protocol = header.next_header;
+                        *target = Some(header);
+                    }
+                }
                 IPV6_FRAG => {
                     if result.fragment.is_some() {
                         // more then one header of this type found -> abort parsing
@@ -417,6 +508,11 @@ impl Ipv6Extensions {
                         result.auth = Some(header);
                     }
                 }
+                ENCAP_SEC => {
+                    result.esp = Some(EspHeader::read_limited(reader).map_err(map_limited_err)?);
+                    // the data after the ESP header is encrypted
+                    return Ok((result, next_protocol));
+                }
                 _ => {
                     // done parsing, the next header is not a known header extension
                     return Ok((result, next_protocol));
@@ -452,7 +548,13 @@ impl Ipv6Extensions {
             pub routing: bool,
             pub fragment: bool,
             pub auth: bool,
+            pub esp: bool,
             pub final_destination_options: bool,
+            pub shim6: bool,
+            pub mobility: bool,
+            pub hip: bool,
+            pub exp0: bool,
+            pub exp1: bool,
         }
 
         let mut needs_write = NeedsWrite {
@@ -461,11 +563,17 @@ impl Ipv6Extensions {
             routing: self.routing.is_some(),
             fragment: self.fragment.is_some(),
             auth: self.auth.is_some(),
+            esp: self.esp.is_some(),
             final_destination_options: if let Some(ref routing) = self.routing {
                 routing.final_destination_options.is_some()
             } else {
                 false
             },
+            shim6: self.shim6.is_some(),
+            mobility: self.mobility.is_some(),
+            hip: self.hip.is_some(),
+            exp0: self.exp0.is_some(),
+            exp1: self.exp1.is_some(),
         };
 
         let mut next_header = first_header;
@@ -535,6 +643,23 @@ impl Ipv6Extensions {
                         break;
                     }
                 }
+                SHIM6 | MOBILITY | HIP | EXP0 | EXP1 => {
+                    let (needs, header) = match next_header {
+                        SHIM6 => (&mut needs_write.shim6, &self.shim6),
+                        MOBILITY => (&mut needs_write.mobility, &self.mobility),
+                        HIP => (&mut needs_write.hip, &self.hip),
+                        EXP0 => (&mut needs_write.exp0, &self.exp0),
+                        _ => (&mut needs_write.exp1, &self.exp1),
+                    };
+                    if *needs {
+                        let header = header.as_ref().unwrap();
+                        header.write(writer).map_err(Io)?;
+                        next_header = header.next_header;
+                        *needs = false;
+                    } else {
+                        break;
+                    }
+                }
                 IPV6_FRAG => {
                     if needs_write.fragment {
                         let header = &self.fragment.as_ref().unwrap();
@@ -555,6 +680,14 @@ impl Ipv6Extensions {
                         break;
                     }
                 }
+                ENCAP_SEC => {
+                    if needs_write.esp {
+                        self.esp.as_ref().unwrap().write(writer).map_err(Io)?;
+                        needs_write.esp = false;
+                    }
+                    // the data after the ESP header is encrypted
+                    break;
+                }
                 _ => {
                     // reached an unknown next_header id, proceed to check if everything was written
                     break;
@@ -583,10 +716,34 @@ impl Ipv6Extensions {
             Err(Content(ExtNotReferenced {
                 missing_ext: IpNumber::AUTHENTICATION_HEADER,
             }))
+        } else if needs_write.esp {
+            Err(Content(ExtNotReferenced {
+                missing_ext: IpNumber::ENCAPSULATING_SECURITY_PAYLOAD,
+            }))
         } else if needs_write.final_destination_options {
             Err(Content(ExtNotReferenced {
                 missing_ext: IpNumber::IPV6_DESTINATION_OPTIONS,
             }))
+        } else if needs_write.shim6 {
+            Err(Content(ExtNotReferenced {
+                missing_ext: IpNumber::SHIM6,
+            }))
+        } else if needs_write.mobility {
+            Err(Content(ExtNotReferenced {
+                missing_ext: IpNumber::MOBILITY_HEADER,
+            }))
+        } else if needs_write.hip {
+            Err(Content(ExtNotReferenced {
+                missing_ext: IpNumber::HIP,
+            }))
+        } else if needs_write.exp0 {
+            Err(Content(ExtNotReferenced {
+                missing_ext: IpNumber::EXPERIMENTAL_AND_TESTING_0,
+            }))
+        } else if needs_write.exp1 {
+            Err(Content(ExtNotReferenced {
+                missing_ext: IpNumber::EXPERIMENTAL_AND_TESTING_1,
+            }))
         } else {
             Ok(())
         }
@@ -614,10 +771,132 @@ impl Ipv6Extensions {
         if let Some(ref header) = self.auth {
             result += header.header_len();
         }
+        if let Some(ref header) = self.esp {
+            result += header.header_len();
+        }
+        for header in [
+            &self.shim6,
+            &self.mobility,
+            &self.hip,
+            &self.exp0,
+            &self.exp1,
+        ]
+        .into_iter()
+        .flatten()
+        {
+            result += header.header_len();
+        }
 
         result
     }
 
+    /// Returns the value of the "Jumbo Payload" option (RFC 2675) if one
+    /// is present in the hop-by-hop options header.
+    pub fn jumbo_payload_len(&self) -> Option<u32> {
+        self.hop_by_hop_options
+            .as_ref()
+            .and_then(|header| header.jumbo_payload_len())
+    }
+
+    /// Sets the "payload length" field of the given IPv6 header based on
+    /// the length of the extension headers and the given length of the
+    /// data following the extension headers (e.g. TCP or UDP header
+    /// plus payload).
+    ///
+    /// If the resulting length does not fit into the 16 bit "payload
+    /// length" field the packet is turned into a jumbogram (RFC 2675). In this case the
+    /// "payload length" field is set to 0 and the length is stored in a
+    /// "Jumbo Payload" option in the hop-by-hop options header (the
+    /// option & the header are added if not yet present). A newly added
+    /// hop-by-hop options header is inserted directly after the IPv6
+    /// header (the `next_header` fields of the IPv6 header & the new
+    /// hop-by-hop options header are updated accordingly).
+    ///
+    /// An error is returned if the length does not fit into the "Jumbo
+    /// Payload" option or if there is not enough space left in the
+    /// hop-by-hop options header to add the option.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use etherparse::{ip_number, Ipv6Extensions, Ipv6Header};
+    ///
+    /// let mut exts = Ipv6Extensions::default();
+    /// let mut header = Ipv6Header::default();
+    ///
+    /// // normal packet
+    /// exts.set_payload_length(&mut header, 1000).unwrap();
+    /// assert_eq!(header.payload_length, 1000);
+    /// assert!(exts.hop_by_hop_options.is_none());
+    ///
+    /// // jumbogram (8 byte hop-by-hop header gets added)
+    /// exts.set_payload_length(&mut header, 70000).unwrap();
+    /// assert_eq!(header.payload_length, 0);
+    /// assert_eq!(header.next_header, ip_number::IPV6_HOP_BY_HOP);
+    /// assert_eq!(exts.jumbo_payload_len(), Some(8 + 70000));
+    /// ```
+    pub fn set_payload_length(
+        &mut self,
+        header: &mut Ipv6Header,
+        upper_layer_len: usize,
+    ) -> Result<(), ValueTooBigError<usize>> {
+        let len = self
+            .header_len_with_upper_layer(upper_layer_len)
+            .saturating_add(upper_layer_len);
+        if len <= usize::from(u16::MAX) {
+            header.payload_length = len as u16;
+            return Ok(());
+        }
+
+        let too_big = ValueTooBigError {
+            actual: len,
+            max_allowed: u32::MAX as usize,
+            value_type: ValueType::Ipv6PayloadLength,
+        };
+        if len > u32::MAX as usize {
+            return Err(too_big);
+        }
+        if let Some(hop_by_hop) = self.hop_by_hop_options.as_mut() {
+            hop_by_hop.set_jumbo_payload_len(len as u32).map_err(|_| {
+                // no space left to add the option, so the maximum is
+                // the limit of the "payload length" field
+                ValueTooBigError {
+                    max_allowed: usize::from(u16::MAX),
+                    ..too_big
+                }
+            })?;
+        } else {
+            // the hop-by-hop header is always the first extension header
+            // so it can be inserted directly after the IPv6 header
+            self.hop_by_hop_options = Some(
+                // unwrap is safe as a single jumbo payload option always fits
+                Ipv6RawExtHeader::try_from_options(
+                    header.next_header,
+                    &[Ipv6OptionElement::JumboPayload(len as u32)],
+                )
+                .unwrap(),
+            );
+            header.next_header = ip_number::IPV6_HOP_BY_HOP;
+        }
+        header.payload_length = 0;
+        Ok(())
+    }
+
+    /// Returns the length of the extension headers after a call to
+    /// [`Ipv6Extensions::set_payload_length`] with the given upper layer
+    /// length (including a potentially added "Jumbo Payload" option).
+    pub(crate) fn header_len_with_upper_layer(&self, upper_layer_len: usize) -> usize {
+        let header_len = self.header_len();
+        if header_len.saturating_add(upper_layer_len) > usize::from(u16::MAX)
+            && self.jumbo_payload_len().is_none()
+        {
+            // size of the added option or hop-by-hop header (both 8 octets)
+            header_len + 8
+        } else {
+            header_len
+        }
+    }
+
     /// Sets all the next_header fields of the headers based on the adviced default order
     /// with the given protocol number as last "next header" value. The return value is the protocol
     /// number of the first existing extension header that should be entered in the ipv6 header as
@@ -642,12 +921,39 @@ impl Ipv6Extensions {
         // * Destination Options header
         // * Upper-Layer header
         //
+        // The Shim6 header is placed in front of the fragment header
+        // (RFC 5533) and the mobility & HIP headers are placed after all
+        // other extension headers (RFC 6275 & RFC 7401). The experimental
+        // & testing headers (RFC 3692) have no defined position and are
+        // placed directly in front of the upper-layer header.
+        //
+        if let Some(ref mut header) = self.exp1 {
+            header.next_header = next;
+            next = EXP1;
+        }
+        if let Some(ref mut header) = self.exp0 {
+            header.next_header = next;
+            next = EXP0;
+        }
+        if let Some(ref mut header) = self.hip {
+            header.next_header = next;
+            next = HIP;
+        }
+        if let Some(ref mut header) = self.mobility {
+            header.next_header = next;
+            next = MOBILITY;
+        }
         if let Some(ref mut routing) = self.routing {
             if let Some(ref mut header) = routing.final_destination_options {
                 header.next_header = next;
                 next = IPV6_DEST_OPTIONS;
             }
         }
+        if self.esp.is_some() {
+            // the "next header" field of ESP is part of the encrypted
+            // trailer, so all following headers are part of the payload
+            next = ENCAP_SEC;
+        }
         if let Some(ref mut header) = self.auth {
             header.next_header = next;
             next = AUTH;
@@ -656,6 +962,10 @@ impl Ipv6Extensions {
             header.next_header = next;
             next = IPV6_FRAG;
         }
+        if let Some(ref mut header) = self.shim6 {
+            header.next_header = next;
+            next = SHIM6;
+        }
         if let Some(ref mut routing) = self.routing {
             routing.routing.next_header = next;
             next = IPV6_ROUTE;
@@ -685,7 +995,13 @@ impl Ipv6Extensions {
             pub routing: bool,
             pub fragment: bool,
             pub auth: bool,
+            pub esp: bool,
             pub final_destination_options: bool,
+            pub shim6: bool,
+            pub mobility: bool,
+            pub hip: bool,
+            pub exp0: bool,
+            pub exp1: bool,
         }
 
         let mut outstanding_refs = OutstandingRef {
@@ -694,11 +1010,17 @@ impl Ipv6Extensions {
             routing: self.routing.is_some(),
             fragment: self.fragment.is_some(),
             auth: self.auth.is_some(),
+            esp: self.esp.is_some(),
             final_destination_options: if let Some(ref routing) = self.routing {
                 routing.final_destination_options.is_some()
             } else {
                 false
             },
+            shim6: self.shim6.is_some(),
+            mobility: self.mobility.is_some(),
+            hip: self.hip.is_some(),
+            exp0: self.exp0.is_some(),
+            exp1: self.exp1.is_some(),
         };
 
         let mut next = first_next_header;
@@ -765,6 +1087,21 @@ impl Ipv6Extensions {
                         break;
                     }
                 }
+                SHIM6 | MOBILITY | HIP | EXP0 | EXP1 => {
+                    let (outstanding, header) = match next {
+                        SHIM6 => (&mut outstanding_refs.shim6, &self.shim6),
+                        MOBILITY => (&mut outstanding_refs.mobility, &self.mobility),
+                        HIP => (&mut outstanding_refs.hip, &self.hip),
+                        EXP0 => (&mut outstanding_refs.exp0, &self.exp0),
+                        _ => (&mut outstanding_refs.exp1, &self.exp1),
+                    };
+                    if *outstanding {
+                        next = header.as_ref().unwrap().next_header;
+                        *outstanding = false;
+                    } else {
+                        break;
+                    }
+                }
                 IPV6_FRAG => {
                     if outstanding_refs.fragment {
                         let header = &self.fragment.as_ref().unwrap();
@@ -783,6 +1120,11 @@ impl Ipv6Extensions {
                         break;
                     }
                 }
+                ENCAP_SEC => {
+                    // the data after the ESP header is encrypted
+                    outstanding_refs.esp = false;
+                    break;
+                }
                 _ => break,
             }
         }
@@ -813,11 +1155,41 @@ impl Ipv6Extensions {
                 missing_ext: IpNumber::AUTHENTICATION_HEADER,
             });
         }
+        if outstanding_refs.esp {
+            return Err(ExtNotReferenced {
+                missing_ext: IpNumber::ENCAPSULATING_SECURITY_PAYLOAD,
+            });
+        }
         if outstanding_refs.final_destination_options {
             return Err(ExtNotReferenced {
                 missing_ext: IpNumber::IPV6_DESTINATION_OPTIONS,
             });
         }
+        if outstanding_refs.shim6 {
+            return Err(ExtNotReferenced {
+                missing_ext: IpNumber::SHIM6,
+            });
+        }
+        if outstanding_refs.mobility {
+            return Err(ExtNotReferenced {
+                missing_ext: IpNumber::MOBILITY_HEADER,
+            });
+        }
+        if outstanding_refs.hip {
+            return Err(ExtNotReferenced {
+                missing_ext: IpNumber::HIP,
+            });
+        }
+        if outstanding_refs.exp0 {
+            return Err(ExtNotReferenced {
+                missing_ext: IpNumber::EXPERIMENTAL_AND_TESTING_0,
+            });
+        }
+        if outstanding_refs.exp1 {
+            return Err(ExtNotReferenced {
+                missing_ext: IpNumber::EXPERIMENTAL_AND_TESTING_1,
+            });
+        }
 
         Ok(next)
     }
@@ -847,5 +1219,69 @@ impl Ipv6Extensions {
             && self.routing.is_none()
             && self.fragment.is_none()
             && self.auth.is_none()
+            && self.esp.is_none()
+            && self.shim6.is_none()
+            && self.mobility.is_none()
+            && self.hip.is_none()
+            && self.exp0.is_none()
+            && self.exp1.is_none()
+    }
+
+    /// Sets the routing header to a segment routing header (SRv6, RFC 8754)
+    /// containing the given segment list & TLVs.
+    ///
+    /// An existing routing header gets replaced, but already present
+    /// "final destination options" are kept. The `next_header` field of
+    /// the segment routing header is set by
+    /// [`Ipv6Extensions::set_next_headers`] (also called during writing
+    /// via the [`crate::PacketBuilder`]).
+    ///
+    /// See [`Ipv6RawExtHeader::try_from_srh`] for a description of the
+    /// arguments.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use etherparse::{ip_number, Ipv6Extensions};
+    ///
+    /// let mut exts = Ipv6Extensions::default();
+    /// exts.set_srh(
+    ///     1, // segments left
+    ///     0, // flags
+    ///     0, // tag
+    ///     &[[1; 16], [2; 16]],
+    ///     &[],
+    /// ).unwrap();
+    ///
+    /// assert_eq!(
+    ///     exts.set_next_headers(ip_number::UDP),
+    ///     ip_number::IPV6_ROUTE
+    /// );
+    /// ```
+    pub fn set_srh(
+        &mut self,
+        segments_left: u8,
+        flags: u8,
+        tag: u16,
+        segments: &[[u8; 16]],
+        tlvs: &[Ipv6SrhTlv],
+    ) -> Result<(), err::ipv6_exts::SrhWriteError> {
+        let routing = Ipv6RawExtHeader::try_from_srh(
+            // placeholder, gets replaced by set_next_headers
+            ip_number::IPV6_NO_NEXT_HEADER,
+            segments_left,
+            flags,
+            tag,
+            segments,
+            tlvs,
+        )?;
+        self.routing = Some(Ipv6RoutingExtensions {
+            routing,
+            final_destination_options: self
+                .routing
+                .take()
+                .and_then(|r| r.final_destination_options),
+        });
+        Ok(())
     }
 }
@@ -10,12 +10,17 @@ use core::slice::from_raw_parts;
 /// * Routing Header
 /// * Fragment
 /// * Authentication Header
-///
-/// Currently not supported:
 /// * Encapsulating Security Payload Header (ESP)
-/// * Host Identity Protocol (HIP)
-/// * IP Mobility
-/// * Site Multihoming by IPv6 Intermediation (SHIM6)
+/// * Mobility Header (see [`Ipv6MobilityHeaderSlice`])
+/// * Host Identity Protocol (HIP) Header (see [`HipHeaderSlice`])
+/// * Site Multihoming by IPv6 Intermediation (SHIM6) Header (see [`Shim6HeaderSlice`])
+/// * Experimental & testing headers (ip numbers 253 & 254, using the
+///   generic extension header format)
+///
+/// Repeated extension headers are supported and the headers are kept
+/// in the order they are present on the wire (use
+/// [`Ipv6ExtensionsSlice::into_iter`] to iterate over them or
+/// [`Ipv6ExtensionChain::from_slice`] to get an owned copy).
 #[derive(Clone, Debug, Eq, PartialEq, Default)]
 pub struct Ipv6ExtensionsSlice<'a> {
     /// IP protocol number of the first header present in the slice.
@@ -29,6 +34,11 @@ pub struct Ipv6ExtensionsSlice<'a> {
 impl<'a> Ipv6ExtensionsSlice<'a> {
     /// Collects all ipv6 extension headers in a slice & checks if
     /// a fragmentation header that fragments the packet is present.
+    ///
+    /// The parsing stops after an ESP header as the data following it is
+    /// encrypted. In this case the returned ip number is
+    /// [`ip_number::ENCAP_SEC`] (the actual "next header" value is part
+    /// of the encrypted ESP trailer).
     pub fn from_slice(
         start_ip_number: IpNumber,
         start_slice: &'a [u8],
@@ -52,7 +62,7 @@ impl<'a> Ipv6ExtensionsSlice<'a> {
                 IPV6_HOP_BY_HOP => {
                     return Err(Content(HopByHopNotAtStart));
                 }
-                IPV6_DEST_OPTIONS | IPV6_ROUTE => {
+                IPV6_DEST_OPTIONS | IPV6_ROUTE | MOBILITY | HIP | SHIM6 | EXP0 | EXP1 => {
                     let slice = Ipv6RawExtHeaderSlice::from_slice(rest)
                         .map_err(|err| Len(err.add_offset(start_slice.len() - rest.len())))?;
                     // SAFETY:
@@ -99,6 +109,20 @@ impl<'a> Ipv6ExtensionsSlice<'a> {
                     };
                     next_header = slice.next_header();
                 }
+                ENCAP_SEC => {
+                    let slice = EspHeaderSlice::from_slice(rest)
+                        .map_err(|err| Len(err.add_offset(start_slice.len() - rest.len())))?;
+                    // SAFETY:
+                    // EspHeaderSlice::from_slice always generates
+                    // a subslice from the given slice rest. Therefor it is guranteed
+                    // that len is always greater or equal the len of rest.
+                    rest = unsafe {
+                        let len = slice.slice().len();
+                        from_raw_parts(rest.as_ptr().add(len), rest.len() - len)
+                    };
+                    // the data after the ESP header is encrypted
+                    break;
+                }
                 // done parsing, the next header is not a known/supported header extension
                 _ => break,
             }
@@ -153,6 +177,19 @@ impl<'a> Ipv6ExtensionsSlice<'a> {
     pub fn is_empty(&self) -> bool {
         self.slice.is_empty()
     }
+
+    /// Decodes all extension headers and copies them (in the order
+    /// they are present in the slice) to an [`Ipv6ExtensionChain`].
+    ///
+    /// Returns an error if more then [`Ipv6ExtensionChain::MAX_HEADERS`]
+    /// headers are present.
+    pub fn to_chain(&self) -> Result<Ipv6ExtensionChain, err::ipv6_exts::HeaderError> {
+        let mut result = Ipv6ExtensionChain::new();
+        for ext in self.clone().into_iter() {
+            result.try_push(ext.to_header())?;
+        }
+        Ok(result)
+    }
 }
 
 impl<'a> IntoIterator for Ipv6ExtensionsSlice<'a> {
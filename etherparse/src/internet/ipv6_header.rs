@@ -113,7 +113,7 @@ impl Ipv6Header {
         // validating the slice length)
         match next_header {
             IPV6_FRAG | AUTH | IPV6_HOP_BY_HOP | IPV6_ROUTE | IPV6_DEST_OPTIONS | MOBILITY
-            | HIP | SHIM6 => {}
+            | HIP | SHIM6 | EXP0 | EXP1 => {}
             _ => {
                 return Ok((next_header, slice));
             }
@@ -124,9 +124,8 @@ impl Ipv6Header {
             let len = match next_header {
                 IPV6_FRAG => 8,
                 AUTH => (usize::from(slice[1]) + 2) * 4,
-                IPV6_HOP_BY_HOP | IPV6_ROUTE | IPV6_DEST_OPTIONS | MOBILITY | HIP | SHIM6 => {
-                    (usize::from(slice[1]) + 1) * 8
-                }
+                IPV6_HOP_BY_HOP | IPV6_ROUTE | IPV6_DEST_OPTIONS | MOBILITY | HIP | SHIM6
+                | EXP0 | EXP1 => (usize::from(slice[1]) + 1) * 8,
                 // not a ipv6 header extension that can be skipped
                 _ => unreachable!(),
             };
@@ -160,7 +159,7 @@ impl Ipv6Header {
     /// octets have to be skipped to reach the start of the following header.
     pub fn is_skippable_header_extension(ip_protocol_number: IpNumber) -> bool {
         use crate::ip_number::*;
-        //Note: EncapsulatingSecurityPayload & ExperimentalAndTesting0 can not be skipped
+        //Note: EncapsulatingSecurityPayload can not be skipped
         matches!(
             ip_protocol_number,
             IPV6_HOP_BY_HOP
@@ -171,6 +170,8 @@ impl Ipv6Header {
                 | MOBILITY
                 | HIP
                 | SHIM6
+                | EXP0
+                | EXP1
         )
     }
 
@@ -216,7 +217,8 @@ impl Ipv6Header {
                 reader.read_exact(&mut buf)?;
                 (IpNumber(buf[0]), i64::from(buf[1]) * 4 + 6)
             }
-            IPV6_HOP_BY_HOP | IPV6_ROUTE | IPV6_DEST_OPTIONS | MOBILITY | HIP | SHIM6 => {
+            IPV6_HOP_BY_HOP | IPV6_ROUTE | IPV6_DEST_OPTIONS | MOBILITY | HIP | SHIM6 | EXP0
+            | EXP1 => {
                 let mut buf = [0; 2];
                 reader.read_exact(&mut buf)?;
                 (IpNumber(buf[0]), i64::from(buf[1]) * 8 + 6)
@@ -288,6 +290,9 @@ impl Ipv6Header {
     }
 
     /// Sets the field total_length based on the size of the payload and the options. Returns an error if the payload is too big to fit.
+    ///
+    /// Use [`Ipv6Extensions::set_payload_length`] to generate jumbograms
+    /// (payloads bigger than 65535 bytes).
     pub fn set_payload_length(&mut self, size: usize) -> Result<(), ValueTooBigError<usize>> {
         use crate::err::ValueType;
         // check that the total length fits into the field
@@ -549,7 +554,7 @@ mod test {
             frag in ipv6_fragment_any(),
             auth in ip_auth_any()
         ) {
-            const GENERICS: [IpNumber;7] = [
+            const GENERICS: [IpNumber;9] = [
                 IPV6_HOP_BY_HOP,
                 IPV6_DEST_OPTIONS,
                 IPV6_ROUTE,
@@ -557,6 +562,8 @@ mod test {
                 MOBILITY,
                 HIP,
                 SHIM6,
+                EXP0,
+                EXP1,
             ];
 
             // generic headers
@@ -649,7 +656,7 @@ mod test {
         for i in 0..0xffu8 {
             let expected = match IpNumber(i) {
                 IPV6_HOP_BY_HOP | IPV6_ROUTE | IPV6_FRAG | AUTH | IPV6_DEST_OPTIONS | MOBILITY
-                | HIP | SHIM6 => true,
+                | HIP | SHIM6 | EXP0 | EXP1 => true,
                 _ => false,
             };
             assert_eq!(
@@ -0,0 +1,179 @@
+use crate::{err::ipv6_exts::MobilityHeaderError, *};
+
+/// Slice containing an IPv6 mobility header with a "Binding
+/// Acknowledgement" message (MH type 6, RFC 6275).
+///
+/// ```text
+///  0               1               2               3
+/// +---------------+---------------+---------------+---------------+
+/// | Payload Proto |  Header Len   |    MH Type    |   Reserved    |
+/// +---------------+---------------+---------------+-+-------------+
+/// |           Checksum            |    Status     |K|  Reserved   |
+/// +-------------------------------+---------------+-+-------------+
+/// |        Sequence Number        |           Lifetime            |
+/// +-------------------------------+-------------------------------+
+/// |                       Mobility Options ...                    |
+/// +---------------------------------------------------------------+
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Ipv6MobilityBindingAckSlice<'a> {
+    /// Slice containing the complete header.
+    slice: &'a [u8],
+}
+
+impl<'a> Ipv6MobilityBindingAckSlice<'a> {
+    /// Minimum length of a binding acknowledgement header (without
+    /// options) in bytes/octets.
+    pub const MIN_LEN: usize = 12;
+
+    /// Creates a binding acknowledgement slice from a raw IPv6 extension
+    /// header slice and verifies the message type & header length.
+    pub fn from_raw(
+        raw: &Ipv6RawExtHeaderSlice<'a>,
+    ) -> Result<Ipv6MobilityBindingAckSlice<'a>, MobilityHeaderError> {
+        use MobilityHeaderError::*;
+
+        let slice = raw.slice();
+        let mh_type = Ipv6MobilityHeaderType(slice[2]);
+        if Ipv6MobilityHeaderType::BINDING_ACK != mh_type {
+            return Err(UnexpectedMhType {
+                expected: Ipv6MobilityHeaderType::BINDING_ACK,
+                actual: mh_type,
+            });
+        }
+        if slice.len() < Ipv6MobilityBindingAckSlice::MIN_LEN {
+            return Err(MessageTooShort {
+                mh_type,
+                hdr_ext_len: slice[1],
+            });
+        }
+        Ok(Ipv6MobilityBindingAckSlice { slice })
+    }
+
+    /// Returns the slice containing the complete header.
+    #[inline]
+    pub fn slice(&self) -> &'a [u8] {
+        self.slice
+    }
+
+    /// Returns the IP protocol number of the next header ("payload proto" field).
+    #[inline]
+    pub fn next_header(&self) -> IpNumber {
+        IpNumber(self.slice[0])
+    }
+
+    /// Returns the "checksum" field.
+    #[inline]
+    pub fn checksum(&self) -> u16 {
+        u16::from_be_bytes([self.slice[4], self.slice[5]])
+    }
+
+    /// Returns the "status" field (values below 128 indicate that the
+    /// binding update was accepted).
+    #[inline]
+    pub fn status(&self) -> u8 {
+        self.slice[6]
+    }
+
+    /// Returns true if the binding update was accepted (status below 128).
+    #[inline]
+    pub fn is_accepted(&self) -> bool {
+        self.status() < 128
+    }
+
+    /// Returns true if the "Key Management Mobility Capability" (K) flag is set.
+    #[inline]
+    pub fn key_management_mobility(&self) -> bool {
+        0 != self.slice[7] & 0x80
+    }
+
+    /// Returns the "sequence number" field.
+    #[inline]
+    pub fn sequence_number(&self) -> u16 {
+        u16::from_be_bytes([self.slice[8], self.slice[9]])
+    }
+
+    /// Returns the "lifetime" field (in units of 4 seconds).
+    #[inline]
+    pub fn lifetime(&self) -> u16 {
+        u16::from_be_bytes([self.slice[10], self.slice[11]])
+    }
+
+    /// Returns an iterator over the mobility options.
+    #[inline]
+    pub fn options(&self) -> Ipv6MobilityOptionsIterator<'a> {
+        Ipv6MobilityOptionsIterator::from_slice(&self.slice[Ipv6MobilityBindingAckSlice::MIN_LEN..])
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::err::ipv6_exts::MobilityHeaderError::*;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn from_raw_getters() {
+        #[rustfmt::skip]
+        let bytes = [
+            ip_number::IPV6_NO_NEXT_HEADER.0, 1, 6, 0,
+            0x12, 0x34, 1, 0x80,
+            0x56, 0x78, 0x9a, 0xbc,
+            ipv6_mobility_option::TYPE_PADN, 2, 0, 0,
+        ];
+        let raw = Ipv6RawExtHeaderSlice::from_slice(&bytes).unwrap();
+        let header = Ipv6MobilityBindingAckSlice::from_raw(&raw).unwrap();
+        assert_eq!(header.slice(), &bytes[..]);
+        assert_eq!(header.next_header(), ip_number::IPV6_NO_NEXT_HEADER);
+        assert_eq!(header.checksum(), 0x1234);
+        assert_eq!(header.status(), 1);
+        assert!(header.is_accepted());
+        assert!(header.key_management_mobility());
+        assert_eq!(header.sequence_number(), 0x5678);
+        assert_eq!(header.lifetime(), 0x9abc);
+        assert_eq!(
+            header.options().collect::<Vec<_>>(),
+            [Ok(Ipv6MobilityOption::PadN(2))]
+        );
+        assert_eq!(header.clone(), header);
+
+        // rejected & no key management flag
+        {
+            let mut bytes = bytes;
+            bytes[6] = 128;
+            bytes[7] = 0x7f;
+            let raw = Ipv6RawExtHeaderSlice::from_slice(&bytes).unwrap();
+            let header = Ipv6MobilityBindingAckSlice::from_raw(&raw).unwrap();
+            assert!(false == header.is_accepted());
+            assert!(false == header.key_management_mobility());
+        }
+    }
+
+    #[test]
+    fn from_raw_errors() {
+        // mh type
+        {
+            let bytes = [0, 1, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+            let raw = Ipv6RawExtHeaderSlice::from_slice(&bytes).unwrap();
+            assert_eq!(
+                Err(UnexpectedMhType {
+                    expected: Ipv6MobilityHeaderType::BINDING_ACK,
+                    actual: Ipv6MobilityHeaderType::BINDING_UPDATE
+                }),
+                Ipv6MobilityBindingAckSlice::from_raw(&raw)
+            );
+        }
+        // header length
+        {
+            let bytes = [0, 0, 6, 0, 0, 0, 0, 0];
+            let raw = Ipv6RawExtHeaderSlice::from_slice(&bytes).unwrap();
+            assert_eq!(
+                Err(MessageTooShort {
+                    mh_type: Ipv6MobilityHeaderType::BINDING_ACK,
+                    hdr_ext_len: 0
+                }),
+                Ipv6MobilityBindingAckSlice::from_raw(&raw)
+            );
+        }
+    }
+}
@@ -0,0 +1,216 @@
+use crate::{err::ipv6_exts::MobilityHeaderError, *};
+
+/// Slice containing an IPv6 mobility header with a "Binding Update"
+/// message (MH type 5, RFC 6275).
+///
+/// ```text
+///  0               1               2               3
+/// +---------------+---------------+---------------+---------------+
+/// | Payload Proto |  Header Len   |    MH Type    |   Reserved    |
+/// +---------------+---------------+---------------+---------------+
+/// |           Checksum            |        Sequence Number        |
+/// +-+-+-+-+-------+---------------+---------------+---------------+
+/// |A|H|L|K|       Reserved        |           Lifetime            |
+/// +-+-+-+-+-------+---------------+---------------+---------------+
+/// |                       Mobility Options ...                    |
+/// +---------------------------------------------------------------+
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Ipv6MobilityBindingUpdateSlice<'a> {
+    /// Slice containing the complete header.
+    slice: &'a [u8],
+}
+
+impl<'a> Ipv6MobilityBindingUpdateSlice<'a> {
+    /// Minimum length of a binding update header (without options) in bytes/octets.
+    pub const MIN_LEN: usize = 12;
+
+    /// Bit mask of the "Acknowledge" (A) flag in [`Self::flags`].
+    pub const FLAG_ACKNOWLEDGE: u16 = 0x8000;
+
+    /// Bit mask of the "Home Registration" (H) flag in [`Self::flags`].
+    pub const FLAG_HOME_REGISTRATION: u16 = 0x4000;
+
+    /// Bit mask of the "Link-Local Address Compatibility" (L) flag in [`Self::flags`].
+    pub const FLAG_LINK_LOCAL_COMPATIBILITY: u16 = 0x2000;
+
+    /// Bit mask of the "Key Management Mobility Capability" (K) flag in [`Self::flags`].
+    pub const FLAG_KEY_MANAGEMENT_MOBILITY: u16 = 0x1000;
+
+    /// Creates a binding update slice from a raw IPv6 extension header
+    /// slice and verifies the message type & header length.
+    pub fn from_raw(
+        raw: &Ipv6RawExtHeaderSlice<'a>,
+    ) -> Result<Ipv6MobilityBindingUpdateSlice<'a>, MobilityHeaderError> {
+        use MobilityHeaderError::*;
+
+        let slice = raw.slice();
+        let mh_type = Ipv6MobilityHeaderType(slice[2]);
+        if Ipv6MobilityHeaderType::BINDING_UPDATE != mh_type {
+            return Err(UnexpectedMhType {
+                expected: Ipv6MobilityHeaderType::BINDING_UPDATE,
+                actual: mh_type,
+            });
+        }
+        if slice.len() < Ipv6MobilityBindingUpdateSlice::MIN_LEN {
+            return Err(MessageTooShort {
+                mh_type,
+                hdr_ext_len: slice[1],
+            });
+        }
+        Ok(Ipv6MobilityBindingUpdateSlice { slice })
+    }
+
+    /// Returns the slice containing the complete header.
+    #[inline]
+    pub fn slice(&self) -> &'a [u8] {
+        self.slice
+    }
+
+    /// Returns the IP protocol number of the next header ("payload proto" field).
+    #[inline]
+    pub fn next_header(&self) -> IpNumber {
+        IpNumber(self.slice[0])
+    }
+
+    /// Returns the "checksum" field.
+    #[inline]
+    pub fn checksum(&self) -> u16 {
+        u16::from_be_bytes([self.slice[4], self.slice[5]])
+    }
+
+    /// Returns the "sequence number" field.
+    #[inline]
+    pub fn sequence_number(&self) -> u16 {
+        u16::from_be_bytes([self.slice[6], self.slice[7]])
+    }
+
+    /// Returns the flags & reserved bits (see the `FLAG_*` constants).
+    #[inline]
+    pub fn flags(&self) -> u16 {
+        u16::from_be_bytes([self.slice[8], self.slice[9]])
+    }
+
+    /// Returns true if the "Acknowledge" (A) flag is set.
+    #[inline]
+    pub fn acknowledge(&self) -> bool {
+        0 != self.flags() & Ipv6MobilityBindingUpdateSlice::FLAG_ACKNOWLEDGE
+    }
+
+    /// Returns true if the "Home Registration" (H) flag is set.
+    #[inline]
+    pub fn home_registration(&self) -> bool {
+        0 != self.flags() & Ipv6MobilityBindingUpdateSlice::FLAG_HOME_REGISTRATION
+    }
+
+    /// Returns true if the "Link-Local Address Compatibility" (L) flag is set.
+    #[inline]
+    pub fn link_local_compatibility(&self) -> bool {
+        0 != self.flags() & Ipv6MobilityBindingUpdateSlice::FLAG_LINK_LOCAL_COMPATIBILITY
+    }
+
+    /// Returns true if the "Key Management Mobility Capability" (K) flag is set.
+    #[inline]
+    pub fn key_management_mobility(&self) -> bool {
+        0 != self.flags() & Ipv6MobilityBindingUpdateSlice::FLAG_KEY_MANAGEMENT_MOBILITY
+    }
+
+    /// Returns the "lifetime" field (in units of 4 seconds).
+    #[inline]
+    pub fn lifetime(&self) -> u16 {
+        u16::from_be_bytes([self.slice[10], self.slice[11]])
+    }
+
+    /// Returns an iterator over the mobility options.
+    #[inline]
+    pub fn options(&self) -> Ipv6MobilityOptionsIterator<'a> {
+        Ipv6MobilityOptionsIterator::from_slice(
+            &self.slice[Ipv6MobilityBindingUpdateSlice::MIN_LEN..],
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::err::ipv6_exts::MobilityHeaderError::*;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn from_raw_getters() {
+        #[rustfmt::skip]
+        let bytes = [
+            ip_number::IPV6_NO_NEXT_HEADER.0, 1, 5, 0,
+            0x12, 0x34, 0x56, 0x78,
+            0xf0, 0, 0x9a, 0xbc,
+            ipv6_mobility_option::TYPE_BINDING_REFRESH_ADVICE, 2, 0, 1,
+        ];
+        let raw = Ipv6RawExtHeaderSlice::from_slice(&bytes).unwrap();
+        let header = Ipv6MobilityBindingUpdateSlice::from_raw(&raw).unwrap();
+        assert_eq!(header.slice(), &bytes[..]);
+        assert_eq!(header.next_header(), ip_number::IPV6_NO_NEXT_HEADER);
+        assert_eq!(header.checksum(), 0x1234);
+        assert_eq!(header.sequence_number(), 0x5678);
+        assert_eq!(header.flags(), 0xf000);
+        assert!(header.acknowledge());
+        assert!(header.home_registration());
+        assert!(header.link_local_compatibility());
+        assert!(header.key_management_mobility());
+        assert_eq!(header.lifetime(), 0x9abc);
+        assert_eq!(
+            header.options().collect::<Vec<_>>(),
+            [Ok(Ipv6MobilityOption::BindingRefreshAdvice(1))]
+        );
+        assert_eq!(header.clone(), header);
+
+        // single flags
+        for (flag, checks) in [
+            (0x80, [true, false, false, false]),
+            (0x40, [false, true, false, false]),
+            (0x20, [false, false, true, false]),
+            (0x10, [false, false, false, true]),
+        ] {
+            let mut bytes = bytes;
+            bytes[8] = flag;
+            let raw = Ipv6RawExtHeaderSlice::from_slice(&bytes).unwrap();
+            let header = Ipv6MobilityBindingUpdateSlice::from_raw(&raw).unwrap();
+            assert_eq!(
+                checks,
+                [
+                    header.acknowledge(),
+                    header.home_registration(),
+                    header.link_local_compatibility(),
+                    header.key_management_mobility()
+                ]
+            );
+        }
+    }
+
+    #[test]
+    fn from_raw_errors() {
+        // mh type
+        {
+            let bytes = [0, 1, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+            let raw = Ipv6RawExtHeaderSlice::from_slice(&bytes).unwrap();
+            assert_eq!(
+                Err(UnexpectedMhType {
+                    expected: Ipv6MobilityHeaderType::BINDING_UPDATE,
+                    actual: Ipv6MobilityHeaderType::BINDING_ACK
+                }),
+                Ipv6MobilityBindingUpdateSlice::from_raw(&raw)
+            );
+        }
+        // header length
+        {
+            let bytes = [0, 0, 5, 0, 0, 0, 0, 0];
+            let raw = Ipv6RawExtHeaderSlice::from_slice(&bytes).unwrap();
+            assert_eq!(
+                Err(MessageTooShort {
+                    mh_type: Ipv6MobilityHeaderType::BINDING_UPDATE,
+                    hdr_ext_len: 0
+                }),
+                Ipv6MobilityBindingUpdateSlice::from_raw(&raw)
+            );
+        }
+    }
+}
@@ -0,0 +1,248 @@
+use crate::{err::ipv6_exts::MobilityHeaderError, *};
+
+/// Slice containing an IPv6 mobility header (RFC 6275) decoded based on
+/// its message type ("MH Type" field).
+///
+/// # Example
+///
+/// ```
+/// use etherparse::{ip_number, Ipv6RawExtHeaderSlice, Ipv6MobilityHeaderSlice};
+///
+/// let bytes = [
+///     ip_number::IPV6_NO_NEXT_HEADER.0, 1, 5, 0, // payload proto, header len, mh type, reserved
+///     0, 0, 0, 1, // checksum, sequence number
+///     0x80, 0, 0, 10, // flags (A), lifetime
+///     1, 2, 0, 0, // PadN mobility option
+/// ];
+///
+/// let raw = Ipv6RawExtHeaderSlice::from_slice(&bytes).unwrap();
+/// match Ipv6MobilityHeaderSlice::from_raw(&raw).unwrap() {
+///     Ipv6MobilityHeaderSlice::BindingUpdate(bu) => {
+///         assert_eq!(bu.sequence_number(), 1);
+///         assert!(bu.acknowledge());
+///         assert_eq!(bu.lifetime(), 10);
+///     },
+///     _ => panic!("expected a binding update"),
+/// }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Ipv6MobilityHeaderSlice<'a> {
+    /// Binding update message.
+    BindingUpdate(Ipv6MobilityBindingUpdateSlice<'a>),
+    /// Binding acknowledgement message.
+    BindingAck(Ipv6MobilityBindingAckSlice<'a>),
+    /// Mobility header with a message type not explicitly supported by etherparse.
+    Other(Ipv6RawExtHeaderSlice<'a>),
+}
+
+impl<'a> Ipv6MobilityHeaderSlice<'a> {
+    /// Decodes a mobility header from a raw IPv6 extension header slice
+    /// based on the message type.
+    pub fn from_raw(
+        raw: &Ipv6RawExtHeaderSlice<'a>,
+    ) -> Result<Ipv6MobilityHeaderSlice<'a>, MobilityHeaderError> {
+        use Ipv6MobilityHeaderSlice::*;
+        Ok(match Ipv6MobilityHeaderType(raw.slice()[2]) {
+            Ipv6MobilityHeaderType::BINDING_UPDATE => {
+                BindingUpdate(Ipv6MobilityBindingUpdateSlice::from_raw(raw)?)
+            }
+            Ipv6MobilityHeaderType::BINDING_ACK => {
+                BindingAck(Ipv6MobilityBindingAckSlice::from_raw(raw)?)
+            }
+            _ => Other(raw.clone()),
+        })
+    }
+
+    /// Returns the slice containing the complete header.
+    pub fn slice(&self) -> &'a [u8] {
+        use Ipv6MobilityHeaderSlice::*;
+        match self {
+            BindingUpdate(s) => s.slice(),
+            BindingAck(s) => s.slice(),
+            Other(s) => s.slice(),
+        }
+    }
+
+    /// Returns the IP protocol number of the next header ("payload proto" field).
+    ///
+    /// Note that RFC 6275 requires this field to be set to
+    /// [`ip_number::IPV6_NO_NEXT_HEADER`].
+    #[inline]
+    pub fn next_header(&self) -> IpNumber {
+        IpNumber(self.slice()[0])
+    }
+
+    /// Returns the message type ("MH Type" field).
+    #[inline]
+    pub fn mh_type(&self) -> Ipv6MobilityHeaderType {
+        Ipv6MobilityHeaderType(self.slice()[2])
+    }
+
+    /// Returns the "checksum" field.
+    #[inline]
+    pub fn checksum(&self) -> u16 {
+        let slice = self.slice();
+        u16::from_be_bytes([slice[4], slice[5]])
+    }
+
+    /// Returns the message data (everything after the "checksum" field).
+    #[inline]
+    pub fn message_data(&self) -> &'a [u8] {
+        &self.slice()[6..]
+    }
+
+    /// Calculates the checksum of the header based on the given source
+    /// & destination addresses of the IPv6 pseudo header (the value of
+    /// the "checksum" field is ignored).
+    pub fn calc_checksum(&self, source: [u8; 16], destination: [u8; 16]) -> u16 {
+        let slice = self.slice();
+        checksum::Sum16BitWords::new()
+            .add_16bytes(source)
+            .add_16bytes(destination)
+            .add_4bytes((slice.len() as u32).to_be_bytes())
+            .add_2bytes([0, ip_number::MOBILITY.0])
+            .add_slice(&slice[..4])
+            .add_slice(&slice[6..])
+            .ones_complement()
+            .to_be()
+    }
+
+    /// Returns true if the checksum in the header is correct.
+    pub fn is_checksum_valid(&self, source: [u8; 16], destination: [u8; 16]) -> bool {
+        checksum::Sum16BitWords::new()
+            .add_16bytes(source)
+            .add_16bytes(destination)
+            .add_4bytes((self.slice().len() as u32).to_be_bytes())
+            .add_2bytes([0, ip_number::MOBILITY.0])
+            .add_slice(self.slice())
+            .ones_complement()
+            == 0
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::err::ipv6_exts::MobilityHeaderError::*;
+
+    #[test]
+    fn from_raw() {
+        use Ipv6MobilityHeaderSlice::*;
+
+        // binding update
+        {
+            let mut bytes = [0u8; 16];
+            bytes[1] = 1;
+            bytes[2] = 5;
+            let raw = Ipv6RawExtHeaderSlice::from_slice(&bytes).unwrap();
+            assert_eq!(
+                Ok(BindingUpdate(
+                    Ipv6MobilityBindingUpdateSlice::from_raw(&raw).unwrap()
+                )),
+                Ipv6MobilityHeaderSlice::from_raw(&raw)
+            );
+        }
+        // binding ack
+        {
+            let mut bytes = [0u8; 16];
+            bytes[1] = 1;
+            bytes[2] = 6;
+            let raw = Ipv6RawExtHeaderSlice::from_slice(&bytes).unwrap();
+            assert_eq!(
+                Ok(BindingAck(
+                    Ipv6MobilityBindingAckSlice::from_raw(&raw).unwrap()
+                )),
+                Ipv6MobilityHeaderSlice::from_raw(&raw)
+            );
+        }
+        // other
+        {
+            let bytes = [0, 0, 0, 0, 0, 0, 0, 0];
+            let raw = Ipv6RawExtHeaderSlice::from_slice(&bytes).unwrap();
+            assert_eq!(
+                Ok(Other(raw.clone())),
+                Ipv6MobilityHeaderSlice::from_raw(&raw)
+            );
+        }
+        // error
+        {
+            let bytes = [0, 0, 6, 0, 0, 0, 0, 0];
+            let raw = Ipv6RawExtHeaderSlice::from_slice(&bytes).unwrap();
+            assert_eq!(
+                Err(MessageTooShort {
+                    mh_type: Ipv6MobilityHeaderType::BINDING_ACK,
+                    hdr_ext_len: 0
+                }),
+                Ipv6MobilityHeaderSlice::from_raw(&raw)
+            );
+        }
+    }
+
+    #[test]
+    fn getters() {
+        for mh_type in [0, 5, 6] {
+            #[rustfmt::skip]
+            let bytes = [
+                ip_number::IPV6_NO_NEXT_HEADER.0, 1, mh_type, 0,
+                0x12, 0x34, 1, 2,
+                3, 4, 5, 6, 7, 8, 9, 10,
+            ];
+            let raw = Ipv6RawExtHeaderSlice::from_slice(&bytes).unwrap();
+            let header = Ipv6MobilityHeaderSlice::from_raw(&raw).unwrap();
+            assert_eq!(header.slice(), &bytes[..]);
+            assert_eq!(header.next_header(), ip_number::IPV6_NO_NEXT_HEADER);
+            assert_eq!(header.mh_type(), Ipv6MobilityHeaderType(mh_type));
+            assert_eq!(header.checksum(), 0x1234);
+            assert_eq!(header.message_data(), &bytes[6..]);
+            assert_eq!(header.clone(), header);
+        }
+    }
+
+    #[test]
+    fn calc_checksum_is_checksum_valid() {
+        let source = [1; 16];
+        let destination = [2; 16];
+
+        #[rustfmt::skip]
+        let mut bytes = [
+            ip_number::IPV6_NO_NEXT_HEADER.0, 1, 5, 0,
+            0xff, 0xff, 0, 1,
+            0x80, 0, 0, 10,
+            1, 2, 0, 0,
+        ];
+
+        // calculate the expected value manually
+        let expected = {
+            let mut sum: u32 = 0;
+            for chunk in source.chunks(2).chain(destination.chunks(2)) {
+                sum += u32::from(u16::from_be_bytes([chunk[0], chunk[1]]));
+            }
+            sum += bytes.len() as u32;
+            sum += u32::from(ip_number::MOBILITY.0);
+            for (i, chunk) in bytes.chunks(2).enumerate() {
+                if i != 2 {
+                    sum += u32::from(u16::from_be_bytes([chunk[0], chunk[1]]));
+                }
+            }
+            while sum > 0xffff {
+                sum = (sum & 0xffff) + (sum >> 16);
+            }
+            !(sum as u16)
+        };
+
+        let checksum = {
+            let raw = Ipv6RawExtHeaderSlice::from_slice(&bytes).unwrap();
+            let header = Ipv6MobilityHeaderSlice::from_raw(&raw).unwrap();
+            assert_eq!(expected, header.calc_checksum(source, destination));
+            assert!(false == header.is_checksum_valid(source, destination));
+            header.calc_checksum(source, destination)
+        };
+
+        bytes[4..6].copy_from_slice(&checksum.to_be_bytes());
+        let raw = Ipv6RawExtHeaderSlice::from_slice(&bytes).unwrap();
+        let header = Ipv6MobilityHeaderSlice::from_raw(&raw).unwrap();
+        assert_eq!(checksum, header.calc_checksum(source, destination));
+        assert!(header.is_checksum_valid(source, destination));
+        assert!(false == header.is_checksum_valid(destination, destination));
+    }
+}
@@ -0,0 +1,131 @@
+/// Type of the message contained in an IPv6 mobility header ("MH Type"
+/// field, values as registered at
+/// <https://www.iana.org/assignments/mobility-parameters/mobility-parameters.xhtml#mobility-parameters-1>).
+///
+/// You can access the underlying `u8` value by using `.0` and any `u8`
+/// can be converted to an `Ipv6MobilityHeaderType`:
+///
+/// ```
+/// use etherparse::Ipv6MobilityHeaderType;
+///
+/// assert_eq!(Ipv6MobilityHeaderType::BINDING_UPDATE.0, 5);
+/// assert_eq!(Ipv6MobilityHeaderType::BINDING_UPDATE, Ipv6MobilityHeaderType(5));
+///
+/// // convert to Ipv6MobilityHeaderType using the from & into trait
+/// let mh_type: Ipv6MobilityHeaderType = 6u8.into();
+/// assert_eq!(Ipv6MobilityHeaderType::BINDING_ACK, mh_type);
+///
+/// // convert to u8 using the from & into trait
+/// let num: u8 = Ipv6MobilityHeaderType::BINDING_ERROR.into();
+/// assert_eq!(7, num);
+/// ```
+#[derive(Default, PartialEq, Eq, Clone, Copy, Hash, Ord, PartialOrd)]
+pub struct Ipv6MobilityHeaderType(pub u8);
+
+impl Ipv6MobilityHeaderType {
+    /// Binding Refresh Request (RFC 6275).
+    pub const BINDING_REFRESH_REQUEST: Ipv6MobilityHeaderType = Self(0);
+    /// Home Test Init (RFC 6275).
+    pub const HOME_TEST_INIT: Ipv6MobilityHeaderType = Self(1);
+    /// Care-of Test Init (RFC 6275).
+    pub const CARE_OF_TEST_INIT: Ipv6MobilityHeaderType = Self(2);
+    /// Home Test (RFC 6275).
+    pub const HOME_TEST: Ipv6MobilityHeaderType = Self(3);
+    /// Care-of Test (RFC 6275).
+    pub const CARE_OF_TEST: Ipv6MobilityHeaderType = Self(4);
+    /// Binding Update (RFC 6275).
+    pub const BINDING_UPDATE: Ipv6MobilityHeaderType = Self(5);
+    /// Binding Acknowledgement (RFC 6275).
+    pub const BINDING_ACK: Ipv6MobilityHeaderType = Self(6);
+    /// Binding Error (RFC 6275).
+    pub const BINDING_ERROR: Ipv6MobilityHeaderType = Self(7);
+}
+
+impl From<u8> for Ipv6MobilityHeaderType {
+    #[inline]
+    fn from(val: u8) -> Self {
+        Ipv6MobilityHeaderType(val)
+    }
+}
+
+impl From<Ipv6MobilityHeaderType> for u8 {
+    #[inline]
+    fn from(val: Ipv6MobilityHeaderType) -> Self {
+        val.0
+    }
+}
+
+impl core::fmt::Debug for Ipv6MobilityHeaderType {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match *self {
+            Self::BINDING_REFRESH_REQUEST => write!(f, "{} (Binding Refresh Request)", self.0),
+            Self::HOME_TEST_INIT => write!(f, "{} (Home Test Init)", self.0),
+            Self::CARE_OF_TEST_INIT => write!(f, "{} (Care-of Test Init)", self.0),
+            Self::HOME_TEST => write!(f, "{} (Home Test)", self.0),
+            Self::CARE_OF_TEST => write!(f, "{} (Care-of Test)", self.0),
+            Self::BINDING_UPDATE => write!(f, "{} (Binding Update)", self.0),
+            Self::BINDING_ACK => write!(f, "{} (Binding Acknowledgement)", self.0),
+            Self::BINDING_ERROR => write!(f, "{} (Binding Error)", self.0),
+            _ => write!(f, "{}", self.0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::format;
+
+    #[test]
+    fn to_u8() {
+        assert_eq!(0, u8::from(Ipv6MobilityHeaderType::BINDING_REFRESH_REQUEST));
+        assert_eq!(7, u8::from(Ipv6MobilityHeaderType::BINDING_ERROR));
+    }
+
+    #[test]
+    fn from_u8() {
+        assert_eq!(
+            Ipv6MobilityHeaderType::from(5u8),
+            Ipv6MobilityHeaderType::BINDING_UPDATE
+        );
+        assert_eq!(
+            Ipv6MobilityHeaderType::from(0xffu8),
+            Ipv6MobilityHeaderType(0xff)
+        );
+    }
+
+    #[test]
+    fn dbg() {
+        let pairs = &[
+            (
+                Ipv6MobilityHeaderType::BINDING_REFRESH_REQUEST,
+                "0 (Binding Refresh Request)",
+            ),
+            (Ipv6MobilityHeaderType::HOME_TEST_INIT, "1 (Home Test Init)"),
+            (
+                Ipv6MobilityHeaderType::CARE_OF_TEST_INIT,
+                "2 (Care-of Test Init)",
+            ),
+            (Ipv6MobilityHeaderType::HOME_TEST, "3 (Home Test)"),
+            (Ipv6MobilityHeaderType::CARE_OF_TEST, "4 (Care-of Test)"),
+            (Ipv6MobilityHeaderType::BINDING_UPDATE, "5 (Binding Update)"),
+            (
+                Ipv6MobilityHeaderType::BINDING_ACK,
+                "6 (Binding Acknowledgement)",
+            ),
+            (Ipv6MobilityHeaderType::BINDING_ERROR, "7 (Binding Error)"),
+            (Ipv6MobilityHeaderType(0xff), "255"),
+        ];
+
+        for (input, expected) in pairs {
+            assert_eq!(expected, &format!("{:?}", input));
+        }
+    }
+
+    #[test]
+    fn clone_eq_default() {
+        let value = Ipv6MobilityHeaderType::BINDING_UPDATE;
+        assert_eq!(value, value.clone());
+        assert_eq!(Ipv6MobilityHeaderType(0), Ipv6MobilityHeaderType::default());
+    }
+}
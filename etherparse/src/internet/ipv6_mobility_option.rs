@@ -0,0 +1,142 @@
+/// Options that can be present in the message data of an IPv6
+/// mobility header (RFC 6275).
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum Ipv6MobilityOption<'a> {
+    /// "Pad1" option (type 0) representing a single byte of padding.
+    Pad1,
+
+    /// "PadN" option (type 1) representing two or more bytes of padding.
+    ///
+    /// The contained value is the data length (the number of padding
+    /// bytes after the type & length fields).
+    PadN(u8),
+
+    /// "Binding Refresh Advice" option (type 2) containing the interval
+    /// (in units of 4 seconds) after which the mobile node should send a
+    /// new home registration.
+    BindingRefreshAdvice(u16),
+
+    /// "Alternate Care-of Address" option (type 3).
+    AlternateCareOfAddress([u8; 16]),
+
+    /// "Nonce Indices" option (type 4).
+    NonceIndices {
+        /// Index of the home nonce used to create the authorization data.
+        home_nonce_index: u16,
+        /// Index of the care-of nonce used to create the authorization data.
+        care_of_nonce_index: u16,
+    },
+
+    /// "Binding Authorization Data" option (type 5) containing the
+    /// authenticator.
+    BindingAuthorizationData(&'a [u8]),
+
+    /// Option with a type not explicitly supported by etherparse.
+    Unknown {
+        /// Type of the option (first byte of the option).
+        option_type: u8,
+        /// Data of the option after the type & length bytes.
+        data: &'a [u8],
+    },
+}
+
+impl<'a> Ipv6MobilityOption<'a> {
+    /// Returns the type number of the option (first byte of the option).
+    pub fn option_type(&self) -> u8 {
+        use crate::ipv6_mobility_option::*;
+        use Ipv6MobilityOption::*;
+        match self {
+            Pad1 => TYPE_PAD1,
+            PadN(_) => TYPE_PADN,
+            BindingRefreshAdvice(_) => TYPE_BINDING_REFRESH_ADVICE,
+            AlternateCareOfAddress(_) => TYPE_ALTERNATE_CARE_OF_ADDRESS,
+            NonceIndices { .. } => TYPE_NONCE_INDICES,
+            BindingAuthorizationData(_) => TYPE_BINDING_AUTHORIZATION_DATA,
+            Unknown { option_type, .. } => *option_type,
+        }
+    }
+
+    /// Returns the length of the serialized option in bytes (including
+    /// the type & length bytes).
+    pub fn header_len(&self) -> usize {
+        use crate::ipv6_mobility_option::*;
+        use Ipv6MobilityOption::*;
+        match self {
+            Pad1 => 1,
+            PadN(len) => 2 + usize::from(*len),
+            BindingRefreshAdvice(_) => 2 + usize::from(LEN_BINDING_REFRESH_ADVICE),
+            AlternateCareOfAddress(_) => 2 + usize::from(LEN_ALTERNATE_CARE_OF_ADDRESS),
+            NonceIndices { .. } => 2 + usize::from(LEN_NONCE_INDICES),
+            BindingAuthorizationData(data) => 2 + data.len(),
+            Unknown { data, .. } => 2 + data.len(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+    use alloc::format;
+
+    #[test]
+    fn clone_eq_debug() {
+        use Ipv6MobilityOption::*;
+        let value = NonceIndices {
+            home_nonce_index: 1,
+            care_of_nonce_index: 2,
+        };
+        assert_eq!(value, value.clone());
+        assert_eq!("PadN(2)", format!("{:?}", PadN(2)));
+        assert_eq!(
+            "Unknown { option_type: 9, data: [1] }",
+            format!(
+                "{:?}",
+                Unknown {
+                    option_type: 9,
+                    data: &[1]
+                }
+            )
+        );
+    }
+
+    #[test]
+    fn option_type_header_len() {
+        use ipv6_mobility_option::*;
+        use Ipv6MobilityOption::*;
+        let tests = [
+            (Pad1, TYPE_PAD1, 1),
+            (PadN(3), TYPE_PADN, 5),
+            (BindingRefreshAdvice(1), TYPE_BINDING_REFRESH_ADVICE, 4),
+            (
+                AlternateCareOfAddress([1; 16]),
+                TYPE_ALTERNATE_CARE_OF_ADDRESS,
+                18,
+            ),
+            (
+                NonceIndices {
+                    home_nonce_index: 1,
+                    care_of_nonce_index: 2,
+                },
+                TYPE_NONCE_INDICES,
+                6,
+            ),
+            (
+                BindingAuthorizationData(&[0; 12]),
+                TYPE_BINDING_AUTHORIZATION_DATA,
+                14,
+            ),
+            (
+                Unknown {
+                    option_type: 9,
+                    data: &[1, 2],
+                },
+                9,
+                4,
+            ),
+        ];
+        for (option, option_type, header_len) in tests {
+            assert_eq!(option_type, option.option_type());
+            assert_eq!(header_len, option.header_len());
+        }
+    }
+}
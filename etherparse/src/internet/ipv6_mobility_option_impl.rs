@@ -0,0 +1,22 @@
+/// Module containing the constants for the options that can be present
+/// in an IPv6 mobility header (RFC 6275).
+pub mod ipv6_mobility_option {
+    /// `u8` identifying a "Pad1" mobility option (single byte of padding).
+    pub const TYPE_PAD1: u8 = 0;
+    /// `u8` identifying a "PadN" mobility option (two or more bytes of padding).
+    pub const TYPE_PADN: u8 = 1;
+    /// `u8` identifying a "Binding Refresh Advice" mobility option.
+    pub const TYPE_BINDING_REFRESH_ADVICE: u8 = 2;
+    /// `u8` identifying an "Alternate Care-of Address" mobility option.
+    pub const TYPE_ALTERNATE_CARE_OF_ADDRESS: u8 = 3;
+    /// `u8` identifying a "Nonce Indices" mobility option.
+    pub const TYPE_NONCE_INDICES: u8 = 4;
+    /// `u8` identifying a "Binding Authorization Data" mobility option.
+    pub const TYPE_BINDING_AUTHORIZATION_DATA: u8 = 5;
+    /// Data length in octets/bytes of the "Binding Refresh Advice" option.
+    pub const LEN_BINDING_REFRESH_ADVICE: u8 = 2;
+    /// Data length in octets/bytes of the "Alternate Care-of Address" option.
+    pub const LEN_ALTERNATE_CARE_OF_ADDRESS: u8 = 16;
+    /// Data length in octets/bytes of the "Nonce Indices" option.
+    pub const LEN_NONCE_INDICES: u8 = 4;
+}
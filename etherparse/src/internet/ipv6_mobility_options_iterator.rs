@@ -0,0 +1,269 @@
+use crate::*;
+
+/// Allows iterating over the options in the message data of an IPv6
+/// mobility header (e.g. see [`Ipv6MobilityBindingUpdateSlice::options`]).
+///
+/// In case an error is encountered the error is returned and the
+/// iteration is stopped. As the mobility options share the encoding of
+/// IPv6 options the errors are reported as
+/// [`err::ipv6_exts::OptionReadError`].
+#[derive(Clone, Eq, PartialEq)]
+pub struct Ipv6MobilityOptionsIterator<'a> {
+    pub(crate) options: &'a [u8],
+}
+
+impl<'a> Ipv6MobilityOptionsIterator<'a> {
+    /// Creates an iterator from a slice containing encoded mobility options.
+    pub fn from_slice(options: &'a [u8]) -> Ipv6MobilityOptionsIterator<'a> {
+        Ipv6MobilityOptionsIterator { options }
+    }
+
+    /// Returns the non processed part of the options slice.
+    pub fn rest(&self) -> &'a [u8] {
+        self.options
+    }
+}
+
+impl<'a> Iterator for Ipv6MobilityOptionsIterator<'a> {
+    type Item = Result<Ipv6MobilityOption<'a>, err::ipv6_exts::OptionReadError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        use err::ipv6_exts::OptionReadError::*;
+        use ipv6_mobility_option::*;
+        use Ipv6MobilityOption::*;
+
+        let options = self.options;
+        let result = match options.first() {
+            None => return None,
+            Some(&TYPE_PAD1) => Ok((Pad1, 1)),
+            Some(&option_type) => {
+                let expected_data_len = match option_type {
+                    TYPE_BINDING_REFRESH_ADVICE => Some(LEN_BINDING_REFRESH_ADVICE),
+                    TYPE_ALTERNATE_CARE_OF_ADDRESS => Some(LEN_ALTERNATE_CARE_OF_ADDRESS),
+                    TYPE_NONCE_INDICES => Some(LEN_NONCE_INDICES),
+                    _ => None,
+                };
+                if options.len() < 2 {
+                    Err(UnexpectedEndOfSlice {
+                        option_type,
+                        expected_len: 2 + usize::from(expected_data_len.unwrap_or(0)),
+                        actual_len: options.len(),
+                    })
+                } else {
+                    let data_len = options[1];
+                    let len = 2 + usize::from(data_len);
+                    if expected_data_len.is_some() && Some(data_len) != expected_data_len {
+                        Err(UnexpectedSize {
+                            option_type,
+                            size: data_len,
+                        })
+                    } else if options.len() < len {
+                        Err(UnexpectedEndOfSlice {
+                            option_type,
+                            expected_len: len,
+                            actual_len: options.len(),
+                        })
+                    } else {
+                        let data = &options[2..len];
+                        let option = match option_type {
+                            TYPE_PADN => PadN(data_len),
+                            TYPE_BINDING_REFRESH_ADVICE => {
+                                BindingRefreshAdvice(u16::from_be_bytes([data[0], data[1]]))
+                            }
+                            TYPE_ALTERNATE_CARE_OF_ADDRESS => {
+                                let mut address = [0u8; 16];
+                                address.copy_from_slice(data);
+                                AlternateCareOfAddress(address)
+                            }
+                            TYPE_NONCE_INDICES => NonceIndices {
+                                home_nonce_index: u16::from_be_bytes([data[0], data[1]]),
+                                care_of_nonce_index: u16::from_be_bytes([data[2], data[3]]),
+                            },
+                            TYPE_BINDING_AUTHORIZATION_DATA => BindingAuthorizationData(data),
+                            _ => Unknown { option_type, data },
+                        };
+                        Ok((option, len))
+                    }
+                }
+            }
+        };
+
+        match result {
+            Ok((option, len)) => {
+                self.options = &options[len..];
+                Some(Ok(option))
+            }
+            Err(err) => {
+                // stop the iteration after an error
+                self.options = &options[options.len()..];
+                Some(Err(err))
+            }
+        }
+    }
+}
+
+impl<'a> core::fmt::Debug for Ipv6MobilityOptionsIterator<'a> {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
+        let mut list = fmt.debug_list();
+
+        // create a copy and iterate over all elements
+        for it in self.clone() {
+            match it {
+                Ok(e) => {
+                    list.entry(&e);
+                }
+                Err(e) => {
+                    list.entry(&Result::<(), err::ipv6_exts::OptionReadError>::Err(e));
+                }
+            }
+        }
+
+        list.finish()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::err::ipv6_exts::OptionReadError::*;
+    use alloc::{format, vec::Vec};
+    use ipv6_mobility_option::*;
+    use Ipv6MobilityOption::*;
+
+    #[test]
+    fn from_slice_rest() {
+        let data = [1, 2, 3, 4];
+        let iter = Ipv6MobilityOptionsIterator::from_slice(&data);
+        assert_eq!(&data[..], iter.rest());
+    }
+
+    #[test]
+    fn next() {
+        // empty
+        assert_eq!(None, Ipv6MobilityOptionsIterator::from_slice(&[]).next());
+
+        // all supported options
+        {
+            #[rustfmt::skip]
+            let data = [
+                TYPE_PAD1,
+                TYPE_PADN, 1, 0,
+                TYPE_BINDING_REFRESH_ADVICE, 2, 0x12, 0x34,
+                TYPE_ALTERNATE_CARE_OF_ADDRESS, 16,
+                1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16,
+                TYPE_NONCE_INDICES, 4, 0, 1, 0, 2,
+                TYPE_BINDING_AUTHORIZATION_DATA, 3, 7, 8, 9,
+                9, 1, 10,
+            ];
+            let mut iter = Ipv6MobilityOptionsIterator::from_slice(&data);
+            let actual: Vec<_> = iter.by_ref().collect();
+            assert_eq!(
+                actual,
+                [
+                    Ok(Pad1),
+                    Ok(PadN(1)),
+                    Ok(BindingRefreshAdvice(0x1234)),
+                    Ok(AlternateCareOfAddress([
+                        1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16
+                    ])),
+                    Ok(NonceIndices {
+                        home_nonce_index: 1,
+                        care_of_nonce_index: 2
+                    }),
+                    Ok(BindingAuthorizationData(&[7, 8, 9])),
+                    Ok(Unknown {
+                        option_type: 9,
+                        data: &[10]
+                    }),
+                ]
+            );
+            assert!(iter.rest().is_empty());
+        }
+
+        // length field missing
+        for (option_type, expected_len) in [
+            (TYPE_PADN, 2),
+            (TYPE_BINDING_REFRESH_ADVICE, 4),
+            (TYPE_ALTERNATE_CARE_OF_ADDRESS, 18),
+            (TYPE_NONCE_INDICES, 6),
+            (9, 2),
+        ] {
+            let data = [option_type];
+            let mut iter = Ipv6MobilityOptionsIterator::from_slice(&data);
+            assert_eq!(
+                Some(Err(UnexpectedEndOfSlice {
+                    option_type,
+                    expected_len,
+                    actual_len: 1,
+                })),
+                iter.next()
+            );
+            assert_eq!(None, iter.next());
+        }
+
+        // bad sizes
+        for (option_type, size) in [
+            (TYPE_BINDING_REFRESH_ADVICE, 3),
+            (TYPE_ALTERNATE_CARE_OF_ADDRESS, 15),
+            (TYPE_NONCE_INDICES, 2),
+        ] {
+            let data = [
+                option_type,
+                size,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+            ];
+            let mut iter = Ipv6MobilityOptionsIterator::from_slice(&data);
+            assert_eq!(Some(Err(UnexpectedSize { option_type, size })), iter.next());
+            assert_eq!(None, iter.next());
+        }
+
+        // not enough data
+        {
+            let data = [TYPE_PADN, 3, 0];
+            let mut iter = Ipv6MobilityOptionsIterator::from_slice(&data);
+            assert_eq!(
+                Some(Err(UnexpectedEndOfSlice {
+                    option_type: TYPE_PADN,
+                    expected_len: 5,
+                    actual_len: 3,
+                })),
+                iter.next()
+            );
+            assert_eq!(None, iter.next());
+        }
+    }
+
+    #[test]
+    fn clone_eq_debug() {
+        let data = [TYPE_PAD1, TYPE_PADN];
+        let iter = Ipv6MobilityOptionsIterator::from_slice(&data);
+        assert_eq!(iter, iter.clone());
+        assert_eq!(
+            format!(
+                "[{:?}, {:?}]",
+                Pad1,
+                Result::<(), err::ipv6_exts::OptionReadError>::Err(UnexpectedEndOfSlice {
+                    option_type: TYPE_PADN,
+                    expected_len: 2,
+                    actual_len: 1
+                })
+            ),
+            format!("{:?}", iter)
+        );
+    }
+}
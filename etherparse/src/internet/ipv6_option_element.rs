@@ -0,0 +1,218 @@
+use crate::Ipv6OptionUnknownAction;
+
+/// Different kinds of options that can be present in an IPv6 hop-by-hop
+/// or destination options header.
+///
+/// Variable length data of the options references the underlying
+/// header data (no copies are made when decoding the options).
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum Ipv6OptionElement<'a> {
+    /// "Pad1" option (type 0) representing a single byte of padding.
+    Pad1,
+
+    /// "PadN" option (type 1) representing two or more bytes of padding.
+    ///
+    /// The contained value is the option data length (the number of
+    /// padding bytes after the type & length fields).
+    PadN(u8),
+
+    /// "Tunnel Encapsulation Limit" option (type 4, RFC 2473) containing
+    /// the number of further nested encapsulations that are permitted.
+    TunnelEncapLimit(u8),
+
+    /// "Router Alert" option (type 5, RFC 2711).
+    ///
+    /// A value of `0` indicates that the packet contains a Multicast
+    /// Listener Discovery message.
+    RouterAlert(u16),
+
+    /// "Common Architecture Label IPv6 Security Option" (CALIPSO,
+    /// type 7, RFC 5570).
+    Calipso {
+        /// Domain of interpretation.
+        doi: u32,
+        /// Sensitivity level.
+        sensitivity_level: u8,
+        /// CRC-16 checksum over the option.
+        checksum: u16,
+        /// Compartment bitmap (length is a multiple of 4 bytes).
+        compartment_bitmap: &'a [u8],
+    },
+
+    /// "Jumbo Payload" option (type 0xC2, RFC 2675) containing the
+    /// length of the packet in octets (excluding the IPv6 header but
+    /// including the extension headers).
+    JumboPayload(u32),
+
+    /// Option with a type not explicitly supported by etherparse.
+    Unknown {
+        /// Option type (first byte of the option).
+        option_type: u8,
+        /// Data of the option after the type & length bytes.
+        data: &'a [u8],
+    },
+}
+
+impl<'a> Ipv6OptionElement<'a> {
+    /// Returns the type number of the option (first byte of the option).
+    pub fn option_type(&self) -> u8 {
+        use crate::ipv6_option::*;
+        use Ipv6OptionElement::*;
+        match self {
+            Pad1 => TYPE_PAD1,
+            PadN(_) => TYPE_PADN,
+            TunnelEncapLimit(_) => TYPE_TUNNEL_ENCAP_LIMIT,
+            RouterAlert(_) => TYPE_ROUTER_ALERT,
+            Calipso { .. } => TYPE_CALIPSO,
+            JumboPayload(_) => TYPE_JUMBO_PAYLOAD,
+            Unknown { option_type, .. } => *option_type,
+        }
+    }
+
+    /// Returns the action that has to be taken by nodes that do not
+    /// recognize the option (highest two bits of the option type).
+    #[inline]
+    pub fn unknown_action(&self) -> Ipv6OptionUnknownAction {
+        Ipv6OptionUnknownAction::from_option_type(self.option_type())
+    }
+
+    /// Returns true if the option data may change en route to the
+    /// packets final destination (third highest bit of the option type).
+    #[inline]
+    pub fn may_change_en_route(&self) -> bool {
+        0 != self.option_type() & 0b0010_0000
+    }
+
+    /// Returns the length of the serialized option in bytes (including
+    /// the type & length bytes).
+    pub fn header_len(&self) -> usize {
+        use crate::ipv6_option::*;
+        use Ipv6OptionElement::*;
+        match self {
+            Pad1 => 1,
+            PadN(len) => 2 + usize::from(*len),
+            TunnelEncapLimit(_) => 2 + usize::from(LEN_TUNNEL_ENCAP_LIMIT),
+            RouterAlert(_) => 2 + usize::from(LEN_ROUTER_ALERT),
+            Calipso {
+                compartment_bitmap, ..
+            } => 2 + usize::from(MIN_LEN_CALIPSO) + compartment_bitmap.len(),
+            JumboPayload(_) => 2 + usize::from(LEN_JUMBO_PAYLOAD),
+            Unknown { data, .. } => 2 + data.len(),
+        }
+    }
+
+    /// Returns the alignment requirement of the option in the form
+    /// `(x, y)` meaning the option has to start at an offset of `x*n + y`
+    /// bytes from the start of the extension header.
+    pub(crate) fn alignment(&self) -> (usize, usize) {
+        use Ipv6OptionElement::*;
+        match self {
+            RouterAlert(_) => (2, 0),
+            Calipso { .. } | JumboPayload(_) => (4, 2),
+            _ => (1, 0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+    use alloc::format;
+
+    #[test]
+    fn clone_eq() {
+        use Ipv6OptionElement::*;
+        let values = [
+            Pad1,
+            PadN(2),
+            RouterAlert(0),
+            Unknown {
+                option_type: 2,
+                data: &[1, 2],
+            },
+        ];
+        for value in values {
+            assert_eq!(value.clone(), value);
+        }
+    }
+
+    #[test]
+    fn debug() {
+        use Ipv6OptionElement::*;
+        assert_eq!("Pad1", format!("{:?}", Pad1));
+        assert_eq!("JumboPayload(70000)", format!("{:?}", JumboPayload(70000)));
+        assert_eq!(
+            "Unknown { option_type: 2, data: [1] }",
+            format!(
+                "{:?}",
+                Unknown {
+                    option_type: 2,
+                    data: &[1]
+                }
+            )
+        );
+    }
+
+    #[test]
+    fn option_type_header_len_alignment() {
+        use ipv6_option::*;
+        use Ipv6OptionElement::*;
+        let tests = [
+            (Pad1, TYPE_PAD1, 1, (1, 0)),
+            (PadN(3), TYPE_PADN, 5, (1, 0)),
+            (TunnelEncapLimit(4), TYPE_TUNNEL_ENCAP_LIMIT, 3, (1, 0)),
+            (RouterAlert(0), TYPE_ROUTER_ALERT, 4, (2, 0)),
+            (
+                Calipso {
+                    doi: 1,
+                    sensitivity_level: 2,
+                    checksum: 3,
+                    compartment_bitmap: &[1, 2, 3, 4],
+                },
+                TYPE_CALIPSO,
+                14,
+                (4, 2),
+            ),
+            (JumboPayload(70000), TYPE_JUMBO_PAYLOAD, 6, (4, 2)),
+            (
+                Unknown {
+                    option_type: 0x3e,
+                    data: &[1],
+                },
+                0x3e,
+                3,
+                (1, 0),
+            ),
+        ];
+        for (element, option_type, header_len, alignment) in tests {
+            assert_eq!(option_type, element.option_type());
+            assert_eq!(header_len, element.header_len());
+            assert_eq!(alignment, element.alignment());
+        }
+    }
+
+    #[test]
+    fn unknown_action_may_change_en_route() {
+        use Ipv6OptionElement::*;
+        use Ipv6OptionUnknownAction::*;
+
+        assert_eq!(Skip, RouterAlert(0).unknown_action());
+        assert!(!RouterAlert(0).may_change_en_route());
+        assert_eq!(
+            DiscardAndIcmpIfNotMulticast,
+            JumboPayload(0).unknown_action()
+        );
+        assert!(!JumboPayload(0).may_change_en_route());
+        for option_type in 0..=u8::MAX {
+            let element = Unknown {
+                option_type,
+                data: &[],
+            };
+            assert_eq!(
+                Ipv6OptionUnknownAction::from_option_type(option_type),
+                element.unknown_action()
+            );
+            assert_eq!(0 != option_type & 0x20, element.may_change_en_route());
+        }
+    }
+}
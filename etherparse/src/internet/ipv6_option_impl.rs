@@ -0,0 +1,30 @@
+/// Module containing the constants for the options contained in IPv6
+/// hop-by-hop & destination options headers (type numbers & sizes).
+///
+/// Note that the lengths are the lengths of the "option data" (excluding
+/// the option type & option data length fields) as encoded in the
+/// "option data length" field.
+pub mod ipv6_option {
+    /// `u8` identifying a "Pad1" IPv6 option (single byte of padding).
+    pub const TYPE_PAD1: u8 = 0x00;
+    /// `u8` identifying a "PadN" IPv6 option (two or more bytes of padding).
+    pub const TYPE_PADN: u8 = 0x01;
+    /// `u8` identifying a "tunnel encapsulation limit" IPv6 option (RFC 2473).
+    pub const TYPE_TUNNEL_ENCAP_LIMIT: u8 = 0x04;
+    /// `u8` identifying a "router alert" IPv6 option (RFC 2711).
+    pub const TYPE_ROUTER_ALERT: u8 = 0x05;
+    /// `u8` identifying a "common architecture label IPv6 security option"
+    /// (CALIPSO, RFC 5570).
+    pub const TYPE_CALIPSO: u8 = 0x07;
+    /// `u8` identifying a "jumbo payload" IPv6 option (RFC 2675).
+    pub const TYPE_JUMBO_PAYLOAD: u8 = 0xC2;
+    /// Option data length in octets/bytes of the "tunnel encapsulation limit" IPv6 option.
+    pub const LEN_TUNNEL_ENCAP_LIMIT: u8 = 1;
+    /// Option data length in octets/bytes of the "router alert" IPv6 option.
+    pub const LEN_ROUTER_ALERT: u8 = 2;
+    /// Option data length in octets/bytes of the "jumbo payload" IPv6 option.
+    pub const LEN_JUMBO_PAYLOAD: u8 = 4;
+    /// Minimum option data length in octets/bytes of the "CALIPSO" IPv6
+    /// option (without compartment bitmap).
+    pub const MIN_LEN_CALIPSO: u8 = 8;
+}
@@ -0,0 +1,75 @@
+/// Action a node has to take if it does not recognize an IPv6 hop-by-hop
+/// or destination option (encoded in the two highest bits of the option
+/// type, see RFC 8200 section 4.2).
+///
+/// ```
+/// use etherparse::{Ipv6OptionUnknownAction, ipv6_option::*};
+///
+/// assert_eq!(
+///     Ipv6OptionUnknownAction::Skip,
+///     Ipv6OptionUnknownAction::from_option_type(TYPE_ROUTER_ALERT)
+/// );
+/// assert_eq!(
+///     Ipv6OptionUnknownAction::DiscardAndIcmpIfNotMulticast,
+///     Ipv6OptionUnknownAction::from_option_type(TYPE_JUMBO_PAYLOAD)
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum Ipv6OptionUnknownAction {
+    /// Skip over the option and continue processing the header (`00`).
+    Skip = 0,
+    /// Discard the packet (`01`).
+    Discard = 1,
+    /// Discard the packet and send an ICMP "Parameter Problem, Code 2"
+    /// message to the source address, regardless of whether or not the
+    /// destination address was a multicast address (`10`).
+    DiscardAndIcmp = 2,
+    /// Discard the packet and, only if the destination address was not a
+    /// multicast address, send an ICMP "Parameter Problem, Code 2" message
+    /// to the source address (`11`).
+    DiscardAndIcmpIfNotMulticast = 3,
+}
+
+impl Ipv6OptionUnknownAction {
+    /// Returns the action encoded in the two highest bits of the given
+    /// option type.
+    #[inline]
+    pub fn from_option_type(option_type: u8) -> Ipv6OptionUnknownAction {
+        use Ipv6OptionUnknownAction::*;
+        match option_type >> 6 {
+            0 => Skip,
+            1 => Discard,
+            2 => DiscardAndIcmp,
+            _ => DiscardAndIcmpIfNotMulticast,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Ipv6OptionUnknownAction::{self, *};
+    use alloc::format;
+
+    #[test]
+    fn from_option_type() {
+        for option_type in 0..=u8::MAX {
+            let expected = match option_type & 0b1100_0000 {
+                0b0000_0000 => Skip,
+                0b0100_0000 => Discard,
+                0b1000_0000 => DiscardAndIcmp,
+                _ => DiscardAndIcmpIfNotMulticast,
+            };
+            assert_eq!(
+                expected,
+                Ipv6OptionUnknownAction::from_option_type(option_type)
+            );
+            assert_eq!(option_type >> 6, expected as u8);
+        }
+    }
+
+    #[test]
+    fn clone_eq_debug() {
+        assert_eq!(Skip, Skip.clone());
+        assert_eq!("DiscardAndIcmp", format!("{:?}", DiscardAndIcmp));
+    }
+}
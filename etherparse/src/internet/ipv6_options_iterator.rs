@@ -0,0 +1,321 @@
+use crate::*;
+
+/// Allows iterating over the options of an IPv6 hop-by-hop or
+/// destination options header.
+///
+/// The iterator expects the data after the "next header" & "header
+/// length" fields (e.g. [`Ipv6RawExtHeader::payload`]). In case an error
+/// is encountered the error is returned and the iteration is stopped.
+///
+/// # Example
+///
+/// ```
+/// use etherparse::{Ipv6OptionsIterator, Ipv6OptionElement::*, ipv6_option::*};
+///
+/// let options = [
+///     TYPE_ROUTER_ALERT, LEN_ROUTER_ALERT, 0, 0,
+///     TYPE_PADN, 0,
+/// ];
+///
+/// let mut iter = Ipv6OptionsIterator::from_slice(&options);
+/// assert_eq!(iter.next(), Some(Ok(RouterAlert(0))));
+/// assert_eq!(iter.next(), Some(Ok(PadN(0))));
+/// assert_eq!(iter.next(), None);
+/// ```
+#[derive(Clone, Eq, PartialEq)]
+pub struct Ipv6OptionsIterator<'a> {
+    pub(crate) options: &'a [u8],
+}
+
+impl<'a> Ipv6OptionsIterator<'a> {
+    /// Creates an options iterator from a slice containing encoded IPv6 options.
+    pub fn from_slice(options: &'a [u8]) -> Ipv6OptionsIterator<'a> {
+        Ipv6OptionsIterator { options }
+    }
+
+    /// Returns the non processed part of the options slice.
+    pub fn rest(&self) -> &'a [u8] {
+        self.options
+    }
+}
+
+impl<'a> Iterator for Ipv6OptionsIterator<'a> {
+    type Item = Result<Ipv6OptionElement<'a>, err::ipv6_exts::OptionReadError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        use err::ipv6_exts::OptionReadError::*;
+        use ipv6_option::*;
+        use Ipv6OptionElement::*;
+
+        let options = self.options;
+        let result = match options.first() {
+            None => None,
+            Some(&TYPE_PAD1) => Some(Ok((Pad1, 1))),
+            Some(&option_type) => {
+                // check the length field can be read & is in range
+                let min_data_len = match option_type {
+                    TYPE_TUNNEL_ENCAP_LIMIT => LEN_TUNNEL_ENCAP_LIMIT,
+                    TYPE_ROUTER_ALERT => LEN_ROUTER_ALERT,
+                    TYPE_CALIPSO => MIN_LEN_CALIPSO,
+                    TYPE_JUMBO_PAYLOAD => LEN_JUMBO_PAYLOAD,
+                    _ => 0,
+                };
+                if options.len() < 2 {
+                    Some(Err(UnexpectedEndOfSlice {
+                        option_type,
+                        expected_len: 2 + usize::from(min_data_len),
+                        actual_len: options.len(),
+                    }))
+                } else {
+                    let data_len = options[1];
+                    let bad_size = match option_type {
+                        TYPE_TUNNEL_ENCAP_LIMIT | TYPE_ROUTER_ALERT | TYPE_JUMBO_PAYLOAD => {
+                            min_data_len != data_len
+                        }
+                        TYPE_CALIPSO => {
+                            data_len < MIN_LEN_CALIPSO
+                                || !(data_len - MIN_LEN_CALIPSO).is_multiple_of(4)
+                        }
+                        _ => false,
+                    };
+                    let len = 2 + usize::from(data_len);
+                    if bad_size {
+                        Some(Err(UnexpectedSize {
+                            option_type,
+                            size: data_len,
+                        }))
+                    } else if options.len() < len {
+                        Some(Err(UnexpectedEndOfSlice {
+                            option_type,
+                            expected_len: len,
+                            actual_len: options.len(),
+                        }))
+                    } else {
+                        let data = &options[2..len];
+                        match option_type {
+                            TYPE_PADN => Some(Ok((PadN(data_len), len))),
+                            TYPE_TUNNEL_ENCAP_LIMIT => Some(Ok((TunnelEncapLimit(data[0]), len))),
+                            TYPE_ROUTER_ALERT => Some(Ok((
+                                RouterAlert(u16::from_be_bytes([data[0], data[1]])),
+                                len,
+                            ))),
+                            TYPE_JUMBO_PAYLOAD => Some(Ok((
+                                JumboPayload(u32::from_be_bytes([
+                                    data[0], data[1], data[2], data[3],
+                                ])),
+                                len,
+                            ))),
+                            TYPE_CALIPSO => {
+                                // the compartment length (in 32 bit words) has
+                                // to match the option data length
+                                let compartment_bitmap = &data[8..];
+                                if usize::from(data[4]) * 4 != compartment_bitmap.len() {
+                                    Some(Err(UnexpectedSize {
+                                        option_type,
+                                        size: data_len,
+                                    }))
+                                } else {
+                                    Some(Ok((
+                                        Calipso {
+                                            doi: u32::from_be_bytes([
+                                                data[0], data[1], data[2], data[3],
+                                            ]),
+                                            sensitivity_level: data[5],
+                                            checksum: u16::from_be_bytes([data[6], data[7]]),
+                                            compartment_bitmap,
+                                        },
+                                        len,
+                                    )))
+                                }
+                            }
+                            _ => Some(Ok((Unknown { option_type, data }, len))),
+                        }
+                    }
+                }
+            }
+        };
+
+        match result {
+            Some(Ok((element, len))) => {
+                self.options = &options[len..];
+                Some(Ok(element))
+            }
+            Some(Err(err)) => {
+                // stop the iteration after an error
+                self.options = &options[options.len()..];
+                Some(Err(err))
+            }
+            None => None,
+        }
+    }
+}
+
+impl<'a> core::fmt::Debug for Ipv6OptionsIterator<'a> {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
+        let mut list = fmt.debug_list();
+
+        // create a copy and iterate over all elements
+        for it in self.clone() {
+            match it {
+                Ok(e) => {
+                    list.entry(&e);
+                }
+                Err(e) => {
+                    list.entry(&Result::<(), err::ipv6_exts::OptionReadError>::Err(e));
+                }
+            }
+        }
+
+        list.finish()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::err::ipv6_exts::OptionReadError::*;
+    use alloc::{format, vec::Vec};
+    use ipv6_option::*;
+    use Ipv6OptionElement::*;
+
+    #[test]
+    fn from_slice_rest() {
+        let data = [1, 2, 3, 4];
+        let iter = Ipv6OptionsIterator::from_slice(&data);
+        assert_eq!(&data[..], iter.rest());
+    }
+
+    #[test]
+    fn next() {
+        // empty
+        assert_eq!(None, Ipv6OptionsIterator::from_slice(&[]).next());
+
+        // all supported options
+        {
+            #[rustfmt::skip]
+            let data = [
+                TYPE_PAD1,
+                TYPE_PADN, 1, 0,
+                TYPE_TUNNEL_ENCAP_LIMIT, 1, 4,
+                TYPE_ROUTER_ALERT, 2, 0x12, 0x34,
+                TYPE_JUMBO_PAYLOAD, 4, 0, 1, 0x11, 0x70,
+                TYPE_CALIPSO, 12, 0, 0, 0, 3, 1, 2, 0xab, 0xcd, 5, 6, 7, 8,
+                0x3e, 1, 9,
+                TYPE_PAD1,
+            ];
+            let mut iter = Ipv6OptionsIterator::from_slice(&data);
+            let actual: Vec<_> = iter.by_ref().collect();
+            assert_eq!(
+                actual,
+                [
+                    Ok(Pad1),
+                    Ok(PadN(1)),
+                    Ok(TunnelEncapLimit(4)),
+                    Ok(RouterAlert(0x1234)),
+                    Ok(JumboPayload(70000)),
+                    Ok(Calipso {
+                        doi: 3,
+                        sensitivity_level: 2,
+                        checksum: 0xabcd,
+                        compartment_bitmap: &[5, 6, 7, 8],
+                    }),
+                    Ok(Unknown {
+                        option_type: 0x3e,
+                        data: &[9]
+                    }),
+                    Ok(Pad1),
+                ]
+            );
+            assert!(iter.rest().is_empty());
+        }
+
+        // length field missing
+        for (option_type, expected_len) in [
+            (TYPE_PADN, 2),
+            (TYPE_TUNNEL_ENCAP_LIMIT, 3),
+            (TYPE_ROUTER_ALERT, 4),
+            (TYPE_JUMBO_PAYLOAD, 6),
+            (TYPE_CALIPSO, 10),
+            (0x3e, 2),
+        ] {
+            let data = [option_type];
+            let mut iter = Ipv6OptionsIterator::from_slice(&data);
+            assert_eq!(
+                Some(Err(UnexpectedEndOfSlice {
+                    option_type,
+                    expected_len,
+                    actual_len: 1,
+                })),
+                iter.next()
+            );
+            assert_eq!(None, iter.next());
+        }
+
+        // bad sizes
+        for (option_type, size) in [
+            (TYPE_TUNNEL_ENCAP_LIMIT, 0),
+            (TYPE_TUNNEL_ENCAP_LIMIT, 2),
+            (TYPE_ROUTER_ALERT, 1),
+            (TYPE_ROUTER_ALERT, 3),
+            (TYPE_JUMBO_PAYLOAD, 3),
+            (TYPE_JUMBO_PAYLOAD, 5),
+            (TYPE_CALIPSO, 7),
+            (TYPE_CALIPSO, 9),
+        ] {
+            let data = [option_type, size, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+            let mut iter = Ipv6OptionsIterator::from_slice(&data);
+            assert_eq!(Some(Err(UnexpectedSize { option_type, size })), iter.next());
+            assert_eq!(None, iter.next());
+        }
+
+        // calipso compartment length not matching the option length
+        {
+            let data = [TYPE_CALIPSO, 8, 0, 0, 0, 1, 1, 0, 0, 0];
+            let mut iter = Ipv6OptionsIterator::from_slice(&data);
+            assert_eq!(
+                Some(Err(UnexpectedSize {
+                    option_type: TYPE_CALIPSO,
+                    size: 8
+                })),
+                iter.next()
+            );
+            assert_eq!(None, iter.next());
+        }
+
+        // not enough data for the complete option
+        {
+            let data = [TYPE_PAD1, TYPE_PADN, 4, 0, 0];
+            let mut iter = Ipv6OptionsIterator::from_slice(&data);
+            assert_eq!(Some(Ok(Pad1)), iter.next());
+            assert_eq!(
+                Some(Err(UnexpectedEndOfSlice {
+                    option_type: TYPE_PADN,
+                    expected_len: 6,
+                    actual_len: 4,
+                })),
+                iter.next()
+            );
+            assert_eq!(None, iter.next());
+        }
+    }
+
+    #[test]
+    fn clone_eq_debug() {
+        let data = [TYPE_PAD1, TYPE_ROUTER_ALERT, 2, 0, 0, 0x3e];
+        let iter = Ipv6OptionsIterator::from_slice(&data);
+        assert_eq!(iter, iter.clone());
+        assert_eq!(
+            format!(
+                "[{:?}, {:?}, {:?}]",
+                Pad1,
+                RouterAlert(0),
+                Result::<(), err::ipv6_exts::OptionReadError>::Err(UnexpectedEndOfSlice {
+                    option_type: 0x3e,
+                    expected_len: 2,
+                    actual_len: 1
+                })
+            ),
+            format!("{:?}", iter)
+        );
+    }
+}
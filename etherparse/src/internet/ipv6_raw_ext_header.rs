@@ -114,6 +114,278 @@ impl Ipv6RawExtHeader {
         }
     }
 
+    /// Creates an IPv6 extension header (hop-by-hop or destination options)
+    /// containing the given options.
+    ///
+    /// Padding ("Pad1" or "PadN" options) is automatically inserted to
+    /// fulfill the alignment requirements of the options and to extend the
+    /// header to a multiple of 8 octets.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use etherparse::{ip_number, Ipv6RawExtHeader, Ipv6OptionElement, ipv6_option::*};
+    ///
+    /// let header = Ipv6RawExtHeader::try_from_options(
+    ///     ip_number::UDP,
+    ///     &[Ipv6OptionElement::RouterAlert(0)],
+    /// ).unwrap();
+    ///
+    /// assert_eq!(
+    ///     header.payload(),
+    ///     &[
+    ///         TYPE_ROUTER_ALERT, LEN_ROUTER_ALERT, 0, 0,
+    ///         // padding to extend the header to 8 bytes
+    ///         TYPE_PADN, 0,
+    ///     ]
+    /// );
+    /// ```
+    pub fn try_from_options(
+        next_header: IpNumber,
+        options: &[Ipv6OptionElement],
+    ) -> Result<Ipv6RawExtHeader, err::ipv6_exts::OptionWriteError> {
+        use err::ipv6_exts::OptionWriteError::*;
+        use ipv6_option::*;
+        use Ipv6OptionElement::*;
+
+        // returns the number of padding bytes needed to fulfill
+        // the alignment requirement of an element
+        fn align_len(pos: usize, element: &Ipv6OptionElement) -> usize {
+            let (x, y) = element.alignment();
+            (x + y - (pos % x)) % x
+        }
+
+        // writes the given number of padding bytes
+        fn write_padding(buf: &mut [u8], len: usize) {
+            match len {
+                0 => {}
+                1 => buf[0] = TYPE_PAD1,
+                _ => {
+                    buf[0] = TYPE_PADN;
+                    buf[1] = (len - 2) as u8;
+                }
+            }
+        }
+
+        // check the options can be represented & calculate the header length
+        let mut pos = 2;
+        for element in options {
+            let bad_data_len = match element {
+                Calipso {
+                    compartment_bitmap, ..
+                } => {
+                    let len = compartment_bitmap.len();
+                    if 0 != len % 4 || len > usize::from(u8::MAX - MIN_LEN_CALIPSO) {
+                        Some(len)
+                    } else {
+                        None
+                    }
+                }
+                Unknown { data, .. } if data.len() > usize::from(u8::MAX) => Some(data.len()),
+                _ => None,
+            };
+            if let Some(data_len) = bad_data_len {
+                return Err(UnexpectedDataLen {
+                    option_type: element.option_type(),
+                    data_len,
+                });
+            }
+            pos += align_len(pos, element) + element.header_len();
+        }
+        let header_len = core::cmp::max(Self::MIN_LEN, pos.div_ceil(8) * 8);
+        if header_len > Self::MAX_LEN {
+            return Err(NotEnoughSpace {
+                required_len: header_len,
+            });
+        }
+
+        // write the options
+        let mut result = Ipv6RawExtHeader {
+            next_header,
+            header_length: ((header_len - 8) / 8) as u8,
+            payload_buffer: [0; Self::MAX_PAYLOAD_LEN],
+        };
+        let mut pos = 2;
+        for element in options {
+            let pad_len = align_len(pos, element);
+            write_padding(&mut result.payload_buffer[pos - 2..], pad_len);
+            pos += pad_len;
+
+            let len = element.header_len();
+            let t = &mut result.payload_buffer[pos - 2..pos - 2 + len];
+            t[0] = element.option_type();
+            match element {
+                Pad1 => {}
+                PadN(data_len) => t[1] = *data_len,
+                TunnelEncapLimit(value) => {
+                    t[1] = LEN_TUNNEL_ENCAP_LIMIT;
+                    t[2] = *value;
+                }
+                RouterAlert(value) => {
+                    t[1] = LEN_ROUTER_ALERT;
+                    t[2..4].copy_from_slice(&value.to_be_bytes());
+                }
+                Calipso {
+                    doi,
+                    sensitivity_level,
+                    checksum,
+                    compartment_bitmap,
+                } => {
+                    t[1] = (len - 2) as u8;
+                    t[2..6].copy_from_slice(&doi.to_be_bytes());
+                    t[6] = (compartment_bitmap.len() / 4) as u8;
+                    t[7] = *sensitivity_level;
+                    t[8..10].copy_from_slice(&checksum.to_be_bytes());
+                    t[10..].copy_from_slice(compartment_bitmap);
+                }
+                JumboPayload(value) => {
+                    t[1] = LEN_JUMBO_PAYLOAD;
+                    t[2..6].copy_from_slice(&value.to_be_bytes());
+                }
+                Unknown { data, .. } => {
+                    t[1] = data.len() as u8;
+                    t[2..].copy_from_slice(data);
+                }
+            }
+            pos += len;
+        }
+        write_padding(&mut result.payload_buffer[pos - 2..], header_len - pos);
+
+        Ok(result)
+    }
+
+    /// Creates a segment routing header (routing header with routing type 4,
+    /// RFC 8754) based on the given segment list & TLVs.
+    ///
+    /// The segment list has to be passed in the order it is present in
+    /// the header. This means `segments[0]` is the final destination and
+    /// the last element is the first segment to be visited. The
+    /// "last entry" field is set based on the length of `segments`.
+    ///
+    /// Padding ("Pad1" or "PadN" TLVs) is automatically appended to extend
+    /// the header to a multiple of 8 octets.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use etherparse::{ip_number, Ipv6RawExtHeader, Ipv6SrhTlv};
+    ///
+    /// let header = Ipv6RawExtHeader::try_from_srh(
+    ///     ip_number::UDP,
+    ///     1, // segments left
+    ///     0, // flags
+    ///     0x1234, // tag
+    ///     &[[1; 16], [2; 16]],
+    ///     &[Ipv6SrhTlv::Hmac{ d_flag: false, key_id: 1, hmac: &[3; 8] }],
+    /// ).unwrap();
+    ///
+    /// assert_eq!(header.header_len(), 8 + 2*16 + 16);
+    /// ```
+    pub fn try_from_srh(
+        next_header: IpNumber,
+        segments_left: u8,
+        flags: u8,
+        tag: u16,
+        segments: &[[u8; 16]],
+        tlvs: &[Ipv6SrhTlv],
+    ) -> Result<Ipv6RawExtHeader, err::ipv6_exts::SrhWriteError> {
+        use err::ipv6_exts::SrhWriteError::*;
+        use ipv6_srh_tlv::*;
+        use Ipv6SrhTlv::*;
+
+        if segments.is_empty() {
+            return Err(EmptySegmentList);
+        }
+
+        // check the tlvs can be represented & calculate the header length
+        let mut pos = 8 + segments.len() * 16;
+        for tlv in tlvs {
+            let bad_data_len = match tlv {
+                Hmac { hmac, .. }
+                    if 0 != hmac.len() % 8 || hmac.len() > usize::from(u8::MAX - MIN_LEN_HMAC) =>
+                {
+                    Some(usize::from(MIN_LEN_HMAC) + hmac.len())
+                }
+                Unknown { data, .. } if data.len() > usize::from(u8::MAX) => Some(data.len()),
+                _ => None,
+            };
+            if let Some(data_len) = bad_data_len {
+                return Err(UnexpectedTlvDataLen {
+                    tlv_type: tlv.tlv_type(),
+                    data_len,
+                });
+            }
+            pos += tlv.header_len();
+        }
+        let header_len = pos.div_ceil(8) * 8;
+        if header_len > Self::MAX_LEN {
+            return Err(NotEnoughSpace {
+                required_len: header_len,
+            });
+        }
+
+        // write the fixed fields & segment list
+        let mut result = Ipv6RawExtHeader {
+            next_header,
+            header_length: ((header_len - 8) / 8) as u8,
+            payload_buffer: [0; Self::MAX_PAYLOAD_LEN],
+        };
+        {
+            let tag_be = tag.to_be_bytes();
+            result.payload_buffer[..6].copy_from_slice(&[
+                Ipv6RoutingType::SEGMENT_ROUTING.0,
+                segments_left,
+                // the length of segments was verified via the header length
+                (segments.len() - 1) as u8,
+                flags,
+                tag_be[0],
+                tag_be[1],
+            ]);
+        }
+        for (i, segment) in segments.iter().enumerate() {
+            result.payload_buffer[6 + i * 16..6 + (i + 1) * 16].copy_from_slice(segment);
+        }
+
+        // write the tlvs
+        let mut pos = 8 + segments.len() * 16;
+        for tlv in tlvs {
+            let len = tlv.header_len();
+            let t = &mut result.payload_buffer[pos - 2..pos - 2 + len];
+            t[0] = tlv.tlv_type();
+            match tlv {
+                Pad1 => {}
+                PadN(data_len) => t[1] = *data_len,
+                Hmac {
+                    d_flag,
+                    key_id,
+                    hmac,
+                } => {
+                    t[1] = (len - 2) as u8;
+                    t[2] = if *d_flag { 0x80 } else { 0 };
+                    t[4..8].copy_from_slice(&key_id.to_be_bytes());
+                    t[8..].copy_from_slice(hmac);
+                }
+                Unknown { data, .. } => {
+                    t[1] = data.len() as u8;
+                    t[2..].copy_from_slice(data);
+                }
+            }
+            pos += len;
+        }
+
+        // pad to a multiple of 8 octets
+        match header_len - pos {
+            0 => {}
+            1 => result.payload_buffer[pos - 2] = TYPE_PAD1,
+            pad_len => {
+                result.payload_buffer[pos - 2] = TYPE_PADN;
+                result.payload_buffer[pos - 1] = (pad_len - 2) as u8;
+            }
+        }
+
+        Ok(result)
+    }
+
     /// Read an Ipv6ExtensionHeader from a slice and return the header & unused parts of the slice.
     pub fn from_slice(slice: &[u8]) -> Result<(Ipv6RawExtHeader, &[u8]), err::LenError> {
         let s = Ipv6RawExtHeaderSlice::from_slice(slice)?;
@@ -129,6 +401,99 @@ impl Ipv6RawExtHeader {
         &self.payload_buffer[..(6 + usize::from(self.header_length) * 8)]
     }
 
+    /// Mutable version of [`Ipv6RawExtHeader::payload`].
+    pub(crate) fn payload_mut(&mut self) -> &mut [u8] {
+        &mut self.payload_buffer[..(6 + usize::from(self.header_length) * 8)]
+    }
+
+    /// Returns an iterator over the options contained in the header.
+    ///
+    /// Note that this is only meaningful for hop-by-hop & destination
+    /// options headers (other extension headers do not contain options).
+    #[inline]
+    pub fn options_iterator(&self) -> Ipv6OptionsIterator<'_> {
+        Ipv6OptionsIterator::from_slice(self.payload())
+    }
+
+    /// Returns the value of the "Jumbo Payload" option (RFC 2675) if
+    /// one is present in the header.
+    ///
+    /// Note that this is only meaningful for hop-by-hop options headers.
+    pub fn jumbo_payload_len(&self) -> Option<u32> {
+        self.options_iterator().find_map(|e| match e {
+            Ok(Ipv6OptionElement::JumboPayload(value)) => Some(value),
+            _ => None,
+        })
+    }
+
+    /// Sets the value of the "Jumbo Payload" option (RFC 2675).
+    ///
+    /// If the header already contains a "Jumbo Payload" option its value
+    /// gets overwritten. Otherwise the option (including padding for its
+    /// alignment) is appended to the header, increasing the header
+    /// length by 8 octets.
+    ///
+    /// Note that this is only meaningful for hop-by-hop options headers.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use etherparse::{ip_number, Ipv6RawExtHeader};
+    ///
+    /// let mut header = Ipv6RawExtHeader::try_from_options(ip_number::UDP, &[]).unwrap();
+    /// header.set_jumbo_payload_len(70000).unwrap();
+    ///
+    /// assert_eq!(header.header_len(), 16);
+    /// assert_eq!(header.jumbo_payload_len(), Some(70000));
+    /// ```
+    pub fn set_jumbo_payload_len(
+        &mut self,
+        len: u32,
+    ) -> Result<(), err::ipv6_exts::OptionWriteError> {
+        use ipv6_option::*;
+
+        // search for an existing option
+        let existing_end = {
+            let mut iter = self.options_iterator();
+            let mut result = None;
+            while let Some(Ok(element)) = iter.next() {
+                if let Ipv6OptionElement::JumboPayload(_) = element {
+                    result = Some(self.payload().len() - iter.rest().len());
+                    break;
+                }
+            }
+            result
+        };
+
+        let len_be = len.to_be_bytes();
+        if let Some(end) = existing_end {
+            self.payload_mut()[end - 4..end].copy_from_slice(&len_be);
+        } else {
+            let header_len = self.header_len() + 8;
+            if header_len > Self::MAX_LEN {
+                return Err(err::ipv6_exts::OptionWriteError::NotEnoughSpace {
+                    required_len: header_len,
+                });
+            }
+            // the header length is always a multiple of 8, so a PadN
+            // option with a length of 2 fulfills the alignment
+            // requirement of the option (4n + 2)
+            let start = self.payload().len();
+            self.payload_buffer[start..start + 8].copy_from_slice(&[
+                TYPE_PADN,
+                0,
+                TYPE_JUMBO_PAYLOAD,
+                LEN_JUMBO_PAYLOAD,
+                len_be[0],
+                len_be[1],
+                len_be[2],
+                len_be[3],
+            ]);
+            self.header_length += 1;
+        }
+        Ok(())
+    }
+
     /// Sets the payload (content of the header after the `next_header` & `header_length` fields).
     ///
     /// Note that `payload` must have at least the length of 6 bytes and only supports
@@ -142,7 +507,7 @@ impl Ipv6RawExtHeader {
             Err(TooSmall(payload.len()))
         } else if payload.len() > Self::MAX_PAYLOAD_LEN {
             Err(TooBig(payload.len()))
-        } else if 0 != (payload.len() + 2) % 8 {
+        } else if !(payload.len() + 2).is_multiple_of(8) {
             Err(Unaligned(payload.len()))
         } else {
             self.payload_buffer[..payload.len()].copy_from_slice(payload);
@@ -318,6 +683,322 @@ mod test {
         }
     }
 
+    #[test]
+    fn try_from_options() {
+        use err::ipv6_exts::OptionWriteError::*;
+        use ip_number::UDP;
+        use ipv6_option::*;
+        use Ipv6OptionElement::*;
+
+        // empty (padded to 8 bytes)
+        {
+            let header = Ipv6RawExtHeader::try_from_options(UDP, &[]).unwrap();
+            assert_eq!(header.next_header, UDP);
+            assert_eq!(header.payload(), &[TYPE_PADN, 4, 0, 0, 0, 0]);
+            assert_eq!(
+                0,
+                header
+                    .options_iterator()
+                    .filter(|e| e != &Ok(PadN(4)))
+                    .count()
+            );
+        }
+
+        // all options with alignment padding
+        {
+            let options = [
+                TunnelEncapLimit(4),
+                RouterAlert(0x1234),
+                JumboPayload(70000),
+                Calipso {
+                    doi: 3,
+                    sensitivity_level: 2,
+                    checksum: 0xabcd,
+                    compartment_bitmap: &[5, 6, 7, 8],
+                },
+                Unknown {
+                    option_type: 0x3e,
+                    data: &[9],
+                },
+                Pad1,
+                PadN(0),
+            ];
+            let header = Ipv6RawExtHeader::try_from_options(UDP, &options).unwrap();
+            #[rustfmt::skip]
+            let expected = [
+                TYPE_TUNNEL_ENCAP_LIMIT, 1, 4,
+                TYPE_PAD1,
+                TYPE_ROUTER_ALERT, 2, 0x12, 0x34,
+                TYPE_JUMBO_PAYLOAD, 4, 0, 1, 0x11, 0x70,
+                TYPE_PADN, 0,
+                TYPE_CALIPSO, 12, 0, 0, 0, 3, 1, 2, 0xab, 0xcd, 5, 6, 7, 8,
+                0x3e, 1, 9,
+                TYPE_PAD1,
+                TYPE_PADN, 0,
+                // padding to a multiple of 8
+                TYPE_PADN, 0,
+            ];
+            assert_eq!(header.payload(), &expected[..]);
+
+            // read back (ignoring the inserted padding)
+            let decoded: Vec<_> = header
+                .options_iterator()
+                .map(|e| e.unwrap())
+                .filter(|e| !matches!(e, Pad1 | PadN(_)))
+                .collect();
+            assert_eq!(&decoded[..], &options[..5]);
+        }
+
+        // single padding byte at the end
+        {
+            let header = Ipv6RawExtHeader::try_from_options(UDP, &[PadN(3)]).unwrap();
+            assert_eq!(header.payload(), &[TYPE_PADN, 3, 0, 0, 0, TYPE_PAD1]);
+        }
+
+        // non representable data
+        assert_eq!(
+            Err(UnexpectedDataLen {
+                option_type: TYPE_CALIPSO,
+                data_len: 3
+            }),
+            Ipv6RawExtHeader::try_from_options(
+                UDP,
+                &[Calipso {
+                    doi: 0,
+                    sensitivity_level: 0,
+                    checksum: 0,
+                    compartment_bitmap: &[0; 3]
+                }]
+            )
+        );
+        assert_eq!(
+            Err(UnexpectedDataLen {
+                option_type: TYPE_CALIPSO,
+                data_len: 248
+            }),
+            Ipv6RawExtHeader::try_from_options(
+                UDP,
+                &[Calipso {
+                    doi: 0,
+                    sensitivity_level: 0,
+                    checksum: 0,
+                    compartment_bitmap: &[0; 248]
+                }]
+            )
+        );
+        assert_eq!(
+            Err(UnexpectedDataLen {
+                option_type: 0x3e,
+                data_len: 256
+            }),
+            Ipv6RawExtHeader::try_from_options(
+                UDP,
+                &[Unknown {
+                    option_type: 0x3e,
+                    data: &[0; 256]
+                }]
+            )
+        );
+
+        // maximum length & not enough space
+        {
+            // 2 + 7*(2 + 255) + (2 + 245) = 2048
+            let data = [0u8; 255];
+            let mut max = [Unknown {
+                option_type: 0x3e,
+                data: &data,
+            }; 9];
+            max[7] = Unknown {
+                option_type: 0x3e,
+                data: &data[..245],
+            };
+            let header = Ipv6RawExtHeader::try_from_options(UDP, &max[..8]).unwrap();
+            assert_eq!(header.header_len(), Ipv6RawExtHeader::MAX_LEN);
+
+            max[8] = Pad1;
+            assert_eq!(
+                Err(NotEnoughSpace {
+                    required_len: Ipv6RawExtHeader::MAX_LEN + 8
+                }),
+                Ipv6RawExtHeader::try_from_options(UDP, &max)
+            );
+        }
+    }
+
+    #[test]
+    fn jumbo_payload_len() {
+        use err::ipv6_exts::OptionWriteError::*;
+        use ip_number::UDP;
+        use ipv6_option::*;
+        use Ipv6OptionElement::*;
+
+        // no option present (gets appended)
+        {
+            let mut header = Ipv6RawExtHeader::try_from_options(UDP, &[RouterAlert(1)]).unwrap();
+            assert_eq!(None, header.jumbo_payload_len());
+
+            header.set_jumbo_payload_len(0x12345678).unwrap();
+            assert_eq!(Some(0x12345678), header.jumbo_payload_len());
+            #[rustfmt::skip]
+            let expected = [
+                TYPE_ROUTER_ALERT, 2, 0, 1,
+                TYPE_PADN, 0,
+                TYPE_PADN, 0,
+                TYPE_JUMBO_PAYLOAD, 4, 0x12, 0x34, 0x56, 0x78,
+            ];
+            assert_eq!(header.payload(), &expected[..]);
+
+            // option already present (gets overwritten)
+            header.set_jumbo_payload_len(70000).unwrap();
+            assert_eq!(Some(70000), header.jumbo_payload_len());
+            assert_eq!(header.header_len(), 16);
+        }
+
+        // not enough space
+        {
+            let mut header = Ipv6RawExtHeader::new_raw(UDP, &[0; 2046]).unwrap();
+            assert_eq!(
+                Err(NotEnoughSpace {
+                    required_len: Ipv6RawExtHeader::MAX_LEN + 8
+                }),
+                header.set_jumbo_payload_len(70000)
+            );
+            assert_eq!(None, header.jumbo_payload_len());
+            assert_eq!(header.header_len(), Ipv6RawExtHeader::MAX_LEN);
+        }
+    }
+
+    #[test]
+    fn try_from_srh() {
+        use err::ipv6_exts::SrhWriteError::*;
+        use ip_number::UDP;
+        use ipv6_srh_tlv::*;
+        use Ipv6SrhTlv::*;
+
+        // segments only
+        {
+            let header =
+                Ipv6RawExtHeader::try_from_srh(UDP, 1, 0x80, 0x1234, &[[1; 16], [2; 16]], &[])
+                    .unwrap();
+            assert_eq!(header.next_header, UDP);
+            #[rustfmt::skip]
+            let expected = [
+                4, 1, 1, 0x80, 0x12, 0x34,
+                1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+                2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
+            ];
+            assert_eq!(header.payload(), &expected[..]);
+        }
+
+        // all tlvs with padding
+        {
+            let tlvs = [
+                Hmac {
+                    d_flag: true,
+                    key_id: 0x01020304,
+                    hmac: &[5; 8],
+                },
+                Unknown {
+                    tlv_type: 9,
+                    data: &[1, 2],
+                },
+                Pad1,
+                PadN(0),
+            ];
+            let header = Ipv6RawExtHeader::try_from_srh(UDP, 0, 0, 0, &[[1; 16]], &tlvs).unwrap();
+            #[rustfmt::skip]
+            let expected = [
+                4, 0, 0, 0, 0, 0,
+                1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+                TYPE_HMAC, 14, 0x80, 0, 1, 2, 3, 4, 5, 5, 5, 5, 5, 5, 5, 5,
+                9, 2, 1, 2,
+                TYPE_PAD1,
+                TYPE_PADN, 0,
+                // padding to a multiple of 8
+                TYPE_PAD1,
+            ];
+            assert_eq!(header.payload(), &expected[..]);
+
+            // read back
+            let bytes = header.to_bytes();
+            let raw = Ipv6RawExtHeaderSlice::from_slice(&bytes).unwrap();
+            let srh = Ipv6SrhSlice::from_raw(&raw).unwrap();
+            let decoded: Vec<_> = srh.tlvs().map(|e| e.unwrap()).collect();
+            assert_eq!(&decoded[..], &[tlvs[0], tlvs[1], Pad1, PadN(0), Pad1][..]);
+        }
+
+        // padn at the end
+        {
+            let header = Ipv6RawExtHeader::try_from_srh(UDP, 0, 0, 0, &[[1; 16]], &[Pad1]).unwrap();
+            assert_eq!(
+                &header.payload()[22..],
+                &[TYPE_PAD1, TYPE_PADN, 5, 0, 0, 0, 0, 0]
+            );
+        }
+
+        // empty segment list
+        assert_eq!(
+            Err(EmptySegmentList),
+            Ipv6RawExtHeader::try_from_srh(UDP, 0, 0, 0, &[], &[])
+        );
+
+        // non representable data
+        for hmac_len in [7, 256] {
+            let hmac = [0u8; 256];
+            assert_eq!(
+                Err(UnexpectedTlvDataLen {
+                    tlv_type: TYPE_HMAC,
+                    data_len: 6 + hmac_len
+                }),
+                Ipv6RawExtHeader::try_from_srh(
+                    UDP,
+                    0,
+                    0,
+                    0,
+                    &[[0; 16]],
+                    &[Hmac {
+                        d_flag: false,
+                        key_id: 0,
+                        hmac: &hmac[..hmac_len]
+                    }]
+                )
+            );
+        }
+        assert_eq!(
+            Err(UnexpectedTlvDataLen {
+                tlv_type: 9,
+                data_len: 256
+            }),
+            Ipv6RawExtHeader::try_from_srh(
+                UDP,
+                0,
+                0,
+                0,
+                &[[0; 16]],
+                &[Unknown {
+                    tlv_type: 9,
+                    data: &[0; 256]
+                }]
+            )
+        );
+
+        // maximum length & not enough space
+        {
+            // 8 + 127*16 + 8 = 2048
+            let segments = [[0u8; 16]; 128];
+            let header =
+                Ipv6RawExtHeader::try_from_srh(UDP, 0, 0, 0, &segments[..127], &[PadN(6)]).unwrap();
+            assert_eq!(header.header_len(), Ipv6RawExtHeader::MAX_LEN);
+
+            assert_eq!(
+                Err(NotEnoughSpace {
+                    required_len: Ipv6RawExtHeader::MAX_LEN + 16
+                }),
+                Ipv6RawExtHeader::try_from_srh(UDP, 0, 0, 0, &segments, &[PadN(6)])
+            );
+        }
+    }
+
     proptest! {
         #[test]
         fn from_slice(header in ipv6_raw_ext_any()) {
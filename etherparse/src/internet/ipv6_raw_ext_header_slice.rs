@@ -112,6 +112,26 @@ impl<'a> Ipv6RawExtHeaderSlice<'a> {
         unsafe { from_raw_parts(self.slice.as_ptr().add(2), self.slice.len() - 2) }
     }
 
+    /// Returns an iterator over the options contained in the header.
+    ///
+    /// Note that this is only meaningful for hop-by-hop & destination
+    /// options headers (other extension headers do not contain options).
+    #[inline]
+    pub fn options_iterator(&self) -> Ipv6OptionsIterator<'a> {
+        Ipv6OptionsIterator::from_slice(self.payload())
+    }
+
+    /// Returns the value of the "Jumbo Payload" option (RFC 2675) if
+    /// one is present in the header.
+    ///
+    /// Note that this is only meaningful for hop-by-hop options headers.
+    pub fn jumbo_payload_len(&self) -> Option<u32> {
+        self.options_iterator().find_map(|e| match e {
+            Ok(Ipv6OptionElement::JumboPayload(value)) => Some(value),
+            _ => None,
+        })
+    }
+
     /// Convert the slice to an [Ipv6RawExtHeader].
     ///
     /// Decode some of the fields and copy the results to a
@@ -220,6 +240,8 @@ mod test {
             let slice = Ipv6RawExtHeaderSlice::from_slice(&bytes).unwrap();
             assert_eq!(slice.next_header(), header.next_header);
             assert_eq!(slice.payload(), header.payload());
+            assert_eq!(slice.options_iterator(), header.options_iterator());
+            assert_eq!(slice.jumbo_payload_len(), header.jumbo_payload_len());
         }
     }
 
@@ -14,4 +14,209 @@ impl Ipv6RoutingExtensions {
 
     /// Maximum summed up length of all extension headers in bytes/octets.
     pub const MAX_LEN: usize = Ipv6RawExtHeader::MAX_LEN * 2;
+
+    /// Returns the final destination of the packet based on the routing header.
+    ///
+    /// `destination` is the destination address present in the IPv6
+    /// header (required to decode the compressed addresses of RPL source
+    /// route headers).
+    ///
+    /// An error is returned if the routing type is not supported or the
+    /// routing header is malformed.
+    pub fn final_destination(
+        &self,
+        destination: &[u8; 16],
+    ) -> Result<[u8; 16], err::ipv6_exts::RoutingHeaderError> {
+        let bytes = self.routing.to_bytes();
+        // SAFETY: Safe as the serialized header always has a valid length.
+        let raw = unsafe { Ipv6RawExtHeaderSlice::from_slice_unchecked(&bytes) };
+        Ipv6RoutingHeaderSlice::from_raw(&raw)?.final_destination(destination)
+    }
+
+    /// Advances the routing header to the next segment by decrementing
+    /// the "segments left" field & updating the given IPv6 destination
+    /// address to the next address to be visited.
+    ///
+    /// For type 2 routing headers the destination address & the home
+    /// address in the routing header are swapped (as defined in RFC 6275).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use etherparse::{ip_number, Ipv6RawExtHeader, Ipv6RoutingExtensions};
+    ///
+    /// let mut routing = Ipv6RoutingExtensions {
+    ///     routing: Ipv6RawExtHeader::try_from_srh(
+    ///         ip_number::UDP,
+    ///         1, // segments left
+    ///         0, // flags
+    ///         0, // tag
+    ///         &[[1; 16], [2; 16]],
+    ///         &[],
+    ///     ).unwrap(),
+    ///     final_destination_options: None,
+    /// };
+    ///
+    /// // the destination address is set to the active segment
+    /// let mut destination = [2; 16];
+    /// routing.advance_segment(&mut destination).unwrap();
+    ///
+    /// assert_eq!(destination, [1; 16]);
+    /// assert_eq!(routing.routing.payload()[1], 0); // segments left
+    /// ```
+    pub fn advance_segment(
+        &mut self,
+        destination: &mut [u8; 16],
+    ) -> Result<(), err::ipv6_exts::RoutingHeaderError> {
+        let bytes = self.routing.to_bytes();
+        // SAFETY: Safe as the serialized header always has a valid length.
+        let raw = unsafe { Ipv6RawExtHeaderSlice::from_slice_unchecked(&bytes) };
+        let header = Ipv6RoutingHeaderSlice::from_raw(&raw)?;
+        let next = header.next_destination(destination)?;
+
+        let payload = self.routing.payload_mut();
+        payload[1] -= 1;
+        if let Ipv6RoutingHeaderSlice::Type2(_) = header {
+            payload[6..22].copy_from_slice(destination);
+        }
+        *destination = next;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::err::ipv6_exts::RoutingHeaderError::*;
+
+    fn from_bytes(bytes: &[u8]) -> Ipv6RoutingExtensions {
+        Ipv6RoutingExtensions {
+            routing: Ipv6RawExtHeader::from_slice(bytes).unwrap().0,
+            final_destination_options: None,
+        }
+    }
+
+    #[test]
+    fn final_destination() {
+        let destination = [0xff; 16];
+
+        // srh
+        {
+            let routing = Ipv6RoutingExtensions {
+                routing: Ipv6RawExtHeader::try_from_srh(
+                    ip_number::UDP,
+                    1,
+                    0,
+                    0,
+                    &[[1; 16], [2; 16]],
+                    &[],
+                )
+                .unwrap(),
+                final_destination_options: None,
+            };
+            assert_eq!(Ok([1; 16]), routing.final_destination(&destination));
+        }
+
+        // type 2
+        {
+            let mut bytes = [1u8; 24];
+            bytes[..8].copy_from_slice(&[0, 2, 2, 1, 0, 0, 0, 0]);
+            assert_eq!(
+                Ok([1; 16]),
+                from_bytes(&bytes).final_destination(&destination)
+            );
+        }
+
+        // unsupported routing type
+        assert_eq!(
+            Err(UnsupportedRoutingType(Ipv6RoutingType::SOURCE_ROUTE)),
+            from_bytes(&[0; 8]).final_destination(&destination)
+        );
+    }
+
+    #[test]
+    fn advance_segment() {
+        // srh
+        {
+            let mut routing = Ipv6RoutingExtensions {
+                routing: Ipv6RawExtHeader::try_from_srh(
+                    ip_number::UDP,
+                    2,
+                    0,
+                    0,
+                    &[[1; 16], [2; 16], [3; 16]],
+                    &[],
+                )
+                .unwrap(),
+                final_destination_options: None,
+            };
+            let mut destination = [3; 16];
+            routing.advance_segment(&mut destination).unwrap();
+            assert_eq!(destination, [2; 16]);
+            assert_eq!(routing.routing.payload()[1], 1);
+            routing.advance_segment(&mut destination).unwrap();
+            assert_eq!(destination, [1; 16]);
+            assert_eq!(routing.routing.payload()[1], 0);
+
+            // no segments left (nothing gets modified)
+            let expected = routing.clone();
+            assert_eq!(
+                Err(NoSegmentsLeft),
+                routing.advance_segment(&mut destination)
+            );
+            assert_eq!(destination, [1; 16]);
+            assert_eq!(routing, expected);
+        }
+
+        // type 2 (destination & home address get swapped)
+        {
+            let mut bytes = [1u8; 24];
+            bytes[..8].copy_from_slice(&[0, 2, 2, 1, 0, 0, 0, 0]);
+            let mut routing = from_bytes(&bytes);
+            let mut destination = [2; 16];
+            routing.advance_segment(&mut destination).unwrap();
+            assert_eq!(destination, [1; 16]);
+            assert_eq!(routing.routing.payload()[1], 0);
+            assert_eq!(&routing.routing.payload()[6..], &[2; 16]);
+        }
+
+        // rpl (three addresses with 8 bytes each)
+        {
+            #[rustfmt::skip]
+            let bytes = [
+                0, 3, 3, 3, 0x88, 0, 0, 0,
+                1, 1, 1, 1, 1, 1, 1, 1,
+                2, 2, 2, 2, 2, 2, 2, 2,
+                3, 3, 3, 3, 3, 3, 3, 3,
+            ];
+            let mut routing = from_bytes(&bytes);
+            let mut destination = [0xff; 16];
+            for (segments_left, value) in [(2, 1), (1, 2), (0, 3)] {
+                routing.advance_segment(&mut destination).unwrap();
+                assert_eq!(&destination[..8], &[0xff; 8]);
+                assert_eq!(&destination[8..], &[value; 8]);
+                assert_eq!(routing.routing.payload()[1], segments_left);
+            }
+        }
+
+        // segments left too big
+        {
+            let mut bytes = [1u8; 24];
+            bytes[..8].copy_from_slice(&[0, 2, 2, 2, 0, 0, 0, 0]);
+            let mut destination = [0; 16];
+            assert_eq!(
+                Err(SegmentsLeftTooBig {
+                    segments_left: 2,
+                    max: 1
+                }),
+                from_bytes(&bytes).advance_segment(&mut destination)
+            );
+        }
+
+        // unsupported routing type
+        assert_eq!(
+            Err(UnsupportedRoutingType(Ipv6RoutingType::SOURCE_ROUTE)),
+            from_bytes(&[0, 0, 0, 1, 0, 0, 0, 0]).advance_segment(&mut [0; 16])
+        );
+    }
 }
@@ -0,0 +1,293 @@
+use crate::{err::ipv6_exts::RoutingHeaderError, *};
+
+/// Slice containing an IPv6 routing header decoded based on its
+/// routing type.
+///
+/// # Example
+///
+/// ```
+/// use etherparse::{ip_number, Ipv6RawExtHeader, Ipv6RawExtHeaderSlice, Ipv6RoutingHeaderSlice};
+///
+/// let header = Ipv6RawExtHeader::try_from_srh(
+///     ip_number::UDP,
+///     1, // segments left
+///     0, // flags
+///     0, // tag
+///     &[[1; 16], [2; 16]],
+///     &[],
+/// ).unwrap();
+/// let bytes = header.to_bytes();
+///
+/// let raw = Ipv6RawExtHeaderSlice::from_slice(&bytes).unwrap();
+/// match Ipv6RoutingHeaderSlice::from_raw(&raw).unwrap() {
+///     Ipv6RoutingHeaderSlice::Srh(srh) => {
+///         assert_eq!(srh.segments(), &[[1; 16], [2; 16]]);
+///     },
+///     _ => panic!("expected a segment routing header"),
+/// }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Ipv6RoutingHeaderSlice<'a> {
+    /// Type 2 routing header (Mobile IPv6, RFC 6275).
+    Type2(Ipv6Type2RoutingHeaderSlice<'a>),
+    /// RPL source route header (RFC 6554).
+    Rpl(Ipv6RplRoutingHeaderSlice<'a>),
+    /// Segment routing header (SRv6, RFC 8754).
+    Srh(Ipv6SrhSlice<'a>),
+    /// Routing header with a routing type not explicitly supported by etherparse.
+    Other(Ipv6RawExtHeaderSlice<'a>),
+}
+
+impl<'a> Ipv6RoutingHeaderSlice<'a> {
+    /// Decodes a routing header from a raw IPv6 extension header slice
+    /// based on the routing type.
+    pub fn from_raw(
+        raw: &Ipv6RawExtHeaderSlice<'a>,
+    ) -> Result<Ipv6RoutingHeaderSlice<'a>, RoutingHeaderError> {
+        use Ipv6RoutingHeaderSlice::*;
+        Ok(match Ipv6RoutingType(raw.slice()[2]) {
+            Ipv6RoutingType::TYPE2 => Type2(Ipv6Type2RoutingHeaderSlice::from_raw(raw)?),
+            Ipv6RoutingType::RPL_SOURCE_ROUTE => Rpl(Ipv6RplRoutingHeaderSlice::from_raw(raw)?),
+            Ipv6RoutingType::SEGMENT_ROUTING => Srh(Ipv6SrhSlice::from_raw(raw)?),
+            _ => Other(raw.clone()),
+        })
+    }
+
+    /// Returns the slice containing the complete header.
+    pub fn slice(&self) -> &'a [u8] {
+        use Ipv6RoutingHeaderSlice::*;
+        match self {
+            Type2(s) => s.slice(),
+            Rpl(s) => s.slice(),
+            Srh(s) => s.slice(),
+            Other(s) => s.slice(),
+        }
+    }
+
+    /// Returns the IP protocol number of the next header or transport layer protocol.
+    #[inline]
+    pub fn next_header(&self) -> IpNumber {
+        IpNumber(self.slice()[0])
+    }
+
+    /// Returns the routing type of the header.
+    #[inline]
+    pub fn routing_type(&self) -> Ipv6RoutingType {
+        Ipv6RoutingType(self.slice()[2])
+    }
+
+    /// Returns the "segments left" field.
+    #[inline]
+    pub fn segments_left(&self) -> u8 {
+        self.slice()[3]
+    }
+
+    /// Returns the final destination of the packet.
+    ///
+    /// The current IPv6 destination address is required to decode the
+    /// compressed addresses of RPL source route headers.
+    pub fn final_destination(
+        &self,
+        destination: &[u8; 16],
+    ) -> Result<[u8; 16], RoutingHeaderError> {
+        use Ipv6RoutingHeaderSlice::*;
+        match self {
+            Type2(s) => Ok(s.home_address()),
+            Rpl(s) => Ok(s.final_destination(destination)),
+            Srh(s) => Ok(s.final_destination()),
+            Other(_) => Err(RoutingHeaderError::UnsupportedRoutingType(
+                self.routing_type(),
+            )),
+        }
+    }
+
+    /// Returns the IPv6 destination address that has to be set when
+    /// advancing to the next segment (after decrementing "segments left").
+    pub fn next_destination(&self, destination: &[u8; 16]) -> Result<[u8; 16], RoutingHeaderError> {
+        use Ipv6RoutingHeaderSlice::*;
+        use RoutingHeaderError::*;
+
+        let segments_left = self.segments_left();
+        let max = match self {
+            Type2(_) => 1,
+            Rpl(s) => s.addresses_len(),
+            Srh(s) => s.segments().len(),
+            Other(_) => return Err(UnsupportedRoutingType(self.routing_type())),
+        };
+        if 0 == segments_left {
+            return Err(NoSegmentsLeft);
+        }
+        if usize::from(segments_left) > max {
+            return Err(SegmentsLeftTooBig {
+                segments_left,
+                // max can not be bigger then 255 in case segments_left is
+                // bigger as segments_left itself is an u8
+                max: max as u8,
+            });
+        }
+        Ok(match self {
+            Type2(s) => s.home_address(),
+            // unwrap is safe as index was verified to be within range
+            Rpl(s) => s
+                .address(max - usize::from(segments_left), destination)
+                .unwrap(),
+            Srh(s) => s.segments()[usize::from(segments_left) - 1],
+            Other(_) => unreachable!(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::err::ipv6_exts::RoutingHeaderError::*;
+
+    #[test]
+    fn from_raw() {
+        use Ipv6RoutingHeaderSlice::*;
+
+        // type 2
+        {
+            let mut bytes = [0u8; 24];
+            bytes[1] = 2;
+            bytes[2] = 2;
+            let raw = Ipv6RawExtHeaderSlice::from_slice(&bytes).unwrap();
+            assert_eq!(
+                Ok(Type2(Ipv6Type2RoutingHeaderSlice::from_raw(&raw).unwrap())),
+                Ipv6RoutingHeaderSlice::from_raw(&raw)
+            );
+        }
+        // rpl
+        {
+            let bytes = [
+                0, 2, 3, 0, 0, 0, 0, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16,
+            ];
+            let raw = Ipv6RawExtHeaderSlice::from_slice(&bytes).unwrap();
+            assert_eq!(
+                Ok(Rpl(Ipv6RplRoutingHeaderSlice::from_raw(&raw).unwrap())),
+                Ipv6RoutingHeaderSlice::from_raw(&raw)
+            );
+        }
+        // srh
+        {
+            let mut bytes = [0u8; 24];
+            bytes[1] = 2;
+            bytes[2] = 4;
+            let raw = Ipv6RawExtHeaderSlice::from_slice(&bytes).unwrap();
+            assert_eq!(
+                Ok(Srh(Ipv6SrhSlice::from_raw(&raw).unwrap())),
+                Ipv6RoutingHeaderSlice::from_raw(&raw)
+            );
+        }
+        // other
+        {
+            let bytes = [0, 0, 0, 0, 0, 0, 0, 0];
+            let raw = Ipv6RawExtHeaderSlice::from_slice(&bytes).unwrap();
+            assert_eq!(
+                Ok(Other(raw.clone())),
+                Ipv6RoutingHeaderSlice::from_raw(&raw)
+            );
+        }
+        // error
+        {
+            let bytes = [0, 0, 4, 0, 0, 0, 0, 0];
+            let raw = Ipv6RawExtHeaderSlice::from_slice(&bytes).unwrap();
+            assert_eq!(
+                Err(InvalidLastEntry {
+                    last_entry: 0,
+                    hdr_ext_len: 0
+                }),
+                Ipv6RoutingHeaderSlice::from_raw(&raw)
+            );
+        }
+    }
+
+    #[test]
+    fn getters_final_next_destination() {
+        let destination = [0xff; 16];
+
+        // type 2
+        {
+            #[rustfmt::skip]
+            let mut bytes = [
+                ip_number::UDP.0, 2, 2, 1, 0, 0, 0, 0,
+                1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+            ];
+            {
+                let raw = Ipv6RawExtHeaderSlice::from_slice(&bytes).unwrap();
+                let header = Ipv6RoutingHeaderSlice::from_raw(&raw).unwrap();
+                assert_eq!(header.slice(), &bytes[..]);
+                assert_eq!(header.next_header(), ip_number::UDP);
+                assert_eq!(header.routing_type(), Ipv6RoutingType::TYPE2);
+                assert_eq!(header.segments_left(), 1);
+                assert_eq!(Ok([1; 16]), header.final_destination(&destination));
+                assert_eq!(Ok([1; 16]), header.next_destination(&destination));
+            }
+            bytes[3] = 2;
+            {
+                let raw = Ipv6RawExtHeaderSlice::from_slice(&bytes).unwrap();
+                let header = Ipv6RoutingHeaderSlice::from_raw(&raw).unwrap();
+                assert_eq!(
+                    Err(SegmentsLeftTooBig {
+                        segments_left: 2,
+                        max: 1
+                    }),
+                    header.next_destination(&destination)
+                );
+            }
+            bytes[3] = 0;
+            {
+                let raw = Ipv6RawExtHeaderSlice::from_slice(&bytes).unwrap();
+                let header = Ipv6RoutingHeaderSlice::from_raw(&raw).unwrap();
+                assert_eq!(Err(NoSegmentsLeft), header.next_destination(&destination));
+            }
+        }
+
+        // rpl (three addresses with 8 bytes each)
+        {
+            #[rustfmt::skip]
+            let bytes = [
+                0, 3, 3, 2, 0x88, 0, 0, 0,
+                1, 1, 1, 1, 1, 1, 1, 1,
+                2, 2, 2, 2, 2, 2, 2, 2,
+                3, 3, 3, 3, 3, 3, 3, 3,
+            ];
+            let raw = Ipv6RawExtHeaderSlice::from_slice(&bytes).unwrap();
+            let header = Ipv6RoutingHeaderSlice::from_raw(&raw).unwrap();
+            let mut expected = destination;
+            expected[8..].copy_from_slice(&[3; 8]);
+            assert_eq!(Ok(expected), header.final_destination(&destination));
+            expected[8..].copy_from_slice(&[2; 8]);
+            assert_eq!(Ok(expected), header.next_destination(&destination));
+        }
+
+        // srh
+        {
+            #[rustfmt::skip]
+            let bytes = [
+                0, 4, 4, 2, 1, 0, 0, 0,
+                1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+                2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
+            ];
+            let raw = Ipv6RawExtHeaderSlice::from_slice(&bytes).unwrap();
+            let header = Ipv6RoutingHeaderSlice::from_raw(&raw).unwrap();
+            assert_eq!(Ok([1; 16]), header.final_destination(&destination));
+            assert_eq!(Ok([2; 16]), header.next_destination(&destination));
+        }
+
+        // other
+        {
+            let bytes = [0, 0, 0, 1, 0, 0, 0, 0];
+            let raw = Ipv6RawExtHeaderSlice::from_slice(&bytes).unwrap();
+            let header = Ipv6RoutingHeaderSlice::from_raw(&raw).unwrap();
+            assert_eq!(
+                Err(UnsupportedRoutingType(Ipv6RoutingType::SOURCE_ROUTE)),
+                header.final_destination(&destination)
+            );
+            assert_eq!(
+                Err(UnsupportedRoutingType(Ipv6RoutingType::SOURCE_ROUTE)),
+                header.next_destination(&destination)
+            );
+        }
+    }
+}
@@ -0,0 +1,109 @@
+/// Routing type of an IPv6 routing header (values as registered at
+/// <https://www.iana.org/assignments/ipv6-parameters/ipv6-parameters.xhtml#ipv6-parameters-3>).
+///
+/// You can access the underlying `u8` value by using `.0` and any `u8`
+/// can be converted to an `Ipv6RoutingType`:
+///
+/// ```
+/// use etherparse::Ipv6RoutingType;
+///
+/// assert_eq!(Ipv6RoutingType::SEGMENT_ROUTING.0, 4);
+/// assert_eq!(Ipv6RoutingType::SEGMENT_ROUTING, Ipv6RoutingType(4));
+///
+/// // convert to Ipv6RoutingType using the from & into trait
+/// let routing_type: Ipv6RoutingType = 2u8.into();
+/// assert_eq!(Ipv6RoutingType::TYPE2, routing_type);
+///
+/// // convert to u8 using the from & into trait
+/// let num: u8 = Ipv6RoutingType::RPL_SOURCE_ROUTE.into();
+/// assert_eq!(3, num);
+/// ```
+#[derive(Default, PartialEq, Eq, Clone, Copy, Hash, Ord, PartialOrd)]
+pub struct Ipv6RoutingType(pub u8);
+
+impl Ipv6RoutingType {
+    /// Source Route (deprecated by RFC 5095).
+    pub const SOURCE_ROUTE: Ipv6RoutingType = Self(0);
+    /// Nimrod (deprecated).
+    pub const NIMROD: Ipv6RoutingType = Self(1);
+    /// Type 2 Routing Header used by Mobile IPv6 (RFC 6275).
+    pub const TYPE2: Ipv6RoutingType = Self(2);
+    /// RPL Source Route Header (RFC 6554).
+    pub const RPL_SOURCE_ROUTE: Ipv6RoutingType = Self(3);
+    /// Segment Routing Header (SRH) used by SRv6 (RFC 8754).
+    pub const SEGMENT_ROUTING: Ipv6RoutingType = Self(4);
+}
+
+impl From<u8> for Ipv6RoutingType {
+    #[inline]
+    fn from(val: u8) -> Self {
+        Ipv6RoutingType(val)
+    }
+}
+
+impl From<Ipv6RoutingType> for u8 {
+    #[inline]
+    fn from(val: Ipv6RoutingType) -> Self {
+        val.0
+    }
+}
+
+impl core::fmt::Debug for Ipv6RoutingType {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match *self {
+            Self::SOURCE_ROUTE => write!(f, "{} (Source Route)", self.0),
+            Self::NIMROD => write!(f, "{} (Nimrod)", self.0),
+            Self::TYPE2 => write!(f, "{} (Type 2 Routing Header)", self.0),
+            Self::RPL_SOURCE_ROUTE => write!(f, "{} (RPL Source Route Header)", self.0),
+            Self::SEGMENT_ROUTING => write!(f, "{} (Segment Routing Header)", self.0),
+            _ => write!(f, "{}", self.0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::format;
+
+    #[test]
+    fn to_u8() {
+        assert_eq!(0, u8::from(Ipv6RoutingType::SOURCE_ROUTE));
+        assert_eq!(4, u8::from(Ipv6RoutingType::SEGMENT_ROUTING));
+    }
+
+    #[test]
+    fn from_u8() {
+        assert_eq!(Ipv6RoutingType::from(2u8), Ipv6RoutingType::TYPE2);
+        assert_eq!(Ipv6RoutingType::from(0xffu8), Ipv6RoutingType(0xff));
+    }
+
+    #[test]
+    fn dbg() {
+        let pairs = &[
+            (Ipv6RoutingType::SOURCE_ROUTE, "0 (Source Route)"),
+            (Ipv6RoutingType::NIMROD, "1 (Nimrod)"),
+            (Ipv6RoutingType::TYPE2, "2 (Type 2 Routing Header)"),
+            (
+                Ipv6RoutingType::RPL_SOURCE_ROUTE,
+                "3 (RPL Source Route Header)",
+            ),
+            (
+                Ipv6RoutingType::SEGMENT_ROUTING,
+                "4 (Segment Routing Header)",
+            ),
+            (Ipv6RoutingType(0xff), "255"),
+        ];
+
+        for (input, expected) in pairs {
+            assert_eq!(expected, &format!("{:?}", input));
+        }
+    }
+
+    #[test]
+    fn clone_eq_default() {
+        let value = Ipv6RoutingType::SEGMENT_ROUTING;
+        assert_eq!(value, value.clone());
+        assert_eq!(Ipv6RoutingType(0), Ipv6RoutingType::default());
+    }
+}
@@ -0,0 +1,194 @@
+use crate::{err::ipv6_exts::RoutingHeaderError, *};
+
+/// Slice containing an IPv6 "RPL Source Route Header" (routing type 3,
+/// RFC 6554).
+///
+/// The addresses in the header are compressed by eliding the prefix
+/// octets they share with the IPv6 destination address. To decode an
+/// address the destination address of the IPv6 header has to be passed
+/// to [`Ipv6RplRoutingHeaderSlice::address`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Ipv6RplRoutingHeaderSlice<'a> {
+    /// Slice containing the complete header.
+    slice: &'a [u8],
+}
+
+impl<'a> Ipv6RplRoutingHeaderSlice<'a> {
+    /// Creates a RPL source route header slice from a raw IPv6 extension
+    /// header slice and verifies the routing type & address layout.
+    pub fn from_raw(
+        raw: &Ipv6RawExtHeaderSlice<'a>,
+    ) -> Result<Ipv6RplRoutingHeaderSlice<'a>, RoutingHeaderError> {
+        use RoutingHeaderError::*;
+
+        let slice = raw.slice();
+        let routing_type = Ipv6RoutingType(slice[2]);
+        if Ipv6RoutingType::RPL_SOURCE_ROUTE != routing_type {
+            return Err(UnexpectedRoutingType {
+                expected: Ipv6RoutingType::RPL_SOURCE_ROUTE,
+                actual: routing_type,
+            });
+        }
+
+        // check the addresses fit exactly into the header
+        let result = Ipv6RplRoutingHeaderSlice { slice };
+        let addresses_data_len = usize::from(slice[1]) * 8;
+        let last_len = 16 - usize::from(result.cmpr_e());
+        let fixed_len = usize::from(result.pad()) + last_len;
+        if addresses_data_len < fixed_len
+            || 0 != (addresses_data_len - fixed_len) % (16 - usize::from(result.cmpr_i()))
+        {
+            return Err(InvalidRplAddressLayout {
+                cmpr_i: result.cmpr_i(),
+                cmpr_e: result.cmpr_e(),
+                pad: result.pad(),
+                hdr_ext_len: slice[1],
+            });
+        }
+        Ok(result)
+    }
+
+    /// Returns the slice containing the complete header.
+    #[inline]
+    pub fn slice(&self) -> &'a [u8] {
+        self.slice
+    }
+
+    /// Returns the IP protocol number of the next header or transport layer protocol.
+    #[inline]
+    pub fn next_header(&self) -> IpNumber {
+        IpNumber(self.slice[0])
+    }
+
+    /// Returns the "segments left" field (number of addresses still to
+    /// be visited).
+    #[inline]
+    pub fn segments_left(&self) -> u8 {
+        self.slice[3]
+    }
+
+    /// Returns the number of prefix octets elided from all addresses
+    /// except the last one ("CmprI" field).
+    #[inline]
+    pub fn cmpr_i(&self) -> u8 {
+        self.slice[4] >> 4
+    }
+
+    /// Returns the number of prefix octets elided from the last
+    /// address ("CmprE" field).
+    #[inline]
+    pub fn cmpr_e(&self) -> u8 {
+        self.slice[4] & 0xf
+    }
+
+    /// Returns the number of padding octets at the end of the header
+    /// ("Pad" field).
+    #[inline]
+    pub fn pad(&self) -> u8 {
+        self.slice[5] >> 4
+    }
+
+    /// Returns the number of addresses in the header.
+    #[inline]
+    pub fn addresses_len(&self) -> usize {
+        let fixed_len = usize::from(self.pad()) + 16 - usize::from(self.cmpr_e());
+        (usize::from(self.slice[1]) * 8 - fixed_len) / (16 - usize::from(self.cmpr_i())) + 1
+    }
+
+    /// Decodes the address with the given index (starting at 0) by
+    /// prepending the elided prefix octets of the given IPv6 destination
+    /// address. Returns `None` if the index is out of range.
+    pub fn address(&self, index: usize, destination: &[u8; 16]) -> Option<[u8; 16]> {
+        let addresses_len = self.addresses_len();
+        if index >= addresses_len {
+            return None;
+        }
+        let cmpr_i = usize::from(self.cmpr_i());
+        let cmpr = if index + 1 == addresses_len {
+            usize::from(self.cmpr_e())
+        } else {
+            cmpr_i
+        };
+        let start = 8 + index * (16 - cmpr_i);
+        let mut result = *destination;
+        result[cmpr..].copy_from_slice(&self.slice[start..start + 16 - cmpr]);
+        Some(result)
+    }
+
+    /// Returns the final destination of the packet (last address of the header).
+    #[inline]
+    pub fn final_destination(&self, destination: &[u8; 16]) -> [u8; 16] {
+        // unwrap is safe as at least one address is always present
+        self.address(self.addresses_len() - 1, destination).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::err::ipv6_exts::RoutingHeaderError::*;
+
+    #[test]
+    fn from_raw_getters() {
+        // cmpr_i 14, cmpr_e 12 & pad 2 -> one address with 2 bytes, the
+        // last address with 4 bytes & 2 bytes padding
+        #[rustfmt::skip]
+        let bytes = [
+            ip_number::UDP.0, 1, 3, 2, 0xec, 0x20, 0, 0,
+            1, 2, 3, 4, 5, 6, 7, 8,
+        ];
+        let raw = Ipv6RawExtHeaderSlice::from_slice(&bytes).unwrap();
+        let header = Ipv6RplRoutingHeaderSlice::from_raw(&raw).unwrap();
+        assert_eq!(header.slice(), &bytes[..]);
+        assert_eq!(header.next_header(), ip_number::UDP);
+        assert_eq!(header.segments_left(), 2);
+        assert_eq!(header.cmpr_i(), 14);
+        assert_eq!(header.cmpr_e(), 12);
+        assert_eq!(header.pad(), 2);
+        assert_eq!(header.addresses_len(), 2);
+
+        let destination = [0xff; 16];
+        let mut expected = destination;
+        expected[14..].copy_from_slice(&[1, 2]);
+        assert_eq!(Some(expected), header.address(0, &destination));
+        expected[12..].copy_from_slice(&[3, 4, 5, 6]);
+        assert_eq!(Some(expected), header.address(1, &destination));
+        assert_eq!(expected, header.final_destination(&destination));
+        assert_eq!(None, header.address(2, &destination));
+        assert_eq!(header.clone(), header);
+    }
+
+    #[test]
+    fn from_raw_errors() {
+        // routing type
+        {
+            let bytes = [0, 0, 4, 0, 0, 0, 0, 0];
+            let raw = Ipv6RawExtHeaderSlice::from_slice(&bytes).unwrap();
+            assert_eq!(
+                Err(UnexpectedRoutingType {
+                    expected: Ipv6RoutingType::RPL_SOURCE_ROUTE,
+                    actual: Ipv6RoutingType::SEGMENT_ROUTING
+                }),
+                Ipv6RplRoutingHeaderSlice::from_raw(&raw)
+            );
+        }
+        // address layout
+        for (hdr_ext_len, cmpr, pad) in [(0, 0x00, 0x00), (2, 0xd8, 0x00), (1, 0xff, 0x80)] {
+            let mut bytes = [0u8; 24];
+            bytes[1] = hdr_ext_len;
+            bytes[2] = 3;
+            bytes[4] = cmpr;
+            bytes[5] = pad;
+            let raw = Ipv6RawExtHeaderSlice::from_slice(&bytes).unwrap();
+            assert_eq!(
+                Err(InvalidRplAddressLayout {
+                    cmpr_i: cmpr >> 4,
+                    cmpr_e: cmpr & 0xf,
+                    pad: pad >> 4,
+                    hdr_ext_len
+                }),
+                Ipv6RplRoutingHeaderSlice::from_raw(&raw)
+            );
+        }
+    }
+}
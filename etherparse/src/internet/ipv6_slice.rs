@@ -1,5 +1,8 @@
 use crate::err::{ipv6::SliceError, Layer, LenError, LenSource};
-use crate::{IpPayload, Ipv6ExtensionsSlice, Ipv6Header, Ipv6HeaderSlice};
+use crate::{
+    ip_number, IpNumber, IpPayload, Ipv6ExtensionsSlice, Ipv6Header, Ipv6HeaderSlice,
+    Ipv6RawExtHeaderSlice,
+};
 
 /// Slice containing the IPv6 headers & payload.
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -11,7 +14,8 @@ pub struct Ipv6Slice<'a> {
 
 impl<'a> Ipv6Slice<'a> {
     /// Decode IPv6 header, extension headers and determine the payload
-    /// length based on the `payload_length` field in the IPv6 header.
+    /// length based on the `payload_length` field in the IPv6 header
+    /// (or the "Jumbo Payload" option in case of jumbograms).
     pub fn from_slice(slice: &'a [u8]) -> Result<Ipv6Slice<'a>, SliceError> {
         // try reading the header
         let header = Ipv6HeaderSlice::from_slice(slice).map_err(|err| {
@@ -24,44 +28,8 @@ impl<'a> Ipv6Slice<'a> {
 
         // restrict slice by the length specified in the header
         let (header_payload, len_source) =
-            if 0 == header.payload_length() && slice.len() > Ipv6Header::LEN {
-                // In case the payload_length is 0 assume that the entire
-                // rest of the slice is part of the packet until the jumbogram
-                // parameters can be parsed.
-
-                // TODO: Add payload length parsing from the jumbogram
-                (
-                    unsafe {
-                        core::slice::from_raw_parts(
-                            slice.as_ptr().add(Ipv6Header::LEN),
-                            slice.len() - Ipv6Header::LEN,
-                        )
-                    },
-                    LenSource::Slice,
-                )
-            } else {
-                let payload_len = usize::from(header.payload_length());
-                let expected_len = Ipv6Header::LEN + payload_len;
-                if slice.len() < expected_len {
-                    return Err(SliceError::Len(LenError {
-                        required_len: expected_len,
-                        len: slice.len(),
-                        len_source: LenSource::Slice,
-                        layer: Layer::Ipv6Packet,
-                        layer_start_offset: 0,
-                    }));
-                } else {
-                    (
-                        unsafe {
-                            core::slice::from_raw_parts(
-                                slice.as_ptr().add(Ipv6Header::LEN),
-                                payload_len,
-                            )
-                        },
-                        LenSource::Ipv6HeaderPayloadLen,
-                    )
-                }
-            };
+            Ipv6Slice::header_payload(header.payload_length(), header.next_header(), slice)
+                .map_err(SliceError::Len)?;
 
         // parse extension headers
         let (exts, payload_ip_number, payload) =
@@ -71,7 +39,7 @@ impl<'a> Ipv6Slice<'a> {
                     use crate::err::ipv6_exts::HeaderSliceError::*;
                     match err {
                         Len(mut err) => {
-                            err.len_source = LenSource::Ipv6HeaderPayloadLen;
+                            err.len_source = len_source;
                             err.layer_start_offset += Ipv6Header::LEN;
                             SliceError::Len(err)
                         }
@@ -93,6 +61,55 @@ impl<'a> Ipv6Slice<'a> {
         })
     }
 
+    /// Returns the data after the IPv6 header (starting at the first
+    /// extension header) restricted to the length given by the "payload
+    /// length" field & the source of the length.
+    ///
+    /// In case the "payload length" is zero the length is taken from a
+    /// "Jumbo Payload" option (RFC 2675) in the hop-by-hop options header.
+    /// If no such option is present the rest of the slice is assumed to
+    /// be part of the packet.
+    ///
+    /// `slice` must start with the IPv6 header & have at least the length
+    /// [`Ipv6Header::LEN`].
+    pub(crate) fn header_payload(
+        payload_length: u16,
+        next_header: IpNumber,
+        slice: &'a [u8],
+    ) -> Result<(&'a [u8], LenSource), LenError> {
+        let rest = &slice[Ipv6Header::LEN..];
+        let (payload_len, len_source) = if 0 == payload_length && false == rest.is_empty() {
+            let jumbo_payload_len = if ip_number::IPV6_HOP_BY_HOP == next_header {
+                Ipv6RawExtHeaderSlice::from_slice(rest)
+                    .ok()
+                    .and_then(|hop_by_hop| hop_by_hop.jumbo_payload_len())
+            } else {
+                None
+            };
+            match jumbo_payload_len {
+                Some(len) => (len as usize, LenSource::Ipv6JumboPayloadLen),
+                // In case the payload_length is 0 and no jumbo payload
+                // option is present assume that the entire rest of the
+                // slice is part of the packet.
+                None => return Ok((rest, LenSource::Slice)),
+            }
+        } else {
+            (usize::from(payload_length), LenSource::Ipv6HeaderPayloadLen)
+        };
+
+        if rest.len() < payload_len {
+            Err(LenError {
+                required_len: Ipv6Header::LEN + payload_len,
+                len: slice.len(),
+                len_source: LenSource::Slice,
+                layer: Layer::Ipv6Packet,
+                layer_start_offset: 0,
+            })
+        } else {
+            Ok((&rest[..payload_len], len_source))
+        }
+    }
+
     /// Returns a slice containing the IPv6 header.
     #[inline]
     pub fn header(&self) -> Ipv6HeaderSlice<'a> {
@@ -450,4 +467,85 @@ mod test {
                 .is_payload_fragmented());
         }
     }
+
+    #[test]
+    fn from_slice_jumbogram() {
+        use crate::{err::ipv6::SliceError::Len, ip_number::IPV6_HOP_BY_HOP, Ipv6RawExtHeader};
+
+        let payload = [1, 2, 3, 4];
+        let build = |jumbo_len: Option<u32>| {
+            let hop_by_hop = {
+                let mut h = Ipv6RawExtHeader::try_from_options(UDP, &[]).unwrap();
+                if let Some(jumbo_len) = jumbo_len {
+                    h.set_jumbo_payload_len(jumbo_len).unwrap();
+                }
+                h
+            };
+            let ipv6 = Ipv6Header {
+                payload_length: 0,
+                next_header: IPV6_HOP_BY_HOP,
+                ..Default::default()
+            };
+            let mut data = Vec::new();
+            data.extend_from_slice(&ipv6.to_bytes());
+            data.extend_from_slice(&hop_by_hop.to_bytes());
+            data.extend_from_slice(&payload);
+            // trailing data that is not part of the packet
+            data.extend_from_slice(&[5, 6]);
+            data
+        };
+
+        // length from the jumbo payload option
+        {
+            let data = build(Some(16 + 4));
+            let actual = Ipv6Slice::from_slice(&data).unwrap();
+            assert_eq!(
+                actual.payload(),
+                &IpPayload {
+                    ip_number: UDP,
+                    fragmented: false,
+                    len_source: LenSource::Ipv6JumboPayloadLen,
+                    payload: &payload,
+                }
+            );
+        }
+
+        // jumbo payload option bigger than the slice
+        {
+            let data = build(Some(16 + 7));
+            assert_eq!(
+                Ipv6Slice::from_slice(&data).unwrap_err(),
+                Len(LenError {
+                    required_len: Ipv6Header::LEN + 16 + 7,
+                    len: data.len(),
+                    len_source: LenSource::Slice,
+                    layer: Layer::Ipv6Packet,
+                    layer_start_offset: 0,
+                })
+            );
+        }
+
+        // jumbo payload option too small for the extension headers
+        {
+            let data = build(Some(8));
+            assert_eq!(
+                Ipv6Slice::from_slice(&data).unwrap_err(),
+                Len(LenError {
+                    required_len: 16,
+                    len: 8,
+                    len_source: LenSource::Ipv6JumboPayloadLen,
+                    layer: Layer::Ipv6ExtHeader,
+                    layer_start_offset: Ipv6Header::LEN,
+                })
+            );
+        }
+
+        // no jumbo payload option (fallback to the slice length)
+        {
+            let data = build(None);
+            let actual = Ipv6Slice::from_slice(&data).unwrap();
+            assert_eq!(LenSource::Slice, actual.payload().len_source);
+            assert_eq!(&[1, 2, 3, 4, 5, 6], actual.payload().payload);
+        }
+    }
 }
@@ -0,0 +1,217 @@
+use crate::{err::ipv6_exts::RoutingHeaderError, *};
+use core::slice::from_raw_parts;
+
+/// Slice containing an IPv6 Segment Routing Header (SRH, routing type 4,
+/// RFC 8754) as used by SRv6.
+///
+/// Note that the segment list is stored in reverse order. The first
+/// entry (`segments()[0]`) is the final destination of the packet and
+/// the entry referenced by "segments left" is the currently active
+/// segment.
+///
+/// # Example
+///
+/// ```
+/// use etherparse::{ip_number, Ipv6RawExtHeader, Ipv6RawExtHeaderSlice, Ipv6SrhSlice};
+///
+/// let header = Ipv6RawExtHeader::try_from_srh(
+///     ip_number::UDP,
+///     1, // segments left
+///     0, // flags
+///     0, // tag
+///     &[[1; 16], [2; 16]],
+///     &[],
+/// ).unwrap();
+/// let bytes = header.to_bytes();
+///
+/// let raw = Ipv6RawExtHeaderSlice::from_slice(&bytes).unwrap();
+/// let srh = Ipv6SrhSlice::from_raw(&raw).unwrap();
+/// assert_eq!(srh.segments_left(), 1);
+/// assert_eq!(srh.last_entry(), 1);
+/// assert_eq!(srh.active_segment(), Some([2; 16]));
+/// assert_eq!(srh.final_destination(), [1; 16]);
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Ipv6SrhSlice<'a> {
+    /// Slice containing the complete header.
+    slice: &'a [u8],
+}
+
+impl<'a> Ipv6SrhSlice<'a> {
+    /// Minimum length of a segment routing header (with one segment)
+    /// in bytes/octets.
+    pub const MIN_LEN: usize = 8 + 16;
+
+    /// Creates a segment routing header slice from a raw IPv6 extension
+    /// header slice and verifies the routing type & "last entry" field.
+    pub fn from_raw(
+        raw: &Ipv6RawExtHeaderSlice<'a>,
+    ) -> Result<Ipv6SrhSlice<'a>, RoutingHeaderError> {
+        use RoutingHeaderError::*;
+
+        let slice = raw.slice();
+        let routing_type = Ipv6RoutingType(slice[2]);
+        if Ipv6RoutingType::SEGMENT_ROUTING != routing_type {
+            return Err(UnexpectedRoutingType {
+                expected: Ipv6RoutingType::SEGMENT_ROUTING,
+                actual: routing_type,
+            });
+        }
+        let last_entry = slice[4];
+        if 8 + (usize::from(last_entry) + 1) * 16 > slice.len() {
+            return Err(InvalidLastEntry {
+                last_entry,
+                hdr_ext_len: slice[1],
+            });
+        }
+        Ok(Ipv6SrhSlice { slice })
+    }
+
+    /// Returns the slice containing the complete header.
+    #[inline]
+    pub fn slice(&self) -> &'a [u8] {
+        self.slice
+    }
+
+    /// Returns the IP protocol number of the next header or transport layer protocol.
+    #[inline]
+    pub fn next_header(&self) -> IpNumber {
+        IpNumber(self.slice[0])
+    }
+
+    /// Returns the "segments left" field (index of the currently active
+    /// segment in the segment list).
+    #[inline]
+    pub fn segments_left(&self) -> u8 {
+        self.slice[3]
+    }
+
+    /// Returns the "last entry" field (index of the last element of the
+    /// segment list).
+    #[inline]
+    pub fn last_entry(&self) -> u8 {
+        self.slice[4]
+    }
+
+    /// Returns the flags of the segment routing header.
+    #[inline]
+    pub fn flags(&self) -> u8 {
+        self.slice[5]
+    }
+
+    /// Returns the tag of the segment routing header.
+    #[inline]
+    pub fn tag(&self) -> u16 {
+        u16::from_be_bytes([self.slice[6], self.slice[7]])
+    }
+
+    /// Returns the segment list (IPv6 addresses in reverse order of
+    /// traversal).
+    #[inline]
+    pub fn segments(&self) -> &'a [[u8; 16]] {
+        // SAFETY: Safe as [u8; 16] has an alignment of 1 & the length
+        // of the segment list was verified in the constructor.
+        unsafe {
+            from_raw_parts(
+                self.slice.as_ptr().add(8) as *const [u8; 16],
+                usize::from(self.last_entry()) + 1,
+            )
+        }
+    }
+
+    /// Returns the final destination of the packet (first element of
+    /// the segment list).
+    #[inline]
+    pub fn final_destination(&self) -> [u8; 16] {
+        self.segments()[0]
+    }
+
+    /// Returns the currently active segment (segment referenced by the
+    /// "segments left" field) or `None` if "segments left" points
+    /// outside of the segment list.
+    #[inline]
+    pub fn active_segment(&self) -> Option<[u8; 16]> {
+        self.segments()
+            .get(usize::from(self.segments_left()))
+            .copied()
+    }
+
+    /// Returns an iterator over the TLVs after the segment list.
+    #[inline]
+    pub fn tlvs(&self) -> Ipv6SrhTlvsIterator<'a> {
+        Ipv6SrhTlvsIterator::from_slice(&self.slice[8 + self.segments().len() * 16..])
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::err::ipv6_exts::RoutingHeaderError::*;
+
+    #[test]
+    fn from_raw_getters() {
+        #[rustfmt::skip]
+        let bytes = [
+            ip_number::UDP.0, 5, 4, 1, 1, 0x80, 0x12, 0x34,
+            1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
+            ipv6_srh_tlv::TYPE_PADN, 6, 0, 0, 0, 0, 0, 0,
+        ];
+        let raw = Ipv6RawExtHeaderSlice::from_slice(&bytes).unwrap();
+        let srh = Ipv6SrhSlice::from_raw(&raw).unwrap();
+        assert_eq!(srh.slice(), &bytes[..]);
+        assert_eq!(srh.next_header(), ip_number::UDP);
+        assert_eq!(srh.segments_left(), 1);
+        assert_eq!(srh.last_entry(), 1);
+        assert_eq!(srh.flags(), 0x80);
+        assert_eq!(srh.tag(), 0x1234);
+        assert_eq!(srh.segments(), &[[1; 16], [2; 16]]);
+        assert_eq!(srh.final_destination(), [1; 16]);
+        assert_eq!(srh.active_segment(), Some([2; 16]));
+        assert_eq!(
+            srh.tlvs().collect::<alloc::vec::Vec<_>>(),
+            [Ok(Ipv6SrhTlv::PadN(6))]
+        );
+        assert_eq!(srh.clone(), srh);
+
+        // segments left outside of the segment list
+        {
+            let mut bytes = bytes;
+            bytes[3] = 2;
+            let raw = Ipv6RawExtHeaderSlice::from_slice(&bytes).unwrap();
+            assert_eq!(None, Ipv6SrhSlice::from_raw(&raw).unwrap().active_segment());
+        }
+    }
+
+    #[test]
+    fn from_raw_errors() {
+        // routing type
+        {
+            let bytes = [0, 0, 2, 0, 0, 0, 0, 0];
+            let raw = Ipv6RawExtHeaderSlice::from_slice(&bytes).unwrap();
+            assert_eq!(
+                Err(UnexpectedRoutingType {
+                    expected: Ipv6RoutingType::SEGMENT_ROUTING,
+                    actual: Ipv6RoutingType::TYPE2
+                }),
+                Ipv6SrhSlice::from_raw(&raw)
+            );
+        }
+        // last entry
+        for (hdr_ext_len, last_entry) in [(0, 0), (2, 1), (4, 2)] {
+            let bytes = [0u8; 8 * 5];
+            let mut bytes = bytes;
+            bytes[1] = hdr_ext_len;
+            bytes[2] = 4;
+            bytes[4] = last_entry;
+            let raw = Ipv6RawExtHeaderSlice::from_slice(&bytes).unwrap();
+            assert_eq!(
+                Err(InvalidLastEntry {
+                    last_entry,
+                    hdr_ext_len
+                }),
+                Ipv6SrhSlice::from_raw(&raw)
+            );
+        }
+    }
+}
@@ -0,0 +1,118 @@
+/// TLVs ("type length value" elements) that can be present after the
+/// segment list of an IPv6 segment routing header (RFC 8754).
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum Ipv6SrhTlv<'a> {
+    /// "Pad1" TLV (type 0) representing a single byte of padding.
+    Pad1,
+
+    /// "PadN" TLV (type 4) representing two or more bytes of padding.
+    ///
+    /// The contained value is the data length (the number of padding
+    /// bytes after the type & length fields).
+    PadN(u8),
+
+    /// "HMAC" TLV (type 5).
+    Hmac {
+        /// "D" flag. If set the destination address verification is disabled.
+        d_flag: bool,
+        /// Identifier of the pre-shared key & algorithm used to calculate
+        /// the HMAC.
+        key_id: u32,
+        /// HMAC value (length is a multiple of 8 bytes).
+        hmac: &'a [u8],
+    },
+
+    /// TLV with a type not explicitly supported by etherparse.
+    Unknown {
+        /// Type of the TLV (first byte of the TLV).
+        tlv_type: u8,
+        /// Data of the TLV after the type & length bytes.
+        data: &'a [u8],
+    },
+}
+
+impl<'a> Ipv6SrhTlv<'a> {
+    /// Returns the type number of the TLV (first byte of the TLV).
+    pub fn tlv_type(&self) -> u8 {
+        use crate::ipv6_srh_tlv::*;
+        use Ipv6SrhTlv::*;
+        match self {
+            Pad1 => TYPE_PAD1,
+            PadN(_) => TYPE_PADN,
+            Hmac { .. } => TYPE_HMAC,
+            Unknown { tlv_type, .. } => *tlv_type,
+        }
+    }
+
+    /// Returns the length of the serialized TLV in bytes (including
+    /// the type & length bytes).
+    pub fn header_len(&self) -> usize {
+        use crate::ipv6_srh_tlv::*;
+        use Ipv6SrhTlv::*;
+        match self {
+            Pad1 => 1,
+            PadN(len) => 2 + usize::from(*len),
+            Hmac { hmac, .. } => 2 + usize::from(MIN_LEN_HMAC) + hmac.len(),
+            Unknown { data, .. } => 2 + data.len(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+    use alloc::format;
+
+    #[test]
+    fn clone_eq_debug() {
+        use Ipv6SrhTlv::*;
+        let value = Hmac {
+            d_flag: true,
+            key_id: 1,
+            hmac: &[2; 8],
+        };
+        assert_eq!(value, value.clone());
+        assert_eq!("PadN(2)", format!("{:?}", PadN(2)));
+        assert_eq!(
+            "Unknown { tlv_type: 9, data: [1] }",
+            format!(
+                "{:?}",
+                Unknown {
+                    tlv_type: 9,
+                    data: &[1]
+                }
+            )
+        );
+    }
+
+    #[test]
+    fn tlv_type_header_len() {
+        use ipv6_srh_tlv::*;
+        use Ipv6SrhTlv::*;
+        let tests = [
+            (Pad1, TYPE_PAD1, 1),
+            (PadN(3), TYPE_PADN, 5),
+            (
+                Hmac {
+                    d_flag: false,
+                    key_id: 1,
+                    hmac: &[0; 32],
+                },
+                TYPE_HMAC,
+                40,
+            ),
+            (
+                Unknown {
+                    tlv_type: 9,
+                    data: &[1, 2],
+                },
+                9,
+                4,
+            ),
+        ];
+        for (tlv, tlv_type, header_len) in tests {
+            assert_eq!(tlv_type, tlv.tlv_type());
+            assert_eq!(header_len, tlv.header_len());
+        }
+    }
+}
@@ -0,0 +1,13 @@
+/// Module containing the constants for the TLVs that can be present in
+/// an IPv6 segment routing header (RFC 8754).
+pub mod ipv6_srh_tlv {
+    /// `u8` identifying a "Pad1" SRH TLV (single byte of padding).
+    pub const TYPE_PAD1: u8 = 0;
+    /// `u8` identifying a "PadN" SRH TLV (two or more bytes of padding).
+    pub const TYPE_PADN: u8 = 4;
+    /// `u8` identifying a "HMAC" SRH TLV.
+    pub const TYPE_HMAC: u8 = 5;
+    /// Minimum data length in octets/bytes of the "HMAC" SRH TLV (the
+    /// "D" flag, reserved bits & HMAC key ID without the HMAC itself).
+    pub const MIN_LEN_HMAC: u8 = 6;
+}
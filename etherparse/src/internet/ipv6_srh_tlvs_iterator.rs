@@ -0,0 +1,231 @@
+use crate::*;
+
+/// Allows iterating over the TLVs after the segment list of an IPv6
+/// segment routing header (see [`Ipv6SrhSlice::tlvs`]).
+///
+/// In case an error is encountered the error is returned and the
+/// iteration is stopped. As the TLVs share the encoding of IPv6
+/// options the errors are reported as [`err::ipv6_exts::OptionReadError`]
+/// (with the TLV type as `option_type`).
+#[derive(Clone, Eq, PartialEq)]
+pub struct Ipv6SrhTlvsIterator<'a> {
+    pub(crate) tlvs: &'a [u8],
+}
+
+impl<'a> Ipv6SrhTlvsIterator<'a> {
+    /// Creates an iterator from a slice containing encoded SRH TLVs.
+    pub fn from_slice(tlvs: &'a [u8]) -> Ipv6SrhTlvsIterator<'a> {
+        Ipv6SrhTlvsIterator { tlvs }
+    }
+
+    /// Returns the non processed part of the TLVs slice.
+    pub fn rest(&self) -> &'a [u8] {
+        self.tlvs
+    }
+}
+
+impl<'a> Iterator for Ipv6SrhTlvsIterator<'a> {
+    type Item = Result<Ipv6SrhTlv<'a>, err::ipv6_exts::OptionReadError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        use err::ipv6_exts::OptionReadError::*;
+        use ipv6_srh_tlv::*;
+        use Ipv6SrhTlv::*;
+
+        let tlvs = self.tlvs;
+        let result = match tlvs.first() {
+            None => return None,
+            Some(&TYPE_PAD1) => Ok((Pad1, 1)),
+            Some(&tlv_type) => {
+                let min_data_len = if TYPE_HMAC == tlv_type {
+                    MIN_LEN_HMAC
+                } else {
+                    0
+                };
+                if tlvs.len() < 2 {
+                    Err(UnexpectedEndOfSlice {
+                        option_type: tlv_type,
+                        expected_len: 2 + usize::from(min_data_len),
+                        actual_len: tlvs.len(),
+                    })
+                } else {
+                    let data_len = tlvs[1];
+                    let len = 2 + usize::from(data_len);
+                    if TYPE_HMAC == tlv_type
+                        && (data_len < MIN_LEN_HMAC || !(data_len - MIN_LEN_HMAC).is_multiple_of(8))
+                    {
+                        Err(UnexpectedSize {
+                            option_type: tlv_type,
+                            size: data_len,
+                        })
+                    } else if tlvs.len() < len {
+                        Err(UnexpectedEndOfSlice {
+                            option_type: tlv_type,
+                            expected_len: len,
+                            actual_len: tlvs.len(),
+                        })
+                    } else {
+                        let data = &tlvs[2..len];
+                        let tlv = match tlv_type {
+                            TYPE_PADN => PadN(data_len),
+                            TYPE_HMAC => Hmac {
+                                d_flag: 0 != data[0] & 0x80,
+                                key_id: u32::from_be_bytes([data[2], data[3], data[4], data[5]]),
+                                hmac: &data[6..],
+                            },
+                            _ => Unknown { tlv_type, data },
+                        };
+                        Ok((tlv, len))
+                    }
+                }
+            }
+        };
+
+        match result {
+            Ok((tlv, len)) => {
+                self.tlvs = &tlvs[len..];
+                Some(Ok(tlv))
+            }
+            Err(err) => {
+                // stop the iteration after an error
+                self.tlvs = &tlvs[tlvs.len()..];
+                Some(Err(err))
+            }
+        }
+    }
+}
+
+impl<'a> core::fmt::Debug for Ipv6SrhTlvsIterator<'a> {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
+        let mut list = fmt.debug_list();
+
+        // create a copy and iterate over all elements
+        for it in self.clone() {
+            match it {
+                Ok(e) => {
+                    list.entry(&e);
+                }
+                Err(e) => {
+                    list.entry(&Result::<(), err::ipv6_exts::OptionReadError>::Err(e));
+                }
+            }
+        }
+
+        list.finish()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::err::ipv6_exts::OptionReadError::*;
+    use alloc::{format, vec::Vec};
+    use ipv6_srh_tlv::*;
+    use Ipv6SrhTlv::*;
+
+    #[test]
+    fn from_slice_rest() {
+        let data = [1, 2, 3, 4];
+        let iter = Ipv6SrhTlvsIterator::from_slice(&data);
+        assert_eq!(&data[..], iter.rest());
+    }
+
+    #[test]
+    fn next() {
+        // empty
+        assert_eq!(None, Ipv6SrhTlvsIterator::from_slice(&[]).next());
+
+        // all supported tlvs
+        {
+            #[rustfmt::skip]
+            let data = [
+                TYPE_PAD1,
+                TYPE_HMAC, 14, 0x80, 0, 0, 0, 0, 7, 1, 2, 3, 4, 5, 6, 7, 8,
+                9, 1, 10,
+                TYPE_PADN, 2, 0, 0,
+            ];
+            let mut iter = Ipv6SrhTlvsIterator::from_slice(&data);
+            let actual: Vec<_> = iter.by_ref().collect();
+            assert_eq!(
+                actual,
+                [
+                    Ok(Pad1),
+                    Ok(Hmac {
+                        d_flag: true,
+                        key_id: 7,
+                        hmac: &[1, 2, 3, 4, 5, 6, 7, 8]
+                    }),
+                    Ok(Unknown {
+                        tlv_type: 9,
+                        data: &[10]
+                    }),
+                    Ok(PadN(2)),
+                ]
+            );
+            assert!(iter.rest().is_empty());
+        }
+
+        // length field missing
+        for (tlv_type, expected_len) in [(TYPE_PADN, 2), (TYPE_HMAC, 8), (9, 2)] {
+            let data = [tlv_type];
+            let mut iter = Ipv6SrhTlvsIterator::from_slice(&data);
+            assert_eq!(
+                Some(Err(UnexpectedEndOfSlice {
+                    option_type: tlv_type,
+                    expected_len,
+                    actual_len: 1,
+                })),
+                iter.next()
+            );
+            assert_eq!(None, iter.next());
+        }
+
+        // bad hmac sizes
+        for size in [5, 7, 13] {
+            let data = [TYPE_HMAC, size, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+            let mut iter = Ipv6SrhTlvsIterator::from_slice(&data);
+            assert_eq!(
+                Some(Err(UnexpectedSize {
+                    option_type: TYPE_HMAC,
+                    size
+                })),
+                iter.next()
+            );
+            assert_eq!(None, iter.next());
+        }
+
+        // not enough data
+        {
+            let data = [TYPE_PADN, 3, 0];
+            let mut iter = Ipv6SrhTlvsIterator::from_slice(&data);
+            assert_eq!(
+                Some(Err(UnexpectedEndOfSlice {
+                    option_type: TYPE_PADN,
+                    expected_len: 5,
+                    actual_len: 3,
+                })),
+                iter.next()
+            );
+            assert_eq!(None, iter.next());
+        }
+    }
+
+    #[test]
+    fn clone_eq_debug() {
+        let data = [TYPE_PAD1, TYPE_PADN];
+        let iter = Ipv6SrhTlvsIterator::from_slice(&data);
+        assert_eq!(iter, iter.clone());
+        assert_eq!(
+            format!(
+                "[{:?}, {:?}]",
+                Pad1,
+                Result::<(), err::ipv6_exts::OptionReadError>::Err(UnexpectedEndOfSlice {
+                    option_type: TYPE_PADN,
+                    expected_len: 2,
+                    actual_len: 1
+                })
+            ),
+            format!("{:?}", iter)
+        );
+    }
+}
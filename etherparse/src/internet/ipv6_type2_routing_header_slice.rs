@@ -0,0 +1,121 @@
+use crate::{err::ipv6_exts::RoutingHeaderError, *};
+
+/// Slice containing an IPv6 "Type 2 Routing Header" (routing type 2,
+/// RFC 6275) used by Mobile IPv6 to carry the home address of a mobile
+/// node.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Ipv6Type2RoutingHeaderSlice<'a> {
+    /// Slice containing the complete header.
+    slice: &'a [u8],
+}
+
+impl<'a> Ipv6Type2RoutingHeaderSlice<'a> {
+    /// Length of a type 2 routing header in bytes/octets.
+    pub const LEN: usize = 24;
+
+    /// Creates a type 2 routing header slice from a raw IPv6 extension
+    /// header slice and verifies the routing type & header length.
+    pub fn from_raw(
+        raw: &Ipv6RawExtHeaderSlice<'a>,
+    ) -> Result<Ipv6Type2RoutingHeaderSlice<'a>, RoutingHeaderError> {
+        use RoutingHeaderError::*;
+
+        let slice = raw.slice();
+        let routing_type = Ipv6RoutingType(slice[2]);
+        if Ipv6RoutingType::TYPE2 != routing_type {
+            return Err(UnexpectedRoutingType {
+                expected: Ipv6RoutingType::TYPE2,
+                actual: routing_type,
+            });
+        }
+        if 2 != slice[1] {
+            return Err(UnexpectedHdrExtLen {
+                routing_type,
+                hdr_ext_len: slice[1],
+            });
+        }
+        Ok(Ipv6Type2RoutingHeaderSlice { slice })
+    }
+
+    /// Returns the slice containing the complete header.
+    #[inline]
+    pub fn slice(&self) -> &'a [u8] {
+        self.slice
+    }
+
+    /// Returns the IP protocol number of the next header or transport layer protocol.
+    #[inline]
+    pub fn next_header(&self) -> IpNumber {
+        IpNumber(self.slice[0])
+    }
+
+    /// Returns the "segments left" field (`1` if the home address has
+    /// not yet been processed).
+    #[inline]
+    pub fn segments_left(&self) -> u8 {
+        self.slice[3]
+    }
+
+    /// Returns the home address of the mobile node.
+    #[inline]
+    pub fn home_address(&self) -> [u8; 16] {
+        let mut result = [0; 16];
+        result.copy_from_slice(&self.slice[8..24]);
+        result
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::err::ipv6_exts::RoutingHeaderError::*;
+
+    #[test]
+    fn from_raw_getters() {
+        #[rustfmt::skip]
+        let bytes = [
+            ip_number::UDP.0, 2, 2, 1, 0, 0, 0, 0,
+            1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16,
+        ];
+        let raw = Ipv6RawExtHeaderSlice::from_slice(&bytes).unwrap();
+        let header = Ipv6Type2RoutingHeaderSlice::from_raw(&raw).unwrap();
+        assert_eq!(header.slice(), &bytes[..]);
+        assert_eq!(header.next_header(), ip_number::UDP);
+        assert_eq!(header.segments_left(), 1);
+        assert_eq!(
+            header.home_address(),
+            [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16]
+        );
+        assert_eq!(header.clone(), header);
+    }
+
+    #[test]
+    fn from_raw_errors() {
+        // routing type
+        {
+            let bytes = [0, 0, 4, 0, 0, 0, 0, 0];
+            let raw = Ipv6RawExtHeaderSlice::from_slice(&bytes).unwrap();
+            assert_eq!(
+                Err(UnexpectedRoutingType {
+                    expected: Ipv6RoutingType::TYPE2,
+                    actual: Ipv6RoutingType::SEGMENT_ROUTING
+                }),
+                Ipv6Type2RoutingHeaderSlice::from_raw(&raw)
+            );
+        }
+        // header length
+        for hdr_ext_len in [0, 1, 3] {
+            let mut bytes = [0u8; 32];
+            bytes[1] = hdr_ext_len;
+            bytes[2] = 2;
+            let raw = Ipv6RawExtHeaderSlice::from_slice(&bytes).unwrap();
+            assert_eq!(
+                Err(UnexpectedHdrExtLen {
+                    routing_type: Ipv6RoutingType::TYPE2,
+                    hdr_ext_len
+                }),
+                Ipv6Type2RoutingHeaderSlice::from_raw(&raw)
+            );
+        }
+    }
+}
@@ -1,3 +1,13 @@
+pub mod arp_eth_ipv4_header;
+pub mod arp_header;
+pub mod arp_operation;
+pub mod arp_packet_slice;
+pub mod esp_header;
+pub mod esp_header_slice;
+pub mod esp_null_payload_slice;
+pub mod gre_header;
+pub mod gre_header_slice;
+pub mod hip_header_slice;
 pub mod internet_slice;
 pub mod ip_auth_header;
 pub mod ip_auth_header_slice;
@@ -11,8 +21,13 @@ pub mod ipv4_exts;
 pub mod ipv4_exts_slice;
 pub mod ipv4_header;
 pub mod ipv4_header_slice;
+pub mod ipv4_option_element;
+pub mod ipv4_option_impl;
 pub mod ipv4_options;
+pub mod ipv4_options_iterator;
 pub mod ipv4_slice;
+pub mod ipv6_ext_chain;
+pub mod ipv6_ext_header;
 pub mod ipv6_ext_slice;
 pub mod ipv6_ext_slice_iter;
 pub mod ipv6_exts;
@@ -22,7 +37,27 @@ pub mod ipv6_fragment_header;
 pub mod ipv6_fragment_header_slice;
 pub mod ipv6_header;
 pub mod ipv6_header_slice;
+pub mod ipv6_mobility_binding_ack_slice;
+pub mod ipv6_mobility_binding_update_slice;
+pub mod ipv6_mobility_header_slice;
+pub mod ipv6_mobility_header_type;
+pub mod ipv6_mobility_option;
+pub mod ipv6_mobility_option_impl;
+pub mod ipv6_mobility_options_iterator;
+pub mod ipv6_option_element;
+pub mod ipv6_option_impl;
+pub mod ipv6_option_unknown_action;
+pub mod ipv6_options_iterator;
 pub mod ipv6_raw_ext_header;
 pub mod ipv6_raw_ext_header_slice;
 pub mod ipv6_routing_exts;
+pub mod ipv6_routing_header_slice;
+pub mod ipv6_routing_type;
+pub mod ipv6_rpl_routing_header_slice;
 pub mod ipv6_slice;
+pub mod ipv6_srh_slice;
+pub mod ipv6_srh_tlv;
+pub mod ipv6_srh_tlv_impl;
+pub mod ipv6_srh_tlvs_iterator;
+pub mod ipv6_type2_routing_header_slice;
+pub mod shim6_header_slice;
@@ -0,0 +1,159 @@
+use crate::*;
+
+/// Slice containing a Shim6 header (RFC 5533).
+///
+/// Shim6 headers come in two formats, distinguished by the "P" bit:
+///
+/// ```text
+/// Payload extension header (P = 1):
+///  0               1               2               3
+/// +---------------+---------------+-+-----------------------------+
+/// |  Next Header  | Hdr Ext Len=0 |1|                             |
+/// +---------------+---------------+-+                             +
+/// |                Receiver Context Tag (47 bits)                 |
+/// +---------------------------------------------------------------+
+///
+/// Control message (P = 0):
+///  0               1               2               3
+/// +---------------+---------------+-+-------------+-------------+-+
+/// |  Next Header  |  Hdr Ext Len  |0|    Type     |Type-specific|0|
+/// +---------------+---------------+-+-------------+-------------+-+
+/// |           Checksum            |                               |
+/// +-------------------------------+                               +
+/// |                    Type-specific format ...                   |
+/// +---------------------------------------------------------------+
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Shim6HeaderSlice<'a> {
+    /// Slice containing the complete header.
+    slice: &'a [u8],
+}
+
+impl<'a> Shim6HeaderSlice<'a> {
+    /// Creates a Shim6 header slice from a raw IPv6 extension header slice.
+    #[inline]
+    pub fn from_raw(raw: &Ipv6RawExtHeaderSlice<'a>) -> Shim6HeaderSlice<'a> {
+        Shim6HeaderSlice { slice: raw.slice() }
+    }
+
+    /// Returns the slice containing the complete header.
+    #[inline]
+    pub fn slice(&self) -> &'a [u8] {
+        self.slice
+    }
+
+    /// Returns the IP protocol number of the next header or transport layer protocol.
+    #[inline]
+    pub fn next_header(&self) -> IpNumber {
+        IpNumber(self.slice[0])
+    }
+
+    /// Returns true if the header is a payload extension header ("P" bit
+    /// set) and false if it contains a control message.
+    #[inline]
+    pub fn is_payload_extension(&self) -> bool {
+        0 != self.slice[2] & 0x80
+    }
+
+    /// Returns the 47 bit "receiver context tag" if the header is a
+    /// payload extension header.
+    pub fn receiver_context_tag(&self) -> Option<u64> {
+        if self.is_payload_extension() {
+            let s = self.slice;
+            Some(u64::from_be_bytes([
+                0,
+                0,
+                s[2] & 0x7f,
+                s[3],
+                s[4],
+                s[5],
+                s[6],
+                s[7],
+            ]))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the message type if the header contains a control message.
+    #[inline]
+    pub fn msg_type(&self) -> Option<u8> {
+        if self.is_payload_extension() {
+            None
+        } else {
+            Some(self.slice[2] & 0x7f)
+        }
+    }
+
+    /// Returns the 7 bit "type-specific" field of the first header word
+    /// if the header contains a control message.
+    #[inline]
+    pub fn type_specific(&self) -> Option<u8> {
+        if self.is_payload_extension() {
+            None
+        } else {
+            Some(self.slice[3] >> 1)
+        }
+    }
+
+    /// Returns the "checksum" field if the header contains a control message.
+    #[inline]
+    pub fn checksum(&self) -> Option<u16> {
+        if self.is_payload_extension() {
+            None
+        } else {
+            Some(u16::from_be_bytes([self.slice[4], self.slice[5]]))
+        }
+    }
+
+    /// Returns the type specific message data (everything after the
+    /// "checksum" field) if the header contains a control message.
+    #[inline]
+    pub fn message_data(&self) -> Option<&'a [u8]> {
+        if self.is_payload_extension() {
+            None
+        } else {
+            Some(&self.slice[6..])
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn payload_extension() {
+        let bytes = [ip_number::TCP.0, 0, 0x81, 2, 3, 4, 5, 6];
+        let raw = Ipv6RawExtHeaderSlice::from_slice(&bytes).unwrap();
+        let header = Shim6HeaderSlice::from_raw(&raw);
+        assert_eq!(header.slice(), &bytes[..]);
+        assert_eq!(header.next_header(), ip_number::TCP);
+        assert!(header.is_payload_extension());
+        assert_eq!(header.receiver_context_tag(), Some(0x0102_0304_0506));
+        assert_eq!(header.msg_type(), None);
+        assert_eq!(header.type_specific(), None);
+        assert_eq!(header.checksum(), None);
+        assert_eq!(header.message_data(), None);
+        assert_eq!(header.clone(), header);
+    }
+
+    #[test]
+    fn control_message() {
+        #[rustfmt::skip]
+        let bytes = [
+            ip_number::IPV6_NO_NEXT_HEADER.0, 1, 0x41, 0x06,
+            0x12, 0x34, 1, 2,
+            3, 4, 5, 6, 7, 8, 9, 10,
+        ];
+        let raw = Ipv6RawExtHeaderSlice::from_slice(&bytes).unwrap();
+        let header = Shim6HeaderSlice::from_raw(&raw);
+        assert_eq!(header.next_header(), ip_number::IPV6_NO_NEXT_HEADER);
+        assert!(false == header.is_payload_extension());
+        assert_eq!(header.receiver_context_tag(), None);
+        assert_eq!(header.msg_type(), Some(0x41));
+        assert_eq!(header.type_specific(), Some(3));
+        assert_eq!(header.checksum(), Some(0x1234));
+        assert_eq!(header.message_data(), Some(&bytes[6..]));
+    }
+}
@@ -2,9 +2,16 @@
 //!
 //! Currently supported are:
 //! * Ethernet II
+//! * Linux Cooked Capture (SLL & SLL2)
 //! * IEEE 802.1Q VLAN Tagging Header
+//! * IEEE 802.1AE MACsec SecTAG (slicing only)
+//! * IEEE 802.3 with IEEE 802.2 LLC & SNAP
+//! * MPLS
+//! * PPPoE & PPP
+//! * ARP
 //! * IPv4
 //! * IPv6 (supporting the most common extension headers, but not all)
+//! * GRE
 //! * UDP
 //! * TCP
 //! * ICMP & ICMPv6 (not all message types are supported)
@@ -64,6 +71,8 @@
 //! Depending from which point downward you want to slice a package check out the functions:
 //!
 //! * [`SlicedPacket::from_ethernet`] for parsing from an Ethernet II header downwards
+//! * [`SlicedPacket::from_ethernet_with_fcs`] for parsing an Ethernet II frame that still contains the frame check sequence (FCS)
+//! * [`SlicedPacket::from_linux_sll`] & [`SlicedPacket::from_linux_sll2`] for parsing from a Linux cooked capture header downwards
 //! * [`SlicedPacket::from_ether_type`] for parsing a slice starting after an Ethernet II header
 //! * [`SlicedPacket::from_ip`] for parsing from an IPv4 or IPv6 downwards
 //!
@@ -100,6 +109,7 @@
 //! Depending from which point downward you want to unpack a package check out the functions
 //!
 //! * [`PacketHeaders::from_ethernet_slice`] for parsing from an Ethernet II header downwards
+//! * [`PacketHeaders::from_linux_sll_slice`] & [`PacketHeaders::from_linux_sll2_slice`] for parsing from a Linux cooked capture header downwards
 //! * [`PacketHeaders::from_ether_type`] for parsing a slice starting after an Ethernet II header
 //! * [`PacketHeaders::from_ip_slice`] for parsing from an IPv4 or IPv6 downwards
 //!
@@ -109,8 +119,17 @@
 //! Have a look at the documentation for the \[NAME\]Slice.from_slice methods, if you want to create your own slices:
 //!
 //! * [`Ethernet2HeaderSlice::from_slice`]
+//! * [`LinuxSllHeaderSlice::from_slice`]
+//! * [`LinuxSll2HeaderSlice::from_slice`]
 //! * [`SingleVlanHeaderSlice::from_slice`]
 //! * [`DoubleVlanHeaderSlice::from_slice`]
+//! * [`VlanStackSlice::from_slice`]
+//! * [`LlcHeaderSlice::from_slice`]
+//! * [`SnapHeaderSlice::from_slice`]
+//! * [`MplsLabelStackSlice::from_slice`]
+//! * [`PppoeHeaderSlice::from_slice`]
+//! * [`PppHeaderSlice::from_slice`]
+//! * [`ArpPacketSlice::from_slice`]
 //! * [`Ipv4HeaderSlice::from_slice`]
 //! * [`Ipv4ExtensionsSlice::from_slice`]
 //! * [`Ipv6HeaderSlice::from_slice`]
@@ -118,6 +137,7 @@
 //! * [`Ipv6RawExtHeaderSlice::from_slice`]
 //! * [`IpAuthHeaderSlice::from_slice`]
 //! * [`Ipv6FragmentHeaderSlice::from_slice`]
+//! * [`GreHeaderSlice::from_slice`]
 //! * [`UdpHeaderSlice::from_slice`]
 //! * [`TcpHeaderSlice::from_slice`]
 //! * [`Icmpv4Slice::from_slice`]
@@ -126,8 +146,18 @@
 //! And for deserialization into the corresponding header structs have a look at:
 //!
 //! * [`Ethernet2Header::read`] & [`Ethernet2Header::from_slice`]
+//! * [`LinuxSllHeader::read`] & [`LinuxSllHeader::from_slice`]
+//! * [`LinuxSll2Header::read`] & [`LinuxSll2Header::from_slice`]
 //! * [`SingleVlanHeader::read`] & [`SingleVlanHeader::from_slice`]
 //! * [`DoubleVlanHeader::read`] & [`DoubleVlanHeader::from_slice`]
+//! * [`VlanStack::from_slice`]
+//! * [`LlcHeader::read`] & [`LlcHeader::from_slice`]
+//! * [`SnapHeader::read`] & [`SnapHeader::from_slice`]
+//! * [`MplsLabelStackEntry::read`] & [`MplsLabelStackEntry::from_slice`]
+//! * [`MplsLabelStack::from_slice`]
+//! * [`PppoeHeader::read`] & [`PppoeHeader::from_slice`]
+//! * [`PppHeader::read`] & [`PppHeader::from_slice`]
+//! * [`ArpHeader::read`] & [`ArpHeader::from_slice`]
 //! * [`IpHeader::read`] & [`IpHeader::from_slice`]
 //! * [`Ipv4Header::read`] & [`Ipv4Header::from_slice`]
 //! * [`Ipv4Extensions::read`] & [`Ipv4Extensions::from_slice`]
@@ -136,6 +166,7 @@
 //! * [`Ipv6RawExtHeader::read`] & [`Ipv6RawExtHeader::from_slice`]
 //! * [`IpAuthHeader::read`] & [`IpAuthHeader::from_slice`]
 //! * [`Ipv6FragmentHeader::read`] & [`Ipv6FragmentHeader::from_slice`]
+//! * [`GreHeader::from_slice`]
 //! * [`UdpHeader::read`] & [`UdpHeader::from_slice`]
 //! * [`TcpHeader::read`] & [`TcpHeader::from_slice`]
 //! * [`Icmpv4Header::read`] & [`Icmpv4Header::from_slice`]
@@ -180,8 +211,18 @@
 //! Read the documentations of the different methods for a more details:
 //!
 //! * [`Ethernet2Header::write`]
+//! * [`LinuxSllHeader::write`]
+//! * [`LinuxSll2Header::write`]
 //! * [`SingleVlanHeader::write`]
 //! * [`DoubleVlanHeader::write`]
+//! * [`VlanStack::write`]
+//! * [`LlcHeader::write`]
+//! * [`SnapHeader::write`]
+//! * [`MplsLabelStackEntry::write`]
+//! * [`MplsLabelStack::write`]
+//! * [`PppoeHeader::write`]
+//! * [`PppHeader::write`]
+//! * [`ArpHeader::write`]
 //! * [`Ipv4Header::write`]
 //! * [`Ipv4Header::write_raw`]
 //! * [`Ipv4Extensions::write`]
@@ -201,7 +242,6 @@
 //! * MutPacketSlice -> modifaction of fields in slices directly?
 //! * Reserializing SlicedPacket & MutSlicedPacket with corrected checksums & id's
 //! * Slicing & reading packet from different layers then ethernet onward (e.g. ip, vlan...)
-//! * IEEE 802.3
 //!
 //! # References
 //! * Darpa Internet Program Protocol Specification [RFC 791](https://tools.ietf.org/html/rfc791)
@@ -209,9 +249,15 @@
 //! * [IANA 802 EtherTypes](https://www.iana.org/assignments/ieee-802-numbers/ieee-802-numbers.xhtml)
 //! * [IANA Protocol Numbers](https://www.iana.org/assignments/protocol-numbers/protocol-numbers.xhtml)
 //! * [Internet Protocol Version 6 (IPv6) Parameters](https://www.iana.org/assignments/ipv6-parameters/ipv6-parameters.xhtml)
+//! * [Linux cooked capture encapsulation (SLL)](https://www.tcpdump.org/linktypes/LINKTYPE_LINUX_SLL.html) & [v2 (SLL2)](https://www.tcpdump.org/linktypes/LINKTYPE_LINUX_SLL2.html)
+//! * Subnetwork Access Protocol (SNAP) for IP over IEEE 802 networks [RFC 1042](https://tools.ietf.org/html/rfc1042)
+//! * MPLS Label Stack Encoding [RFC 3032](https://tools.ietf.org/html/rfc3032)
+//! * A Method for Transmitting PPP Over Ethernet (PPPoE) [RFC 2516](https://tools.ietf.org/html/rfc2516)
+//! * The Point-to-Point Protocol (PPP) [RFC 1661](https://tools.ietf.org/html/rfc1661)
 //! * [Wikipedia IEEE_802.1Q](https://en.wikipedia.org/w/index.php?title=IEEE_802.1Q&oldid=820983900)
 //! * User Datagram Protocol (UDP) [RFC 768](https://tools.ietf.org/html/rfc768)
 //! * Transmission Control Protocol [RFC 793](https://tools.ietf.org/html/rfc793)
+//! * An Ethernet Address Resolution Protocol [RFC 826](https://tools.ietf.org/html/rfc826)
 //! * TCP Extensions for High Performance [RFC 7323](https://tools.ietf.org/html/rfc7323)
 //! * The Addition of Explicit Congestion Notification (ECN) to IP [RFC 3168](https://tools.ietf.org/html/rfc3168)
 //! * Robust Explicit Congestion Notification (ECN) Signaling with Nonces [RFC 3540](https://tools.ietf.org/html/rfc3540)
@@ -259,23 +305,65 @@ extern crate std;
 pub mod err;
 
 mod link;
+pub use crate::link::arp_hardware_id::*;
 pub use crate::link::double_vlan_header::*;
 pub use crate::link::double_vlan_header_slice::*;
 pub use crate::link::ether_type_impl::*;
 pub use crate::link::ethernet2_header::*;
 pub use crate::link::ethernet2_header_slice::*;
+pub use crate::link::link_header::*;
 pub use crate::link::link_slice::*;
+pub use crate::link::linux_sll2_header::*;
+pub use crate::link::linux_sll2_header_slice::*;
+pub use crate::link::linux_sll_header::*;
+pub use crate::link::linux_sll_header_slice::*;
+pub use crate::link::linux_sll_packet_type::*;
+pub use crate::link::llc_header::*;
+pub use crate::link::llc_header_slice::*;
+pub use crate::link::mac_address::*;
+pub use crate::link::macsec_header_slice::*;
+pub use crate::link::mpls_label::*;
+pub use crate::link::mpls_label_stack::*;
+pub use crate::link::mpls_label_stack_entry::*;
+pub use crate::link::mpls_label_stack_entry_slice::*;
+pub use crate::link::mpls_label_stack_slice::*;
+pub use crate::link::mpls_label_stack_slice_iter::*;
+pub use crate::link::mpls_traffic_class::*;
+pub use crate::link::ppp_header::*;
+pub use crate::link::ppp_header_slice::*;
+pub use crate::link::ppp_protocol::*;
+pub use crate::link::pppoe_code::*;
+pub use crate::link::pppoe_header::*;
+pub use crate::link::pppoe_header_slice::*;
+pub use crate::link::pppoe_tag::*;
+pub use crate::link::pppoe_tag_type::*;
+pub use crate::link::pppoe_tags_iterator::*;
 pub use crate::link::single_vlan_header::*;
 pub use crate::link::single_vlan_header_slice::*;
+pub use crate::link::snap_header::*;
+pub use crate::link::snap_header_slice::*;
 pub use crate::link::vlan_header::*;
 pub use crate::link::vlan_id::*;
 pub use crate::link::vlan_pcp::*;
 pub use crate::link::vlan_slice::*;
+pub use crate::link::vlan_stack::*;
+pub use crate::link::vlan_stack_slice::*;
+pub use crate::link::vlan_stack_slice_iter::*;
 
 #[cfg(test)]
 pub(crate) mod test_gens;
 
 mod internet;
+pub use crate::internet::arp_eth_ipv4_header::*;
+pub use crate::internet::arp_header::*;
+pub use crate::internet::arp_operation::*;
+pub use crate::internet::arp_packet_slice::*;
+pub use crate::internet::esp_header::*;
+pub use crate::internet::esp_header_slice::*;
+pub use crate::internet::esp_null_payload_slice::*;
+pub use crate::internet::gre_header::*;
+pub use crate::internet::gre_header_slice::*;
+pub use crate::internet::hip_header_slice::*;
 pub use crate::internet::internet_slice::*;
 pub use crate::internet::ip_auth_header::*;
 pub use crate::internet::ip_auth_header_slice::*;
@@ -289,8 +377,13 @@ pub use crate::internet::ipv4_exts::*;
 pub use crate::internet::ipv4_exts_slice::*;
 pub use crate::internet::ipv4_header::*;
 pub use crate::internet::ipv4_header_slice::*;
+pub use crate::internet::ipv4_option_element::*;
+pub use crate::internet::ipv4_option_impl::*;
 pub use crate::internet::ipv4_options::*;
+pub use crate::internet::ipv4_options_iterator::*;
 pub use crate::internet::ipv4_slice::*;
+pub use crate::internet::ipv6_ext_chain::*;
+pub use crate::internet::ipv6_ext_header::*;
 pub use crate::internet::ipv6_ext_slice::*;
 pub use crate::internet::ipv6_ext_slice_iter::*;
 pub use crate::internet::ipv6_exts::*;
@@ -300,16 +393,58 @@ pub use crate::internet::ipv6_fragment_header::*;
 pub use crate::internet::ipv6_fragment_header_slice::*;
 pub use crate::internet::ipv6_header::*;
 pub use crate::internet::ipv6_header_slice::*;
+pub use crate::internet::ipv6_mobility_binding_ack_slice::*;
+pub use crate::internet::ipv6_mobility_binding_update_slice::*;
+pub use crate::internet::ipv6_mobility_header_slice::*;
+pub use crate::internet::ipv6_mobility_header_type::*;
+pub use crate::internet::ipv6_mobility_option::*;
+pub use crate::internet::ipv6_mobility_option_impl::*;
+pub use crate::internet::ipv6_mobility_options_iterator::*;
+pub use crate::internet::ipv6_option_element::*;
+pub use crate::internet::ipv6_option_impl::*;
+pub use crate::internet::ipv6_option_unknown_action::*;
+pub use crate::internet::ipv6_options_iterator::*;
 pub use crate::internet::ipv6_raw_ext_header::*;
 pub use crate::internet::ipv6_raw_ext_header_slice::*;
 pub use crate::internet::ipv6_routing_exts::*;
+pub use crate::internet::ipv6_routing_header_slice::*;
+pub use crate::internet::ipv6_routing_type::*;
+pub use crate::internet::ipv6_rpl_routing_header_slice::*;
 pub use crate::internet::ipv6_slice::*;
+pub use crate::internet::ipv6_srh_slice::*;
+pub use crate::internet::ipv6_srh_tlv::*;
+pub use crate::internet::ipv6_srh_tlv_impl::*;
+pub use crate::internet::ipv6_srh_tlvs_iterator::*;
+pub use crate::internet::ipv6_type2_routing_header_slice::*;
+pub use crate::internet::shim6_header_slice::*;
 
 #[cfg(feature = "std")]
 pub mod io;
 
+/// Module containing helpers for reassembling fragmented IP packets.
+#[cfg(feature = "std")]
+pub mod defrag;
+
+/// Module containing helpers for splitting IP packets into fragments.
+#[cfg(feature = "std")]
+pub mod frag;
+
 mod transport;
+pub use crate::transport::geneve_header::*;
+pub use crate::transport::geneve_header_slice::*;
+pub use crate::transport::geneve_option::*;
+pub use crate::transport::geneve_options::*;
+pub use crate::transport::geneve_options_iterator::*;
+pub use crate::transport::gtpu_extension_header::*;
+pub use crate::transport::gtpu_extension_header_type::*;
+pub use crate::transport::gtpu_extension_headers::*;
+pub use crate::transport::gtpu_extension_headers_iterator::*;
+pub use crate::transport::gtpu_header::*;
+pub use crate::transport::gtpu_header_slice::*;
+pub use crate::transport::gtpu_message_type::*;
+pub use crate::transport::gtpu_pdu_session_container::*;
 pub use crate::transport::icmp_echo_header::*;
+pub use crate::transport::icmp_quote_slice::*;
 pub use crate::transport::icmpv4;
 pub use crate::transport::icmpv4_header::*;
 pub use crate::transport::icmpv4_slice::*;
@@ -331,6 +466,10 @@ pub use crate::transport::transport_slice::*;
 pub use crate::transport::udp_header::*;
 pub use crate::transport::udp_header_slice::*;
 pub use crate::transport::udp_slice::*;
+pub use crate::transport::vni::*;
+pub use crate::transport::vxlan_gpe_next_protocol::*;
+pub use crate::transport::vxlan_header::*;
+pub use crate::transport::vxlan_header_slice::*;
 
 /// Helpers for calculating checksums.
 pub mod checksum;
@@ -421,6 +560,27 @@ unsafe fn get_unchecked_6_byte_array(ptr: *const u8) -> [u8; 6] {
     ]
 }
 
+/// Helper function for reading a 8 byte fixed-size array.
+///
+/// # Safety
+///
+/// It is in the responsibility of the caller to ensure there are at least 8
+/// bytes accessable via the ptr. If this is not the case undefined behavior
+/// will be triggered.
+#[inline]
+unsafe fn get_unchecked_8_byte_array(ptr: *const u8) -> [u8; 8] {
+    [
+        *ptr,
+        *ptr.add(1),
+        *ptr.add(2),
+        *ptr.add(3),
+        *ptr.add(4),
+        *ptr.add(5),
+        *ptr.add(6),
+        *ptr.add(7),
+    ]
+}
+
 /// Helper function for reading a 16 byte fixed-size array.
 ///
 /// # Safety
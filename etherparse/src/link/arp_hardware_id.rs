@@ -0,0 +1,306 @@
+/// Represents an ARP hardware type (also called "hardware address space")
+/// identifying the type of hardware address used in an ARP packet.
+///
+/// You can access the underlying `u16` value by using `.0` and any `u16`
+/// can be converted to an `ArpHardwareId`:
+///
+/// ```
+/// use etherparse::ArpHardwareId;
+///
+/// assert_eq!(ArpHardwareId::ETHERNET.0, 1);
+/// assert_eq!(ArpHardwareId::ETHERNET, ArpHardwareId(1));
+///
+/// // convert to ArpHardwareId using the from & into trait
+/// let hw_id: ArpHardwareId = 1.into();
+/// assert_eq!(ArpHardwareId::ETHERNET, hw_id);
+///
+/// // convert to u16 using the from & into trait
+/// let num: u16 = ArpHardwareId::ETHERNET.into();
+/// assert_eq!(1, num);
+/// ```
+///
+/// The list original values were copied from
+/// <https://www.iana.org/assignments/arp-parameters/arp-parameters.xhtml>.
+/// Additionally some Linux specific values (`ARPHRD_*` in `if_arp.h`)
+/// are defined, as these are used in Linux cooked capture headers.
+#[derive(Default, PartialEq, Eq, Clone, Copy, Hash, Ord, PartialOrd)]
+pub struct ArpHardwareId(pub u16);
+
+impl ArpHardwareId {
+    /// Ethernet (10Mb)
+    pub const ETHERNET: ArpHardwareId = Self(1);
+    /// Experimental Ethernet (3Mb)
+    pub const EXPERIMENTAL_ETHERNET: ArpHardwareId = Self(2);
+    /// Amateur Radio AX.25
+    pub const AX25: ArpHardwareId = Self(3);
+    /// Proteon ProNET Token Ring
+    pub const PRONET: ArpHardwareId = Self(4);
+    /// Chaos
+    pub const CHAOS: ArpHardwareId = Self(5);
+    /// IEEE 802 Networks
+    pub const IEEE802: ArpHardwareId = Self(6);
+    /// ARCNET
+    pub const ARCNET: ArpHardwareId = Self(7);
+    /// Hyperchannel
+    pub const HYPERCHANNEL: ArpHardwareId = Self(8);
+    /// Lanstar
+    pub const LANSTAR: ArpHardwareId = Self(9);
+    /// Autonet Short Address
+    pub const AUTONET: ArpHardwareId = Self(10);
+    /// LocalTalk
+    pub const LOCALTALK: ArpHardwareId = Self(11);
+    /// LocalNet (IBM PCNet or SYTEK LocalNET)
+    pub const LOCALNET: ArpHardwareId = Self(12);
+    /// Ultra link
+    pub const ULTRA_LINK: ArpHardwareId = Self(13);
+    /// SMDS
+    pub const SMDS: ArpHardwareId = Self(14);
+    /// Frame Relay
+    pub const FRAME_RELAY: ArpHardwareId = Self(15);
+    /// Asynchronous Transmission Mode (ATM)
+    pub const ATM: ArpHardwareId = Self(16);
+    /// HDLC
+    pub const HDLC: ArpHardwareId = Self(17);
+    /// Fibre Channel
+    pub const FIBRE_CHANNEL: ArpHardwareId = Self(18);
+    /// Serial Line
+    pub const SERIAL_LINE: ArpHardwareId = Self(20);
+    /// MIL-STD-188-220
+    pub const MIL_STD_188_220: ArpHardwareId = Self(22);
+    /// Metricom
+    pub const METRICOM: ArpHardwareId = Self(23);
+    /// IEEE 1394.1995
+    pub const IEEE1394: ArpHardwareId = Self(24);
+    /// MAPOS
+    pub const MAPOS: ArpHardwareId = Self(25);
+    /// Twinaxial
+    pub const TWINAXIAL: ArpHardwareId = Self(26);
+    /// EUI-64
+    pub const EUI64: ArpHardwareId = Self(27);
+    /// HIPARP
+    pub const HIPARP: ArpHardwareId = Self(28);
+    /// IP and ARP over ISO 7816-3
+    pub const IP_AND_ARP_OVER_ISO_7816_3: ArpHardwareId = Self(29);
+    /// ARPSec
+    pub const ARPSEC: ArpHardwareId = Self(30);
+    /// IPsec tunnel
+    pub const IPSEC_TUNNEL: ArpHardwareId = Self(31);
+    /// InfiniBand (TM)
+    pub const INFINIBAND: ArpHardwareId = Self(32);
+    /// TIA-102 Project 25 Common Air Interface (CAI)
+    pub const CAI: ArpHardwareId = Self(33);
+    /// Wiegand Interface
+    pub const WIEGAND_INTERFACE: ArpHardwareId = Self(34);
+    /// Pure IP
+    pub const PURE_IP: ArpHardwareId = Self(35);
+    /// HW_EXP1
+    pub const HW_EXP1: ArpHardwareId = Self(36);
+    /// HFI
+    pub const HFI: ArpHardwareId = Self(37);
+    /// Unified Bus (UB)
+    pub const UNIFIED_BUS: ArpHardwareId = Self(38);
+    /// HW_EXP2
+    pub const HW_EXP2: ArpHardwareId = Self(256);
+    /// AEthernet
+    pub const AETHERNET: ArpHardwareId = Self(257);
+    /// IPIP tunnel (Linux specific)
+    pub const TUNNEL: ArpHardwareId = Self(768);
+    /// IP6IP6 tunnel (Linux specific)
+    pub const TUNNEL6: ArpHardwareId = Self(769);
+    /// Loopback device (Linux specific)
+    pub const LOOPBACK: ArpHardwareId = Self(772);
+    /// IPv6-in-IPv4 tunnel (Linux specific)
+    pub const SIT: ArpHardwareId = Self(776);
+    /// GRE over IP (Linux specific)
+    pub const IPGRE: ArpHardwareId = Self(778);
+    /// IEEE 802.11 (Linux specific)
+    pub const IEEE80211: ArpHardwareId = Self(801);
+    /// IEEE 802.11 with radiotap header (Linux specific)
+    pub const IEEE80211_RADIOTAP: ArpHardwareId = Self(803);
+    /// GRE over IPv6 (Linux specific)
+    pub const IP6GRE: ArpHardwareId = Self(823);
+    /// Netlink (Linux specific)
+    pub const NETLINK: ArpHardwareId = Self(824);
+    /// Device without a hardware header (Linux specific)
+    pub const NONE: ArpHardwareId = Self(0xfffe);
+    /// Void type, nothing is known (Linux specific)
+    pub const VOID: ArpHardwareId = Self(0xffff);
+}
+
+impl From<u16> for ArpHardwareId {
+    #[inline]
+    fn from(val: u16) -> Self {
+        ArpHardwareId(val)
+    }
+}
+
+impl From<ArpHardwareId> for u16 {
+    #[inline]
+    fn from(val: ArpHardwareId) -> Self {
+        val.0
+    }
+}
+
+impl core::fmt::Debug for ArpHardwareId {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let name = match *self {
+            Self::ETHERNET => "Ethernet (10Mb)",
+            Self::EXPERIMENTAL_ETHERNET => "Experimental Ethernet (3Mb)",
+            Self::AX25 => "Amateur Radio AX.25",
+            Self::PRONET => "Proteon ProNET Token Ring",
+            Self::CHAOS => "Chaos",
+            Self::IEEE802 => "IEEE 802 Networks",
+            Self::ARCNET => "ARCNET",
+            Self::HYPERCHANNEL => "Hyperchannel",
+            Self::LANSTAR => "Lanstar",
+            Self::AUTONET => "Autonet Short Address",
+            Self::LOCALTALK => "LocalTalk",
+            Self::LOCALNET => "LocalNet (IBM PCNet or SYTEK LocalNET)",
+            Self::ULTRA_LINK => "Ultra link",
+            Self::SMDS => "SMDS",
+            Self::FRAME_RELAY => "Frame Relay",
+            Self::ATM => "Asynchronous Transmission Mode (ATM)",
+            Self::HDLC => "HDLC",
+            Self::FIBRE_CHANNEL => "Fibre Channel",
+            Self::SERIAL_LINE => "Serial Line",
+            Self::MIL_STD_188_220 => "MIL-STD-188-220",
+            Self::METRICOM => "Metricom",
+            Self::IEEE1394 => "IEEE 1394.1995",
+            Self::MAPOS => "MAPOS",
+            Self::TWINAXIAL => "Twinaxial",
+            Self::EUI64 => "EUI-64",
+            Self::HIPARP => "HIPARP",
+            Self::IP_AND_ARP_OVER_ISO_7816_3 => "IP and ARP over ISO 7816-3",
+            Self::ARPSEC => "ARPSec",
+            Self::IPSEC_TUNNEL => "IPsec tunnel",
+            Self::INFINIBAND => "InfiniBand (TM)",
+            Self::CAI => "TIA-102 Project 25 Common Air Interface (CAI)",
+            Self::WIEGAND_INTERFACE => "Wiegand Interface",
+            Self::PURE_IP => "Pure IP",
+            Self::HW_EXP1 => "HW_EXP1",
+            Self::HFI => "HFI",
+            Self::UNIFIED_BUS => "Unified Bus (UB)",
+            Self::HW_EXP2 => "HW_EXP2",
+            Self::AETHERNET => "AEthernet",
+            Self::TUNNEL => "IPIP tunnel",
+            Self::TUNNEL6 => "IP6IP6 tunnel",
+            Self::LOOPBACK => "Loopback",
+            Self::SIT => "IPv6-in-IPv4 tunnel",
+            Self::IPGRE => "GRE over IP",
+            Self::IEEE80211 => "IEEE 802.11",
+            Self::IEEE80211_RADIOTAP => "IEEE 802.11 + radiotap header",
+            Self::IP6GRE => "GRE over IPv6",
+            Self::NETLINK => "Netlink",
+            Self::NONE => "No header",
+            Self::VOID => "Void",
+            _ => return write!(f, "{}", self.0),
+        };
+        write!(f, "{} ({})", self.0, name)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::format;
+
+    #[test]
+    fn to_u16() {
+        assert_eq!(1, u16::from(ArpHardwareId::ETHERNET));
+        assert_eq!(6, u16::from(ArpHardwareId::IEEE802));
+        assert_eq!(257, u16::from(ArpHardwareId::AETHERNET));
+        assert_eq!(824, u16::from(ArpHardwareId::NETLINK));
+    }
+
+    #[test]
+    fn from_u16() {
+        assert_eq!(ArpHardwareId::from(1), ArpHardwareId::ETHERNET);
+        assert_eq!(ArpHardwareId::from(6), ArpHardwareId::IEEE802);
+        assert_eq!(ArpHardwareId::from(0x1234), ArpHardwareId(0x1234));
+    }
+
+    #[test]
+    fn dbg() {
+        let pairs = &[
+            (ArpHardwareId::ETHERNET, "1 (Ethernet (10Mb))"),
+            (
+                ArpHardwareId::EXPERIMENTAL_ETHERNET,
+                "2 (Experimental Ethernet (3Mb))",
+            ),
+            (ArpHardwareId::AX25, "3 (Amateur Radio AX.25)"),
+            (ArpHardwareId::PRONET, "4 (Proteon ProNET Token Ring)"),
+            (ArpHardwareId::CHAOS, "5 (Chaos)"),
+            (ArpHardwareId::IEEE802, "6 (IEEE 802 Networks)"),
+            (ArpHardwareId::ARCNET, "7 (ARCNET)"),
+            (ArpHardwareId::HYPERCHANNEL, "8 (Hyperchannel)"),
+            (ArpHardwareId::LANSTAR, "9 (Lanstar)"),
+            (ArpHardwareId::AUTONET, "10 (Autonet Short Address)"),
+            (ArpHardwareId::LOCALTALK, "11 (LocalTalk)"),
+            (
+                ArpHardwareId::LOCALNET,
+                "12 (LocalNet (IBM PCNet or SYTEK LocalNET))",
+            ),
+            (ArpHardwareId::ULTRA_LINK, "13 (Ultra link)"),
+            (ArpHardwareId::SMDS, "14 (SMDS)"),
+            (ArpHardwareId::FRAME_RELAY, "15 (Frame Relay)"),
+            (
+                ArpHardwareId::ATM,
+                "16 (Asynchronous Transmission Mode (ATM))",
+            ),
+            (ArpHardwareId::HDLC, "17 (HDLC)"),
+            (ArpHardwareId::FIBRE_CHANNEL, "18 (Fibre Channel)"),
+            (ArpHardwareId::SERIAL_LINE, "20 (Serial Line)"),
+            (ArpHardwareId::MIL_STD_188_220, "22 (MIL-STD-188-220)"),
+            (ArpHardwareId::METRICOM, "23 (Metricom)"),
+            (ArpHardwareId::IEEE1394, "24 (IEEE 1394.1995)"),
+            (ArpHardwareId::MAPOS, "25 (MAPOS)"),
+            (ArpHardwareId::TWINAXIAL, "26 (Twinaxial)"),
+            (ArpHardwareId::EUI64, "27 (EUI-64)"),
+            (ArpHardwareId::HIPARP, "28 (HIPARP)"),
+            (
+                ArpHardwareId::IP_AND_ARP_OVER_ISO_7816_3,
+                "29 (IP and ARP over ISO 7816-3)",
+            ),
+            (ArpHardwareId::ARPSEC, "30 (ARPSec)"),
+            (ArpHardwareId::IPSEC_TUNNEL, "31 (IPsec tunnel)"),
+            (ArpHardwareId::INFINIBAND, "32 (InfiniBand (TM))"),
+            (
+                ArpHardwareId::CAI,
+                "33 (TIA-102 Project 25 Common Air Interface (CAI))",
+            ),
+            (ArpHardwareId::WIEGAND_INTERFACE, "34 (Wiegand Interface)"),
+            (ArpHardwareId::PURE_IP, "35 (Pure IP)"),
+            (ArpHardwareId::HW_EXP1, "36 (HW_EXP1)"),
+            (ArpHardwareId::HFI, "37 (HFI)"),
+            (ArpHardwareId::UNIFIED_BUS, "38 (Unified Bus (UB))"),
+            (ArpHardwareId::HW_EXP2, "256 (HW_EXP2)"),
+            (ArpHardwareId::AETHERNET, "257 (AEthernet)"),
+            (ArpHardwareId::TUNNEL, "768 (IPIP tunnel)"),
+            (ArpHardwareId::TUNNEL6, "769 (IP6IP6 tunnel)"),
+            (ArpHardwareId::LOOPBACK, "772 (Loopback)"),
+            (ArpHardwareId::SIT, "776 (IPv6-in-IPv4 tunnel)"),
+            (ArpHardwareId::IPGRE, "778 (GRE over IP)"),
+            (ArpHardwareId::IEEE80211, "801 (IEEE 802.11)"),
+            (
+                ArpHardwareId::IEEE80211_RADIOTAP,
+                "803 (IEEE 802.11 + radiotap header)",
+            ),
+            (ArpHardwareId::IP6GRE, "823 (GRE over IPv6)"),
+            (ArpHardwareId::NETLINK, "824 (Netlink)"),
+            (ArpHardwareId::NONE, "65534 (No header)"),
+            (ArpHardwareId::VOID, "65535 (Void)"),
+            (ArpHardwareId(0x1234), "4660"),
+        ];
+
+        for (input, expected) in pairs {
+            assert_eq!(expected, &format!("{:?}", input));
+        }
+    }
+
+    #[test]
+    fn clone_eq_default() {
+        let value = ArpHardwareId::ETHERNET;
+        assert_eq!(value, value.clone());
+        assert_eq!(ArpHardwareId(0), ArpHardwareId::default());
+    }
+}
@@ -38,6 +38,12 @@ impl EtherType {
     pub const VLAN_TAGGED_FRAME: EtherType = Self(0x8100);
     pub const PROVIDER_BRIDGING: EtherType = Self(0x88A8);
     pub const VLAN_DOUBLE_TAGGED_FRAME: EtherType = Self(0x9100);
+    pub const MPLS_UNICAST: EtherType = Self(0x8847);
+    pub const MPLS_MULTICAST: EtherType = Self(0x8848);
+    pub const PPPOE_DISCOVERY: EtherType = Self(0x8863);
+    pub const PPPOE_SESSION: EtherType = Self(0x8864);
+    pub const MACSEC: EtherType = Self(0x88E5);
+    pub const TRANSPARENT_ETHERNET_BRIDGING: EtherType = Self(0x6558);
 }
 
 impl From<u16> for EtherType {
@@ -74,6 +80,18 @@ impl core::fmt::Debug for EtherType {
             Self::VLAN_DOUBLE_TAGGED_FRAME => {
                 write!(f, "{:#06X} (VLAN Double Tagged Frame)", self.0)
             }
+            Self::MPLS_UNICAST => write!(f, "{:#06X} (MPLS unicast)", self.0),
+            Self::MPLS_MULTICAST => write!(f, "{:#06X} (MPLS multicast)", self.0),
+            Self::PPPOE_DISCOVERY => write!(f, "{:#06X} (PPPoE discovery stage)", self.0),
+            Self::PPPOE_SESSION => write!(f, "{:#06X} (PPPoE session stage)", self.0),
+            Self::MACSEC => write!(
+                f,
+                "{:#06X} (MAC security (MACsec) as defined in IEEE Std 802.1AE)",
+                self.0
+            ),
+            Self::TRANSPARENT_ETHERNET_BRIDGING => {
+                write!(f, "{:#06X} (Transparent Ethernet Bridging)", self.0)
+            }
             _ => write!(f, "{:#06X}", self.0),
         }
     }
@@ -106,6 +124,12 @@ pub mod ether_type {
     pub const VLAN_TAGGED_FRAME: EtherType = EtherType::VLAN_TAGGED_FRAME;
     pub const PROVIDER_BRIDGING: EtherType = EtherType::PROVIDER_BRIDGING;
     pub const VLAN_DOUBLE_TAGGED_FRAME: EtherType = EtherType::VLAN_DOUBLE_TAGGED_FRAME;
+    pub const MPLS_UNICAST: EtherType = EtherType::MPLS_UNICAST;
+    pub const MPLS_MULTICAST: EtherType = EtherType::MPLS_MULTICAST;
+    pub const PPPOE_DISCOVERY: EtherType = EtherType::PPPOE_DISCOVERY;
+    pub const PPPOE_SESSION: EtherType = EtherType::PPPOE_SESSION;
+    pub const MACSEC: EtherType = EtherType::MACSEC;
+    pub const TRANSPARENT_ETHERNET_BRIDGING: EtherType = EtherType::TRANSPARENT_ETHERNET_BRIDGING;
 }
 
 #[cfg(test)]
@@ -122,6 +146,12 @@ mod test {
         assert_eq!(0x8100, u16::from(EtherType::VLAN_TAGGED_FRAME));
         assert_eq!(0x88A8, u16::from(EtherType::PROVIDER_BRIDGING));
         assert_eq!(0x9100, u16::from(EtherType::VLAN_DOUBLE_TAGGED_FRAME));
+        assert_eq!(0x8847, u16::from(EtherType::MPLS_UNICAST));
+        assert_eq!(0x8848, u16::from(EtherType::MPLS_MULTICAST));
+        assert_eq!(0x8863, u16::from(EtherType::PPPOE_DISCOVERY));
+        assert_eq!(0x8864, u16::from(EtherType::PPPOE_SESSION));
+        assert_eq!(0x88E5, u16::from(EtherType::MACSEC));
+        assert_eq!(0x6558, u16::from(EtherType::TRANSPARENT_ETHERNET_BRIDGING));
     }
 
     #[test]
@@ -133,6 +163,15 @@ mod test {
         assert_eq!(EtherType::from(0x8100), EtherType::VLAN_TAGGED_FRAME);
         assert_eq!(EtherType::from(0x88A8), EtherType::PROVIDER_BRIDGING);
         assert_eq!(EtherType::from(0x9100), EtherType::VLAN_DOUBLE_TAGGED_FRAME);
+        assert_eq!(EtherType::from(0x8847), EtherType::MPLS_UNICAST);
+        assert_eq!(EtherType::from(0x8848), EtherType::MPLS_MULTICAST);
+        assert_eq!(EtherType::from(0x8863), EtherType::PPPOE_DISCOVERY);
+        assert_eq!(EtherType::from(0x8864), EtherType::PPPOE_SESSION);
+        assert_eq!(EtherType::from(0x88E5), EtherType::MACSEC);
+        assert_eq!(
+            EtherType::from(0x6558),
+            EtherType::TRANSPARENT_ETHERNET_BRIDGING
+        );
         assert_eq!(EtherType::from(0x1234), EtherType(0x1234));
     }
 
@@ -150,6 +189,15 @@ mod test {
                 EtherType::VLAN_DOUBLE_TAGGED_FRAME,
                 VLAN_DOUBLE_TAGGED_FRAME,
             ),
+            (EtherType::MPLS_UNICAST, MPLS_UNICAST),
+            (EtherType::MPLS_MULTICAST, MPLS_MULTICAST),
+            (EtherType::PPPOE_DISCOVERY, PPPOE_DISCOVERY),
+            (EtherType::PPPOE_SESSION, PPPOE_SESSION),
+            (EtherType::MACSEC, MACSEC),
+            (
+                EtherType::TRANSPARENT_ETHERNET_BRIDGING,
+                TRANSPARENT_ETHERNET_BRIDGING,
+            ),
         ];
 
         for (ether_type, constant) in pairs {
@@ -182,6 +230,18 @@ mod test {
                 EtherType::VLAN_DOUBLE_TAGGED_FRAME,
                 "0x9100 (VLAN Double Tagged Frame)",
             ),
+            (EtherType::MPLS_UNICAST, "0x8847 (MPLS unicast)"),
+            (EtherType::MPLS_MULTICAST, "0x8848 (MPLS multicast)"),
+            (EtherType::PPPOE_DISCOVERY, "0x8863 (PPPoE discovery stage)"),
+            (EtherType::PPPOE_SESSION, "0x8864 (PPPoE session stage)"),
+            (
+                EtherType::MACSEC,
+                "0x88E5 (MAC security (MACsec) as defined in IEEE Std 802.1AE)",
+            ),
+            (
+                EtherType::TRANSPARENT_ETHERNET_BRIDGING,
+                "0x6558 (Transparent Ethernet Bridging)",
+            ),
             (EtherType(1), "0x0001"),
         ];
 
@@ -206,6 +266,12 @@ mod test {
             EtherType::VLAN_TAGGED_FRAME,
             EtherType::PROVIDER_BRIDGING,
             EtherType::VLAN_DOUBLE_TAGGED_FRAME,
+            EtherType::MPLS_UNICAST,
+            EtherType::MPLS_MULTICAST,
+            EtherType::PPPOE_DISCOVERY,
+            EtherType::PPPOE_SESSION,
+            EtherType::MACSEC,
+            EtherType::TRANSPARENT_ETHERNET_BRIDGING,
         ];
 
         // clone
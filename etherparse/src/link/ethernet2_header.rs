@@ -4,10 +4,14 @@ use crate::{err::Layer, err::SliceWriteSpaceError, *};
 #[derive(Clone, Debug, Eq, PartialEq, Default)]
 pub struct Ethernet2Header {
     /// Source MAC Address
-    pub source: [u8; 6],
+    pub source: MacAddress,
     /// Destination MAC Address
-    pub destination: [u8; 6],
+    pub destination: MacAddress,
     /// Protocol present after the ethernet2 header.
+    ///
+    /// Values from `1` to `1500` (`0x05DC`) are not an ether type but the
+    /// payload length of an IEEE 802.3 frame (see
+    /// [`Ethernet2Header::ieee802_3_len`]).
     pub ether_type: EtherType,
 }
 
@@ -19,6 +23,19 @@ impl Ethernet2Header {
     #[deprecated(since = "0.14.0", note = "Use `Ethernet2Header::LEN` instead")]
     pub const SERIALIZED_SIZE: usize = Ethernet2Header::LEN;
 
+    /// Maximum payload length that can be stored in the type/length
+    /// field of an IEEE 802.3 frame.
+    pub const MAX_IEEE802_3_PAYLOAD_LEN: usize = 1500;
+
+    /// Length of the frame check sequence (CRC-32) that can be present
+    /// at the end of an ethernet frame (see [`crate::checksum::Crc32`]).
+    pub const FCS_LEN: usize = 4;
+
+    /// Minimum length of an ethernet frame (headers & payload) without
+    /// the frame check sequence. Shorter frames have to be padded with
+    /// zeros before the frame check sequence is calculated.
+    pub const MIN_FRAME_LEN: usize = 60;
+
     /// Deprecated use [`Ethernet2Header::from_slice`] instead.
     #[deprecated(since = "0.10.1", note = "Use Ethernet2Header::from_slice instead.")]
     #[inline]
@@ -39,8 +56,8 @@ impl Ethernet2Header {
     #[inline]
     pub fn from_bytes(bytes: [u8; 14]) -> Ethernet2Header {
         Ethernet2Header {
-            destination: [bytes[0], bytes[1], bytes[2], bytes[3], bytes[4], bytes[5]],
-            source: [bytes[6], bytes[7], bytes[8], bytes[9], bytes[10], bytes[11]],
+            destination: MacAddress([bytes[0], bytes[1], bytes[2], bytes[3], bytes[4], bytes[5]]),
+            source: MacAddress([bytes[6], bytes[7], bytes[8], bytes[9], bytes[10], bytes[11]]),
             ether_type: EtherType(u16::from_be_bytes([bytes[12], bytes[13]])),
         }
     }
@@ -62,6 +79,31 @@ impl Ethernet2Header {
         )
     }
 
+    /// Returns the payload length if the `ether_type` field contains a
+    /// length instead of an ether type (IEEE 802.3 frame).
+    ///
+    /// The type/length field is interpreted as a length if its value is
+    /// in the range from `1` to [`Ethernet2Header::MAX_IEEE802_3_PAYLOAD_LEN`]
+    /// (`1500`). In this case the payload starts with an [`LlcHeader`].
+    ///
+    /// The value `0` (too short for an LLC header) and the undefined
+    /// range `0x05DD..=0x05FF` are treated as (unknown) ether types.
+    #[inline]
+    pub fn ieee802_3_len(&self) -> Option<u16> {
+        ieee802_3_len(self.ether_type)
+    }
+
+    /// Returns the ether type of the payload if the `ether_type` field
+    /// contains an ether type (`None` is returned for IEEE 802.3 frames).
+    #[inline]
+    pub fn payload_ether_type(&self) -> Option<EtherType> {
+        if ieee802_3_len(self.ether_type).is_some() {
+            None
+        } else {
+            Some(self.ether_type)
+        }
+    }
+
     /// Serialize the header to a given slice. Returns the unused part of the slice.
     pub fn write_to_slice<'a>(
         &self,
@@ -100,24 +142,64 @@ impl Ethernet2Header {
     pub fn to_bytes(&self) -> [u8; 14] {
         let ether_type_be = self.ether_type.0.to_be_bytes();
         [
-            self.destination[0],
-            self.destination[1],
-            self.destination[2],
-            self.destination[3],
-            self.destination[4],
-            self.destination[5],
-            self.source[0],
-            self.source[1],
-            self.source[2],
-            self.source[3],
-            self.source[4],
-            self.source[5],
+            self.destination.0[0],
+            self.destination.0[1],
+            self.destination.0[2],
+            self.destination.0[3],
+            self.destination.0[4],
+            self.destination.0[5],
+            self.source.0[0],
+            self.source.0[1],
+            self.source.0[2],
+            self.source.0[3],
+            self.source.0[4],
+            self.source.0[5],
             ether_type_be[0],
             ether_type_be[1],
         ]
     }
 }
 
+/// Returns the length in case the given value of an type/length field
+/// (Ethernet II or VLAN header) contains a length instead of an ether type.
+#[inline]
+pub(crate) fn ieee802_3_len(type_len: EtherType) -> Option<u16> {
+    if type_len.0 != 0 && usize::from(type_len.0) <= Ethernet2Header::MAX_IEEE802_3_PAYLOAD_LEN {
+        Some(type_len.0)
+    } else {
+        None
+    }
+}
+
+/// Verifies the frame check sequence (FCS) at the end of an ethernet
+/// frame and returns the frame without the FCS.
+pub(crate) fn strip_fcs(data: &[u8]) -> Result<&[u8], err::packet::EthFcsSliceError> {
+    use err::packet::EthFcsSliceError::*;
+
+    // check that the frame can contain a header & fcs
+    if data.len() < Ethernet2Header::LEN + Ethernet2Header::FCS_LEN {
+        return Err(Len(err::LenError {
+            required_len: Ethernet2Header::LEN + Ethernet2Header::FCS_LEN,
+            len: data.len(),
+            len_source: err::LenSource::Slice,
+            layer: Layer::Ethernet2Header,
+            layer_start_offset: 0,
+        }));
+    }
+
+    // verify the fcs (transmitted in little endian byte order)
+    let (frame, fcs) = data.split_at(data.len() - Ethernet2Header::FCS_LEN);
+    let received = u32::from_le_bytes([fcs[0], fcs[1], fcs[2], fcs[3]]);
+    let calculated = checksum::Crc32::new().add_slice(frame).value();
+    if received != calculated {
+        return Err(FcsMismatch {
+            received,
+            calculated,
+        });
+    }
+    Ok(frame)
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -208,6 +290,28 @@ mod test {
         }
     }
 
+    proptest! {
+        #[test]
+        fn ieee802_3_len_payload_ether_type(
+            input in ethernet_2_any(),
+            len in 1..=0x05DCu16,
+            ether_type in prop_oneof![Just(0u16), 0x05DD..=u16::MAX],
+        ) {
+            // length
+            {
+                let eth = Ethernet2Header{ ether_type: EtherType(len), ..input.clone() };
+                assert_eq!(Some(len), eth.ieee802_3_len());
+                assert_eq!(None, eth.payload_ether_type());
+            }
+            // ether type
+            {
+                let eth = Ethernet2Header{ ether_type: EtherType(ether_type), ..input.clone() };
+                assert_eq!(None, eth.ieee802_3_len());
+                assert_eq!(Some(EtherType(ether_type)), eth.payload_ether_type());
+            }
+        }
+    }
+
     proptest! {
         #[test]
         fn write_to_slice(input in ethernet_2_any()) {
@@ -266,18 +370,18 @@ mod test {
             assert_eq!(
                 input.to_bytes(),
                 [
-                    input.destination[0],
-                    input.destination[1],
-                    input.destination[2],
-                    input.destination[3],
-                    input.destination[4],
-                    input.destination[5],
-                    input.source[0],
-                    input.source[1],
-                    input.source[2],
-                    input.source[3],
-                    input.source[4],
-                    input.source[5],
+                    input.destination.0[0],
+                    input.destination.0[1],
+                    input.destination.0[2],
+                    input.destination.0[3],
+                    input.destination.0[4],
+                    input.destination.0[5],
+                    input.source.0[0],
+                    input.source.0[1],
+                    input.source.0[2],
+                    input.source.0[3],
+                    input.source.0[4],
+                    input.source.0[5],
                     ether_type_be[0],
                     ether_type_be[1],
                 ]
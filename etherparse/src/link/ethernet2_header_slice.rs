@@ -55,20 +55,20 @@ impl<'a> Ethernet2HeaderSlice<'a> {
 
     /// Read the destination MAC address
     #[inline]
-    pub fn destination(&self) -> [u8; 6] {
+    pub fn destination(&self) -> MacAddress {
         // SAFETY:
         // Safe as the contructor checks that the slice has
         // at least the length of Ethernet2Header::LEN (14).
-        unsafe { get_unchecked_6_byte_array(self.slice.as_ptr()) }
+        MacAddress(unsafe { get_unchecked_6_byte_array(self.slice.as_ptr()) })
     }
 
     /// Read the source MAC address
     #[inline]
-    pub fn source(&self) -> [u8; 6] {
+    pub fn source(&self) -> MacAddress {
         // SAFETY:
         // Safe as the contructor checks that the slice has
         // at least the length of Ethernet2Header::LEN (14).
-        unsafe { get_unchecked_6_byte_array(self.slice.as_ptr().add(6)) }
+        MacAddress(unsafe { get_unchecked_6_byte_array(self.slice.as_ptr().add(6)) })
     }
 
     /// Read the ether_type field of the header indicating the protocol
@@ -81,6 +81,27 @@ impl<'a> Ethernet2HeaderSlice<'a> {
         EtherType(unsafe { get_unchecked_be_u16(self.slice.as_ptr().add(12)) })
     }
 
+    /// Returns the payload length if the type/length field contains a
+    /// length instead of an ether type (IEEE 802.3 frame).
+    ///
+    /// See [`Ethernet2Header::ieee802_3_len`] for details.
+    #[inline]
+    pub fn ieee802_3_len(&self) -> Option<u16> {
+        crate::link::ethernet2_header::ieee802_3_len(self.ether_type())
+    }
+
+    /// Returns the ether type of the payload if the type/length field
+    /// contains an ether type (`None` is returned for IEEE 802.3 frames).
+    #[inline]
+    pub fn payload_ether_type(&self) -> Option<EtherType> {
+        let value = self.ether_type();
+        if crate::link::ethernet2_header::ieee802_3_len(value).is_some() {
+            None
+        } else {
+            Some(value)
+        }
+    }
+
     /// Decode all the fields and copy the results to a [``] struct
     pub fn to_header(&self) -> Ethernet2Header {
         Ethernet2Header {
@@ -139,6 +160,8 @@ mod test {
             assert_eq!(input.destination, slice.destination());
             assert_eq!(input.source, slice.source());
             assert_eq!(input.ether_type, slice.ether_type());
+            assert_eq!(input.ieee802_3_len(), slice.ieee802_3_len());
+            assert_eq!(input.payload_ether_type(), slice.payload_ether_type());
         }
     }
 
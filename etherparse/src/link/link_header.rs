@@ -0,0 +1,206 @@
+use crate::*;
+
+/// Link layer header (e.g. Ethernet II or Linux cooked capture header).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum LinkHeader {
+    /// Ethernet II header.
+    Ethernet2(Ethernet2Header),
+    /// Linux cooked capture (SLL) header.
+    LinuxSll(LinuxSllHeader),
+    /// Linux cooked capture v2 (SLL2) header.
+    LinuxSll2(LinuxSll2Header),
+}
+
+impl LinkHeader {
+    /// Returns the Ethernet II header if the link header is one.
+    #[inline]
+    pub fn ethernet2(&self) -> Option<&Ethernet2Header> {
+        match self {
+            LinkHeader::Ethernet2(eth) => Some(eth),
+            _ => None,
+        }
+    }
+
+    /// Returns the ether type of the payload following the link
+    /// header (if the link header contains an ether type).
+    ///
+    /// See [`Ethernet2Header::payload_ether_type`] &
+    /// [`LinuxSllHeader::payload_ether_type`] for the cases in which
+    /// the header contains no ether type.
+    #[inline]
+    pub fn payload_ether_type(&self) -> Option<EtherType> {
+        use LinkHeader::*;
+        match self {
+            Ethernet2(eth) => eth.payload_ether_type(),
+            LinuxSll(sll) => sll.payload_ether_type(),
+            LinuxSll2(sll2) => sll2.payload_ether_type(),
+        }
+    }
+
+    /// Sets the field describing the type of the payload.
+    ///
+    /// In case of an Linux cooked capture header the `protocol_type`
+    /// field is set.
+    #[inline]
+    pub fn set_payload_ether_type(&mut self, ether_type: EtherType) {
+        use LinkHeader::*;
+        match self {
+            Ethernet2(eth) => eth.ether_type = ether_type,
+            LinuxSll(sll) => sll.protocol_type = ether_type,
+            LinuxSll2(sll2) => sll2.protocol_type = ether_type,
+        }
+    }
+
+    /// Write the link header to the given writer.
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn write<T: std::io::Write + Sized>(&self, writer: &mut T) -> Result<(), std::io::Error> {
+        use LinkHeader::*;
+        match self {
+            Ethernet2(header) => header.write(writer),
+            LinuxSll(header) => header.write(writer),
+            LinuxSll2(header) => header.write(writer),
+        }
+    }
+
+    /// Length of the serialized header in bytes.
+    #[inline]
+    pub fn header_len(&self) -> usize {
+        use LinkHeader::*;
+        match self {
+            Ethernet2(_) => Ethernet2Header::LEN,
+            LinuxSll(_) => LinuxSllHeader::LEN,
+            LinuxSll2(_) => LinuxSll2Header::LEN,
+        }
+    }
+}
+
+impl From<Ethernet2Header> for LinkHeader {
+    #[inline]
+    fn from(value: Ethernet2Header) -> Self {
+        LinkHeader::Ethernet2(value)
+    }
+}
+
+impl From<LinuxSllHeader> for LinkHeader {
+    #[inline]
+    fn from(value: LinuxSllHeader) -> Self {
+        LinkHeader::LinuxSll(value)
+    }
+}
+
+impl From<LinuxSll2Header> for LinkHeader {
+    #[inline]
+    fn from(value: LinuxSll2Header) -> Self {
+        LinkHeader::LinuxSll2(value)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_gens::*;
+    use alloc::{format, vec::Vec};
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn clone_eq_dbg(
+            eth in ethernet_2_any(),
+            sll in linux_sll_any(),
+            sll2 in linux_sll2_any(),
+        ) {
+            let values = [
+                (LinkHeader::Ethernet2(eth.clone()), format!("Ethernet2({:?})", eth)),
+                (LinkHeader::LinuxSll(sll.clone()), format!("LinuxSll({:?})", sll)),
+                (LinkHeader::LinuxSll2(sll2.clone()), format!("LinuxSll2({:?})", sll2)),
+            ];
+            for (value, expected_dbg) in values {
+                assert_eq!(value, value.clone());
+                assert_eq!(expected_dbg, format!("{:?}", value));
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn from(
+            eth in ethernet_2_any(),
+            sll in linux_sll_any(),
+            sll2 in linux_sll2_any(),
+        ) {
+            assert_eq!(LinkHeader::Ethernet2(eth.clone()), LinkHeader::from(eth));
+            assert_eq!(LinkHeader::LinuxSll(sll.clone()), LinkHeader::from(sll));
+            assert_eq!(LinkHeader::LinuxSll2(sll2.clone()), LinkHeader::from(sll2));
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn ethernet2(
+            eth in ethernet_2_any(),
+            sll in linux_sll_any(),
+        ) {
+            assert_eq!(Some(&eth), LinkHeader::Ethernet2(eth.clone()).ethernet2());
+            assert_eq!(None, LinkHeader::LinuxSll(sll).ethernet2());
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn payload_ether_type(
+            eth in ethernet_2_any(),
+            sll in linux_sll_any(),
+            sll2 in linux_sll2_any(),
+            ether_type in ether_type_any(),
+        ) {
+            assert_eq!(eth.payload_ether_type(), LinkHeader::Ethernet2(eth.clone()).payload_ether_type());
+            assert_eq!(sll.payload_ether_type(), LinkHeader::LinuxSll(sll.clone()).payload_ether_type());
+            assert_eq!(sll2.payload_ether_type(), LinkHeader::LinuxSll2(sll2.clone()).payload_ether_type());
+
+            // set
+            {
+                let mut value = LinkHeader::Ethernet2(eth.clone());
+                value.set_payload_ether_type(ether_type);
+                assert_eq!(ether_type, value.ethernet2().unwrap().ether_type);
+            }
+            {
+                let mut value = LinkHeader::LinuxSll(sll.clone());
+                value.set_payload_ether_type(ether_type);
+                assert_eq!(
+                    LinkHeader::LinuxSll(LinuxSllHeader{ protocol_type: ether_type, ..sll.clone() }),
+                    value
+                );
+            }
+            {
+                let mut value = LinkHeader::LinuxSll2(sll2.clone());
+                value.set_payload_ether_type(ether_type);
+                assert_eq!(
+                    LinkHeader::LinuxSll2(LinuxSll2Header{ protocol_type: ether_type, ..sll2.clone() }),
+                    value
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn header_len_write(
+            eth in ethernet_2_any(),
+            sll in linux_sll_any(),
+            sll2 in linux_sll2_any(),
+        ) {
+            let values = [
+                (LinkHeader::Ethernet2(eth.clone()), eth.to_bytes().to_vec()),
+                (LinkHeader::LinuxSll(sll.clone()), sll.to_bytes().to_vec()),
+                (LinkHeader::LinuxSll2(sll2.clone()), sll2.to_bytes().to_vec()),
+            ];
+            for (value, expected) in values {
+                assert_eq!(expected.len(), value.header_len());
+                let mut buffer = Vec::with_capacity(value.header_len());
+                value.write(&mut buffer).unwrap();
+                assert_eq!(expected, buffer);
+            }
+        }
+    }
+}
@@ -1,19 +1,36 @@
 use crate::*;
 
-/// A slice containing the link layer header (currently only Ethernet II is supported).
+/// A slice containing the link layer header (e.g. Ethernet II or Linux cooked capture).
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum LinkSlice<'a> {
     /// A slice containing an Ethernet II header.
     Ethernet2(Ethernet2HeaderSlice<'a>),
+    /// A slice containing a Linux cooked capture (SLL) header.
+    LinuxSll(LinuxSllHeaderSlice<'a>),
+    /// A slice containing a Linux cooked capture v2 (SLL2) header.
+    LinuxSll2(LinuxSll2HeaderSlice<'a>),
 }
 
 impl<'a> LinkSlice<'a> {
-    /// Convert the link slice to a header (currently just the
-    /// ethernet2 header as this is the only value it can take).
-    pub fn to_header(&self) -> Ethernet2Header {
+    /// Convert the link slice to a header.
+    pub fn to_header(&self) -> LinkHeader {
         use LinkSlice::*;
         match self {
-            Ethernet2(slice) => slice.to_header(),
+            Ethernet2(slice) => LinkHeader::Ethernet2(slice.to_header()),
+            LinuxSll(slice) => LinkHeader::LinuxSll(slice.to_header()),
+            LinuxSll2(slice) => LinkHeader::LinuxSll2(slice.to_header()),
+        }
+    }
+
+    /// Returns the ether type of the payload following the link
+    /// header (if the link header contains an ether type).
+    #[inline]
+    pub fn payload_ether_type(&self) -> Option<EtherType> {
+        use LinkSlice::*;
+        match self {
+            Ethernet2(eth) => eth.payload_ether_type(),
+            LinuxSll(sll) => sll.payload_ether_type(),
+            LinuxSll2(sll2) => sll2.payload_ether_type(),
         }
     }
 }
@@ -47,17 +64,109 @@ mod test {
 
     proptest! {
         #[test]
-        fn to_header(ref eth in ethernet_2_unknown()) {
-            let bytes = eth.to_bytes();
-            let slice = LinkSlice::Ethernet2(
-                Ethernet2HeaderSlice::from_slice(&bytes).unwrap()
-            );
+        fn debug_clone_eq_sll(
+            ref sll in linux_sll_any(),
+            ref sll2 in linux_sll2_any(),
+        ) {
+            // sll
+            {
+                let bytes = sll.to_bytes();
+                let s = LinuxSllHeaderSlice::from_slice(&bytes).unwrap();
+                let slice = LinkSlice::LinuxSll(s.clone());
+                assert_eq!(slice.clone(), slice);
+                assert_eq!(
+                    format!("{:?}", slice),
+                    format!("LinuxSll({:?})", s),
+                );
+            }
+            // sll2
+            {
+                let bytes = sll2.to_bytes();
+                let s = LinuxSll2HeaderSlice::from_slice(&bytes).unwrap();
+                let slice = LinkSlice::LinuxSll2(s.clone());
+                assert_eq!(slice.clone(), slice);
+                assert_eq!(
+                    format!("{:?}", slice),
+                    format!("LinuxSll2({:?})", s),
+                );
+            }
+        }
+    }
 
-            // clone & eq
-            assert_eq!(
-                slice.to_header(),
-                *eth
-            );
+    proptest! {
+        #[test]
+        fn to_header(
+            ref eth in ethernet_2_unknown(),
+            ref sll in linux_sll_any(),
+            ref sll2 in linux_sll2_any(),
+        ) {
+            // ethernet 2
+            {
+                let bytes = eth.to_bytes();
+                let slice = LinkSlice::Ethernet2(
+                    Ethernet2HeaderSlice::from_slice(&bytes).unwrap()
+                );
+                assert_eq!(
+                    slice.to_header(),
+                    LinkHeader::Ethernet2(eth.clone())
+                );
+            }
+            // sll
+            {
+                let bytes = sll.to_bytes();
+                let slice = LinkSlice::LinuxSll(
+                    LinuxSllHeaderSlice::from_slice(&bytes).unwrap()
+                );
+                assert_eq!(
+                    slice.to_header(),
+                    LinkHeader::LinuxSll(sll.clone())
+                );
+            }
+            // sll2
+            {
+                let bytes = sll2.to_bytes();
+                let slice = LinkSlice::LinuxSll2(
+                    LinuxSll2HeaderSlice::from_slice(&bytes).unwrap()
+                );
+                assert_eq!(
+                    slice.to_header(),
+                    LinkHeader::LinuxSll2(sll2.clone())
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn payload_ether_type(
+            ref eth in ethernet_2_unknown(),
+            ref sll in linux_sll_any(),
+            ref sll2 in linux_sll2_any(),
+        ) {
+            // ethernet 2
+            {
+                let bytes = eth.to_bytes();
+                let slice = LinkSlice::Ethernet2(
+                    Ethernet2HeaderSlice::from_slice(&bytes).unwrap()
+                );
+                assert_eq!(eth.payload_ether_type(), slice.payload_ether_type());
+            }
+            // sll
+            {
+                let bytes = sll.to_bytes();
+                let slice = LinkSlice::LinuxSll(
+                    LinuxSllHeaderSlice::from_slice(&bytes).unwrap()
+                );
+                assert_eq!(sll.payload_ether_type(), slice.payload_ether_type());
+            }
+            // sll2
+            {
+                let bytes = sll2.to_bytes();
+                let slice = LinkSlice::LinuxSll2(
+                    LinuxSll2HeaderSlice::from_slice(&bytes).unwrap()
+                );
+                assert_eq!(sll2.payload_ether_type(), slice.payload_ether_type());
+            }
         }
     }
 }
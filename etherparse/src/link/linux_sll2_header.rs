@@ -0,0 +1,342 @@
+use crate::{err::Layer, err::SliceWriteSpaceError, *};
+
+/// Linux cooked capture v2 header (also called "SLL2" header).
+///
+/// Successor of the [`LinuxSllHeader`] that additionally contains the
+/// index of the interface the packet was captured on.
+///
+/// See <https://www.tcpdump.org/linktypes/LINKTYPE_LINUX_SLL2.html>
+/// for the details of the format.
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Default)]
+pub struct LinuxSll2Header {
+    /// Protocol of the payload.
+    ///
+    /// For most device types this is an ether type, but for some
+    /// (e.g. [`ArpHardwareId::NETLINK`]) the field has a different
+    /// meaning (see [`LinuxSll2Header::payload_ether_type`]).
+    pub protocol_type: EtherType,
+    /// Index of the interface the packet was captured on.
+    pub interface_index: u32,
+    /// Linux `ARPHRD_*` value of the device the packet was captured on.
+    pub arp_hrd_type: ArpHardwareId,
+    /// Describes to whom the packet was sent (see [`LinuxSllPacketType`]
+    /// for the known values).
+    ///
+    /// Note that in contrast to the SLL header this field is only
+    /// 8 bits wide in the SLL2 header.
+    pub packet_type: u8,
+    /// Number of bytes in `sender_address` that contain the link
+    /// layer address of the sender.
+    pub sender_address_valid_length: u8,
+    /// Link layer address of the sender (only the first
+    /// `sender_address_valid_length` bytes are valid).
+    pub sender_address: [u8; 8],
+}
+
+impl LinuxSll2Header {
+    /// Serialized size of a Linux cooked capture v2 header in bytes/octets.
+    pub const LEN: usize = 20;
+
+    /// Read a [`LinuxSll2Header`] from a slice and return the header & unused parts of the slice.
+    #[inline]
+    pub fn from_slice(slice: &[u8]) -> Result<(LinuxSll2Header, &[u8]), err::LenError> {
+        Ok((
+            LinuxSll2HeaderSlice::from_slice(slice)?.to_header(),
+            &slice[LinuxSll2Header::LEN..],
+        ))
+    }
+
+    /// Read a [`LinuxSll2Header`] from a static sized byte array.
+    ///
+    /// Note that the reserved field (bytes 2 & 3) is ignored.
+    #[inline]
+    pub fn from_bytes(bytes: [u8; 20]) -> LinuxSll2Header {
+        LinuxSll2Header {
+            protocol_type: EtherType(u16::from_be_bytes([bytes[0], bytes[1]])),
+            interface_index: u32::from_be_bytes([bytes[4], bytes[5], bytes[6], bytes[7]]),
+            arp_hrd_type: ArpHardwareId(u16::from_be_bytes([bytes[8], bytes[9]])),
+            packet_type: bytes[10],
+            sender_address_valid_length: bytes[11],
+            sender_address: [
+                bytes[12], bytes[13], bytes[14], bytes[15], bytes[16], bytes[17], bytes[18],
+                bytes[19],
+            ],
+        }
+    }
+
+    /// Reads a Linux cooked capture v2 header from the current position of the read argument.
+    #[cfg(feature = "std")]
+    pub fn read<T: std::io::Read + std::io::Seek + Sized>(
+        reader: &mut T,
+    ) -> Result<LinuxSll2Header, std::io::Error> {
+        let mut buffer = [0; LinuxSll2Header::LEN];
+        reader.read_exact(&mut buffer)?;
+        Ok(LinuxSll2Header::from_bytes(buffer))
+    }
+
+    /// Returns the ether type of the payload if the `protocol_type`
+    /// field contains an ether type.
+    ///
+    /// The same rules as for [`LinuxSllHeader::payload_ether_type`] apply.
+    #[inline]
+    pub fn payload_ether_type(&self) -> Option<EtherType> {
+        crate::link::linux_sll_header::sll_payload_ether_type(self.arp_hrd_type, self.protocol_type)
+    }
+
+    /// Serialize the header to a given slice. Returns the unused part of the slice.
+    pub fn write_to_slice<'a>(
+        &self,
+        slice: &'a mut [u8],
+    ) -> Result<&'a mut [u8], SliceWriteSpaceError> {
+        // length check
+        if slice.len() < LinuxSll2Header::LEN {
+            Err(SliceWriteSpaceError {
+                required_len: LinuxSll2Header::LEN,
+                len: slice.len(),
+                layer: Layer::LinuxSll2Header,
+                layer_start_offset: 0,
+            })
+        } else {
+            slice[..LinuxSll2Header::LEN].copy_from_slice(&self.to_bytes());
+            Ok(&mut slice[LinuxSll2Header::LEN..])
+        }
+    }
+
+    /// Writes a given Linux cooked capture v2 header to the current position of the write argument.
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn write<T: std::io::Write + Sized>(&self, writer: &mut T) -> Result<(), std::io::Error> {
+        writer.write_all(&self.to_bytes())
+    }
+
+    /// Length of the serialized header in bytes.
+    #[inline]
+    pub fn header_len(&self) -> usize {
+        LinuxSll2Header::LEN
+    }
+
+    /// Returns the serialized form of the header as a statically
+    /// sized byte array (the reserved field is set to zero).
+    #[inline]
+    pub fn to_bytes(&self) -> [u8; 20] {
+        let protocol_type_be = self.protocol_type.0.to_be_bytes();
+        let if_index_be = self.interface_index.to_be_bytes();
+        let arp_hrd_type_be = self.arp_hrd_type.0.to_be_bytes();
+        [
+            protocol_type_be[0],
+            protocol_type_be[1],
+            0,
+            0,
+            if_index_be[0],
+            if_index_be[1],
+            if_index_be[2],
+            if_index_be[3],
+            arp_hrd_type_be[0],
+            arp_hrd_type_be[1],
+            self.packet_type,
+            self.sender_address_valid_length,
+            self.sender_address[0],
+            self.sender_address[1],
+            self.sender_address[2],
+            self.sender_address[3],
+            self.sender_address[4],
+            self.sender_address[5],
+            self.sender_address[6],
+            self.sender_address[7],
+        ]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_gens::*;
+    use alloc::{format, vec::Vec};
+    use proptest::prelude::*;
+    use std::io::{Cursor, ErrorKind};
+
+    proptest! {
+        #[test]
+        fn from_slice(
+            input in linux_sll2_any(),
+            dummy_data in proptest::collection::vec(any::<u8>(), 0..20)
+        ) {
+            // serialize
+            let mut buffer: Vec<u8> = Vec::with_capacity(20 + dummy_data.len());
+            input.write(&mut buffer).unwrap();
+            buffer.extend(&dummy_data[..]);
+
+            // calls with a valid result
+            {
+                let (result, rest) = LinuxSll2Header::from_slice(&buffer[..]).unwrap();
+                assert_eq!(input, result);
+                assert_eq!(&buffer[20..], rest);
+            }
+
+            // call with not enough data in the slice
+            for len in 0..20 {
+                assert_eq!(
+                    LinuxSll2Header::from_slice(&buffer[..len]),
+                    Err(err::LenError{
+                        required_len: LinuxSll2Header::LEN,
+                        len,
+                        len_source: err::LenSource::Slice,
+                        layer: err::Layer::LinuxSll2Header,
+                        layer_start_offset: 0,
+                    })
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn from_bytes(input in linux_sll2_any()) {
+            assert_eq!(
+                input,
+                LinuxSll2Header::from_bytes(input.to_bytes())
+            );
+
+            // reserved field is ignored
+            let mut bytes = input.to_bytes();
+            bytes[2] = 0xff;
+            bytes[3] = 0xff;
+            assert_eq!(input, LinuxSll2Header::from_bytes(bytes));
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn read(
+            input in linux_sll2_any(),
+            dummy_data in proptest::collection::vec(any::<u8>(), 0..20)
+        ) {
+            let mut buffer = Vec::with_capacity(20 + dummy_data.len());
+            input.write(&mut buffer).unwrap();
+            buffer.extend(&dummy_data[..]);
+
+            // calls with a valid result
+            {
+                let mut cursor = Cursor::new(&buffer);
+                let result = LinuxSll2Header::read(&mut cursor).unwrap();
+                assert_eq!(input, result);
+                assert_eq!(cursor.position(), 20);
+            }
+
+            // unexpected eof
+            for len in 0..20 {
+                let mut cursor = Cursor::new(&buffer[0..len]);
+                assert_eq!(
+                    LinuxSll2Header::read(&mut cursor)
+                    .unwrap_err()
+                    .kind(),
+                    ErrorKind::UnexpectedEof
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn payload_ether_type(input in linux_sll2_any()) {
+            let sll = LinuxSllHeader {
+                packet_type: LinuxSllPacketType::from(input.packet_type),
+                arp_hrd_type: input.arp_hrd_type,
+                sender_address_valid_length: input.sender_address_valid_length.into(),
+                sender_address: input.sender_address,
+                protocol_type: input.protocol_type,
+            };
+            assert_eq!(sll.payload_ether_type(), input.payload_ether_type());
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn write_to_slice(input in linux_sll2_any()) {
+            // normal write
+            {
+                let mut buffer: [u8;20] = [0;20];
+                input.write_to_slice(&mut buffer).unwrap();
+                assert_eq!(buffer, input.to_bytes());
+            }
+            // len to small
+            for len in 0..20 {
+                let mut buffer: [u8;20] = [0;20];
+                assert_eq!(
+                    SliceWriteSpaceError {
+                        required_len: LinuxSll2Header::LEN,
+                        len,
+                        layer: Layer::LinuxSll2Header,
+                        layer_start_offset: 0,
+                    },
+                    input.write_to_slice(&mut buffer[..len]).unwrap_err()
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn write(input in linux_sll2_any()) {
+            // successfull write
+            {
+                let mut buffer: Vec<u8> = Vec::with_capacity(20);
+                input.write(&mut buffer).unwrap();
+                assert_eq!(&buffer[..], &input.to_bytes());
+            }
+
+            // not enough memory for write (unexpected eof)
+            for len in 0..20 {
+                let mut buffer = [0u8;20];
+                let mut writer = Cursor::new(&mut buffer[..len]);
+                assert!(input.write(&mut writer).is_err());
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn header_len(input in linux_sll2_any()) {
+            assert_eq!(input.header_len(), 20);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn to_bytes(input in linux_sll2_any()) {
+            let bytes = input.to_bytes();
+            assert_eq!(&bytes[0..2], &input.protocol_type.0.to_be_bytes());
+            assert_eq!(&bytes[2..4], &[0, 0]);
+            assert_eq!(&bytes[4..8], &input.interface_index.to_be_bytes());
+            assert_eq!(&bytes[8..10], &input.arp_hrd_type.0.to_be_bytes());
+            assert_eq!(bytes[10], input.packet_type);
+            assert_eq!(bytes[11], input.sender_address_valid_length);
+            assert_eq!(&bytes[12..20], &input.sender_address);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn clone_eq(input in linux_sll2_any()) {
+            assert_eq!(input, input.clone());
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn dbg(input in linux_sll2_any()) {
+            assert_eq!(
+                &format!(
+                    "LinuxSll2Header {{ protocol_type: {:?}, interface_index: {:?}, arp_hrd_type: {:?}, packet_type: {:?}, sender_address_valid_length: {:?}, sender_address: {:?} }}",
+                    input.protocol_type,
+                    input.interface_index,
+                    input.arp_hrd_type,
+                    input.packet_type,
+                    input.sender_address_valid_length,
+                    input.sender_address,
+                ),
+                &format!("{:?}", input)
+            );
+        }
+    }
+}
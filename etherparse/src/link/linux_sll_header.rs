@@ -0,0 +1,367 @@
+use crate::{err::Layer, err::SliceWriteSpaceError, *};
+
+/// Linux cooked capture header (also called "SLL" header).
+///
+/// The header is generated by libpcap & the Linux kernel in place of
+/// the real link layer header when capturing on the "any" device
+/// (e.g. `tcpdump -i any`) or on devices without a link layer
+/// header.
+///
+/// See <https://www.tcpdump.org/linktypes/LINKTYPE_LINUX_SLL.html>
+/// for the details of the format.
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Default)]
+pub struct LinuxSllHeader {
+    /// Describes to whom the packet was sent (e.g. host, broadcast or outgoing).
+    pub packet_type: LinuxSllPacketType,
+    /// Linux `ARPHRD_*` value of the device the packet was captured on.
+    pub arp_hrd_type: ArpHardwareId,
+    /// Number of bytes in `sender_address` that contain the link
+    /// layer address of the sender.
+    pub sender_address_valid_length: u16,
+    /// Link layer address of the sender (only the first
+    /// `sender_address_valid_length` bytes are valid).
+    pub sender_address: [u8; 8],
+    /// Protocol of the payload.
+    ///
+    /// For most device types this is an ether type, but for some
+    /// (e.g. [`ArpHardwareId::NETLINK`]) the field has a different
+    /// meaning (see [`LinuxSllHeader::payload_ether_type`]).
+    pub protocol_type: EtherType,
+}
+
+impl LinuxSllHeader {
+    /// Serialized size of a Linux cooked capture header in bytes/octets.
+    pub const LEN: usize = 16;
+
+    /// Read a [`LinuxSllHeader`] from a slice and return the header & unused parts of the slice.
+    #[inline]
+    pub fn from_slice(slice: &[u8]) -> Result<(LinuxSllHeader, &[u8]), err::LenError> {
+        Ok((
+            LinuxSllHeaderSlice::from_slice(slice)?.to_header(),
+            &slice[LinuxSllHeader::LEN..],
+        ))
+    }
+
+    /// Read a [`LinuxSllHeader`] from a static sized byte array.
+    #[inline]
+    pub fn from_bytes(bytes: [u8; 16]) -> LinuxSllHeader {
+        LinuxSllHeader {
+            packet_type: LinuxSllPacketType(u16::from_be_bytes([bytes[0], bytes[1]])),
+            arp_hrd_type: ArpHardwareId(u16::from_be_bytes([bytes[2], bytes[3]])),
+            sender_address_valid_length: u16::from_be_bytes([bytes[4], bytes[5]]),
+            sender_address: [
+                bytes[6], bytes[7], bytes[8], bytes[9], bytes[10], bytes[11], bytes[12], bytes[13],
+            ],
+            protocol_type: EtherType(u16::from_be_bytes([bytes[14], bytes[15]])),
+        }
+    }
+
+    /// Reads a Linux cooked capture header from the current position of the read argument.
+    #[cfg(feature = "std")]
+    pub fn read<T: std::io::Read + std::io::Seek + Sized>(
+        reader: &mut T,
+    ) -> Result<LinuxSllHeader, std::io::Error> {
+        let mut buffer = [0; LinuxSllHeader::LEN];
+        reader.read_exact(&mut buffer)?;
+        Ok(LinuxSllHeader::from_bytes(buffer))
+    }
+
+    /// Returns the ether type of the payload if the `protocol_type`
+    /// field contains an ether type.
+    ///
+    /// `None` is returned if the device type is [`ArpHardwareId::NETLINK`]
+    /// (the field then contains the netlink protocol) or if the value is
+    /// smaller then `0x0600` (Linux `ETH_P_*` pseudo protocols like
+    /// `ETH_P_802_3` or `ETH_P_802_2`).
+    #[inline]
+    pub fn payload_ether_type(&self) -> Option<EtherType> {
+        sll_payload_ether_type(self.arp_hrd_type, self.protocol_type)
+    }
+
+    /// Serialize the header to a given slice. Returns the unused part of the slice.
+    pub fn write_to_slice<'a>(
+        &self,
+        slice: &'a mut [u8],
+    ) -> Result<&'a mut [u8], SliceWriteSpaceError> {
+        // length check
+        if slice.len() < LinuxSllHeader::LEN {
+            Err(SliceWriteSpaceError {
+                required_len: LinuxSllHeader::LEN,
+                len: slice.len(),
+                layer: Layer::LinuxSllHeader,
+                layer_start_offset: 0,
+            })
+        } else {
+            slice[..LinuxSllHeader::LEN].copy_from_slice(&self.to_bytes());
+            Ok(&mut slice[LinuxSllHeader::LEN..])
+        }
+    }
+
+    /// Writes a given Linux cooked capture header to the current position of the write argument.
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn write<T: std::io::Write + Sized>(&self, writer: &mut T) -> Result<(), std::io::Error> {
+        writer.write_all(&self.to_bytes())
+    }
+
+    /// Length of the serialized header in bytes.
+    #[inline]
+    pub fn header_len(&self) -> usize {
+        LinuxSllHeader::LEN
+    }
+
+    /// Returns the serialized form of the header as a statically
+    /// sized byte array.
+    #[inline]
+    pub fn to_bytes(&self) -> [u8; 16] {
+        let packet_type_be = self.packet_type.0.to_be_bytes();
+        let arp_hrd_type_be = self.arp_hrd_type.0.to_be_bytes();
+        let addr_len_be = self.sender_address_valid_length.to_be_bytes();
+        let protocol_type_be = self.protocol_type.0.to_be_bytes();
+        [
+            packet_type_be[0],
+            packet_type_be[1],
+            arp_hrd_type_be[0],
+            arp_hrd_type_be[1],
+            addr_len_be[0],
+            addr_len_be[1],
+            self.sender_address[0],
+            self.sender_address[1],
+            self.sender_address[2],
+            self.sender_address[3],
+            self.sender_address[4],
+            self.sender_address[5],
+            self.sender_address[6],
+            self.sender_address[7],
+            protocol_type_be[0],
+            protocol_type_be[1],
+        ]
+    }
+}
+
+/// Determines the ether type of the payload of a SLL or SLL2 header
+/// based on the device type & protocol type field.
+#[inline]
+pub(crate) fn sll_payload_ether_type(
+    arp_hrd_type: ArpHardwareId,
+    protocol_type: EtherType,
+) -> Option<EtherType> {
+    if ArpHardwareId::NETLINK == arp_hrd_type || protocol_type.0 < 0x0600 {
+        None
+    } else {
+        Some(protocol_type)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_gens::*;
+    use alloc::{format, vec::Vec};
+    use proptest::prelude::*;
+    use std::io::{Cursor, ErrorKind};
+
+    proptest! {
+        #[test]
+        fn from_slice(
+            input in linux_sll_any(),
+            dummy_data in proptest::collection::vec(any::<u8>(), 0..20)
+        ) {
+            // serialize
+            let mut buffer: Vec<u8> = Vec::with_capacity(16 + dummy_data.len());
+            input.write(&mut buffer).unwrap();
+            buffer.extend(&dummy_data[..]);
+
+            // calls with a valid result
+            {
+                let (result, rest) = LinuxSllHeader::from_slice(&buffer[..]).unwrap();
+                assert_eq!(input, result);
+                assert_eq!(&buffer[16..], rest);
+            }
+
+            // call with not enough data in the slice
+            for len in 0..16 {
+                assert_eq!(
+                    LinuxSllHeader::from_slice(&buffer[..len]),
+                    Err(err::LenError{
+                        required_len: LinuxSllHeader::LEN,
+                        len,
+                        len_source: err::LenSource::Slice,
+                        layer: err::Layer::LinuxSllHeader,
+                        layer_start_offset: 0,
+                    })
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn from_bytes(input in linux_sll_any()) {
+            assert_eq!(
+                input,
+                LinuxSllHeader::from_bytes(input.to_bytes())
+            );
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn read(
+            input in linux_sll_any(),
+            dummy_data in proptest::collection::vec(any::<u8>(), 0..20)
+        ) {
+            let mut buffer = Vec::with_capacity(16 + dummy_data.len());
+            input.write(&mut buffer).unwrap();
+            buffer.extend(&dummy_data[..]);
+
+            // calls with a valid result
+            {
+                let mut cursor = Cursor::new(&buffer);
+                let result = LinuxSllHeader::read(&mut cursor).unwrap();
+                assert_eq!(input, result);
+                assert_eq!(cursor.position(), 16);
+            }
+
+            // unexpected eof
+            for len in 0..16 {
+                let mut cursor = Cursor::new(&buffer[0..len]);
+                assert_eq!(
+                    LinuxSllHeader::read(&mut cursor)
+                    .unwrap_err()
+                    .kind(),
+                    ErrorKind::UnexpectedEof
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn payload_ether_type() {
+        let base = LinuxSllHeader {
+            packet_type: LinuxSllPacketType::HOST,
+            arp_hrd_type: ArpHardwareId::ETHERNET,
+            sender_address_valid_length: 6,
+            sender_address: [1, 2, 3, 4, 5, 6, 0, 0],
+            protocol_type: ether_type::IPV4,
+        };
+        assert_eq!(Some(ether_type::IPV4), base.payload_ether_type());
+
+        // netlink
+        assert_eq!(
+            None,
+            LinuxSllHeader {
+                arp_hrd_type: ArpHardwareId::NETLINK,
+                ..base.clone()
+            }
+            .payload_ether_type()
+        );
+
+        // pseudo protocols (e.g. ETH_P_802_2)
+        for (protocol_type, expected) in [
+            (0x0004, None),
+            (0x05ff, None),
+            (0x0600, Some(EtherType(0x0600))),
+        ] {
+            assert_eq!(
+                expected,
+                LinuxSllHeader {
+                    protocol_type: EtherType(protocol_type),
+                    ..base.clone()
+                }
+                .payload_ether_type()
+            );
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn write_to_slice(input in linux_sll_any()) {
+            // normal write
+            {
+                let mut buffer: [u8;16] = [0;16];
+                input.write_to_slice(&mut buffer).unwrap();
+                assert_eq!(buffer, input.to_bytes());
+            }
+            // len to small
+            for len in 0..16 {
+                let mut buffer: [u8;16] = [0;16];
+                assert_eq!(
+                    SliceWriteSpaceError {
+                        required_len: LinuxSllHeader::LEN,
+                        len,
+                        layer: Layer::LinuxSllHeader,
+                        layer_start_offset: 0,
+                    },
+                    input.write_to_slice(&mut buffer[..len]).unwrap_err()
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn write(input in linux_sll_any()) {
+            // successfull write
+            {
+                let mut buffer: Vec<u8> = Vec::with_capacity(16);
+                input.write(&mut buffer).unwrap();
+                assert_eq!(&buffer[..], &input.to_bytes());
+            }
+
+            // not enough memory for write (unexpected eof)
+            for len in 0..16 {
+                let mut buffer = [0u8;16];
+                let mut writer = Cursor::new(&mut buffer[..len]);
+                assert!(input.write(&mut writer).is_err());
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn header_len(input in linux_sll_any()) {
+            assert_eq!(input.header_len(), 16);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn to_bytes(input in linux_sll_any()) {
+            let packet_type_be = input.packet_type.0.to_be_bytes();
+            let arp_hrd_type_be = input.arp_hrd_type.0.to_be_bytes();
+            let addr_len_be = input.sender_address_valid_length.to_be_bytes();
+            let protocol_type_be = input.protocol_type.0.to_be_bytes();
+            let bytes = input.to_bytes();
+            assert_eq!(&bytes[0..2], &packet_type_be);
+            assert_eq!(&bytes[2..4], &arp_hrd_type_be);
+            assert_eq!(&bytes[4..6], &addr_len_be);
+            assert_eq!(&bytes[6..14], &input.sender_address);
+            assert_eq!(&bytes[14..16], &protocol_type_be);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn clone_eq(input in linux_sll_any()) {
+            assert_eq!(input, input.clone());
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn dbg(input in linux_sll_any()) {
+            assert_eq!(
+                &format!(
+                    "LinuxSllHeader {{ packet_type: {:?}, arp_hrd_type: {:?}, sender_address_valid_length: {:?}, sender_address: {:?}, protocol_type: {:?} }}",
+                    input.packet_type,
+                    input.arp_hrd_type,
+                    input.sender_address_valid_length,
+                    input.sender_address,
+                    input.protocol_type,
+                ),
+                &format!("{:?}", input)
+            );
+        }
+    }
+}
@@ -0,0 +1,211 @@
+use crate::*;
+use core::slice::from_raw_parts;
+
+/// A slice containing a Linux cooked capture (SLL) header of a network package.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LinuxSllHeaderSlice<'a> {
+    slice: &'a [u8],
+}
+
+impl<'a> LinuxSllHeaderSlice<'a> {
+    /// Creates a SLL header slice from an other slice.
+    pub fn from_slice(slice: &'a [u8]) -> Result<LinuxSllHeaderSlice<'a>, err::LenError> {
+        //check length
+        if slice.len() < LinuxSllHeader::LEN {
+            return Err(err::LenError {
+                required_len: LinuxSllHeader::LEN,
+                len: slice.len(),
+                len_source: err::LenSource::Slice,
+                layer: err::Layer::LinuxSllHeader,
+                layer_start_offset: 0,
+            });
+        }
+
+        //all done
+        Ok(LinuxSllHeaderSlice {
+            // SAFETY:
+            // Safe as slice length is checked to be at least
+            // LinuxSllHeader::LEN (16) before this.
+            slice: unsafe { from_raw_parts(slice.as_ptr(), LinuxSllHeader::LEN) },
+        })
+    }
+
+    /// Returns the slice containing the SLL header
+    #[inline]
+    pub fn slice(&self) -> &'a [u8] {
+        self.slice
+    }
+
+    /// Read the packet type field (describes to whom the packet was sent).
+    #[inline]
+    pub fn packet_type(&self) -> LinuxSllPacketType {
+        // SAFETY:
+        // Safe as the contructor checks that the slice has
+        // at least the length of LinuxSllHeader::LEN (16).
+        LinuxSllPacketType(unsafe { get_unchecked_be_u16(self.slice.as_ptr()) })
+    }
+
+    /// Read the `ARPHRD_*` type of the device the packet was captured on.
+    #[inline]
+    pub fn arp_hrd_type(&self) -> ArpHardwareId {
+        // SAFETY:
+        // Safe as the contructor checks that the slice has
+        // at least the length of LinuxSllHeader::LEN (16).
+        ArpHardwareId(unsafe { get_unchecked_be_u16(self.slice.as_ptr().add(2)) })
+    }
+
+    /// Read the number of valid bytes in the sender address.
+    #[inline]
+    pub fn sender_address_valid_length(&self) -> u16 {
+        // SAFETY:
+        // Safe as the contructor checks that the slice has
+        // at least the length of LinuxSllHeader::LEN (16).
+        unsafe { get_unchecked_be_u16(self.slice.as_ptr().add(4)) }
+    }
+
+    /// Read the sender address field (only the first
+    /// `sender_address_valid_length` bytes are valid).
+    #[inline]
+    pub fn sender_address_full(&self) -> [u8; 8] {
+        // SAFETY:
+        // Safe as the contructor checks that the slice has
+        // at least the length of LinuxSllHeader::LEN (16).
+        unsafe { get_unchecked_8_byte_array(self.slice.as_ptr().add(6)) }
+    }
+
+    /// Returns the valid part of the sender address (limited by
+    /// `sender_address_valid_length` and the 8 bytes available in
+    /// the header).
+    #[inline]
+    pub fn sender_address(&self) -> &'a [u8] {
+        let len = usize::from(self.sender_address_valid_length()).min(8);
+        // SAFETY:
+        // Safe as the contructor checks that the slice has
+        // at least the length of LinuxSllHeader::LEN (16) and
+        // len is limited to 8.
+        unsafe { from_raw_parts(self.slice.as_ptr().add(6), len) }
+    }
+
+    /// Read the protocol type field.
+    #[inline]
+    pub fn protocol_type(&self) -> EtherType {
+        // SAFETY:
+        // Safe as the contructor checks that the slice has
+        // at least the length of LinuxSllHeader::LEN (16).
+        EtherType(unsafe { get_unchecked_be_u16(self.slice.as_ptr().add(14)) })
+    }
+
+    /// Returns the ether type of the payload if the `protocol_type`
+    /// field contains an ether type (see [`LinuxSllHeader::payload_ether_type`]).
+    #[inline]
+    pub fn payload_ether_type(&self) -> Option<EtherType> {
+        crate::link::linux_sll_header::sll_payload_ether_type(
+            self.arp_hrd_type(),
+            self.protocol_type(),
+        )
+    }
+
+    /// Decode all the fields and copy the results to a [`LinuxSllHeader`] struct
+    pub fn to_header(&self) -> LinuxSllHeader {
+        LinuxSllHeader {
+            packet_type: self.packet_type(),
+            arp_hrd_type: self.arp_hrd_type(),
+            sender_address_valid_length: self.sender_address_valid_length(),
+            sender_address: self.sender_address_full(),
+            protocol_type: self.protocol_type(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_gens::*;
+    use alloc::{format, vec::Vec};
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn from_slice(
+            input in linux_sll_any(),
+            dummy_data in proptest::collection::vec(any::<u8>(), 0..20)
+        ) {
+            // serialize
+            let mut buffer: Vec<u8> = Vec::with_capacity(16 + dummy_data.len());
+            input.write(&mut buffer).unwrap();
+            buffer.extend(&dummy_data[..]);
+
+            // calls with a valid result
+            {
+                let result = LinuxSllHeaderSlice::from_slice(&buffer[..]).unwrap();
+                assert_eq!(&buffer[..16], result.slice());
+            }
+
+            // call with not enough data in the slice
+            for len in 0..16 {
+                assert_eq!(
+                    LinuxSllHeaderSlice::from_slice(&buffer[..len]),
+                    Err(err::LenError{
+                        required_len: LinuxSllHeader::LEN,
+                        len,
+                        len_source: err::LenSource::Slice,
+                        layer: err::Layer::LinuxSllHeader,
+                        layer_start_offset: 0,
+                    })
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn getters(input in linux_sll_any()) {
+            let bytes = input.to_bytes();
+            let slice = LinuxSllHeaderSlice::from_slice(&bytes).unwrap();
+
+            assert_eq!(input.packet_type, slice.packet_type());
+            assert_eq!(input.arp_hrd_type, slice.arp_hrd_type());
+            assert_eq!(input.sender_address_valid_length, slice.sender_address_valid_length());
+            assert_eq!(input.sender_address, slice.sender_address_full());
+            assert_eq!(
+                &input.sender_address[..usize::from(input.sender_address_valid_length).min(8)],
+                slice.sender_address()
+            );
+            assert_eq!(input.protocol_type, slice.protocol_type());
+            assert_eq!(input.payload_ether_type(), slice.payload_ether_type());
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn to_header(input in linux_sll_any()) {
+            let bytes = input.to_bytes();
+            let slice = LinuxSllHeaderSlice::from_slice(&bytes).unwrap();
+            assert_eq!(input, slice.to_header());
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn clone_eq(input in linux_sll_any()) {
+            let bytes = input.to_bytes();
+            let slice = LinuxSllHeaderSlice::from_slice(&bytes).unwrap();
+            assert_eq!(slice, slice.clone());
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn dbg(input in linux_sll_any()) {
+            let bytes = input.to_bytes();
+            let slice = LinuxSllHeaderSlice::from_slice(&bytes).unwrap();
+            assert_eq!(
+                &format!(
+                    "LinuxSllHeaderSlice {{ slice: {:?} }}",
+                    slice.slice(),
+                ),
+                &format!("{:?}", slice)
+            );
+        }
+    }
+}
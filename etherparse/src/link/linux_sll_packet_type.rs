@@ -0,0 +1,133 @@
+/// Packet type of a Linux cooked capture header, describing to whom
+/// the captured packet was sent (`PACKET_*` values in `if_packet.h`).
+///
+/// You can access the underlying `u16` value by using `.0` and any `u16`
+/// can be converted to a `LinuxSllPacketType`:
+///
+/// ```
+/// use etherparse::LinuxSllPacketType;
+///
+/// assert_eq!(LinuxSllPacketType::OUTGOING.0, 4);
+/// assert_eq!(LinuxSllPacketType::OUTGOING, LinuxSllPacketType(4));
+///
+/// // convert to LinuxSllPacketType using the from & into trait
+/// let packet_type: LinuxSllPacketType = 0u16.into();
+/// assert_eq!(LinuxSllPacketType::HOST, packet_type);
+///
+/// // convert to u16 using the from & into trait
+/// let num: u16 = LinuxSllPacketType::BROADCAST.into();
+/// assert_eq!(1, num);
+/// ```
+#[derive(Default, PartialEq, Eq, Clone, Copy, Hash, Ord, PartialOrd)]
+pub struct LinuxSllPacketType(pub u16);
+
+impl LinuxSllPacketType {
+    /// Packet was addressed to the local host.
+    pub const HOST: LinuxSllPacketType = Self(0);
+    /// Packet was sent as a broadcast (physical layer).
+    pub const BROADCAST: LinuxSllPacketType = Self(1);
+    /// Packet was sent as a multicast (physical layer).
+    pub const MULTICAST: LinuxSllPacketType = Self(2);
+    /// Packet was addressed to another host (captured in promiscuous mode).
+    pub const OTHERHOST: LinuxSllPacketType = Self(3);
+    /// Packet was sent by the local host.
+    pub const OUTGOING: LinuxSllPacketType = Self(4);
+    /// Packet was looped back.
+    pub const LOOPBACK: LinuxSllPacketType = Self(5);
+    /// Packet was sent to user space.
+    pub const USER: LinuxSllPacketType = Self(6);
+    /// Packet was sent to the kernel.
+    pub const KERNEL: LinuxSllPacketType = Self(7);
+}
+
+impl From<u16> for LinuxSllPacketType {
+    #[inline]
+    fn from(val: u16) -> Self {
+        LinuxSllPacketType(val)
+    }
+}
+
+impl From<u8> for LinuxSllPacketType {
+    #[inline]
+    fn from(val: u8) -> Self {
+        LinuxSllPacketType(u16::from(val))
+    }
+}
+
+impl From<LinuxSllPacketType> for u16 {
+    #[inline]
+    fn from(val: LinuxSllPacketType) -> Self {
+        val.0
+    }
+}
+
+impl core::fmt::Debug for LinuxSllPacketType {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match *self {
+            Self::HOST => write!(f, "{} (Host)", self.0),
+            Self::BROADCAST => write!(f, "{} (Broadcast)", self.0),
+            Self::MULTICAST => write!(f, "{} (Multicast)", self.0),
+            Self::OTHERHOST => write!(f, "{} (Other host)", self.0),
+            Self::OUTGOING => write!(f, "{} (Outgoing)", self.0),
+            Self::LOOPBACK => write!(f, "{} (Loopback)", self.0),
+            Self::USER => write!(f, "{} (User)", self.0),
+            Self::KERNEL => write!(f, "{} (Kernel)", self.0),
+            _ => write!(f, "{}", self.0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::format;
+
+    #[test]
+    fn to_u16() {
+        assert_eq!(0, u16::from(LinuxSllPacketType::HOST));
+        assert_eq!(4, u16::from(LinuxSllPacketType::OUTGOING));
+        assert_eq!(7, u16::from(LinuxSllPacketType::KERNEL));
+    }
+
+    #[test]
+    fn from_u16() {
+        assert_eq!(LinuxSllPacketType::from(0u16), LinuxSllPacketType::HOST);
+        assert_eq!(LinuxSllPacketType::from(4u16), LinuxSllPacketType::OUTGOING);
+        assert_eq!(
+            LinuxSllPacketType::from(0x1234u16),
+            LinuxSllPacketType(0x1234)
+        );
+    }
+
+    #[test]
+    fn from_u8() {
+        assert_eq!(LinuxSllPacketType::from(3u8), LinuxSllPacketType::OTHERHOST);
+        assert_eq!(LinuxSllPacketType::from(0xffu8), LinuxSllPacketType(0xff));
+    }
+
+    #[test]
+    fn dbg() {
+        let pairs = &[
+            (LinuxSllPacketType::HOST, "0 (Host)"),
+            (LinuxSllPacketType::BROADCAST, "1 (Broadcast)"),
+            (LinuxSllPacketType::MULTICAST, "2 (Multicast)"),
+            (LinuxSllPacketType::OTHERHOST, "3 (Other host)"),
+            (LinuxSllPacketType::OUTGOING, "4 (Outgoing)"),
+            (LinuxSllPacketType::LOOPBACK, "5 (Loopback)"),
+            (LinuxSllPacketType::USER, "6 (User)"),
+            (LinuxSllPacketType::KERNEL, "7 (Kernel)"),
+            (LinuxSllPacketType(0x1234), "4660"),
+        ];
+
+        for (input, expected) in pairs {
+            assert_eq!(expected, &format!("{:?}", input));
+        }
+    }
+
+    #[test]
+    fn clone_eq_default() {
+        let value = LinuxSllPacketType::OUTGOING;
+        assert_eq!(value, value.clone());
+        assert_eq!(LinuxSllPacketType(0), LinuxSllPacketType::default());
+    }
+}
@@ -0,0 +1,345 @@
+use crate::{err::Layer, err::SliceWriteSpaceError, *};
+use arrayvec::ArrayVec;
+
+/// IEEE 802.2 LLC (Logical Link Control) header.
+///
+/// The LLC header is present at the start of the payload of IEEE 802.3
+/// frames (Ethernet frames that contain a length instead of an ether
+/// type, see [`Ethernet2Header::ieee802_3_len`]).
+///
+/// The control field is one octet long for unnumbered (U-format) frames
+/// (lowest two bits of the first control octet set) and two octets long
+/// for information (I-format) & supervisory (S-format) frames.
+///
+/// In case DSAP & SSAP are set to [`LlcHeader::SNAP_SAP`] and the control
+/// field to `0x03` (unnumbered information) a [`SnapHeader`] follows the
+/// LLC header.
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Default)]
+pub struct LlcHeader {
+    /// Destination service access point.
+    pub dsap: u8,
+    /// Source service access point.
+    pub ssap: u8,
+    /// Control field.
+    ///
+    /// The second octet is only present for I- & S-format frames and
+    /// ignored (& expected to be zero) for U-format frames.
+    pub control: [u8; 2],
+}
+
+impl LlcHeader {
+    /// Minimum length of an LLC header in bytes/octets (U-format control field).
+    pub const MIN_LEN: usize = 3;
+
+    /// Maximum length of an LLC header in bytes/octets (I- & S-format control field).
+    pub const MAX_LEN: usize = 4;
+
+    /// Service access point indicating that a SNAP header follows the
+    /// LLC header.
+    pub const SNAP_SAP: u8 = 0xAA;
+
+    /// Service access point of the spanning tree protocol (IEEE 802.1D).
+    pub const STP_SAP: u8 = 0x42;
+
+    /// Control field value of an unnumbered information (UI) frame.
+    pub const UI_CONTROL: u8 = 0x03;
+
+    /// LLC header that is used in front of a [`SnapHeader`].
+    pub const SNAP: LlcHeader = LlcHeader {
+        dsap: LlcHeader::SNAP_SAP,
+        ssap: LlcHeader::SNAP_SAP,
+        control: [LlcHeader::UI_CONTROL, 0],
+    };
+
+    /// Read an [`LlcHeader`] from a slice and return the header & unused parts of the slice.
+    #[inline]
+    pub fn from_slice(slice: &[u8]) -> Result<(LlcHeader, &[u8]), err::LenError> {
+        let header = LlcHeaderSlice::from_slice(slice)?;
+        Ok((header.to_header(), &slice[header.slice().len()..]))
+    }
+
+    /// Reads an LLC header from the current position of the read argument.
+    #[cfg(feature = "std")]
+    pub fn read<T: std::io::Read + std::io::Seek + Sized>(
+        reader: &mut T,
+    ) -> Result<LlcHeader, std::io::Error> {
+        let mut buffer = [0; LlcHeader::MAX_LEN];
+        reader.read_exact(&mut buffer[..LlcHeader::MIN_LEN])?;
+        if 0b11 != buffer[2] & 0b11 {
+            reader.read_exact(&mut buffer[LlcHeader::MIN_LEN..])?;
+        }
+        Ok(LlcHeader {
+            dsap: buffer[0],
+            ssap: buffer[1],
+            control: [buffer[2], buffer[3]],
+        })
+    }
+
+    /// Returns true if the control field is one octet long (U-format frame).
+    #[inline]
+    pub fn is_unnumbered(&self) -> bool {
+        0b11 == self.control[0] & 0b11
+    }
+
+    /// Returns true if the header indicates that a [`SnapHeader`] follows.
+    #[inline]
+    pub fn is_snap(&self) -> bool {
+        LlcHeader::SNAP_SAP == self.dsap
+            && LlcHeader::SNAP_SAP == self.ssap
+            && LlcHeader::UI_CONTROL == self.control[0]
+    }
+
+    /// Serialize the header to a given slice. Returns the unused part of the slice.
+    pub fn write_to_slice<'a>(
+        &self,
+        slice: &'a mut [u8],
+    ) -> Result<&'a mut [u8], SliceWriteSpaceError> {
+        let len = self.header_len();
+        // length check
+        if slice.len() < len {
+            Err(SliceWriteSpaceError {
+                required_len: len,
+                len: slice.len(),
+                layer: Layer::LlcHeader,
+                layer_start_offset: 0,
+            })
+        } else {
+            slice[..len].copy_from_slice(&self.to_bytes());
+            Ok(&mut slice[len..])
+        }
+    }
+
+    /// Writes a given LLC header to the current position of the write argument.
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn write<T: std::io::Write + Sized>(&self, writer: &mut T) -> Result<(), std::io::Error> {
+        writer.write_all(&self.to_bytes())
+    }
+
+    /// Length of the serialized header in bytes.
+    #[inline]
+    pub fn header_len(&self) -> usize {
+        if self.is_unnumbered() {
+            LlcHeader::MIN_LEN
+        } else {
+            LlcHeader::MAX_LEN
+        }
+    }
+
+    /// Returns the serialized form of the header.
+    #[inline]
+    pub fn to_bytes(&self) -> ArrayVec<u8, { LlcHeader::MAX_LEN }> {
+        let mut result = ArrayVec::from([self.dsap, self.ssap, self.control[0], self.control[1]]);
+        if self.is_unnumbered() {
+            result.truncate(LlcHeader::MIN_LEN);
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_gens::*;
+    use alloc::{format, vec::Vec};
+    use proptest::prelude::*;
+    use std::io::{Cursor, ErrorKind};
+
+    proptest! {
+        #[test]
+        fn from_slice(
+            input in llc_any(),
+            dummy_data in proptest::collection::vec(any::<u8>(), 0..20)
+        ) {
+            // serialize
+            let mut buffer: Vec<u8> = Vec::with_capacity(4 + dummy_data.len());
+            input.write(&mut buffer).unwrap();
+            buffer.extend(&dummy_data[..]);
+
+            // calls with a valid result
+            {
+                let (result, rest) = LlcHeader::from_slice(&buffer[..]).unwrap();
+                assert_eq!(input, result);
+                assert_eq!(&buffer[input.header_len()..], rest);
+            }
+
+            // call with not enough data in the slice
+            for len in 0..input.header_len() {
+                assert_eq!(
+                    LlcHeader::from_slice(&buffer[..len]),
+                    Err(err::LenError{
+                        required_len: if len < LlcHeader::MIN_LEN {
+                            LlcHeader::MIN_LEN
+                        } else {
+                            input.header_len()
+                        },
+                        len,
+                        len_source: err::LenSource::Slice,
+                        layer: err::Layer::LlcHeader,
+                        layer_start_offset: 0,
+                    })
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn read(
+            input in llc_any(),
+            dummy_data in proptest::collection::vec(any::<u8>(), 0..20)
+        ) {
+            let mut buffer = Vec::with_capacity(4 + dummy_data.len());
+            input.write(&mut buffer).unwrap();
+            buffer.extend(&dummy_data[..]);
+
+            // calls with a valid result
+            {
+                let mut cursor = Cursor::new(&buffer);
+                let result = LlcHeader::read(&mut cursor).unwrap();
+                assert_eq!(input, result);
+                assert_eq!(cursor.position(), input.header_len() as u64);
+            }
+
+            // unexpected eof
+            for len in 0..input.header_len() {
+                let mut cursor = Cursor::new(&buffer[0..len]);
+                assert_eq!(
+                    LlcHeader::read(&mut cursor)
+                    .unwrap_err()
+                    .kind(),
+                    ErrorKind::UnexpectedEof
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn is_unnumbered() {
+        for control in 0..=u8::MAX {
+            let header = LlcHeader {
+                dsap: 0,
+                ssap: 0,
+                control: [control, 0],
+            };
+            assert_eq!(0b11 == control & 0b11, header.is_unnumbered());
+        }
+    }
+
+    #[test]
+    fn is_snap() {
+        assert!(LlcHeader::SNAP.is_snap());
+        assert!(
+            false
+                == LlcHeader {
+                    dsap: 0,
+                    ..LlcHeader::SNAP
+                }
+                .is_snap()
+        );
+        assert!(
+            false
+                == LlcHeader {
+                    ssap: 0,
+                    ..LlcHeader::SNAP
+                }
+                .is_snap()
+        );
+        assert!(
+            false
+                == LlcHeader {
+                    control: [0x13, 0],
+                    ..LlcHeader::SNAP
+                }
+                .is_snap()
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn write_to_slice(input in llc_any()) {
+            // normal write
+            {
+                let mut buffer: [u8;4] = [0;4];
+                let rest = input.write_to_slice(&mut buffer).unwrap();
+                assert_eq!(4 - input.header_len(), rest.len());
+                assert_eq!(&buffer[..input.header_len()], &input.to_bytes()[..]);
+            }
+            // len to small
+            for len in 0..input.header_len() {
+                let mut buffer: [u8;4] = [0;4];
+                assert_eq!(
+                    SliceWriteSpaceError {
+                        required_len: input.header_len(),
+                        len,
+                        layer: Layer::LlcHeader,
+                        layer_start_offset: 0,
+                    },
+                    input.write_to_slice(&mut buffer[..len]).unwrap_err()
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn write(input in llc_any()) {
+            // successfull write
+            {
+                let mut buffer: Vec<u8> = Vec::with_capacity(4);
+                input.write(&mut buffer).unwrap();
+                assert_eq!(&buffer[..], &input.to_bytes()[..]);
+            }
+
+            // not enough memory for write (unexpected eof)
+            for len in 0..input.header_len() {
+                let mut buffer = [0u8;4];
+                let mut writer = Cursor::new(&mut buffer[..len]);
+                assert!(input.write(&mut writer).is_err());
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn header_len_to_bytes(input in llc_any()) {
+            let bytes = input.to_bytes();
+            assert_eq!(input.header_len(), bytes.len());
+            assert_eq!(bytes[0], input.dsap);
+            assert_eq!(bytes[1], input.ssap);
+            assert_eq!(bytes[2], input.control[0]);
+            if input.is_unnumbered() {
+                assert_eq!(3, input.header_len());
+            } else {
+                assert_eq!(4, input.header_len());
+                assert_eq!(bytes[3], input.control[1]);
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn clone_eq_default(input in llc_any()) {
+            assert_eq!(input, input.clone());
+            assert_eq!(
+                LlcHeader{ dsap: 0, ssap: 0, control: [0, 0] },
+                LlcHeader::default()
+            );
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn dbg(input in llc_any()) {
+            assert_eq!(
+                &format!(
+                    "LlcHeader {{ dsap: {:?}, ssap: {:?}, control: {:?} }}",
+                    input.dsap,
+                    input.ssap,
+                    input.control,
+                ),
+                &format!("{:?}", input)
+            );
+        }
+    }
+}
@@ -0,0 +1,199 @@
+use crate::*;
+use core::slice::from_raw_parts;
+
+/// A slice containing an IEEE 802.2 LLC header of a network package.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LlcHeaderSlice<'a> {
+    slice: &'a [u8],
+}
+
+impl<'a> LlcHeaderSlice<'a> {
+    /// Creates a LLC header slice from an other slice.
+    pub fn from_slice(slice: &'a [u8]) -> Result<LlcHeaderSlice<'a>, err::LenError> {
+        //check length
+        if slice.len() < LlcHeader::MIN_LEN {
+            return Err(err::LenError {
+                required_len: LlcHeader::MIN_LEN,
+                len: slice.len(),
+                len_source: err::LenSource::Slice,
+                layer: err::Layer::LlcHeader,
+                layer_start_offset: 0,
+            });
+        }
+
+        // SAFETY:
+        // Safe as the slice length is checked to be at least
+        // LlcHeader::MIN_LEN (3) before this.
+        let len = if 0b11 == unsafe { *slice.get_unchecked(2) } & 0b11 {
+            LlcHeader::MIN_LEN
+        } else {
+            LlcHeader::MAX_LEN
+        };
+
+        //check length for I- & S-format control fields
+        if slice.len() < len {
+            return Err(err::LenError {
+                required_len: len,
+                len: slice.len(),
+                len_source: err::LenSource::Slice,
+                layer: err::Layer::LlcHeader,
+                layer_start_offset: 0,
+            });
+        }
+
+        //all done
+        Ok(LlcHeaderSlice {
+            // SAFETY:
+            // Safe as slice length is checked to be at least
+            // len before this.
+            slice: unsafe { from_raw_parts(slice.as_ptr(), len) },
+        })
+    }
+
+    /// Returns the slice containing the LLC header
+    #[inline]
+    pub fn slice(&self) -> &'a [u8] {
+        self.slice
+    }
+
+    /// Read the destination service access point.
+    #[inline]
+    pub fn dsap(&self) -> u8 {
+        // SAFETY:
+        // Safe as the contructor checks that the slice has
+        // at least the length of LlcHeader::MIN_LEN (3).
+        unsafe { *self.slice.get_unchecked(0) }
+    }
+
+    /// Read the source service access point.
+    #[inline]
+    pub fn ssap(&self) -> u8 {
+        // SAFETY:
+        // Safe as the contructor checks that the slice has
+        // at least the length of LlcHeader::MIN_LEN (3).
+        unsafe { *self.slice.get_unchecked(1) }
+    }
+
+    /// Read the control field (the second octet is set to zero
+    /// for U-format frames).
+    #[inline]
+    pub fn control(&self) -> [u8; 2] {
+        // SAFETY:
+        // Safe as the contructor checks that the slice has
+        // at least the length of LlcHeader::MIN_LEN (3).
+        let first = unsafe { *self.slice.get_unchecked(2) };
+        [first, self.slice.get(3).copied().unwrap_or(0)]
+    }
+
+    /// Returns true if the control field is one octet long (U-format frame).
+    #[inline]
+    pub fn is_unnumbered(&self) -> bool {
+        LlcHeader::MIN_LEN == self.slice.len()
+    }
+
+    /// Returns true if the header indicates that a [`SnapHeader`] follows.
+    #[inline]
+    pub fn is_snap(&self) -> bool {
+        LlcHeader::SNAP_SAP == self.dsap()
+            && LlcHeader::SNAP_SAP == self.ssap()
+            && LlcHeader::UI_CONTROL == self.control()[0]
+    }
+
+    /// Decode all the fields and copy the results to a [`LlcHeader`] struct
+    pub fn to_header(&self) -> LlcHeader {
+        LlcHeader {
+            dsap: self.dsap(),
+            ssap: self.ssap(),
+            control: self.control(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_gens::*;
+    use alloc::{format, vec::Vec};
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn from_slice(
+            input in llc_any(),
+            dummy_data in proptest::collection::vec(any::<u8>(), 0..20)
+        ) {
+            // serialize
+            let mut buffer: Vec<u8> = Vec::with_capacity(4 + dummy_data.len());
+            input.write(&mut buffer).unwrap();
+            buffer.extend(&dummy_data[..]);
+
+            // calls with a valid result
+            {
+                let result = LlcHeaderSlice::from_slice(&buffer[..]).unwrap();
+                assert_eq!(&buffer[..input.header_len()], result.slice());
+            }
+
+            // call with not enough data in the slice
+            for len in 0..input.header_len() {
+                assert_eq!(
+                    LlcHeaderSlice::from_slice(&buffer[..len]),
+                    Err(err::LenError{
+                        required_len: if len < LlcHeader::MIN_LEN {
+                            LlcHeader::MIN_LEN
+                        } else {
+                            LlcHeader::MAX_LEN
+                        },
+                        len,
+                        len_source: err::LenSource::Slice,
+                        layer: err::Layer::LlcHeader,
+                        layer_start_offset: 0,
+                    })
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn getters(input in llc_any()) {
+            let bytes = input.to_bytes();
+            let slice = LlcHeaderSlice::from_slice(&bytes).unwrap();
+            assert_eq!(input.dsap, slice.dsap());
+            assert_eq!(input.ssap, slice.ssap());
+            assert_eq!(input.control, slice.control());
+            assert_eq!(input.is_unnumbered(), slice.is_unnumbered());
+            assert_eq!(input.is_snap(), slice.is_snap());
+        }
+    }
+
+    #[test]
+    fn is_snap() {
+        let bytes = LlcHeader::SNAP.to_bytes();
+        assert!(LlcHeaderSlice::from_slice(&bytes).unwrap().is_snap());
+    }
+
+    proptest! {
+        #[test]
+        fn to_header(input in llc_any()) {
+            let bytes = input.to_bytes();
+            let slice = LlcHeaderSlice::from_slice(&bytes).unwrap();
+            assert_eq!(input, slice.to_header());
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn clone_eq_dbg(input in llc_any()) {
+            let bytes = input.to_bytes();
+            let slice = LlcHeaderSlice::from_slice(&bytes).unwrap();
+            assert_eq!(slice, slice.clone());
+            assert_eq!(
+                &format!(
+                    "LlcHeaderSlice {{ slice: {:?} }}",
+                    slice.slice(),
+                ),
+                &format!("{:?}", slice)
+            );
+        }
+    }
+}
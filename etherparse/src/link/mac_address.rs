@@ -0,0 +1,402 @@
+use crate::err::mac_address::ParseError;
+
+/// 48 bit IEEE 802 MAC address (e.g. used as source & destination
+/// address in an [`crate::Ethernet2Header`]).
+///
+/// You can access the underlying octets by using `.0` and any `[u8; 6]`
+/// can be converted to a `MacAddress` (and back):
+///
+/// ```
+/// use etherparse::MacAddress;
+///
+/// let mac = MacAddress([0x00, 0x1b, 0x21, 0x3a, 0x4b, 0x5c]);
+///
+/// // formatting & parsing (colon, dash & dotted Cisco notation)
+/// assert_eq!("00:1b:21:3a:4b:5c", mac.to_string());
+/// assert_eq!(mac, "00:1B:21:3A:4B:5C".parse().unwrap());
+/// assert_eq!(mac, "00-1b-21-3a-4b-5c".parse().unwrap());
+/// assert_eq!(mac, "001b.213a.4b5c".parse().unwrap());
+///
+/// // classification
+/// assert!(mac.is_unicast());
+/// assert!(mac.is_universal());
+/// assert_eq!([0x00, 0x1b, 0x21], mac.oui());
+///
+/// // conversion from & to octet arrays
+/// let octets: [u8; 6] = mac.into();
+/// assert_eq!(mac, MacAddress::from(octets));
+/// ```
+#[derive(Copy, Clone, Default, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct MacAddress(pub [u8; 6]);
+
+impl MacAddress {
+    /// Serialized size of a MAC address in bytes/octets.
+    pub const LEN: usize = 6;
+
+    /// MAC address containing only zeros (`00:00:00:00:00:00`).
+    pub const ZERO: MacAddress = MacAddress([0; 6]);
+
+    /// Broadcast MAC address (`ff:ff:ff:ff:ff:ff`).
+    pub const BROADCAST: MacAddress = MacAddress([0xff; 6]);
+
+    /// Creates a MAC address from the given octets.
+    #[inline]
+    pub const fn new(octets: [u8; 6]) -> MacAddress {
+        MacAddress(octets)
+    }
+
+    /// Returns the octets of the MAC address.
+    #[inline]
+    pub const fn octets(&self) -> [u8; 6] {
+        self.0
+    }
+
+    /// Returns true if the address is the broadcast address
+    /// `ff:ff:ff:ff:ff:ff`.
+    #[inline]
+    pub fn is_broadcast(&self) -> bool {
+        *self == MacAddress::BROADCAST
+    }
+
+    /// Returns true if the individual/group bit (least significant bit
+    /// of the first octet) is set (includes the broadcast address).
+    #[inline]
+    pub const fn is_multicast(&self) -> bool {
+        0 != self.0[0] & 0b0000_0001
+    }
+
+    /// Returns true if the individual/group bit (least significant bit
+    /// of the first octet) is not set.
+    #[inline]
+    pub const fn is_unicast(&self) -> bool {
+        false == self.is_multicast()
+    }
+
+    /// Returns true if the universal/local bit (second least significant
+    /// bit of the first octet) is set.
+    #[inline]
+    pub const fn is_locally_administered(&self) -> bool {
+        0 != self.0[0] & 0b0000_0010
+    }
+
+    /// Returns true if the universal/local bit (second least significant
+    /// bit of the first octet) is not set (address assigned via an OUI).
+    #[inline]
+    pub const fn is_universal(&self) -> bool {
+        false == self.is_locally_administered()
+    }
+
+    /// Returns the organizationally unique identifier (first three octets)
+    /// of the address.
+    ///
+    /// Note that the OUI is only meaningful if the address is universally
+    /// administered (see [`MacAddress::is_universal`]).
+    #[inline]
+    pub const fn oui(&self) -> [u8; 3] {
+        [self.0[0], self.0[1], self.0[2]]
+    }
+
+    /// Returns the multicast MAC address an IPv4 multicast address maps
+    /// to (RFC 1112, `01:00:5e` followed by the lower 23 bits of the
+    /// IPv4 address).
+    ///
+    /// Returns `None` if the given address is not an IPv4 multicast
+    /// address (`224.0.0.0/4`).
+    ///
+    /// ```
+    /// use etherparse::MacAddress;
+    ///
+    /// assert_eq!(
+    ///     Some(MacAddress([0x01, 0x00, 0x5e, 0x00, 0x00, 0xfb])),
+    ///     MacAddress::from_ipv4_multicast([224, 0, 0, 251])
+    /// );
+    /// assert_eq!(None, MacAddress::from_ipv4_multicast([192, 168, 1, 1]));
+    /// ```
+    #[inline]
+    pub const fn from_ipv4_multicast(addr: [u8; 4]) -> Option<MacAddress> {
+        if 0xe0 == addr[0] & 0xf0 {
+            Some(MacAddress([
+                0x01,
+                0x00,
+                0x5e,
+                addr[1] & 0x7f,
+                addr[2],
+                addr[3],
+            ]))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the multicast MAC address an IPv6 multicast address maps
+    /// to (RFC 2464, `33:33` followed by the last 32 bits of the IPv6
+    /// address).
+    ///
+    /// Returns `None` if the given address is not an IPv6 multicast
+    /// address (`ff00::/8`).
+    ///
+    /// ```
+    /// use etherparse::MacAddress;
+    ///
+    /// assert_eq!(
+    ///     Some(MacAddress([0x33, 0x33, 0x00, 0x00, 0x00, 0x01])),
+    ///     MacAddress::from_ipv6_multicast([0xff, 0x02, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1])
+    /// );
+    /// assert_eq!(None, MacAddress::from_ipv6_multicast([0; 16]));
+    /// ```
+    #[inline]
+    pub const fn from_ipv6_multicast(addr: [u8; 16]) -> Option<MacAddress> {
+        if 0xff == addr[0] {
+            Some(MacAddress([
+                0x33, 0x33, addr[12], addr[13], addr[14], addr[15],
+            ]))
+        } else {
+            None
+        }
+    }
+}
+
+impl core::fmt::Display for MacAddress {
+    /// Formats the address in the colon notation with lower case
+    /// hexadecimal digits (e.g. `00:1b:21:3a:4b:5c`).
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let o = &self.0;
+        write!(
+            f,
+            "{:02x}:{:02x}:{:02x}:{:02x}:{:02x}:{:02x}",
+            o[0], o[1], o[2], o[3], o[4], o[5]
+        )
+    }
+}
+
+impl core::str::FromStr for MacAddress {
+    type Err = ParseError;
+
+    /// Parses a MAC address in the colon (`00:1b:21:3a:4b:5c`), dash
+    /// (`00-1b-21-3a-4b-5c`) or dotted Cisco (`001b.213a.4b5c`) notation.
+    /// Upper & lower case hexadecimal digits are accepted.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bytes = s.as_bytes();
+
+        // error for the character at the given position (all characters
+        // before the position are ascii so the position is a char boundary)
+        let invalid = |position: usize| ParseError::InvalidCharacter {
+            character: s[position..].chars().next().unwrap_or_default(),
+            position,
+        };
+
+        // determine the positions of the separators based on the length
+        let (separator, separator_positions): (u8, &[usize]) = match bytes.len() {
+            17 => match bytes[2] {
+                b':' => (b':', &[2, 5, 8, 11, 14]),
+                b'-' => (b'-', &[2, 5, 8, 11, 14]),
+                _ => {
+                    // report the first non hex digit
+                    let pos = if bytes[..2].iter().all(u8::is_ascii_hexdigit) {
+                        2
+                    } else if bytes[0].is_ascii_hexdigit() {
+                        1
+                    } else {
+                        0
+                    };
+                    return Err(invalid(pos));
+                }
+            },
+            14 => (b'.', &[4, 9]),
+            len => return Err(ParseError::UnexpectedLen { len }),
+        };
+
+        let mut result = [0u8; 6];
+        let mut nibble = 0;
+        for (position, c) in bytes.iter().enumerate() {
+            if separator_positions.contains(&position) {
+                if *c != separator {
+                    return Err(invalid(position));
+                }
+            } else {
+                let value = match c {
+                    b'0'..=b'9' => c - b'0',
+                    b'a'..=b'f' => c - b'a' + 10,
+                    b'A'..=b'F' => c - b'A' + 10,
+                    _ => return Err(invalid(position)),
+                };
+                result[nibble / 2] |= value << (4 * (1 - (nibble % 2)));
+                nibble += 1;
+            }
+        }
+        Ok(MacAddress(result))
+    }
+}
+
+impl From<[u8; 6]> for MacAddress {
+    #[inline]
+    fn from(value: [u8; 6]) -> Self {
+        MacAddress(value)
+    }
+}
+
+impl From<MacAddress> for [u8; 6] {
+    #[inline]
+    fn from(value: MacAddress) -> Self {
+        value.0
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::{format, string::ToString};
+    use core::str::FromStr;
+    use proptest::prelude::*;
+
+    #[test]
+    fn constants() {
+        assert_eq!(6, MacAddress::LEN);
+        assert_eq!([0; 6], MacAddress::ZERO.0);
+        assert_eq!([0xff; 6], MacAddress::BROADCAST.0);
+        assert_eq!(MacAddress::ZERO, MacAddress::default());
+    }
+
+    proptest! {
+        #[test]
+        fn new_octets_from_into(octets in prop::array::uniform6(any::<u8>())) {
+            let mac = MacAddress::new(octets);
+            assert_eq!(octets, mac.0);
+            assert_eq!(octets, mac.octets());
+            assert_eq!(mac, MacAddress::from(octets));
+            let back: [u8; 6] = mac.into();
+            assert_eq!(octets, back);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn classification(octets in prop::array::uniform6(any::<u8>())) {
+            let mac = MacAddress(octets);
+            assert_eq!(0 != octets[0] & 1, mac.is_multicast());
+            assert_eq!(0 == octets[0] & 1, mac.is_unicast());
+            assert_eq!(0 != octets[0] & 2, mac.is_locally_administered());
+            assert_eq!(0 == octets[0] & 2, mac.is_universal());
+            assert_eq!(octets == [0xff; 6], mac.is_broadcast());
+            assert_eq!([octets[0], octets[1], octets[2]], mac.oui());
+        }
+    }
+
+    #[test]
+    fn classification_examples() {
+        assert!(MacAddress::BROADCAST.is_broadcast());
+        assert!(MacAddress::BROADCAST.is_multicast());
+        assert!(false == MacAddress::BROADCAST.is_unicast());
+
+        let unicast = MacAddress([0x00, 0x1b, 0x21, 0x3a, 0x4b, 0x5c]);
+        assert!(unicast.is_unicast());
+        assert!(unicast.is_universal());
+        assert!(false == unicast.is_broadcast());
+
+        let local = MacAddress([0x02, 0x42, 0xac, 0x11, 0x00, 0x02]);
+        assert!(local.is_unicast());
+        assert!(local.is_locally_administered());
+    }
+
+    proptest! {
+        #[test]
+        fn from_ipv4_multicast(addr in prop::array::uniform4(any::<u8>())) {
+            let actual = MacAddress::from_ipv4_multicast(addr);
+            if (224..=239).contains(&addr[0]) {
+                let mac = actual.unwrap();
+                assert_eq!([0x01, 0x00, 0x5e, addr[1] & 0x7f, addr[2], addr[3]], mac.0);
+                assert!(mac.is_multicast());
+            } else {
+                assert_eq!(None, actual);
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn from_ipv6_multicast(addr in prop::array::uniform16(any::<u8>())) {
+            let actual = MacAddress::from_ipv6_multicast(addr);
+            if 0xff == addr[0] {
+                let mac = actual.unwrap();
+                assert_eq!([0x33, 0x33, addr[12], addr[13], addr[14], addr[15]], mac.0);
+                assert!(mac.is_multicast());
+            } else {
+                assert_eq!(None, actual);
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn display_from_str(octets in prop::array::uniform6(any::<u8>())) {
+            let mac = MacAddress(octets);
+            let o = octets;
+
+            // display
+            let colon = format!(
+                "{:02x}:{:02x}:{:02x}:{:02x}:{:02x}:{:02x}",
+                o[0], o[1], o[2], o[3], o[4], o[5]
+            );
+            assert_eq!(colon, mac.to_string());
+
+            // colon notation
+            assert_eq!(Ok(mac), MacAddress::from_str(&colon));
+            assert_eq!(Ok(mac), MacAddress::from_str(&colon.to_uppercase()));
+
+            // dash notation
+            assert_eq!(Ok(mac), MacAddress::from_str(&colon.replace(':', "-")));
+
+            // dotted cisco notation
+            let dotted = format!(
+                "{:02x}{:02x}.{:02x}{:02x}.{:02x}{:02x}",
+                o[0], o[1], o[2], o[3], o[4], o[5]
+            );
+            assert_eq!(Ok(mac), MacAddress::from_str(&dotted));
+            assert_eq!(Ok(mac), MacAddress::from_str(&dotted.to_uppercase()));
+        }
+    }
+
+    #[test]
+    fn from_str_errors() {
+        use ParseError::*;
+
+        // length
+        for s in ["", "00:11:22:33:4", "00:11:22:33:44:55:", "0011.2233.44556"] {
+            assert_eq!(Err(UnexpectedLen { len: s.len() }), MacAddress::from_str(s));
+        }
+
+        // invalid characters
+        let tests = [
+            ("x0:11:22:33:44:55", 'x', 0),
+            ("0x:11:22:33:44:55", 'x', 1),
+            ("00x11:22:33:44:55", 'x', 2),
+            ("00:11:22:33:44:5g", 'g', 16),
+            ("00:11-22:33:44:55", '-', 5),
+            ("00-11-22-33-44:55", ':', 14),
+            ("0011:2233.4455", ':', 4),
+            ("0011.2233.445z", 'z', 13),
+            ("00:11:22:33:44:ä", 'ä', 15),
+        ];
+        for (s, character, position) in tests {
+            assert_eq!(
+                Err(InvalidCharacter {
+                    character,
+                    position
+                }),
+                MacAddress::from_str(s),
+                "{}",
+                s
+            );
+        }
+    }
+
+    #[test]
+    fn derived_traits() {
+        let mac = MacAddress([1, 2, 3, 4, 5, 6]);
+        let copy = mac;
+        assert_eq!(mac, copy);
+        assert_eq!(mac, mac.clone());
+        assert_eq!("MacAddress([1, 2, 3, 4, 5, 6])", format!("{:?}", mac));
+        assert!(MacAddress([1, 2, 3, 4, 5, 6]) < MacAddress([1, 2, 3, 4, 5, 7]));
+    }
+}
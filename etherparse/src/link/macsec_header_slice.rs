@@ -0,0 +1,364 @@
+use crate::*;
+use core::slice::from_raw_parts;
+
+/// A slice containing a MACsec header (SecTAG as defined in IEEE 802.1AE).
+///
+/// If the frame is neither encrypted nor modified (E & C bits not set) the
+/// slice additionally contains the ether type of the secure data (see
+/// [`MacsecHeaderSlice::payload_ether_type`]).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MacsecHeaderSlice<'a> {
+    slice: &'a [u8],
+}
+
+impl<'a> MacsecHeaderSlice<'a> {
+    /// Length of a SecTAG without a secure channel identifier (SCI).
+    pub const MIN_LEN: usize = 6;
+
+    /// Maximum length of the header (SecTAG with SCI & ether type of
+    /// the unencrypted payload).
+    pub const MAX_LEN: usize = 16;
+
+    /// Length of the secure channel identifier (SCI).
+    pub const SCI_LEN: usize = 8;
+
+    /// Length of the integrity check value (ICV) trailer when the default
+    /// cipher suite (GCM-AES-128/256) is used.
+    pub const ICV_LEN: usize = 16;
+
+    /// Maximum value of the "short length" field (values 48 and above
+    /// are not allowed).
+    pub const MAX_SHORT_LEN: u8 = 47;
+
+    /// Creates a MACsec header slice from an other slice (verifies that
+    /// the version bit is `0` & the short length is smaller then 48).
+    pub fn from_slice(
+        slice: &'a [u8],
+    ) -> Result<MacsecHeaderSlice<'a>, err::macsec::HeaderSliceError> {
+        use err::macsec::{HeaderError::*, HeaderSliceError::*};
+
+        //check minimum length
+        if slice.len() < MacsecHeaderSlice::MIN_LEN {
+            return Err(Len(err::LenError {
+                required_len: MacsecHeaderSlice::MIN_LEN,
+                len: slice.len(),
+                len_source: err::LenSource::Slice,
+                layer: err::Layer::MacsecHeader,
+                layer_start_offset: 0,
+            }));
+        }
+
+        // SAFETY:
+        // Safe as the slice length is checked to be at least
+        // MacsecHeaderSlice::MIN_LEN (6) before this.
+        let (tci_an, short_len) = unsafe { (*slice.get_unchecked(0), *slice.get_unchecked(1)) };
+
+        //check version & short length
+        let version_number = tci_an >> 7;
+        if 0 != version_number {
+            return Err(Content(UnexpectedVersion { version_number }));
+        }
+        if short_len > MacsecHeaderSlice::MAX_SHORT_LEN {
+            return Err(Content(InvalidShortLen { short_len }));
+        }
+
+        //determine the actual length
+        let len = MacsecHeaderSlice::MIN_LEN
+            + if 0 != tci_an & 0b0010_0000 {
+                MacsecHeaderSlice::SCI_LEN
+            } else {
+                0
+            }
+            + if 0 == tci_an & 0b0000_1100 { 2 } else { 0 };
+        if slice.len() < len {
+            return Err(Len(err::LenError {
+                required_len: len,
+                len: slice.len(),
+                len_source: err::LenSource::Slice,
+                layer: err::Layer::MacsecHeader,
+                layer_start_offset: 0,
+            }));
+        }
+
+        //all done
+        Ok(MacsecHeaderSlice {
+            // SAFETY:
+            // Safe as slice length is checked to be at least len before this.
+            slice: unsafe { from_raw_parts(slice.as_ptr(), len) },
+        })
+    }
+
+    /// Returns the slice containing the MACsec header (including the
+    /// ether type of the payload if the frame is unencrypted).
+    #[inline]
+    pub fn slice(&self) -> &'a [u8] {
+        self.slice
+    }
+
+    /// Read the "version" bit (always `0`, as verified by the constructor).
+    #[inline]
+    pub fn version(&self) -> u8 {
+        // SAFETY:
+        // Safe as the contructor checks that the slice has
+        // at least the length of MacsecHeaderSlice::MIN_LEN (6).
+        unsafe { *self.slice.get_unchecked(0) >> 7 }
+    }
+
+    /// Read the "end station" (ES) bit.
+    #[inline]
+    pub fn end_station(&self) -> bool {
+        // SAFETY:
+        // Safe as the contructor checks that the slice has
+        // at least the length of MacsecHeaderSlice::MIN_LEN (6).
+        0 != unsafe { *self.slice.get_unchecked(0) } & 0b0100_0000
+    }
+
+    /// Read the "SCI present" (SC) bit (indicates that the header
+    /// contains a secure channel identifier).
+    #[inline]
+    pub fn sci_present(&self) -> bool {
+        // SAFETY:
+        // Safe as the contructor checks that the slice has
+        // at least the length of MacsecHeaderSlice::MIN_LEN (6).
+        0 != unsafe { *self.slice.get_unchecked(0) } & 0b0010_0000
+    }
+
+    /// Read the "single copy broadcast" (SCB) bit.
+    #[inline]
+    pub fn single_copy_broadcast(&self) -> bool {
+        // SAFETY:
+        // Safe as the contructor checks that the slice has
+        // at least the length of MacsecHeaderSlice::MIN_LEN (6).
+        0 != unsafe { *self.slice.get_unchecked(0) } & 0b0001_0000
+    }
+
+    /// Read the "encryption" (E) bit.
+    #[inline]
+    pub fn encrypted(&self) -> bool {
+        // SAFETY:
+        // Safe as the contructor checks that the slice has
+        // at least the length of MacsecHeaderSlice::MIN_LEN (6).
+        0 != unsafe { *self.slice.get_unchecked(0) } & 0b0000_1000
+    }
+
+    /// Read the "changed text" (C) bit.
+    #[inline]
+    pub fn changed_text(&self) -> bool {
+        // SAFETY:
+        // Safe as the contructor checks that the slice has
+        // at least the length of MacsecHeaderSlice::MIN_LEN (6).
+        0 != unsafe { *self.slice.get_unchecked(0) } & 0b0000_0100
+    }
+
+    /// Returns true if neither the "encryption" (E) nor the "changed text"
+    /// (C) bit are set (payload is the unmodified user data).
+    #[inline]
+    pub fn is_unmodified(&self) -> bool {
+        false == self.encrypted() && false == self.changed_text()
+    }
+
+    /// Read the "association number" (AN) field (2 bits).
+    #[inline]
+    pub fn association_nr(&self) -> u8 {
+        // SAFETY:
+        // Safe as the contructor checks that the slice has
+        // at least the length of MacsecHeaderSlice::MIN_LEN (6).
+        unsafe { *self.slice.get_unchecked(0) & 0b0000_0011 }
+    }
+
+    /// Read the "short length" (SL) field.
+    ///
+    /// Contains the length of the secure data if it is smaller then 48
+    /// bytes, otherwise `0`.
+    #[inline]
+    pub fn short_len(&self) -> u8 {
+        // SAFETY:
+        // Safe as the contructor checks that the slice has
+        // at least the length of MacsecHeaderSlice::MIN_LEN (6).
+        unsafe { *self.slice.get_unchecked(1) }
+    }
+
+    /// Read the "packet number" (PN) field.
+    #[inline]
+    pub fn packet_nr(&self) -> u32 {
+        // SAFETY:
+        // Safe as the contructor checks that the slice has
+        // at least the length of MacsecHeaderSlice::MIN_LEN (6).
+        unsafe { get_unchecked_be_u32(self.slice.as_ptr().add(2)) }
+    }
+
+    /// Read the "secure channel identifier" (SCI) field if present.
+    pub fn sci(&self) -> Option<u64> {
+        if self.sci_present() {
+            // SAFETY:
+            // Safe as the contructor checks that the slice has
+            // at least the length of MacsecHeaderSlice::MIN_LEN +
+            // MacsecHeaderSlice::SCI_LEN (14) if the SC bit is set.
+            Some(u64::from_be_bytes(unsafe {
+                [
+                    *self.slice.get_unchecked(6),
+                    *self.slice.get_unchecked(7),
+                    *self.slice.get_unchecked(8),
+                    *self.slice.get_unchecked(9),
+                    *self.slice.get_unchecked(10),
+                    *self.slice.get_unchecked(11),
+                    *self.slice.get_unchecked(12),
+                    *self.slice.get_unchecked(13),
+                ]
+            }))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the ether type of the payload if the frame is neither
+    /// encrypted nor modified (otherwise `None` is returned).
+    pub fn payload_ether_type(&self) -> Option<EtherType> {
+        if self.is_unmodified() {
+            // SAFETY:
+            // Safe as the contructor checks that the slice contains
+            // the ether type if the E & C bits are not set.
+            Some(EtherType(unsafe {
+                get_unchecked_be_u16(self.slice.as_ptr().add(self.sectag_len()))
+            }))
+        } else {
+            None
+        }
+    }
+
+    /// Length of the SecTAG (6 or 14 bytes, depending on if an SCI
+    /// is present).
+    #[inline]
+    pub fn sectag_len(&self) -> usize {
+        if self.sci_present() {
+            MacsecHeaderSlice::MIN_LEN + MacsecHeaderSlice::SCI_LEN
+        } else {
+            MacsecHeaderSlice::MIN_LEN
+        }
+    }
+
+    /// Length of the header (SecTAG & ether type of the payload if
+    /// the frame is not encrypted or modified).
+    #[inline]
+    pub fn header_len(&self) -> usize {
+        self.slice.len()
+    }
+
+    /// Length of the integrity check value (ICV) trailer at the
+    /// end of the secure data (assumes the default cipher suite).
+    #[inline]
+    pub fn icv_len(&self) -> usize {
+        MacsecHeaderSlice::ICV_LEN
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::{format, vec::Vec};
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn from_slice(
+            tci_an in 0u8..0b1000_0000,
+            short_len in 0..=MacsecHeaderSlice::MAX_SHORT_LEN,
+            packet_nr in any::<u32>(),
+            sci in any::<u64>(),
+            ether_type in any::<u16>(),
+            dummy_data in proptest::collection::vec(any::<u8>(), 0..20),
+            bad_short_len in (MacsecHeaderSlice::MAX_SHORT_LEN + 1)..=u8::MAX,
+        ) {
+            use err::macsec::{HeaderError::*, HeaderSliceError::*};
+
+            let has_sci = 0 != tci_an & 0b0010_0000;
+            let unmodified = 0 == tci_an & 0b0000_1100;
+
+            // serialize
+            let mut buffer = Vec::with_capacity(MacsecHeaderSlice::MAX_LEN + dummy_data.len());
+            buffer.push(tci_an);
+            buffer.push(short_len);
+            buffer.extend_from_slice(&packet_nr.to_be_bytes());
+            if has_sci {
+                buffer.extend_from_slice(&sci.to_be_bytes());
+            }
+            if unmodified {
+                buffer.extend_from_slice(&ether_type.to_be_bytes());
+            }
+            let header_len = buffer.len();
+            buffer.extend_from_slice(&dummy_data);
+
+            // calls with a valid result
+            {
+                let result = MacsecHeaderSlice::from_slice(&buffer[..]).unwrap();
+                assert_eq!(&buffer[..header_len], result.slice());
+                assert_eq!(header_len, result.header_len());
+                assert_eq!(0, result.version());
+                assert_eq!(0 != tci_an & 0b0100_0000, result.end_station());
+                assert_eq!(has_sci, result.sci_present());
+                assert_eq!(0 != tci_an & 0b0001_0000, result.single_copy_broadcast());
+                assert_eq!(0 != tci_an & 0b0000_1000, result.encrypted());
+                assert_eq!(0 != tci_an & 0b0000_0100, result.changed_text());
+                assert_eq!(unmodified, result.is_unmodified());
+                assert_eq!(tci_an & 0b11, result.association_nr());
+                assert_eq!(short_len, result.short_len());
+                assert_eq!(packet_nr, result.packet_nr());
+                assert_eq!(if has_sci { Some(sci) } else { None }, result.sci());
+                assert_eq!(
+                    if has_sci { 14 } else { 6 },
+                    result.sectag_len()
+                );
+                assert_eq!(
+                    if unmodified { Some(EtherType(ether_type)) } else { None },
+                    result.payload_ether_type()
+                );
+                assert_eq!(MacsecHeaderSlice::ICV_LEN, result.icv_len());
+            }
+
+            // call with not enough data in the slice
+            for len in 0..header_len {
+                assert_eq!(
+                    MacsecHeaderSlice::from_slice(&buffer[..len]),
+                    Err(Len(err::LenError{
+                        required_len: if len < 6 { 6 } else { header_len },
+                        len,
+                        len_source: err::LenSource::Slice,
+                        layer: err::Layer::MacsecHeader,
+                        layer_start_offset: 0,
+                    }))
+                );
+            }
+
+            // bad version
+            {
+                let mut buffer = buffer.clone();
+                buffer[0] |= 0b1000_0000;
+                assert_eq!(
+                    MacsecHeaderSlice::from_slice(&buffer[..]),
+                    Err(Content(UnexpectedVersion{ version_number: 1 }))
+                );
+            }
+
+            // bad short length
+            {
+                let mut buffer = buffer.clone();
+                buffer[1] = bad_short_len;
+                assert_eq!(
+                    MacsecHeaderSlice::from_slice(&buffer[..]),
+                    Err(Content(InvalidShortLen{ short_len: bad_short_len }))
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn clone_eq_dbg() {
+        let bytes = [0b0000_1011, 0, 1, 2, 3, 4];
+        let slice = MacsecHeaderSlice::from_slice(&bytes).unwrap();
+        assert_eq!(slice, slice.clone());
+        assert_eq!(
+            &format!("MacsecHeaderSlice {{ slice: {:?} }}", slice.slice()),
+            &format!("{:?}", slice)
+        );
+    }
+}
@@ -1,12 +1,44 @@
+pub mod arp_hardware_id;
 pub mod double_vlan_header;
 pub mod double_vlan_header_slice;
 pub mod ether_type_impl;
 pub mod ethernet2_header;
 pub mod ethernet2_header_slice;
+pub mod link_header;
 pub mod link_slice;
+pub mod linux_sll2_header;
+pub mod linux_sll2_header_slice;
+pub mod linux_sll_header;
+pub mod linux_sll_header_slice;
+pub mod linux_sll_packet_type;
+pub mod llc_header;
+pub mod llc_header_slice;
+pub mod mac_address;
+pub mod macsec_header_slice;
+pub mod mpls_label;
+pub mod mpls_label_stack;
+pub mod mpls_label_stack_entry;
+pub mod mpls_label_stack_entry_slice;
+pub mod mpls_label_stack_slice;
+pub mod mpls_label_stack_slice_iter;
+pub mod mpls_traffic_class;
+pub mod ppp_header;
+pub mod ppp_header_slice;
+pub mod ppp_protocol;
+pub mod pppoe_code;
+pub mod pppoe_header;
+pub mod pppoe_header_slice;
+pub mod pppoe_tag;
+pub mod pppoe_tag_type;
+pub mod pppoe_tags_iterator;
 pub mod single_vlan_header;
 pub mod single_vlan_header_slice;
+pub mod snap_header;
+pub mod snap_header_slice;
 pub mod vlan_header;
 pub mod vlan_id;
 pub mod vlan_pcp;
 pub mod vlan_slice;
+pub mod vlan_stack;
+pub mod vlan_stack_slice;
+pub mod vlan_stack_slice_iter;
@@ -0,0 +1,270 @@
+use crate::err::ValueTooBigError;
+
+/// 20 bit unsigned integer containing the "label" of an MPLS label stack
+/// entry (present in the [`crate::MplsLabelStackEntry`]).
+#[derive(Copy, Clone, Default, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct MplsLabel(u32);
+
+impl MplsLabel {
+    /// MplsLabel with value 0.
+    pub const ZERO: MplsLabel = MplsLabel(0);
+
+    /// Reserved label "IPv4 Explicit NULL Label" (RFC 3032).
+    pub const IPV4_EXPLICIT_NULL: MplsLabel = MplsLabel(0);
+
+    /// Reserved label "Router Alert Label" (RFC 3032).
+    pub const ROUTER_ALERT: MplsLabel = MplsLabel(1);
+
+    /// Reserved label "IPv6 Explicit NULL Label" (RFC 3032).
+    pub const IPV6_EXPLICIT_NULL: MplsLabel = MplsLabel(2);
+
+    /// Reserved label "Implicit NULL Label" (RFC 3032).
+    pub const IMPLICIT_NULL: MplsLabel = MplsLabel(3);
+
+    /// Reserved label "Entropy Label Indicator" (RFC 6790).
+    pub const ENTROPY_LABEL_INDICATOR: MplsLabel = MplsLabel(7);
+
+    /// Reserved label "Generic Associated Channel Label" (RFC 5586).
+    pub const GAL: MplsLabel = MplsLabel(13);
+
+    /// Maximum value of an MPLS label.
+    pub const MAX_U32: u32 = 0b1111_1111_1111_1111_1111;
+
+    /// Tries to create an [`MplsLabel`] and checks that the passed value
+    /// is smaller or equal than [`MplsLabel::MAX_U32`] (20 bit unsigned integer).
+    ///
+    /// In case the passed value is bigger then what can be represented in an 20 bit
+    /// integer an error is returned. Otherwise an `Ok` containing the [`MplsLabel`].
+    ///
+    /// ```
+    /// use etherparse::MplsLabel;
+    ///
+    /// let label = MplsLabel::try_new(2).unwrap();
+    /// assert_eq!(label.value(), 2);
+    ///
+    /// // if a number that can not be represented in an 20 bit integer
+    /// // gets passed in an error is returned
+    /// use etherparse::err::{ValueTooBigError, ValueType};
+    /// assert_eq!(
+    ///     MplsLabel::try_new(MplsLabel::MAX_U32 + 1),
+    ///     Err(ValueTooBigError{
+    ///         actual: MplsLabel::MAX_U32 + 1,
+    ///         max_allowed: MplsLabel::MAX_U32,
+    ///         value_type: ValueType::MplsLabel,
+    ///     })
+    /// );
+    /// ```
+    #[inline]
+    pub const fn try_new(value: u32) -> Result<MplsLabel, ValueTooBigError<u32>> {
+        use crate::err::ValueType;
+        if value <= MplsLabel::MAX_U32 {
+            Ok(MplsLabel(value))
+        } else {
+            Err(ValueTooBigError {
+                actual: value,
+                max_allowed: MplsLabel::MAX_U32,
+                value_type: ValueType::MplsLabel,
+            })
+        }
+    }
+
+    /// Creates an [`MplsLabel`] WITHOUT checking that the value
+    /// is smaller or equal than [`MplsLabel::MAX_U32`] (20 bit unsigned integer).
+    /// The caller must gurantee that `value <= MplsLabel::MAX_U32`.
+    ///
+    /// # Safety
+    ///
+    /// `value` must be smaller or equal than [`MplsLabel::MAX_U32`]
+    /// otherwise the behaviour of functions or datastructures relying
+    /// on this pre-requirement is undefined.
+    #[inline]
+    pub const unsafe fn new_unchecked(value: u32) -> MplsLabel {
+        debug_assert!(value <= MplsLabel::MAX_U32);
+        MplsLabel(value)
+    }
+
+    /// Returns the underlying unsigned 20 bit value as an `u32` value.
+    #[inline]
+    pub const fn value(self) -> u32 {
+        self.0
+    }
+}
+
+impl core::fmt::Display for MplsLabel {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl From<MplsLabel> for u32 {
+    #[inline]
+    fn from(value: MplsLabel) -> Self {
+        value.0
+    }
+}
+
+impl TryFrom<u32> for MplsLabel {
+    type Error = ValueTooBigError<u32>;
+
+    #[inline]
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        use crate::err::ValueType;
+        if value <= MplsLabel::MAX_U32 {
+            Ok(MplsLabel(value))
+        } else {
+            Err(Self::Error {
+                actual: value,
+                max_allowed: MplsLabel::MAX_U32,
+                value_type: ValueType::MplsLabel,
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use core::hash::{Hash, Hasher};
+    use proptest::prelude::*;
+    use std::format;
+
+    #[test]
+    fn derived_traits() {
+        // copy & clone
+        {
+            let a = MplsLabel(2);
+            let b = a;
+            assert_eq!(a, b);
+            assert_eq!(a.clone(), a);
+        }
+
+        // default
+        {
+            let actual: MplsLabel = Default::default();
+            assert_eq!(actual.value(), 0);
+        }
+
+        // debug
+        {
+            let a = MplsLabel(2);
+            assert_eq!(format!("{:?}", a), format!("MplsLabel(2)"));
+        }
+
+        // ord & partial ord
+        {
+            use core::cmp::Ordering;
+            let a = MplsLabel(2);
+            let b = a;
+            assert_eq!(a.cmp(&b), Ordering::Equal);
+            assert_eq!(a.partial_cmp(&b), Some(Ordering::Equal));
+        }
+
+        // hash
+        {
+            use std::collections::hash_map::DefaultHasher;
+            let a = {
+                let mut hasher = DefaultHasher::new();
+                MplsLabel(2).hash(&mut hasher);
+                hasher.finish()
+            };
+            let b = {
+                let mut hasher = DefaultHasher::new();
+                MplsLabel(2).hash(&mut hasher);
+                hasher.finish()
+            };
+            assert_eq!(a, b);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn try_new(
+            valid_value in 0..=0b1111_1111_1111_1111_1111u32,
+            invalid_value in 0b1_0000_0000_0000_0000_0000u32..=u32::MAX
+        ) {
+            use crate::err::{ValueType, ValueTooBigError};
+            assert_eq!(
+                valid_value,
+                MplsLabel::try_new(valid_value).unwrap().value()
+            );
+            assert_eq!(
+                MplsLabel::try_new(invalid_value).unwrap_err(),
+                ValueTooBigError{
+                    actual: invalid_value,
+                    max_allowed: 0b1111_1111_1111_1111_1111,
+                    value_type:  ValueType::MplsLabel
+                }
+            );
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn try_from(
+            valid_value in 0..=0b1111_1111_1111_1111_1111u32,
+            invalid_value in 0b1_0000_0000_0000_0000_0000u32..=u32::MAX
+        ) {
+            use crate::err::{ValueType, ValueTooBigError};
+            // try_into
+            {
+                let actual: MplsLabel = valid_value.try_into().unwrap();
+                assert_eq!(actual.value(), valid_value);
+
+                let err: Result<MplsLabel, ValueTooBigError<u32>> = invalid_value.try_into();
+                assert_eq!(
+                    err.unwrap_err(),
+                    ValueTooBigError{
+                        actual: invalid_value,
+                        max_allowed: 0b1111_1111_1111_1111_1111,
+                        value_type:  ValueType::MplsLabel
+                    }
+                );
+            }
+            // try_from
+            {
+                assert_eq!(
+                    MplsLabel::try_from(valid_value).unwrap().value(),
+                    valid_value
+                );
+
+                assert_eq!(
+                    MplsLabel::try_from(invalid_value).unwrap_err(),
+                    ValueTooBigError{
+                        actual: invalid_value,
+                        max_allowed: 0b1111_1111_1111_1111_1111,
+                        value_type:  ValueType::MplsLabel
+                    }
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn new_unchecked(valid_value in 0..=0b1111_1111_1111_1111_1111u32) {
+            assert_eq!(
+                valid_value,
+                unsafe {
+                    MplsLabel::new_unchecked(valid_value).value()
+                }
+            );
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn fmt(valid_value in 0..=0b1111_1111_1111_1111_1111u32) {
+            assert_eq!(format!("{}", MplsLabel(valid_value)), format!("{}", valid_value));
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn from(valid_value in 0..=0b1111_1111_1111_1111_1111u32,) {
+            let label = MplsLabel::try_new(valid_value).unwrap();
+            let actual: u32 = label.into();
+            assert_eq!(actual, valid_value);
+        }
+    }
+}
@@ -0,0 +1,276 @@
+use crate::{err::Layer, err::SliceWriteSpaceError, *};
+use arrayvec::ArrayVec;
+
+/// MPLS label stack (RFC 3032) containing up to
+/// [`MplsLabelStack::MAX_ENTRIES`] entries.
+///
+/// If you need to decode label stacks with more entries use
+/// [`MplsLabelStackSlice`] instead, which supports stacks of any depth.
+///
+/// Note that the "bottom of stack" flag of the entries is written as
+/// stored in the entries. It is up to the caller to ensure that only
+/// the last entry has the flag set.
+///
+/// # Example
+///
+/// ```
+/// use etherparse::{MplsLabelStack, MplsLabelStackEntry};
+///
+/// let stack = MplsLabelStack::try_from(&[
+///     MplsLabelStackEntry {
+///         label: 16.try_into().unwrap(),
+///         traffic_class: 0.try_into().unwrap(),
+///         bottom_of_stack: false,
+///         ttl: 64,
+///     },
+///     MplsLabelStackEntry {
+///         label: 17.try_into().unwrap(),
+///         traffic_class: 0.try_into().unwrap(),
+///         bottom_of_stack: true,
+///         ttl: 64,
+///     },
+/// ][..]).unwrap();
+///
+/// assert_eq!(2, stack.entries().len());
+/// assert_eq!(8, stack.header_len());
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Default)]
+pub struct MplsLabelStack {
+    entries: ArrayVec<MplsLabelStackEntry, { MplsLabelStack::MAX_ENTRIES }>,
+}
+
+impl MplsLabelStack {
+    /// Maximum number of entries that can be stored in an [`MplsLabelStack`].
+    pub const MAX_ENTRIES: usize = 16;
+
+    /// Creates an empty label stack.
+    #[inline]
+    pub fn new() -> MplsLabelStack {
+        MplsLabelStack {
+            entries: ArrayVec::new(),
+        }
+    }
+
+    /// Read an [`MplsLabelStack`] from a slice and return the stack & unused parts of the slice.
+    pub fn from_slice(
+        slice: &[u8],
+    ) -> Result<(MplsLabelStack, &[u8]), err::mpls::HeaderSliceError> {
+        use err::mpls::HeaderSliceError::*;
+        let stack = MplsLabelStackSlice::from_slice(slice).map_err(Len)?;
+        Ok((
+            stack.to_header().map_err(Content)?,
+            &slice[stack.slice().len()..],
+        ))
+    }
+
+    /// Returns the entries of the label stack (starting with the top entry).
+    #[inline]
+    pub fn entries(&self) -> &[MplsLabelStackEntry] {
+        &self.entries
+    }
+
+    /// Adds an entry to the bottom of the label stack. Returns an error if
+    /// the stack already contains [`MplsLabelStack::MAX_ENTRIES`] entries.
+    pub fn try_push(&mut self, entry: MplsLabelStackEntry) -> Result<(), err::mpls::HeaderError> {
+        self.entries
+            .try_push(entry)
+            .map_err(|_| err::mpls::HeaderError::TooManyEntries {
+                max_entries: MplsLabelStack::MAX_ENTRIES,
+            })
+    }
+
+    /// Serialize the label stack to a given slice. Returns the unused part of the slice.
+    pub fn write_to_slice<'a>(
+        &self,
+        slice: &'a mut [u8],
+    ) -> Result<&'a mut [u8], SliceWriteSpaceError> {
+        // length check
+        let len = self.header_len();
+        if slice.len() < len {
+            Err(SliceWriteSpaceError {
+                required_len: len,
+                len: slice.len(),
+                layer: Layer::MplsLabelStack,
+                layer_start_offset: 0,
+            })
+        } else {
+            for (i, entry) in self.entries.iter().enumerate() {
+                let start = i * MplsLabelStackEntry::LEN;
+                slice[start..start + MplsLabelStackEntry::LEN].copy_from_slice(&entry.to_bytes());
+            }
+            Ok(&mut slice[len..])
+        }
+    }
+
+    /// Writes the label stack to the current position of the write argument.
+    #[cfg(feature = "std")]
+    pub fn write<T: std::io::Write + Sized>(&self, writer: &mut T) -> Result<(), std::io::Error> {
+        for entry in &self.entries {
+            entry.write(writer)?;
+        }
+        Ok(())
+    }
+
+    /// Length of the serialized label stack in bytes.
+    #[inline]
+    pub fn header_len(&self) -> usize {
+        self.entries.len() * MplsLabelStackEntry::LEN
+    }
+}
+
+impl<'a> TryFrom<&'a [MplsLabelStackEntry]> for MplsLabelStack {
+    type Error = err::mpls::HeaderError;
+
+    fn try_from(value: &'a [MplsLabelStackEntry]) -> Result<Self, Self::Error> {
+        let mut result = MplsLabelStack::new();
+        for entry in value {
+            result.try_push(entry.clone())?;
+        }
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_gens::*;
+    use alloc::{format, vec::Vec};
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn from_slice(
+            input in mpls_stack_any(),
+            dummy_data in proptest::collection::vec(any::<u8>(), 0..20)
+        ) {
+            // serialize
+            let mut buffer: Vec<u8> = Vec::with_capacity(input.header_len() + dummy_data.len());
+            input.write(&mut buffer).unwrap();
+            buffer.extend(&dummy_data[..]);
+
+            // calls with a valid result
+            {
+                let (result, rest) = MplsLabelStack::from_slice(&buffer[..]).unwrap();
+                assert_eq!(input, result);
+                assert_eq!(&buffer[input.header_len()..], rest);
+            }
+
+            // call with not enough data in the slice
+            for len in 0..input.header_len() {
+                assert_eq!(
+                    MplsLabelStack::from_slice(&buffer[..len]),
+                    Err(err::mpls::HeaderSliceError::Len(err::LenError{
+                        required_len: (len / 4 + 1) * 4,
+                        len,
+                        len_source: err::LenSource::Slice,
+                        layer: err::Layer::MplsLabelStack,
+                        layer_start_offset: 0,
+                    }))
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn from_slice_too_many_entries() {
+        let mut bytes = Vec::new();
+        for i in 0..=MplsLabelStack::MAX_ENTRIES {
+            MplsLabelStackEntry {
+                bottom_of_stack: i == MplsLabelStack::MAX_ENTRIES,
+                ..Default::default()
+            }
+            .write(&mut bytes)
+            .unwrap();
+        }
+        assert_eq!(
+            Err(err::mpls::HeaderSliceError::Content(
+                err::mpls::HeaderError::TooManyEntries {
+                    max_entries: MplsLabelStack::MAX_ENTRIES
+                }
+            )),
+            MplsLabelStack::from_slice(&bytes)
+        );
+    }
+
+    #[test]
+    fn try_push_try_from() {
+        let entry = MplsLabelStackEntry::default();
+        let mut stack = MplsLabelStack::new();
+        for i in 0..MplsLabelStack::MAX_ENTRIES {
+            assert_eq!(i, stack.entries().len());
+            stack.try_push(entry.clone()).unwrap();
+        }
+        assert_eq!(
+            Err(err::mpls::HeaderError::TooManyEntries {
+                max_entries: MplsLabelStack::MAX_ENTRIES
+            }),
+            stack.try_push(entry.clone())
+        );
+
+        // try_from
+        let entries = alloc::vec![entry.clone(); MplsLabelStack::MAX_ENTRIES + 1];
+        assert_eq!(
+            Ok(stack),
+            MplsLabelStack::try_from(&entries[..MplsLabelStack::MAX_ENTRIES])
+        );
+        assert_eq!(
+            Err(err::mpls::HeaderError::TooManyEntries {
+                max_entries: MplsLabelStack::MAX_ENTRIES
+            }),
+            MplsLabelStack::try_from(&entries[..])
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn write_to_slice(input in mpls_stack_any()) {
+            // normal write
+            {
+                let mut buffer = Vec::new();
+                input.write(&mut buffer).unwrap();
+                let mut slice_buffer = [0u8;MplsLabelStack::MAX_ENTRIES*4 + 1];
+                let rest = input.write_to_slice(&mut slice_buffer).unwrap();
+                assert_eq!(rest.len(), MplsLabelStack::MAX_ENTRIES*4 + 1 - input.header_len());
+                assert_eq!(&buffer[..], &slice_buffer[..input.header_len()]);
+            }
+            // len to small
+            for len in 0..input.header_len() {
+                let mut buffer = [0u8;MplsLabelStack::MAX_ENTRIES*4];
+                assert_eq!(
+                    SliceWriteSpaceError {
+                        required_len: input.header_len(),
+                        len,
+                        layer: Layer::MplsLabelStack,
+                        layer_start_offset: 0,
+                    },
+                    input.write_to_slice(&mut buffer[..len]).unwrap_err()
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn write_header_len(input in mpls_stack_any()) {
+            let mut buffer = Vec::new();
+            input.write(&mut buffer).unwrap();
+            assert_eq!(input.header_len(), buffer.len());
+            assert_eq!(input.entries().len()*4, input.header_len());
+            for (i, entry) in input.entries().iter().enumerate() {
+                assert_eq!(&buffer[i*4..i*4 + 4], &entry.to_bytes());
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn clone_eq_default_dbg(input in mpls_stack_any()) {
+            assert_eq!(input, input.clone());
+            assert_eq!(MplsLabelStack::new(), MplsLabelStack::default());
+            assert_eq!(
+                format!("MplsLabelStack {{ entries: {:?} }}", input.entries()),
+                format!("{:?}", input)
+            );
+        }
+    }
+}
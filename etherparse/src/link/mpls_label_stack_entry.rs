@@ -0,0 +1,292 @@
+use crate::{err::Layer, err::SliceWriteSpaceError, *};
+
+/// A single entry of an MPLS label stack (RFC 3032).
+///
+/// MPLS packets start with one or more label stack entries. The last
+/// entry of the stack has the `bottom_of_stack` flag set.
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Default)]
+pub struct MplsLabelStackEntry {
+    /// 20 bit label value.
+    pub label: MplsLabel,
+    /// 3 bit traffic class field (used for QoS & ECN).
+    pub traffic_class: MplsTrafficClass,
+    /// Set if this entry is the last entry of the label stack.
+    pub bottom_of_stack: bool,
+    /// Time to live.
+    pub ttl: u8,
+}
+
+impl MplsLabelStackEntry {
+    /// Serialized size of an MPLS label stack entry in bytes/octets.
+    pub const LEN: usize = 4;
+
+    /// Read an [`MplsLabelStackEntry`] from a slice and return the entry & unused parts of the slice.
+    #[inline]
+    pub fn from_slice(slice: &[u8]) -> Result<(MplsLabelStackEntry, &[u8]), err::LenError> {
+        Ok((
+            MplsLabelStackEntrySlice::from_slice(slice)?.to_header(),
+            &slice[MplsLabelStackEntry::LEN..],
+        ))
+    }
+
+    /// Read an [`MplsLabelStackEntry`] from a static sized byte array.
+    #[inline]
+    pub fn from_bytes(bytes: [u8; 4]) -> MplsLabelStackEntry {
+        MplsLabelStackEntry {
+            label: unsafe {
+                // SAFETY: Safe as the value is constructed from 20 bits.
+                MplsLabel::new_unchecked(u32::from_be_bytes([0, bytes[0], bytes[1], bytes[2]]) >> 4)
+            },
+            traffic_class: unsafe {
+                // SAFETY: Safe as bitmasks gurantee that value does not exceed
+                //         0b0000_0111.
+                MplsTrafficClass::new_unchecked((bytes[2] >> 1) & 0b0000_0111)
+            },
+            bottom_of_stack: 0 != bytes[2] & 0b0000_0001,
+            ttl: bytes[3],
+        }
+    }
+
+    /// Reads an MPLS label stack entry from the current position of the read argument.
+    #[cfg(feature = "std")]
+    pub fn read<T: std::io::Read + std::io::Seek + Sized>(
+        reader: &mut T,
+    ) -> Result<MplsLabelStackEntry, std::io::Error> {
+        let mut buffer = [0; MplsLabelStackEntry::LEN];
+        reader.read_exact(&mut buffer)?;
+        Ok(MplsLabelStackEntry::from_bytes(buffer))
+    }
+
+    /// Serialize the entry to a given slice. Returns the unused part of the slice.
+    pub fn write_to_slice<'a>(
+        &self,
+        slice: &'a mut [u8],
+    ) -> Result<&'a mut [u8], SliceWriteSpaceError> {
+        // length check
+        if slice.len() < MplsLabelStackEntry::LEN {
+            Err(SliceWriteSpaceError {
+                required_len: MplsLabelStackEntry::LEN,
+                len: slice.len(),
+                layer: Layer::MplsLabelStack,
+                layer_start_offset: 0,
+            })
+        } else {
+            slice[..MplsLabelStackEntry::LEN].copy_from_slice(&self.to_bytes());
+            Ok(&mut slice[MplsLabelStackEntry::LEN..])
+        }
+    }
+
+    /// Writes a given MPLS label stack entry to the current position of the write argument.
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn write<T: std::io::Write + Sized>(&self, writer: &mut T) -> Result<(), std::io::Error> {
+        writer.write_all(&self.to_bytes())
+    }
+
+    /// Length of the serialized entry in bytes.
+    #[inline]
+    pub fn header_len(&self) -> usize {
+        MplsLabelStackEntry::LEN
+    }
+
+    /// Returns the serialized form of the entry as a statically
+    /// sized byte array.
+    #[inline]
+    pub fn to_bytes(&self) -> [u8; 4] {
+        let label_be = (self.label.value() << 4).to_be_bytes();
+        [
+            label_be[1],
+            label_be[2],
+            label_be[3]
+                | (self.traffic_class.value() << 1)
+                | (if self.bottom_of_stack { 1 } else { 0 }),
+            self.ttl,
+        ]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_gens::*;
+    use alloc::{format, vec::Vec};
+    use proptest::prelude::*;
+    use std::io::{Cursor, ErrorKind};
+
+    proptest! {
+        #[test]
+        fn from_slice(
+            input in mpls_entry_any(),
+            dummy_data in proptest::collection::vec(any::<u8>(), 0..20)
+        ) {
+            // serialize
+            let mut buffer: Vec<u8> = Vec::with_capacity(4 + dummy_data.len());
+            input.write(&mut buffer).unwrap();
+            buffer.extend(&dummy_data[..]);
+
+            // calls with a valid result
+            {
+                let (result, rest) = MplsLabelStackEntry::from_slice(&buffer[..]).unwrap();
+                assert_eq!(input, result);
+                assert_eq!(&buffer[4..], rest);
+            }
+
+            // call with not enough data in the slice
+            for len in 0..4 {
+                assert_eq!(
+                    MplsLabelStackEntry::from_slice(&buffer[..len]),
+                    Err(err::LenError{
+                        required_len: MplsLabelStackEntry::LEN,
+                        len,
+                        len_source: err::LenSource::Slice,
+                        layer: err::Layer::MplsLabelStack,
+                        layer_start_offset: 0,
+                    })
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn from_bytes(input in mpls_entry_any()) {
+            assert_eq!(
+                input,
+                MplsLabelStackEntry::from_bytes(input.to_bytes())
+            );
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn read(
+            input in mpls_entry_any(),
+            dummy_data in proptest::collection::vec(any::<u8>(), 0..20)
+        ) {
+            let mut buffer = Vec::with_capacity(4 + dummy_data.len());
+            input.write(&mut buffer).unwrap();
+            buffer.extend(&dummy_data[..]);
+
+            // calls with a valid result
+            {
+                let mut cursor = Cursor::new(&buffer);
+                let result = MplsLabelStackEntry::read(&mut cursor).unwrap();
+                assert_eq!(input, result);
+                assert_eq!(cursor.position(), 4);
+            }
+
+            // unexpected eof
+            for len in 0..4 {
+                let mut cursor = Cursor::new(&buffer[0..len]);
+                assert_eq!(
+                    MplsLabelStackEntry::read(&mut cursor)
+                    .unwrap_err()
+                    .kind(),
+                    ErrorKind::UnexpectedEof
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn write_to_slice(input in mpls_entry_any()) {
+            // normal write
+            {
+                let mut buffer: [u8;4] = [0;4];
+                input.write_to_slice(&mut buffer).unwrap();
+                assert_eq!(buffer, input.to_bytes());
+            }
+            // len to small
+            for len in 0..4 {
+                let mut buffer: [u8;4] = [0;4];
+                assert_eq!(
+                    SliceWriteSpaceError {
+                        required_len: MplsLabelStackEntry::LEN,
+                        len,
+                        layer: Layer::MplsLabelStack,
+                        layer_start_offset: 0,
+                    },
+                    input.write_to_slice(&mut buffer[..len]).unwrap_err()
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn write(input in mpls_entry_any()) {
+            // successfull write
+            {
+                let mut buffer: Vec<u8> = Vec::with_capacity(4);
+                input.write(&mut buffer).unwrap();
+                assert_eq!(&buffer[..], &input.to_bytes());
+            }
+
+            // not enough memory for write (unexpected eof)
+            for len in 0..4 {
+                let mut buffer = [0u8;4];
+                let mut writer = Cursor::new(&mut buffer[..len]);
+                assert!(input.write(&mut writer).is_err());
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn header_len(input in mpls_entry_any()) {
+            assert_eq!(input.header_len(), 4);
+        }
+    }
+
+    #[test]
+    fn to_bytes() {
+        let entry = MplsLabelStackEntry {
+            label: 0x12345.try_into().unwrap(),
+            traffic_class: 0b101.try_into().unwrap(),
+            bottom_of_stack: true,
+            ttl: 0x67,
+        };
+        assert_eq!([0x12, 0x34, 0x5b, 0x67], entry.to_bytes());
+        assert_eq!(
+            [0x12, 0x34, 0x5a, 0x67],
+            MplsLabelStackEntry {
+                bottom_of_stack: false,
+                ..entry
+            }
+            .to_bytes()
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn clone_eq_default(input in mpls_entry_any()) {
+            assert_eq!(input, input.clone());
+            assert_eq!(
+                MplsLabelStackEntry{
+                    label: MplsLabel::ZERO,
+                    traffic_class: MplsTrafficClass::ZERO,
+                    bottom_of_stack: false,
+                    ttl: 0,
+                },
+                MplsLabelStackEntry::default()
+            );
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn dbg(input in mpls_entry_any()) {
+            assert_eq!(
+                &format!(
+                    "MplsLabelStackEntry {{ label: {:?}, traffic_class: {:?}, bottom_of_stack: {:?}, ttl: {:?} }}",
+                    input.label,
+                    input.traffic_class,
+                    input.bottom_of_stack,
+                    input.ttl,
+                ),
+                &format!("{:?}", input)
+            );
+        }
+    }
+}
@@ -0,0 +1,186 @@
+use crate::*;
+use core::slice::from_raw_parts;
+
+/// A slice containing a single MPLS label stack entry.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MplsLabelStackEntrySlice<'a> {
+    slice: &'a [u8],
+}
+
+impl<'a> MplsLabelStackEntrySlice<'a> {
+    /// Creates an MPLS label stack entry slice from an other slice.
+    pub fn from_slice(slice: &'a [u8]) -> Result<MplsLabelStackEntrySlice<'a>, err::LenError> {
+        //check length
+        if slice.len() < MplsLabelStackEntry::LEN {
+            return Err(err::LenError {
+                required_len: MplsLabelStackEntry::LEN,
+                len: slice.len(),
+                len_source: err::LenSource::Slice,
+                layer: err::Layer::MplsLabelStack,
+                layer_start_offset: 0,
+            });
+        }
+
+        //all done
+        Ok(MplsLabelStackEntrySlice {
+            // SAFETY:
+            // Safe as slice length is checked to be at least
+            // MplsLabelStackEntry::LEN (4) before this.
+            slice: unsafe { from_raw_parts(slice.as_ptr(), MplsLabelStackEntry::LEN) },
+        })
+    }
+
+    /// Converts the given slice into an entry slice WITHOUT any checks.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensured that the given slice has the length of
+    /// [`MplsLabelStackEntry::LEN`]
+    #[inline]
+    pub(crate) unsafe fn from_slice_unchecked(slice: &'a [u8]) -> MplsLabelStackEntrySlice<'a> {
+        MplsLabelStackEntrySlice { slice }
+    }
+
+    /// Returns the slice containing the MPLS label stack entry.
+    #[inline]
+    pub fn slice(&self) -> &'a [u8] {
+        self.slice
+    }
+
+    /// Read the 20 bit "label" field.
+    #[inline]
+    pub fn label(&self) -> MplsLabel {
+        // SAFETY:
+        // Safe as the contructor checks that the slice has
+        // at least the length of MplsLabelStackEntry::LEN (4) &
+        // the value is constructed from 20 bits.
+        unsafe {
+            MplsLabel::new_unchecked(
+                u32::from_be_bytes([
+                    0,
+                    *self.slice.get_unchecked(0),
+                    *self.slice.get_unchecked(1),
+                    *self.slice.get_unchecked(2),
+                ]) >> 4,
+            )
+        }
+    }
+
+    /// Read the 3 bit "traffic class" field.
+    #[inline]
+    pub fn traffic_class(&self) -> MplsTrafficClass {
+        // SAFETY:
+        // Safe as the contructor checks that the slice has
+        // at least the length of MplsLabelStackEntry::LEN (4) &
+        // the bitmask gurantees values does not exceed 0b0000_0111.
+        unsafe {
+            MplsTrafficClass::new_unchecked((*self.slice.get_unchecked(2) >> 1) & 0b0000_0111)
+        }
+    }
+
+    /// Read the "bottom of stack" flag.
+    #[inline]
+    pub fn bottom_of_stack(&self) -> bool {
+        // SAFETY:
+        // Safe as the contructor checks that the slice has
+        // at least the length of MplsLabelStackEntry::LEN (4).
+        0 != unsafe { *self.slice.get_unchecked(2) } & 0b0000_0001
+    }
+
+    /// Read the "time to live" field.
+    #[inline]
+    pub fn ttl(&self) -> u8 {
+        // SAFETY:
+        // Safe as the contructor checks that the slice has
+        // at least the length of MplsLabelStackEntry::LEN (4).
+        unsafe { *self.slice.get_unchecked(3) }
+    }
+
+    /// Decode all the fields and copy the results to a [`MplsLabelStackEntry`] struct
+    pub fn to_header(&self) -> MplsLabelStackEntry {
+        MplsLabelStackEntry {
+            label: self.label(),
+            traffic_class: self.traffic_class(),
+            bottom_of_stack: self.bottom_of_stack(),
+            ttl: self.ttl(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_gens::*;
+    use alloc::{format, vec::Vec};
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn from_slice(
+            input in mpls_entry_any(),
+            dummy_data in proptest::collection::vec(any::<u8>(), 0..20)
+        ) {
+            // serialize
+            let mut buffer: Vec<u8> = Vec::with_capacity(4 + dummy_data.len());
+            input.write(&mut buffer).unwrap();
+            buffer.extend(&dummy_data[..]);
+
+            // calls with a valid result
+            {
+                let result = MplsLabelStackEntrySlice::from_slice(&buffer[..]).unwrap();
+                assert_eq!(&buffer[..4], result.slice());
+            }
+
+            // call with not enough data in the slice
+            for len in 0..4 {
+                assert_eq!(
+                    MplsLabelStackEntrySlice::from_slice(&buffer[..len]),
+                    Err(err::LenError{
+                        required_len: MplsLabelStackEntry::LEN,
+                        len,
+                        len_source: err::LenSource::Slice,
+                        layer: err::Layer::MplsLabelStack,
+                        layer_start_offset: 0,
+                    })
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn getters(input in mpls_entry_any()) {
+            let bytes = input.to_bytes();
+            let slice = MplsLabelStackEntrySlice::from_slice(&bytes).unwrap();
+            assert_eq!(input.label, slice.label());
+            assert_eq!(input.traffic_class, slice.traffic_class());
+            assert_eq!(input.bottom_of_stack, slice.bottom_of_stack());
+            assert_eq!(input.ttl, slice.ttl());
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn to_header(input in mpls_entry_any()) {
+            let bytes = input.to_bytes();
+            let slice = MplsLabelStackEntrySlice::from_slice(&bytes).unwrap();
+            assert_eq!(input, slice.to_header());
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn clone_eq_dbg(input in mpls_entry_any()) {
+            let bytes = input.to_bytes();
+            let slice = MplsLabelStackEntrySlice::from_slice(&bytes).unwrap();
+            assert_eq!(slice, slice.clone());
+            assert_eq!(
+                &format!(
+                    "MplsLabelStackEntrySlice {{ slice: {:?} }}",
+                    slice.slice(),
+                ),
+                &format!("{:?}", slice)
+            );
+        }
+    }
+}
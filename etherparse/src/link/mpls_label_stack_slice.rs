@@ -0,0 +1,238 @@
+use crate::*;
+use core::slice::from_raw_parts;
+
+/// A slice containing an MPLS label stack (all entries up to & including
+/// the entry with the "bottom of stack" flag set).
+///
+/// The label stack can have any depth, use [`MplsLabelStackSlice::entries`]
+/// to iterate over the entries.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MplsLabelStackSlice<'a> {
+    slice: &'a [u8],
+}
+
+impl<'a> MplsLabelStackSlice<'a> {
+    /// Creates an MPLS label stack slice from an other slice.
+    ///
+    /// The entries are read until an entry with the "bottom of stack"
+    /// flag set is encountered. If the slice ends before such an entry
+    /// is found an error is returned.
+    pub fn from_slice(slice: &'a [u8]) -> Result<MplsLabelStackSlice<'a>, err::LenError> {
+        let mut len = 0;
+        loop {
+            //check length
+            if slice.len() < len + MplsLabelStackEntry::LEN {
+                return Err(err::LenError {
+                    required_len: len + MplsLabelStackEntry::LEN,
+                    len: slice.len(),
+                    len_source: err::LenSource::Slice,
+                    layer: err::Layer::MplsLabelStack,
+                    layer_start_offset: 0,
+                });
+            }
+            // SAFETY:
+            // Safe as the slice length is checked to be at least
+            // len + MplsLabelStackEntry::LEN (4) above.
+            let bottom_of_stack = 0 != unsafe { *slice.get_unchecked(len + 2) } & 0b0000_0001;
+            len += MplsLabelStackEntry::LEN;
+            if bottom_of_stack {
+                break;
+            }
+        }
+
+        //all done
+        Ok(MplsLabelStackSlice {
+            // SAFETY:
+            // Safe as slice length is checked to be at least
+            // len before this.
+            slice: unsafe { from_raw_parts(slice.as_ptr(), len) },
+        })
+    }
+
+    /// Returns the slice containing the MPLS label stack.
+    #[inline]
+    pub fn slice(&self) -> &'a [u8] {
+        self.slice
+    }
+
+    /// Returns an iterator over all entries of the label stack (starting
+    /// with the top entry).
+    #[inline]
+    pub fn entries(&self) -> MplsLabelStackSliceIter<'a> {
+        MplsLabelStackSliceIter { rest: self.slice }
+    }
+
+    /// Number of entries in the label stack.
+    #[inline]
+    pub fn num_entries(&self) -> usize {
+        self.slice.len() / MplsLabelStackEntry::LEN
+    }
+
+    /// Returns the top (first) entry of the label stack.
+    #[inline]
+    pub fn top(&self) -> MplsLabelStackEntrySlice<'a> {
+        // SAFETY:
+        // Safe as the constructor ensures that at least one entry is present.
+        unsafe {
+            MplsLabelStackEntrySlice::from_slice_unchecked(from_raw_parts(
+                self.slice.as_ptr(),
+                MplsLabelStackEntry::LEN,
+            ))
+        }
+    }
+
+    /// Returns the bottom (last) entry of the label stack.
+    #[inline]
+    pub fn bottom(&self) -> MplsLabelStackEntrySlice<'a> {
+        // SAFETY:
+        // Safe as the constructor ensures that at least one entry is present
+        // and that the slice length is a multiple of MplsLabelStackEntry::LEN.
+        unsafe {
+            MplsLabelStackEntrySlice::from_slice_unchecked(from_raw_parts(
+                self.slice
+                    .as_ptr()
+                    .add(self.slice.len() - MplsLabelStackEntry::LEN),
+                MplsLabelStackEntry::LEN,
+            ))
+        }
+    }
+
+    /// Decode all the entries and copy the results to a [`MplsLabelStack`]
+    /// (errors if the stack has more entries then [`MplsLabelStack::MAX_ENTRIES`]).
+    pub fn to_header(&self) -> Result<MplsLabelStack, err::mpls::HeaderError> {
+        let mut result = MplsLabelStack::new();
+        for entry in self.entries() {
+            result.try_push(entry.to_header())?;
+        }
+        Ok(result)
+    }
+}
+
+/// Guesses the ether type of the payload of an MPLS label stack based
+/// on the IP version number in the first nibble (MPLS does not contain
+/// any field identifying the payload protocol).
+#[inline]
+pub(crate) fn mpls_payload_ether_type_guess(payload: &[u8]) -> Option<EtherType> {
+    match payload.first().map(|v| v >> 4) {
+        Some(4) => Some(ether_type::IPV4),
+        Some(6) => Some(ether_type::IPV6),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_gens::*;
+    use alloc::{format, vec::Vec};
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn from_slice(
+            input in mpls_stack_any(),
+            dummy_data in proptest::collection::vec(any::<u8>(), 0..20)
+        ) {
+            // serialize
+            let mut buffer: Vec<u8> = Vec::with_capacity(input.header_len() + dummy_data.len());
+            input.write(&mut buffer).unwrap();
+            buffer.extend(&dummy_data[..]);
+
+            // calls with a valid result
+            {
+                let result = MplsLabelStackSlice::from_slice(&buffer[..]).unwrap();
+                assert_eq!(&buffer[..input.header_len()], result.slice());
+                assert_eq!(input.entries().len(), result.num_entries());
+            }
+
+            // call with not enough data in the slice
+            for len in 0..input.header_len() {
+                assert_eq!(
+                    MplsLabelStackSlice::from_slice(&buffer[..len]),
+                    Err(err::LenError{
+                        required_len: (len / 4 + 1) * 4,
+                        len,
+                        len_source: err::LenSource::Slice,
+                        layer: err::Layer::MplsLabelStack,
+                        layer_start_offset: 0,
+                    })
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn entries_top_bottom(input in mpls_stack_any()) {
+            let mut bytes = Vec::with_capacity(input.header_len());
+            input.write(&mut bytes).unwrap();
+            let slice = MplsLabelStackSlice::from_slice(&bytes).unwrap();
+
+            let actual: Vec<MplsLabelStackEntry> = slice.entries().map(|e| e.to_header()).collect();
+            assert_eq!(input.entries(), &actual[..]);
+            assert_eq!(&input.entries()[0], &slice.top().to_header());
+            assert_eq!(input.entries().last().unwrap(), &slice.bottom().to_header());
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn to_header(input in mpls_stack_any()) {
+            let mut bytes = Vec::with_capacity(input.header_len());
+            input.write(&mut bytes).unwrap();
+            let slice = MplsLabelStackSlice::from_slice(&bytes).unwrap();
+            assert_eq!(Ok(input), slice.to_header());
+        }
+    }
+
+    #[test]
+    fn to_header_too_many_entries() {
+        let mut bytes = Vec::new();
+        for i in 0..=MplsLabelStack::MAX_ENTRIES {
+            MplsLabelStackEntry {
+                label: MplsLabel::try_new(i as u32).unwrap(),
+                traffic_class: MplsTrafficClass::ZERO,
+                bottom_of_stack: i == MplsLabelStack::MAX_ENTRIES,
+                ttl: 1,
+            }
+            .write(&mut bytes)
+            .unwrap();
+        }
+        let slice = MplsLabelStackSlice::from_slice(&bytes).unwrap();
+        assert_eq!(MplsLabelStack::MAX_ENTRIES + 1, slice.num_entries());
+        assert_eq!(MplsLabelStack::MAX_ENTRIES + 1, slice.entries().count());
+        assert_eq!(
+            Err(err::mpls::HeaderError::TooManyEntries {
+                max_entries: MplsLabelStack::MAX_ENTRIES
+            }),
+            slice.to_header()
+        );
+    }
+
+    #[test]
+    fn mpls_payload_ether_type_guess() {
+        use super::mpls_payload_ether_type_guess as guess;
+        assert_eq!(None, guess(&[]));
+        assert_eq!(Some(ether_type::IPV4), guess(&[0x45, 0]));
+        assert_eq!(Some(ether_type::IPV6), guess(&[0x60, 0]));
+        assert_eq!(None, guess(&[0x00, 0]));
+        assert_eq!(None, guess(&[0x50, 0]));
+    }
+
+    proptest! {
+        #[test]
+        fn clone_eq_dbg(input in mpls_stack_any()) {
+            let mut bytes = Vec::with_capacity(input.header_len());
+            input.write(&mut bytes).unwrap();
+            let slice = MplsLabelStackSlice::from_slice(&bytes).unwrap();
+            assert_eq!(slice, slice.clone());
+            assert_eq!(
+                &format!(
+                    "MplsLabelStackSlice {{ slice: {:?} }}",
+                    slice.slice(),
+                ),
+                &format!("{:?}", slice)
+            );
+        }
+    }
+}
@@ -0,0 +1,78 @@
+use crate::*;
+use core::slice::from_raw_parts;
+
+/// Allows iterating over the entries present in an [`MplsLabelStackSlice`].
+#[derive(Clone, Debug, Eq, PartialEq, Default)]
+pub struct MplsLabelStackSliceIter<'a> {
+    pub(crate) rest: &'a [u8],
+}
+
+impl<'a> Iterator for MplsLabelStackSliceIter<'a> {
+    type Item = MplsLabelStackEntrySlice<'a>;
+
+    fn next(&mut self) -> Option<MplsLabelStackEntrySlice<'a>> {
+        if self.rest.len() < MplsLabelStackEntry::LEN {
+            None
+        } else {
+            // SAFETY:
+            // Safe as the length of the rest was checked above to contain
+            // at least MplsLabelStackEntry::LEN (4) bytes.
+            unsafe {
+                let result = MplsLabelStackEntrySlice::from_slice_unchecked(from_raw_parts(
+                    self.rest.as_ptr(),
+                    MplsLabelStackEntry::LEN,
+                ));
+                self.rest = from_raw_parts(
+                    self.rest.as_ptr().add(MplsLabelStackEntry::LEN),
+                    self.rest.len() - MplsLabelStackEntry::LEN,
+                );
+                Some(result)
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.rest.len() / MplsLabelStackEntry::LEN;
+        (len, Some(len))
+    }
+}
+
+impl<'a> ExactSizeIterator for MplsLabelStackSliceIter<'a> {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::format;
+
+    #[test]
+    fn iterate() {
+        let data = [0, 0x01, 0x00, 0x10, 0, 0x02, 0x01, 0x20, 0xff];
+        let mut iter = MplsLabelStackSliceIter { rest: &data };
+        assert_eq!(2, iter.len());
+
+        let first = iter.next().unwrap();
+        assert_eq!(16, first.label().value());
+        assert!(false == first.bottom_of_stack());
+        assert_eq!(0x10, first.ttl());
+        assert_eq!(1, iter.len());
+
+        let second = iter.next().unwrap();
+        assert_eq!(32, second.label().value());
+        assert!(second.bottom_of_stack());
+        assert_eq!(0x20, second.ttl());
+        assert_eq!(0, iter.len());
+
+        assert_eq!(None, iter.next());
+    }
+
+    #[test]
+    fn default_clone_eq_dbg() {
+        let iter = MplsLabelStackSliceIter::default();
+        assert_eq!(iter.rest, &[]);
+        assert_eq!(iter, iter.clone());
+        assert_eq!(
+            "MplsLabelStackSliceIter { rest: [] }",
+            format!("{:?}", iter)
+        );
+    }
+}
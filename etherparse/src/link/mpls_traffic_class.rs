@@ -0,0 +1,255 @@
+use crate::err::ValueTooBigError;
+
+/// 3 bit unsigned integer containing the "Traffic Class" of an MPLS
+/// label stack entry (present in the [`crate::MplsLabelStackEntry`]).
+///
+/// Used for QoS and ECN purposes (formerly called "EXP" field, see
+/// RFC 5462).
+#[derive(Copy, Clone, Default, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct MplsTrafficClass(u8);
+
+impl MplsTrafficClass {
+    /// MplsTrafficClass with value 0.
+    pub const ZERO: MplsTrafficClass = MplsTrafficClass(0);
+
+    /// Maximum value of an MPLS traffic class.
+    pub const MAX_U8: u8 = 0b0000_0111;
+
+    /// Tries to create an [`MplsTrafficClass`] and checks that the passed value
+    /// is smaller or equal than [`MplsTrafficClass::MAX_U8`] (3 bit unsigned integer).
+    ///
+    /// In case the passed value is bigger then what can be represented in an 3 bit
+    /// integer an error is returned. Otherwise an `Ok` containing the [`MplsTrafficClass`].
+    ///
+    /// ```
+    /// use etherparse::MplsTrafficClass;
+    ///
+    /// let tc = MplsTrafficClass::try_new(2).unwrap();
+    /// assert_eq!(tc.value(), 2);
+    ///
+    /// // if a number that can not be represented in an 3 bit integer
+    /// // gets passed in an error is returned
+    /// use etherparse::err::{ValueTooBigError, ValueType};
+    /// assert_eq!(
+    ///     MplsTrafficClass::try_new(MplsTrafficClass::MAX_U8 + 1),
+    ///     Err(ValueTooBigError{
+    ///         actual: MplsTrafficClass::MAX_U8 + 1,
+    ///         max_allowed: MplsTrafficClass::MAX_U8,
+    ///         value_type: ValueType::MplsTrafficClass,
+    ///     })
+    /// );
+    /// ```
+    #[inline]
+    pub const fn try_new(value: u8) -> Result<MplsTrafficClass, ValueTooBigError<u8>> {
+        use crate::err::ValueType;
+        if value <= MplsTrafficClass::MAX_U8 {
+            Ok(MplsTrafficClass(value))
+        } else {
+            Err(ValueTooBigError {
+                actual: value,
+                max_allowed: MplsTrafficClass::MAX_U8,
+                value_type: ValueType::MplsTrafficClass,
+            })
+        }
+    }
+
+    /// Creates an [`MplsTrafficClass`] without checking that the value
+    /// is smaller or equal than [`MplsTrafficClass::MAX_U8`] (3 bit unsigned integer).
+    /// The caller must gurantee that `value <= MplsTrafficClass::MAX_U8`.
+    ///
+    /// # Safety
+    ///
+    /// `value` must be smaller or equal than [`MplsTrafficClass::MAX_U8`]
+    /// otherwise the behaviour of functions or datastructures relying
+    /// on this pre-requirement is undefined.
+    #[inline]
+    pub const unsafe fn new_unchecked(value: u8) -> MplsTrafficClass {
+        debug_assert!(value <= MplsTrafficClass::MAX_U8);
+        MplsTrafficClass(value)
+    }
+
+    /// Returns the underlying unsigned 3 bit value as an `u8` value.
+    #[inline]
+    pub const fn value(self) -> u8 {
+        self.0
+    }
+}
+
+impl core::fmt::Display for MplsTrafficClass {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl From<MplsTrafficClass> for u8 {
+    #[inline]
+    fn from(value: MplsTrafficClass) -> Self {
+        value.0
+    }
+}
+
+impl TryFrom<u8> for MplsTrafficClass {
+    type Error = ValueTooBigError<u8>;
+
+    #[inline]
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        use crate::err::ValueType;
+        if value <= MplsTrafficClass::MAX_U8 {
+            Ok(MplsTrafficClass(value))
+        } else {
+            Err(Self::Error {
+                actual: value,
+                max_allowed: MplsTrafficClass::MAX_U8,
+                value_type: ValueType::MplsTrafficClass,
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use core::hash::{Hash, Hasher};
+    use proptest::prelude::*;
+    use std::format;
+
+    #[test]
+    fn derived_traits() {
+        // copy & clone
+        {
+            let a = MplsTrafficClass(2);
+            let b = a;
+            assert_eq!(a, b);
+            assert_eq!(a.clone(), a);
+        }
+
+        // default
+        {
+            let actual: MplsTrafficClass = Default::default();
+            assert_eq!(actual.value(), 0);
+        }
+
+        // debug
+        {
+            let a = MplsTrafficClass(2);
+            assert_eq!(format!("{:?}", a), format!("MplsTrafficClass(2)"));
+        }
+
+        // ord & partial ord
+        {
+            use core::cmp::Ordering;
+            let a = MplsTrafficClass(2);
+            let b = a;
+            assert_eq!(a.cmp(&b), Ordering::Equal);
+            assert_eq!(a.partial_cmp(&b), Some(Ordering::Equal));
+        }
+
+        // hash
+        {
+            use std::collections::hash_map::DefaultHasher;
+            let a = {
+                let mut hasher = DefaultHasher::new();
+                MplsTrafficClass(2).hash(&mut hasher);
+                hasher.finish()
+            };
+            let b = {
+                let mut hasher = DefaultHasher::new();
+                MplsTrafficClass(2).hash(&mut hasher);
+                hasher.finish()
+            };
+            assert_eq!(a, b);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn try_new(
+            valid_value in 0..=0b0000_0111u8,
+            invalid_value in 0b0000_1000u8..=u8::MAX
+        ) {
+            use crate::err::{ValueType, ValueTooBigError};
+            assert_eq!(
+                valid_value,
+                MplsTrafficClass::try_new(valid_value).unwrap().value()
+            );
+            assert_eq!(
+                MplsTrafficClass::try_new(invalid_value).unwrap_err(),
+                ValueTooBigError{
+                    actual: invalid_value,
+                    max_allowed: 0b0000_0111,
+                    value_type:  ValueType::MplsTrafficClass
+                }
+            );
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn try_from(
+            valid_value in 0..=0b0000_0111u8,
+            invalid_value in 0b0000_1000u8..=u8::MAX
+        ) {
+            use crate::err::{ValueType, ValueTooBigError};
+            // try_into
+            {
+                let actual: MplsTrafficClass = valid_value.try_into().unwrap();
+                assert_eq!(actual.value(), valid_value);
+
+                let err: Result<MplsTrafficClass, ValueTooBigError<u8>> = invalid_value.try_into();
+                assert_eq!(
+                    err.unwrap_err(),
+                    ValueTooBigError{
+                        actual: invalid_value,
+                        max_allowed: 0b0000_0111,
+                        value_type:  ValueType::MplsTrafficClass
+                    }
+                );
+            }
+            // try_from
+            {
+                assert_eq!(
+                    MplsTrafficClass::try_from(valid_value).unwrap().value(),
+                    valid_value
+                );
+
+                assert_eq!(
+                    MplsTrafficClass::try_from(invalid_value).unwrap_err(),
+                    ValueTooBigError{
+                        actual: invalid_value,
+                        max_allowed: 0b0000_0111,
+                        value_type:  ValueType::MplsTrafficClass
+                    }
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn new_unchecked(valid_value in 0..=0b0000_0111u8) {
+            assert_eq!(
+                valid_value,
+                unsafe {
+                    MplsTrafficClass::new_unchecked(valid_value).value()
+                }
+            );
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn fmt(valid_value in 0..=0b0000_0111u8) {
+            assert_eq!(format!("{}", MplsTrafficClass(valid_value)), format!("{}", valid_value));
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn from(valid_value in 0..=0b0000_0111u8,) {
+            let tc = MplsTrafficClass::try_new(valid_value).unwrap();
+            let actual: u8 = tc.into();
+            assert_eq!(actual, valid_value);
+        }
+    }
+}
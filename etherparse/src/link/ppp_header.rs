@@ -0,0 +1,255 @@
+use crate::{err::Layer, err::SliceWriteSpaceError, *};
+
+/// PPP header (RFC 1661) as present at the start of the payload of PPPoE
+/// session stage packets.
+///
+/// Only the uncompressed two octet protocol field is supported (PPPoE
+/// frames do not contain the address & control fields and protocol
+/// field compression is only used on low speed links).
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Default)]
+pub struct PppHeader {
+    /// Protocol of the encapsulated datagram.
+    pub protocol: PppProtocol,
+}
+
+impl PppHeader {
+    /// Serialized size of a PPP header in bytes/octets.
+    pub const LEN: usize = 2;
+
+    /// Read a [`PppHeader`] from a slice and return the header & unused parts of the slice.
+    #[inline]
+    pub fn from_slice(slice: &[u8]) -> Result<(PppHeader, &[u8]), err::LenError> {
+        Ok((
+            PppHeaderSlice::from_slice(slice)?.to_header(),
+            &slice[PppHeader::LEN..],
+        ))
+    }
+
+    /// Read a PPP header from the given source.
+    #[cfg(feature = "std")]
+    pub fn read<T: std::io::Read + std::io::Seek + Sized>(
+        reader: &mut T,
+    ) -> Result<PppHeader, std::io::Error> {
+        let mut buffer = [0; PppHeader::LEN];
+        reader.read_exact(&mut buffer)?;
+        Ok(PppHeader {
+            protocol: PppProtocol(u16::from_be_bytes(buffer)),
+        })
+    }
+
+    /// Serialize the header to a given slice. Returns the unused part of the slice.
+    pub fn write_to_slice<'a>(
+        &self,
+        slice: &'a mut [u8],
+    ) -> Result<&'a mut [u8], SliceWriteSpaceError> {
+        // length check
+        if slice.len() < PppHeader::LEN {
+            Err(SliceWriteSpaceError {
+                required_len: PppHeader::LEN,
+                len: slice.len(),
+                layer: Layer::PppHeader,
+                layer_start_offset: 0,
+            })
+        } else {
+            slice[..PppHeader::LEN].copy_from_slice(&self.to_bytes());
+            Ok(&mut slice[PppHeader::LEN..])
+        }
+    }
+
+    /// Writes a given PPP header to the current position of the write argument.
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn write<T: std::io::Write + Sized>(&self, writer: &mut T) -> Result<(), std::io::Error> {
+        writer.write_all(&self.to_bytes())
+    }
+
+    /// Length of the serialized header in bytes.
+    #[inline]
+    pub fn header_len(&self) -> usize {
+        PppHeader::LEN
+    }
+
+    /// Returns the ether type corresponding to the PPP protocol field
+    /// if the payload contains an IPv4 or IPv6 packet.
+    #[inline]
+    pub fn payload_ether_type(&self) -> Option<EtherType> {
+        ppp_payload_ether_type(self.protocol)
+    }
+
+    /// Returns the serialized form of the header.
+    #[inline]
+    pub fn to_bytes(&self) -> [u8; 2] {
+        self.protocol.0.to_be_bytes()
+    }
+}
+
+/// Maps the PPP protocol field to the ether type of the payload (if the
+/// payload is an IPv4 or IPv6 packet).
+#[inline]
+pub(crate) fn ppp_payload_ether_type(protocol: PppProtocol) -> Option<EtherType> {
+    match protocol {
+        PppProtocol::IPV4 => Some(ether_type::IPV4),
+        PppProtocol::IPV6 => Some(ether_type::IPV6),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::{format, vec::Vec};
+    use proptest::prelude::*;
+    use std::io::{Cursor, ErrorKind};
+
+    proptest! {
+        #[test]
+        fn from_slice(
+            protocol in any::<u16>(),
+            dummy_data in proptest::collection::vec(any::<u8>(), 0..20)
+        ) {
+            let input = PppHeader{ protocol: PppProtocol(protocol) };
+
+            // serialize
+            let mut buffer: Vec<u8> = Vec::with_capacity(2 + dummy_data.len());
+            input.write(&mut buffer).unwrap();
+            buffer.extend(&dummy_data[..]);
+
+            // calls with a valid result
+            {
+                let (result, rest) = PppHeader::from_slice(&buffer[..]).unwrap();
+                assert_eq!(input, result);
+                assert_eq!(&buffer[2..], rest);
+            }
+
+            // call with not enough data in the slice
+            for len in 0..2 {
+                assert_eq!(
+                    PppHeader::from_slice(&buffer[..len]),
+                    Err(err::LenError{
+                        required_len: PppHeader::LEN,
+                        len,
+                        len_source: err::LenSource::Slice,
+                        layer: err::Layer::PppHeader,
+                        layer_start_offset: 0,
+                    })
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn read(
+            protocol in any::<u16>(),
+            dummy_data in proptest::collection::vec(any::<u8>(), 0..20)
+        ) {
+            let input = PppHeader{ protocol: PppProtocol(protocol) };
+
+            let mut buffer = Vec::with_capacity(2 + dummy_data.len());
+            input.write(&mut buffer).unwrap();
+            buffer.extend(&dummy_data[..]);
+
+            // calls with a valid result
+            {
+                let mut cursor = Cursor::new(&buffer);
+                let result = PppHeader::read(&mut cursor).unwrap();
+                assert_eq!(input, result);
+                assert_eq!(cursor.position(), 2);
+            }
+
+            // unexpected eof
+            for len in 0..2 {
+                let mut cursor = Cursor::new(&buffer[0..len]);
+                assert_eq!(
+                    PppHeader::read(&mut cursor)
+                    .unwrap_err()
+                    .kind(),
+                    ErrorKind::UnexpectedEof
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn write_to_slice(protocol in any::<u16>()) {
+            let input = PppHeader{ protocol: PppProtocol(protocol) };
+            // normal write
+            {
+                let mut buffer: [u8;2] = [0;2];
+                input.write_to_slice(&mut buffer).unwrap();
+                assert_eq!(buffer, input.to_bytes());
+            }
+            // len to small
+            for len in 0..2 {
+                let mut buffer: [u8;2] = [0;2];
+                assert_eq!(
+                    SliceWriteSpaceError {
+                        required_len: PppHeader::LEN,
+                        len,
+                        layer: Layer::PppHeader,
+                        layer_start_offset: 0,
+                    },
+                    input.write_to_slice(&mut buffer[..len]).unwrap_err()
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn write(protocol in any::<u16>()) {
+            let input = PppHeader{ protocol: PppProtocol(protocol) };
+
+            // successfull write
+            {
+                let mut buffer: Vec<u8> = Vec::with_capacity(2);
+                input.write(&mut buffer).unwrap();
+                assert_eq!(&buffer[..], &protocol.to_be_bytes());
+            }
+
+            // not enough memory for write (unexpected eof)
+            for len in 0..2 {
+                let mut buffer = [0u8;2];
+                let mut writer = Cursor::new(&mut buffer[..len]);
+                assert!(input.write(&mut writer).is_err());
+            }
+        }
+    }
+
+    #[test]
+    fn header_len() {
+        assert_eq!(2, PppHeader::default().header_len());
+    }
+
+    #[test]
+    fn payload_ether_type() {
+        let pairs = [
+            (PppProtocol::IPV4, Some(ether_type::IPV4)),
+            (PppProtocol::IPV6, Some(ether_type::IPV6)),
+            (PppProtocol::LCP, None),
+            (PppProtocol(0x1234), None),
+        ];
+        for (protocol, expected) in pairs {
+            assert_eq!(expected, PppHeader { protocol }.payload_ether_type());
+        }
+    }
+
+    #[test]
+    fn clone_eq_default_dbg() {
+        let header = PppHeader {
+            protocol: PppProtocol::IPV6,
+        };
+        assert_eq!(header, header.clone());
+        assert_eq!(
+            PppHeader {
+                protocol: PppProtocol(0)
+            },
+            PppHeader::default()
+        );
+        assert_eq!(
+            format!("PppHeader {{ protocol: {:?} }}", PppProtocol::IPV6),
+            format!("{:?}", header)
+        );
+    }
+}
@@ -0,0 +1,123 @@
+use crate::*;
+use core::slice::from_raw_parts;
+
+/// A slice containing a PPP header of a network package.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PppHeaderSlice<'a> {
+    slice: &'a [u8],
+}
+
+impl<'a> PppHeaderSlice<'a> {
+    /// Creates a PPP header slice from an other slice.
+    pub fn from_slice(slice: &'a [u8]) -> Result<PppHeaderSlice<'a>, err::LenError> {
+        //check length
+        if slice.len() < PppHeader::LEN {
+            return Err(err::LenError {
+                required_len: PppHeader::LEN,
+                len: slice.len(),
+                len_source: err::LenSource::Slice,
+                layer: err::Layer::PppHeader,
+                layer_start_offset: 0,
+            });
+        }
+
+        //all done
+        Ok(PppHeaderSlice {
+            // SAFETY:
+            // Safe as slice length is checked to be at least
+            // PppHeader::LEN (2) before this.
+            slice: unsafe { from_raw_parts(slice.as_ptr(), PppHeader::LEN) },
+        })
+    }
+
+    /// Returns the slice containing the PPP header
+    #[inline]
+    pub fn slice(&self) -> &'a [u8] {
+        self.slice
+    }
+
+    /// Read the "protocol" field.
+    #[inline]
+    pub fn protocol(&self) -> PppProtocol {
+        // SAFETY:
+        // Safe as the contructor checks that the slice has
+        // at least the length of PppHeader::LEN (2).
+        PppProtocol(unsafe { get_unchecked_be_u16(self.slice.as_ptr()) })
+    }
+
+    /// Returns the ether type corresponding to the PPP protocol field
+    /// if the payload contains an IPv4 or IPv6 packet.
+    #[inline]
+    pub fn payload_ether_type(&self) -> Option<EtherType> {
+        crate::link::ppp_header::ppp_payload_ether_type(self.protocol())
+    }
+
+    /// Decode all the fields and copy the results to a [`PppHeader`] struct
+    pub fn to_header(&self) -> PppHeader {
+        PppHeader {
+            protocol: self.protocol(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::{format, vec::Vec};
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn from_slice(
+            protocol in any::<u16>(),
+            dummy_data in proptest::collection::vec(any::<u8>(), 0..20)
+        ) {
+            // serialize
+            let mut buffer: Vec<u8> = Vec::with_capacity(2 + dummy_data.len());
+            buffer.extend_from_slice(&protocol.to_be_bytes());
+            buffer.extend(&dummy_data[..]);
+
+            // calls with a valid result
+            {
+                let result = PppHeaderSlice::from_slice(&buffer[..]).unwrap();
+                assert_eq!(&buffer[..2], result.slice());
+            }
+
+            // call with not enough data in the slice
+            for len in 0..2 {
+                assert_eq!(
+                    PppHeaderSlice::from_slice(&buffer[..len]),
+                    Err(err::LenError{
+                        required_len: PppHeader::LEN,
+                        len,
+                        len_source: err::LenSource::Slice,
+                        layer: err::Layer::PppHeader,
+                        layer_start_offset: 0,
+                    })
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn getters_to_header(protocol in any::<u16>()) {
+            let bytes = protocol.to_be_bytes();
+            let slice = PppHeaderSlice::from_slice(&bytes).unwrap();
+            assert_eq!(PppProtocol(protocol), slice.protocol());
+            assert_eq!(
+                PppHeader{ protocol: PppProtocol(protocol) }.payload_ether_type(),
+                slice.payload_ether_type()
+            );
+            assert_eq!(PppHeader{ protocol: PppProtocol(protocol) }, slice.to_header());
+        }
+    }
+
+    #[test]
+    fn clone_eq_dbg() {
+        let bytes = [0x00, 0x21];
+        let slice = PppHeaderSlice::from_slice(&bytes).unwrap();
+        assert_eq!(slice, slice.clone());
+        assert_eq!("PppHeaderSlice { slice: [0, 33] }", format!("{:?}", slice));
+    }
+}
@@ -0,0 +1,113 @@
+/// Protocol field of a PPP header, identifying the protocol of the
+/// encapsulated datagram (RFC 1661).
+///
+/// You can access the underlying `u16` value by using `.0` and any `u16`
+/// can be converted to a `PppProtocol`:
+///
+/// ```
+/// use etherparse::PppProtocol;
+///
+/// assert_eq!(PppProtocol::IPV4.0, 0x0021);
+/// assert_eq!(PppProtocol::IPV4, PppProtocol(0x0021));
+///
+/// // convert to PppProtocol using the from & into trait
+/// let protocol: PppProtocol = 0x0057u16.into();
+/// assert_eq!(PppProtocol::IPV6, protocol);
+///
+/// // convert to u16 using the from & into trait
+/// let num: u16 = PppProtocol::LCP.into();
+/// assert_eq!(0xc021, num);
+/// ```
+#[derive(Default, PartialEq, Eq, Clone, Copy, Hash, Ord, PartialOrd)]
+pub struct PppProtocol(pub u16);
+
+impl PppProtocol {
+    /// Internet Protocol version 4.
+    pub const IPV4: PppProtocol = Self(0x0021);
+    /// Internet Protocol version 6.
+    pub const IPV6: PppProtocol = Self(0x0057);
+    /// Internet Protocol Control Protocol (RFC 1332).
+    pub const IPCP: PppProtocol = Self(0x8021);
+    /// IPv6 Control Protocol (RFC 5072).
+    pub const IPV6CP: PppProtocol = Self(0x8057);
+    /// Link Control Protocol (RFC 1661).
+    pub const LCP: PppProtocol = Self(0xc021);
+    /// Password Authentication Protocol (RFC 1334).
+    pub const PAP: PppProtocol = Self(0xc023);
+    /// Challenge Handshake Authentication Protocol (RFC 1994).
+    pub const CHAP: PppProtocol = Self(0xc223);
+}
+
+impl From<u16> for PppProtocol {
+    #[inline]
+    fn from(val: u16) -> Self {
+        PppProtocol(val)
+    }
+}
+
+impl From<PppProtocol> for u16 {
+    #[inline]
+    fn from(val: PppProtocol) -> Self {
+        val.0
+    }
+}
+
+impl core::fmt::Debug for PppProtocol {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match *self {
+            Self::IPV4 => write!(f, "{:#06X} (IPv4)", self.0),
+            Self::IPV6 => write!(f, "{:#06X} (IPv6)", self.0),
+            Self::IPCP => write!(f, "{:#06X} (IPCP)", self.0),
+            Self::IPV6CP => write!(f, "{:#06X} (IPV6CP)", self.0),
+            Self::LCP => write!(f, "{:#06X} (LCP)", self.0),
+            Self::PAP => write!(f, "{:#06X} (PAP)", self.0),
+            Self::CHAP => write!(f, "{:#06X} (CHAP)", self.0),
+            _ => write!(f, "{:#06X}", self.0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::format;
+
+    #[test]
+    fn to_u16() {
+        assert_eq!(0x0021, u16::from(PppProtocol::IPV4));
+        assert_eq!(0x0057, u16::from(PppProtocol::IPV6));
+        assert_eq!(0xc223, u16::from(PppProtocol::CHAP));
+    }
+
+    #[test]
+    fn from_u16() {
+        assert_eq!(PppProtocol::from(0x0021), PppProtocol::IPV4);
+        assert_eq!(PppProtocol::from(0xc021), PppProtocol::LCP);
+        assert_eq!(PppProtocol::from(0x1234), PppProtocol(0x1234));
+    }
+
+    #[test]
+    fn dbg() {
+        let pairs = &[
+            (PppProtocol::IPV4, "0x0021 (IPv4)"),
+            (PppProtocol::IPV6, "0x0057 (IPv6)"),
+            (PppProtocol::IPCP, "0x8021 (IPCP)"),
+            (PppProtocol::IPV6CP, "0x8057 (IPV6CP)"),
+            (PppProtocol::LCP, "0xC021 (LCP)"),
+            (PppProtocol::PAP, "0xC023 (PAP)"),
+            (PppProtocol::CHAP, "0xC223 (CHAP)"),
+            (PppProtocol(0x1234), "0x1234"),
+        ];
+
+        for (input, expected) in pairs {
+            assert_eq!(expected, &format!("{:?}", input));
+        }
+    }
+
+    #[test]
+    fn clone_eq_default() {
+        let value = PppProtocol::LCP;
+        assert_eq!(value, value.clone());
+        assert_eq!(PppProtocol(0), PppProtocol::default());
+    }
+}
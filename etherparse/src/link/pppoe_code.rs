@@ -0,0 +1,109 @@
+/// Code field of a PPPoE header, identifying the type of the PPPoE
+/// packet (RFC 2516).
+///
+/// You can access the underlying `u8` value by using `.0` and any `u8`
+/// can be converted to a `PppoeCode`:
+///
+/// ```
+/// use etherparse::PppoeCode;
+///
+/// assert_eq!(PppoeCode::PADI.0, 0x09);
+/// assert_eq!(PppoeCode::PADI, PppoeCode(0x09));
+///
+/// // convert to PppoeCode using the from & into trait
+/// let code: PppoeCode = 0x07u8.into();
+/// assert_eq!(PppoeCode::PADO, code);
+///
+/// // convert to u8 using the from & into trait
+/// let num: u8 = PppoeCode::PADT.into();
+/// assert_eq!(0xa7, num);
+/// ```
+#[derive(Default, PartialEq, Eq, Clone, Copy, Hash, Ord, PartialOrd)]
+pub struct PppoeCode(pub u8);
+
+impl PppoeCode {
+    /// Code used in all session stage packets (PPP payload).
+    pub const SESSION_DATA: PppoeCode = Self(0x00);
+    /// PPPoE Active Discovery Offer (sent by access concentrators).
+    pub const PADO: PppoeCode = Self(0x07);
+    /// PPPoE Active Discovery Initiation (broadcast by hosts).
+    pub const PADI: PppoeCode = Self(0x09);
+    /// PPPoE Active Discovery Request (sent by hosts).
+    pub const PADR: PppoeCode = Self(0x19);
+    /// PPPoE Active Discovery Session-confirmation (sent by access concentrators).
+    pub const PADS: PppoeCode = Self(0x65);
+    /// PPPoE Active Discovery Terminate (sent by either side).
+    pub const PADT: PppoeCode = Self(0xa7);
+}
+
+impl From<u8> for PppoeCode {
+    #[inline]
+    fn from(val: u8) -> Self {
+        PppoeCode(val)
+    }
+}
+
+impl From<PppoeCode> for u8 {
+    #[inline]
+    fn from(val: PppoeCode) -> Self {
+        val.0
+    }
+}
+
+impl core::fmt::Debug for PppoeCode {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match *self {
+            Self::SESSION_DATA => write!(f, "{:#04X} (Session Data)", self.0),
+            Self::PADO => write!(f, "{:#04X} (PADO)", self.0),
+            Self::PADI => write!(f, "{:#04X} (PADI)", self.0),
+            Self::PADR => write!(f, "{:#04X} (PADR)", self.0),
+            Self::PADS => write!(f, "{:#04X} (PADS)", self.0),
+            Self::PADT => write!(f, "{:#04X} (PADT)", self.0),
+            _ => write!(f, "{:#04X}", self.0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::format;
+
+    #[test]
+    fn to_u8() {
+        assert_eq!(0x00, u8::from(PppoeCode::SESSION_DATA));
+        assert_eq!(0x09, u8::from(PppoeCode::PADI));
+        assert_eq!(0xa7, u8::from(PppoeCode::PADT));
+    }
+
+    #[test]
+    fn from_u8() {
+        assert_eq!(PppoeCode::from(0x00), PppoeCode::SESSION_DATA);
+        assert_eq!(PppoeCode::from(0x19), PppoeCode::PADR);
+        assert_eq!(PppoeCode::from(0x12), PppoeCode(0x12));
+    }
+
+    #[test]
+    fn dbg() {
+        let pairs = &[
+            (PppoeCode::SESSION_DATA, "0x00 (Session Data)"),
+            (PppoeCode::PADO, "0x07 (PADO)"),
+            (PppoeCode::PADI, "0x09 (PADI)"),
+            (PppoeCode::PADR, "0x19 (PADR)"),
+            (PppoeCode::PADS, "0x65 (PADS)"),
+            (PppoeCode::PADT, "0xA7 (PADT)"),
+            (PppoeCode(0x12), "0x12"),
+        ];
+
+        for (input, expected) in pairs {
+            assert_eq!(expected, &format!("{:?}", input));
+        }
+    }
+
+    #[test]
+    fn clone_eq_default() {
+        let value = PppoeCode::PADI;
+        assert_eq!(value, value.clone());
+        assert_eq!(PppoeCode(0), PppoeCode::default());
+    }
+}
@@ -0,0 +1,390 @@
+use crate::{err::Layer, err::SliceWriteSpaceError, *};
+
+/// PPPoE header (PPP over Ethernet, RFC 2516).
+///
+/// PPPoE headers are present in frames with the ether types
+/// [`ether_type::PPPOE_DISCOVERY`] & [`ether_type::PPPOE_SESSION`].
+/// In the discovery stage the payload contains a list of tags (see
+/// [`PppoeTagsIterator`]), in the session stage (code
+/// [`PppoeCode::SESSION_DATA`]) the payload starts with a [`PppHeader`].
+///
+/// The version & type fields are not stored in the struct as only the
+/// value `1` is defined for both of them. They are verified when decoding
+/// and always written as `1`.
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Default)]
+pub struct PppoeHeader {
+    /// Code identifying the type of the PPPoE packet.
+    pub code: PppoeCode,
+    /// Session id (zero until a session has been established).
+    pub session_id: u16,
+    /// Length of the PPPoE payload in bytes (excluding the PPPoE header).
+    pub payload_length: u16,
+}
+
+impl PppoeHeader {
+    /// Serialized size of a PPPoE header in bytes/octets.
+    pub const LEN: usize = 6;
+
+    /// Supported PPPoE version.
+    pub const VERSION: u8 = 1;
+
+    /// Supported PPPoE type.
+    pub const TYPE: u8 = 1;
+
+    /// Read a [`PppoeHeader`] from a slice and return the header & unused parts of the slice.
+    #[inline]
+    pub fn from_slice(slice: &[u8]) -> Result<(PppoeHeader, &[u8]), err::pppoe::HeaderSliceError> {
+        Ok((
+            PppoeHeaderSlice::from_slice(slice)?.to_header(),
+            &slice[PppoeHeader::LEN..],
+        ))
+    }
+
+    /// Read a PPPoE header from the given source.
+    #[cfg(feature = "std")]
+    pub fn read<T: std::io::Read + std::io::Seek + Sized>(
+        reader: &mut T,
+    ) -> Result<PppoeHeader, err::pppoe::HeaderReadError> {
+        use err::pppoe::{HeaderError::*, HeaderReadError::*};
+
+        let buffer = {
+            let mut buffer: [u8; PppoeHeader::LEN] = [0; PppoeHeader::LEN];
+            reader.read_exact(&mut buffer).map_err(Io)?;
+            buffer
+        };
+
+        let version_number = buffer[0] >> 4;
+        if PppoeHeader::VERSION != version_number {
+            return Err(Content(UnexpectedVersion { version_number }));
+        }
+        let type_number = buffer[0] & 0xf;
+        if PppoeHeader::TYPE != type_number {
+            return Err(Content(UnexpectedType { type_number }));
+        }
+
+        Ok(PppoeHeader {
+            code: PppoeCode(buffer[1]),
+            session_id: u16::from_be_bytes([buffer[2], buffer[3]]),
+            payload_length: u16::from_be_bytes([buffer[4], buffer[5]]),
+        })
+    }
+
+    /// Serialize the header to a given slice. Returns the unused part of the slice.
+    pub fn write_to_slice<'a>(
+        &self,
+        slice: &'a mut [u8],
+    ) -> Result<&'a mut [u8], SliceWriteSpaceError> {
+        // length check
+        if slice.len() < PppoeHeader::LEN {
+            Err(SliceWriteSpaceError {
+                required_len: PppoeHeader::LEN,
+                len: slice.len(),
+                layer: Layer::PppoeHeader,
+                layer_start_offset: 0,
+            })
+        } else {
+            slice[..PppoeHeader::LEN].copy_from_slice(&self.to_bytes());
+            Ok(&mut slice[PppoeHeader::LEN..])
+        }
+    }
+
+    /// Writes a given PPPoE header to the current position of the write argument.
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn write<T: std::io::Write + Sized>(&self, writer: &mut T) -> Result<(), std::io::Error> {
+        writer.write_all(&self.to_bytes())
+    }
+
+    /// Length of the serialized header in bytes.
+    #[inline]
+    pub fn header_len(&self) -> usize {
+        PppoeHeader::LEN
+    }
+
+    /// Returns true if the header belongs to the session stage (code
+    /// [`PppoeCode::SESSION_DATA`]) and the payload contains a PPP frame.
+    #[inline]
+    pub fn is_session_data(&self) -> bool {
+        PppoeCode::SESSION_DATA == self.code
+    }
+
+    /// Returns the ether type that has to be used for a frame containing
+    /// this header ([`ether_type::PPPOE_SESSION`] for session data &
+    /// [`ether_type::PPPOE_DISCOVERY`] for all other codes).
+    #[inline]
+    pub fn ether_type(&self) -> EtherType {
+        if self.is_session_data() {
+            ether_type::PPPOE_SESSION
+        } else {
+            ether_type::PPPOE_DISCOVERY
+        }
+    }
+
+    /// Returns the serialized form of the header.
+    #[inline]
+    pub fn to_bytes(&self) -> [u8; 6] {
+        let session_id_be = self.session_id.to_be_bytes();
+        let payload_length_be = self.payload_length.to_be_bytes();
+        [
+            (PppoeHeader::VERSION << 4) | PppoeHeader::TYPE,
+            self.code.0,
+            session_id_be[0],
+            session_id_be[1],
+            payload_length_be[0],
+            payload_length_be[1],
+        ]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_gens::*;
+    use alloc::{format, vec::Vec};
+    use proptest::prelude::*;
+    use std::io::{Cursor, ErrorKind};
+
+    #[test]
+    fn constants() {
+        assert_eq!(6, PppoeHeader::LEN);
+        assert_eq!(1, PppoeHeader::VERSION);
+        assert_eq!(1, PppoeHeader::TYPE);
+    }
+
+    proptest! {
+        #[test]
+        fn from_slice(
+            input in pppoe_any(),
+            dummy_data in proptest::collection::vec(any::<u8>(), 0..20),
+            bad_version in any::<u8>().prop_filter("version must not be 1", |v| 1 != v >> 4),
+            bad_type in any::<u8>().prop_filter("type must not be 1", |v| 1 != v & 0xf),
+        ) {
+            use err::pppoe::{HeaderError::*, HeaderSliceError::*};
+
+            // serialize
+            let mut buffer: Vec<u8> = Vec::with_capacity(6 + dummy_data.len());
+            input.write(&mut buffer).unwrap();
+            buffer.extend(&dummy_data[..]);
+
+            // calls with a valid result
+            {
+                let (result, rest) = PppoeHeader::from_slice(&buffer[..]).unwrap();
+                assert_eq!(input, result);
+                assert_eq!(&buffer[6..], rest);
+            }
+
+            // call with not enough data in the slice
+            for len in 0..6 {
+                assert_eq!(
+                    PppoeHeader::from_slice(&buffer[..len]),
+                    Err(Len(err::LenError{
+                        required_len: PppoeHeader::LEN,
+                        len,
+                        len_source: err::LenSource::Slice,
+                        layer: err::Layer::PppoeHeader,
+                        layer_start_offset: 0,
+                    }))
+                );
+            }
+
+            // bad version
+            {
+                let mut buffer = buffer.clone();
+                buffer[0] = (bad_version & 0xf0) | PppoeHeader::TYPE;
+                assert_eq!(
+                    PppoeHeader::from_slice(&buffer[..]),
+                    Err(Content(UnexpectedVersion{ version_number: bad_version >> 4 }))
+                );
+            }
+
+            // bad type
+            {
+                let mut buffer = buffer.clone();
+                buffer[0] = (PppoeHeader::VERSION << 4) | (bad_type & 0xf);
+                assert_eq!(
+                    PppoeHeader::from_slice(&buffer[..]),
+                    Err(Content(UnexpectedType{ type_number: bad_type & 0xf }))
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn read(
+            input in pppoe_any(),
+            dummy_data in proptest::collection::vec(any::<u8>(), 0..20),
+            bad_version in any::<u8>().prop_filter("version must not be 1", |v| 1 != v >> 4),
+            bad_type in any::<u8>().prop_filter("type must not be 1", |v| 1 != v & 0xf),
+        ) {
+            use err::pppoe::HeaderError::*;
+
+            // serialize
+            let mut buffer: Vec<u8> = Vec::with_capacity(6 + dummy_data.len());
+            input.write(&mut buffer).unwrap();
+            buffer.extend(&dummy_data[..]);
+
+            // calls with a valid result
+            {
+                let mut cursor = Cursor::new(&buffer);
+                let result = PppoeHeader::read(&mut cursor).unwrap();
+                assert_eq!(input, result);
+                assert_eq!(cursor.position(), 6);
+            }
+
+            // unexpected eof
+            for len in 0..6 {
+                let mut cursor = Cursor::new(&buffer[0..len]);
+                assert_eq!(
+                    PppoeHeader::read(&mut cursor)
+                    .unwrap_err()
+                    .io_error()
+                    .unwrap()
+                    .kind(),
+                    ErrorKind::UnexpectedEof
+                );
+            }
+
+            // bad version
+            {
+                let mut buffer = buffer.clone();
+                buffer[0] = (bad_version & 0xf0) | PppoeHeader::TYPE;
+                let mut cursor = Cursor::new(&buffer);
+                assert_eq!(
+                    PppoeHeader::read(&mut cursor).unwrap_err().content_error(),
+                    Some(UnexpectedVersion{ version_number: bad_version >> 4 })
+                );
+            }
+
+            // bad type
+            {
+                let mut buffer = buffer.clone();
+                buffer[0] = (PppoeHeader::VERSION << 4) | (bad_type & 0xf);
+                let mut cursor = Cursor::new(&buffer);
+                assert_eq!(
+                    PppoeHeader::read(&mut cursor).unwrap_err().content_error(),
+                    Some(UnexpectedType{ type_number: bad_type & 0xf })
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn write_to_slice(input in pppoe_any()) {
+            // normal write
+            {
+                let mut buffer: [u8;6] = [0;6];
+                input.write_to_slice(&mut buffer).unwrap();
+                assert_eq!(buffer, input.to_bytes());
+            }
+            // len to small
+            for len in 0..6 {
+                let mut buffer: [u8;6] = [0;6];
+                assert_eq!(
+                    SliceWriteSpaceError {
+                        required_len: PppoeHeader::LEN,
+                        len,
+                        layer: Layer::PppoeHeader,
+                        layer_start_offset: 0,
+                    },
+                    input.write_to_slice(&mut buffer[..len]).unwrap_err()
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn write(input in pppoe_any()) {
+            // successfull write
+            {
+                let mut buffer: Vec<u8> = Vec::with_capacity(6);
+                input.write(&mut buffer).unwrap();
+                assert_eq!(&buffer[..], &input.to_bytes());
+            }
+
+            // not enough memory for write (unexpected eof)
+            for len in 0..6 {
+                let mut buffer = [0u8;6];
+                let mut writer = Cursor::new(&mut buffer[..len]);
+                assert!(input.write(&mut writer).is_err());
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn header_len(input in pppoe_any()) {
+            assert_eq!(input.header_len(), 6);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn is_session_data_ether_type(input in pppoe_any()) {
+            let session = PppoeHeader {
+                code: PppoeCode::SESSION_DATA,
+                ..input.clone()
+            };
+            assert!(session.is_session_data());
+            assert_eq!(ether_type::PPPOE_SESSION, session.ether_type());
+
+            let discovery = PppoeHeader {
+                code: PppoeCode::PADI,
+                ..input.clone()
+            };
+            assert!(false == discovery.is_session_data());
+            assert_eq!(ether_type::PPPOE_DISCOVERY, discovery.ether_type());
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn to_bytes(input in pppoe_any()) {
+            let session_id_be = input.session_id.to_be_bytes();
+            let payload_length_be = input.payload_length.to_be_bytes();
+            assert_eq!(
+                input.to_bytes(),
+                [
+                    0x11,
+                    input.code.0,
+                    session_id_be[0],
+                    session_id_be[1],
+                    payload_length_be[0],
+                    payload_length_be[1],
+                ]
+            );
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn clone_eq_default(input in pppoe_any()) {
+            assert_eq!(input, input.clone());
+            assert_eq!(
+                PppoeHeader {
+                    code: PppoeCode::SESSION_DATA,
+                    session_id: 0,
+                    payload_length: 0,
+                },
+                PppoeHeader::default()
+            );
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn dbg(input in pppoe_any()) {
+            assert_eq!(
+                &format!(
+                    "PppoeHeader {{ code: {:?}, session_id: {}, payload_length: {} }}",
+                    input.code,
+                    input.session_id,
+                    input.payload_length,
+                ),
+                &format!("{:?}", input)
+            );
+        }
+    }
+}
@@ -0,0 +1,223 @@
+use crate::*;
+use core::slice::from_raw_parts;
+
+/// A slice containing a PPPoE header of a network package.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PppoeHeaderSlice<'a> {
+    slice: &'a [u8],
+}
+
+impl<'a> PppoeHeaderSlice<'a> {
+    /// Creates a PPPoE header slice from an other slice (verifies that
+    /// the version & type fields are both set to `1`).
+    pub fn from_slice(
+        slice: &'a [u8],
+    ) -> Result<PppoeHeaderSlice<'a>, err::pppoe::HeaderSliceError> {
+        use err::pppoe::{HeaderError::*, HeaderSliceError::*};
+
+        //check length
+        if slice.len() < PppoeHeader::LEN {
+            return Err(Len(err::LenError {
+                required_len: PppoeHeader::LEN,
+                len: slice.len(),
+                len_source: err::LenSource::Slice,
+                layer: err::Layer::PppoeHeader,
+                layer_start_offset: 0,
+            }));
+        }
+
+        // SAFETY:
+        // Safe as the slice length is checked to be at least
+        // PppoeHeader::LEN (6) before this.
+        let version_type = unsafe { *slice.get_unchecked(0) };
+
+        //check version & type
+        let version_number = version_type >> 4;
+        if PppoeHeader::VERSION != version_number {
+            return Err(Content(UnexpectedVersion { version_number }));
+        }
+        let type_number = version_type & 0xf;
+        if PppoeHeader::TYPE != type_number {
+            return Err(Content(UnexpectedType { type_number }));
+        }
+
+        //all done
+        Ok(PppoeHeaderSlice {
+            // SAFETY:
+            // Safe as slice length is checked to be at least
+            // PppoeHeader::LEN (6) before this.
+            slice: unsafe { from_raw_parts(slice.as_ptr(), PppoeHeader::LEN) },
+        })
+    }
+
+    /// Returns the slice containing the PPPoE header
+    #[inline]
+    pub fn slice(&self) -> &'a [u8] {
+        self.slice
+    }
+
+    /// Read the "version" field (always `1`, as verified by the constructor).
+    #[inline]
+    pub fn version(&self) -> u8 {
+        // SAFETY:
+        // Safe as the contructor checks that the slice has
+        // at least the length of PppoeHeader::LEN (6).
+        unsafe { *self.slice.get_unchecked(0) >> 4 }
+    }
+
+    /// Read the "type" field (always `1`, as verified by the constructor).
+    #[inline]
+    pub fn pppoe_type(&self) -> u8 {
+        // SAFETY:
+        // Safe as the contructor checks that the slice has
+        // at least the length of PppoeHeader::LEN (6).
+        unsafe { *self.slice.get_unchecked(0) & 0xf }
+    }
+
+    /// Read the "code" field.
+    #[inline]
+    pub fn code(&self) -> PppoeCode {
+        // SAFETY:
+        // Safe as the contructor checks that the slice has
+        // at least the length of PppoeHeader::LEN (6).
+        PppoeCode(unsafe { *self.slice.get_unchecked(1) })
+    }
+
+    /// Read the "session id" field.
+    #[inline]
+    pub fn session_id(&self) -> u16 {
+        // SAFETY:
+        // Safe as the contructor checks that the slice has
+        // at least the length of PppoeHeader::LEN (6).
+        unsafe { get_unchecked_be_u16(self.slice.as_ptr().add(2)) }
+    }
+
+    /// Read the "length" field (length of the PPPoE payload).
+    #[inline]
+    pub fn payload_length(&self) -> u16 {
+        // SAFETY:
+        // Safe as the contructor checks that the slice has
+        // at least the length of PppoeHeader::LEN (6).
+        unsafe { get_unchecked_be_u16(self.slice.as_ptr().add(4)) }
+    }
+
+    /// Returns true if the header belongs to the session stage (code
+    /// [`PppoeCode::SESSION_DATA`]) and the payload contains a PPP frame.
+    #[inline]
+    pub fn is_session_data(&self) -> bool {
+        PppoeCode::SESSION_DATA == self.code()
+    }
+
+    /// Decode all the fields and copy the results to a [`PppoeHeader`] struct
+    pub fn to_header(&self) -> PppoeHeader {
+        PppoeHeader {
+            code: self.code(),
+            session_id: self.session_id(),
+            payload_length: self.payload_length(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_gens::*;
+    use alloc::{format, vec::Vec};
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn from_slice(
+            input in pppoe_any(),
+            dummy_data in proptest::collection::vec(any::<u8>(), 0..20),
+            bad_version in any::<u8>().prop_filter("version must not be 1", |v| 1 != v >> 4),
+            bad_type in any::<u8>().prop_filter("type must not be 1", |v| 1 != v & 0xf),
+        ) {
+            use err::pppoe::{HeaderError::*, HeaderSliceError::*};
+
+            // serialize
+            let mut buffer: Vec<u8> = Vec::with_capacity(6 + dummy_data.len());
+            input.write(&mut buffer).unwrap();
+            buffer.extend(&dummy_data[..]);
+
+            // calls with a valid result
+            {
+                let result = PppoeHeaderSlice::from_slice(&buffer[..]).unwrap();
+                assert_eq!(&buffer[..6], result.slice());
+            }
+
+            // call with not enough data in the slice
+            for len in 0..6 {
+                assert_eq!(
+                    PppoeHeaderSlice::from_slice(&buffer[..len]),
+                    Err(Len(err::LenError{
+                        required_len: PppoeHeader::LEN,
+                        len,
+                        len_source: err::LenSource::Slice,
+                        layer: err::Layer::PppoeHeader,
+                        layer_start_offset: 0,
+                    }))
+                );
+            }
+
+            // bad version
+            {
+                let mut buffer = buffer.clone();
+                buffer[0] = (bad_version & 0xf0) | PppoeHeader::TYPE;
+                assert_eq!(
+                    PppoeHeaderSlice::from_slice(&buffer[..]),
+                    Err(Content(UnexpectedVersion{ version_number: bad_version >> 4 }))
+                );
+            }
+
+            // bad type
+            {
+                let mut buffer = buffer.clone();
+                buffer[0] = (PppoeHeader::VERSION << 4) | (bad_type & 0xf);
+                assert_eq!(
+                    PppoeHeaderSlice::from_slice(&buffer[..]),
+                    Err(Content(UnexpectedType{ type_number: bad_type & 0xf }))
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn getters(input in pppoe_any()) {
+            let bytes = input.to_bytes();
+            let slice = PppoeHeaderSlice::from_slice(&bytes).unwrap();
+            assert_eq!(1, slice.version());
+            assert_eq!(1, slice.pppoe_type());
+            assert_eq!(input.code, slice.code());
+            assert_eq!(input.session_id, slice.session_id());
+            assert_eq!(input.payload_length, slice.payload_length());
+            assert_eq!(input.is_session_data(), slice.is_session_data());
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn to_header(input in pppoe_any()) {
+            let bytes = input.to_bytes();
+            let slice = PppoeHeaderSlice::from_slice(&bytes).unwrap();
+            assert_eq!(input, slice.to_header());
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn clone_eq_dbg(input in pppoe_any()) {
+            let bytes = input.to_bytes();
+            let slice = PppoeHeaderSlice::from_slice(&bytes).unwrap();
+            assert_eq!(slice, slice.clone());
+            assert_eq!(
+                &format!(
+                    "PppoeHeaderSlice {{ slice: {:?} }}",
+                    slice.slice(),
+                ),
+                &format!("{:?}", slice)
+            );
+        }
+    }
+}
@@ -0,0 +1,64 @@
+use crate::*;
+
+/// Tag contained in the payload of a PPPoE discovery stage packet
+/// (see [`PppoeTagsIterator`] to decode the tags).
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct PppoeTag<'a> {
+    /// Type of the tag.
+    pub tag_type: PppoeTagType,
+    /// Value of the tag (length given by the tag length field).
+    pub value: &'a [u8],
+}
+
+impl<'a> PppoeTag<'a> {
+    /// Length of the tag type & tag length fields in bytes/octets.
+    pub const HEADER_LEN: usize = 4;
+
+    /// Length of the serialized tag in bytes (including the type & length fields).
+    #[inline]
+    pub fn tag_len(&self) -> usize {
+        PppoeTag::HEADER_LEN + self.value.len()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::format;
+
+    #[test]
+    fn tag_len() {
+        assert_eq!(
+            4,
+            PppoeTag {
+                tag_type: PppoeTagType::SERVICE_NAME,
+                value: &[]
+            }
+            .tag_len()
+        );
+        assert_eq!(
+            7,
+            PppoeTag {
+                tag_type: PppoeTagType::AC_NAME,
+                value: &[1, 2, 3]
+            }
+            .tag_len()
+        );
+    }
+
+    #[test]
+    fn clone_eq_dbg() {
+        let tag = PppoeTag {
+            tag_type: PppoeTagType::HOST_UNIQ,
+            value: &[1, 2],
+        };
+        assert_eq!(tag, tag.clone());
+        assert_eq!(
+            format!(
+                "PppoeTag {{ tag_type: {:?}, value: [1, 2] }}",
+                PppoeTagType::HOST_UNIQ
+            ),
+            format!("{:?}", tag)
+        );
+    }
+}
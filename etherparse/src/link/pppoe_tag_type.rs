@@ -0,0 +1,128 @@
+/// Type of a PPPoE discovery tag (RFC 2516).
+///
+/// You can access the underlying `u16` value by using `.0` and any `u16`
+/// can be converted to a `PppoeTagType`:
+///
+/// ```
+/// use etherparse::PppoeTagType;
+///
+/// assert_eq!(PppoeTagType::SERVICE_NAME.0, 0x0101);
+/// assert_eq!(PppoeTagType::SERVICE_NAME, PppoeTagType(0x0101));
+///
+/// // convert to PppoeTagType using the from & into trait
+/// let tag_type: PppoeTagType = 0x0103u16.into();
+/// assert_eq!(PppoeTagType::HOST_UNIQ, tag_type);
+///
+/// // convert to u16 using the from & into trait
+/// let num: u16 = PppoeTagType::AC_COOKIE.into();
+/// assert_eq!(0x0104, num);
+/// ```
+#[derive(Default, PartialEq, Eq, Clone, Copy, Hash, Ord, PartialOrd)]
+pub struct PppoeTagType(pub u16);
+
+impl PppoeTagType {
+    /// Indicates that there are no further tags in the list.
+    pub const END_OF_LIST: PppoeTagType = Self(0x0000);
+    /// Service name (UTF-8 string, empty if any service is acceptable).
+    pub const SERVICE_NAME: PppoeTagType = Self(0x0101);
+    /// Name of the access concentrator (UTF-8 string).
+    pub const AC_NAME: PppoeTagType = Self(0x0102);
+    /// Binary data used by the host to match responses to requests.
+    pub const HOST_UNIQ: PppoeTagType = Self(0x0103);
+    /// Binary data used by the access concentrator to protect against
+    /// denial of service attacks.
+    pub const AC_COOKIE: PppoeTagType = Self(0x0104);
+    /// Vendor specific data (first four octets contain the vendor id).
+    pub const VENDOR_SPECIFIC: PppoeTagType = Self(0x0105);
+    /// Binary data added by relays forwarding discovery packets.
+    pub const RELAY_SESSION_ID: PppoeTagType = Self(0x0110);
+    /// Requested service name could not be honored.
+    pub const SERVICE_NAME_ERROR: PppoeTagType = Self(0x0201);
+    /// Access concentrator experienced an error while processing the request.
+    pub const AC_SYSTEM_ERROR: PppoeTagType = Self(0x0202);
+    /// Generic unrecoverable error.
+    pub const GENERIC_ERROR: PppoeTagType = Self(0x0203);
+}
+
+impl From<u16> for PppoeTagType {
+    #[inline]
+    fn from(val: u16) -> Self {
+        PppoeTagType(val)
+    }
+}
+
+impl From<PppoeTagType> for u16 {
+    #[inline]
+    fn from(val: PppoeTagType) -> Self {
+        val.0
+    }
+}
+
+impl core::fmt::Debug for PppoeTagType {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match *self {
+            Self::END_OF_LIST => write!(f, "{:#06X} (End-Of-List)", self.0),
+            Self::SERVICE_NAME => write!(f, "{:#06X} (Service-Name)", self.0),
+            Self::AC_NAME => write!(f, "{:#06X} (AC-Name)", self.0),
+            Self::HOST_UNIQ => write!(f, "{:#06X} (Host-Uniq)", self.0),
+            Self::AC_COOKIE => write!(f, "{:#06X} (AC-Cookie)", self.0),
+            Self::VENDOR_SPECIFIC => write!(f, "{:#06X} (Vendor-Specific)", self.0),
+            Self::RELAY_SESSION_ID => write!(f, "{:#06X} (Relay-Session-Id)", self.0),
+            Self::SERVICE_NAME_ERROR => write!(f, "{:#06X} (Service-Name-Error)", self.0),
+            Self::AC_SYSTEM_ERROR => write!(f, "{:#06X} (AC-System-Error)", self.0),
+            Self::GENERIC_ERROR => write!(f, "{:#06X} (Generic-Error)", self.0),
+            _ => write!(f, "{:#06X}", self.0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::format;
+
+    #[test]
+    fn to_u16() {
+        assert_eq!(0x0000, u16::from(PppoeTagType::END_OF_LIST));
+        assert_eq!(0x0101, u16::from(PppoeTagType::SERVICE_NAME));
+        assert_eq!(0x0203, u16::from(PppoeTagType::GENERIC_ERROR));
+    }
+
+    #[test]
+    fn from_u16() {
+        assert_eq!(PppoeTagType::from(0x0000), PppoeTagType::END_OF_LIST);
+        assert_eq!(PppoeTagType::from(0x0110), PppoeTagType::RELAY_SESSION_ID);
+        assert_eq!(PppoeTagType::from(0x1234), PppoeTagType(0x1234));
+    }
+
+    #[test]
+    fn dbg() {
+        let pairs = &[
+            (PppoeTagType::END_OF_LIST, "0x0000 (End-Of-List)"),
+            (PppoeTagType::SERVICE_NAME, "0x0101 (Service-Name)"),
+            (PppoeTagType::AC_NAME, "0x0102 (AC-Name)"),
+            (PppoeTagType::HOST_UNIQ, "0x0103 (Host-Uniq)"),
+            (PppoeTagType::AC_COOKIE, "0x0104 (AC-Cookie)"),
+            (PppoeTagType::VENDOR_SPECIFIC, "0x0105 (Vendor-Specific)"),
+            (PppoeTagType::RELAY_SESSION_ID, "0x0110 (Relay-Session-Id)"),
+            (
+                PppoeTagType::SERVICE_NAME_ERROR,
+                "0x0201 (Service-Name-Error)",
+            ),
+            (PppoeTagType::AC_SYSTEM_ERROR, "0x0202 (AC-System-Error)"),
+            (PppoeTagType::GENERIC_ERROR, "0x0203 (Generic-Error)"),
+            (PppoeTagType(0x1234), "0x1234"),
+        ];
+
+        for (input, expected) in pairs {
+            assert_eq!(expected, &format!("{:?}", input));
+        }
+    }
+
+    #[test]
+    fn clone_eq_default() {
+        let value = PppoeTagType::AC_NAME;
+        assert_eq!(value, value.clone());
+        assert_eq!(PppoeTagType(0), PppoeTagType::default());
+    }
+}
@@ -0,0 +1,214 @@
+use crate::*;
+
+/// Allows iterating over the tags in the payload of a PPPoE discovery
+/// stage packet.
+///
+/// The iteration stops when an [`PppoeTagType::END_OF_LIST`] tag or the
+/// end of the slice is reached. In case a tag is truncated an error is
+/// returned and the iteration stops afterwards.
+///
+/// # Example
+///
+/// ```
+/// use etherparse::{PppoeTag, PppoeTagType, PppoeTagsIterator};
+///
+/// let tags = [
+///     0x01, 0x01, 0x00, 0x00, // Service-Name (empty)
+///     0x01, 0x03, 0x00, 0x02, 0xab, 0xcd, // Host-Uniq
+/// ];
+/// let mut iter = PppoeTagsIterator::from_slice(&tags);
+/// assert_eq!(
+///     Some(Ok(PppoeTag{ tag_type: PppoeTagType::SERVICE_NAME, value: &[] })),
+///     iter.next()
+/// );
+/// assert_eq!(
+///     Some(Ok(PppoeTag{ tag_type: PppoeTagType::HOST_UNIQ, value: &[0xab, 0xcd] })),
+///     iter.next()
+/// );
+/// assert_eq!(None, iter.next());
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Default)]
+pub struct PppoeTagsIterator<'a> {
+    rest: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> PppoeTagsIterator<'a> {
+    /// Creates a tags iterator from a slice containing encoded PPPoE tags
+    /// (payload of a PPPoE discovery stage packet).
+    #[inline]
+    pub fn from_slice(tags: &'a [u8]) -> PppoeTagsIterator<'a> {
+        PppoeTagsIterator {
+            rest: tags,
+            offset: 0,
+        }
+    }
+
+    /// Returns the non processed part of the tags slice.
+    #[inline]
+    pub fn rest(&self) -> &'a [u8] {
+        self.rest
+    }
+}
+
+impl<'a> Iterator for PppoeTagsIterator<'a> {
+    type Item = Result<PppoeTag<'a>, err::LenError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.rest.is_empty() {
+            return None;
+        }
+
+        // check that the tag type & length fields are present
+        if self.rest.len() < PppoeTag::HEADER_LEN {
+            let err = err::LenError {
+                required_len: PppoeTag::HEADER_LEN,
+                len: self.rest.len(),
+                len_source: err::LenSource::Slice,
+                layer: err::Layer::PppoeTag,
+                layer_start_offset: self.offset,
+            };
+            self.rest = &[];
+            return Some(Err(err));
+        }
+
+        let tag_type = PppoeTagType(u16::from_be_bytes([self.rest[0], self.rest[1]]));
+        if PppoeTagType::END_OF_LIST == tag_type {
+            return None;
+        }
+
+        // check that the value is present
+        let tag_len =
+            PppoeTag::HEADER_LEN + usize::from(u16::from_be_bytes([self.rest[2], self.rest[3]]));
+        if self.rest.len() < tag_len {
+            let err = err::LenError {
+                required_len: tag_len,
+                len: self.rest.len(),
+                len_source: err::LenSource::Slice,
+                layer: err::Layer::PppoeTag,
+                layer_start_offset: self.offset,
+            };
+            self.rest = &[];
+            return Some(Err(err));
+        }
+
+        let result = PppoeTag {
+            tag_type,
+            value: &self.rest[PppoeTag::HEADER_LEN..tag_len],
+        };
+        self.rest = &self.rest[tag_len..];
+        self.offset += tag_len;
+        Some(Ok(result))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::{format, vec::Vec};
+
+    #[test]
+    fn from_slice_rest() {
+        let data = [1, 2, 3];
+        let iter = PppoeTagsIterator::from_slice(&data);
+        assert_eq!(&data, iter.rest());
+    }
+
+    #[test]
+    fn next() {
+        // empty
+        assert_eq!(None, PppoeTagsIterator::from_slice(&[]).next());
+
+        // tags
+        {
+            let data = [
+                0x01, 0x02, 0x00, 0x01, b'a', // AC-Name
+                0x01, 0x04, 0x00, 0x00, // AC-Cookie (empty)
+                0x12, 0x34, 0x00, 0x02, 1, 2, // unknown
+            ];
+            let actual: Vec<_> = PppoeTagsIterator::from_slice(&data).collect();
+            assert_eq!(
+                &actual[..],
+                &[
+                    Ok(PppoeTag {
+                        tag_type: PppoeTagType::AC_NAME,
+                        value: b"a"
+                    }),
+                    Ok(PppoeTag {
+                        tag_type: PppoeTagType::AC_COOKIE,
+                        value: &[]
+                    }),
+                    Ok(PppoeTag {
+                        tag_type: PppoeTagType(0x1234),
+                        value: &[1, 2]
+                    }),
+                ]
+            );
+        }
+
+        // end of list
+        {
+            let data = [
+                0x01, 0x01, 0x00, 0x00, // Service-Name (empty)
+                0x00, 0x00, 0x00, 0x00, // End-Of-List
+                0x01, 0x01, 0x00, 0x00, // Service-Name (empty)
+            ];
+            let mut iter = PppoeTagsIterator::from_slice(&data);
+            assert_eq!(
+                Some(Ok(PppoeTag {
+                    tag_type: PppoeTagType::SERVICE_NAME,
+                    value: &[]
+                })),
+                iter.next()
+            );
+            assert_eq!(None, iter.next());
+            assert_eq!(&data[4..], iter.rest());
+        }
+
+        // tag header too short
+        for len in 1..PppoeTag::HEADER_LEN {
+            let data = [0x01, 0x01, 0x00, 0x00, 0x01, 0x01, 0x00, 0x00];
+            let mut iter = PppoeTagsIterator::from_slice(&data[..4 + len]);
+            assert!(iter.next().unwrap().is_ok());
+            assert_eq!(
+                Some(Err(err::LenError {
+                    required_len: PppoeTag::HEADER_LEN,
+                    len,
+                    len_source: err::LenSource::Slice,
+                    layer: err::Layer::PppoeTag,
+                    layer_start_offset: 4,
+                })),
+                iter.next()
+            );
+            assert_eq!(None, iter.next());
+        }
+
+        // tag value too short
+        {
+            let data = [0x01, 0x03, 0x00, 0x03, 1, 2];
+            let mut iter = PppoeTagsIterator::from_slice(&data);
+            assert_eq!(
+                Some(Err(err::LenError {
+                    required_len: 7,
+                    len: 6,
+                    len_source: err::LenSource::Slice,
+                    layer: err::Layer::PppoeTag,
+                    layer_start_offset: 0,
+                })),
+                iter.next()
+            );
+            assert_eq!(None, iter.next());
+        }
+    }
+
+    #[test]
+    fn default_clone_eq_dbg() {
+        let iter = PppoeTagsIterator::default();
+        assert_eq!(iter.rest(), &[]);
+        assert_eq!(iter, iter.clone());
+        assert_eq!(
+            "PppoeTagsIterator { rest: [], offset: 0 }",
+            format!("{:?}", iter)
+        );
+    }
+}
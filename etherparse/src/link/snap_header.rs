@@ -0,0 +1,306 @@
+use crate::{err::Layer, err::SliceWriteSpaceError, *};
+
+/// SNAP (Subnetwork Access Protocol) header.
+///
+/// A SNAP header follows an [`LlcHeader`] if DSAP & SSAP are set to
+/// [`LlcHeader::SNAP_SAP`] (see [`LlcHeader::is_snap`]). It contains an
+/// organizationally unique identifier (OUI) and a protocol identifier.
+/// If the OUI is zero (RFC 1042) or [`SnapHeader::OUI_BRIDGE_TUNNEL`]
+/// (IEEE 802.1H) the protocol identifier is an ether type.
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Default)]
+pub struct SnapHeader {
+    /// Organizationally unique identifier.
+    pub oui: [u8; 3],
+    /// Protocol identifier (ether type in case of the OUIs
+    /// [`SnapHeader::OUI_RFC1042`] & [`SnapHeader::OUI_BRIDGE_TUNNEL`]).
+    pub ether_type: EtherType,
+}
+
+impl SnapHeader {
+    /// Serialized size of a SNAP header in bytes/octets.
+    pub const LEN: usize = 5;
+
+    /// OUI used for encapsulating ether type based protocols (RFC 1042).
+    pub const OUI_RFC1042: [u8; 3] = [0, 0, 0];
+
+    /// OUI used for encapsulating ether type based protocols via
+    /// bridge tunneling (IEEE 802.1H).
+    pub const OUI_BRIDGE_TUNNEL: [u8; 3] = [0, 0, 0xf8];
+
+    /// Read a [`SnapHeader`] from a slice and return the header & unused parts of the slice.
+    #[inline]
+    pub fn from_slice(slice: &[u8]) -> Result<(SnapHeader, &[u8]), err::LenError> {
+        Ok((
+            SnapHeaderSlice::from_slice(slice)?.to_header(),
+            &slice[SnapHeader::LEN..],
+        ))
+    }
+
+    /// Read a [`SnapHeader`] from a static sized byte array.
+    #[inline]
+    pub fn from_bytes(bytes: [u8; 5]) -> SnapHeader {
+        SnapHeader {
+            oui: [bytes[0], bytes[1], bytes[2]],
+            ether_type: EtherType(u16::from_be_bytes([bytes[3], bytes[4]])),
+        }
+    }
+
+    /// Reads a SNAP header from the current position of the read argument.
+    #[cfg(feature = "std")]
+    pub fn read<T: std::io::Read + std::io::Seek + Sized>(
+        reader: &mut T,
+    ) -> Result<SnapHeader, std::io::Error> {
+        let mut buffer = [0; SnapHeader::LEN];
+        reader.read_exact(&mut buffer)?;
+        Ok(SnapHeader::from_bytes(buffer))
+    }
+
+    /// Returns the ether type of the payload if the OUI indicates that
+    /// the protocol identifier is an ether type.
+    #[inline]
+    pub fn payload_ether_type(&self) -> Option<EtherType> {
+        snap_payload_ether_type(self.oui, self.ether_type)
+    }
+
+    /// Serialize the header to a given slice. Returns the unused part of the slice.
+    pub fn write_to_slice<'a>(
+        &self,
+        slice: &'a mut [u8],
+    ) -> Result<&'a mut [u8], SliceWriteSpaceError> {
+        // length check
+        if slice.len() < SnapHeader::LEN {
+            Err(SliceWriteSpaceError {
+                required_len: SnapHeader::LEN,
+                len: slice.len(),
+                layer: Layer::SnapHeader,
+                layer_start_offset: 0,
+            })
+        } else {
+            slice[..SnapHeader::LEN].copy_from_slice(&self.to_bytes());
+            Ok(&mut slice[SnapHeader::LEN..])
+        }
+    }
+
+    /// Writes a given SNAP header to the current position of the write argument.
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn write<T: std::io::Write + Sized>(&self, writer: &mut T) -> Result<(), std::io::Error> {
+        writer.write_all(&self.to_bytes())
+    }
+
+    /// Length of the serialized header in bytes.
+    #[inline]
+    pub fn header_len(&self) -> usize {
+        SnapHeader::LEN
+    }
+
+    /// Returns the serialized form of the header as a statically
+    /// sized byte array.
+    #[inline]
+    pub fn to_bytes(&self) -> [u8; 5] {
+        let ether_type_be = self.ether_type.0.to_be_bytes();
+        [
+            self.oui[0],
+            self.oui[1],
+            self.oui[2],
+            ether_type_be[0],
+            ether_type_be[1],
+        ]
+    }
+}
+
+/// Returns the ether type if the given OUI indicates that the protocol
+/// identifier contains an ether type.
+#[inline]
+pub(crate) fn snap_payload_ether_type(oui: [u8; 3], ether_type: EtherType) -> Option<EtherType> {
+    if SnapHeader::OUI_RFC1042 == oui || SnapHeader::OUI_BRIDGE_TUNNEL == oui {
+        Some(ether_type)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_gens::*;
+    use alloc::{format, vec::Vec};
+    use proptest::prelude::*;
+    use std::io::{Cursor, ErrorKind};
+
+    proptest! {
+        #[test]
+        fn from_slice(
+            input in snap_any(),
+            dummy_data in proptest::collection::vec(any::<u8>(), 0..20)
+        ) {
+            // serialize
+            let mut buffer: Vec<u8> = Vec::with_capacity(5 + dummy_data.len());
+            input.write(&mut buffer).unwrap();
+            buffer.extend(&dummy_data[..]);
+
+            // calls with a valid result
+            {
+                let (result, rest) = SnapHeader::from_slice(&buffer[..]).unwrap();
+                assert_eq!(input, result);
+                assert_eq!(&buffer[5..], rest);
+            }
+
+            // call with not enough data in the slice
+            for len in 0..5 {
+                assert_eq!(
+                    SnapHeader::from_slice(&buffer[..len]),
+                    Err(err::LenError{
+                        required_len: SnapHeader::LEN,
+                        len,
+                        len_source: err::LenSource::Slice,
+                        layer: err::Layer::SnapHeader,
+                        layer_start_offset: 0,
+                    })
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn from_bytes(input in snap_any()) {
+            assert_eq!(
+                input,
+                SnapHeader::from_bytes(input.to_bytes())
+            );
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn read(
+            input in snap_any(),
+            dummy_data in proptest::collection::vec(any::<u8>(), 0..20)
+        ) {
+            let mut buffer = Vec::with_capacity(5 + dummy_data.len());
+            input.write(&mut buffer).unwrap();
+            buffer.extend(&dummy_data[..]);
+
+            // calls with a valid result
+            {
+                let mut cursor = Cursor::new(&buffer);
+                let result = SnapHeader::read(&mut cursor).unwrap();
+                assert_eq!(input, result);
+                assert_eq!(cursor.position(), 5);
+            }
+
+            // unexpected eof
+            for len in 0..5 {
+                let mut cursor = Cursor::new(&buffer[0..len]);
+                assert_eq!(
+                    SnapHeader::read(&mut cursor)
+                    .unwrap_err()
+                    .kind(),
+                    ErrorKind::UnexpectedEof
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn payload_ether_type(
+            oui in prop::array::uniform3(any::<u8>()),
+            ether_type in ether_type_any()
+        ) {
+            // ether type ouis
+            for oui in [SnapHeader::OUI_RFC1042, SnapHeader::OUI_BRIDGE_TUNNEL] {
+                assert_eq!(
+                    Some(ether_type),
+                    SnapHeader{ oui, ether_type }.payload_ether_type()
+                );
+            }
+            // other ouis
+            if SnapHeader::OUI_RFC1042 != oui && SnapHeader::OUI_BRIDGE_TUNNEL != oui {
+                assert_eq!(None, SnapHeader{ oui, ether_type }.payload_ether_type());
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn write_to_slice(input in snap_any()) {
+            // normal write
+            {
+                let mut buffer: [u8;5] = [0;5];
+                input.write_to_slice(&mut buffer).unwrap();
+                assert_eq!(buffer, input.to_bytes());
+            }
+            // len to small
+            for len in 0..5 {
+                let mut buffer: [u8;5] = [0;5];
+                assert_eq!(
+                    SliceWriteSpaceError {
+                        required_len: SnapHeader::LEN,
+                        len,
+                        layer: Layer::SnapHeader,
+                        layer_start_offset: 0,
+                    },
+                    input.write_to_slice(&mut buffer[..len]).unwrap_err()
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn write(input in snap_any()) {
+            // successfull write
+            {
+                let mut buffer: Vec<u8> = Vec::with_capacity(5);
+                input.write(&mut buffer).unwrap();
+                assert_eq!(&buffer[..], &input.to_bytes());
+            }
+
+            // not enough memory for write (unexpected eof)
+            for len in 0..5 {
+                let mut buffer = [0u8;5];
+                let mut writer = Cursor::new(&mut buffer[..len]);
+                assert!(input.write(&mut writer).is_err());
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn header_len(input in snap_any()) {
+            assert_eq!(input.header_len(), 5);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn to_bytes(input in snap_any()) {
+            let bytes = input.to_bytes();
+            assert_eq!(&bytes[0..3], &input.oui);
+            assert_eq!(&bytes[3..5], &input.ether_type.0.to_be_bytes());
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn clone_eq(input in snap_any()) {
+            assert_eq!(input, input.clone());
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn dbg(input in snap_any()) {
+            assert_eq!(
+                &format!(
+                    "SnapHeader {{ oui: {:?}, ether_type: {:?} }}",
+                    input.oui,
+                    input.ether_type,
+                ),
+                &format!("{:?}", input)
+            );
+        }
+    }
+}
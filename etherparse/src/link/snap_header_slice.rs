@@ -0,0 +1,155 @@
+use crate::*;
+use core::slice::from_raw_parts;
+
+/// A slice containing a SNAP header of a network package.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SnapHeaderSlice<'a> {
+    slice: &'a [u8],
+}
+
+impl<'a> SnapHeaderSlice<'a> {
+    /// Creates a SNAP header slice from an other slice.
+    pub fn from_slice(slice: &'a [u8]) -> Result<SnapHeaderSlice<'a>, err::LenError> {
+        //check length
+        if slice.len() < SnapHeader::LEN {
+            return Err(err::LenError {
+                required_len: SnapHeader::LEN,
+                len: slice.len(),
+                len_source: err::LenSource::Slice,
+                layer: err::Layer::SnapHeader,
+                layer_start_offset: 0,
+            });
+        }
+
+        //all done
+        Ok(SnapHeaderSlice {
+            // SAFETY:
+            // Safe as slice length is checked to be at least
+            // SnapHeader::LEN (5) before this.
+            slice: unsafe { from_raw_parts(slice.as_ptr(), SnapHeader::LEN) },
+        })
+    }
+
+    /// Returns the slice containing the SNAP header
+    #[inline]
+    pub fn slice(&self) -> &'a [u8] {
+        self.slice
+    }
+
+    /// Read the organizationally unique identifier.
+    #[inline]
+    pub fn oui(&self) -> [u8; 3] {
+        // SAFETY:
+        // Safe as the contructor checks that the slice has
+        // at least the length of SnapHeader::LEN (5).
+        unsafe {
+            [
+                *self.slice.get_unchecked(0),
+                *self.slice.get_unchecked(1),
+                *self.slice.get_unchecked(2),
+            ]
+        }
+    }
+
+    /// Read the protocol identifier field.
+    #[inline]
+    pub fn ether_type(&self) -> EtherType {
+        // SAFETY:
+        // Safe as the contructor checks that the slice has
+        // at least the length of SnapHeader::LEN (5).
+        EtherType(unsafe { get_unchecked_be_u16(self.slice.as_ptr().add(3)) })
+    }
+
+    /// Returns the ether type of the payload if the OUI indicates that
+    /// the protocol identifier is an ether type (see
+    /// [`SnapHeader::payload_ether_type`]).
+    #[inline]
+    pub fn payload_ether_type(&self) -> Option<EtherType> {
+        crate::link::snap_header::snap_payload_ether_type(self.oui(), self.ether_type())
+    }
+
+    /// Decode all the fields and copy the results to a [`SnapHeader`] struct
+    pub fn to_header(&self) -> SnapHeader {
+        SnapHeader {
+            oui: self.oui(),
+            ether_type: self.ether_type(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_gens::*;
+    use alloc::{format, vec::Vec};
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn from_slice(
+            input in snap_any(),
+            dummy_data in proptest::collection::vec(any::<u8>(), 0..20)
+        ) {
+            // serialize
+            let mut buffer: Vec<u8> = Vec::with_capacity(5 + dummy_data.len());
+            input.write(&mut buffer).unwrap();
+            buffer.extend(&dummy_data[..]);
+
+            // calls with a valid result
+            {
+                let result = SnapHeaderSlice::from_slice(&buffer[..]).unwrap();
+                assert_eq!(&buffer[..5], result.slice());
+            }
+
+            // call with not enough data in the slice
+            for len in 0..5 {
+                assert_eq!(
+                    SnapHeaderSlice::from_slice(&buffer[..len]),
+                    Err(err::LenError{
+                        required_len: SnapHeader::LEN,
+                        len,
+                        len_source: err::LenSource::Slice,
+                        layer: err::Layer::SnapHeader,
+                        layer_start_offset: 0,
+                    })
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn getters(input in snap_any()) {
+            let bytes = input.to_bytes();
+            let slice = SnapHeaderSlice::from_slice(&bytes).unwrap();
+            assert_eq!(input.oui, slice.oui());
+            assert_eq!(input.ether_type, slice.ether_type());
+            assert_eq!(input.payload_ether_type(), slice.payload_ether_type());
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn to_header(input in snap_any()) {
+            let bytes = input.to_bytes();
+            let slice = SnapHeaderSlice::from_slice(&bytes).unwrap();
+            assert_eq!(input, slice.to_header());
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn clone_eq_dbg(input in snap_any()) {
+            let bytes = input.to_bytes();
+            let slice = SnapHeaderSlice::from_slice(&bytes).unwrap();
+            assert_eq!(slice, slice.clone());
+            assert_eq!(
+                &format!(
+                    "SnapHeaderSlice {{ slice: {:?} }}",
+                    slice.slice(),
+                ),
+                &format!("{:?}", slice)
+            );
+        }
+    }
+}
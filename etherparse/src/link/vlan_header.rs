@@ -1,12 +1,15 @@
 use crate::*;
 
-/// IEEE 802.1Q VLAN Tagging Header (can be single or double tagged).
+/// IEEE 802.1Q VLAN Tagging Header (can be single, double or multi tagged).
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum VlanHeader {
     /// IEEE 802.1Q VLAN Tagging Header
     Single(SingleVlanHeader),
     /// IEEE 802.1Q double VLAN Tagging Header
     Double(DoubleVlanHeader),
+    /// Stack of IEEE 802.1Q VLAN Tagging Headers (used when decoding
+    /// three or more tags)
+    Multi(VlanStack),
 }
 
 impl VlanHeader {
@@ -17,7 +20,7 @@ impl VlanHeader {
         ether_type::VLAN_DOUBLE_TAGGED_FRAME,
     ];
 
-    /// Write the IEEE 802.1Q VLAN single or double tagging header
+    /// Write the IEEE 802.1Q VLAN single, double or multi tagging header
     #[cfg(feature = "std")]
     #[inline]
     pub fn write<T: std::io::Write + Sized>(&self, writer: &mut T) -> Result<(), std::io::Error> {
@@ -25,6 +28,7 @@ impl VlanHeader {
         match &self {
             Single(header) => header.write(writer),
             Double(header) => header.write(writer),
+            Multi(stack) => stack.write(writer),
         }
     }
 
@@ -35,6 +39,7 @@ impl VlanHeader {
         match &self {
             Single(_) => SingleVlanHeader::LEN,
             Double(_) => DoubleVlanHeader::LEN,
+            Multi(stack) => stack.header_len(),
         }
     }
 }
@@ -62,6 +67,7 @@ mod test {
         fn clone_eq(
             single in vlan_single_any(),
             double in vlan_double_any(),
+            multi in vlan_stack_any(),
         ) {
             // single eq
             {
@@ -73,6 +79,11 @@ mod test {
                 let value = VlanHeader::Double(double);
                 assert_eq!(value, value.clone());
             }
+            // multi
+            {
+                let value = VlanHeader::Multi(multi);
+                assert_eq!(value, value.clone());
+            }
         }
     }
 
@@ -81,6 +92,7 @@ mod test {
         fn dbg(
             single in vlan_single_any(),
             double in vlan_double_any(),
+            multi in vlan_stack_any(),
         ) {
             // single
             {
@@ -104,6 +116,17 @@ mod test {
                     &format!("{:?}", value)
                 );
             }
+            // multi
+            {
+                let value = VlanHeader::Multi(multi.clone());
+                assert_eq!(
+                    &format!(
+                        "Multi({:?})",
+                        multi
+                    ),
+                    &format!("{:?}", value)
+                );
+            }
         }
     }
 
@@ -112,6 +135,7 @@ mod test {
         fn header_len(
             single in vlan_single_any(),
             double in vlan_double_any(),
+            multi in vlan_stack_any(),
         ) {
             // single
             assert_eq!(
@@ -123,6 +147,11 @@ mod test {
                 DoubleVlanHeader::LEN,
                 VlanHeader::Double(double.clone()).header_len()
             );
+            // multi
+            assert_eq!(
+                multi.header_len(),
+                VlanHeader::Multi(multi.clone()).header_len()
+            );
         }
     }
 
@@ -131,6 +160,7 @@ mod test {
         fn write(
             single in vlan_single_any(),
             double in vlan_double_any(),
+            multi in vlan_stack_any(),
         ) {
             // single
             {
@@ -161,6 +191,21 @@ mod test {
                 };
                 assert_eq!(expected, actual);
             }
+
+            // multi
+            {
+                let expected = {
+                    let mut buffer = Vec::with_capacity(multi.header_len());
+                    multi.write(&mut buffer).unwrap();
+                    buffer
+                };
+                let actual = {
+                    let mut buffer = Vec::with_capacity(multi.header_len());
+                    VlanHeader::Multi(multi.clone()).write(&mut buffer).unwrap();
+                    buffer
+                };
+                assert_eq!(expected, actual);
+            }
         }
     }
 }
@@ -1,21 +1,49 @@
 use crate::*;
 
-/// A slice containing a single or double vlan header.
+/// A slice containing a single, double or multi vlan header.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum VlanSlice<'a> {
     SingleVlan(SingleVlanHeaderSlice<'a>),
     DoubleVlan(DoubleVlanHeaderSlice<'a>),
+    /// Stack of three or more vlan headers.
+    MultiVlan(VlanStackSlice<'a>),
 }
 
 impl<'a> VlanSlice<'a> {
     /// Decode all the fields and copy the results to a VlanHeader struct
+    /// (errors if a multi vlan header contains more tags then
+    /// [`VlanStack::MAX_TAGS`]).
     #[inline]
-    pub fn to_header(&self) -> VlanHeader {
+    pub fn to_header(&self) -> Result<VlanHeader, err::vlan_stack::HeaderError> {
         use crate::VlanHeader::*;
         use crate::VlanSlice::*;
         match self {
-            SingleVlan(value) => Single(value.to_header()),
-            DoubleVlan(value) => Double(value.to_header()),
+            SingleVlan(value) => Ok(Single(value.to_header())),
+            DoubleVlan(value) => Ok(Double(value.to_header())),
+            MultiVlan(value) => Ok(Multi(value.to_header()?)),
+        }
+    }
+
+    /// Number of vlan tags present in the slice.
+    #[inline]
+    pub fn num_tags(&self) -> usize {
+        use crate::VlanSlice::*;
+        match self {
+            SingleVlan(_) => 1,
+            DoubleVlan(_) => 2,
+            MultiVlan(value) => value.num_tags(),
+        }
+    }
+
+    /// Returns the ether type of the payload after the vlan tag(s)
+    /// (ether type of the inner most tag).
+    #[inline]
+    pub fn payload_ether_type(&self) -> EtherType {
+        use crate::VlanSlice::*;
+        match self {
+            SingleVlan(value) => value.ether_type(),
+            DoubleVlan(value) => value.inner().ether_type(),
+            MultiVlan(value) => value.inner().ether_type(),
         }
     }
 }
@@ -23,7 +51,7 @@ impl<'a> VlanSlice<'a> {
 #[cfg(test)]
 mod test {
     use crate::{test_gens::*, *};
-    use alloc::format;
+    use alloc::{format, vec::Vec};
     use proptest::prelude::*;
 
     proptest! {
@@ -31,6 +59,7 @@ mod test {
         fn to_header(
             single in vlan_single_any(),
             double in vlan_double_any(),
+            multi in vlan_stack_any(),
         ) {
             // single
             {
@@ -40,7 +69,7 @@ mod test {
                 );
                 assert_eq!(
                     slice.to_header(),
-                    VlanHeader::Single(single)
+                    Ok(VlanHeader::Single(single))
                 );
             }
 
@@ -52,7 +81,63 @@ mod test {
                 );
                 assert_eq!(
                     slice.to_header(),
-                    VlanHeader::Double(double)
+                    Ok(VlanHeader::Double(double))
+                );
+            }
+
+            // multi
+            {
+                let mut raw = Vec::with_capacity(multi.header_len());
+                multi.write(&mut raw).unwrap();
+                let slice = VlanSlice::MultiVlan(
+                    VlanStackSlice::from_slice(&raw).unwrap()
+                );
+                assert_eq!(
+                    slice.to_header(),
+                    Ok(VlanHeader::Multi(multi))
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn num_tags_payload_ether_type(
+            single in vlan_single_any(),
+            double in vlan_double_any(),
+            multi in vlan_stack_any(),
+        ) {
+            // single
+            {
+                let raw = single.to_bytes();
+                let slice = VlanSlice::SingleVlan(
+                    SingleVlanHeaderSlice::from_slice(&raw).unwrap()
+                );
+                assert_eq!(1, slice.num_tags());
+                assert_eq!(single.ether_type, slice.payload_ether_type());
+            }
+
+            // double
+            {
+                let raw = double.to_bytes();
+                let slice = VlanSlice::DoubleVlan(
+                    DoubleVlanHeaderSlice::from_slice(&raw).unwrap()
+                );
+                assert_eq!(2, slice.num_tags());
+                assert_eq!(double.inner.ether_type, slice.payload_ether_type());
+            }
+
+            // multi
+            {
+                let mut raw = Vec::with_capacity(multi.header_len());
+                multi.write(&mut raw).unwrap();
+                let slice = VlanSlice::MultiVlan(
+                    VlanStackSlice::from_slice(&raw).unwrap()
+                );
+                assert_eq!(multi.tags().len(), slice.num_tags());
+                assert_eq!(
+                    multi.tags().last().unwrap().ether_type,
+                    slice.payload_ether_type()
                 );
             }
         }
@@ -63,6 +148,7 @@ mod test {
         fn debug(
             single in vlan_single_any(),
             double in vlan_double_any(),
+            multi in vlan_stack_any(),
         ) {
             // single
             {
@@ -83,6 +169,17 @@ mod test {
                     format!("DoubleVlan({:?})", d)
                 );
             }
+
+            // multi
+            {
+                let mut raw = Vec::with_capacity(multi.header_len());
+                multi.write(&mut raw).unwrap();
+                let m = VlanStackSlice::from_slice(&raw).unwrap();
+                assert_eq!(
+                    format!("{:?}", VlanSlice::MultiVlan(m.clone())),
+                    format!("MultiVlan({:?})", m)
+                );
+            }
         }
     }
 
@@ -91,6 +188,7 @@ mod test {
         fn clone_eq(
             single in vlan_single_any(),
             double in vlan_double_any(),
+            multi in vlan_stack_any(),
         ) {
             // single
             {
@@ -109,6 +207,16 @@ mod test {
                 );
                 assert_eq!(d.clone(), d);
             }
+
+            // multi
+            {
+                let mut raw = Vec::with_capacity(multi.header_len());
+                multi.write(&mut raw).unwrap();
+                let m = VlanSlice::MultiVlan(
+                    VlanStackSlice::from_slice(&raw).unwrap()
+                );
+                assert_eq!(m.clone(), m);
+            }
         }
     }
 }
@@ -0,0 +1,280 @@
+use crate::{err::Layer, err::SliceWriteSpaceError, *};
+use arrayvec::ArrayVec;
+
+/// Stack of IEEE 802.1Q VLAN tags containing up to
+/// [`VlanStack::MAX_TAGS`] tags (starting with the outer most tag).
+///
+/// If you need to decode stacks with more tags use [`VlanStackSlice`]
+/// instead, which supports stacks of any depth.
+///
+/// Note that the ether types of the tags are written as stored in the
+/// tags. It is up to the caller to ensure that all tags except the last
+/// one contain a VLAN ether type (e.g. [`ether_type::VLAN_TAGGED_FRAME`]).
+///
+/// # Example
+///
+/// ```
+/// use etherparse::{ether_type, SingleVlanHeader, VlanStack};
+///
+/// let stack = VlanStack::try_from(&[
+///     SingleVlanHeader {
+///         vlan_id: 1.try_into().unwrap(),
+///         ether_type: ether_type::VLAN_TAGGED_FRAME,
+///         ..Default::default()
+///     },
+///     SingleVlanHeader {
+///         vlan_id: 2.try_into().unwrap(),
+///         ether_type: ether_type::VLAN_TAGGED_FRAME,
+///         ..Default::default()
+///     },
+///     SingleVlanHeader {
+///         vlan_id: 3.try_into().unwrap(),
+///         ether_type: ether_type::IPV4,
+///         ..Default::default()
+///     },
+/// ][..]).unwrap();
+///
+/// assert_eq!(3, stack.tags().len());
+/// assert_eq!(12, stack.header_len());
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Default)]
+pub struct VlanStack {
+    tags: ArrayVec<SingleVlanHeader, { VlanStack::MAX_TAGS }>,
+}
+
+impl VlanStack {
+    /// Maximum number of tags that can be stored in a [`VlanStack`].
+    pub const MAX_TAGS: usize = 8;
+
+    /// Creates an empty VLAN stack.
+    #[inline]
+    pub fn new() -> VlanStack {
+        VlanStack {
+            tags: ArrayVec::new(),
+        }
+    }
+
+    /// Read a [`VlanStack`] from a slice and return the stack & unused parts of the slice.
+    pub fn from_slice(
+        slice: &[u8],
+    ) -> Result<(VlanStack, &[u8]), err::vlan_stack::HeaderSliceError> {
+        use err::vlan_stack::HeaderSliceError::*;
+        let stack = VlanStackSlice::from_slice(slice).map_err(Len)?;
+        Ok((
+            stack.to_header().map_err(Content)?,
+            &slice[stack.slice().len()..],
+        ))
+    }
+
+    /// Returns the tags of the stack (starting with the outer most tag).
+    #[inline]
+    pub fn tags(&self) -> &[SingleVlanHeader] {
+        &self.tags
+    }
+
+    /// Adds a tag to the inner end of the stack. Returns an error if
+    /// the stack already contains [`VlanStack::MAX_TAGS`] tags.
+    pub fn try_push(&mut self, tag: SingleVlanHeader) -> Result<(), err::vlan_stack::HeaderError> {
+        self.tags
+            .try_push(tag)
+            .map_err(|_| err::vlan_stack::HeaderError::TooManyTags {
+                max_tags: VlanStack::MAX_TAGS,
+            })
+    }
+
+    /// Serialize the stack to a given slice. Returns the unused part of the slice.
+    pub fn write_to_slice<'a>(
+        &self,
+        slice: &'a mut [u8],
+    ) -> Result<&'a mut [u8], SliceWriteSpaceError> {
+        // length check
+        let len = self.header_len();
+        if slice.len() < len {
+            Err(SliceWriteSpaceError {
+                required_len: len,
+                len: slice.len(),
+                layer: Layer::VlanHeader,
+                layer_start_offset: 0,
+            })
+        } else {
+            for (i, tag) in self.tags.iter().enumerate() {
+                let start = i * SingleVlanHeader::LEN;
+                slice[start..start + SingleVlanHeader::LEN].copy_from_slice(&tag.to_bytes());
+            }
+            Ok(&mut slice[len..])
+        }
+    }
+
+    /// Writes the stack to the current position of the write argument.
+    #[cfg(feature = "std")]
+    pub fn write<T: std::io::Write + Sized>(&self, writer: &mut T) -> Result<(), std::io::Error> {
+        for tag in &self.tags {
+            tag.write(writer)?;
+        }
+        Ok(())
+    }
+
+    /// Length of the serialized stack in bytes.
+    #[inline]
+    pub fn header_len(&self) -> usize {
+        self.tags.len() * SingleVlanHeader::LEN
+    }
+}
+
+impl<'a> TryFrom<&'a [SingleVlanHeader]> for VlanStack {
+    type Error = err::vlan_stack::HeaderError;
+
+    fn try_from(value: &'a [SingleVlanHeader]) -> Result<Self, Self::Error> {
+        let mut result = VlanStack::new();
+        for tag in value {
+            result.try_push(tag.clone())?;
+        }
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_gens::*;
+    use alloc::{format, vec::Vec};
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn from_slice(
+            input in vlan_stack_any(),
+            dummy_data in proptest::collection::vec(any::<u8>(), 0..20)
+        ) {
+            // serialize
+            let mut buffer: Vec<u8> = Vec::with_capacity(input.header_len() + dummy_data.len());
+            input.write(&mut buffer).unwrap();
+            buffer.extend(&dummy_data[..]);
+
+            // calls with a valid result
+            {
+                let (result, rest) = VlanStack::from_slice(&buffer[..]).unwrap();
+                assert_eq!(input, result);
+                assert_eq!(&buffer[input.header_len()..], rest);
+            }
+
+            // call with not enough data in the slice
+            for len in 0..input.header_len() {
+                assert_eq!(
+                    VlanStack::from_slice(&buffer[..len]),
+                    Err(err::vlan_stack::HeaderSliceError::Len(err::LenError{
+                        required_len: (len / 4 + 1) * 4,
+                        len,
+                        len_source: err::LenSource::Slice,
+                        layer: err::Layer::VlanHeader,
+                        layer_start_offset: 0,
+                    }))
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn from_slice_too_many_tags() {
+        let mut bytes = Vec::new();
+        for i in 0..=VlanStack::MAX_TAGS {
+            SingleVlanHeader {
+                ether_type: if i == VlanStack::MAX_TAGS {
+                    ether_type::IPV6
+                } else {
+                    ether_type::PROVIDER_BRIDGING
+                },
+                ..Default::default()
+            }
+            .write(&mut bytes)
+            .unwrap();
+        }
+        assert_eq!(
+            Err(err::vlan_stack::HeaderSliceError::Content(
+                err::vlan_stack::HeaderError::TooManyTags {
+                    max_tags: VlanStack::MAX_TAGS
+                }
+            )),
+            VlanStack::from_slice(&bytes)
+        );
+    }
+
+    #[test]
+    fn try_push_try_from() {
+        let tag = SingleVlanHeader::default();
+        let mut stack = VlanStack::new();
+        for i in 0..VlanStack::MAX_TAGS {
+            assert_eq!(i, stack.tags().len());
+            stack.try_push(tag.clone()).unwrap();
+        }
+        assert_eq!(
+            Err(err::vlan_stack::HeaderError::TooManyTags {
+                max_tags: VlanStack::MAX_TAGS
+            }),
+            stack.try_push(tag.clone())
+        );
+
+        // try_from
+        let tags = alloc::vec![tag.clone(); VlanStack::MAX_TAGS + 1];
+        assert_eq!(Ok(stack), VlanStack::try_from(&tags[..VlanStack::MAX_TAGS]));
+        assert_eq!(
+            Err(err::vlan_stack::HeaderError::TooManyTags {
+                max_tags: VlanStack::MAX_TAGS
+            }),
+            VlanStack::try_from(&tags[..])
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn write_to_slice(input in vlan_stack_any()) {
+            // normal write
+            {
+                let mut buffer = Vec::new();
+                input.write(&mut buffer).unwrap();
+                let mut slice_buffer = [0u8;VlanStack::MAX_TAGS*4 + 1];
+                let rest = input.write_to_slice(&mut slice_buffer).unwrap();
+                assert_eq!(rest.len(), VlanStack::MAX_TAGS*4 + 1 - input.header_len());
+                assert_eq!(&buffer[..], &slice_buffer[..input.header_len()]);
+            }
+            // len to small
+            for len in 0..input.header_len() {
+                let mut buffer = [0u8;VlanStack::MAX_TAGS*4];
+                assert_eq!(
+                    SliceWriteSpaceError {
+                        required_len: input.header_len(),
+                        len,
+                        layer: Layer::VlanHeader,
+                        layer_start_offset: 0,
+                    },
+                    input.write_to_slice(&mut buffer[..len]).unwrap_err()
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn write_header_len(input in vlan_stack_any()) {
+            let mut buffer = Vec::new();
+            input.write(&mut buffer).unwrap();
+            assert_eq!(input.header_len(), buffer.len());
+            assert_eq!(input.tags().len()*4, input.header_len());
+            for (i, tag) in input.tags().iter().enumerate() {
+                assert_eq!(&buffer[i*4..i*4 + 4], &tag.to_bytes());
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn clone_eq_default_dbg(input in vlan_stack_any()) {
+            assert_eq!(input, input.clone());
+            assert_eq!(VlanStack::new(), VlanStack::default());
+            assert_eq!(
+                format!("VlanStack {{ tags: {:?} }}", input.tags()),
+                format!("{:?}", input)
+            );
+        }
+    }
+}
@@ -0,0 +1,225 @@
+use crate::*;
+use core::slice::from_raw_parts;
+
+/// A slice containing a stack of IEEE 802.1Q VLAN tags (all consecutive
+/// tags up to & including the first tag that does not announce a further
+/// VLAN tag via its ether type).
+///
+/// The stack can have any depth, use [`VlanStackSlice::tags`] to iterate
+/// over the tags (starting with the outer most tag).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VlanStackSlice<'a> {
+    pub(crate) slice: &'a [u8],
+}
+
+impl<'a> VlanStackSlice<'a> {
+    /// Creates a VLAN stack slice from an other slice.
+    ///
+    /// Tags are read as long as the ether type of the previous tag is
+    /// one of [`VlanHeader::VLAN_ETHER_TYPES`]. If the slice ends before a
+    /// tag with a non VLAN ether type is found an error is returned.
+    pub fn from_slice(slice: &'a [u8]) -> Result<VlanStackSlice<'a>, err::LenError> {
+        use ether_type::*;
+        let mut len = 0;
+        loop {
+            //check length
+            if slice.len() < len + SingleVlanHeader::LEN {
+                return Err(err::LenError {
+                    required_len: len + SingleVlanHeader::LEN,
+                    len: slice.len(),
+                    len_source: err::LenSource::Slice,
+                    layer: err::Layer::VlanHeader,
+                    layer_start_offset: 0,
+                });
+            }
+            // SAFETY:
+            // Safe as the slice length is checked to be at least
+            // len + SingleVlanHeader::LEN (4) above.
+            let ether_type =
+                EtherType(unsafe { get_unchecked_be_u16(slice.as_ptr().add(len + 2)) });
+            len += SingleVlanHeader::LEN;
+            match ether_type {
+                VLAN_TAGGED_FRAME | PROVIDER_BRIDGING | VLAN_DOUBLE_TAGGED_FRAME => {}
+                _ => break,
+            }
+        }
+
+        //all done
+        Ok(VlanStackSlice {
+            // SAFETY:
+            // Safe as slice length is checked to be at least
+            // len before this.
+            slice: unsafe { from_raw_parts(slice.as_ptr(), len) },
+        })
+    }
+
+    /// Returns the slice containing the VLAN stack.
+    #[inline]
+    pub fn slice(&self) -> &'a [u8] {
+        self.slice
+    }
+
+    /// Returns an iterator over all tags of the stack (starting with the
+    /// outer most tag).
+    #[inline]
+    pub fn tags(&self) -> VlanStackSliceIter<'a> {
+        VlanStackSliceIter { rest: self.slice }
+    }
+
+    /// Number of tags in the stack.
+    #[inline]
+    pub fn num_tags(&self) -> usize {
+        self.slice.len() / SingleVlanHeader::LEN
+    }
+
+    /// Returns the outer most (first) tag of the stack.
+    #[inline]
+    pub fn outer(&self) -> SingleVlanHeaderSlice<'a> {
+        // SAFETY:
+        // Safe as the constructor ensures that at least one tag is present.
+        unsafe {
+            SingleVlanHeaderSlice::from_slice_unchecked(from_raw_parts(
+                self.slice.as_ptr(),
+                SingleVlanHeader::LEN,
+            ))
+        }
+    }
+
+    /// Returns the inner most (last) tag of the stack. The ether type of
+    /// this tag identifies the payload following the stack.
+    #[inline]
+    pub fn inner(&self) -> SingleVlanHeaderSlice<'a> {
+        // SAFETY:
+        // Safe as the constructor ensures that at least one tag is present
+        // and that the slice length is a multiple of SingleVlanHeader::LEN.
+        unsafe {
+            SingleVlanHeaderSlice::from_slice_unchecked(from_raw_parts(
+                self.slice
+                    .as_ptr()
+                    .add(self.slice.len() - SingleVlanHeader::LEN),
+                SingleVlanHeader::LEN,
+            ))
+        }
+    }
+
+    /// Decode all the tags and copy the results to a [`VlanStack`]
+    /// (errors if the stack has more tags then [`VlanStack::MAX_TAGS`]).
+    pub fn to_header(&self) -> Result<VlanStack, err::vlan_stack::HeaderError> {
+        let mut result = VlanStack::new();
+        for tag in self.tags() {
+            result.try_push(tag.to_header())?;
+        }
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_gens::*;
+    use alloc::{format, vec::Vec};
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn from_slice(
+            input in vlan_stack_any(),
+            dummy_data in proptest::collection::vec(any::<u8>(), 0..20)
+        ) {
+            // serialize
+            let mut buffer: Vec<u8> = Vec::with_capacity(input.header_len() + dummy_data.len());
+            input.write(&mut buffer).unwrap();
+            buffer.extend(&dummy_data[..]);
+
+            // calls with a valid result
+            {
+                let result = VlanStackSlice::from_slice(&buffer[..]).unwrap();
+                assert_eq!(&buffer[..input.header_len()], result.slice());
+                assert_eq!(input.tags().len(), result.num_tags());
+            }
+
+            // call with not enough data in the slice
+            for len in 0..input.header_len() {
+                assert_eq!(
+                    VlanStackSlice::from_slice(&buffer[..len]),
+                    Err(err::LenError{
+                        required_len: (len / 4 + 1) * 4,
+                        len,
+                        len_source: err::LenSource::Slice,
+                        layer: err::Layer::VlanHeader,
+                        layer_start_offset: 0,
+                    })
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn tags_outer_inner(input in vlan_stack_any()) {
+            let mut bytes = Vec::with_capacity(input.header_len());
+            input.write(&mut bytes).unwrap();
+            let slice = VlanStackSlice::from_slice(&bytes).unwrap();
+
+            let actual: Vec<SingleVlanHeader> = slice.tags().map(|e| e.to_header()).collect();
+            assert_eq!(input.tags(), &actual[..]);
+            assert_eq!(&input.tags()[0], &slice.outer().to_header());
+            assert_eq!(input.tags().last().unwrap(), &slice.inner().to_header());
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn to_header(input in vlan_stack_any()) {
+            let mut bytes = Vec::with_capacity(input.header_len());
+            input.write(&mut bytes).unwrap();
+            let slice = VlanStackSlice::from_slice(&bytes).unwrap();
+            assert_eq!(Ok(input), slice.to_header());
+        }
+    }
+
+    #[test]
+    fn to_header_too_many_tags() {
+        let mut bytes = Vec::new();
+        for i in 0..=VlanStack::MAX_TAGS {
+            SingleVlanHeader {
+                pcp: VlanPcp::ZERO,
+                drop_eligible_indicator: false,
+                vlan_id: VlanId::try_new(i as u16).unwrap(),
+                ether_type: if i == VlanStack::MAX_TAGS {
+                    ether_type::IPV4
+                } else {
+                    ether_type::VLAN_TAGGED_FRAME
+                },
+            }
+            .write(&mut bytes)
+            .unwrap();
+        }
+        let slice = VlanStackSlice::from_slice(&bytes).unwrap();
+        assert_eq!(VlanStack::MAX_TAGS + 1, slice.num_tags());
+        assert_eq!(VlanStack::MAX_TAGS + 1, slice.tags().count());
+        assert_eq!(
+            Err(err::vlan_stack::HeaderError::TooManyTags {
+                max_tags: VlanStack::MAX_TAGS
+            }),
+            slice.to_header()
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn clone_eq_dbg(input in vlan_stack_any()) {
+            let mut bytes = Vec::with_capacity(input.header_len());
+            input.write(&mut bytes).unwrap();
+            let slice = VlanStackSlice::from_slice(&bytes).unwrap();
+            assert_eq!(slice, slice.clone());
+            assert_eq!(
+                &format!(
+                    "VlanStackSlice {{ slice: {:?} }}",
+                    slice.slice(),
+                ),
+                &format!("{:?}", slice)
+            );
+        }
+    }
+}
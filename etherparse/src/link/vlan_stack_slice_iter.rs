@@ -0,0 +1,74 @@
+use crate::*;
+use core::slice::from_raw_parts;
+
+/// Allows iterating over the tags present in an [`VlanStackSlice`].
+#[derive(Clone, Debug, Eq, PartialEq, Default)]
+pub struct VlanStackSliceIter<'a> {
+    pub(crate) rest: &'a [u8],
+}
+
+impl<'a> Iterator for VlanStackSliceIter<'a> {
+    type Item = SingleVlanHeaderSlice<'a>;
+
+    fn next(&mut self) -> Option<SingleVlanHeaderSlice<'a>> {
+        if self.rest.len() < SingleVlanHeader::LEN {
+            None
+        } else {
+            // SAFETY:
+            // Safe as the length of the rest was checked above to contain
+            // at least SingleVlanHeader::LEN (4) bytes.
+            unsafe {
+                let result = SingleVlanHeaderSlice::from_slice_unchecked(from_raw_parts(
+                    self.rest.as_ptr(),
+                    SingleVlanHeader::LEN,
+                ));
+                self.rest = from_raw_parts(
+                    self.rest.as_ptr().add(SingleVlanHeader::LEN),
+                    self.rest.len() - SingleVlanHeader::LEN,
+                );
+                Some(result)
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.rest.len() / SingleVlanHeader::LEN;
+        (len, Some(len))
+    }
+}
+
+impl<'a> ExactSizeIterator for VlanStackSliceIter<'a> {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::format;
+
+    #[test]
+    fn iterate() {
+        let data = [0x20, 0x01, 0x81, 0x00, 0x00, 0x02, 0x08, 0x00, 0xff];
+        let mut iter = VlanStackSliceIter { rest: &data };
+        assert_eq!(2, iter.len());
+
+        let first = iter.next().unwrap();
+        assert_eq!(1, first.vlan_identifier().value());
+        assert_eq!(1, first.priority_code_point().value());
+        assert_eq!(ether_type::VLAN_TAGGED_FRAME, first.ether_type());
+        assert_eq!(1, iter.len());
+
+        let second = iter.next().unwrap();
+        assert_eq!(2, second.vlan_identifier().value());
+        assert_eq!(ether_type::IPV4, second.ether_type());
+        assert_eq!(0, iter.len());
+
+        assert_eq!(None, iter.next());
+    }
+
+    #[test]
+    fn default_clone_eq_dbg() {
+        let iter = VlanStackSliceIter::default();
+        assert_eq!(iter.rest, &[]);
+        assert_eq!(iter, iter.clone());
+        assert_eq!("VlanStackSliceIter { rest: [] }", format!("{:?}", iter));
+    }
+}
@@ -1,8 +1,9 @@
 use crate::err::packet::BuildWriteError;
+use crate::frag::IpFragmenter;
 
 use super::*;
 
-use std::{io, marker};
+use std::{io, marker, vec::Vec};
 
 /// Helper for building packets.
 ///
@@ -48,16 +49,69 @@ use std::{io, marker};
 ///     * [`PacketBuilder::ipv4`]
 ///     * [`PacketBuilder::ipv6`]
 /// * Options after an Ethernet2 header was added:
+///     * [`PacketBuilderStep<Ethernet2Header>::fcs`]
 ///     * [`PacketBuilderStep<Ethernet2Header>::vlan`]
 ///     * [`PacketBuilderStep<Ethernet2Header>::single_vlan`]
 ///     * [`PacketBuilderStep<Ethernet2Header>::double_vlan`]
+///     * [`PacketBuilderStep<Ethernet2Header>::vlan_stack`]
 ///     * [`PacketBuilderStep<Ethernet2Header>::ip`]
 ///     * [`PacketBuilderStep<Ethernet2Header>::ipv4`]
 ///     * [`PacketBuilderStep<Ethernet2Header>::ipv6`]
+///     * [`PacketBuilderStep<Ethernet2Header>::arp`]
+///     * [`PacketBuilderStep<Ethernet2Header>::arp_request`]
+///     * [`PacketBuilderStep<Ethernet2Header>::arp_reply`]
+///     * [`PacketBuilderStep<Ethernet2Header>::arp_gratuitous`]
+///     * [`PacketBuilderStep<Ethernet2Header>::llc`]
+///     * [`PacketBuilderStep<Ethernet2Header>::snap`]
+///     * [`PacketBuilderStep<Ethernet2Header>::mpls`]
+///     * [`PacketBuilderStep<Ethernet2Header>::pppoe`]
+///     * [`PacketBuilderStep<Ethernet2Header>::pppoe_session`]
 /// * Options after an Vlan header was added:
 ///     * [`PacketBuilderStep<VlanHeader>::ip`]
 ///     * [`PacketBuilderStep<VlanHeader>::ipv4`]
 ///     * [`PacketBuilderStep<VlanHeader>::ipv6`]
+///     * [`PacketBuilderStep<VlanHeader>::arp`]
+///     * [`PacketBuilderStep<VlanHeader>::arp_request`]
+///     * [`PacketBuilderStep<VlanHeader>::arp_reply`]
+///     * [`PacketBuilderStep<VlanHeader>::arp_gratuitous`]
+///     * [`PacketBuilderStep<VlanHeader>::llc`]
+///     * [`PacketBuilderStep<VlanHeader>::snap`]
+///     * [`PacketBuilderStep<VlanHeader>::mpls`]
+///     * [`PacketBuilderStep<VlanHeader>::pppoe`]
+///     * [`PacketBuilderStep<VlanHeader>::pppoe_session`]
+/// * Options after an LLC header was added:
+///     * [`PacketBuilderStep<LlcHeader>::write`]
+///     * [`PacketBuilderStep<LlcHeader>::size`]
+/// * Options after an SNAP header was added:
+///     * [`PacketBuilderStep<SnapHeader>::ip`]
+///     * [`PacketBuilderStep<SnapHeader>::ipv4`]
+///     * [`PacketBuilderStep<SnapHeader>::ipv6`]
+///     * [`PacketBuilderStep<SnapHeader>::arp`]
+///     * [`PacketBuilderStep<SnapHeader>::write`]
+///     * [`PacketBuilderStep<SnapHeader>::size`]
+/// * Options after an MPLS label stack entry was added:
+///     * [`PacketBuilderStep<MplsLabelStackEntry>::mpls`]
+///     * [`PacketBuilderStep<MplsLabelStackEntry>::ip`]
+///     * [`PacketBuilderStep<MplsLabelStackEntry>::ipv4`]
+///     * [`PacketBuilderStep<MplsLabelStackEntry>::ipv6`]
+///     * [`PacketBuilderStep<MplsLabelStackEntry>::write`]
+///     * [`PacketBuilderStep<MplsLabelStackEntry>::size`]
+/// * Options after a PPPoE header was added:
+///     * [`PacketBuilderStep<PppoeHeader>::ppp`]
+///     * [`PacketBuilderStep<PppoeHeader>::ip`]
+///     * [`PacketBuilderStep<PppoeHeader>::ipv4`]
+///     * [`PacketBuilderStep<PppoeHeader>::ipv6`]
+///     * [`PacketBuilderStep<PppoeHeader>::write`]
+///     * [`PacketBuilderStep<PppoeHeader>::size`]
+/// * Options after a PPP header was added:
+///     * [`PacketBuilderStep<PppHeader>::ip`]
+///     * [`PacketBuilderStep<PppHeader>::ipv4`]
+///     * [`PacketBuilderStep<PppHeader>::ipv6`]
+///     * [`PacketBuilderStep<PppHeader>::write`]
+///     * [`PacketBuilderStep<PppHeader>::size`]
+/// * Options after an ARP packet was added:
+///     * [`PacketBuilderStep<ArpHeader>::write`]
+///     * [`PacketBuilderStep<ArpHeader>::size`]
 /// * Options after an IP header was added:
 ///     * [`PacketBuilderStep<IpHeader>::write`]
 ///     * [`PacketBuilderStep<IpHeader>::tcp`]
@@ -99,6 +153,9 @@ pub struct PacketBuilder {}
 impl PacketBuilder {
     /// Start an packet with an ethernetII header.
     ///
+    /// The MAC addresses can be passed as [`MacAddress`] or as `[u8; 6]`
+    /// octet arrays.
+    ///
     /// # Example
     ///
     /// Basic usage:
@@ -125,17 +182,32 @@ impl PacketBuilder {
     /// //serialize
     /// builder.write(&mut result, &payload).unwrap();
     /// ```
-    pub fn ethernet2(source: [u8; 6], destination: [u8; 6]) -> PacketBuilderStep<Ethernet2Header> {
+    pub fn ethernet2(
+        source: impl Into<MacAddress>,
+        destination: impl Into<MacAddress>,
+    ) -> PacketBuilderStep<Ethernet2Header> {
         PacketBuilderStep {
             state: PacketImpl {
                 ethernet2_header: Some(Ethernet2Header {
-                    source,
-                    destination,
+                    source: source.into(),
+                    destination: destination.into(),
                     ether_type: EtherType(0), //the type identifier
                 }),
                 vlan_header: None,
+                llc_header: None,
+                snap_header: None,
+                mpls_entries: Vec::new(),
+                outer_ip_headers: Vec::new(),
+                gre_header: None,
+                pppoe_header: None,
+                ppp_header: None,
+                arp_header: None,
                 ip_header: None,
                 transport_header: None,
+                vxlan_header: None,
+                geneve_header: None,
+                gtpu_header: None,
+                fcs: false,
             },
             _marker: marker::PhantomData::<Ethernet2Header> {},
         }
@@ -176,8 +248,20 @@ impl PacketBuilder {
             state: PacketImpl {
                 ethernet2_header: None,
                 vlan_header: None,
+                llc_header: None,
+                snap_header: None,
+                mpls_entries: Vec::new(),
+                outer_ip_headers: Vec::new(),
+                gre_header: None,
+                pppoe_header: None,
+                ppp_header: None,
+                arp_header: None,
                 ip_header: None,
                 transport_header: None,
+                vxlan_header: None,
+                geneve_header: None,
+                gtpu_header: None,
+                fcs: false,
             },
             _marker: marker::PhantomData::<Ethernet2Header> {},
         }
@@ -223,8 +307,20 @@ impl PacketBuilder {
             state: PacketImpl {
                 ethernet2_header: None,
                 vlan_header: None,
+                llc_header: None,
+                snap_header: None,
+                mpls_entries: Vec::new(),
+                outer_ip_headers: Vec::new(),
+                gre_header: None,
+                pppoe_header: None,
+                ppp_header: None,
+                arp_header: None,
                 ip_header: None,
                 transport_header: None,
+                vxlan_header: None,
+                geneve_header: None,
+                gtpu_header: None,
+                fcs: false,
             },
             _marker: marker::PhantomData::<Ethernet2Header> {},
         }
@@ -300,8 +396,20 @@ impl PacketBuilder {
             state: PacketImpl {
                 ethernet2_header: None,
                 vlan_header: None,
+                llc_header: None,
+                snap_header: None,
+                mpls_entries: Vec::new(),
+                outer_ip_headers: Vec::new(),
+                gre_header: None,
+                pppoe_header: None,
+                ppp_header: None,
+                arp_header: None,
                 ip_header: None,
                 transport_header: None,
+                vxlan_header: None,
+                geneve_header: None,
+                gtpu_header: None,
+                fcs: false,
             },
             _marker: marker::PhantomData::<Ethernet2Header> {},
         }
@@ -309,11 +417,31 @@ impl PacketBuilder {
     }
 }
 
+#[derive(Clone)]
 struct PacketImpl {
     ethernet2_header: Option<Ethernet2Header>,
     ip_header: Option<IpHeader>,
     vlan_header: Option<VlanHeader>,
+    llc_header: Option<LlcHeader>,
+    snap_header: Option<SnapHeader>,
+    mpls_entries: Vec<MplsLabelStackEntry>,
+    /// Outer IP headers of IP-in-IP & GRE tunnels (outermost first) and
+    /// the GRE header following them (if present). The innermost IP
+    /// header is stored in `ip_header`.
+    outer_ip_headers: Vec<(IpHeader, Option<GreHeader>)>,
+    /// GRE header following the innermost IP header.
+    gre_header: Option<GreHeader>,
+    pppoe_header: Option<PppoeHeader>,
+    ppp_header: Option<PppHeader>,
+    arp_header: Option<ArpHeader>,
     transport_header: Option<TransportHeader>,
+    /// VXLAN header prepended to the UDP payload.
+    vxlan_header: Option<VxlanHeader>,
+    /// Geneve header prepended to the UDP payload.
+    geneve_header: Option<GeneveHeader>,
+    /// GTP-U header prepended to the UDP payload.
+    gtpu_header: Option<GtpuHeader>,
+    fcs: bool,
 }
 
 ///An unfinished packet that is build with the packet builder
@@ -486,6 +614,52 @@ impl PacketBuilderStep<Ethernet2Header> {
         }
     }
 
+    /// Appends the frame check sequence (CRC-32) at the end of the
+    /// ethernet frame when the packet gets written.
+    ///
+    /// The FCS is calculated over the complete frame (see
+    /// [`crate::checksum::Crc32`]) and adds [`Ethernet2Header::FCS_LEN`]
+    /// bytes to the size of the packet. Frames shorter then
+    /// [`Ethernet2Header::MIN_FRAME_LEN`] are padded with zeros before the
+    /// FCS is calculated (the padding is also included in the size
+    /// returned by `size`).
+    ///
+    /// # Example
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use etherparse::{PacketBuilder, SlicedPacket};
+    /// #
+    /// let builder = PacketBuilder::
+    ///     ethernet2([1,2,3,4,5,6],     //source mac
+    ///               [7,8,9,10,11,12]) //destionation mac
+    ///     .fcs()
+    ///     .ipv4([192,168,1,1], //source ip
+    ///           [192,168,1,2], //desitionation ip
+    ///           20)            //time to life
+    ///     .udp(21,    //source port
+    ///          1234); //desitnation port
+    ///
+    /// //payload of the udp packet
+    /// let payload = [1,2,3,4,5,6,7,8];
+    ///
+    /// //get some memory to store the result
+    /// let mut result = Vec::<u8>::with_capacity(
+    ///                     builder.size(payload.len()));
+    ///
+    /// //serialize
+    /// builder.write(&mut result, &payload).unwrap();
+    ///
+    /// //the fcs can be verified & stripped when slicing
+    /// let sliced = SlicedPacket::from_ethernet_with_fcs(&result).unwrap();
+    /// assert_eq!(&payload, sliced.payload);
+    /// ```
+    pub fn fcs(mut self) -> PacketBuilderStep<Ethernet2Header> {
+        self.state.fcs = true;
+        self
+    }
+
     /// Adds a vlan tagging header with the given vlan identifier
     ///
     /// # Example
@@ -628,122 +802,283 @@ impl PacketBuilderStep<Ethernet2Header> {
             _marker: marker::PhantomData::<VlanHeader> {},
         }
     }
-}
 
-impl PacketBuilderStep<VlanHeader> {
-    ///Add an ip header (length, protocol/next_header & checksum fields will be overwritten based on the rest of the packet).
+    /// Adds a stack of vlan tagging headers (e.g. for triple tagged
+    /// packets). The ether types of the tags will be set automatically
+    /// during the write.
+    ///
+    /// # Example
+    ///
+    /// Basic usage:
     ///
-    /// # Example IPv4
     /// ```
-    /// # use etherparse::*;
+    /// # use etherparse::{PacketBuilder, SingleVlanHeader, VlanStack};
     /// #
+    /// let mut stack = VlanStack::new();
+    /// for vlan_id in [0x123, 0x234, 0x345] {
+    ///     stack.try_push(SingleVlanHeader {
+    ///         vlan_id: vlan_id.try_into().unwrap(),
+    ///         ..Default::default()
+    ///     }).unwrap();
+    /// }
+    ///
     /// let builder = PacketBuilder::
-    ///     ethernet2([1,2,3,4,5,6],
-    ///               [7,8,9,10,11,12])
-    ///    .single_vlan(0x132.try_into().unwrap())
-    ///    //payload_len, protocol & checksum will be replaced during write
-    ///    .ip(IpHeader::Version4(
-    ///         Ipv4Header::new(
-    ///             0, //payload_len will be replaced during write
-    ///             12, //time_to_live
-    ///             ip_number::UDP, //will be replaced during write
-    ///             [0,1,2,3], //source
-    ///             [4,5,6,7] //destination
-    ///         ).unwrap(),
-    ///         Default::default() // IPv4 extension headers (default is none)
-    ///     ));
+    ///     ethernet2([1,2,3,4,5,6],     //source mac
+    ///               [7,8,9,10,11,12]) //destionation mac
+    ///     .vlan_stack(stack)
+    ///     .ipv4([192,168,1,1], //source ip
+    ///           [192,168,1,2], //desitionation ip
+    ///           20)            //time to life
+    ///     .udp(21,    //source port
+    ///          1234); //desitnation port
+    ///
+    /// //payload of the udp packet
+    /// let payload = [1,2,3,4,5,6,7,8];
+    ///
+    /// //get some memory to store the result
+    /// let mut result = Vec::<u8>::with_capacity(
+    ///                     builder.size(payload.len()));
+    ///
+    /// //serialize
+    /// builder.write(&mut result, &payload).unwrap();
     /// ```
+    pub fn vlan_stack(mut self, stack: VlanStack) -> PacketBuilderStep<VlanHeader> {
+        self.state.vlan_header = Some(VlanHeader::Multi(stack));
+        //return for next step
+        PacketBuilderStep {
+            state: self.state,
+            _marker: marker::PhantomData::<VlanHeader> {},
+        }
+    }
+    /// Adds an ARP packet (no further headers can be added after an ARP
+    /// packet). The ether type of the Ethernet II header will be set
+    /// automatically during the write.
+    ///
+    /// # Example
+    ///
+    /// Basic usage:
     ///
-    /// # Example IPv6
     /// ```
     /// # use etherparse::*;
     /// #
     /// let builder = PacketBuilder::
-    ///     ethernet2([1,2,3,4,5,6],
-    ///               [7,8,9,10,11,12])
-    ///    .single_vlan(0x132.try_into().unwrap())
-    ///    .ip(IpHeader::Version6(
-    ///         Ipv6Header{
-    ///             traffic_class: 0,
-    ///             flow_label: 0.try_into().unwrap(),
-    ///             hop_limit: 4,
-    ///             source: [0;16],
-    ///             destination: [0;16],
-    ///             // payload_length & next_header will be replaced during write
-    ///             ..Default::default()
-    ///         },
-    ///         Default::default() // IPv6 extension headers (default is none)
-    ///     ));
+    ///     ethernet2([1,2,3,4,5,6],     //source mac
+    ///               [7,8,9,10,11,12]) //destionation mac
+    ///     .arp(
+    ///         ArpHeader::new(
+    ///             ArpHardwareId::ETHERNET,
+    ///             EtherType::IPV4,
+    ///             ArpOperation::REQUEST,
+    ///             &[1,2,3,4,5,6], // sender hardware address
+    ///             &[192,168,1,1], // sender protocol address
+    ///             &[0,0,0,0,0,0], // target hardware address
+    ///             &[192,168,1,2], // target protocol address
+    ///         ).unwrap()
+    ///     );
+    ///
+    /// //get some memory to store the result
+    /// let mut result = Vec::<u8>::with_capacity(builder.size());
+    ///
+    /// //serialize
+    /// builder.write(&mut result).unwrap();
     /// ```
-    pub fn ip(self, ip_header: IpHeader) -> PacketBuilderStep<IpHeader> {
-        //use the method from the Ethernet2Header implementation
+    pub fn arp(mut self, arp: ArpHeader) -> PacketBuilderStep<ArpHeader> {
+        self.state.arp_header = Some(arp);
+        //return for next step
         PacketBuilderStep {
             state: self.state,
-            _marker: marker::PhantomData::<Ethernet2Header> {},
+            _marker: marker::PhantomData::<ArpHeader> {},
         }
-        .ip(ip_header)
     }
 
-    /// Add an IPv6 header
+    /// Adds an ARP request for an IPv4 address.
+    ///
+    /// The source MAC of the Ethernet II header is used as the sender
+    /// hardware address and the target hardware address is set to zero
+    /// (as it is the unknown value that is requested). Usually ARP requests
+    /// are send to the broadcast MAC address ([`MacAddress::BROADCAST`]).
     ///
     /// # Example
     ///
     /// Basic usage:
     ///
     /// ```
-    /// # use etherparse::{PacketBuilder, SingleVlanHeader, VlanHeader};
+    /// # use etherparse::{MacAddress, PacketBuilder};
+    /// #
+    /// let builder = PacketBuilder::
+    ///     ethernet2([1,2,3,4,5,6],         //source mac
+    ///               MacAddress::BROADCAST) //destionation mac
+    ///     .arp_request([192,168,1,1], // sender ip (own ip)
+    ///                  [192,168,1,2]); // target ip (ip whose mac is requested)
+    ///
+    /// //get some memory to store the result
+    /// let mut result = Vec::<u8>::with_capacity(builder.size());
+    ///
+    /// //serialize
+    /// builder.write(&mut result).unwrap();
+    /// ```
+    pub fn arp_request(
+        self,
+        sender_ipv4: [u8; 4],
+        target_ipv4: [u8; 4],
+    ) -> PacketBuilderStep<ArpHeader> {
+        let sender_mac = self
+            .state
+            .ethernet2_header
+            .as_ref()
+            .map(|eth| eth.source)
+            .unwrap_or_default();
+        self.arp(
+            ArpEthIpv4Header {
+                operation: ArpOperation::REQUEST,
+                sender_mac,
+                sender_ipv4,
+                target_mac: MacAddress::ZERO,
+                target_ipv4,
+            }
+            .into(),
+        )
+    }
+
+    /// Adds an ARP reply for an IPv4 address.
+    ///
+    /// The source MAC of the Ethernet II header is used as the sender
+    /// hardware address (the resolved address) and the destination MAC of
+    /// the Ethernet II header is used as the target hardware address.
+    ///
+    /// # Example
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use etherparse::PacketBuilder;
     /// #
     /// let builder = PacketBuilder::
     ///     ethernet2([1,2,3,4,5,6],     //source mac
     ///               [7,8,9,10,11,12]) //destionation mac
-    ///     .single_vlan(0x123.try_into().unwrap()) // vlan identifier
-    ///     .ipv6(
-    ///         //source
-    ///         [11,12,13,14,15,16,17,18,19,10,21,22,23,24,25,26],
-    ///         //destination
-    ///         [31,32,33,34,35,36,37,38,39,40,41,42,43,44,45,46],
-    ///         //hop_limit
-    ///         47)
-    ///     .udp(21,    //source port
-    ///          1234); //desitnation port
+    ///     .arp_reply([192,168,1,2], // sender ip (ip that got resolved)
+    ///                [192,168,1,1]); // target ip (ip of the requester)
     ///
-    /// //payload of the udp packet
-    /// let payload = [1,2,3,4,5,6,7,8];
+    /// //get some memory to store the result
+    /// let mut result = Vec::<u8>::with_capacity(builder.size());
+    ///
+    /// //serialize
+    /// builder.write(&mut result).unwrap();
+    /// ```
+    pub fn arp_reply(
+        self,
+        sender_ipv4: [u8; 4],
+        target_ipv4: [u8; 4],
+    ) -> PacketBuilderStep<ArpHeader> {
+        let (sender_mac, target_mac) = self
+            .state
+            .ethernet2_header
+            .as_ref()
+            .map(|eth| (eth.source, eth.destination))
+            .unwrap_or_default();
+        self.arp(
+            ArpEthIpv4Header {
+                operation: ArpOperation::REPLY,
+                sender_mac,
+                sender_ipv4,
+                target_mac,
+                target_ipv4,
+            }
+            .into(),
+        )
+    }
+
+    /// Adds a gratuitous ARP packet (also called "ARP announcement")
+    /// announcing the given IPv4 address.
+    ///
+    /// A gratuitous ARP is an ARP request that has the given IPv4 address
+    /// set as the sender & target protocol address. The source MAC of the
+    /// Ethernet II header is used as the sender hardware address and the
+    /// target hardware address is set to zero. Usually gratuitous ARPs are
+    /// send to the broadcast MAC address ([`MacAddress::BROADCAST`]).
+    ///
+    /// # Example
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use etherparse::PacketBuilder;
+    /// #
+    /// let builder = PacketBuilder::
+    ///     ethernet2([1,2,3,4,5,6], //source mac
+    ///               [0xff;6])      //destionation mac (broadcast)
+    ///     .arp_gratuitous([192,168,1,1]); // announced ip
     ///
     /// //get some memory to store the result
-    /// let mut result = Vec::<u8>::with_capacity(
-    ///                     builder.size(payload.len()));
+    /// let mut result = Vec::<u8>::with_capacity(builder.size());
+    ///
+    /// //serialize
+    /// builder.write(&mut result).unwrap();
+    /// ```
+    pub fn arp_gratuitous(self, ipv4: [u8; 4]) -> PacketBuilderStep<ArpHeader> {
+        self.arp_request(ipv4, ipv4)
+    }
+
+    /// Adds an IEEE 802.2 LLC header and turns the packet into an
+    /// IEEE 802.3 frame.
+    ///
+    /// The type/length field of the Ethernet II header (or VLAN header)
+    /// is set to the length of the data following it during the write.
+    ///
+    /// # Example
+    ///
+    /// Basic usage (spanning tree BPDU):
+    ///
+    /// ```
+    /// # use etherparse::{PacketBuilder, LlcHeader};
+    /// #
+    /// let builder = PacketBuilder::
+    ///     ethernet2([1,2,3,4,5,6],        //source mac
+    ///               [1,0x80,0xc2,0,0,0]) //destionation mac
+    ///     .llc(LlcHeader {
+    ///         dsap: LlcHeader::STP_SAP,
+    ///         ssap: LlcHeader::STP_SAP,
+    ///         control: [LlcHeader::UI_CONTROL, 0],
+    ///     });
+    ///
+    /// //payload of the llc header
+    /// let payload = [0,0,0,0];
+    ///
+    /// //get some memory to store the result
+    /// let mut result = Vec::<u8>::with_capacity(builder.size(payload.len()));
     ///
     /// //serialize
     /// builder.write(&mut result, &payload).unwrap();
     /// ```
-    pub fn ipv6(
-        self,
-        source: [u8; 16],
-        destination: [u8; 16],
-        hop_limit: u8,
-    ) -> PacketBuilderStep<IpHeader> {
-        //use the method from the Ethernet2Header implementation
+    pub fn llc(mut self, llc: LlcHeader) -> PacketBuilderStep<LlcHeader> {
+        self.state.llc_header = Some(llc);
+        //return for next step
         PacketBuilderStep {
             state: self.state,
-            _marker: marker::PhantomData::<Ethernet2Header> {},
+            _marker: marker::PhantomData::<LlcHeader> {},
         }
-        .ipv6(source, destination, hop_limit)
     }
 
-    /// Add an IPv4 header
+    /// Adds an LLC header indicating SNAP ([`LlcHeader::SNAP`]) & the
+    /// given SNAP header and turns the packet into an IEEE 802.3 frame.
+    ///
+    /// The `ether_type` field of the SNAP header is overwritten in case an
+    /// IP header or ARP packet is added after the SNAP header.
     ///
     /// # Example
     ///
     /// Basic usage:
     ///
     /// ```
-    /// # use etherparse::{PacketBuilder, SingleVlanHeader, VlanHeader};
+    /// # use etherparse::{PacketBuilder, SnapHeader};
     /// #
     /// let builder = PacketBuilder::
     ///     ethernet2([1,2,3,4,5,6],     //source mac
     ///               [7,8,9,10,11,12]) //destionation mac
-    ///     .single_vlan(0x123.try_into().unwrap()) // vlan identifier
+    ///     .snap(SnapHeader {
+    ///         oui: SnapHeader::OUI_RFC1042,
+    ///         ether_type: 0.into(), // will be overwritten during write
+    ///     })
     ///     .ipv4([192,168,1,1], //source ip
     ///           [192,168,1,2], //desitionation ip
     ///           20)            //time to life
@@ -754,88 +1089,1229 @@ impl PacketBuilderStep<VlanHeader> {
     /// let payload = [1,2,3,4,5,6,7,8];
     ///
     /// //get some memory to store the result
-    /// let mut result = Vec::<u8>::with_capacity(
-    ///                     builder.size(payload.len()));
+    /// let mut result = Vec::<u8>::with_capacity(builder.size(payload.len()));
     ///
     /// //serialize
     /// builder.write(&mut result, &payload).unwrap();
     /// ```
-    pub fn ipv4(
-        self,
-        source: [u8; 4],
-        destination: [u8; 4],
-        time_to_live: u8,
-    ) -> PacketBuilderStep<IpHeader> {
-        //use the method from the Ethernet2Header implementation
+    pub fn snap(mut self, snap: SnapHeader) -> PacketBuilderStep<SnapHeader> {
+        self.state.llc_header = Some(LlcHeader::SNAP);
+        self.state.snap_header = Some(snap);
+        //return for next step
         PacketBuilderStep {
             state: self.state,
-            _marker: marker::PhantomData::<Ethernet2Header> {},
+            _marker: marker::PhantomData::<SnapHeader> {},
         }
-        .ipv4(source, destination, time_to_live)
     }
-}
 
-impl PacketBuilderStep<IpHeader> {
-    /// Adds an ICMPv4 header of the given [`Icmpv4Type`] to the packet.
-    ///
-    /// If an ICMPv4 header gets added the payload used during the builders `write`
-    /// call contains the bytes after the header and has different meanings
-    /// and contents based on the type. Ususally all statically sized values
-    /// known based on the ICMPv4 type & code are part of the header and the
-    /// payload is used to store contains the dynamic parts of the ICMPv4 packet.
+    /// Adds an MPLS label stack entry (more entries can be pushed
+    /// by calling [`PacketBuilderStep<MplsLabelStackEntry>::mpls`]).
     ///
-    /// Check [`Icmpv4Type`] for a documentation which values are part of the
-    /// header and what is stored as part of the payload.
+    /// The ether type of the preceding header is set to
+    /// [`ether_type::MPLS_UNICAST`] and the `bottom_of_stack` flag of
+    /// the entries is overwritten during the write, so that only the
+    /// last entry has the flag set.
     ///
     /// # Example
     ///
     /// Basic usage:
     ///
     /// ```
-    /// # use etherparse::{PacketBuilder, Icmpv4Type, icmpv4};
+    /// # use etherparse::{PacketBuilder, MplsLabelStackEntry};
     /// #
     /// let builder = PacketBuilder::
-    ///    ipv4([192,168,1,1],  //source ip
-    ///          [192,168,1,2], //desitionation ip
-    ///          20)            //time to life
-    ///    .icmpv4(
-    ///         Icmpv4Type::TimeExceeded(
-    ///             icmpv4::TimeExceededCode::TtlExceededInTransit
-    ///         )
-    ///     );
+    ///     ethernet2([1,2,3,4,5,6],     //source mac
+    ///               [7,8,9,10,11,12]) //destionation mac
+    ///     .mpls(MplsLabelStackEntry {
+    ///         label: 16.try_into().unwrap(),
+    ///         traffic_class: 0.try_into().unwrap(),
+    ///         bottom_of_stack: false, // will be overwritten during write
+    ///         ttl: 64,
+    ///     })
+    ///     .ipv4([192,168,1,1], //source ip
+    ///           [192,168,1,2], //desitionation ip
+    ///           20)            //time to life
+    ///     .udp(21,    //source port
+    ///          1234); //desitnation port
     ///
-    /// // what is part of the payload depends on the Icmpv4Type
-    /// //
-    /// // In case of `Icmpv4Type::TimeExceeded` the "internet header
-    /// // + 64 bits of the original data datagram" should be given as
-    /// // the payload
+    /// //payload of the udp packet
     /// let payload = [1,2,3,4,5,6,7,8];
     ///
     /// //get some memory to store the result
-    /// let mut result = Vec::<u8>::with_capacity(
-    ///                     builder.size(payload.len()));
+    /// let mut result = Vec::<u8>::with_capacity(builder.size(payload.len()));
     ///
     /// //serialize
     /// builder.write(&mut result, &payload).unwrap();
     /// ```
-    pub fn icmpv4(mut self, icmp_type: Icmpv4Type) -> PacketBuilderStep<Icmpv4Header> {
-        self.state.transport_header = Some(TransportHeader::Icmpv4(Icmpv4Header {
-            icmp_type,
-            checksum: 0, // calculated later
-        }));
+    pub fn mpls(mut self, entry: MplsLabelStackEntry) -> PacketBuilderStep<MplsLabelStackEntry> {
+        self.state.mpls_entries.push(entry);
         //return for next step
         PacketBuilderStep {
             state: self.state,
-            _marker: marker::PhantomData::<Icmpv4Header> {},
+            _marker: marker::PhantomData::<MplsLabelStackEntry> {},
         }
     }
 
-    /// Adds an ICMPv4 header based on raw numbers.
+    /// Adds a PPPoE header.
     ///
-    /// This can be usefull when trying to build an ICMPv4 packet
-    /// which is not fully supported by etherparse and is the equivalent
-    /// of using [`Icmpv4Type::Unknown`] together with
-    /// [`PacketBuilderStep<IpHeader>::icmpv4`].
+    /// The ether type of the preceding header is set based on the code
+    /// of the PPPoE header ([`ether_type::PPPOE_SESSION`] for
+    /// [`PppoeCode::SESSION_DATA`] & [`ether_type::PPPOE_DISCOVERY`] for
+    /// all other codes). The `payload_length` field is overwritten during
+    /// the write based on the rest of the packet.
+    ///
+    /// For discovery stage packets the tags have to be passed as payload
+    /// to the `write` call.
+    ///
+    /// # Example
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use etherparse::{PacketBuilder, PppoeHeader, PppoeCode};
+    /// #
+    /// let builder = PacketBuilder::
+    ///     ethernet2([1,2,3,4,5,6],     //source mac
+    ///               [7,8,9,10,11,12]) //destionation mac
+    ///     .pppoe(PppoeHeader {
+    ///         code: PppoeCode::PADI,
+    ///         session_id: 0,
+    ///         payload_length: 0, // will be overwritten during write
+    ///     });
+    ///
+    /// //tags of the discovery packet (empty service name)
+    /// let tags = [0x01, 0x01, 0x00, 0x00];
+    ///
+    /// //get some memory to store the result
+    /// let mut result = Vec::<u8>::with_capacity(builder.size(tags.len()));
+    ///
+    /// //serialize
+    /// builder.write(&mut result, &tags).unwrap();
+    /// ```
+    pub fn pppoe(mut self, pppoe: PppoeHeader) -> PacketBuilderStep<PppoeHeader> {
+        self.state.pppoe_header = Some(pppoe);
+        //return for next step
+        PacketBuilderStep {
+            state: self.state,
+            _marker: marker::PhantomData::<PppoeHeader> {},
+        }
+    }
+
+    /// Adds a PPPoE session stage header with the given session id (see
+    /// [`PacketBuilderStep<Ethernet2Header>::pppoe`] for details).
+    ///
+    /// # Example
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use etherparse::PacketBuilder;
+    /// #
+    /// let builder = PacketBuilder::
+    ///     ethernet2([1,2,3,4,5,6],     //source mac
+    ///               [7,8,9,10,11,12]) //destionation mac
+    ///     .pppoe_session(0x1234) // session id
+    ///     .ipv4([192,168,1,1], //source ip
+    ///           [192,168,1,2], //desitionation ip
+    ///           20)            //time to life
+    ///     .udp(21,    //source port
+    ///          1234); //desitnation port
+    ///
+    /// //payload of the udp packet
+    /// let payload = [1,2,3,4,5,6,7,8];
+    ///
+    /// //get some memory to store the result
+    /// let mut result = Vec::<u8>::with_capacity(builder.size(payload.len()));
+    ///
+    /// //serialize
+    /// builder.write(&mut result, &payload).unwrap();
+    /// ```
+    pub fn pppoe_session(self, session_id: u16) -> PacketBuilderStep<PppoeHeader> {
+        self.pppoe(PppoeHeader {
+            code: PppoeCode::SESSION_DATA,
+            session_id,
+            payload_length: 0,
+        })
+    }
+}
+
+impl PacketBuilderStep<VlanHeader> {
+    ///Add an ip header (length, protocol/next_header & checksum fields will be overwritten based on the rest of the packet).
+    ///
+    /// # Example IPv4
+    /// ```
+    /// # use etherparse::*;
+    /// #
+    /// let builder = PacketBuilder::
+    ///     ethernet2([1,2,3,4,5,6],
+    ///               [7,8,9,10,11,12])
+    ///    .single_vlan(0x132.try_into().unwrap())
+    ///    //payload_len, protocol & checksum will be replaced during write
+    ///    .ip(IpHeader::Version4(
+    ///         Ipv4Header::new(
+    ///             0, //payload_len will be replaced during write
+    ///             12, //time_to_live
+    ///             ip_number::UDP, //will be replaced during write
+    ///             [0,1,2,3], //source
+    ///             [4,5,6,7] //destination
+    ///         ).unwrap(),
+    ///         Default::default() // IPv4 extension headers (default is none)
+    ///     ));
+    /// ```
+    ///
+    /// # Example IPv6
+    /// ```
+    /// # use etherparse::*;
+    /// #
+    /// let builder = PacketBuilder::
+    ///     ethernet2([1,2,3,4,5,6],
+    ///               [7,8,9,10,11,12])
+    ///    .single_vlan(0x132.try_into().unwrap())
+    ///    .ip(IpHeader::Version6(
+    ///         Ipv6Header{
+    ///             traffic_class: 0,
+    ///             flow_label: 0.try_into().unwrap(),
+    ///             hop_limit: 4,
+    ///             source: [0;16],
+    ///             destination: [0;16],
+    ///             // payload_length & next_header will be replaced during write
+    ///             ..Default::default()
+    ///         },
+    ///         Default::default() // IPv6 extension headers (default is none)
+    ///     ));
+    /// ```
+    pub fn ip(self, ip_header: IpHeader) -> PacketBuilderStep<IpHeader> {
+        //use the method from the Ethernet2Header implementation
+        PacketBuilderStep {
+            state: self.state,
+            _marker: marker::PhantomData::<Ethernet2Header> {},
+        }
+        .ip(ip_header)
+    }
+
+    /// Add an IPv6 header
+    ///
+    /// # Example
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use etherparse::{PacketBuilder, SingleVlanHeader, VlanHeader};
+    /// #
+    /// let builder = PacketBuilder::
+    ///     ethernet2([1,2,3,4,5,6],     //source mac
+    ///               [7,8,9,10,11,12]) //destionation mac
+    ///     .single_vlan(0x123.try_into().unwrap()) // vlan identifier
+    ///     .ipv6(
+    ///         //source
+    ///         [11,12,13,14,15,16,17,18,19,10,21,22,23,24,25,26],
+    ///         //destination
+    ///         [31,32,33,34,35,36,37,38,39,40,41,42,43,44,45,46],
+    ///         //hop_limit
+    ///         47)
+    ///     .udp(21,    //source port
+    ///          1234); //desitnation port
+    ///
+    /// //payload of the udp packet
+    /// let payload = [1,2,3,4,5,6,7,8];
+    ///
+    /// //get some memory to store the result
+    /// let mut result = Vec::<u8>::with_capacity(
+    ///                     builder.size(payload.len()));
+    ///
+    /// //serialize
+    /// builder.write(&mut result, &payload).unwrap();
+    /// ```
+    pub fn ipv6(
+        self,
+        source: [u8; 16],
+        destination: [u8; 16],
+        hop_limit: u8,
+    ) -> PacketBuilderStep<IpHeader> {
+        //use the method from the Ethernet2Header implementation
+        PacketBuilderStep {
+            state: self.state,
+            _marker: marker::PhantomData::<Ethernet2Header> {},
+        }
+        .ipv6(source, destination, hop_limit)
+    }
+
+    /// Add an IPv4 header
+    ///
+    /// # Example
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use etherparse::{PacketBuilder, SingleVlanHeader, VlanHeader};
+    /// #
+    /// let builder = PacketBuilder::
+    ///     ethernet2([1,2,3,4,5,6],     //source mac
+    ///               [7,8,9,10,11,12]) //destionation mac
+    ///     .single_vlan(0x123.try_into().unwrap()) // vlan identifier
+    ///     .ipv4([192,168,1,1], //source ip
+    ///           [192,168,1,2], //desitionation ip
+    ///           20)            //time to life
+    ///     .udp(21,    //source port
+    ///          1234); //desitnation port
+    ///
+    /// //payload of the udp packet
+    /// let payload = [1,2,3,4,5,6,7,8];
+    ///
+    /// //get some memory to store the result
+    /// let mut result = Vec::<u8>::with_capacity(
+    ///                     builder.size(payload.len()));
+    ///
+    /// //serialize
+    /// builder.write(&mut result, &payload).unwrap();
+    /// ```
+    pub fn ipv4(
+        self,
+        source: [u8; 4],
+        destination: [u8; 4],
+        time_to_live: u8,
+    ) -> PacketBuilderStep<IpHeader> {
+        //use the method from the Ethernet2Header implementation
+        PacketBuilderStep {
+            state: self.state,
+            _marker: marker::PhantomData::<Ethernet2Header> {},
+        }
+        .ipv4(source, destination, time_to_live)
+    }
+    /// Adds an ARP packet (no further headers can be added after an ARP
+    /// packet). The ether type of the VLAN header will be set
+    /// automatically during the write.
+    ///
+    /// # Example
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use etherparse::*;
+    /// #
+    /// let builder = PacketBuilder::
+    ///     ethernet2([1,2,3,4,5,6],     //source mac
+    ///               [7,8,9,10,11,12]) //destionation mac
+    ///     .single_vlan(0x123.try_into().unwrap()) // vlan identifier
+    ///     .arp(
+    ///         ArpHeader::new(
+    ///             ArpHardwareId::ETHERNET,
+    ///             EtherType::IPV4,
+    ///             ArpOperation::REQUEST,
+    ///             &[1,2,3,4,5,6], // sender hardware address
+    ///             &[192,168,1,1], // sender protocol address
+    ///             &[0,0,0,0,0,0], // target hardware address
+    ///             &[192,168,1,2], // target protocol address
+    ///         ).unwrap()
+    ///     );
+    ///
+    /// //get some memory to store the result
+    /// let mut result = Vec::<u8>::with_capacity(builder.size());
+    ///
+    /// //serialize
+    /// builder.write(&mut result).unwrap();
+    /// ```
+    pub fn arp(self, arp: ArpHeader) -> PacketBuilderStep<ArpHeader> {
+        //use the method from the Ethernet2Header implementation
+        PacketBuilderStep {
+            state: self.state,
+            _marker: marker::PhantomData::<Ethernet2Header> {},
+        }
+        .arp(arp)
+    }
+
+    /// Adds an ARP request for an IPv4 address (see
+    /// [`PacketBuilderStep<Ethernet2Header>::arp_request`] for details).
+    ///
+    /// # Example
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use etherparse::PacketBuilder;
+    /// #
+    /// let builder = PacketBuilder::
+    ///     ethernet2([1,2,3,4,5,6], //source mac
+    ///               [0xff;6])      //destionation mac (broadcast)
+    ///     .single_vlan(0x123.try_into().unwrap()) // vlan identifier
+    ///     .arp_request([192,168,1,1], // sender ip (own ip)
+    ///                  [192,168,1,2]); // target ip (ip whose mac is requested)
+    ///
+    /// //get some memory to store the result
+    /// let mut result = Vec::<u8>::with_capacity(builder.size());
+    ///
+    /// //serialize
+    /// builder.write(&mut result).unwrap();
+    /// ```
+    pub fn arp_request(
+        self,
+        sender_ipv4: [u8; 4],
+        target_ipv4: [u8; 4],
+    ) -> PacketBuilderStep<ArpHeader> {
+        //use the method from the Ethernet2Header implementation
+        PacketBuilderStep {
+            state: self.state,
+            _marker: marker::PhantomData::<Ethernet2Header> {},
+        }
+        .arp_request(sender_ipv4, target_ipv4)
+    }
+
+    /// Adds an ARP reply for an IPv4 address (see
+    /// [`PacketBuilderStep<Ethernet2Header>::arp_reply`] for details).
+    ///
+    /// # Example
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use etherparse::PacketBuilder;
+    /// #
+    /// let builder = PacketBuilder::
+    ///     ethernet2([1,2,3,4,5,6],     //source mac
+    ///               [7,8,9,10,11,12]) //destionation mac
+    ///     .single_vlan(0x123.try_into().unwrap()) // vlan identifier
+    ///     .arp_reply([192,168,1,2], // sender ip (ip that got resolved)
+    ///                [192,168,1,1]); // target ip (ip of the requester)
+    ///
+    /// //get some memory to store the result
+    /// let mut result = Vec::<u8>::with_capacity(builder.size());
+    ///
+    /// //serialize
+    /// builder.write(&mut result).unwrap();
+    /// ```
+    pub fn arp_reply(
+        self,
+        sender_ipv4: [u8; 4],
+        target_ipv4: [u8; 4],
+    ) -> PacketBuilderStep<ArpHeader> {
+        //use the method from the Ethernet2Header implementation
+        PacketBuilderStep {
+            state: self.state,
+            _marker: marker::PhantomData::<Ethernet2Header> {},
+        }
+        .arp_reply(sender_ipv4, target_ipv4)
+    }
+
+    /// Adds a gratuitous ARP packet announcing the given IPv4 address (see
+    /// [`PacketBuilderStep<Ethernet2Header>::arp_gratuitous`] for details).
+    ///
+    /// # Example
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use etherparse::PacketBuilder;
+    /// #
+    /// let builder = PacketBuilder::
+    ///     ethernet2([1,2,3,4,5,6], //source mac
+    ///               [0xff;6])      //destionation mac (broadcast)
+    ///     .single_vlan(0x123.try_into().unwrap()) // vlan identifier
+    ///     .arp_gratuitous([192,168,1,1]); // announced ip
+    ///
+    /// //get some memory to store the result
+    /// let mut result = Vec::<u8>::with_capacity(builder.size());
+    ///
+    /// //serialize
+    /// builder.write(&mut result).unwrap();
+    /// ```
+    pub fn arp_gratuitous(self, ipv4: [u8; 4]) -> PacketBuilderStep<ArpHeader> {
+        //use the method from the Ethernet2Header implementation
+        PacketBuilderStep {
+            state: self.state,
+            _marker: marker::PhantomData::<Ethernet2Header> {},
+        }
+        .arp_gratuitous(ipv4)
+    }
+
+    /// Adds an IEEE 802.2 LLC header and turns the packet into an
+    /// IEEE 802.3 frame (see [`PacketBuilderStep<Ethernet2Header>::llc`]
+    /// for details).
+    ///
+    /// # Example
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use etherparse::{PacketBuilder, LlcHeader};
+    /// #
+    /// let builder = PacketBuilder::
+    ///     ethernet2([1,2,3,4,5,6],     //source mac
+    ///               [7,8,9,10,11,12]) //destionation mac
+    ///     .single_vlan(0x123.try_into().unwrap()) // vlan identifier
+    ///     .llc(LlcHeader {
+    ///         dsap: 0xf0,
+    ///         ssap: 0xf0,
+    ///         control: [LlcHeader::UI_CONTROL, 0],
+    ///     });
+    ///
+    /// //payload of the llc header
+    /// let payload = [1,2,3,4];
+    ///
+    /// //get some memory to store the result
+    /// let mut result = Vec::<u8>::with_capacity(builder.size(payload.len()));
+    ///
+    /// //serialize
+    /// builder.write(&mut result, &payload).unwrap();
+    /// ```
+    pub fn llc(self, llc: LlcHeader) -> PacketBuilderStep<LlcHeader> {
+        //use the method from the Ethernet2Header implementation
+        PacketBuilderStep {
+            state: self.state,
+            _marker: marker::PhantomData::<Ethernet2Header> {},
+        }
+        .llc(llc)
+    }
+
+    /// Adds an LLC header indicating SNAP & the given SNAP header (see
+    /// [`PacketBuilderStep<Ethernet2Header>::snap`] for details).
+    ///
+    /// # Example
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use etherparse::{PacketBuilder, SnapHeader};
+    /// #
+    /// let builder = PacketBuilder::
+    ///     ethernet2([1,2,3,4,5,6],     //source mac
+    ///               [7,8,9,10,11,12]) //destionation mac
+    ///     .single_vlan(0x123.try_into().unwrap()) // vlan identifier
+    ///     .snap(SnapHeader {
+    ///         oui: SnapHeader::OUI_RFC1042,
+    ///         ether_type: 0.into(), // will be overwritten during write
+    ///     })
+    ///     .ipv6([11,12,13,14,15,16,17,18,19,10,21,22,23,24,25,26], //source ip
+    ///           [31,32,33,34,35,36,37,38,39,40,41,42,43,44,45,46], //desitionation ip
+    ///           47)                                              //time to life
+    ///     .udp(21,    //source port
+    ///          1234); //desitnation port
+    ///
+    /// //payload of the udp packet
+    /// let payload = [1,2,3,4,5,6,7,8];
+    ///
+    /// //get some memory to store the result
+    /// let mut result = Vec::<u8>::with_capacity(builder.size(payload.len()));
+    ///
+    /// //serialize
+    /// builder.write(&mut result, &payload).unwrap();
+    /// ```
+    pub fn snap(self, snap: SnapHeader) -> PacketBuilderStep<SnapHeader> {
+        //use the method from the Ethernet2Header implementation
+        PacketBuilderStep {
+            state: self.state,
+            _marker: marker::PhantomData::<Ethernet2Header> {},
+        }
+        .snap(snap)
+    }
+
+    /// Adds an MPLS label stack entry (see
+    /// [`PacketBuilderStep<Ethernet2Header>::mpls`] for details).
+    ///
+    /// # Example
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use etherparse::{PacketBuilder, MplsLabelStackEntry};
+    /// #
+    /// let builder = PacketBuilder::
+    ///     ethernet2([1,2,3,4,5,6],     //source mac
+    ///               [7,8,9,10,11,12]) //destionation mac
+    ///     .single_vlan(0x123.try_into().unwrap()) // vlan identifier
+    ///     .mpls(MplsLabelStackEntry {
+    ///         label: 16.try_into().unwrap(),
+    ///         traffic_class: 0.try_into().unwrap(),
+    ///         bottom_of_stack: false, // will be overwritten during write
+    ///         ttl: 64,
+    ///     })
+    ///     .ipv6([11,12,13,14,15,16,17,18,19,10,21,22,23,24,25,26], //source ip
+    ///           [31,32,33,34,35,36,37,38,39,40,41,42,43,44,45,46], //desitionation ip
+    ///           47)                                              //time to life
+    ///     .udp(21,    //source port
+    ///          1234); //desitnation port
+    ///
+    /// //payload of the udp packet
+    /// let payload = [1,2,3,4,5,6,7,8];
+    ///
+    /// //get some memory to store the result
+    /// let mut result = Vec::<u8>::with_capacity(builder.size(payload.len()));
+    ///
+    /// //serialize
+    /// builder.write(&mut result, &payload).unwrap();
+    /// ```
+    pub fn mpls(self, entry: MplsLabelStackEntry) -> PacketBuilderStep<MplsLabelStackEntry> {
+        //use the method from the Ethernet2Header implementation
+        PacketBuilderStep {
+            state: self.state,
+            _marker: marker::PhantomData::<Ethernet2Header> {},
+        }
+        .mpls(entry)
+    }
+
+    /// Adds a PPPoE header (see [`PacketBuilderStep<Ethernet2Header>::pppoe`]
+    /// for details).
+    pub fn pppoe(self, pppoe: PppoeHeader) -> PacketBuilderStep<PppoeHeader> {
+        //use the method from the Ethernet2Header implementation
+        PacketBuilderStep {
+            state: self.state,
+            _marker: marker::PhantomData::<Ethernet2Header> {},
+        }
+        .pppoe(pppoe)
+    }
+
+    /// Adds a PPPoE session stage header with the given session id (see
+    /// [`PacketBuilderStep<Ethernet2Header>::pppoe_session`] for details).
+    ///
+    /// # Example
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use etherparse::PacketBuilder;
+    /// #
+    /// let builder = PacketBuilder::
+    ///     ethernet2([1,2,3,4,5,6],     //source mac
+    ///               [7,8,9,10,11,12]) //destionation mac
+    ///     .single_vlan(0x123.try_into().unwrap()) // vlan identifier
+    ///     .pppoe_session(0x1234) // session id
+    ///     .ipv6([11,12,13,14,15,16,17,18,19,10,21,22,23,24,25,26], //source ip
+    ///           [31,32,33,34,35,36,37,38,39,40,41,42,43,44,45,46], //desitionation ip
+    ///           47)                                              //time to life
+    ///     .udp(21,    //source port
+    ///          1234); //desitnation port
+    ///
+    /// //payload of the udp packet
+    /// let payload = [1,2,3,4,5,6,7,8];
+    ///
+    /// //get some memory to store the result
+    /// let mut result = Vec::<u8>::with_capacity(builder.size(payload.len()));
+    ///
+    /// //serialize
+    /// builder.write(&mut result, &payload).unwrap();
+    /// ```
+    pub fn pppoe_session(self, session_id: u16) -> PacketBuilderStep<PppoeHeader> {
+        //use the method from the Ethernet2Header implementation
+        PacketBuilderStep {
+            state: self.state,
+            _marker: marker::PhantomData::<Ethernet2Header> {},
+        }
+        .pppoe_session(session_id)
+    }
+}
+
+impl PacketBuilderStep<ArpHeader> {
+    /// Write all the headers and the ARP packet.
+    pub fn write<T: io::Write + Sized>(self, writer: &mut T) -> Result<(), BuildWriteError> {
+        final_write(self, writer, &[])
+    }
+
+    /// Returns the size of the packet when it is serialized
+    pub fn size(&self) -> usize {
+        final_size(self, 0)
+    }
+}
+
+impl PacketBuilderStep<LlcHeader> {
+    /// Write all the headers and the payload following the LLC header.
+    pub fn write<T: io::Write + Sized>(
+        self,
+        writer: &mut T,
+        payload: &[u8],
+    ) -> Result<(), BuildWriteError> {
+        final_write(self, writer, payload)
+    }
+
+    /// Returns the size of the packet when it is serialized
+    pub fn size(&self, payload_size: usize) -> usize {
+        final_size(self, payload_size)
+    }
+}
+
+impl PacketBuilderStep<SnapHeader> {
+    /// Add an IPv4 header (see [`PacketBuilderStep<Ethernet2Header>::ipv4`]
+    /// for details).
+    pub fn ipv4(
+        self,
+        source: [u8; 4],
+        destination: [u8; 4],
+        time_to_live: u8,
+    ) -> PacketBuilderStep<IpHeader> {
+        //use the method from the Ethernet2Header implementation
+        PacketBuilderStep {
+            state: self.state,
+            _marker: marker::PhantomData::<Ethernet2Header> {},
+        }
+        .ipv4(source, destination, time_to_live)
+    }
+
+    /// Add an IPv6 header (see [`PacketBuilderStep<Ethernet2Header>::ipv6`]
+    /// for details).
+    pub fn ipv6(
+        self,
+        source: [u8; 16],
+        destination: [u8; 16],
+        hop_limit: u8,
+    ) -> PacketBuilderStep<IpHeader> {
+        //use the method from the Ethernet2Header implementation
+        PacketBuilderStep {
+            state: self.state,
+            _marker: marker::PhantomData::<Ethernet2Header> {},
+        }
+        .ipv6(source, destination, hop_limit)
+    }
+
+    /// Add an IP header (see [`PacketBuilderStep<Ethernet2Header>::ip`]
+    /// for details).
+    pub fn ip(self, ip_header: IpHeader) -> PacketBuilderStep<IpHeader> {
+        //use the method from the Ethernet2Header implementation
+        PacketBuilderStep {
+            state: self.state,
+            _marker: marker::PhantomData::<Ethernet2Header> {},
+        }
+        .ip(ip_header)
+    }
+
+    /// Add an ARP packet (see [`PacketBuilderStep<Ethernet2Header>::arp`]
+    /// for details).
+    pub fn arp(self, arp: ArpHeader) -> PacketBuilderStep<ArpHeader> {
+        //use the method from the Ethernet2Header implementation
+        PacketBuilderStep {
+            state: self.state,
+            _marker: marker::PhantomData::<Ethernet2Header> {},
+        }
+        .arp(arp)
+    }
+
+    /// Write all the headers and the payload following the SNAP header
+    /// (the `ether_type` of the SNAP header is kept as given).
+    pub fn write<T: io::Write + Sized>(
+        self,
+        writer: &mut T,
+        payload: &[u8],
+    ) -> Result<(), BuildWriteError> {
+        final_write(self, writer, payload)
+    }
+
+    /// Returns the size of the packet when it is serialized
+    pub fn size(&self, payload_size: usize) -> usize {
+        final_size(self, payload_size)
+    }
+}
+
+impl PacketBuilderStep<MplsLabelStackEntry> {
+    /// Pushes an additional MPLS label stack entry below the previously
+    /// added entries.
+    ///
+    /// # Example
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use etherparse::{PacketBuilder, MplsLabelStackEntry};
+    /// #
+    /// let entry = MplsLabelStackEntry {
+    ///     label: 16.try_into().unwrap(),
+    ///     traffic_class: 0.try_into().unwrap(),
+    ///     bottom_of_stack: false, // will be overwritten during write
+    ///     ttl: 64,
+    /// };
+    /// let builder = PacketBuilder::
+    ///     ethernet2([1,2,3,4,5,6],     //source mac
+    ///               [7,8,9,10,11,12]) //destionation mac
+    ///     .mpls(entry.clone())
+    ///     .mpls(MplsLabelStackEntry {
+    ///         label: 17.try_into().unwrap(),
+    ///         ..entry
+    ///     })
+    ///     .ipv4([192,168,1,1], //source ip
+    ///           [192,168,1,2], //desitionation ip
+    ///           20)            //time to life
+    ///     .udp(21,    //source port
+    ///          1234); //desitnation port
+    ///
+    /// //payload of the udp packet
+    /// let payload = [1,2,3,4,5,6,7,8];
+    ///
+    /// //get some memory to store the result
+    /// let mut result = Vec::<u8>::with_capacity(builder.size(payload.len()));
+    ///
+    /// //serialize
+    /// builder.write(&mut result, &payload).unwrap();
+    /// ```
+    pub fn mpls(mut self, entry: MplsLabelStackEntry) -> PacketBuilderStep<MplsLabelStackEntry> {
+        self.state.mpls_entries.push(entry);
+        self
+    }
+
+    /// Add an IPv4 header (see [`PacketBuilderStep<Ethernet2Header>::ipv4`]
+    /// for details).
+    pub fn ipv4(
+        self,
+        source: [u8; 4],
+        destination: [u8; 4],
+        time_to_live: u8,
+    ) -> PacketBuilderStep<IpHeader> {
+        //use the method from the Ethernet2Header implementation
+        PacketBuilderStep {
+            state: self.state,
+            _marker: marker::PhantomData::<Ethernet2Header> {},
+        }
+        .ipv4(source, destination, time_to_live)
+    }
+
+    /// Add an IPv6 header (see [`PacketBuilderStep<Ethernet2Header>::ipv6`]
+    /// for details).
+    pub fn ipv6(
+        self,
+        source: [u8; 16],
+        destination: [u8; 16],
+        hop_limit: u8,
+    ) -> PacketBuilderStep<IpHeader> {
+        //use the method from the Ethernet2Header implementation
+        PacketBuilderStep {
+            state: self.state,
+            _marker: marker::PhantomData::<Ethernet2Header> {},
+        }
+        .ipv6(source, destination, hop_limit)
+    }
+
+    /// Add an IP header (see [`PacketBuilderStep<Ethernet2Header>::ip`]
+    /// for details).
+    pub fn ip(self, ip_header: IpHeader) -> PacketBuilderStep<IpHeader> {
+        //use the method from the Ethernet2Header implementation
+        PacketBuilderStep {
+            state: self.state,
+            _marker: marker::PhantomData::<Ethernet2Header> {},
+        }
+        .ip(ip_header)
+    }
+
+    /// Write all the headers and the payload following the MPLS label stack.
+    pub fn write<T: io::Write + Sized>(
+        self,
+        writer: &mut T,
+        payload: &[u8],
+    ) -> Result<(), BuildWriteError> {
+        final_write(self, writer, payload)
+    }
+
+    /// Returns the size of the packet when it is serialized
+    pub fn size(&self, payload_size: usize) -> usize {
+        final_size(self, payload_size)
+    }
+}
+
+impl PacketBuilderStep<PppoeHeader> {
+    /// Adds a PPP header and turns the packet into a PPPoE session stage
+    /// packet (the code of the PPPoE header is set to
+    /// [`PppoeCode::SESSION_DATA`]).
+    ///
+    /// The `protocol` field of the PPP header is overwritten in case an
+    /// IP header is added after the PPP header.
+    ///
+    /// # Example
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use etherparse::{PacketBuilder, PppHeader, PppProtocol};
+    /// #
+    /// let builder = PacketBuilder::
+    ///     ethernet2([1,2,3,4,5,6],     //source mac
+    ///               [7,8,9,10,11,12]) //destionation mac
+    ///     .pppoe_session(0x1234) // session id
+    ///     .ppp(PppHeader {
+    ///         protocol: PppProtocol::LCP,
+    ///     });
+    ///
+    /// //LCP packet
+    /// let payload = [1,2,0,4];
+    ///
+    /// //get some memory to store the result
+    /// let mut result = Vec::<u8>::with_capacity(builder.size(payload.len()));
+    ///
+    /// //serialize
+    /// builder.write(&mut result, &payload).unwrap();
+    /// ```
+    pub fn ppp(mut self, ppp: PppHeader) -> PacketBuilderStep<PppHeader> {
+        if let Some(pppoe) = self.state.pppoe_header.as_mut() {
+            pppoe.code = PppoeCode::SESSION_DATA;
+        }
+        self.state.ppp_header = Some(ppp);
+        //return for next step
+        PacketBuilderStep {
+            state: self.state,
+            _marker: marker::PhantomData::<PppHeader> {},
+        }
+    }
+
+    /// Add an IPv4 header (see [`PacketBuilderStep<Ethernet2Header>::ipv4`]
+    /// for details). A PPP header identifying the IPv4 payload gets added
+    /// automatically.
+    pub fn ipv4(
+        self,
+        source: [u8; 4],
+        destination: [u8; 4],
+        time_to_live: u8,
+    ) -> PacketBuilderStep<IpHeader> {
+        self.ppp(Default::default())
+            .ipv4(source, destination, time_to_live)
+    }
+
+    /// Add an IPv6 header (see [`PacketBuilderStep<Ethernet2Header>::ipv6`]
+    /// for details). A PPP header identifying the IPv6 payload gets added
+    /// automatically.
+    pub fn ipv6(
+        self,
+        source: [u8; 16],
+        destination: [u8; 16],
+        hop_limit: u8,
+    ) -> PacketBuilderStep<IpHeader> {
+        self.ppp(Default::default())
+            .ipv6(source, destination, hop_limit)
+    }
+
+    /// Add an IP header (see [`PacketBuilderStep<Ethernet2Header>::ip`]
+    /// for details). A PPP header identifying the IP payload gets added
+    /// automatically.
+    pub fn ip(self, ip_header: IpHeader) -> PacketBuilderStep<IpHeader> {
+        self.ppp(Default::default()).ip(ip_header)
+    }
+
+    /// Write all the headers and the payload following the PPPoE header
+    /// (e.g. the tags of a discovery stage packet).
+    pub fn write<T: io::Write + Sized>(
+        self,
+        writer: &mut T,
+        payload: &[u8],
+    ) -> Result<(), BuildWriteError> {
+        final_write(self, writer, payload)
+    }
+
+    /// Returns the size of the packet when it is serialized
+    pub fn size(&self, payload_size: usize) -> usize {
+        final_size(self, payload_size)
+    }
+}
+
+impl PacketBuilderStep<PppHeader> {
+    /// Add an IPv4 header (see [`PacketBuilderStep<Ethernet2Header>::ipv4`]
+    /// for details).
+    pub fn ipv4(
+        self,
+        source: [u8; 4],
+        destination: [u8; 4],
+        time_to_live: u8,
+    ) -> PacketBuilderStep<IpHeader> {
+        //use the method from the Ethernet2Header implementation
+        PacketBuilderStep {
+            state: self.state,
+            _marker: marker::PhantomData::<Ethernet2Header> {},
+        }
+        .ipv4(source, destination, time_to_live)
+    }
+
+    /// Add an IPv6 header (see [`PacketBuilderStep<Ethernet2Header>::ipv6`]
+    /// for details).
+    pub fn ipv6(
+        self,
+        source: [u8; 16],
+        destination: [u8; 16],
+        hop_limit: u8,
+    ) -> PacketBuilderStep<IpHeader> {
+        //use the method from the Ethernet2Header implementation
+        PacketBuilderStep {
+            state: self.state,
+            _marker: marker::PhantomData::<Ethernet2Header> {},
+        }
+        .ipv6(source, destination, hop_limit)
+    }
+
+    /// Add an IP header (see [`PacketBuilderStep<Ethernet2Header>::ip`]
+    /// for details).
+    pub fn ip(self, ip_header: IpHeader) -> PacketBuilderStep<IpHeader> {
+        //use the method from the Ethernet2Header implementation
+        PacketBuilderStep {
+            state: self.state,
+            _marker: marker::PhantomData::<Ethernet2Header> {},
+        }
+        .ip(ip_header)
+    }
+
+    /// Write all the headers and the payload following the PPP header
+    /// (the `protocol` of the PPP header is kept as given).
+    pub fn write<T: io::Write + Sized>(
+        self,
+        writer: &mut T,
+        payload: &[u8],
+    ) -> Result<(), BuildWriteError> {
+        final_write(self, writer, payload)
+    }
+
+    /// Returns the size of the packet when it is serialized
+    pub fn size(&self, payload_size: usize) -> usize {
+        final_size(self, payload_size)
+    }
+}
+
+impl PacketBuilderStep<IpHeader> {
+    /// Encapsulates the following packet in an IPv4 tunnel by adding
+    /// an inner IPv4 header (IP-in-IP or 4in6).
+    ///
+    /// The protocol number/next header & length fields of the outer
+    /// IP header get set automatically during the write.
+    ///
+    /// # Example
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use etherparse::PacketBuilder;
+    /// #
+    /// let builder = PacketBuilder::
+    ///     ethernet2([1,2,3,4,5,6],     //source mac
+    ///               [7,8,9,10,11,12]) //destionation mac
+    ///     .ipv6([11;16], //source ip of the tunnel
+    ///           [12;16], //destination ip of the tunnel
+    ///           20)      //hop limit
+    ///     .ipv4([192,168,1,1], //inner source ip
+    ///           [192,168,1,2], //inner desitionation ip
+    ///           20)            //time to life
+    ///     .udp(21,    //source port
+    ///          1234); //desitnation port
+    ///
+    /// //payload of the udp packet
+    /// let payload = [1,2,3,4,5,6,7,8];
+    ///
+    /// //get some memory to store the result
+    /// let mut result = Vec::<u8>::with_capacity(
+    ///                     builder.size(payload.len()));
+    ///
+    /// //serialize
+    /// builder.write(&mut result, &payload).unwrap();
+    /// ```
+    pub fn ipv4(
+        self,
+        source: [u8; 4],
+        destination: [u8; 4],
+        time_to_live: u8,
+    ) -> PacketBuilderStep<IpHeader> {
+        self.tunnel().ipv4(source, destination, time_to_live)
+    }
+
+    /// Encapsulates the following packet in an IPv6 tunnel by adding
+    /// an inner IPv6 header (6in4 or IPv6-in-IPv6).
+    ///
+    /// The protocol number/next header & length fields of the outer
+    /// IP header get set automatically during the write.
+    ///
+    /// # Example
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use etherparse::PacketBuilder;
+    /// #
+    /// let builder = PacketBuilder::
+    ///     ipv4([192,168,1,1], //source ip of the tunnel
+    ///          [192,168,1,2], //destination ip of the tunnel
+    ///          20)            //time to life
+    ///     .ipv6([11;16], //inner source ip
+    ///           [12;16], //inner destination ip
+    ///           20)      //hop limit
+    ///     .udp(21,    //source port
+    ///          1234); //desitnation port
+    ///
+    /// //payload of the udp packet
+    /// let payload = [1,2,3,4,5,6,7,8];
+    ///
+    /// //get some memory to store the result
+    /// let mut result = Vec::<u8>::with_capacity(
+    ///                     builder.size(payload.len()));
+    ///
+    /// //serialize
+    /// builder.write(&mut result, &payload).unwrap();
+    /// ```
+    pub fn ipv6(
+        self,
+        source: [u8; 16],
+        destination: [u8; 16],
+        hop_limit: u8,
+    ) -> PacketBuilderStep<IpHeader> {
+        self.tunnel().ipv6(source, destination, hop_limit)
+    }
+
+    /// Encapsulates the following packet in an IP tunnel by adding the
+    /// given inner IP header (length, protocol/next_header & checksum
+    /// fields will be overwritten based on the rest of the packet).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use etherparse::*;
+    /// #
+    /// let builder = PacketBuilder::
+    ///     ipv4([192,168,1,1], [192,168,1,2], 20)
+    ///     .ip(IpHeader::Version4(
+    ///         Ipv4Header::new(
+    ///             0, //payload_len will be replaced during write
+    ///             12, //time_to_live
+    ///             ip_number::UDP, //will be replaced during write
+    ///             [0,1,2,3], //source
+    ///             [4,5,6,7] //destination
+    ///         ).unwrap(),
+    ///         Default::default()))
+    ///     .udp(21, 1234);
+    /// ```
+    pub fn ip(self, ip_header: IpHeader) -> PacketBuilderStep<IpHeader> {
+        self.tunnel().ip(ip_header)
+    }
+
+    /// Moves the current ip header (and the GRE header following it) to
+    /// the outer ip headers so a new inner ip header can be added.
+    fn tunnel(mut self) -> PacketBuilderStep<Ethernet2Header> {
+        if let Some(ip_header) = self.state.ip_header.take() {
+            self.state
+                .outer_ip_headers
+                .push((ip_header, self.state.gre_header.take()));
+        }
+        //use the methods from the Ethernet2Header implementation
+        PacketBuilderStep {
+            state: self.state,
+            _marker: marker::PhantomData::<Ethernet2Header> {},
+        }
+    }
+
+    /// Adds a GRE header (version 0 without optional fields) to the
+    /// packet.
+    ///
+    /// The protocol type of the GRE header gets set automatically if an
+    /// inner IP header follows. Alternatively the protocol type can be
+    /// passed to the `write` call together with an already serialized
+    /// payload (e.g. an ethernet frame with
+    /// [`ether_type::TRANSPARENT_ETHERNET_BRIDGING`]).
+    ///
+    /// # Example
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use etherparse::PacketBuilder;
+    /// #
+    /// let builder = PacketBuilder::
+    ///     ipv4([192,168,1,1], //source ip of the tunnel
+    ///          [192,168,1,2], //destination ip of the tunnel
+    ///          20)            //time to life
+    ///     .gre()
+    ///     .ipv6([11;16], //inner source ip
+    ///           [12;16], //inner destination ip
+    ///           20)      //hop limit
+    ///     .udp(21,    //source port
+    ///          1234); //desitnation port
+    ///
+    /// //payload of the udp packet
+    /// let payload = [1,2,3,4,5,6,7,8];
+    ///
+    /// //get some memory to store the result
+    /// let mut result = Vec::<u8>::with_capacity(
+    ///                     builder.size(payload.len()));
+    ///
+    /// //serialize
+    /// builder.write(&mut result, &payload).unwrap();
+    /// ```
+    pub fn gre(self) -> PacketBuilderStep<GreHeader> {
+        self.gre_header(Default::default())
+    }
+
+    /// Adds the given GRE header to the packet.
+    ///
+    /// The protocol type gets set automatically if an inner IP header
+    /// follows and the checksum gets calculated during the write if the
+    /// `checksum` field is set to `Some` (the value itself is ignored).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use etherparse::*;
+    /// #
+    /// let builder = PacketBuilder::
+    ///     ipv4([192,168,1,1], [192,168,1,2], 20)
+    ///     .gre_header(GreHeader {
+    ///         checksum: Some(0), // will be calculated during write
+    ///         key: Some(1234),
+    ///         ..Default::default()
+    ///     })
+    ///     .ipv4([10,0,0,1], [10,0,0,2], 20)
+    ///     .udp(21, 1234);
+    /// ```
+    pub fn gre_header(mut self, gre: GreHeader) -> PacketBuilderStep<GreHeader> {
+        self.state.gre_header = Some(gre);
+        PacketBuilderStep {
+            state: self.state,
+            _marker: marker::PhantomData::<GreHeader> {},
+        }
+    }
+
+    /// Adds an ICMPv4 header of the given [`Icmpv4Type`] to the packet.
+    ///
+    /// If an ICMPv4 header gets added the payload used during the builders `write`
+    /// call contains the bytes after the header and has different meanings
+    /// and contents based on the type. Ususally all statically sized values
+    /// known based on the ICMPv4 type & code are part of the header and the
+    /// payload is used to store contains the dynamic parts of the ICMPv4 packet.
+    ///
+    /// Check [`Icmpv4Type`] for a documentation which values are part of the
+    /// header and what is stored as part of the payload.
+    ///
+    /// # Example
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use etherparse::{PacketBuilder, Icmpv4Type, icmpv4};
+    /// #
+    /// let builder = PacketBuilder::
+    ///    ipv4([192,168,1,1],  //source ip
+    ///          [192,168,1,2], //desitionation ip
+    ///          20)            //time to life
+    ///    .icmpv4(
+    ///         Icmpv4Type::TimeExceeded(
+    ///             icmpv4::TimeExceededCode::TtlExceededInTransit
+    ///         )
+    ///     );
+    ///
+    /// // what is part of the payload depends on the Icmpv4Type
+    /// //
+    /// // In case of `Icmpv4Type::TimeExceeded` the "internet header
+    /// // + 64 bits of the original data datagram" should be given as
+    /// // the payload
+    /// let payload = [1,2,3,4,5,6,7,8];
+    ///
+    /// //get some memory to store the result
+    /// let mut result = Vec::<u8>::with_capacity(
+    ///                     builder.size(payload.len()));
+    ///
+    /// //serialize
+    /// builder.write(&mut result, &payload).unwrap();
+    /// ```
+    pub fn icmpv4(mut self, icmp_type: Icmpv4Type) -> PacketBuilderStep<Icmpv4Header> {
+        self.state.transport_header = Some(TransportHeader::Icmpv4(Icmpv4Header {
+            icmp_type,
+            checksum: 0, // calculated later
+        }));
+        //return for next step
+        PacketBuilderStep {
+            state: self.state,
+            _marker: marker::PhantomData::<Icmpv4Header> {},
+        }
+    }
+
+    /// Adds an ICMPv4 header based on raw numbers.
+    ///
+    /// This can be usefull when trying to build an ICMPv4 packet
+    /// which is not fully supported by etherparse and is the equivalent
+    /// of using [`Icmpv4Type::Unknown`] together with
+    /// [`PacketBuilderStep<IpHeader>::icmpv4`].
     ///
     /// # Example
     ///
@@ -1269,7 +2745,7 @@ impl PacketBuilderStep<IpHeader> {
     /// ```
     /// # use etherparse::PacketBuilder;
     /// use etherparse::TcpHeader;
-    /// 
+    ///
     /// let mut tcp_header = TcpHeader::new(
     ///     21,     // source port
     ///     12,     // destination port
@@ -1279,7 +2755,7 @@ impl PacketBuilderStep<IpHeader> {
     /// tcp_header.psh = true;
     /// tcp_header.ack = true;
     /// tcp_header.acknowledgment_number = 1;
-    /// 
+    ///
     /// let builder = PacketBuilder::
     ///     ethernet2([1,2,3,4,5,6],     // source mac
     ///               [7,8,9,10,11,12]) // destionation mac
@@ -1298,34 +2774,114 @@ impl PacketBuilderStep<IpHeader> {
     /// //serialize
     /// builder.write(&mut result, &payload).unwrap();
     /// ```
-    pub fn tcp_header(
-        mut self,
-        tcp_header: TcpHeader,
-    ) -> PacketBuilderStep<TcpHeader> {
+    pub fn tcp_header(mut self, tcp_header: TcpHeader) -> PacketBuilderStep<TcpHeader> {
         self.state.transport_header = Some(TransportHeader::Tcp(tcp_header));
         //return for next step
         PacketBuilderStep {
             state: self.state,
-            _marker: marker::PhantomData::<TcpHeader> {},
+            _marker: marker::PhantomData::<TcpHeader> {},
+        }
+    }
+
+    /// Write all the headers and the payload with the given ip number.
+    ///
+    /// `last_next_header_ip_number` will be set in the last extension header
+    /// or if no extension header exists the ip header as the "next header" or
+    /// "protocol number".
+    pub fn write<T: io::Write + Sized>(
+        mut self,
+        writer: &mut T,
+        last_next_header_ip_number: IpNumber,
+        payload: &[u8],
+    ) -> Result<(), BuildWriteError> {
+        self.state
+            .ip_header
+            .as_mut()
+            .unwrap()
+            .set_next_headers(last_next_header_ip_number);
+        final_write(self, writer, payload)
+    }
+
+    /// Builds the packet with the given ip number and splits it into
+    /// multiple frames containing IP fragments that fit into the MTU of
+    /// the `fragmenter` (see [`PacketBuilderStep<UdpHeader>::build_fragments`]).
+    ///
+    /// `last_next_header_ip_number` will be set in the last extension header
+    /// or if no extension header exists the ip header as the "next header" or
+    /// "protocol number".
+    pub fn build_fragments(
+        mut self,
+        fragmenter: IpFragmenter,
+        last_next_header_ip_number: IpNumber,
+        payload: &[u8],
+    ) -> Result<Vec<Vec<u8>>, BuildWriteError> {
+        self.state
+            .ip_header
+            .as_mut()
+            .unwrap()
+            .set_next_headers(last_next_header_ip_number);
+        final_build_fragments(self, fragmenter, payload)
+    }
+
+    ///Returns the size of the packet when it is serialized
+    pub fn size(&self, payload_size: usize) -> usize {
+        final_size(self, payload_size)
+    }
+}
+
+impl PacketBuilderStep<GreHeader> {
+    /// Adds an IPv4 header encapsulated by the GRE header (see
+    /// [`PacketBuilderStep<IpHeader>::ipv4`]).
+    pub fn ipv4(
+        self,
+        source: [u8; 4],
+        destination: [u8; 4],
+        time_to_live: u8,
+    ) -> PacketBuilderStep<IpHeader> {
+        self.ip_step().ipv4(source, destination, time_to_live)
+    }
+
+    /// Adds an IPv6 header encapsulated by the GRE header (see
+    /// [`PacketBuilderStep<IpHeader>::ipv6`]).
+    pub fn ipv6(
+        self,
+        source: [u8; 16],
+        destination: [u8; 16],
+        hop_limit: u8,
+    ) -> PacketBuilderStep<IpHeader> {
+        self.ip_step().ipv6(source, destination, hop_limit)
+    }
+
+    /// Adds the given IP header encapsulated by the GRE header (see
+    /// [`PacketBuilderStep<IpHeader>::ip`]).
+    pub fn ip(self, ip_header: IpHeader) -> PacketBuilderStep<IpHeader> {
+        self.ip_step().ip(ip_header)
+    }
+
+    /// Returns to the ip step so an inner ip header can be added via
+    /// the tunnel methods.
+    fn ip_step(self) -> PacketBuilderStep<IpHeader> {
+        PacketBuilderStep {
+            state: self.state,
+            _marker: marker::PhantomData::<IpHeader> {},
         }
     }
 
-    /// Write all the headers and the payload with the given ip number.
-    ///
-    /// `last_next_header_ip_number` will be set in the last extension header
-    /// or if no extension header exists the ip header as the "next header" or
-    /// "protocol number".
+    /// Write all the headers and the payload with the given GRE protocol
+    /// type (e.g. [`ether_type::TRANSPARENT_ETHERNET_BRIDGING`] if the
+    /// payload contains an ethernet frame).
     pub fn write<T: io::Write + Sized>(
         mut self,
         writer: &mut T,
-        last_next_header_ip_number: IpNumber,
+        protocol_type: EtherType,
         payload: &[u8],
     ) -> Result<(), BuildWriteError> {
         self.state
             .ip_header
             .as_mut()
             .unwrap()
-            .set_next_headers(last_next_header_ip_number);
+            .set_next_headers(ip_number::GRE);
+        self.state.gre_header.as_mut().unwrap().protocol_type = protocol_type;
         final_write(self, writer, payload)
     }
 
@@ -1349,6 +2905,17 @@ impl PacketBuilderStep<Icmpv4Header> {
     pub fn size(&self, payload_size: usize) -> usize {
         final_size(self, payload_size)
     }
+
+    /// Builds the packet and splits it into multiple frames containing
+    /// IP fragments that fit into the MTU of the `fragmenter` (see
+    /// [`PacketBuilderStep<UdpHeader>::build_fragments`]).
+    pub fn build_fragments(
+        self,
+        fragmenter: IpFragmenter,
+        payload: &[u8],
+    ) -> Result<Vec<Vec<u8>>, BuildWriteError> {
+        final_build_fragments(self, fragmenter, payload)
+    }
 }
 
 impl PacketBuilderStep<Icmpv6Header> {
@@ -1365,6 +2932,17 @@ impl PacketBuilderStep<Icmpv6Header> {
     pub fn size(&self, payload_size: usize) -> usize {
         final_size(self, payload_size)
     }
+
+    /// Builds the packet and splits it into multiple frames containing
+    /// IP fragments that fit into the MTU of the `fragmenter` (see
+    /// [`PacketBuilderStep<UdpHeader>::build_fragments`]).
+    pub fn build_fragments(
+        self,
+        fragmenter: IpFragmenter,
+        payload: &[u8],
+    ) -> Result<Vec<Vec<u8>>, BuildWriteError> {
+        final_build_fragments(self, fragmenter, payload)
+    }
 }
 
 impl PacketBuilderStep<UdpHeader> {
@@ -1381,6 +2959,253 @@ impl PacketBuilderStep<UdpHeader> {
     pub fn size(&self, payload_size: usize) -> usize {
         final_size(self, payload_size)
     }
+
+    /// Builds the packet and splits it into multiple frames containing
+    /// IP fragments that fit into the MTU of the `fragmenter`.
+    ///
+    /// The checksums & lengths of the transport layer are calculated over
+    /// the complete payload before the IP packet gets split. The link
+    /// layer headers (Ethernet II, VLAN, MPLS, ...) are repeated in every
+    /// frame. If the packet fits into the MTU a single unfragmented frame
+    /// is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use etherparse::{frag::IpFragmenter, PacketBuilder};
+    ///
+    /// let builder = PacketBuilder::
+    ///     ethernet2([1,2,3,4,5,6], [7,8,9,10,11,12])
+    ///    .ipv4([192,168,1,1], [192,168,1,2], 20)
+    ///    .udp(21, 1234);
+    ///
+    /// let frames = builder.build_fragments(
+    ///     IpFragmenter::new(1500, 1234),
+    ///     &[0u8; 4000]
+    /// ).unwrap();
+    /// assert_eq!(3, frames.len());
+    /// ```
+    pub fn build_fragments(
+        self,
+        fragmenter: IpFragmenter,
+        payload: &[u8],
+    ) -> Result<Vec<Vec<u8>>, BuildWriteError> {
+        final_build_fragments(self, fragmenter, payload)
+    }
+
+    /// Adds a VXLAN header with the given VNI. The payload passed to
+    /// [`PacketBuilderStep<VxlanHeader>::write`] is the encapsulated
+    /// ethernet frame.
+    ///
+    /// Note that the UDP destination port is not modified (VXLAN uses
+    /// the port [`VxlanHeader::UDP_PORT`]).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use etherparse::{PacketBuilder, VxlanHeader, Vni};
+    ///
+    /// let builder = PacketBuilder::
+    ///     ipv4([192,168,1,1], [192,168,1,2], 20)
+    ///     .udp(49152, VxlanHeader::UDP_PORT)
+    ///     .vxlan(Vni::try_new(1234).unwrap());
+    ///
+    /// // encapsulated ethernet frame
+    /// let inner_frame = [0u8; 64];
+    ///
+    /// let mut result = Vec::<u8>::with_capacity(builder.size(inner_frame.len()));
+    /// builder.write(&mut result, &inner_frame).unwrap();
+    /// ```
+    pub fn vxlan(self, vni: Vni) -> PacketBuilderStep<VxlanHeader> {
+        self.vxlan_header(VxlanHeader::new(vni))
+    }
+
+    /// Adds the given VXLAN or VXLAN-GPE header (see
+    /// [`PacketBuilderStep<UdpHeader>::vxlan`]).
+    pub fn vxlan_header(mut self, vxlan: VxlanHeader) -> PacketBuilderStep<VxlanHeader> {
+        self.state.vxlan_header = Some(vxlan);
+        //return for next step
+        PacketBuilderStep {
+            state: self.state,
+            _marker: marker::PhantomData::<VxlanHeader> {},
+        }
+    }
+
+    /// Adds a Geneve header (without options) with the given VNI and the
+    /// protocol type [`ether_type::TRANSPARENT_ETHERNET_BRIDGING`]. The
+    /// payload passed to [`PacketBuilderStep<GeneveHeader>::write`] is the
+    /// encapsulated ethernet frame.
+    ///
+    /// Note that the UDP destination port is not modified (Geneve uses
+    /// the port [`GeneveHeader::UDP_PORT`]).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use etherparse::{PacketBuilder, GeneveHeader, Vni};
+    ///
+    /// let builder = PacketBuilder::
+    ///     ipv6([1;16], [2;16], 20)
+    ///     .udp(49152, GeneveHeader::UDP_PORT)
+    ///     .geneve(Vni::try_new(1234).unwrap());
+    ///
+    /// // encapsulated ethernet frame
+    /// let inner_frame = [0u8; 64];
+    ///
+    /// let mut result = Vec::<u8>::with_capacity(builder.size(inner_frame.len()));
+    /// builder.write(&mut result, &inner_frame).unwrap();
+    /// ```
+    pub fn geneve(self, vni: Vni) -> PacketBuilderStep<GeneveHeader> {
+        self.geneve_header(GeneveHeader::new(
+            ether_type::TRANSPARENT_ETHERNET_BRIDGING,
+            vni,
+        ))
+    }
+
+    /// Adds the given Geneve header (see [`PacketBuilderStep<UdpHeader>::geneve`]).
+    pub fn geneve_header(mut self, geneve: GeneveHeader) -> PacketBuilderStep<GeneveHeader> {
+        self.state.geneve_header = Some(geneve);
+        //return for next step
+        PacketBuilderStep {
+            state: self.state,
+            _marker: marker::PhantomData::<GeneveHeader> {},
+        }
+    }
+
+    /// Adds a G-PDU GTP-U header (without optional fields) with the given
+    /// tunnel endpoint identifier (TEID). The payload passed to
+    /// [`PacketBuilderStep<GtpuHeader>::write`] is the encapsulated IP
+    /// packet and the length field of the GTP-U header is set based on it.
+    ///
+    /// Note that the UDP destination port is not modified (GTP-U uses
+    /// the port [`GtpuHeader::UDP_PORT`]).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use etherparse::{PacketBuilder, GtpuHeader};
+    ///
+    /// let builder = PacketBuilder::
+    ///     ipv4([192,168,1,1], [192,168,1,2], 20)
+    ///     .udp(GtpuHeader::UDP_PORT, GtpuHeader::UDP_PORT)
+    ///     .gtpu(0x1234_5678);
+    ///
+    /// // encapsulated ip packet
+    /// let inner_packet = [0u8; 64];
+    ///
+    /// let mut result = Vec::<u8>::with_capacity(builder.size(inner_packet.len()));
+    /// builder.write(&mut result, &inner_packet).unwrap();
+    /// ```
+    pub fn gtpu(self, teid: u32) -> PacketBuilderStep<GtpuHeader> {
+        self.gtpu_header(GtpuHeader::new(teid))
+    }
+
+    /// Adds the given GTP-U header (see [`PacketBuilderStep<UdpHeader>::gtpu`]).
+    ///
+    /// The length field of the header is overwritten when the packet is
+    /// written.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use etherparse::{
+    ///     PacketBuilder, GtpuExtensionHeaders, GtpuHeader, GtpuPduSessionContainer
+    /// };
+    ///
+    /// let container = GtpuPduSessionContainer::try_new(
+    ///     GtpuPduSessionContainer::PDU_TYPE_UL,
+    ///     9
+    /// ).unwrap();
+    /// let builder = PacketBuilder::
+    ///     ipv6([1;16], [2;16], 20)
+    ///     .udp(GtpuHeader::UDP_PORT, GtpuHeader::UDP_PORT)
+    ///     .gtpu_header(GtpuHeader {
+    ///         extension_headers: GtpuExtensionHeaders::from_pdu_session_container(container),
+    ///         ..GtpuHeader::new(0x1234_5678)
+    ///     });
+    ///
+    /// // encapsulated ip packet
+    /// let inner_packet = [0u8; 64];
+    ///
+    /// let mut result = Vec::<u8>::with_capacity(builder.size(inner_packet.len()));
+    /// builder.write(&mut result, &inner_packet).unwrap();
+    /// ```
+    pub fn gtpu_header(mut self, gtpu: GtpuHeader) -> PacketBuilderStep<GtpuHeader> {
+        self.state.gtpu_header = Some(gtpu);
+        //return for next step
+        PacketBuilderStep {
+            state: self.state,
+            _marker: marker::PhantomData::<GtpuHeader> {},
+        }
+    }
+}
+
+impl PacketBuilderStep<VxlanHeader> {
+    /// Write all the headers and the encapsulated frame.
+    pub fn write<T: io::Write + Sized>(
+        mut self,
+        writer: &mut T,
+        inner_frame: &[u8],
+    ) -> Result<(), BuildWriteError> {
+        let vxlan = self.state.vxlan_header.take().unwrap();
+        let mut payload = Vec::with_capacity(vxlan.header_len() + inner_frame.len());
+        payload.extend_from_slice(&vxlan.to_bytes());
+        payload.extend_from_slice(inner_frame);
+        final_write(self, writer, &payload)
+    }
+
+    /// Returns the size of the packet when it is serialized
+    pub fn size(&self, inner_frame_size: usize) -> usize {
+        final_size(self, VxlanHeader::LEN + inner_frame_size)
+    }
+}
+
+impl PacketBuilderStep<GeneveHeader> {
+    /// Write all the headers and the encapsulated frame.
+    pub fn write<T: io::Write + Sized>(
+        mut self,
+        writer: &mut T,
+        inner_frame: &[u8],
+    ) -> Result<(), BuildWriteError> {
+        let geneve = self.state.geneve_header.take().unwrap();
+        let mut payload = Vec::with_capacity(geneve.header_len() + inner_frame.len());
+        payload.extend_from_slice(&geneve.to_bytes());
+        payload.extend_from_slice(inner_frame);
+        final_write(self, writer, &payload)
+    }
+
+    /// Returns the size of the packet when it is serialized
+    pub fn size(&self, inner_frame_size: usize) -> usize {
+        final_size(
+            self,
+            self.state.geneve_header.as_ref().unwrap().header_len() + inner_frame_size,
+        )
+    }
+}
+
+impl PacketBuilderStep<GtpuHeader> {
+    /// Write all the headers and the encapsulated packet.
+    pub fn write<T: io::Write + Sized>(
+        mut self,
+        writer: &mut T,
+        inner_packet: &[u8],
+    ) -> Result<(), BuildWriteError> {
+        let mut gtpu = self.state.gtpu_header.take().unwrap();
+        gtpu.set_payload_len(inner_packet.len())
+            .map_err(BuildWriteError::PayloadLen)?;
+        let mut payload = Vec::with_capacity(gtpu.header_len() + inner_packet.len());
+        payload.extend_from_slice(&gtpu.to_bytes());
+        payload.extend_from_slice(inner_packet);
+        final_write(self, writer, &payload)
+    }
+
+    /// Returns the size of the packet when it is serialized
+    pub fn size(&self, inner_packet_size: usize) -> usize {
+        final_size(
+            self,
+            self.state.gtpu_header.as_ref().unwrap().header_len() + inner_packet_size,
+        )
+    }
 }
 
 impl PacketBuilderStep<TcpHeader> {
@@ -1537,6 +3362,17 @@ impl PacketBuilderStep<TcpHeader> {
         final_write(self, writer, payload)
     }
 
+    /// Builds the packet and splits it into multiple frames containing
+    /// IP fragments that fit into the MTU of the `fragmenter` (see
+    /// [`PacketBuilderStep<UdpHeader>::build_fragments`]).
+    pub fn build_fragments(
+        self,
+        fragmenter: IpFragmenter,
+        payload: &[u8],
+    ) -> Result<Vec<Vec<u8>>, BuildWriteError> {
+        final_build_fragments(self, fragmenter, payload)
+    }
+
     ///Returns the size of the packet when it is serialized
     pub fn size(&self, payload_size: usize) -> usize {
         final_size(self, payload_size)
@@ -1548,16 +3384,235 @@ fn final_write<T: io::Write + Sized, B>(
     builder: PacketBuilderStep<B>,
     writer: &mut T,
     payload: &[u8],
+) -> Result<(), BuildWriteError> {
+    if builder.state.fcs {
+        //frames shorter then the minimum frame size get padded with zeros
+        let padding_len = Ethernet2Header::MIN_FRAME_LEN
+            .saturating_sub(final_frame_size(&builder, payload.len()));
+
+        //calculate the crc while writing the frame & append it afterwards
+        let mut crc_writer = Crc32Writer {
+            writer,
+            crc: checksum::Crc32::new(),
+        };
+        final_write_frame(builder, &mut crc_writer, payload)?;
+        io::Write::write_all(
+            &mut crc_writer,
+            &[0u8; Ethernet2Header::MIN_FRAME_LEN][..padding_len],
+        )
+        .map_err(BuildWriteError::Io)?;
+        let fcs = crc_writer.crc.value().to_le_bytes();
+        crc_writer
+            .writer
+            .write_all(&fcs)
+            .map_err(BuildWriteError::Io)
+    } else {
+        final_write_frame(builder, writer, payload)
+    }
+}
+
+/// Builds the packet and splits it into frames containing IP fragments.
+fn final_build_fragments<B>(
+    builder: PacketBuilderStep<B>,
+    fragmenter: IpFragmenter,
+    payload: &[u8],
+) -> Result<Vec<Vec<u8>>, BuildWriteError> {
+    let mut link_state = builder.state;
+
+    // serialize the ip packet without the link layer headers (in case
+    // of ip tunnels the outermost ip header gets fragmented)
+    let is_ipv4 = matches!(
+        link_state
+            .outer_ip_headers
+            .first()
+            .map(|(ip, _)| ip)
+            .or(link_state.ip_header.as_ref()),
+        Some(IpHeader::Version4(_, _))
+    );
+    let mut ip_packet = Vec::new();
+    final_write_frame(
+        PacketBuilderStep::<B> {
+            state: PacketImpl {
+                ethernet2_header: None,
+                ip_header: link_state.ip_header.take(),
+                vlan_header: None,
+                llc_header: None,
+                snap_header: None,
+                mpls_entries: Vec::new(),
+                outer_ip_headers: core::mem::take(&mut link_state.outer_ip_headers),
+                gre_header: link_state.gre_header.take(),
+                pppoe_header: None,
+                ppp_header: None,
+                arp_header: None,
+                transport_header: link_state.transport_header.take(),
+                vxlan_header: None,
+                geneve_header: None,
+                gtpu_header: None,
+                fcs: false,
+            },
+            _marker: marker::PhantomData::<B> {},
+        },
+        &mut ip_packet,
+        payload,
+    )?;
+
+    // split the ip packet
+    let fragments = if is_ipv4 {
+        let (header, rest) =
+            Ipv4Header::from_slice(&ip_packet).expect("serialized IPv4 header is valid");
+        fragmenter.fragment_ipv4(&header, rest)
+    } else {
+        let (header, rest) =
+            Ipv6Header::from_slice(&ip_packet).expect("serialized IPv6 header is valid");
+        fragmenter.fragment_ipv6(&header, rest)
+    }
+    .map_err(BuildWriteError::IpFrag)?;
+
+    // add the link layer headers to each fragment
+    let mut result = Vec::with_capacity(fragments.len());
+    for fragment in fragments {
+        let mut state = link_state.clone();
+        let ip_payload = if is_ipv4 {
+            let (header, rest) =
+                Ipv4Header::from_slice(&fragment).expect("fragment IPv4 header is valid");
+            state.ip_header = Some(IpHeader::Version4(header, Default::default()));
+            rest
+        } else {
+            let (header, rest) =
+                Ipv6Header::from_slice(&fragment).expect("fragment IPv6 header is valid");
+            state.ip_header = Some(IpHeader::Version6(header, Default::default()));
+            rest
+        };
+        let step = PacketBuilderStep::<B> {
+            state,
+            _marker: marker::PhantomData::<B> {},
+        };
+        let mut frame = Vec::with_capacity(final_size(&step, ip_payload.len()));
+        final_write(step, &mut frame, ip_payload)?;
+        result.push(frame);
+    }
+    Ok(result)
+}
+
+/// Writer that calculates the CRC-32 of all data written through it.
+struct Crc32Writer<'a, T: io::Write + Sized> {
+    writer: &'a mut T,
+    crc: checksum::Crc32,
+}
+
+impl<'a, T: io::Write + Sized> io::Write for Crc32Writer<'a, T> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let len = self.writer.write(buf)?;
+        self.crc = self.crc.clone().add_slice(&buf[..len]);
+        Ok(len)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+}
+
+///Writes the frame without the frame check sequence
+fn final_write_frame<T: io::Write + Sized, B>(
+    builder: PacketBuilderStep<B>,
+    writer: &mut T,
+    payload: &[u8],
 ) -> Result<(), BuildWriteError> {
     use BuildWriteError::*;
 
+    //in case of ip tunnels the outermost ip header defines the ether type
+    let outermost_ip_header = builder
+        .state
+        .outer_ip_headers
+        .first()
+        .map(|(ip, _)| ip)
+        .or(builder.state.ip_header.as_ref());
+
     let ip_ether_type = {
         use crate::IpHeader::*;
-        match builder.state.ip_header {
-            Some(Version4(_, _)) => ether_type::IPV4,
-            Some(Version6(_, _)) => ether_type::IPV6,
-            None => panic!("Missing ip header"),
+        match outermost_ip_header {
+            Some(Version4(_, _)) => Some(ether_type::IPV4),
+            Some(Version6(_, _)) => Some(ether_type::IPV6),
+            None if builder.state.arp_header.is_some() => Some(ether_type::ARP),
+            None => None,
+        }
+    };
+
+    //ether type of the header following the vlan, ethernet or snap header
+    let next_ether_type = if let Some(pppoe) = &builder.state.pppoe_header {
+        Some(pppoe.ether_type())
+    } else if builder.state.mpls_entries.is_empty() {
+        ip_ether_type
+    } else {
+        Some(ether_type::MPLS_UNICAST)
+    };
+
+    //value of the length field in the pppoe header
+    let pppoe_payload_length = if builder.state.pppoe_header.is_some() {
+        let len = final_frame_size(&builder, payload.len())
+            - builder
+                .state
+                .ethernet2_header
+                .as_ref()
+                .map(|eth| eth.header_len())
+                .unwrap_or(0)
+            - builder
+                .state
+                .vlan_header
+                .as_ref()
+                .map(|vlan| vlan.header_len())
+                .unwrap_or(0)
+            - builder
+                .state
+                .llc_header
+                .as_ref()
+                .map(|llc| llc.header_len())
+                .unwrap_or(0)
+            - builder
+                .state
+                .snap_header
+                .as_ref()
+                .map(|snap| snap.header_len())
+                .unwrap_or(0)
+            - PppoeHeader::LEN;
+        if len > usize::from(u16::MAX) {
+            return Err(PayloadLen(err::ValueTooBigError {
+                actual: len,
+                max_allowed: usize::from(u16::MAX),
+                value_type: err::ValueType::PppoePayloadLength,
+            }));
+        }
+        len as u16
+    } else {
+        0
+    };
+
+    //value of the type/length field in the ethernet or vlan header
+    let type_len = if builder.state.llc_header.is_some() {
+        //IEEE 802.3 frame, the field contains the length of the data after it
+        let len = final_frame_size(&builder, payload.len())
+            - builder
+                .state
+                .ethernet2_header
+                .as_ref()
+                .map(|eth| eth.header_len())
+                .unwrap_or(0)
+            - builder
+                .state
+                .vlan_header
+                .as_ref()
+                .map(|vlan| vlan.header_len())
+                .unwrap_or(0);
+        if len > Ethernet2Header::MAX_IEEE802_3_PAYLOAD_LEN {
+            return Err(PayloadLen(err::ValueTooBigError {
+                actual: len,
+                max_allowed: Ethernet2Header::MAX_IEEE802_3_PAYLOAD_LEN,
+                value_type: err::ValueType::Ieee802_3PayloadLength,
+            }));
         }
+        EtherType(len as u16)
+    } else {
+        next_ether_type.expect("Missing ip header")
     };
 
     //ethernetII header
@@ -1565,11 +3620,14 @@ fn final_write<T: io::Write + Sized, B>(
         eth.ether_type = {
             use crate::VlanHeader::*;
             //determine the ether type depending on if there is a vlan tagging header
-            match builder.state.vlan_header {
+            match &builder.state.vlan_header {
                 Some(Single(_)) => ether_type::VLAN_TAGGED_FRAME,
                 Some(Double(_)) => ether_type::PROVIDER_BRIDGING,
+                Some(Multi(stack)) if false == stack.tags().is_empty() => {
+                    ether_type::PROVIDER_BRIDGING
+                }
                 //if no vlan header exists, the id is purely defined by the ip type
-                None => ip_ether_type,
+                _ => type_len,
             }
         };
         eth.write(writer).map_err(Io)?;
@@ -1580,24 +3638,196 @@ fn final_write<T: io::Write + Sized, B>(
     match builder.state.vlan_header {
         Some(Single(mut value)) => {
             //set ether types
-            value.ether_type = ip_ether_type;
+            value.ether_type = type_len;
             //serialize
             value.write(writer).map_err(Io)?;
         }
         Some(Double(mut value)) => {
             //set ether types
             value.outer.ether_type = ether_type::VLAN_TAGGED_FRAME;
-            value.inner.ether_type = ip_ether_type;
+            value.inner.ether_type = type_len;
             //serialize
             value.write(writer).map_err(Io)?;
         }
+        Some(Multi(stack)) => {
+            //set ether types (only the last tag contains the type/length
+            //of the following data) & serialize
+            let last = stack.tags().len().wrapping_sub(1);
+            for (i, tag) in stack.tags().iter().enumerate() {
+                let mut tag = tag.clone();
+                tag.ether_type = if i == last {
+                    type_len
+                } else {
+                    ether_type::VLAN_TAGGED_FRAME
+                };
+                tag.write(writer).map_err(Io)?;
+            }
+        }
         None => {}
     }
 
+    //llc & snap header (IEEE 802.3 frame)
+    if let Some(llc) = builder.state.llc_header {
+        llc.write(writer).map_err(Io)?;
+        if let Some(mut snap) = builder.state.snap_header {
+            if let Some(next_ether_type) = next_ether_type {
+                snap.ether_type = next_ether_type;
+            }
+            snap.write(writer).map_err(Io)?;
+        }
+        //no mpls, pppoe, ip or arp following (raw llc payload)
+        if next_ether_type.is_none() {
+            writer.write_all(payload).map_err(Io)?;
+            return Ok(());
+        }
+    }
+
+    //pppoe & ppp header
+    if let Some(mut pppoe) = builder.state.pppoe_header {
+        pppoe.payload_length = pppoe_payload_length;
+        pppoe.write(writer).map_err(Io)?;
+        if let Some(mut ppp) = builder.state.ppp_header {
+            //set the protocol based on the following ip header
+            use crate::IpHeader::*;
+            match outermost_ip_header {
+                Some(Version4(_, _)) => ppp.protocol = PppProtocol::IPV4,
+                Some(Version6(_, _)) => ppp.protocol = PppProtocol::IPV6,
+                None => {}
+            }
+            ppp.write(writer).map_err(Io)?;
+        }
+        //no ip following (raw ppp payload or discovery tags)
+        if builder.state.ip_header.is_none() {
+            writer.write_all(payload).map_err(Io)?;
+            return Ok(());
+        }
+    }
+
+    //mpls label stack (only the last entry is marked as bottom of stack)
+    if false == builder.state.mpls_entries.is_empty() {
+        let last = builder.state.mpls_entries.len() - 1;
+        for (i, mut entry) in builder.state.mpls_entries.into_iter().enumerate() {
+            entry.bottom_of_stack = i == last;
+            entry.write(writer).map_err(Io)?;
+        }
+        //no ip following (raw mpls payload)
+        if builder.state.ip_header.is_none() {
+            writer.write_all(payload).map_err(Io)?;
+            return Ok(());
+        }
+    }
+
+    //arp packet (no further headers follow)
+    if let Some(arp) = builder.state.arp_header {
+        arp.write(writer).map_err(Io)?;
+        writer.write_all(payload).map_err(Io)?;
+        return Ok(());
+    }
+
     //ip header
     use crate::IpHeader::*;
     let ip_header = builder.state.ip_header.unwrap();
 
+    //outer ip headers of ip tunnels
+    let outer_ip_headers = builder.state.outer_ip_headers;
+    if false == outer_ip_headers.is_empty() {
+        let transport_size = builder
+            .state
+            .transport_header
+            .as_ref()
+            .map(|t| t.header_len())
+            .unwrap_or(0)
+            + payload.len();
+        let inner_size = ip_packet_size(
+            &ip_header,
+            builder.state.gre_header.as_ref(),
+            transport_size,
+        );
+        for (i, (outer, gre)) in outer_ip_headers.iter().enumerate() {
+            let following = &outer_ip_headers[i + 1..];
+            let inner_ip_number = match following.first().map(|(ip, _)| ip).unwrap_or(&ip_header) {
+                Version4(_, _) => ip_number::IPV4,
+                Version6(_, _) => ip_number::IPV6,
+            };
+            let next_header = if gre.is_some() {
+                ip_number::GRE
+            } else {
+                inner_ip_number
+            };
+            let payload_size = gre.as_ref().map(|gre| gre.header_len()).unwrap_or(0)
+                + tunnel_payload_size(following, inner_size);
+            match outer.clone() {
+                Version4(mut ip, mut ext) => {
+                    ip.protocol = ext.set_next_headers(next_header);
+                    ip.set_payload_len(ext.header_len() + payload_size)
+                        .map_err(PayloadLen)?;
+                    ip.write(writer).map_err(Io)?;
+                    ext.write(writer, ip.protocol).map_err(|err| {
+                        use err::ipv4_exts::HeaderWriteError as I;
+                        match err {
+                            I::Io(err) => Io(err),
+                            I::Content(err) => Ipv4Exts(err),
+                        }
+                    })?;
+                }
+                Version6(mut ip, mut ext) => {
+                    ip.next_header = ext.set_next_headers(next_header);
+                    ext.set_payload_length(&mut ip, payload_size)
+                        .map_err(PayloadLen)?;
+                    ip.write(writer).map_err(Io)?;
+                    ext.write(writer, ip.next_header).map_err(|err| {
+                        use err::ipv6_exts::HeaderWriteError as I;
+                        match err {
+                            I::Io(err) => Io(err),
+                            I::Content(err) => Ipv6Exts(err),
+                        }
+                    })?;
+                }
+            }
+            if let Some(gre) = gre {
+                //the GRE checksum covers the encapsulated packet, so the
+                //inner packet gets serialized before the GRE header
+                let mut inner_packet = Vec::with_capacity(payload_size - gre.header_len());
+                final_write_frame(
+                    PacketBuilderStep::<B> {
+                        state: PacketImpl {
+                            ethernet2_header: None,
+                            ip_header: Some(ip_header),
+                            vlan_header: None,
+                            llc_header: None,
+                            snap_header: None,
+                            mpls_entries: Vec::new(),
+                            outer_ip_headers: following.to_vec(),
+                            gre_header: builder.state.gre_header,
+                            pppoe_header: None,
+                            ppp_header: None,
+                            arp_header: None,
+                            transport_header: builder.state.transport_header,
+                            vxlan_header: None,
+                            geneve_header: None,
+                            gtpu_header: None,
+                            fcs: false,
+                        },
+                        _marker: marker::PhantomData::<B> {},
+                    },
+                    &mut inner_packet,
+                    payload,
+                )?;
+                let mut gre = gre.clone();
+                gre.protocol_type = match inner_ip_number {
+                    ip_number::IPV4 => ether_type::IPV4,
+                    _ => ether_type::IPV6,
+                };
+                if gre.checksum.is_some() {
+                    gre.update_checksum(&inner_packet);
+                }
+                gre.write(writer).map_err(Io)?;
+                writer.write_all(&inner_packet).map_err(Io)?;
+                return Ok(());
+            }
+        }
+    }
+
     //transport header
     let transport = builder.state.transport_header;
     match transport {
@@ -1605,9 +3835,15 @@ fn final_write<T: io::Write + Sized, B>(
             // in case no transport header is present the protocol
             // number and next_header fields are set in the write call
             // directly and don't need to be set here again.
+            let gre_len = builder
+                .state
+                .gre_header
+                .as_ref()
+                .map(|gre| gre.header_len())
+                .unwrap_or(0);
             match ip_header {
                 Version4(mut ip, ext) => {
-                    ip.set_payload_len(ext.header_len() + payload.len())
+                    ip.set_payload_len(ext.header_len() + gre_len + payload.len())
                         .map_err(PayloadLen)?;
                     ip.write(writer).map_err(Io)?;
                     ext.write(writer, ip.protocol).map_err(|err| {
@@ -1618,8 +3854,9 @@ fn final_write<T: io::Write + Sized, B>(
                         }
                     })?;
                 }
-                Version6(mut ip, ext) => {
-                    ip.set_payload_length(ext.header_len() + payload.len())
+                Version6(mut ip, mut ext) => {
+                    // adds a jumbo payload option if required
+                    ext.set_payload_length(&mut ip, gre_len + payload.len())
                         .map_err(PayloadLen)?;
                     ip.write(writer).map_err(Io)?;
                     ext.write(writer, ip.next_header).map_err(|err| {
@@ -1631,6 +3868,13 @@ fn final_write<T: io::Write + Sized, B>(
                     })?;
                 }
             }
+            // the protocol type of the gre header is set in the write call
+            if let Some(mut gre) = builder.state.gre_header {
+                if gre.checksum.is_some() {
+                    gre.update_checksum(payload);
+                }
+                gre.write(writer).map_err(Io)?;
+            }
         }
         Some(mut transport) => {
             match ip_header {
@@ -1679,16 +3923,16 @@ fn final_write<T: io::Write + Sized, B>(
                     })?;
                 }
                 Version6(mut ip, mut ext) => {
-                    //set total length
+                    //set total length (adds a jumbo payload option if required)
                     let transport_size = transport.header_len() + payload.len();
-                    ip.set_payload_length(ext.header_len() + transport_size)
+                    ext.set_payload_length(&mut ip, transport_size)
                         .map_err(PayloadLen)?;
                     use crate::TransportHeader::*;
                     match transport {
                         Icmpv4(_) => {}
                         Icmpv6(_) => {}
                         Udp(ref mut udp) => {
-                            udp.length = transport_size as u16;
+                            udp.length = UdpHeader::ipv6_length_field(payload.len());
                         }
                         Tcp(_) => {}
                     }
@@ -1728,27 +3972,97 @@ fn final_write<T: io::Write + Sized, B>(
 
 ///Returns the size of the packet when it is serialized
 fn final_size<B>(builder: &PacketBuilderStep<B>, payload_size: usize) -> usize {
-    use crate::IpHeader::*;
+    let frame_size = final_frame_size(builder, payload_size);
+    if builder.state.fcs {
+        // short frames get padded to the minimum frame size before the fcs
+        frame_size.max(Ethernet2Header::MIN_FRAME_LEN) + Ethernet2Header::FCS_LEN
+    } else {
+        frame_size
+    }
+}
+
+///Returns the size of the packet without the frame check sequence
+fn final_frame_size<B>(builder: &PacketBuilderStep<B>, payload_size: usize) -> usize {
     use crate::TransportHeader::*;
     use crate::VlanHeader::*;
+    let transport_size = match builder.state.transport_header {
+        Some(Icmpv4(ref value)) => value.header_len(),
+        Some(Icmpv6(ref value)) => value.header_len(),
+        Some(Udp(_)) => UdpHeader::LEN,
+        Some(Tcp(ref value)) => value.header_len() as usize,
+        None => 0,
+    } + payload_size;
     (match builder.state.ethernet2_header {
         Some(_) => Ethernet2Header::LEN,
         None => 0,
     }) + match builder.state.vlan_header {
         Some(Single(_)) => SingleVlanHeader::LEN,
         Some(Double(_)) => DoubleVlanHeader::LEN,
+        Some(Multi(ref stack)) => stack.header_len(),
         None => 0,
-    } + match builder.state.ip_header {
-        Some(Version4(ref value, ref ext)) => value.header_len() + ext.header_len(),
-        Some(Version6(_, ref ext)) => Ipv6Header::LEN + ext.header_len(),
+    } + match builder.state.llc_header {
+        Some(ref value) => value.header_len(),
         None => 0,
-    } + match builder.state.transport_header {
-        Some(Icmpv4(ref value)) => value.header_len(),
-        Some(Icmpv6(ref value)) => value.header_len(),
-        Some(Udp(_)) => UdpHeader::LEN,
-        Some(Tcp(ref value)) => value.header_len() as usize,
+    } + match builder.state.snap_header {
+        Some(_) => SnapHeader::LEN,
         None => 0,
-    } + payload_size
+    } + builder.state.mpls_entries.len() * MplsLabelStackEntry::LEN
+        + match builder.state.pppoe_header {
+            Some(_) => PppoeHeader::LEN,
+            None => 0,
+        }
+        + match builder.state.ppp_header {
+            Some(_) => PppHeader::LEN,
+            None => 0,
+        }
+        + match builder.state.arp_header {
+            Some(ref value) => value.header_len(),
+            None => 0,
+        }
+        + match builder.state.ip_header {
+            Some(ref ip_header) => tunnel_payload_size(
+                &builder.state.outer_ip_headers,
+                ip_packet_size(ip_header, builder.state.gre_header.as_ref(), transport_size),
+            ),
+            None => transport_size,
+        }
+}
+
+///Returns the length of an ip header including its extension headers
+fn ip_header_len(ip_header: &IpHeader, payload_size: usize) -> usize {
+    use crate::IpHeader::*;
+    match ip_header {
+        Version4(value, ext) => value.header_len() + ext.header_len(),
+        Version6(_, ext) => {
+            // includes a potentially added jumbo payload option
+            Ipv6Header::LEN + ext.header_len_with_upper_layer(payload_size)
+        }
+    }
+}
+
+///Returns the size of an ip packet including the GRE header following
+///the ip header (if present)
+fn ip_packet_size(
+    ip_header: &IpHeader,
+    gre_header: Option<&GreHeader>,
+    payload_size: usize,
+) -> usize {
+    let size = gre_header.map(|gre| gre.header_len()).unwrap_or(0) + payload_size;
+    ip_header_len(ip_header, size) + size
+}
+
+///Returns the size of the given tunnel ip & GRE headers (outermost first)
+///and the inner packet (`inner_size`) encapsulated by them
+fn tunnel_payload_size(
+    outer_ip_headers: &[(IpHeader, Option<GreHeader>)],
+    inner_size: usize,
+) -> usize {
+    outer_ip_headers
+        .iter()
+        .rev()
+        .fold(inner_size, |size, (ip_header, gre_header)| {
+            ip_packet_size(ip_header, gre_header.as_ref(), size)
+        })
 }
 
 #[cfg(test)]
@@ -1766,7 +4080,19 @@ mod whitebox_tests {
                     ethernet2_header: None,
                     ip_header: None,
                     vlan_header: None,
-                    transport_header: None
+                    llc_header: None,
+                    snap_header: None,
+                    mpls_entries: Vec::new(),
+                    outer_ip_headers: Vec::new(),
+                    gre_header: None,
+                    pppoe_header: None,
+                    ppp_header: None,
+                    arp_header: None,
+                    transport_header: None,
+                    vxlan_header: None,
+                    geneve_header: None,
+                    gtpu_header: None,
+                    fcs: false,
                 },
                 _marker: marker::PhantomData::<UdpHeader> {}
             }
@@ -1784,7 +4110,19 @@ mod whitebox_tests {
                     ethernet2_header: None,
                     ip_header: None,
                     vlan_header: None,
+                    llc_header: None,
+                    snap_header: None,
+                    mpls_entries: Vec::new(),
+                    outer_ip_headers: Vec::new(),
+                    gre_header: None,
+                    pppoe_header: None,
+                    ppp_header: None,
+                    arp_header: None,
                     transport_header: None,
+                    vxlan_header: None,
+                    geneve_header: None,
+                    gtpu_header: None,
+                    fcs: false,
                 },
                 _marker: marker::PhantomData::<UdpHeader> {},
             },
@@ -1,4 +1,5 @@
 use crate::err::{LenError, LenSource};
+use crate::link::mpls_label_stack_slice::mpls_payload_ether_type_guess;
 
 use super::*;
 
@@ -7,6 +8,8 @@ use super::*;
 /// You can use
 ///
 /// * [`PacketHeaders::from_ethernet_slice`]
+/// * [`PacketHeaders::from_linux_sll_slice`]
+/// * [`PacketHeaders::from_linux_sll2_slice`]
 /// * [`PacketHeaders::from_ether_type`]
 /// * [`PacketHeaders::from_ip_slice`]
 ///
@@ -14,16 +17,45 @@ use super::*;
 /// struct as a result.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct PacketHeaders<'a> {
-    /// Ethernet II header if present.
-    pub link: Option<Ethernet2Header>,
-    /// Single or double vlan headers if present.
+    /// Ethernet II or Linux cooked capture header if present.
+    pub link: Option<LinkHeader>,
+    /// Single, double or multi (three or more tags) vlan headers if present.
     pub vlan: Option<VlanHeader>,
-    /// IPv4 or IPv6 header and IP extension headers if present.
+    /// IEEE 802.2 LLC header if present (only present in IEEE 802.3 frames).
+    pub llc: Option<LlcHeader>,
+    /// SNAP header if present (follows an LLC header).
+    pub snap: Option<SnapHeader>,
+    /// MPLS label stack if present.
+    pub mpls: Option<MplsLabelStack>,
+    /// PPPoE header if present.
+    pub pppoe: Option<PppoeHeader>,
+    /// PPP header if present (only present in PPPoE session stage packets).
+    pub ppp: Option<PppHeader>,
+    /// ARP packet if present.
+    pub arp: Option<ArpHeader>,
+    /// IPv4 or IPv6 header and IP extension headers if present (outer
+    /// header in case IP tunnels were decoded).
     pub ip: Option<IpHeader>,
+    /// Innermost IPv4 or IPv6 header and IP extension headers of IP-in-IP,
+    /// 6in4 or 4in6 tunnels (only present if the tunnels were decoded via
+    /// [`PacketHeaders::decode_ip_tunnels`]).
+    ///
+    /// If present the `transport` & `payload` fields describe the data
+    /// of this inner packet.
+    pub inner_ip: Option<IpHeader>,
     /// TCP or UDP header if present.
     pub transport: Option<TransportHeader>,
     /// Rest of the packet that could not be decoded as a header (usually the payload).
     pub payload: &'a [u8],
+    /// Data after the end of the IP packet until the end of the decoded
+    /// data (e.g. ethernet padding or trailers appended by network devices).
+    ///
+    /// The end of the IP packet is determined based on the "total length"
+    /// field (IPv4) or the "payload length" field (IPv6). Data after link
+    /// layer length fields (IEEE 802.3 length or PPPoE payload length) is
+    /// part of the trailer. Empty if no IP header is present or no data
+    /// follows the IP packet.
+    pub link_trailer: &'a [u8],
 }
 
 impl<'a> PacketHeaders<'a> {
@@ -31,6 +63,10 @@ impl<'a> PacketHeaders<'a> {
     ///
     /// The result is returned as a [`PacketHeaders`] struct.
     ///
+    /// If the type/length field of the ethernet header contains a length
+    /// (IEEE 802.3 frame) instead of an ether type the decoding continues with
+    /// the LLC & SNAP headers (see [`PacketHeaders::from_ether_type`]).
+    ///
     /// # Example
     ///
     /// Basic usage:
@@ -78,7 +114,7 @@ impl<'a> PacketHeaders<'a> {
 
         match &mut result {
             // inject ethernet header into the result
-            Ok(result) => result.link = Some(ethernet),
+            Ok(result) => result.link = Some(LinkHeader::Ethernet2(ethernet)),
             // add the ethernet header to the overall offset in case there is a length error
             Err(Len(err)) => err.layer_start_offset += Ethernet2Header::LEN,
             _ => {}
@@ -86,17 +122,200 @@ impl<'a> PacketHeaders<'a> {
         result
     }
 
+    /// Verifies & strips the frame check sequence (FCS) at the end of an
+    /// ethernet frame and decodes the rest of the frame (the same way as
+    /// [`PacketHeaders::from_ethernet_slice`]).
+    ///
+    /// Use this function if your capture source delivers the ethernet
+    /// frames with the 4 byte CRC-32 still attached. The FCS is verified
+    /// using [`crate::checksum::Crc32`] and is not part of the resulting
+    /// payload or link trailer. See [`crate::SlicedPacket::from_ethernet_with_fcs`]
+    /// for why the FCS is not detected automatically.
+    ///
+    /// # Example
+    ///
+    /// Basic usage:
+    ///
+    ///```
+    /// # use etherparse::PacketBuilder;
+    /// # let builder = PacketBuilder::
+    /// #    ethernet2([1,2,3,4,5,6],     //source mac
+    /// #               [7,8,9,10,11,12]) //destionation mac
+    /// #    .fcs()
+    /// #    .ipv4([192,168,1,1], //source ip
+    /// #          [192,168,1,2], //desitionation ip
+    /// #          20)            //time to life
+    /// #    .udp(21,    //source port
+    /// #         1234); //desitnation port
+    /// # // payload of the udp packet
+    /// # let payload = [1,2,3,4,5,6,7,8];
+    /// # // get some memory to store the serialized data
+    /// # let mut packet = Vec::<u8>::with_capacity(
+    /// #     builder.size(payload.len())
+    /// # );
+    /// # builder.write(&mut packet, &payload).unwrap();
+    /// use etherparse::PacketHeaders;
+    ///
+    /// match PacketHeaders::from_ethernet_slice_with_fcs(&packet) {
+    ///     Err(value) => println!("Err {:?}", value),
+    ///     Ok(value) => {
+    ///         println!("link: {:?}", value.link);
+    ///         println!("ip: {:?}", value.ip);
+    ///         println!("transport: {:?}", value.transport);
+    ///     }
+    /// }
+    /// ```
+    pub fn from_ethernet_slice_with_fcs(
+        slice: &'a [u8],
+    ) -> Result<PacketHeaders<'a>, err::packet::EthFcsSliceError> {
+        let frame = crate::link::ethernet2_header::strip_fcs(slice)?;
+        Self::from_ethernet_slice(frame).map_err(err::packet::EthFcsSliceError::Slice)
+    }
+
+    /// Decodes a network packet into different headers from a slice that starts
+    /// with a Linux cooked capture (SLL) header.
+    ///
+    /// The decoding continues based on the `protocol_type` field of the SLL
+    /// header. If it does not contain an ether type (see
+    /// [`LinuxSllHeader::payload_ether_type`]) everything after the SLL header
+    /// is set as payload.
+    ///
+    /// # Example
+    ///
+    /// Basic usage:
+    ///
+    ///```
+    /// # use etherparse::{PacketBuilder, LinuxSllHeader, LinuxSllPacketType, ArpHardwareId, ether_type};
+    /// # let builder = PacketBuilder::
+    /// #    ipv4([192,168,1,1], //source ip
+    /// #         [192,168,1,2], //desitionation ip
+    /// #         20)            //time to life
+    /// #    .udp(21,    //source port
+    /// #         1234); //desitnation port
+    /// # let payload = [1,2,3,4,5,6,7,8];
+    /// # let sll = LinuxSllHeader {
+    /// #     packet_type: LinuxSllPacketType::OUTGOING,
+    /// #     arp_hrd_type: ArpHardwareId::ETHERNET,
+    /// #     sender_address_valid_length: 6,
+    /// #     sender_address: [1,2,3,4,5,6,0,0],
+    /// #     protocol_type: ether_type::IPV4,
+    /// # };
+    /// # let mut packet = Vec::<u8>::with_capacity(
+    /// #     sll.header_len() + builder.size(payload.len())
+    /// # );
+    /// # sll.write(&mut packet).unwrap();
+    /// # builder.write(&mut packet, &payload).unwrap();
+    /// use etherparse::PacketHeaders;
+    ///
+    /// match PacketHeaders::from_linux_sll_slice(&packet) {
+    ///     Err(value) => println!("Err {:?}", value),
+    ///     Ok(value) => {
+    ///         println!("link: {:?}", value.link);
+    ///         println!("vlan: {:?}", value.vlan);
+    ///         println!("ip: {:?}", value.ip);
+    ///         println!("transport: {:?}", value.transport);
+    ///     }
+    /// }
+    /// ```
+    pub fn from_linux_sll_slice(
+        slice: &'a [u8],
+    ) -> Result<PacketHeaders<'a>, err::packet::EthSliceError> {
+        let (sll, rest) =
+            LinuxSllHeader::from_slice(slice).map_err(err::packet::EthSliceError::Len)?;
+        Self::from_link_payload(LinkHeader::LinuxSll(sll), rest)
+    }
+
+    /// Decodes a network packet into different headers from a slice that starts
+    /// with a Linux cooked capture v2 (SLL2) header.
+    ///
+    /// Works the same way as [`PacketHeaders::from_linux_sll_slice`] but
+    /// expects the data to start with a [`LinuxSll2Header`].
+    pub fn from_linux_sll2_slice(
+        slice: &'a [u8],
+    ) -> Result<PacketHeaders<'a>, err::packet::EthSliceError> {
+        let (sll2, rest) =
+            LinuxSll2Header::from_slice(slice).map_err(err::packet::EthSliceError::Len)?;
+        Self::from_link_payload(LinkHeader::LinuxSll2(sll2), rest)
+    }
+
+    /// Decodes the payload of the given link header and injects the
+    /// link header into the result.
+    fn from_link_payload(
+        link: LinkHeader,
+        rest: &'a [u8],
+    ) -> Result<PacketHeaders<'a>, err::packet::EthSliceError> {
+        use err::packet::EthSliceError::Len;
+
+        let link_len = link.header_len();
+        let mut result = match link.payload_ether_type() {
+            Some(ether_type) => Self::from_ether_type(ether_type, rest),
+            None => Ok(PacketHeaders {
+                link: None,
+                vlan: None,
+                llc: None,
+                snap: None,
+                mpls: None,
+                pppoe: None,
+                ppp: None,
+                arp: None,
+                ip: None,
+                inner_ip: None,
+                transport: None,
+                payload: rest,
+                link_trailer: &[],
+            }),
+        };
+
+        match &mut result {
+            // inject link header into the result
+            Ok(result) => result.link = Some(link),
+            // add the link header to the overall offset in case there is a length error
+            Err(Len(err)) => err.layer_start_offset += link_len,
+            _ => {}
+        }
+        result
+    }
+
     /// Tries to decode a network packet into different headers using the
     /// given `ether_type` number to identify the first header.
     ///
     /// The result is returned as a [`PacketHeaders`] struct. Currently supported
     /// ether type numbers are:
     ///
+    /// * `ether_type::ARP`
     /// * `ether_type::IPV4`
     /// * `ether_type::IPV6`
     /// * `ether_type::VLAN_TAGGED_FRAME`
     /// * `ether_type::PROVIDER_BRIDGING`
     /// * `ether_type::VLAN_DOUBLE_TAGGED_FRAME`
+    /// * `ether_type::MPLS_UNICAST`
+    /// * `ether_type::MPLS_MULTICAST`
+    /// * `ether_type::PPPOE_DISCOVERY`
+    /// * `ether_type::PPPOE_SESSION`
+    ///
+    /// As MPLS does not identify the protocol of its payload the decoding
+    /// after an MPLS label stack only continues with an IPv4 or IPv6 header
+    /// if the first nibble after the bottom of stack entry contains the
+    /// value 4 or 6. Label stacks with more then
+    /// [`MplsLabelStack::MAX_ENTRIES`] entries result in an
+    /// [`err::packet::EthSliceError::Mpls`] error (use [`SlicedPacket`] if
+    /// you need to decode deeper label stacks).
+    ///
+    /// VLAN tags are decoded as long as the ether type of the previous
+    /// tag announces a further tag. Stacks with more then
+    /// [`VlanStack::MAX_TAGS`] tags result in an
+    /// [`err::packet::EthSliceError::VlanStack`] error (use [`SlicedPacket`]
+    /// if you need to decode deeper stacks).
+    ///
+    /// The payload of PPPoE packets is limited to the length given in the
+    /// PPPoE header. Session stage packets are decoded further if the PPP
+    /// header identifies an IPv4 or IPv6 payload, for discovery stage
+    /// packets the payload contains the tags (see [`PppoeTagsIterator`]).
+    ///
+    /// Values from `1` to `1500` are interpreted as the length of an
+    /// IEEE 802.3 frame. In this case the decoding continues with an
+    /// [`LlcHeader`] (and [`SnapHeader`] if indicated by the LLC header).
+    /// The payload is limited to the given length.
     ///
     /// If an unsupported ether type is given the given slice will be set as payload
     /// and all other fields will be set to `None`.
@@ -146,21 +365,20 @@ impl<'a> PacketHeaders<'a> {
 
         let mut rest = slice;
 
-        // helper function to add the current offset to length errors
-        let add_offset = |mut len_error: LenError, rest: &[u8]| -> LenError {
-            len_error.layer_start_offset += unsafe {
-                // SAFETY: Safe as rest is a subslice of slice.
-                rest.as_ptr().offset_from(slice.as_ptr()) as usize
-            };
-            len_error
-        };
-
         let mut result = PacketHeaders {
             link: None,
             vlan: None,
+            llc: None,
+            snap: None,
+            mpls: None,
+            pppoe: None,
+            ppp: None,
+            arp: None,
             ip: None,
+            inner_ip: None,
             transport: None,
             payload: &[],
+            link_trailer: &[],
         };
 
         //parse vlan header(s)
@@ -169,36 +387,178 @@ impl<'a> PacketHeaders<'a> {
         result.vlan = match ether_type {
             VLAN_TAGGED_FRAME | PROVIDER_BRIDGING | VLAN_DOUBLE_TAGGED_FRAME => {
                 use crate::VlanHeader::*;
-                let (outer, outer_rest) = SingleVlanHeader::from_slice(rest).map_err(Len)?;
 
-                //set the rest & ether_type for the following operations
-                rest = outer_rest;
-                ether_type = outer.ether_type;
+                //read tags until a tag with a non vlan ether type is encountered
+                let mut stack = crate::VlanStack::new();
+                loop {
+                    let offset = slice.len() - rest.len();
+                    let (tag, tag_rest) = SingleVlanHeader::from_slice(rest)
+                        .map_err(|err| Len(err.add_offset(offset)))?;
+
+                    //set the rest & ether_type for the following operations
+                    rest = tag_rest;
+                    ether_type = tag.ether_type;
+                    stack.try_push(tag).map_err(VlanStack)?;
+
+                    match ether_type {
+                        VLAN_TAGGED_FRAME | PROVIDER_BRIDGING | VLAN_DOUBLE_TAGGED_FRAME => {}
+                        _ => break,
+                    }
+                }
 
-                //parse second vlan header if present
-                match ether_type {
-                    //second vlan tagging header
-                    VLAN_TAGGED_FRAME | PROVIDER_BRIDGING | VLAN_DOUBLE_TAGGED_FRAME => {
-                        let (inner, inner_rest) = SingleVlanHeader::from_slice(rest)
-                            .map_err(|err| Len(err.add_offset(SingleVlanHeader::LEN)))?;
+                //use single & double vlan headers if possible
+                Some(match stack.tags() {
+                    [single] => Single(single.clone()),
+                    [outer, inner] => Double(DoubleVlanHeader {
+                        outer: outer.clone(),
+                        inner: inner.clone(),
+                    }),
+                    _ => Multi(stack),
+                })
+            }
+            //no vlan header
+            _ => None,
+        };
 
-                        //set the rest & ether_type for the following operations
-                        rest = inner_rest;
-                        ether_type = inner.ether_type;
+        // parse llc & snap headers in case of an IEEE 802.3 frame
+        let (payload_ether_type, len_source) =
+            if let Some(len) = crate::link::ethernet2_header::ieee802_3_len(ether_type) {
+                let offset = slice.len() - rest.len();
+
+                // limit the data to the length given in the type/length field
+                let len = usize::from(len);
+                if rest.len() < len {
+                    return Err(Len(LenError {
+                        required_len: len,
+                        len: rest.len(),
+                        len_source: LenSource::Slice,
+                        layer: err::Layer::LlcHeader,
+                        layer_start_offset: offset,
+                    }));
+                }
+                rest = &rest[..len];
+
+                let (llc, llc_rest) = LlcHeader::from_slice(rest).map_err(|mut err| {
+                    err.len_source = LenSource::Ieee802_3Len;
+                    Len(err.add_offset(offset))
+                })?;
+                let snap_offset = offset + llc.header_len();
+                let is_snap = llc.is_snap();
+                rest = llc_rest;
+                result.llc = Some(llc);
+
+                if is_snap {
+                    let (snap, snap_rest) = SnapHeader::from_slice(rest).map_err(|mut err| {
+                        err.len_source = LenSource::Ieee802_3Len;
+                        Len(err.add_offset(snap_offset))
+                    })?;
+                    rest = snap_rest;
+                    let payload_ether_type = snap.payload_ether_type();
+                    result.snap = Some(snap);
+                    (payload_ether_type, LenSource::Ieee802_3Len)
+                } else {
+                    (None, LenSource::Ieee802_3Len)
+                }
+            } else {
+                (Some(ether_type), LenSource::Slice)
+            };
 
-                        Some(Double(DoubleVlanHeader { outer, inner }))
+        // parse pppoe & ppp headers
+        let (payload_ether_type, len_source) = match payload_ether_type {
+            Some(PPPOE_DISCOVERY) | Some(PPPOE_SESSION) => {
+                let offset = slice.len() - rest.len();
+                let (pppoe, pppoe_rest) = PppoeHeader::from_slice(rest).map_err(|err| {
+                    use err::pppoe::HeaderSliceError as I;
+                    match err {
+                        I::Len(mut err) => {
+                            err.len_source = len_source;
+                            Len(err.add_offset(offset))
+                        }
+                        I::Content(err) => Pppoe(err),
                     }
-                    //no second vlan header detected -> single vlan header
-                    _ => Some(Single(outer)),
+                })?;
+
+                // limit the data to the length given in the pppoe header
+                let len = usize::from(pppoe.payload_length);
+                if pppoe_rest.len() < len {
+                    return Err(Len(LenError {
+                        required_len: PppoeHeader::LEN + len,
+                        len: rest.len(),
+                        len_source,
+                        layer: err::Layer::PppoeHeader,
+                        layer_start_offset: offset,
+                    }));
+                }
+                rest = &pppoe_rest[..len];
+                let is_session_data = pppoe.is_session_data();
+                result.pppoe = Some(pppoe);
+
+                if is_session_data {
+                    let (ppp, ppp_rest) = PppHeader::from_slice(rest).map_err(|mut err| {
+                        err.len_source = LenSource::PppoeHeaderLen;
+                        Len(err.add_offset(offset + PppoeHeader::LEN))
+                    })?;
+                    rest = ppp_rest;
+                    let payload_ether_type = ppp.payload_ether_type();
+                    result.ppp = Some(ppp);
+                    (payload_ether_type, LenSource::PppoeHeaderLen)
+                } else {
+                    (None, LenSource::PppoeHeaderLen)
                 }
             }
-            //no vlan header
-            _ => None,
+            value => (value, len_source),
+        };
+
+        // helper function to determine the data after the ip packet
+        let data_after = |ip_payload: &[u8]| -> &'a [u8] {
+            // SAFETY: Safe as ip_payload is a subslice of slice.
+            let end = unsafe {
+                ip_payload
+                    .as_ptr()
+                    .add(ip_payload.len())
+                    .offset_from(slice.as_ptr()) as usize
+            };
+            &slice[end..]
+        };
+
+        // helper function to add the current offset & length source to length errors
+        let add_offset = |mut len_error: LenError, rest: &[u8]| -> LenError {
+            len_error.layer_start_offset += unsafe {
+                // SAFETY: Safe as rest is a subslice of slice.
+                rest.as_ptr().offset_from(slice.as_ptr()) as usize
+            };
+            if LenSource::Slice == len_error.len_source {
+                len_error.len_source = len_source;
+            }
+            len_error
+        };
+
+        // parse mpls label stack & guess the payload type based on the ip version
+        let payload_ether_type = match payload_ether_type {
+            Some(MPLS_UNICAST) | Some(MPLS_MULTICAST) => {
+                let (mpls, mpls_rest) = MplsLabelStack::from_slice(rest).map_err(|err| {
+                    use err::mpls::HeaderSliceError as I;
+                    match err {
+                        I::Len(err) => Len(add_offset(err, rest)),
+                        I::Content(err) => Mpls(err),
+                    }
+                })?;
+                rest = mpls_rest;
+                result.mpls = Some(mpls);
+                mpls_payload_ether_type_guess(rest)
+            }
+            value => value,
         };
 
-        // parse ip
-        match ether_type {
-            IPV4 => {
+        // parse arp or ip
+        match payload_ether_type {
+            Some(ARP) => {
+                let (arp, arp_rest) =
+                    ArpHeader::from_slice(rest).map_err(|err| Len(add_offset(err, rest)))?;
+                rest = arp_rest;
+                result.arp = Some(arp);
+            }
+            Some(IPV4) => {
                 // read ipv4 header & extensions and payload slice
                 let (ip, ip_payload) = IpHeader::ipv4_from_slice(rest).map_err(|err| {
                     use err::ipv4::SliceError as I;
@@ -210,6 +570,7 @@ impl<'a> PacketHeaders<'a> {
                 })?;
 
                 // set the next
+                result.link_trailer = data_after(ip_payload.payload);
                 rest = ip_payload.payload;
                 result.ip = Some(ip);
 
@@ -225,7 +586,7 @@ impl<'a> PacketHeaders<'a> {
                 rest = transport_rest;
                 result.transport = transport;
             }
-            IPV6 => {
+            Some(IPV6) => {
                 // read ipv6 header & extensions and payload slice
                 let (ip, ip_payload) = IpHeader::ipv6_from_slice(rest).map_err(|err| {
                     use err::ipv6::SliceError as I;
@@ -237,6 +598,7 @@ impl<'a> PacketHeaders<'a> {
                 })?;
 
                 //set the ip result & rest
+                result.link_trailer = data_after(ip_payload.payload);
                 rest = ip_payload.payload;
                 result.ip = Some(ip);
 
@@ -315,14 +677,29 @@ impl<'a> PacketHeaders<'a> {
         let mut result = PacketHeaders {
             link: None,
             vlan: None,
+            llc: None,
+            snap: None,
+            mpls: None,
+            pppoe: None,
+            ppp: None,
+            arp: None,
             ip: Some(ip_header),
+            inner_ip: None,
             transport: None,
             payload: &[],
+            link_trailer: &[],
         };
 
         // cache rest for offset addition
         let rest = ip_payload.payload;
 
+        // data after the ip packet
+        result.link_trailer = {
+            // SAFETY: Safe as rest is a subslice of slice.
+            let end = unsafe { rest.as_ptr().add(rest.len()).offset_from(slice.as_ptr()) as usize };
+            &slice[end..]
+        };
+
         // try to parse the transport header (only if data is not fragmented)
         let (transport, rest) = read_transport(ip_payload).map_err(|err| {
             use err::tcp::HeaderSliceError as I;
@@ -345,26 +722,124 @@ impl<'a> PacketHeaders<'a> {
         Ok(result)
     }
 
+    /// Decodes IP-in-IP, 6in4 & 4in6 tunnels (IP packets with the
+    /// payload ip number [`ip_number::IPV4`] or [`ip_number::IPV6`]) and
+    /// decodes the transport header of the innermost IP packet.
+    ///
+    /// After calling this method the outer IP header stays in the `ip`
+    /// field, the innermost IP header is stored in the `inner_ip` field
+    /// and the `transport` & `payload` fields describe the content of
+    /// the innermost packet.
+    ///
+    /// `max_depth` limits the number of tunnels that are decoded. In case
+    /// of nested tunnels only the outermost & innermost IP headers are
+    /// kept. Fragmented packets are not decoded.
+    ///
+    /// The `layer_start_offset` of returned length errors is relative to
+    /// the start of the outer IP header.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use etherparse::{PacketBuilder, PacketHeaders, TransportHeader};
+    ///
+    /// let builder = PacketBuilder::
+    ///     ipv6([1;16], [2;16], 20)
+    ///     .ipv4([192,168,1,1], [192,168,1,2], 20)
+    ///     .udp(21, 1234);
+    /// let mut packet = Vec::with_capacity(builder.size(4));
+    /// builder.write(&mut packet, &[1,2,3,4]).unwrap();
+    ///
+    /// let headers = PacketHeaders::from_ip_slice(&packet)
+    ///     .unwrap()
+    ///     .decode_ip_tunnels(1)
+    ///     .unwrap();
+    /// assert!(headers.inner_ip.is_some());
+    /// assert!(matches!(headers.transport, Some(TransportHeader::Udp(_))));
+    /// assert_eq!(&[1,2,3,4], headers.payload);
+    /// ```
+    pub fn decode_ip_tunnels(
+        self,
+        max_depth: usize,
+    ) -> Result<PacketHeaders<'a>, err::packet::IpSliceError> {
+        let mut result = self;
+        let mut offset = match &result.ip {
+            Some(ip) => ip.header_len(),
+            None => return Ok(result),
+        };
+        for _ in 0..max_depth {
+            let ip = match result.inner_ip.as_ref().or(result.ip.as_ref()) {
+                Some(ip) => ip,
+                None => return Ok(result),
+            };
+            let is_tunnel = matches!(ip.next_header(), Ok(ip_number::IPV4) | Ok(ip_number::IPV6));
+            if result.transport.is_some() || false == is_tunnel || ip.is_fragmenting_payload() {
+                return Ok(result);
+            }
+
+            // the inner packet is limited by the outer length field
+            let len_source = match ip {
+                IpHeader::Version4(_, _) => LenSource::Ipv4HeaderTotalLen,
+                IpHeader::Version6(header, _) if 0 != header.payload_length => {
+                    LenSource::Ipv6HeaderPayloadLen
+                }
+                IpHeader::Version6(_, _) => LenSource::Slice,
+            };
+            let inner = PacketHeaders::from_ip_slice(result.payload).map_err(|err| {
+                use err::packet::IpSliceError::*;
+                match err {
+                    Len(mut err) => {
+                        err.layer_start_offset += offset;
+                        if LenSource::Slice == err.len_source {
+                            err.len_source = len_source;
+                        }
+                        Len(err)
+                    }
+                    err => err,
+                }
+            })?;
+            if let Some(inner_ip) = &inner.ip {
+                offset += inner_ip.header_len();
+            }
+            result.inner_ip = inner.ip;
+            result.transport = inner.transport;
+            result.payload = inner.payload;
+        }
+        Ok(result)
+    }
+
     /// If the slice in the `payload` field contains an ethernet payload
     /// this method returns the ether type number describing the payload type.
     ///
-    /// The ether type number can come from an ethernet II header or a
-    /// VLAN header depending on which headers are present.
+    /// The ether type number can come from an ethernet II header, a
+    /// Linux cooked capture header, a VLAN header or a SNAP header
+    /// depending on which headers are present.
     ///
-    /// In case that `ip` and/or `transport` fields are the filled None
-    /// is returned, as the payload contents then are defined by a
-    /// lower layer protocol described in these fields.
+    /// In case that `mpls`, `pppoe`, `ppp`, `arp`, `ip` and/or `transport`
+    /// fields are the filled None is returned, as the payload contents then
+    /// are defined by a lower layer protocol described in these fields.
     pub fn payload_ether_type(&self) -> Option<EtherType> {
-        if self.ip.is_some() || self.transport.is_some() {
+        if self.mpls.is_some()
+            || self.pppoe.is_some()
+            || self.ppp.is_some()
+            || self.arp.is_some()
+            || self.ip.is_some()
+            || self.transport.is_some()
+        {
+            None
+        } else if let Some(snap) = &self.snap {
+            snap.payload_ether_type()
+        } else if self.llc.is_some() {
             None
         } else if let Some(vlan) = &self.vlan {
             use VlanHeader::*;
             match vlan {
                 Single(s) => Some(s.ether_type),
                 Double(d) => Some(d.inner.ether_type),
+                Multi(m) => m.tags().last().map(|t| t.ether_type),
             }
         } else {
-            self.link.as_ref().map(|l| l.ether_type)
+            self.link.as_ref().and_then(|l| l.payload_ether_type())
         }
     }
 }
@@ -432,32 +907,57 @@ mod test {
             transport: None,
         });
 
-        // eth
-        {
-            let eth = Ethernet2Header {
-                source: [1, 2, 3, 4, 5, 6],
-                destination: [1, 2, 3, 4, 5, 6],
-                ether_type: 0.into(),
-            };
+        // eth & linux sll
+        for (link, layer) in [
+            (
+                LinkHeader::Ethernet2(Ethernet2Header {
+                    source: MacAddress([1, 2, 3, 4, 5, 6]),
+                    destination: MacAddress([1, 2, 3, 4, 5, 6]),
+                    ether_type: ether_type::WAKE_ON_LAN,
+                }),
+                err::Layer::Ethernet2Header,
+            ),
+            (
+                LinkHeader::LinuxSll(LinuxSllHeader {
+                    packet_type: LinuxSllPacketType::OUTGOING,
+                    arp_hrd_type: ArpHardwareId::ETHERNET,
+                    sender_address_valid_length: 6,
+                    sender_address: [1, 2, 3, 4, 5, 6, 0, 0],
+                    protocol_type: 0.into(),
+                }),
+                err::Layer::LinuxSllHeader,
+            ),
+            (
+                LinkHeader::LinuxSll2(LinuxSll2Header {
+                    protocol_type: 0.into(),
+                    interface_index: 2,
+                    arp_hrd_type: ArpHardwareId::ETHERNET,
+                    packet_type: 0,
+                    sender_address_valid_length: 6,
+                    sender_address: [1, 2, 3, 4, 5, 6, 0, 0],
+                }),
+                err::Layer::LinuxSll2Header,
+            ),
+        ] {
             let test = TestPacket {
-                link: Some(eth.clone()),
+                link: Some(link.clone()),
                 vlan: None,
                 ip: None,
                 transport: None,
             };
 
-            // ok ethernet header (with unknown next)
+            // ok link header (with unknown next)
             from_x_slice_vlan_variants(&test);
 
-            // eth len error
+            // link len error
             {
                 let data = test.to_vec(&[]);
                 for len in 0..data.len() {
                     let err = LenError {
-                        required_len: eth.header_len(),
+                        required_len: link.header_len(),
                         len,
                         len_source: err::LenSource::Slice,
-                        layer: err::Layer::Ethernet2Header,
+                        layer,
                         layer_start_offset: 0,
                     };
 
@@ -472,6 +972,899 @@ mod test {
         }
     }
 
+    #[test]
+    fn from_linux_sll_non_ether_type() {
+        use alloc::vec::Vec;
+
+        let payload = [1, 2, 3, 4];
+        let sll = LinuxSllHeader {
+            packet_type: LinuxSllPacketType::OUTGOING,
+            arp_hrd_type: ArpHardwareId::NETLINK,
+            sender_address_valid_length: 0,
+            sender_address: [0; 8],
+            // netlink protocol (would be IPv4 if it was an ether type)
+            protocol_type: ether_type::IPV4,
+        };
+        let sll2 = LinuxSll2Header {
+            protocol_type: ether_type::IPV4,
+            interface_index: 1,
+            arp_hrd_type: ArpHardwareId::NETLINK,
+            packet_type: 4,
+            sender_address_valid_length: 0,
+            sender_address: [0; 8],
+        };
+
+        // sll
+        {
+            let mut data = Vec::new();
+            sll.write(&mut data).unwrap();
+            data.extend_from_slice(&payload);
+
+            let result = PacketHeaders::from_linux_sll_slice(&data).unwrap();
+            assert_eq!(Some(LinkHeader::LinuxSll(sll.clone())), result.link);
+            assert_eq!(None, result.vlan);
+            assert_eq!(None, result.ip);
+            assert_eq!(&payload[..], result.payload);
+            assert_eq!(None, result.payload_ether_type());
+        }
+
+        // sll2
+        {
+            let mut data = Vec::new();
+            sll2.write(&mut data).unwrap();
+            data.extend_from_slice(&payload);
+
+            let result = PacketHeaders::from_linux_sll2_slice(&data).unwrap();
+            assert_eq!(Some(LinkHeader::LinuxSll2(sll2.clone())), result.link);
+            assert_eq!(None, result.vlan);
+            assert_eq!(None, result.ip);
+            assert_eq!(&payload[..], result.payload);
+            assert_eq!(None, result.payload_ether_type());
+        }
+    }
+
+    #[test]
+    fn from_ethernet_slice_with_fcs() {
+        use alloc::vec::Vec;
+        use err::packet::EthFcsSliceError;
+
+        let eth = Ethernet2Header {
+            source: MacAddress([1, 2, 3, 4, 5, 6]),
+            destination: MacAddress([7, 8, 9, 10, 11, 12]),
+            ether_type: ether_type::WAKE_ON_LAN,
+        };
+        let frame = {
+            let mut frame = Vec::new();
+            eth.write(&mut frame).unwrap();
+            frame.extend_from_slice(&[13, 14, 15, 16]);
+            frame
+        };
+        let crc = checksum::Crc32::new().add_slice(&frame).value();
+        let data = {
+            let mut data = frame.clone();
+            data.extend_from_slice(&crc.to_le_bytes());
+            data
+        };
+
+        // ok
+        {
+            let actual = PacketHeaders::from_ethernet_slice_with_fcs(&data).unwrap();
+            assert_eq!(actual, PacketHeaders::from_ethernet_slice(&frame).unwrap());
+            assert_eq!(&[13, 14, 15, 16], actual.payload);
+        }
+
+        // len error
+        for len in 0..Ethernet2Header::LEN + Ethernet2Header::FCS_LEN {
+            assert_eq!(
+                EthFcsSliceError::Len(LenError {
+                    required_len: Ethernet2Header::LEN + Ethernet2Header::FCS_LEN,
+                    len,
+                    len_source: LenSource::Slice,
+                    layer: err::Layer::Ethernet2Header,
+                    layer_start_offset: 0,
+                }),
+                PacketHeaders::from_ethernet_slice_with_fcs(&data[..len]).unwrap_err()
+            );
+        }
+
+        // fcs mismatch
+        {
+            let mut modified = data.clone();
+            modified[0] ^= 0x01;
+            assert_eq!(
+                EthFcsSliceError::FcsMismatch {
+                    received: crc,
+                    calculated: checksum::Crc32::new()
+                        .add_slice(&modified[..modified.len() - 4])
+                        .value(),
+                },
+                PacketHeaders::from_ethernet_slice_with_fcs(&modified).unwrap_err()
+            );
+        }
+
+        // slice error (vlan tag announced but fcs directly after the header)
+        {
+            let mut data = Vec::new();
+            Ethernet2Header {
+                ether_type: ether_type::VLAN_TAGGED_FRAME,
+                ..eth.clone()
+            }
+            .write(&mut data)
+            .unwrap();
+            let crc = checksum::Crc32::new().add_slice(&data).value();
+            data.extend_from_slice(&crc.to_le_bytes());
+            assert_eq!(
+                EthFcsSliceError::Slice(
+                    PacketHeaders::from_ethernet_slice(&data[..data.len() - 4]).unwrap_err()
+                ),
+                PacketHeaders::from_ethernet_slice_with_fcs(&data).unwrap_err()
+            );
+        }
+    }
+
+    #[test]
+    fn esp() {
+        use alloc::vec::Vec;
+
+        let esp = EspHeader {
+            spi: 1,
+            sequence_number: 2,
+        };
+        let esp_payload = [1, 2, 3, 4, 0, ip_number::UDP.0];
+        let ipv4 = {
+            let mut ipv4 = Ipv4Header::new(
+                (EspHeader::LEN + esp_payload.len()) as u16,
+                20,
+                ip_number::ENCAP_SEC,
+                [1, 2, 3, 4],
+                [5, 6, 7, 8],
+            )
+            .unwrap();
+            ipv4.header_checksum = ipv4.calc_header_checksum();
+            ipv4
+        };
+
+        let mut data = Vec::new();
+        ipv4.write(&mut data).unwrap();
+        esp.write(&mut data).unwrap();
+        data.extend_from_slice(&esp_payload);
+
+        // the data after the esp header is treated as payload
+        let actual = PacketHeaders::from_ip_slice(&data).unwrap();
+        assert_eq!(
+            Some(IpHeader::Version4(
+                ipv4,
+                Ipv4Extensions {
+                    auth: None,
+                    esp: Some(esp),
+                }
+            )),
+            actual.ip
+        );
+        assert_eq!(None, actual.transport);
+        assert_eq!(&esp_payload[..], actual.payload);
+    }
+
+    #[test]
+    fn ip_tunnels() {
+        use alloc::vec::Vec;
+
+        let udp = UdpHeader {
+            source_port: 1234,
+            destination_port: 5678,
+            length: (UdpHeader::LEN + 2) as u16,
+            checksum: 0,
+        };
+        let ipv4 = |protocol: IpNumber, payload_len: usize| {
+            let mut ipv4 =
+                Ipv4Header::new(payload_len as u16, 20, protocol, [1; 4], [2; 4]).unwrap();
+            ipv4.header_checksum = ipv4.calc_header_checksum();
+            ipv4
+        };
+        let ipv6 = |next_header: IpNumber, payload_len: usize| Ipv6Header {
+            traffic_class: 0,
+            flow_label: Ipv6FlowLabel::ZERO,
+            payload_length: payload_len as u16,
+            next_header,
+            hop_limit: 20,
+            source: [3; 16],
+            destination: [4; 16],
+        };
+
+        // 4in6 in 4
+        let inner = ipv4(ip_number::UDP, UdpHeader::LEN + 2);
+        let middle = ipv6(ip_number::IPV4, inner.header_len() + UdpHeader::LEN + 2);
+        let outer = ipv4(
+            ip_number::IPV6,
+            middle.header_len() + inner.header_len() + UdpHeader::LEN + 2,
+        );
+        let mut data = Vec::new();
+        outer.write(&mut data).unwrap();
+        middle.write(&mut data).unwrap();
+        inner.write(&mut data).unwrap();
+        udp.write(&mut data).unwrap();
+        data.extend_from_slice(&[1, 2]);
+
+        // tunnels are not decoded by default
+        let headers = PacketHeaders::from_ip_slice(&data).unwrap();
+        assert_eq!(None, headers.inner_ip);
+        assert_eq!(None, headers.transport);
+        assert_eq!(Ok(headers.clone()), headers.clone().decode_ip_tunnels(0));
+
+        // depth limit reached
+        let decoded = headers.clone().decode_ip_tunnels(1).unwrap();
+        assert_eq!(headers.ip, decoded.ip);
+        assert_eq!(
+            Some(IpHeader::Version6(middle.clone(), Default::default())),
+            decoded.inner_ip
+        );
+        assert_eq!(None, decoded.transport);
+        assert_eq!(
+            &data[outer.header_len() + middle.header_len()..],
+            decoded.payload
+        );
+
+        // innermost packet
+        for depth in [2, 3] {
+            let decoded = headers.clone().decode_ip_tunnels(depth).unwrap();
+            assert_eq!(headers.ip, decoded.ip);
+            assert_eq!(
+                Some(IpHeader::Version4(inner.clone(), Default::default())),
+                decoded.inner_ip
+            );
+            assert_eq!(Some(TransportHeader::Udp(udp.clone())), decoded.transport);
+            assert_eq!(&[1, 2], decoded.payload);
+        }
+
+        // fragmented packets are not decoded
+        {
+            let mut outer = ipv4(ip_number::IPV4, inner.header_len());
+            outer.more_fragments = true;
+            outer.header_checksum = outer.calc_header_checksum();
+            let mut data = Vec::new();
+            outer.write(&mut data).unwrap();
+            inner.write(&mut data).unwrap();
+
+            let headers = PacketHeaders::from_ip_slice(&data).unwrap();
+            assert_eq!(Ok(headers.clone()), headers.clone().decode_ip_tunnels(1));
+        }
+
+        // len error (offset relative to the outer ip header)
+        {
+            let inner = ipv4(ip_number::UDP, 2);
+            let outer = ipv6(ip_number::IPV4, inner.header_len() + 2);
+            let mut data = Vec::new();
+            outer.write(&mut data).unwrap();
+            inner.write(&mut data).unwrap();
+            data.extend_from_slice(&[1, 2]);
+
+            let headers = PacketHeaders::from_ip_slice(&data).unwrap();
+            assert_eq!(
+                IpSliceError::Len(LenError {
+                    required_len: UdpHeader::LEN,
+                    len: 2,
+                    len_source: LenSource::Ipv4HeaderTotalLen,
+                    layer: err::Layer::UdpHeader,
+                    layer_start_offset: Ipv6Header::LEN + Ipv4Header::MIN_LEN,
+                }),
+                headers.decode_ip_tunnels(1).unwrap_err()
+            );
+        }
+    }
+
+    #[test]
+    fn from_x_slice_arp() {
+        use alloc::vec::Vec;
+
+        let arp = ArpHeader::new(
+            ArpHardwareId::ETHERNET,
+            ether_type::IPV4,
+            ArpOperation::REPLY,
+            &[1, 2, 3, 4, 5, 6],
+            &[7, 8, 9, 10],
+            &[11, 12, 13, 14, 15, 16],
+            &[17, 18, 19, 20],
+        )
+        .unwrap();
+        // ethernet padding after the arp packet
+        let padding = [0u8; 18];
+
+        let eth = Ethernet2Header {
+            source: MacAddress([1, 2, 3, 4, 5, 6]),
+            destination: MacAddress([11, 12, 13, 14, 15, 16]),
+            ether_type: ether_type::ARP,
+        };
+        let single = SingleVlanHeader {
+            pcp: 1.try_into().unwrap(),
+            drop_eligible_indicator: false,
+            vlan_id: 2.try_into().unwrap(),
+            ether_type: ether_type::ARP,
+        };
+        let double = DoubleVlanHeader {
+            outer: SingleVlanHeader {
+                ether_type: ether_type::VLAN_TAGGED_FRAME,
+                ..single.clone()
+            },
+            inner: single.clone(),
+        };
+
+        for vlan in [
+            None,
+            Some(VlanHeader::Single(single.clone())),
+            Some(VlanHeader::Double(double.clone())),
+        ] {
+            let mut eth = eth.clone();
+            if vlan.is_some() {
+                eth.ether_type = ether_type::VLAN_TAGGED_FRAME;
+            }
+
+            let mut data = Vec::new();
+            eth.write(&mut data).unwrap();
+            if let Some(vlan) = &vlan {
+                vlan.write(&mut data).unwrap();
+            }
+            let arp_offset = data.len();
+            arp.write(&mut data).unwrap();
+            data.extend_from_slice(&padding);
+
+            // ok
+            {
+                let result = PacketHeaders::from_ethernet_slice(&data).unwrap();
+                assert_eq!(Some(LinkHeader::Ethernet2(eth.clone())), result.link);
+                assert_eq!(vlan, result.vlan);
+                assert_eq!(Some(arp.clone()), result.arp);
+                assert_eq!(None, result.ip);
+                assert_eq!(None, result.transport);
+                assert_eq!(&padding[..], result.payload);
+                assert_eq!(None, result.payload_ether_type());
+            }
+
+            // len error
+            for len in 0..arp.header_len() {
+                let err = LenError {
+                    required_len: if len < ArpHeader::MIN_LEN {
+                        ArpHeader::MIN_LEN
+                    } else {
+                        arp.header_len()
+                    },
+                    len,
+                    len_source: err::LenSource::Slice,
+                    layer: err::Layer::ArpPacket,
+                    layer_start_offset: arp_offset,
+                };
+                assert_eq!(
+                    EthSliceError::Len(err.clone()),
+                    PacketHeaders::from_ethernet_slice(&data[..arp_offset + len]).unwrap_err()
+                );
+                assert_eq!(
+                    EthSliceError::Len(LenError {
+                        layer_start_offset: arp_offset - Ethernet2Header::LEN,
+                        ..err.clone()
+                    }),
+                    PacketHeaders::from_ether_type(
+                        eth.ether_type,
+                        &data[Ethernet2Header::LEN..arp_offset + len]
+                    )
+                    .unwrap_err()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn from_x_slice_llc_snap() {
+        use alloc::vec::Vec;
+
+        let payload = [1, 2, 3, 4, 5, 6, 7, 8];
+        // ethernet padding after the 802.3 payload
+        let padding = [0u8; 4];
+
+        let llc = LlcHeader {
+            dsap: LlcHeader::STP_SAP,
+            ssap: LlcHeader::STP_SAP,
+            control: [LlcHeader::UI_CONTROL, 0],
+        };
+        let snap = SnapHeader {
+            oui: SnapHeader::OUI_RFC1042,
+            ether_type: ether_type::IPV6,
+        };
+        let ipv6 = Ipv6Header {
+            traffic_class: 0,
+            flow_label: 0.try_into().unwrap(),
+            payload_length: payload.len() as u16,
+            next_header: ip_number::UDP,
+            hop_limit: 1,
+            source: [2; 16],
+            destination: [3; 16],
+        };
+
+        let build = |llc: &LlcHeader, snap: Option<&SnapHeader>, ip: bool| {
+            let mut data = Vec::new();
+            llc.write(&mut data).unwrap();
+            if let Some(snap) = snap {
+                snap.write(&mut data).unwrap();
+            }
+            if ip {
+                ipv6.write(&mut data).unwrap();
+            }
+            data.extend_from_slice(&payload);
+            let len = data.len();
+            let mut result = Vec::new();
+            Ethernet2Header {
+                source: MacAddress([1, 2, 3, 4, 5, 6]),
+                destination: MacAddress([7, 8, 9, 10, 11, 12]),
+                ether_type: EtherType(len as u16),
+            }
+            .write(&mut result)
+            .unwrap();
+            result.extend_from_slice(&data);
+            result.extend_from_slice(&padding);
+            result
+        };
+
+        // llc only
+        {
+            let data = build(&llc, None, false);
+            let result = PacketHeaders::from_ethernet_slice(&data).unwrap();
+            assert_eq!(Some(llc.clone()), result.llc);
+            assert_eq!(None, result.snap);
+            assert_eq!(None, result.ip);
+            assert_eq!(&payload[..], result.payload);
+            assert_eq!(None, result.payload_ether_type());
+        }
+
+        // snap with unknown oui
+        {
+            let snap = SnapHeader {
+                oui: [0, 0, 0x0c],
+                ether_type: 0x2000.into(),
+            };
+            let data = build(&LlcHeader::SNAP, Some(&snap), false);
+            let result = PacketHeaders::from_ethernet_slice(&data).unwrap();
+            assert_eq!(Some(LlcHeader::SNAP), result.llc);
+            assert_eq!(Some(snap), result.snap);
+            assert_eq!(&payload[..], result.payload);
+            assert_eq!(None, result.payload_ether_type());
+        }
+
+        // snap with ether type oui but unknown ether type
+        {
+            let snap = SnapHeader {
+                oui: SnapHeader::OUI_BRIDGE_TUNNEL,
+                ether_type: 0x2000.into(),
+            };
+            let data = build(&LlcHeader::SNAP, Some(&snap), false);
+            let result = PacketHeaders::from_ethernet_slice(&data).unwrap();
+            assert_eq!(&payload[..], result.payload);
+            assert_eq!(Some(EtherType(0x2000)), result.payload_ether_type());
+        }
+
+        // snap & ipv6
+        {
+            let data = build(&LlcHeader::SNAP, Some(&snap), true);
+            let result = PacketHeaders::from_ethernet_slice(&data).unwrap();
+            assert_eq!(Some(snap.clone()), result.snap);
+            assert_eq!(
+                Some(IpHeader::Version6(ipv6.clone(), Default::default())),
+                result.ip
+            );
+            assert!(result.transport.is_some());
+            assert_eq!(None, result.payload_ether_type());
+            // ethernet padding after the 802.3 payload is part of the trailer
+            assert_eq!(&padding[..], result.link_trailer);
+        }
+
+        // length field bigger then the data
+        {
+            let data = build(&llc, None, false);
+            let len = data.len() - Ethernet2Header::LEN - padding.len();
+            for cut in 1..=padding.len() + 1 {
+                let cut_data = &data[..data.len() - padding.len() - cut];
+                assert_eq!(
+                    EthSliceError::Len(LenError {
+                        required_len: len,
+                        len: len - cut,
+                        len_source: err::LenSource::Slice,
+                        layer: err::Layer::LlcHeader,
+                        layer_start_offset: Ethernet2Header::LEN,
+                    }),
+                    PacketHeaders::from_ethernet_slice(cut_data).unwrap_err()
+                );
+            }
+        }
+
+        // length field too small for the llc, snap & ip header
+        for (len, required_len, layer) in [
+            (2, LlcHeader::MIN_LEN, err::Layer::LlcHeader),
+            (
+                LlcHeader::MIN_LEN + 4,
+                SnapHeader::LEN,
+                err::Layer::SnapHeader,
+            ),
+            (
+                LlcHeader::MIN_LEN + SnapHeader::LEN + 4,
+                Ipv6Header::LEN,
+                err::Layer::Ipv6Header,
+            ),
+        ] {
+            let mut data = build(&LlcHeader::SNAP, Some(&snap), true);
+            data[12..14].copy_from_slice(&(len as u16).to_be_bytes());
+            let offset = match layer {
+                err::Layer::LlcHeader => Ethernet2Header::LEN,
+                err::Layer::SnapHeader => Ethernet2Header::LEN + LlcHeader::MIN_LEN,
+                _ => Ethernet2Header::LEN + LlcHeader::MIN_LEN + SnapHeader::LEN,
+            };
+            assert_eq!(
+                EthSliceError::Len(LenError {
+                    required_len,
+                    len: len + Ethernet2Header::LEN - offset,
+                    len_source: err::LenSource::Ieee802_3Len,
+                    layer,
+                    layer_start_offset: offset,
+                }),
+                PacketHeaders::from_ethernet_slice(&data).unwrap_err()
+            );
+        }
+
+        // values outside of 1..=1500 are not interpreted as lengths
+        for type_len in [0u16, 0x05DD, 0x05FF] {
+            let mut data = build(&llc, None, false);
+            data[12..14].copy_from_slice(&type_len.to_be_bytes());
+            let result = PacketHeaders::from_ethernet_slice(&data).unwrap();
+            assert_eq!(None, result.llc);
+            assert_eq!(&data[Ethernet2Header::LEN..], result.payload);
+            assert_eq!(Some(EtherType(type_len)), result.payload_ether_type());
+        }
+    }
+
+    #[test]
+    fn from_x_slice_vlan_stack() {
+        use alloc::vec::Vec;
+
+        // ethernet frame with more tags then a VlanStack can hold
+        let mut data = Vec::new();
+        Ethernet2Header {
+            source: MacAddress([1, 2, 3, 4, 5, 6]),
+            destination: MacAddress([7, 8, 9, 10, 11, 12]),
+            ether_type: ether_type::PROVIDER_BRIDGING,
+        }
+        .write(&mut data)
+        .unwrap();
+        for i in 0..=VlanStack::MAX_TAGS {
+            SingleVlanHeader {
+                pcp: VlanPcp::ZERO,
+                drop_eligible_indicator: false,
+                vlan_id: VlanId::try_new(i as u16).unwrap(),
+                ether_type: if i == VlanStack::MAX_TAGS {
+                    ether_type::IPV4
+                } else {
+                    ether_type::VLAN_TAGGED_FRAME
+                },
+            }
+            .write(&mut data)
+            .unwrap();
+        }
+        Ipv4Header::new(0, 1, ip_number::IGMP, [2, 3, 4, 5], [6, 7, 8, 9])
+            .unwrap()
+            .write(&mut data)
+            .unwrap();
+
+        assert_eq!(
+            EthSliceError::VlanStack(err::vlan_stack::HeaderError::TooManyTags {
+                max_tags: VlanStack::MAX_TAGS
+            }),
+            PacketHeaders::from_ethernet_slice(&data).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn from_x_slice_mpls() {
+        use alloc::vec::Vec;
+
+        let payload = [1, 2, 3, 4, 5, 6, 7, 8];
+        let entry = |label: u32, bottom_of_stack: bool| MplsLabelStackEntry {
+            label: label.try_into().unwrap(),
+            traffic_class: 1.try_into().unwrap(),
+            bottom_of_stack,
+            ttl: 2,
+        };
+        let stack = MplsLabelStack::try_from(&[entry(16, false), entry(17, true)][..]).unwrap();
+        let ipv4 = Ipv4Header::new(
+            payload.len() as u16,
+            1,
+            ip_number::UDP,
+            [2, 3, 4, 5],
+            [6, 7, 8, 9],
+        )
+        .unwrap();
+
+        let build = |stack: &MplsLabelStack, ip: bool| {
+            let mut result = Vec::new();
+            Ethernet2Header {
+                source: MacAddress([1, 2, 3, 4, 5, 6]),
+                destination: MacAddress([7, 8, 9, 10, 11, 12]),
+                ether_type: ether_type::MPLS_UNICAST,
+            }
+            .write(&mut result)
+            .unwrap();
+            stack.write(&mut result).unwrap();
+            if ip {
+                ipv4.write(&mut result).unwrap();
+            }
+            result.extend_from_slice(&payload);
+            result
+        };
+
+        // ipv4 payload
+        {
+            let data = build(&stack, true);
+            let result = PacketHeaders::from_ethernet_slice(&data).unwrap();
+            assert_eq!(Some(&stack), result.mpls.as_ref());
+            match &result.ip {
+                Some(IpHeader::Version4(actual, _)) => assert_eq!(ipv4.source, actual.source),
+                _ => panic!("expected ipv4 header"),
+            }
+            assert_eq!(None, result.payload_ether_type());
+
+            // from ether type
+            let result = PacketHeaders::from_ether_type(
+                ether_type::MPLS_MULTICAST,
+                &data[Ethernet2Header::LEN..],
+            )
+            .unwrap();
+            assert_eq!(None, result.link);
+            assert_eq!(Some(&stack), result.mpls.as_ref());
+            assert!(result.ip.is_some());
+        }
+
+        // unknown payload (first nibble is neither 4 nor 6)
+        {
+            let data = build(&stack, false);
+            let result = PacketHeaders::from_ethernet_slice(&data).unwrap();
+            assert_eq!(Some(&stack), result.mpls.as_ref());
+            assert_eq!(None, result.ip);
+            assert_eq!(&payload[..], result.payload);
+            assert_eq!(None, result.payload_ether_type());
+        }
+
+        // length error in the label stack
+        {
+            let data = build(&stack, false);
+            for len in 0..stack.header_len() {
+                assert_eq!(
+                    EthSliceError::Len(LenError {
+                        required_len: (len / 4 + 1) * 4,
+                        len,
+                        len_source: LenSource::Slice,
+                        layer: err::Layer::MplsLabelStack,
+                        layer_start_offset: Ethernet2Header::LEN,
+                    }),
+                    PacketHeaders::from_ethernet_slice(&data[..Ethernet2Header::LEN + len])
+                        .unwrap_err()
+                );
+            }
+        }
+
+        // label stack with too many entries
+        {
+            let mut data = Vec::new();
+            Ethernet2Header {
+                source: MacAddress([1, 2, 3, 4, 5, 6]),
+                destination: MacAddress([7, 8, 9, 10, 11, 12]),
+                ether_type: ether_type::MPLS_UNICAST,
+            }
+            .write(&mut data)
+            .unwrap();
+            for i in 0..=MplsLabelStack::MAX_ENTRIES {
+                entry(i as u32, i == MplsLabelStack::MAX_ENTRIES)
+                    .write(&mut data)
+                    .unwrap();
+            }
+            assert_eq!(
+                EthSliceError::Mpls(err::mpls::HeaderError::TooManyEntries {
+                    max_entries: MplsLabelStack::MAX_ENTRIES
+                }),
+                PacketHeaders::from_ethernet_slice(&data).unwrap_err()
+            );
+        }
+    }
+
+    #[test]
+    fn from_x_slice_pppoe() {
+        use alloc::vec::Vec;
+
+        let payload = [1, 2, 3, 4, 5, 6, 7, 8];
+        let padding = [0, 0, 0, 0];
+        let ipv4 = Ipv4Header::new(
+            payload.len() as u16,
+            1,
+            ip_number::UDP,
+            [2, 3, 4, 5],
+            [6, 7, 8, 9],
+        )
+        .unwrap();
+
+        // builds an ethernet frame containing a PPPoE header, the given
+        // pppoe payload & ethernet padding
+        let build = |code: PppoeCode, pppoe_payload: &[u8]| {
+            let mut result = Vec::new();
+            let pppoe = PppoeHeader {
+                code,
+                session_id: 0x1234,
+                payload_length: pppoe_payload.len() as u16,
+            };
+            Ethernet2Header {
+                source: MacAddress([1, 2, 3, 4, 5, 6]),
+                destination: MacAddress([7, 8, 9, 10, 11, 12]),
+                ether_type: pppoe.ether_type(),
+            }
+            .write(&mut result)
+            .unwrap();
+            pppoe.write(&mut result).unwrap();
+            result.extend_from_slice(pppoe_payload);
+            result.extend_from_slice(&padding);
+            result
+        };
+        let ppp_payload = |protocol: PppProtocol, ip: bool| {
+            let mut result = Vec::new();
+            PppHeader { protocol }.write(&mut result).unwrap();
+            if ip {
+                ipv4.write(&mut result).unwrap();
+            }
+            result.extend_from_slice(&payload);
+            result
+        };
+
+        // session stage with ipv4 payload
+        {
+            let ppp = ppp_payload(PppProtocol::IPV4, true);
+            let data = build(PppoeCode::SESSION_DATA, &ppp);
+            let result = PacketHeaders::from_ethernet_slice(&data).unwrap();
+            assert_eq!(
+                Some(PppoeHeader {
+                    code: PppoeCode::SESSION_DATA,
+                    session_id: 0x1234,
+                    payload_length: ppp.len() as u16,
+                }),
+                result.pppoe
+            );
+            assert_eq!(
+                Some(PppHeader {
+                    protocol: PppProtocol::IPV4
+                }),
+                result.ppp
+            );
+            match &result.ip {
+                Some(IpHeader::Version4(actual, _)) => assert_eq!(ipv4.source, actual.source),
+                _ => panic!("expected ipv4 header"),
+            }
+            assert_eq!(None, result.payload_ether_type());
+            // ethernet padding after the pppoe payload is part of the trailer
+            assert_eq!(&padding[..], result.link_trailer);
+
+            // from ether type
+            let result = PacketHeaders::from_ether_type(
+                ether_type::PPPOE_SESSION,
+                &data[Ethernet2Header::LEN..],
+            )
+            .unwrap();
+            assert_eq!(None, result.link);
+            assert!(result.pppoe.is_some());
+            assert!(result.ppp.is_some());
+            assert!(result.ip.is_some());
+        }
+
+        // link trailer contains the data after the ip packet in the pppoe
+        // payload & the ethernet padding
+        {
+            let mut ppp = ppp_payload(PppProtocol::IPV4, true);
+            ppp.extend_from_slice(&[0xaa, 0xbb]);
+            let data = build(PppoeCode::SESSION_DATA, &ppp);
+            let result = PacketHeaders::from_ethernet_slice(&data).unwrap();
+            assert!(result.ip.is_some());
+            assert_eq!(&[0xaa, 0xbb, 0, 0, 0, 0], result.link_trailer);
+        }
+
+        // session stage with non ip payload (ethernet padding is cut off)
+        {
+            let data = build(
+                PppoeCode::SESSION_DATA,
+                &ppp_payload(PppProtocol::LCP, false),
+            );
+            let result = PacketHeaders::from_ethernet_slice(&data).unwrap();
+            assert_eq!(
+                Some(PppHeader {
+                    protocol: PppProtocol::LCP
+                }),
+                result.ppp
+            );
+            assert_eq!(None, result.ip);
+            assert_eq!(&payload[..], result.payload);
+            assert_eq!(None, result.payload_ether_type());
+        }
+
+        // discovery stage (payload contains the tags)
+        {
+            let tags = [0x01, 0x01, 0x00, 0x00];
+            let data = build(PppoeCode::PADI, &tags);
+            let result = PacketHeaders::from_ethernet_slice(&data).unwrap();
+            assert_eq!(PppoeCode::PADI, result.pppoe.as_ref().unwrap().code);
+            assert_eq!(None, result.ppp);
+            assert_eq!(&tags[..], result.payload);
+            assert_eq!(None, result.payload_ether_type());
+        }
+
+        // length error in the pppoe header
+        {
+            let data = build(PppoeCode::PADI, &[]);
+            for len in 0..PppoeHeader::LEN {
+                assert_eq!(
+                    EthSliceError::Len(LenError {
+                        required_len: PppoeHeader::LEN,
+                        len,
+                        len_source: LenSource::Slice,
+                        layer: err::Layer::PppoeHeader,
+                        layer_start_offset: Ethernet2Header::LEN,
+                    }),
+                    PacketHeaders::from_ethernet_slice(&data[..Ethernet2Header::LEN + len])
+                        .unwrap_err()
+                );
+            }
+        }
+
+        // length error in the pppoe payload
+        {
+            let ppp = ppp_payload(PppProtocol::LCP, false);
+            let data = build(PppoeCode::SESSION_DATA, &ppp);
+            let len = PppoeHeader::LEN + ppp.len() - 1;
+            assert_eq!(
+                EthSliceError::Len(LenError {
+                    required_len: PppoeHeader::LEN + ppp.len(),
+                    len,
+                    len_source: LenSource::Slice,
+                    layer: err::Layer::PppoeHeader,
+                    layer_start_offset: Ethernet2Header::LEN,
+                }),
+                PacketHeaders::from_ethernet_slice(&data[..Ethernet2Header::LEN + len])
+                    .unwrap_err()
+            );
+        }
+
+        // length error in the ppp header
+        {
+            let data = build(PppoeCode::SESSION_DATA, &[0]);
+            assert_eq!(
+                EthSliceError::Len(LenError {
+                    required_len: PppHeader::LEN,
+                    len: 1,
+                    len_source: LenSource::PppoeHeaderLen,
+                    layer: err::Layer::PppHeader,
+                    layer_start_offset: Ethernet2Header::LEN + PppoeHeader::LEN,
+                }),
+                PacketHeaders::from_ethernet_slice(&data).unwrap_err()
+            );
+        }
+
+        // length error in the ip header (length source is the pppoe header)
+        {
+            let data = build(PppoeCode::SESSION_DATA, &[0x00, 0x21, 0x45, 0x00]);
+            match PacketHeaders::from_ethernet_slice(&data).unwrap_err() {
+                EthSliceError::Len(err) => assert_eq!(LenSource::PppoeHeaderLen, err.len_source),
+                _ => panic!("expected length error"),
+            }
+        }
+
+        // content error in the pppoe header
+        {
+            let mut data = build(PppoeCode::PADI, &[]);
+            data[Ethernet2Header::LEN] = 0x12;
+            assert_eq!(
+                EthSliceError::Pppoe(err::pppoe::HeaderError::UnexpectedType { type_number: 2 }),
+                PacketHeaders::from_ethernet_slice(&data).unwrap_err()
+            );
+        }
+    }
+
     fn from_x_slice_vlan_variants(base: &TestPacket) {
         // none
         from_x_slice_ip_variants(base);
@@ -482,7 +1875,7 @@ mod test {
                 pcp: 1.try_into().unwrap(),
                 drop_eligible_indicator: false,
                 vlan_id: 2.try_into().unwrap(),
-                ether_type: 3.into(),
+                ether_type: ether_type::WAKE_ON_LAN,
             };
 
             for vlan_ether_type in VLAN_ETHER_TYPES {
@@ -531,7 +1924,7 @@ mod test {
                         pcp: 1.try_into().unwrap(),
                         drop_eligible_indicator: false,
                         vlan_id: 2.try_into().unwrap(),
-                        ether_type: 3.into(),
+                        ether_type: ether_type::WAKE_ON_LAN,
                     },
                 };
                 let mut test = base.clone();
@@ -564,6 +1957,52 @@ mod test {
                 }
             }
         }
+
+        // multi vlan header
+        for vlan_ether_type in VLAN_ETHER_TYPES {
+            let tag = |ether_type| SingleVlanHeader {
+                pcp: 1.try_into().unwrap(),
+                drop_eligible_indicator: false,
+                vlan_id: 2.try_into().unwrap(),
+                ether_type,
+            };
+            let stack = VlanStack::try_from(
+                &[
+                    tag(vlan_ether_type),
+                    tag(vlan_ether_type),
+                    tag(ether_type::WAKE_ON_LAN),
+                ][..],
+            )
+            .unwrap();
+            let mut test = base.clone();
+            test.set_ether_type(vlan_ether_type);
+            test.vlan = Some(VlanHeader::Multi(stack));
+
+            // ok multi vlan header
+            from_x_slice_ip_variants(&test);
+
+            // len error
+            {
+                let data = test.to_vec(&[]);
+                for len in 0..SingleVlanHeader::LEN {
+                    let base_len = test.len(&[]) - SingleVlanHeader::LEN;
+
+                    let err = LenError {
+                        required_len: SingleVlanHeader::LEN,
+                        len,
+                        len_source: err::LenSource::Slice,
+                        layer: err::Layer::VlanHeader,
+                        layer_start_offset: base_len,
+                    };
+                    from_slice_assert_err(
+                        &test,
+                        &data[..base_len + len],
+                        EthSliceError::Len(err.clone()),
+                        IpSliceError::Len(err.clone()),
+                    );
+                }
+            }
+        }
     }
 
     fn from_x_slice_ip_variants(base: &TestPacket) {
@@ -653,6 +2092,7 @@ mod test {
                     },
                     Ipv4Extensions {
                         auth: Some(auth.clone()),
+                        esp: None,
                     },
                 ));
                 test.set_payload_len(0);
@@ -1107,9 +2547,9 @@ mod test {
         // write data
         let data = test.to_vec(&payload);
 
-        // from_ethernet_slice
-        if test.link.is_some() {
-            let result = PacketHeaders::from_ethernet_slice(&data).unwrap();
+        // from_ethernet_slice & from_linux_sll*_slice
+        if let Some(link) = &test.link {
+            let result = from_link_slice(link, &data).unwrap();
             assert_eq!(result.link, test.link);
             assert_eq!(result.vlan, test.vlan);
             assert_eq!(result.ip, test.ip);
@@ -1170,6 +2610,38 @@ mod test {
                 assert_eq!(result.payload, &[1, 2, 3, 4]);
             }
         }
+
+        // data after the ip packet is returned as link trailer
+        if test.ip.is_some() {
+            let trailer = [0xaa, 0xbb, 0xcc];
+            let mut data = data.clone();
+            data.extend_from_slice(&trailer);
+            let result = if let Some(link) = &test.link {
+                from_link_slice(link, &data).unwrap()
+            } else if test.vlan.is_some() {
+                PacketHeaders::from_ether_type(VLAN_ETHER_TYPES[0], &data).unwrap()
+            } else {
+                PacketHeaders::from_ip_slice(&data).unwrap()
+            };
+            assert_eq!(result.ip, test.ip);
+            assert_eq!(&trailer[..], result.link_trailer);
+            if false == is_fragmented {
+                assert_eq!(result.transport, test.transport);
+                assert_eq!(result.payload, &[1, 2, 3, 4]);
+            }
+        }
+    }
+
+    /// Decode the given data with the function matching the link header.
+    fn from_link_slice<'a>(
+        link: &LinkHeader,
+        data: &'a [u8],
+    ) -> Result<PacketHeaders<'a>, EthSliceError> {
+        match link {
+            LinkHeader::Ethernet2(_) => PacketHeaders::from_ethernet_slice(data),
+            LinkHeader::LinuxSll(_) => PacketHeaders::from_linux_sll_slice(data),
+            LinkHeader::LinuxSll2(_) => PacketHeaders::from_linux_sll2_slice(data),
+        }
     }
 
     /// Check that the given errors get triggered if presented with the given
@@ -1180,12 +2652,9 @@ mod test {
         eth_err: EthSliceError,
         ip_err: IpSliceError,
     ) {
-        // from_ethernet_slice
-        if test.link.is_some() {
-            assert_eq!(
-                eth_err.clone(),
-                PacketHeaders::from_ethernet_slice(&data).unwrap_err()
-            );
+        // from_ethernet_slice & from_linux_sll*_slice
+        if let Some(link) = &test.link {
+            assert_eq!(eth_err.clone(), from_link_slice(link, &data).unwrap_err());
         }
         // from_ether_type (vlan at start)
         if test.link.is_none() && test.vlan.is_some() {
@@ -1,4 +1,5 @@
 use super::*;
+use arrayvec::ArrayVec;
 
 #[derive(Debug, Default, Clone, Eq, PartialEq)]
 pub enum ElementFilter<T> {
@@ -11,8 +12,8 @@ pub enum ElementFilter<T> {
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub enum LinkFilter {
     Ethernet2 {
-        source: Option<[u8; 6]>,
-        destination: Option<[u8; 6]>,
+        source: Option<MacAddress>,
+        destination: Option<MacAddress>,
     },
 }
 
@@ -23,6 +24,10 @@ pub enum VlanFilter {
         outer_identifier: Option<VlanId>,
         inner_identifier: Option<VlanId>,
     },
+    /// Matches if the number of vlan tags is equal to the number of given
+    /// identifiers & all identifiers that are set match (starting with the
+    /// outer most tag).
+    Multi(ArrayVec<Option<VlanId>, { VlanStack::MAX_TAGS }>),
 }
 
 #[derive(Debug, Clone, Eq, PartialEq)]
@@ -74,6 +79,7 @@ impl LinkFilter {
                         None => true,
                     })
                 }
+                LinuxSll(_) | LinuxSll2(_) => false,
             },
         }
     }
@@ -107,6 +113,26 @@ impl VlanFilter {
                 }
                 _ => false,
             },
+            VlanFilter::Multi(expected_ids) => {
+                let matches =
+                    |expected_id: &Option<VlanId>, header: SingleVlanHeaderSlice| match expected_id
+                    {
+                        Some(e) => header.vlan_identifier() == *e,
+                        None => true,
+                    };
+                expected_ids.len() == slice.num_tags()
+                    && match slice {
+                        SingleVlan(header) => matches(&expected_ids[0], header.clone()),
+                        DoubleVlan(header) => {
+                            matches(&expected_ids[0], header.outer())
+                                && matches(&expected_ids[1], header.inner())
+                        }
+                        MultiVlan(header) => expected_ids
+                            .iter()
+                            .zip(header.tags())
+                            .all(|(expected_id, tag)| matches(expected_id, tag)),
+                    }
+            }
         }
     }
 }
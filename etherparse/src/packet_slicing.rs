@@ -1,4 +1,6 @@
 use crate::err::LenSource;
+use crate::link::ethernet2_header::{ieee802_3_len, strip_fcs};
+use crate::link::mpls_label_stack_slice::mpls_payload_ether_type_guess;
 
 use super::*;
 
@@ -9,6 +11,8 @@ use super::*;
 /// You can use
 ///
 /// * [`SlicedPacket::from_ethernet`]
+/// * [`SlicedPacket::from_linux_sll`]
+/// * [`SlicedPacket::from_linux_sll2`]
 /// * [`SlicedPacket::from_ether_type`]
 /// * [`SlicedPacket::from_ip`]
 ///
@@ -46,14 +50,54 @@ use super::*;
 /// ```
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct SlicedPacket<'a> {
-    /// Ethernet II header if present.
+    /// Ethernet II or Linux cooked capture header if present.
     pub link: Option<LinkSlice<'a>>,
-    /// Single or double vlan headers if present.
+    /// Single, double or multi (three or more tags) vlan headers if present.
     pub vlan: Option<VlanSlice<'a>>,
-    /// IPv4 or IPv6 header and IP extension headers if present.
+    /// MACsec header (SecTAG) if present.
+    pub macsec: Option<MacsecHeaderSlice<'a>>,
+    /// IEEE 802.2 LLC header if present (only present in IEEE 802.3 frames).
+    pub llc: Option<LlcHeaderSlice<'a>>,
+    /// SNAP header if present (follows an LLC header).
+    pub snap: Option<SnapHeaderSlice<'a>>,
+    /// MPLS label stack if present.
+    pub mpls: Option<MplsLabelStackSlice<'a>>,
+    /// PPPoE header if present.
+    pub pppoe: Option<PppoeHeaderSlice<'a>>,
+    /// PPP header if present (only present in PPPoE session stage packets).
+    pub ppp: Option<PppHeaderSlice<'a>>,
+    /// ARP packet if present.
+    pub arp: Option<ArpPacketSlice<'a>>,
+    /// IPv4 or IPv6 header and IP extension headers if present (outer
+    /// header in case IP tunnels were decoded).
     pub ip: Option<InternetSlice<'a>>,
+    /// Innermost IPv4 or IPv6 header and IP extension headers of IP-in-IP,
+    /// 6in4 or 4in6 tunnels (only present if the tunnels were decoded via
+    /// [`SlicedPacket::decode_ip_tunnels`]).
+    ///
+    /// If present the `transport` & `payload` fields describe the data
+    /// of this inner packet.
+    pub inner_ip: Option<InternetSlice<'a>>,
+    /// GRE header if present (the encapsulated packet can be sliced via
+    /// [`SlicedPacket::slice_gre_payload`]).
+    pub gre: Option<GreHeaderSlice<'a>>,
     /// TCP or UDP header if present.
     pub transport: Option<TransportSlice<'a>>,
+    /// VXLAN or VXLAN-GPE header if present (only present if decoded via
+    /// [`SlicedPacket::decode_udp_tunnels`] or [`SlicedPacket::decode_vxlan`],
+    /// the encapsulated frame can be sliced via
+    /// [`SlicedPacket::slice_vxlan_payload`]).
+    pub vxlan: Option<VxlanHeaderSlice<'a>>,
+    /// Geneve header if present (only present if decoded via
+    /// [`SlicedPacket::decode_udp_tunnels`] or [`SlicedPacket::decode_geneve`],
+    /// the encapsulated frame can be sliced via
+    /// [`SlicedPacket::slice_geneve_payload`]).
+    pub geneve: Option<GeneveHeaderSlice<'a>>,
+    /// GTP-U header if present (only present if decoded via
+    /// [`SlicedPacket::decode_udp_tunnels`] or [`SlicedPacket::decode_gtpu`],
+    /// the encapsulated IP packet can be sliced via
+    /// [`SlicedPacket::slice_gtpu_payload`]).
+    pub gtpu: Option<GtpuHeaderSlice<'a>>,
     /// The payload field points to the rest of the packet that could not be parsed by etherparse.
     ///
     /// Depending on what other fields contain a "Some" values the payload contains the corresponding
@@ -61,8 +105,43 @@ pub struct SlicedPacket<'a> {
     ///
     /// For example if transport field contains Some(Udp(_)) then the payload field points to the udp payload.
     /// On the other hand if the transport field contains None then the payload contains the payload of
-    /// next field containing a Some value (in order of transport, ip, vlan, link).
+    /// next field containing a Some value (in order of vxlan, geneve, gtpu, transport, gre, ip, arp, ppp, pppoe, mpls, snap, llc, vlan, macsec, link).
+    ///
+    /// In case an ARP packet is present the payload contains the data after
+    /// the ARP packet (e.g. ethernet padding).
+    ///
+    /// In case of an IEEE 802.3 frame the payload is limited by the length
+    /// given in the type/length field (data after it is not included).
+    ///
+    /// In case a PPPoE header is present the payload is limited by the
+    /// length given in the PPPoE header. For PPPoE discovery stage packets
+    /// the payload contains the tags (see [`PppoeTagsIterator`]).
+    ///
+    /// In case a MACsec header is present the payload does not include the
+    /// integrity check value (ICV) and padding after the secure data. For
+    /// encrypted or modified frames the payload contains the secure data.
+    ///
+    /// In case a GRE header is present the payload contains the
+    /// encapsulated packet (identified by [`GreHeaderSlice::protocol_type`]).
+    /// The same applies to VXLAN & Geneve headers.
+    ///
+    /// In case a GTP-U header is present the payload is limited by the
+    /// length given in the GTP-U header.
     pub payload: &'a [u8],
+    /// Data after the end of the IP packet until the end of the sliced
+    /// data (e.g. ethernet padding or trailers appended by network devices).
+    ///
+    /// The end of the IP packet is determined based on the "total length"
+    /// field (IPv4) or the "payload length" field (IPv6). Data after
+    /// link layer length fields (IEEE 802.3 length or PPPoE payload length)
+    /// is part of the trailer. In case a MACsec header is present the
+    /// trailer ends at the end of the secure data (the ICV and the data
+    /// after it are not part of the trailer). Empty if no IP header is
+    /// present or no data follows the IP packet.
+    ///
+    /// In case IP tunnels were decoded the trailer of the outer IP packet
+    /// is kept.
+    pub link_trailer: &'a [u8],
 }
 
 impl<'a> SlicedPacket<'a> {
@@ -71,6 +150,13 @@ impl<'a> SlicedPacket<'a> {
     /// The result is returned as a [`SlicedPacket`] struct. This function assumes the given data starts
     /// with an ethernet II header.
     ///
+    /// If the type/length field of the ethernet header contains a length
+    /// (IEEE 802.3 frame) instead of an ether type the slicing continues with
+    /// the LLC & SNAP headers (see [`SlicedPacket::from_ether_type`]).
+    ///
+    /// If the frame still contains the frame check sequence (FCS) at its
+    /// end use [`SlicedPacket::from_ethernet_with_fcs`] instead.
+    ///
     /// # Examples
     ///
     /// Basic usage:
@@ -105,17 +191,158 @@ impl<'a> SlicedPacket<'a> {
         CursorSlice::new(data).slice_ethernet2()
     }
 
+    /// Verifies & strips the frame check sequence (FCS) at the end of an
+    /// ethernet frame and seperates the rest of the frame into different
+    /// slices (the same way as [`SlicedPacket::from_ethernet`]).
+    ///
+    /// Use this function if your capture source delivers the ethernet
+    /// frames with the 4 byte CRC-32 still attached. The FCS is verified
+    /// using [`crate::checksum::Crc32`] and is not part of the resulting
+    /// slices (including the payload).
+    ///
+    /// This is a separate function (instead of the FCS being detected
+    /// automatically by [`SlicedPacket::from_ethernet`]) as the presence
+    /// of an FCS can not be determined from the frame data itself. Most
+    /// capture sources (e.g. libpcap & raw sockets) strip the FCS before
+    /// the frame is delivered, so the caller has to know if it is present.
+    ///
+    /// # Example
+    ///
+    ///```
+    /// # use etherparse::{SlicedPacket, PacketBuilder};
+    /// # let builder = PacketBuilder::
+    /// #    ethernet2([1,2,3,4,5,6],     //source mac
+    /// #               [7,8,9,10,11,12]) //destionation mac
+    /// #    .fcs()
+    /// #    .ipv4([192,168,1,1], //source ip
+    /// #          [192,168,1,2], //desitionation ip
+    /// #          20)            //time to life
+    /// #    .udp(21,    //source port
+    /// #         1234); //desitnation port
+    /// #    //payload of the udp packet
+    /// #    let payload = [1,2,3,4,5,6,7,8];
+    /// #    //get some memory to store the serialized data
+    /// #    let mut packet = Vec::<u8>::with_capacity(
+    /// #                            builder.size(payload.len()));
+    /// #    builder.write(&mut packet, &payload).unwrap();
+    /// match SlicedPacket::from_ethernet_with_fcs(&packet) {
+    ///     Err(value) => println!("Err {:?}", value),
+    ///     Ok(value) => {
+    ///         println!("link: {:?}", value.link);
+    ///         println!("ip: {:?}", value.ip);
+    ///         println!("transport: {:?}", value.transport);
+    ///     }
+    /// }
+    /// ```
+    pub fn from_ethernet_with_fcs(
+        data: &'a [u8],
+    ) -> Result<SlicedPacket<'a>, err::packet::EthFcsSliceError> {
+        let frame = strip_fcs(data)?;
+        CursorSlice::new(frame)
+            .slice_ethernet2()
+            .map_err(err::packet::EthFcsSliceError::Slice)
+    }
+
+    /// Seperates a network packet slice into different slices containing the
+    /// headers from the Linux cooked capture (SLL) header downwards.
+    ///
+    /// SLL headers are present in captures of the Linux "any" device
+    /// (e.g. `tcpdump -i any`). The slicing continues with the VLAN, ARP
+    /// and IP headers based on the `protocol_type` field of the SLL header
+    /// (see [`LinuxSllHeader::payload_ether_type`] for when the field does
+    /// not contain an ether type).
+    ///
+    /// # Example
+    ///
+    /// Basic usage:
+    ///
+    ///```
+    /// # use etherparse::{PacketBuilder, LinuxSllHeader, LinuxSllPacketType, ArpHardwareId, ether_type};
+    /// # let builder = PacketBuilder::
+    /// #    ipv4([192,168,1,1], //source ip
+    /// #         [192,168,1,2], //desitionation ip
+    /// #         20)            //time to life
+    /// #    .udp(21,    //source port
+    /// #         1234); //desitnation port
+    /// # let payload = [1,2,3,4,5,6,7,8];
+    /// # let sll = LinuxSllHeader {
+    /// #     packet_type: LinuxSllPacketType::OUTGOING,
+    /// #     arp_hrd_type: ArpHardwareId::ETHERNET,
+    /// #     sender_address_valid_length: 6,
+    /// #     sender_address: [1,2,3,4,5,6,0,0],
+    /// #     protocol_type: ether_type::IPV4,
+    /// # };
+    /// # let mut packet = Vec::<u8>::with_capacity(
+    /// #     sll.header_len() + builder.size(payload.len())
+    /// # );
+    /// # sll.write(&mut packet).unwrap();
+    /// # builder.write(&mut packet, &payload).unwrap();
+    /// use etherparse::SlicedPacket;
+    ///
+    /// match SlicedPacket::from_linux_sll(&packet) {
+    ///     Err(value) => println!("Err {:?}", value),
+    ///     Ok(value) => {
+    ///         println!("link: {:?}", value.link);
+    ///         println!("vlan: {:?}", value.vlan);
+    ///         println!("ip: {:?}", value.ip);
+    ///         println!("transport: {:?}", value.transport);
+    ///     }
+    /// }
+    /// ```
+    pub fn from_linux_sll(data: &'a [u8]) -> Result<SlicedPacket<'a>, err::packet::EthSliceError> {
+        CursorSlice::new(data).slice_linux_sll()
+    }
+
+    /// Seperates a network packet slice into different slices containing the
+    /// headers from the Linux cooked capture v2 (SLL2) header downwards.
+    ///
+    /// Works the same way as [`SlicedPacket::from_linux_sll`] but expects
+    /// the data to start with a [`LinuxSll2Header`].
+    pub fn from_linux_sll2(data: &'a [u8]) -> Result<SlicedPacket<'a>, err::packet::EthSliceError> {
+        CursorSlice::new(data).slice_linux_sll2()
+    }
+
     /// Seperates a network packet slice into different slices containing the headers using
     /// the given `ether_type` number to identify the first header.
     ///
     /// The result is returned as a [`SlicedPacket`] struct. Currently supported
     /// ether type numbers are:
     ///
+    /// * `ether_type::ARP`
     /// * `ether_type::IPV4`
     /// * `ether_type::IPV6`
     /// * `ether_type::VLAN_TAGGED_FRAME`
     /// * `ether_type::PROVIDER_BRIDGING`
     /// * `ether_type::VLAN_DOUBLE_TAGGED_FRAME`
+    /// * `ether_type::MPLS_UNICAST`
+    /// * `ether_type::MPLS_MULTICAST`
+    /// * `ether_type::PPPOE_DISCOVERY`
+    /// * `ether_type::PPPOE_SESSION`
+    /// * `ether_type::MACSEC`
+    /// * `ether_type::TRANSPARENT_ETHERNET_BRIDGING`
+    ///
+    /// As MPLS does not identify the protocol of its payload the slicing
+    /// after an MPLS label stack only continues with an IPv4 or IPv6 header
+    /// if the first nibble after the bottom of stack entry contains the
+    /// value 4 or 6 (see [`MplsLabelStackSlice`]).
+    ///
+    /// The payload of PPPoE packets is limited to the length given in the
+    /// PPPoE header. Session stage packets are sliced further if the PPP
+    /// header identifies an IPv4 or IPv6 payload, for discovery stage
+    /// packets the payload contains the tags (see [`PppoeTagsIterator`]).
+    ///
+    /// The slicing after a MACsec header only continues if the frame is
+    /// neither encrypted nor modified (see [`MacsecHeaderSlice`]). The
+    /// payload is limited to the secure data (without the integrity check
+    /// value).
+    ///
+    /// `ether_type::TRANSPARENT_ETHERNET_BRIDGING` (used by GRE to
+    /// identify encapsulated ethernet frames) is sliced starting with an
+    /// ethernet II header, the same way as [`SlicedPacket::from_ethernet`].
+    ///
+    /// Values from `1` to `1500` are interpreted as the length of an
+    /// IEEE 802.3 frame. In this case the slicing continues with an
+    /// [`LlcHeaderSlice`] (and [`SnapHeaderSlice`] if indicated by the LLC header).
     ///
     /// If an unsupported ether type is given the given slice will be set as payload
     /// and all other fields will be set to `None`.
@@ -161,20 +388,10 @@ impl<'a> SlicedPacket<'a> {
         ether_type: EtherType,
         data: &'a [u8],
     ) -> Result<SlicedPacket, err::packet::EthSliceError> {
-        use ether_type::*;
-        match ether_type {
-            IPV4 => CursorSlice::new(data).slice_ipv4(),
-            IPV6 => CursorSlice::new(data).slice_ipv6(),
-            VLAN_TAGGED_FRAME | PROVIDER_BRIDGING | VLAN_DOUBLE_TAGGED_FRAME => {
-                CursorSlice::new(data).slice_vlan()
-            }
-            _ => Ok(SlicedPacket {
-                link: None,
-                vlan: None,
-                ip: None,
-                transport: None,
-                payload: data,
-            }),
+        if ether_type::TRANSPARENT_ETHERNET_BRIDGING == ether_type {
+            CursorSlice::new(data).slice_ethernet2()
+        } else {
+            CursorSlice::new(data).slice_ether_type(ether_type)
         }
     }
 
@@ -218,37 +435,563 @@ impl<'a> SlicedPacket<'a> {
         CursorSlice::new(data).slice_ip()
     }
 
+    /// Decodes the payload of an IPsec ESP packet using the "NULL"
+    /// encryption algorithm (rfc2410) and slices the transport layer
+    /// contained in it.
+    ///
+    /// As the encryption algorithm is not part of the packet itself, the
+    /// data after an ESP header is by default treated as an (encrypted)
+    /// payload. If you know that "NULL" encryption is used you can call
+    /// this method to decode the ESP trailer (see [`EspNullPayloadSlice`])
+    /// and the transport header. The length of the integrity check value
+    /// (`icv_len`) depends on the negotiated integrity algorithm (e.g. 12
+    /// for HMAC-SHA1-96).
+    ///
+    /// After a successful call the `payload` field contains the payload of
+    /// the transport layer (padding, trailer & integrity check value are
+    /// not included). In case the packet contains no ESP header, is
+    /// fragmented or the transport layer was already decoded the packet is
+    /// returned unchanged.
+    ///
+    /// The `layer_start_offset` of returned length errors is relative to
+    /// the start of the IP header.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use etherparse::{ip_number, EspHeader, Ipv4Header, SlicedPacket, TransportSlice, UdpHeader};
+    ///
+    /// let udp = UdpHeader {
+    ///     source_port: 1234,
+    ///     destination_port: 5678,
+    ///     length: 8,
+    ///     checksum: 0,
+    /// };
+    /// let esp = EspHeader { spi: 1, sequence_number: 2 };
+    /// let trailer = [0, ip_number::UDP.0];
+    /// let ip = Ipv4Header::new(
+    ///     (EspHeader::LEN + UdpHeader::LEN + trailer.len()) as u16,
+    ///     20,
+    ///     ip_number::ENCAP_SEC,
+    ///     [192, 168, 1, 1],
+    ///     [192, 168, 1, 2],
+    /// ).unwrap();
+    ///
+    /// let mut packet = Vec::new();
+    /// ip.write(&mut packet).unwrap();
+    /// esp.write(&mut packet).unwrap();
+    /// udp.write(&mut packet).unwrap();
+    /// packet.extend_from_slice(&trailer);
+    ///
+    /// // by default the data after the ESP header is treated as payload
+    /// let sliced = SlicedPacket::from_ip(&packet).unwrap();
+    /// assert!(sliced.transport.is_none());
+    ///
+    /// // opt in to decode "NULL" encrypted payloads (without ICV)
+    /// let sliced = sliced.decode_esp_null(0).unwrap();
+    /// match sliced.transport {
+    ///     Some(TransportSlice::Udp(udp)) => assert_eq!(udp.source_port(), 1234),
+    ///     _ => panic!("expected udp"),
+    /// }
+    /// ```
+    pub fn decode_esp_null(
+        self,
+        icv_len: usize,
+    ) -> Result<SlicedPacket<'a>, err::packet::IpSliceError> {
+        use err::packet::IpSliceError::*;
+
+        let (ip_start, ip_payload) = match &self.ip {
+            Some(InternetSlice::Ipv4(s)) => (s.header().slice().as_ptr(), s.payload().clone()),
+            Some(InternetSlice::Ipv6(s)) => (s.header().slice().as_ptr(), s.payload().clone()),
+            None => return Ok(self),
+        };
+        if ip_number::ENCAP_SEC != ip_payload.ip_number
+            || ip_payload.fragmented
+            || self.transport.is_some()
+        {
+            return Ok(self);
+        }
+
+        // SAFETY: The payload is a subslice of the ip packet therefor
+        // calculating the offset from it is safe and the result should
+        // always be a positive number.
+        let offset = unsafe { ip_payload.payload.as_ptr().offset_from(ip_start) as usize };
+        let esp =
+            EspNullPayloadSlice::from_slice(ip_payload.payload, icv_len).map_err(|mut err| {
+                err.layer_start_offset += offset;
+                err.len_source = ip_payload.len_source;
+                Len(err)
+            })?;
+
+        let mut cursor = CursorSlice {
+            data: esp.payload(),
+            slice: esp.payload(),
+            offset,
+            len_source: ip_payload.len_source,
+            result: self,
+        };
+        match esp.next_header() {
+            ip_number::ICMP => cursor.slice_icmp4().map_err(Len),
+            ip_number::UDP => cursor.slice_udp().map_err(Len),
+            ip_number::TCP => cursor.slice_tcp().map_err(|err| {
+                use err::tcp::HeaderSliceError as I;
+                match err {
+                    I::Len(err) => Len(err),
+                    I::Content(err) => Tcp(err),
+                }
+            }),
+            ip_number::IPV6_ICMP => cursor.slice_icmp6().map_err(Len),
+            value => {
+                cursor.result.transport = Some(TransportSlice::Unknown(value));
+                Ok(cursor.slice_payload())
+            }
+        }
+    }
+
+    /// Decodes IP-in-IP, 6in4 & 4in6 tunnels (IP packets with the
+    /// payload ip number [`ip_number::IPV4`] or [`ip_number::IPV6`]) and
+    /// slices the transport layer of the innermost IP packet.
+    ///
+    /// By default the slicing stops after the first IP header and the
+    /// tunneled packet is treated as payload (`transport` is set to
+    /// [`TransportSlice::Unknown`]). After calling this method the outer
+    /// IP header stays in the `ip` field, the innermost IP header is
+    /// stored in the `inner_ip` field and the `transport` & `payload`
+    /// fields describe the content of the innermost packet.
+    ///
+    /// `max_depth` limits the number of tunnels that are decoded. In case
+    /// of nested tunnels only the outermost & innermost IP headers are
+    /// kept (intermediate headers can be accessed by slicing the payload
+    /// of the outer IP header). Fragmented packets are not decoded.
+    ///
+    /// The `layer_start_offset` of returned length errors is relative to
+    /// the start of the innermost IP header present when the method is
+    /// called (the outer IP header if no tunnels were decoded before).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use etherparse::{PacketBuilder, SlicedPacket, TransportSlice};
+    ///
+    /// let builder = PacketBuilder::
+    ///     ipv4([192,168,1,1], [192,168,1,2], 20)
+    ///     .ipv6([1;16], [2;16], 20)
+    ///     .udp(21, 1234);
+    /// let mut packet = Vec::with_capacity(builder.size(4));
+    /// builder.write(&mut packet, &[1,2,3,4]).unwrap();
+    ///
+    /// // by default the tunneled packet is treated as payload
+    /// let sliced = SlicedPacket::from_ip(&packet).unwrap();
+    /// assert_eq!(None, sliced.inner_ip);
+    ///
+    /// // opt in to decode the tunnel
+    /// let sliced = sliced.decode_ip_tunnels(1).unwrap();
+    /// assert!(sliced.inner_ip.as_ref().unwrap().ipv6().is_some());
+    /// match sliced.transport {
+    ///     Some(TransportSlice::Udp(udp)) => assert_eq!(udp.source_port(), 21),
+    ///     _ => panic!("expected udp"),
+    /// }
+    /// assert_eq!(&[1,2,3,4], sliced.payload);
+    /// ```
+    pub fn decode_ip_tunnels(
+        self,
+        max_depth: usize,
+    ) -> Result<SlicedPacket<'a>, err::packet::IpSliceError> {
+        let mut result = self;
+        // offset of the innermost ip header relative to the ip header
+        // that was the innermost one when the method was called
+        let mut ip_offset = 0;
+        for _ in 0..max_depth {
+            let (ip_payload, ip_headers_len) = match result.inner_ip.as_ref().or(result.ip.as_ref())
+            {
+                Some(ip) => (ip.payload().clone(), ip_headers_len(ip)),
+                None => return Ok(result),
+            };
+            let is_tunnel = matches!(
+                result.transport,
+                Some(TransportSlice::Unknown(ip_number::IPV4))
+                    | Some(TransportSlice::Unknown(ip_number::IPV6))
+            );
+            if false == is_tunnel || ip_payload.fragmented {
+                return Ok(result);
+            }
+
+            // keep the outer ip header & trailer
+            let ip = result.ip.take();
+            let link_trailer = result.link_trailer;
+            result.inner_ip = None;
+            result.transport = None;
+
+            let offset = ip_offset + ip_headers_len;
+            let mut sliced = CursorSlice {
+                data: ip_payload.payload,
+                slice: ip_payload.payload,
+                offset,
+                len_source: ip_payload.len_source,
+                result,
+            }
+            .slice_ip()
+            .map_err(|err| {
+                use err::packet::IpSliceError::*;
+                match err {
+                    // the inner packet is limited by the outer length field
+                    Len(mut err) if LenSource::Slice == err.len_source => {
+                        err.len_source = ip_payload.len_source;
+                        Len(err)
+                    }
+                    err => err,
+                }
+            })?;
+            sliced.inner_ip = sliced.ip.take();
+            sliced.ip = ip;
+            sliced.link_trailer = link_trailer;
+            result = sliced;
+            ip_offset = offset;
+        }
+        Ok(result)
+    }
+
+    /// Slices the packet encapsulated in a GRE header (present in the
+    /// `gre` field) as a second [`SlicedPacket`].
+    ///
+    /// The slicing starts with the header identified by the protocol type
+    /// of the GRE header (see [`SlicedPacket::from_ether_type`] for the
+    /// supported values, e.g. IPv4, IPv6, MPLS or ethernet frames via
+    /// `ether_type::TRANSPARENT_ETHERNET_BRIDGING`). `None` is returned if
+    /// no GRE header is present.
+    ///
+    /// The `layer_start_offset` of returned errors is relative to the
+    /// start of the GRE payload.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use etherparse::{PacketBuilder, SlicedPacket, TransportSlice};
+    ///
+    /// let builder = PacketBuilder::
+    ///     ipv4([192,168,1,1], [192,168,1,2], 20)
+    ///     .gre()
+    ///     .ipv6([1;16], [2;16], 20)
+    ///     .udp(21, 1234);
+    /// let mut packet = Vec::with_capacity(builder.size(4));
+    /// builder.write(&mut packet, &[1,2,3,4]).unwrap();
+    ///
+    /// // the outer packet stops after the GRE header
+    /// let outer = SlicedPacket::from_ip(&packet).unwrap();
+    /// assert!(outer.gre.is_some());
+    ///
+    /// // slice the encapsulated packet
+    /// let inner = outer.slice_gre_payload().unwrap().unwrap();
+    /// assert!(inner.ip.as_ref().unwrap().ipv6().is_some());
+    /// match inner.transport {
+    ///     Some(TransportSlice::Udp(udp)) => assert_eq!(udp.source_port(), 21),
+    ///     _ => panic!("expected udp"),
+    /// }
+    /// assert_eq!(&[1,2,3,4], inner.payload);
+    /// ```
+    pub fn slice_gre_payload(
+        &self,
+    ) -> Option<Result<SlicedPacket<'a>, err::packet::EthSliceError>> {
+        self.gre
+            .as_ref()
+            .map(|gre| SlicedPacket::from_ether_type(gre.protocol_type(), self.payload))
+    }
+
+    /// Decodes VXLAN, Geneve & GTP-U headers in UDP datagrams based on the
+    /// well-known UDP destination ports ([`VxlanHeader::UDP_PORT`],
+    /// [`VxlanHeader::GPE_UDP_PORT`], [`GeneveHeader::UDP_PORT`] &
+    /// [`GtpuHeader::UDP_PORT`]).
+    ///
+    /// By default the slicing stops after the UDP header and the
+    /// encapsulated frame is treated as payload. After calling this
+    /// method the `vxlan`, `geneve` or `gtpu` field is set and the `payload`
+    /// field contains the encapsulated frame or packet (which can be sliced
+    /// via [`SlicedPacket::slice_vxlan_payload`],
+    /// [`SlicedPacket::slice_geneve_payload`] or
+    /// [`SlicedPacket::slice_gtpu_payload`]). Packets with other
+    /// destination ports are returned unchanged.
+    ///
+    /// The `layer_start_offset` of returned length errors is relative to
+    /// the start of the innermost IP header (`inner_ip` if present,
+    /// otherwise `ip`).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use etherparse::{PacketBuilder, SlicedPacket, TransportSlice, VxlanHeader, Vni};
+    ///
+    /// // inner ethernet frame
+    /// let inner_builder = PacketBuilder::
+    ///     ethernet2([1,2,3,4,5,6], [7,8,9,10,11,12])
+    ///     .ipv4([10,0,0,1], [10,0,0,2], 20)
+    ///     .udp(21, 1234);
+    /// let mut inner = Vec::with_capacity(inner_builder.size(4));
+    /// inner_builder.write(&mut inner, &[1,2,3,4]).unwrap();
+    ///
+    /// // outer packet
+    /// let builder = PacketBuilder::
+    ///     ipv4([192,168,1,1], [192,168,1,2], 20)
+    ///     .udp(49152, VxlanHeader::UDP_PORT)
+    ///     .vxlan(Vni::try_new(1234).unwrap());
+    /// let mut packet = Vec::with_capacity(builder.size(inner.len()));
+    /// builder.write(&mut packet, &inner).unwrap();
+    ///
+    /// let outer = SlicedPacket::from_ip(&packet).unwrap().decode_udp_tunnels().unwrap();
+    /// assert_eq!(1234, outer.vxlan.as_ref().unwrap().vni().value());
+    ///
+    /// // slice the encapsulated ethernet frame
+    /// let inner = outer.slice_vxlan_payload().unwrap().unwrap();
+    /// match inner.transport {
+    ///     Some(TransportSlice::Udp(udp)) => assert_eq!(udp.source_port(), 21),
+    ///     _ => panic!("expected udp"),
+    /// }
+    /// assert_eq!(&[1,2,3,4], inner.payload);
+    /// ```
+    pub fn decode_udp_tunnels(self) -> Result<SlicedPacket<'a>, err::packet::IpSliceError> {
+        let destination_port = match &self.transport {
+            Some(TransportSlice::Udp(udp)) => udp.destination_port(),
+            _ => return Ok(self),
+        };
+        match destination_port {
+            VxlanHeader::UDP_PORT | VxlanHeader::GPE_UDP_PORT => self.decode_vxlan(),
+            GeneveHeader::UDP_PORT => self.decode_geneve(),
+            GtpuHeader::UDP_PORT => self.decode_gtpu(),
+            _ => Ok(self),
+        }
+    }
+
+    /// Decodes the payload of an UDP datagram as a VXLAN or VXLAN-GPE
+    /// header (independent of the UDP ports).
+    ///
+    /// In case the packet contains no UDP header or a VXLAN, Geneve or
+    /// GTP-U header was already decoded the packet is returned unchanged.
+    ///
+    /// The `layer_start_offset` of returned length errors is relative to
+    /// the start of the innermost IP header (`inner_ip` if present,
+    /// otherwise `ip`).
+    pub fn decode_vxlan(self) -> Result<SlicedPacket<'a>, err::packet::IpSliceError> {
+        match self.udp_payload_cursor() {
+            Some(cursor) => cursor.slice_vxlan().map_err(err::packet::IpSliceError::Len),
+            None => Ok(self),
+        }
+    }
+
+    /// Decodes the payload of an UDP datagram as a Geneve header
+    /// (independent of the UDP ports).
+    ///
+    /// In case the packet contains no UDP header or a VXLAN, Geneve or
+    /// GTP-U header was already decoded the packet is returned unchanged.
+    ///
+    /// The `layer_start_offset` of returned length errors is relative to
+    /// the start of the innermost IP header (`inner_ip` if present,
+    /// otherwise `ip`).
+    pub fn decode_geneve(self) -> Result<SlicedPacket<'a>, err::packet::IpSliceError> {
+        use err::packet::IpSliceError::*;
+        match self.udp_payload_cursor() {
+            Some(cursor) => cursor.slice_geneve().map_err(|err| {
+                use err::geneve::HeaderSliceError as I;
+                match err {
+                    I::Len(err) => Len(err),
+                    I::Content(err) => Geneve(err),
+                }
+            }),
+            None => Ok(self),
+        }
+    }
+
+    /// Decodes the payload of an UDP datagram as a GTP-U header
+    /// (independent of the UDP ports).
+    ///
+    /// The `payload` field is limited to the length given in the GTP-U
+    /// header. In case the packet contains no UDP header or a VXLAN, Geneve
+    /// or GTP-U header was already decoded the packet is returned unchanged.
+    ///
+    /// The `layer_start_offset` of returned length errors is relative to
+    /// the start of the innermost IP header (`inner_ip` if present,
+    /// otherwise `ip`).
+    pub fn decode_gtpu(self) -> Result<SlicedPacket<'a>, err::packet::IpSliceError> {
+        use err::packet::IpSliceError::*;
+        match self.udp_payload_cursor() {
+            Some(cursor) => cursor.slice_gtpu().map_err(|err| {
+                use err::gtpu::HeaderSliceError as I;
+                match err {
+                    I::Len(err) => Len(err),
+                    I::Content(err) => Gtpu(err),
+                }
+            }),
+            None => Ok(self),
+        }
+    }
+
+    /// Returns a cursor starting at the UDP payload (`None` in case no
+    /// UDP header is present or the payload was already decoded).
+    fn udp_payload_cursor(&self) -> Option<CursorSlice<'a>> {
+        let ip = self.inner_ip.as_ref().or(self.ip.as_ref())?;
+        let len_source = match ip {
+            InternetSlice::Ipv4(s) => s.payload().len_source,
+            InternetSlice::Ipv6(s) => s.payload().len_source,
+        };
+        let is_udp = matches!(self.transport, Some(TransportSlice::Udp(_)));
+        if !is_udp || self.vxlan.is_some() || self.geneve.is_some() || self.gtpu.is_some() {
+            return None;
+        }
+        Some(CursorSlice {
+            data: self.payload,
+            slice: self.payload,
+            offset: ip_headers_len(ip) + UdpHeader::LEN,
+            len_source,
+            result: self.clone(),
+        })
+    }
+
+    /// Slices the ethernet frame (or other packet for VXLAN-GPE)
+    /// encapsulated in a VXLAN header (present in the `vxlan` field) as a
+    /// second [`SlicedPacket`].
+    ///
+    /// `None` is returned if no VXLAN header is present or the next
+    /// protocol of a VXLAN-GPE header has no matching ether type (e.g.
+    /// [`VxlanGpeNextProtocol::NSH`]).
+    ///
+    /// The `layer_start_offset` of returned errors is relative to the
+    /// start of the VXLAN payload.
+    pub fn slice_vxlan_payload(
+        &self,
+    ) -> Option<Result<SlicedPacket<'a>, err::packet::EthSliceError>> {
+        self.vxlan
+            .as_ref()
+            .and_then(|vxlan| vxlan.payload_ether_type())
+            .map(|ether_type| SlicedPacket::from_ether_type(ether_type, self.payload))
+    }
+
+    /// Slices the packet encapsulated in a Geneve header (present in the
+    /// `geneve` field) as a second [`SlicedPacket`].
+    ///
+    /// The slicing starts with the header identified by the protocol type
+    /// of the Geneve header (see [`SlicedPacket::from_ether_type`] for the
+    /// supported values). `None` is returned if no Geneve header is present.
+    ///
+    /// The `layer_start_offset` of returned errors is relative to the
+    /// start of the Geneve payload.
+    pub fn slice_geneve_payload(
+        &self,
+    ) -> Option<Result<SlicedPacket<'a>, err::packet::EthSliceError>> {
+        self.geneve
+            .as_ref()
+            .map(|geneve| SlicedPacket::from_ether_type(geneve.protocol_type(), self.payload))
+    }
+
+    /// Slices the IPv4 or IPv6 packet encapsulated in a GTP-U header
+    /// (present in the `gtpu` field) as a second [`SlicedPacket`] via
+    /// [`SlicedPacket::from_ip`].
+    ///
+    /// `None` is returned if no GTP-U header is present or the message
+    /// type is not [`GtpuMessageType::G_PDU`] (only G-PDUs contain user
+    /// packets).
+    ///
+    /// The `layer_start_offset` of returned errors is relative to the
+    /// start of the GTP-U payload.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use etherparse::{GtpuHeader, PacketBuilder, SlicedPacket, TransportSlice};
+    ///
+    /// // inner user ip packet
+    /// let inner_builder = PacketBuilder::
+    ///     ipv4([10,0,0,1], [10,0,0,2], 20)
+    ///     .udp(21, 1234);
+    /// let mut inner = Vec::with_capacity(inner_builder.size(4));
+    /// inner_builder.write(&mut inner, &[1,2,3,4]).unwrap();
+    ///
+    /// // outer packet
+    /// let builder = PacketBuilder::
+    ///     ipv4([192,168,1,1], [192,168,1,2], 20)
+    ///     .udp(GtpuHeader::UDP_PORT, GtpuHeader::UDP_PORT)
+    ///     .gtpu(0x1234_5678);
+    /// let mut packet = Vec::with_capacity(builder.size(inner.len()));
+    /// builder.write(&mut packet, &inner).unwrap();
+    ///
+    /// let outer = SlicedPacket::from_ip(&packet).unwrap().decode_udp_tunnels().unwrap();
+    /// assert_eq!(0x1234_5678, outer.gtpu.as_ref().unwrap().teid());
+    ///
+    /// // slice the encapsulated ip packet
+    /// let inner = outer.slice_gtpu_payload().unwrap().unwrap();
+    /// match inner.transport {
+    ///     Some(TransportSlice::Udp(udp)) => assert_eq!(udp.source_port(), 21),
+    ///     _ => panic!("expected udp"),
+    /// }
+    /// assert_eq!(&[1,2,3,4], inner.payload);
+    /// ```
+    pub fn slice_gtpu_payload(
+        &self,
+    ) -> Option<Result<SlicedPacket<'a>, err::packet::IpSliceError>> {
+        self.gtpu
+            .as_ref()
+            .filter(|gtpu| GtpuMessageType::G_PDU == gtpu.message_type())
+            .map(|_| SlicedPacket::from_ip(self.payload))
+    }
+
     /// If the slice in the `payload` field contains an ethernet payload
     /// this method returns the ether type number describing the payload type.
     ///
-    /// The ether type number can come from an ethernet II header or a
-    /// VLAN header depending on which headers are present.
+    /// The ether type number can come from an ethernet II header, a
+    /// Linux cooked capture header, a MACsec header, a VLAN header, a
+    /// SNAP header, a GRE header, a VXLAN header or a Geneve header
+    /// depending on which headers are present.
     ///
-    /// In case that `ip` and/or `transport` fields are the filled None
-    /// is returned, as the payload contents then are defined by a
-    /// lower layer protocol described in these fields.
+    /// In case that `mpls`, `pppoe`, `ppp`, `arp`, `ip` and/or `transport`
+    /// fields are the filled None is returned, as the payload contents then
+    /// are defined by a lower layer protocol described in these fields.
     pub fn payload_ether_type(&self) -> Option<EtherType> {
-        if self.ip.is_some() || self.transport.is_some() {
+        if let Some(vxlan) = &self.vxlan {
+            vxlan.payload_ether_type()
+        } else if let Some(geneve) = &self.geneve {
+            Some(geneve.protocol_type())
+        } else if let Some(gre) = &self.gre {
+            Some(gre.protocol_type())
+        } else if self.mpls.is_some()
+            || self.pppoe.is_some()
+            || self.ppp.is_some()
+            || self.arp.is_some()
+            || self.ip.is_some()
+            || self.transport.is_some()
+        {
+            None
+        } else if let Some(snap) = &self.snap {
+            snap.payload_ether_type()
+        } else if self.llc.is_some() {
             None
         } else if let Some(vlan) = &self.vlan {
-            use VlanSlice::*;
-            match vlan {
-                SingleVlan(s) => Some(s.ether_type()),
-                DoubleVlan(d) => Some(d.inner().ether_type()),
-            }
+            Some(vlan.payload_ether_type())
+        } else if let Some(macsec) = &self.macsec {
+            macsec.payload_ether_type()
         } else if let Some(link) = &self.link {
-            use LinkSlice::*;
-            match link {
-                Ethernet2(eth) => Some(eth.ether_type()),
-            }
+            link.payload_ether_type()
         } else {
             None
         }
     }
 }
 
+/// Returns the length of the IP header including the extension headers
+/// (offset of the IP payload relative to the start of the IP header).
+fn ip_headers_len(ip: &InternetSlice) -> usize {
+    match ip {
+        InternetSlice::Ipv4(s) => {
+            let exts = s.extensions();
+            s.header().slice().len()
+                + exts.auth.map(|auth| auth.slice().len()).unwrap_or(0)
+                + exts.esp.map(|esp| esp.slice().len()).unwrap_or(0)
+        }
+        InternetSlice::Ipv6(s) => s.header().slice().len() + s.extensions().slice().len(),
+    }
+}
+
 ///Helper class for slicing packets
 struct CursorSlice<'a> {
+    /// Data from the start of the decoded data until the end of the frame
+    /// (used to determine the link trailer).
+    pub data: &'a [u8],
     pub slice: &'a [u8],
     pub offset: usize,
     pub len_source: LenSource,
@@ -258,19 +1001,46 @@ struct CursorSlice<'a> {
 impl<'a> CursorSlice<'a> {
     pub fn new(slice: &'a [u8]) -> CursorSlice<'a> {
         CursorSlice {
+            data: slice,
             slice,
             offset: 0,
             len_source: LenSource::Slice,
             result: SlicedPacket {
                 link: None,
                 vlan: None,
+                macsec: None,
+                llc: None,
+                snap: None,
+                mpls: None,
+                pppoe: None,
+                ppp: None,
+                arp: None,
                 ip: None,
+                inner_ip: None,
+                gre: None,
                 transport: None,
+                vxlan: None,
+                geneve: None,
+                gtpu: None,
                 payload: slice,
+                link_trailer: &[],
             },
         }
     }
 
+    /// Returns the data after the given subslice until the end of the
+    /// frame.
+    fn data_after(&self, subslice: &'a [u8]) -> &'a [u8] {
+        unsafe {
+            // SAFETY: Safe as the subslice is part of self.data.
+            let end = subslice
+                .as_ptr()
+                .add(subslice.len())
+                .offset_from(self.data.as_ptr()) as usize;
+            core::slice::from_raw_parts(self.data.as_ptr().add(end), self.data.len() - end)
+        }
+    }
+
     fn move_by_slice(&mut self, other: &'a [u8]) {
         unsafe {
             use core::slice::from_raw_parts;
@@ -284,7 +1054,6 @@ impl<'a> CursorSlice<'a> {
 
     pub fn slice_ethernet2(mut self) -> Result<SlicedPacket<'a>, err::packet::EthSliceError> {
         use err::packet::EthSliceError::*;
-        use ether_type::*;
         use LinkSlice::*;
 
         let result = Ethernet2HeaderSlice::from_slice(self.slice)
@@ -298,70 +1067,366 @@ impl<'a> CursorSlice<'a> {
         self.result.link = Some(Ethernet2(result));
 
         //continue parsing (if required)
-        match ether_type {
-            IPV4 => self.slice_ipv4(),
-            IPV6 => self.slice_ipv6(),
-            VLAN_TAGGED_FRAME | PROVIDER_BRIDGING | VLAN_DOUBLE_TAGGED_FRAME => self.slice_vlan(),
-            _ => Ok(self.slice_payload()),
-        }
+        self.slice_ether_type(ether_type)
     }
 
-    pub fn slice_vlan(mut self) -> Result<SlicedPacket<'a>, err::packet::EthSliceError> {
+    pub fn slice_linux_sll(mut self) -> Result<SlicedPacket<'a>, err::packet::EthSliceError> {
         use err::packet::EthSliceError::*;
-        use ether_type::*;
-        use VlanSlice::*;
 
-        let outer = SingleVlanHeaderSlice::from_slice(self.slice)
+        let result = LinuxSllHeaderSlice::from_slice(self.slice)
             .map_err(|err| Len(err.add_offset(self.offset)))?;
 
-        //check if it is a double vlan header
-        match outer.ether_type() {
-            //in case of a double vlan header continue with the inner
-            VLAN_TAGGED_FRAME | PROVIDER_BRIDGING | VLAN_DOUBLE_TAGGED_FRAME => {
-                self.move_by_slice(outer.slice());
-                let inner = SingleVlanHeaderSlice::from_slice(self.slice)
-                    .map_err(|err| Len(err.add_offset(self.offset)))?;
-                self.move_by_slice(inner.slice());
-
-                let inner_ether_type = inner.ether_type();
-                self.result.vlan = Some(DoubleVlan(DoubleVlanHeaderSlice {
-                    // SAFETY: Safe as the lenght of the slice was previously verified.
-                    slice: unsafe {
-                        core::slice::from_raw_parts(
-                            outer.slice().as_ptr(),
-                            outer.slice().len() + inner.slice().len(),
-                        )
-                    },
-                }));
+        //cache the ether_type for later
+        let ether_type = result.payload_ether_type();
 
-                match inner_ether_type {
-                    IPV4 => self.slice_ipv4(),
-                    IPV6 => self.slice_ipv6(),
-                    _ => Ok(self.slice_payload()),
-                }
-            }
-            value => {
-                //set the vlan header and continue the normal parsing
-                self.move_by_slice(outer.slice());
-                self.result.vlan = Some(SingleVlan(outer));
-
-                match value {
-                    IPV4 => self.slice_ipv4(),
-                    IPV6 => self.slice_ipv6(),
-                    _ => Ok(self.slice_payload()),
-                }
-            }
+        //set the new data
+        self.move_by_slice(result.slice());
+        self.result.link = Some(LinkSlice::LinuxSll(result));
+
+        //continue parsing (if required)
+        match ether_type {
+            Some(ether_type) => self.slice_ether_type(ether_type),
+            None => Ok(self.slice_payload()),
         }
     }
 
-    pub fn slice_ip(mut self) -> Result<SlicedPacket<'a>, err::packet::IpSliceError> {
-        use err::packet::IpSliceError::*;
+    pub fn slice_linux_sll2(mut self) -> Result<SlicedPacket<'a>, err::packet::EthSliceError> {
+        use err::packet::EthSliceError::*;
 
-        // slice header, extension headers and identify payload range
-        let ip = InternetSlice::from_ip_slice(self.slice).map_err(|err| {
-            use err::ip::SliceError as I;
-            match err {
-                I::Len(mut err) => {
+        let result = LinuxSll2HeaderSlice::from_slice(self.slice)
+            .map_err(|err| Len(err.add_offset(self.offset)))?;
+
+        //cache the ether_type for later
+        let ether_type = result.payload_ether_type();
+
+        //set the new data
+        self.move_by_slice(result.slice());
+        self.result.link = Some(LinkSlice::LinuxSll2(result));
+
+        //continue parsing (if required)
+        match ether_type {
+            Some(ether_type) => self.slice_ether_type(ether_type),
+            None => Ok(self.slice_payload()),
+        }
+    }
+
+    /// Continues slicing with the header identified by the given ether type.
+    fn slice_ether_type(
+        self,
+        ether_type: EtherType,
+    ) -> Result<SlicedPacket<'a>, err::packet::EthSliceError> {
+        use ether_type::*;
+        match ether_type {
+            ARP => self.slice_arp(),
+            IPV4 => self.slice_ipv4(),
+            IPV6 => self.slice_ipv6(),
+            VLAN_TAGGED_FRAME | PROVIDER_BRIDGING | VLAN_DOUBLE_TAGGED_FRAME => self.slice_vlan(),
+            MPLS_UNICAST | MPLS_MULTICAST => self.slice_mpls(),
+            PPPOE_DISCOVERY | PPPOE_SESSION => self.slice_pppoe(),
+            MACSEC => self.slice_macsec(),
+            value => match ieee802_3_len(value) {
+                Some(len) => self.slice_llc(len),
+                None => Ok(self.slice_payload()),
+            },
+        }
+    }
+
+    /// Continues slicing after the VLAN header(s) with the header
+    /// identified by the given ether type.
+    fn slice_vlan_payload(
+        self,
+        ether_type: EtherType,
+    ) -> Result<SlicedPacket<'a>, err::packet::EthSliceError> {
+        use ether_type::*;
+        match ether_type {
+            ARP => self.slice_arp(),
+            IPV4 => self.slice_ipv4(),
+            IPV6 => self.slice_ipv6(),
+            MPLS_UNICAST | MPLS_MULTICAST => self.slice_mpls(),
+            PPPOE_DISCOVERY | PPPOE_SESSION => self.slice_pppoe(),
+            value => match ieee802_3_len(value) {
+                Some(len) => self.slice_llc(len),
+                None => Ok(self.slice_payload()),
+            },
+        }
+    }
+
+    pub fn slice_vlan(mut self) -> Result<SlicedPacket<'a>, err::packet::EthSliceError> {
+        use err::packet::EthSliceError::*;
+        use ether_type::*;
+        use VlanSlice::*;
+
+        let start = self.slice;
+
+        //read tags until a tag with a non vlan ether type is encountered
+        let mut num_tags = 0;
+        let payload_ether_type = loop {
+            let tag = SingleVlanHeaderSlice::from_slice(self.slice)
+                .map_err(|err| Len(err.add_offset(self.offset)))?;
+            self.move_by_slice(tag.slice());
+            num_tags += 1;
+            match tag.ether_type() {
+                VLAN_TAGGED_FRAME | PROVIDER_BRIDGING | VLAN_DOUBLE_TAGGED_FRAME => {}
+                value => break value,
+            }
+        };
+
+        // SAFETY: Safe as the lenght of the slice was previously verified.
+        let slice = unsafe {
+            core::slice::from_raw_parts(start.as_ptr(), num_tags * SingleVlanHeader::LEN)
+        };
+
+        //set the vlan header and continue the normal parsing
+        self.result.vlan = Some(match num_tags {
+            // SAFETY: Safe as the slice has the length of SingleVlanHeader::LEN (4).
+            1 => SingleVlan(unsafe { SingleVlanHeaderSlice::from_slice_unchecked(slice) }),
+            2 => DoubleVlan(DoubleVlanHeaderSlice { slice }),
+            _ => MultiVlan(VlanStackSlice { slice }),
+        });
+
+        self.slice_vlan_payload(payload_ether_type)
+    }
+
+    pub fn slice_macsec(mut self) -> Result<SlicedPacket<'a>, err::packet::EthSliceError> {
+        use err::packet::EthSliceError::*;
+
+        let macsec = MacsecHeaderSlice::from_slice(self.slice).map_err(|err| {
+            use err::macsec::HeaderSliceError as I;
+            match err {
+                I::Len(mut err) => {
+                    err.len_source = self.len_source;
+                    Len(err.add_offset(self.offset))
+                }
+                I::Content(err) => Macsec(err),
+            }
+        })?;
+
+        //determine the length of the secure data (after the SecTAG)
+        let sectag_len = macsec.sectag_len();
+        let short_len = usize::from(macsec.short_len());
+        let secure_data_len = if 0 != short_len {
+            short_len
+        } else {
+            self.slice
+                .len()
+                .saturating_sub(sectag_len + MacsecHeaderSlice::ICV_LEN)
+        };
+
+        //check that the secure data & icv are present
+        let required_len = sectag_len + secure_data_len + MacsecHeaderSlice::ICV_LEN;
+        if self.slice.len() < required_len {
+            return Err(Len(err::LenError {
+                required_len,
+                len: self.slice.len(),
+                len_source: self.len_source,
+                layer: err::Layer::MacsecHeader,
+                layer_start_offset: self.offset,
+            }));
+        }
+
+        //limit the slice to the secure data (cut off icv & padding)
+        // SAFETY: Safe as the slice length was verified to be at least required_len.
+        self.slice = unsafe {
+            core::slice::from_raw_parts(self.slice.as_ptr(), sectag_len + secure_data_len)
+        };
+        if 0 != short_len {
+            self.len_source = LenSource::MacsecShortLen;
+        }
+
+        //exclude the icv (and the data after it) from the link trailer
+        // SAFETY: Safe as self.slice is a subslice of self.data.
+        self.data = unsafe {
+            let end = self
+                .slice
+                .as_ptr()
+                .add(self.slice.len())
+                .offset_from(self.data.as_ptr()) as usize;
+            core::slice::from_raw_parts(self.data.as_ptr(), end)
+        };
+
+        //check the ether type of unencrypted frames is part of the secure data
+        if self.slice.len() < macsec.header_len() {
+            return Err(Len(err::LenError {
+                required_len: macsec.header_len(),
+                len: self.slice.len(),
+                len_source: self.len_source,
+                layer: err::Layer::MacsecHeader,
+                layer_start_offset: self.offset,
+            }));
+        }
+
+        //set the new data
+        let ether_type = macsec.payload_ether_type();
+        self.move_by_slice(macsec.slice());
+        self.result.macsec = Some(macsec);
+
+        //continue parsing (if the payload is not encrypted or modified)
+        match ether_type {
+            None | Some(ether_type::MACSEC) => Ok(self.slice_payload()),
+            Some(ether_type) => self.slice_ether_type(ether_type),
+        }
+    }
+
+    pub fn slice_llc(mut self, len: u16) -> Result<SlicedPacket<'a>, err::packet::EthSliceError> {
+        use err::packet::EthSliceError::*;
+
+        //limit the slice to the length given in the type/length field
+        let len = usize::from(len);
+        if self.slice.len() < len {
+            return Err(Len(err::LenError {
+                required_len: len,
+                len: self.slice.len(),
+                len_source: self.len_source,
+                layer: err::Layer::LlcHeader,
+                layer_start_offset: self.offset,
+            }));
+        }
+        // SAFETY: Safe as the slice length was verified to be at least len.
+        self.slice = unsafe { core::slice::from_raw_parts(self.slice.as_ptr(), len) };
+        self.len_source = LenSource::Ieee802_3Len;
+
+        let llc = LlcHeaderSlice::from_slice(self.slice).map_err(|mut err| {
+            err.len_source = self.len_source;
+            Len(err.add_offset(self.offset))
+        })?;
+
+        //set the new data
+        let is_snap = llc.is_snap();
+        self.move_by_slice(llc.slice());
+        self.result.llc = Some(llc);
+
+        if false == is_snap {
+            return Ok(self.slice_payload());
+        }
+
+        let snap = SnapHeaderSlice::from_slice(self.slice).map_err(|mut err| {
+            err.len_source = self.len_source;
+            Len(err.add_offset(self.offset))
+        })?;
+
+        //set the new data
+        let ether_type = snap.payload_ether_type();
+        self.move_by_slice(snap.slice());
+        self.result.snap = Some(snap);
+
+        //continue parsing (if required)
+        use ether_type::*;
+        match ether_type {
+            Some(ARP) => self.slice_arp(),
+            Some(IPV4) => self.slice_ipv4(),
+            Some(IPV6) => self.slice_ipv6(),
+            Some(MPLS_UNICAST) | Some(MPLS_MULTICAST) => self.slice_mpls(),
+            Some(PPPOE_DISCOVERY) | Some(PPPOE_SESSION) => self.slice_pppoe(),
+            _ => Ok(self.slice_payload()),
+        }
+    }
+
+    pub fn slice_mpls(mut self) -> Result<SlicedPacket<'a>, err::packet::EthSliceError> {
+        use err::packet::EthSliceError::*;
+
+        let result = MplsLabelStackSlice::from_slice(self.slice).map_err(|mut err| {
+            err.len_source = self.len_source;
+            Len(err.add_offset(self.offset))
+        })?;
+
+        //set the new data
+        self.move_by_slice(result.slice());
+        self.result.mpls = Some(result);
+
+        //continue parsing (if required)
+        use ether_type::*;
+        match mpls_payload_ether_type_guess(self.slice) {
+            Some(IPV4) => self.slice_ipv4(),
+            Some(IPV6) => self.slice_ipv6(),
+            _ => Ok(self.slice_payload()),
+        }
+    }
+
+    pub fn slice_pppoe(mut self) -> Result<SlicedPacket<'a>, err::packet::EthSliceError> {
+        use err::packet::EthSliceError::*;
+
+        let pppoe = PppoeHeaderSlice::from_slice(self.slice).map_err(|err| {
+            use err::pppoe::HeaderSliceError as I;
+            match err {
+                I::Len(mut err) => {
+                    err.len_source = self.len_source;
+                    Len(err.add_offset(self.offset))
+                }
+                I::Content(err) => Pppoe(err),
+            }
+        })?;
+
+        //limit the slice to the length given in the PPPoE header
+        let len = PppoeHeader::LEN + usize::from(pppoe.payload_length());
+        if self.slice.len() < len {
+            return Err(Len(err::LenError {
+                required_len: len,
+                len: self.slice.len(),
+                len_source: self.len_source,
+                layer: err::Layer::PppoeHeader,
+                layer_start_offset: self.offset,
+            }));
+        }
+        // SAFETY: Safe as the slice length was verified to be at least len.
+        self.slice = unsafe { core::slice::from_raw_parts(self.slice.as_ptr(), len) };
+        self.len_source = LenSource::PppoeHeaderLen;
+
+        //set the new data
+        let is_session_data = pppoe.is_session_data();
+        self.move_by_slice(pppoe.slice());
+        self.result.pppoe = Some(pppoe);
+
+        //discovery stage packets contain tags as payload
+        if false == is_session_data {
+            return Ok(self.slice_payload());
+        }
+
+        let ppp = PppHeaderSlice::from_slice(self.slice).map_err(|mut err| {
+            err.len_source = self.len_source;
+            Len(err.add_offset(self.offset))
+        })?;
+
+        //set the new data
+        let ether_type = ppp.payload_ether_type();
+        self.move_by_slice(ppp.slice());
+        self.result.ppp = Some(ppp);
+
+        //continue parsing (if required)
+        use ether_type::*;
+        match ether_type {
+            Some(IPV4) => self.slice_ipv4(),
+            Some(IPV6) => self.slice_ipv6(),
+            _ => Ok(self.slice_payload()),
+        }
+    }
+
+    pub fn slice_arp(mut self) -> Result<SlicedPacket<'a>, err::packet::EthSliceError> {
+        use err::packet::EthSliceError::*;
+
+        let result = ArpPacketSlice::from_slice(self.slice).map_err(|mut err| {
+            err.layer_start_offset += self.offset;
+            if LenSource::Slice == err.len_source {
+                err.len_source = self.len_source;
+            }
+            Len(err)
+        })?;
+
+        //set the new data
+        self.move_by_slice(result.slice());
+        self.result.arp = Some(result);
+
+        //done
+        Ok(self.slice_payload())
+    }
+
+    pub fn slice_ip(mut self) -> Result<SlicedPacket<'a>, err::packet::IpSliceError> {
+        use err::packet::IpSliceError::*;
+
+        // slice header, extension headers and identify payload range
+        let ip = InternetSlice::from_ip_slice(self.slice).map_err(|err| {
+            use err::ip::SliceError as I;
+            match err {
+                I::Len(mut err) => {
                     err.layer_start_offset += self.offset;
                     Len(err)
                 }
@@ -380,6 +1445,7 @@ impl<'a> CursorSlice<'a> {
             payload.payload.as_ptr().offset_from(self.slice.as_ptr()) as usize
         };
         self.len_source = payload.len_source;
+        self.result.link_trailer = self.data_after(payload.payload);
         self.slice = payload.payload;
         self.result.ip = Some(ip);
 
@@ -398,6 +1464,16 @@ impl<'a> CursorSlice<'a> {
                     }
                 }),
                 ip_number::IPV6_ICMP => self.slice_icmp6().map_err(Len),
+                ip_number::GRE => self.slice_gre().map_err(|err| {
+                    use err::gre::HeaderSliceError as I;
+                    match err {
+                        I::Len(err) => Len(err),
+                        I::Content(err) => Gre(err),
+                    }
+                }),
+                // data after an ESP header is usually encrypted (see
+                // SlicedPacket::decode_esp_null for "NULL" encryption)
+                ip_number::ENCAP_SEC => Ok(self.slice_payload()),
                 value => {
                     use TransportSlice::*;
                     self.result.transport = Some(Unknown(value));
@@ -416,6 +1492,9 @@ impl<'a> CursorSlice<'a> {
             match err {
                 I::Len(mut err) => {
                     err.layer_start_offset += self.offset;
+                    if LenSource::Slice == err.len_source {
+                        err.len_source = self.len_source;
+                    }
                     Len(err)
                 }
                 I::Header(err) => Ipv4(err),
@@ -434,6 +1513,7 @@ impl<'a> CursorSlice<'a> {
             payload.payload.as_ptr().offset_from(self.slice.as_ptr()) as usize
         };
         self.len_source = payload.len_source;
+        self.result.link_trailer = self.data_after(payload.payload);
         self.slice = payload.payload;
         self.result.ip = Some(InternetSlice::Ipv4(ipv4));
 
@@ -451,6 +1531,16 @@ impl<'a> CursorSlice<'a> {
                 }),
                 ip_number::ICMP => self.slice_icmp4().map_err(Len),
                 ip_number::IPV6_ICMP => self.slice_icmp6().map_err(Len),
+                ip_number::GRE => self.slice_gre().map_err(|err| {
+                    use err::gre::HeaderSliceError as I;
+                    match err {
+                        I::Len(err) => Len(err),
+                        I::Content(err) => Gre(err),
+                    }
+                }),
+                // data after an ESP header is usually encrypted (see
+                // SlicedPacket::decode_esp_null for "NULL" encryption)
+                ip_number::ENCAP_SEC => Ok(self.slice_payload()),
                 value => {
                     use TransportSlice::*;
                     self.result.transport = Some(Unknown(value));
@@ -468,6 +1558,9 @@ impl<'a> CursorSlice<'a> {
             match err {
                 I::Len(mut err) => {
                     err.layer_start_offset += self.offset;
+                    if LenSource::Slice == err.len_source {
+                        err.len_source = self.len_source;
+                    }
                     Len(err)
                 }
                 I::Header(err) => Ipv6(err),
@@ -490,6 +1583,7 @@ impl<'a> CursorSlice<'a> {
                 .offset_from(self.slice.as_ptr()) as usize
         };
         self.len_source = ipv6.payload().len_source;
+        self.result.link_trailer = self.data_after(ipv6.payload().payload);
         self.slice = ipv6.payload().payload;
         self.result.ip = Some(InternetSlice::Ipv6(ipv6));
 
@@ -510,6 +1604,16 @@ impl<'a> CursorSlice<'a> {
                     }
                 }),
                 ip_number::IPV6_ICMP => self.slice_icmp6().map_err(Len),
+                ip_number::GRE => self.slice_gre().map_err(|err| {
+                    use err::gre::HeaderSliceError as I;
+                    match err {
+                        I::Len(err) => Len(err),
+                        I::Content(err) => Gre(err),
+                    }
+                }),
+                // data after an ESP header is usually encrypted (see
+                // SlicedPacket::decode_esp_null for "NULL" encryption)
+                ip_number::ENCAP_SEC => Ok(self.slice_payload()),
                 value => {
                     use TransportSlice::*;
                     self.result.transport = Some(Unknown(value));
@@ -517,156 +1621,2393 @@ impl<'a> CursorSlice<'a> {
                 }
             }
         }
-    }
+    }
+
+    pub fn slice_icmp4(mut self) -> Result<SlicedPacket<'a>, err::LenError> {
+        use crate::TransportSlice::*;
+
+        let result = Icmpv4Slice::from_slice(self.slice).map_err(|mut err| {
+            err.layer_start_offset += self.offset;
+            if LenSource::Slice == err.len_source {
+                err.len_source = self.len_source;
+            }
+            err
+        })?;
+
+        //set the new data
+        self.move_by_slice(result.slice());
+        self.result.transport = Some(Icmpv4(result));
+
+        //done
+        Ok(self.slice_payload())
+    }
+
+    pub fn slice_icmp6(mut self) -> Result<SlicedPacket<'a>, err::LenError> {
+        use crate::TransportSlice::*;
+
+        let result = Icmpv6Slice::from_slice(self.slice).map_err(|mut err| {
+            err.layer_start_offset += self.offset;
+            if LenSource::Slice == err.len_source {
+                err.len_source = self.len_source;
+            }
+            err
+        })?;
+
+        //set the new data
+        self.move_by_slice(result.slice());
+        self.result.transport = Some(Icmpv6(result));
+
+        //done
+        Ok(self.slice_payload())
+    }
+
+    pub fn slice_udp(mut self) -> Result<SlicedPacket<'a>, err::LenError> {
+        use crate::TransportSlice::*;
+
+        let result = UdpHeaderSlice::from_slice(self.slice).map_err(|mut err| {
+            err.layer_start_offset += self.offset;
+            if LenSource::Slice == err.len_source {
+                err.len_source = self.len_source;
+            }
+            err
+        })?;
+
+        //set the new data
+        self.move_by_slice(result.slice());
+        self.result.transport = Some(Udp(result));
+
+        //done
+        Ok(self.slice_payload())
+    }
+
+    pub fn slice_tcp(mut self) -> Result<SlicedPacket<'a>, err::tcp::HeaderSliceError> {
+        use crate::TransportSlice::*;
+
+        let result = TcpHeaderSlice::from_slice(self.slice).map_err(|mut err| {
+            use err::tcp::HeaderSliceError::Len;
+            if let Len(err) = &mut err {
+                err.layer_start_offset += self.offset;
+                if LenSource::Slice == err.len_source {
+                    err.len_source = self.len_source;
+                }
+            }
+            err
+        })?;
+
+        //set the new data
+        self.move_by_slice(result.slice());
+        self.result.transport = Some(Tcp(result));
+
+        //done
+        Ok(self.slice_payload())
+    }
+
+    pub fn slice_gre(mut self) -> Result<SlicedPacket<'a>, err::gre::HeaderSliceError> {
+        let result = GreHeaderSlice::from_slice(self.slice).map_err(|mut err| {
+            use err::gre::HeaderSliceError::Len;
+            if let Len(err) = &mut err {
+                err.layer_start_offset += self.offset;
+                if LenSource::Slice == err.len_source {
+                    err.len_source = self.len_source;
+                }
+            }
+            err
+        })?;
+
+        //set the new data
+        self.move_by_slice(result.slice());
+        self.result.gre = Some(result);
+
+        //done (the encapsulated packet is sliced on request)
+        Ok(self.slice_payload())
+    }
+
+    pub fn slice_vxlan(mut self) -> Result<SlicedPacket<'a>, err::LenError> {
+        let result = VxlanHeaderSlice::from_slice(self.slice).map_err(|mut err| {
+            err.layer_start_offset += self.offset;
+            if LenSource::Slice == err.len_source {
+                err.len_source = self.len_source;
+            }
+            err
+        })?;
+
+        //set the new data
+        self.move_by_slice(result.slice());
+        self.result.vxlan = Some(result);
+
+        //done (the encapsulated frame is sliced on request)
+        Ok(self.slice_payload())
+    }
+
+    pub fn slice_geneve(mut self) -> Result<SlicedPacket<'a>, err::geneve::HeaderSliceError> {
+        let result = GeneveHeaderSlice::from_slice(self.slice).map_err(|mut err| {
+            use err::geneve::HeaderSliceError::Len;
+            if let Len(err) = &mut err {
+                err.layer_start_offset += self.offset;
+                if LenSource::Slice == err.len_source {
+                    err.len_source = self.len_source;
+                }
+            }
+            err
+        })?;
+
+        //set the new data
+        self.move_by_slice(result.slice());
+        self.result.geneve = Some(result);
+
+        //done (the encapsulated frame is sliced on request)
+        Ok(self.slice_payload())
+    }
+
+    pub fn slice_gtpu(mut self) -> Result<SlicedPacket<'a>, err::gtpu::HeaderSliceError> {
+        use err::gtpu::HeaderSliceError::Len;
+
+        let result = GtpuHeaderSlice::from_slice(self.slice).map_err(|mut err| {
+            if let Len(err) = &mut err {
+                err.layer_start_offset += self.offset;
+                if LenSource::Slice == err.len_source {
+                    err.len_source = self.len_source;
+                }
+            }
+            err
+        })?;
+
+        //limit the slice to the length given in the GTP-U header
+        let len = result.header_len() + result.payload_len();
+        if self.slice.len() < len {
+            return Err(Len(err::LenError {
+                required_len: len,
+                len: self.slice.len(),
+                len_source: self.len_source,
+                layer: err::Layer::GtpuHeader,
+                layer_start_offset: self.offset,
+            }));
+        }
+        // SAFETY: Safe as the slice length was verified to be at least len.
+        self.slice = unsafe { core::slice::from_raw_parts(self.slice.as_ptr(), len) };
+
+        //set the new data
+        self.move_by_slice(result.slice());
+        self.result.gtpu = Some(result);
+
+        //done (the encapsulated packet is sliced on request)
+        Ok(self.slice_payload())
+    }
+
+    pub fn slice_payload(mut self) -> SlicedPacket<'a> {
+        self.result.payload = self.slice;
+        self.result
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::err::{
+        packet::{EthSliceError, IpSliceError},
+        Layer, LenError,
+    };
+    use crate::test_packet::TestPacket;
+
+    const VLAN_ETHER_TYPES: [EtherType; 3] = [
+        ether_type::VLAN_TAGGED_FRAME,
+        ether_type::PROVIDER_BRIDGING,
+        ether_type::VLAN_DOUBLE_TAGGED_FRAME,
+    ];
+
+    #[test]
+    fn from_x_slice() {
+        // no eth
+        from_x_slice_vlan_variants(&TestPacket {
+            link: None,
+            vlan: None,
+            ip: None,
+            transport: None,
+        });
+
+        // eth & linux sll
+        for (link, layer) in [
+            (
+                LinkHeader::Ethernet2(Ethernet2Header {
+                    source: MacAddress([1, 2, 3, 4, 5, 6]),
+                    destination: MacAddress([1, 2, 3, 4, 5, 6]),
+                    ether_type: ether_type::WAKE_ON_LAN,
+                }),
+                Layer::Ethernet2Header,
+            ),
+            (
+                LinkHeader::LinuxSll(LinuxSllHeader {
+                    packet_type: LinuxSllPacketType::OUTGOING,
+                    arp_hrd_type: ArpHardwareId::ETHERNET,
+                    sender_address_valid_length: 6,
+                    sender_address: [1, 2, 3, 4, 5, 6, 0, 0],
+                    protocol_type: 0.into(),
+                }),
+                Layer::LinuxSllHeader,
+            ),
+            (
+                LinkHeader::LinuxSll2(LinuxSll2Header {
+                    protocol_type: 0.into(),
+                    interface_index: 2,
+                    arp_hrd_type: ArpHardwareId::ETHERNET,
+                    packet_type: 0,
+                    sender_address_valid_length: 6,
+                    sender_address: [1, 2, 3, 4, 5, 6, 0, 0],
+                }),
+                Layer::LinuxSll2Header,
+            ),
+        ] {
+            let test = TestPacket {
+                link: Some(link.clone()),
+                vlan: None,
+                ip: None,
+                transport: None,
+            };
+
+            // ok link header (with unknown next)
+            from_x_slice_vlan_variants(&test);
+
+            // link len error
+            {
+                let data = test.to_vec(&[]);
+                for len in 0..data.len() {
+                    let err = LenError {
+                        required_len: link.header_len(),
+                        len,
+                        len_source: LenSource::Slice,
+                        layer,
+                        layer_start_offset: 0,
+                    };
+
+                    from_slice_assert_err(
+                        &test,
+                        &data[..len],
+                        EthSliceError::Len(err.clone()),
+                        IpSliceError::Len(err.clone()),
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn from_linux_sll_non_ether_type() {
+        use alloc::vec::Vec;
+
+        let payload = [1, 2, 3, 4];
+        let sll = LinuxSllHeader {
+            packet_type: LinuxSllPacketType::OUTGOING,
+            arp_hrd_type: ArpHardwareId::NETLINK,
+            sender_address_valid_length: 0,
+            sender_address: [0; 8],
+            // netlink protocol (would be IPv4 if it was an ether type)
+            protocol_type: ether_type::IPV4,
+        };
+        let sll2 = LinuxSll2Header {
+            protocol_type: ether_type::IPV4,
+            interface_index: 1,
+            arp_hrd_type: ArpHardwareId::NETLINK,
+            packet_type: 4,
+            sender_address_valid_length: 0,
+            sender_address: [0; 8],
+        };
+
+        // sll
+        {
+            let mut data = Vec::new();
+            sll.write(&mut data).unwrap();
+            data.extend_from_slice(&payload);
+
+            let result = SlicedPacket::from_linux_sll(&data).unwrap();
+            assert_eq!(
+                Some(LinkHeader::LinuxSll(sll.clone())),
+                result.link.as_ref().map(|l| l.to_header())
+            );
+            assert_eq!(None, result.vlan);
+            assert_eq!(None, result.ip);
+            assert_eq!(&payload[..], result.payload);
+            assert_eq!(None, result.payload_ether_type());
+        }
+
+        // sll2
+        {
+            let mut data = Vec::new();
+            sll2.write(&mut data).unwrap();
+            data.extend_from_slice(&payload);
+
+            let result = SlicedPacket::from_linux_sll2(&data).unwrap();
+            assert_eq!(
+                Some(LinkHeader::LinuxSll2(sll2.clone())),
+                result.link.as_ref().map(|l| l.to_header())
+            );
+            assert_eq!(None, result.vlan);
+            assert_eq!(None, result.ip);
+            assert_eq!(&payload[..], result.payload);
+            assert_eq!(None, result.payload_ether_type());
+        }
+    }
+
+    #[test]
+    fn esp() {
+        use alloc::vec::Vec;
+
+        let esp = EspHeader {
+            spi: 1,
+            sequence_number: 2,
+        };
+        let udp = UdpHeader {
+            source_port: 1234,
+            destination_port: 5678,
+            length: (UdpHeader::LEN + 2) as u16,
+            checksum: 0,
+        };
+        let esp_payload = {
+            let mut data = Vec::new();
+            udp.write(&mut data).unwrap();
+            data.extend_from_slice(&[1, 2]); // udp payload
+            data.extend_from_slice(&[1, 2, 2, ip_number::UDP.0]); // padding & trailer
+            data.extend_from_slice(&[9, 9, 9, 9]); // icv
+            data
+        };
+
+        // ipv4
+        {
+            let ipv4 = Ipv4Header::new(
+                (EspHeader::LEN + esp_payload.len()) as u16,
+                20,
+                ip_number::ENCAP_SEC,
+                [1, 2, 3, 4],
+                [5, 6, 7, 8],
+            )
+            .unwrap();
+            let mut data = Vec::new();
+            Ethernet2Header {
+                source: MacAddress([0; 6]),
+                destination: MacAddress([0; 6]),
+                ether_type: ether_type::IPV4,
+            }
+            .write(&mut data)
+            .unwrap();
+            ipv4.write(&mut data).unwrap();
+            esp.write(&mut data).unwrap();
+            data.extend_from_slice(&esp_payload);
+
+            // esp payload is not decoded by default
+            let sliced = SlicedPacket::from_ethernet(&data).unwrap();
+            assert_eq!(
+                Some(esp.clone()),
+                sliced.ip.as_ref().and_then(|ip| match ip {
+                    InternetSlice::Ipv4(s) => s.extensions().esp.map(|e| e.to_header()),
+                    InternetSlice::Ipv6(_) => None,
+                })
+            );
+            assert_eq!(None, sliced.transport);
+            assert_eq!(&esp_payload[..], sliced.payload);
+
+            // opt in to null encryption decoding
+            let decoded = sliced.clone().decode_esp_null(4).unwrap();
+            assert_eq!(
+                Some(udp.clone()),
+                match &decoded.transport {
+                    Some(TransportSlice::Udp(s)) => Some(s.to_header()),
+                    _ => None,
+                }
+            );
+            assert_eq!(&[1, 2], decoded.payload);
+
+            // trailer len error (offset relative to the ip header)
+            assert_eq!(
+                IpSliceError::Len(LenError {
+                    required_len: 30,
+                    len: esp_payload.len(),
+                    len_source: LenSource::Ipv4HeaderTotalLen,
+                    layer: Layer::EspTrailer,
+                    layer_start_offset: Ipv4Header::MIN_LEN + EspHeader::LEN,
+                }),
+                sliced.decode_esp_null(28).unwrap_err()
+            );
+        }
+
+        // ipv6 with unknown next header in the trailer
+        {
+            let mut esp_payload = esp_payload.clone();
+            esp_payload[UdpHeader::LEN + 5] = ip_number::GGP.0;
+            let ipv6 = Ipv6Header {
+                traffic_class: 0,
+                flow_label: Ipv6FlowLabel::ZERO,
+                payload_length: (EspHeader::LEN + esp_payload.len()) as u16,
+                next_header: ip_number::ENCAP_SEC,
+                hop_limit: 20,
+                source: [0; 16],
+                destination: [0; 16],
+            };
+            let mut data = Vec::new();
+            ipv6.write(&mut data).unwrap();
+            esp.write(&mut data).unwrap();
+            data.extend_from_slice(&esp_payload);
+
+            let sliced = SlicedPacket::from_ip(&data).unwrap();
+            assert_eq!(None, sliced.transport);
+            assert_eq!(&esp_payload[..], sliced.payload);
+
+            let decoded = sliced.decode_esp_null(4).unwrap();
+            assert_eq!(
+                Some(TransportSlice::Unknown(ip_number::GGP)),
+                decoded.transport
+            );
+            assert_eq!(&esp_payload[..UdpHeader::LEN + 2], decoded.payload);
+        }
+
+        // packets without esp header are not modified
+        {
+            let mut data = Vec::new();
+            Ipv4Header::new(0, 20, ip_number::GGP, [0; 4], [0; 4])
+                .unwrap()
+                .write(&mut data)
+                .unwrap();
+            let sliced = SlicedPacket::from_ip(&data).unwrap();
+            assert_eq!(Ok(sliced.clone()), sliced.clone().decode_esp_null(4));
+        }
+    }
+
+    #[test]
+    fn ip_tunnels() {
+        use alloc::vec::Vec;
+
+        let udp = UdpHeader {
+            source_port: 1234,
+            destination_port: 5678,
+            length: (UdpHeader::LEN + 2) as u16,
+            checksum: 0,
+        };
+        let ipv6 = Ipv6Header {
+            traffic_class: 0,
+            flow_label: Ipv6FlowLabel::ZERO,
+            payload_length: (UdpHeader::LEN + 2) as u16,
+            next_header: ip_number::UDP,
+            hop_limit: 20,
+            source: [1; 16],
+            destination: [2; 16],
+        };
+        let inner_ipv4 = {
+            let mut ipv4 = Ipv4Header::new(
+                (UdpHeader::LEN + 2) as u16,
+                20,
+                ip_number::UDP,
+                [1, 2, 3, 4],
+                [5, 6, 7, 8],
+            )
+            .unwrap();
+            ipv4.header_checksum = ipv4.calc_header_checksum();
+            ipv4
+        };
+        let outer_ipv4 = |protocol: IpNumber, payload_len: usize| {
+            let mut ipv4 =
+                Ipv4Header::new(payload_len as u16, 20, protocol, [9; 4], [10; 4]).unwrap();
+            ipv4.header_checksum = ipv4.calc_header_checksum();
+            ipv4
+        };
+
+        // 6in4 with an ethernet trailer
+        {
+            let mut data = Vec::new();
+            Ethernet2Header {
+                source: MacAddress([0; 6]),
+                destination: MacAddress([0; 6]),
+                ether_type: ether_type::IPV4,
+            }
+            .write(&mut data)
+            .unwrap();
+            outer_ipv4(ip_number::IPV6, ipv6.header_len() + UdpHeader::LEN + 2)
+                .write(&mut data)
+                .unwrap();
+            ipv6.write(&mut data).unwrap();
+            udp.write(&mut data).unwrap();
+            data.extend_from_slice(&[1, 2]);
+            data.extend_from_slice(&[0, 0, 0]); // trailer
+
+            // tunnels are not decoded by default
+            let sliced = SlicedPacket::from_ethernet(&data).unwrap();
+            assert_eq!(None, sliced.inner_ip);
+            assert_eq!(
+                Some(TransportSlice::Unknown(ip_number::IPV6)),
+                sliced.transport
+            );
+            assert_eq!(Ok(sliced.clone()), sliced.clone().decode_ip_tunnels(0));
+
+            let decoded = sliced.clone().decode_ip_tunnels(1).unwrap();
+            assert_eq!(sliced.link, decoded.link);
+            assert_eq!(sliced.ip, decoded.ip);
+            assert_eq!(
+                Some(ipv6.clone()),
+                decoded
+                    .inner_ip
+                    .as_ref()
+                    .and_then(|ip| ip.ipv6())
+                    .map(|ip| ip.header().to_header())
+            );
+            assert_eq!(
+                Some(udp.clone()),
+                match &decoded.transport {
+                    Some(TransportSlice::Udp(s)) => Some(s.to_header()),
+                    _ => None,
+                }
+            );
+            assert_eq!(&[1, 2], decoded.payload);
+            assert_eq!(&[0, 0, 0], decoded.link_trailer);
+
+            // already decoded packets are not modified
+            assert_eq!(Ok(decoded.clone()), decoded.clone().decode_ip_tunnels(1));
+        }
+
+        // nested tunnels (4in4 in 6)
+        {
+            let middle_ipv4 = outer_ipv4(
+                ip_number::IPV4,
+                inner_ipv4.header_len() + UdpHeader::LEN + 2,
+            );
+            let outer_ipv6 = Ipv6Header {
+                payload_length: (middle_ipv4.header_len()
+                    + inner_ipv4.header_len()
+                    + UdpHeader::LEN
+                    + 2) as u16,
+                next_header: ip_number::IPV4,
+                ..ipv6.clone()
+            };
+            let mut data = Vec::new();
+            outer_ipv6.write(&mut data).unwrap();
+            middle_ipv4.write(&mut data).unwrap();
+            inner_ipv4.write(&mut data).unwrap();
+            udp.write(&mut data).unwrap();
+            data.extend_from_slice(&[1, 2]);
+
+            let sliced = SlicedPacket::from_ip(&data).unwrap();
+
+            // depth limit reached
+            let decoded = sliced.clone().decode_ip_tunnels(1).unwrap();
+            assert_eq!(sliced.ip, decoded.ip);
+            assert_eq!(
+                Some(middle_ipv4.clone()),
+                decoded
+                    .inner_ip
+                    .as_ref()
+                    .and_then(|ip| ip.ipv4())
+                    .map(|ip| ip.header().to_header())
+            );
+            assert_eq!(
+                Some(TransportSlice::Unknown(ip_number::IPV4)),
+                decoded.transport
+            );
+
+            // innermost packet
+            for depth in [2, 3] {
+                let decoded = sliced.clone().decode_ip_tunnels(depth).unwrap();
+                assert_eq!(sliced.ip, decoded.ip);
+                assert_eq!(
+                    Some(inner_ipv4.clone()),
+                    decoded
+                        .inner_ip
+                        .as_ref()
+                        .and_then(|ip| ip.ipv4())
+                        .map(|ip| ip.header().to_header())
+                );
+                assert!(matches!(decoded.transport, Some(TransportSlice::Udp(_))));
+                assert_eq!(&[1, 2], decoded.payload);
+            }
+        }
+
+        // fragmented packets are not decoded
+        {
+            let mut outer = outer_ipv4(ip_number::IPV4, inner_ipv4.header_len());
+            outer.more_fragments = true;
+            outer.header_checksum = outer.calc_header_checksum();
+            let mut data = Vec::new();
+            outer.write(&mut data).unwrap();
+            inner_ipv4.write(&mut data).unwrap();
+
+            let sliced = SlicedPacket::from_ip(&data).unwrap();
+            assert_eq!(Ok(sliced.clone()), sliced.clone().decode_ip_tunnels(1));
+        }
+
+        // len error (offset relative to the outer ip header)
+        {
+            let mut data = Vec::new();
+            outer_ipv4(ip_number::IPV6, 10).write(&mut data).unwrap();
+            data.extend_from_slice(&ipv6.to_bytes()[..10]);
+
+            let sliced = SlicedPacket::from_ip(&data).unwrap();
+            assert_eq!(
+                IpSliceError::Len(LenError {
+                    required_len: Ipv6Header::LEN,
+                    len: 10,
+                    len_source: LenSource::Ipv4HeaderTotalLen,
+                    layer: Layer::Ipv6Header,
+                    layer_start_offset: Ipv4Header::MIN_LEN,
+                }),
+                sliced.decode_ip_tunnels(1).unwrap_err()
+            );
+        }
+
+        // len error in a nested tunnel
+        {
+            let middle_ipv4 = outer_ipv4(ip_number::IPV4, 10);
+            let outer_ipv6 = Ipv6Header {
+                payload_length: (middle_ipv4.header_len() + 10) as u16,
+                next_header: ip_number::IPV4,
+                ..ipv6.clone()
+            };
+            let mut data = Vec::new();
+            outer_ipv6.write(&mut data).unwrap();
+            middle_ipv4.write(&mut data).unwrap();
+            data.extend_from_slice(&inner_ipv4.to_bytes()[..10]);
+            let expected = |layer_start_offset: usize| {
+                IpSliceError::Len(LenError {
+                    required_len: Ipv4Header::MIN_LEN,
+                    len: 10,
+                    len_source: LenSource::Ipv4HeaderTotalLen,
+                    layer: Layer::Ipv4Header,
+                    layer_start_offset,
+                })
+            };
+
+            // offset relative to the outer ip header
+            let sliced = SlicedPacket::from_ip(&data).unwrap();
+            assert_eq!(
+                expected(Ipv6Header::LEN + Ipv4Header::MIN_LEN),
+                sliced.clone().decode_ip_tunnels(2).unwrap_err()
+            );
+
+            // offset relative to the already decoded inner ip header (also
+            // if the outer ip header is from a different buffer)
+            let mut decoded = sliced.decode_ip_tunnels(1).unwrap();
+            let other_data = data.clone();
+            decoded.ip = SlicedPacket::from_ip(&other_data).unwrap().ip;
+            assert_eq!(
+                expected(Ipv4Header::MIN_LEN),
+                decoded.decode_ip_tunnels(1).unwrap_err()
+            );
+        }
+    }
+
+    #[test]
+    fn gre() {
+        use alloc::vec::Vec;
+
+        let udp = UdpHeader {
+            source_port: 1234,
+            destination_port: 5678,
+            length: (UdpHeader::LEN + 2) as u16,
+            checksum: 0,
+        };
+        let inner_ipv4 = {
+            let mut ipv4 = Ipv4Header::new(
+                (UdpHeader::LEN + 2) as u16,
+                20,
+                ip_number::UDP,
+                [1, 2, 3, 4],
+                [5, 6, 7, 8],
+            )
+            .unwrap();
+            ipv4.header_checksum = ipv4.calc_header_checksum();
+            ipv4
+        };
+        let eth = Ethernet2Header {
+            source: MacAddress([1; 6]),
+            destination: MacAddress([2; 6]),
+            ether_type: ether_type::IPV4,
+        };
+        let outer_ipv6 = |payload_length: usize| Ipv6Header {
+            traffic_class: 0,
+            flow_label: Ipv6FlowLabel::ZERO,
+            payload_length: payload_length as u16,
+            next_header: ip_number::GRE,
+            hop_limit: 20,
+            source: [1; 16],
+            destination: [2; 16],
+        };
+
+        // ethernet frame in GRE (transparent ethernet bridging)
+        {
+            let mut gre = GreHeader {
+                protocol_type: ether_type::TRANSPARENT_ETHERNET_BRIDGING,
+                key: Some(0x1234),
+                checksum: Some(0),
+                ..Default::default()
+            };
+            let mut inner = Vec::new();
+            eth.write(&mut inner).unwrap();
+            inner_ipv4.write(&mut inner).unwrap();
+            udp.write(&mut inner).unwrap();
+            inner.extend_from_slice(&[1, 2]);
+            gre.update_checksum(&inner);
+
+            let mut data = Vec::new();
+            outer_ipv6(gre.header_len() + inner.len())
+                .write(&mut data)
+                .unwrap();
+            gre.write(&mut data).unwrap();
+            data.extend_from_slice(&inner);
+
+            let sliced = SlicedPacket::from_ip(&data).unwrap();
+            assert_eq!(
+                Some(gre.clone()),
+                sliced.gre.as_ref().map(|g| g.to_header())
+            );
+            assert!(sliced
+                .gre
+                .as_ref()
+                .unwrap()
+                .is_checksum_valid(sliced.payload));
+            assert_eq!(None, sliced.transport);
+            assert_eq!(&inner[..], sliced.payload);
+            assert_eq!(
+                Some(ether_type::TRANSPARENT_ETHERNET_BRIDGING),
+                sliced.payload_ether_type()
+            );
+
+            let inner_sliced = sliced.slice_gre_payload().unwrap().unwrap();
+            assert_eq!(
+                Some(LinkHeader::Ethernet2(eth.clone())),
+                inner_sliced.link.as_ref().map(|l| l.to_header())
+            );
+            assert_eq!(
+                Some(inner_ipv4.clone()),
+                inner_sliced
+                    .ip
+                    .as_ref()
+                    .and_then(|ip| ip.ipv4())
+                    .map(|ip| ip.header().to_header())
+            );
+            assert!(matches!(
+                inner_sliced.transport,
+                Some(TransportSlice::Udp(_))
+            ));
+            assert_eq!(&[1, 2], inner_sliced.payload);
+        }
+
+        // ip in GRE (from ethernet)
+        {
+            let gre = GreHeader {
+                protocol_type: ether_type::IPV4,
+                ..Default::default()
+            };
+            let mut data = Vec::new();
+            Ethernet2Header {
+                ether_type: ether_type::IPV6,
+                ..eth.clone()
+            }
+            .write(&mut data)
+            .unwrap();
+            outer_ipv6(gre.header_len() + inner_ipv4.total_len as usize)
+                .write(&mut data)
+                .unwrap();
+            gre.write(&mut data).unwrap();
+            inner_ipv4.write(&mut data).unwrap();
+            udp.write(&mut data).unwrap();
+            data.extend_from_slice(&[1, 2]);
+
+            let sliced = SlicedPacket::from_ethernet(&data).unwrap();
+            assert_eq!(Some(gre), sliced.gre.as_ref().map(|g| g.to_header()));
+            let inner_sliced = sliced.slice_gre_payload().unwrap().unwrap();
+            assert_eq!(None, inner_sliced.link);
+            assert!(inner_sliced.ip.as_ref().unwrap().ipv4().is_some());
+            assert_eq!(&[1, 2], inner_sliced.payload);
+        }
+
+        // no gre header
+        {
+            let mut data = Vec::new();
+            inner_ipv4.write(&mut data).unwrap();
+            udp.write(&mut data).unwrap();
+            data.extend_from_slice(&[1, 2]);
+            let sliced = SlicedPacket::from_ip(&data).unwrap();
+            assert_eq!(None, sliced.gre);
+            assert!(sliced.slice_gre_payload().is_none());
+        }
+
+        // len error
+        {
+            let gre = GreHeader {
+                key: Some(1),
+                ..Default::default()
+            };
+            let mut data = Vec::new();
+            outer_ipv6(GreHeader::MIN_LEN).write(&mut data).unwrap();
+            data.extend_from_slice(&gre.to_bytes());
+
+            assert_eq!(
+                IpSliceError::Len(LenError {
+                    required_len: gre.header_len(),
+                    len: GreHeader::MIN_LEN,
+                    len_source: LenSource::Ipv6HeaderPayloadLen,
+                    layer: Layer::GreHeader,
+                    layer_start_offset: Ipv6Header::LEN,
+                }),
+                SlicedPacket::from_ip(&data).unwrap_err()
+            );
+        }
+
+        // content error
+        {
+            let mut data = Vec::new();
+            outer_ipv6(GreHeader::MIN_LEN).write(&mut data).unwrap();
+            data.extend_from_slice(&[0, 2, 0, 0]);
+
+            assert_eq!(
+                IpSliceError::Gre(err::gre::HeaderError::UnsupportedVersion { version_number: 2 }),
+                SlicedPacket::from_ip(&data).unwrap_err()
+            );
+            let mut eth_data = Vec::new();
+            Ethernet2Header {
+                ether_type: ether_type::IPV6,
+                ..eth.clone()
+            }
+            .write(&mut eth_data)
+            .unwrap();
+            eth_data.extend_from_slice(&data);
+            assert_eq!(
+                EthSliceError::Gre(err::gre::HeaderError::UnsupportedVersion { version_number: 2 }),
+                SlicedPacket::from_ethernet(&eth_data).unwrap_err()
+            );
+        }
+    }
+
+    #[test]
+    fn udp_tunnels() {
+        use alloc::vec::Vec;
+
+        let eth = Ethernet2Header {
+            source: MacAddress([1; 6]),
+            destination: MacAddress([2; 6]),
+            ether_type: ether_type::IPV4,
+        };
+        let inner_ipv4 = {
+            let mut ipv4 = Ipv4Header::new(
+                (UdpHeader::LEN + 2) as u16,
+                20,
+                ip_number::UDP,
+                [1, 2, 3, 4],
+                [5, 6, 7, 8],
+            )
+            .unwrap();
+            ipv4.header_checksum = ipv4.calc_header_checksum();
+            ipv4
+        };
+        let inner_frame = {
+            let mut inner = Vec::new();
+            eth.write(&mut inner).unwrap();
+            inner_ipv4.write(&mut inner).unwrap();
+            UdpHeader {
+                source_port: 1,
+                destination_port: 2,
+                length: (UdpHeader::LEN + 2) as u16,
+                checksum: 0,
+            }
+            .write(&mut inner)
+            .unwrap();
+            inner.extend_from_slice(&[1, 2]);
+            inner
+        };
+        let build = |destination_port: u16, tunnel_header: &[u8], inner: &[u8]| {
+            let payload_len = UdpHeader::LEN + tunnel_header.len() + inner.len();
+            let mut data = Vec::new();
+            Ipv6Header {
+                traffic_class: 0,
+                flow_label: Ipv6FlowLabel::ZERO,
+                payload_length: payload_len as u16,
+                next_header: ip_number::UDP,
+                hop_limit: 20,
+                source: [1; 16],
+                destination: [2; 16],
+            }
+            .write(&mut data)
+            .unwrap();
+            UdpHeader {
+                source_port: 49152,
+                destination_port,
+                length: payload_len as u16,
+                checksum: 0,
+            }
+            .write(&mut data)
+            .unwrap();
+            data.extend_from_slice(tunnel_header);
+            data.extend_from_slice(inner);
+            data
+        };
+        let vxlan = VxlanHeader::new(Vni::try_new(0x123456).unwrap());
+        let geneve = GeneveHeader {
+            critical: true,
+            options: GeneveOptions::try_from(&[0x01, 0x02, 0x83, 0x01, 1, 2, 3, 4][..]).unwrap(),
+            ..GeneveHeader::new(
+                ether_type::TRANSPARENT_ETHERNET_BRIDGING,
+                Vni::try_new(7).unwrap(),
+            )
+        };
+
+        // vxlan (automatically via port & forced)
+        {
+            let data = build(VxlanHeader::UDP_PORT, &vxlan.to_bytes(), &inner_frame);
+            let sliced = SlicedPacket::from_ip(&data).unwrap();
+            assert_eq!(None, sliced.vxlan);
+            assert!(sliced.slice_vxlan_payload().is_none());
+
+            for sliced in [
+                sliced.clone().decode_udp_tunnels().unwrap(),
+                sliced.clone().decode_vxlan().unwrap(),
+            ] {
+                assert_eq!(
+                    Some(vxlan.clone()),
+                    sliced.vxlan.as_ref().map(|v| v.to_header())
+                );
+                assert_eq!(None, sliced.geneve);
+                assert_eq!(&inner_frame[..], sliced.payload);
+                assert_eq!(
+                    Some(ether_type::TRANSPARENT_ETHERNET_BRIDGING),
+                    sliced.payload_ether_type()
+                );
+
+                // decoding twice has no effect
+                assert_eq!(sliced, sliced.clone().decode_udp_tunnels().unwrap());
+                assert_eq!(sliced, sliced.clone().decode_geneve().unwrap());
+
+                let inner = sliced.slice_vxlan_payload().unwrap().unwrap();
+                assert_eq!(
+                    Some(LinkHeader::Ethernet2(eth.clone())),
+                    inner.link.as_ref().map(|l| l.to_header())
+                );
+                assert_eq!(
+                    Some(inner_ipv4.clone()),
+                    inner
+                        .ip
+                        .as_ref()
+                        .and_then(|ip| ip.ipv4())
+                        .map(|ip| ip.header().to_header())
+                );
+                assert!(matches!(inner.transport, Some(TransportSlice::Udp(_))));
+                assert_eq!(&[1, 2], inner.payload);
+            }
+        }
+
+        // vxlan-gpe with an ip payload
+        {
+            let gpe = VxlanHeader {
+                gpe_next_protocol: Some(VxlanGpeNextProtocol::IPV4),
+                ..vxlan.clone()
+            };
+            let data = build(
+                VxlanHeader::GPE_UDP_PORT,
+                &gpe.to_bytes(),
+                &inner_frame[Ethernet2Header::LEN..],
+            );
+            let sliced = SlicedPacket::from_ip(&data)
+                .unwrap()
+                .decode_udp_tunnels()
+                .unwrap();
+            assert_eq!(Some(gpe), sliced.vxlan.as_ref().map(|v| v.to_header()));
+            assert_eq!(Some(ether_type::IPV4), sliced.payload_ether_type());
+            let inner = sliced.slice_vxlan_payload().unwrap().unwrap();
+            assert_eq!(None, inner.link);
+            assert!(inner.ip.as_ref().unwrap().ipv4().is_some());
+            assert_eq!(&[1, 2], inner.payload);
+
+            // next protocol without ether type
+            let nsh = VxlanHeader {
+                gpe_next_protocol: Some(VxlanGpeNextProtocol::NSH),
+                ..vxlan.clone()
+            };
+            let data = build(VxlanHeader::GPE_UDP_PORT, &nsh.to_bytes(), &[]);
+            let sliced = SlicedPacket::from_ip(&data)
+                .unwrap()
+                .decode_udp_tunnels()
+                .unwrap();
+            assert!(sliced.vxlan.is_some());
+            assert_eq!(None, sliced.payload_ether_type());
+            assert!(sliced.slice_vxlan_payload().is_none());
+        }
+
+        // geneve (automatically via port & forced)
+        {
+            let data = build(GeneveHeader::UDP_PORT, &geneve.to_bytes(), &inner_frame);
+            let sliced = SlicedPacket::from_ip(&data).unwrap();
+            assert_eq!(None, sliced.geneve);
+            assert!(sliced.slice_geneve_payload().is_none());
+
+            for sliced in [
+                sliced.clone().decode_udp_tunnels().unwrap(),
+                sliced.clone().decode_geneve().unwrap(),
+            ] {
+                let header = sliced.geneve.as_ref().unwrap();
+                assert_eq!(geneve, header.to_header());
+                assert_eq!(
+                    Some(Ok(GeneveOption {
+                        class: 0x0102,
+                        option_type: 0x83,
+                        data: &[1, 2, 3, 4]
+                    })),
+                    header.options_iter().next()
+                );
+                assert_eq!(None, sliced.vxlan);
+                assert_eq!(&inner_frame[..], sliced.payload);
+                assert_eq!(
+                    Some(ether_type::TRANSPARENT_ETHERNET_BRIDGING),
+                    sliced.payload_ether_type()
+                );
+                assert_eq!(sliced, sliced.clone().decode_vxlan().unwrap());
+
+                let inner = sliced.slice_geneve_payload().unwrap().unwrap();
+                assert_eq!(
+                    Some(LinkHeader::Ethernet2(eth.clone())),
+                    inner.link.as_ref().map(|l| l.to_header())
+                );
+                assert_eq!(&[1, 2], inner.payload);
+            }
+        }
+
+        // other ports & non udp packets are not decoded
+        {
+            let data = build(1234, &vxlan.to_bytes(), &inner_frame);
+            let sliced = SlicedPacket::from_ip(&data).unwrap();
+            assert_eq!(sliced, sliced.clone().decode_udp_tunnels().unwrap());
+
+            // forced decoding still works
+            assert!(sliced.decode_vxlan().unwrap().vxlan.is_some());
+
+            let sliced = SlicedPacket::from_ip(&inner_frame[Ethernet2Header::LEN..]).unwrap();
+            let sliced = SlicedPacket {
+                transport: None,
+                ..sliced
+            };
+            assert_eq!(sliced, sliced.clone().decode_udp_tunnels().unwrap());
+            assert_eq!(sliced, sliced.clone().decode_vxlan().unwrap());
+            assert_eq!(sliced, sliced.clone().decode_geneve().unwrap());
+
+            let sliced = SlicedPacket::from_ethernet(&inner_frame).unwrap();
+            let sliced = SlicedPacket { ip: None, ..sliced };
+            assert_eq!(sliced, sliced.clone().decode_vxlan().unwrap());
+        }
+
+        // len errors
+        {
+            let data = build(VxlanHeader::UDP_PORT, &vxlan.to_bytes()[..7], &[]);
+            assert_eq!(
+                IpSliceError::Len(LenError {
+                    required_len: VxlanHeader::LEN,
+                    len: 7,
+                    len_source: LenSource::Ipv6HeaderPayloadLen,
+                    layer: Layer::VxlanHeader,
+                    layer_start_offset: Ipv6Header::LEN + UdpHeader::LEN,
+                }),
+                SlicedPacket::from_ip(&data)
+                    .unwrap()
+                    .decode_udp_tunnels()
+                    .unwrap_err()
+            );
+
+            let data = build(GeneveHeader::UDP_PORT, &geneve.to_bytes()[..8], &[]);
+            assert_eq!(
+                IpSliceError::Len(LenError {
+                    required_len: geneve.header_len(),
+                    len: 8,
+                    len_source: LenSource::Ipv6HeaderPayloadLen,
+                    layer: Layer::GeneveHeader,
+                    layer_start_offset: Ipv6Header::LEN + UdpHeader::LEN,
+                }),
+                SlicedPacket::from_ip(&data)
+                    .unwrap()
+                    .decode_udp_tunnels()
+                    .unwrap_err()
+            );
+
+            // offset relative to the ip header the udp header belongs to
+            // (fields pointing to different buffers)
+            let ipv4_data = {
+                let mut ipv4 = Ipv4Header::new(
+                    (UdpHeader::LEN + 7) as u16,
+                    20,
+                    ip_number::UDP,
+                    [1, 2, 3, 4],
+                    [5, 6, 7, 8],
+                )
+                .unwrap();
+                ipv4.header_checksum = ipv4.calc_header_checksum();
+                let mut ipv4_data = ipv4.to_bytes().to_vec();
+                ipv4_data.extend_from_slice(&data[Ipv6Header::LEN..Ipv6Header::LEN + 8]);
+                ipv4_data.extend_from_slice(&vxlan.to_bytes()[..7]);
+                ipv4_data
+            };
+            let data = build(VxlanHeader::UDP_PORT, &vxlan.to_bytes()[..7], &[]);
+            let sliced = SlicedPacket::from_ip(&data).unwrap();
+            let ipv4_sliced = SlicedPacket::from_ip(&ipv4_data).unwrap();
+            let expected = IpSliceError::Len(LenError {
+                required_len: VxlanHeader::LEN,
+                len: 7,
+                len_source: LenSource::Ipv4HeaderTotalLen,
+                layer: Layer::VxlanHeader,
+                layer_start_offset: Ipv4Header::MIN_LEN + UdpHeader::LEN,
+            });
+            assert_eq!(
+                expected,
+                SlicedPacket {
+                    ip: ipv4_sliced.ip.clone(),
+                    ..sliced.clone()
+                }
+                .decode_vxlan()
+                .unwrap_err()
+            );
+            assert_eq!(
+                expected,
+                SlicedPacket {
+                    inner_ip: ipv4_sliced.ip.clone(),
+                    ..sliced.clone()
+                }
+                .decode_vxlan()
+                .unwrap_err()
+            );
+        }
+
+        // content error
+        {
+            let mut bytes = geneve.to_bytes();
+            bytes[0] |= 0b0100_0000;
+            let data = build(GeneveHeader::UDP_PORT, &bytes, &[]);
+            assert_eq!(
+                IpSliceError::Geneve(err::geneve::HeaderError::UnsupportedVersion {
+                    version_number: 1
+                }),
+                SlicedPacket::from_ip(&data)
+                    .unwrap()
+                    .decode_udp_tunnels()
+                    .unwrap_err()
+            );
+        }
+    }
+
+    #[test]
+    fn gtpu() {
+        use alloc::vec::Vec;
+
+        let inner_ipv4 = {
+            let mut ipv4 = Ipv4Header::new(
+                (UdpHeader::LEN + 2) as u16,
+                20,
+                ip_number::UDP,
+                [10, 0, 0, 1],
+                [10, 0, 0, 2],
+            )
+            .unwrap();
+            ipv4.header_checksum = ipv4.calc_header_checksum();
+            ipv4
+        };
+        let inner_packet = {
+            let mut inner = Vec::new();
+            inner_ipv4.write(&mut inner).unwrap();
+            UdpHeader {
+                source_port: 1,
+                destination_port: 2,
+                length: (UdpHeader::LEN + 2) as u16,
+                checksum: 0,
+            }
+            .write(&mut inner)
+            .unwrap();
+            inner.extend_from_slice(&[1, 2]);
+            inner
+        };
+        let build = |destination_port: u16, gtpu: &[u8], inner: &[u8]| {
+            let payload_len = UdpHeader::LEN + gtpu.len() + inner.len();
+            let mut data = Vec::new();
+            Ipv4Header::new(
+                payload_len as u16,
+                20,
+                ip_number::UDP,
+                [192, 168, 1, 1],
+                [192, 168, 1, 2],
+            )
+            .unwrap()
+            .write(&mut data)
+            .unwrap();
+            UdpHeader {
+                source_port: GtpuHeader::UDP_PORT,
+                destination_port,
+                length: payload_len as u16,
+                checksum: 0,
+            }
+            .write(&mut data)
+            .unwrap();
+            data.extend_from_slice(gtpu);
+            data.extend_from_slice(inner);
+            data
+        };
+        let container = GtpuPduSessionContainer::try_new(1, 9).unwrap();
+        let gtpu = {
+            let mut gtpu = GtpuHeader {
+                sequence_number: Some(1234),
+                extension_headers: GtpuExtensionHeaders::from_pdu_session_container(container),
+                ..GtpuHeader::new(0x1234_5678)
+            };
+            gtpu.set_payload_len(inner_packet.len()).unwrap();
+            gtpu
+        };
+
+        // gtpu (automatically via port & forced)
+        {
+            // bytes after the gtpu payload are not part of the payload
+            let mut inner = inner_packet.clone();
+            inner.extend_from_slice(&[3, 4]);
+            let data = build(GtpuHeader::UDP_PORT, &gtpu.to_bytes(), &inner);
+            let sliced = SlicedPacket::from_ip(&data).unwrap();
+            assert_eq!(None, sliced.gtpu);
+            assert!(sliced.slice_gtpu_payload().is_none());
+
+            for sliced in [
+                sliced.clone().decode_udp_tunnels().unwrap(),
+                sliced.clone().decode_gtpu().unwrap(),
+            ] {
+                let header = sliced.gtpu.as_ref().unwrap();
+                assert_eq!(Ok(gtpu.clone()), header.to_header());
+                assert_eq!(Some(container), header.pdu_session_container());
+                assert_eq!(None, sliced.vxlan);
+                assert_eq!(None, sliced.geneve);
+                assert_eq!(&inner_packet[..], sliced.payload);
+                assert_eq!(None, sliced.payload_ether_type());
+
+                // decoding twice has no effect
+                assert_eq!(sliced, sliced.clone().decode_udp_tunnels().unwrap());
+                assert_eq!(sliced, sliced.clone().decode_vxlan().unwrap());
+                assert_eq!(sliced, sliced.clone().decode_gtpu().unwrap());
+
+                let inner = sliced.slice_gtpu_payload().unwrap().unwrap();
+                assert_eq!(
+                    Some(inner_ipv4.clone()),
+                    inner
+                        .ip
+                        .as_ref()
+                        .and_then(|ip| ip.ipv4())
+                        .map(|ip| ip.header().to_header())
+                );
+                assert!(matches!(inner.transport, Some(TransportSlice::Udp(_))));
+                assert_eq!(&[1, 2], inner.payload);
+            }
+        }
+
+        // non g-pdu messages have no ip payload
+        {
+            let echo = GtpuHeader {
+                message_type: GtpuMessageType::ECHO_REQUEST,
+                ..gtpu.clone()
+            };
+            let data = build(GtpuHeader::UDP_PORT, &echo.to_bytes(), &inner_packet);
+            let sliced = SlicedPacket::from_ip(&data)
+                .unwrap()
+                .decode_udp_tunnels()
+                .unwrap();
+            assert!(sliced.gtpu.is_some());
+            assert!(sliced.slice_gtpu_payload().is_none());
+        }
+
+        // len errors
+        {
+            let bytes = gtpu.to_bytes();
+            let data = build(GtpuHeader::UDP_PORT, &bytes[..bytes.len() - 1], &[]);
+            assert_eq!(
+                IpSliceError::Len(LenError {
+                    required_len: gtpu.header_len(),
+                    len: gtpu.header_len() - 1,
+                    len_source: LenSource::Ipv4HeaderTotalLen,
+                    layer: Layer::GtpuHeader,
+                    layer_start_offset: Ipv4Header::MIN_LEN + UdpHeader::LEN,
+                }),
+                SlicedPacket::from_ip(&data)
+                    .unwrap()
+                    .decode_udp_tunnels()
+                    .unwrap_err()
+            );
+
+            // payload shorter then the gtpu length field
+            let data = build(
+                GtpuHeader::UDP_PORT,
+                &bytes,
+                &inner_packet[..inner_packet.len() - 1],
+            );
+            assert_eq!(
+                IpSliceError::Len(LenError {
+                    required_len: gtpu.header_len() + inner_packet.len(),
+                    len: gtpu.header_len() + inner_packet.len() - 1,
+                    len_source: LenSource::Ipv4HeaderTotalLen,
+                    layer: Layer::GtpuHeader,
+                    layer_start_offset: Ipv4Header::MIN_LEN + UdpHeader::LEN,
+                }),
+                SlicedPacket::from_ip(&data)
+                    .unwrap()
+                    .decode_udp_tunnels()
+                    .unwrap_err()
+            );
+        }
+
+        // content error
+        {
+            let mut bytes = gtpu.to_bytes();
+            bytes[0] = (bytes[0] & 0b0001_1111) | (2 << 5);
+            let data = build(GtpuHeader::UDP_PORT, &bytes, &inner_packet);
+            assert_eq!(
+                IpSliceError::Gtpu(err::gtpu::HeaderError::UnsupportedVersion {
+                    version_number: 2
+                }),
+                SlicedPacket::from_ip(&data)
+                    .unwrap()
+                    .decode_udp_tunnels()
+                    .unwrap_err()
+            );
+        }
+    }
+
+    #[test]
+    fn from_x_slice_arp() {
+        use alloc::vec::Vec;
+
+        let arp = ArpEthIpv4Header {
+            operation: ArpOperation::REQUEST,
+            sender_mac: MacAddress([1, 2, 3, 4, 5, 6]),
+            sender_ipv4: [7, 8, 9, 10],
+            target_mac: MacAddress::ZERO,
+            target_ipv4: [11, 12, 13, 14],
+        }
+        .to_header();
+        // ethernet padding after the arp packet
+        let padding = [0u8; 18];
+
+        let eth = Ethernet2Header {
+            source: MacAddress([1, 2, 3, 4, 5, 6]),
+            destination: MacAddress([0xff; 6]),
+            ether_type: ether_type::ARP,
+        };
+        let single = SingleVlanHeader {
+            pcp: 1.try_into().unwrap(),
+            drop_eligible_indicator: false,
+            vlan_id: 2.try_into().unwrap(),
+            ether_type: ether_type::ARP,
+        };
+        let double = DoubleVlanHeader {
+            outer: SingleVlanHeader {
+                ether_type: ether_type::VLAN_TAGGED_FRAME,
+                ..single.clone()
+            },
+            inner: single.clone(),
+        };
+
+        for vlan in [
+            None,
+            Some(VlanHeader::Single(single.clone())),
+            Some(VlanHeader::Double(double.clone())),
+        ] {
+            let mut eth = eth.clone();
+            if vlan.is_some() {
+                eth.ether_type = ether_type::VLAN_TAGGED_FRAME;
+            }
+
+            let mut data = Vec::new();
+            eth.write(&mut data).unwrap();
+            if let Some(vlan) = &vlan {
+                vlan.write(&mut data).unwrap();
+            }
+            let arp_offset = data.len();
+            arp.write(&mut data).unwrap();
+            data.extend_from_slice(&padding);
+
+            // ok
+            {
+                let check = |result: SlicedPacket| {
+                    assert_eq!(vlan, result.vlan.as_ref().map(|v| v.to_header().unwrap()));
+                    assert_eq!(
+                        Some(arp.clone()),
+                        result.arp.as_ref().map(|a| a.to_header())
+                    );
+                    assert_eq!(None, result.ip);
+                    assert_eq!(None, result.transport);
+                    assert_eq!(&padding[..], result.payload);
+                    assert_eq!(None, result.payload_ether_type());
+                };
+
+                let result = SlicedPacket::from_ethernet(&data).unwrap();
+                assert_eq!(
+                    Some(LinkHeader::Ethernet2(eth.clone())),
+                    result.link.as_ref().map(|l| l.to_header())
+                );
+                check(result);
+
+                let result =
+                    SlicedPacket::from_ether_type(eth.ether_type, &data[Ethernet2Header::LEN..])
+                        .unwrap();
+                assert_eq!(None, result.link);
+                check(result);
+            }
+
+            // len error
+            for len in 0..arp.header_len() {
+                let err = LenError {
+                    required_len: if len < ArpHeader::MIN_LEN {
+                        ArpHeader::MIN_LEN
+                    } else {
+                        arp.header_len()
+                    },
+                    len,
+                    len_source: LenSource::Slice,
+                    layer: Layer::ArpPacket,
+                    layer_start_offset: arp_offset,
+                };
+                assert_eq!(
+                    EthSliceError::Len(err.clone()),
+                    SlicedPacket::from_ethernet(&data[..arp_offset + len]).unwrap_err()
+                );
+                assert_eq!(
+                    EthSliceError::Len(LenError {
+                        layer_start_offset: arp_offset - Ethernet2Header::LEN,
+                        ..err.clone()
+                    }),
+                    SlicedPacket::from_ether_type(
+                        eth.ether_type,
+                        &data[Ethernet2Header::LEN..arp_offset + len]
+                    )
+                    .unwrap_err()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn from_x_slice_llc_snap() {
+        use alloc::vec::Vec;
+
+        let payload = [1, 2, 3, 4, 5, 6, 7, 8];
+        // ethernet padding after the 802.3 payload
+        let padding = [0u8; 4];
+
+        let llc = LlcHeader {
+            dsap: LlcHeader::STP_SAP,
+            ssap: LlcHeader::STP_SAP,
+            control: [LlcHeader::UI_CONTROL, 0],
+        };
+        let snap = SnapHeader {
+            oui: SnapHeader::OUI_RFC1042,
+            ether_type: ether_type::IPV4,
+        };
+        let ipv4 = Ipv4Header::new(
+            payload.len() as u16,
+            1,
+            ip_number::UDP,
+            [2, 3, 4, 5],
+            [6, 7, 8, 9],
+        )
+        .unwrap();
+
+        let build = |llc: &LlcHeader, snap: Option<&SnapHeader>, ip: bool| {
+            let mut data = Vec::new();
+            llc.write(&mut data).unwrap();
+            if let Some(snap) = snap {
+                snap.write(&mut data).unwrap();
+            }
+            if ip {
+                ipv4.write(&mut data).unwrap();
+            }
+            data.extend_from_slice(&payload);
+            let len = data.len();
+            let mut result = Vec::new();
+            Ethernet2Header {
+                source: MacAddress([1, 2, 3, 4, 5, 6]),
+                destination: MacAddress([7, 8, 9, 10, 11, 12]),
+                ether_type: EtherType(len as u16),
+            }
+            .write(&mut result)
+            .unwrap();
+            result.extend_from_slice(&data);
+            result.extend_from_slice(&padding);
+            result
+        };
+
+        // llc only
+        {
+            let data = build(&llc, None, false);
+            let result = SlicedPacket::from_ethernet(&data).unwrap();
+            assert_eq!(
+                Some(llc.clone()),
+                result.llc.as_ref().map(|l| l.to_header())
+            );
+            assert_eq!(None, result.snap);
+            assert_eq!(None, result.ip);
+            assert_eq!(&payload[..], result.payload);
+            assert_eq!(None, result.payload_ether_type());
+
+            // from ether type
+            let result = SlicedPacket::from_ether_type(
+                EtherType((llc.header_len() + payload.len()) as u16),
+                &data[Ethernet2Header::LEN..],
+            )
+            .unwrap();
+            assert_eq!(None, result.link);
+            assert_eq!(
+                Some(llc.clone()),
+                result.llc.as_ref().map(|l| l.to_header())
+            );
+            assert_eq!(&payload[..], result.payload);
+        }
+
+        // snap with unknown oui
+        {
+            let snap = SnapHeader {
+                oui: [0, 0, 0x0c],
+                ether_type: 0x2000.into(),
+            };
+            let data = build(&LlcHeader::SNAP, Some(&snap), false);
+            let result = SlicedPacket::from_ethernet(&data).unwrap();
+            assert_eq!(
+                Some(LlcHeader::SNAP),
+                result.llc.as_ref().map(|l| l.to_header())
+            );
+            assert_eq!(Some(snap), result.snap.as_ref().map(|s| s.to_header()));
+            assert_eq!(&payload[..], result.payload);
+            assert_eq!(None, result.payload_ether_type());
+        }
+
+        // snap with ether type oui but unknown ether type
+        {
+            let snap = SnapHeader {
+                oui: SnapHeader::OUI_BRIDGE_TUNNEL,
+                ether_type: 0x2000.into(),
+            };
+            let data = build(&LlcHeader::SNAP, Some(&snap), false);
+            let result = SlicedPacket::from_ethernet(&data).unwrap();
+            assert_eq!(&payload[..], result.payload);
+            assert_eq!(Some(EtherType(0x2000)), result.payload_ether_type());
+        }
+
+        // snap & ipv4
+        {
+            let data = build(&LlcHeader::SNAP, Some(&snap), true);
+            let result = SlicedPacket::from_ethernet(&data).unwrap();
+            assert_eq!(
+                Some(snap.clone()),
+                result.snap.as_ref().map(|s| s.to_header())
+            );
+            match &result.ip {
+                Some(InternetSlice::Ipv4(ipv4_slice)) => {
+                    assert_eq!(ipv4.source, ipv4_slice.header().source())
+                }
+                _ => panic!("expected ipv4 slice"),
+            }
+            assert_eq!(None, result.payload_ether_type());
+            // ethernet padding after the 802.3 payload is part of the trailer
+            assert_eq!(&padding[..], result.link_trailer);
+        }
+
+        // length field bigger then the data
+        {
+            let data = build(&llc, None, false);
+            let len = data.len() - Ethernet2Header::LEN - padding.len();
+            for cut in 1..=padding.len() + 1 {
+                let cut_data = &data[..data.len() - padding.len() - cut];
+                assert_eq!(
+                    EthSliceError::Len(LenError {
+                        required_len: len,
+                        len: len - cut,
+                        len_source: LenSource::Slice,
+                        layer: Layer::LlcHeader,
+                        layer_start_offset: Ethernet2Header::LEN,
+                    }),
+                    SlicedPacket::from_ethernet(cut_data).unwrap_err()
+                );
+            }
+        }
+
+        // length field too small for the llc & snap header
+        for (len, required_len, layer) in [
+            (2, LlcHeader::MIN_LEN, Layer::LlcHeader),
+            (LlcHeader::MIN_LEN + 4, SnapHeader::LEN, Layer::SnapHeader),
+        ] {
+            let mut data = build(&LlcHeader::SNAP, Some(&snap), true);
+            data[12..14].copy_from_slice(&(len as u16).to_be_bytes());
+            let offset = if Layer::LlcHeader == layer {
+                Ethernet2Header::LEN
+            } else {
+                Ethernet2Header::LEN + LlcHeader::MIN_LEN
+            };
+            assert_eq!(
+                EthSliceError::Len(LenError {
+                    required_len,
+                    len: len + Ethernet2Header::LEN - offset,
+                    len_source: LenSource::Ieee802_3Len,
+                    layer,
+                    layer_start_offset: offset,
+                }),
+                SlicedPacket::from_ethernet(&data).unwrap_err()
+            );
+        }
+
+        // values outside of 1..=1500 are not interpreted as lengths
+        for type_len in [0u16, 0x05DD, 0x05FF] {
+            let mut data = build(&llc, None, false);
+            data[12..14].copy_from_slice(&type_len.to_be_bytes());
+            let result = SlicedPacket::from_ethernet(&data).unwrap();
+            assert_eq!(None, result.llc);
+            assert_eq!(&data[Ethernet2Header::LEN..], result.payload);
+            assert_eq!(Some(EtherType(type_len)), result.payload_ether_type());
+        }
+    }
+
+    #[test]
+    fn from_ethernet_with_fcs() {
+        use alloc::vec::Vec;
+        use err::packet::EthFcsSliceError;
+
+        let eth = Ethernet2Header {
+            source: MacAddress([1, 2, 3, 4, 5, 6]),
+            destination: MacAddress([7, 8, 9, 10, 11, 12]),
+            ether_type: ether_type::WAKE_ON_LAN,
+        };
+        let frame = {
+            let mut frame = Vec::new();
+            eth.write(&mut frame).unwrap();
+            frame.extend_from_slice(&[13, 14, 15, 16]);
+            frame
+        };
+        let crc = checksum::Crc32::new().add_slice(&frame).value();
+        let data = {
+            let mut data = frame.clone();
+            data.extend_from_slice(&crc.to_le_bytes());
+            data
+        };
+
+        // ok
+        {
+            let actual = SlicedPacket::from_ethernet_with_fcs(&data).unwrap();
+            assert_eq!(actual, SlicedPacket::from_ethernet(&frame).unwrap());
+            assert_eq!(&[13, 14, 15, 16], actual.payload);
+        }
+
+        // len error
+        for len in 0..Ethernet2Header::LEN + Ethernet2Header::FCS_LEN {
+            assert_eq!(
+                EthFcsSliceError::Len(LenError {
+                    required_len: Ethernet2Header::LEN + Ethernet2Header::FCS_LEN,
+                    len,
+                    len_source: LenSource::Slice,
+                    layer: Layer::Ethernet2Header,
+                    layer_start_offset: 0,
+                }),
+                SlicedPacket::from_ethernet_with_fcs(&data[..len]).unwrap_err()
+            );
+        }
+
+        // fcs mismatch (bit flips in the frame & the fcs)
+        for i in 0..data.len() {
+            let mut modified = data.clone();
+            modified[i] ^= 0x01;
+            let received = u32::from_le_bytes([
+                modified[modified.len() - 4],
+                modified[modified.len() - 3],
+                modified[modified.len() - 2],
+                modified[modified.len() - 1],
+            ]);
+            assert_eq!(
+                EthFcsSliceError::FcsMismatch {
+                    received,
+                    calculated: checksum::Crc32::new()
+                        .add_slice(&modified[..modified.len() - 4])
+                        .value(),
+                },
+                SlicedPacket::from_ethernet_with_fcs(&modified).unwrap_err()
+            );
+        }
+
+        // slice error (vlan tag announced but fcs directly after the header)
+        {
+            let mut data = Vec::new();
+            Ethernet2Header {
+                ether_type: ether_type::VLAN_TAGGED_FRAME,
+                ..eth.clone()
+            }
+            .write(&mut data)
+            .unwrap();
+            let crc = checksum::Crc32::new().add_slice(&data).value();
+            data.extend_from_slice(&crc.to_le_bytes());
+            assert_eq!(
+                EthFcsSliceError::Slice(EthSliceError::Len(LenError {
+                    required_len: SingleVlanHeader::LEN,
+                    len: 0,
+                    len_source: LenSource::Slice,
+                    layer: Layer::VlanHeader,
+                    layer_start_offset: Ethernet2Header::LEN,
+                })),
+                SlicedPacket::from_ethernet_with_fcs(&data).unwrap_err()
+            );
+        }
+    }
+
+    #[test]
+    fn from_x_slice_vlan_stack() {
+        use alloc::vec::Vec;
+
+        // ethernet frame with more tags then a VlanStack can hold
+        let mut data = Vec::new();
+        Ethernet2Header {
+            source: MacAddress([1, 2, 3, 4, 5, 6]),
+            destination: MacAddress([7, 8, 9, 10, 11, 12]),
+            ether_type: ether_type::PROVIDER_BRIDGING,
+        }
+        .write(&mut data)
+        .unwrap();
+        for i in 0..=VlanStack::MAX_TAGS {
+            SingleVlanHeader {
+                pcp: VlanPcp::ZERO,
+                drop_eligible_indicator: false,
+                vlan_id: VlanId::try_new(i as u16).unwrap(),
+                ether_type: if i == VlanStack::MAX_TAGS {
+                    ether_type::IPV4
+                } else {
+                    ether_type::VLAN_TAGGED_FRAME
+                },
+            }
+            .write(&mut data)
+            .unwrap();
+        }
+        Ipv4Header::new(0, 1, ip_number::IGMP, [2, 3, 4, 5], [6, 7, 8, 9])
+            .unwrap()
+            .write(&mut data)
+            .unwrap();
+
+        // slicing is not limited by the maximum number of tags
+        let result = SlicedPacket::from_ethernet(&data).unwrap();
+        match result.vlan.as_ref().unwrap() {
+            VlanSlice::MultiVlan(stack) => {
+                assert_eq!(VlanStack::MAX_TAGS + 1, stack.num_tags());
+                for (i, tag) in stack.tags().enumerate() {
+                    assert_eq!(i as u16, tag.vlan_identifier().value());
+                }
+            }
+            value => panic!("unexpected vlan slice {:?}", value),
+        }
+        assert!(matches!(result.ip, Some(InternetSlice::Ipv4(_))));
+    }
+
+    #[test]
+    fn from_x_slice_mpls() {
+        use alloc::vec::Vec;
+
+        let payload = [1, 2, 3, 4, 5, 6, 7, 8];
+        let entries = [
+            MplsLabelStackEntry {
+                label: 16.try_into().unwrap(),
+                traffic_class: 1.try_into().unwrap(),
+                bottom_of_stack: false,
+                ttl: 2,
+            },
+            MplsLabelStackEntry {
+                label: 17.try_into().unwrap(),
+                traffic_class: 3.try_into().unwrap(),
+                bottom_of_stack: true,
+                ttl: 4,
+            },
+        ];
+        let ipv4 = Ipv4Header::new(
+            payload.len() as u16,
+            1,
+            ip_number::UDP,
+            [2, 3, 4, 5],
+            [6, 7, 8, 9],
+        )
+        .unwrap();
+        let ipv6 = Ipv6Header {
+            traffic_class: 0,
+            flow_label: Default::default(),
+            payload_length: payload.len() as u16,
+            next_header: ip_number::UDP,
+            hop_limit: 1,
+            source: [1; 16],
+            destination: [2; 16],
+        };
+
+        let build = |ether_type: EtherType, ip: Option<&IpHeader>| {
+            let mut result = Vec::new();
+            Ethernet2Header {
+                source: MacAddress([1, 2, 3, 4, 5, 6]),
+                destination: MacAddress([7, 8, 9, 10, 11, 12]),
+                ether_type,
+            }
+            .write(&mut result)
+            .unwrap();
+            for entry in &entries {
+                entry.write(&mut result).unwrap();
+            }
+            match ip {
+                Some(IpHeader::Version4(h, _)) => h.write(&mut result).unwrap(),
+                Some(IpHeader::Version6(h, _)) => h.write(&mut result).unwrap(),
+                None => {}
+            }
+            result.extend_from_slice(&payload);
+            result
+        };
+        let assert_entries = |result: &SlicedPacket| {
+            let mpls = result.mpls.as_ref().unwrap();
+            assert_eq!(entries.len(), mpls.num_entries());
+            for (expected, actual) in entries.iter().zip(mpls.entries()) {
+                assert_eq!(expected, &actual.to_header());
+            }
+        };
+
+        // ipv4 payload
+        for ether_type in [ether_type::MPLS_UNICAST, ether_type::MPLS_MULTICAST] {
+            let data = build(
+                ether_type,
+                Some(&IpHeader::Version4(ipv4.clone(), Default::default())),
+            );
+            let result = SlicedPacket::from_ethernet(&data).unwrap();
+            assert_entries(&result);
+            match &result.ip {
+                Some(InternetSlice::Ipv4(ipv4_slice)) => {
+                    assert_eq!(ipv4.source, ipv4_slice.header().source())
+                }
+                _ => panic!("expected ipv4 slice"),
+            }
+            assert_eq!(None, result.payload_ether_type());
+
+            // from ether type
+            let result =
+                SlicedPacket::from_ether_type(ether_type, &data[Ethernet2Header::LEN..]).unwrap();
+            assert_eq!(None, result.link);
+            assert_entries(&result);
+            assert!(result.ip.is_some());
+        }
+
+        // ipv6 payload
+        {
+            let data = build(
+                ether_type::MPLS_UNICAST,
+                Some(&IpHeader::Version6(ipv6.clone(), Default::default())),
+            );
+            let result = SlicedPacket::from_ethernet(&data).unwrap();
+            assert_entries(&result);
+            match &result.ip {
+                Some(InternetSlice::Ipv6(ipv6_slice)) => {
+                    assert_eq!(ipv6.source, ipv6_slice.header().source())
+                }
+                _ => panic!("expected ipv6 slice"),
+            }
+        }
+
+        // unknown payload (first nibble is neither 4 nor 6)
+        {
+            let data = build(ether_type::MPLS_UNICAST, None);
+            let result = SlicedPacket::from_ethernet(&data).unwrap();
+            assert_entries(&result);
+            assert_eq!(None, result.ip);
+            assert_eq!(&payload[..], result.payload);
+            assert_eq!(None, result.payload_ether_type());
+        }
+
+        // vlan & mpls
+        {
+            let mut data = Vec::new();
+            Ethernet2Header {
+                source: MacAddress([1, 2, 3, 4, 5, 6]),
+                destination: MacAddress([7, 8, 9, 10, 11, 12]),
+                ether_type: ether_type::VLAN_TAGGED_FRAME,
+            }
+            .write(&mut data)
+            .unwrap();
+            SingleVlanHeader {
+                pcp: 1.try_into().unwrap(),
+                drop_eligible_indicator: false,
+                vlan_id: 2.try_into().unwrap(),
+                ether_type: ether_type::MPLS_UNICAST,
+            }
+            .write(&mut data)
+            .unwrap();
+            for entry in &entries {
+                entry.write(&mut data).unwrap();
+            }
+            data.extend_from_slice(&payload);
+
+            let result = SlicedPacket::from_ethernet(&data).unwrap();
+            assert!(result.vlan.is_some());
+            assert_entries(&result);
+            assert_eq!(&payload[..], result.payload);
+        }
+
+        // length error in the label stack
+        {
+            let data = build(ether_type::MPLS_UNICAST, None);
+            for len in 0..entries.len() * MplsLabelStackEntry::LEN {
+                assert_eq!(
+                    EthSliceError::Len(LenError {
+                        required_len: (len / 4 + 1) * 4,
+                        len,
+                        len_source: LenSource::Slice,
+                        layer: Layer::MplsLabelStack,
+                        layer_start_offset: Ethernet2Header::LEN,
+                    }),
+                    SlicedPacket::from_ethernet(&data[..Ethernet2Header::LEN + len]).unwrap_err()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn from_x_slice_pppoe() {
+        use alloc::vec::Vec;
+
+        let payload = [1, 2, 3, 4, 5, 6, 7, 8];
+        let padding = [0, 0, 0, 0];
+        let ipv4 = Ipv4Header::new(
+            payload.len() as u16,
+            1,
+            ip_number::UDP,
+            [2, 3, 4, 5],
+            [6, 7, 8, 9],
+        )
+        .unwrap();
+        let ipv6 = Ipv6Header {
+            traffic_class: 0,
+            flow_label: Default::default(),
+            payload_length: payload.len() as u16,
+            next_header: ip_number::UDP,
+            hop_limit: 1,
+            source: [1; 16],
+            destination: [2; 16],
+        };
+
+        // builds an ethernet frame containing a PPPoE header, the given
+        // pppoe payload & ethernet padding
+        let build = |code: PppoeCode, pppoe_payload: &[u8]| {
+            let mut result = Vec::new();
+            let pppoe = PppoeHeader {
+                code,
+                session_id: 0x1234,
+                payload_length: pppoe_payload.len() as u16,
+            };
+            Ethernet2Header {
+                source: MacAddress([1, 2, 3, 4, 5, 6]),
+                destination: MacAddress([7, 8, 9, 10, 11, 12]),
+                ether_type: pppoe.ether_type(),
+            }
+            .write(&mut result)
+            .unwrap();
+            pppoe.write(&mut result).unwrap();
+            result.extend_from_slice(pppoe_payload);
+            result.extend_from_slice(&padding);
+            result
+        };
+        let ppp_payload = |protocol: PppProtocol, ip: Option<&IpHeader>| {
+            let mut result = Vec::new();
+            PppHeader { protocol }.write(&mut result).unwrap();
+            match ip {
+                Some(IpHeader::Version4(h, _)) => h.write(&mut result).unwrap(),
+                Some(IpHeader::Version6(h, _)) => h.write(&mut result).unwrap(),
+                None => {}
+            }
+            result.extend_from_slice(&payload);
+            result
+        };
+
+        // session stage with ipv4 payload
+        {
+            let data = build(
+                PppoeCode::SESSION_DATA,
+                &ppp_payload(
+                    PppProtocol::IPV4,
+                    Some(&IpHeader::Version4(ipv4.clone(), Default::default())),
+                ),
+            );
+            let result = SlicedPacket::from_ethernet(&data).unwrap();
+            let pppoe = result.pppoe.as_ref().unwrap();
+            assert_eq!(PppoeCode::SESSION_DATA, pppoe.code());
+            assert_eq!(0x1234, pppoe.session_id());
+            assert_eq!(PppProtocol::IPV4, result.ppp.as_ref().unwrap().protocol());
+            match &result.ip {
+                Some(InternetSlice::Ipv4(ipv4_slice)) => {
+                    assert_eq!(ipv4.source, ipv4_slice.header().source())
+                }
+                _ => panic!("expected ipv4 slice"),
+            }
+            assert_eq!(None, result.payload_ether_type());
+            // ethernet padding after the pppoe payload is part of the trailer
+            assert_eq!(&padding[..], result.link_trailer);
+
+            // from ether type
+            let result = SlicedPacket::from_ether_type(
+                ether_type::PPPOE_SESSION,
+                &data[Ethernet2Header::LEN..],
+            )
+            .unwrap();
+            assert_eq!(None, result.link);
+            assert!(result.pppoe.is_some());
+            assert!(result.ppp.is_some());
+            assert!(result.ip.is_some());
+        }
 
-    pub fn slice_icmp4(mut self) -> Result<SlicedPacket<'a>, err::LenError> {
-        use crate::TransportSlice::*;
+        // link trailer contains the data after the ip packet in the pppoe
+        // payload & the ethernet padding
+        {
+            let mut pppoe_payload = ppp_payload(
+                PppProtocol::IPV4,
+                Some(&IpHeader::Version4(ipv4.clone(), Default::default())),
+            );
+            pppoe_payload.extend_from_slice(&[0xaa, 0xbb]);
+            let data = build(PppoeCode::SESSION_DATA, &pppoe_payload);
+            let result = SlicedPacket::from_ethernet(&data).unwrap();
+            assert!(result.ip.is_some());
+            assert_eq!(&[0xaa, 0xbb, 0, 0, 0, 0], result.link_trailer);
+        }
 
-        let result = Icmpv4Slice::from_slice(self.slice).map_err(|mut err| {
-            err.layer_start_offset += self.offset;
-            if LenSource::Slice == err.len_source {
-                err.len_source = self.len_source;
+        // session stage with ipv6 payload
+        {
+            let data = build(
+                PppoeCode::SESSION_DATA,
+                &ppp_payload(
+                    PppProtocol::IPV6,
+                    Some(&IpHeader::Version6(ipv6.clone(), Default::default())),
+                ),
+            );
+            let result = SlicedPacket::from_ethernet(&data).unwrap();
+            assert_eq!(PppProtocol::IPV6, result.ppp.as_ref().unwrap().protocol());
+            match &result.ip {
+                Some(InternetSlice::Ipv6(ipv6_slice)) => {
+                    assert_eq!(ipv6.source, ipv6_slice.header().source())
+                }
+                _ => panic!("expected ipv6 slice"),
             }
-            err
-        })?;
-
-        //set the new data
-        self.move_by_slice(result.slice());
-        self.result.transport = Some(Icmpv4(result));
+        }
 
-        //done
-        Ok(self.slice_payload())
-    }
+        // session stage with non ip payload (ethernet padding is cut off)
+        {
+            let data = build(
+                PppoeCode::SESSION_DATA,
+                &ppp_payload(PppProtocol::LCP, None),
+            );
+            let result = SlicedPacket::from_ethernet(&data).unwrap();
+            assert_eq!(PppProtocol::LCP, result.ppp.as_ref().unwrap().protocol());
+            assert_eq!(None, result.ip);
+            assert_eq!(&payload[..], result.payload);
+            assert_eq!(None, result.payload_ether_type());
+        }
 
-    pub fn slice_icmp6(mut self) -> Result<SlicedPacket<'a>, err::LenError> {
-        use crate::TransportSlice::*;
+        // discovery stage (payload contains the tags)
+        {
+            let tags = [0x01, 0x01, 0x00, 0x00, 0x01, 0x03, 0x00, 0x02, 0xab, 0xcd];
+            let data = build(PppoeCode::PADI, &tags);
+            let result = SlicedPacket::from_ethernet(&data).unwrap();
+            assert_eq!(PppoeCode::PADI, result.pppoe.as_ref().unwrap().code());
+            assert_eq!(None, result.ppp);
+            assert_eq!(&tags[..], result.payload);
+            assert_eq!(2, PppoeTagsIterator::from_slice(result.payload).count());
+            assert_eq!(None, result.payload_ether_type());
+        }
 
-        let result = Icmpv6Slice::from_slice(self.slice).map_err(|mut err| {
-            err.layer_start_offset += self.offset;
-            if LenSource::Slice == err.len_source {
-                err.len_source = self.len_source;
+        // vlan & pppoe
+        {
+            let mut data = Vec::new();
+            Ethernet2Header {
+                source: MacAddress([1, 2, 3, 4, 5, 6]),
+                destination: MacAddress([7, 8, 9, 10, 11, 12]),
+                ether_type: ether_type::VLAN_TAGGED_FRAME,
             }
-            err
-        })?;
-
-        //set the new data
-        self.move_by_slice(result.slice());
-        self.result.transport = Some(Icmpv6(result));
-
-        //done
-        Ok(self.slice_payload())
-    }
+            .write(&mut data)
+            .unwrap();
+            SingleVlanHeader {
+                pcp: 1.try_into().unwrap(),
+                drop_eligible_indicator: false,
+                vlan_id: 2.try_into().unwrap(),
+                ether_type: ether_type::PPPOE_SESSION,
+            }
+            .write(&mut data)
+            .unwrap();
+            let ppp = ppp_payload(PppProtocol::LCP, None);
+            PppoeHeader {
+                code: PppoeCode::SESSION_DATA,
+                session_id: 1,
+                payload_length: ppp.len() as u16,
+            }
+            .write(&mut data)
+            .unwrap();
+            data.extend_from_slice(&ppp);
 
-    pub fn slice_udp(mut self) -> Result<SlicedPacket<'a>, err::LenError> {
-        use crate::TransportSlice::*;
+            let result = SlicedPacket::from_ethernet(&data).unwrap();
+            assert!(result.vlan.is_some());
+            assert!(result.pppoe.is_some());
+            assert!(result.ppp.is_some());
+            assert_eq!(&payload[..], result.payload);
+        }
 
-        let result = UdpHeaderSlice::from_slice(self.slice).map_err(|mut err| {
-            err.layer_start_offset += self.offset;
-            if LenSource::Slice == err.len_source {
-                err.len_source = self.len_source;
+        // length error in the pppoe header
+        {
+            let data = build(
+                PppoeCode::SESSION_DATA,
+                &ppp_payload(PppProtocol::LCP, None),
+            );
+            for len in 0..PppoeHeader::LEN {
+                assert_eq!(
+                    EthSliceError::Len(LenError {
+                        required_len: PppoeHeader::LEN,
+                        len,
+                        len_source: LenSource::Slice,
+                        layer: Layer::PppoeHeader,
+                        layer_start_offset: Ethernet2Header::LEN,
+                    }),
+                    SlicedPacket::from_ethernet(&data[..Ethernet2Header::LEN + len]).unwrap_err()
+                );
             }
-            err
-        })?;
+        }
 
-        //set the new data
-        self.move_by_slice(result.slice());
-        self.result.transport = Some(Udp(result));
+        // length error in the pppoe payload
+        {
+            let ppp = ppp_payload(PppProtocol::LCP, None);
+            let data = build(PppoeCode::SESSION_DATA, &ppp);
+            let len = PppoeHeader::LEN + ppp.len() - 1;
+            assert_eq!(
+                EthSliceError::Len(LenError {
+                    required_len: PppoeHeader::LEN + ppp.len(),
+                    len,
+                    len_source: LenSource::Slice,
+                    layer: Layer::PppoeHeader,
+                    layer_start_offset: Ethernet2Header::LEN,
+                }),
+                SlicedPacket::from_ethernet(&data[..Ethernet2Header::LEN + len]).unwrap_err()
+            );
+        }
 
-        //done
-        Ok(self.slice_payload())
-    }
+        // length error in the ppp header
+        {
+            let data = build(PppoeCode::SESSION_DATA, &[0]);
+            assert_eq!(
+                EthSliceError::Len(LenError {
+                    required_len: PppHeader::LEN,
+                    len: 1,
+                    len_source: LenSource::PppoeHeaderLen,
+                    layer: Layer::PppHeader,
+                    layer_start_offset: Ethernet2Header::LEN + PppoeHeader::LEN,
+                }),
+                SlicedPacket::from_ethernet(&data).unwrap_err()
+            );
+        }
 
-    pub fn slice_tcp(mut self) -> Result<SlicedPacket<'a>, err::tcp::HeaderSliceError> {
-        use crate::TransportSlice::*;
+        // content error in the pppoe header
+        {
+            let mut data = build(PppoeCode::PADI, &[]);
+            data[Ethernet2Header::LEN] = 0x21;
+            assert_eq!(
+                EthSliceError::Pppoe(err::pppoe::HeaderError::UnexpectedVersion {
+                    version_number: 2
+                }),
+                SlicedPacket::from_ethernet(&data).unwrap_err()
+            );
+        }
+    }
+    #[test]
+    fn from_x_slice_macsec() {
+        use alloc::vec::Vec;
+
+        let payload = [1, 2, 3, 4, 5, 6, 7, 8];
+        let icv = [0xee; MacsecHeaderSlice::ICV_LEN];
+        let padding = [0, 0, 0, 0];
+        let ipv4 = Ipv4Header::new(
+            payload.len() as u16,
+            1,
+            ip_number::UDP,
+            [2, 3, 4, 5],
+            [6, 7, 8, 9],
+        )
+        .unwrap();
+
+        // builds an ethernet frame containing a SecTAG (with SCI), the
+        // given secure data, the icv & ethernet padding
+        let build = |tci_an: u8, short_len: u8, secure_data: &[u8]| {
+            let mut result = Vec::new();
+            Ethernet2Header {
+                source: MacAddress([1, 2, 3, 4, 5, 6]),
+                destination: MacAddress([7, 8, 9, 10, 11, 12]),
+                ether_type: ether_type::MACSEC,
+            }
+            .write(&mut result)
+            .unwrap();
+            result.extend_from_slice(&[tci_an | 0b0010_0000, short_len, 0, 0, 0, 1]);
+            result.extend_from_slice(&0x0102_0304_0506_0001u64.to_be_bytes());
+            result.extend_from_slice(secure_data);
+            result.extend_from_slice(&icv);
+            result.extend_from_slice(&padding);
+            result
+        };
+        let ipv4_data = {
+            let mut result = Vec::new();
+            result.extend_from_slice(&ether_type::IPV4.0.to_be_bytes());
+            ipv4.write(&mut result).unwrap();
+            result.extend_from_slice(&payload);
+            result
+        };
+        let sectag_len = MacsecHeaderSlice::MIN_LEN + MacsecHeaderSlice::SCI_LEN;
 
-        let result = TcpHeaderSlice::from_slice(self.slice).map_err(|mut err| {
-            use err::tcp::HeaderSliceError::Len;
-            if let Len(err) = &mut err {
-                err.layer_start_offset += self.offset;
-                if LenSource::Slice == err.len_source {
-                    err.len_source = self.len_source;
+        // unencrypted with ipv4 payload (short length set)
+        {
+            let data = build(0, ipv4_data.len() as u8, &ipv4_data);
+            let result = SlicedPacket::from_ethernet(&data).unwrap();
+            let macsec = result.macsec.as_ref().unwrap();
+            assert_eq!(1, macsec.packet_nr());
+            assert_eq!(Some(0x0102_0304_0506_0001), macsec.sci());
+            assert_eq!(Some(ether_type::IPV4), macsec.payload_ether_type());
+            match &result.ip {
+                Some(InternetSlice::Ipv4(ipv4_slice)) => {
+                    assert_eq!(ipv4.source, ipv4_slice.header().source())
                 }
+                _ => panic!("expected ipv4 slice"),
             }
-            err
-        })?;
-
-        //set the new data
-        self.move_by_slice(result.slice());
-        self.result.transport = Some(Tcp(result));
+            assert_eq!(None, result.payload_ether_type());
+            // icv & padding are not part of the link trailer
+            assert!(result.link_trailer.is_empty());
+
+            // from ether type
+            let result =
+                SlicedPacket::from_ether_type(ether_type::MACSEC, &data[Ethernet2Header::LEN..])
+                    .unwrap();
+            assert_eq!(None, result.link);
+            assert!(result.macsec.is_some());
+            assert!(result.ip.is_some());
+        }
 
-        //done
-        Ok(self.slice_payload())
-    }
+        // link trailer is limited to the secure data (icv is excluded)
+        {
+            let mut secure_data = ipv4_data.clone();
+            secure_data.extend_from_slice(&[0xaa, 0xbb]);
 
-    pub fn slice_payload(mut self) -> SlicedPacket<'a> {
-        self.result.payload = self.slice;
-        self.result
-    }
-}
+            // short length set
+            let data = build(0, secure_data.len() as u8, &secure_data);
+            let result = SlicedPacket::from_ethernet(&data).unwrap();
+            assert!(result.ip.is_some());
+            assert_eq!(&[0xaa, 0xbb], result.link_trailer);
 
-#[cfg(test)]
-mod test {
-    use super::*;
-    use crate::err::{
-        packet::{EthSliceError, IpSliceError},
-        Layer, LenError,
-    };
-    use crate::test_packet::TestPacket;
+            // no short length (the last ICV_LEN bytes are the icv)
+            let data = build(0, 0, &secure_data);
+            let result = SlicedPacket::from_ethernet(&data).unwrap();
+            let ip_end = Ethernet2Header::LEN + sectag_len + ipv4_data.len();
+            assert_eq!(
+                &data[ip_end..data.len() - MacsecHeaderSlice::ICV_LEN],
+                result.link_trailer
+            );
+        }
 
-    const VLAN_ETHER_TYPES: [EtherType; 3] = [
-        ether_type::VLAN_TAGGED_FRAME,
-        ether_type::PROVIDER_BRIDGING,
-        ether_type::VLAN_DOUBLE_TAGGED_FRAME,
-    ];
+        // unencrypted with unknown payload (icv & padding are cut off)
+        {
+            let mut secure_data = Vec::new();
+            secure_data.extend_from_slice(&[0x12, 0x34]);
+            secure_data.extend_from_slice(&payload);
 
-    #[test]
-    fn from_x_slice() {
-        // no eth
-        from_x_slice_vlan_variants(&TestPacket {
-            link: None,
-            vlan: None,
-            ip: None,
-            transport: None,
-        });
+            // short length set
+            let data = build(0, secure_data.len() as u8, &secure_data);
+            let result = SlicedPacket::from_ethernet(&data).unwrap();
+            assert_eq!(&payload[..], result.payload);
+            assert_eq!(Some(EtherType(0x1234)), result.payload_ether_type());
+
+            // short length not set (only the icv is cut off)
+            let data = build(0, 0, &secure_data);
+            let result = SlicedPacket::from_ethernet(&data[..data.len() - padding.len()]).unwrap();
+            assert_eq!(&payload[..], result.payload);
+            assert_eq!(Some(EtherType(0x1234)), result.payload_ether_type());
+        }
 
-        // eth
+        // encrypted (payload contains the secure data)
         {
-            let eth = Ethernet2Header {
-                source: [1, 2, 3, 4, 5, 6],
-                destination: [1, 2, 3, 4, 5, 6],
-                ether_type: 0.into(),
-            };
-            let test = TestPacket {
-                link: Some(eth.clone()),
-                vlan: None,
-                ip: None,
-                transport: None,
-            };
+            let data = build(0b0000_1100, payload.len() as u8, &payload);
+            let result = SlicedPacket::from_ethernet(&data).unwrap();
+            assert!(result.macsec.as_ref().unwrap().encrypted());
+            assert_eq!(None, result.ip);
+            assert_eq!(&payload[..], result.payload);
+            assert_eq!(None, result.payload_ether_type());
+        }
 
-            // ok ethernet header (with unknown next)
-            from_x_slice_vlan_variants(&test);
+        // vlan tag inside the secure data
+        {
+            let mut secure_data = Vec::new();
+            secure_data.extend_from_slice(&ether_type::VLAN_TAGGED_FRAME.0.to_be_bytes());
+            secure_data.extend_from_slice(&[0, 2]);
+            secure_data.extend_from_slice(&ipv4_data);
+
+            let data = build(0, 0, &secure_data);
+            let result = SlicedPacket::from_ethernet(&data[..data.len() - padding.len()]).unwrap();
+            assert!(result.macsec.is_some());
+            assert!(result.vlan.is_some());
+            assert!(result.ip.is_some());
+            assert!(result.transport.is_some());
+        }
 
-            // eth len error
-            {
-                let data = test.to_vec(&[]);
-                for len in 0..data.len() {
-                    let err = LenError {
-                        required_len: eth.header_len(),
+        // length error in the macsec header
+        {
+            let data = build(0, ipv4_data.len() as u8, &ipv4_data);
+            for len in 0..MacsecHeaderSlice::MIN_LEN {
+                assert_eq!(
+                    EthSliceError::Len(LenError {
+                        required_len: MacsecHeaderSlice::MIN_LEN,
                         len,
                         len_source: LenSource::Slice,
-                        layer: Layer::Ethernet2Header,
-                        layer_start_offset: 0,
-                    };
-
-                    from_slice_assert_err(
-                        &test,
-                        &data[..len],
-                        EthSliceError::Len(err.clone()),
-                        IpSliceError::Len(err.clone()),
-                    );
-                }
+                        layer: Layer::MacsecHeader,
+                        layer_start_offset: Ethernet2Header::LEN,
+                    }),
+                    SlicedPacket::from_ethernet(&data[..Ethernet2Header::LEN + len]).unwrap_err()
+                );
             }
         }
+
+        // length error in the secure data & icv
+        {
+            let data = build(0, ipv4_data.len() as u8, &ipv4_data);
+            let required_len = sectag_len + ipv4_data.len() + icv.len();
+            assert_eq!(
+                EthSliceError::Len(LenError {
+                    required_len,
+                    len: required_len - 1,
+                    len_source: LenSource::Slice,
+                    layer: Layer::MacsecHeader,
+                    layer_start_offset: Ethernet2Header::LEN,
+                }),
+                SlicedPacket::from_ethernet(&data[..Ethernet2Header::LEN + required_len - 1])
+                    .unwrap_err()
+            );
+        }
+
+        // short length too small to contain the ether type
+        {
+            let data = build(0, 1, &ipv4_data);
+            assert_eq!(
+                EthSliceError::Len(LenError {
+                    required_len: sectag_len + 2,
+                    len: sectag_len + 1,
+                    len_source: LenSource::MacsecShortLen,
+                    layer: Layer::MacsecHeader,
+                    layer_start_offset: Ethernet2Header::LEN,
+                }),
+                SlicedPacket::from_ethernet(&data).unwrap_err()
+            );
+        }
+
+        // length error in the ipv4 header (limited by the short length)
+        {
+            let data = build(0, 4, &ipv4_data);
+            assert_eq!(
+                EthSliceError::Len(LenError {
+                    required_len: Ipv4Header::MIN_LEN,
+                    len: 2,
+                    len_source: LenSource::MacsecShortLen,
+                    layer: Layer::Ipv4Header,
+                    layer_start_offset: Ethernet2Header::LEN + sectag_len + 2,
+                }),
+                SlicedPacket::from_ethernet(&data).unwrap_err()
+            );
+        }
+
+        // content error in the macsec header
+        {
+            let data = build(0b1000_0000, 0, &ipv4_data);
+            assert_eq!(
+                EthSliceError::Macsec(err::macsec::HeaderError::UnexpectedVersion {
+                    version_number: 1
+                }),
+                SlicedPacket::from_ethernet(&data).unwrap_err()
+            );
+        }
     }
 
     fn from_x_slice_vlan_variants(base: &TestPacket) {
@@ -679,7 +4020,7 @@ mod test {
                 pcp: 1.try_into().unwrap(),
                 drop_eligible_indicator: false,
                 vlan_id: 2.try_into().unwrap(),
-                ether_type: 3.into(),
+                ether_type: ether_type::WAKE_ON_LAN,
             };
 
             for vlan_ether_type in VLAN_ETHER_TYPES {
@@ -728,7 +4069,7 @@ mod test {
                         pcp: 1.try_into().unwrap(),
                         drop_eligible_indicator: false,
                         vlan_id: 2.try_into().unwrap(),
-                        ether_type: 3.into(),
+                        ether_type: ether_type::WAKE_ON_LAN,
                     },
                 };
                 let mut test = base.clone();
@@ -761,6 +4102,52 @@ mod test {
                 }
             }
         }
+
+        // multi vlan header
+        for vlan_ether_type in VLAN_ETHER_TYPES {
+            let tag = |ether_type| SingleVlanHeader {
+                pcp: 1.try_into().unwrap(),
+                drop_eligible_indicator: false,
+                vlan_id: 2.try_into().unwrap(),
+                ether_type,
+            };
+            let stack = VlanStack::try_from(
+                &[
+                    tag(vlan_ether_type),
+                    tag(vlan_ether_type),
+                    tag(ether_type::WAKE_ON_LAN),
+                ][..],
+            )
+            .unwrap();
+            let mut test = base.clone();
+            test.set_ether_type(vlan_ether_type);
+            test.vlan = Some(VlanHeader::Multi(stack));
+
+            // ok multi vlan header
+            from_x_slice_ip_variants(&test);
+
+            // len error
+            {
+                let data = test.to_vec(&[]);
+                for len in 0..SingleVlanHeader::LEN {
+                    let base_len = test.len(&[]) - SingleVlanHeader::LEN;
+
+                    let err = LenError {
+                        required_len: SingleVlanHeader::LEN,
+                        len,
+                        len_source: LenSource::Slice,
+                        layer: Layer::VlanHeader,
+                        layer_start_offset: base_len,
+                    };
+                    from_slice_assert_err(
+                        &test,
+                        &data[..base_len + len],
+                        EthSliceError::Len(err.clone()),
+                        IpSliceError::Len(err.clone()),
+                    );
+                }
+            }
+        }
     }
 
     fn from_x_slice_ip_variants(base: &TestPacket) {
@@ -879,6 +4266,7 @@ mod test {
                     },
                     Ipv4Extensions {
                         auth: Some(auth.clone()),
+                        esp: None,
                     },
                 ));
                 test.set_payload_len(0);
@@ -1333,7 +4721,10 @@ mod test {
 
             // check headers
             assert_eq!(test.link, result.link.as_ref().map(|e| e.to_header()));
-            assert_eq!(test.vlan, result.vlan.as_ref().map(|e| e.to_header()));
+            assert_eq!(
+                test.vlan,
+                result.vlan.as_ref().map(|e| e.to_header().unwrap())
+            );
             assert_eq!(
                 test.ip,
                 result.ip.as_ref().map(|s: &InternetSlice| -> IpHeader {
@@ -1414,9 +4805,9 @@ mod test {
         // write data
         let data = test.to_vec(&payload);
 
-        // from_ethernet
-        if test.link.is_some() {
-            let result = SlicedPacket::from_ethernet(&data).unwrap();
+        // from_ethernet & from_linux_sll*
+        if let Some(link) = &test.link {
+            let result = from_link_slice(link, &data).unwrap();
             assert_test_result(&test, &payload, &data, &result);
         }
         // from_ether_type (vlan at start)
@@ -1445,6 +4836,39 @@ mod test {
             let result = SlicedPacket::from_ip(&data).unwrap();
             assert_test_result(&test, &payload, &data, &result);
         }
+
+        // data after the ip packet is returned as link trailer
+        if test.ip.is_some() {
+            let trailer = [0xaa, 0xbb, 0xcc];
+            let mut data = data.clone();
+            data.extend_from_slice(&trailer);
+            let result = if let Some(link) = &test.link {
+                from_link_slice(link, &data).unwrap()
+            } else if test.vlan.is_some() {
+                SlicedPacket::from_ether_type(VLAN_ETHER_TYPES[0], &data).unwrap()
+            } else {
+                SlicedPacket::from_ip(&data).unwrap()
+            };
+            assert_test_result(
+                &test,
+                &payload,
+                &data[..data.len() - trailer.len()],
+                &result,
+            );
+            assert_eq!(&trailer[..], result.link_trailer);
+        }
+    }
+
+    /// Slice the given data with the function matching the link header.
+    fn from_link_slice<'a>(
+        link: &LinkHeader,
+        data: &'a [u8],
+    ) -> Result<SlicedPacket<'a>, EthSliceError> {
+        match link {
+            LinkHeader::Ethernet2(_) => SlicedPacket::from_ethernet(data),
+            LinkHeader::LinuxSll(_) => SlicedPacket::from_linux_sll(data),
+            LinkHeader::LinuxSll2(_) => SlicedPacket::from_linux_sll2(data),
+        }
     }
 
     /// Check that the given errors get triggered if presented with the given
@@ -1455,12 +4879,9 @@ mod test {
         eth_err: EthSliceError,
         ip_err: IpSliceError,
     ) {
-        // from_ethernet_slice
-        if test.link.is_some() {
-            assert_eq!(
-                eth_err.clone(),
-                SlicedPacket::from_ethernet(&data).unwrap_err()
-            );
+        // from_ethernet & from_linux_sll*
+        if let Some(link) = &test.link {
+            assert_eq!(eth_err.clone(), from_link_slice(link, &data).unwrap_err());
         }
         // from_ether_type (vlan at start)
         if test.link.is_none() && test.vlan.is_some() {
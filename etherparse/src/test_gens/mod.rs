@@ -63,8 +63,8 @@ prop_compose! {
         -> Ethernet2Header
     {
         Ethernet2Header {
-            source: source,
-            destination: dest,
+            source: MacAddress(source),
+            destination: MacAddress(dest),
             ether_type: ether_type
         }
     }
@@ -83,9 +83,14 @@ prop_compose! {
 pub static ETHERNET_KNOWN_ETHER_TYPES: &'static [EtherType] = &[
     ether_type::IPV4,
     ether_type::IPV6,
+    ether_type::ARP,
     ether_type::VLAN_TAGGED_FRAME,
     ether_type::PROVIDER_BRIDGING,
     ether_type::VLAN_DOUBLE_TAGGED_FRAME,
+    ether_type::MPLS_UNICAST,
+    ether_type::MPLS_MULTICAST,
+    ether_type::PPPOE_DISCOVERY,
+    ether_type::PPPOE_SESSION,
 ];
 
 prop_compose! {
@@ -93,17 +98,165 @@ prop_compose! {
         source in prop::array::uniform6(any::<u8>()),
         dest in prop::array::uniform6(any::<u8>()),
         ether_type in ether_type_any().prop_filter("ether_type must be unknown",
-            |v| !ETHERNET_KNOWN_ETHER_TYPES.iter().any(|&x| v == &x)))
+            // values from 1 to 1500 are IEEE 802.3 lengths
+            |v| v.0 >= 0x0600 && !ETHERNET_KNOWN_ETHER_TYPES.iter().any(|&x| v == &x)))
         -> Ethernet2Header
     {
         Ethernet2Header {
-            source: source,
-            destination: dest,
+            source: MacAddress(source),
+            destination: MacAddress(dest),
             ether_type: ether_type
         }
     }
 }
 
+prop_compose! {
+    pub fn linux_sll_any()(
+        packet_type in any::<u16>(),
+        arp_hrd_type in any::<u16>(),
+        sender_address_valid_length in any::<u16>(),
+        sender_address in prop::array::uniform8(any::<u8>()),
+        protocol_type in any::<u16>())
+        -> LinuxSllHeader
+    {
+        LinuxSllHeader {
+            packet_type: LinuxSllPacketType(packet_type),
+            arp_hrd_type: ArpHardwareId(arp_hrd_type),
+            sender_address_valid_length,
+            sender_address,
+            protocol_type: EtherType(protocol_type),
+        }
+    }
+}
+
+prop_compose! {
+    pub fn linux_sll2_any()(
+        protocol_type in any::<u16>(),
+        interface_index in any::<u32>(),
+        arp_hrd_type in any::<u16>(),
+        packet_type in any::<u8>(),
+        sender_address_valid_length in any::<u8>(),
+        sender_address in prop::array::uniform8(any::<u8>()))
+        -> LinuxSll2Header
+    {
+        LinuxSll2Header {
+            protocol_type: EtherType(protocol_type),
+            interface_index,
+            arp_hrd_type: ArpHardwareId(arp_hrd_type),
+            packet_type,
+            sender_address_valid_length,
+            sender_address,
+        }
+    }
+}
+
+prop_compose! {
+    pub fn llc_any()(
+        dsap in any::<u8>(),
+        ssap in any::<u8>(),
+        control in any::<u8>(),
+        control_ext in any::<u8>())
+        -> LlcHeader
+    {
+        LlcHeader {
+            dsap,
+            ssap,
+            control: [
+                control,
+                // second control octet is only present for I- & S-format frames
+                if 0b11 == control & 0b11 { 0 } else { control_ext }
+            ],
+        }
+    }
+}
+
+prop_compose! {
+    pub fn snap_any()(
+        oui in prop::array::uniform3(any::<u8>()),
+        ether_type in ether_type_any())
+        -> SnapHeader
+    {
+        SnapHeader {
+            oui,
+            ether_type,
+        }
+    }
+}
+
+prop_compose! {
+    pub fn pppoe_any()(
+        code in any::<u8>(),
+        session_id in any::<u16>(),
+        payload_length in any::<u16>())
+        -> PppoeHeader
+    {
+        PppoeHeader {
+            code: PppoeCode(code),
+            session_id,
+            payload_length,
+        }
+    }
+}
+
+prop_compose! {
+    pub fn gre_any()(
+        version in 0..=GreHeader::VERSION_ENHANCED,
+        protocol_type in ether_type_any(),
+        checksum in proptest::option::of(any::<u16>()),
+        key in proptest::option::of(any::<u32>()),
+        sequence_number in proptest::option::of(any::<u32>()),
+        acknowledgment_number in proptest::option::of(any::<u32>()))
+        -> GreHeader
+    {
+        GreHeader {
+            version,
+            protocol_type,
+            checksum,
+            key,
+            sequence_number,
+            // acknowledgment numbers are only supported in the enhanced header
+            acknowledgment_number: if GreHeader::VERSION_ENHANCED == version {
+                acknowledgment_number
+            } else {
+                None
+            },
+        }
+    }
+}
+
+prop_compose! {
+    pub fn mpls_entry_any()(
+        label in 0..=MplsLabel::MAX_U32,
+        traffic_class in 0..=MplsTrafficClass::MAX_U8,
+        bottom_of_stack in any::<bool>(),
+        ttl in any::<u8>())
+        -> MplsLabelStackEntry
+    {
+        MplsLabelStackEntry {
+            label: label.try_into().unwrap(),
+            traffic_class: traffic_class.try_into().unwrap(),
+            bottom_of_stack,
+            ttl,
+        }
+    }
+}
+
+prop_compose! {
+    pub fn mpls_stack_any()(
+        entries in prop::collection::vec(mpls_entry_any(), 1..=MplsLabelStack::MAX_ENTRIES))
+        -> MplsLabelStack
+    {
+        let mut result = MplsLabelStack::new();
+        let last = entries.len() - 1;
+        for (i, mut entry) in entries.into_iter().enumerate() {
+            // only the last entry has the bottom of stack flag set
+            entry.bottom_of_stack = i == last;
+            result.try_push(entry).unwrap();
+        }
+        result
+    }
+}
+
 prop_compose! {
     pub fn vlan_single_with(ether_type: EtherType)(
         pcp in vlan_pcp_any(),
@@ -156,6 +309,74 @@ prop_compose! {
     }
 }
 
+prop_compose! {
+    pub fn vlan_stack_any()(
+        tags in prop::collection::vec(vlan_single_any(), 1..=VlanStack::MAX_TAGS),
+        vlan_ether_type in vlan_ethertype_any(),
+        ether_type in ether_type_any().prop_filter(
+            "ether_type must not be a vlan ether type",
+            |v| !VlanHeader::VLAN_ETHER_TYPES.iter().any(|&x| v == &x)
+        )
+    ) -> VlanStack {
+        let mut result = VlanStack::new();
+        let last = tags.len() - 1;
+        for (i, mut tag) in tags.into_iter().enumerate() {
+            // only the last tag has a non vlan ether type
+            tag.ether_type = if i == last { ether_type } else { vlan_ether_type };
+            result.try_push(tag).unwrap();
+        }
+        result
+    }
+}
+
+prop_compose! {
+    pub fn arp_any()
+        (
+            hw_addr_size in 0usize..20,
+            protocol_addr_size in 0usize..20
+        )
+        (
+            hw_addr_type in any::<u16>(),
+            proto_addr_type in any::<u16>(),
+            operation in any::<u16>(),
+            sender_hw_addr in prop::collection::vec(any::<u8>(), hw_addr_size),
+            sender_protocol_addr in prop::collection::vec(any::<u8>(), protocol_addr_size),
+            target_hw_addr in prop::collection::vec(any::<u8>(), hw_addr_size),
+            target_protocol_addr in prop::collection::vec(any::<u8>(), protocol_addr_size)
+        ) -> ArpHeader
+    {
+        ArpHeader::new(
+            ArpHardwareId(hw_addr_type),
+            EtherType(proto_addr_type),
+            ArpOperation(operation),
+            &sender_hw_addr,
+            &sender_protocol_addr,
+            &target_hw_addr,
+            &target_protocol_addr
+        ).unwrap()
+    }
+}
+
+prop_compose! {
+    pub fn arp_eth_ipv4_any()
+        (
+            operation in any::<u16>(),
+            sender_mac in prop::array::uniform6(any::<u8>()),
+            sender_ipv4 in prop::array::uniform4(any::<u8>()),
+            target_mac in prop::array::uniform6(any::<u8>()),
+            target_ipv4 in prop::array::uniform4(any::<u8>())
+        ) -> ArpEthIpv4Header
+    {
+        ArpEthIpv4Header {
+            operation: ArpOperation(operation),
+            sender_mac: MacAddress(sender_mac),
+            sender_ipv4,
+            target_mac: MacAddress(target_mac),
+            target_ipv4,
+        }
+    }
+}
+
 prop_compose! {
     pub fn ipv4_options_any()
     (
@@ -240,10 +461,12 @@ prop_compose! {
         if has_auth {
             Ipv4Extensions{
                 auth: Some(auth),
+                esp: None,
             }
         } else {
             Ipv4Extensions{
                 auth: None,
+                esp: None,
             }
         }
     }
@@ -251,7 +474,10 @@ prop_compose! {
 
 prop_compose! {
     pub fn ipv4_extensions_any()
-               (protocol in ip_number_any())
+               (protocol in ip_number_any().prop_filter(
+                   "protocol must not be ESP (would be parsed as an extension header)",
+                   |v| ip_number::ENCAP_SEC != *v
+               ))
                (result in ipv4_extensions_with(protocol))
                -> Ipv4Extensions
     {
@@ -374,6 +600,12 @@ prop_compose! {
             } else {
                 None
             },
+            esp: None,
+            shim6: None,
+            mobility: None,
+            hip: None,
+            exp0: None,
+            exp1: None,
         };
         result.set_next_headers(next_header);
         result
@@ -452,6 +684,18 @@ prop_compose! {
     }
 }
 
+prop_compose! {
+    pub fn esp_any()(
+        spi in any::<u32>(),
+        sequence_number in any::<u32>()
+    ) -> EspHeader {
+        EspHeader {
+            spi,
+            sequence_number,
+        }
+    }
+}
+
 prop_compose! {
     pub fn udp_any()(
             source_port in any::<u16>(),
@@ -469,6 +713,88 @@ prop_compose! {
     }
 }
 
+prop_compose! {
+    pub fn vni_any()(value in 0..=Vni::MAX_U32) -> Vni {
+        Vni::try_new(value).unwrap()
+    }
+}
+
+prop_compose! {
+    pub fn vxlan_any()(
+        vni in vni_any(),
+        gpe_next_protocol in proptest::option::of(any::<u8>()))
+        -> VxlanHeader
+    {
+        VxlanHeader {
+            vni,
+            gpe_next_protocol: gpe_next_protocol.map(VxlanGpeNextProtocol),
+        }
+    }
+}
+
+prop_compose! {
+    pub fn geneve_any()
+        (options_len_div_4 in 0..=(GeneveOptions::MAX_LEN / 4))
+        (
+            oam in any::<bool>(),
+            critical in any::<bool>(),
+            protocol_type in ether_type_any(),
+            vni in vni_any(),
+            options in prop::collection::vec(any::<u8>(), options_len_div_4 * 4)
+        ) -> GeneveHeader
+    {
+        GeneveHeader {
+            oam,
+            critical,
+            protocol_type,
+            vni,
+            options: GeneveOptions::try_from(&options[..]).unwrap(),
+        }
+    }
+}
+
+prop_compose! {
+    pub fn gtpu_extension_header_any()
+        (len_div_4 in 1usize..4)
+        (
+            header_type in 1u8..=u8::MAX,
+            content in prop::collection::vec(any::<u8>(), len_div_4 * 4 - 2)
+        ) -> (GtpuExtensionHeaderType, alloc::vec::Vec<u8>)
+    {
+        (GtpuExtensionHeaderType(header_type), content)
+    }
+}
+
+prop_compose! {
+    pub fn gtpu_any()(
+        message_type in any::<u8>(),
+        teid in any::<u32>(),
+        sequence_number in proptest::option::of(any::<u16>()),
+        n_pdu_number in proptest::option::of(any::<u8>()),
+        extension_headers in prop::collection::vec(gtpu_extension_header_any(), 0..3),
+        payload_len in 0usize..1000
+    ) -> GtpuHeader
+    {
+        let extension_headers: alloc::vec::Vec<_> = extension_headers
+            .iter()
+            .map(|(header_type, content)| GtpuExtensionHeader {
+                header_type: *header_type,
+                content: &content[..],
+            })
+            .collect();
+        let mut result = GtpuHeader {
+            message_type: GtpuMessageType(message_type),
+            length: 0,
+            teid,
+            sequence_number,
+            n_pdu_number,
+            extension_headers: GtpuExtensionHeaders::try_from_headers(&extension_headers).unwrap(),
+        };
+        result.set_payload_len(payload_len).unwrap();
+        result
+    }
+}
+
 prop_compose! {
     pub fn tcp_any()
         (data_offset in TcpHeader::MIN_DATA_OFFSET..(TcpHeader::MAX_DATA_OFFSET + 1))
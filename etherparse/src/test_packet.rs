@@ -1,9 +1,9 @@
-use crate::{EtherType, Ethernet2Header, IpHeader, TransportHeader, VlanHeader};
+use crate::{EtherType, IpHeader, LinkHeader, TransportHeader, VlanHeader};
 use alloc::vec::Vec;
 
 #[derive(Clone)]
 pub(crate) struct TestPacket {
-    pub link: Option<Ethernet2Header>,
+    pub link: Option<LinkHeader>,
     pub vlan: Option<VlanHeader>,
     pub ip: Option<IpHeader>,
     pub transport: Option<TransportHeader>,
@@ -55,9 +55,16 @@ impl TestPacket {
                 Double(double) => {
                     double.inner.ether_type = ether_type;
                 }
+                Multi(stack) => {
+                    let mut tags = stack.tags().to_vec();
+                    if let Some(inner) = tags.last_mut() {
+                        inner.ether_type = ether_type;
+                    }
+                    *stack = crate::VlanStack::try_from(&tags[..]).unwrap();
+                }
             }
         } else if let Some(link) = &mut self.link {
-            link.ether_type = ether_type;
+            link.set_payload_ether_type(ether_type);
         }
     }
 
@@ -0,0 +1,256 @@
+use crate::{err::Layer, err::SliceWriteSpaceError, *};
+use arrayvec::ArrayVec;
+
+/// Geneve header (Generic Network Virtualization Encapsulation, RFC 8926).
+///
+/// Geneve headers are transported in UDP datagrams with the destination
+/// port [`GeneveHeader::UDP_PORT`] (6081). The `protocol_type` identifies
+/// the payload via an ether type (e.g.
+/// [`ether_type::TRANSPARENT_ETHERNET_BRIDGING`] for ethernet frames).
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Default)]
+pub struct GeneveHeader {
+    /// If set the payload contains a control message ("O" flag).
+    pub oam: bool,
+    /// If set at least one critical option is present ("C" flag).
+    pub critical: bool,
+    /// Ether type identifying the payload.
+    pub protocol_type: EtherType,
+    /// Virtual network identifier.
+    pub vni: Vni,
+    /// Encoded options (see [`GeneveOptions::iter`] to decode them).
+    pub options: GeneveOptions,
+}
+
+impl GeneveHeader {
+    /// Minimum length of a Geneve header in bytes/octets (no options).
+    pub const MIN_LEN: usize = 8;
+
+    /// Maximum length of a Geneve header in bytes/octets.
+    pub const MAX_LEN: usize = GeneveHeader::MIN_LEN + GeneveOptions::MAX_LEN;
+
+    /// UDP destination port assigned by IANA for Geneve.
+    pub const UDP_PORT: u16 = 6081;
+
+    /// Version number of the Geneve header defined in RFC 8926.
+    pub const VERSION: u8 = 0;
+
+    /// Flag indicating that the payload contains a control message ("O" flag).
+    pub const FLAG_OAM: u8 = 0b1000_0000;
+
+    /// Flag indicating that critical options are present ("C" flag).
+    pub const FLAG_CRITICAL: u8 = 0b0100_0000;
+
+    /// Creates a Geneve header without options.
+    #[inline]
+    pub fn new(protocol_type: EtherType, vni: Vni) -> GeneveHeader {
+        GeneveHeader {
+            oam: false,
+            critical: false,
+            protocol_type,
+            vni,
+            options: GeneveOptions::new(),
+        }
+    }
+
+    /// Read a [`GeneveHeader`] from a slice and return the header & unused parts of the slice.
+    #[inline]
+    pub fn from_slice(
+        slice: &[u8],
+    ) -> Result<(GeneveHeader, &[u8]), err::geneve::HeaderSliceError> {
+        let header = GeneveHeaderSlice::from_slice(slice)?;
+        Ok((header.to_header(), &slice[header.slice().len()..]))
+    }
+
+    /// Serialize the header to a given slice. Returns the unused part of the slice.
+    pub fn write_to_slice<'a>(
+        &self,
+        slice: &'a mut [u8],
+    ) -> Result<&'a mut [u8], SliceWriteSpaceError> {
+        let len = self.header_len();
+        // length check
+        if slice.len() < len {
+            Err(SliceWriteSpaceError {
+                required_len: len,
+                len: slice.len(),
+                layer: Layer::GeneveHeader,
+                layer_start_offset: 0,
+            })
+        } else {
+            slice[..len].copy_from_slice(&self.to_bytes());
+            Ok(&mut slice[len..])
+        }
+    }
+
+    /// Writes a given Geneve header to the current position of the write argument.
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn write<T: std::io::Write + Sized>(&self, writer: &mut T) -> Result<(), std::io::Error> {
+        writer.write_all(&self.to_bytes())
+    }
+
+    /// Length of the serialized header in bytes.
+    #[inline]
+    pub fn header_len(&self) -> usize {
+        GeneveHeader::MIN_LEN + self.options.len()
+    }
+
+    /// Returns the serialized form of the header.
+    pub fn to_bytes(&self) -> ArrayVec<u8, { GeneveHeader::MAX_LEN }> {
+        let mut flags = 0;
+        if self.oam {
+            flags |= GeneveHeader::FLAG_OAM;
+        }
+        if self.critical {
+            flags |= GeneveHeader::FLAG_CRITICAL;
+        }
+        let protocol_type_be = self.protocol_type.0.to_be_bytes();
+        let vni_be = self.vni.value().to_be_bytes();
+
+        let mut result = ArrayVec::new();
+        // version is always 0, options length in multiples of 4
+        result.push((self.options.len() / 4) as u8);
+        result.push(flags);
+        result.push(protocol_type_be[0]);
+        result.push(protocol_type_be[1]);
+        result.push(vni_be[1]);
+        result.push(vni_be[2]);
+        result.push(vni_be[3]);
+        result.push(0);
+        // options length is guaranteed to fit as MAX_LEN includes the options
+        let _ = result.try_extend_from_slice(self.options.as_slice());
+        result
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_gens::*;
+    use alloc::{format, vec::Vec};
+    use proptest::prelude::*;
+
+    #[test]
+    fn constants() {
+        assert_eq!(8, GeneveHeader::MIN_LEN);
+        assert_eq!(260, GeneveHeader::MAX_LEN);
+        assert_eq!(6081, GeneveHeader::UDP_PORT);
+        assert_eq!(0, GeneveHeader::VERSION);
+    }
+
+    proptest! {
+        #[test]
+        fn new(
+            protocol_type in ether_type_any(),
+            vni in vni_any()
+        ) {
+            assert_eq!(
+                GeneveHeader::new(protocol_type, vni),
+                GeneveHeader {
+                    oam: false,
+                    critical: false,
+                    protocol_type,
+                    vni,
+                    options: GeneveOptions::new(),
+                }
+            );
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn from_slice(
+            input in geneve_any(),
+            dummy_data in proptest::collection::vec(any::<u8>(), 0..20)
+        ) {
+            // serialize
+            let mut buffer: Vec<u8> = Vec::with_capacity(input.header_len() + dummy_data.len());
+            input.write(&mut buffer).unwrap();
+            buffer.extend(&dummy_data[..]);
+
+            // calls with a valid result
+            {
+                let (result, rest) = GeneveHeader::from_slice(&buffer[..]).unwrap();
+                assert_eq!(input, result);
+                assert_eq!(&buffer[input.header_len()..], rest);
+            }
+
+            // call with not enough data in the slice
+            for len in 0..input.header_len() {
+                assert_eq!(
+                    GeneveHeader::from_slice(&buffer[..len]),
+                    Err(err::geneve::HeaderSliceError::Len(err::LenError{
+                        required_len: if len < GeneveHeader::MIN_LEN {
+                            GeneveHeader::MIN_LEN
+                        } else {
+                            input.header_len()
+                        },
+                        len,
+                        len_source: err::LenSource::Slice,
+                        layer: err::Layer::GeneveHeader,
+                        layer_start_offset: 0,
+                    }))
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn write_to_slice(input in geneve_any()) {
+            // normal write
+            {
+                let mut buffer = [0u8; GeneveHeader::MAX_LEN];
+                let rest_len = input.write_to_slice(&mut buffer).unwrap().len();
+                assert_eq!(GeneveHeader::MAX_LEN - input.header_len(), rest_len);
+                assert_eq!(&input.to_bytes()[..], &buffer[..input.header_len()]);
+            }
+            // len error
+            for len in 0..input.header_len() {
+                let mut buffer = [0u8; GeneveHeader::MAX_LEN];
+                assert_eq!(
+                    SliceWriteSpaceError {
+                        required_len: input.header_len(),
+                        len,
+                        layer: Layer::GeneveHeader,
+                        layer_start_offset: 0,
+                    },
+                    input.write_to_slice(&mut buffer[..len]).unwrap_err()
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn to_bytes(input in geneve_any()) {
+            let bytes = input.to_bytes();
+            let vni_be = input.vni.value().to_be_bytes();
+            assert_eq!(input.header_len(), bytes.len());
+            assert_eq!((input.options.len() / 4) as u8, bytes[0]);
+            assert_eq!(input.oam, 0 != bytes[1] & GeneveHeader::FLAG_OAM);
+            assert_eq!(input.critical, 0 != bytes[1] & GeneveHeader::FLAG_CRITICAL);
+            assert_eq!(input.protocol_type.0.to_be_bytes(), [bytes[2], bytes[3]]);
+            assert_eq!(&vni_be[1..], &bytes[4..7]);
+            assert_eq!(0, bytes[7]);
+            assert_eq!(input.options.as_slice(), &bytes[8..]);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn clone_eq_dbg(input in geneve_any()) {
+            assert_eq!(input, input.clone());
+            assert_eq!(
+                format!(
+                    "GeneveHeader {{ oam: {:?}, critical: {:?}, protocol_type: {:?}, vni: {:?}, options: {:?} }}",
+                    input.oam,
+                    input.critical,
+                    input.protocol_type,
+                    input.vni,
+                    input.options,
+                ),
+                format!("{:?}", input)
+            );
+        }
+    }
+}
@@ -0,0 +1,260 @@
+use crate::*;
+use core::slice::from_raw_parts;
+
+/// A slice containing a Geneve header (RFC 8926) including the options.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GeneveHeaderSlice<'a> {
+    slice: &'a [u8],
+}
+
+impl<'a> GeneveHeaderSlice<'a> {
+    /// Creates a Geneve header slice from an other slice (verifies that the
+    /// version is `0`).
+    pub fn from_slice(
+        slice: &'a [u8],
+    ) -> Result<GeneveHeaderSlice<'a>, err::geneve::HeaderSliceError> {
+        use err::geneve::{HeaderError::*, HeaderSliceError::*};
+
+        let len_error = |required_len: usize| {
+            Len(err::LenError {
+                required_len,
+                len: slice.len(),
+                len_source: err::LenSource::Slice,
+                layer: err::Layer::GeneveHeader,
+                layer_start_offset: 0,
+            })
+        };
+
+        //check minimum length
+        if slice.len() < GeneveHeader::MIN_LEN {
+            return Err(len_error(GeneveHeader::MIN_LEN));
+        }
+
+        // SAFETY:
+        // Safe as the slice length is checked to be at least
+        // GeneveHeader::MIN_LEN (8) before this.
+        let ver_opt_len = unsafe { *slice.get_unchecked(0) };
+
+        //check version
+        let version_number = ver_opt_len >> 6;
+        if version_number != GeneveHeader::VERSION {
+            return Err(Content(UnsupportedVersion { version_number }));
+        }
+
+        //check that the options are present
+        let len = GeneveHeader::MIN_LEN + usize::from(ver_opt_len & 0b11_1111) * 4;
+        if slice.len() < len {
+            return Err(len_error(len));
+        }
+
+        //all done
+        Ok(GeneveHeaderSlice {
+            // SAFETY:
+            // Safe as slice length is checked to be at least len before this.
+            slice: unsafe { from_raw_parts(slice.as_ptr(), len) },
+        })
+    }
+
+    /// Returns the slice containing the Geneve header (including options).
+    #[inline]
+    pub fn slice(&self) -> &'a [u8] {
+        self.slice
+    }
+
+    /// Returns the version field of the header (always `0` as other
+    /// versions are rejected by [`GeneveHeaderSlice::from_slice`]).
+    #[inline]
+    pub fn version(&self) -> u8 {
+        // SAFETY:
+        // Safe as the contructor checks that the slice has
+        // at least the length of GeneveHeader::MIN_LEN (8).
+        unsafe { *self.slice.get_unchecked(0) >> 6 }
+    }
+
+    /// Length of the options in bytes.
+    #[inline]
+    pub fn options_len(&self) -> usize {
+        self.slice.len() - GeneveHeader::MIN_LEN
+    }
+
+    /// Length of the header (including options) in bytes.
+    #[inline]
+    pub fn header_len(&self) -> usize {
+        self.slice.len()
+    }
+
+    /// Read the "O" flag (payload contains a control message).
+    #[inline]
+    pub fn oam(&self) -> bool {
+        // SAFETY:
+        // Safe as the contructor checks that the slice has
+        // at least the length of GeneveHeader::MIN_LEN (8).
+        0 != unsafe { *self.slice.get_unchecked(1) } & GeneveHeader::FLAG_OAM
+    }
+
+    /// Read the "C" flag (critical options are present).
+    #[inline]
+    pub fn critical(&self) -> bool {
+        // SAFETY:
+        // Safe as the contructor checks that the slice has
+        // at least the length of GeneveHeader::MIN_LEN (8).
+        0 != unsafe { *self.slice.get_unchecked(1) } & GeneveHeader::FLAG_CRITICAL
+    }
+
+    /// Returns the ether type identifying the payload.
+    #[inline]
+    pub fn protocol_type(&self) -> EtherType {
+        // SAFETY:
+        // Safe as the contructor checks that the slice has
+        // at least the length of GeneveHeader::MIN_LEN (8).
+        EtherType(unsafe { get_unchecked_be_u16(self.slice.as_ptr().add(2)) })
+    }
+
+    /// Returns the virtual network identifier.
+    #[inline]
+    pub fn vni(&self) -> Vni {
+        // SAFETY:
+        // Safe as the contructor checks that the slice has
+        // at least the length of GeneveHeader::MIN_LEN (8) and the
+        // value is guaranteed to be a 24 bit value.
+        unsafe {
+            Vni::new_unchecked(u32::from_be_bytes([
+                0,
+                *self.slice.get_unchecked(4),
+                *self.slice.get_unchecked(5),
+                *self.slice.get_unchecked(6),
+            ]))
+        }
+    }
+
+    /// Returns the slice containing the encoded options.
+    #[inline]
+    pub fn options(&self) -> &'a [u8] {
+        // SAFETY:
+        // Safe as the contructor checks that the slice has
+        // at least the length of GeneveHeader::MIN_LEN (8).
+        unsafe {
+            from_raw_parts(
+                self.slice.as_ptr().add(GeneveHeader::MIN_LEN),
+                self.slice.len() - GeneveHeader::MIN_LEN,
+            )
+        }
+    }
+
+    /// Returns an iterator over the options of the header.
+    #[inline]
+    pub fn options_iter(&self) -> GeneveOptionsIterator<'a> {
+        GeneveOptionsIterator::from_slice(self.options())
+    }
+
+    /// Decode all the fields and copy the results to a [`GeneveHeader`] struct.
+    pub fn to_header(&self) -> GeneveHeader {
+        GeneveHeader {
+            oam: self.oam(),
+            critical: self.critical(),
+            protocol_type: self.protocol_type(),
+            vni: self.vni(),
+            // the options length is guaranteed to be a multiple of 4 &
+            // smaller or equal to 252 by the 6 bit length field
+            options: GeneveOptions::try_from(self.options()).unwrap(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{test_gens::*, *};
+    use alloc::{format, vec::Vec};
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn from_slice(
+            input in geneve_any(),
+            dummy_data in proptest::collection::vec(any::<u8>(), 0..20),
+            bad_version in 1u8..=3
+        ) {
+            // serialize
+            let mut buffer: Vec<u8> = Vec::with_capacity(input.header_len() + dummy_data.len());
+            input.write(&mut buffer).unwrap();
+            buffer.extend(&dummy_data[..]);
+
+            // calls with a valid result
+            {
+                let result = GeneveHeaderSlice::from_slice(&buffer[..]).unwrap();
+                assert_eq!(&buffer[..input.header_len()], result.slice());
+            }
+
+            // call with not enough data in the slice
+            for len in 0..input.header_len() {
+                assert_eq!(
+                    GeneveHeaderSlice::from_slice(&buffer[..len]).unwrap_err(),
+                    err::geneve::HeaderSliceError::Len(err::LenError{
+                        required_len: if len < GeneveHeader::MIN_LEN {
+                            GeneveHeader::MIN_LEN
+                        } else {
+                            input.header_len()
+                        },
+                        len,
+                        len_source: err::LenSource::Slice,
+                        layer: err::Layer::GeneveHeader,
+                        layer_start_offset: 0,
+                    })
+                );
+            }
+
+            // unsupported version
+            {
+                let mut buffer = buffer.clone();
+                buffer[0] |= bad_version << 6;
+                assert_eq!(
+                    GeneveHeaderSlice::from_slice(&buffer[..]).unwrap_err(),
+                    err::geneve::HeaderSliceError::Content(
+                        err::geneve::HeaderError::UnsupportedVersion {
+                            version_number: bad_version
+                        }
+                    )
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn getters(input in geneve_any()) {
+            let bytes = input.to_bytes();
+            let slice = GeneveHeaderSlice::from_slice(&bytes).unwrap();
+            assert_eq!(0, slice.version());
+            assert_eq!(input.options.len(), slice.options_len());
+            assert_eq!(input.header_len(), slice.header_len());
+            assert_eq!(input.oam, slice.oam());
+            assert_eq!(input.critical, slice.critical());
+            assert_eq!(input.protocol_type, slice.protocol_type());
+            assert_eq!(input.vni, slice.vni());
+            assert_eq!(input.options.as_slice(), slice.options());
+            assert_eq!(input.options.iter(), slice.options_iter());
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn to_header(input in geneve_any()) {
+            let bytes = input.to_bytes();
+            let slice = GeneveHeaderSlice::from_slice(&bytes).unwrap();
+            assert_eq!(input, slice.to_header());
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn clone_eq_dbg(input in geneve_any()) {
+            let bytes = input.to_bytes();
+            let slice = GeneveHeaderSlice::from_slice(&bytes).unwrap();
+            assert_eq!(slice, slice.clone());
+            assert_eq!(
+                format!("GeneveHeaderSlice {{ slice: {:?} }}", slice.slice()),
+                format!("{:?}", slice)
+            );
+        }
+    }
+}
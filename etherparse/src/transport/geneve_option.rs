@@ -0,0 +1,92 @@
+/// Option (TLV) contained in a Geneve header (see [`crate::GeneveOptionsIterator`]
+/// to decode the options).
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct GeneveOption<'a> {
+    /// Namespace of the option type.
+    pub class: u16,
+    /// Type of the option (the highest bit indicates that the option is
+    /// critical).
+    pub option_type: u8,
+    /// Data of the option (length is always a multiple of 4).
+    pub data: &'a [u8],
+}
+
+impl<'a> GeneveOption<'a> {
+    /// Length of the option class, type & length fields in bytes/octets.
+    pub const HEADER_LEN: usize = 4;
+
+    /// Maximum length of the option data in bytes/octets.
+    pub const MAX_DATA_LEN: usize = 124;
+
+    /// Bit in the option type indicating that the option is critical.
+    pub const TYPE_CRITICAL: u8 = 0b1000_0000;
+
+    /// Returns true if the "critical" bit is set in the option type
+    /// (tunnel endpoints that do not understand the option must drop
+    /// the packet).
+    #[inline]
+    pub fn is_critical(&self) -> bool {
+        0 != self.option_type & GeneveOption::TYPE_CRITICAL
+    }
+
+    /// Length of the serialized option in bytes (including the class,
+    /// type & length fields).
+    #[inline]
+    pub fn option_len(&self) -> usize {
+        GeneveOption::HEADER_LEN + self.data.len()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::format;
+
+    #[test]
+    fn is_critical() {
+        for (option_type, expected) in [(0, false), (0x7f, false), (0x80, true), (0xff, true)] {
+            let option = GeneveOption {
+                class: 0,
+                option_type,
+                data: &[],
+            };
+            assert_eq!(expected, option.is_critical());
+        }
+    }
+
+    #[test]
+    fn option_len() {
+        assert_eq!(
+            4,
+            GeneveOption {
+                class: 0x0101,
+                option_type: 1,
+                data: &[]
+            }
+            .option_len()
+        );
+        assert_eq!(
+            12,
+            GeneveOption {
+                class: 0x0101,
+                option_type: 1,
+                data: &[1, 2, 3, 4, 5, 6, 7, 8]
+            }
+            .option_len()
+        );
+    }
+
+    #[test]
+    fn clone_eq_dbg() {
+        let option = GeneveOption {
+            class: 0x0102,
+            option_type: 3,
+            data: &[1, 2, 3, 4],
+        };
+        assert_eq!(option, option.clone());
+        assert_eq!(
+            "GeneveOption { class: 258, option_type: 3, data: [1, 2, 3, 4] }",
+            format!("{:?}", option)
+        );
+    }
+}
@@ -0,0 +1,227 @@
+use crate::*;
+
+/// Options present in a [`crate::GeneveHeader`].
+///
+/// Geneve options can only have a length that is a multiple of 4 bytes
+/// and a maximum length of 252 bytes (limited by the 6 bit "option
+/// length" field in the Geneve header).
+///
+/// # Examples
+///
+/// ```
+/// use etherparse::{GeneveOption, GeneveOptions};
+///
+/// {
+///     // slices can be "try_from" converted
+///     let some_data = [0x01, 0x02, 0x03, 0x01, 1, 2, 3, 4];
+///     let options: GeneveOptions = (&some_data[..]).try_into().unwrap();
+///     assert_eq!(options.as_slice(), &some_data);
+///
+///     // the contained options can be decoded via an iterator
+///     assert_eq!(
+///         Some(Ok(GeneveOption{ class: 0x0102, option_type: 3, data: &[1, 2, 3, 4] })),
+///         options.iter().next()
+///     );
+/// }
+/// {
+///     // only slices with a length that is multiple of 4 and a maximum value of 252
+///     // can be converted, otherwise you will get an error
+///     use etherparse::err::geneve::BadOptionsLen;
+///
+///     let result = GeneveOptions::try_from(&[1,2,3][..]);
+///     assert_eq!(result, Err(BadOptionsLen { bad_len: 3 }));
+/// }
+/// ```
+#[derive(Clone)]
+pub struct GeneveOptions {
+    len: u8,
+    buf: [u8; 252],
+}
+
+impl GeneveOptions {
+    /// Maximum length of the Geneve options in bytes.
+    pub const MAX_LEN: usize = 252;
+
+    /// Setup an empty options array.
+    #[inline]
+    pub fn new() -> GeneveOptions {
+        GeneveOptions {
+            len: 0,
+            buf: [0; 252],
+        }
+    }
+
+    /// Returns the slice containing the data of the options.
+    #[inline]
+    pub fn as_slice(&self) -> &[u8] {
+        &self.buf[..usize::from(self.len)]
+    }
+
+    /// Returns the length of the options in bytes.
+    #[inline]
+    pub fn len(&self) -> usize {
+        usize::from(self.len)
+    }
+
+    /// Returns if the length of the options is zero.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns an iterator that allows to iterate through the
+    /// options.
+    #[inline]
+    pub fn iter(&self) -> GeneveOptionsIterator<'_> {
+        GeneveOptionsIterator::from_slice(self.as_slice())
+    }
+}
+
+impl TryFrom<&[u8]> for GeneveOptions {
+    type Error = crate::err::geneve::BadOptionsLen;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        if value.len() <= GeneveOptions::MAX_LEN && 0 == value.len() & 0b11 {
+            let mut result = GeneveOptions::new();
+            result.len = value.len() as u8;
+            result.buf[..value.len()].copy_from_slice(value);
+            Ok(result)
+        } else {
+            Err(Self::Error {
+                bad_len: value.len(),
+            })
+        }
+    }
+}
+
+impl Default for GeneveOptions {
+    #[inline]
+    fn default() -> Self {
+        GeneveOptions::new()
+    }
+}
+
+impl core::fmt::Debug for GeneveOptions {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.as_slice().fmt(f)
+    }
+}
+
+impl PartialEq for GeneveOptions {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+impl Eq for GeneveOptions {}
+
+impl core::hash::Hash for GeneveOptions {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.as_slice().hash(state);
+    }
+}
+
+impl core::cmp::PartialOrd for GeneveOptions {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl core::cmp::Ord for GeneveOptions {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.as_slice().cmp(other.as_slice())
+    }
+}
+
+impl AsRef<[u8]> for GeneveOptions {
+    fn as_ref(&self) -> &[u8] {
+        self.as_slice()
+    }
+}
+
+impl core::ops::Deref for GeneveOptions {
+    type Target = [u8];
+
+    #[inline]
+    fn deref(&self) -> &[u8] {
+        self.as_slice()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{
+        cmp::Ordering,
+        collections::hash_map::DefaultHasher,
+        format,
+        hash::{Hash, Hasher},
+    };
+
+    #[test]
+    fn new_default() {
+        let actual = GeneveOptions::new();
+        assert_eq!(0, actual.len());
+        assert!(actual.is_empty());
+        assert_eq!(actual, GeneveOptions::default());
+    }
+
+    #[test]
+    fn try_from() {
+        let data: [u8; 260] = core::array::from_fn(|i| i as u8);
+
+        // ok cases
+        for len_div_4 in 0usize..=63 {
+            let actual = GeneveOptions::try_from(&data[..len_div_4 * 4]).unwrap();
+            assert_eq!(actual.as_slice(), &data[..len_div_4 * 4]);
+            assert_eq!(&actual[..], &data[..len_div_4 * 4]);
+            assert_eq!(actual.as_ref(), &data[..len_div_4 * 4]);
+            assert_eq!(actual.len(), len_div_4 * 4);
+            assert_eq!(actual.is_empty(), len_div_4 == 0);
+        }
+
+        // error cases
+        use crate::err::geneve::BadOptionsLen;
+        for len in 0usize..260 {
+            if (len % 4 != 0) || len > 252 {
+                assert_eq!(
+                    Err(BadOptionsLen { bad_len: len }),
+                    GeneveOptions::try_from(&data[..len])
+                )
+            }
+        }
+    }
+
+    #[test]
+    fn iter() {
+        let data = [0x01, 0x02, 0x03, 0x01, 1, 2, 3, 4];
+        let options = GeneveOptions::try_from(&data[..]).unwrap();
+        assert_eq!(GeneveOptionsIterator::from_slice(&data), options.iter());
+    }
+
+    #[test]
+    fn debug() {
+        let options = GeneveOptions::try_from(&[1, 2, 3, 4][..]).unwrap();
+        assert_eq!("[1, 2, 3, 4]", format!("{:?}", options));
+    }
+
+    #[test]
+    fn clone_eq_hash_ord() {
+        let a = GeneveOptions::try_from(&[1, 2, 3, 4][..]).unwrap();
+        let b = GeneveOptions::try_from(&[1, 2, 3, 5][..]).unwrap();
+        assert_eq!(a, a.clone());
+        assert_ne!(a, b);
+        let hash_a = {
+            let mut hasher = DefaultHasher::new();
+            a.hash(&mut hasher);
+            hasher.finish()
+        };
+        let hash_b = {
+            let mut hasher = DefaultHasher::new();
+            a.clone().hash(&mut hasher);
+            hasher.finish()
+        };
+        assert_eq!(hash_a, hash_b);
+        assert_eq!(Ordering::Less, a.cmp(&b));
+        assert_eq!(Some(Ordering::Greater), b.partial_cmp(&a));
+    }
+}
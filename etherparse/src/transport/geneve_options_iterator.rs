@@ -0,0 +1,186 @@
+use crate::*;
+
+/// Allows iterating over the options (TLVs) of a Geneve header.
+///
+/// In case an option is truncated an error is returned and the
+/// iteration stops afterwards.
+///
+/// # Example
+///
+/// ```
+/// use etherparse::{GeneveOption, GeneveOptionsIterator};
+///
+/// let options = [
+///     0x01, 0x02, 0x83, 0x01, 1, 2, 3, 4, // class 0x0102, type 0x83
+///     0x01, 0x03, 0x04, 0x00, // class 0x0103, type 0x04 (no data)
+/// ];
+/// let mut iter = GeneveOptionsIterator::from_slice(&options);
+/// assert_eq!(
+///     Some(Ok(GeneveOption{ class: 0x0102, option_type: 0x83, data: &[1, 2, 3, 4] })),
+///     iter.next()
+/// );
+/// assert_eq!(
+///     Some(Ok(GeneveOption{ class: 0x0103, option_type: 0x04, data: &[] })),
+///     iter.next()
+/// );
+/// assert_eq!(None, iter.next());
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Default)]
+pub struct GeneveOptionsIterator<'a> {
+    rest: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> GeneveOptionsIterator<'a> {
+    /// Creates an options iterator from a slice containing encoded
+    /// Geneve options.
+    #[inline]
+    pub fn from_slice(options: &'a [u8]) -> GeneveOptionsIterator<'a> {
+        GeneveOptionsIterator {
+            rest: options,
+            offset: 0,
+        }
+    }
+
+    /// Returns the non processed part of the options slice.
+    #[inline]
+    pub fn rest(&self) -> &'a [u8] {
+        self.rest
+    }
+}
+
+impl<'a> Iterator for GeneveOptionsIterator<'a> {
+    type Item = Result<GeneveOption<'a>, err::LenError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.rest.is_empty() {
+            return None;
+        }
+
+        // check that the class, type & length fields are present
+        if self.rest.len() < GeneveOption::HEADER_LEN {
+            let err = err::LenError {
+                required_len: GeneveOption::HEADER_LEN,
+                len: self.rest.len(),
+                len_source: err::LenSource::Slice,
+                layer: err::Layer::GeneveOption,
+                layer_start_offset: self.offset,
+            };
+            self.rest = &[];
+            return Some(Err(err));
+        }
+
+        // check that the data is present (length in multiples of 4 bytes)
+        let option_len = GeneveOption::HEADER_LEN + usize::from(self.rest[3] & 0b1_1111) * 4;
+        if self.rest.len() < option_len {
+            let err = err::LenError {
+                required_len: option_len,
+                len: self.rest.len(),
+                len_source: err::LenSource::Slice,
+                layer: err::Layer::GeneveOption,
+                layer_start_offset: self.offset,
+            };
+            self.rest = &[];
+            return Some(Err(err));
+        }
+
+        let result = GeneveOption {
+            class: u16::from_be_bytes([self.rest[0], self.rest[1]]),
+            option_type: self.rest[2],
+            data: &self.rest[GeneveOption::HEADER_LEN..option_len],
+        };
+        self.rest = &self.rest[option_len..];
+        self.offset += option_len;
+        Some(Ok(result))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::{format, vec::Vec};
+
+    #[test]
+    fn from_slice_rest() {
+        let data = [1, 2, 3];
+        let iter = GeneveOptionsIterator::from_slice(&data);
+        assert_eq!(&data, iter.rest());
+    }
+
+    #[test]
+    fn next() {
+        // empty
+        assert_eq!(None, GeneveOptionsIterator::from_slice(&[]).next());
+
+        // options
+        {
+            #[rustfmt::skip]
+            let data = [
+                0x01, 0x02, 0x03, 0x00, // no data
+                0xff, 0xff, 0x80, 0b1110_0010, 1, 2, 3, 4, 5, 6, 7, 8, // reserved bits set
+            ];
+            let actual: Vec<_> = GeneveOptionsIterator::from_slice(&data).collect();
+            assert_eq!(
+                &actual[..],
+                &[
+                    Ok(GeneveOption {
+                        class: 0x0102,
+                        option_type: 0x03,
+                        data: &[]
+                    }),
+                    Ok(GeneveOption {
+                        class: 0xffff,
+                        option_type: 0x80,
+                        data: &[1, 2, 3, 4, 5, 6, 7, 8]
+                    }),
+                ]
+            );
+        }
+
+        // option header too short
+        for len in 1..GeneveOption::HEADER_LEN {
+            let data = [0x01, 0x02, 0x03, 0x00, 0x01, 0x02, 0x03, 0x00];
+            let mut iter = GeneveOptionsIterator::from_slice(&data[..4 + len]);
+            assert!(iter.next().unwrap().is_ok());
+            assert_eq!(
+                Some(Err(err::LenError {
+                    required_len: GeneveOption::HEADER_LEN,
+                    len,
+                    len_source: err::LenSource::Slice,
+                    layer: err::Layer::GeneveOption,
+                    layer_start_offset: 4,
+                })),
+                iter.next()
+            );
+            assert_eq!(None, iter.next());
+        }
+
+        // option data too short
+        {
+            let data = [0x01, 0x02, 0x03, 0x02, 1, 2, 3, 4];
+            let mut iter = GeneveOptionsIterator::from_slice(&data);
+            assert_eq!(
+                Some(Err(err::LenError {
+                    required_len: 12,
+                    len: 8,
+                    len_source: err::LenSource::Slice,
+                    layer: err::Layer::GeneveOption,
+                    layer_start_offset: 0,
+                })),
+                iter.next()
+            );
+            assert_eq!(None, iter.next());
+        }
+    }
+
+    #[test]
+    fn default_clone_eq_dbg() {
+        let iter = GeneveOptionsIterator::default();
+        assert_eq!(iter.rest(), &[]);
+        assert_eq!(iter, iter.clone());
+        assert_eq!(
+            "GeneveOptionsIterator { rest: [], offset: 0 }",
+            format!("{:?}", iter)
+        );
+    }
+}
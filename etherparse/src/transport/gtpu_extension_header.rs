@@ -0,0 +1,101 @@
+use crate::*;
+
+/// Extension header of a GTP-U header (see
+/// [`crate::GtpuExtensionHeadersIterator`] to decode the extension
+/// headers).
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct GtpuExtensionHeader<'a> {
+    /// Type of the extension header.
+    pub header_type: GtpuExtensionHeaderType,
+    /// Content of the extension header (without the length & "next
+    /// extension header type" fields).
+    pub content: &'a [u8],
+}
+
+impl<'a> GtpuExtensionHeader<'a> {
+    /// Length of the length & "next extension header type" fields in
+    /// bytes/octets.
+    pub const FIELDS_LEN: usize = 2;
+
+    /// Maximum length of a serialized extension header in bytes/octets
+    /// (the length field contains the length in multiples of 4 bytes).
+    pub const MAX_LEN: usize = 255 * 4;
+
+    /// Length of the serialized extension header in bytes (including the
+    /// length & "next extension header type" fields).
+    #[inline]
+    pub fn header_len(&self) -> usize {
+        GtpuExtensionHeader::FIELDS_LEN + self.content.len()
+    }
+
+    /// Decodes the PDU type & QFI if the extension header is a "PDU
+    /// Session Container".
+    pub fn pdu_session_container(&self) -> Option<GtpuPduSessionContainer> {
+        if GtpuExtensionHeaderType::PDU_SESSION_CONTAINER == self.header_type {
+            GtpuPduSessionContainer::from_content(self.content)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::format;
+
+    #[test]
+    fn header_len() {
+        assert_eq!(
+            4,
+            GtpuExtensionHeader {
+                header_type: GtpuExtensionHeaderType::PDU_SESSION_CONTAINER,
+                content: &[1, 2],
+            }
+            .header_len()
+        );
+    }
+
+    #[test]
+    fn pdu_session_container() {
+        assert_eq!(
+            Some(GtpuPduSessionContainer::try_new(1, 9).unwrap()),
+            GtpuExtensionHeader {
+                header_type: GtpuExtensionHeaderType::PDU_SESSION_CONTAINER,
+                content: &[0x10, 0x09],
+            }
+            .pdu_session_container()
+        );
+        // content too short
+        assert_eq!(
+            None,
+            GtpuExtensionHeader {
+                header_type: GtpuExtensionHeaderType::PDU_SESSION_CONTAINER,
+                content: &[0x10],
+            }
+            .pdu_session_container()
+        );
+        // other type
+        assert_eq!(
+            None,
+            GtpuExtensionHeader {
+                header_type: GtpuExtensionHeaderType::NR_RAN_CONTAINER,
+                content: &[0x10, 0x09],
+            }
+            .pdu_session_container()
+        );
+    }
+
+    #[test]
+    fn clone_eq_dbg() {
+        let value = GtpuExtensionHeader {
+            header_type: GtpuExtensionHeaderType(0x12),
+            content: &[1, 2],
+        };
+        assert_eq!(value, value.clone());
+        assert_eq!(
+            "GtpuExtensionHeader { header_type: 0x12, content: [1, 2] }",
+            format!("{:?}", value)
+        );
+    }
+}
@@ -0,0 +1,144 @@
+/// Type of a GTP-U extension header ("next extension header type"
+/// field, 3GPP TS 29.281).
+///
+/// You can access the underlying `u8` value by using `.0` and any `u8`
+/// can be converted to a `GtpuExtensionHeaderType`:
+///
+/// ```
+/// use etherparse::GtpuExtensionHeaderType;
+///
+/// assert_eq!(GtpuExtensionHeaderType::PDU_SESSION_CONTAINER.0, 0x85);
+/// assert_eq!(
+///     GtpuExtensionHeaderType::PDU_SESSION_CONTAINER,
+///     GtpuExtensionHeaderType(0x85)
+/// );
+///
+/// // convert to GtpuExtensionHeaderType using the from & into trait
+/// let header_type: GtpuExtensionHeaderType = 0x40u8.into();
+/// assert_eq!(GtpuExtensionHeaderType::UDP_PORT, header_type);
+///
+/// // convert to u8 using the from & into trait
+/// let num: u8 = GtpuExtensionHeaderType::NO_MORE_EXTENSION_HEADERS.into();
+/// assert_eq!(0, num);
+/// ```
+#[derive(Default, PartialEq, Eq, Clone, Copy, Hash, Ord, PartialOrd)]
+pub struct GtpuExtensionHeaderType(pub u8);
+
+impl GtpuExtensionHeaderType {
+    /// No more extension headers follow.
+    pub const NO_MORE_EXTENSION_HEADERS: GtpuExtensionHeaderType = Self(0x00);
+    /// Service Class Indicator.
+    pub const SERVICE_CLASS_INDICATOR: GtpuExtensionHeaderType = Self(0x20);
+    /// UDP Port (used in Error Indication messages).
+    pub const UDP_PORT: GtpuExtensionHeaderType = Self(0x40);
+    /// RAN Container.
+    pub const RAN_CONTAINER: GtpuExtensionHeaderType = Self(0x81);
+    /// Long PDCP PDU Number.
+    pub const LONG_PDCP_PDU_NUMBER: GtpuExtensionHeaderType = Self(0x82);
+    /// Xw RAN Container.
+    pub const XW_RAN_CONTAINER: GtpuExtensionHeaderType = Self(0x83);
+    /// NR RAN Container.
+    pub const NR_RAN_CONTAINER: GtpuExtensionHeaderType = Self(0x84);
+    /// PDU Session Container (contains the QoS flow identifier, see
+    /// [`crate::GtpuPduSessionContainer`]).
+    pub const PDU_SESSION_CONTAINER: GtpuExtensionHeaderType = Self(0x85);
+    /// PDCP PDU Number.
+    pub const PDCP_PDU_NUMBER: GtpuExtensionHeaderType = Self(0xc0);
+}
+
+impl From<u8> for GtpuExtensionHeaderType {
+    #[inline]
+    fn from(val: u8) -> Self {
+        GtpuExtensionHeaderType(val)
+    }
+}
+
+impl From<GtpuExtensionHeaderType> for u8 {
+    #[inline]
+    fn from(val: GtpuExtensionHeaderType) -> Self {
+        val.0
+    }
+}
+
+impl core::fmt::Debug for GtpuExtensionHeaderType {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match *self {
+            Self::NO_MORE_EXTENSION_HEADERS => {
+                write!(f, "{:#04x} (No More Extension Headers)", self.0)
+            }
+            Self::SERVICE_CLASS_INDICATOR => write!(f, "{:#04x} (Service Class Indicator)", self.0),
+            Self::UDP_PORT => write!(f, "{:#04x} (UDP Port)", self.0),
+            Self::RAN_CONTAINER => write!(f, "{:#04x} (RAN Container)", self.0),
+            Self::LONG_PDCP_PDU_NUMBER => write!(f, "{:#04x} (Long PDCP PDU Number)", self.0),
+            Self::XW_RAN_CONTAINER => write!(f, "{:#04x} (Xw RAN Container)", self.0),
+            Self::NR_RAN_CONTAINER => write!(f, "{:#04x} (NR RAN Container)", self.0),
+            Self::PDU_SESSION_CONTAINER => write!(f, "{:#04x} (PDU Session Container)", self.0),
+            Self::PDCP_PDU_NUMBER => write!(f, "{:#04x} (PDCP PDU Number)", self.0),
+            _ => write!(f, "{:#04x}", self.0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::format;
+
+    #[test]
+    fn to_u8() {
+        assert_eq!(
+            0,
+            u8::from(GtpuExtensionHeaderType::NO_MORE_EXTENSION_HEADERS)
+        );
+        assert_eq!(
+            0x85,
+            u8::from(GtpuExtensionHeaderType::PDU_SESSION_CONTAINER)
+        );
+    }
+
+    #[test]
+    fn from_u8() {
+        assert_eq!(
+            GtpuExtensionHeaderType::from(0xc0),
+            GtpuExtensionHeaderType::PDCP_PDU_NUMBER
+        );
+        assert_eq!(
+            GtpuExtensionHeaderType::from(0x12),
+            GtpuExtensionHeaderType(0x12)
+        );
+    }
+
+    #[test]
+    fn dbg() {
+        use GtpuExtensionHeaderType as T;
+        let pairs = &[
+            (
+                T::NO_MORE_EXTENSION_HEADERS,
+                "0x00 (No More Extension Headers)",
+            ),
+            (T::SERVICE_CLASS_INDICATOR, "0x20 (Service Class Indicator)"),
+            (T::UDP_PORT, "0x40 (UDP Port)"),
+            (T::RAN_CONTAINER, "0x81 (RAN Container)"),
+            (T::LONG_PDCP_PDU_NUMBER, "0x82 (Long PDCP PDU Number)"),
+            (T::XW_RAN_CONTAINER, "0x83 (Xw RAN Container)"),
+            (T::NR_RAN_CONTAINER, "0x84 (NR RAN Container)"),
+            (T::PDU_SESSION_CONTAINER, "0x85 (PDU Session Container)"),
+            (T::PDCP_PDU_NUMBER, "0xc0 (PDCP PDU Number)"),
+            (T(0x12), "0x12"),
+        ];
+
+        for (input, expected) in pairs {
+            assert_eq!(expected, &format!("{:?}", input));
+        }
+    }
+
+    #[test]
+    fn clone_eq_default() {
+        let value = GtpuExtensionHeaderType::PDU_SESSION_CONTAINER;
+        assert_eq!(value, value.clone());
+        assert_eq!(
+            GtpuExtensionHeaderType::NO_MORE_EXTENSION_HEADERS,
+            GtpuExtensionHeaderType::default()
+        );
+    }
+}
@@ -0,0 +1,413 @@
+use crate::{err::gtpu::ExtHeadersError, *};
+
+/// Encoded extension headers of a [`crate::GtpuHeader`].
+///
+/// The extension headers are stored in their serialized form (the type of
+/// the first extension header is stored separately as it is part of the
+/// optional fields of the GTP-U header). Extension headers with a total
+/// length of up to [`GtpuExtensionHeaders::MAX_LEN`] can be stored.
+///
+/// # Examples
+///
+/// ```
+/// use etherparse::{
+///     GtpuExtensionHeader, GtpuExtensionHeaders, GtpuExtensionHeaderType,
+///     GtpuPduSessionContainer
+/// };
+///
+/// // PDU session container with a QFI
+/// let qfi = GtpuPduSessionContainer::try_new(GtpuPduSessionContainer::PDU_TYPE_UL, 9).unwrap();
+/// let exts = GtpuExtensionHeaders::from_pdu_session_container(qfi);
+/// assert_eq!(Some(qfi), exts.pdu_session_container());
+///
+/// // any extension headers
+/// let exts = GtpuExtensionHeaders::try_from_headers(&[
+///     GtpuExtensionHeader {
+///         header_type: GtpuExtensionHeaderType::PDU_SESSION_CONTAINER,
+///         content: &qfi.to_content(),
+///     },
+///     GtpuExtensionHeader {
+///         header_type: GtpuExtensionHeaderType::NR_RAN_CONTAINER,
+///         content: &[1, 2, 3, 4, 5, 6],
+///     },
+/// ]).unwrap();
+/// assert_eq!(2, exts.iter().count());
+///
+/// // the content length plus 2 must be a multiple of 4
+/// use etherparse::err::gtpu::ExtHeadersError;
+/// assert_eq!(
+///     GtpuExtensionHeaders::try_from_headers(&[GtpuExtensionHeader {
+///         header_type: GtpuExtensionHeaderType::NR_RAN_CONTAINER,
+///         content: &[1, 2, 3],
+///     }]),
+///     Err(ExtHeadersError::BadContentLen { content_len: 3 })
+/// );
+/// ```
+#[derive(Clone)]
+pub struct GtpuExtensionHeaders {
+    first_type: GtpuExtensionHeaderType,
+    len: u16,
+    buf: [u8; GtpuExtensionHeaders::MAX_LEN],
+}
+
+impl GtpuExtensionHeaders {
+    /// Maximum length of the encoded extension headers in bytes (enough
+    /// to contain one extension header of maximum length).
+    pub const MAX_LEN: usize = GtpuExtensionHeader::MAX_LEN;
+
+    /// Setup empty extension headers.
+    #[inline]
+    pub fn new() -> GtpuExtensionHeaders {
+        GtpuExtensionHeaders {
+            first_type: GtpuExtensionHeaderType::NO_MORE_EXTENSION_HEADERS,
+            len: 0,
+            buf: [0; GtpuExtensionHeaders::MAX_LEN],
+        }
+    }
+
+    /// Encodes the given extension headers (in the given order).
+    pub fn try_from_headers(
+        headers: &[GtpuExtensionHeader<'_>],
+    ) -> Result<GtpuExtensionHeaders, ExtHeadersError> {
+        use ExtHeadersError::*;
+
+        // validate the headers
+        let mut required_len = 0;
+        for header in headers {
+            if GtpuExtensionHeaderType::NO_MORE_EXTENSION_HEADERS == header.header_type {
+                return Err(ZeroHeaderType);
+            }
+            let header_len = header.header_len();
+            if header_len > GtpuExtensionHeader::MAX_LEN || 0 != header_len & 0b11 {
+                return Err(BadContentLen {
+                    content_len: header.content.len(),
+                });
+            }
+            required_len += header_len;
+        }
+        if required_len > GtpuExtensionHeaders::MAX_LEN {
+            return Err(ExceedsMaxLen { required_len });
+        }
+
+        // serialize
+        let mut result = GtpuExtensionHeaders::new();
+        if let Some(first) = headers.first() {
+            result.first_type = first.header_type;
+        }
+        let mut offset = 0;
+        for (index, header) in headers.iter().enumerate() {
+            let header_len = header.header_len();
+            let next_type = headers
+                .get(index + 1)
+                .map(|next| next.header_type)
+                .unwrap_or(GtpuExtensionHeaderType::NO_MORE_EXTENSION_HEADERS);
+            result.buf[offset] = (header_len / 4) as u8;
+            result.buf[offset + 1..offset + header_len - 1].copy_from_slice(header.content);
+            result.buf[offset + header_len - 1] = next_type.0;
+            offset += header_len;
+        }
+        result.len = offset as u16;
+        Ok(result)
+    }
+
+    /// Creates extension headers only containing a "PDU Session Container".
+    pub fn from_pdu_session_container(container: GtpuPduSessionContainer) -> GtpuExtensionHeaders {
+        let content = container.to_content();
+        let mut result = GtpuExtensionHeaders::new();
+        result.first_type = GtpuExtensionHeaderType::PDU_SESSION_CONTAINER;
+        result.buf[..4].copy_from_slice(&[
+            1,
+            content[0],
+            content[1],
+            GtpuExtensionHeaderType::NO_MORE_EXTENSION_HEADERS.0,
+        ]);
+        result.len = 4;
+        result
+    }
+
+    /// Creates extension headers from already encoded (& validated)
+    /// extension headers (`None` if the encoded extension headers exceed
+    /// [`GtpuExtensionHeaders::MAX_LEN`]).
+    pub(crate) fn from_encoded(
+        first_type: GtpuExtensionHeaderType,
+        encoded: &[u8],
+    ) -> Option<GtpuExtensionHeaders> {
+        if encoded.len() > GtpuExtensionHeaders::MAX_LEN {
+            None
+        } else {
+            let mut result = GtpuExtensionHeaders::new();
+            result.first_type = first_type;
+            result.buf[..encoded.len()].copy_from_slice(encoded);
+            result.len = encoded.len() as u16;
+            Some(result)
+        }
+    }
+
+    /// Type of the first extension header
+    /// ([`GtpuExtensionHeaderType::NO_MORE_EXTENSION_HEADERS`] if no
+    /// extension headers are present).
+    #[inline]
+    pub fn first_type(&self) -> GtpuExtensionHeaderType {
+        self.first_type
+    }
+
+    /// Returns the slice containing the encoded extension headers.
+    #[inline]
+    pub fn as_slice(&self) -> &[u8] {
+        &self.buf[..usize::from(self.len)]
+    }
+
+    /// Returns the length of the encoded extension headers in bytes.
+    #[inline]
+    pub fn len(&self) -> usize {
+        usize::from(self.len)
+    }
+
+    /// Returns true if no extension headers are present.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns an iterator over the extension headers.
+    #[inline]
+    pub fn iter(&self) -> GtpuExtensionHeadersIterator<'_> {
+        GtpuExtensionHeadersIterator::new(self.first_type, self.as_slice())
+    }
+
+    /// Returns the PDU type & QFI of the first "PDU Session Container"
+    /// extension header (if present).
+    pub fn pdu_session_container(&self) -> Option<GtpuPduSessionContainer> {
+        self.iter()
+            .find_map(|header| header.pdu_session_container())
+    }
+}
+
+impl Default for GtpuExtensionHeaders {
+    #[inline]
+    fn default() -> Self {
+        GtpuExtensionHeaders::new()
+    }
+}
+
+impl core::fmt::Debug for GtpuExtensionHeaders {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("GtpuExtensionHeaders")
+            .field("first_type", &self.first_type)
+            .field("data", &self.as_slice())
+            .finish()
+    }
+}
+
+impl PartialEq for GtpuExtensionHeaders {
+    fn eq(&self, other: &Self) -> bool {
+        self.first_type == other.first_type && self.as_slice() == other.as_slice()
+    }
+}
+impl Eq for GtpuExtensionHeaders {}
+
+impl core::hash::Hash for GtpuExtensionHeaders {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.first_type.hash(state);
+        self.as_slice().hash(state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{
+        collections::hash_map::DefaultHasher,
+        format,
+        hash::{Hash, Hasher},
+    };
+
+    #[test]
+    fn new_default() {
+        let actual = GtpuExtensionHeaders::new();
+        assert_eq!(0, actual.len());
+        assert!(actual.is_empty());
+        assert_eq!(
+            GtpuExtensionHeaderType::NO_MORE_EXTENSION_HEADERS,
+            actual.first_type()
+        );
+        assert_eq!(None, actual.iter().next());
+        assert_eq!(actual, GtpuExtensionHeaders::default());
+    }
+
+    #[test]
+    fn try_from_headers() {
+        use ExtHeadersError::*;
+        use GtpuExtensionHeaderType as T;
+
+        let content: [u8; 1018] = core::array::from_fn(|i| i as u8);
+
+        // no headers
+        assert_eq!(
+            GtpuExtensionHeaders::new(),
+            GtpuExtensionHeaders::try_from_headers(&[]).unwrap()
+        );
+
+        // multiple headers
+        {
+            let actual = GtpuExtensionHeaders::try_from_headers(&[
+                GtpuExtensionHeader {
+                    header_type: T::PDU_SESSION_CONTAINER,
+                    content: &[0x10, 0x09],
+                },
+                GtpuExtensionHeader {
+                    header_type: T::NR_RAN_CONTAINER,
+                    content: &content[..6],
+                },
+            ])
+            .unwrap();
+            assert_eq!(T::PDU_SESSION_CONTAINER, actual.first_type());
+            assert_eq!(
+                actual.as_slice(),
+                &[1, 0x10, 0x09, 0x84, 2, 0, 1, 2, 3, 4, 5, 0]
+            );
+            assert_eq!(12, actual.len());
+            assert!(!actual.is_empty());
+        }
+
+        // maximum length
+        {
+            let actual = GtpuExtensionHeaders::try_from_headers(&[GtpuExtensionHeader {
+                header_type: T::NR_RAN_CONTAINER,
+                content: &content,
+            }])
+            .unwrap();
+            assert_eq!(GtpuExtensionHeaders::MAX_LEN, actual.len());
+            assert_eq!(255, actual.as_slice()[0]);
+            assert_eq!(&content[..], &actual.as_slice()[1..1019]);
+            assert_eq!(0, actual.as_slice()[1019]);
+        }
+
+        // zero type
+        assert_eq!(
+            Err(ZeroHeaderType),
+            GtpuExtensionHeaders::try_from_headers(&[GtpuExtensionHeader {
+                header_type: T::NO_MORE_EXTENSION_HEADERS,
+                content: &content[..2],
+            }])
+        );
+
+        // bad content length
+        for content_len in [0, 1, 3, 4, 5, 1019, 1022] {
+            let content = [0u8; 1022];
+            assert_eq!(
+                Err(BadContentLen { content_len }),
+                GtpuExtensionHeaders::try_from_headers(&[GtpuExtensionHeader {
+                    header_type: T::NR_RAN_CONTAINER,
+                    content: &content[..content_len],
+                }])
+            );
+        }
+
+        // exceeding the maximum length
+        assert_eq!(
+            Err(ExceedsMaxLen { required_len: 1024 }),
+            GtpuExtensionHeaders::try_from_headers(&[
+                GtpuExtensionHeader {
+                    header_type: T::NR_RAN_CONTAINER,
+                    content: &content,
+                },
+                GtpuExtensionHeader {
+                    header_type: T::PDU_SESSION_CONTAINER,
+                    content: &[0x10, 0x09],
+                },
+            ])
+        );
+    }
+
+    #[test]
+    fn from_pdu_session_container() {
+        let container = GtpuPduSessionContainer::try_new(1, 9).unwrap();
+        let actual = GtpuExtensionHeaders::from_pdu_session_container(container);
+        assert_eq!(
+            actual,
+            GtpuExtensionHeaders::try_from_headers(&[GtpuExtensionHeader {
+                header_type: GtpuExtensionHeaderType::PDU_SESSION_CONTAINER,
+                content: &container.to_content(),
+            }])
+            .unwrap()
+        );
+        assert_eq!(Some(container), actual.pdu_session_container());
+    }
+
+    #[test]
+    fn from_encoded() {
+        let encoded = [0u8; GtpuExtensionHeaders::MAX_LEN + 1];
+        let actual = GtpuExtensionHeaders::from_encoded(
+            GtpuExtensionHeaderType::NR_RAN_CONTAINER,
+            &encoded[..GtpuExtensionHeaders::MAX_LEN],
+        )
+        .unwrap();
+        assert_eq!(
+            GtpuExtensionHeaderType::NR_RAN_CONTAINER,
+            actual.first_type()
+        );
+        assert_eq!(&encoded[..GtpuExtensionHeaders::MAX_LEN], actual.as_slice());
+        assert_eq!(
+            None,
+            GtpuExtensionHeaders::from_encoded(GtpuExtensionHeaderType::NR_RAN_CONTAINER, &encoded)
+        );
+    }
+
+    #[test]
+    fn pdu_session_container() {
+        use GtpuExtensionHeaderType as T;
+        let container = GtpuPduSessionContainer::try_new(0, 5).unwrap();
+
+        // not present
+        assert_eq!(None, GtpuExtensionHeaders::new().pdu_session_container());
+
+        // present after an other extension header
+        let actual = GtpuExtensionHeaders::try_from_headers(&[
+            GtpuExtensionHeader {
+                header_type: T::NR_RAN_CONTAINER,
+                content: &[1, 2],
+            },
+            GtpuExtensionHeader {
+                header_type: T::PDU_SESSION_CONTAINER,
+                content: &container.to_content(),
+            },
+        ])
+        .unwrap();
+        assert_eq!(Some(container), actual.pdu_session_container());
+    }
+
+    #[test]
+    fn debug() {
+        let container = GtpuPduSessionContainer::try_new(1, 9).unwrap();
+        let actual = GtpuExtensionHeaders::from_pdu_session_container(container);
+        assert_eq!(
+            "GtpuExtensionHeaders { first_type: 0x85 (PDU Session Container), data: [1, 16, 9, 0] }",
+            format!("{:?}", actual)
+        );
+    }
+
+    #[test]
+    fn clone_eq_hash() {
+        let a = GtpuExtensionHeaders::from_pdu_session_container(
+            GtpuPduSessionContainer::try_new(1, 9).unwrap(),
+        );
+        let b = GtpuExtensionHeaders::from_pdu_session_container(
+            GtpuPduSessionContainer::try_new(1, 8).unwrap(),
+        );
+        assert_eq!(a, a.clone());
+        assert_ne!(a, b);
+        assert_ne!(a, GtpuExtensionHeaders::new());
+        let hash_a = {
+            let mut hasher = DefaultHasher::new();
+            a.hash(&mut hasher);
+            hasher.finish()
+        };
+        let hash_b = {
+            let mut hasher = DefaultHasher::new();
+            a.clone().hash(&mut hasher);
+            hasher.finish()
+        };
+        assert_eq!(hash_a, hash_b);
+    }
+}
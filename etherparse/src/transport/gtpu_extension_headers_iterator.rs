@@ -0,0 +1,173 @@
+use crate::*;
+
+/// Allows iterating over the extension headers of a GTP-U header.
+///
+/// The iteration stops at the first extension header that indicates that
+/// no more extension headers follow. In case the data is truncated or
+/// contains an extension header with a length of zero the iteration stops
+/// early (this can not happen for [`GtpuHeaderSlice::extension_headers`]
+/// & [`GtpuExtensionHeaders::iter`] as the extension headers are
+/// validated during their construction).
+///
+/// # Example
+///
+/// ```
+/// use etherparse::{GtpuExtensionHeader, GtpuExtensionHeaderType, GtpuExtensionHeadersIterator};
+///
+/// let data = [
+///     1, 0x10, 0x09, 0x00, // PDU session container (no more extension headers)
+/// ];
+/// let mut iter = GtpuExtensionHeadersIterator::new(
+///     GtpuExtensionHeaderType::PDU_SESSION_CONTAINER,
+///     &data
+/// );
+/// assert_eq!(
+///     Some(GtpuExtensionHeader{
+///         header_type: GtpuExtensionHeaderType::PDU_SESSION_CONTAINER,
+///         content: &[0x10, 0x09],
+///     }),
+///     iter.next()
+/// );
+/// assert_eq!(None, iter.next());
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Default)]
+pub struct GtpuExtensionHeadersIterator<'a> {
+    next_type: GtpuExtensionHeaderType,
+    rest: &'a [u8],
+}
+
+impl<'a> GtpuExtensionHeadersIterator<'a> {
+    /// Creates an iterator over encoded extension headers starting with
+    /// an extension header of the type `first_type`.
+    #[inline]
+    pub fn new(
+        first_type: GtpuExtensionHeaderType,
+        data: &'a [u8],
+    ) -> GtpuExtensionHeadersIterator<'a> {
+        GtpuExtensionHeadersIterator {
+            next_type: first_type,
+            rest: data,
+        }
+    }
+
+    /// Returns the type of the next extension header.
+    #[inline]
+    pub fn next_type(&self) -> GtpuExtensionHeaderType {
+        self.next_type
+    }
+
+    /// Returns the non processed part of the extension headers slice.
+    #[inline]
+    pub fn rest(&self) -> &'a [u8] {
+        self.rest
+    }
+}
+
+impl<'a> Iterator for GtpuExtensionHeadersIterator<'a> {
+    type Item = GtpuExtensionHeader<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if GtpuExtensionHeaderType::NO_MORE_EXTENSION_HEADERS == self.next_type
+            || self.rest.is_empty()
+        {
+            return None;
+        }
+
+        // length in multiples of 4 bytes
+        let len = usize::from(self.rest[0]) * 4;
+        if 0 == len || self.rest.len() < len {
+            self.rest = &[];
+            return None;
+        }
+
+        let result = GtpuExtensionHeader {
+            header_type: self.next_type,
+            content: &self.rest[1..len - 1],
+        };
+        self.next_type = GtpuExtensionHeaderType(self.rest[len - 1]);
+        self.rest = &self.rest[len..];
+        Some(result)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::{format, vec::Vec};
+
+    #[test]
+    fn new_next_type_rest() {
+        let data = [1, 2, 3];
+        let iter = GtpuExtensionHeadersIterator::new(GtpuExtensionHeaderType(0x12), &data);
+        assert_eq!(GtpuExtensionHeaderType(0x12), iter.next_type());
+        assert_eq!(&data, iter.rest());
+    }
+
+    #[test]
+    fn next() {
+        use GtpuExtensionHeaderType as T;
+
+        // no extension headers
+        assert_eq!(
+            None,
+            GtpuExtensionHeadersIterator::new(T::NO_MORE_EXTENSION_HEADERS, &[1, 2, 3, 0]).next()
+        );
+        assert_eq!(
+            None,
+            GtpuExtensionHeadersIterator::new(T::PDU_SESSION_CONTAINER, &[]).next()
+        );
+
+        // multiple extension headers
+        {
+            #[rustfmt::skip]
+            let data = [
+                1, 0x10, 0x09, 0x84, // PDU session container
+                2, 1, 2, 3, 4, 5, 6, 0x00, // NR RAN container
+                1, 2, 3, 4, // ignored as after the end
+            ];
+            let mut iter = GtpuExtensionHeadersIterator::new(T::PDU_SESSION_CONTAINER, &data);
+            let actual: Vec<_> = iter.by_ref().collect();
+            assert_eq!(
+                &actual[..],
+                &[
+                    GtpuExtensionHeader {
+                        header_type: T::PDU_SESSION_CONTAINER,
+                        content: &[0x10, 0x09],
+                    },
+                    GtpuExtensionHeader {
+                        header_type: T::NR_RAN_CONTAINER,
+                        content: &[1, 2, 3, 4, 5, 6],
+                    },
+                ]
+            );
+            assert_eq!(T::NO_MORE_EXTENSION_HEADERS, iter.next_type());
+            assert_eq!(&[1, 2, 3, 4], iter.rest());
+        }
+
+        // zero length
+        {
+            let mut iter = GtpuExtensionHeadersIterator::new(T::PDU_SESSION_CONTAINER, &[0, 1]);
+            assert_eq!(None, iter.next());
+            assert!(iter.rest().is_empty());
+        }
+
+        // truncated
+        {
+            let mut iter =
+                GtpuExtensionHeadersIterator::new(T::PDU_SESSION_CONTAINER, &[1, 0x10, 0x09]);
+            assert_eq!(None, iter.next());
+            assert!(iter.rest().is_empty());
+        }
+    }
+
+    #[test]
+    fn default_clone_eq_dbg() {
+        let iter = GtpuExtensionHeadersIterator::default();
+        assert_eq!(iter.rest(), &[]);
+        assert_eq!(iter, iter.clone());
+        assert_eq!(
+            "GtpuExtensionHeadersIterator { next_type: 0x00 (No More Extension Headers), rest: [] }",
+            format!("{:?}", iter)
+        );
+    }
+}
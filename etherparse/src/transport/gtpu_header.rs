@@ -0,0 +1,402 @@
+use crate::{err::Layer, err::SliceWriteSpaceError, err::ValueTooBigError, *};
+use arrayvec::ArrayVec;
+
+/// GTP-U header (GPRS Tunnelling Protocol User Plane, 3GPP TS 29.281).
+///
+/// GTP-U headers are transported in UDP datagrams with the port
+/// [`GtpuHeader::UDP_PORT`] (2152) and are used to tunnel user IP packets
+/// in mobile networks (e.g. on the N3 & N9 interfaces of a 5G core).
+///
+/// The optional fields (sequence number, N-PDU number & next extension
+/// header type) are written if a sequence number, a N-PDU number or
+/// extension headers are present.
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Default)]
+pub struct GtpuHeader {
+    /// Type of the message ([`GtpuMessageType::G_PDU`] for user data).
+    pub message_type: GtpuMessageType,
+    /// Length of the data after the mandatory 8 bytes of the header in
+    /// bytes (including the optional fields & extension headers). Set
+    /// automatically by the [`crate::PacketBuilder`].
+    pub length: u16,
+    /// Tunnel endpoint identifier.
+    pub teid: u32,
+    /// Sequence number (written if `Some`, sets the "S" flag).
+    pub sequence_number: Option<u16>,
+    /// N-PDU number (written if `Some`, sets the "PN" flag).
+    pub n_pdu_number: Option<u8>,
+    /// Extension headers (written if not empty, sets the "E" flag).
+    pub extension_headers: GtpuExtensionHeaders,
+}
+
+impl GtpuHeader {
+    /// Minimum length of a GTP-U header in bytes/octets (no optional
+    /// fields).
+    pub const MIN_LEN: usize = 8;
+
+    /// Length of the optional fields (sequence number, N-PDU number &
+    /// next extension header type) in bytes/octets.
+    pub const OPTIONAL_FIELDS_LEN: usize = 4;
+
+    /// Maximum length of a GTP-U header in bytes/octets that can be
+    /// represented by [`GtpuHeader`].
+    pub const MAX_LEN: usize =
+        GtpuHeader::MIN_LEN + GtpuHeader::OPTIONAL_FIELDS_LEN + GtpuExtensionHeaders::MAX_LEN;
+
+    /// UDP port assigned by IANA for GTP-U.
+    pub const UDP_PORT: u16 = 2152;
+
+    /// Version number of GTP-U headers (GTPv1).
+    pub const VERSION: u8 = 1;
+
+    /// Flag indicating that the header is a GTP header (and not a GTP'
+    /// header, "PT" flag).
+    pub const FLAG_PROTOCOL_TYPE: u8 = 0b0001_0000;
+
+    /// Flag indicating that extension headers are present ("E" flag).
+    pub const FLAG_EXTENSION_HEADER: u8 = 0b0000_0100;
+
+    /// Flag indicating that the sequence number is present ("S" flag).
+    pub const FLAG_SEQUENCE_NUMBER: u8 = 0b0000_0010;
+
+    /// Flag indicating that the N-PDU number is present ("PN" flag).
+    pub const FLAG_N_PDU_NUMBER: u8 = 0b0000_0001;
+
+    /// Creates a G-PDU GTP-U header without optional fields (length set
+    /// to 0).
+    #[inline]
+    pub fn new(teid: u32) -> GtpuHeader {
+        GtpuHeader {
+            message_type: GtpuMessageType::G_PDU,
+            length: 0,
+            teid,
+            sequence_number: None,
+            n_pdu_number: None,
+            extension_headers: GtpuExtensionHeaders::new(),
+        }
+    }
+
+    /// Read a [`GtpuHeader`] from a slice and return the header & unused parts of the slice.
+    #[inline]
+    pub fn from_slice(slice: &[u8]) -> Result<(GtpuHeader, &[u8]), err::gtpu::HeaderSliceError> {
+        let header = GtpuHeaderSlice::from_slice(slice)?;
+        Ok((
+            header
+                .to_header()
+                .map_err(err::gtpu::HeaderSliceError::Content)?,
+            &slice[header.slice().len()..],
+        ))
+    }
+
+    /// Returns true if the optional fields (sequence number, N-PDU number
+    /// & next extension header type) are written.
+    #[inline]
+    pub fn has_optional_fields(&self) -> bool {
+        self.sequence_number.is_some()
+            || self.n_pdu_number.is_some()
+            || !self.extension_headers.is_empty()
+    }
+
+    /// Sets the length field based on the length of the payload (T-PDU).
+    pub fn set_payload_len(&mut self, payload_len: usize) -> Result<(), ValueTooBigError<usize>> {
+        let max_allowed = usize::from(u16::MAX) - (self.header_len() - GtpuHeader::MIN_LEN);
+        if payload_len > max_allowed {
+            Err(ValueTooBigError {
+                actual: payload_len,
+                max_allowed,
+                value_type: err::ValueType::GtpuPayloadLength,
+            })
+        } else {
+            self.length = (self.header_len() - GtpuHeader::MIN_LEN + payload_len) as u16;
+            Ok(())
+        }
+    }
+
+    /// Serialize the header to a given slice. Returns the unused part of the slice.
+    pub fn write_to_slice<'a>(
+        &self,
+        slice: &'a mut [u8],
+    ) -> Result<&'a mut [u8], SliceWriteSpaceError> {
+        let len = self.header_len();
+        // length check
+        if slice.len() < len {
+            Err(SliceWriteSpaceError {
+                required_len: len,
+                len: slice.len(),
+                layer: Layer::GtpuHeader,
+                layer_start_offset: 0,
+            })
+        } else {
+            slice[..len].copy_from_slice(&self.to_bytes());
+            Ok(&mut slice[len..])
+        }
+    }
+
+    /// Writes a given GTP-U header to the current position of the write argument.
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn write<T: std::io::Write + Sized>(&self, writer: &mut T) -> Result<(), std::io::Error> {
+        writer.write_all(&self.to_bytes())
+    }
+
+    /// Length of the serialized header in bytes.
+    #[inline]
+    pub fn header_len(&self) -> usize {
+        if self.has_optional_fields() {
+            GtpuHeader::MIN_LEN + GtpuHeader::OPTIONAL_FIELDS_LEN + self.extension_headers.len()
+        } else {
+            GtpuHeader::MIN_LEN
+        }
+    }
+
+    /// Returns the serialized form of the header.
+    pub fn to_bytes(&self) -> ArrayVec<u8, { GtpuHeader::MAX_LEN }> {
+        let mut flags = (GtpuHeader::VERSION << 5) | GtpuHeader::FLAG_PROTOCOL_TYPE;
+        if !self.extension_headers.is_empty() {
+            flags |= GtpuHeader::FLAG_EXTENSION_HEADER;
+        }
+        if self.sequence_number.is_some() {
+            flags |= GtpuHeader::FLAG_SEQUENCE_NUMBER;
+        }
+        if self.n_pdu_number.is_some() {
+            flags |= GtpuHeader::FLAG_N_PDU_NUMBER;
+        }
+        let length_be = self.length.to_be_bytes();
+        let teid_be = self.teid.to_be_bytes();
+
+        let mut result = ArrayVec::new();
+        result.push(flags);
+        result.push(self.message_type.0);
+        result.push(length_be[0]);
+        result.push(length_be[1]);
+        result.push(teid_be[0]);
+        result.push(teid_be[1]);
+        result.push(teid_be[2]);
+        result.push(teid_be[3]);
+        if self.has_optional_fields() {
+            let sequence_number_be = self.sequence_number.unwrap_or(0).to_be_bytes();
+            result.push(sequence_number_be[0]);
+            result.push(sequence_number_be[1]);
+            result.push(self.n_pdu_number.unwrap_or(0));
+            result.push(self.extension_headers.first_type().0);
+            // extension headers length is guaranteed to fit as MAX_LEN includes them
+            let _ = result.try_extend_from_slice(self.extension_headers.as_slice());
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_gens::*;
+    use alloc::{format, vec::Vec};
+    use proptest::prelude::*;
+
+    #[test]
+    fn constants() {
+        assert_eq!(8, GtpuHeader::MIN_LEN);
+        assert_eq!(4, GtpuHeader::OPTIONAL_FIELDS_LEN);
+        assert_eq!(1032, GtpuHeader::MAX_LEN);
+        assert_eq!(2152, GtpuHeader::UDP_PORT);
+        assert_eq!(1, GtpuHeader::VERSION);
+    }
+
+    proptest! {
+        #[test]
+        fn new(teid in any::<u32>()) {
+            assert_eq!(
+                GtpuHeader::new(teid),
+                GtpuHeader {
+                    message_type: GtpuMessageType::G_PDU,
+                    length: 0,
+                    teid,
+                    sequence_number: None,
+                    n_pdu_number: None,
+                    extension_headers: GtpuExtensionHeaders::new(),
+                }
+            );
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn from_slice(
+            input in gtpu_any(),
+            dummy_data in proptest::collection::vec(any::<u8>(), 0..20)
+        ) {
+            // serialize
+            let mut buffer: Vec<u8> = Vec::with_capacity(input.header_len() + dummy_data.len());
+            input.write(&mut buffer).unwrap();
+            buffer.extend(&dummy_data[..]);
+
+            // calls with a valid result
+            {
+                let (result, rest) = GtpuHeader::from_slice(&buffer[..]).unwrap();
+                assert_eq!(input, result);
+                assert_eq!(&buffer[input.header_len()..], rest);
+            }
+
+            // call with not enough data in the slice
+            for len in 0..input.header_len() {
+                assert_eq!(
+                    GtpuHeader::from_slice(&buffer[..len]).unwrap_err(),
+                    GtpuHeaderSlice::from_slice(&buffer[..len]).unwrap_err()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn from_slice_extension_headers_too_long() {
+        let mut data = Vec::with_capacity(12 + 1024);
+        data.extend_from_slice(&[0b0011_0100, 255, 0x04, 0x04, 1, 2, 3, 4, 0, 0, 0, 0x84]);
+        data.push(255);
+        data.extend_from_slice(&[0; 1018]);
+        data.push(0x85);
+        data.extend_from_slice(&[1, 0x10, 0x09, 0x00]);
+        assert_eq!(
+            GtpuHeader::from_slice(&data).unwrap_err(),
+            err::gtpu::HeaderSliceError::Content(err::gtpu::HeaderError::ExtensionHeadersTooLong {
+                len: 1024
+            })
+        );
+    }
+
+    #[test]
+    fn has_optional_fields_header_len() {
+        let mut header = GtpuHeader::new(1234);
+        assert!(!header.has_optional_fields());
+        assert_eq!(8, header.header_len());
+
+        header.sequence_number = Some(1);
+        assert!(header.has_optional_fields());
+        assert_eq!(12, header.header_len());
+
+        header.sequence_number = None;
+        header.n_pdu_number = Some(1);
+        assert!(header.has_optional_fields());
+        assert_eq!(12, header.header_len());
+
+        header.n_pdu_number = None;
+        header.extension_headers = GtpuExtensionHeaders::from_pdu_session_container(
+            GtpuPduSessionContainer::try_new(1, 9).unwrap(),
+        );
+        assert!(header.has_optional_fields());
+        assert_eq!(16, header.header_len());
+    }
+
+    proptest! {
+        #[test]
+        fn set_payload_len(input in gtpu_any()) {
+            let optional_len = input.header_len() - GtpuHeader::MIN_LEN;
+
+            // ok
+            {
+                let mut header = input.clone();
+                header.set_payload_len(10).unwrap();
+                assert_eq!((optional_len + 10) as u16, header.length);
+
+                header.set_payload_len(usize::from(u16::MAX) - optional_len).unwrap();
+                assert_eq!(u16::MAX, header.length);
+            }
+
+            // too big
+            {
+                let mut header = input.clone();
+                let max_allowed = usize::from(u16::MAX) - optional_len;
+                assert_eq!(
+                    header.set_payload_len(max_allowed + 1).unwrap_err(),
+                    ValueTooBigError {
+                        actual: max_allowed + 1,
+                        max_allowed,
+                        value_type: err::ValueType::GtpuPayloadLength,
+                    }
+                );
+                assert_eq!(input.length, header.length);
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn write_to_slice(input in gtpu_any()) {
+            // normal write
+            {
+                let mut buffer = [0u8; GtpuHeader::MAX_LEN];
+                let rest_len = input.write_to_slice(&mut buffer).unwrap().len();
+                assert_eq!(GtpuHeader::MAX_LEN - input.header_len(), rest_len);
+                assert_eq!(&input.to_bytes()[..], &buffer[..input.header_len()]);
+            }
+            // length error
+            for len in 0..input.header_len() {
+                let mut buffer = [0u8; GtpuHeader::MAX_LEN];
+                assert_eq!(
+                    input.write_to_slice(&mut buffer[..len]).unwrap_err(),
+                    SliceWriteSpaceError {
+                        required_len: input.header_len(),
+                        len,
+                        layer: Layer::GtpuHeader,
+                        layer_start_offset: 0,
+                    }
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn write(input in gtpu_any()) {
+            let mut buffer = Vec::with_capacity(input.header_len());
+            input.write(&mut buffer).unwrap();
+            assert_eq!(&input.to_bytes()[..], &buffer[..]);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn to_bytes(input in gtpu_any()) {
+            let bytes = input.to_bytes();
+            assert_eq!(input.header_len(), bytes.len());
+
+            let mut flags = 0b0011_0000;
+            if !input.extension_headers.is_empty() {
+                flags |= 0b100;
+            }
+            if input.sequence_number.is_some() {
+                flags |= 0b10;
+            }
+            if input.n_pdu_number.is_some() {
+                flags |= 0b1;
+            }
+            assert_eq!(flags, bytes[0]);
+            assert_eq!(input.message_type.0, bytes[1]);
+            assert_eq!(&input.length.to_be_bytes(), &bytes[2..4]);
+            assert_eq!(&input.teid.to_be_bytes(), &bytes[4..8]);
+            if input.has_optional_fields() {
+                assert_eq!(&input.sequence_number.unwrap_or(0).to_be_bytes(), &bytes[8..10]);
+                assert_eq!(input.n_pdu_number.unwrap_or(0), bytes[10]);
+                assert_eq!(input.extension_headers.first_type().0, bytes[11]);
+                assert_eq!(input.extension_headers.as_slice(), &bytes[12..]);
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn clone_eq_dbg(input in gtpu_any()) {
+            assert_eq!(input, input.clone());
+            assert_eq!(
+                format!(
+                    "GtpuHeader {{ message_type: {:?}, length: {:?}, teid: {:?}, sequence_number: {:?}, n_pdu_number: {:?}, extension_headers: {:?} }}",
+                    input.message_type,
+                    input.length,
+                    input.teid,
+                    input.sequence_number,
+                    input.n_pdu_number,
+                    input.extension_headers,
+                ),
+                format!("{:?}", input)
+            );
+        }
+    }
+}
@@ -0,0 +1,483 @@
+use crate::*;
+use core::slice::from_raw_parts;
+
+/// A slice containing a GTP-U header (3GPP TS 29.281) including the
+/// optional fields & extension headers.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GtpuHeaderSlice<'a> {
+    slice: &'a [u8],
+}
+
+impl<'a> GtpuHeaderSlice<'a> {
+    /// Creates a GTP-U header slice from an other slice (verifies the
+    /// version, the protocol type bit, the extension header lengths & that
+    /// the "length" field is big enough to contain the optional fields &
+    /// extension headers).
+    pub fn from_slice(slice: &'a [u8]) -> Result<GtpuHeaderSlice<'a>, err::gtpu::HeaderSliceError> {
+        use err::gtpu::{HeaderError::*, HeaderSliceError::*};
+
+        let len_error = |required_len: usize| {
+            Len(err::LenError {
+                required_len,
+                len: slice.len(),
+                len_source: err::LenSource::Slice,
+                layer: err::Layer::GtpuHeader,
+                layer_start_offset: 0,
+            })
+        };
+
+        //check minimum length
+        if slice.len() < GtpuHeader::MIN_LEN {
+            return Err(len_error(GtpuHeader::MIN_LEN));
+        }
+
+        // SAFETY:
+        // Safe as the slice length is checked to be at least
+        // GtpuHeader::MIN_LEN (8) before this.
+        let flags = unsafe { *slice.get_unchecked(0) };
+
+        //check version & protocol type
+        let version_number = flags >> 5;
+        if version_number != GtpuHeader::VERSION {
+            return Err(Content(UnsupportedVersion { version_number }));
+        }
+        if 0 == flags & GtpuHeader::FLAG_PROTOCOL_TYPE {
+            return Err(Content(UnsupportedProtocolType));
+        }
+
+        //check the optional fields
+        let optional_flags = GtpuHeader::FLAG_EXTENSION_HEADER
+            | GtpuHeader::FLAG_SEQUENCE_NUMBER
+            | GtpuHeader::FLAG_N_PDU_NUMBER;
+        let mut len = if 0 == flags & optional_flags {
+            GtpuHeader::MIN_LEN
+        } else {
+            GtpuHeader::MIN_LEN + GtpuHeader::OPTIONAL_FIELDS_LEN
+        };
+        if slice.len() < len {
+            return Err(len_error(len));
+        }
+
+        //walk the extension headers
+        if 0 != flags & GtpuHeader::FLAG_EXTENSION_HEADER {
+            let mut next_type = slice[len - 1];
+            while GtpuExtensionHeaderType::NO_MORE_EXTENSION_HEADERS.0 != next_type {
+                if slice.len() < len + 1 {
+                    return Err(len_error(len + 1));
+                }
+                let ext_len = usize::from(slice[len]) * 4;
+                if 0 == ext_len {
+                    return Err(Content(ZeroExtensionHeaderLen {
+                        header_type: next_type,
+                    }));
+                }
+                len += ext_len;
+                if slice.len() < len {
+                    return Err(len_error(len));
+                }
+                next_type = slice[len - 1];
+            }
+        }
+
+        //check the length field covers the optional fields & extension headers
+        let length = u16::from_be_bytes([slice[2], slice[3]]);
+        if usize::from(length) < len - GtpuHeader::MIN_LEN {
+            return Err(Content(LengthTooSmall {
+                length,
+                min_length: len - GtpuHeader::MIN_LEN,
+            }));
+        }
+
+        //all done
+        Ok(GtpuHeaderSlice {
+            // SAFETY:
+            // Safe as slice length is checked to be at least len before this.
+            slice: unsafe { from_raw_parts(slice.as_ptr(), len) },
+        })
+    }
+
+    /// Returns the slice containing the GTP-U header (including the
+    /// optional fields & extension headers).
+    #[inline]
+    pub fn slice(&self) -> &'a [u8] {
+        self.slice
+    }
+
+    /// Returns the version field of the header (always `1` as other
+    /// versions are rejected by [`GtpuHeaderSlice::from_slice`]).
+    #[inline]
+    pub fn version(&self) -> u8 {
+        self.flags() >> 5
+    }
+
+    /// Returns the first byte of the header containing the version,
+    /// protocol type & the "E", "S" & "PN" flags.
+    #[inline]
+    pub fn flags(&self) -> u8 {
+        // SAFETY:
+        // Safe as the contructor checks that the slice has
+        // at least the length of GtpuHeader::MIN_LEN (8).
+        unsafe { *self.slice.get_unchecked(0) }
+    }
+
+    /// Returns true if the "E" flag is set (extension headers are present).
+    #[inline]
+    pub fn extension_header_flag(&self) -> bool {
+        0 != self.flags() & GtpuHeader::FLAG_EXTENSION_HEADER
+    }
+
+    /// Returns true if the "S" flag is set (sequence number is present).
+    #[inline]
+    pub fn sequence_number_flag(&self) -> bool {
+        0 != self.flags() & GtpuHeader::FLAG_SEQUENCE_NUMBER
+    }
+
+    /// Returns true if the "PN" flag is set (N-PDU number is present).
+    #[inline]
+    pub fn n_pdu_number_flag(&self) -> bool {
+        0 != self.flags() & GtpuHeader::FLAG_N_PDU_NUMBER
+    }
+
+    /// Returns the message type.
+    #[inline]
+    pub fn message_type(&self) -> GtpuMessageType {
+        // SAFETY:
+        // Safe as the contructor checks that the slice has
+        // at least the length of GtpuHeader::MIN_LEN (8).
+        GtpuMessageType(unsafe { *self.slice.get_unchecked(1) })
+    }
+
+    /// Returns the "length" field (length of the data after the mandatory
+    /// 8 bytes of the header, including the optional fields & extension
+    /// headers).
+    #[inline]
+    pub fn length(&self) -> u16 {
+        // SAFETY:
+        // Safe as the contructor checks that the slice has
+        // at least the length of GtpuHeader::MIN_LEN (8).
+        unsafe { get_unchecked_be_u16(self.slice.as_ptr().add(2)) }
+    }
+
+    /// Returns the tunnel endpoint identifier (TEID).
+    #[inline]
+    pub fn teid(&self) -> u32 {
+        // SAFETY:
+        // Safe as the contructor checks that the slice has
+        // at least the length of GtpuHeader::MIN_LEN (8).
+        unsafe { get_unchecked_be_u32(self.slice.as_ptr().add(4)) }
+    }
+
+    /// Returns true if the optional fields (sequence number, N-PDU number
+    /// & next extension header type) are present.
+    #[inline]
+    pub fn has_optional_fields(&self) -> bool {
+        self.slice.len() > GtpuHeader::MIN_LEN
+    }
+
+    /// Returns the sequence number if the "S" flag is set.
+    #[inline]
+    pub fn sequence_number(&self) -> Option<u16> {
+        if self.sequence_number_flag() {
+            // SAFETY:
+            // Safe as the contructor checks that the slice has
+            // at least the length of GtpuHeader::MIN_LEN + 4 (12)
+            // if a flag indicating optional fields is set.
+            Some(unsafe { get_unchecked_be_u16(self.slice.as_ptr().add(8)) })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the N-PDU number if the "PN" flag is set.
+    #[inline]
+    pub fn n_pdu_number(&self) -> Option<u8> {
+        if self.n_pdu_number_flag() {
+            // SAFETY:
+            // Safe as the contructor checks that the slice has
+            // at least the length of GtpuHeader::MIN_LEN + 4 (12)
+            // if a flag indicating optional fields is set.
+            Some(unsafe { *self.slice.get_unchecked(10) })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the type of the first extension header if the "E" flag is
+    /// set.
+    #[inline]
+    pub fn next_extension_header_type(&self) -> Option<GtpuExtensionHeaderType> {
+        if self.extension_header_flag() {
+            // SAFETY:
+            // Safe as the contructor checks that the slice has
+            // at least the length of GtpuHeader::MIN_LEN + 4 (12)
+            // if a flag indicating optional fields is set.
+            Some(GtpuExtensionHeaderType(unsafe {
+                *self.slice.get_unchecked(11)
+            }))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the slice containing the encoded extension headers.
+    #[inline]
+    pub fn extension_headers_slice(&self) -> &'a [u8] {
+        if self.has_optional_fields() {
+            // SAFETY:
+            // Safe as the slice has at least the length of
+            // GtpuHeader::MIN_LEN + 4 (12) if optional fields are present.
+            unsafe {
+                from_raw_parts(
+                    self.slice
+                        .as_ptr()
+                        .add(GtpuHeader::MIN_LEN + GtpuHeader::OPTIONAL_FIELDS_LEN),
+                    self.slice.len() - GtpuHeader::MIN_LEN - GtpuHeader::OPTIONAL_FIELDS_LEN,
+                )
+            }
+        } else {
+            &[]
+        }
+    }
+
+    /// Returns an iterator over the extension headers.
+    #[inline]
+    pub fn extension_headers(&self) -> GtpuExtensionHeadersIterator<'a> {
+        GtpuExtensionHeadersIterator::new(
+            self.next_extension_header_type()
+                .unwrap_or(GtpuExtensionHeaderType::NO_MORE_EXTENSION_HEADERS),
+            self.extension_headers_slice(),
+        )
+    }
+
+    /// Returns the PDU type & QoS flow identifier (QFI) of the first "PDU
+    /// Session Container" extension header (if present).
+    #[inline]
+    pub fn pdu_session_container(&self) -> Option<GtpuPduSessionContainer> {
+        self.extension_headers()
+            .find_map(|header| header.pdu_session_container())
+    }
+
+    /// Length of the header (including the optional fields & extension
+    /// headers) in bytes.
+    #[inline]
+    pub fn header_len(&self) -> usize {
+        self.slice.len()
+    }
+
+    /// Length of the payload (T-PDU) in bytes as indicated by the "length"
+    /// field.
+    #[inline]
+    pub fn payload_len(&self) -> usize {
+        // the constructor verifies that the length field covers the
+        // optional fields & extension headers
+        usize::from(self.length()) + GtpuHeader::MIN_LEN - self.slice.len()
+    }
+
+    /// Decode all the fields and copy the results to a [`GtpuHeader`] struct.
+    ///
+    /// An error is returned if the extension headers exceed
+    /// [`GtpuExtensionHeaders::MAX_LEN`].
+    pub fn to_header(&self) -> Result<GtpuHeader, err::gtpu::HeaderError> {
+        let extension_headers = match self.next_extension_header_type() {
+            Some(first_type) => {
+                let encoded = self.extension_headers_slice();
+                GtpuExtensionHeaders::from_encoded(first_type, encoded)
+                    .ok_or(err::gtpu::HeaderError::ExtensionHeadersTooLong { len: encoded.len() })?
+            }
+            None => GtpuExtensionHeaders::new(),
+        };
+        Ok(GtpuHeader {
+            message_type: self.message_type(),
+            length: self.length(),
+            teid: self.teid(),
+            sequence_number: self.sequence_number(),
+            n_pdu_number: self.n_pdu_number(),
+            extension_headers,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{err::gtpu::*, test_gens::*, *};
+    use alloc::{format, vec::Vec};
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn from_slice(
+            input in gtpu_any(),
+            dummy_data in proptest::collection::vec(any::<u8>(), 0..20),
+            bad_version in 0u8..=7
+        ) {
+            // serialize
+            let mut buffer: Vec<u8> = Vec::with_capacity(input.header_len() + dummy_data.len());
+            input.write(&mut buffer).unwrap();
+            buffer.extend(&dummy_data[..]);
+
+            // calls with a valid result
+            {
+                let result = GtpuHeaderSlice::from_slice(&buffer[..]).unwrap();
+                assert_eq!(&buffer[..input.header_len()], result.slice());
+            }
+
+            // call with not enough data in the slice
+            for len in 0..input.header_len() {
+                let err = match GtpuHeaderSlice::from_slice(&buffer[..len]).unwrap_err() {
+                    HeaderSliceError::Len(err) => err,
+                    HeaderSliceError::Content(_) => panic!("expected len error"),
+                };
+                assert!(err.required_len > len);
+                assert!(err.required_len <= input.header_len());
+                assert_eq!(len, err.len);
+                assert_eq!(err::LenSource::Slice, err.len_source);
+                assert_eq!(err::Layer::GtpuHeader, err.layer);
+                assert_eq!(0, err.layer_start_offset);
+            }
+
+            // unsupported version
+            if bad_version != GtpuHeader::VERSION {
+                let mut buffer = buffer.clone();
+                buffer[0] = (buffer[0] & 0b0001_1111) | (bad_version << 5);
+                assert_eq!(
+                    GtpuHeaderSlice::from_slice(&buffer[..]).unwrap_err(),
+                    HeaderSliceError::Content(HeaderError::UnsupportedVersion {
+                        version_number: bad_version
+                    })
+                );
+            }
+
+            // unsupported protocol type
+            {
+                let mut buffer = buffer.clone();
+                buffer[0] &= !GtpuHeader::FLAG_PROTOCOL_TYPE;
+                assert_eq!(
+                    GtpuHeaderSlice::from_slice(&buffer[..]).unwrap_err(),
+                    HeaderSliceError::Content(HeaderError::UnsupportedProtocolType)
+                );
+            }
+
+            // length too small
+            if input.header_len() > GtpuHeader::MIN_LEN {
+                let mut buffer = buffer.clone();
+                let length = (input.header_len() - GtpuHeader::MIN_LEN - 1) as u16;
+                buffer[2..4].copy_from_slice(&length.to_be_bytes());
+                assert_eq!(
+                    GtpuHeaderSlice::from_slice(&buffer[..]).unwrap_err(),
+                    HeaderSliceError::Content(HeaderError::LengthTooSmall {
+                        length,
+                        min_length: input.header_len() - GtpuHeader::MIN_LEN,
+                    })
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn from_slice_extension_header_errors() {
+        #[rustfmt::skip]
+        let data = [
+            0b0011_0100, 255, 0, 12, 1, 2, 3, 4,
+            0, 0, 0, 0x85,
+            1, 0x10, 0x09, 0x84,
+            0, 0, 0, 0,
+        ];
+
+        // missing length of the second extension header
+        assert_eq!(
+            GtpuHeaderSlice::from_slice(&data[..16]).unwrap_err(),
+            HeaderSliceError::Len(err::LenError {
+                required_len: 17,
+                len: 16,
+                len_source: err::LenSource::Slice,
+                layer: err::Layer::GtpuHeader,
+                layer_start_offset: 0,
+            })
+        );
+
+        // zero length extension header
+        assert_eq!(
+            GtpuHeaderSlice::from_slice(&data).unwrap_err(),
+            HeaderSliceError::Content(HeaderError::ZeroExtensionHeaderLen { header_type: 0x84 })
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn getters(input in gtpu_any()) {
+            let bytes = input.to_bytes();
+            let slice = GtpuHeaderSlice::from_slice(&bytes).unwrap();
+            assert_eq!(GtpuHeader::VERSION, slice.version());
+            assert_eq!(bytes[0], slice.flags());
+            assert_eq!(!input.extension_headers.is_empty(), slice.extension_header_flag());
+            assert_eq!(input.sequence_number.is_some(), slice.sequence_number_flag());
+            assert_eq!(input.n_pdu_number.is_some(), slice.n_pdu_number_flag());
+            assert_eq!(input.message_type, slice.message_type());
+            assert_eq!(input.length, slice.length());
+            assert_eq!(input.teid, slice.teid());
+            assert_eq!(input.header_len() > GtpuHeader::MIN_LEN, slice.has_optional_fields());
+            assert_eq!(input.sequence_number, slice.sequence_number());
+            assert_eq!(input.n_pdu_number, slice.n_pdu_number());
+            assert_eq!(
+                if input.extension_headers.is_empty() {
+                    None
+                } else {
+                    Some(input.extension_headers.first_type())
+                },
+                slice.next_extension_header_type()
+            );
+            assert_eq!(input.extension_headers.as_slice(), slice.extension_headers_slice());
+            assert_eq!(input.extension_headers.iter(), slice.extension_headers());
+            assert_eq!(
+                input.extension_headers.pdu_session_container(),
+                slice.pdu_session_container()
+            );
+            assert_eq!(input.header_len(), slice.header_len());
+            assert_eq!(
+                usize::from(input.length) + GtpuHeader::MIN_LEN - input.header_len(),
+                slice.payload_len()
+            );
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn to_header(input in gtpu_any()) {
+            let bytes = input.to_bytes();
+            let slice = GtpuHeaderSlice::from_slice(&bytes).unwrap();
+            assert_eq!(input, slice.to_header().unwrap());
+        }
+    }
+
+    #[test]
+    fn to_header_extension_headers_too_long() {
+        let mut data = Vec::with_capacity(12 + 1024);
+        data.extend_from_slice(&[0b0011_0100, 255, 0x04, 0x04, 1, 2, 3, 4, 0, 0, 0, 0x84]);
+        // first extension header of maximum length
+        data.push(255);
+        data.extend_from_slice(&[0; 1018]);
+        data.push(0x85);
+        // second extension header
+        data.extend_from_slice(&[1, 0x10, 0x09, 0x00]);
+
+        let slice = GtpuHeaderSlice::from_slice(&data).unwrap();
+        assert_eq!(2, slice.extension_headers().count());
+        assert_eq!(
+            Err(HeaderError::ExtensionHeadersTooLong { len: 1024 }),
+            slice.to_header()
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn clone_eq_dbg(input in gtpu_any()) {
+            let bytes = input.to_bytes();
+            let slice = GtpuHeaderSlice::from_slice(&bytes).unwrap();
+            assert_eq!(slice, slice.clone());
+            assert_eq!(
+                format!("GtpuHeaderSlice {{ slice: {:?} }}", slice.slice()),
+                format!("{:?}", slice)
+            );
+        }
+    }
+}
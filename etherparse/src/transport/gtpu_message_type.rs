@@ -0,0 +1,117 @@
+/// Message type field of a GTP-U header (3GPP TS 29.281).
+///
+/// You can access the underlying `u8` value by using `.0` and any `u8`
+/// can be converted to a `GtpuMessageType`:
+///
+/// ```
+/// use etherparse::GtpuMessageType;
+///
+/// assert_eq!(GtpuMessageType::G_PDU.0, 255);
+/// assert_eq!(GtpuMessageType::G_PDU, GtpuMessageType(255));
+///
+/// // convert to GtpuMessageType using the from & into trait
+/// let message_type: GtpuMessageType = 1u8.into();
+/// assert_eq!(GtpuMessageType::ECHO_REQUEST, message_type);
+///
+/// // convert to u8 using the from & into trait
+/// let num: u8 = GtpuMessageType::END_MARKER.into();
+/// assert_eq!(254, num);
+/// ```
+#[derive(Default, PartialEq, Eq, Clone, Copy, Hash, Ord, PartialOrd)]
+pub struct GtpuMessageType(pub u8);
+
+impl GtpuMessageType {
+    /// Echo Request.
+    pub const ECHO_REQUEST: GtpuMessageType = Self(1);
+    /// Echo Response.
+    pub const ECHO_RESPONSE: GtpuMessageType = Self(2);
+    /// Error Indication.
+    pub const ERROR_INDICATION: GtpuMessageType = Self(26);
+    /// Supported Extension Headers Notification.
+    pub const SUPPORTED_EXTENSION_HEADERS_NOTIFICATION: GtpuMessageType = Self(31);
+    /// Tunnel Status.
+    pub const TUNNEL_STATUS: GtpuMessageType = Self(253);
+    /// End Marker.
+    pub const END_MARKER: GtpuMessageType = Self(254);
+    /// G-PDU (payload contains an user IP packet, the T-PDU).
+    pub const G_PDU: GtpuMessageType = Self(255);
+}
+
+impl From<u8> for GtpuMessageType {
+    #[inline]
+    fn from(val: u8) -> Self {
+        GtpuMessageType(val)
+    }
+}
+
+impl From<GtpuMessageType> for u8 {
+    #[inline]
+    fn from(val: GtpuMessageType) -> Self {
+        val.0
+    }
+}
+
+impl core::fmt::Debug for GtpuMessageType {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match *self {
+            Self::ECHO_REQUEST => write!(f, "{} (Echo Request)", self.0),
+            Self::ECHO_RESPONSE => write!(f, "{} (Echo Response)", self.0),
+            Self::ERROR_INDICATION => write!(f, "{} (Error Indication)", self.0),
+            Self::SUPPORTED_EXTENSION_HEADERS_NOTIFICATION => {
+                write!(f, "{} (Supported Extension Headers Notification)", self.0)
+            }
+            Self::TUNNEL_STATUS => write!(f, "{} (Tunnel Status)", self.0),
+            Self::END_MARKER => write!(f, "{} (End Marker)", self.0),
+            Self::G_PDU => write!(f, "{} (G-PDU)", self.0),
+            _ => write!(f, "{}", self.0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::format;
+
+    #[test]
+    fn to_u8() {
+        assert_eq!(1, u8::from(GtpuMessageType::ECHO_REQUEST));
+        assert_eq!(254, u8::from(GtpuMessageType::END_MARKER));
+        assert_eq!(255, u8::from(GtpuMessageType::G_PDU));
+    }
+
+    #[test]
+    fn from_u8() {
+        assert_eq!(GtpuMessageType::from(2), GtpuMessageType::ECHO_RESPONSE);
+        assert_eq!(GtpuMessageType::from(26), GtpuMessageType::ERROR_INDICATION);
+        assert_eq!(GtpuMessageType::from(123), GtpuMessageType(123));
+    }
+
+    #[test]
+    fn dbg() {
+        let pairs = &[
+            (GtpuMessageType::ECHO_REQUEST, "1 (Echo Request)"),
+            (GtpuMessageType::ECHO_RESPONSE, "2 (Echo Response)"),
+            (GtpuMessageType::ERROR_INDICATION, "26 (Error Indication)"),
+            (
+                GtpuMessageType::SUPPORTED_EXTENSION_HEADERS_NOTIFICATION,
+                "31 (Supported Extension Headers Notification)",
+            ),
+            (GtpuMessageType::TUNNEL_STATUS, "253 (Tunnel Status)"),
+            (GtpuMessageType::END_MARKER, "254 (End Marker)"),
+            (GtpuMessageType::G_PDU, "255 (G-PDU)"),
+            (GtpuMessageType(123), "123"),
+        ];
+
+        for (input, expected) in pairs {
+            assert_eq!(expected, &format!("{:?}", input));
+        }
+    }
+
+    #[test]
+    fn clone_eq_default() {
+        let value = GtpuMessageType::G_PDU;
+        assert_eq!(value, value.clone());
+        assert_eq!(GtpuMessageType(0), GtpuMessageType::default());
+    }
+}
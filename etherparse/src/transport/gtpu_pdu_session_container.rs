@@ -0,0 +1,196 @@
+use crate::err::{ValueTooBigError, ValueType};
+
+/// PDU type & QoS flow identifier (QFI) contained in a GTP-U "PDU Session
+/// Container" extension header (3GPP TS 38.415).
+///
+/// Only the PDU type & QFI are decoded, other flags & optional fields of
+/// the container are ignored.
+///
+/// # Example
+///
+/// ```
+/// use etherparse::GtpuPduSessionContainer;
+///
+/// let container = GtpuPduSessionContainer::try_new(
+///     GtpuPduSessionContainer::PDU_TYPE_UL,
+///     9
+/// ).unwrap();
+/// assert!(container.is_uplink());
+/// assert_eq!(9, container.qfi());
+/// assert_eq!([0x10, 0x09], container.to_content());
+///
+/// // values that are too big are rejected
+/// assert!(GtpuPduSessionContainer::try_new(0, 64).is_err());
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Default, Ord, PartialOrd)]
+pub struct GtpuPduSessionContainer {
+    pdu_type: u8,
+    qfi: u8,
+}
+
+impl GtpuPduSessionContainer {
+    /// PDU type of a "DL PDU SESSION INFORMATION" container (downlink).
+    pub const PDU_TYPE_DL: u8 = 0;
+
+    /// PDU type of an "UL PDU SESSION INFORMATION" container (uplink).
+    pub const PDU_TYPE_UL: u8 = 1;
+
+    /// Maximum value of the PDU type (4 bits).
+    pub const MAX_PDU_TYPE: u8 = 0b1111;
+
+    /// Maximum value of the QoS flow identifier (6 bits).
+    pub const MAX_QFI: u8 = 0b11_1111;
+
+    /// Minimum length of the content of a PDU session container extension
+    /// header in bytes/octets.
+    pub const MIN_CONTENT_LEN: usize = 2;
+
+    /// Creates a PDU session container with the given PDU type & QoS flow
+    /// identifier.
+    pub fn try_new(pdu_type: u8, qfi: u8) -> Result<GtpuPduSessionContainer, ValueTooBigError<u8>> {
+        if pdu_type > GtpuPduSessionContainer::MAX_PDU_TYPE {
+            Err(ValueTooBigError {
+                actual: pdu_type,
+                max_allowed: GtpuPduSessionContainer::MAX_PDU_TYPE,
+                value_type: ValueType::GtpuPduType,
+            })
+        } else if qfi > GtpuPduSessionContainer::MAX_QFI {
+            Err(ValueTooBigError {
+                actual: qfi,
+                max_allowed: GtpuPduSessionContainer::MAX_QFI,
+                value_type: ValueType::GtpuQfi,
+            })
+        } else {
+            Ok(GtpuPduSessionContainer { pdu_type, qfi })
+        }
+    }
+
+    /// Decodes the PDU type & QFI from the content of a PDU session
+    /// container extension header (`None` if the content is shorter then
+    /// [`GtpuPduSessionContainer::MIN_CONTENT_LEN`]).
+    pub fn from_content(content: &[u8]) -> Option<GtpuPduSessionContainer> {
+        if content.len() < GtpuPduSessionContainer::MIN_CONTENT_LEN {
+            None
+        } else {
+            Some(GtpuPduSessionContainer {
+                pdu_type: content[0] >> 4,
+                qfi: content[1] & GtpuPduSessionContainer::MAX_QFI,
+            })
+        }
+    }
+
+    /// PDU type (`0` for downlink & `1` for uplink containers).
+    #[inline]
+    pub fn pdu_type(&self) -> u8 {
+        self.pdu_type
+    }
+
+    /// QoS flow identifier.
+    #[inline]
+    pub fn qfi(&self) -> u8 {
+        self.qfi
+    }
+
+    /// Returns true if the container is a "DL PDU SESSION INFORMATION".
+    #[inline]
+    pub fn is_downlink(&self) -> bool {
+        GtpuPduSessionContainer::PDU_TYPE_DL == self.pdu_type
+    }
+
+    /// Returns true if the container is an "UL PDU SESSION INFORMATION".
+    #[inline]
+    pub fn is_uplink(&self) -> bool {
+        GtpuPduSessionContainer::PDU_TYPE_UL == self.pdu_type
+    }
+
+    /// Returns the content of a PDU session container extension header
+    /// (all other flags set to zero).
+    #[inline]
+    pub fn to_content(&self) -> [u8; 2] {
+        [self.pdu_type << 4, self.qfi]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::format;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn try_new(
+            pdu_type in 0..=GtpuPduSessionContainer::MAX_PDU_TYPE,
+            qfi in 0..=GtpuPduSessionContainer::MAX_QFI,
+            bad_pdu_type in (GtpuPduSessionContainer::MAX_PDU_TYPE + 1)..=u8::MAX,
+            bad_qfi in (GtpuPduSessionContainer::MAX_QFI + 1)..=u8::MAX,
+        ) {
+            let actual = GtpuPduSessionContainer::try_new(pdu_type, qfi).unwrap();
+            assert_eq!(pdu_type, actual.pdu_type());
+            assert_eq!(qfi, actual.qfi());
+            assert_eq!(
+                GtpuPduSessionContainer::try_new(bad_pdu_type, qfi),
+                Err(ValueTooBigError {
+                    actual: bad_pdu_type,
+                    max_allowed: GtpuPduSessionContainer::MAX_PDU_TYPE,
+                    value_type: ValueType::GtpuPduType,
+                })
+            );
+            assert_eq!(
+                GtpuPduSessionContainer::try_new(pdu_type, bad_qfi),
+                Err(ValueTooBigError {
+                    actual: bad_qfi,
+                    max_allowed: GtpuPduSessionContainer::MAX_QFI,
+                    value_type: ValueType::GtpuQfi,
+                })
+            );
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn from_to_content(
+            pdu_type in 0..=GtpuPduSessionContainer::MAX_PDU_TYPE,
+            qfi in 0..=GtpuPduSessionContainer::MAX_QFI,
+            flags0 in 0u8..=0b1111,
+            flags1 in 0u8..=0b11,
+        ) {
+            let expected = GtpuPduSessionContainer::try_new(pdu_type, qfi).unwrap();
+            assert_eq!([pdu_type << 4, qfi], expected.to_content());
+
+            // other flags are ignored
+            let content = [(pdu_type << 4) | flags0, (flags1 << 6) | qfi, 1, 2];
+            assert_eq!(Some(expected), GtpuPduSessionContainer::from_content(&content));
+            assert_eq!(Some(expected), GtpuPduSessionContainer::from_content(&content[..2]));
+            assert_eq!(None, GtpuPduSessionContainer::from_content(&content[..1]));
+            assert_eq!(None, GtpuPduSessionContainer::from_content(&[]));
+        }
+    }
+
+    #[test]
+    fn is_downlink_uplink() {
+        let dl = GtpuPduSessionContainer::try_new(0, 1).unwrap();
+        assert!(dl.is_downlink());
+        assert!(!dl.is_uplink());
+        let ul = GtpuPduSessionContainer::try_new(1, 1).unwrap();
+        assert!(!ul.is_downlink());
+        assert!(ul.is_uplink());
+        let other = GtpuPduSessionContainer::try_new(2, 1).unwrap();
+        assert!(!other.is_downlink());
+        assert!(!other.is_uplink());
+    }
+
+    #[test]
+    fn clone_eq_dbg_default() {
+        let value = GtpuPduSessionContainer::try_new(1, 9).unwrap();
+        assert_eq!(value, value.clone());
+        assert_eq!(
+            "GtpuPduSessionContainer { pdu_type: 1, qfi: 9 }",
+            format!("{:?}", value)
+        );
+        assert_eq!(
+            GtpuPduSessionContainer::try_new(0, 0).unwrap(),
+            GtpuPduSessionContainer::default()
+        );
+    }
+}
@@ -0,0 +1,747 @@
+use crate::{
+    err::{ip, Layer, LenError, LenSource},
+    *,
+};
+
+/// Slice containing the original packet quoted in an ICMP error message
+/// (e.g. "Destination Unreachable", "Time Exceeded", "Parameter Problem"
+/// or "Packet Too Big").
+///
+/// ICMP error messages usually only contain the start of the original
+/// packet (ICMPv4 only requires the IP header & the first 8 bytes of the
+/// IP payload to be quoted). In contrast to [`SlicedPacket`] the slicing
+/// therefor tolerates packets that are shorter then the length given in
+/// their IP header and only decodes the transport header if it is
+/// completely present. The ports of TCP & UDP packets are also available
+/// if only the start of the transport header was quoted (see
+/// [`IcmpQuoteSlice::source_port`] & [`IcmpQuoteSlice::destination_port`]).
+///
+/// Usually created via [`Icmpv4Slice::quoted_packet`] or
+/// [`Icmpv6Slice::quoted_packet`].
+///
+/// # Example
+///
+/// ```
+/// use etherparse::{icmpv4, Icmpv4Type, ip_number, PacketBuilder, SlicedPacket, TransportSlice};
+///
+/// // original packet that triggered the ICMP error
+/// let builder = PacketBuilder::
+///     ipv4([192,168,1,1], [10,0,0,1], 1)
+///     .tcp(49152, 443, 1234, 4000);
+/// let mut original = Vec::with_capacity(builder.size(100));
+/// builder.write(&mut original, &[0u8;100]).unwrap();
+///
+/// // the router only quotes the ip header & 8 bytes of the tcp header
+/// let builder = PacketBuilder::
+///     ipv4([172,16,0,1], [192,168,1,1], 20)
+///     .icmpv4(Icmpv4Type::TimeExceeded(icmpv4::TimeExceededCode::TtlExceededInTransit));
+/// let mut packet = Vec::with_capacity(builder.size(28));
+/// builder.write(&mut packet, &original[..28]).unwrap();
+///
+/// let sliced = SlicedPacket::from_ip(&packet).unwrap();
+/// let quote = match sliced.transport {
+///     Some(TransportSlice::Icmpv4(icmp)) => icmp.quoted_packet().unwrap().unwrap(),
+///     _ => panic!("expected icmpv4"),
+/// };
+///
+/// // original 5-tuple
+/// let ipv4 = quote.ip.ipv4().unwrap().header();
+/// assert_eq!([192,168,1,1], ipv4.source());
+/// assert_eq!([10,0,0,1], ipv4.destination());
+/// assert_eq!(ip_number::TCP, quote.ip.payload_ip_number());
+/// assert_eq!(Some(49152), quote.source_port());
+/// assert_eq!(Some(443), quote.destination_port());
+///
+/// // the tcp header itself is incomplete
+/// assert!(quote.truncated);
+/// assert_eq!(None, quote.transport);
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct IcmpQuoteSlice<'a> {
+    /// IPv4 or IPv6 header & extension headers of the quoted packet.
+    ///
+    /// In case the quoted packet is truncated the IP payload only contains
+    /// the quoted data and has the length source [`LenSource::Slice`].
+    pub ip: InternetSlice<'a>,
+    /// Transport header of the quoted packet (only present if the header is
+    /// completely contained in the quoted data and the IP payload is not
+    /// fragmented).
+    pub transport: Option<TransportSlice<'a>>,
+    /// Quoted data after the IP & transport headers.
+    pub payload: &'a [u8],
+    /// True if the quoted data is shorter then the length given in the IP
+    /// header of the quoted packet.
+    pub truncated: bool,
+}
+
+impl<'a> IcmpQuoteSlice<'a> {
+    /// Slices a quoted packet starting with an IPv4 or IPv6 header.
+    ///
+    /// Only the IP header itself has to be completely present. Missing data
+    /// after it is tolerated and sets the `truncated` flag. In case the
+    /// truncation cuts an extension header the extension headers are not
+    /// decoded and the IP payload starts directly after the IP header.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the IP header is incomplete or contains
+    /// invalid values or if the extension headers of a complete packet
+    /// could not be decoded.
+    pub fn from_slice(slice: &'a [u8]) -> Result<IcmpQuoteSlice<'a>, ip::SliceError> {
+        use ip::SliceError::*;
+
+        if slice.is_empty() {
+            return Err(Len(LenError {
+                required_len: 1,
+                len: slice.len(),
+                len_source: LenSource::Slice,
+                layer: Layer::IpHeader,
+                layer_start_offset: 0,
+            }));
+        }
+
+        // SAFETY: Safe as slice is not empty.
+        let first_byte = unsafe { *slice.get_unchecked(0) };
+        let (ip, truncated) = match first_byte >> 4 {
+            4 => IcmpQuoteSlice::slice_ipv4(slice)?,
+            6 => IcmpQuoteSlice::slice_ipv6(slice)?,
+            version_number => {
+                return Err(IpHeader(ip::HeaderError::UnsupportedIpVersion {
+                    version_number,
+                }))
+            }
+        };
+
+        // only decode transport headers that are completely present
+        let ip_payload = ip.payload().clone();
+        let transport = if ip_payload.fragmented {
+            None
+        } else {
+            use TransportSlice::*;
+            let data = ip_payload.payload;
+            match ip_payload.ip_number {
+                ip_number::ICMP => Icmpv4Slice::from_slice(data).ok().map(Icmpv4),
+                ip_number::IPV6_ICMP => Icmpv6Slice::from_slice(data).ok().map(Icmpv6),
+                ip_number::UDP => UdpHeaderSlice::from_slice(data).ok().map(Udp),
+                ip_number::TCP => TcpHeaderSlice::from_slice(data).ok().map(Tcp),
+                ip_number::GRE | ip_number::ENCAP_SEC => None,
+                value => Some(Unknown(value)),
+            }
+        };
+
+        let transport_len = match &transport {
+            Some(TransportSlice::Icmpv4(s)) => s.slice().len(),
+            Some(TransportSlice::Icmpv6(s)) => s.slice().len(),
+            Some(TransportSlice::Udp(s)) => s.slice().len(),
+            Some(TransportSlice::Tcp(s)) => s.slice().len(),
+            Some(TransportSlice::Unknown(_)) | None => 0,
+        };
+
+        Ok(IcmpQuoteSlice {
+            ip,
+            transport,
+            payload: &ip_payload.payload[transport_len..],
+            truncated,
+        })
+    }
+
+    /// Slices the IPv4 header & extension headers and returns if the
+    /// packet is truncated.
+    fn slice_ipv4(slice: &'a [u8]) -> Result<(InternetSlice<'a>, bool), ip::SliceError> {
+        use ip::SliceError::*;
+
+        // SAFETY: Safe as the caller checks that the slice is not empty.
+        let ihl = unsafe { *slice.get_unchecked(0) } & 0xf;
+
+        // check that the ihl has at least the lenght of the base IPv4 header
+        if ihl < 5 {
+            use ip::HeaderError::Ipv4HeaderLengthSmallerThanHeader;
+            return Err(IpHeader(Ipv4HeaderLengthSmallerThanHeader { ihl }));
+        }
+
+        // check there is enough data for the header
+        let header_len = usize::from(ihl) * 4;
+        if slice.len() < header_len {
+            return Err(Len(LenError {
+                required_len: header_len,
+                len: slice.len(),
+                len_source: LenSource::Slice,
+                layer: Layer::Ipv4Header,
+                layer_start_offset: 0,
+            }));
+        }
+
+        // SAFETY: Safe as the slice length is checked to be at least
+        // header_len above.
+        let header = unsafe { Ipv4HeaderSlice::from_slice_unchecked(&slice[..header_len]) };
+
+        // check the total_len at least contains the header
+        let total_len = usize::from(header.total_len());
+        if total_len < header_len {
+            return Err(Len(LenError {
+                required_len: header_len,
+                len: total_len,
+                len_source: LenSource::Ipv4HeaderTotalLen,
+                layer: Layer::Ipv4Packet,
+                layer_start_offset: 0,
+            }));
+        }
+
+        // tolerate missing data at the end of the packet
+        let truncated = slice.len() < total_len;
+        let (header_payload, len_source) = if truncated {
+            (&slice[header_len..], LenSource::Slice)
+        } else {
+            (&slice[header_len..total_len], LenSource::Ipv4HeaderTotalLen)
+        };
+
+        // slice extension headers
+        let (exts, ip_number, payload) =
+            match Ipv4ExtensionsSlice::from_slice(header.protocol(), header_payload) {
+                Ok(value) => value,
+                Err(err) => {
+                    use crate::err::ip_auth::HeaderSliceError as E;
+                    match err {
+                        // extension headers cut by the truncation are not decoded
+                        E::Len(_) if truncated => {
+                            (Default::default(), header.protocol(), header_payload)
+                        }
+                        E::Len(mut err) => {
+                            err.len_source = LenSource::Ipv4HeaderTotalLen;
+                            err.layer_start_offset += header_len;
+                            return Err(Len(err));
+                        }
+                        E::Content(err) => return Err(IpHeader(ip::HeaderError::Ipv4Ext(err))),
+                    }
+                }
+            };
+
+        let fragmented = header.is_fragmenting_payload();
+        Ok((
+            InternetSlice::Ipv4(Ipv4Slice {
+                header,
+                exts,
+                payload: IpPayload {
+                    ip_number,
+                    fragmented,
+                    len_source,
+                    payload,
+                },
+            }),
+            truncated,
+        ))
+    }
+
+    /// Slices the IPv6 header & extension headers and returns if the
+    /// packet is truncated.
+    fn slice_ipv6(slice: &'a [u8]) -> Result<(InternetSlice<'a>, bool), ip::SliceError> {
+        use ip::SliceError::*;
+
+        // check length
+        if slice.len() < Ipv6Header::LEN {
+            return Err(Len(LenError {
+                required_len: Ipv6Header::LEN,
+                len: slice.len(),
+                len_source: LenSource::Slice,
+                layer: Layer::Ipv6Header,
+                layer_start_offset: 0,
+            }));
+        }
+
+        // SAFETY: Safe as the slice length is checked to be at least
+        // Ipv6Header::LEN above.
+        let header = unsafe { Ipv6HeaderSlice::from_slice_unchecked(&slice[..Ipv6Header::LEN]) };
+
+        // tolerate missing data at the end of the packet
+        let (header_payload, len_source, truncated) =
+            match Ipv6Slice::header_payload(header.payload_length(), header.next_header(), slice) {
+                Ok((header_payload, len_source)) => (header_payload, len_source, false),
+                Err(_) => (&slice[Ipv6Header::LEN..], LenSource::Slice, true),
+            };
+
+        // slice extension headers
+        let (exts, ip_number, payload) =
+            match Ipv6ExtensionsSlice::from_slice(header.next_header(), header_payload) {
+                Ok(value) => value,
+                Err(err) => {
+                    use crate::err::ipv6_exts::HeaderSliceError as E;
+                    match err {
+                        // extension headers cut by the truncation are not decoded
+                        E::Len(_) if truncated => {
+                            (Default::default(), header.next_header(), header_payload)
+                        }
+                        E::Len(mut err) => {
+                            err.len_source = len_source;
+                            err.layer_start_offset += Ipv6Header::LEN;
+                            return Err(Len(err));
+                        }
+                        E::Content(err) => return Err(IpHeader(ip::HeaderError::Ipv6Ext(err))),
+                    }
+                }
+            };
+
+        let fragmented = exts.is_fragmenting_payload();
+        Ok((
+            InternetSlice::Ipv6(Ipv6Slice {
+                header,
+                exts,
+                payload: IpPayload {
+                    ip_number,
+                    fragmented,
+                    len_source,
+                    payload,
+                },
+            }),
+            truncated,
+        ))
+    }
+
+    /// Source port of a quoted TCP or UDP packet.
+    ///
+    /// The port is read directly from the quoted data and is therefor also
+    /// available if the transport header is truncated (only the first 4
+    /// bytes of it are required). `None` is returned for other protocols,
+    /// fragmented payloads or if less data was quoted.
+    #[inline]
+    pub fn source_port(&self) -> Option<u16> {
+        self.ports().map(|(source, _)| source)
+    }
+
+    /// Destination port of a quoted TCP or UDP packet (see
+    /// [`IcmpQuoteSlice::source_port`] for details).
+    #[inline]
+    pub fn destination_port(&self) -> Option<u16> {
+        self.ports().map(|(_, destination)| destination)
+    }
+
+    /// Returns the source & destination port of quoted TCP & UDP packets.
+    fn ports(&self) -> Option<(u16, u16)> {
+        let ip_payload = self.ip.payload();
+        if ip_payload.fragmented || ip_payload.payload.len() < 4 {
+            return None;
+        }
+        match ip_payload.ip_number {
+            ip_number::TCP | ip_number::UDP => {
+                let data = ip_payload.payload;
+                Some((
+                    u16::from_be_bytes([data[0], data[1]]),
+                    u16::from_be_bytes([data[2], data[3]]),
+                ))
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::err::ip::{HeaderError, SliceError};
+    use alloc::{format, vec::Vec};
+
+    fn ipv4(protocol: IpNumber, payload_len: usize) -> Ipv4Header {
+        let mut header =
+            Ipv4Header::new(payload_len as u16, 1, protocol, [1, 2, 3, 4], [5, 6, 7, 8]).unwrap();
+        header.header_checksum = header.calc_header_checksum();
+        header
+    }
+
+    fn ipv6(next_header: IpNumber, payload_len: usize) -> Ipv6Header {
+        Ipv6Header {
+            traffic_class: 0,
+            flow_label: Ipv6FlowLabel::ZERO,
+            payload_length: payload_len as u16,
+            next_header,
+            hop_limit: 1,
+            source: [1; 16],
+            destination: [2; 16],
+        }
+    }
+
+    fn tcp_bytes() -> Vec<u8> {
+        let mut result = Vec::new();
+        TcpHeader::new(1234, 443, 1, 4000)
+            .write(&mut result)
+            .unwrap();
+        result.extend_from_slice(&[1, 2, 3, 4]);
+        result
+    }
+
+    #[test]
+    fn debug_clone_eq() {
+        let mut data = Vec::new();
+        ipv4(ip_number::UDP, 8).write(&mut data).unwrap();
+        data.extend_from_slice(&[0; 8]);
+        let quote = IcmpQuoteSlice::from_slice(&data).unwrap();
+        assert_eq!(quote, quote.clone());
+        assert_eq!(
+            format!(
+                "IcmpQuoteSlice {{ ip: {:?}, transport: {:?}, payload: {:?}, truncated: {:?} }}",
+                quote.ip, quote.transport, quote.payload, quote.truncated
+            ),
+            format!("{:?}", quote)
+        );
+    }
+
+    #[test]
+    fn from_slice_ipv4() {
+        let tcp = tcp_bytes();
+        let header = ipv4(ip_number::TCP, tcp.len());
+        let mut data = Vec::new();
+        header.write(&mut data).unwrap();
+        data.extend_from_slice(&tcp);
+
+        // complete packet
+        {
+            let quote = IcmpQuoteSlice::from_slice(&data).unwrap();
+            assert!(!quote.truncated);
+            assert_eq!(header, quote.ip.ipv4().unwrap().header().to_header());
+            assert_eq!(LenSource::Ipv4HeaderTotalLen, quote.ip.payload().len_source);
+            assert_eq!(&tcp[..], quote.ip.payload().payload);
+            match quote.transport.as_ref().unwrap() {
+                TransportSlice::Tcp(tcp) => assert_eq!(443, tcp.destination_port()),
+                _ => panic!("expected tcp"),
+            }
+            assert_eq!(&[1, 2, 3, 4], quote.payload);
+            assert_eq!(Some(1234), quote.source_port());
+            assert_eq!(Some(443), quote.destination_port());
+        }
+
+        // data after the packet is ignored
+        {
+            let mut padded = data.clone();
+            padded.extend_from_slice(&[9, 9]);
+            let quote = IcmpQuoteSlice::from_slice(&padded).unwrap();
+            assert!(!quote.truncated);
+            assert_eq!(&[1, 2, 3, 4], quote.payload);
+        }
+
+        // truncated packets
+        for len in Ipv4Header::MIN_LEN..data.len() {
+            let quote = IcmpQuoteSlice::from_slice(&data[..len]).unwrap();
+            assert!(quote.truncated);
+            assert_eq!(header, quote.ip.ipv4().unwrap().header().to_header());
+            assert_eq!(ip_number::TCP, quote.ip.payload_ip_number());
+            assert_eq!(LenSource::Slice, quote.ip.payload().len_source);
+            assert_eq!(&data[Ipv4Header::MIN_LEN..len], quote.ip.payload().payload);
+
+            if len >= Ipv4Header::MIN_LEN + TcpHeader::MIN_LEN {
+                assert!(matches!(quote.transport, Some(TransportSlice::Tcp(_))));
+                assert_eq!(
+                    &data[Ipv4Header::MIN_LEN + TcpHeader::MIN_LEN..len],
+                    quote.payload
+                );
+            } else {
+                assert_eq!(None, quote.transport);
+                assert_eq!(&data[Ipv4Header::MIN_LEN..len], quote.payload);
+            }
+
+            if len >= Ipv4Header::MIN_LEN + 4 {
+                assert_eq!(Some(1234), quote.source_port());
+                assert_eq!(Some(443), quote.destination_port());
+            } else {
+                assert_eq!(None, quote.source_port());
+                assert_eq!(None, quote.destination_port());
+            }
+        }
+    }
+
+    #[test]
+    fn from_slice_ipv4_exts() {
+        let auth = IpAuthHeader::new(ip_number::UDP, 1, 2, &[3, 4, 5, 6]).unwrap();
+        let header = ipv4(ip_number::AUTH, auth.header_len() + UdpHeader::LEN);
+        let mut data = Vec::new();
+        header.write(&mut data).unwrap();
+        auth.write(&mut data).unwrap();
+        UdpHeader {
+            source_port: 53,
+            destination_port: 49152,
+            length: UdpHeader::LEN as u16,
+            checksum: 0,
+        }
+        .write(&mut data)
+        .unwrap();
+
+        // complete
+        {
+            let quote = IcmpQuoteSlice::from_slice(&data).unwrap();
+            assert!(!quote.truncated);
+            assert_eq!(
+                Some(auth.clone()),
+                quote
+                    .ip
+                    .ipv4()
+                    .unwrap()
+                    .extensions()
+                    .auth
+                    .map(|a| a.to_header())
+            );
+            assert!(matches!(quote.transport, Some(TransportSlice::Udp(_))));
+            assert_eq!(Some(53), quote.source_port());
+        }
+
+        // truncated in the auth header (extension headers are not decoded)
+        {
+            let len = Ipv4Header::MIN_LEN + auth.header_len() - 1;
+            let quote = IcmpQuoteSlice::from_slice(&data[..len]).unwrap();
+            assert!(quote.truncated);
+            assert!(quote.ip.ipv4().unwrap().extensions().is_empty());
+            assert_eq!(ip_number::AUTH, quote.ip.payload_ip_number());
+            assert_eq!(
+                Some(TransportSlice::Unknown(ip_number::AUTH)),
+                quote.transport
+            );
+            assert_eq!(None, quote.source_port());
+            assert_eq!(&data[Ipv4Header::MIN_LEN..len], quote.payload);
+        }
+
+        // len error in the extension headers of a complete packet
+        {
+            let header = ipv4(ip_number::AUTH, auth.header_len() - 1);
+            let mut data = Vec::new();
+            header.write(&mut data).unwrap();
+            data.extend_from_slice(&auth.to_bytes()[..auth.header_len() - 1]);
+            assert_eq!(
+                SliceError::Len(LenError {
+                    required_len: auth.header_len(),
+                    len: auth.header_len() - 1,
+                    len_source: LenSource::Ipv4HeaderTotalLen,
+                    layer: Layer::IpAuthHeader,
+                    layer_start_offset: Ipv4Header::MIN_LEN,
+                }),
+                IcmpQuoteSlice::from_slice(&data).unwrap_err()
+            );
+        }
+
+        // content error in the extension headers
+        {
+            let mut data = data.clone();
+            // set the auth header payload length to zero
+            data[Ipv4Header::MIN_LEN + 1] = 0;
+            assert!(matches!(
+                IcmpQuoteSlice::from_slice(&data).unwrap_err(),
+                SliceError::IpHeader(HeaderError::Ipv4Ext(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn from_slice_ipv6() {
+        let tcp = tcp_bytes();
+        let header = ipv6(ip_number::TCP, tcp.len());
+        let mut data = Vec::new();
+        header.write(&mut data).unwrap();
+        data.extend_from_slice(&tcp);
+
+        // complete packet
+        {
+            let quote = IcmpQuoteSlice::from_slice(&data).unwrap();
+            assert!(!quote.truncated);
+            assert_eq!(header, quote.ip.ipv6().unwrap().header().to_header());
+            assert_eq!(
+                LenSource::Ipv6HeaderPayloadLen,
+                quote.ip.payload().len_source
+            );
+            assert!(matches!(quote.transport, Some(TransportSlice::Tcp(_))));
+            assert_eq!(&[1, 2, 3, 4], quote.payload);
+            assert_eq!(Some(1234), quote.source_port());
+            assert_eq!(Some(443), quote.destination_port());
+        }
+
+        // truncated packets
+        for len in Ipv6Header::LEN..data.len() {
+            let quote = IcmpQuoteSlice::from_slice(&data[..len]).unwrap();
+            assert!(quote.truncated);
+            assert_eq!(LenSource::Slice, quote.ip.payload().len_source);
+            assert_eq!(&data[Ipv6Header::LEN..len], quote.ip.payload().payload);
+            assert_eq!(
+                len >= Ipv6Header::LEN + TcpHeader::MIN_LEN,
+                quote.transport.is_some()
+            );
+            assert_eq!(len >= Ipv6Header::LEN + 4, quote.source_port().is_some());
+        }
+
+        // truncated in an extension header
+        {
+            let mut data = Vec::new();
+            ipv6(ip_number::IPV6_HOP_BY_HOP, 8 + UdpHeader::LEN)
+                .write(&mut data)
+                .unwrap();
+            data.extend_from_slice(&[ip_number::UDP.0, 0, 0, 0, 0, 0, 0, 0]);
+            data.extend_from_slice(&[0; UdpHeader::LEN]);
+
+            let quote = IcmpQuoteSlice::from_slice(&data).unwrap();
+            assert!(!quote.truncated);
+            assert!(!quote.ip.ipv6().unwrap().extensions().is_empty());
+            assert!(matches!(quote.transport, Some(TransportSlice::Udp(_))));
+
+            let quote = IcmpQuoteSlice::from_slice(&data[..Ipv6Header::LEN + 7]).unwrap();
+            assert!(quote.truncated);
+            assert!(quote.ip.ipv6().unwrap().extensions().is_empty());
+            assert_eq!(ip_number::IPV6_HOP_BY_HOP, quote.ip.payload_ip_number());
+            assert_eq!(None, quote.source_port());
+            assert_eq!(&data[Ipv6Header::LEN..Ipv6Header::LEN + 7], quote.payload);
+
+            // len error in the extension headers of a complete packet
+            let mut data = data[..Ipv6Header::LEN + 7].to_vec();
+            data[4..6].copy_from_slice(&7u16.to_be_bytes());
+            assert_eq!(
+                SliceError::Len(LenError {
+                    required_len: 8,
+                    len: 7,
+                    len_source: LenSource::Ipv6HeaderPayloadLen,
+                    layer: Layer::Ipv6ExtHeader,
+                    layer_start_offset: Ipv6Header::LEN,
+                }),
+                IcmpQuoteSlice::from_slice(&data).unwrap_err()
+            );
+        }
+    }
+
+    #[test]
+    fn from_slice_transport() {
+        // icmpv4
+        {
+            let mut data = Vec::new();
+            ipv4(ip_number::ICMP, 12).write(&mut data).unwrap();
+            Icmpv4Header::new(Icmpv4Type::EchoRequest(IcmpEchoHeader { id: 1, seq: 2 }))
+                .write(&mut data)
+                .unwrap();
+            data.extend_from_slice(&[1, 2, 3, 4]);
+            let quote = IcmpQuoteSlice::from_slice(&data).unwrap();
+            match &quote.transport {
+                Some(TransportSlice::Icmpv4(icmp)) => assert_eq!(&[1, 2, 3, 4], icmp.payload()),
+                _ => panic!("expected icmpv4"),
+            }
+            assert!(quote.payload.is_empty());
+            assert_eq!(None, quote.source_port());
+        }
+
+        // icmpv6
+        {
+            let mut data = Vec::new();
+            ipv6(ip_number::IPV6_ICMP, 8).write(&mut data).unwrap();
+            Icmpv6Header::new(Icmpv6Type::EchoRequest(IcmpEchoHeader { id: 1, seq: 2 }))
+                .write(&mut data)
+                .unwrap();
+            let quote = IcmpQuoteSlice::from_slice(&data).unwrap();
+            assert!(matches!(quote.transport, Some(TransportSlice::Icmpv6(_))));
+            assert!(quote.payload.is_empty());
+        }
+
+        // gre, esp & unknown protocols
+        for (protocol, expected) in [
+            (ip_number::GRE, None),
+            (ip_number::ENCAP_SEC, None),
+            (
+                ip_number::EXP0,
+                Some(TransportSlice::Unknown(ip_number::EXP0)),
+            ),
+        ] {
+            let mut data = Vec::new();
+            ipv4(protocol, 8).write(&mut data).unwrap();
+            data.extend_from_slice(&[1, 2, 3, 4, 5, 6, 7, 8]);
+            let quote = IcmpQuoteSlice::from_slice(&data).unwrap();
+            assert_eq!(expected, quote.transport);
+            assert_eq!(quote.ip.payload().payload, quote.payload);
+            assert_eq!(None, quote.source_port());
+        }
+
+        // fragmented payloads are not decoded
+        {
+            let mut header = ipv4(ip_number::UDP, 8);
+            header.fragment_offset = IpFragOffset::try_new(1).unwrap();
+            header.header_checksum = header.calc_header_checksum();
+            let mut data = Vec::new();
+            header.write(&mut data).unwrap();
+            data.extend_from_slice(&[1, 2, 3, 4, 5, 6, 7, 8]);
+            let quote = IcmpQuoteSlice::from_slice(&data).unwrap();
+            assert_eq!(None, quote.transport);
+            assert_eq!(None, quote.source_port());
+            assert_eq!(None, quote.destination_port());
+            assert_eq!(&data[Ipv4Header::MIN_LEN..], quote.payload);
+        }
+    }
+
+    #[test]
+    fn from_slice_errors() {
+        // empty
+        assert_eq!(
+            SliceError::Len(LenError {
+                required_len: 1,
+                len: 0,
+                len_source: LenSource::Slice,
+                layer: Layer::IpHeader,
+                layer_start_offset: 0,
+            }),
+            IcmpQuoteSlice::from_slice(&[]).unwrap_err()
+        );
+
+        // unsupported version
+        assert_eq!(
+            SliceError::IpHeader(HeaderError::UnsupportedIpVersion { version_number: 5 }),
+            IcmpQuoteSlice::from_slice(&[5 << 4]).unwrap_err()
+        );
+
+        let mut ipv4_data = Vec::new();
+        ipv4(ip_number::UDP, 0).write(&mut ipv4_data).unwrap();
+
+        // ihl too small
+        {
+            let mut data = ipv4_data.clone();
+            data[0] = (4 << 4) | 4;
+            assert_eq!(
+                SliceError::IpHeader(HeaderError::Ipv4HeaderLengthSmallerThanHeader { ihl: 4 }),
+                IcmpQuoteSlice::from_slice(&data).unwrap_err()
+            );
+        }
+
+        // ipv4 header incomplete
+        for len in 1..Ipv4Header::MIN_LEN {
+            assert_eq!(
+                SliceError::Len(LenError {
+                    required_len: Ipv4Header::MIN_LEN,
+                    len,
+                    len_source: LenSource::Slice,
+                    layer: Layer::Ipv4Header,
+                    layer_start_offset: 0,
+                }),
+                IcmpQuoteSlice::from_slice(&ipv4_data[..len]).unwrap_err()
+            );
+        }
+
+        // total length smaller then the header
+        {
+            let mut data = ipv4_data.clone();
+            data[2..4].copy_from_slice(&19u16.to_be_bytes());
+            assert_eq!(
+                SliceError::Len(LenError {
+                    required_len: Ipv4Header::MIN_LEN,
+                    len: 19,
+                    len_source: LenSource::Ipv4HeaderTotalLen,
+                    layer: Layer::Ipv4Packet,
+                    layer_start_offset: 0,
+                }),
+                IcmpQuoteSlice::from_slice(&data).unwrap_err()
+            );
+        }
+
+        // ipv6 header incomplete
+        {
+            let mut data = Vec::new();
+            ipv6(ip_number::UDP, 0).write(&mut data).unwrap();
+            for len in 1..Ipv6Header::LEN {
+                assert_eq!(
+                    SliceError::Len(LenError {
+                        required_len: Ipv6Header::LEN,
+                        len,
+                        len_source: LenSource::Slice,
+                        layer: Layer::Ipv6Header,
+                        layer_start_offset: 0,
+                    }),
+                    IcmpQuoteSlice::from_slice(&data[..len]).unwrap_err()
+                );
+            }
+        }
+    }
+}
@@ -331,6 +331,25 @@ impl<'a> Icmpv4Slice<'a> {
         }
     }
 
+    /// Slices the original packet quoted in the payload of ICMPv4 error
+    /// messages ([`Icmpv4Type::DestinationUnreachable`],
+    /// [`Icmpv4Type::Redirect`], [`Icmpv4Type::TimeExceeded`] &
+    /// [`Icmpv4Type::ParameterProblem`]) as an [`IcmpQuoteSlice`].
+    ///
+    /// The quoted packet is usually truncated (see [`IcmpQuoteSlice`]).
+    /// `None` is returned for all other message types.
+    ///
+    /// The `layer_start_offset` of returned errors is relative to the
+    /// start of the ICMPv4 payload.
+    pub fn quoted_packet(&self) -> Option<Result<IcmpQuoteSlice<'a>, err::ip::SliceError>> {
+        match self.type_u8() {
+            TYPE_DEST_UNREACH | TYPE_REDIRECT | TYPE_TIME_EXCEEDED | TYPE_PARAMETER_PROBLEM => {
+                Some(IcmpQuoteSlice::from_slice(self.payload()))
+            }
+            _ => None,
+        }
+    }
+
     /// Returns the slice containing the ICMPv4 packet.
     #[inline]
     pub fn slice(&self) -> &'a [u8] {
@@ -884,6 +903,70 @@ mod test {
         }
     }
 
+    #[test]
+    fn quoted_packet() {
+        use Icmpv4Type::*;
+
+        // original packet (only the ip header & 8 bytes of the tcp header)
+        let mut quoted = Vec::new();
+        {
+            let mut ipv4 =
+                Ipv4Header::new(100, 1, ip_number::TCP, [1, 2, 3, 4], [5, 6, 7, 8]).unwrap();
+            ipv4.header_checksum = ipv4.calc_header_checksum();
+            ipv4.write(&mut quoted).unwrap();
+            let mut tcp = Vec::new();
+            TcpHeader::new(49152, 80, 1, 2).write(&mut tcp).unwrap();
+            quoted.extend_from_slice(&tcp[..8]);
+        }
+
+        let quoting_types = [
+            DestinationUnreachable(DestUnreachableHeader::Port),
+            Redirect(RedirectHeader {
+                code: RedirectCode::RedirectForHost,
+                gateway_internet_address: [9, 10, 11, 12],
+            }),
+            TimeExceeded(TimeExceededCode::TtlExceededInTransit),
+            ParameterProblem(ParameterProblemHeader::PointerIndicatesError(1)),
+        ];
+        for t in quoting_types {
+            let mut bytes = Vec::new();
+            Icmpv4Header::new(t).write(&mut bytes).unwrap();
+            bytes.extend_from_slice(&quoted);
+
+            let slice = Icmpv4Slice::from_slice(&bytes).unwrap();
+            let quote = slice.quoted_packet().unwrap().unwrap();
+            assert_eq!(IcmpQuoteSlice::from_slice(&quoted).unwrap(), quote);
+            assert!(quote.truncated);
+            assert_eq!(None, quote.transport);
+            assert_eq!(Some(49152), quote.source_port());
+            assert_eq!(Some(80), quote.destination_port());
+
+            // errors are forwarded
+            let slice = Icmpv4Slice::from_slice(&bytes[..Icmpv4Header::MIN_LEN + 1]).unwrap();
+            assert!(slice.quoted_packet().unwrap().is_err());
+        }
+
+        // other types contain no quoted packet
+        let other_types = [
+            EchoRequest(IcmpEchoHeader { id: 1, seq: 2 }),
+            EchoReply(IcmpEchoHeader { id: 1, seq: 2 }),
+            Unknown {
+                type_u8: TYPE_ROUTER_ADVERTISEMENT,
+                code_u8: 0,
+                bytes5to8: [0; 4],
+            },
+        ];
+        for t in other_types {
+            let mut bytes = Vec::new();
+            Icmpv4Header::new(t).write(&mut bytes).unwrap();
+            bytes.extend_from_slice(&quoted);
+            assert_eq!(
+                None,
+                Icmpv4Slice::from_slice(&bytes).unwrap().quoted_packet()
+            );
+        }
+    }
+
     proptest! {
         #[test]
         fn clone_eq(bytes in any::<[u8;20]>()) {
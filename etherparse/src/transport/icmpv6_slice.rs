@@ -188,6 +188,27 @@ impl<'a> Icmpv6Slice<'a> {
         // at least the length of Icmpv6Header::MIN_LEN(8).
         unsafe { core::slice::from_raw_parts(self.slice.as_ptr().add(8), self.slice.len() - 8) }
     }
+
+    /// Slices the original packet quoted in the payload of ICMPv6 error
+    /// messages ([`Icmpv6Type::DestinationUnreachable`],
+    /// [`Icmpv6Type::PacketTooBig`], [`Icmpv6Type::TimeExceeded`] &
+    /// [`Icmpv6Type::ParameterProblem`]) as an [`IcmpQuoteSlice`].
+    ///
+    /// The quoted packet is usually truncated (see [`IcmpQuoteSlice`]).
+    /// `None` is returned for all other message types.
+    ///
+    /// The `layer_start_offset` of returned errors is relative to the
+    /// start of the ICMPv6 payload.
+    pub fn quoted_packet(&self) -> Option<Result<IcmpQuoteSlice<'a>, err::ip::SliceError>> {
+        use icmpv6::*;
+        match self.type_u8() {
+            TYPE_DST_UNREACH
+            | TYPE_PACKET_TOO_BIG
+            | TYPE_TIME_EXCEEDED
+            | TYPE_PARAMETER_PROBLEM => Some(IcmpQuoteSlice::from_slice(self.payload())),
+            _ => None,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -603,6 +624,79 @@ mod test {
         }
     }
 
+    #[test]
+    fn quoted_packet() {
+        // original packet (truncated within the udp payload)
+        let mut quoted = Vec::new();
+        Ipv6Header {
+            traffic_class: 0,
+            flow_label: Ipv6FlowLabel::ZERO,
+            payload_length: (UdpHeader::LEN + 100) as u16,
+            next_header: ip_number::UDP,
+            hop_limit: 1,
+            source: [1; 16],
+            destination: [2; 16],
+        }
+        .write(&mut quoted)
+        .unwrap();
+        UdpHeader {
+            source_port: 1234,
+            destination_port: 53,
+            length: (UdpHeader::LEN + 100) as u16,
+            checksum: 0,
+        }
+        .write(&mut quoted)
+        .unwrap();
+        quoted.extend_from_slice(&[1, 2, 3, 4]);
+
+        let quoting_types = [
+            DestinationUnreachable(DestUnreachableCode::Port),
+            PacketTooBig { mtu: 1280 },
+            TimeExceeded(TimeExceededCode::HopLimitExceeded),
+            ParameterProblem(ParameterProblemHeader {
+                code: ParameterProblemCode::ErroneousHeaderField,
+                pointer: 4,
+            }),
+        ];
+        for t in quoting_types {
+            let mut bytes = Vec::new();
+            Icmpv6Header::new(t).write(&mut bytes).unwrap();
+            bytes.extend_from_slice(&quoted);
+
+            let slice = Icmpv6Slice::from_slice(&bytes).unwrap();
+            let quote = slice.quoted_packet().unwrap().unwrap();
+            assert_eq!(IcmpQuoteSlice::from_slice(&quoted).unwrap(), quote);
+            assert!(quote.truncated);
+            assert_eq!(Some(1234), quote.source_port());
+            assert_eq!(Some(53), quote.destination_port());
+            assert_eq!(&[1, 2, 3, 4], quote.payload);
+
+            // errors are forwarded
+            let slice = Icmpv6Slice::from_slice(&bytes[..t.header_len() + 1]).unwrap();
+            assert!(slice.quoted_packet().unwrap().is_err());
+        }
+
+        // other types contain no quoted packet
+        let other_types = [
+            EchoRequest(IcmpEchoHeader { id: 1, seq: 2 }),
+            EchoReply(IcmpEchoHeader { id: 1, seq: 2 }),
+            Unknown {
+                type_u8: TYPE_ROUTER_ADVERTISEMENT,
+                code_u8: 0,
+                bytes5to8: [0; 4],
+            },
+        ];
+        for t in other_types {
+            let mut bytes = Vec::new();
+            Icmpv6Header::new(t).write(&mut bytes).unwrap();
+            bytes.extend_from_slice(&quoted);
+            assert_eq!(
+                None,
+                Icmpv6Slice::from_slice(&bytes).unwrap().quoted_packet()
+            );
+        }
+    }
+
     #[test]
     fn debug() {
         let data = [0u8; 8];
@@ -1,4 +1,18 @@
+pub mod geneve_header;
+pub mod geneve_header_slice;
+pub mod geneve_option;
+pub mod geneve_options;
+pub mod geneve_options_iterator;
+pub mod gtpu_extension_header;
+pub mod gtpu_extension_header_type;
+pub mod gtpu_extension_headers;
+pub mod gtpu_extension_headers_iterator;
+pub mod gtpu_header;
+pub mod gtpu_header_slice;
+pub mod gtpu_message_type;
+pub mod gtpu_pdu_session_container;
 pub mod icmp_echo_header;
+pub mod icmp_quote_slice;
 /// Module containing ICMPv4 related types and constants.
 pub mod icmpv4;
 pub mod icmpv4_header;
@@ -22,3 +36,7 @@ pub mod transport_slice;
 pub mod udp_header;
 pub mod udp_header_slice;
 pub mod udp_slice;
+pub mod vni;
+pub mod vxlan_gpe_next_protocol;
+pub mod vxlan_header;
+pub mod vxlan_header_slice;
@@ -120,18 +120,33 @@ impl UdpHeader {
     }
 
     /// Calculate an udp header given an ipv6 header and the payload
+    ///
+    /// Payloads bigger then the 16 bit `length` field are only allowed if
+    /// the IPv6 header belongs to a jumbogram (RFC 2675), meaning its
+    /// `payload_length` is 0 and it is followed by a hop-by-hop options
+    /// header (that contains the "Jumbo Payload" option, see
+    /// [`Ipv6Extensions::set_payload_length`]). In this case the `length`
+    /// field is set to 0 if the length does not fit and the actual length
+    /// is used in the checksum calculation.
     pub fn with_ipv6_checksum(
         source_port: u16,
         destination_port: u16,
         ip_header: &Ipv6Header,
         payload: &[u8],
     ) -> Result<UdpHeader, ValueTooBigError<usize>> {
-        // check that the total length fits into the field
-        const MAX_PAYLOAD_LENGTH: usize = (core::u16::MAX as usize) - UdpHeader::LEN;
-        if MAX_PAYLOAD_LENGTH < payload.len() {
+        // check that the total length fits into the length field (or
+        // into the jumbo payload option in case of jumbograms)
+        let is_jumbogram =
+            0 == ip_header.payload_length && ip_number::IPV6_HOP_BY_HOP == ip_header.next_header;
+        let max_payload_length = if is_jumbogram {
+            (u32::MAX as usize) - UdpHeader::LEN
+        } else {
+            (u16::MAX as usize) - UdpHeader::LEN
+        };
+        if max_payload_length < payload.len() {
             return Err(ValueTooBigError {
                 actual: payload.len(),
-                max_allowed: MAX_PAYLOAD_LENGTH,
+                max_allowed: max_payload_length,
                 value_type: err::ValueType::UdpPayloadLengthIpv6,
             });
         }
@@ -139,7 +154,7 @@ impl UdpHeader {
         let mut result = UdpHeader {
             source_port,
             destination_port,
-            length: (UdpHeader::LEN + payload.len()) as u16, //payload plus udp header
+            length: UdpHeader::ipv6_length_field(payload.len()),
             checksum: 0,
         };
         result.checksum =
@@ -164,7 +179,7 @@ impl UdpHeader {
         payload: &[u8],
     ) -> Result<u16, err::ValueTooBigError<usize>> {
         //check that the total length fits into the field
-        const MAX_PAYLOAD_LENGTH: usize = (core::u32::MAX as usize) - UdpHeader::LEN;
+        const MAX_PAYLOAD_LENGTH: usize = (u32::MAX as usize) - UdpHeader::LEN;
         if MAX_PAYLOAD_LENGTH < payload.len() {
             return Err(err::ValueTooBigError {
                 actual: payload.len(),
@@ -176,19 +191,39 @@ impl UdpHeader {
         Ok(self.calc_checksum_ipv6_internal(source, destination, payload))
     }
 
+    /// Returns the value of the `length` field for an UDP packet in an
+    /// IPv6 packet with the given payload length (`0` for jumbograms,
+    /// RFC 2675).
+    #[inline]
+    pub(crate) fn ipv6_length_field(payload_len: usize) -> u16 {
+        let len = UdpHeader::LEN + payload_len;
+        if len > usize::from(u16::MAX) {
+            0
+        } else {
+            len as u16
+        }
+    }
+
     fn calc_checksum_ipv6_internal(
         &self,
         source: [u8; 16],
         destination: [u8; 16],
         payload: &[u8],
     ) -> u16 {
+        // in jumbograms (RFC 2675) the length field is set to zero and
+        // the actual length is used in the pseudo header
+        let len = if 0 == self.length && UdpHeader::LEN + payload.len() > usize::from(u16::MAX) {
+            (UdpHeader::LEN + payload.len()) as u32
+        } else {
+            u32::from(self.length)
+        };
         self.calc_checksum_post_ip(
             //pseudo header
             checksum::Sum16BitWords::new()
                 .add_16bytes(source)
                 .add_16bytes(destination)
                 .add_2bytes([0, ip_number::UDP.0])
-                .add_2bytes(self.length.to_be_bytes()),
+                .add_4bytes(len.to_be_bytes()),
             payload,
         )
     }
@@ -606,7 +641,8 @@ mod test {
             destination_port in any::<u16>(),
             ipv6 in ipv6_any(),
             payload in proptest::collection::vec(any::<u8>(), 0..20),
-            bad_len in ((core::u16::MAX as usize) - UdpHeader::LEN + 1)..=usize::MAX,
+            bad_len in ((u16::MAX as usize) - UdpHeader::LEN + 1)..=usize::MAX,
+            bad_jumbo_len in ((u32::MAX as usize) - UdpHeader::LEN + 1)..=usize::MAX,
         ) {
             // normal case
             assert_eq!(
@@ -672,7 +708,27 @@ mod test {
             }
 
             // length error case
-            {
+            for (ipv6, bad_len, max_allowed) in [
+                (
+                    // no jumbogram
+                    Ipv6Header {
+                        next_header: ip_number::UDP,
+                        ..ipv6.clone()
+                    },
+                    bad_len,
+                    (u16::MAX as usize) - UdpHeader::LEN,
+                ),
+                (
+                    // jumbogram
+                    Ipv6Header {
+                        payload_length: 0,
+                        next_header: ip_number::IPV6_HOP_BY_HOP,
+                        ..ipv6.clone()
+                    },
+                    bad_jumbo_len,
+                    (u32::MAX as usize) - UdpHeader::LEN,
+                ),
+            ] {
                 // SAFETY: In case the error is not triggered
                 //         a segmentation fault will be triggered.
                 let too_big_slice = unsafe {
@@ -694,7 +750,7 @@ mod test {
                     ).unwrap_err(),
                     ValueTooBigError{
                         actual: bad_len,
-                        max_allowed: usize::from(u16::MAX) - UdpHeader::LEN,
+                        max_allowed,
                         value_type: err::ValueType::UdpPayloadLengthIpv6,
                     }
                 );
@@ -702,6 +758,59 @@ mod test {
         }
     }
 
+    #[test]
+    fn with_ipv6_checksum_jumbogram() {
+        let ipv6 = Ipv6Header {
+            payload_length: 0,
+            next_header: ip_number::IPV6_HOP_BY_HOP,
+            source: [1; 16],
+            destination: [2; 16],
+            ..Default::default()
+        };
+        let payload: Vec<u8> = (0..70000u32).map(|v| v as u8).collect();
+
+        // without jumbogram the length is limited to the 16 bit length field
+        assert_eq!(
+            UdpHeader::with_ipv6_checksum(
+                1234,
+                5678,
+                &Ipv6Header {
+                    next_header: ip_number::UDP,
+                    ..ipv6.clone()
+                },
+                &payload
+            )
+            .unwrap_err(),
+            ValueTooBigError {
+                actual: payload.len(),
+                max_allowed: (u16::MAX as usize) - UdpHeader::LEN,
+                value_type: err::ValueType::UdpPayloadLengthIpv6,
+            }
+        );
+
+        let actual = UdpHeader::with_ipv6_checksum(1234, 5678, &ipv6, &payload).unwrap();
+        assert_eq!(0, actual.length);
+
+        // the pseudo header uses the actual length
+        let expected_checksum = checksum::Sum16BitWords::new()
+            .add_16bytes(ipv6.source)
+            .add_16bytes(ipv6.destination)
+            .add_2bytes([0, ip_number::UDP.0])
+            .add_4bytes(((UdpHeader::LEN + payload.len()) as u32).to_be_bytes())
+            .add_2bytes(1234u16.to_be_bytes())
+            .add_2bytes(5678u16.to_be_bytes())
+            .add_2bytes([0, 0])
+            .add_2bytes([0, 0])
+            .add_slice(&payload)
+            .to_ones_complement_with_no_zero()
+            .to_be();
+        assert_eq!(expected_checksum, actual.checksum);
+        assert_eq!(
+            Ok(expected_checksum),
+            actual.calc_checksum_ipv6(&ipv6, &payload)
+        );
+    }
+
     proptest! {
         #[test]
         fn calc_checksum_ipv6(
@@ -709,7 +818,7 @@ mod test {
             destination_port in any::<u16>(),
             ipv6 in ipv6_any(),
             payload in proptest::collection::vec(any::<u8>(), 0..20),
-            bad_len in ((core::u32::MAX as usize) - UdpHeader::LEN + 1)..=usize::MAX,
+            bad_len in ((u32::MAX as usize) - UdpHeader::LEN + 1)..=usize::MAX,
         ) {
             // normal case
             assert_eq!(
@@ -813,7 +922,7 @@ mod test {
             dummy_checksum in any::<u16>(),
             ipv6 in ipv6_any(),
             payload in proptest::collection::vec(any::<u8>(), 0..20),
-            bad_len in ((core::u32::MAX as usize) - UdpHeader::LEN + 1)..=usize::MAX,
+            bad_len in ((u32::MAX as usize) - UdpHeader::LEN + 1)..=usize::MAX,
         ) {
             // normal case
             {
@@ -905,7 +1014,7 @@ mod test {
                     ).unwrap_err(),
                     ValueTooBigError{
                         actual: bad_len,
-                        max_allowed: (core::u32::MAX as usize) - UdpHeader::LEN,
+                        max_allowed: (u32::MAX as usize) - UdpHeader::LEN,
                         value_type: ValueType::UdpPayloadLengthIpv6,
                     }
                 );
@@ -0,0 +1,253 @@
+use crate::err::ValueTooBigError;
+
+/// 24 bit unsigned integer containing the virtual network identifier
+/// (VNI) of a VXLAN or Geneve header (present in [`crate::VxlanHeader`]
+/// & [`crate::GeneveHeader`]).
+#[derive(Copy, Clone, Default, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Vni(u32);
+
+impl Vni {
+    /// Vni with value 0.
+    pub const ZERO: Vni = Vni(0);
+
+    /// Maximum value of a VNI.
+    pub const MAX_U32: u32 = 0xff_ffff;
+
+    /// Tries to create an [`Vni`] and checks that the passed value
+    /// is smaller or equal than [`Vni::MAX_U32`] (24 bit unsigned integer).
+    ///
+    /// In case the passed value is bigger then what can be represented in an 24 bit
+    /// integer an error is returned. Otherwise an `Ok` containing the [`Vni`].
+    ///
+    /// ```
+    /// use etherparse::Vni;
+    ///
+    /// let vni = Vni::try_new(2).unwrap();
+    /// assert_eq!(vni.value(), 2);
+    ///
+    /// // if a number that can not be represented in an 24 bit integer
+    /// // gets passed in an error is returned
+    /// use etherparse::err::{ValueTooBigError, ValueType};
+    /// assert_eq!(
+    ///     Vni::try_new(Vni::MAX_U32 + 1),
+    ///     Err(ValueTooBigError{
+    ///         actual: Vni::MAX_U32 + 1,
+    ///         max_allowed: Vni::MAX_U32,
+    ///         value_type: ValueType::Vni,
+    ///     })
+    /// );
+    /// ```
+    #[inline]
+    pub const fn try_new(value: u32) -> Result<Vni, ValueTooBigError<u32>> {
+        use crate::err::ValueType;
+        if value <= Vni::MAX_U32 {
+            Ok(Vni(value))
+        } else {
+            Err(ValueTooBigError {
+                actual: value,
+                max_allowed: Vni::MAX_U32,
+                value_type: ValueType::Vni,
+            })
+        }
+    }
+
+    /// Creates an [`Vni`] WITHOUT checking that the value
+    /// is smaller or equal than [`Vni::MAX_U32`] (24 bit unsigned integer).
+    /// The caller must gurantee that `value <= Vni::MAX_U32`.
+    ///
+    /// # Safety
+    ///
+    /// `value` must be smaller or equal than [`Vni::MAX_U32`]
+    /// otherwise the behaviour of functions or datastructures relying
+    /// on this pre-requirement is undefined.
+    #[inline]
+    pub const unsafe fn new_unchecked(value: u32) -> Vni {
+        debug_assert!(value <= Vni::MAX_U32);
+        Vni(value)
+    }
+
+    /// Returns the underlying unsigned 24 bit value as an `u32` value.
+    #[inline]
+    pub const fn value(self) -> u32 {
+        self.0
+    }
+}
+
+impl core::fmt::Display for Vni {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl From<Vni> for u32 {
+    #[inline]
+    fn from(value: Vni) -> Self {
+        value.0
+    }
+}
+
+impl TryFrom<u32> for Vni {
+    type Error = ValueTooBigError<u32>;
+
+    #[inline]
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        use crate::err::ValueType;
+        if value <= Vni::MAX_U32 {
+            Ok(Vni(value))
+        } else {
+            Err(Self::Error {
+                actual: value,
+                max_allowed: Vni::MAX_U32,
+                value_type: ValueType::Vni,
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use core::hash::{Hash, Hasher};
+    use proptest::prelude::*;
+    use std::format;
+
+    #[test]
+    fn derived_traits() {
+        // copy & clone
+        {
+            let a = Vni(2);
+            let b = a;
+            assert_eq!(a, b);
+            assert_eq!(a.clone(), a);
+        }
+
+        // default
+        {
+            let actual: Vni = Default::default();
+            assert_eq!(actual.value(), 0);
+        }
+
+        // debug
+        {
+            let a = Vni(2);
+            assert_eq!(format!("{:?}", a), format!("Vni(2)"));
+        }
+
+        // ord & partial ord
+        {
+            use core::cmp::Ordering;
+            let a = Vni(2);
+            let b = a;
+            assert_eq!(a.cmp(&b), Ordering::Equal);
+            assert_eq!(a.partial_cmp(&b), Some(Ordering::Equal));
+        }
+
+        // hash
+        {
+            use std::collections::hash_map::DefaultHasher;
+            let a = {
+                let mut hasher = DefaultHasher::new();
+                Vni(2).hash(&mut hasher);
+                hasher.finish()
+            };
+            let b = {
+                let mut hasher = DefaultHasher::new();
+                Vni(2).hash(&mut hasher);
+                hasher.finish()
+            };
+            assert_eq!(a, b);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn try_new(
+            valid_value in 0..=0xff_ffffu32,
+            invalid_value in 0x100_0000u32..=u32::MAX
+        ) {
+            use crate::err::{ValueType, ValueTooBigError};
+            assert_eq!(
+                valid_value,
+                Vni::try_new(valid_value).unwrap().value()
+            );
+            assert_eq!(
+                Vni::try_new(invalid_value).unwrap_err(),
+                ValueTooBigError{
+                    actual: invalid_value,
+                    max_allowed: 0xff_ffff,
+                    value_type:  ValueType::Vni
+                }
+            );
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn try_from(
+            valid_value in 0..=0xff_ffffu32,
+            invalid_value in 0x100_0000u32..=u32::MAX
+        ) {
+            use crate::err::{ValueType, ValueTooBigError};
+            // try_into
+            {
+                let actual: Vni = valid_value.try_into().unwrap();
+                assert_eq!(actual.value(), valid_value);
+
+                let err: Result<Vni, ValueTooBigError<u32>> = invalid_value.try_into();
+                assert_eq!(
+                    err.unwrap_err(),
+                    ValueTooBigError{
+                        actual: invalid_value,
+                        max_allowed: 0xff_ffff,
+                        value_type:  ValueType::Vni
+                    }
+                );
+            }
+            // try_from
+            {
+                assert_eq!(
+                    Vni::try_from(valid_value).unwrap().value(),
+                    valid_value
+                );
+
+                assert_eq!(
+                    Vni::try_from(invalid_value).unwrap_err(),
+                    ValueTooBigError{
+                        actual: invalid_value,
+                        max_allowed: 0xff_ffff,
+                        value_type:  ValueType::Vni
+                    }
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn new_unchecked(valid_value in 0..=0xff_ffffu32) {
+            assert_eq!(
+                valid_value,
+                unsafe {
+                    Vni::new_unchecked(valid_value).value()
+                }
+            );
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn fmt(valid_value in 0..=0xff_ffffu32) {
+            assert_eq!(format!("{}", Vni(valid_value)), format!("{}", valid_value));
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn from(valid_value in 0..=0xff_ffffu32,) {
+            let vni = Vni::try_new(valid_value).unwrap();
+            let actual: u32 = vni.into();
+            assert_eq!(actual, valid_value);
+        }
+    }
+}
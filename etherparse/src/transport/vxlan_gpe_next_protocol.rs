@@ -0,0 +1,140 @@
+/// Next protocol field of a VXLAN-GPE header, identifying the protocol
+/// of the encapsulated packet (draft-ietf-nvo3-vxlan-gpe).
+///
+/// You can access the underlying `u8` value by using `.0` and any `u8`
+/// can be converted to a `VxlanGpeNextProtocol`:
+///
+/// ```
+/// use etherparse::VxlanGpeNextProtocol;
+///
+/// assert_eq!(VxlanGpeNextProtocol::ETHERNET.0, 3);
+/// assert_eq!(VxlanGpeNextProtocol::ETHERNET, VxlanGpeNextProtocol(3));
+///
+/// // convert to VxlanGpeNextProtocol using the from & into trait
+/// let protocol: VxlanGpeNextProtocol = 1u8.into();
+/// assert_eq!(VxlanGpeNextProtocol::IPV4, protocol);
+///
+/// // convert to u8 using the from & into trait
+/// let num: u8 = VxlanGpeNextProtocol::IPV6.into();
+/// assert_eq!(2, num);
+/// ```
+#[derive(Default, PartialEq, Eq, Clone, Copy, Hash, Ord, PartialOrd)]
+pub struct VxlanGpeNextProtocol(pub u8);
+
+impl VxlanGpeNextProtocol {
+    /// Internet Protocol version 4.
+    pub const IPV4: VxlanGpeNextProtocol = Self(1);
+    /// Internet Protocol version 6.
+    pub const IPV6: VxlanGpeNextProtocol = Self(2);
+    /// Ethernet frame.
+    pub const ETHERNET: VxlanGpeNextProtocol = Self(3);
+    /// Network Service Header (RFC 8300).
+    pub const NSH: VxlanGpeNextProtocol = Self(4);
+    /// MPLS label stack.
+    pub const MPLS: VxlanGpeNextProtocol = Self(5);
+
+    /// Returns the ether type identifying the same protocol (if one
+    /// exists).
+    pub fn ether_type(self) -> Option<crate::EtherType> {
+        use crate::ether_type;
+        match self {
+            Self::IPV4 => Some(ether_type::IPV4),
+            Self::IPV6 => Some(ether_type::IPV6),
+            Self::ETHERNET => Some(ether_type::TRANSPARENT_ETHERNET_BRIDGING),
+            Self::MPLS => Some(ether_type::MPLS_UNICAST),
+            _ => None,
+        }
+    }
+}
+
+impl From<u8> for VxlanGpeNextProtocol {
+    #[inline]
+    fn from(val: u8) -> Self {
+        VxlanGpeNextProtocol(val)
+    }
+}
+
+impl From<VxlanGpeNextProtocol> for u8 {
+    #[inline]
+    fn from(val: VxlanGpeNextProtocol) -> Self {
+        val.0
+    }
+}
+
+impl core::fmt::Debug for VxlanGpeNextProtocol {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match *self {
+            Self::IPV4 => write!(f, "{} (IPv4)", self.0),
+            Self::IPV6 => write!(f, "{} (IPv6)", self.0),
+            Self::ETHERNET => write!(f, "{} (Ethernet)", self.0),
+            Self::NSH => write!(f, "{} (NSH)", self.0),
+            Self::MPLS => write!(f, "{} (MPLS)", self.0),
+            _ => write!(f, "{}", self.0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::ether_type;
+    use alloc::format;
+
+    #[test]
+    fn to_u8() {
+        assert_eq!(1, u8::from(VxlanGpeNextProtocol::IPV4));
+        assert_eq!(2, u8::from(VxlanGpeNextProtocol::IPV6));
+        assert_eq!(5, u8::from(VxlanGpeNextProtocol::MPLS));
+    }
+
+    #[test]
+    fn from_u8() {
+        assert_eq!(
+            VxlanGpeNextProtocol::from(3),
+            VxlanGpeNextProtocol::ETHERNET
+        );
+        assert_eq!(VxlanGpeNextProtocol::from(4), VxlanGpeNextProtocol::NSH);
+        assert_eq!(VxlanGpeNextProtocol::from(123), VxlanGpeNextProtocol(123));
+    }
+
+    #[test]
+    fn ether_type() {
+        let pairs = &[
+            (VxlanGpeNextProtocol::IPV4, Some(ether_type::IPV4)),
+            (VxlanGpeNextProtocol::IPV6, Some(ether_type::IPV6)),
+            (
+                VxlanGpeNextProtocol::ETHERNET,
+                Some(ether_type::TRANSPARENT_ETHERNET_BRIDGING),
+            ),
+            (VxlanGpeNextProtocol::NSH, None),
+            (VxlanGpeNextProtocol::MPLS, Some(ether_type::MPLS_UNICAST)),
+            (VxlanGpeNextProtocol(0), None),
+        ];
+        for (input, expected) in pairs {
+            assert_eq!(*expected, input.ether_type());
+        }
+    }
+
+    #[test]
+    fn dbg() {
+        let pairs = &[
+            (VxlanGpeNextProtocol::IPV4, "1 (IPv4)"),
+            (VxlanGpeNextProtocol::IPV6, "2 (IPv6)"),
+            (VxlanGpeNextProtocol::ETHERNET, "3 (Ethernet)"),
+            (VxlanGpeNextProtocol::NSH, "4 (NSH)"),
+            (VxlanGpeNextProtocol::MPLS, "5 (MPLS)"),
+            (VxlanGpeNextProtocol(123), "123"),
+        ];
+
+        for (input, expected) in pairs {
+            assert_eq!(expected, &format!("{:?}", input));
+        }
+    }
+
+    #[test]
+    fn clone_eq_default() {
+        let value = VxlanGpeNextProtocol::NSH;
+        assert_eq!(value, value.clone());
+        assert_eq!(VxlanGpeNextProtocol(0), VxlanGpeNextProtocol::default());
+    }
+}
@@ -0,0 +1,234 @@
+use crate::{err::Layer, err::SliceWriteSpaceError, *};
+
+/// VXLAN header (Virtual eXtensible Local Area Network, RFC 7348) or
+/// VXLAN-GPE header (Generic Protocol Extension, draft-ietf-nvo3-vxlan-gpe).
+///
+/// VXLAN headers are transported in UDP datagrams with the destination
+/// port [`VxlanHeader::UDP_PORT`] (4789) and are followed by an ethernet
+/// frame. VXLAN-GPE headers use the destination port
+/// [`VxlanHeader::GPE_UDP_PORT`] (4790) and identify the payload via
+/// a "next protocol" field.
+///
+/// The header is serialized as a VXLAN-GPE header if `gpe_next_protocol`
+/// is set to `Some`.
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Default)]
+pub struct VxlanHeader {
+    /// Virtual network identifier.
+    pub vni: Vni,
+    /// Protocol of the payload (only present in VXLAN-GPE headers).
+    pub gpe_next_protocol: Option<VxlanGpeNextProtocol>,
+}
+
+impl VxlanHeader {
+    /// Serialized length of a VXLAN header in bytes/octets.
+    pub const LEN: usize = 8;
+
+    /// UDP destination port assigned by IANA for VXLAN.
+    pub const UDP_PORT: u16 = 4789;
+
+    /// UDP destination port assigned by IANA for VXLAN-GPE.
+    pub const GPE_UDP_PORT: u16 = 4790;
+
+    /// Flag indicating that the VNI field is valid ("I" flag).
+    pub const FLAG_VNI: u8 = 0b0000_1000;
+
+    /// Flag indicating that the next protocol field is present ("P" flag,
+    /// VXLAN-GPE only).
+    pub const FLAG_NEXT_PROTOCOL: u8 = 0b0000_0100;
+
+    /// Flag indicating that the packet contains an OAM message ("O" flag,
+    /// VXLAN-GPE only).
+    pub const FLAG_GPE_OAM: u8 = 0b0000_0001;
+
+    /// Creates a VXLAN header with the given VNI.
+    #[inline]
+    pub fn new(vni: Vni) -> VxlanHeader {
+        VxlanHeader {
+            vni,
+            gpe_next_protocol: None,
+        }
+    }
+
+    /// Read a [`VxlanHeader`] from a slice and return the header & unused parts of the slice.
+    #[inline]
+    pub fn from_slice(slice: &[u8]) -> Result<(VxlanHeader, &[u8]), err::LenError> {
+        let header = VxlanHeaderSlice::from_slice(slice)?;
+        Ok((header.to_header(), &slice[VxlanHeader::LEN..]))
+    }
+
+    /// Serialize the header to a given slice. Returns the unused part of the slice.
+    pub fn write_to_slice<'a>(
+        &self,
+        slice: &'a mut [u8],
+    ) -> Result<&'a mut [u8], SliceWriteSpaceError> {
+        // length check
+        if slice.len() < VxlanHeader::LEN {
+            Err(SliceWriteSpaceError {
+                required_len: VxlanHeader::LEN,
+                len: slice.len(),
+                layer: Layer::VxlanHeader,
+                layer_start_offset: 0,
+            })
+        } else {
+            slice[..VxlanHeader::LEN].copy_from_slice(&self.to_bytes());
+            Ok(&mut slice[VxlanHeader::LEN..])
+        }
+    }
+
+    /// Writes a given VXLAN header to the current position of the write argument.
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn write<T: std::io::Write + Sized>(&self, writer: &mut T) -> Result<(), std::io::Error> {
+        writer.write_all(&self.to_bytes())
+    }
+
+    /// Length of the serialized header in bytes.
+    #[inline]
+    pub fn header_len(&self) -> usize {
+        VxlanHeader::LEN
+    }
+
+    /// Returns the serialized form of the header.
+    pub fn to_bytes(&self) -> [u8; 8] {
+        let vni_be = self.vni.value().to_be_bytes();
+        let (flags, next_protocol) = match self.gpe_next_protocol {
+            Some(p) => (VxlanHeader::FLAG_VNI | VxlanHeader::FLAG_NEXT_PROTOCOL, p.0),
+            None => (VxlanHeader::FLAG_VNI, 0),
+        };
+        [
+            flags,
+            0,
+            0,
+            next_protocol,
+            vni_be[1],
+            vni_be[2],
+            vni_be[3],
+            0,
+        ]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_gens::*;
+    use alloc::{format, vec::Vec};
+    use proptest::prelude::*;
+
+    #[test]
+    fn constants() {
+        assert_eq!(8, VxlanHeader::LEN);
+        assert_eq!(4789, VxlanHeader::UDP_PORT);
+        assert_eq!(4790, VxlanHeader::GPE_UDP_PORT);
+    }
+
+    proptest! {
+        #[test]
+        fn new(vni in vni_any()) {
+            assert_eq!(
+                VxlanHeader::new(vni),
+                VxlanHeader {
+                    vni,
+                    gpe_next_protocol: None,
+                }
+            );
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn from_slice(
+            input in vxlan_any(),
+            dummy_data in proptest::collection::vec(any::<u8>(), 0..20)
+        ) {
+            // serialize
+            let mut buffer: Vec<u8> = Vec::with_capacity(VxlanHeader::LEN + dummy_data.len());
+            input.write(&mut buffer).unwrap();
+            buffer.extend(&dummy_data[..]);
+
+            // calls with a valid result
+            {
+                let (result, rest) = VxlanHeader::from_slice(&buffer[..]).unwrap();
+                assert_eq!(input, result);
+                assert_eq!(&buffer[VxlanHeader::LEN..], rest);
+            }
+
+            // call with not enough data in the slice
+            for len in 0..VxlanHeader::LEN {
+                assert_eq!(
+                    VxlanHeader::from_slice(&buffer[..len]),
+                    Err(err::LenError{
+                        required_len: VxlanHeader::LEN,
+                        len,
+                        len_source: err::LenSource::Slice,
+                        layer: err::Layer::VxlanHeader,
+                        layer_start_offset: 0,
+                    })
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn write_to_slice(input in vxlan_any()) {
+            // normal write
+            {
+                let mut buffer = [0u8; VxlanHeader::LEN + 1];
+                let rest_len = input.write_to_slice(&mut buffer).unwrap().len();
+                assert_eq!(1, rest_len);
+                assert_eq!(input.to_bytes(), buffer[..VxlanHeader::LEN]);
+            }
+            // len error
+            for len in 0..VxlanHeader::LEN {
+                let mut buffer = [0u8; VxlanHeader::LEN];
+                assert_eq!(
+                    SliceWriteSpaceError {
+                        required_len: VxlanHeader::LEN,
+                        len,
+                        layer: Layer::VxlanHeader,
+                        layer_start_offset: 0,
+                    },
+                    input.write_to_slice(&mut buffer[..len]).unwrap_err()
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn to_bytes(input in vxlan_any()) {
+            let bytes = input.to_bytes();
+            assert_eq!(input.header_len(), bytes.len());
+            let vni_be = input.vni.value().to_be_bytes();
+            assert_eq!(
+                bytes,
+                [
+                    if input.gpe_next_protocol.is_some() { 0b0000_1100 } else { 0b0000_1000 },
+                    0,
+                    0,
+                    input.gpe_next_protocol.map(|v| v.0).unwrap_or(0),
+                    vni_be[1],
+                    vni_be[2],
+                    vni_be[3],
+                    0,
+                ]
+            );
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn clone_eq_dbg(input in vxlan_any()) {
+            assert_eq!(input, input.clone());
+            assert_eq!(
+                format!(
+                    "VxlanHeader {{ vni: {:?}, gpe_next_protocol: {:?} }}",
+                    input.vni,
+                    input.gpe_next_protocol,
+                ),
+                format!("{:?}", input)
+            );
+        }
+    }
+}
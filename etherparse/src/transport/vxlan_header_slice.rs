@@ -0,0 +1,225 @@
+use crate::*;
+use core::slice::from_raw_parts;
+
+/// A slice containing a VXLAN or VXLAN-GPE header of a network package.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VxlanHeaderSlice<'a> {
+    slice: &'a [u8],
+}
+
+impl<'a> VxlanHeaderSlice<'a> {
+    /// Creates a slice containing a VXLAN header.
+    pub fn from_slice(slice: &'a [u8]) -> Result<VxlanHeaderSlice<'a>, err::LenError> {
+        // check length
+        if slice.len() < VxlanHeader::LEN {
+            return Err(err::LenError {
+                required_len: VxlanHeader::LEN,
+                len: slice.len(),
+                len_source: err::LenSource::Slice,
+                layer: err::Layer::VxlanHeader,
+                layer_start_offset: 0,
+            });
+        }
+
+        Ok(VxlanHeaderSlice {
+            // SAFETY:
+            // Safe as slice length is checked to be at least
+            // VxlanHeader::LEN (8) before this.
+            slice: unsafe { from_raw_parts(slice.as_ptr(), VxlanHeader::LEN) },
+        })
+    }
+
+    /// Returns the slice containing the VXLAN header.
+    #[inline]
+    pub fn slice(&self) -> &'a [u8] {
+        self.slice
+    }
+
+    /// Returns the flags byte of the header.
+    #[inline]
+    pub fn flags(&self) -> u8 {
+        // SAFETY:
+        // Safe as the contructor checks that the slice has
+        // at least the length of VxlanHeader::LEN (8).
+        unsafe { *self.slice.get_unchecked(0) }
+    }
+
+    /// Returns true if the "I" flag is set (VNI field is valid).
+    #[inline]
+    pub fn vni_present(&self) -> bool {
+        0 != self.flags() & VxlanHeader::FLAG_VNI
+    }
+
+    /// Returns the virtual network identifier.
+    #[inline]
+    pub fn vni(&self) -> Vni {
+        // SAFETY:
+        // Safe as the contructor checks that the slice has
+        // at least the length of VxlanHeader::LEN (8) and the
+        // value is guaranteed to be a 24 bit value.
+        unsafe {
+            Vni::new_unchecked(u32::from_be_bytes([
+                0,
+                *self.slice.get_unchecked(4),
+                *self.slice.get_unchecked(5),
+                *self.slice.get_unchecked(6),
+            ]))
+        }
+    }
+
+    /// Returns true if the "P" flag is set, indicating that the header
+    /// is a VXLAN-GPE header containing a next protocol field.
+    #[inline]
+    pub fn is_gpe(&self) -> bool {
+        0 != self.flags() & VxlanHeader::FLAG_NEXT_PROTOCOL
+    }
+
+    /// Returns the version field of a VXLAN-GPE header.
+    #[inline]
+    pub fn gpe_version(&self) -> u8 {
+        (self.flags() >> 4) & 0b11
+    }
+
+    /// Returns true if the "O" flag of a VXLAN-GPE header is set
+    /// (payload is an OAM message).
+    #[inline]
+    pub fn gpe_oam(&self) -> bool {
+        0 != self.flags() & VxlanHeader::FLAG_GPE_OAM
+    }
+
+    /// Returns the next protocol field if the header is a VXLAN-GPE
+    /// header ("P" flag is set).
+    #[inline]
+    pub fn gpe_next_protocol(&self) -> Option<VxlanGpeNextProtocol> {
+        if self.is_gpe() {
+            // SAFETY:
+            // Safe as the contructor checks that the slice has
+            // at least the length of VxlanHeader::LEN (8).
+            Some(VxlanGpeNextProtocol(unsafe {
+                *self.slice.get_unchecked(3)
+            }))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the ether type of the payload.
+    ///
+    /// For a plain VXLAN header this is always
+    /// [`ether_type::TRANSPARENT_ETHERNET_BRIDGING`] (ethernet frame). For
+    /// VXLAN-GPE headers the ether type is derived from the next protocol
+    /// field (`None` if there is no matching ether type).
+    #[inline]
+    pub fn payload_ether_type(&self) -> Option<EtherType> {
+        match self.gpe_next_protocol() {
+            Some(p) => p.ether_type(),
+            None => Some(ether_type::TRANSPARENT_ETHERNET_BRIDGING),
+        }
+    }
+
+    /// Decode all the fields and copy the results to a [`VxlanHeader`] struct.
+    #[inline]
+    pub fn to_header(&self) -> VxlanHeader {
+        VxlanHeader {
+            vni: self.vni(),
+            gpe_next_protocol: self.gpe_next_protocol(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{test_gens::*, *};
+    use alloc::{format, vec::Vec};
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn from_slice(
+            input in vxlan_any(),
+            dummy_data in proptest::collection::vec(any::<u8>(), 0..20)
+        ) {
+            // serialize
+            let mut buffer: Vec<u8> = Vec::with_capacity(8 + dummy_data.len());
+            input.write(&mut buffer).unwrap();
+            buffer.extend(&dummy_data[..]);
+
+            // calls with a valid result
+            {
+                let result = VxlanHeaderSlice::from_slice(&buffer[..]).unwrap();
+                assert_eq!(&buffer[..8], result.slice());
+            }
+
+            // call with not enough data in the slice
+            for len in 0..8 {
+                assert_eq!(
+                    VxlanHeaderSlice::from_slice(&buffer[0..len]).unwrap_err(),
+                    err::LenError{
+                        required_len: VxlanHeader::LEN,
+                        len,
+                        len_source: err::LenSource::Slice,
+                        layer: err::Layer::VxlanHeader,
+                        layer_start_offset: 0,
+                    }
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn getters(input in vxlan_any()) {
+            let bytes = input.to_bytes();
+            let slice = VxlanHeaderSlice::from_slice(&bytes).unwrap();
+            assert!(slice.vni_present());
+            assert_eq!(input.vni, slice.vni());
+            assert_eq!(input.gpe_next_protocol.is_some(), slice.is_gpe());
+            assert_eq!(0, slice.gpe_version());
+            assert!(!slice.gpe_oam());
+            assert_eq!(input.gpe_next_protocol, slice.gpe_next_protocol());
+            assert_eq!(
+                match input.gpe_next_protocol {
+                    Some(p) => p.ether_type(),
+                    None => Some(ether_type::TRANSPARENT_ETHERNET_BRIDGING),
+                },
+                slice.payload_ether_type()
+            );
+        }
+    }
+
+    #[test]
+    fn gpe_flags() {
+        let bytes = [0b0010_1101, 0, 0, 4, 0x12, 0x34, 0x56, 0];
+        let slice = VxlanHeaderSlice::from_slice(&bytes).unwrap();
+        assert_eq!(0b0010_1101, slice.flags());
+        assert!(slice.vni_present());
+        assert!(slice.is_gpe());
+        assert_eq!(2, slice.gpe_version());
+        assert!(slice.gpe_oam());
+        assert_eq!(Some(VxlanGpeNextProtocol::NSH), slice.gpe_next_protocol());
+        assert_eq!(None, slice.payload_ether_type());
+        assert_eq!(0x123456, slice.vni().value());
+    }
+
+    proptest! {
+        #[test]
+        fn to_header(input in vxlan_any()) {
+            let bytes = input.to_bytes();
+            let slice = VxlanHeaderSlice::from_slice(&bytes).unwrap();
+            assert_eq!(input, slice.to_header());
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn clone_eq_dbg(input in vxlan_any()) {
+            let bytes = input.to_bytes();
+            let slice = VxlanHeaderSlice::from_slice(&bytes).unwrap();
+            assert_eq!(slice, slice.clone());
+            assert_eq!(
+                format!("VxlanHeaderSlice {{ slice: {:?} }}", slice.slice()),
+                format!("{:?}", slice)
+            );
+        }
+    }
+}
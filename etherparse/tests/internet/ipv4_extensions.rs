@@ -53,7 +53,12 @@ pub mod header {
     }
     proptest! {
         #[test]
-        fn read(auth in ip_auth_any()) {
+        fn read(
+            auth in ip_auth_any().prop_filter(
+                "next header must not be ESP",
+                |v| ENCAP_SEC != v.next_header
+            )
+        ) {
             // None
             {
                 let mut cursor = Cursor::new(&[]);
@@ -63,6 +68,7 @@ pub mod header {
                     actual,
                     Ipv4Extensions{
                         auth: None,
+                        esp: None,
                     }
                 );
             }
@@ -83,6 +89,7 @@ pub mod header {
                     actual,
                     Ipv4Extensions{
                         auth: Some(auth.clone()),
+                        esp: None,
                     }
                 );
             }
@@ -100,9 +107,12 @@ pub mod header {
         // None
         {
             let mut buffer = Vec::new();
-            Ipv4Extensions { auth: None }
-                .write(&mut buffer, UDP)
-                .unwrap();
+            Ipv4Extensions {
+                auth: None,
+                esp: None,
+            }
+            .write(&mut buffer, UDP)
+            .unwrap();
             assert_eq!(0, buffer.len());
         }
 
@@ -112,6 +122,7 @@ pub mod header {
             let mut buffer = Vec::with_capacity(auth_header.header_len());
             Ipv4Extensions {
                 auth: Some(auth_header.clone()),
+                esp: None,
             }
             .write(&mut buffer, AUTH)
             .unwrap();
@@ -126,6 +137,7 @@ pub mod header {
             let mut buffer = Vec::new();
             let err = Ipv4Extensions {
                 auth: Some(auth_header.clone()),
+                esp: None,
             }
             .write(&mut buffer, UDP)
             .unwrap_err();
@@ -142,6 +154,7 @@ pub mod header {
             let mut writer = TestWriter::with_max_size(auth_header.header_len() - 1);
             let err = Ipv4Extensions {
                 auth: Some(auth_header.clone()),
+                esp: None,
             }
             .write(&mut writer, AUTH)
             .unwrap_err();
@@ -152,14 +165,25 @@ pub mod header {
     #[test]
     fn header_len() {
         // None
-        assert_eq!(0, Ipv4Extensions { auth: None }.header_len());
+        assert_eq!(
+            0,
+            Ipv4Extensions {
+                auth: None,
+                esp: None
+            }
+            .header_len()
+        );
 
         // Some
         {
             let auth = IpAuthHeader::new(UDP, 0, 0, &[]).unwrap();
             assert_eq!(
                 auth.header_len(),
-                Ipv4Extensions { auth: Some(auth) }.header_len()
+                Ipv4Extensions {
+                    auth: Some(auth),
+                    esp: None
+                }
+                .header_len()
             );
         }
         // Some with paylaod
@@ -167,7 +191,11 @@ pub mod header {
             let auth = IpAuthHeader::new(UDP, 0, 0, &[1, 2, 3, 4]).unwrap();
             assert_eq!(
                 auth.header_len(),
-                Ipv4Extensions { auth: Some(auth) }.header_len()
+                Ipv4Extensions {
+                    auth: Some(auth),
+                    esp: None
+                }
+                .header_len()
             );
         }
     }
@@ -176,7 +204,10 @@ pub mod header {
     fn set_next_headers() {
         // None
         {
-            let mut exts = Ipv4Extensions { auth: None };
+            let mut exts = Ipv4Extensions {
+                auth: None,
+                esp: None,
+            };
             assert_eq!(UDP, exts.set_next_headers(UDP));
         }
 
@@ -184,6 +215,7 @@ pub mod header {
         {
             let mut exts = Ipv4Extensions {
                 auth: Some(IpAuthHeader::new(TCP, 0, 0, &[]).unwrap()),
+                esp: None,
             };
             assert_eq!(TCP, exts.auth.as_ref().unwrap().next_header);
             // change from TCP to UDP
@@ -197,13 +229,17 @@ pub mod header {
     fn next_header() {
         // None
         {
-            let exts = Ipv4Extensions { auth: None };
+            let exts = Ipv4Extensions {
+                auth: None,
+                esp: None,
+            };
             assert_eq!(UDP, exts.next_header(UDP).unwrap());
         }
         // Some
         {
             let exts = Ipv4Extensions {
                 auth: Some(IpAuthHeader::new(TCP, 0, 0, &[]).unwrap()),
+                esp: None,
             };
 
             // auth referenced
@@ -223,39 +259,134 @@ pub mod header {
     #[test]
     fn is_empty() {
         // empty
-        assert!(Ipv4Extensions { auth: None }.is_empty());
+        assert!(Ipv4Extensions {
+            auth: None,
+            esp: None
+        }
+        .is_empty());
 
         // auth
         assert_eq!(
             false,
             Ipv4Extensions {
                 auth: Some(IpAuthHeader::new(ip_number::UDP, 0, 0, &[]).unwrap()),
+                esp: None,
             }
             .is_empty()
         );
     }
 
+    #[test]
+    fn esp() {
+        let auth = IpAuthHeader::new(ENCAP_SEC, 0, 0, &[]).unwrap();
+        let esp = EspHeader {
+            spi: 1,
+            sequence_number: 2,
+        };
+        let exts = Ipv4Extensions {
+            auth: Some(auth.clone()),
+            esp: Some(esp.clone()),
+        };
+        assert_eq!(auth.header_len() + EspHeader::LEN, exts.header_len());
+        assert_eq!(Ok(ENCAP_SEC), exts.next_header(AUTH));
+        assert!(!exts.is_empty());
+
+        // set_next_headers
+        {
+            let mut exts = exts.clone();
+            exts.auth.as_mut().unwrap().next_header = UDP;
+            assert_eq!(AUTH, exts.set_next_headers(UDP));
+            assert_eq!(exts.auth.as_ref().unwrap().next_header, ENCAP_SEC);
+        }
+
+        // write & read back
+        let mut buffer = Vec::new();
+        exts.write(&mut buffer, AUTH).unwrap();
+        buffer.extend_from_slice(&[1, 2, 3]);
+        {
+            let (actual, next, rest) = Ipv4Extensions::from_slice(AUTH, &buffer).unwrap();
+            assert_eq!(exts, actual);
+            assert_eq!(ENCAP_SEC, next);
+            assert_eq!(&[1, 2, 3], rest);
+        }
+        {
+            let mut cursor = Cursor::new(&buffer);
+            let (actual, next) = Ipv4Extensions::read(&mut cursor, AUTH).unwrap();
+            assert_eq!(exts, actual);
+            assert_eq!(ENCAP_SEC, next);
+            assert_eq!(exts.header_len(), cursor.position() as usize);
+        }
+
+        // esp without auth
+        {
+            let (actual, next, rest) =
+                Ipv4Extensions::from_slice(ENCAP_SEC, &buffer[auth.header_len()..]).unwrap();
+            assert_eq!(None, actual.auth);
+            assert_eq!(Some(esp.clone()), actual.esp);
+            assert_eq!(ENCAP_SEC, next);
+            assert_eq!(&[1, 2, 3], rest);
+        }
+
+        // len error in esp header
+        {
+            use err::ip_auth::HeaderSliceError::Len;
+            assert_eq!(
+                Ipv4Extensions::from_slice(AUTH, &buffer[..auth.header_len() + 7]).unwrap_err(),
+                Len(err::LenError {
+                    required_len: EspHeader::LEN,
+                    len: 7,
+                    len_source: err::LenSource::Slice,
+                    layer: err::Layer::EspHeader,
+                    layer_start_offset: auth.header_len(),
+                })
+            );
+        }
+
+        // esp not referenced
+        {
+            use etherparse::err::ipv4_exts::ExtsWalkError::ExtNotReferenced;
+            let err = exts.write(&mut Vec::new(), ENCAP_SEC).unwrap_err();
+            assert_eq!(
+                err.content().unwrap(),
+                &ExtNotReferenced {
+                    missing_ext: IpNumber::AUTHENTICATION_HEADER,
+                }
+            );
+            let mut exts = exts.clone();
+            exts.auth.as_mut().unwrap().next_header = UDP;
+            let err = exts.write(&mut Vec::new(), AUTH).unwrap_err();
+            assert_eq!(
+                err.content().unwrap(),
+                &ExtNotReferenced {
+                    missing_ext: IpNumber::ENCAPSULATING_SECURITY_PAYLOAD,
+                }
+            );
+        }
+    }
+
     proptest! {
         #[test]
         fn debug(auth in ip_auth_any()) {
             // None
             assert_eq!(
-                &format!("Ipv4Extensions {{ auth: {:?} }}", Option::<IpAuthHeader>::None),
+                &format!("Ipv4Extensions {{ auth: {:?}, esp: None }}", Option::<IpAuthHeader>::None),
                 &format!(
                     "{:?}",
                     Ipv4Extensions {
                         auth: None,
+                        esp: None,
                     }
                 )
             );
 
             // Some
             assert_eq!(
-                &format!("Ipv4Extensions {{ auth: {:?} }}", Some(auth.clone())),
+                &format!("Ipv4Extensions {{ auth: {:?}, esp: None }}", Some(auth.clone())),
                 &format!(
                     "{:?}",
                     Ipv4Extensions {
                         auth: Some(auth.clone()),
+                        esp: None,
                     }
                 )
             );
@@ -269,11 +400,13 @@ pub mod header {
             {
                 let header = Ipv4Extensions{
                     auth: None,
+                    esp: None,
                 };
                 assert_eq!(
                     header.clone(),
                     Ipv4Extensions{
                         auth: None,
+                        esp: None,
                     }
                 );
             }
@@ -282,11 +415,13 @@ pub mod header {
             {
                 let header = Ipv4Extensions{
                     auth: Some(auth.clone()),
+                    esp: None,
                 };
                 assert_eq!(
                     header.clone(),
                     Ipv4Extensions{
                         auth: Some(auth.clone()),
+                        esp: None,
                     }
                 );
             }
@@ -299,7 +434,12 @@ mod slice {
 
     proptest! {
         #[test]
-        fn from_slice(auth in ip_auth_any()) {
+        fn from_slice(
+            auth in ip_auth_any().prop_filter(
+                "next header must not be ESP",
+                |v| ENCAP_SEC != v.next_header
+            )
+        ) {
             // None
             {
                 let buffer = [1,2,3,4];
@@ -308,6 +448,7 @@ mod slice {
                     slice,
                     Ipv4ExtensionsSlice{
                         auth: None,
+                        esp: None,
                     }
                 );
                 assert_eq!(next, UDP);
@@ -331,6 +472,7 @@ mod slice {
                         auth: Some(
                             IpAuthHeaderSlice::from_slice(&buffer).unwrap()
                         ),
+                        esp: None,
                     }
                 );
                 assert_eq!(next, auth.next_header);
@@ -361,9 +503,11 @@ mod slice {
             assert_eq!(
                 Ipv4ExtensionsSlice{
                     auth: None,
+                    esp: None,
                 }.to_header(),
                 Ipv4Extensions{
                     auth: None,
+                    esp: None,
                 }
             );
 
@@ -378,11 +522,13 @@ mod slice {
                     auth: Some(
                         IpAuthHeaderSlice::from_slice(&buffer).unwrap()
                     ),
+                    esp: None,
                 };
                 assert_eq!(
                     slice.to_header(),
                     Ipv4Extensions{
                         auth: Some(auth.clone()),
+                        esp: None,
                     }
                 );
             }
@@ -392,7 +538,11 @@ mod slice {
     #[test]
     fn is_empty() {
         // empty
-        assert!(Ipv4ExtensionsSlice { auth: None }.is_empty());
+        assert!(Ipv4ExtensionsSlice {
+            auth: None,
+            esp: None
+        }
+        .is_empty());
 
         // auth
         {
@@ -406,6 +556,7 @@ mod slice {
                 false,
                 Ipv4ExtensionsSlice {
                     auth: Some(IpAuthHeaderSlice::from_slice(&buffer).unwrap()),
+                    esp: None,
                 }
                 .is_empty()
             );
@@ -417,11 +568,12 @@ mod slice {
         fn debug(auth in ip_auth_any()) {
             // None
             assert_eq!(
-                &format!("Ipv4ExtensionsSlice {{ auth: {:?} }}", Option::<IpAuthHeader>::None),
+                &format!("Ipv4ExtensionsSlice {{ auth: {:?}, esp: None }}", Option::<IpAuthHeader>::None),
                 &format!(
                     "{:?}",
                     Ipv4ExtensionsSlice {
                         auth: None,
+                        esp: None,
                     }
                 )
             );
@@ -434,11 +586,12 @@ mod slice {
             };
             let auth_slice = IpAuthHeaderSlice::from_slice(&buffer).unwrap();
             assert_eq!(
-                &format!("Ipv4ExtensionsSlice {{ auth: {:?} }}", Some(auth_slice.clone())),
+                &format!("Ipv4ExtensionsSlice {{ auth: {:?}, esp: None }}", Some(auth_slice.clone())),
                 &format!(
                     "{:?}",
                     Ipv4ExtensionsSlice {
                         auth: Some(auth_slice.clone()),
+                        esp: None,
                     }
                 )
             );
@@ -452,11 +605,13 @@ mod slice {
             {
                 let header = Ipv4ExtensionsSlice{
                     auth: None,
+                    esp: None,
                 };
                 assert_eq!(
                     header.clone(),
                     Ipv4ExtensionsSlice{
                         auth: None,
+                        esp: None,
                     }
                 );
             }
@@ -471,11 +626,13 @@ mod slice {
                 let auth_slice = IpAuthHeaderSlice::from_slice(&buffer).unwrap();
                 let slice = Ipv4ExtensionsSlice {
                     auth: Some(auth_slice.clone()),
+                    esp: None,
                 };
                 assert_eq!(
                     slice.clone(),
                     Ipv4ExtensionsSlice{
                         auth: Some(auth_slice.clone()),
+                        esp: None,
                     }
                 );
             }
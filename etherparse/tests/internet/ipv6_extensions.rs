@@ -499,7 +499,7 @@ pub mod header {
             header_size in any::<u8>(),
             post_header in ip_number_any()
                 .prop_filter("Must be a non ipv6 header relevant ip number".to_owned(),
-                    |v| !EXTESION_KNOWN_IP_NUMBERS.iter().any(|&x| v == &x)
+                    |v| ENCAP_SEC != *v && MOBILITY != *v && HIP != *v && SHIM6 != *v && EXP0 != *v && EXP1 != *v && !EXTESION_KNOWN_IP_NUMBERS.iter().any(|&x| v == &x)
                 )
         ) {
             use err::ipv6_exts::{HeaderError::*, HeaderSliceError::*};
@@ -597,7 +597,7 @@ pub mod header {
             header_size in any::<u8>(),
             post_header in ip_number_any()
                 .prop_filter("Must be a non ipv6 header relevant ip number".to_owned(),
-                    |v| !EXTESION_KNOWN_IP_NUMBERS.iter().any(|&x| v == &x)
+                    |v| ENCAP_SEC != *v && MOBILITY != *v && HIP != *v && SHIM6 != *v && EXP0 != *v && EXP1 != *v && !EXTESION_KNOWN_IP_NUMBERS.iter().any(|&x| v == &x)
                 )
         ) {
             use err::ipv6_exts::HeaderError::*;
@@ -681,7 +681,7 @@ pub mod header {
             header_size in any::<u8>(),
             post_header in ip_number_any()
                 .prop_filter("Must be a non ipv6 header relevant ip number".to_owned(),
-                    |v| !EXTESION_KNOWN_IP_NUMBERS.iter().any(|&x| v == &x)
+                    |v| ENCAP_SEC != *v && MOBILITY != *v && HIP != *v && SHIM6 != *v && EXP0 != *v && EXP1 != *v && !EXTESION_KNOWN_IP_NUMBERS.iter().any(|&x| v == &x)
                 )
         ) {
             // no extension headers filled
@@ -825,6 +825,12 @@ pub mod header {
                     ),
                     fragment: Some(fragment.clone()),
                     auth: Some(auth.clone()),
+                    esp: None,
+                    shim6: None,
+                    mobility: None,
+                    hip: None,
+                    exp0: None,
+                    exp1: None,
                 };
                 assert_eq!(
                     exts.header_len(),
@@ -852,6 +858,12 @@ pub mod header {
                     ),
                     fragment: Some(fragment.clone()),
                     auth: Some(auth.clone()),
+                    esp: None,
+                    shim6: None,
+                    mobility: None,
+                    hip: None,
+                    exp0: None,
+                    exp1: None,
                 };
                 assert_eq!(
                     exts.header_len(),
@@ -878,7 +890,7 @@ pub mod header {
             final_destination_options in ipv6_raw_ext_any(),
             post_header in ip_number_any()
                 .prop_filter("Must be a non ipv6 header relevant ip number".to_owned(),
-                    |v| !EXTESION_KNOWN_IP_NUMBERS.iter().any(|&x| v == &x)
+                    |v| ENCAP_SEC != *v && MOBILITY != *v && HIP != *v && SHIM6 != *v && EXP0 != *v && EXP1 != *v && !EXTESION_KNOWN_IP_NUMBERS.iter().any(|&x| v == &x)
                 ),
         ) {
             // none filled
@@ -905,6 +917,12 @@ pub mod header {
                     ),
                     fragment: Some(fragment.clone()),
                     auth: Some(auth.clone()),
+                    esp: None,
+                    shim6: None,
+                    mobility: None,
+                    hip: None,
+                    exp0: None,
+                    exp1: None,
                 };
                 assert_eq!(IPV6_HOP_BY_HOP, exts.set_next_headers(post_header));
 
@@ -924,7 +942,7 @@ pub mod header {
             header_size in any::<u8>(),
             post_header in ip_number_any()
                 .prop_filter("Must be a non ipv6 header relevant ip number".to_owned(),
-                    |v| !EXTESION_KNOWN_IP_NUMBERS.iter().any(|&x| v == &x)
+                    |v| ENCAP_SEC != *v && MOBILITY != *v && HIP != *v && SHIM6 != *v && EXP0 != *v && EXP1 != *v && !EXTESION_KNOWN_IP_NUMBERS.iter().any(|&x| v == &x)
                 ),)
         {
             // test empty
@@ -1012,6 +1030,12 @@ pub mod header {
                 routing: None,
                 fragment: None,
                 auth: None,
+                esp: None,
+                shim6: None,
+                mobility: None,
+                hip: None,
+                exp0: None,
+                exp1: None,
             }
             .is_fragmenting_payload()
         );
@@ -1030,6 +1054,12 @@ pub mod header {
                     0
                 )),
                 auth: None,
+                esp: None,
+                shim6: None,
+                mobility: None,
+                hip: None,
+                exp0: None,
+                exp1: None,
             }
             .is_fragmenting_payload()
         );
@@ -1046,6 +1076,12 @@ pub mod header {
                 0
             )),
             auth: None,
+            esp: None,
+            shim6: None,
+            mobility: None,
+            hip: None,
+            exp0: None,
+            exp1: None,
         }
         .is_fragmenting_payload());
     }
@@ -1059,6 +1095,12 @@ pub mod header {
             routing: None,
             fragment: None,
             auth: None,
+            esp: None,
+            shim6: None,
+            mobility: None,
+            hip: None,
+            exp0: None,
+            exp1: None,
         }
         .is_empty());
 
@@ -1073,6 +1115,12 @@ pub mod header {
                 routing: None,
                 fragment: None,
                 auth: None,
+                esp: None,
+                shim6: None,
+                mobility: None,
+                hip: None,
+                exp0: None,
+                exp1: None,
             }
             .is_empty()
         );
@@ -1088,6 +1136,12 @@ pub mod header {
                 routing: None,
                 fragment: None,
                 auth: None,
+                esp: None,
+                shim6: None,
+                mobility: None,
+                hip: None,
+                exp0: None,
+                exp1: None,
             }
             .is_empty()
         );
@@ -1105,6 +1159,12 @@ pub mod header {
                 }),
                 fragment: None,
                 auth: None,
+                esp: None,
+                shim6: None,
+                mobility: None,
+                hip: None,
+                exp0: None,
+                exp1: None,
             }
             .is_empty()
         );
@@ -1123,6 +1183,12 @@ pub mod header {
                     0
                 )),
                 auth: None,
+                esp: None,
+                shim6: None,
+                mobility: None,
+                hip: None,
+                exp0: None,
+                exp1: None,
             }
             .is_empty()
         );
@@ -1136,22 +1202,600 @@ pub mod header {
                 routing: None,
                 fragment: None,
                 auth: Some(IpAuthHeader::new(ip_number::UDP, 0, 0, &[]).unwrap()),
+                esp: None,
+                shim6: None,
+                mobility: None,
+                hip: None,
+                exp0: None,
+                exp1: None,
             }
             .is_empty()
         );
     }
 
+    #[test]
+    fn set_srh() {
+        // new routing header
+        {
+            let mut exts = Ipv6Extensions::default();
+            exts.set_srh(1, 0x80, 0x1234, &[[1; 16], [2; 16]], &[])
+                .unwrap();
+            assert_eq!(
+                exts.routing,
+                Some(Ipv6RoutingExtensions {
+                    routing: Ipv6RawExtHeader::try_from_srh(
+                        ip_number::IPV6_NO_NEXT_HEADER,
+                        1,
+                        0x80,
+                        0x1234,
+                        &[[1; 16], [2; 16]],
+                        &[]
+                    )
+                    .unwrap(),
+                    final_destination_options: None,
+                })
+            );
+        }
+
+        // final destination options are kept
+        {
+            let final_destination_options =
+                Ipv6RawExtHeader::new_raw(ip_number::UDP, &[1, 2, 3, 4, 5, 6]).unwrap();
+            let mut exts = Ipv6Extensions {
+                routing: Some(Ipv6RoutingExtensions {
+                    routing: Ipv6RawExtHeader::new_raw(ip_number::UDP, &[1, 2, 3, 4, 5, 6])
+                        .unwrap(),
+                    final_destination_options: Some(final_destination_options.clone()),
+                }),
+                ..Default::default()
+            };
+            exts.set_srh(0, 0, 0, &[[1; 16]], &[]).unwrap();
+            let routing = exts.routing.as_ref().unwrap();
+            assert_eq!(
+                routing.final_destination_options,
+                Some(final_destination_options)
+            );
+            assert_eq!(Ok([1; 16]), routing.final_destination(&[0; 16]));
+
+            // next headers get set
+            assert_eq!(IPV6_ROUTE, exts.set_next_headers(ip_number::UDP));
+            assert_eq!(
+                IPV6_DEST_OPTIONS,
+                exts.routing.as_ref().unwrap().routing.next_header
+            );
+        }
+
+        // error
+        {
+            let mut exts = Ipv6Extensions::default();
+            assert_eq!(
+                Err(err::ipv6_exts::SrhWriteError::EmptySegmentList),
+                exts.set_srh(0, 0, 0, &[], &[])
+            );
+            assert_eq!(None, exts.routing);
+        }
+    }
+
+    #[test]
+    fn set_payload_length() {
+        use err::{ValueTooBigError, ValueType};
+
+        // normal payload length
+        {
+            let mut exts = Ipv6Extensions {
+                fragment: Some(Ipv6FragmentHeader::new(UDP, IpFragOffset::ZERO, false, 0)),
+                ..Default::default()
+            };
+            let mut header = Ipv6Header::default();
+            exts.set_payload_length(&mut header, 1234).unwrap();
+            assert_eq!(header.payload_length, 8 + 1234);
+            assert_eq!(None, exts.jumbo_payload_len());
+            assert_eq!(None, exts.hop_by_hop_options);
+
+            // maximum without a jumbo payload option
+            exts.set_payload_length(&mut header, 0xffff - 8).unwrap();
+            assert_eq!(header.payload_length, 0xffff);
+            assert_eq!(None, exts.hop_by_hop_options);
+        }
+
+        // jumbogram without hop-by-hop header
+        {
+            let mut exts = Ipv6Extensions::default();
+            let mut header = Ipv6Header {
+                next_header: UDP,
+                ..Default::default()
+            };
+            exts.set_payload_length(&mut header, 0x10000).unwrap();
+            assert_eq!(header.payload_length, 0);
+            assert_eq!(header.next_header, IPV6_HOP_BY_HOP);
+            let hop_by_hop = exts.hop_by_hop_options.as_ref().unwrap();
+            assert_eq!(hop_by_hop.next_header, UDP);
+            assert_eq!(hop_by_hop.header_len(), 8);
+            assert_eq!(Some(8 + 0x10000), exts.jumbo_payload_len());
+
+            // update of the existing option
+            exts.set_payload_length(&mut header, 0x20000).unwrap();
+            assert_eq!(header.payload_length, 0);
+            assert_eq!(exts.hop_by_hop_options.as_ref().unwrap().header_len(), 8);
+            assert_eq!(Some(8 + 0x20000), exts.jumbo_payload_len());
+
+            // back to a normal payload length (option is kept but ignored)
+            exts.set_payload_length(&mut header, 100).unwrap();
+            assert_eq!(header.payload_length, 8 + 100);
+        }
+
+        // jumbogram with an existing hop-by-hop header
+        {
+            let mut exts = Ipv6Extensions {
+                hop_by_hop_options: Some(Ipv6RawExtHeader::new_raw(UDP, &[0; 6]).unwrap()),
+                ..Default::default()
+            };
+            let mut header = Ipv6Header {
+                next_header: IPV6_HOP_BY_HOP,
+                ..Default::default()
+            };
+            exts.set_payload_length(&mut header, 0xffff).unwrap();
+            assert_eq!(header.payload_length, 0);
+            assert_eq!(header.next_header, IPV6_HOP_BY_HOP);
+            assert_eq!(exts.hop_by_hop_options.as_ref().unwrap().header_len(), 16);
+            assert_eq!(Some(16 + 0xffff), exts.jumbo_payload_len());
+        }
+
+        // no space left in the hop-by-hop header
+        {
+            let mut exts = Ipv6Extensions {
+                hop_by_hop_options: Some(Ipv6RawExtHeader::new_raw(UDP, &[0; 2046]).unwrap()),
+                ..Default::default()
+            };
+            let mut header = Ipv6Header::default();
+            assert_eq!(
+                Err(ValueTooBigError {
+                    actual: 2048 + 8 + 0x10000,
+                    max_allowed: 0xffff,
+                    value_type: ValueType::Ipv6PayloadLength,
+                }),
+                exts.set_payload_length(&mut header, 0x10000)
+            );
+        }
+
+        // too big for a jumbo payload option
+        {
+            let mut exts = Ipv6Extensions::default();
+            let mut header = Ipv6Header::default();
+            assert_eq!(
+                Err(ValueTooBigError {
+                    actual: usize::MAX,
+                    max_allowed: u32::MAX as usize,
+                    value_type: ValueType::Ipv6PayloadLength,
+                }),
+                exts.set_payload_length(&mut header, usize::MAX)
+            );
+        }
+    }
+
+    #[test]
+    fn esp() {
+        let exts = Ipv6Extensions {
+            fragment: Some(Ipv6FragmentHeader::new(
+                ENCAP_SEC,
+                IpFragOffset::ZERO,
+                false,
+                0,
+            )),
+            esp: Some(EspHeader {
+                spi: 1,
+                sequence_number: 2,
+            }),
+            ..Default::default()
+        };
+        assert_eq!(Ipv6FragmentHeader::LEN + EspHeader::LEN, exts.header_len());
+        assert_eq!(Ok(ENCAP_SEC), exts.next_header(IPV6_FRAG));
+        assert!(!exts.is_empty());
+
+        // set_next_headers
+        {
+            let mut exts = exts.clone();
+            exts.fragment.as_mut().unwrap().next_header = UDP;
+            assert_eq!(IPV6_FRAG, exts.set_next_headers(UDP));
+            assert_eq!(ENCAP_SEC, exts.fragment.as_ref().unwrap().next_header);
+        }
+
+        // write & read back (data after the esp header is not parsed)
+        let mut buffer = Vec::new();
+        exts.write(&mut buffer, IPV6_FRAG).unwrap();
+        buffer.extend_from_slice(&[IPV6_FRAG.0, 0, 0, 0]);
+        {
+            let (actual, next, rest) = Ipv6Extensions::from_slice(IPV6_FRAG, &buffer).unwrap();
+            assert_eq!(exts, actual);
+            assert_eq!(ENCAP_SEC, next);
+            assert_eq!(&buffer[exts.header_len()..], rest);
+        }
+        {
+            let mut cursor = Cursor::new(&buffer);
+            let (actual, next) = Ipv6Extensions::read(&mut cursor, IPV6_FRAG).unwrap();
+            assert_eq!(exts, actual);
+            assert_eq!(ENCAP_SEC, next);
+            assert_eq!(exts.header_len(), cursor.position() as usize);
+        }
+
+        // slice & iterator
+        {
+            let (slice, next, rest) = Ipv6ExtensionsSlice::from_slice(IPV6_FRAG, &buffer).unwrap();
+            assert_eq!(ENCAP_SEC, next);
+            assert_eq!(&buffer[exts.header_len()..], rest);
+            let iter_result: Vec<_> = slice.into_iter().collect();
+            assert_eq!(2, iter_result.len());
+            assert_eq!(
+                Ipv6ExtensionSlice::Esp(
+                    EspHeaderSlice::from_slice(&buffer[Ipv6FragmentHeader::LEN..]).unwrap()
+                ),
+                iter_result[1]
+            );
+        }
+
+        // len error in esp header
+        {
+            use err::ipv6_exts::HeaderSliceError::Len;
+            assert_eq!(
+                Ipv6Extensions::from_slice(IPV6_FRAG, &buffer[..Ipv6FragmentHeader::LEN + 7])
+                    .unwrap_err(),
+                Len(err::LenError {
+                    required_len: EspHeader::LEN,
+                    len: 7,
+                    len_source: err::LenSource::Slice,
+                    layer: err::Layer::EspHeader,
+                    layer_start_offset: Ipv6FragmentHeader::LEN,
+                })
+            );
+        }
+
+        // esp not referenced
+        {
+            use etherparse::err::ipv6_exts::ExtsWalkError::ExtNotReferenced;
+            let mut exts = exts.clone();
+            exts.fragment.as_mut().unwrap().next_header = UDP;
+            let err = exts.write(&mut Vec::new(), IPV6_FRAG).unwrap_err();
+            assert_eq!(
+                err.content().unwrap(),
+                &ExtNotReferenced {
+                    missing_ext: IpNumber::ENCAPSULATING_SECURITY_PAYLOAD,
+                }
+            );
+            assert_eq!(
+                Err(ExtNotReferenced {
+                    missing_ext: IpNumber::ENCAPSULATING_SECURITY_PAYLOAD,
+                }),
+                exts.next_header(IPV6_FRAG)
+            );
+        }
+    }
+
+    #[test]
+    fn mobility_hip_shim6() {
+        let raw = |data: u8| Ipv6RawExtHeader::new_raw(IPV6_NO_NEXT_HEADER, &[data; 6]).unwrap();
+        let mut exts = Ipv6Extensions {
+            routing: Some(Ipv6RoutingExtensions {
+                routing: raw(1),
+                final_destination_options: None,
+            }),
+            fragment: Some(Ipv6FragmentHeader::new(
+                IPV6_NO_NEXT_HEADER,
+                IpFragOffset::ZERO,
+                false,
+                0,
+            )),
+            shim6: Some(raw(2)),
+            mobility: Some(raw(3)),
+            hip: Some(raw(4)),
+            ..Default::default()
+        };
+        assert_eq!(4 * 8 + Ipv6FragmentHeader::LEN, exts.header_len());
+        assert!(!exts.is_empty());
+
+        // set_next_headers (shim6 in front of the fragment header,
+        // mobility & hip at the end)
+        assert_eq!(IPV6_ROUTE, exts.set_next_headers(UDP));
+        assert_eq!(SHIM6, exts.routing.as_ref().unwrap().routing.next_header);
+        assert_eq!(IPV6_FRAG, exts.shim6.as_ref().unwrap().next_header);
+        assert_eq!(MOBILITY, exts.fragment.as_ref().unwrap().next_header);
+        assert_eq!(HIP, exts.mobility.as_ref().unwrap().next_header);
+        assert_eq!(UDP, exts.hip.as_ref().unwrap().next_header);
+        assert_eq!(Ok(UDP), exts.next_header(IPV6_ROUTE));
+
+        // write & read back
+        let mut buffer = Vec::new();
+        exts.write(&mut buffer, IPV6_ROUTE).unwrap();
+        assert_eq!(exts.header_len(), buffer.len());
+        buffer.extend_from_slice(&[1, 2, 3, 4]);
+        {
+            let (actual, next, rest) = Ipv6Extensions::from_slice(IPV6_ROUTE, &buffer).unwrap();
+            assert_eq!(exts, actual);
+            assert_eq!(UDP, next);
+            assert_eq!(&[1, 2, 3, 4], rest);
+        }
+        {
+            let mut cursor = Cursor::new(&buffer);
+            let (actual, next) = Ipv6Extensions::read(&mut cursor, IPV6_ROUTE).unwrap();
+            assert_eq!(exts, actual);
+            assert_eq!(UDP, next);
+            assert_eq!(exts.header_len(), cursor.position() as usize);
+        }
+        {
+            let mut reader = etherparse::io::LimitedReader::new(
+                Cursor::new(&buffer),
+                buffer.len(),
+                err::LenSource::Slice,
+                0,
+                err::Layer::Ipv6Header,
+            );
+            let (actual, next) = Ipv6Extensions::read_limited(&mut reader, IPV6_ROUTE).unwrap();
+            assert_eq!(exts, actual);
+            assert_eq!(UDP, next);
+        }
+
+        // slice & iterator
+        {
+            let (slice, next, rest) = Ipv6ExtensionsSlice::from_slice(IPV6_ROUTE, &buffer).unwrap();
+            assert_eq!(UDP, next);
+            assert_eq!(&[1, 2, 3, 4], rest);
+            let ip_numbers: Vec<_> = slice.clone().into_iter().map(|e| e.ip_number()).collect();
+            assert_eq!(
+                vec![IPV6_ROUTE, SHIM6, IPV6_FRAG, MOBILITY, HIP],
+                ip_numbers
+            );
+            assert_eq!(Ok(exts.clone()), slice.to_chain().unwrap().to_extensions());
+        }
+
+        // repeated headers stop the parsing
+        for ip_number in [SHIM6, MOBILITY, HIP] {
+            let mut buffer = Vec::new();
+            buffer.extend_from_slice(
+                &Ipv6RawExtHeader::new_raw(ip_number, &[0; 6])
+                    .unwrap()
+                    .to_bytes(),
+            );
+            buffer.extend_from_slice(&Ipv6RawExtHeader::new_raw(UDP, &[0; 6]).unwrap().to_bytes());
+            let (actual, next, rest) = Ipv6Extensions::from_slice(ip_number, &buffer).unwrap();
+            assert_eq!(ip_number, next);
+            assert_eq!(&buffer[8..], rest);
+            assert_eq!(8, actual.header_len());
+
+            let mut cursor = Cursor::new(&buffer);
+            let (_, next) = Ipv6Extensions::read(&mut cursor, ip_number).unwrap();
+            assert_eq!(ip_number, next);
+            assert_eq!(8, cursor.position());
+        }
+
+        // headers not referenced
+        {
+            use etherparse::err::ipv6_exts::ExtsWalkError::ExtNotReferenced;
+            for missing_ext in [IpNumber::SHIM6, IpNumber::MOBILITY_HEADER, IpNumber::HIP] {
+                // skip the missing header by linking its predecessor to its successor
+                let mut exts = exts.clone();
+                match missing_ext {
+                    SHIM6 => exts.routing.as_mut().unwrap().routing.next_header = IPV6_FRAG,
+                    MOBILITY => exts.fragment.as_mut().unwrap().next_header = HIP,
+                    _ => exts.mobility.as_mut().unwrap().next_header = UDP,
+                }
+                assert_eq!(
+                    exts.write(&mut Vec::new(), IPV6_ROUTE)
+                        .unwrap_err()
+                        .content()
+                        .unwrap(),
+                    &ExtNotReferenced { missing_ext }
+                );
+                assert_eq!(
+                    Err(ExtNotReferenced { missing_ext }),
+                    exts.next_header(IPV6_ROUTE)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn experimental() {
+        let raw = |data: u8| Ipv6RawExtHeader::new_raw(IPV6_NO_NEXT_HEADER, &[data; 6]).unwrap();
+        let mut exts = Ipv6Extensions {
+            destination_options: Some(raw(1)),
+            hip: Some(raw(2)),
+            exp0: Some(raw(3)),
+            exp1: Some(raw(4)),
+            ..Default::default()
+        };
+        assert_eq!(4 * 8, exts.header_len());
+        assert!(!exts.is_empty());
+
+        // set_next_headers (experimental headers at the end)
+        assert_eq!(IPV6_DEST_OPTIONS, exts.set_next_headers(UDP));
+        assert_eq!(HIP, exts.destination_options.as_ref().unwrap().next_header);
+        assert_eq!(EXP0, exts.hip.as_ref().unwrap().next_header);
+        assert_eq!(EXP1, exts.exp0.as_ref().unwrap().next_header);
+        assert_eq!(UDP, exts.exp1.as_ref().unwrap().next_header);
+        assert_eq!(Ok(UDP), exts.next_header(IPV6_DEST_OPTIONS));
+
+        // write & read back
+        let mut buffer = Vec::new();
+        exts.write(&mut buffer, IPV6_DEST_OPTIONS).unwrap();
+        assert_eq!(exts.header_len(), buffer.len());
+        buffer.extend_from_slice(&[1, 2, 3, 4]);
+        {
+            let (actual, next, rest) =
+                Ipv6Extensions::from_slice(IPV6_DEST_OPTIONS, &buffer).unwrap();
+            assert_eq!(exts, actual);
+            assert_eq!(UDP, next);
+            assert_eq!(&[1, 2, 3, 4], rest);
+        }
+        {
+            let mut cursor = Cursor::new(&buffer);
+            let (actual, next) = Ipv6Extensions::read(&mut cursor, IPV6_DEST_OPTIONS).unwrap();
+            assert_eq!(exts, actual);
+            assert_eq!(UDP, next);
+            assert_eq!(exts.header_len(), cursor.position() as usize);
+        }
+        {
+            let mut reader = etherparse::io::LimitedReader::new(
+                Cursor::new(&buffer),
+                buffer.len(),
+                err::LenSource::Slice,
+                0,
+                err::Layer::Ipv6Header,
+            );
+            let (actual, next) =
+                Ipv6Extensions::read_limited(&mut reader, IPV6_DEST_OPTIONS).unwrap();
+            assert_eq!(exts, actual);
+            assert_eq!(UDP, next);
+        }
+
+        // slice & chain
+        {
+            let (slice, next, rest) =
+                Ipv6ExtensionsSlice::from_slice(IPV6_DEST_OPTIONS, &buffer).unwrap();
+            assert_eq!(UDP, next);
+            assert_eq!(&[1, 2, 3, 4], rest);
+            assert_eq!(Ok(exts.clone()), slice.to_chain().unwrap().to_extensions());
+        }
+
+        // repeated headers stop the parsing
+        for ip_number in [EXP0, EXP1] {
+            let mut buffer = Vec::new();
+            buffer.extend_from_slice(
+                &Ipv6RawExtHeader::new_raw(ip_number, &[0; 6])
+                    .unwrap()
+                    .to_bytes(),
+            );
+            buffer.extend_from_slice(&Ipv6RawExtHeader::new_raw(UDP, &[0; 6]).unwrap().to_bytes());
+            let (actual, next, rest) = Ipv6Extensions::from_slice(ip_number, &buffer).unwrap();
+            assert_eq!(ip_number, next);
+            assert_eq!(&buffer[8..], rest);
+            assert_eq!(8, actual.header_len());
+
+            let mut cursor = Cursor::new(&buffer);
+            let (_, next) = Ipv6Extensions::read(&mut cursor, ip_number).unwrap();
+            assert_eq!(ip_number, next);
+            assert_eq!(8, cursor.position());
+        }
+
+        // headers not referenced
+        {
+            use etherparse::err::ipv6_exts::ExtsWalkError::ExtNotReferenced;
+            for missing_ext in [
+                IpNumber::EXPERIMENTAL_AND_TESTING_0,
+                IpNumber::EXPERIMENTAL_AND_TESTING_1,
+            ] {
+                // skip the missing header by linking its predecessor to its successor
+                let mut exts = exts.clone();
+                match missing_ext {
+                    EXP0 => exts.hip.as_mut().unwrap().next_header = EXP1,
+                    _ => exts.exp0.as_mut().unwrap().next_header = UDP,
+                }
+                assert_eq!(
+                    exts.write(&mut Vec::new(), IPV6_DEST_OPTIONS)
+                        .unwrap_err()
+                        .content()
+                        .unwrap(),
+                    &ExtNotReferenced { missing_ext }
+                );
+                assert_eq!(
+                    Err(ExtNotReferenced { missing_ext }),
+                    exts.next_header(IPV6_DEST_OPTIONS)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn experimental_decoders_agree() {
+        for exp in [EXP0, EXP1] {
+            // ipv6 -> experimental header -> udp
+            let payload = [1, 2, 3, 4];
+            let exp_header = Ipv6RawExtHeader::new_raw(UDP, &[0; 6]).unwrap();
+            let udp = UdpHeader {
+                source_port: 5,
+                destination_port: 6,
+                length: (UdpHeader::LEN + payload.len()) as u16,
+                checksum: 0,
+            };
+            let ip = Ipv6Header {
+                payload_length: (exp_header.header_len() + udp.header_len() + payload.len()) as u16,
+                next_header: exp,
+                ..Default::default()
+            };
+            let mut packet = Vec::new();
+            ip.write(&mut packet).unwrap();
+            packet.extend_from_slice(&exp_header.to_bytes());
+            packet.extend_from_slice(&udp.to_bytes());
+            packet.extend_from_slice(&payload);
+            let ip_payload = &packet[Ipv6Header::LEN..];
+
+            // skip functions
+            assert_eq!(
+                Ok((UDP, &ip_payload[exp_header.header_len()..])),
+                Ipv6Header::skip_all_header_extensions_in_slice(ip_payload, exp)
+            );
+            {
+                let mut cursor = Cursor::new(ip_payload);
+                assert_eq!(
+                    UDP,
+                    Ipv6Header::skip_all_header_extensions(&mut cursor, exp).unwrap()
+                );
+                assert_eq!(exp_header.header_len(), cursor.position() as usize);
+            }
+
+            // sliced packet
+            {
+                let sliced = SlicedPacket::from_ip(&packet).unwrap();
+                let ipv6 = sliced.ip.as_ref().unwrap().ipv6().unwrap();
+                assert_eq!(
+                    vec![exp],
+                    ipv6.extensions()
+                        .clone()
+                        .into_iter()
+                        .map(|e| e.ip_number())
+                        .collect::<Vec<_>>()
+                );
+                match &sliced.transport {
+                    Some(TransportSlice::Udp(actual)) => assert_eq!(udp, actual.to_header()),
+                    _ => panic!("expected udp slice"),
+                }
+                assert_eq!(&payload, sliced.payload);
+            }
+
+            // packet headers
+            {
+                let headers = PacketHeaders::from_ip_slice(&packet).unwrap();
+                let exts = match headers.ip.as_ref().unwrap() {
+                    IpHeader::Version6(_, exts) => exts.clone(),
+                    _ => panic!("expected ipv6 header"),
+                };
+                let expected_exp = Some(exp_header.clone());
+                if EXP0 == exp {
+                    assert_eq!(expected_exp, exts.exp0);
+                } else {
+                    assert_eq!(expected_exp, exts.exp1);
+                }
+                assert_eq!(Some(TransportHeader::Udp(udp.clone())), headers.transport);
+                assert_eq!(&payload, headers.payload);
+            }
+        }
+    }
+
     #[test]
     fn debug() {
         let a: Ipv6Extensions = Default::default();
         assert_eq!(
             &format!(
-                "Ipv6Extensions {{ hop_by_hop_options: {:?}, destination_options: {:?}, routing: {:?}, fragment: {:?}, auth: {:?} }}",
+                "Ipv6Extensions {{ hop_by_hop_options: {:?}, destination_options: {:?}, routing: {:?}, fragment: {:?}, auth: {:?}, esp: {:?}, shim6: {:?}, mobility: {:?}, hip: {:?}, exp0: {:?}, exp1: {:?} }}",
                 a.hop_by_hop_options,
                 a.destination_options,
                 a.routing,
                 a.fragment,
                 a.auth,
+                a.esp,
+                a.shim6,
+                a.mobility,
+                a.hip,
+                a.exp0,
+                a.exp1,
             ),
             &format!("{:?}", a)
         );
@@ -1171,6 +1815,11 @@ pub mod header {
         assert_eq!(a.routing, None);
         assert_eq!(a.fragment, None);
         assert_eq!(a.auth, None);
+        assert_eq!(a.shim6, None);
+        assert_eq!(a.mobility, None);
+        assert_eq!(a.hip, None);
+        assert_eq!(a.exp0, None);
+        assert_eq!(a.exp1, None);
     }
 }
 
@@ -1183,7 +1832,7 @@ pub mod slice {
             header_size in any::<u8>(),
             post_header in ip_number_any()
                 .prop_filter("Must be a non ipv6 header relevant ip number".to_owned(),
-                    |v| !EXTESION_KNOWN_IP_NUMBERS.iter().any(|&x| v == &x)
+                    |v| ENCAP_SEC != *v && MOBILITY != *v && HIP != *v && SHIM6 != *v && EXP0 != *v && EXP1 != *v && !EXTESION_KNOWN_IP_NUMBERS.iter().any(|&x| v == &x)
                 )
         ) {
             use err::ipv6_exts::{HeaderError::*, HeaderSliceError::*};
@@ -1294,6 +1943,12 @@ pub mod slice {
                     ),
                     fragment: None,
                     auth: Some(auth),
+                    esp: None,
+                    shim6: None,
+                    mobility: None,
+                    hip: None,
+                    exp0: None,
+                    exp1: None,
                 };
                 let first_ip_number = exts.set_next_headers(UDP);
 
@@ -1359,6 +2014,111 @@ pub mod slice {
         }
     }
 
+    #[test]
+    fn experimental() {
+        // experimental headers are decoded as generic extension headers
+        for exp in [EXP0, EXP1] {
+            let first = Ipv6RawExtHeader::new_raw(exp, &[1; 6]).unwrap();
+            let second = Ipv6RawExtHeader::new_raw(UDP, &[2; 6]).unwrap();
+            let mut bytes = Vec::new();
+            bytes.extend_from_slice(&first.to_bytes());
+            bytes.extend_from_slice(&second.to_bytes());
+            bytes.extend_from_slice(&[3, 4]);
+
+            let (slice, next_header, rest) =
+                Ipv6ExtensionsSlice::from_slice(IPV6_DEST_OPTIONS, &bytes).unwrap();
+            assert_eq!(UDP, next_header);
+            assert_eq!(&[3, 4], rest);
+            assert_eq!(
+                slice.clone().into_iter().collect::<Vec<_>>(),
+                vec![
+                    Ipv6ExtensionSlice::DestinationOptions(
+                        Ipv6RawExtHeaderSlice::from_slice(&bytes[..8]).unwrap()
+                    ),
+                    Ipv6ExtensionSlice::Experimental(
+                        exp,
+                        Ipv6RawExtHeaderSlice::from_slice(&bytes[8..16]).unwrap()
+                    ),
+                ]
+            );
+        }
+    }
+
+    #[test]
+    fn to_chain() {
+        use err::ipv6_exts::HeaderError::*;
+
+        // empty
+        {
+            let slice = Ipv6ExtensionsSlice::from_slice(UDP, &[]).unwrap().0;
+            assert_eq!(Ok(Ipv6ExtensionChain::new()), slice.to_chain());
+        }
+
+        // repeated headers in non standard order
+        {
+            let mut bytes = Vec::new();
+            bytes.extend_from_slice(
+                &Ipv6FragmentHeader::new(IPV6_DEST_OPTIONS, IpFragOffset::ZERO, false, 1)
+                    .to_bytes(),
+            );
+            bytes.extend_from_slice(
+                &Ipv6RawExtHeader::new_raw(IPV6_FRAG, &[0; 6])
+                    .unwrap()
+                    .to_bytes(),
+            );
+            bytes.extend_from_slice(
+                &Ipv6FragmentHeader::new(UDP, IpFragOffset::ZERO, false, 2).to_bytes(),
+            );
+
+            let slice = Ipv6ExtensionsSlice::from_slice(IPV6_FRAG, &bytes)
+                .unwrap()
+                .0;
+            let chain = slice.to_chain().unwrap();
+            assert_eq!(
+                chain.headers(),
+                &[
+                    Ipv6ExtensionHeader::Fragment(Ipv6FragmentHeader::new(
+                        IPV6_DEST_OPTIONS,
+                        IpFragOffset::ZERO,
+                        false,
+                        1
+                    )),
+                    Ipv6ExtensionHeader::DestinationOptions(
+                        Ipv6RawExtHeader::new_raw(IPV6_FRAG, &[0; 6]).unwrap()
+                    ),
+                    Ipv6ExtensionHeader::Fragment(Ipv6FragmentHeader::new(
+                        UDP,
+                        IpFragOffset::ZERO,
+                        false,
+                        2
+                    )),
+                ]
+            );
+        }
+
+        // too many headers
+        {
+            let mut bytes = Vec::new();
+            for _ in 0..Ipv6ExtensionChain::MAX_HEADERS {
+                bytes.extend_from_slice(
+                    &Ipv6RawExtHeader::new_raw(IPV6_DEST_OPTIONS, &[0; 6])
+                        .unwrap()
+                        .to_bytes(),
+                );
+            }
+            bytes.extend_from_slice(&Ipv6RawExtHeader::new_raw(UDP, &[0; 6]).unwrap().to_bytes());
+            let slice = Ipv6ExtensionsSlice::from_slice(IPV6_DEST_OPTIONS, &bytes)
+                .unwrap()
+                .0;
+            assert_eq!(
+                Err(TooManyExtHeaders {
+                    max_headers: Ipv6ExtensionChain::MAX_HEADERS
+                }),
+                slice.to_chain()
+            );
+        }
+    }
+
     #[test]
     fn debug() {
         let a: Ipv6ExtensionsSlice = Default::default();
@@ -1474,6 +2234,99 @@ pub mod ipv6_extension_slice {
 
         assert_ne!(route, hop);
     }
+
+    #[test]
+    fn ip_number_slice_to_header() {
+        use Ipv6ExtensionHeader as H;
+        use Ipv6ExtensionSlice as S;
+
+        let raw = Ipv6RawExtHeader::new_raw(UDP, &[1, 2, 3, 4, 5, 6]).unwrap();
+        let raw_bytes = raw.to_bytes();
+        let raw_slice = Ipv6RawExtHeaderSlice::from_slice(&raw_bytes).unwrap();
+
+        let frag = Ipv6FragmentHeader::new(UDP, 1.try_into().unwrap(), true, 2);
+        let frag_bytes = frag.to_bytes();
+        let frag_slice = Ipv6FragmentHeaderSlice::from_slice(&frag_bytes).unwrap();
+
+        let auth = IpAuthHeader::new(UDP, 1, 2, &[1, 2, 3, 4]).unwrap();
+        let auth_bytes = auth.to_bytes();
+        let auth_slice = IpAuthHeaderSlice::from_slice(&auth_bytes).unwrap();
+
+        let esp = EspHeader {
+            spi: 1,
+            sequence_number: 2,
+        };
+        let esp_bytes = esp.to_bytes();
+        let esp_slice = EspHeaderSlice::from_slice(&esp_bytes).unwrap();
+
+        let tests = [
+            (
+                S::HopByHop(raw_slice.clone()),
+                IPV6_HOP_BY_HOP,
+                &raw_bytes[..],
+                H::HopByHop(raw.clone()),
+            ),
+            (
+                S::Routing(raw_slice.clone()),
+                IPV6_ROUTE,
+                &raw_bytes[..],
+                H::Routing(raw.clone()),
+            ),
+            (
+                S::Fragment(frag_slice),
+                IPV6_FRAG,
+                &frag_bytes[..],
+                H::Fragment(frag.clone()),
+            ),
+            (
+                S::DestinationOptions(raw_slice.clone()),
+                IPV6_DEST_OPTIONS,
+                &raw_bytes[..],
+                H::DestinationOptions(raw.clone()),
+            ),
+            (
+                S::Authentication(auth_slice),
+                AUTH,
+                &auth_bytes[..],
+                H::Authentication(auth.clone()),
+            ),
+            (
+                S::Esp(esp_slice),
+                ENCAP_SEC,
+                &esp_bytes[..],
+                H::Esp(esp.clone()),
+            ),
+            (
+                S::Mobility(raw_slice.clone()),
+                MOBILITY,
+                &raw_bytes[..],
+                H::Mobility(raw.clone()),
+            ),
+            (
+                S::Hip(raw_slice.clone()),
+                HIP,
+                &raw_bytes[..],
+                H::Hip(raw.clone()),
+            ),
+            (
+                S::Shim6(raw_slice.clone()),
+                SHIM6,
+                &raw_bytes[..],
+                H::Shim6(raw.clone()),
+            ),
+            (
+                S::Experimental(EXP1, raw_slice.clone()),
+                EXP1,
+                &raw_bytes[..],
+                H::Experimental(EXP1, raw.clone()),
+            ),
+        ];
+        for (slice, ip_number, bytes, header) in tests {
+            assert_eq!(ip_number, slice.ip_number());
+            assert_eq!(bytes, slice.slice());
+            assert_eq!(header, slice.to_header());
+        }
+    }
 }
 
 pub mod slice_iter {
@@ -1492,7 +2345,7 @@ pub mod slice_iter {
             header_size in any::<u8>(),
             post_header in ip_number_any()
                 .prop_filter("Must be a non ipv6 header relevant ip number".to_owned(),
-                    |v| !EXTESION_KNOWN_IP_NUMBERS.iter().any(|&x| v == &x)
+                    |v| ENCAP_SEC != *v && MOBILITY != *v && HIP != *v && SHIM6 != *v && EXP0 != *v && EXP1 != *v && !EXTESION_KNOWN_IP_NUMBERS.iter().any(|&x| v == &x)
                 )
         ) {
             /// Run a test with the given ip numbers
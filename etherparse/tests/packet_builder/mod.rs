@@ -28,8 +28,8 @@ fn eth_ipv4_udp() {
     assert_eq!(
         Ethernet2Header::read(&mut cursor).unwrap(),
         Ethernet2Header {
-            source: [1, 2, 3, 4, 5, 6],
-            destination: [7, 8, 9, 10, 11, 12],
+            source: MacAddress([1, 2, 3, 4, 5, 6]),
+            destination: MacAddress([7, 8, 9, 10, 11, 12]),
             ether_type: ether_type::IPV4
         }
     );
@@ -76,6 +76,7 @@ fn ipv4() {
         .unwrap(),
         Ipv4Extensions {
             auth: Some(auth_ext.clone()),
+            esp: None,
         },
     ));
 
@@ -162,6 +163,12 @@ fn ipv6() {
             routing: None,
             fragment: None,
             auth: Some(auth_ext.clone()),
+            esp: None,
+            shim6: None,
+            mobility: None,
+            hip: None,
+            exp0: None,
+            exp1: None,
         },
     ));
 
@@ -418,8 +425,8 @@ fn udp_builder_eth_ipv6_udp() {
     assert_eq!(
         Ethernet2Header::read(&mut cursor).unwrap(),
         Ethernet2Header {
-            source: [1, 2, 3, 4, 5, 6],
-            destination: [7, 8, 9, 10, 11, 12],
+            source: MacAddress([1, 2, 3, 4, 5, 6]),
+            destination: MacAddress([7, 8, 9, 10, 11, 12]),
             ether_type: ether_type::IPV6
         }
     );
@@ -483,8 +490,8 @@ fn udp_builder_eth_single_vlan_ipv4_udp() {
     assert_eq!(
         Ethernet2Header::read(&mut cursor).unwrap(),
         Ethernet2Header {
-            source: [1, 2, 3, 4, 5, 6],
-            destination: [7, 8, 9, 10, 11, 12],
+            source: MacAddress([1, 2, 3, 4, 5, 6]),
+            destination: MacAddress([7, 8, 9, 10, 11, 12]),
             ether_type: ether_type::VLAN_TAGGED_FRAME
         }
     );
@@ -565,8 +572,8 @@ fn udp_builder_eth_double_vlan_ipv6_udp() {
     assert_eq!(
         Ethernet2Header::read(&mut cursor).unwrap(),
         Ethernet2Header {
-            source: [1, 2, 3, 4, 5, 6],
-            destination: [7, 8, 9, 10, 11, 12],
+            source: MacAddress([1, 2, 3, 4, 5, 6]),
+            destination: MacAddress([7, 8, 9, 10, 11, 12]),
             ether_type: ether_type::PROVIDER_BRIDGING,
         }
     );
@@ -664,8 +671,8 @@ fn udp_builder_eth_ip_udp() {
     assert_eq!(
         Ethernet2Header::read(&mut cursor).unwrap(),
         Ethernet2Header {
-            source: [1, 2, 3, 4, 5, 6],
-            destination: [7, 8, 9, 10, 11, 12],
+            source: MacAddress([1, 2, 3, 4, 5, 6]),
+            destination: MacAddress([7, 8, 9, 10, 11, 12]),
             ether_type: ether_type::IPV6
         }
     );
@@ -751,8 +758,8 @@ fn udp_builder_eth_vlan_ip_udp() {
     assert_eq!(
         Ethernet2Header::read(&mut cursor).unwrap(),
         Ethernet2Header {
-            source: [1, 2, 3, 4, 5, 6],
-            destination: [7, 8, 9, 10, 11, 12],
+            source: MacAddress([1, 2, 3, 4, 5, 6]),
+            destination: MacAddress([7, 8, 9, 10, 11, 12]),
             ether_type: ether_type::VLAN_TAGGED_FRAME
         }
     );
@@ -883,8 +890,8 @@ proptest! {
         //ethernet 2 header
         assert_eq!(Ethernet2Header::read(&mut cursor).unwrap(),
                    Ethernet2Header{
-                        source: [1,2,3,4,5,6],
-                        destination: [7,8,9,10,11,12],
+                        source: MacAddress([1,2,3,4,5,6]),
+                        destination: MacAddress([7,8,9,10,11,12]),
                         ether_type: ether_type::IPV4
                    });
 
@@ -995,8 +1002,8 @@ proptest! {
         //ethernet 2 header
         assert_eq!(Ethernet2Header::read(&mut cursor).unwrap(),
                    Ethernet2Header{
-                        source: [1,2,3,4,5,6],
-                        destination: [7,8,9,10,11,12],
+                        source: MacAddress([1,2,3,4,5,6]),
+                        destination: MacAddress([7,8,9,10,11,12]),
                         ether_type: ether_type::IPV6
                    });
 
@@ -1163,11 +1170,11 @@ proptest! {
 
             // check the packets could be decoded
             assert_eq!(
-                Some(Ethernet2Header{
-                    source: [1,2,3,4,5,6],
-                    destination: [7,8,9,10,11,12],
+                Some(LinkHeader::Ethernet2(Ethernet2Header{
+                    source: MacAddress([1,2,3,4,5,6]),
+                    destination: MacAddress([7,8,9,10,11,12]),
                     ether_type: ether_type::IPV4
-                }),
+                })),
                 actual.link
             );
             assert_eq!(
@@ -1382,11 +1389,11 @@ proptest! {
 
             // check the packets could be decoded
             assert_eq!(
-                Some(Ethernet2Header{
-                    source: [1,2,3,4,5,6],
-                    destination: [7,8,9,10,11,12],
+                Some(LinkHeader::Ethernet2(Ethernet2Header{
+                    source: MacAddress([1,2,3,4,5,6]),
+                    destination: MacAddress([7,8,9,10,11,12]),
                     ether_type: ether_type::IPV6
-                }),
+                })),
                 actual.link
             );
             assert_eq!(
@@ -1509,11 +1516,11 @@ proptest! {
 
             // check the packets could be decoded
             assert_eq!(
-                Some(Ethernet2Header{
-                    source: [1,2,3,4,5,6],
-                    destination: [7,8,9,10,11,12],
+                Some(LinkHeader::Ethernet2(Ethernet2Header{
+                    source: MacAddress([1,2,3,4,5,6]),
+                    destination: MacAddress([7,8,9,10,11,12]),
                     ether_type: ether_type::IPV6
-                }),
+                })),
                 actual.link
             );
             assert_eq!(
@@ -1586,3 +1593,1591 @@ proptest! {
         }
     }
 }
+
+#[test]
+fn eth_arp() {
+    let eth_source = MacAddress([1, 2, 3, 4, 5, 6]);
+    let eth_destination = MacAddress([7, 8, 9, 10, 11, 12]);
+
+    // (builder, expected arp packet)
+    let tests = [
+        (
+            PacketBuilder::ethernet2(eth_source, eth_destination)
+                .arp_request([13, 14, 15, 16], [17, 18, 19, 20]),
+            ArpEthIpv4Header {
+                operation: ArpOperation::REQUEST,
+                sender_mac: eth_source,
+                sender_ipv4: [13, 14, 15, 16],
+                target_mac: MacAddress::ZERO,
+                target_ipv4: [17, 18, 19, 20],
+            },
+        ),
+        (
+            PacketBuilder::ethernet2(eth_source, eth_destination)
+                .arp_reply([13, 14, 15, 16], [17, 18, 19, 20]),
+            ArpEthIpv4Header {
+                operation: ArpOperation::REPLY,
+                sender_mac: eth_source,
+                sender_ipv4: [13, 14, 15, 16],
+                target_mac: eth_destination,
+                target_ipv4: [17, 18, 19, 20],
+            },
+        ),
+        (
+            PacketBuilder::ethernet2(eth_source, eth_destination).arp_gratuitous([13, 14, 15, 16]),
+            ArpEthIpv4Header {
+                operation: ArpOperation::REQUEST,
+                sender_mac: eth_source,
+                sender_ipv4: [13, 14, 15, 16],
+                target_mac: MacAddress::ZERO,
+                target_ipv4: [13, 14, 15, 16],
+            },
+        ),
+    ];
+
+    for (builder, expected) in tests {
+        assert_eq!(Ethernet2Header::LEN + ArpEthIpv4Header::LEN, builder.size());
+        let mut serialized = Vec::with_capacity(builder.size());
+        builder.write(&mut serialized).unwrap();
+        assert_eq!(
+            Ethernet2Header::LEN + ArpEthIpv4Header::LEN,
+            serialized.len()
+        );
+
+        let headers = PacketHeaders::from_ethernet_slice(&serialized).unwrap();
+        assert_eq!(
+            headers.link,
+            Some(LinkHeader::Ethernet2(Ethernet2Header {
+                source: eth_source,
+                destination: eth_destination,
+                ether_type: ether_type::ARP,
+            }))
+        );
+        assert_eq!(headers.vlan, None);
+        assert_eq!(headers.arp, Some(expected.to_header()));
+        assert_eq!(headers.ip, None);
+        assert_eq!(headers.transport, None);
+        assert_eq!(headers.payload, &[]);
+    }
+}
+
+#[test]
+fn eth_vlan_arp() {
+    let arp = ArpHeader::new(
+        ArpHardwareId::IEEE802,
+        EtherType::IPV6,
+        ArpOperation::REPLY,
+        &[1, 2, 3, 4, 5, 6, 7, 8],
+        &[9; 16],
+        &[10, 11, 12, 13, 14, 15, 16, 17],
+        &[18; 16],
+    )
+    .unwrap();
+
+    // single vlan
+    {
+        let builder = PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+            .single_vlan(0x123.try_into().unwrap())
+            .arp(arp.clone());
+        assert_eq!(
+            Ethernet2Header::LEN + SingleVlanHeader::LEN + arp.header_len(),
+            builder.size()
+        );
+        let mut serialized = Vec::with_capacity(builder.size());
+        builder.write(&mut serialized).unwrap();
+
+        let headers = PacketHeaders::from_ethernet_slice(&serialized).unwrap();
+        assert_eq!(
+            headers.link.unwrap().ethernet2().unwrap().ether_type,
+            ether_type::VLAN_TAGGED_FRAME
+        );
+        assert_eq!(
+            headers.vlan,
+            Some(VlanHeader::Single(SingleVlanHeader {
+                pcp: VlanPcp::ZERO,
+                drop_eligible_indicator: false,
+                vlan_id: 0x123.try_into().unwrap(),
+                ether_type: ether_type::ARP,
+            }))
+        );
+        assert_eq!(headers.arp, Some(arp.clone()));
+        assert_eq!(headers.payload, &[]);
+    }
+
+    // double vlan
+    {
+        let builder = PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+            .double_vlan(0x123.try_into().unwrap(), 0x234.try_into().unwrap())
+            .arp_request([13, 14, 15, 16], [17, 18, 19, 20]);
+        assert_eq!(
+            Ethernet2Header::LEN + DoubleVlanHeader::LEN + ArpEthIpv4Header::LEN,
+            builder.size()
+        );
+        let mut serialized = Vec::with_capacity(builder.size());
+        builder.write(&mut serialized).unwrap();
+
+        let sliced = SlicedPacket::from_ethernet(&serialized).unwrap();
+        match sliced.vlan.unwrap() {
+            VlanSlice::DoubleVlan(d) => {
+                assert_eq!(d.outer().ether_type(), ether_type::VLAN_TAGGED_FRAME);
+                assert_eq!(d.inner().ether_type(), ether_type::ARP);
+            }
+            _ => panic!("expected double vlan"),
+        }
+        assert_eq!(
+            sliced.arp.unwrap().try_eth_ipv4(),
+            Some(ArpEthIpv4Header {
+                operation: ArpOperation::REQUEST,
+                sender_mac: MacAddress([1, 2, 3, 4, 5, 6]),
+                sender_ipv4: [13, 14, 15, 16],
+                target_mac: MacAddress::ZERO,
+                target_ipv4: [17, 18, 19, 20],
+            })
+        );
+    }
+
+    // vlan arp_reply & arp_gratuitous
+    {
+        let mut reply = Vec::new();
+        PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+            .single_vlan(0x123.try_into().unwrap())
+            .arp_reply([13, 14, 15, 16], [17, 18, 19, 20])
+            .write(&mut reply)
+            .unwrap();
+        let reply = PacketHeaders::from_ethernet_slice(&reply).unwrap();
+        assert_eq!(
+            reply.arp.unwrap().try_eth_ipv4().unwrap().target_mac,
+            MacAddress([7, 8, 9, 10, 11, 12])
+        );
+
+        let mut gratuitous = Vec::new();
+        PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [0xff; 6])
+            .single_vlan(0x123.try_into().unwrap())
+            .arp_gratuitous([13, 14, 15, 16])
+            .write(&mut gratuitous)
+            .unwrap();
+        let gratuitous = PacketHeaders::from_ethernet_slice(&gratuitous)
+            .unwrap()
+            .arp
+            .unwrap()
+            .try_eth_ipv4()
+            .unwrap();
+        assert_eq!(gratuitous.sender_ipv4, gratuitous.target_ipv4);
+    }
+}
+
+#[test]
+fn eth_llc() {
+    let llc = LlcHeader {
+        dsap: LlcHeader::STP_SAP,
+        ssap: LlcHeader::STP_SAP,
+        control: [LlcHeader::UI_CONTROL, 0],
+    };
+    let payload = [1, 2, 3, 4, 5, 6, 7, 8];
+
+    // without vlan
+    {
+        let builder =
+            PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12]).llc(llc.clone());
+        assert_eq!(
+            Ethernet2Header::LEN + llc.header_len() + payload.len(),
+            builder.size(payload.len())
+        );
+        let mut serialized = Vec::with_capacity(builder.size(payload.len()));
+        builder.write(&mut serialized, &payload).unwrap();
+
+        let headers = PacketHeaders::from_ethernet_slice(&serialized).unwrap();
+        assert_eq!(
+            headers.link.unwrap().ethernet2().unwrap().ether_type,
+            EtherType((llc.header_len() + payload.len()) as u16)
+        );
+        assert_eq!(headers.llc, Some(llc.clone()));
+        assert_eq!(headers.snap, None);
+        assert_eq!(headers.ip, None);
+        assert_eq!(headers.payload, &payload);
+    }
+
+    // with single vlan & two octet control field
+    {
+        let llc = LlcHeader {
+            dsap: 0xf0,
+            ssap: 0xf0,
+            control: [0x10, 0x20],
+        };
+        let builder = PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+            .single_vlan(0x123.try_into().unwrap())
+            .llc(llc.clone());
+        assert_eq!(
+            Ethernet2Header::LEN + SingleVlanHeader::LEN + LlcHeader::MAX_LEN + payload.len(),
+            builder.size(payload.len())
+        );
+        let mut serialized = Vec::with_capacity(builder.size(payload.len()));
+        builder.write(&mut serialized, &payload).unwrap();
+
+        let sliced = SlicedPacket::from_ethernet(&serialized).unwrap();
+        match sliced.vlan.unwrap() {
+            VlanSlice::SingleVlan(s) => {
+                assert_eq!(
+                    s.ether_type(),
+                    EtherType((LlcHeader::MAX_LEN + payload.len()) as u16)
+                );
+            }
+            _ => panic!("expected single vlan"),
+        }
+        assert_eq!(sliced.llc.unwrap().to_header(), llc);
+        assert_eq!(sliced.payload, &payload);
+    }
+
+    // payload too big for the length field
+    {
+        let payload = [0u8; Ethernet2Header::MAX_IEEE802_3_PAYLOAD_LEN];
+        let builder =
+            PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12]).llc(llc.clone());
+        let mut serialized = Vec::new();
+        assert_eq!(
+            builder
+                .write(&mut serialized, &payload)
+                .unwrap_err()
+                .payload_len(),
+            Some(&err::ValueTooBigError {
+                actual: llc.header_len() + payload.len(),
+                max_allowed: Ethernet2Header::MAX_IEEE802_3_PAYLOAD_LEN,
+                value_type: err::ValueType::Ieee802_3PayloadLength,
+            })
+        );
+    }
+}
+
+#[test]
+fn eth_snap() {
+    let payload = [1, 2, 3, 4, 5, 6, 7, 8];
+
+    // snap with ipv4 & udp
+    {
+        let builder = PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+            .snap(SnapHeader {
+                oui: SnapHeader::OUI_RFC1042,
+                ether_type: 0.into(),
+            })
+            .ipv4([13, 14, 15, 16], [17, 18, 19, 20], 21)
+            .udp(22, 23);
+        let expected_len = LlcHeader::MIN_LEN
+            + SnapHeader::LEN
+            + Ipv4Header::MIN_LEN
+            + UdpHeader::LEN
+            + payload.len();
+        assert_eq!(
+            Ethernet2Header::LEN + expected_len,
+            builder.size(payload.len())
+        );
+        let mut serialized = Vec::with_capacity(builder.size(payload.len()));
+        builder.write(&mut serialized, &payload).unwrap();
+
+        let headers = PacketHeaders::from_ethernet_slice(&serialized).unwrap();
+        assert_eq!(
+            headers.link.unwrap().ethernet2().unwrap().ether_type,
+            EtherType(expected_len as u16)
+        );
+        assert_eq!(headers.llc, Some(LlcHeader::SNAP));
+        assert_eq!(
+            headers.snap,
+            Some(SnapHeader {
+                oui: SnapHeader::OUI_RFC1042,
+                ether_type: ether_type::IPV4,
+            })
+        );
+        match headers.ip.unwrap() {
+            IpHeader::Version4(ipv4, _) => assert_eq!(ipv4.source, [13, 14, 15, 16]),
+            _ => panic!("expected ipv4 header"),
+        }
+        assert_eq!(headers.transport.unwrap().udp().unwrap().source_port, 22);
+        assert_eq!(headers.payload, &payload);
+    }
+
+    // snap with double vlan & ipv6
+    {
+        let builder = PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+            .double_vlan(0x123.try_into().unwrap(), 0x234.try_into().unwrap())
+            .snap(SnapHeader {
+                oui: SnapHeader::OUI_BRIDGE_TUNNEL,
+                ether_type: 0.into(),
+            })
+            .ipv6([11; 16], [12; 16], 13)
+            .udp(22, 23);
+        let mut serialized = Vec::with_capacity(builder.size(payload.len()));
+        builder.write(&mut serialized, &payload).unwrap();
+
+        let sliced = SlicedPacket::from_ethernet(&serialized).unwrap();
+        assert_eq!(
+            sliced.snap.as_ref().unwrap().to_header(),
+            SnapHeader {
+                oui: SnapHeader::OUI_BRIDGE_TUNNEL,
+                ether_type: ether_type::IPV6,
+            }
+        );
+        assert!(sliced.ip.is_some());
+        assert_eq!(sliced.payload, &payload);
+    }
+
+    // snap with arp
+    {
+        let arp = ArpHeader::new(
+            ArpHardwareId::IEEE802,
+            EtherType::IPV4,
+            ArpOperation::REQUEST,
+            &[1, 2, 3, 4, 5, 6],
+            &[13, 14, 15, 16],
+            &[0; 6],
+            &[17, 18, 19, 20],
+        )
+        .unwrap();
+        let builder = PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+            .snap(SnapHeader {
+                oui: SnapHeader::OUI_RFC1042,
+                ether_type: 0.into(),
+            })
+            .arp(arp.clone());
+        let mut serialized = Vec::with_capacity(builder.size());
+        builder.write(&mut serialized).unwrap();
+
+        let headers = PacketHeaders::from_ethernet_slice(&serialized).unwrap();
+        assert_eq!(headers.snap.unwrap().ether_type, ether_type::ARP);
+        assert_eq!(headers.arp, Some(arp));
+    }
+
+    // snap without a following header (ether type kept)
+    {
+        let snap = SnapHeader {
+            oui: [0, 0, 0x0c],
+            ether_type: 0x2000.into(),
+        };
+        let builder =
+            PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12]).snap(snap.clone());
+        assert_eq!(
+            Ethernet2Header::LEN + LlcHeader::MIN_LEN + SnapHeader::LEN + payload.len(),
+            builder.size(payload.len())
+        );
+        let mut serialized = Vec::with_capacity(builder.size(payload.len()));
+        builder.write(&mut serialized, &payload).unwrap();
+
+        let headers = PacketHeaders::from_ethernet_slice(&serialized).unwrap();
+        assert_eq!(headers.snap, Some(snap));
+        assert_eq!(headers.payload, &payload);
+    }
+}
+
+#[test]
+fn eth_mpls() {
+    let payload = [1, 2, 3, 4, 5, 6, 7, 8];
+    let entry = |label: u32| MplsLabelStackEntry {
+        label: label.try_into().unwrap(),
+        traffic_class: 5.try_into().unwrap(),
+        // set on all entries to check that only the last one keeps it
+        bottom_of_stack: true,
+        ttl: 64,
+    };
+
+    // multiple labels with ipv4 & udp
+    {
+        let builder = PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+            .mpls(entry(16))
+            .mpls(entry(17))
+            .mpls(entry(18))
+            .ipv4([13, 14, 15, 16], [17, 18, 19, 20], 21)
+            .udp(22, 23);
+        assert_eq!(
+            Ethernet2Header::LEN
+                + 3 * MplsLabelStackEntry::LEN
+                + Ipv4Header::MIN_LEN
+                + UdpHeader::LEN
+                + payload.len(),
+            builder.size(payload.len())
+        );
+        let mut serialized = Vec::with_capacity(builder.size(payload.len()));
+        builder.write(&mut serialized, &payload).unwrap();
+
+        let headers = PacketHeaders::from_ethernet_slice(&serialized).unwrap();
+        assert_eq!(
+            headers.link.unwrap().ethernet2().unwrap().ether_type,
+            ether_type::MPLS_UNICAST
+        );
+        let stack = headers.mpls.unwrap();
+        assert_eq!(3, stack.entries().len());
+        for (i, actual) in stack.entries().iter().enumerate() {
+            assert_eq!(
+                &MplsLabelStackEntry {
+                    bottom_of_stack: i == 2,
+                    ..entry(16 + i as u32)
+                },
+                actual
+            );
+        }
+        match headers.ip.unwrap() {
+            IpHeader::Version4(ipv4, _) => assert_eq!(ipv4.source, [13, 14, 15, 16]),
+            _ => panic!("expected ipv4 header"),
+        }
+        assert_eq!(headers.transport.unwrap().udp().unwrap().source_port, 22);
+        assert_eq!(headers.payload, &payload);
+    }
+
+    // vlan with ipv6
+    {
+        let builder = PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+            .single_vlan(0x123.try_into().unwrap())
+            .mpls(entry(16))
+            .ipv6([11; 16], [12; 16], 13)
+            .udp(22, 23);
+        let mut serialized = Vec::with_capacity(builder.size(payload.len()));
+        builder.write(&mut serialized, &payload).unwrap();
+
+        let sliced = SlicedPacket::from_ethernet(&serialized).unwrap();
+        assert!(sliced.vlan.is_some());
+        let mpls = sliced.mpls.as_ref().unwrap();
+        assert_eq!(1, mpls.num_entries());
+        assert_eq!(entry(16), mpls.top().to_header());
+        assert!(sliced.ip.is_some());
+        assert_eq!(sliced.payload, &payload);
+    }
+
+    // raw payload after the label stack
+    {
+        let builder = PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12]).mpls(
+            MplsLabelStackEntry {
+                bottom_of_stack: false,
+                ..entry(16)
+            },
+        );
+        assert_eq!(
+            Ethernet2Header::LEN + MplsLabelStackEntry::LEN + payload.len(),
+            builder.size(payload.len())
+        );
+        let mut serialized = Vec::with_capacity(builder.size(payload.len()));
+        builder.write(&mut serialized, &payload).unwrap();
+
+        let headers = PacketHeaders::from_ethernet_slice(&serialized).unwrap();
+        assert_eq!(headers.mpls.unwrap().entries(), &[entry(16)]);
+        assert_eq!(headers.ip, None);
+        assert_eq!(headers.payload, &payload);
+    }
+}
+
+#[test]
+fn eth_pppoe() {
+    let payload = [1, 2, 3, 4, 5, 6, 7, 8];
+
+    // session with ipv4 & udp
+    {
+        let builder = PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+            .pppoe_session(0x1234)
+            .ipv4([13, 14, 15, 16], [17, 18, 19, 20], 21)
+            .udp(22, 23);
+        assert_eq!(
+            Ethernet2Header::LEN
+                + PppoeHeader::LEN
+                + PppHeader::LEN
+                + Ipv4Header::MIN_LEN
+                + UdpHeader::LEN
+                + payload.len(),
+            builder.size(payload.len())
+        );
+        let mut serialized = Vec::with_capacity(builder.size(payload.len()));
+        builder.write(&mut serialized, &payload).unwrap();
+
+        let headers = PacketHeaders::from_ethernet_slice(&serialized).unwrap();
+        assert_eq!(
+            headers.link.unwrap().ethernet2().unwrap().ether_type,
+            ether_type::PPPOE_SESSION
+        );
+        assert_eq!(
+            headers.pppoe.unwrap(),
+            PppoeHeader {
+                code: PppoeCode::SESSION_DATA,
+                session_id: 0x1234,
+                payload_length: (PppHeader::LEN
+                    + Ipv4Header::MIN_LEN
+                    + UdpHeader::LEN
+                    + payload.len()) as u16,
+            }
+        );
+        assert_eq!(
+            headers.ppp.unwrap(),
+            PppHeader {
+                protocol: PppProtocol::IPV4
+            }
+        );
+        match headers.ip.unwrap() {
+            IpHeader::Version4(ipv4, _) => assert_eq!(ipv4.source, [13, 14, 15, 16]),
+            _ => panic!("expected ipv4 header"),
+        }
+        assert_eq!(headers.transport.unwrap().udp().unwrap().source_port, 22);
+        assert_eq!(headers.payload, &payload);
+    }
+
+    // vlan & explicit ppp header with ipv6 (protocol & code get overwritten)
+    {
+        let builder = PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+            .single_vlan(0x123.try_into().unwrap())
+            .pppoe(PppoeHeader {
+                code: PppoeCode::PADI,
+                session_id: 1,
+                payload_length: 0,
+            })
+            .ppp(PppHeader {
+                protocol: PppProtocol::LCP,
+            })
+            .ipv6([11; 16], [12; 16], 13)
+            .udp(22, 23);
+        let mut serialized = Vec::with_capacity(builder.size(payload.len()));
+        builder.write(&mut serialized, &payload).unwrap();
+
+        let sliced = SlicedPacket::from_ethernet(&serialized).unwrap();
+        assert!(sliced.vlan.is_some());
+        assert_eq!(
+            PppoeCode::SESSION_DATA,
+            sliced.pppoe.as_ref().unwrap().code()
+        );
+        assert_eq!(PppProtocol::IPV6, sliced.ppp.as_ref().unwrap().protocol());
+        assert!(sliced.ip.is_some());
+        assert_eq!(sliced.payload, &payload);
+    }
+
+    // raw ppp payload
+    {
+        let builder = PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+            .pppoe_session(2)
+            .ppp(PppHeader {
+                protocol: PppProtocol::LCP,
+            });
+        assert_eq!(
+            Ethernet2Header::LEN + PppoeHeader::LEN + PppHeader::LEN + payload.len(),
+            builder.size(payload.len())
+        );
+        let mut serialized = Vec::with_capacity(builder.size(payload.len()));
+        builder.write(&mut serialized, &payload).unwrap();
+
+        let headers = PacketHeaders::from_ethernet_slice(&serialized).unwrap();
+        assert_eq!(
+            headers.ppp.unwrap(),
+            PppHeader {
+                protocol: PppProtocol::LCP
+            }
+        );
+        assert_eq!(headers.ip, None);
+        assert_eq!(headers.payload, &payload);
+    }
+
+    // discovery stage with tags as payload
+    {
+        let tags = [0x01, 0x01, 0x00, 0x00, 0x01, 0x03, 0x00, 0x01, 0xab];
+        let builder = PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12]).pppoe(
+            PppoeHeader {
+                code: PppoeCode::PADI,
+                session_id: 0,
+                payload_length: 0,
+            },
+        );
+        assert_eq!(
+            Ethernet2Header::LEN + PppoeHeader::LEN + tags.len(),
+            builder.size(tags.len())
+        );
+        let mut serialized = Vec::with_capacity(builder.size(tags.len()));
+        builder.write(&mut serialized, &tags).unwrap();
+
+        let headers = PacketHeaders::from_ethernet_slice(&serialized).unwrap();
+        assert_eq!(
+            headers.link.unwrap().ethernet2().unwrap().ether_type,
+            ether_type::PPPOE_DISCOVERY
+        );
+        assert_eq!(
+            headers.pppoe.unwrap(),
+            PppoeHeader {
+                code: PppoeCode::PADI,
+                session_id: 0,
+                payload_length: tags.len() as u16,
+            }
+        );
+        assert_eq!(headers.ppp, None);
+        assert_eq!(headers.payload, &tags);
+        assert_eq!(2, PppoeTagsIterator::from_slice(headers.payload).count());
+    }
+
+    // payload too big for the length field
+    {
+        let payload = [0u8; 0xffff];
+        let builder = PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+            .pppoe_session(1)
+            .ppp(PppHeader {
+                protocol: PppProtocol::LCP,
+            });
+        let mut serialized = Vec::new();
+        assert_eq!(
+            builder
+                .write(&mut serialized, &payload)
+                .unwrap_err()
+                .payload_len(),
+            Some(&err::ValueTooBigError {
+                actual: PppHeader::LEN + payload.len(),
+                max_allowed: 0xffff,
+                value_type: err::ValueType::PppoePayloadLength,
+            })
+        );
+        assert!(serialized.is_empty());
+    }
+}
+
+#[test]
+fn eth_fcs() {
+    let payload = [1, 2, 3, 4, 5, 6, 7, 8];
+
+    // ipv4 & udp (short frame that gets padded & frame without padding)
+    for payload in [&payload[..], &[0xab; 100][..]] {
+        let builder = || {
+            PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+                .ipv4([13, 14, 15, 16], [17, 18, 19, 20], 21)
+                .udp(22, 23)
+        };
+        let mut expected_frame = Vec::new();
+        builder().write(&mut expected_frame, payload).unwrap();
+        let unpadded_len = expected_frame.len();
+        if expected_frame.len() < Ethernet2Header::MIN_FRAME_LEN {
+            expected_frame.resize(Ethernet2Header::MIN_FRAME_LEN, 0);
+        }
+
+        let with_fcs = {
+            let b = PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+                .fcs()
+                .ipv4([13, 14, 15, 16], [17, 18, 19, 20], 21)
+                .udp(22, 23);
+            assert_eq!(
+                expected_frame.len() + Ethernet2Header::FCS_LEN,
+                b.size(payload.len())
+            );
+            let mut serialized = Vec::with_capacity(b.size(payload.len()));
+            b.write(&mut serialized, payload).unwrap();
+            serialized
+        };
+
+        // frame is unchanged (besides the padding) & followed by the little endian crc
+        assert_eq!(
+            expected_frame.len() + Ethernet2Header::FCS_LEN,
+            with_fcs.len()
+        );
+        assert_eq!(&expected_frame[..], &with_fcs[..expected_frame.len()]);
+        assert_eq!(
+            etherparse::checksum::Crc32::new()
+                .add_slice(&expected_frame)
+                .value()
+                .to_le_bytes(),
+            with_fcs[expected_frame.len()..]
+        );
+
+        // slicing with fcs verification (padding ends up in the link trailer)
+        let sliced = SlicedPacket::from_ethernet_with_fcs(&with_fcs).unwrap();
+        assert_eq!(
+            sliced,
+            SlicedPacket::from_ethernet(&expected_frame).unwrap()
+        );
+        assert_eq!(payload, sliced.payload);
+        assert_eq!(&expected_frame[unpadded_len..], sliced.link_trailer);
+    }
+
+    // pppoe (length fields must not include the fcs)
+    {
+        let builder = PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+            .fcs()
+            .pppoe_session(0x1234)
+            .ipv4([13, 14, 15, 16], [17, 18, 19, 20], 21)
+            .udp(22, 23);
+        let mut serialized = Vec::with_capacity(builder.size(payload.len()));
+        builder.write(&mut serialized, &payload).unwrap();
+
+        let sliced = SlicedPacket::from_ethernet_with_fcs(&serialized).unwrap();
+        assert_eq!(
+            (PppHeader::LEN + Ipv4Header::MIN_LEN + UdpHeader::LEN + payload.len()) as u16,
+            sliced.pppoe.unwrap().payload_length()
+        );
+        assert_eq!(sliced.payload, &payload);
+    }
+
+    // llc (length in the type/length field must not include the fcs)
+    {
+        let llc = LlcHeader {
+            dsap: LlcHeader::STP_SAP,
+            ssap: LlcHeader::STP_SAP,
+            control: [LlcHeader::UI_CONTROL, 0],
+        };
+        let builder = PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+            .fcs()
+            .llc(llc.clone());
+        let mut serialized = Vec::with_capacity(builder.size(payload.len()));
+        builder.write(&mut serialized, &payload).unwrap();
+        assert_eq!(
+            llc.header_len() + payload.len(),
+            usize::from(u16::from_be_bytes([serialized[12], serialized[13]]))
+        );
+        let sliced = SlicedPacket::from_ethernet_with_fcs(&serialized).unwrap();
+        assert_eq!(sliced.payload, &payload);
+    }
+
+    // io error when writing the fcs
+    {
+        let builder = PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+            .fcs()
+            .ipv4([13, 14, 15, 16], [17, 18, 19, 20], 21)
+            .udp(22, 23);
+        let mut writer = TestWriter::with_max_size(builder.size(payload.len()) - 1);
+        assert_eq!(
+            std::io::ErrorKind::UnexpectedEof,
+            builder
+                .write(&mut writer, &payload)
+                .unwrap_err()
+                .io()
+                .unwrap()
+                .kind()
+        );
+    }
+}
+
+#[test]
+fn ipv6_jumbogram() {
+    let source = [
+        11, 12, 13, 14, 15, 16, 17, 18, 19, 10, 21, 22, 23, 24, 25, 26,
+    ];
+    let destination = [
+        31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46,
+    ];
+    let payload: Vec<u8> = (0..70000u32).map(|v| v as u8).collect();
+
+    // udp
+    {
+        let builder = PacketBuilder::ipv6(source, destination, 47).udp(22, 23);
+        // 8 bytes for the added hop-by-hop header with the jumbo payload option
+        let expected_size = Ipv6Header::LEN + 8 + UdpHeader::LEN + payload.len();
+        assert_eq!(expected_size, builder.size(payload.len()));
+
+        let mut serialized = Vec::with_capacity(builder.size(payload.len()));
+        builder.write(&mut serialized, &payload).unwrap();
+        assert_eq!(expected_size, serialized.len());
+
+        let sliced = SlicedPacket::from_ip(&serialized).unwrap();
+        let ipv6 = match sliced.ip.as_ref().unwrap() {
+            InternetSlice::Ipv6(ipv6) => ipv6,
+            _ => panic!("expected ipv6"),
+        };
+        assert_eq!(0, ipv6.header().payload_length());
+        assert_eq!(ip_number::IPV6_HOP_BY_HOP, ipv6.header().next_header());
+        assert_eq!(
+            err::LenSource::Ipv6JumboPayloadLen,
+            ipv6.payload().len_source
+        );
+        match IpHeader::from_slice(&serialized).unwrap().0 {
+            IpHeader::Version6(_, exts) => assert_eq!(
+                Some((8 + UdpHeader::LEN + payload.len()) as u32),
+                exts.jumbo_payload_len()
+            ),
+            _ => panic!("expected ipv6"),
+        }
+
+        let udp = match sliced.transport.as_ref().unwrap() {
+            TransportSlice::Udp(udp) => udp.to_header(),
+            _ => panic!("expected udp"),
+        };
+        assert_eq!(0, udp.length);
+        assert_eq!(
+            udp.checksum,
+            udp.calc_checksum_ipv6(&ipv6.header().to_header(), &payload)
+                .unwrap()
+        );
+        assert_eq!(sliced.payload, &payload[..]);
+    }
+
+    // tcp
+    {
+        let builder = PacketBuilder::ipv6(source, destination, 47).tcp(22, 23, 1234, 4000);
+        let mut serialized = Vec::with_capacity(builder.size(payload.len()));
+        builder.write(&mut serialized, &payload).unwrap();
+        assert_eq!(
+            Ipv6Header::LEN + 8 + TcpHeader::MIN_LEN + payload.len(),
+            serialized.len()
+        );
+
+        let (ip, _) = IpHeader::from_slice(&serialized).unwrap();
+        let sliced = SlicedPacket::from_ip(&serialized).unwrap();
+        let tcp = match sliced.transport.as_ref().unwrap() {
+            TransportSlice::Tcp(tcp) => tcp.to_header(),
+            _ => panic!("expected tcp"),
+        };
+        match ip {
+            IpHeader::Version6(ipv6, _) => {
+                assert_eq!(
+                    tcp.checksum,
+                    tcp.calc_checksum_ipv6(&ipv6, &payload).unwrap()
+                );
+            }
+            _ => panic!("expected ipv6"),
+        }
+        assert_eq!(sliced.payload, &payload[..]);
+    }
+
+    // no transport header
+    {
+        let builder = PacketBuilder::ip(IpHeader::Version6(
+            Ipv6Header {
+                source,
+                destination,
+                hop_limit: 47,
+                ..Default::default()
+            },
+            Default::default(),
+        ));
+        let mut serialized = Vec::with_capacity(builder.size(payload.len()));
+        builder
+            .write(&mut serialized, ip_number::UDP, &payload)
+            .unwrap();
+        assert_eq!(Ipv6Header::LEN + 8 + payload.len(), serialized.len());
+
+        let sliced = SlicedPacket::from_ip(&serialized).unwrap();
+        match sliced.ip.as_ref().unwrap() {
+            InternetSlice::Ipv6(ipv6) => {
+                assert_eq!(ip_number::UDP, ipv6.payload().ip_number);
+                assert_eq!(
+                    err::LenSource::Ipv6JumboPayloadLen,
+                    ipv6.payload().len_source
+                );
+                assert_eq!(&payload[..], ipv6.payload().payload);
+            }
+            _ => panic!("expected ipv6"),
+        }
+    }
+}
+
+#[test]
+fn ipv6_mobility() {
+    // binding update with a PadN option
+    #[rustfmt::skip]
+    let mobility = Ipv6RawExtHeader::new_raw(
+        // placeholder, gets replaced by the builder
+        ip_number::UDP,
+        &[
+            5, 0, // mh type & reserved
+            0, 0, // checksum
+            0, 1, // sequence number
+            0x80, 0, // flags (A)
+            0, 10, // lifetime
+            1, 2, 0, 0, // PadN mobility option
+        ],
+    )
+    .unwrap();
+
+    let builder = PacketBuilder::ip(IpHeader::Version6(
+        Ipv6Header {
+            source: [1; 16],
+            destination: [2; 16],
+            hop_limit: 47,
+            ..Default::default()
+        },
+        Ipv6Extensions {
+            mobility: Some(mobility.clone()),
+            ..Default::default()
+        },
+    ));
+    assert_eq!(Ipv6Header::LEN + mobility.header_len(), builder.size(0));
+
+    let mut serialized = Vec::with_capacity(builder.size(0));
+    builder
+        .write(&mut serialized, ip_number::IPV6_NO_NEXT_HEADER, &[])
+        .unwrap();
+    assert_eq!(Ipv6Header::LEN + mobility.header_len(), serialized.len());
+
+    let sliced = SlicedPacket::from_ip(&serialized).unwrap();
+    let ipv6 = match sliced.ip.as_ref().unwrap() {
+        InternetSlice::Ipv6(ipv6) => ipv6,
+        _ => panic!("expected ipv6"),
+    };
+    assert_eq!(ip_number::MOBILITY, ipv6.header().next_header());
+    assert_eq!(ip_number::IPV6_NO_NEXT_HEADER, ipv6.payload().ip_number);
+    assert!(ipv6.payload().payload.is_empty());
+
+    let exts: Vec<_> = ipv6.extensions().clone().into_iter().collect();
+    assert_eq!(1, exts.len());
+    match &exts[0] {
+        Ipv6ExtensionSlice::Mobility(raw) => {
+            match Ipv6MobilityHeaderSlice::from_raw(raw).unwrap() {
+                Ipv6MobilityHeaderSlice::BindingUpdate(bu) => {
+                    assert_eq!(ip_number::IPV6_NO_NEXT_HEADER, bu.next_header());
+                    assert_eq!(1, bu.sequence_number());
+                    assert!(bu.acknowledge());
+                    assert_eq!(10, bu.lifetime());
+                    assert_eq!(
+                        vec![Ok(Ipv6MobilityOption::PadN(2))],
+                        bu.options().collect::<Vec<_>>()
+                    );
+                }
+                _ => panic!("expected a binding update"),
+            }
+        }
+        _ => panic!("expected a mobility header"),
+    }
+}
+
+#[test]
+fn eth_vlan_ipv4_udp_fragments() {
+    use etherparse::{defrag::Ipv4DefragPool, frag::IpFragmenter};
+
+    let payload: Vec<u8> = (0..3000).map(|i| i as u8).collect();
+    let builder = PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+        .single_vlan(0x123.try_into().unwrap())
+        .ipv4([192, 168, 1, 1], [192, 168, 1, 2], 21)
+        .udp(22, 23);
+    let frames = builder
+        .build_fragments(IpFragmenter::new(1500, 1234), &payload)
+        .unwrap();
+
+    // 3008 bytes udp: 1480 + 1480 + 48
+    assert_eq!(3, frames.len());
+
+    let mut pool = Ipv4DefragPool::default();
+    let mut reassembled = None;
+    for (i, frame) in frames.iter().enumerate() {
+        let sliced = SlicedPacket::from_ethernet(frame).unwrap();
+        assert_eq!(
+            0x123,
+            match sliced.vlan {
+                Some(VlanSlice::SingleVlan(vlan)) => vlan.vlan_identifier().value(),
+                _ => panic!("expected a single vlan header"),
+            }
+        );
+        let ipv4 = match sliced.ip.as_ref().unwrap() {
+            InternetSlice::Ipv4(ipv4) => ipv4,
+            _ => panic!("expected ipv4"),
+        };
+        assert!(ipv4.header().slice().len() + ipv4.payload().payload.len() <= 1500);
+        assert_eq!(1234, ipv4.header().identification());
+        assert_eq!(i < 2, ipv4.header().more_fragments());
+        assert_eq!((i * 185) as u16, ipv4.header().fragments_offset().value());
+        reassembled = pool.process(0, ipv4).unwrap();
+    }
+
+    // check that the reassembled udp packet is valid
+    let reassembled = reassembled.unwrap();
+    let sliced = SlicedPacket::from_ip(&reassembled).unwrap();
+    let udp = match sliced.transport.unwrap() {
+        TransportSlice::Udp(udp) => udp,
+        _ => panic!("expected udp"),
+    };
+    assert_eq!(3008, udp.length());
+    let ipv4 = sliced.ip.as_ref().unwrap().ipv4().unwrap();
+    assert_eq!(
+        udp.checksum(),
+        udp.to_header()
+            .calc_checksum_ipv4(&ipv4.header().to_header(), &payload)
+            .unwrap()
+    );
+    assert_eq!(&payload[..], sliced.payload);
+}
+
+#[test]
+fn eth_fcs_ipv6_tcp_fragments() {
+    use etherparse::{defrag::Ipv6DefragPool, frag::IpFragmenter};
+
+    let payload: Vec<u8> = (0..2000).map(|i| i as u8).collect();
+    let builder = PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+        .fcs()
+        .ipv6([1; 16], [2; 16], 21)
+        .tcp(22, 23, 24, 25);
+    let frames = builder
+        .build_fragments(IpFragmenter::new(1280, 1234), &payload)
+        .unwrap();
+
+    // 2020 bytes tcp: 1232 + 788
+    assert_eq!(2, frames.len());
+
+    let mut pool = Ipv6DefragPool::default();
+    let mut reassembled = None;
+    for frame in frames.iter() {
+        assert!(frame.len() - Ethernet2Header::LEN - Ethernet2Header::FCS_LEN <= 1280);
+
+        // slicing with fcs verification
+        let sliced = SlicedPacket::from_ethernet_with_fcs(frame).unwrap();
+        let ipv6 = sliced.ip.as_ref().unwrap().ipv6().unwrap();
+        assert!(ipv6.is_payload_fragmented());
+        reassembled = pool.process(0, ipv6).unwrap();
+    }
+
+    let reassembled = reassembled.unwrap();
+    let sliced = SlicedPacket::from_ip(&reassembled).unwrap();
+    let tcp = match sliced.transport.unwrap() {
+        TransportSlice::Tcp(tcp) => tcp,
+        _ => panic!("expected tcp"),
+    };
+    let ipv6 = sliced.ip.as_ref().unwrap().ipv6().unwrap();
+    assert_eq!(
+        tcp.checksum(),
+        tcp.to_header()
+            .calc_checksum_ipv6(&ipv6.header().to_header(), &payload)
+            .unwrap()
+    );
+    assert_eq!(&payload[..], sliced.payload);
+}
+
+#[test]
+fn ip_fragments() {
+    use etherparse::frag::IpFragmenter;
+
+    // packet fitting into the mtu
+    {
+        let builder = PacketBuilder::ipv4([1, 2, 3, 4], [5, 6, 7, 8], 20);
+        let frames = builder
+            .build_fragments(IpFragmenter::new(1500, 1), ip_number::UDP, &[1, 2, 3, 4])
+            .unwrap();
+        let mut expected = Vec::new();
+        PacketBuilder::ipv4([1, 2, 3, 4], [5, 6, 7, 8], 20)
+            .write(&mut expected, ip_number::UDP, &[1, 2, 3, 4])
+            .unwrap();
+        assert_eq!(vec![expected], frames);
+    }
+
+    // error
+    {
+        let builder = PacketBuilder::ipv4([1, 2, 3, 4], [5, 6, 7, 8], 20);
+        assert_eq!(
+            Some(&err::ip_frag::IpFragError::MtuTooSmall {
+                mtu: 20,
+                min_mtu: 28
+            }),
+            builder
+                .build_fragments(IpFragmenter::new(20, 1), ip_number::UDP, &[1, 2, 3, 4])
+                .unwrap_err()
+                .ip_frag()
+        );
+    }
+}
+
+#[test]
+fn ip_tunnels() {
+    let payload = [1, 2, 3, 4, 5, 6, 7, 8];
+
+    // 4in6 with udp
+    {
+        let builder = PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+            .ipv6([11; 16], [12; 16], 13)
+            .ipv4([14, 15, 16, 17], [18, 19, 20, 21], 22)
+            .udp(23, 24);
+        assert_eq!(
+            Ethernet2Header::LEN
+                + Ipv6Header::LEN
+                + Ipv4Header::MIN_LEN
+                + UdpHeader::LEN
+                + payload.len(),
+            builder.size(payload.len())
+        );
+        let mut serialized = Vec::with_capacity(builder.size(payload.len()));
+        builder.write(&mut serialized, &payload).unwrap();
+        assert_eq!(
+            Ethernet2Header::LEN
+                + Ipv6Header::LEN
+                + Ipv4Header::MIN_LEN
+                + UdpHeader::LEN
+                + payload.len(),
+            serialized.len()
+        );
+
+        let headers = PacketHeaders::from_ethernet_slice(&serialized)
+            .unwrap()
+            .decode_ip_tunnels(1)
+            .unwrap();
+        assert_eq!(
+            headers.link.unwrap().ethernet2().unwrap().ether_type,
+            ether_type::IPV6
+        );
+        match headers.ip.unwrap() {
+            IpHeader::Version6(ipv6, _) => {
+                assert_eq!(ip_number::IPV4, ipv6.next_header);
+                assert_eq!(
+                    (Ipv4Header::MIN_LEN + UdpHeader::LEN + payload.len()) as u16,
+                    ipv6.payload_length
+                );
+            }
+            _ => panic!("expected ipv6 header"),
+        }
+        let ipv4 = match headers.inner_ip.unwrap() {
+            IpHeader::Version4(ipv4, _) => ipv4,
+            _ => panic!("expected ipv4 header"),
+        };
+        assert_eq!(ip_number::UDP, ipv4.protocol);
+        assert_eq!(
+            (Ipv4Header::MIN_LEN + UdpHeader::LEN + payload.len()) as u16,
+            ipv4.total_len
+        );
+        assert_eq!(ipv4.calc_header_checksum(), ipv4.header_checksum);
+        let udp = headers.transport.unwrap().udp().unwrap();
+        assert_eq!(23, udp.source_port);
+        assert_eq!(
+            udp.checksum,
+            udp.calc_checksum_ipv4(&ipv4, &payload).unwrap()
+        );
+        assert_eq!(headers.payload, &payload);
+    }
+
+    // nested 6in4 in ipv4 with tcp & pppoe
+    {
+        let builder = PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+            .pppoe_session(0x1234)
+            .ipv4([13, 14, 15, 16], [17, 18, 19, 20], 21)
+            .ipv4([22, 23, 24, 25], [26, 27, 28, 29], 30)
+            .ipv6([31; 16], [32; 16], 33)
+            .tcp(34, 35, 36, 37);
+        let ip_size =
+            2 * Ipv4Header::MIN_LEN + Ipv6Header::LEN + TcpHeader::MIN_LEN + payload.len();
+        assert_eq!(
+            Ethernet2Header::LEN + PppoeHeader::LEN + PppHeader::LEN + ip_size,
+            builder.size(payload.len())
+        );
+        let mut serialized = Vec::with_capacity(builder.size(payload.len()));
+        builder.write(&mut serialized, &payload).unwrap();
+
+        let sliced = SlicedPacket::from_ethernet(&serialized).unwrap();
+        assert_eq!(PppProtocol::IPV4, sliced.ppp.as_ref().unwrap().protocol());
+        assert_eq!(
+            (PppHeader::LEN + ip_size) as u16,
+            sliced.pppoe.as_ref().unwrap().payload_length()
+        );
+        assert_eq!(
+            Some(TransportSlice::Unknown(ip_number::IPV4)),
+            sliced.transport
+        );
+
+        // outer header
+        let outer = sliced.ip.as_ref().unwrap().ipv4().unwrap().header();
+        assert_eq!(ip_size as u16, outer.total_len());
+        assert_eq!(
+            outer.to_header().calc_header_checksum(),
+            outer.header_checksum()
+        );
+
+        // middle header
+        let middle = Ipv4HeaderSlice::from_slice(sliced.payload).unwrap();
+        assert_eq!(ip_number::IPV6, middle.protocol());
+        assert_eq!((ip_size - Ipv4Header::MIN_LEN) as u16, middle.total_len());
+        assert_eq!(
+            middle.to_header().calc_header_checksum(),
+            middle.header_checksum()
+        );
+
+        // inner header & transport
+        let sliced = sliced.decode_ip_tunnels(2).unwrap();
+        let inner = sliced.inner_ip.as_ref().unwrap().ipv6().unwrap().header();
+        assert_eq!(ip_number::TCP, inner.next_header());
+        assert_eq!(
+            (TcpHeader::MIN_LEN + payload.len()) as u16,
+            inner.payload_length()
+        );
+        let tcp = match sliced.transport.as_ref().unwrap() {
+            TransportSlice::Tcp(tcp) => tcp.to_header(),
+            _ => panic!("expected tcp"),
+        };
+        assert_eq!(34, tcp.source_port);
+        assert_eq!(
+            tcp.checksum,
+            tcp.calc_checksum_ipv6(&inner.to_header(), &payload)
+                .unwrap()
+        );
+        assert_eq!(sliced.payload, &payload);
+    }
+
+    // fragmented tunnel (the outer header gets fragmented)
+    {
+        use etherparse::{defrag::Ipv4DefragPool, frag::IpFragmenter};
+
+        let payload: Vec<u8> = (0..1000).map(|i| i as u8).collect();
+        let frames = PacketBuilder::ipv4([1, 2, 3, 4], [5, 6, 7, 8], 9)
+            .ipv6([10; 16], [11; 16], 12)
+            .udp(13, 14)
+            .build_fragments(IpFragmenter::new(600, 15), &payload)
+            .unwrap();
+        assert_eq!(2, frames.len());
+
+        let mut pool = Ipv4DefragPool::default();
+        let mut reassembled = None;
+        for frame in frames.iter() {
+            assert!(frame.len() <= 600);
+            let sliced = SlicedPacket::from_ip(frame).unwrap();
+            // fragmented tunnels are not decoded
+            assert_eq!(None, sliced.clone().decode_ip_tunnels(1).unwrap().inner_ip);
+            let ipv4 = sliced.ip.as_ref().unwrap().ipv4().unwrap();
+            assert_eq!(ip_number::IPV6, ipv4.header().protocol());
+            reassembled = pool.process(0, ipv4).unwrap();
+        }
+
+        let reassembled = reassembled.unwrap();
+        let sliced = SlicedPacket::from_ip(&reassembled)
+            .unwrap()
+            .decode_ip_tunnels(1)
+            .unwrap();
+        assert!(sliced.inner_ip.as_ref().unwrap().ipv6().is_some());
+        match sliced.transport.unwrap() {
+            TransportSlice::Udp(udp) => assert_eq!(13, udp.source_port()),
+            _ => panic!("expected udp"),
+        }
+        assert_eq!(&payload[..], sliced.payload);
+    }
+}
+
+#[test]
+fn gre() {
+    let payload = [1, 2, 3, 4, 5, 6, 7, 8];
+
+    // ipv6 in gre (with checksum & key) in ipv4
+    {
+        let builder = PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+            .ipv4([13, 14, 15, 16], [17, 18, 19, 20], 21)
+            .gre_header(GreHeader {
+                checksum: Some(0),
+                key: Some(0x1234_5678),
+                ..Default::default()
+            })
+            .ipv6([22; 16], [23; 16], 24)
+            .udp(25, 26);
+        let gre_len = GreHeader::MIN_LEN + 8;
+        let ip_size =
+            Ipv4Header::MIN_LEN + gre_len + Ipv6Header::LEN + UdpHeader::LEN + payload.len();
+        assert_eq!(Ethernet2Header::LEN + ip_size, builder.size(payload.len()));
+        let mut serialized = Vec::with_capacity(builder.size(payload.len()));
+        builder.write(&mut serialized, &payload).unwrap();
+        assert_eq!(Ethernet2Header::LEN + ip_size, serialized.len());
+
+        let sliced = SlicedPacket::from_ethernet(&serialized).unwrap();
+        let outer = sliced.ip.as_ref().unwrap().ipv4().unwrap().header();
+        assert_eq!(ip_number::GRE, outer.protocol());
+        assert_eq!(ip_size as u16, outer.total_len());
+
+        let gre = sliced.gre.as_ref().unwrap();
+        assert_eq!(ether_type::IPV6, gre.protocol_type());
+        assert_eq!(Some(0x1234_5678), gre.key());
+        assert!(gre.checksum().is_some());
+        assert!(gre.is_checksum_valid(sliced.payload));
+
+        let inner = sliced.slice_gre_payload().unwrap().unwrap();
+        let ipv6 = inner.ip.as_ref().unwrap().ipv6().unwrap().header();
+        assert_eq!(ip_number::UDP, ipv6.next_header());
+        assert_eq!(
+            (UdpHeader::LEN + payload.len()) as u16,
+            ipv6.payload_length()
+        );
+        let udp = match inner.transport.as_ref().unwrap() {
+            TransportSlice::Udp(udp) => udp.to_header(),
+            _ => panic!("expected udp"),
+        };
+        assert_eq!(25, udp.source_port);
+        assert_eq!(
+            udp.checksum,
+            udp.calc_checksum_ipv6(&ipv6.to_header(), &payload).unwrap()
+        );
+        assert_eq!(inner.payload, &payload);
+    }
+
+    // ipv4 in gre in ipv6 in ipv4 (gre in a nested tunnel)
+    {
+        let builder = PacketBuilder::ipv4([1, 2, 3, 4], [5, 6, 7, 8], 9)
+            .ipv6([10; 16], [11; 16], 12)
+            .gre()
+            .ipv4([13, 14, 15, 16], [17, 18, 19, 20], 21)
+            .tcp(22, 23, 24, 25);
+        let ip_size = 2 * Ipv4Header::MIN_LEN
+            + Ipv6Header::LEN
+            + GreHeader::MIN_LEN
+            + TcpHeader::MIN_LEN
+            + payload.len();
+        assert_eq!(ip_size, builder.size(payload.len()));
+        let mut serialized = Vec::with_capacity(builder.size(payload.len()));
+        builder.write(&mut serialized, &payload).unwrap();
+        assert_eq!(ip_size, serialized.len());
+
+        let sliced = SlicedPacket::from_ip(&serialized)
+            .unwrap()
+            .decode_ip_tunnels(1)
+            .unwrap();
+        let ipv6 = sliced.inner_ip.as_ref().unwrap().ipv6().unwrap().header();
+        assert_eq!(ip_number::GRE, ipv6.next_header());
+        assert_eq!(
+            (ip_size - Ipv4Header::MIN_LEN - Ipv6Header::LEN) as u16,
+            ipv6.payload_length()
+        );
+        let gre = sliced.gre.as_ref().unwrap();
+        assert_eq!(ether_type::IPV4, gre.protocol_type());
+        assert_eq!(None, gre.checksum());
+
+        let inner = sliced.slice_gre_payload().unwrap().unwrap();
+        let ipv4 = inner.ip.as_ref().unwrap().ipv4().unwrap().header();
+        assert_eq!(ip_number::TCP, ipv4.protocol());
+        match inner.transport.as_ref().unwrap() {
+            TransportSlice::Tcp(tcp) => assert_eq!(22, tcp.source_port()),
+            _ => panic!("expected tcp"),
+        }
+        assert_eq!(inner.payload, &payload);
+    }
+
+    // raw payload with a given protocol type
+    {
+        let frame = {
+            let builder = PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+                .ipv4([13, 14, 15, 16], [17, 18, 19, 20], 21)
+                .udp(22, 23);
+            let mut frame = Vec::with_capacity(builder.size(payload.len()));
+            builder.write(&mut frame, &payload).unwrap();
+            frame
+        };
+        let builder = PacketBuilder::ipv6([1; 16], [2; 16], 3).gre_header(GreHeader {
+            checksum: Some(0),
+            sequence_number: Some(4),
+            ..Default::default()
+        });
+        let ip_size = Ipv6Header::LEN + GreHeader::MIN_LEN + 8 + frame.len();
+        assert_eq!(ip_size, builder.size(frame.len()));
+        let mut serialized = Vec::with_capacity(builder.size(frame.len()));
+        builder
+            .write(
+                &mut serialized,
+                ether_type::TRANSPARENT_ETHERNET_BRIDGING,
+                &frame,
+            )
+            .unwrap();
+        assert_eq!(ip_size, serialized.len());
+
+        let sliced = SlicedPacket::from_ip(&serialized).unwrap();
+        let ipv6 = sliced.ip.as_ref().unwrap().ipv6().unwrap().header();
+        assert_eq!(ip_number::GRE, ipv6.next_header());
+        let gre = sliced.gre.as_ref().unwrap();
+        assert_eq!(
+            ether_type::TRANSPARENT_ETHERNET_BRIDGING,
+            gre.protocol_type()
+        );
+        assert_eq!(Some(4), gre.sequence_number());
+        assert!(gre.is_checksum_valid(sliced.payload));
+        assert_eq!(&frame[..], sliced.payload);
+
+        let inner = sliced.slice_gre_payload().unwrap().unwrap();
+        assert!(inner.link.is_some());
+        match inner.transport.as_ref().unwrap() {
+            TransportSlice::Udp(udp) => assert_eq!(22, udp.source_port()),
+            _ => panic!("expected udp"),
+        }
+        assert_eq!(inner.payload, &payload);
+    }
+}
+
+#[test]
+fn vxlan_geneve() {
+    let payload = [1, 2, 3, 4, 5, 6, 7, 8];
+
+    // inner ethernet frame
+    let inner_builder = PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+        .ipv4([13, 14, 15, 16], [17, 18, 19, 20], 21)
+        .udp(22, 23);
+    let mut inner_frame = Vec::with_capacity(inner_builder.size(payload.len()));
+    inner_builder.write(&mut inner_frame, &payload).unwrap();
+
+    let check_inner = |inner: SlicedPacket| {
+        assert!(inner.link.is_some());
+        let udp = match inner.transport.as_ref().unwrap() {
+            TransportSlice::Udp(udp) => udp.to_header(),
+            _ => panic!("expected udp"),
+        };
+        assert_eq!(22, udp.source_port);
+        assert_eq!(inner.payload, &payload);
+    };
+
+    // vxlan in ipv4
+    {
+        let vni = Vni::try_new(0x12_3456).unwrap();
+        let builder = PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+            .ipv4([24, 25, 26, 27], [28, 29, 30, 31], 32)
+            .udp(33, VxlanHeader::UDP_PORT)
+            .vxlan(vni);
+        let udp_len = UdpHeader::LEN + VxlanHeader::LEN + inner_frame.len();
+        let size = Ethernet2Header::LEN + Ipv4Header::MIN_LEN + udp_len;
+        assert_eq!(size, builder.size(inner_frame.len()));
+        let mut serialized = Vec::with_capacity(builder.size(inner_frame.len()));
+        builder.write(&mut serialized, &inner_frame).unwrap();
+        assert_eq!(size, serialized.len());
+
+        let sliced = SlicedPacket::from_ethernet(&serialized)
+            .unwrap()
+            .decode_udp_tunnels()
+            .unwrap();
+        let ipv4 = sliced.ip.as_ref().unwrap().ipv4().unwrap().header();
+        let udp = match sliced.transport.as_ref().unwrap() {
+            TransportSlice::Udp(udp) => udp.to_header(),
+            _ => panic!("expected udp"),
+        };
+        assert_eq!(udp_len as u16, udp.length);
+        assert_eq!(
+            udp.checksum,
+            udp.calc_checksum_ipv4(
+                &ipv4.to_header(),
+                &serialized[Ethernet2Header::LEN + Ipv4Header::MIN_LEN + UdpHeader::LEN..]
+            )
+            .unwrap()
+        );
+        assert_eq!(
+            Some(VxlanHeader::new(vni)),
+            sliced.vxlan.as_ref().map(|v| v.to_header())
+        );
+        assert_eq!(&inner_frame[..], sliced.payload);
+        check_inner(sliced.slice_vxlan_payload().unwrap().unwrap());
+    }
+
+    // geneve (with options) in ipv6
+    {
+        let geneve = GeneveHeader {
+            oam: true,
+            options: GeneveOptions::try_from(&[0x01, 0x02, 0x03, 0x01, 1, 2, 3, 4][..]).unwrap(),
+            ..GeneveHeader::new(
+                ether_type::TRANSPARENT_ETHERNET_BRIDGING,
+                Vni::try_new(1234).unwrap(),
+            )
+        };
+        let builder = PacketBuilder::ipv6([1; 16], [2; 16], 3)
+            .udp(4, GeneveHeader::UDP_PORT)
+            .geneve_header(geneve.clone());
+        let size = Ipv6Header::LEN + UdpHeader::LEN + geneve.header_len() + inner_frame.len();
+        assert_eq!(size, builder.size(inner_frame.len()));
+        let mut serialized = Vec::with_capacity(builder.size(inner_frame.len()));
+        builder.write(&mut serialized, &inner_frame).unwrap();
+        assert_eq!(size, serialized.len());
+
+        let sliced = SlicedPacket::from_ip(&serialized)
+            .unwrap()
+            .decode_udp_tunnels()
+            .unwrap();
+        assert_eq!(Some(geneve), sliced.geneve.as_ref().map(|g| g.to_header()));
+        check_inner(sliced.slice_geneve_payload().unwrap().unwrap());
+    }
+
+    // geneve with default settings
+    {
+        let builder = PacketBuilder::ipv4([1, 2, 3, 4], [5, 6, 7, 8], 9)
+            .udp(10, 11)
+            .geneve(Vni::try_new(12).unwrap());
+        let mut serialized = Vec::with_capacity(builder.size(inner_frame.len()));
+        builder.write(&mut serialized, &inner_frame).unwrap();
+
+        // port 11 is not a geneve port, force the decoding
+        let sliced = SlicedPacket::from_ip(&serialized)
+            .unwrap()
+            .decode_geneve()
+            .unwrap();
+        let geneve = sliced.geneve.as_ref().unwrap();
+        assert_eq!(12, geneve.vni().value());
+        assert_eq!(
+            ether_type::TRANSPARENT_ETHERNET_BRIDGING,
+            geneve.protocol_type()
+        );
+        assert_eq!(0, geneve.options_len());
+        check_inner(sliced.slice_geneve_payload().unwrap().unwrap());
+    }
+}
+
+#[test]
+fn gtpu() {
+    let payload = [1, 2, 3, 4, 5, 6, 7, 8];
+
+    // inner user ip packet
+    let inner_builder = PacketBuilder::ipv4([10, 0, 0, 1], [10, 0, 0, 2], 21).udp(22, 23);
+    let mut inner_packet = Vec::with_capacity(inner_builder.size(payload.len()));
+    inner_builder.write(&mut inner_packet, &payload).unwrap();
+
+    let check_inner = |inner: SlicedPacket| {
+        assert!(inner.link.is_none());
+        assert!(inner.ip.as_ref().unwrap().ipv4().is_some());
+        let udp = match inner.transport.as_ref().unwrap() {
+            TransportSlice::Udp(udp) => udp.to_header(),
+            _ => panic!("expected udp"),
+        };
+        assert_eq!(22, udp.source_port);
+        assert_eq!(inner.payload, &payload);
+    };
+
+    // gtpu with default settings in ipv4
+    {
+        let builder = PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+            .ipv4([24, 25, 26, 27], [28, 29, 30, 31], 32)
+            .udp(GtpuHeader::UDP_PORT, GtpuHeader::UDP_PORT)
+            .gtpu(0x1234_5678);
+        let udp_len = UdpHeader::LEN + GtpuHeader::MIN_LEN + inner_packet.len();
+        let size = Ethernet2Header::LEN + Ipv4Header::MIN_LEN + udp_len;
+        assert_eq!(size, builder.size(inner_packet.len()));
+        let mut serialized = Vec::with_capacity(builder.size(inner_packet.len()));
+        builder.write(&mut serialized, &inner_packet).unwrap();
+        assert_eq!(size, serialized.len());
+
+        let sliced = SlicedPacket::from_ethernet(&serialized)
+            .unwrap()
+            .decode_udp_tunnels()
+            .unwrap();
+        let udp = match sliced.transport.as_ref().unwrap() {
+            TransportSlice::Udp(udp) => udp.to_header(),
+            _ => panic!("expected udp"),
+        };
+        assert_eq!(udp_len as u16, udp.length);
+        let gtpu = sliced.gtpu.as_ref().unwrap();
+        assert_eq!(GtpuMessageType::G_PDU, gtpu.message_type());
+        assert_eq!(0x1234_5678, gtpu.teid());
+        assert_eq!(inner_packet.len(), usize::from(gtpu.length()));
+        assert_eq!(&inner_packet[..], sliced.payload);
+        check_inner(sliced.slice_gtpu_payload().unwrap().unwrap());
+    }
+
+    // gtpu with optional fields & extension headers in ipv6
+    {
+        let container = GtpuPduSessionContainer::try_new(1, 9).unwrap();
+        let gtpu = GtpuHeader {
+            sequence_number: Some(1234),
+            n_pdu_number: Some(5),
+            extension_headers: GtpuExtensionHeaders::from_pdu_session_container(container),
+            ..GtpuHeader::new(0x1234_5678)
+        };
+        let builder = PacketBuilder::ipv6([1; 16], [2; 16], 3)
+            .udp(4, GtpuHeader::UDP_PORT)
+            .gtpu_header(gtpu.clone());
+        let size = Ipv6Header::LEN + UdpHeader::LEN + gtpu.header_len() + inner_packet.len();
+        assert_eq!(size, builder.size(inner_packet.len()));
+        let mut serialized = Vec::with_capacity(builder.size(inner_packet.len()));
+        builder.write(&mut serialized, &inner_packet).unwrap();
+        assert_eq!(size, serialized.len());
+
+        let sliced = SlicedPacket::from_ip(&serialized)
+            .unwrap()
+            .decode_udp_tunnels()
+            .unwrap();
+        let expected = {
+            let mut expected = gtpu.clone();
+            expected.set_payload_len(inner_packet.len()).unwrap();
+            expected
+        };
+        let actual = sliced.gtpu.as_ref().unwrap();
+        assert_eq!(Ok(expected), actual.to_header());
+        assert_eq!(Some(container), actual.pdu_session_container());
+        check_inner(sliced.slice_gtpu_payload().unwrap().unwrap());
+    }
+
+    // payload too big for the length field
+    {
+        let builder = PacketBuilder::ipv6([1; 16], [2; 16], 3)
+            .udp(4, GtpuHeader::UDP_PORT)
+            .gtpu(1);
+        let inner_packet = vec![0u8; usize::from(u16::MAX) + 1];
+        let mut serialized = Vec::new();
+        assert_eq!(
+            builder
+                .write(&mut serialized, &inner_packet)
+                .unwrap_err()
+                .payload_len(),
+            Some(&err::ValueTooBigError {
+                actual: inner_packet.len(),
+                max_allowed: usize::from(u16::MAX),
+                value_type: err::ValueType::GtpuPayloadLength,
+            })
+        );
+    }
+}
@@ -42,6 +42,7 @@ impl ComponentTest {
         match &self.vlan {
             Some(Single(header)) => header.write(&mut buffer).unwrap(),
             Some(Double(header)) => header.write(&mut buffer).unwrap(),
+            Some(Multi(stack)) => stack.write(&mut buffer).unwrap(),
             None => {}
         }
         match &self.ip {
@@ -193,9 +194,9 @@ impl ComponentTest {
 
     /// Creates slice lengths at which an too short slice error
     /// should be triggered.
-    fn invalid_ser_lengths(&self) -> [Option<usize>; 12] {
+    fn invalid_ser_lengths(&self) -> [Option<usize>; 13] {
         struct Builder {
-            result: [Option<usize>; 12],
+            result: [Option<usize>; 13],
             next_index: usize,
             offset: usize,
         }
@@ -209,7 +210,7 @@ impl ComponentTest {
         }
 
         let mut builder = Builder {
-            result: [None; 12],
+            result: [None; 13],
             next_index: 0,
             offset: 0,
         };
@@ -225,6 +226,11 @@ impl ComponentTest {
                     builder.add(double.outer.header_len());
                     builder.add(double.inner.header_len());
                 }
+                Multi(stack) => {
+                    for tag in stack.tags() {
+                        builder.add(tag.header_len());
+                    }
+                }
             }
         }
         if let Some(ip) = self.ip.as_ref() {
@@ -267,7 +273,7 @@ impl ComponentTest {
     }
 
     fn assert_headers(&self, actual: PacketHeaders) {
-        assert_eq!(self.link, actual.link);
+        assert_eq!(self.link.clone().map(LinkHeader::Ethernet2), actual.link);
         assert_eq!(self.vlan, actual.vlan);
         assert_eq!(self.ip, self.ip);
         assert_eq!(self.transport, actual.transport);
@@ -279,8 +285,11 @@ impl ComponentTest {
         assert_eq!(result, result);
 
         //ethernet & vlan
-        assert_eq!(self.link, result.link.map(|ref x| x.to_header()));
-        assert_eq!(self.vlan, result.vlan.map(|ref x| x.to_header()));
+        assert_eq!(
+            self.link.clone().map(LinkHeader::Ethernet2),
+            result.link.map(|ref x| x.to_header())
+        );
+        assert_eq!(self.vlan, result.vlan.map(|ref x| x.to_header().unwrap()));
 
         //ip
         assert_eq!(self.ip, {
@@ -291,6 +300,7 @@ impl ComponentTest {
                     actual.header().to_header(),
                     Ipv4Extensions {
                         auth: actual.extensions().auth.map(|ref x| x.to_header()),
+                        esp: None,
                     },
                 )),
                 Some(Ipv6(actual)) => Some(Version6(
@@ -386,6 +396,31 @@ impl ComponentTest {
                 }));
                 result
             };
+        let setup_multi =
+            |outer_ether_type: EtherType, inner_ether_type: EtherType| -> ComponentTest {
+                let mut result = self.clone();
+                let mut stack = VlanStack::new();
+                stack
+                    .try_push(SingleVlanHeader {
+                        ether_type: outer_ether_type,
+                        ..outer_vlan.clone()
+                    })
+                    .unwrap();
+                stack
+                    .try_push(SingleVlanHeader {
+                        ether_type: ether_type::VLAN_TAGGED_FRAME,
+                        ..outer_vlan.clone()
+                    })
+                    .unwrap();
+                stack
+                    .try_push(SingleVlanHeader {
+                        ether_type: inner_ether_type,
+                        ..inner_vlan.clone()
+                    })
+                    .unwrap();
+                result.vlan = Some(VlanHeader::Multi(stack));
+                result
+            };
 
         //single
         setup_single(inner_vlan.ether_type).run();
@@ -400,6 +435,15 @@ impl ComponentTest {
             setup_double(*ether_type, ether_type::IPV6)
                 .run_ipv6(ipv6, ipv6_ext, udp, tcp, icmpv4, icmpv6);
         }
+
+        //multi (triple tagged)
+        for ether_type in VLAN_ETHER_TYPES {
+            setup_multi(*ether_type, inner_vlan.ether_type).run();
+            setup_multi(*ether_type, ether_type::IPV4)
+                .run_ipv4(ipv4, ipv4_ext, udp, tcp, icmpv4, icmpv6);
+            setup_multi(*ether_type, ether_type::IPV6)
+                .run_ipv6(ipv6, ipv6_ext, udp, tcp, icmpv4, icmpv6);
+        }
     }
 
     fn run_ipv4(
@@ -604,14 +648,27 @@ fn test_packet_slicing_panics() {
     let s = SlicedPacket {
         link: None,
         vlan: None,
+        macsec: None,
+        llc: None,
+        snap: None,
+        mpls: None,
+        pppoe: None,
+        ppp: None,
+        arp: None,
         ip: None,
+        inner_ip: None,
+        gre: None,
         transport: None,
+        vxlan: None,
+        geneve: None,
+        gtpu: None,
         payload: &v[..],
+        link_trailer: &[],
     };
     ComponentTest {
         link: Some(Ethernet2Header {
-            source: [0; 6],
-            destination: [0; 6],
+            source: MacAddress([0; 6]),
+            destination: MacAddress([0; 6]),
             ether_type: 0.into(),
         }),
         vlan: None,
@@ -8,19 +8,35 @@ mod packet_headers {
         let header = PacketHeaders {
             link: None,
             vlan: None,
+            llc: None,
+            snap: None,
+            mpls: None,
+            pppoe: None,
+            ppp: None,
+            arp: None,
             ip: None,
+            inner_ip: None,
             transport: None,
             payload: &[],
+            link_trailer: &[],
         };
         assert_eq!(
             &format!("{:?}", header),
             &format!(
-                "PacketHeaders {{ link: {:?}, vlan: {:?}, ip: {:?}, transport: {:?}, payload: {:?} }}",
+                "PacketHeaders {{ link: {:?}, vlan: {:?}, llc: {:?}, snap: {:?}, mpls: {:?}, pppoe: {:?}, ppp: {:?}, arp: {:?}, ip: {:?}, inner_ip: {:?}, transport: {:?}, payload: {:?}, link_trailer: {:?} }}",
                 header.link,
                 header.vlan,
+                header.llc,
+                header.snap,
+                header.mpls,
+                header.pppoe,
+                header.ppp,
+                header.arp,
                 header.ip,
+                header.inner_ip,
                 header.transport,
-                header.payload
+                header.payload,
+                header.link_trailer
             )
         );
     }
@@ -30,9 +46,17 @@ mod packet_headers {
         let header = PacketHeaders {
             link: None,
             vlan: None,
+            llc: None,
+            snap: None,
+            mpls: None,
+            pppoe: None,
+            ppp: None,
+            arp: None,
             ip: None,
+            inner_ip: None,
             transport: None,
             payload: &[],
+            link_trailer: &[],
         };
         assert_eq!(header.clone(), header);
     }
@@ -56,9 +80,17 @@ mod packet_headers {
                 PacketHeaders{
                     link: None,
                     vlan: None,
+                    llc: None,
+                    snap: None,
+                    mpls: None,
+                    pppoe: None,
+                    ppp: None,
+                    arp: None,
                     ip: None,
+                    inner_ip: None,
                     transport: None,
-                    payload: &[]
+                    payload: &[],
+                    link_trailer: &[]
                 }.payload_ether_type()
             );
 
@@ -66,11 +98,19 @@ mod packet_headers {
             assert_eq!(
                 Some(eth.ether_type),
                 PacketHeaders{
-                    link: Some(eth.clone()),
+                    link: Some(LinkHeader::Ethernet2(eth.clone())),
                     vlan: None,
+                    llc: None,
+                    snap: None,
+                    mpls: None,
+                    pppoe: None,
+                    ppp: None,
+                    arp: None,
                     ip: None,
+                    inner_ip: None,
                     transport: None,
-                    payload: &[]
+                    payload: &[],
+                    link_trailer: &[]
                 }.payload_ether_type()
             );
 
@@ -78,11 +118,19 @@ mod packet_headers {
             assert_eq!(
                 Some(vlan_outer.ether_type),
                 PacketHeaders{
-                    link: Some(eth.clone()),
+                    link: Some(LinkHeader::Ethernet2(eth.clone())),
                     vlan: Some(Single(vlan_outer.clone())),
+                    llc: None,
+                    snap: None,
+                    mpls: None,
+                    pppoe: None,
+                    ppp: None,
+                    arp: None,
                     ip: None,
+                    inner_ip: None,
                     transport: None,
-                    payload: &[]
+                    payload: &[],
+                    link_trailer: &[]
                 }.payload_ether_type()
             );
 
@@ -90,7 +138,7 @@ mod packet_headers {
             assert_eq!(
                 Some(vlan_inner.ether_type),
                 PacketHeaders{
-                    link: Some(eth.clone()),
+                    link: Some(LinkHeader::Ethernet2(eth.clone())),
                     vlan: Some(
                         Double(
                             DoubleVlanHeader {
@@ -99,9 +147,17 @@ mod packet_headers {
                             }
                         )
                     ),
+                    llc: None,
+                    snap: None,
+                    mpls: None,
+                    pppoe: None,
+                    ppp: None,
+                    arp: None,
                     ip: None,
+                    inner_ip: None,
                     transport: None,
-                    payload: &[]
+                    payload: &[],
+                    link_trailer: &[]
                 }.payload_ether_type()
             );
 
@@ -109,13 +165,21 @@ mod packet_headers {
             assert_eq!(
                 None,
                 PacketHeaders{
-                    link: Some(eth.clone()),
+                    link: Some(LinkHeader::Ethernet2(eth.clone())),
                     vlan: None,
+                    llc: None,
+                    snap: None,
+                    mpls: None,
+                    pppoe: None,
+                    ppp: None,
+                    arp: None,
                     ip: Some(
                         Version4(ipv4.clone(), Default::default())
                     ),
+                    inner_ip: None,
                     transport: None,
-                    payload: &[]
+                    payload: &[],
+                    link_trailer: &[]
                 }.payload_ether_type()
             );
 
@@ -123,15 +187,23 @@ mod packet_headers {
             assert_eq!(
                 None,
                 PacketHeaders{
-                    link: Some(eth.clone()),
+                    link: Some(LinkHeader::Ethernet2(eth.clone())),
                     vlan: None,
+                    llc: None,
+                    snap: None,
+                    mpls: None,
+                    pppoe: None,
+                    ppp: None,
+                    arp: None,
                     ip: Some(
                         Version4(ipv4.clone(), Default::default())
                     ),
+                    inner_ip: None,
                     transport: Some(
                         Udp(udp.clone())
                     ),
-                    payload: &[]
+                    payload: &[],
+                    link_trailer: &[]
                 }.payload_ether_type()
             );
         }
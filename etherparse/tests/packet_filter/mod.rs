@@ -21,7 +21,7 @@ struct PacketFilterTest {
 }
 
 impl PacketFilterTest {
-    ///Add all permutations of vlan data types to the test (none, single, double)
+    ///Add all permutations of vlan data types to the test (none, single, double, multi)
     ///and then proceeds calling "add_ip_data" with each permutations.
     fn add_vlan_data(
         &self,
@@ -53,6 +53,17 @@ impl PacketFilterTest {
             }));
             t.add_ip_data(ipv4, ipv6, udp, tcp);
         }
+        //multi
+        {
+            let mut t = self.clone();
+            t.vlan = Some(VlanHeader::Multi(
+                VlanStack::try_from(
+                    &[outer_vlan.clone(), outer_vlan.clone(), inner_vlan.clone()][..],
+                )
+                .unwrap(),
+            ));
+            t.add_ip_data(ipv4, ipv6, udp, tcp);
+        }
     }
 
     ///Add all permutations of ip data types to the test (none, v4, v6)
@@ -180,6 +191,13 @@ impl PacketFilterTest {
                 });
                 t.add_ip_filter(expected_result);
             }
+            Some(VlanHeader::Multi(stack)) => {
+                let mut t = self.clone();
+                t.filter.vlan = ElementFilter::Some(VlanFilter::Multi(
+                    stack.tags().iter().map(|_| None).collect(),
+                ));
+                t.add_ip_filter(expected_result);
+            }
             _ => {
                 //test that the filter results in a negative match
                 let mut t = self.clone();
@@ -316,8 +334,21 @@ impl PacketFilterTest {
                         DoubleVlanHeaderSlice::from_slice(&vlan_data[..]).unwrap(),
                     ))
                 }
+                Some(VlanHeader::Multi(stack)) => {
+                    stack.write(&mut vlan_data).unwrap();
+                    Some(VlanSlice::MultiVlan(
+                        VlanStackSlice::from_slice(&vlan_data[..]).unwrap(),
+                    ))
+                }
                 None => None,
             },
+            macsec: None,
+            llc: None,
+            snap: None,
+            mpls: None,
+            pppoe: None,
+            ppp: None,
+            arp: None,
             ip: match &self.ip {
                 Some(IpHeader::Version4(header, _)) => {
                     let mut header = header.clone();
@@ -338,6 +369,8 @@ impl PacketFilterTest {
 
                 None => None,
             },
+            inner_ip: None,
+            gre: None,
             transport: match &self.transport {
                 Some(TransportHeader::Icmpv4(header)) => {
                     header.write(&mut transport_data).unwrap();
@@ -365,7 +398,11 @@ impl PacketFilterTest {
                 }
                 None => None,
             },
+            vxlan: None,
+            geneve: None,
+            gtpu: None,
             payload: &payload[..],
+            link_trailer: &[],
         };
 
         assert_eq!(expected_result, self.filter.applies_to_slice(&slice));
@@ -392,7 +429,7 @@ fn test_compositions() {
             },
             &{
                 let mut re: Ipv6Header = Default::default();
-                re.next_header = ip_number::HIP;
+                re.next_header = ip_number::MANET;
                 re
             },
             &Default::default(),
@@ -419,7 +456,7 @@ fn test_compositions() {
             },
             &{
                 let mut re: Ipv6Header = Default::default();
-                re.next_header = ip_number::HIP;
+                re.next_header = ip_number::MANET;
                 re
             },
             &Default::default(),
@@ -462,7 +499,7 @@ mod link_filter {
             assert_eq!(false, Ethernet2 {
                 source: Some({
                     let mut value = eth.source;
-                    value[0] = !value[0];
+                    value.0[0] = !value.0[0];
                     value
                 }),
                 destination: Some(eth.destination)
@@ -471,7 +508,7 @@ mod link_filter {
                 source: Some(eth.source),
                 destination: Some({
                     let mut value = eth.destination;
-                    value[0] = !value[0];
+                    value.0[0] = !value.0[0];
                     value
                 })
             }.applies_to_slice(&eth_slice));
@@ -506,6 +543,18 @@ mod vlan_filter {
             let double_slice = VlanSlice::DoubleVlan(
                 DoubleVlanHeaderSlice::from_slice(&double_data[..]).unwrap()
             );
+            let multi_data = {
+                let mut multi_data = Vec::new();
+                vlan_outer.write(&mut multi_data).unwrap();
+                vlan_outer.write(&mut multi_data).unwrap();
+                vlan_inner.write(&mut multi_data).unwrap();
+                multi_data };
+            let multi_slice = VlanSlice::MultiVlan(
+                VlanStackSlice::from_slice(&multi_data[..]).unwrap()
+            );
+            let other_id = |id: VlanId| VlanId::try_new(
+                id.value().wrapping_add(1) & VlanId::MAX_U16
+            ).unwrap();
 
             //test single vlan filter with wildcards
             {
@@ -551,6 +600,52 @@ mod vlan_filter {
                     vlan_inner.vlan_id.value().wrapping_add(1) & VlanId::MAX_U16
                 ).unwrap())
             }.applies_to_slice(&double_slice));
+
+            //test multi vlan filter with wildcards
+            {
+                let wildcard = |num: usize| Multi((0..num).map(|_| None).collect());
+                assert!(wildcard(1).applies_to_slice(&single_slice));
+                assert!(wildcard(2).applies_to_slice(&double_slice));
+                assert!(wildcard(3).applies_to_slice(&multi_slice));
+                assert_eq!(false, wildcard(0).applies_to_slice(&single_slice));
+                assert_eq!(false, wildcard(2).applies_to_slice(&single_slice));
+                assert_eq!(false, wildcard(3).applies_to_slice(&double_slice));
+                assert_eq!(false, wildcard(2).applies_to_slice(&multi_slice));
+                assert_eq!(false, wildcard(4).applies_to_slice(&multi_slice));
+            }
+            //matching
+            assert!(Multi(
+                [Some(vlan_inner.vlan_id)].into_iter().collect()
+            ).applies_to_slice(&single_slice));
+            assert!(Multi(
+                [Some(vlan_outer.vlan_id), Some(vlan_inner.vlan_id)].into_iter().collect()
+            ).applies_to_slice(&double_slice));
+            assert!(Multi(
+                [Some(vlan_outer.vlan_id), None, Some(vlan_inner.vlan_id)].into_iter().collect()
+            ).applies_to_slice(&multi_slice));
+            //non matching
+            assert_eq!(false, Multi(
+                [Some(other_id(vlan_inner.vlan_id))].into_iter().collect()
+            ).applies_to_slice(&single_slice));
+            assert_eq!(false, Multi(
+                [Some(vlan_outer.vlan_id), Some(other_id(vlan_inner.vlan_id))].into_iter().collect()
+            ).applies_to_slice(&double_slice));
+            assert_eq!(false, Multi(
+                [Some(other_id(vlan_outer.vlan_id)), Some(vlan_inner.vlan_id)].into_iter().collect()
+            ).applies_to_slice(&double_slice));
+            assert_eq!(false, Multi(
+                [None, Some(other_id(vlan_outer.vlan_id)), None].into_iter().collect()
+            ).applies_to_slice(&multi_slice));
+            assert_eq!(false, Multi(
+                [None, None, Some(other_id(vlan_inner.vlan_id))].into_iter().collect()
+            ).applies_to_slice(&multi_slice));
+
+            //single & double filters don't match multi vlan slices
+            assert_eq!(false, Single(None).applies_to_slice(&multi_slice));
+            assert_eq!(false, Double {
+                outer_identifier: None,
+                inner_identifier: None
+            }.applies_to_slice(&multi_slice));
         }
     }
 }
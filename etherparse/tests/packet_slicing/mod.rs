@@ -37,19 +37,45 @@ mod sliced_packet {
         let header = SlicedPacket {
             link: None,
             vlan: None,
+            macsec: None,
+            llc: None,
+            snap: None,
+            mpls: None,
+            pppoe: None,
+            ppp: None,
+            arp: None,
             ip: None,
+            inner_ip: None,
+            gre: None,
             transport: None,
+            vxlan: None,
+            geneve: None,
+            gtpu: None,
             payload: &[],
+            link_trailer: &[],
         };
         assert_eq!(
             format!("{:?}", header),
             format!(
-                "SlicedPacket {{ link: {:?}, vlan: {:?}, ip: {:?}, transport: {:?}, payload: {:?} }}",
+                "SlicedPacket {{ link: {:?}, vlan: {:?}, macsec: {:?}, llc: {:?}, snap: {:?}, mpls: {:?}, pppoe: {:?}, ppp: {:?}, arp: {:?}, ip: {:?}, inner_ip: {:?}, gre: {:?}, transport: {:?}, vxlan: {:?}, geneve: {:?}, gtpu: {:?}, payload: {:?}, link_trailer: {:?} }}",
                 header.link,
                 header.vlan,
+                header.macsec,
+                header.llc,
+                header.snap,
+                header.mpls,
+                header.pppoe,
+                header.ppp,
+                header.arp,
                 header.ip,
+                header.inner_ip,
+                header.gre,
                 header.transport,
-                header.payload
+                header.vxlan,
+                header.geneve,
+                header.gtpu,
+                header.payload,
+                header.link_trailer
             )
         );
     }
@@ -59,9 +85,22 @@ mod sliced_packet {
         let header = SlicedPacket {
             link: None,
             vlan: None,
+            macsec: None,
+            llc: None,
+            snap: None,
+            mpls: None,
+            pppoe: None,
+            ppp: None,
+            arp: None,
             ip: None,
+            inner_ip: None,
+            gre: None,
             transport: None,
+            vxlan: None,
+            geneve: None,
+            gtpu: None,
             payload: &[],
+            link_trailer: &[],
         };
         assert_eq!(header.clone(), header);
     }
@@ -82,9 +121,22 @@ mod sliced_packet {
                 let s = SlicedPacket{
                     link: None,
                     vlan: None,
+                    macsec: None,
+                    llc: None,
+                    snap: None,
+                    mpls: None,
+                    pppoe: None,
+                    ppp: None,
+                    arp: None,
                     ip: None,
+                    inner_ip: None,
+                    gre: None,
                     transport: None,
-                    payload: &[]
+                    vxlan: None,
+                    geneve: None,
+                    gtpu: None,
+                    payload: &[],
+                    link_trailer: &[]
                 };
                 assert_eq!(None, s.payload_ether_type());
             }
@@ -146,7 +198,7 @@ mod sliced_packet {
 
             // with ip
             {
-                let builder = PacketBuilder::ethernet2(eth.source, eth.destination)
+                let builder = PacketBuilder::ethernet2(eth.source.0, eth.destination.0)
                     .ip(Version4(ipv4.clone(), Default::default()));
 
                 let mut serialized = Vec::with_capacity(builder.size(0));
@@ -162,7 +214,7 @@ mod sliced_packet {
 
             // with transport
             {
-                let builder = PacketBuilder::ethernet2(eth.source, eth.destination)
+                let builder = PacketBuilder::ethernet2(eth.source.0, eth.destination.0)
                     .ip(Version4(ipv4.clone(), Default::default()))
                     .udp(udp.source_port, udp.destination_port);
                 let mut serialized = Vec::with_capacity(builder.size(0));
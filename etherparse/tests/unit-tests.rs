@@ -29,8 +29,8 @@ fn test_debug_write() {
     //slice
     {
         let input = Ethernet2Header {
-            destination: [1, 2, 3, 4, 5, 6],
-            source: [10, 11, 12, 13, 14, 15],
+            destination: MacAddress([1, 2, 3, 4, 5, 6]),
+            source: MacAddress([10, 11, 12, 13, 14, 15]),
             ether_type: 0x0800.into(),
         };
 
@@ -45,9 +45,17 @@ fn test_debug_write() {
         let value = PacketHeaders {
             link: None,
             vlan: None,
+            llc: None,
+            snap: None,
+            mpls: None,
+            pppoe: None,
+            ppp: None,
+            arp: None,
             ip: None,
+            inner_ip: None,
             transport: None,
             payload: &dummy[..],
+            link_trailer: &[],
         };
         println!("{:?}", value);
     }
@@ -74,8 +74,8 @@ prop_compose! {
         -> Ethernet2Header
     {
         Ethernet2Header {
-            source,
-            destination,
+            source: MacAddress(source),
+            destination: MacAddress(destination),
             ether_type,
         }
     }
@@ -94,9 +94,14 @@ prop_compose! {
 pub static ETHERNET_KNOWN_ETHER_TYPES: &[EtherType] = &[
     ether_type::IPV4,
     ether_type::IPV6,
+    ether_type::ARP,
     ether_type::VLAN_TAGGED_FRAME,
     ether_type::PROVIDER_BRIDGING,
     ether_type::VLAN_DOUBLE_TAGGED_FRAME,
+    ether_type::MPLS_UNICAST,
+    ether_type::MPLS_MULTICAST,
+    ether_type::PPPOE_DISCOVERY,
+    ether_type::PPPOE_SESSION,
 ];
 
 prop_compose! {
@@ -104,12 +109,13 @@ prop_compose! {
         source in prop::array::uniform6(any::<u8>()),
         destination in prop::array::uniform6(any::<u8>()),
         ether_type in ether_type_any().prop_filter("ether_type must be unknown",
-            |v| !ETHERNET_KNOWN_ETHER_TYPES.iter().any(|&x| v == &x)))
+            // values below 0x0600 are IEEE 802.3 lengths
+            |v| v.0 >= 0x0600 && !ETHERNET_KNOWN_ETHER_TYPES.iter().any(|&x| v == &x)))
         -> Ethernet2Header
     {
         Ethernet2Header {
-            source,
-            destination,
+            source: MacAddress(source),
+            destination: MacAddress(destination),
             ether_type,
         }
     }
@@ -121,7 +127,8 @@ prop_compose! {
         drop_eligible_indicator in any::<bool>(),
         vlan_id in vlan_id_any(),
         ether_type in ether_type_any().prop_filter("ether_type must be unknown",
-            |v| !ETHERNET_KNOWN_ETHER_TYPES.iter().any(|&x| v == &x)))
+            // values below 0x0600 are IEEE 802.3 lengths
+            |v| v.0 >= 0x0600 && !ETHERNET_KNOWN_ETHER_TYPES.iter().any(|&x| v == &x)))
         -> SingleVlanHeader
     {
         SingleVlanHeader {
@@ -265,6 +272,8 @@ static IPV4_KNOWN_PROTOCOLS: &[IpNumber] = &[
     ip_number::UDP,
     ip_number::TCP,
     ip_number::AUTH,
+    ip_number::ENCAP_SEC,
+    ip_number::GRE,
     ip_number::IPV6_ICMP,
 ];
 
@@ -290,10 +299,12 @@ prop_compose! {
         if has_auth {
             Ipv4Extensions{
                 auth: Some(auth),
+                esp: None,
             }
         } else {
             Ipv4Extensions{
                 auth: None,
+                esp: None,
             }
         }
     }
@@ -301,7 +312,10 @@ prop_compose! {
 
 prop_compose! {
     pub fn ipv4_extensions_any()
-               (protocol in ip_number_any())
+               (protocol in ip_number_any().prop_filter(
+                   "protocol must not be ESP (would be parsed as an extension header)",
+                   |v| ip_number::ENCAP_SEC != *v
+               ))
                (result in ipv4_extensions_with(protocol))
                -> Ipv4Extensions
     {
@@ -371,10 +385,10 @@ static IPV6_KNOWN_NEXT_HEADERS: &[IpNumber] = &[
     ip_number::MOBILITY,
     ip_number::HIP,
     ip_number::SHIM6,
-    // currently not supported:
-    // - EncapsulatingSecurityPayload
-    // - ExperimentalAndTesting0
-    // - ExperimentalAndTesting1
+    ip_number::ENCAP_SEC,
+    ip_number::GRE,
+    ip_number::EXP0,
+    ip_number::EXP1,
 ];
 
 prop_compose! {
@@ -482,6 +496,12 @@ prop_compose! {
             } else {
                 None
             },
+            esp: None,
+            shim6: None,
+            mobility: None,
+            hip: None,
+            exp0: None,
+            exp1: None,
         };
         result.set_next_headers(next_header);
         result
@@ -575,6 +595,18 @@ prop_compose! {
     }
 }
 
+prop_compose! {
+    pub fn esp_any()(
+        spi in any::<u32>(),
+        sequence_number in any::<u32>()
+    ) -> EspHeader {
+        EspHeader {
+            spi,
+            sequence_number,
+        }
+    }
+}
+
 prop_compose! {
     pub fn udp_any()(
             source_port in any::<u16>(),